@@ -0,0 +1,114 @@
+//! Criterion benchmarks for the hot paths, run through the GraphQL schema
+//! against the test database (testcontainers, or `DATABASE_URL`'s server via
+//! the integration helpers).
+//!
+//! ```text
+//! cargo bench -p api --bench hot_paths -- --save-baseline main
+//! cargo bench -p api --bench hot_paths -- --baseline main
+//! ```
+//!
+//! Criterion reports changes against the saved baseline; the hard regression
+//! budgets live in the load tests (`tests/load`).
+
+#![recursion_limit = "512"]
+
+#[allow(dead_code)]
+#[path = "../tests/integration/common/mod.rs"]
+mod common;
+#[path = "../tests/load/fixtures.rs"]
+mod fixtures;
+
+use std::time::{Duration, Instant};
+
+use api::gql::build_schema;
+use async_graphql::Variables;
+use criterion::{criterion_group, criterion_main, Criterion};
+use serde_json::json;
+
+use common::{execute_graphql, setup_test_db};
+
+fn hot_paths(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().expect("tokio runtime");
+    let app_state = runtime.block_on(setup_test_db());
+    let schema = build_schema(app_state.clone());
+
+    let mut group = c.benchmark_group("hot_paths");
+    group
+        .sample_size(30)
+        .measurement_time(Duration::from_secs(10));
+
+    let seated = runtime.block_on(fixtures::seated_tournament(&app_state, 30, 9));
+    group.bench_function("seating_chart_30_tables", |b| {
+        b.to_async(&runtime).iter(|| async {
+            let vars = Variables::from_json(json!({ "id": seated.tournament_id }));
+            let response = execute_graphql(
+                &schema,
+                r#"query($id: UUID!) {
+                    tournamentSeatingChart(tournamentId: $id) {
+                        tables { table { id } seats { displayName player { id } } }
+                        unassignedPlayers { clubPlayerId }
+                    }
+                }"#,
+                Some(vars),
+                Some(seated.manager.clone()),
+            )
+            .await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        })
+    });
+
+    let club_id = runtime.block_on(fixtures::club_season(&app_state, 40, 300));
+    group.bench_function("leaderboard_40_events", |b| {
+        b.to_async(&runtime).iter(|| async {
+            let vars = Variables::from_json(json!({ "clubId": club_id.to_string() }));
+            let response = execute_graphql(
+                &schema,
+                r#"query($clubId: ID) {
+                    leaderboard(clubId: $clubId, period: ALL_TIME, pagination: { limit: 50, offset: 0 }) {
+                        items { rank displayName points }
+                        totalCount
+                    }
+                }"#,
+                Some(vars),
+                None,
+            )
+            .await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        })
+    });
+
+    // Every registration needs a fresh player, so each sample seeds its own
+    // tournament and accounts and only the mutations are timed.
+    group.bench_function("register_for_tournament", |b| {
+        b.to_async(&runtime).iter_custom(|iters| {
+            let (app_state, schema) = (app_state.clone(), &schema);
+            async move {
+                let seats = i32::try_from(iters).unwrap_or(i32::MAX);
+                let tournament_id = fixtures::open_tournament(&app_state, seats).await;
+                let players = fixtures::players(&app_state, seats).await;
+                let start = Instant::now();
+                for player in players {
+                    let vars = Variables::from_json(json!({
+                        "input": { "tournamentId": tournament_id.to_string() }
+                    }));
+                    let response = execute_graphql(
+                        schema,
+                        r#"mutation($input: RegisterForTournamentInput!) {
+                            registerForTournament(input: $input) { id }
+                        }"#,
+                        Some(vars),
+                        Some(player),
+                    )
+                    .await;
+                    assert!(response.errors.is_empty(), "{:?}", response.errors);
+                }
+                start.elapsed()
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, hot_paths);
+criterion_main!(benches);
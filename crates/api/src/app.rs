@@ -6,13 +6,13 @@ use async_graphql_axum::{GraphQLProtocol, GraphQLWebSocket};
 use axum::{
     extract::{Request, State, WebSocketUpgrade},
     http::{
-        header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE},
+        header::{HeaderName, HeaderValue, AUTHORIZATION, CONTENT_TYPE},
         Method, StatusCode,
     },
     middleware,
     response::{IntoResponse, Response},
     routing::{get, post},
-    Json, Router,
+    Extension, Json, Router,
 };
 use tower_governor::governor::GovernorConfigBuilder;
 use tower_governor::key_extractor::SmartIpKeyExtractor;
@@ -21,11 +21,17 @@ use tower_http::{
     cors::CorsLayer, set_header::SetResponseHeaderLayer, timeout::TimeoutLayer, trace::TraceLayer,
 };
 
+use crate::auth::jwt::DisplayClaims;
 use crate::auth::Claims;
 use crate::error::AppError;
+use crate::gql::domains::persisted_operations::allowlist::{ApiClient, CLIENT_NAME_HEADER};
+use crate::middleware::csrf::csrf_middleware;
 use crate::middleware::jwt::jwt_middleware;
+use crate::middleware::quota::{quota_middleware, Principal};
 use crate::observability::{correlation_id, render_metrics, track_metrics};
-use crate::routes::{auth, oauth_server, token, unified_auth};
+use crate::routes::{
+    auth, exports, oauth_server, printouts, receipts, seat_slips, stat_cards, token, unified_auth,
+};
 use crate::state::AppState;
 
 /// Build the Axum router with health endpoint and GraphQL
@@ -43,10 +49,11 @@ where
         .finish()
         .unwrap();
 
-    // Rate-limited auth routes (login + register POST)
+    // Rate-limited auth routes (login, register and login-link exchange POST)
     let rate_limited_routes = Router::new()
         .route("/oauth/login", post(oauth_server::login))
         .route("/oauth/register", post(oauth_server::register))
+        .route("/auth/login-link", post(token::login_link_handler))
         .layer(GovernorLayer::new(Arc::new(governor_conf)));
 
     // Coarse abuse-protection on the GraphQL endpoint. Generous limits: real-time
@@ -64,6 +71,35 @@ where
         .unwrap();
     let graphql_governor = Arc::new(graphql_governor);
 
+    // Cookie-authenticated routes, CSRF-checked against the allowed origins
+    let cookie_auth_routes = Router::new()
+        .route("/auth/refresh", post(token::refresh_handler))
+        .route("/auth/logout", post(token::logout_handler))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            csrf_middleware,
+        ));
+
+    let cors = {
+        let origins: Vec<HeaderValue> = state
+            .auth_config()
+            .security
+            .allowed_origins
+            .iter()
+            .filter_map(|o| o.parse().ok())
+            .collect();
+
+        CorsLayer::new()
+            .allow_origin(origins)
+            .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
+            .allow_headers([
+                CONTENT_TYPE,
+                AUTHORIZATION,
+                HeaderName::from_static(CLIENT_NAME_HEADER),
+            ])
+            .allow_credentials(true)
+    };
+
     Router::new()
         // Simple liveness check; also proves DB connectivity.
         .route("/health", get(health))
@@ -85,8 +121,27 @@ where
         // Rate-limited auth routes
         .merge(rate_limited_routes)
         // Refresh token and logout endpoints (no JWT auth required — uses cookie)
-        .route("/auth/refresh", post(token::refresh_handler))
-        .route("/auth/logout", post(token::logout_handler))
+        .merge(cookie_auth_routes)
+        // Printout PDFs behind signed, expiring links (no JWT: the signature
+        // is the credential)
+        .route("/printouts/{id}", get(printouts::download))
+        // Buy-in receipts as ESC/POS bytes for the desk's thermal printer,
+        // same signed-link scheme
+        .route("/receipts/{id}", get(receipts::print))
+        // Seat slips from the print queue, same scheme
+        .route("/seat-slips/{id}", get(seat_slips::print))
+        // Players' monthly stat cards as PNG for social posts, same scheme
+        .route("/stat-cards/{id}", get(stat_cards::image))
+        // Club CSV exports, streamed straight from the database (JWT, club
+        // managers only)
+        .route(
+            "/exports/clubs/{id}/results.csv",
+            get(exports::club_results),
+        )
+        .route(
+            "/exports/clubs/{id}/activity.csv",
+            get(exports::club_activity),
+        )
         // GraphQL endpoint with custom handler that includes JWT claims in context
         .route(
             "/graphql",
@@ -96,10 +151,12 @@ where
             })
             .get({
                 let schema_clone = schema.clone();
-                move |state, protocol, upgrade| {
-                    graphql_ws_handler(state, protocol, upgrade, schema_clone)
+                move |state, principal, protocol, upgrade| {
+                    graphql_ws_handler(state, principal, protocol, upgrade, schema_clone)
                 }
             })
+            // Per-user / per-client quotas, inside the per-IP governor.
+            .layer::<_, std::convert::Infallible>(middleware::from_fn(quota_middleware))
             .layer(GovernorLayer::new(graphql_governor)),
         )
         // App state (PgPool, broadcasters, etc.)
@@ -112,21 +169,7 @@ where
             StatusCode::REQUEST_TIMEOUT,
             Duration::from_secs(30),
         ))
-        .layer({
-            let allowed_origins = std::env::var("ALLOWED_ORIGINS")
-                .unwrap_or_else(|_| "http://localhost:3000,http://localhost:3001".to_string());
-
-            let origins: Vec<HeaderValue> = allowed_origins
-                .split(',')
-                .filter_map(|o| o.trim().parse().ok())
-                .collect();
-
-            CorsLayer::new()
-                .allow_origin(origins)
-                .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
-                .allow_headers([CONTENT_TYPE, AUTHORIZATION])
-                .allow_credentials(true)
-        })
+        .layer(cors)
         // Security headers on every response. The API also serves HTML (OAuth
         // login/register forms), so clickjacking protection is not theoretical.
         .layer(SetResponseHeaderLayer::if_not_present(
@@ -160,8 +203,12 @@ where
     M: ObjectType + Send + Sync + 'static,
     S: SubscriptionType + Send + Sync + 'static,
 {
-    // Extract claims from request extensions (set by JWT middleware)
+    // Extract claims from request extensions (set by JWT middleware), and
+    // the principal the quota middleware charged the request to
     let claims = req.extensions().get::<Claims>().cloned();
+    let display = req.extensions().get::<DisplayClaims>().cloned();
+    let principal = req.extensions().get::<Principal>().cloned();
+    let client = api_client(req.headers().get(CLIENT_NAME_HEADER));
 
     // Extract the GraphQL request from the HTTP request
     let (_parts, body) = req.into_parts();
@@ -177,6 +224,15 @@ where
     if let Some(claims) = claims {
         gql_request = gql_request.data(claims);
     }
+    if let Some(display) = display {
+        gql_request = gql_request.data(display);
+    }
+    if let Some(principal) = principal {
+        gql_request = gql_request.data(principal);
+    }
+    if let Some(client) = client {
+        gql_request = gql_request.data(client);
+    }
 
     // Execute the GraphQL request
     let gql_response = schema.execute(gql_request).await;
@@ -190,9 +246,12 @@ where
 }
 
 /// WebSocket handler for GraphQL subscriptions with JWT authentication.
-/// Extracts the JWT from the `connection_init` payload and injects Claims into the context.
+/// Extracts the JWT from the `connection_init` payload and injects Claims (or a paired
+/// display's DisplayClaims) into the context.
+/// Subscriptions count against the signed-in user's quota, else the client IP's.
 async fn graphql_ws_handler<Q, M, S>(
     State(state): State<AppState>,
+    principal: Option<Extension<Principal>>,
     protocol: GraphQLProtocol,
     upgrade: WebSocketUpgrade,
     schema: Schema<Q, M, S>,
@@ -203,6 +262,7 @@ where
     S: SubscriptionType + Send + Sync + 'static,
 {
     let jwt_service = state.jwt_service().clone();
+    let db = state.db.clone();
 
     upgrade
         .protocols(["graphql-transport-ws", "graphql-ws"])
@@ -211,6 +271,7 @@ where
                 .on_connection_init(move |value: serde_json::Value| {
                     async move {
                         let mut data = async_graphql::Data::default();
+                        let mut principal = principal.map(|Extension(p)| p);
 
                         // Extract token from connectionParams: { headers: { Authorization: "Bearer <token>" } }
                         let token = value
@@ -220,17 +281,38 @@ where
                             .and_then(|s| s.strip_prefix("Bearer "));
 
                         if let Some(token) = token {
-                            match jwt_service.verify_token(token) {
+                            match jwt_service.verify_current(&db, token).await {
                                 Ok(claims) => {
+                                    if let Ok(user_id) = uuid::Uuid::parse_str(&claims.sub) {
+                                        principal = Some(Principal::User(user_id));
+                                    }
                                     data.insert(claims);
                                 }
                                 Err(_) => {
-                                    return Err(async_graphql::Error::new(
-                                        "Invalid or expired token",
-                                    ));
+                                    match jwt_service.verify_display_current(&db, token).await {
+                                        Ok(display) => {
+                                            data.insert(display);
+                                        }
+                                        Err(_) => {
+                                            return Err(async_graphql::Error::new(
+                                                "Invalid or expired token",
+                                            ));
+                                        }
+                                    }
                                 }
                             }
                         }
+                        if let Some(principal) = principal {
+                            data.insert(principal);
+                        }
+                        let client = value
+                            .get("headers")
+                            .and_then(|h| h.get(CLIENT_NAME_HEADER))
+                            .and_then(|v| v.as_str())
+                            .and_then(api_client_named);
+                        if let Some(client) = client {
+                            data.insert(client);
+                        }
 
                         Ok(data)
                     }
@@ -239,6 +321,18 @@ where
         })
 }
 
+/// The API client named by the `x-client-name` header, if any.
+fn api_client(header: Option<&HeaderValue>) -> Option<ApiClient> {
+    header
+        .and_then(|v| v.to_str().ok())
+        .and_then(api_client_named)
+}
+
+fn api_client_named(name: &str) -> Option<ApiClient> {
+    let name = name.trim();
+    (!name.is_empty() && name.len() <= 100).then(|| ApiClient(name.to_string()))
+}
+
 /// Liveness + quick DB probe.
 async fn health(State(state): State<AppState>) -> Result<&'static str, AppError> {
     // Inexpensive round-trip; replace by `SELECT 1` if you prefer.
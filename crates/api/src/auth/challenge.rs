@@ -0,0 +1,290 @@
+//! Bot gate on the public `registerUser` and `requestPasswordReset` mutations
+//! (`SIGNUP_CHALLENGE`). Either a CAPTCHA token checked with its provider, or
+//! a proof-of-work puzzle the server hands out through `signupChallenge`.
+//!
+//! A puzzle is `{expires}.{salt}.{signature}`, HMAC-signed with the JWT secret
+//! so it needs no storage until it's redeemed. The client answers with
+//! `{puzzle}:{solution}` such that SHA-256 of that string starts with
+//! `difficulty` zero bits. Redeemed puzzles go to `spent_challenges` so one
+//! solution can't be replayed.
+
+use std::str::FromStr;
+use std::time::Duration as StdDuration;
+
+use anyhow::bail;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, KeyInit, Mac};
+use rand::RngExt;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+
+use crate::auth::refresh::hash_token;
+use crate::config::Env;
+use crate::error::AppError;
+
+/// How long a proof-of-work puzzle can be solved and redeemed.
+pub const POW_TTL: Duration = Duration::minutes(10);
+
+/// Which gate `SIGNUP_CHALLENGE` turns on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChallengeKind {
+    Off,
+    Turnstile,
+    HCaptcha,
+    ProofOfWork,
+}
+
+impl FromStr for ChallengeKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "off" | "none" => Ok(Self::Off),
+            "turnstile" => Ok(Self::Turnstile),
+            "hcaptcha" => Ok(Self::HCaptcha),
+            "pow" | "proof-of-work" => Ok(Self::ProofOfWork),
+            _ => bail!("expected off, turnstile, hcaptcha or pow"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ChallengeConfig {
+    pub kind: ChallengeKind,
+    /// Public key the frontend renders the CAPTCHA widget with.
+    pub captcha_site_key: Option<String>,
+    pub captcha_secret: Option<String>,
+    /// Leading zero bits a proof-of-work solution needs.
+    pub pow_difficulty: u32,
+}
+
+impl ChallengeConfig {
+    pub fn load(env: &mut Env) -> Self {
+        let kind = env.parse("SIGNUP_CHALLENGE").unwrap_or(ChallengeKind::Off);
+        let captcha_site_key = env.string("CAPTCHA_SITE_KEY");
+        let captcha_secret = env.secret("CAPTCHA_SECRET");
+        let pow_difficulty = env.parse_or("POW_DIFFICULTY", 20u32);
+
+        match kind {
+            ChallengeKind::Turnstile | ChallengeKind::HCaptcha if captcha_secret.is_none() => {
+                env.problem("SIGNUP_CHALLENGE needs CAPTCHA_SECRET for turnstile and hcaptcha");
+            }
+            ChallengeKind::ProofOfWork if !(8..=32).contains(&pow_difficulty) => {
+                env.problem(format!(
+                    "POW_DIFFICULTY: expected 8 to 32 bits, got {pow_difficulty}"
+                ));
+            }
+            _ => {}
+        }
+
+        Self {
+            kind,
+            captcha_site_key,
+            captcha_secret,
+            pow_difficulty,
+        }
+    }
+}
+
+fn mac(secret: &str, difficulty: u32, expires: i64, salt: &str) -> Hmac<Sha256> {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(format!("pow:{difficulty}:{expires}:{salt}").as_bytes());
+    mac
+}
+
+/// A fresh proof-of-work puzzle and when it stops being accepted.
+pub fn issue_puzzle(secret: &str, difficulty: u32, now: DateTime<Utc>) -> (String, DateTime<Utc>) {
+    let expires_at = now + POW_TTL;
+    let expires = expires_at.timestamp();
+    let salt = URL_SAFE_NO_PAD.encode(rand::rng().random::<[u8; 16]>());
+    let signature = URL_SAFE_NO_PAD.encode(
+        mac(secret, difficulty, expires, &salt)
+            .finalize()
+            .into_bytes(),
+    );
+    (format!("{expires}.{salt}.{signature}"), expires_at)
+}
+
+fn leading_zero_bits(digest: &[u8]) -> u32 {
+    let mut bits = 0;
+    for byte in digest {
+        if *byte == 0 {
+            bits += 8;
+        } else {
+            return bits + byte.leading_zeros();
+        }
+    }
+    bits
+}
+
+/// Whether `response` solves a genuine, unexpired puzzle of this difficulty.
+/// Returns the puzzle's expiry so the caller can mark it spent.
+pub fn check_solution(
+    secret: &str,
+    difficulty: u32,
+    response: &str,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let (puzzle, _solution) = response.rsplit_once(':')?;
+    let mut parts = puzzle.splitn(3, '.');
+    let expires: i64 = parts.next()?.parse().ok()?;
+    let salt = parts.next()?;
+    let signature = URL_SAFE_NO_PAD.decode(parts.next()?).ok()?;
+
+    if expires < now.timestamp() {
+        return None;
+    }
+    mac(secret, difficulty, expires, salt)
+        .verify_slice(&signature)
+        .ok()?;
+    if leading_zero_bits(&Sha256::digest(response.as_bytes())) < difficulty {
+        return None;
+    }
+    DateTime::<Utc>::from_timestamp(expires, 0)
+}
+
+#[derive(Deserialize)]
+struct SiteVerifyResponse {
+    success: bool,
+}
+
+/// Ask the CAPTCHA provider whether `token` is a pass. Provider outages
+/// count as failures: the gate stays closed.
+async fn verify_captcha(
+    kind: ChallengeKind,
+    secret: &str,
+    token: &str,
+    remote_ip: Option<&str>,
+) -> bool {
+    let endpoint = match kind {
+        ChallengeKind::Turnstile => "https://challenges.cloudflare.com/turnstile/v0/siteverify",
+        ChallengeKind::HCaptcha => "https://api.hcaptcha.com/siteverify",
+        _ => return false,
+    };
+    let client = match reqwest::Client::builder()
+        .timeout(StdDuration::from_secs(5))
+        .build()
+    {
+        Ok(c) => c,
+        Err(_) => return false,
+    };
+
+    let mut form = vec![("secret", secret), ("response", token)];
+    if let Some(ip) = remote_ip {
+        form.push(("remoteip", ip));
+    }
+    let response = match client.post(endpoint).form(&form).send().await {
+        Ok(r) => r,
+        Err(e) => {
+            tracing::warn!("CAPTCHA verification request failed: {e}");
+            return false;
+        }
+    };
+    match response.json::<SiteVerifyResponse>().await {
+        Ok(body) => body.success,
+        Err(e) => {
+            tracing::warn!("CAPTCHA verification response unreadable: {e}");
+            false
+        }
+    }
+}
+
+/// Whether the caller passed the configured gate. Always true when the gate
+/// is off.
+pub async fn passes(
+    pool: &PgPool,
+    config: &ChallengeConfig,
+    jwt_secret: &str,
+    response: Option<&str>,
+    remote_ip: Option<&str>,
+) -> Result<bool, AppError> {
+    if config.kind == ChallengeKind::Off {
+        return Ok(true);
+    }
+    let Some(response) = response.map(str::trim).filter(|r| !r.is_empty()) else {
+        return Ok(false);
+    };
+
+    match config.kind {
+        ChallengeKind::Off => Ok(true),
+        ChallengeKind::Turnstile | ChallengeKind::HCaptcha => {
+            let secret = config.captcha_secret.as_deref().unwrap_or_default();
+            Ok(verify_captcha(config.kind, secret, response, remote_ip).await)
+        }
+        ChallengeKind::ProofOfWork => {
+            let Some(expires_at) =
+                check_solution(jwt_secret, config.pow_difficulty, response, Utc::now())
+            else {
+                return Ok(false);
+            };
+            let (puzzle, _) = response.rsplit_once(':').unwrap_or((response, ""));
+            Ok(
+                infra::repos::spent_challenges::spend(pool, &hash_token(puzzle), expires_at)
+                    .await?,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "unit-test-secret-please-ignore";
+
+    fn solve(puzzle: &str, difficulty: u32) -> String {
+        (0u64..)
+            .map(|n| format!("{puzzle}:{n}"))
+            .find(|r| leading_zero_bits(&Sha256::digest(r.as_bytes())) >= difficulty)
+            .unwrap()
+    }
+
+    #[test]
+    fn accepts_a_solved_puzzle_until_it_expires() {
+        let now = Utc::now();
+        let (puzzle, expires_at) = issue_puzzle(SECRET, 8, now);
+        let response = solve(&puzzle, 8);
+
+        assert_eq!(
+            check_solution(SECRET, 8, &response, now).map(|t| t.timestamp()),
+            Some(expires_at.timestamp())
+        );
+        assert!(check_solution(SECRET, 8, &response, expires_at + Duration::seconds(1)).is_none());
+    }
+
+    #[test]
+    fn rejects_forged_or_unsolved_puzzles() {
+        let now = Utc::now();
+        let (puzzle, _) = issue_puzzle(SECRET, 8, now);
+        let response = solve(&puzzle, 8);
+
+        // Signed for another difficulty or with another key.
+        assert!(check_solution(SECRET, 12, &response, now).is_none());
+        assert!(check_solution("another-secret", 8, &response, now).is_none());
+
+        // Genuine puzzle, wrong answer.
+        let unsolved = (0u64..)
+            .map(|n| format!("{puzzle}:{n}"))
+            .find(|r| leading_zero_bits(&Sha256::digest(r.as_bytes())) == 0)
+            .unwrap();
+        assert!(check_solution(SECRET, 8, &unsolved, now).is_none());
+        assert!(check_solution(SECRET, 8, "garbage", now).is_none());
+    }
+
+    #[test]
+    fn parses_challenge_kinds() {
+        assert_eq!(
+            "POW".parse::<ChallengeKind>().unwrap(),
+            ChallengeKind::ProofOfWork
+        );
+        assert_eq!(
+            "turnstile".parse::<ChallengeKind>().unwrap(),
+            ChallengeKind::Turnstile
+        );
+        assert!("recaptcha".parse::<ChallengeKind>().is_err());
+    }
+}
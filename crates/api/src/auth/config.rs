@@ -1,5 +1,8 @@
-use anyhow::Result;
-use std::env;
+use anyhow::{bail, Result};
+use std::str::FromStr;
+
+use crate::auth::challenge::ChallengeConfig;
+use crate::config::Env;
 
 #[derive(Clone, Debug)]
 pub struct AuthConfig {
@@ -16,30 +19,205 @@ pub struct AuthConfig {
     pub google_client_id: String,
     pub google_client_secret: String,
     pub redirect_base_url: String,
+    /// CORS, CSRF and cookie hardening for browser clients.
+    pub security: SecurityConfig,
+    /// Bot gate on signup and password reset.
+    pub challenge: ChallengeConfig,
+}
+
+/// Deployment profile the security defaults come from (`SECURITY_PRESET`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SecurityPreset {
+    /// Local frontends on localhost over plain HTTP; no CSRF check.
+    Development,
+    /// HTTPS, but `SameSite=Lax` so preview frontends on sibling hosts work.
+    Staging,
+    /// HTTPS, `SameSite=Strict`, CSRF check on.
+    Production,
+}
+
+impl FromStr for SecurityPreset {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "development" | "dev" => Ok(Self::Development),
+            "staging" => Ok(Self::Staging),
+            "production" | "prod" => Ok(Self::Production),
+            _ => bail!("expected development, staging or production"),
+        }
+    }
+}
+
+/// `SameSite` attribute of the refresh-token cookie.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SameSite {
+    Strict,
+    Lax,
+    None,
+}
+
+impl SameSite {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SameSite::Strict => "Strict",
+            SameSite::Lax => "Lax",
+            SameSite::None => "None",
+        }
+    }
+}
+
+impl FromStr for SameSite {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "strict" => Ok(Self::Strict),
+            "lax" => Ok(Self::Lax),
+            "none" => Ok(Self::None),
+            _ => bail!("expected strict, lax or none"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SecurityConfig {
+    pub preset: SecurityPreset,
+    /// Browser origins allowed to call the API with credentials, and to use
+    /// the refresh cookie when the CSRF check is on.
+    pub allowed_origins: Vec<String>,
+    pub cookie_same_site: SameSite,
+    /// Reject cookie-authenticated `/auth/*` POSTs whose `Origin` (or
+    /// `Referer`) isn't an allowed origin.
+    pub csrf_protection: bool,
+}
+
+impl SecurityConfig {
+    /// The preset's defaults, before env overrides. Only development has
+    /// default origins; the others must list theirs in `ALLOWED_ORIGINS`.
+    pub fn preset(preset: SecurityPreset) -> Self {
+        match preset {
+            SecurityPreset::Development => Self {
+                preset,
+                allowed_origins: vec![
+                    "http://localhost:3000".to_string(),
+                    "http://localhost:3001".to_string(),
+                ],
+                cookie_same_site: SameSite::Lax,
+                csrf_protection: false,
+            },
+            SecurityPreset::Staging => Self {
+                preset,
+                allowed_origins: Vec::new(),
+                cookie_same_site: SameSite::Lax,
+                csrf_protection: true,
+            },
+            SecurityPreset::Production => Self {
+                preset,
+                allowed_origins: Vec::new(),
+                cookie_same_site: SameSite::Strict,
+                csrf_protection: true,
+            },
+        }
+    }
+
+    /// Whether cookies should carry `Secure` unless `COOKIE_SECURE` says
+    /// otherwise.
+    pub fn secure_cookies_by_default(&self) -> bool {
+        self.preset != SecurityPreset::Development
+    }
+
+    pub fn is_allowed_origin(&self, origin: &str) -> bool {
+        self.allowed_origins.iter().any(|o| o == origin)
+    }
+}
+
+/// Comma-separated origins, trimmed, without trailing slashes.
+fn parse_origins(value: &str) -> Result<Vec<String>> {
+    let origins: Vec<String> = value
+        .split(',')
+        .map(|o| o.trim().trim_end_matches('/').to_string())
+        .filter(|o| !o.is_empty())
+        .collect();
+    if origins.iter().any(|o| o == "*") {
+        // Browsers refuse a wildcard origin on credentialed requests anyway.
+        bail!("ALLOWED_ORIGINS cannot contain '*': list the frontends' origins");
+    }
+    Ok(origins)
+}
+
+impl SecurityConfig {
+    fn load(env: &mut Env) -> (Self, bool) {
+        let preset = env
+            .parse("SECURITY_PRESET")
+            .unwrap_or(SecurityPreset::Production);
+        let mut security = Self::preset(preset);
+        if let Some(origins) = env.string("ALLOWED_ORIGINS") {
+            match parse_origins(&origins) {
+                Ok(origins) => security.allowed_origins = origins,
+                Err(e) => env.problem(e.to_string()),
+            }
+        }
+        if let Some(same_site) = env.parse("COOKIE_SAME_SITE") {
+            security.cookie_same_site = same_site;
+        }
+        security.csrf_protection = env.flag("CSRF_PROTECTION", security.csrf_protection);
+        let cookie_secure = env.flag("COOKIE_SECURE", security.secure_cookies_by_default());
+        if security.cookie_same_site == SameSite::None && !cookie_secure {
+            env.problem("COOKIE_SAME_SITE=none requires COOKIE_SECURE=true");
+        }
+        if security.allowed_origins.is_empty() {
+            env.warn("ALLOWED_ORIGINS is empty: browsers can't call the API cross-origin");
+        }
+        (security, cookie_secure)
+    }
 }
 
 impl AuthConfig {
-    pub fn from_env() -> Result<Self> {
-        Ok(Self {
-            jwt_secret: env::var("JWT_SECRET").expect("JWT_SECRET must be set"),
-            access_token_expiration_minutes: env::var("ACCESS_TOKEN_EXPIRATION_MINUTES")
-                .unwrap_or_else(|_| "15".to_string())
-                .parse()
-                .unwrap_or(15),
-            refresh_token_expiration_days: env::var("REFRESH_TOKEN_EXPIRATION_DAYS")
-                .unwrap_or_else(|_| "7".to_string())
-                .parse()
-                .unwrap_or(7),
-            cookie_domain: env::var("COOKIE_DOMAIN").ok(),
-            cookie_secure: env::var("COOKIE_SECURE")
-                .unwrap_or_else(|_| "true".to_string())
-                .parse()
-                .unwrap_or(true),
-            cookie_path: env::var("COOKIE_PATH").unwrap_or_else(|_| "/auth".to_string()),
-            google_client_id: env::var("GOOGLE_CLIENT_ID").unwrap_or_default(),
-            google_client_secret: env::var("GOOGLE_CLIENT_SECRET").unwrap_or_default(),
-            redirect_base_url: env::var("REDIRECT_BASE_URL")
-                .unwrap_or_else(|_| "http://localhost:8080".to_string()),
-        })
+    pub fn load(env: &mut Env) -> Self {
+        let (security, cookie_secure) = SecurityConfig::load(env);
+        Self {
+            jwt_secret: env.required_secret("JWT_SECRET"),
+            access_token_expiration_minutes: env.parse_or("ACCESS_TOKEN_EXPIRATION_MINUTES", 15),
+            refresh_token_expiration_days: env.parse_or("REFRESH_TOKEN_EXPIRATION_DAYS", 7),
+            cookie_domain: env.string("COOKIE_DOMAIN"),
+            cookie_secure,
+            cookie_path: env.string_or("COOKIE_PATH", "/auth"),
+            google_client_id: env.string("GOOGLE_CLIENT_ID").unwrap_or_default(),
+            google_client_secret: env.secret("GOOGLE_CLIENT_SECRET").unwrap_or_default(),
+            redirect_base_url: env.string_or("REDIRECT_BASE_URL", "http://localhost:8080"),
+            security,
+            challenge: ChallengeConfig::load(env),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_tighten_towards_production() {
+        let dev = SecurityConfig::preset("dev".parse().unwrap());
+        assert!(!dev.csrf_protection);
+        assert!(!dev.secure_cookies_by_default());
+        assert!(dev.is_allowed_origin("http://localhost:3000"));
+
+        let prod = SecurityConfig::preset("Production".parse().unwrap());
+        assert!(prod.csrf_protection);
+        assert!(prod.secure_cookies_by_default());
+        assert_eq!(prod.cookie_same_site, SameSite::Strict);
+        assert!(prod.allowed_origins.is_empty());
+
+        assert!("qa".parse::<SecurityPreset>().is_err());
+    }
+
+    #[test]
+    fn parses_origin_lists() {
+        assert_eq!(
+            parse_origins(" https://app.example.com/, ,https://desk.example.com").unwrap(),
+            vec!["https://app.example.com", "https://desk.example.com"]
+        );
+        assert!(parse_origins("https://app.example.com,*").is_err());
     }
 }
@@ -1,16 +1,18 @@
+use crate::auth::AuthConfig;
+
 pub fn build_refresh_cookie(
     raw_token: &str,
     max_age_secs: Option<u64>,
-    cookie_domain: &Option<String>,
-    secure: bool,
-    path: &str,
+    config: &AuthConfig,
 ) -> String {
-    let secure_flag = if secure { "; Secure" } else { "" };
-    let same_site = if secure { "Strict" } else { "Lax" };
+    let secure_flag = if config.cookie_secure { "; Secure" } else { "" };
 
     let mut cookie = format!(
         "refresh_token={}; HttpOnly{}; SameSite={}; Path={}",
-        raw_token, secure_flag, same_site, path
+        raw_token,
+        secure_flag,
+        config.security.cookie_same_site.as_str(),
+        config.cookie_path
     );
 
     // When max_age is Some, the cookie persists across browser sessions ("remember me").
@@ -19,24 +21,25 @@ pub fn build_refresh_cookie(
         cookie.push_str(&format!("; Max-Age={}", secs));
     }
 
-    if let Some(domain) = cookie_domain {
+    if let Some(domain) = &config.cookie_domain {
         cookie.push_str(&format!("; Domain={}", domain));
     }
 
     cookie
 }
 
-pub fn build_clear_cookie(cookie_domain: &Option<String>, secure: bool, path: &str) -> String {
-    let secure_flag = if secure { "; Secure" } else { "" };
-    let same_site = if secure { "Strict" } else { "Lax" };
+pub fn build_clear_cookie(config: &AuthConfig) -> String {
+    let secure_flag = if config.cookie_secure { "; Secure" } else { "" };
 
     // Path must match the set-cookie path or the browser won't clear it.
     let mut cookie = format!(
         "refresh_token=; HttpOnly{}; SameSite={}; Path={}; Max-Age=0",
-        secure_flag, same_site, path
+        secure_flag,
+        config.security.cookie_same_site.as_str(),
+        config.cookie_path
     );
 
-    if let Some(domain) = cookie_domain {
+    if let Some(domain) = &config.cookie_domain {
         cookie.push_str(&format!("; Domain={}", domain));
     }
 
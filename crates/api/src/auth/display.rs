@@ -0,0 +1,100 @@
+//! Display pairing: a manager registers a screen and gets a short code, the
+//! screen exchanges it for a display token (`DisplayClaims`) and renews that
+//! token for as long as the device isn't revoked.
+
+use chrono::{Duration, Utc};
+use rand::RngExt;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use infra::repos::display_devices::{self, DisplayDeviceRow};
+
+use crate::auth::jwt::DisplayClaims;
+use crate::auth::refresh::hash_token;
+use crate::auth::JwtService;
+use crate::error::AppError;
+
+/// How long a pairing code can be typed into a screen.
+pub const PAIRING_CODE_TTL_MINUTES: i64 = 10;
+
+const PAIRING_CODE_LEN: usize = 8;
+
+/// Codes are read off a phone and typed on a TV remote: no 0/O or 1/I/L.
+const PAIRING_ALPHABET: &[u8] = b"ABCDEFGHJKMNPQRSTUVWXYZ23456789";
+
+/// Uppercase and drop separators, so `abcd-efgh` pairs like `ABCDEFGH`.
+pub fn normalize_code(code: &str) -> String {
+    code.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+fn pairing_code() -> String {
+    let mut rng = rand::rng();
+    (0..PAIRING_CODE_LEN)
+        .map(|_| PAIRING_ALPHABET[rng.random_range(0..PAIRING_ALPHABET.len())] as char)
+        .collect()
+}
+
+/// Register a screen for `club_id` and return it with its raw pairing code.
+pub async fn start_pairing(
+    pool: &PgPool,
+    club_id: Uuid,
+    name: &str,
+    created_by: Uuid,
+) -> Result<(DisplayDeviceRow, String), AppError> {
+    let code = pairing_code();
+    let expires_at = Utc::now() + Duration::minutes(PAIRING_CODE_TTL_MINUTES);
+
+    let device = display_devices::create(
+        pool,
+        club_id,
+        name,
+        &hash_token(&code),
+        expires_at,
+        created_by,
+    )
+    .await?;
+
+    Ok((device, code))
+}
+
+/// Use up a pairing code and mint the screen's first display token.
+pub async fn pair(
+    pool: &PgPool,
+    jwt: &JwtService,
+    code: &str,
+) -> Result<(DisplayClaims, String), AppError> {
+    // The screen is signed out, so the code is looked up outside the
+    // request's row scope.
+    let device = infra::db::without_request_scope(display_devices::pair(
+        pool,
+        &hash_token(&normalize_code(code)),
+    ))
+    .await?
+    .ok_or_else(|| AppError::Unauthorized("Invalid or expired pairing code".to_string()))?;
+
+    let claims = DisplayClaims::new(device.id, device.club_id);
+    let token = jwt.create_display_token(&claims)?;
+    Ok((claims, token))
+}
+
+/// Fresh token for a screen that is still paired.
+pub async fn renew(
+    pool: &PgPool,
+    jwt: &JwtService,
+    current: &DisplayClaims,
+) -> Result<(DisplayClaims, String), AppError> {
+    let device_id = Uuid::parse_str(&current.sub)
+        .map_err(|e| AppError::Unauthorized(format!("Invalid device ID: {}", e)))?;
+
+    display_devices::touch(pool, device_id)
+        .await?
+        .filter(|club_id| *club_id == current.club_id)
+        .ok_or_else(|| AppError::Unauthorized("Display has been revoked".to_string()))?;
+
+    let claims = DisplayClaims::new(device_id, current.club_id);
+    let token = jwt.create_display_token(&claims)?;
+    Ok((claims, token))
+}
@@ -0,0 +1,147 @@
+//! Field guards for sensitive data on shared object types.
+//!
+//! `User` and `TournamentEntry` are reachable through many parents (seating
+//! charts, registrations, results, the `users` list), so checking the query
+//! that returned them isn't enough: the field itself decides who may read it.
+//! Attach with `#[graphql(guard = "...")]`; a denied field fails with a
+//! `FORBIDDEN` error on its path, so clients should only select these fields
+//! where the viewer is entitled to them.
+
+use async_graphql::dataloader::DataLoader;
+use async_graphql::{Context, Error, ErrorExtensions, Guard, Result, ID};
+use uuid::Uuid;
+
+use crate::auth::Claims;
+use crate::gql::error::{auth_error, ResultExt};
+use crate::gql::loaders::{ManagedClubLoader, ManagedPlayerLoader, TournamentLoader};
+use crate::gql::types::{Role, User};
+
+/// The viewer's id and role, from the request's JWT claims.
+fn viewer(ctx: &Context<'_>) -> Result<(Uuid, Role)> {
+    let claims = ctx.data::<Claims>().map_err(|_| auth_error())?;
+    let user_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+    Ok((user_id, Role::from(claims.role.clone())))
+}
+
+fn forbidden() -> Error {
+    Error::new("Access denied: you are not allowed to see this field")
+        .extend_with(|_, e| e.set("code", "FORBIDDEN"))
+}
+
+/// A user's contact details: the user themself, a manager of a club they are
+/// on the roster of, or an admin.
+pub struct PersonalDataGuard {
+    user_id: ID,
+    visible: bool,
+}
+
+impl PersonalDataGuard {
+    pub fn new(user: &User) -> Self {
+        Self {
+            user_id: user.id.clone(),
+            visible: user.contact_details_visible,
+        }
+    }
+}
+
+impl Guard for PersonalDataGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        if self.visible {
+            return Ok(());
+        }
+        let (viewer_id, role) = viewer(ctx)?;
+        let user_id = Uuid::parse_str(self.user_id.as_str()).gql_err("Invalid user ID")?;
+        if viewer_id == user_id || role == Role::Admin {
+            return Ok(());
+        }
+        if role == Role::Manager {
+            let loader = ctx.data::<DataLoader<ManagedPlayerLoader>>()?;
+            let manages = loader
+                .load_one((viewer_id, user_id))
+                .await
+                .gql_err("Checking club membership failed")?;
+            if manages == Some(true) {
+                return Ok(());
+            }
+        }
+        Err(forbidden())
+    }
+}
+
+/// Details of how an account signs in: the user themself or an admin.
+pub struct AccountOwnerGuard {
+    user_id: ID,
+}
+
+impl AccountOwnerGuard {
+    pub fn new(user: &User) -> Self {
+        Self {
+            user_id: user.id.clone(),
+        }
+    }
+}
+
+impl Guard for AccountOwnerGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        let (viewer_id, role) = viewer(ctx)?;
+        let user_id = Uuid::parse_str(self.user_id.as_str()).gql_err("Invalid user ID")?;
+        if viewer_id == user_id || role == Role::Admin {
+            return Ok(());
+        }
+        Err(forbidden())
+    }
+}
+
+/// Money a player paid into a tournament: that player, a manager of the
+/// tournament's club, or an admin.
+pub struct EntryAmountGuard {
+    user_id: Option<ID>,
+    tournament_id: ID,
+}
+
+impl EntryAmountGuard {
+    pub fn new(user_id: Option<&ID>, tournament_id: &ID) -> Self {
+        Self {
+            user_id: user_id.cloned(),
+            tournament_id: tournament_id.clone(),
+        }
+    }
+}
+
+impl Guard for EntryAmountGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        let (viewer_id, role) = viewer(ctx)?;
+        let is_payer = self
+            .user_id
+            .as_ref()
+            .is_some_and(|id| Uuid::parse_str(id.as_str()) == Ok(viewer_id));
+        if is_payer || role == Role::Admin {
+            return Ok(());
+        }
+        if role == Role::Manager {
+            let tournament_id =
+                Uuid::parse_str(self.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+            let tournament = ctx
+                .data::<DataLoader<TournamentLoader>>()?
+                .load_one(tournament_id)
+                .await
+                .gql_err("Loading tournament failed")?
+                .ok_or_else(|| Error::new("Tournament not found"))?;
+            if ctx
+                .data::<Claims>()
+                .is_ok_and(|claims| claims.manages_club(tournament.club_id))
+            {
+                return Ok(());
+            }
+            let manages = ctx
+                .data::<DataLoader<ManagedClubLoader>>()?
+                .load_one((viewer_id, tournament.club_id))
+                .await
+                .gql_err("Checking club permissions failed")?;
+            if manages == Some(true) {
+                return Ok(());
+            }
+        }
+        Err(forbidden())
+    }
+}
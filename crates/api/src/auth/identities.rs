@@ -0,0 +1,69 @@
+//! Which account an external (OAuth) login belongs to.
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use infra::repos::{auth_identities, users};
+
+use crate::auth::oauth::{OAuthProvider, OAuthUserInfo};
+
+/// The account for a provider login: the linked identity first, then an
+/// account with the same email, else a new account. External identities are
+/// recorded on the way, so later logins resolve even if the provider email
+/// changes. Custom logins are this server's own accounts and match by email.
+pub async fn find_or_create_oauth_user(
+    db: &PgPool,
+    provider: &OAuthProvider,
+    info: &OAuthUserInfo,
+) -> Result<Uuid, sqlx::Error> {
+    let external = !matches!(provider, OAuthProvider::Custom);
+
+    if external {
+        if let Some(user_id) =
+            auth_identities::find_user_id(db, provider.as_str(), &info.provider_id).await?
+        {
+            return Ok(user_id);
+        }
+    }
+
+    let user_id = match users::get_by_email(db, &info.email).await? {
+        Some(user) => user.id,
+        None => create_user(db, provider, info).await?,
+    };
+
+    if external {
+        auth_identities::record(
+            db,
+            user_id,
+            provider.as_str(),
+            &info.provider_id,
+            Some(&info.email),
+        )
+        .await?;
+    }
+
+    Ok(user_id)
+}
+
+async fn create_user(
+    db: &PgPool,
+    provider: &OAuthProvider,
+    info: &OAuthUserInfo,
+) -> Result<Uuid, sqlx::Error> {
+    sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO users (email, username, first_name, last_name, oauth_provider, oauth_provider_id, avatar_url)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id
+        "#,
+    )
+    .bind(&info.email)
+    .bind(&info.username)
+    .bind(&info.first_name)
+    .bind(&info.last_name)
+    .bind(provider.as_str())
+    .bind(&info.provider_id)
+    .bind(&info.avatar_url)
+    .fetch_one(db)
+    .await
+}
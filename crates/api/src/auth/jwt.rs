@@ -1,6 +1,8 @@
 use chrono::{Duration, Utc};
+use infra::models::TokenSubjectRow;
 use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::auth::AuthConfig;
@@ -12,32 +14,76 @@ use crate::error::AppError;
 pub const TOKEN_ISSUER: &str = "pocketpair-api";
 pub const TOKEN_AUDIENCE: &str = "pocketpair-clients";
 
+/// Audience of display tokens. Kept apart from `TOKEN_AUDIENCE` so a paired
+/// screen's token never verifies as a user session, and vice versa.
+pub const DISPLAY_TOKEN_AUDIENCE: &str = "pocketpair-displays";
+
+/// Lifetime of a display token. Screens renew well before it runs out.
+pub const DISPLAY_TOKEN_TTL_MINUTES: i64 = 60;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String, // Subject (user ID)
     pub email: String,
     pub role: String,
-    pub iss: String, // Issuer
-    pub aud: String, // Audience
-    pub iat: i64,    // Issued at
-    pub exp: i64,    // Expiration
+    pub club_ids: Vec<Uuid>, // Clubs the user is assigned to manage
+    pub ver: i32,            // users.token_version at issue time
+    pub iss: String,         // Issuer
+    pub aud: String,         // Audience
+    pub iat: i64,            // Issued at
+    pub exp: i64,            // Expiration
 }
 
 impl Claims {
-    pub fn new(user_id: Uuid, email: String, role: String, expiration_minutes: u64) -> Self {
+    pub fn new(subject: &TokenSubjectRow, expiration_minutes: u64) -> Self {
         let now = Utc::now();
         let exp = now + Duration::minutes(expiration_minutes as i64);
 
         Self {
-            sub: user_id.to_string(),
-            email,
-            role,
+            sub: subject.id.to_string(),
+            email: subject.email.clone(),
+            role: subject.role.clone().unwrap_or_else(|| "player".to_string()),
+            club_ids: subject.club_ids.clone(),
+            ver: subject.token_version,
             iss: TOKEN_ISSUER.to_string(),
             aud: TOKEN_AUDIENCE.to_string(),
             iat: now.timestamp(),
             exp: exp.timestamp(),
         }
     }
+
+    /// Whether the token lists `club_id` among the user's managed clubs. Only
+    /// meaningful together with a manager or admin role.
+    pub fn manages_club(&self, club_id: Uuid) -> bool {
+        self.club_ids.contains(&club_id)
+    }
+}
+
+/// Claims of a paired display device: one screen, one club.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisplayClaims {
+    pub sub: String, // Subject (display device ID)
+    pub club_id: Uuid,
+    pub iss: String,
+    pub aud: String,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+impl DisplayClaims {
+    pub fn new(device_id: Uuid, club_id: Uuid) -> Self {
+        let now = Utc::now();
+        let exp = now + Duration::minutes(DISPLAY_TOKEN_TTL_MINUTES);
+
+        Self {
+            sub: device_id.to_string(),
+            club_id,
+            iss: TOKEN_ISSUER.to_string(),
+            aud: DISPLAY_TOKEN_AUDIENCE.to_string(),
+            iat: now.timestamp(),
+            exp: exp.timestamp(),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -57,14 +103,32 @@ impl JwtService {
         }
     }
 
-    pub fn create_token(
-        &self,
-        user_id: Uuid,
-        email: String,
-        role: String,
-    ) -> Result<String, AppError> {
-        let claims = Claims::new(user_id, email, role, self.expiration_minutes);
-        encode(&Header::default(), &claims, &self.encoding_key)
+    /// Claims for a fresh access token, read from the user's current role,
+    /// club assignments and token version.
+    pub async fn claims_for(&self, db: &PgPool, user_id: Uuid) -> Result<Claims, AppError> {
+        // Minted for signed-out callers too (login), so read the club
+        // assignments outside the request's row scope.
+        let subject =
+            infra::db::without_request_scope(infra::repos::users::get_token_subject(db, user_id))
+                .await
+                .map_err(|e| AppError::Internal(format!("DB error: {}", e)))?
+                .ok_or_else(|| AppError::Unauthorized("User not found".to_string()))?;
+
+        if !subject.is_active {
+            return Err(AppError::Unauthorized("Account is deactivated".to_string()));
+        }
+
+        Ok(Claims::new(&subject, self.expiration_minutes))
+    }
+
+    /// Mint an access token for the user as they are in the database now.
+    pub async fn create_token(&self, db: &PgPool, user_id: Uuid) -> Result<String, AppError> {
+        let claims = self.claims_for(db, user_id).await?;
+        self.sign(&claims)
+    }
+
+    fn sign(&self, claims: &Claims) -> Result<String, AppError> {
+        encode(&Header::default(), claims, &self.encoding_key)
             .map_err(|e| AppError::Internal(e.to_string()))
     }
 
@@ -78,11 +142,68 @@ impl JwtService {
 
         Ok(token_data.claims)
     }
+
+    /// `verify_token`, then reject tokens minted before the user's last role,
+    /// club-assignment or account change (which bump `users.token_version`).
+    pub async fn verify_current(&self, db: &PgPool, token: &str) -> Result<Claims, AppError> {
+        let claims = self.verify_token(token)?;
+        let user_id = Uuid::parse_str(&claims.sub)
+            .map_err(|e| AppError::Unauthorized(format!("Invalid user ID: {}", e)))?;
+
+        let version = infra::repos::users::get_token_version(db, user_id)
+            .await
+            .map_err(|e| AppError::Internal(format!("DB error: {}", e)))?;
+
+        match version {
+            Some(v) if v == claims.ver => Ok(claims),
+            Some(_) => Err(AppError::Unauthorized("Token has been revoked".to_string())),
+            None => Err(AppError::Unauthorized("User not found".to_string())),
+        }
+    }
+
+    /// Mint a display token for a paired device.
+    pub fn create_display_token(&self, claims: &DisplayClaims) -> Result<String, AppError> {
+        encode(&Header::default(), claims, &self.encoding_key)
+            .map_err(|e| AppError::Internal(e.to_string()))
+    }
+
+    pub fn verify_display_token(&self, token: &str) -> Result<DisplayClaims, AppError> {
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.set_issuer(&[TOKEN_ISSUER]);
+        validation.set_audience(&[DISPLAY_TOKEN_AUDIENCE]);
+
+        let token_data = decode::<DisplayClaims>(token, &self.decoding_key, &validation)
+            .map_err(|e| AppError::Internal(format!("Invalid token: {}", e)))?;
+
+        Ok(token_data.claims)
+    }
+
+    /// `verify_display_token`, then reject tokens of revoked devices.
+    pub async fn verify_display_current(
+        &self,
+        db: &PgPool,
+        token: &str,
+    ) -> Result<DisplayClaims, AppError> {
+        let claims = self.verify_display_token(token)?;
+        let device_id = Uuid::parse_str(&claims.sub)
+            .map_err(|e| AppError::Unauthorized(format!("Invalid device ID: {}", e)))?;
+
+        let club_id = infra::repos::display_devices::active_club_id(db, device_id)
+            .await
+            .map_err(|e| AppError::Internal(format!("DB error: {}", e)))?;
+
+        match club_id {
+            Some(club_id) if club_id == claims.club_id => Ok(claims),
+            _ => Err(AppError::Unauthorized(
+                "Display has been revoked".to_string(),
+            )),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Claims, JwtService, TOKEN_AUDIENCE, TOKEN_ISSUER};
+    use super::{Claims, DisplayClaims, JwtService, TOKEN_AUDIENCE, TOKEN_ISSUER};
     use chrono::{Duration, Utc};
     use jsonwebtoken::{encode, DecodingKey, EncodingKey, Header};
     use uuid::Uuid;
@@ -104,6 +225,8 @@ mod tests {
             sub: Uuid::new_v4().to_string(),
             email: "u@test.dev".into(),
             role: "player".into(),
+            club_ids: vec![],
+            ver: 0,
             iss: iss.into(),
             aud: aud.into(),
             iat: now.timestamp(),
@@ -123,13 +246,17 @@ mod tests {
     #[test]
     fn round_trips_a_valid_token() {
         let svc = service(SECRET);
-        let token = svc
-            .create_token(Uuid::new_v4(), "u@test.dev".into(), "player".into())
-            .unwrap();
+        let club_id = Uuid::new_v4();
+        let mut claims = claims_with(TOKEN_ISSUER, TOKEN_AUDIENCE);
+        claims.club_ids = vec![club_id];
+        claims.ver = 3;
+        let token = svc.sign(&claims).unwrap();
         let claims = svc.verify_token(&token).expect("valid token verifies");
         assert_eq!(claims.iss, TOKEN_ISSUER);
         assert_eq!(claims.aud, TOKEN_AUDIENCE);
         assert_eq!(claims.role, "player");
+        assert_eq!(claims.ver, 3);
+        assert!(claims.manages_club(club_id));
     }
 
     #[test]
@@ -140,6 +267,55 @@ mod tests {
         assert!(service(SECRET).verify_token(&token).is_err());
     }
 
+    #[test]
+    fn rejects_claims_without_version() {
+        // Tokens minted before club_ids/ver existed must not verify: they can't
+        // be checked against the user's current token version.
+        #[derive(serde::Serialize)]
+        struct Legacy<'a> {
+            sub: String,
+            email: &'a str,
+            role: &'a str,
+            iss: &'a str,
+            aud: &'a str,
+            iat: i64,
+            exp: i64,
+        }
+        let now = Utc::now();
+        let legacy = Legacy {
+            sub: Uuid::new_v4().to_string(),
+            email: "u@test.dev",
+            role: "admin",
+            iss: TOKEN_ISSUER,
+            aud: TOKEN_AUDIENCE,
+            iat: now.timestamp(),
+            exp: (now + Duration::minutes(15)).timestamp(),
+        };
+        let token = encode(
+            &Header::default(),
+            &legacy,
+            &EncodingKey::from_secret(SECRET.as_bytes()),
+        )
+        .unwrap();
+        assert!(service(SECRET).verify_token(&token).is_err());
+    }
+
+    #[test]
+    fn keeps_display_and_user_tokens_apart() {
+        let svc = service(SECRET);
+        let club_id = Uuid::new_v4();
+        let display = svc
+            .create_display_token(&DisplayClaims::new(Uuid::new_v4(), club_id))
+            .unwrap();
+        assert_eq!(svc.verify_display_token(&display).unwrap().club_id, club_id);
+        assert!(svc.verify_token(&display).is_err());
+
+        let user = svc
+            .sign(&claims_with(TOKEN_ISSUER, TOKEN_AUDIENCE))
+            .unwrap();
+        assert!(svc.verify_display_token(&user).is_err());
+    }
+
     #[test]
     fn rejects_wrong_issuer() {
         let token = sign(SECRET, &claims_with("another-service", TOKEN_AUDIENCE));
@@ -160,7 +336,7 @@ mod tests {
     fn rejects_tampered_token() {
         let svc = service(SECRET);
         let mut token = svc
-            .create_token(Uuid::new_v4(), "u@test.dev".into(), "player".into())
+            .sign(&claims_with(TOKEN_ISSUER, TOKEN_AUDIENCE))
             .unwrap();
         token.push('x'); // corrupt the trailing signature segment
         assert!(svc.verify_token(&token).is_err());
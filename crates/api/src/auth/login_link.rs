@@ -0,0 +1,50 @@
+//! Passwordless sign-in: single-use links emailed on request and exchanged
+//! for a session at `POST /auth/login-link`.
+
+use chrono::{Duration, Utc};
+use rand::distr::Alphanumeric;
+use rand::RngExt;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use infra::repos::login_link_tokens;
+
+use crate::auth::refresh::hash_token;
+use crate::error::AppError;
+
+/// How long an emailed link stays valid.
+pub const LINK_TTL_MINUTES: i64 = 15;
+
+/// Links a user can be sent per hour; further requests are silently dropped
+/// so the mutation can't be used to flood an inbox.
+pub const MAX_LINKS_PER_HOUR: i64 = 5;
+
+/// Issue a link for `user_id`, retiring any earlier unused one. `None` when
+/// the user hit the hourly cap.
+pub async fn issue(pool: &PgPool, user_id: Uuid) -> Result<Option<String>, AppError> {
+    let recent =
+        login_link_tokens::created_since_count(pool, user_id, Utc::now() - Duration::hours(1))
+            .await?;
+    if recent >= MAX_LINKS_PER_HOUR {
+        return Ok(None);
+    }
+
+    login_link_tokens::invalidate_for_user(pool, user_id).await?;
+
+    let raw_token: String = rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(64)
+        .map(char::from)
+        .collect();
+    let expires_at = Utc::now() + Duration::minutes(LINK_TTL_MINUTES);
+    login_link_tokens::create(pool, &hash_token(&raw_token), user_id, expires_at).await?;
+
+    Ok(Some(raw_token))
+}
+
+/// Use up a link and return the user it signs in.
+pub async fn redeem(pool: &PgPool, raw_token: &str) -> Result<Uuid, AppError> {
+    login_link_tokens::consume(pool, &hash_token(raw_token))
+        .await?
+        .ok_or_else(|| AppError::Unauthorized("Invalid or expired login link".to_string()))
+}
@@ -1,8 +1,13 @@
+pub mod challenge;
 pub mod config;
 pub mod cookie;
 pub mod custom_oauth;
+pub mod display;
+pub mod guards;
+pub mod identities;
 pub mod jwt;
 pub mod lockout;
+pub mod login_link;
 pub mod oauth;
 pub mod password;
 pub mod permissions;
@@ -1,4 +1,6 @@
+use crate::auth::jwt::DisplayClaims;
 use crate::auth::Claims;
+use crate::gql::error::auth_error;
 use crate::gql::types::{Role, User};
 use crate::state::AppState;
 use async_graphql::{Context, Error, Result};
@@ -74,6 +76,16 @@ pub async fn require_club_manager(ctx: &Context<'_>, club_id: Uuid) -> Result<Us
         return Ok(user);
     }
 
+    // Club assignments travel in the token; the middleware has already rejected
+    // tokens older than the user's last assignment change. Fall back to the
+    // database when the token doesn't list the club.
+    if ctx
+        .data::<Claims>()
+        .is_ok_and(|claims| claims.manages_club(club_id))
+    {
+        return Ok(user);
+    }
+
     let state = ctx.data::<AppState>()?;
 
     let user_id = Uuid::parse_str(user.id.as_str())
@@ -95,6 +107,36 @@ pub async fn require_club_manager(ctx: &Context<'_>, club_id: Uuid) -> Result<Us
     Ok(user)
 }
 
+/// Check if the authenticated user is an admin of an organization. Global
+/// admins pass for every organization.
+pub async fn require_organization_admin(ctx: &Context<'_>, organization_id: Uuid) -> Result<User> {
+    let user = require_role(ctx, Role::Player).await?;
+
+    if user.role == Role::Admin {
+        return Ok(user);
+    }
+
+    let state = ctx.data::<AppState>()?;
+
+    let user_id = Uuid::parse_str(user.id.as_str())
+        .map_err(|e| Error::new(format!("Invalid user ID: {}", e)))?;
+
+    let is_admin = infra::repos::organizations::is_admin(&state.db, organization_id, user_id)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check organization admin status: {}", e);
+            Error::new("Failed to verify organization permissions")
+        })?;
+
+    if !is_admin {
+        return Err(Error::new(
+            "Access denied: you are not an admin of this organization",
+        ));
+    }
+
+    Ok(user)
+}
+
 /// Check if the authenticated user is an admin (global access)
 pub async fn require_admin(ctx: &Context<'_>) -> Result<User> {
     require_role(ctx, Role::Admin).await
@@ -142,6 +184,9 @@ pub async fn viewer_manages_club(ctx: &Context<'_>, club_id: Uuid) -> bool {
     if role != Role::Manager {
         return false;
     }
+    if claims.manages_club(club_id) {
+        return true;
+    }
     let Ok(user_id) = Uuid::parse_str(&claims.sub) else {
         return false;
     };
@@ -153,6 +198,45 @@ pub async fn viewer_manages_club(ctx: &Context<'_>, club_id: Uuid) -> bool {
         .unwrap_or(false)
 }
 
+/// Soft check: is the viewer a display paired to `club_id`?
+pub fn viewer_is_display_of(ctx: &Context<'_>, club_id: Uuid) -> bool {
+    ctx.data::<DisplayClaims>()
+        .map(|d| d.club_id == club_id)
+        .unwrap_or(false)
+}
+
+/// Let through any signed-in user, or a display paired to `club_id`. Guards
+/// the queries and subscriptions a lobby screen renders; display tokens reach
+/// nothing else.
+pub fn require_viewer_or_display(ctx: &Context<'_>, club_id: Uuid) -> Result<()> {
+    if ctx.data::<Claims>().is_ok() {
+        return Ok(());
+    }
+    match ctx.data::<DisplayClaims>() {
+        Ok(display) if display.club_id == club_id => Ok(()),
+        Ok(_) => Err(Error::new("This display isn't paired to this club")),
+        Err(_) => Err(auth_error()),
+    }
+}
+
+/// `require_viewer_or_display` for the club hosting `tournament_id`.
+pub async fn require_viewer_or_display_for_tournament(
+    ctx: &Context<'_>,
+    tournament_id: Uuid,
+) -> Result<()> {
+    if ctx.data::<Claims>().is_ok() {
+        return Ok(());
+    }
+    if ctx.data::<DisplayClaims>().is_err() {
+        return Err(auth_error());
+    }
+    let state = ctx.data::<AppState>()?;
+    let tournament = infra::repos::tournaments::get_by_id(&state.db, tournament_id)
+        .await?
+        .ok_or_else(|| Error::new("Tournament not found"))?;
+    require_viewer_or_display(ctx, tournament.club_id)
+}
+
 fn has_required_role(user_role: &Role, required_role: Role) -> bool {
     match required_role {
         Role::Admin => *user_role == Role::Admin,
@@ -0,0 +1,401 @@
+//! Typed configuration, read from the environment once at startup.
+//!
+//! Every setting goes through [`Env`], which
+//! - accepts `NAME_FILE` naming a file that holds the value (Docker and
+//!   Kubernetes secrets) wherever `NAME` is accepted,
+//! - collects every missing or invalid value, so a bad deploy fails once
+//!   listing all of them rather than on the first, and
+//! - records each setting's effective value and where it came from for the
+//!   admin `configDiagnostics` query, with secrets redacted.
+//!
+//! Each component keeps its own config struct and `load(&mut Env)` next to
+//! its code; [`Config::from_env`] gathers them.
+
+use std::fmt;
+use std::str::FromStr;
+
+use infra::pii::Keyring;
+
+use crate::auth::AuthConfig;
+use crate::gql::GraphqlConfig;
+use crate::grpc::GrpcConfig;
+use crate::middleware::quota::QuotaConfig;
+use crate::observability::SentryConfig;
+use crate::services::{EmailConfig, MqttConfig, OpenRouterConfig, RetentionConfig, SmsConfig};
+
+/// Where a setting's value came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Source {
+    Env,
+    /// Read from the file named by `NAME_FILE`.
+    File,
+    /// Not set; the built-in default applies.
+    Default,
+    /// Not set, and there is no default.
+    Unset,
+}
+
+/// One setting as the server resolved it.
+#[derive(Clone, Debug)]
+pub struct Setting {
+    pub name: String,
+    /// `None` when unset, and for secrets.
+    pub value: Option<String>,
+    pub source: Source,
+    pub secret: bool,
+}
+
+/// Every problem found while loading the configuration.
+#[derive(Debug)]
+pub struct ConfigError {
+    pub problems: Vec<String>,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid configuration:")?;
+        for problem in &self.problems {
+            write!(f, "\n  - {problem}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Reads settings from the process environment, recording what it read.
+#[derive(Default)]
+pub struct Env {
+    settings: Vec<Setting>,
+    problems: Vec<String>,
+    warnings: Vec<String>,
+}
+
+impl Env {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `NAME`, else the contents of the file named by `NAME_FILE`. Blank
+    /// values count as unset.
+    fn lookup(&mut self, name: &str) -> Option<(String, Source)> {
+        if let Some(value) = std::env::var(name).ok().filter(|v| !v.trim().is_empty()) {
+            return Some((value, Source::Env));
+        }
+        let file_var = format!("{name}_FILE");
+        let path = std::env::var(&file_var)
+            .ok()
+            .filter(|p| !p.trim().is_empty())?;
+        match std::fs::read_to_string(path.trim()) {
+            Ok(contents) => {
+                let value = contents.trim_end_matches(['\r', '\n']).to_string();
+                if value.trim().is_empty() {
+                    self.problem(format!("{file_var}: {path} is empty"));
+                    return None;
+                }
+                Some((value, Source::File))
+            }
+            Err(e) => {
+                self.problem(format!("{file_var}: can't read {path}: {e}"));
+                None
+            }
+        }
+    }
+
+    fn record(&mut self, name: &str, value: Option<&str>, source: Source, secret: bool) {
+        self.settings.push(Setting {
+            name: name.to_string(),
+            value: value.map(str::to_string),
+            source,
+            secret,
+        });
+    }
+
+    fn read(&mut self, name: &str, secret: bool) -> Option<String> {
+        match self.lookup(name) {
+            Some((value, source)) => {
+                self.record(name, Some(&value), source, secret);
+                Some(value)
+            }
+            None => {
+                self.record(name, None, Source::Unset, secret);
+                None
+            }
+        }
+    }
+
+    /// An optional setting.
+    pub fn string(&mut self, name: &str) -> Option<String> {
+        self.read(name, false)
+    }
+
+    /// An optional setting that must never be shown.
+    pub fn secret(&mut self, name: &str) -> Option<String> {
+        self.read(name, true)
+    }
+
+    /// A secret the server can't run without; missing ones are reported and
+    /// come back empty.
+    pub fn required_secret(&mut self, name: &str) -> String {
+        self.secret(name).unwrap_or_else(|| {
+            self.problem(format!("{name} is required (set it, or {name}_FILE)"));
+            String::new()
+        })
+    }
+
+    /// A setting with a default.
+    pub fn string_or(&mut self, name: &str, default: &str) -> String {
+        match self.lookup(name) {
+            Some((value, source)) => {
+                self.record(name, Some(&value), source, false);
+                value
+            }
+            None => {
+                self.record(name, Some(default), Source::Default, false);
+                default.to_string()
+            }
+        }
+    }
+
+    /// An optional setting parsed as `T`; unparsable values are reported.
+    pub fn parse<T>(&mut self, name: &str) -> Option<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let raw = self.string(name)?;
+        match raw.trim().parse() {
+            Ok(value) => Some(value),
+            Err(e) => {
+                self.problem(format!("{name}: invalid value '{raw}' ({e})"));
+                None
+            }
+        }
+    }
+
+    /// A setting parsed as `T`, with a default.
+    pub fn parse_or<T>(&mut self, name: &str, default: T) -> T
+    where
+        T: FromStr + fmt::Display,
+        T::Err: fmt::Display,
+    {
+        let raw = self.string_or(name, &default.to_string());
+        match raw.trim().parse() {
+            Ok(value) => value,
+            Err(e) => {
+                self.problem(format!("{name}: invalid value '{raw}' ({e})"));
+                default
+            }
+        }
+    }
+
+    /// An on/off setting: `true`/`false`, `1`/`0`, `yes`/`no` or `on`/`off`.
+    pub fn flag(&mut self, name: &str, default: bool) -> bool {
+        let raw = self.string_or(name, if default { "true" } else { "false" });
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "true" | "1" | "yes" | "on" => true,
+            "false" | "0" | "no" | "off" => false,
+            _ => {
+                self.problem(format!("{name}: expected true or false, got '{raw}'"));
+                default
+            }
+        }
+    }
+
+    /// Whether all of `names` (already read) are set. Setting only some of
+    /// them is reported, since it's almost certainly a half-done deploy.
+    pub fn all_or_none(&mut self, names: &[&str]) -> bool {
+        let missing: Vec<&str> = names
+            .iter()
+            .copied()
+            .filter(|name| {
+                !self
+                    .settings
+                    .iter()
+                    .any(|s| s.name == *name && matches!(s.source, Source::Env | Source::File))
+            })
+            .collect();
+        if !missing.is_empty() && missing.len() < names.len() {
+            self.problem(format!(
+                "{} must be set together (missing {})",
+                names.join(", "),
+                missing.join(", ")
+            ));
+        }
+        missing.is_empty()
+    }
+
+    pub fn problem(&mut self, message: impl Into<String>) {
+        self.problems.push(message.into());
+    }
+
+    /// Something allowed but probably unintended, logged once tracing is up.
+    pub fn warn(&mut self, message: impl Into<String>) {
+        self.warnings.push(message.into());
+    }
+}
+
+/// The whole server configuration.
+#[derive(Clone)]
+pub struct Config {
+    pub port: u16,
+    pub database_url: String,
+    pub database_max_connections: u32,
+    pub skip_migrations: bool,
+    /// `LOG_FORMAT=json`: structured log lines.
+    pub log_json: bool,
+    pub sentry: Option<SentryConfig>,
+    pub auth: AuthConfig,
+    pub graphql: GraphqlConfig,
+    pub quotas: QuotaConfig,
+    pub email: Option<EmailConfig>,
+    pub openrouter: Option<OpenRouterConfig>,
+    pub sms: Option<SmsConfig>,
+    pub mqtt: Option<MqttConfig>,
+    pub grpc: Option<GrpcConfig>,
+    /// Set only when `ENABLE_DATA_RETENTION` is on.
+    pub data_retention: Option<RetentionConfig>,
+    pub expo_access_token: Option<String>,
+    /// Whether a VIES "not found" rejects club onboarding.
+    pub vies_hard_block: bool,
+    /// `PII_ENCRYPTION_KEYS`: seals personal data at rest when set.
+    pub pii_keys: Option<Keyring>,
+    settings: Vec<Setting>,
+    warnings: Vec<String>,
+}
+
+impl Config {
+    /// Load and validate everything, reporting all problems at once.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let mut env = Env::new();
+        let mut config = Self {
+            port: env.parse_or("PORT", 8080),
+            database_url: env.required_secret("DATABASE_URL"),
+            database_max_connections: env.parse_or("DATABASE_MAX_CONNECTIONS", 30),
+            skip_migrations: env.flag("SKIP_MIGRATIONS", false),
+            log_json: env.string_or("LOG_FORMAT", "text") == "json",
+            sentry: SentryConfig::load(&mut env),
+            auth: AuthConfig::load(&mut env),
+            graphql: GraphqlConfig::load(&mut env),
+            quotas: QuotaConfig::load(&mut env),
+            email: EmailConfig::load(&mut env),
+            openrouter: OpenRouterConfig::load(&mut env),
+            sms: SmsConfig::load(&mut env),
+            mqtt: MqttConfig::load(&mut env),
+            grpc: GrpcConfig::load(&mut env),
+            data_retention: RetentionConfig::load(&mut env),
+            expo_access_token: env.secret("EXPO_ACCESS_TOKEN"),
+            vies_hard_block: env.flag("VIES_HARD_BLOCK", true),
+            pii_keys: env.secret("PII_ENCRYPTION_KEYS").and_then(|spec| {
+                match Keyring::parse(&spec) {
+                    Ok(keys) => Some(keys),
+                    Err(e) => {
+                        env.problem(format!("PII_ENCRYPTION_KEYS: {e}"));
+                        None
+                    }
+                }
+            }),
+            settings: Vec::new(),
+            warnings: Vec::new(),
+        };
+        if !env.problems.is_empty() {
+            return Err(ConfigError {
+                problems: env.problems,
+            });
+        }
+        config.settings = env.settings;
+        config.warnings = env.warnings;
+        Ok(config)
+    }
+
+    /// Every setting read, in load order, with secrets' values removed.
+    pub fn redacted(&self) -> Vec<Setting> {
+        self.settings
+            .iter()
+            .map(|s| Setting {
+                value: if s.secret { None } else { s.value.clone() },
+                ..s.clone()
+            })
+            .collect()
+    }
+
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Each test uses its own variable names: the environment is shared by
+    // the whole test binary.
+
+    #[test]
+    fn reports_every_problem_and_records_sources() {
+        std::env::set_var("CFG_TEST_PORT", "eighty");
+        std::env::set_var("CFG_TEST_FLAG", "maybe");
+        std::env::set_var("CFG_TEST_NAME", "kiosk");
+
+        let mut env = Env::new();
+        assert_eq!(env.parse_or("CFG_TEST_PORT", 8080u16), 8080);
+        assert!(env.flag("CFG_TEST_FLAG", true));
+        assert_eq!(env.required_secret("CFG_TEST_MISSING"), "");
+        assert_eq!(env.string_or("CFG_TEST_NAME", "web"), "kiosk");
+        assert_eq!(env.string_or("CFG_TEST_REGION", "fr-par"), "fr-par");
+
+        assert_eq!(env.problems.len(), 3);
+        assert!(env.problems[0].starts_with("CFG_TEST_PORT: invalid value 'eighty'"));
+        assert!(env.problems[2].contains("CFG_TEST_MISSING_FILE"));
+        let sources: Vec<_> = env.settings.iter().map(|s| s.source).collect();
+        assert_eq!(
+            sources,
+            vec![
+                Source::Env,
+                Source::Env,
+                Source::Unset,
+                Source::Env,
+                Source::Default
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_secrets_from_files() {
+        let path = std::env::temp_dir().join(format!("cfg-test-{}", std::process::id()));
+        std::fs::write(&path, "s3cret\n").unwrap();
+        std::env::set_var("CFG_TEST_TOKEN_FILE", &path);
+        std::env::set_var("CFG_TEST_BROKEN_FILE", path.with_extension("missing"));
+
+        let mut env = Env::new();
+        assert_eq!(env.secret("CFG_TEST_TOKEN").as_deref(), Some("s3cret"));
+        assert_eq!(env.secret("CFG_TEST_BROKEN"), None);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(env.settings[0].source, Source::File);
+        assert_eq!(env.problems.len(), 1);
+        assert!(env.problems[0].starts_with("CFG_TEST_BROKEN_FILE: can't read"));
+    }
+
+    #[test]
+    fn flags_half_configured_groups() {
+        std::env::set_var("CFG_TEST_SID", "AC123");
+        let mut env = Env::new();
+        env.string("CFG_TEST_SID");
+        env.secret("CFG_TEST_AUTH");
+        env.string("CFG_TEST_FROM");
+        assert!(!env.all_or_none(&["CFG_TEST_SID", "CFG_TEST_AUTH", "CFG_TEST_FROM"]));
+        assert_eq!(
+            env.problems,
+            vec![
+                "CFG_TEST_SID, CFG_TEST_AUTH, CFG_TEST_FROM must be set together \
+                 (missing CFG_TEST_AUTH, CFG_TEST_FROM)"
+            ]
+        );
+
+        let mut env = Env::new();
+        env.string("CFG_TEST_UNSET_A");
+        assert!(!env.all_or_none(&["CFG_TEST_UNSET_A"]));
+        assert!(env.problems.is_empty());
+    }
+}
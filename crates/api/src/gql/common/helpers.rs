@@ -1,8 +1,10 @@
 use async_graphql::Context;
 use uuid::Uuid;
 
+use crate::auth::jwt::Claims;
 use crate::auth::permissions::viewer_manages_club;
 use crate::state::AppState;
+use infra::repos::tournaments;
 
 /// Derive a human-readable display name for an app user: "First Last", trimmed,
 /// falling back to the username and then the email when no name is set.
@@ -27,26 +29,104 @@ pub async fn get_club_id_for_tournament(
     Ok(tournament.club_id)
 }
 
-/// Whether a tournament must be hidden from the current viewer because it
-/// belongs to a free ("Home Game") club. Free clubs are private host tools:
-/// invisible to the player app and public, but still fully visible to their own
-/// managers and to admins. Non-existent tournaments resolve to `false` so the
-/// caller's normal not-found handling applies.
+/// Why a tournament is (or isn't) visible to the current viewer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TournamentAccess {
+    Visible,
+    /// Belongs to a free ("Home Game") club: a private host tool, invisible to
+    /// the player app and public.
+    FreeClub,
+    /// Members-only and the viewer isn't on the club's roster.
+    MembersOnly,
+    /// Unlisted and the viewer has neither the invite link nor a place in it.
+    InviteOnly,
+    /// Waiting for (or refused) the organization's approval; only the club's
+    /// staff see it until then.
+    AwaitingApproval,
+}
+
+/// Whether a tournament must be hidden from the current viewer: free-club
+/// tournaments, members-only ones for non-members, unlisted ones without
+/// the invite link and ones not yet approved (see `tournament_access`). Non-existent tournaments resolve
+/// to `false` so the caller's normal not-found handling applies.
 pub async fn tournament_hidden_from_viewer(
     ctx: &Context<'_>,
     tournament_id: Uuid,
 ) -> async_graphql::Result<bool> {
+    Ok(tournament_access(ctx, tournament_id, None).await? != TournamentAccess::Visible)
+}
+
+/// Who may see a tournament. The club's managers and admins always can.
+/// Members-only tournaments are open to players on the club's roster, and
+/// unlisted ones to whoever presents the invite token; players already
+/// registered or entered keep seeing either.
+pub async fn tournament_access(
+    ctx: &Context<'_>,
+    tournament_id: Uuid,
+    invite_token: Option<&str>,
+) -> async_graphql::Result<TournamentAccess> {
     let state = ctx.data::<AppState>()?;
-    let row: Option<(String, Uuid)> = sqlx::query_as(
-        "SELECT c.plan, c.id FROM tournaments t JOIN clubs c ON c.id = t.club_id WHERE t.id = $1",
-    )
-    .bind(tournament_id)
-    .fetch_optional(&state.db)
-    .await
-    .map_err(|_| async_graphql::Error::new("Database operation failed"))?;
-
-    match row {
-        Some((plan, club_id)) if plan == "free" => Ok(!viewer_manages_club(ctx, club_id).await),
-        _ => Ok(false),
+    let db_err = |_| async_graphql::Error::new("Database operation failed");
+    // The approval is private to the club and its organization; the check
+    // reads it whoever is asking.
+    let Some(access) =
+        infra::db::without_request_scope(tournaments::get_access(&state.db, tournament_id))
+            .await
+            .map_err(db_err)?
+    else {
+        return Ok(TournamentAccess::Visible);
+    };
+    if viewer_manages_club(ctx, access.club_id).await {
+        return Ok(TournamentAccess::Visible);
+    }
+    if access.plan == "free" {
+        return Ok(TournamentAccess::FreeClub);
+    }
+    if access.approval_status != "approved" {
+        return Ok(TournamentAccess::AwaitingApproval);
+    }
+
+    let viewer = ctx
+        .data::<Claims>()
+        .ok()
+        .and_then(|claims| Uuid::parse_str(&claims.sub).ok());
+    let participant = match viewer {
+        Some(user_id) => tournaments::is_participant(&state.db, tournament_id, user_id)
+            .await
+            .map_err(db_err)?,
+        None => false,
+    };
+
+    match access.visibility.as_str() {
+        "members_only" => {
+            let member = match viewer {
+                Some(user_id) => tournaments::is_club_member(&state.db, access.club_id, user_id)
+                    .await
+                    .map_err(db_err)?,
+                None => false,
+            };
+            if member || participant {
+                Ok(TournamentAccess::Visible)
+            } else {
+                Ok(TournamentAccess::MembersOnly)
+            }
+        }
+        "unlisted" => {
+            let invited = match invite_token {
+                Some(token) => {
+                    tournaments::find_by_invite_token(&state.db, token)
+                        .await
+                        .map_err(db_err)?
+                        == Some(tournament_id)
+                }
+                None => false,
+            };
+            if invited || participant {
+                Ok(TournamentAccess::Visible)
+            } else {
+                Ok(TournamentAccess::InviteOnly)
+            }
+        }
+        _ => Ok(TournamentAccess::Visible),
     }
 }
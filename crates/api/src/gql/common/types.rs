@@ -10,6 +10,17 @@ pub const TITLE_SEAT_ASSIGNED: &str = "Seat Assigned";
 pub const TITLE_PLAYER_MOVED: &str = "Table Change";
 pub const TITLE_PLAYER_ELIMINATED: &str = "Eliminated";
 pub const TITLE_QUALIFIED_FOR_DAY_2: &str = "Qualified for Day 2";
+pub const TITLE_SEAT_CHANGE_REQUESTED: &str = "Seat Change Requested";
+pub const TITLE_SEAT_CHANGE_APPROVED: &str = "Seat Change Approved";
+pub const TITLE_SEAT_CHANGE_DECLINED: &str = "Seat Change Declined";
+pub const TITLE_FRIEND_FINAL_TABLE: &str = "Friend at the Final Table";
+pub const TITLE_FRIEND_WON: &str = "Friend Won";
+pub const TITLE_FRIEND_BUSTED: &str = "Friend Busted";
+pub const TITLE_RAFFLE_WON: &str = "Raffle Winner";
+pub const TITLE_PAYOUTS_FINALIZED: &str = "Payouts Final";
+pub const TITLE_TOURNAMENT_APPROVED: &str = "Tournament Approved";
+pub const TITLE_TOURNAMENT_REJECTED: &str = "Tournament Rejected";
+pub const TITLE_TOURNAMENT_CANCELLED: &str = "Tournament Cancelled";
 
 // Pagination types
 
@@ -42,6 +53,10 @@ impl PaginationInput {
     name = "PaginatedLeaderboard",
     params(crate::gql::types::LeaderboardEntry)
 ))]
+#[graphql(concrete(
+    name = "PaginatedOrganizationLeaderboard",
+    params(crate::gql::types::OrganizationLeaderboardEntry)
+))]
 #[graphql(concrete(
     name = "PaginatedActivityLog",
     params(crate::gql::types::ActivityLogEntry)
@@ -50,6 +65,14 @@ impl PaginationInput {
     name = "PaginatedAnnouncements",
     params(crate::gql::types::Announcement)
 ))]
+#[graphql(concrete(
+    name = "PaginatedTournamentChatMessages",
+    params(crate::gql::types::TournamentChatMessage)
+))]
+#[graphql(concrete(
+    name = "PaginatedTournamentHistory",
+    params(crate::gql::types::TournamentHistoryEntry)
+))]
 pub struct PaginatedResponse<T: async_graphql::OutputType> {
     /// List of items for the current page
     pub items: Vec<T>,
@@ -111,6 +134,18 @@ pub enum NotificationType {
     PlayerMoved,
     PlayerEliminated,
     QualifiedForDay2,
+    SeatChangeRequested,
+    SeatChangeApproved,
+    SeatChangeDeclined,
+    FriendFinalTable,
+    FriendWon,
+    FriendBusted,
+    FloorAnnouncement,
+    RaffleWon,
+    PayoutsFinalized,
+    TournamentApproved,
+    TournamentRejected,
+    TournamentCancelled,
 }
 
 #[derive(SimpleObject, Clone, Debug, serde::Serialize, serde::Deserialize)]
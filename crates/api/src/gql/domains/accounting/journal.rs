@@ -0,0 +1,337 @@
+//! Journal lines for the accountant.
+//!
+//! Each finished tournament books, in the club's accounts:
+//! - money taken in, per payment method: payment account → prize liability;
+//! - rake, collected on top of the buy-in: cash → rake revenue;
+//! - prizes and bounties paid out: prize liability → cash.
+//!
+//! Promotion jackpots book their ledger movements as they happen:
+//! - seeds, funded by the club: promotion → jackpot liability;
+//! - contributions (the drop): cash → jackpot liability;
+//! - payouts to qualifying hands: jackpot liability → cash.
+//!
+//! Pure (no DB) so the bookings and both CSV layouts are unit-testable.
+
+use chrono::{DateTime, Utc};
+use infra::repos::accounting::{
+    AccountingSettingsRow, JournalCollectionRow, JournalJackpotEntryRow, JournalTournamentRow,
+};
+
+use super::types::AccountingLayout;
+
+/// One double-entry booking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalLine {
+    pub date: DateTime<Utc>,
+    /// Voucher number: one per tournament, so its lines stay together, and one
+    /// per jackpot ledger entry.
+    pub document: String,
+    pub text: String,
+    pub debit_account: String,
+    pub credit_account: String,
+    pub amount_cents: i64,
+}
+
+/// DATEV caps the booking text at 60 characters.
+const DATEV_TEXT_MAX: usize = 60;
+
+fn payment_account<'a>(settings: &'a AccountingSettingsRow, method: &str) -> &'a str {
+    match method {
+        "card" => &settings.card_account,
+        "bank_transfer" => &settings.bank_account,
+        "voucher" | "comp" => &settings.promotion_account,
+        _ => &settings.cash_account,
+    }
+}
+
+fn payment_label(method: &str) -> &str {
+    match method {
+        "bank_transfer" => "bank transfer",
+        other => other,
+    }
+}
+
+pub fn build_lines(
+    settings: &AccountingSettingsRow,
+    tournaments: &[JournalTournamentRow],
+    collections: &[JournalCollectionRow],
+    jackpot_entries: &[JournalJackpotEntryRow],
+) -> Vec<JournalLine> {
+    let mut lines = Vec::new();
+    for t in tournaments {
+        let document = format!(
+            "T{}-{}",
+            t.start_time.format("%Y%m%d"),
+            &t.tournament_id.simple().to_string()[..8]
+        );
+        let mut push = |text: String, debit: &str, credit: &str, amount_cents: i64| {
+            if amount_cents > 0 {
+                lines.push(JournalLine {
+                    date: t.start_time,
+                    document: document.clone(),
+                    text,
+                    debit_account: debit.to_string(),
+                    credit_account: credit.to_string(),
+                    amount_cents,
+                });
+            }
+        };
+
+        for c in collections
+            .iter()
+            .filter(|c| c.tournament_id == t.tournament_id)
+        {
+            push(
+                format!("{}: buy-ins ({})", t.name, payment_label(&c.payment_method)),
+                payment_account(settings, &c.payment_method),
+                &settings.prize_liability_account,
+                c.amount_cents,
+            );
+        }
+        push(
+            format!("{}: rake", t.name),
+            &settings.cash_account,
+            &settings.rake_revenue_account,
+            t.rake_cents,
+        );
+        push(
+            format!("{}: prizes paid", t.name),
+            &settings.prize_liability_account,
+            &settings.cash_account,
+            t.prizes_paid_cents,
+        );
+        push(
+            format!("{}: bounties paid", t.name),
+            &settings.prize_liability_account,
+            &settings.cash_account,
+            t.bounties_paid_cents,
+        );
+    }
+    for e in jackpot_entries {
+        let (label, debit, credit) = match e.kind.as_str() {
+            "seed" => (
+                "jackpot seed",
+                &settings.promotion_account,
+                &settings.jackpot_liability_account,
+            ),
+            "payout" => (
+                "jackpot paid",
+                &settings.jackpot_liability_account,
+                &settings.cash_account,
+            ),
+            _ => (
+                "jackpot contribution",
+                &settings.cash_account,
+                &settings.jackpot_liability_account,
+            ),
+        };
+        if e.amount_cents > 0 {
+            lines.push(JournalLine {
+                date: e.created_at,
+                document: format!(
+                    "J{}-{}",
+                    e.created_at.format("%Y%m%d"),
+                    &e.entry_id.simple().to_string()[..8]
+                ),
+                text: format!("{}: {label}", e.jackpot_name),
+                debit_account: debit.clone(),
+                credit_account: credit.clone(),
+                amount_cents: e.amount_cents,
+            });
+        }
+    }
+    lines
+}
+
+fn amount(cents: i64, decimal_mark: char) -> String {
+    format!("{}{decimal_mark}{:02}", cents / 100, cents % 100)
+}
+
+pub fn render(layout: AccountingLayout, lines: &[JournalLine]) -> Result<String, csv::Error> {
+    let mut builder = csv::WriterBuilder::new();
+    if layout == AccountingLayout::Datev {
+        builder.delimiter(b';');
+    }
+    let mut writer = builder.from_writer(Vec::new());
+
+    match layout {
+        AccountingLayout::Generic => {
+            writer.write_record([
+                "date",
+                "document",
+                "text",
+                "debit_account",
+                "credit_account",
+                "amount",
+            ])?;
+            for line in lines {
+                writer.write_record([
+                    line.date.format("%Y-%m-%d").to_string(),
+                    line.document.clone(),
+                    line.text.clone(),
+                    line.debit_account.clone(),
+                    line.credit_account.clone(),
+                    amount(line.amount_cents, '.'),
+                ])?;
+            }
+        }
+        AccountingLayout::Datev => {
+            writer.write_record([
+                "Umsatz (ohne Soll/Haben-Kz)",
+                "Soll/Haben-Kennzeichen",
+                "Konto",
+                "Gegenkonto (ohne BU-Schlüssel)",
+                "Belegdatum",
+                "Belegfeld 1",
+                "Buchungstext",
+            ])?;
+            for line in lines {
+                writer.write_record([
+                    amount(line.amount_cents, ','),
+                    "S".to_string(),
+                    line.debit_account.clone(),
+                    line.credit_account.clone(),
+                    line.date.format("%d%m").to_string(),
+                    line.document.clone(),
+                    line.text.chars().take(DATEV_TEXT_MAX).collect(),
+                ])?;
+            }
+        }
+    }
+
+    let bytes = writer.into_inner().map_err(|e| e.into_error())?;
+    Ok(String::from_utf8(bytes).expect("CSV writer only emits UTF-8 input"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    fn settings() -> AccountingSettingsRow {
+        let now = Utc::now();
+        AccountingSettingsRow {
+            club_id: Uuid::new_v4(),
+            layout: "generic".into(),
+            cash_account: "1000".into(),
+            card_account: "1360".into(),
+            bank_account: "1200".into(),
+            promotion_account: "4600".into(),
+            prize_liability_account: "1700".into(),
+            rake_revenue_account: "8400".into(),
+            jackpot_liability_account: "1710".into(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn tournament() -> JournalTournamentRow {
+        JournalTournamentRow {
+            tournament_id: Uuid::parse_str("1a2b3c4d-0000-0000-0000-000000000000").unwrap(),
+            name: "Friday Deepstack".into(),
+            start_time: Utc.with_ymd_and_hms(2025, 3, 7, 19, 0, 0).unwrap(),
+            rake_cents: 1_000,
+            prizes_paid_cents: 15_000,
+            bounties_paid_cents: 0,
+        }
+    }
+
+    #[test]
+    fn books_collections_rake_and_prizes() {
+        let t = tournament();
+        let collection = |method: &str, amount_cents| JournalCollectionRow {
+            tournament_id: t.tournament_id,
+            payment_method: method.into(),
+            amount_cents,
+        };
+        let lines = build_lines(
+            &settings(),
+            std::slice::from_ref(&t),
+            &[collection("card", 5_000), collection("cash", 10_000)],
+            &[],
+        );
+
+        let bookings: Vec<(&str, &str, i64)> = lines
+            .iter()
+            .map(|l| {
+                (
+                    l.debit_account.as_str(),
+                    l.credit_account.as_str(),
+                    l.amount_cents,
+                )
+            })
+            .collect();
+        assert_eq!(
+            bookings,
+            [
+                ("1360", "1700", 5_000),
+                ("1000", "1700", 10_000),
+                ("1000", "8400", 1_000),
+                ("1700", "1000", 15_000),
+            ]
+        );
+        assert!(lines.iter().all(|l| l.document == "T20250307-1a2b3c4d"));
+        assert_eq!(lines[0].text, "Friday Deepstack: buy-ins (card)");
+    }
+
+    #[test]
+    fn renders_both_layouts() {
+        let t = tournament();
+        let lines = build_lines(&settings(), std::slice::from_ref(&t), &[], &[]);
+
+        let generic = render(AccountingLayout::Generic, &lines).unwrap();
+        assert_eq!(
+            generic.lines().nth(1),
+            Some("2025-03-07,T20250307-1a2b3c4d,Friday Deepstack: rake,1000,8400,10.00")
+        );
+
+        let datev = render(AccountingLayout::Datev, &lines).unwrap();
+        assert_eq!(
+            datev.lines().nth(2),
+            Some("150,00;S;1700;1000;0703;T20250307-1a2b3c4d;Friday Deepstack: prizes paid")
+        );
+    }
+
+    #[test]
+    fn books_jackpot_movements() {
+        let entry = |kind: &str, amount_cents| JournalJackpotEntryRow {
+            entry_id: Uuid::parse_str("9f8e7d6c-0000-0000-0000-000000000000").unwrap(),
+            jackpot_name: "Bad Beat".into(),
+            kind: kind.into(),
+            amount_cents,
+            created_at: Utc.with_ymd_and_hms(2025, 3, 8, 1, 30, 0).unwrap(),
+        };
+        let lines = build_lines(
+            &settings(),
+            &[],
+            &[],
+            &[
+                entry("seed", 50_000),
+                entry("contribution", 2_000),
+                entry("payout", 30_000),
+            ],
+        );
+
+        let bookings: Vec<(&str, &str, &str, i64)> = lines
+            .iter()
+            .map(|l| {
+                (
+                    l.text.as_str(),
+                    l.debit_account.as_str(),
+                    l.credit_account.as_str(),
+                    l.amount_cents,
+                )
+            })
+            .collect();
+        assert_eq!(
+            bookings,
+            [
+                ("Bad Beat: jackpot seed", "4600", "1710", 50_000),
+                ("Bad Beat: jackpot contribution", "1000", "1710", 2_000),
+                ("Bad Beat: jackpot paid", "1710", "1000", 30_000),
+            ]
+        );
+        assert_eq!(lines[0].document, "J20250308-9f8e7d6c");
+    }
+}
@@ -0,0 +1,6 @@
+pub mod journal;
+pub mod rake;
+pub mod resolvers;
+pub mod types;
+
+pub use resolvers::{AccountingMutation, AccountingQuery};
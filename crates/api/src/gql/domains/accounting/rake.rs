@@ -0,0 +1,125 @@
+//! The club rake report: per-tournament rake bucketed by day, week or month.
+//!
+//! Rake is charged on top of the buy-in for each initial entry and
+//! re-entry, so it never enters the prize pool. Buckets follow the
+//! tournament's start date in UTC; weeks start on Monday.
+//!
+//! Pure (no DB) so the bucketing is unit-testable.
+
+use chrono::{Datelike, NaiveDate};
+use infra::repos::accounting::RakeTournamentRow;
+
+use super::types::RakePeriod;
+
+/// One bucket's totals, before conversion to the GraphQL type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RakeBucket {
+    pub period_start: NaiveDate,
+    pub tournament_count: i64,
+    pub raked_entries: i64,
+    pub rake_cents: i64,
+    pub prize_pool_cents: i64,
+}
+
+/// First day of the period containing `date`.
+pub fn period_start(period: RakePeriod, date: NaiveDate) -> NaiveDate {
+    match period {
+        RakePeriod::Day => date,
+        RakePeriod::Week => {
+            date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64)
+        }
+        RakePeriod::Month => date.with_day(1).unwrap_or(date),
+    }
+}
+
+/// Sum `tournaments` (oldest first) into consecutive buckets. Periods
+/// without tournaments are omitted.
+pub fn bucket(period: RakePeriod, tournaments: &[RakeTournamentRow]) -> Vec<RakeBucket> {
+    let mut buckets: Vec<RakeBucket> = Vec::new();
+    for t in tournaments {
+        let start = period_start(period, t.start_time.date_naive());
+        if buckets.last().is_none_or(|last| last.period_start != start) {
+            buckets.push(RakeBucket {
+                period_start: start,
+                tournament_count: 0,
+                raked_entries: 0,
+                rake_cents: 0,
+                prize_pool_cents: 0,
+            });
+        }
+        if let Some(bucket) = buckets.last_mut() {
+            bucket.tournament_count += 1;
+            bucket.raked_entries += t.raked_entries;
+            bucket.rake_cents += t.rake_cents;
+            bucket.prize_pool_cents += t.prize_pool_cents;
+        }
+    }
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    fn tournament(day: u32, hour: u32, raked_entries: i64) -> RakeTournamentRow {
+        RakeTournamentRow {
+            tournament_id: Uuid::new_v4(),
+            name: format!("March {day}"),
+            start_time: Utc.with_ymd_and_hms(2025, 3, day, hour, 0, 0).unwrap(),
+            rake_per_entry_cents: 500,
+            raked_entries,
+            rake_cents: 500 * raked_entries,
+            prize_pool_cents: 5_000 * raked_entries,
+        }
+    }
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[test]
+    fn periods_start_on_the_day_the_monday_and_the_first() {
+        // 2025-03-07 is a Friday.
+        let friday = date(3, 7);
+        assert_eq!(period_start(RakePeriod::Day, friday), friday);
+        assert_eq!(period_start(RakePeriod::Week, friday), date(3, 3));
+        assert_eq!(period_start(RakePeriod::Week, date(3, 3)), date(3, 3));
+        assert_eq!(period_start(RakePeriod::Month, friday), date(3, 1));
+    }
+
+    #[test]
+    fn sums_tournaments_per_period() {
+        let tournaments = [
+            tournament(7, 14, 10),
+            tournament(7, 20, 20),
+            tournament(9, 19, 5),
+            tournament(10, 19, 8),
+        ];
+
+        let days = bucket(RakePeriod::Day, &tournaments);
+        assert_eq!(
+            days.iter()
+                .map(|b| (b.period_start, b.tournament_count, b.rake_cents))
+                .collect::<Vec<_>>(),
+            [
+                (date(3, 7), 2, 15_000),
+                (date(3, 9), 1, 2_500),
+                (date(3, 10), 1, 4_000),
+            ]
+        );
+
+        let weeks = bucket(RakePeriod::Week, &tournaments);
+        assert_eq!(weeks.len(), 2);
+        assert_eq!(weeks[0].period_start, date(3, 3));
+        assert_eq!(weeks[0].raked_entries, 35);
+        assert_eq!(weeks[0].prize_pool_cents, 175_000);
+        assert_eq!(weeks[1].period_start, date(3, 10));
+
+        let months = bucket(RakePeriod::Month, &tournaments);
+        assert_eq!(months.len(), 1);
+        assert_eq!(months[0].tournament_count, 4);
+        assert_eq!(months[0].rake_cents, 21_500);
+    }
+}
@@ -0,0 +1,214 @@
+use async_graphql::{Context, Object, Result, ID};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::auth::permissions::require_club_manager;
+use crate::gql::error::ResultExt;
+use crate::state::AppState;
+use infra::repos::accounting::{self, CreateAccountingExport, UpsertAccountingSettings};
+
+use super::types::{
+    AccountingExport, AccountingLayout, AccountingSettings, ClubRakeReport,
+    CreateAccountingExportInput, RakePeriod, RakeReportTournament, UpdateAccountingSettingsInput,
+};
+use super::{journal, rake};
+
+/// Longest account number we accept (DATEV allows up to 9 digits; other
+/// charts use short alphanumeric codes).
+const MAX_ACCOUNT_LEN: usize = 20;
+
+fn validate_account(field: &str, value: String) -> Result<String> {
+    let value = value.trim().to_string();
+    if value.is_empty()
+        || value.len() > MAX_ACCOUNT_LEN
+        || !value.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        return Err(async_graphql::Error::new(format!(
+            "{field} must be 1-{MAX_ACCOUNT_LEN} letters or digits"
+        )));
+    }
+    Ok(value)
+}
+
+#[derive(Default)]
+pub struct AccountingQuery;
+
+#[Object]
+impl AccountingQuery {
+    /// The club's chart-of-accounts mapping and default export layout.
+    /// Managers of the club only.
+    async fn club_accounting_settings(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+    ) -> Result<AccountingSettings> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+
+        let state = ctx.data::<AppState>()?;
+        let row = accounting::get_or_create_settings(&state.db, club_id).await?;
+        Ok(row.into())
+    }
+
+    /// The club's accounting exports, newest first. Managers of the club only.
+    async fn accounting_exports(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+    ) -> Result<Vec<AccountingExport>> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+
+        let state = ctx.data::<AppState>()?;
+        let rows = accounting::list_exports(&state.db, club_id).await?;
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// Rake the club took from tournaments starting in `[from, to)`, per
+    /// tournament and per day, week or month (default DAY). Managers of the
+    /// club only.
+    async fn club_rake_report(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        group_by: Option<RakePeriod>,
+    ) -> Result<ClubRakeReport> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        if to <= from {
+            return Err(async_graphql::Error::new("`to` must be after `from`"));
+        }
+
+        let state = ctx.data::<AppState>()?;
+        let group_by = group_by.unwrap_or_default();
+        let rows = accounting::list_rake_tournaments(&state.db, club_id, from, to).await?;
+        let sum = |f: fn(&accounting::RakeTournamentRow) -> i64| rows.iter().map(f).sum::<i64>();
+
+        Ok(ClubRakeReport {
+            club_id: club_id.into(),
+            from,
+            to,
+            group_by,
+            periods: rake::bucket(group_by, &rows)
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            raked_entries: sum(|r| r.raked_entries) as i32,
+            total_rake_cents: sum(|r| r.rake_cents).into(),
+            prize_pool_cents: sum(|r| r.prize_pool_cents).into(),
+            tournaments: rows.into_iter().map(RakeReportTournament::from).collect(),
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct AccountingMutation;
+
+#[Object]
+impl AccountingMutation {
+    /// Change the club's account numbers or default layout. Managers of the
+    /// club only.
+    async fn update_club_accounting_settings(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateAccountingSettingsInput,
+    ) -> Result<AccountingSettings> {
+        let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+
+        let state = ctx.data::<AppState>()?;
+        let current = accounting::get_or_create_settings(&state.db, club_id).await?;
+        let account = |field: &str, new: Option<String>, current: String| match new {
+            Some(value) => validate_account(field, value),
+            None => Ok(current),
+        };
+        let data = UpsertAccountingSettings {
+            layout: input
+                .layout
+                .map(|l| l.as_str().to_string())
+                .unwrap_or(current.layout),
+            cash_account: account("cashAccount", input.cash_account, current.cash_account)?,
+            card_account: account("cardAccount", input.card_account, current.card_account)?,
+            bank_account: account("bankAccount", input.bank_account, current.bank_account)?,
+            promotion_account: account(
+                "promotionAccount",
+                input.promotion_account,
+                current.promotion_account,
+            )?,
+            prize_liability_account: account(
+                "prizeLiabilityAccount",
+                input.prize_liability_account,
+                current.prize_liability_account,
+            )?,
+            rake_revenue_account: account(
+                "rakeRevenueAccount",
+                input.rake_revenue_account,
+                current.rake_revenue_account,
+            )?,
+            jackpot_liability_account: account(
+                "jackpotLiabilityAccount",
+                input.jackpot_liability_account,
+                current.jackpot_liability_account,
+            )?,
+        };
+
+        let row = accounting::upsert_settings(&state.db, club_id, data).await?;
+        Ok(row.into())
+    }
+
+    /// Generate the journal for the club's finished tournaments starting in
+    /// `[from, to)`, plus its jackpot ledger movements in that window, and
+    /// store it as an immutable export. Managers of the club
+    /// only.
+    async fn create_accounting_export(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateAccountingExportInput,
+    ) -> Result<AccountingExport> {
+        let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid manager ID")?;
+        if input.to <= input.from {
+            return Err(async_graphql::Error::new("`to` must be after `from`"));
+        }
+
+        let state = ctx.data::<AppState>()?;
+        let settings = accounting::get_or_create_settings(&state.db, club_id).await?;
+        let layout = input
+            .layout
+            .unwrap_or_else(|| AccountingLayout::from(settings.layout.as_str()));
+
+        let (tournaments, jackpot_entries) = tokio::try_join!(
+            accounting::list_journal_tournaments(&state.db, club_id, input.from, input.to),
+            accounting::list_journal_jackpot_entries(&state.db, club_id, input.from, input.to),
+        )?;
+        if tournaments.is_empty() && jackpot_entries.is_empty() {
+            return Err(async_graphql::Error::new(
+                "No finished tournaments or jackpot movements in this period",
+            ));
+        }
+        let tournament_ids: Vec<Uuid> = tournaments.iter().map(|t| t.tournament_id).collect();
+        let collections = accounting::list_journal_collections(&state.db, &tournament_ids).await?;
+
+        let lines = journal::build_lines(&settings, &tournaments, &collections, &jackpot_entries);
+        let content = journal::render(layout, &lines).gql_err("Failed to render export")?;
+
+        let row = accounting::create_export(
+            &state.db,
+            CreateAccountingExport {
+                club_id,
+                period_from: input.from,
+                period_to: input.to,
+                layout: layout.as_str().to_string(),
+                line_count: lines.len() as i32,
+                total_cents: lines.iter().map(|l| l.amount_cents).sum(),
+                content,
+                created_by: manager_id,
+            },
+        )
+        .await?;
+        Ok(row.into())
+    }
+}
@@ -0,0 +1,207 @@
+use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, NaiveDate, Utc};
+
+use super::rake::RakeBucket;
+use crate::gql::scalars::Money;
+use infra::repos::accounting::{AccountingExportRow, AccountingSettingsRow, RakeTournamentRow};
+
+/// CSV layout of an accounting export.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum AccountingLayout {
+    /// Comma-separated, ISO dates, decimal point, debit and credit account
+    /// columns.
+    Generic,
+    /// Semicolon-separated booking lines with the columns DATEV's
+    /// booking-batch import maps (amount, S/H flag, account, contra account,
+    /// voucher date as DDMM, voucher number, booking text).
+    Datev,
+}
+
+impl AccountingLayout {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AccountingLayout::Generic => "generic",
+            AccountingLayout::Datev => "datev",
+        }
+    }
+}
+
+impl From<&str> for AccountingLayout {
+    fn from(s: &str) -> Self {
+        match s {
+            "datev" => AccountingLayout::Datev,
+            _ => AccountingLayout::Generic,
+        }
+    }
+}
+
+/// How a club's journal maps onto its chart of accounts.
+#[derive(SimpleObject, Clone)]
+pub struct AccountingSettings {
+    pub club_id: ID,
+    /// Layout used when an export doesn't name one.
+    pub layout: AccountingLayout,
+    pub cash_account: String,
+    pub card_account: String,
+    pub bank_account: String,
+    /// Debited for entries paid with a voucher or comped.
+    pub promotion_account: String,
+    /// Buy-ins owed back to players as prizes.
+    pub prize_liability_account: String,
+    pub rake_revenue_account: String,
+    /// Promotion jackpots held for players until paid out.
+    pub jackpot_liability_account: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<AccountingSettingsRow> for AccountingSettings {
+    fn from(row: AccountingSettingsRow) -> Self {
+        Self {
+            club_id: row.club_id.into(),
+            layout: AccountingLayout::from(row.layout.as_str()),
+            cash_account: row.cash_account,
+            card_account: row.card_account,
+            bank_account: row.bank_account,
+            promotion_account: row.promotion_account,
+            prize_liability_account: row.prize_liability_account,
+            rake_revenue_account: row.rake_revenue_account,
+            jackpot_liability_account: row.jackpot_liability_account,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+/// Omitted fields keep their current value.
+#[derive(InputObject)]
+pub struct UpdateAccountingSettingsInput {
+    pub club_id: ID,
+    pub layout: Option<AccountingLayout>,
+    pub cash_account: Option<String>,
+    pub card_account: Option<String>,
+    pub bank_account: Option<String>,
+    pub promotion_account: Option<String>,
+    pub prize_liability_account: Option<String>,
+    pub rake_revenue_account: Option<String>,
+    pub jackpot_liability_account: Option<String>,
+}
+
+#[derive(InputObject)]
+pub struct CreateAccountingExportInput {
+    pub club_id: ID,
+    /// Tournaments starting at or after this instant...
+    pub from: DateTime<Utc>,
+    /// ...and before this one.
+    pub to: DateTime<Utc>,
+    /// Defaults to the club's configured layout.
+    pub layout: Option<AccountingLayout>,
+}
+
+/// A generated export. The file is stored as generated and never changes;
+/// corrections to the period show up in the next export.
+#[derive(SimpleObject, Clone)]
+pub struct AccountingExport {
+    pub id: ID,
+    pub club_id: ID,
+    pub period_from: DateTime<Utc>,
+    pub period_to: DateTime<Utc>,
+    pub layout: AccountingLayout,
+    pub line_count: i32,
+    /// Sum of the journal line amounts.
+    pub total_cents: Money,
+    /// The CSV file.
+    pub content: String,
+    pub created_by: Option<ID>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AccountingExportRow> for AccountingExport {
+    fn from(row: AccountingExportRow) -> Self {
+        Self {
+            id: row.id.into(),
+            club_id: row.club_id.into(),
+            period_from: row.period_from,
+            period_to: row.period_to,
+            layout: AccountingLayout::from(row.layout.as_str()),
+            line_count: row.line_count,
+            total_cents: row.total_cents.into(),
+            content: row.content,
+            created_by: row.created_by.map(Into::into),
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// Bucket size of the club rake report.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum RakePeriod {
+    #[default]
+    Day,
+    /// Monday to Sunday.
+    Week,
+    Month,
+}
+
+/// One tournament's line in the rake report.
+#[derive(SimpleObject, Clone)]
+pub struct RakeReportTournament {
+    pub tournament_id: ID,
+    pub name: String,
+    pub start_time: DateTime<Utc>,
+    pub rake_per_entry_cents: Money,
+    /// Initial entries and re-entries; rebuys and add-ons carry no rake.
+    pub raked_entries: i32,
+    pub rake_cents: Money,
+    pub prize_pool_cents: Money,
+}
+
+impl From<RakeTournamentRow> for RakeReportTournament {
+    fn from(row: RakeTournamentRow) -> Self {
+        Self {
+            tournament_id: row.tournament_id.into(),
+            name: row.name,
+            start_time: row.start_time,
+            rake_per_entry_cents: row.rake_per_entry_cents.into(),
+            raked_entries: row.raked_entries as i32,
+            rake_cents: row.rake_cents.into(),
+            prize_pool_cents: row.prize_pool_cents.into(),
+        }
+    }
+}
+
+/// Rake over one day, week or month (UTC).
+#[derive(SimpleObject, Clone)]
+pub struct RakeReportPeriod {
+    pub period_start: NaiveDate,
+    pub tournament_count: i32,
+    pub raked_entries: i32,
+    pub rake_cents: Money,
+    pub prize_pool_cents: Money,
+}
+
+impl From<RakeBucket> for RakeReportPeriod {
+    fn from(bucket: RakeBucket) -> Self {
+        Self {
+            period_start: bucket.period_start,
+            tournament_count: bucket.tournament_count as i32,
+            raked_entries: bucket.raked_entries as i32,
+            rake_cents: bucket.rake_cents.into(),
+            prize_pool_cents: bucket.prize_pool_cents.into(),
+        }
+    }
+}
+
+/// Rake a club took over `[from, to)`, by tournament start time.
+#[derive(SimpleObject, Clone)]
+pub struct ClubRakeReport {
+    pub club_id: ID,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub group_by: RakePeriod,
+    /// Oldest first; periods without tournaments are omitted.
+    pub periods: Vec<RakeReportPeriod>,
+    /// Oldest first.
+    pub tournaments: Vec<RakeReportTournament>,
+    pub raked_entries: i32,
+    pub total_rake_cents: Money,
+    pub prize_pool_cents: Money,
+}
@@ -40,7 +40,7 @@ pub async fn evaluate_for_player<'a>(
                     WHERE pd.tournament_id = tr.tournament_id
                       AND tr.final_position = ANY(pd.affected_positions)
                 ) THEN 1 ELSE 0 END)::int4 as runner_ups,
-            COALESCE(SUM(tr.prize_cents), 0)::int4 as total_winnings_cents
+            COALESCE(SUM(tr.prize_cents), 0)::int8 as total_winnings_cents
         FROM tournament_results tr
         WHERE tr.user_id = $1
         "#,
@@ -54,7 +54,7 @@ pub async fn evaluate_for_player<'a>(
     let wins: i32 = stats_row.get("wins");
     let final_tables: i32 = stats_row.get("final_tables");
     let runner_ups: i32 = stats_row.get("runner_ups");
-    let total_winnings_cents: i32 = stats_row.get("total_winnings_cents");
+    let total_winnings_cents: i64 = stats_row.get("total_winnings_cents");
 
     // Count total tournament participations (registrations, not busted/no-show/cancelled)
     let participation_row = sqlx::query_scalar::<_, i64>(
@@ -106,7 +106,7 @@ pub async fn evaluate_for_player<'a>(
 
     let mut current_cash_streak = 0;
     for row in streak_rows {
-        let prize: i32 = row.get("prize_cents");
+        let prize: i64 = row.get("prize_cents");
         if prize > 0 {
             current_cash_streak += 1;
         } else {
@@ -246,11 +246,11 @@ pub async fn evaluate_for_player<'a>(
                 threshold.map(|t| final_tables >= t).unwrap_or(false),
             ),
             "winnings_1000" => {
-                // threshold_value is in cents
+                // threshold_value is in cents; progress saturates at i32::MAX
                 (
-                    total_winnings_cents,
+                    total_winnings_cents.min(i32::MAX as i64) as i32,
                     threshold
-                        .map(|t| total_winnings_cents >= t)
+                        .map(|t| total_winnings_cents >= t as i64)
                         .unwrap_or(false),
                 )
             }
@@ -1,4 +1,5 @@
 pub mod resolvers;
+pub mod timeline;
 pub mod types;
 
 pub use resolvers::ActivityLogQuery;
@@ -3,10 +3,17 @@ use uuid::Uuid;
 
 use crate::gql::error::ResultExt;
 use crate::gql::types::{
-    ActivityEventCategory, ActivityLogEntry, PaginatedResponse, PaginationInput,
+    ActivityEventCategory, ActivityLogEntry, PaginatedResponse, PaginationInput, TimelineEvent,
+    TimelineEventKind, TournamentTimeline,
 };
 use crate::state::AppState;
-use infra::repos::activity_log;
+use infra::repos::{activity_log, tournament_timeline};
+
+use super::timeline::decode_cursor;
+
+/// Timeline page size when `first` is omitted, and the most one page returns.
+const DEFAULT_TIMELINE_PAGE: i32 = 100;
+const MAX_TIMELINE_PAGE: i32 = 500;
 
 #[derive(Default)]
 pub struct ActivityLogQuery;
@@ -56,4 +63,50 @@ impl ActivityLogQuery {
             has_next_page,
         })
     }
+    /// Everything that happened in a tournament as one stream, oldest first:
+    /// clock, seating and status events, entries, eliminations and sent
+    /// announcements. Page with `after` (an event's cursor) and `first`;
+    /// `kinds` narrows it to some kinds of event.
+    async fn tournament_timeline(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: Uuid,
+        kinds: Option<Vec<TimelineEventKind>>,
+        after: Option<String>,
+        first: Option<i32>,
+    ) -> Result<TournamentTimeline> {
+        let state = ctx.data::<AppState>()?;
+
+        let after = after
+            .map(|c| decode_cursor(&c).ok_or_else(|| async_graphql::Error::new("Invalid cursor")))
+            .transpose()?;
+        let first = first
+            .unwrap_or(DEFAULT_TIMELINE_PAGE)
+            .clamp(1, MAX_TIMELINE_PAGE);
+        let kinds: Vec<&str> = kinds
+            .unwrap_or_else(|| TimelineEventKind::ALL.to_vec())
+            .iter()
+            .map(|k| k.as_str())
+            .collect();
+
+        // One extra row tells whether another page follows.
+        let mut rows = tournament_timeline::list(
+            &state.db,
+            tournament_id,
+            &kinds,
+            after,
+            i64::from(first) + 1,
+        )
+        .await
+        .gql_err("Database operation failed")?;
+        let has_next_page = rows.len() > first as usize;
+        rows.truncate(first as usize);
+
+        let events: Vec<TimelineEvent> = rows.into_iter().map(TimelineEvent::from).collect();
+        Ok(TournamentTimeline {
+            end_cursor: events.last().map(|e| e.cursor.clone()),
+            events,
+            has_next_page,
+        })
+    }
 }
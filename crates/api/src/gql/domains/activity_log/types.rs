@@ -6,6 +6,9 @@ use uuid::Uuid;
 use crate::gql::common::helpers::display_name_from_user;
 use crate::gql::loaders::{ClubPlayerLoader, UserLoader};
 use infra::models::TournamentActivityLogRow;
+use infra::repos::tournament_timeline::TimelineRow;
+
+use super::timeline::encode_cursor;
 
 #[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
 pub enum ActivityEventCategory {
@@ -120,3 +123,124 @@ impl From<TournamentActivityLogRow> for ActivityLogEntry {
         }
     }
 }
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TimelineEventKind {
+    /// Clock starts, pauses and level changes.
+    Clock,
+    /// Seats, moves, table changes and stack counts.
+    Seating,
+    /// Buy-ins, rebuys, re-entries and add-ons.
+    Entry,
+    Elimination,
+    Announcement,
+    /// Status changes and other tournament-wide events.
+    Tournament,
+}
+
+impl TimelineEventKind {
+    pub const ALL: [Self; 6] = [
+        Self::Clock,
+        Self::Seating,
+        Self::Entry,
+        Self::Elimination,
+        Self::Announcement,
+        Self::Tournament,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Clock => "clock",
+            Self::Seating => "seating",
+            Self::Entry => "entry",
+            Self::Elimination => "elimination",
+            Self::Announcement => "announcement",
+            Self::Tournament => "tournament",
+        }
+    }
+
+    fn from_db(s: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|k| k.as_str() == s)
+            .unwrap_or(Self::Tournament)
+    }
+}
+
+/// One event of the tournament timeline.
+#[derive(SimpleObject, Clone, Debug)]
+#[graphql(complex)]
+pub struct TimelineEvent {
+    /// Pass as `after` to continue from this event.
+    pub cursor: String,
+    pub kind: TimelineEventKind,
+    /// The logged action (`start`, `player_moved`, ...), the entry type
+    /// (`initial`, `rebuy`, ...), `eliminated`, or an announcement's priority.
+    pub action: String,
+    pub occurred_at: DateTime<Utc>,
+    /// Clock level at the time, when known.
+    pub level_number: Option<i32>,
+    pub actor_id: Option<ID>,
+    /// The player concerned, when they have an account.
+    pub user_id: Option<ID>,
+    pub club_player_id: Option<ID>,
+    /// Announcement title and body.
+    pub title: Option<String>,
+    pub body: Option<String>,
+    pub metadata: async_graphql::Json<serde_json::Value>,
+}
+
+#[ComplexObject]
+impl TimelineEvent {
+    /// Display name of the player concerned.
+    async fn player_name(&self, ctx: &Context<'_>) -> Result<Option<String>> {
+        if let Some(uuid) = self
+            .club_player_id
+            .as_ref()
+            .and_then(|id| Uuid::parse_str(id.as_str()).ok())
+        {
+            let loader = ctx.data::<DataLoader<ClubPlayerLoader>>()?;
+            return Ok(loader.load_one(uuid).await?.map(|r| r.display_name));
+        }
+        if let Some(uuid) = self
+            .user_id
+            .as_ref()
+            .and_then(|id| Uuid::parse_str(id.as_str()).ok())
+        {
+            let loader = ctx.data::<DataLoader<UserLoader>>()?;
+            return Ok(loader
+                .load_one(uuid)
+                .await?
+                .map(|u| display_name_from_user(&u)));
+        }
+        Ok(None)
+    }
+}
+
+impl From<TimelineRow> for TimelineEvent {
+    fn from(row: TimelineRow) -> Self {
+        Self {
+            cursor: encode_cursor(row.occurred_at, row.source_id),
+            kind: TimelineEventKind::from_db(&row.kind),
+            action: row.action,
+            occurred_at: row.occurred_at,
+            level_number: row.level_number,
+            actor_id: row.actor_id.map(Into::into),
+            user_id: row.user_id.map(Into::into),
+            club_player_id: row.club_player_id.map(Into::into),
+            title: row.title,
+            body: row.body,
+            metadata: async_graphql::Json(row.metadata),
+        }
+    }
+}
+
+/// A page of the timeline, oldest event first.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct TournamentTimeline {
+    pub events: Vec<TimelineEvent>,
+    /// Cursor of the last event; pass as `after` for the next page. Null on
+    /// an empty page.
+    pub end_cursor: Option<String>,
+    pub has_next_page: bool,
+}
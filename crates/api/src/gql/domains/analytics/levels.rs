@@ -0,0 +1,226 @@
+//! Per-level structure analytics: how the field entered, rebought and busted
+//! level by level, and how the average stack moved.
+//!
+//! Activity without a level (before the clock started) counts towards the
+//! field at the start of the first level. The average stack is the chips
+//! handed out so far over the players left, so it needs no chip counts.
+//!
+//! This module is pure (no DB) so the arithmetic is unit-testable; the
+//! resolvers load the activity and structure and call `level_lines` and
+//! `pool_levels`.
+
+use std::collections::BTreeMap;
+
+use infra::repos::level_statistics::LevelActivityRow;
+
+/// One level of one tournament.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelLine {
+    pub level_number: i32,
+    pub entries: i64,
+    pub re_entries: i64,
+    pub rebuys: i64,
+    pub addons: i64,
+    pub eliminations: i64,
+    pub players_at_start: i64,
+    pub players_at_end: i64,
+    /// Chips handed out up to the end of the level.
+    pub chips_in_play: i64,
+}
+
+impl LevelLine {
+    pub fn average_stack(&self) -> Option<i64> {
+        (self.players_at_end > 0).then(|| self.chips_in_play / self.players_at_end)
+    }
+}
+
+/// A tournament's levels, through the last one anything happened in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelBreakdown {
+    /// Buy-ins recorded before the clock started.
+    pub entries_before_start: i64,
+    pub lines: Vec<LevelLine>,
+}
+
+/// Walk the tournament's `structure` levels (and any level past it that saw
+/// activity), carrying the field and chips from one level to the next.
+pub fn level_lines(structure: &[i32], activity: &[LevelActivityRow]) -> LevelBreakdown {
+    let mut by_level: BTreeMap<i32, &LevelActivityRow> = BTreeMap::new();
+    let mut players = 0;
+    let mut chips = 0;
+    let mut entries_before_start = 0;
+    for row in activity {
+        match row.level_number {
+            Some(level) => {
+                by_level.insert(level, row);
+            }
+            None => {
+                players += row.entries - row.eliminations;
+                chips += row.chips;
+                entries_before_start += row.entries;
+            }
+        }
+    }
+
+    let Some(&last) = by_level.keys().next_back() else {
+        return LevelBreakdown {
+            entries_before_start,
+            lines: Vec::new(),
+        };
+    };
+    let mut levels: Vec<i32> = structure
+        .iter()
+        .copied()
+        .filter(|l| *l <= last)
+        .chain(by_level.keys().copied())
+        .collect();
+    levels.sort_unstable();
+    levels.dedup();
+
+    let empty = LevelActivityRow::default();
+    let lines = levels
+        .into_iter()
+        .map(|level_number| {
+            let row = by_level.get(&level_number).copied().unwrap_or(&empty);
+            let players_at_start = players.max(0);
+            players = players_at_start + row.entries - row.eliminations;
+            chips += row.chips;
+            LevelLine {
+                level_number,
+                entries: row.entries,
+                re_entries: row.re_entries,
+                rebuys: row.rebuys,
+                addons: row.addons,
+                eliminations: row.eliminations,
+                players_at_start,
+                players_at_end: players.max(0),
+                chips_in_play: chips,
+            }
+        })
+        .collect();
+
+    LevelBreakdown {
+        entries_before_start,
+        lines,
+    }
+}
+
+/// One level across a club's tournaments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PooledLevel {
+    pub level_number: i32,
+    /// Tournaments that played the level.
+    pub tournaments: i32,
+    pub average_entries: f64,
+    pub average_rebuys: f64,
+    pub average_addons: f64,
+    pub average_eliminations: f64,
+    /// Share of the players at the start of the level who busted in it.
+    pub bust_rate: f64,
+}
+
+/// Average each level over the tournaments that reached it.
+pub fn pool_levels(tournaments: &[Vec<LevelLine>]) -> Vec<PooledLevel> {
+    let mut pooled: BTreeMap<i32, Vec<&LevelLine>> = BTreeMap::new();
+    for line in tournaments.iter().flatten() {
+        pooled.entry(line.level_number).or_default().push(line);
+    }
+    pooled
+        .into_iter()
+        .map(|(level_number, lines)| {
+            let n = lines.len() as f64;
+            let sum = |f: fn(&LevelLine) -> i64| lines.iter().map(|l| f(l)).sum::<i64>() as f64;
+            let at_start = sum(|l| l.players_at_start);
+            let eliminations = sum(|l| l.eliminations);
+            PooledLevel {
+                level_number,
+                tournaments: lines.len() as i32,
+                average_entries: sum(|l| l.entries) / n,
+                average_rebuys: sum(|l| l.rebuys) / n,
+                average_addons: sum(|l| l.addons) / n,
+                average_eliminations: eliminations / n,
+                bust_rate: if at_start > 0.0 {
+                    eliminations / at_start
+                } else {
+                    0.0
+                },
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn activity(level: Option<i32>, entries: i64, rebuys: i64, busts: i64) -> LevelActivityRow {
+        LevelActivityRow {
+            level_number: level,
+            entries,
+            rebuys,
+            chips: (entries + rebuys) * 10_000,
+            eliminations: busts,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn carries_the_field_through_the_levels() {
+        let breakdown = level_lines(
+            &[1, 2, 3, 4, 5],
+            &[
+                activity(None, 10, 0, 0),
+                activity(Some(1), 2, 3, 1),
+                activity(Some(3), 0, 0, 4),
+            ],
+        );
+        assert_eq!(breakdown.entries_before_start, 10);
+        // Levels 4 and 5 haven't been reached.
+        let levels: Vec<_> = breakdown.lines.iter().map(|l| l.level_number).collect();
+        assert_eq!(levels, vec![1, 2, 3]);
+
+        let first = &breakdown.lines[0];
+        assert_eq!((first.players_at_start, first.players_at_end), (10, 11));
+        assert_eq!(first.chips_in_play, 150_000);
+        // A quiet level keeps the field as it was.
+        let second = &breakdown.lines[1];
+        assert_eq!((second.players_at_start, second.players_at_end), (11, 11));
+        assert_eq!(second.eliminations, 0);
+        let third = &breakdown.lines[2];
+        assert_eq!(third.players_at_end, 7);
+        assert_eq!(third.average_stack(), Some(150_000 / 7));
+    }
+
+    #[test]
+    fn nothing_to_show_before_the_clock_runs() {
+        let breakdown = level_lines(&[1, 2], &[activity(None, 8, 0, 0)]);
+        assert_eq!(breakdown.entries_before_start, 8);
+        assert!(breakdown.lines.is_empty());
+    }
+
+    #[test]
+    fn pools_levels_across_tournaments() {
+        let a = level_lines(
+            &[1, 2],
+            &[
+                activity(None, 20, 0, 0),
+                activity(Some(1), 0, 4, 2),
+                activity(Some(2), 0, 0, 6),
+            ],
+        );
+        let b = level_lines(
+            &[1, 2],
+            &[activity(None, 10, 0, 0), activity(Some(1), 0, 0, 3)],
+        );
+        let pooled = pool_levels(&[a.lines, b.lines]);
+
+        assert_eq!(pooled.len(), 2);
+        assert_eq!(pooled[0].tournaments, 2);
+        assert_eq!(pooled[0].average_rebuys, 2.0);
+        assert_eq!(pooled[0].average_eliminations, 2.5);
+        assert!((pooled[0].bust_rate - 5.0 / 30.0).abs() < 1e-9);
+        // Only the first tournament reached level 2: 6 of its 18 busted.
+        assert_eq!(pooled[1].tournaments, 1);
+        assert!((pooled[1].bust_rate - 6.0 / 18.0).abs() < 1e-9);
+    }
+}
@@ -1,3 +1,4 @@
+pub mod levels;
 pub mod resolvers;
 pub mod types;
 
@@ -1,12 +1,22 @@
-use async_graphql::{Context, Object, Result};
+use async_graphql::{Context, Object, Result, ID};
 use uuid::Uuid;
 
 use crate::auth::jwt::Claims;
+use crate::auth::permissions::require_club_manager;
+use crate::gql::common::helpers::get_club_id_for_tournament;
 use crate::gql::error::ResultExt;
+use crate::gql::types::TournamentStructure;
 use crate::state::AppState;
-use infra::repos::analytics;
+use infra::repos::{analytics, level_statistics, tournament_clock};
 
-use super::types::{BuyInBreakdown, ClubBreakdown, PnlPoint, ProAnalytics};
+use super::levels;
+use super::types::{
+    BuyInBreakdown, ClubBreakdown, ClubLevelStatistics, LevelStatistics, PnlPoint, ProAnalytics,
+    TournamentLevelStatistics,
+};
+
+/// Finished tournaments `clubLevelStatistics` averages over by default.
+const DEFAULT_POOLED_TOURNAMENTS: i64 = 20;
 
 #[derive(Default)]
 pub struct AnalyticsQuery;
@@ -40,8 +50,8 @@ impl AnalyticsQuery {
                 cumulative += p.net_cents;
                 PnlPoint {
                     day: p.day.to_string(),
-                    net_cents: p.net_cents.clamp(i32::MIN as i64, i32::MAX as i64) as i32,
-                    cumulative_cents: cumulative.clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+                    net_cents: p.net_cents.into(),
+                    cumulative_cents: cumulative.into(),
                 }
             })
             .collect();
@@ -52,4 +62,102 @@ impl AnalyticsQuery {
             cumulative_pnl,
         })
     }
+    /// Entries, rebuys, busts and the average stack level by level, for
+    /// tuning the structure. Club managers.
+    async fn tournament_level_statistics(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<TournamentLevelStatistics> {
+        let state = ctx.data::<AppState>()?;
+        let tournament_uuid =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_uuid).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let structure = tournament_clock::get_all_structures(&state.db, tournament_uuid).await?;
+        let activity = level_statistics::activity(&state.db, &[tournament_uuid]).await?;
+        let measured = level_statistics::measured_stacks(&state.db, tournament_uuid).await?;
+        let rebuys = level_statistics::rebuy_distribution(&state.db, tournament_uuid).await?;
+
+        let numbers: Vec<i32> = structure.iter().map(|s| s.level_number).collect();
+        let breakdown = levels::level_lines(&numbers, &activity);
+        let levels = breakdown
+            .lines
+            .into_iter()
+            .map(|line| {
+                let level = structure
+                    .iter()
+                    .find(|s| s.level_number == line.level_number);
+                let measured = measured
+                    .iter()
+                    .find(|m| m.level_number == line.level_number);
+                let average_stack = line.average_stack();
+                LevelStatistics {
+                    level_number: line.level_number,
+                    structure: level.cloned().map(TournamentStructure::from),
+                    entries: line.entries as i32,
+                    re_entries: line.re_entries as i32,
+                    rebuys: line.rebuys as i32,
+                    addons: line.addons as i32,
+                    eliminations: line.eliminations as i32,
+                    players_at_start: line.players_at_start as i32,
+                    players_at_end: line.players_at_end as i32,
+                    chips_in_play: line.chips_in_play,
+                    average_stack,
+                    average_stack_big_blinds: average_stack.zip(level).and_then(|(avg, l)| {
+                        (l.big_blind > 0).then(|| avg as f64 / f64::from(l.big_blind))
+                    }),
+                    measured_average_stack: measured.map(|m| m.average_stack.round() as i64),
+                    stacks_counted: measured.map_or(0, |m| m.players as i32),
+                }
+            })
+            .collect();
+
+        Ok(TournamentLevelStatistics {
+            tournament_id,
+            entries_before_start: breakdown.entries_before_start as i32,
+            levels,
+            rebuy_distribution: rebuys.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    /// Per-level averages over the club's recent finished tournaments
+    /// (default 20). Club managers.
+    async fn club_level_statistics(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+        limit: Option<i32>,
+    ) -> Result<ClubLevelStatistics> {
+        let state = ctx.data::<AppState>()?;
+        let club_uuid = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_uuid).await?;
+
+        let limit = limit.map_or(DEFAULT_POOLED_TOURNAMENTS, |l| i64::from(l.clamp(1, 100)));
+        let tournament_ids =
+            level_statistics::recent_tracked_tournaments(&state.db, club_uuid, limit).await?;
+        let activity = level_statistics::activity(&state.db, &tournament_ids).await?;
+
+        let mut per_tournament = Vec::with_capacity(tournament_ids.len());
+        for tournament_id in &tournament_ids {
+            let structure = tournament_clock::get_all_structures(&state.db, *tournament_id).await?;
+            let numbers: Vec<i32> = structure.iter().map(|s| s.level_number).collect();
+            let rows: Vec<_> = activity
+                .iter()
+                .filter(|a| a.tournament_id == *tournament_id)
+                .cloned()
+                .collect();
+            per_tournament.push(levels::level_lines(&numbers, &rows).lines);
+        }
+
+        Ok(ClubLevelStatistics {
+            club_id,
+            tournaments: tournament_ids.len() as i32,
+            levels: levels::pool_levels(&per_tournament)
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
 }
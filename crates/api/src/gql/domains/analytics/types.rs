@@ -1,6 +1,11 @@
 use async_graphql::{SimpleObject, ID};
 
 use infra::repos::analytics as repo;
+use infra::repos::level_statistics as repo_levels;
+
+use super::levels::PooledLevel;
+use crate::gql::scalars::Money;
+use crate::gql::types::TournamentStructure;
 
 fn clamp_i32(v: i64) -> i32 {
     v.clamp(i32::MIN as i64, i32::MAX as i64) as i32
@@ -11,9 +16,9 @@ pub struct ClubBreakdown {
     pub club_id: ID,
     pub club_name: String,
     pub tournaments: i32,
-    pub buyins_cents: i32,
-    pub winnings_cents: i32,
-    pub net_cents: i32,
+    pub buyins_cents: Money,
+    pub winnings_cents: Money,
+    pub net_cents: Money,
 }
 
 impl From<repo::ClubBreakdownRow> for ClubBreakdown {
@@ -22,30 +27,30 @@ impl From<repo::ClubBreakdownRow> for ClubBreakdown {
             club_id: r.club_id.into(),
             club_name: r.club_name,
             tournaments: clamp_i32(r.tournaments),
-            buyins_cents: clamp_i32(r.buyins_cents),
-            winnings_cents: clamp_i32(r.winnings_cents),
-            net_cents: clamp_i32(r.winnings_cents - r.buyins_cents),
+            buyins_cents: r.buyins_cents.into(),
+            winnings_cents: r.winnings_cents.into(),
+            net_cents: (r.winnings_cents - r.buyins_cents).into(),
         }
     }
 }
 
 #[derive(SimpleObject, Clone, Debug)]
 pub struct BuyInBreakdown {
-    pub buy_in_cents: i32,
+    pub buy_in_cents: Money,
     pub tournaments: i32,
-    pub buyins_cents: i32,
-    pub winnings_cents: i32,
-    pub net_cents: i32,
+    pub buyins_cents: Money,
+    pub winnings_cents: Money,
+    pub net_cents: Money,
 }
 
 impl From<repo::BuyInBreakdownRow> for BuyInBreakdown {
     fn from(r: repo::BuyInBreakdownRow) -> Self {
         Self {
-            buy_in_cents: r.buy_in_cents,
+            buy_in_cents: r.buy_in_cents.into(),
             tournaments: clamp_i32(r.tournaments),
-            buyins_cents: clamp_i32(r.buyins_cents),
-            winnings_cents: clamp_i32(r.winnings_cents),
-            net_cents: clamp_i32(r.winnings_cents - r.buyins_cents),
+            buyins_cents: r.buyins_cents.into(),
+            winnings_cents: r.winnings_cents.into(),
+            net_cents: (r.winnings_cents - r.buyins_cents).into(),
         }
     }
 }
@@ -54,8 +59,8 @@ impl From<repo::BuyInBreakdownRow> for BuyInBreakdown {
 pub struct PnlPoint {
     /// ISO date (YYYY-MM-DD) of the play day.
     pub day: String,
-    pub net_cents: i32,
-    pub cumulative_cents: i32,
+    pub net_cents: Money,
+    pub cumulative_cents: Money,
 }
 
 #[derive(SimpleObject, Clone, Debug)]
@@ -64,3 +69,91 @@ pub struct ProAnalytics {
     pub by_buy_in: Vec<BuyInBreakdown>,
     pub cumulative_pnl: Vec<PnlPoint>,
 }
+
+/// One level of a tournament: who joined, rebought and busted during it, and
+/// the field and average stack at its end.
+#[derive(SimpleObject, Clone)]
+pub struct LevelStatistics {
+    pub level_number: i32,
+    /// Blinds of the level; null past the end of the structure.
+    pub structure: Option<TournamentStructure>,
+    /// Buy-ins and re-entries recorded during the level.
+    pub entries: i32,
+    pub re_entries: i32,
+    pub rebuys: i32,
+    pub addons: i32,
+    pub eliminations: i32,
+    pub players_at_start: i32,
+    pub players_at_end: i32,
+    /// Chips handed out up to the end of the level.
+    pub chips_in_play: i64,
+    /// Chips in play over the players left.
+    pub average_stack: Option<i64>,
+    /// The average stack in big blinds of the level.
+    pub average_stack_big_blinds: Option<f64>,
+    /// Average of the chip counts recorded during the level, when any were.
+    pub measured_average_stack: Option<i64>,
+    pub stacks_counted: i32,
+}
+
+/// Players by number of rebuys.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct RebuyBucket {
+    pub rebuys: i32,
+    pub players: i32,
+}
+
+impl From<repo_levels::RebuyBucketRow> for RebuyBucket {
+    fn from(r: repo_levels::RebuyBucketRow) -> Self {
+        Self {
+            rebuys: clamp_i32(r.rebuys),
+            players: clamp_i32(r.players),
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct TournamentLevelStatistics {
+    pub tournament_id: ID,
+    /// Buy-ins recorded before the clock started.
+    pub entries_before_start: i32,
+    /// Levels played so far, through the last one anything happened in.
+    pub levels: Vec<LevelStatistics>,
+    pub rebuy_distribution: Vec<RebuyBucket>,
+}
+
+/// One level averaged over a club's recent tournaments.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct PooledLevelStatistics {
+    pub level_number: i32,
+    /// Tournaments that played the level.
+    pub tournaments: i32,
+    pub average_entries: f64,
+    pub average_rebuys: f64,
+    pub average_addons: f64,
+    pub average_eliminations: f64,
+    /// Share of the players at the start of the level who busted in it.
+    pub bust_rate: f64,
+}
+
+impl From<PooledLevel> for PooledLevelStatistics {
+    fn from(p: PooledLevel) -> Self {
+        Self {
+            level_number: p.level_number,
+            tournaments: p.tournaments,
+            average_entries: p.average_entries,
+            average_rebuys: p.average_rebuys,
+            average_addons: p.average_addons,
+            average_eliminations: p.average_eliminations,
+            bust_rate: p.bust_rate,
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone, Debug)]
+pub struct ClubLevelStatistics {
+    pub club_id: ID,
+    /// Finished tournaments with level data the averages are taken over.
+    pub tournaments: i32,
+    pub levels: Vec<PooledLevelStatistics>,
+}
@@ -1,4 +1,5 @@
 use async_graphql::{Context, Object, Result, ID};
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::auth::jwt::Claims;
@@ -7,10 +8,10 @@ use crate::gql::common::helpers::get_club_id_for_tournament;
 use crate::gql::error::ResultExt;
 use crate::gql::types::{PaginatedResponse, PaginationInput};
 use crate::state::AppState;
-use infra::repos::announcements;
+use infra::repos::{announcements, tournaments};
 
 use super::service;
-use super::types::{Announcement, AnnouncementScope, CreateAnnouncementInput};
+use super::types::{Announcement, AnnouncementScope, BroadcastChannel, CreateAnnouncementInput};
 
 fn default_page() -> PaginationInput {
     PaginationInput {
@@ -155,4 +156,98 @@ impl AnnouncementMutation {
 
         Ok(Announcement::from(row))
     }
+
+    /// Floor broadcast: a high-priority message to every player registered
+    /// for the tournament over the chosen channels. Pinned to the tournament
+    /// lobby unless `pin` is false. With `scheduledFor` it is held and sent by
+    /// the dispatcher when due (e.g. "dinner break in 10 min"). Club managers.
+    async fn broadcast_announcement(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        message: String,
+        channels: Vec<BroadcastChannel>,
+        scheduled_for: Option<DateTime<Utc>>,
+        pin: Option<bool>,
+    ) -> Result<Announcement> {
+        let state = ctx.data::<AppState>()?;
+        let tid = Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let tournament = tournaments::get_by_id(&state.db, tid)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        let manager = require_club_manager(ctx, tournament.club_id).await?;
+        let created_by = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid user ID")?;
+
+        if is_free_plan(ctx, tournament.club_id).await? {
+            return Err(async_graphql::Error::new(
+                "Announcements require the Club plan. Upgrade to reach your players.",
+            ));
+        }
+
+        let row = service::broadcast(
+            state,
+            tournament.club_id,
+            tid,
+            &tournament.name,
+            &message,
+            &channels,
+            pin.unwrap_or(true),
+            scheduled_for,
+            created_by,
+        )
+        .await?;
+
+        Ok(Announcement::from(row))
+    }
+
+    /// Pin or unpin a tournament announcement in the lobby. Club managers.
+    async fn set_announcement_pinned(
+        &self,
+        ctx: &Context<'_>,
+        announcement_id: ID,
+        pinned: bool,
+    ) -> Result<Announcement> {
+        let state = ctx.data::<AppState>()?;
+        let row = load_tournament_announcement(ctx, &announcement_id).await?;
+
+        let row = announcements::set_pinned(&state.db, row.id, pinned)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Announcement not found"))?;
+        Ok(Announcement::from(row))
+    }
+
+    /// Cancel a scheduled broadcast before it goes out. Club managers.
+    async fn cancel_scheduled_announcement(
+        &self,
+        ctx: &Context<'_>,
+        announcement_id: ID,
+    ) -> Result<bool> {
+        let state = ctx.data::<AppState>()?;
+        let row = load_tournament_announcement(ctx, &announcement_id).await?;
+
+        if !announcements::delete_pending(&state.db, row.id).await? {
+            return Err(async_graphql::Error::new(
+                "This announcement has already been sent",
+            ));
+        }
+        Ok(true)
+    }
+}
+
+/// Load a tournament-scoped announcement and check the caller manages its club.
+async fn load_tournament_announcement(
+    ctx: &Context<'_>,
+    announcement_id: &ID,
+) -> Result<infra::models::AnnouncementRow> {
+    let state = ctx.data::<AppState>()?;
+    let id = Uuid::parse_str(announcement_id.as_str()).gql_err("Invalid announcement ID")?;
+    let row = announcements::get_by_id(&state.db, id)
+        .await?
+        .filter(|row| row.scope == "tournament")
+        .ok_or_else(|| async_graphql::Error::new("Announcement not found"))?;
+    let club_id = row
+        .club_id
+        .ok_or_else(|| async_graphql::Error::new("Announcement not found"))?;
+    require_club_manager(ctx, club_id).await?;
+    Ok(row)
 }
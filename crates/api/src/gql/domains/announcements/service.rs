@@ -1,12 +1,20 @@
+use async_graphql::ID;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::gql::error::GqlError;
+use crate::gql::subscriptions::publish_user_notification;
+use crate::gql::types::{NotificationType, UserNotification};
 use crate::services::push_service;
+use crate::state::AppState;
 use infra::models::AnnouncementRow;
 use infra::repos::announcements;
 
-use super::types::AnnouncementScope;
+use super::types::{AnnouncementScope, BroadcastChannel};
+
+/// Longest floor broadcast accepted — it has to fit a couple of SMS segments.
+pub const MAX_BROADCAST_LENGTH: usize = 320;
 
 /// Persist an announcement and fan its push out to the resolved audience.
 ///
@@ -43,30 +51,172 @@ pub async fn create_announcement(
     .await?;
 
     // Fan out the push without blocking the mutation response.
+    spawn_push(db, row.clone());
+
+    Ok(row)
+}
+
+/// Push an announcement to its resolved audience in a background task.
+fn spawn_push(db: &PgPool, row: AnnouncementRow) {
     let db = db.clone();
-    let row_for_push = row.clone();
     tokio::spawn(async move {
-        match announcements::audience_device_tokens(&db, &row_for_push).await {
+        match announcements::audience_device_tokens(&db, &row).await {
             Ok(audience) => {
                 push_service::send_announcement(
                     &db,
                     audience,
-                    row_for_push.id,
-                    row_for_push.tournament_id,
-                    &row_for_push.title,
-                    &row_for_push.body,
+                    row.id,
+                    row.tournament_id,
+                    &row.title,
+                    &row.body,
                 )
                 .await;
             }
             Err(e) => {
                 tracing::warn!(
-                    announcement_id = %row_for_push.id,
+                    announcement_id = %row.id,
                     error = %e,
                     "announcement: failed to resolve push audience"
                 );
             }
         }
     });
+}
+
+/// Persist a tournament floor broadcast and, unless it is scheduled, deliver
+/// it right away. Auth, plan and club derivation happen in the resolver.
+#[allow(clippy::too_many_arguments)]
+pub async fn broadcast(
+    state: &AppState,
+    club_id: Uuid,
+    tournament_id: Uuid,
+    tournament_name: &str,
+    message: &str,
+    channels: &[BroadcastChannel],
+    pinned: bool,
+    scheduled_for: Option<DateTime<Utc>>,
+    created_by: Uuid,
+) -> Result<AnnouncementRow, GqlError> {
+    let message = message.trim();
+    if message.is_empty() {
+        return Err(GqlError::new("Message cannot be empty"));
+    }
+    if message.chars().count() > MAX_BROADCAST_LENGTH {
+        return Err(GqlError::new(format!(
+            "Message cannot exceed {MAX_BROADCAST_LENGTH} characters"
+        )));
+    }
+    let mut channel_names: Vec<String> = Vec::new();
+    for channel in channels {
+        let name = channel.as_str().to_string();
+        if !channel_names.contains(&name) {
+            channel_names.push(name);
+        }
+    }
+    if channel_names.is_empty() {
+        return Err(GqlError::new("Pick at least one channel"));
+    }
+    if scheduled_for.is_some_and(|at| at <= Utc::now()) {
+        return Err(GqlError::new("scheduledFor must be in the future"));
+    }
+
+    let row = announcements::create_broadcast(
+        &state.db,
+        club_id,
+        tournament_id,
+        tournament_name,
+        message,
+        &channel_names,
+        pinned,
+        scheduled_for,
+        created_by,
+    )
+    .await?;
+
+    if row.sent_at.is_some() {
+        deliver_broadcast(state, &row).await;
+    }
 
     Ok(row)
 }
+
+/// Send every scheduled broadcast that is due at `now`. Called by the
+/// announcement dispatcher; returns how many went out.
+pub async fn dispatch_due(state: &AppState, now: DateTime<Utc>) -> Result<usize, sqlx::Error> {
+    let due = announcements::claim_due(&state.db, now).await?;
+    for row in &due {
+        deliver_broadcast(state, row).await;
+    }
+    Ok(due.len())
+}
+
+/// Fan a sent floor broadcast out over its channels. The in-app notifications
+/// are published inline; push and SMS run in background tasks and are
+/// best-effort, like every other announcement delivery.
+async fn deliver_broadcast(state: &AppState, row: &AnnouncementRow) {
+    let Some(tournament_id) = row.tournament_id else {
+        return;
+    };
+    let channels: Vec<BroadcastChannel> = row
+        .channels
+        .iter()
+        .filter_map(|c| BroadcastChannel::from_db(c))
+        .collect();
+
+    if channels.contains(&BroadcastChannel::InApp) {
+        match announcements::tournament_audience_user_ids(&state.db, tournament_id).await {
+            Ok(users) => {
+                for user_id in users {
+                    publish_user_notification(UserNotification {
+                        id: ID::from(Uuid::new_v4().to_string()),
+                        user_id: ID::from(user_id.to_string()),
+                        notification_type: NotificationType::FloorAnnouncement,
+                        title: row.title.clone(),
+                        message: row.body.clone(),
+                        tournament_id: Some(ID::from(tournament_id.to_string())),
+                        created_at: Utc::now(),
+                    });
+                }
+            }
+            Err(e) => {
+                tracing::warn!(
+                    announcement_id = %row.id,
+                    error = %e,
+                    "broadcast: failed to resolve in-app audience"
+                );
+            }
+        }
+    }
+
+    if channels.contains(&BroadcastChannel::Push) {
+        spawn_push(&state.db, row.clone());
+    }
+
+    if channels.contains(&BroadcastChannel::Sms) {
+        let Some(sms) = state.sms_service().cloned() else {
+            tracing::warn!(announcement_id = %row.id, "broadcast: SMS not configured, skipping");
+            return;
+        };
+        let db = state.db.clone();
+        let announcement_id = row.id;
+        let text = format!("{}: {}", row.title, row.body);
+        tokio::spawn(async move {
+            let phones = match announcements::tournament_audience_phones(&db, tournament_id).await {
+                Ok(phones) => phones,
+                Err(e) => {
+                    tracing::warn!(
+                        %announcement_id,
+                        error = %e,
+                        "broadcast: failed to resolve SMS audience"
+                    );
+                    return;
+                }
+            };
+            for (user_id, phone) in phones {
+                if let Err(e) = sms.send_sms(&phone, &text).await {
+                    tracing::warn!(%announcement_id, %user_id, error = %e, "broadcast: SMS failed");
+                }
+            }
+        });
+    }
+}
@@ -31,6 +31,53 @@ impl AnnouncementScope {
     }
 }
 
+/// How urgently an announcement is delivered. Floor broadcasts are `HIGH`.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum AnnouncementPriority {
+    Normal,
+    High,
+}
+
+impl AnnouncementPriority {
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "high" => AnnouncementPriority::High,
+            _ => AnnouncementPriority::Normal,
+        }
+    }
+}
+
+/// A delivery channel for a floor broadcast.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum BroadcastChannel {
+    /// Live `userNotifications` subscription of every registered player.
+    InApp,
+    /// Mobile push (honours the `announcements` preference).
+    Push,
+    /// Text message to players with a phone number on file (honours the
+    /// `announcements` preference; skipped when SMS is not configured).
+    Sms,
+}
+
+impl BroadcastChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BroadcastChannel::InApp => "in_app",
+            BroadcastChannel::Push => "push",
+            BroadcastChannel::Sms => "sms",
+        }
+    }
+
+    pub fn from_db(s: &str) -> Option<Self> {
+        match s {
+            "in_app" => Some(BroadcastChannel::InApp),
+            "push" => Some(BroadcastChannel::Push),
+            "sms" => Some(BroadcastChannel::Sms),
+            _ => None,
+        }
+    }
+}
+
 /// An announcement authored by a manager/admin. Persisted (this is the player
 /// app's in-app feed) and also pushed to its audience on creation.
 #[derive(SimpleObject, Clone, Debug)]
@@ -41,6 +88,14 @@ pub struct Announcement {
     pub tournament_id: Option<ID>,
     pub title: String,
     pub body: String,
+    pub priority: AnnouncementPriority,
+    pub channels: Vec<BroadcastChannel>,
+    /// Shown in the tournament lobby until unpinned.
+    pub pinned: bool,
+    /// Due time of a scheduled broadcast.
+    pub scheduled_for: Option<DateTime<Utc>>,
+    /// When it went out; null while a scheduled broadcast is pending.
+    pub sent_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -53,6 +108,15 @@ impl From<infra::models::AnnouncementRow> for Announcement {
             tournament_id: row.tournament_id.map(Into::into),
             title: row.title,
             body: row.body,
+            priority: AnnouncementPriority::from_db(&row.priority),
+            channels: row
+                .channels
+                .iter()
+                .filter_map(|c| BroadcastChannel::from_db(c))
+                .collect(),
+            pinned: row.pinned,
+            scheduled_for: row.scheduled_for,
+            sent_at: row.sent_at,
             created_at: row.created_at,
         }
     }
@@ -1,20 +1,53 @@
-use async_graphql::{Context, Object, Result};
+use async_graphql::{Context, ErrorExtensions, Object, Result};
 use rand::{distr::Alphanumeric, RngExt};
 use uuid::Uuid;
 
+use crate::auth::challenge::{self, ChallengeKind};
+use crate::auth::identities::find_or_create_oauth_user;
 use crate::auth::{
     custom_oauth::CustomOAuthService, password::PasswordService, Claims, OAuthProvider,
 };
 use crate::gql::error::{auth_error, ResultExt};
 use crate::gql::types::User;
+use crate::middleware::quota::Principal;
 use crate::state::AppState;
 
+use super::service;
 use super::types::{
-    AuthPayload, CreateOAuthClientInput, CreateOAuthClientResponse, OAuthCallbackInput,
-    OAuthClient, OAuthUrlResponse, RequestPasswordResetInput, RequestPasswordResetResponse,
-    ResetPasswordInput, ResetPasswordResponse, UserLoginInput, UserRegistrationInput,
+    AuthPayload, CreateOAuthClientInput, CreateOAuthClientResponse, LinkOAuthProviderInput,
+    OAuthCallbackInput, OAuthClient, OAuthUrlResponse, RequestLoginLinkInput,
+    RequestPasswordResetInput, RequestPasswordResetResponse, ResetPasswordInput,
+    ResetPasswordResponse, SignupChallenge, SignupChallengeKind, UserLoginInput,
+    UserRegistrationInput,
 };
 
+/// Refuse the request unless it passed the environment's bot gate
+/// (`CHALLENGE_FAILED`).
+async fn require_challenge(ctx: &Context<'_>, response: Option<&str>) -> Result<()> {
+    let state = ctx.data::<AppState>()?;
+    let auth = state.auth_config();
+    let remote_ip = match ctx.data_opt::<Principal>() {
+        Some(Principal::Ip(ip)) => Some(ip.as_str()),
+        _ => None,
+    };
+    let passed = challenge::passes(
+        &state.db,
+        &auth.challenge,
+        &auth.jwt_secret,
+        response,
+        remote_ip,
+    )
+    .await
+    .gql_err("Database operation failed")?;
+    if !passed {
+        return Err(
+            async_graphql::Error::new("Please complete the challenge and try again")
+                .extend_with(|_, e| e.set("code", "CHALLENGE_FAILED")),
+        );
+    }
+    Ok(())
+}
+
 // ── Queries ──────────────────────────────────────────────────────────
 
 #[derive(Default)]
@@ -37,6 +70,45 @@ impl AuthQuery {
         Ok(user.into())
     }
 
+    /// The bot gate to pass before `registerUser` or `requestPasswordReset`,
+    /// with a fresh puzzle when it is proof of work.
+    async fn signup_challenge(&self, ctx: &Context<'_>) -> Result<SignupChallenge> {
+        let state = ctx.data::<AppState>()?;
+        let auth = state.auth_config();
+        let config = &auth.challenge;
+
+        let mut out = SignupChallenge {
+            kind: SignupChallengeKind::None,
+            site_key: None,
+            puzzle: None,
+            difficulty: None,
+            expires_at: None,
+        };
+        match config.kind {
+            ChallengeKind::Off => {}
+            ChallengeKind::Turnstile => {
+                out.kind = SignupChallengeKind::Turnstile;
+                out.site_key = config.captcha_site_key.clone();
+            }
+            ChallengeKind::HCaptcha => {
+                out.kind = SignupChallengeKind::Hcaptcha;
+                out.site_key = config.captcha_site_key.clone();
+            }
+            ChallengeKind::ProofOfWork => {
+                let (puzzle, expires_at) = challenge::issue_puzzle(
+                    &auth.jwt_secret,
+                    config.pow_difficulty,
+                    chrono::Utc::now(),
+                );
+                out.kind = SignupChallengeKind::ProofOfWork;
+                out.puzzle = Some(puzzle);
+                out.difficulty = Some(config.pow_difficulty as i32);
+                out.expires_at = Some(expires_at);
+            }
+        }
+        Ok(out)
+    }
+
     /// Get OAuth authorization URL for a provider
     async fn get_oauth_url(&self, ctx: &Context<'_>, provider: String) -> Result<OAuthUrlResponse> {
         let state = ctx.data::<AppState>()?;
@@ -72,72 +144,69 @@ impl AuthMutation {
         ctx: &Context<'_>,
         input: OAuthCallbackInput,
     ) -> Result<AuthPayload> {
-        let state = ctx.data::<AppState>()?;
-
-        let provider = match input.provider.as_str() {
-            "google" => OAuthProvider::Google,
-            "custom" => OAuthProvider::Custom,
-            _ => return Err(async_graphql::Error::new("Invalid OAuth provider")),
-        };
-
-        let oauth_user = match provider {
-            OAuthProvider::Custom => {
-                CustomOAuthService::exchange_code_for_user_info(state, input.code)
+        // Signing in reads and creates accounts before there is a viewer to
+        // scope them to.
+        infra::db::without_request_scope(async move {
+            let state = ctx.data::<AppState>()?;
+
+            let provider = match input.provider.as_str() {
+                "google" => OAuthProvider::Google,
+                "custom" => OAuthProvider::Custom,
+                _ => return Err(async_graphql::Error::new("Invalid OAuth provider")),
+            };
+
+            let oauth_user = match provider {
+                OAuthProvider::Custom => {
+                    CustomOAuthService::exchange_code_for_user_info(state, input.code)
+                        .await
+                        .gql_err("Database operation failed")?
+                }
+                _ => state
+                    .oauth_service()
+                    .exchange_code_for_user_info(provider.clone(), input.code)
                     .await
-                    .gql_err("Database operation failed")?
-            }
-            _ => state
-                .oauth_service()
-                .exchange_code_for_user_info(provider, input.code)
-                .await
-                .gql_err("Database operation failed")?,
-        };
-
-        // Check if user exists, if not create them
-        let user_id = match find_user_by_email(state, &oauth_user.email).await? {
-            Some(existing_user) => {
-                Uuid::parse_str(existing_user.id.as_str()).gql_err("Invalid user ID")?
-            }
-            None => create_user_from_oauth(state, &oauth_user, &input.provider).await?,
-        };
+                    .gql_err("Database operation failed")?,
+            };
 
-        // Get user info for response
-        let user = get_user_by_id(state, user_id).await?;
+            // Find the linked or same-email account, or create one
+            let user_id = find_or_create_oauth_user(&state.db, &provider, &oauth_user)
+                .await
+                .gql_err("Database operation failed")?;
 
-        // Generate JWT token
-        let role_str: String = user.role.into();
-        let token = state
-            .jwt_service()
-            .create_token(user_id, oauth_user.email.clone(), role_str)
-            .gql_err("Database operation failed")?;
+            // Get user info for response
+            let user = get_user_by_id(state, user_id).await?;
 
-        // Create refresh token and set HttpOnly cookie
-        let auth_config = state.auth_config();
-        let raw_refresh = crate::auth::refresh::create_refresh_token(
-            &state.db,
-            user_id,
-            auth_config.refresh_token_expiration_days,
-            true, // OAuth logins always persist the session
-        )
-        .await
-        .gql_err("Failed to create refresh token")?;
-
-        let max_age_secs = Some(auth_config.refresh_token_expiration_days * 24 * 60 * 60);
-        let cookie_value = crate::auth::cookie::build_refresh_cookie(
-            &raw_refresh,
-            max_age_secs,
-            &auth_config.cookie_domain,
-            auth_config.cookie_secure,
-            &auth_config.cookie_path,
-        );
-        ctx.insert_http_header("Set-Cookie", cookie_value);
+            // Generate JWT token
+            let token = state
+                .jwt_service()
+                .create_token(&state.db, user_id)
+                .await
+                .gql_err("Database operation failed")?;
 
-        // OAuth web flow uses the HttpOnly cookie; native OAuth isn't wired yet.
-        Ok(AuthPayload {
-            token,
-            user,
-            refresh_token: None,
+            // Create refresh token and set HttpOnly cookie
+            let auth_config = state.auth_config();
+            let raw_refresh = crate::auth::refresh::create_refresh_token(
+                &state.db,
+                user_id,
+                auth_config.refresh_token_expiration_days,
+                true, // OAuth logins always persist the session
+            )
+            .await
+            .gql_err("Failed to create refresh token")?;
+
+            let max_age_secs = Some(auth_config.refresh_token_expiration_days * 24 * 60 * 60);
+            let cookie_value =
+                crate::auth::cookie::build_refresh_cookie(&raw_refresh, max_age_secs, auth_config);
+            ctx.insert_http_header("Set-Cookie", cookie_value);
+
+            // OAuth web flow uses the HttpOnly cookie; native OAuth isn't wired yet.
+            Ok(AuthPayload {
+                token,
+                user,
+                refresh_token: None,
+            })
         })
+        .await
     }
 
     /// Validate token and get current user
@@ -152,6 +221,45 @@ impl AuthMutation {
         get_user_by_id(state, user_id).await
     }
 
+    /// Link an external login (e.g. Google) to the current account, from the
+    /// authorization code of the provider's consent screen.
+    async fn link_oauth_provider(
+        &self,
+        ctx: &Context<'_>,
+        input: LinkOAuthProviderInput,
+    ) -> Result<User> {
+        let claims = ctx.data::<Claims>().map_err(|_| auth_error())?;
+        let user_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+        let state = ctx.data::<AppState>()?;
+
+        let provider = match input.provider.as_str() {
+            "google" => OAuthProvider::Google,
+            _ => return Err(async_graphql::Error::new("Invalid OAuth provider")),
+        };
+
+        let oauth_user = state
+            .oauth_service()
+            .exchange_code_for_user_info(provider.clone(), input.code)
+            .await
+            .gql_err("OAuth code exchange failed")?;
+
+        service::link_provider(&state.db, user_id, &provider, &oauth_user).await?;
+
+        Ok(get_user_by_id(state, user_id).await?.with_contact_details())
+    }
+
+    /// Remove an external login from the current account. The account must
+    /// keep a password or another linked provider.
+    async fn unlink_oauth_provider(&self, ctx: &Context<'_>, provider: String) -> Result<User> {
+        let claims = ctx.data::<Claims>().map_err(|_| auth_error())?;
+        let user_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+        let state = ctx.data::<AppState>()?;
+
+        service::unlink_provider(&state.db, user_id, &provider).await?;
+
+        Ok(get_user_by_id(state, user_id).await?.with_contact_details())
+    }
+
     /// Create OAuth client (admin only)
     async fn create_oauth_client(
         &self,
@@ -201,160 +309,166 @@ impl AuthMutation {
 
     /// Register user with password (for custom OAuth)
     async fn register_user(&self, ctx: &Context<'_>, input: UserRegistrationInput) -> Result<User> {
-        let state = ctx.data::<AppState>()?;
+        // Signing up creates the account before there is a viewer to scope it to.
+        infra::db::without_request_scope(async move {
+            let state = ctx.data::<AppState>()?;
+            require_challenge(ctx, input.challenge_response.as_deref()).await?;
 
-        // Validate password strength
-        PasswordService::validate_password_strength(&input.password)
-            .gql_err("Database operation failed")?;
+            // Validate password strength
+            PasswordService::validate_password_strength(&input.password)
+                .gql_err("Database operation failed")?;
 
-        // Hash password
-        let password_hash =
-            PasswordService::hash_password(&input.password).gql_err("Database operation failed")?;
+            // Hash password
+            let password_hash = PasswordService::hash_password(&input.password)
+                .gql_err("Database operation failed")?;
 
-        // Check if user already exists
-        let existing_user = sqlx::query!("SELECT id FROM users WHERE email = $1", input.email)
-            .fetch_optional(&state.db)
-            .await
-            .gql_err("Database operation failed")?;
+            // Check if user already exists
+            let existing_user = sqlx::query!("SELECT id FROM users WHERE email = $1", input.email)
+                .fetch_optional(&state.db)
+                .await
+                .gql_err("Database operation failed")?;
 
-        if existing_user.is_some() {
-            return Err(async_graphql::Error::new(
-                "User with this email already exists",
-            ));
-        }
+            if existing_user.is_some() {
+                return Err(async_graphql::Error::new(
+                    "User with this email already exists",
+                ));
+            }
 
-        // Create user
-        let row = sqlx::query!(
-            r#"
+            // Create user
+            let row = sqlx::query!(
+                r#"
             INSERT INTO users (email, first_name, last_name, username, password_hash)
             VALUES ($1, $2, $3, $4, $5)
             RETURNING id
             "#,
-            input.email,
-            input.first_name,
-            input.last_name,
-            input.username,
-            password_hash
-        )
-        .fetch_one(&state.db)
-        .await
-        .gql_err("Database operation failed")?;
+                input.email,
+                input.first_name,
+                input.last_name,
+                input.username,
+                password_hash
+            )
+            .fetch_one(&state.db)
+            .await
+            .gql_err("Database operation failed")?;
 
-        Ok(User {
-            id: row.id.into(),
-            email: input.email,
-            username: input.username,
-            first_name: input.first_name,
-            last_name: Some(input.last_name),
-            phone: None,
-            is_active: true,
-            role: crate::gql::types::Role::Player,
-            locale: "en".to_string(),
+            Ok(User {
+                id: row.id.into(),
+                email: input.email,
+                username: input.username,
+                first_name: input.first_name,
+                last_name: Some(input.last_name),
+                phone: None,
+                avatar_url: None,
+                is_active: true,
+                role: crate::gql::types::Role::Player,
+                locale: "en".to_string(),
+                // The caller just registered it.
+                contact_details_visible: true,
+            })
         })
+        .await
     }
 
     /// Login user with password (returns JWT token)
     async fn login_user(&self, ctx: &Context<'_>, input: UserLoginInput) -> Result<AuthPayload> {
-        use sqlx::Row;
-
-        let state = ctx.data::<AppState>()?;
-
-        // Reject early if this account is temporarily locked from too many
-        // failures (same per-account lockout as the REST /oauth/login path).
-        if crate::auth::lockout::is_locked(&input.email) {
-            return Err(async_graphql::Error::new(
-                "Too many failed attempts. Please try again later.",
-            ));
-        }
+        // Signing in reads the account before there is a viewer to scope it to.
+        infra::db::without_request_scope(async move {
+            use sqlx::Row;
+
+            let state = ctx.data::<AppState>()?;
+
+            // Reject early if this account is temporarily locked from too many
+            // failures (same per-account lockout as the REST /oauth/login path).
+            if crate::auth::lockout::is_locked(&input.email) {
+                return Err(async_graphql::Error::new(
+                    "Too many failed attempts. Please try again later.",
+                ));
+            }
 
-        // Find user by email — only fetch id + password_hash for auth check
-        let auth_row = sqlx::query("SELECT id, password_hash FROM users WHERE email = $1")
-            .bind(&input.email)
-            .fetch_optional(&state.db)
-            .await
-            .gql_err("Database operation failed")?;
+            // Find user by email — only fetch id + password_hash for auth check
+            let auth_row = sqlx::query("SELECT id, password_hash FROM users WHERE email = $1")
+                .bind(&input.email)
+                .fetch_optional(&state.db)
+                .await
+                .gql_err("Database operation failed")?;
 
-        let auth_row = match auth_row {
-            Some(row) => row,
-            None => {
-                // Burn a bcrypt round so unknown-email and wrong-password take
-                // the same time, and count the failure so probing can't dodge
-                // the lockout.
-                PasswordService::verify_dummy(&input.password);
-                crate::auth::lockout::record_failure(&input.email);
-                return Err(async_graphql::Error::new("Invalid credentials"));
-            }
-        };
+            let auth_row = match auth_row {
+                Some(row) => row,
+                None => {
+                    // Burn a bcrypt round so unknown-email and wrong-password take
+                    // the same time, and count the failure so probing can't dodge
+                    // the lockout.
+                    PasswordService::verify_dummy(&input.password);
+                    crate::auth::lockout::record_failure(&input.email);
+                    return Err(async_graphql::Error::new("Invalid credentials"));
+                }
+            };
 
-        let user_id: Uuid = auth_row.get("id");
-        let password_hash: Option<String> = auth_row.get("password_hash");
+            let user_id: Uuid = auth_row.get("id");
+            let password_hash: Option<String> = auth_row.get("password_hash");
 
-        // Verify password
-        if let Some(ref hash) = password_hash {
-            if !PasswordService::verify_password(&input.password, hash)
-                .gql_err("Database operation failed")?
-            {
+            // Verify password
+            if let Some(ref hash) = password_hash {
+                if !PasswordService::verify_password(&input.password, hash)
+                    .gql_err("Database operation failed")?
+                {
+                    crate::auth::lockout::record_failure(&input.email);
+                    return Err(async_graphql::Error::new("Invalid credentials"));
+                }
+            } else {
+                // OAuth-only account: same generic error as a bad password so
+                // probing can't distinguish account types.
                 crate::auth::lockout::record_failure(&input.email);
                 return Err(async_graphql::Error::new("Invalid credentials"));
             }
-        } else {
-            // OAuth-only account: same generic error as a bad password so
-            // probing can't distinguish account types.
-            crate::auth::lockout::record_failure(&input.email);
-            return Err(async_graphql::Error::new("Invalid credentials"));
-        }
-
-        crate::auth::lockout::record_success(&input.email);
-
-        // Fetch full user data via repo (includes locale)
-        let user: User = infra::repos::users::get_by_id(&state.db, user_id)
-            .await?
-            .ok_or_else(|| async_graphql::Error::new("User not found"))?
-            .into();
-
-        let role_str: String = user.role.into();
 
-        // Generate JWT token
-        let token = state
-            .jwt_service()
-            .create_token(user_id, user.email.clone(), role_str)
-            .gql_err("Database operation failed")?;
-
-        // Create refresh token and set HttpOnly cookie
-        let auth_config = state.auth_config();
-        let raw_refresh = crate::auth::refresh::create_refresh_token(
-            &state.db,
-            user_id,
-            auth_config.refresh_token_expiration_days,
-            input.remember_me,
-        )
-        .await
-        .gql_err("Failed to create refresh token")?;
+            crate::auth::lockout::record_success(&input.email);
 
-        // "Remember me" → persistent cookie with Max-Age; otherwise session cookie
-        let max_age_secs = if input.remember_me {
-            Some(auth_config.refresh_token_expiration_days * 24 * 60 * 60)
-        } else {
-            None
-        };
-        let cookie_value = crate::auth::cookie::build_refresh_cookie(
-            &raw_refresh,
-            max_age_secs,
-            &auth_config.cookie_domain,
-            auth_config.cookie_secure,
-            &auth_config.cookie_path,
-        );
-        ctx.insert_http_header("Set-Cookie", cookie_value);
+            // Fetch full user data via repo (includes locale)
+            let user: User = infra::repos::users::get_by_id(&state.db, user_id)
+                .await?
+                .ok_or_else(|| async_graphql::Error::new("User not found"))?
+                .into();
 
-        // Native clients have no cookie jar: hand them the raw refresh token to
-        // store in the keychain. Web clients get None and use the cookie above.
-        let refresh_token = input.native_client.then_some(raw_refresh);
+            // Generate JWT token
+            let token = state
+                .jwt_service()
+                .create_token(&state.db, user_id)
+                .await
+                .gql_err("Database operation failed")?;
 
-        Ok(AuthPayload {
-            token,
-            user,
-            refresh_token,
+            // Create refresh token and set HttpOnly cookie
+            let auth_config = state.auth_config();
+            let raw_refresh = crate::auth::refresh::create_refresh_token(
+                &state.db,
+                user_id,
+                auth_config.refresh_token_expiration_days,
+                input.remember_me,
+            )
+            .await
+            .gql_err("Failed to create refresh token")?;
+
+            // "Remember me" → persistent cookie with Max-Age; otherwise session cookie
+            let max_age_secs = if input.remember_me {
+                Some(auth_config.refresh_token_expiration_days * 24 * 60 * 60)
+            } else {
+                None
+            };
+            let cookie_value =
+                crate::auth::cookie::build_refresh_cookie(&raw_refresh, max_age_secs, auth_config);
+            ctx.insert_http_header("Set-Cookie", cookie_value);
+
+            // Native clients have no cookie jar: hand them the raw refresh token to
+            // store in the keychain. Web clients get None and use the cookie above.
+            let refresh_token = input.native_client.then_some(raw_refresh);
+
+            Ok(AuthPayload {
+                token,
+                user,
+                refresh_token,
+            })
         })
+        .await
     }
 
     /// Get OAuth authorization URL for a provider
@@ -378,90 +492,145 @@ impl AuthMutation {
         })
     }
 
-    /// Request a password reset email (unauthenticated)
-    async fn request_password_reset(
+    /// Email a single-use sign-in link (unauthenticated). Always succeeds so
+    /// the response doesn't reveal whether the account exists; links beyond
+    /// the hourly cap are silently not sent.
+    async fn request_login_link(
         &self,
         ctx: &Context<'_>,
-        input: RequestPasswordResetInput,
-    ) -> Result<RequestPasswordResetResponse> {
-        use chrono::{Duration, Utc};
-        use rand::RngExt;
-
-        let state = ctx.data::<AppState>()?;
-
-        // Look up user by email
-        let user = find_user_by_email(state, &input.email).await?;
-
-        if let Some(user) = user {
+        input: RequestLoginLinkInput,
+    ) -> Result<bool> {
+        // Signed out: the account and its links are the service's to read.
+        infra::db::without_request_scope(async move {
+            let state = ctx.data::<AppState>()?;
+
+            let Some(user) = find_user_by_email(state, &input.email).await? else {
+                return Ok(true);
+            };
+            if !user.is_active {
+                return Ok(true);
+            }
             let user_id = Uuid::parse_str(user.id.as_str()).gql_err("Invalid user ID")?;
 
-            // Anti-abuse cap: at most 3 reset emails per user per hour. Beyond
-            // that, silently skip (the response stays generic) so an attacker
-            // can neither bomb a victim's inbox nor detect the throttle.
-            let recent = infra::repos::password_reset_tokens::created_since_count(
-                &state.db,
-                user_id,
-                Utc::now() - Duration::hours(1),
-            )
-            .await
-            .gql_err("Database operation failed")?;
-
-            if recent >= 3 {
-                tracing::warn!(user_id = %user_id, "password reset throttled (3/hour cap)");
-                return Ok(RequestPasswordResetResponse {
-                    success: true,
-                    message:
-                        "If an account with that email exists, a password reset link has been sent."
-                            .to_string(),
-                });
+            let Some(raw_token) = crate::auth::login_link::issue(&state.db, user_id)
+                .await
+                .gql_err("Database operation failed")?
+            else {
+                tracing::warn!(user_id = %user_id, "login link throttled (hourly cap)");
+                return Ok(true);
+            };
+
+            match state.email_service() {
+                Some(email_service) => {
+                    let locale = crate::services::email_service::Locale::from_str_lossy(
+                        input.locale.as_deref().unwrap_or(&user.locale),
+                    );
+                    if let Err(e) = email_service
+                        .send_login_link(&user.email, &user.first_name, &raw_token, locale)
+                        .await
+                    {
+                        tracing::error!("Failed to send login link email: {}", e);
+                    }
+                }
+                None => {
+                    tracing::warn!("Login link requested but the email service is not configured")
+                }
             }
 
-            // Invalidate any existing pending tokens for this user
-            infra::repos::password_reset_tokens::invalidate_for_user(&state.db, user_id)
+            Ok(true)
+        })
+        .await
+    }
+
+    /// Request a password reset email (unauthenticated)
+    async fn request_password_reset(
+        &self,
+        ctx: &Context<'_>,
+        input: RequestPasswordResetInput,
+    ) -> Result<RequestPasswordResetResponse> {
+        // Signed out: the account and its reset tokens are the service's to read.
+        infra::db::without_request_scope(async move {
+            use chrono::{Duration, Utc};
+            use rand::RngExt;
+
+            let state = ctx.data::<AppState>()?;
+            require_challenge(ctx, input.challenge_response.as_deref()).await?;
+
+            // Look up user by email
+            let user = find_user_by_email(state, &input.email).await?;
+
+            if let Some(user) = user {
+                let user_id = Uuid::parse_str(user.id.as_str()).gql_err("Invalid user ID")?;
+
+                // Anti-abuse cap: at most 3 reset emails per user per hour. Beyond
+                // that, silently skip (the response stays generic) so an attacker
+                // can neither bomb a victim's inbox nor detect the throttle.
+                let recent = infra::repos::password_reset_tokens::created_since_count(
+                    &state.db,
+                    user_id,
+                    Utc::now() - Duration::hours(1),
+                )
                 .await
                 .gql_err("Database operation failed")?;
 
-            // Generate a 64-char random token
-            let raw_token: String = rand::rng()
-                .sample_iter(&Alphanumeric)
-                .take(64)
-                .map(char::from)
-                .collect();
-
-            // Hash and store
-            let token_hash = crate::auth::refresh::hash_token(&raw_token);
-            let expires_at = Utc::now() + Duration::hours(1);
-
-            infra::repos::password_reset_tokens::create(
-                &state.db,
-                &token_hash,
-                user_id,
-                expires_at,
-            )
-            .await
-            .gql_err("Database operation failed")?;
+                if recent >= 3 {
+                    tracing::warn!(user_id = %user_id, "password reset throttled (3/hour cap)");
+                    return Ok(RequestPasswordResetResponse {
+                        success: true,
+                        message:
+                            "If an account with that email exists, a password reset link has been sent."
+                                .to_string(),
+                    });
+                }
 
-            // Send email (awaited, not fire-and-forget)
-            if let Some(email_service) = state.email_service() {
-                let display_name = user.first_name.clone();
-                let locale = crate::services::email_service::Locale::from_str_lossy(
-                    input.locale.as_deref().unwrap_or(&user.locale),
-                );
-                if let Err(e) = email_service
-                    .send_password_reset(&user.email, &display_name, &raw_token, locale)
+                // Invalidate any existing pending tokens for this user
+                infra::repos::password_reset_tokens::invalidate_for_user(&state.db, user_id)
                     .await
-                {
-                    tracing::error!("Failed to send password reset email: {}", e);
+                    .gql_err("Database operation failed")?;
+
+                // Generate a 64-char random token
+                let raw_token: String = rand::rng()
+                    .sample_iter(&Alphanumeric)
+                    .take(64)
+                    .map(char::from)
+                    .collect();
+
+                // Hash and store
+                let token_hash = crate::auth::refresh::hash_token(&raw_token);
+                let expires_at = Utc::now() + Duration::hours(1);
+
+                infra::repos::password_reset_tokens::create(
+                    &state.db,
+                    &token_hash,
+                    user_id,
+                    expires_at,
+                )
+                .await
+                .gql_err("Database operation failed")?;
+
+                // Send email (awaited, not fire-and-forget)
+                if let Some(email_service) = state.email_service() {
+                    let display_name = user.first_name.clone();
+                    let locale = crate::services::email_service::Locale::from_str_lossy(
+                        input.locale.as_deref().unwrap_or(&user.locale),
+                    );
+                    if let Err(e) = email_service
+                        .send_password_reset(&user.email, &display_name, &raw_token, locale)
+                        .await
+                    {
+                        tracing::error!("Failed to send password reset email: {}", e);
+                    }
                 }
             }
-        }
 
-        // Always return success to prevent user enumeration
-        Ok(RequestPasswordResetResponse {
-            success: true,
-            message: "If an account with that email exists, a password reset link has been sent."
-                .to_string(),
+            // Always return success to prevent user enumeration
+            Ok(RequestPasswordResetResponse {
+                success: true,
+                message: "If an account with that email exists, a password reset link has been sent."
+                    .to_string(),
+            })
         })
+        .await
     }
 
     /// Reset password using a token (unauthenticated)
@@ -470,50 +639,54 @@ impl AuthMutation {
         ctx: &Context<'_>,
         input: ResetPasswordInput,
     ) -> Result<ResetPasswordResponse> {
-        let state = ctx.data::<AppState>()?;
+        // Signed out: the reset token is the service's to read.
+        infra::db::without_request_scope(async move {
+            let state = ctx.data::<AppState>()?;
+
+            // Validate password strength
+            PasswordService::validate_password_strength(&input.new_password)
+                .gql_err("Password validation failed")?;
+
+            // Hash the incoming token and look up
+            let token_hash = crate::auth::refresh::hash_token(&input.token);
+            let token_row =
+                infra::repos::password_reset_tokens::find_valid_by_hash(&state.db, &token_hash)
+                    .await
+                    .gql_err("Database operation failed")?;
+
+            let token_row = match token_row {
+                Some(row) => row,
+                None => {
+                    return Ok(ResetPasswordResponse {
+                        success: false,
+                        message: "Invalid or expired reset token.".to_string(),
+                    });
+                }
+            };
 
-        // Validate password strength
-        PasswordService::validate_password_strength(&input.new_password)
-            .gql_err("Password validation failed")?;
+            // Hash new password
+            let password_hash = PasswordService::hash_password(&input.new_password)
+                .gql_err("Password hashing failed")?;
 
-        // Hash the incoming token and look up
-        let token_hash = crate::auth::refresh::hash_token(&input.token);
-        let token_row =
-            infra::repos::password_reset_tokens::find_valid_by_hash(&state.db, &token_hash)
+            // Update user password
+            sqlx::query("UPDATE users SET password_hash = $1 WHERE id = $2")
+                .bind(&password_hash)
+                .bind(token_row.user_id)
+                .execute(&state.db)
                 .await
                 .gql_err("Database operation failed")?;
 
-        let token_row = match token_row {
-            Some(row) => row,
-            None => {
-                return Ok(ResetPasswordResponse {
-                    success: false,
-                    message: "Invalid or expired reset token.".to_string(),
-                });
-            }
-        };
-
-        // Hash new password
-        let password_hash = PasswordService::hash_password(&input.new_password)
-            .gql_err("Password hashing failed")?;
-
-        // Update user password
-        sqlx::query("UPDATE users SET password_hash = $1 WHERE id = $2")
-            .bind(&password_hash)
-            .bind(token_row.user_id)
-            .execute(&state.db)
-            .await
-            .gql_err("Database operation failed")?;
-
-        // Mark token as used
-        infra::repos::password_reset_tokens::mark_used(&state.db, token_row.id)
-            .await
-            .gql_err("Database operation failed")?;
+            // Mark token as used
+            infra::repos::password_reset_tokens::mark_used(&state.db, token_row.id)
+                .await
+                .gql_err("Database operation failed")?;
 
-        Ok(ResetPasswordResponse {
-            success: true,
-            message: "Password has been reset successfully.".to_string(),
+            Ok(ResetPasswordResponse {
+                success: true,
+                message: "Password has been reset successfully.".to_string(),
+            })
         })
+        .await
     }
 }
 
@@ -540,7 +713,7 @@ fn generate_client_secret() -> String {
 
 async fn find_user_by_email(state: &AppState, email: &str) -> Result<Option<User>> {
     let row = sqlx::query_as::<_, infra::models::UserRow>(
-        "SELECT id, email, username, first_name, last_name, phone, is_active, role, locale, created_at, updated_at FROM users WHERE email = $1",
+        "SELECT id, email, username, first_name, last_name, phone, avatar_url, is_active, role, locale, created_at, updated_at FROM users WHERE email = $1",
     )
     .bind(email)
     .fetch_optional(&state.db)
@@ -550,32 +723,6 @@ async fn find_user_by_email(state: &AppState, email: &str) -> Result<Option<User
     Ok(row.map(User::from))
 }
 
-async fn create_user_from_oauth(
-    state: &AppState,
-    oauth_user: &crate::auth::oauth::OAuthUserInfo,
-    provider: &str,
-) -> Result<Uuid> {
-    let row = sqlx::query!(
-        r#"
-        INSERT INTO users (email, username, first_name, last_name, oauth_provider, oauth_provider_id, avatar_url)
-        VALUES ($1, $2, $3, $4, $5, $6, $7)
-        RETURNING id
-        "#,
-        oauth_user.email,
-        oauth_user.username,
-        oauth_user.first_name,
-        oauth_user.last_name,
-        provider,
-        oauth_user.provider_id,
-        oauth_user.avatar_url
-    )
-    .fetch_one(&state.db)
-    .await
-    .gql_err("Database operation failed")?;
-
-    Ok(row.id)
-}
-
 async fn get_user_by_id(state: &AppState, user_id: Uuid) -> Result<User> {
     let row = infra::repos::users::get_by_id(&state.db, user_id)
         .await
@@ -0,0 +1,89 @@
+//! Linking and unlinking external logins on an existing account.
+
+use async_graphql::{Error, ErrorExtensions, Result};
+use infra::repos::auth_identities::{self, AuthIdentityRow};
+use infra::repos::users;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::auth::oauth::{OAuthProvider, OAuthUserInfo};
+use crate::gql::error::ResultExt;
+
+fn coded(message: &str, code: &'static str) -> Error {
+    Error::new(message).extend_with(|_, e| e.set("code", code))
+}
+
+/// Link a provider identity to `user_id`. Linking the same identity again is
+/// a no-op; an identity linked to another account is `IDENTITY_IN_USE`, and a
+/// second identity for a provider already linked is `PROVIDER_ALREADY_LINKED`.
+pub async fn link_provider(
+    db: &PgPool,
+    user_id: Uuid,
+    provider: &OAuthProvider,
+    info: &OAuthUserInfo,
+) -> Result<AuthIdentityRow> {
+    let mut tx = db.begin().await.gql_err("Database operation failed")?;
+    users::lock_has_password(&mut *tx, user_id)
+        .await?
+        .ok_or_else(|| Error::new("User not found"))?;
+
+    let linked = auth_identities::list_for_user(&mut *tx, user_id).await?;
+    if let Some(existing) = linked.iter().find(|i| i.provider == provider.as_str()) {
+        if existing.provider_user_id == info.provider_id {
+            return Ok(existing.clone());
+        }
+        return Err(coded(
+            "Another account from this provider is already linked",
+            "PROVIDER_ALREADY_LINKED",
+        ));
+    }
+
+    let identity = match auth_identities::create(
+        &mut *tx,
+        user_id,
+        provider.as_str(),
+        &info.provider_id,
+        Some(&info.email),
+    )
+    .await
+    {
+        Ok(identity) => identity,
+        Err(sqlx::Error::Database(e)) if e.is_unique_violation() => {
+            return Err(coded(
+                "This login is already linked to another account",
+                "IDENTITY_IN_USE",
+            ));
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    tx.commit().await.gql_err("Database operation failed")?;
+    Ok(identity)
+}
+
+/// Remove a linked provider. Refused with `LAST_LOGIN_METHOD` when it is the
+/// account's only way to sign in (no password and no other provider).
+pub async fn unlink_provider(db: &PgPool, user_id: Uuid, provider: &str) -> Result<()> {
+    let mut tx = db.begin().await.gql_err("Database operation failed")?;
+    let has_password = users::lock_has_password(&mut *tx, user_id)
+        .await?
+        .ok_or_else(|| Error::new("User not found"))?;
+
+    let linked = auth_identities::list_for_user(&mut *tx, user_id).await?;
+    if !linked.iter().any(|i| i.provider == provider) {
+        return Err(coded(
+            "This provider is not linked to your account",
+            "PROVIDER_NOT_LINKED",
+        ));
+    }
+    if !has_password && linked.len() == 1 {
+        return Err(coded(
+            "Set a password or link another provider before removing your last login method",
+            "LAST_LOGIN_METHOD",
+        ));
+    }
+
+    auth_identities::delete(&mut *tx, user_id, provider).await?;
+    tx.commit().await.gql_err("Database operation failed")?;
+    Ok(())
+}
@@ -1,4 +1,6 @@
-use async_graphql::{InputObject, SimpleObject, ID};
+use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+use infra::repos::auth_identities::AuthIdentityRow;
 
 use crate::gql::types::User;
 
@@ -56,6 +58,8 @@ pub struct UserRegistrationInput {
     pub first_name: String,
     pub last_name: String,
     pub username: Option<String>,
+    /// Answer to `signupChallenge`, when the server asks for one.
+    pub challenge_response: Option<String>,
 }
 
 #[derive(InputObject)]
@@ -77,6 +81,8 @@ pub struct RequestPasswordResetInput {
     pub email: String,
     /// Optional locale for the email (e.g. "en", "fr", "nl"). Defaults to "en".
     pub locale: Option<String>,
+    /// Answer to `signupChallenge`, when the server asks for one.
+    pub challenge_response: Option<String>,
 }
 
 #[derive(SimpleObject)]
@@ -85,6 +91,14 @@ pub struct RequestPasswordResetResponse {
     pub message: String,
 }
 
+#[derive(InputObject)]
+pub struct RequestLoginLinkInput {
+    pub email: String,
+    /// Optional locale for the email (e.g. "en", "fr", "nl"). Defaults to the
+    /// account's locale.
+    pub locale: Option<String>,
+}
+
 #[derive(InputObject)]
 pub struct ResetPasswordInput {
     pub token: String,
@@ -96,3 +110,55 @@ pub struct ResetPasswordResponse {
     pub success: bool,
     pub message: String,
 }
+
+/// An external login (e.g. Google) linked to an account.
+#[derive(SimpleObject, Clone)]
+pub struct LinkedProvider {
+    pub provider: String,
+    /// Email the provider reported when the login was linked.
+    pub email: Option<String>,
+    pub linked_at: DateTime<Utc>,
+}
+
+impl From<AuthIdentityRow> for LinkedProvider {
+    fn from(row: AuthIdentityRow) -> Self {
+        Self {
+            provider: row.provider,
+            email: row.email,
+            linked_at: row.created_at,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct LinkOAuthProviderInput {
+    pub provider: String,
+    /// Authorization code from the provider's consent screen.
+    pub code: String,
+    pub csrf_token: String,
+}
+
+/// The bot gate in front of signup and password reset.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum SignupChallengeKind {
+    /// No gate: leave `challengeResponse` out.
+    None,
+    /// Render the Cloudflare Turnstile widget with `siteKey`; send its token.
+    Turnstile,
+    /// Render the hCaptcha widget with `siteKey`; send its token.
+    Hcaptcha,
+    /// Find a `solution` such that SHA-256 of `"{puzzle}:{solution}"` starts
+    /// with `difficulty` zero bits; send `"{puzzle}:{solution}"`.
+    ProofOfWork,
+}
+
+/// What to solve before `registerUser` or `requestPasswordReset`.
+#[derive(SimpleObject, Clone)]
+pub struct SignupChallenge {
+    pub kind: SignupChallengeKind,
+    pub site_key: Option<String>,
+    pub puzzle: Option<String>,
+    pub difficulty: Option<i32>,
+    /// When `puzzle` stops being accepted.
+    pub expires_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,143 @@
+use async_graphql::{Context, Object, Result, ID};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::auth::jwt::Claims;
+use crate::gql::error::ResultExt;
+use crate::state::AppState;
+use infra::db::RowScope;
+use infra::repos::bankroll::{self, CreateBankrollTransaction};
+
+use super::types::{
+    AddBankrollTransactionInput, BankrollHistory, BankrollMonth, BankrollTransaction,
+};
+
+/// The bankroll is private to its owner: every resolver reads the viewer from
+/// the token and passes it to owner-scoped queries, run in a transaction
+/// scoped to the viewer so row-level security backs those filters up. Staff
+/// and admin roles get no wider access.
+fn owner_id(ctx: &Context<'_>) -> Result<Uuid> {
+    let claims = ctx.data::<Claims>()?;
+    Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")
+}
+
+fn trim_opt(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+#[derive(Default)]
+pub struct BankrollQuery;
+
+#[Object]
+impl BankrollQuery {
+    /// The viewer's bankroll ledger with monthly profit and loss, optionally
+    /// limited to `[from, to)`.
+    async fn my_bankroll_history(
+        &self,
+        ctx: &Context<'_>,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<BankrollHistory> {
+        let user_id = owner_id(ctx)?;
+        let state = ctx.data::<AppState>()?;
+
+        let mut tx = state.scoped(RowScope::user(user_id)).await?;
+        let transactions = bankroll::list_for_user(&mut *tx, user_id, from, to).await?;
+
+        let mut cumulative: i64 = 0;
+        let (mut total_buy_ins, mut total_cashes) = (0_i64, 0_i64);
+        let months = bankroll::monthly_for_user(&mut *tx, user_id, from, to)
+            .await?
+            .into_iter()
+            .map(|m| {
+                let net = m.cashes_cents - m.buy_ins_cents;
+                cumulative += net;
+                total_buy_ins += m.buy_ins_cents;
+                total_cashes += m.cashes_cents;
+                BankrollMonth {
+                    month: m.month.format("%Y-%m").to_string(),
+                    buy_ins_cents: m.buy_ins_cents.into(),
+                    cashes_cents: m.cashes_cents.into(),
+                    net_cents: net.into(),
+                    cumulative_cents: cumulative.into(),
+                }
+            })
+            .collect();
+        tx.commit().await?;
+
+        Ok(BankrollHistory {
+            transactions: transactions
+                .into_iter()
+                .map(BankrollTransaction::from)
+                .collect(),
+            months,
+            total_buy_ins_cents: total_buy_ins.into(),
+            total_cashes_cents: total_cashes.into(),
+            net_cents: (total_cashes - total_buy_ins).into(),
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct BankrollMutation;
+
+#[Object]
+impl BankrollMutation {
+    /// Log a buy-in or cash from a game played outside this system.
+    async fn add_bankroll_transaction(
+        &self,
+        ctx: &Context<'_>,
+        input: AddBankrollTransactionInput,
+    ) -> Result<BankrollTransaction> {
+        let user_id = owner_id(ctx)?;
+        if input.amount_cents.cents() <= 0 {
+            return Err(async_graphql::Error::new("Amount must be positive"));
+        }
+
+        let state = ctx.data::<AppState>()?;
+        let mut tx = state.scoped(RowScope::user(user_id)).await?;
+        let row = bankroll::create(
+            &mut *tx,
+            user_id,
+            CreateBankrollTransaction {
+                kind: input.kind.as_db().to_string(),
+                amount_cents: input.amount_cents.cents(),
+                occurred_at: input.occurred_at,
+                venue: trim_opt(input.venue),
+                description: trim_opt(input.description),
+            },
+        )
+        .await?;
+        tx.commit().await?;
+        Ok(row.into())
+    }
+
+    /// Remove one of the viewer's ledger rows. An imported row comes back on
+    /// the next import.
+    async fn delete_bankroll_transaction(&self, ctx: &Context<'_>, id: ID) -> Result<bool> {
+        let user_id = owner_id(ctx)?;
+        let id = Uuid::parse_str(id.as_str()).gql_err("Invalid transaction ID")?;
+
+        let state = ctx.data::<AppState>()?;
+        let mut tx = state.scoped(RowScope::user(user_id)).await?;
+        if !bankroll::delete_owned(&mut *tx, user_id, id).await? {
+            return Err(async_graphql::Error::new("Transaction not found"));
+        }
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    /// Copy the viewer's finished tournaments in this system into their
+    /// ledger. Re-running refreshes earlier imports instead of duplicating
+    /// them. Returns the number of rows added or updated.
+    async fn import_my_tournament_bankroll(&self, ctx: &Context<'_>) -> Result<i32> {
+        let user_id = owner_id(ctx)?;
+        let state = ctx.data::<AppState>()?;
+        let mut tx = state.scoped(RowScope::user(user_id)).await?;
+        let affected = bankroll::import_from_tournaments(&mut *tx, user_id).await?;
+        tx.commit().await?;
+        Ok(affected as i32)
+    }
+}
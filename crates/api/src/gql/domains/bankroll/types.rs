@@ -0,0 +1,102 @@
+use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use infra::repos::bankroll::BankrollTransactionRow;
+
+use crate::gql::scalars::Money;
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum BankrollTransactionKind {
+    /// Money put into a game: buy-ins, rebuys, add-ons, fees.
+    BuyIn,
+    /// Money taken out: prizes, cash-game wins.
+    Cash,
+}
+
+impl BankrollTransactionKind {
+    pub fn as_db(self) -> &'static str {
+        match self {
+            BankrollTransactionKind::BuyIn => "buy_in",
+            BankrollTransactionKind::Cash => "cash",
+        }
+    }
+
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "cash" => BankrollTransactionKind::Cash,
+            _ => BankrollTransactionKind::BuyIn,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum BankrollTransactionSource {
+    /// Logged by the player.
+    Manual,
+    /// Copied from a tournament played in this system.
+    Import,
+}
+
+#[derive(SimpleObject, Clone, Debug)]
+pub struct BankrollTransaction {
+    pub id: ID,
+    pub kind: BankrollTransactionKind,
+    pub amount_cents: Money,
+    pub occurred_at: DateTime<Utc>,
+    pub venue: Option<String>,
+    pub description: Option<String>,
+    pub source: BankrollTransactionSource,
+    /// The tournament an imported row came from.
+    pub tournament_id: Option<ID>,
+}
+
+impl From<BankrollTransactionRow> for BankrollTransaction {
+    fn from(r: BankrollTransactionRow) -> Self {
+        Self {
+            id: r.id.into(),
+            kind: BankrollTransactionKind::from_db(&r.kind),
+            amount_cents: r.amount_cents.into(),
+            occurred_at: r.occurred_at,
+            venue: r.venue,
+            description: r.description,
+            source: if r.source == "import" {
+                BankrollTransactionSource::Import
+            } else {
+                BankrollTransactionSource::Manual
+            },
+            tournament_id: r.tournament_id.map(Into::into),
+        }
+    }
+}
+
+/// Profit and loss for one calendar month (UTC).
+#[derive(SimpleObject, Clone, Debug)]
+pub struct BankrollMonth {
+    /// "YYYY-MM".
+    pub month: String,
+    pub buy_ins_cents: Money,
+    pub cashes_cents: Money,
+    pub net_cents: Money,
+    /// Running net from the first month in the window.
+    pub cumulative_cents: Money,
+}
+
+#[derive(SimpleObject, Clone, Debug)]
+pub struct BankrollHistory {
+    /// Newest first.
+    pub transactions: Vec<BankrollTransaction>,
+    /// Oldest first; months without activity are omitted.
+    pub months: Vec<BankrollMonth>,
+    pub total_buy_ins_cents: Money,
+    pub total_cashes_cents: Money,
+    pub net_cents: Money,
+}
+
+#[derive(InputObject)]
+pub struct AddBankrollTransactionInput {
+    pub kind: BankrollTransactionKind,
+    pub amount_cents: Money,
+    pub occurred_at: DateTime<Utc>,
+    pub venue: Option<String>,
+    pub description: Option<String>,
+}
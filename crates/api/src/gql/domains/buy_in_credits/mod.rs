@@ -0,0 +1,219 @@
+//! Prepaid buy-ins: packages sold to a player and vouchers handed out by the
+//! club. Players redeem the code when registering; the desk then records the
+//! buy-in as voucher-funded.
+
+pub mod resolvers;
+pub mod types;
+
+pub use resolvers::{BuyInCreditMutation, BuyInCreditQuery};
+
+use chrono::{DateTime, Utc};
+use rand::RngExt;
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use infra::repos::buy_in_credits::{self, CreditRow, NewLedgerEntry};
+
+/// Unambiguous characters for redemption codes (no 0/O, 1/I/L).
+const CODE_ALPHABET: &[u8] = b"23456789ABCDEFGHJKMNPQRSTUVWXYZ";
+
+/// A random `XXXXX-XXXXX` redemption code.
+pub fn redemption_code() -> String {
+    let mut rng = rand::rng();
+    let chars: String = (0..10)
+        .map(|_| char::from(CODE_ALPHABET[rng.random_range(0..CODE_ALPHABET.len())]))
+        .collect();
+    format!("{}-{}", &chars[..5], &chars[5..])
+}
+
+/// Normalize a typed code: case and separators don't matter.
+pub fn normalize_code(input: &str) -> String {
+    let chars: String = input
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    if chars.len() == 10 {
+        format!("{}-{}", &chars[..5], &chars[5..])
+    } else {
+        chars
+    }
+}
+
+/// Why `club_player_id` can't redeem the credit for a tournament of
+/// `club_id`; `None` when it can.
+pub fn redemption_refusal(
+    credit: &CreditRow,
+    club_id: Uuid,
+    club_player_id: Uuid,
+    now: DateTime<Utc>,
+) -> Option<&'static str> {
+    if credit.club_id != club_id {
+        Some("This voucher is for another club")
+    } else if credit.voided_at.is_some() {
+        Some("This voucher has been voided")
+    } else if credit.expires_at.is_some_and(|at| at <= now) {
+        Some("This voucher has expired")
+    } else if credit
+        .club_player_id
+        .is_some_and(|owner| owner != club_player_id)
+    {
+        Some("This voucher belongs to another player")
+    } else if credit.remaining_uses < 1 {
+        Some("This voucher has no uses left")
+    } else {
+        None
+    }
+}
+
+/// Redeem one use of the credit with `code` for a fresh registration, inside
+/// the registration's transaction. A bearer voucher becomes the player's.
+pub async fn redeem(
+    conn: &mut PgConnection,
+    code: &str,
+    club_id: Uuid,
+    club_player_id: Uuid,
+    tournament_id: Uuid,
+    registration_id: Uuid,
+    redeemed_by: Uuid,
+) -> async_graphql::Result<()> {
+    let credit = buy_in_credits::lock_by_code(&mut *conn, &normalize_code(code))
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Unknown voucher code"))?;
+    if let Some(reason) = redemption_refusal(&credit, club_id, club_player_id, Utc::now()) {
+        return Err(async_graphql::Error::new(reason));
+    }
+    buy_in_credits::claim(&mut *conn, credit.id, club_player_id).await?;
+    buy_in_credits::add_ledger_entry(
+        &mut *conn,
+        NewLedgerEntry {
+            credit_id: credit.id,
+            kind: "redemption",
+            delta: -1,
+            tournament_id: Some(tournament_id),
+            registration_id: Some(registration_id),
+            created_by: Some(redeemed_by),
+            ..Default::default()
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+/// Give back the use a cancelled registration redeemed, unless the desk
+/// already recorded the buy-in it paid for.
+pub async fn refund_for_registration(
+    conn: &mut PgConnection,
+    registration_id: Uuid,
+    refunded_by: Option<Uuid>,
+) -> sqlx::Result<()> {
+    refund_redemption(conn, registration_id, refunded_by, false).await
+}
+
+/// Give back the use a registration redeemed when its tournament is
+/// cancelled: the buy-in it paid for, if recorded, is voided with it.
+pub async fn refund_for_cancellation(
+    conn: &mut PgConnection,
+    registration_id: Uuid,
+    refunded_by: Option<Uuid>,
+) -> sqlx::Result<()> {
+    refund_redemption(conn, registration_id, refunded_by, true).await
+}
+
+async fn refund_redemption(
+    conn: &mut PgConnection,
+    registration_id: Uuid,
+    refunded_by: Option<Uuid>,
+    entered_too: bool,
+) -> sqlx::Result<()> {
+    let Some(redemption) = buy_in_credits::open_redemption(&mut *conn, registration_id).await?
+    else {
+        return Ok(());
+    };
+    if redemption.entry_id.is_some() && !entered_too {
+        return Ok(());
+    }
+    buy_in_credits::add_ledger_entry(
+        &mut *conn,
+        NewLedgerEntry {
+            credit_id: redemption.credit_id,
+            kind: "refund",
+            delta: 1,
+            tournament_id: redemption.tournament_id,
+            registration_id: Some(registration_id),
+            reverses_id: Some(redemption.id),
+            created_by: refunded_by,
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credit() -> CreditRow {
+        CreditRow {
+            id: Uuid::nil(),
+            club_id: Uuid::nil(),
+            kind: "package".into(),
+            code: "ABCDE-FGHJK".into(),
+            label: "5 tournament bundle".into(),
+            club_player_id: None,
+            player_name: None,
+            total_uses: 5,
+            remaining_uses: 2,
+            expires_at: None,
+            issued_by: None,
+            issued_at: Utc::now(),
+            voided_by: None,
+            voided_at: None,
+        }
+    }
+
+    #[test]
+    fn codes_are_grouped_and_normalized() {
+        let code = redemption_code();
+        assert_eq!(code.len(), 11);
+        assert_eq!(normalize_code(&code.to_lowercase().replace('-', " ")), code);
+    }
+
+    #[test]
+    fn refuses_unusable_credits() {
+        let now = Utc::now();
+        let player = Uuid::new_v4();
+        assert_eq!(
+            redemption_refusal(&credit(), Uuid::nil(), player, now),
+            None
+        );
+        assert_eq!(
+            redemption_refusal(&credit(), Uuid::new_v4(), player, now),
+            Some("This voucher is for another club")
+        );
+        let owned = CreditRow {
+            club_player_id: Some(Uuid::new_v4()),
+            ..credit()
+        };
+        assert_eq!(
+            redemption_refusal(&owned, Uuid::nil(), player, now),
+            Some("This voucher belongs to another player")
+        );
+        let spent = CreditRow {
+            remaining_uses: 0,
+            ..credit()
+        };
+        assert_eq!(
+            redemption_refusal(&spent, Uuid::nil(), player, now),
+            Some("This voucher has no uses left")
+        );
+        let expired = CreditRow {
+            expires_at: Some(now),
+            ..credit()
+        };
+        assert_eq!(
+            redemption_refusal(&expired, Uuid::nil(), player, now),
+            Some("This voucher has expired")
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use async_graphql::{Context, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::auth::jwt::Claims;
+use crate::auth::permissions::{require_club_manager, viewer_manages_club};
+use crate::gql::error::ResultExt;
+use crate::state::AppState;
+use infra::repos::{buy_in_credits, club_players};
+
+use super::redemption_code;
+use super::types::{BuyInCredit, BuyInCreditKind, BuyInCreditLedgerEntry, IssueBuyInCreditInput};
+
+const MAX_LABEL_LENGTH: usize = 120;
+const MAX_USES: i32 = 100;
+
+async fn load_credit(state: &AppState, credit_id: &ID) -> Result<buy_in_credits::CreditRow> {
+    let id = Uuid::parse_str(credit_id.as_str()).gql_err("Invalid credit ID")?;
+    buy_in_credits::get_by_id(&state.db, id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Credit not found"))
+}
+
+#[derive(Default)]
+pub struct BuyInCreditQuery;
+
+#[Object]
+impl BuyInCreditQuery {
+    /// A club's packages and vouchers, optionally for one player. Club
+    /// managers.
+    async fn club_buy_in_credits(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+        club_player_id: Option<ID>,
+    ) -> Result<Vec<BuyInCredit>> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        let club_player_id = club_player_id
+            .map(|id| Uuid::parse_str(id.as_str()))
+            .transpose()
+            .gql_err("Invalid club player ID")?;
+        let state = ctx.data::<AppState>()?;
+        let rows = buy_in_credits::list_for_club(&state.db, club_id, club_player_id).await?;
+        Ok(rows.into_iter().map(BuyInCredit::from).collect())
+    }
+
+    /// The logged-in player's packages and vouchers across clubs.
+    async fn my_buy_in_credits(&self, ctx: &Context<'_>) -> Result<Vec<BuyInCredit>> {
+        let claims = ctx.data::<Claims>()?;
+        let user_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+        let state = ctx.data::<AppState>()?;
+        let rows = buy_in_credits::list_for_user(&state.db, user_id).await?;
+        Ok(rows.into_iter().map(BuyInCredit::from).collect())
+    }
+
+    /// Issues, redemptions and refunds of a credit, newest first. Club
+    /// managers and the credit's owner.
+    async fn buy_in_credit_ledger(
+        &self,
+        ctx: &Context<'_>,
+        credit_id: ID,
+    ) -> Result<Vec<BuyInCreditLedgerEntry>> {
+        let claims = ctx.data::<Claims>()?;
+        let user_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+        let state = ctx.data::<AppState>()?;
+        let credit = load_credit(state, &credit_id).await?;
+
+        let is_owner = match credit.club_player_id {
+            Some(owner) => club_players::get_by_id(&state.db, owner)
+                .await?
+                .is_some_and(|p| p.app_user_id == Some(user_id)),
+            None => false,
+        };
+        if !is_owner && !viewer_manages_club(ctx, credit.club_id).await {
+            return Err(async_graphql::Error::new("Credit not found"));
+        }
+
+        let rows = buy_in_credits::list_ledger(&state.db, credit.id).await?;
+        Ok(rows.into_iter().map(BuyInCreditLedgerEntry::from).collect())
+    }
+}
+
+#[derive(Default)]
+pub struct BuyInCreditMutation;
+
+#[Object]
+impl BuyInCreditMutation {
+    /// Issue a package sold to a player or a voucher. The code is shown to
+    /// the player, who enters it when registering. Club managers.
+    async fn issue_buy_in_credit(
+        &self,
+        ctx: &Context<'_>,
+        input: IssueBuyInCreditInput,
+    ) -> Result<BuyInCredit> {
+        let state = ctx.data::<AppState>()?;
+        let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid user ID")?;
+
+        let label = input.label.trim();
+        if label.is_empty() || label.chars().count() > MAX_LABEL_LENGTH {
+            return Err(async_graphql::Error::new(
+                "Label must be between 1 and 120 characters",
+            ));
+        }
+        let uses = input.uses.unwrap_or(1);
+        if !(1..=MAX_USES).contains(&uses) {
+            return Err(async_graphql::Error::new("Uses must be between 1 and 100"));
+        }
+        let club_player_id = match &input.club_player_id {
+            Some(id) => {
+                let id = Uuid::parse_str(id.as_str()).gql_err("Invalid club player ID")?;
+                let player = club_players::get_by_id(&state.db, id)
+                    .await?
+                    .filter(|p| p.club_id == club_id)
+                    .ok_or_else(|| async_graphql::Error::new("Player not found in this club"))?;
+                Some(player.id)
+            }
+            None if input.kind == BuyInCreditKind::Package => {
+                return Err(async_graphql::Error::new(
+                    "A package must be issued to a player",
+                ));
+            }
+            None => None,
+        };
+
+        let mut tx = state.db.begin().await?;
+        let id = buy_in_credits::create(
+            &mut *tx,
+            buy_in_credits::NewCredit {
+                club_id,
+                kind: input.kind.as_db().to_string(),
+                code: redemption_code(),
+                label: label.to_string(),
+                club_player_id,
+                total_uses: uses,
+                expires_at: input.expires_at,
+                issued_by: manager_id,
+            },
+        )
+        .await?;
+        buy_in_credits::add_ledger_entry(
+            &mut *tx,
+            buy_in_credits::NewLedgerEntry {
+                credit_id: id,
+                kind: "issue",
+                delta: uses,
+                created_by: Some(manager_id),
+                ..Default::default()
+            },
+        )
+        .await?;
+        tx.commit().await?;
+
+        let row = buy_in_credits::get_by_id(&state.db, id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Credit not found"))?;
+        Ok(BuyInCredit::from(row))
+    }
+
+    /// Void a credit so its remaining uses can't be redeemed. Uses already
+    /// redeemed stand. Club managers.
+    async fn void_buy_in_credit(&self, ctx: &Context<'_>, credit_id: ID) -> Result<BuyInCredit> {
+        let state = ctx.data::<AppState>()?;
+        let credit = load_credit(state, &credit_id).await?;
+        let manager = require_club_manager(ctx, credit.club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid user ID")?;
+
+        if !buy_in_credits::void(&state.db, credit.id, manager_id).await? {
+            return Err(async_graphql::Error::new("This credit is already void"));
+        }
+        let row = load_credit(state, &credit_id).await?;
+        Ok(BuyInCredit::from(row))
+    }
+}
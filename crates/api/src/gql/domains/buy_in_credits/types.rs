@@ -0,0 +1,121 @@
+use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use infra::repos::buy_in_credits::{CreditRow, LedgerRow};
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum BuyInCreditKind {
+    /// A bundle of buy-ins sold to a player.
+    Package,
+    /// A buy-in the club hands out (prize, promotion, comp).
+    Voucher,
+}
+
+impl BuyInCreditKind {
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "package" => BuyInCreditKind::Package,
+            _ => BuyInCreditKind::Voucher,
+        }
+    }
+
+    pub fn as_db(self) -> &'static str {
+        match self {
+            BuyInCreditKind::Package => "package",
+            BuyInCreditKind::Voucher => "voucher",
+        }
+    }
+}
+
+/// Prepaid buy-ins redeemable with `code` when registering.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct BuyInCredit {
+    pub id: ID,
+    pub club_id: ID,
+    pub kind: BuyInCreditKind,
+    pub code: String,
+    pub label: String,
+    /// Null for a bearer voucher nobody has redeemed yet.
+    pub club_player_id: Option<ID>,
+    pub player_name: Option<String>,
+    pub total_uses: i32,
+    pub remaining_uses: i32,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub issued_at: DateTime<Utc>,
+    pub voided_at: Option<DateTime<Utc>>,
+}
+
+impl From<CreditRow> for BuyInCredit {
+    fn from(row: CreditRow) -> Self {
+        Self {
+            id: row.id.into(),
+            club_id: row.club_id.into(),
+            kind: BuyInCreditKind::from_db(&row.kind),
+            code: row.code,
+            label: row.label,
+            club_player_id: row.club_player_id.map(Into::into),
+            player_name: row.player_name,
+            total_uses: row.total_uses,
+            remaining_uses: row.remaining_uses,
+            expires_at: row.expires_at,
+            issued_at: row.issued_at,
+            voided_at: row.voided_at,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum BuyInCreditLedgerKind {
+    Issue,
+    Redemption,
+    /// A cancelled registration gave its use back.
+    Refund,
+}
+
+/// One movement of a credit's uses.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct BuyInCreditLedgerEntry {
+    pub id: ID,
+    pub kind: BuyInCreditLedgerKind,
+    /// Uses added (positive) or taken (negative).
+    pub delta: i32,
+    pub tournament_id: Option<ID>,
+    pub tournament_name: Option<String>,
+    pub registration_id: Option<ID>,
+    /// The buy-in entry a redemption paid for, once the desk recorded it.
+    pub entry_id: Option<ID>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<LedgerRow> for BuyInCreditLedgerEntry {
+    fn from(row: LedgerRow) -> Self {
+        Self {
+            id: row.id.into(),
+            kind: match row.kind.as_str() {
+                "issue" => BuyInCreditLedgerKind::Issue,
+                "refund" => BuyInCreditLedgerKind::Refund,
+                _ => BuyInCreditLedgerKind::Redemption,
+            },
+            delta: row.delta,
+            tournament_id: row.tournament_id.map(Into::into),
+            tournament_name: row.tournament_name,
+            registration_id: row.registration_id.map(Into::into),
+            entry_id: row.entry_id.map(Into::into),
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct IssueBuyInCreditInput {
+    pub club_id: ID,
+    pub kind: BuyInCreditKind,
+    /// e.g. "5 tournament bundle".
+    pub label: String,
+    /// Owner. Required for packages; omit for a bearer voucher that becomes
+    /// the first redeemer's.
+    pub club_player_id: Option<ID>,
+    /// Buy-ins included. Defaults to 1.
+    pub uses: Option<i32>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
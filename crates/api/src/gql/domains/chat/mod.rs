@@ -0,0 +1,38 @@
+pub mod resolvers;
+pub mod types;
+
+pub use resolvers::{ChatMutation, ChatQuery};
+
+use async_graphql::{Context, Result};
+use uuid::Uuid;
+
+use crate::auth::jwt::Claims;
+use crate::auth::permissions::viewer_is_admin;
+use crate::gql::common::helpers::tournament_hidden_from_viewer;
+use crate::gql::error::ResultExt;
+use crate::state::AppState;
+
+/// The viewer's id, when they may read and post in the tournament's chat:
+/// registered players, the hosting club's managers and staff, and admins.
+pub async fn require_participant(ctx: &Context<'_>, tournament_id: Uuid) -> Result<Uuid> {
+    let claims = ctx.data::<Claims>()?;
+    let user_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+    if viewer_is_admin(ctx) {
+        return Ok(user_id);
+    }
+    if tournament_hidden_from_viewer(ctx, tournament_id).await? {
+        return Err(async_graphql::Error::new(
+            "This tournament isn't available in the app",
+        ));
+    }
+
+    let state = ctx.data::<AppState>()?;
+    let role =
+        infra::repos::tournament_chat::participant_role(&state.db, tournament_id, user_id).await?;
+    if role.is_none() {
+        return Err(async_graphql::Error::new(
+            "Only registered players and club staff can use this chat",
+        ));
+    }
+    Ok(user_id)
+}
@@ -0,0 +1,213 @@
+use async_graphql::{Context, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::auth::permissions::require_club_manager;
+use crate::gql::common::helpers::get_club_id_for_tournament;
+use crate::gql::error::ResultExt;
+use crate::gql::subscriptions::publish_chat_event;
+use crate::gql::types::{PaginatedResponse, PaginationInput};
+use crate::state::AppState;
+use infra::repos::tournament_chat;
+
+use super::require_participant;
+use super::types::{
+    MuteTournamentChatUserInput, PostTournamentChatMessageInput, TournamentChatEvent,
+    TournamentChatEventType, TournamentChatMessage, TournamentChatMute,
+};
+
+/// Longest message accepted, in characters (matches the table's CHECK).
+const MAX_MESSAGE_CHARS: usize = 1000;
+
+#[derive(Default)]
+pub struct ChatQuery;
+
+#[Object]
+impl ChatQuery {
+    /// A page of a tournament's chat, newest first. Registered players, the
+    /// club's managers and staff only.
+    async fn tournament_chat_messages(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        pagination: Option<PaginationInput>,
+    ) -> Result<PaginatedResponse<TournamentChatMessage>> {
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        require_participant(ctx, tournament_id).await?;
+
+        let state = ctx.data::<AppState>()?;
+        let limit_offset = pagination
+            .unwrap_or(PaginationInput {
+                limit: Some(50),
+                offset: Some(0),
+            })
+            .to_limit_offset();
+        let (rows, total_count) = tokio::try_join!(
+            tournament_chat::list_messages(
+                &state.db,
+                tournament_id,
+                limit_offset.limit,
+                limit_offset.offset,
+            ),
+            tournament_chat::count_messages(&state.db, tournament_id)
+        )
+        .gql_err("Database operation failed")?;
+
+        let items: Vec<TournamentChatMessage> = rows.into_iter().map(Into::into).collect();
+        let page_size = items.len() as i32;
+        let offset = limit_offset.offset as i32;
+        Ok(PaginatedResponse {
+            items,
+            total_count: total_count as i32,
+            page_size,
+            offset,
+            has_next_page: (offset + page_size) < total_count as i32,
+        })
+    }
+
+    /// Users currently muted in a tournament's chat (managers only).
+    async fn tournament_chat_mutes(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Vec<TournamentChatMute>> {
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let rows = tournament_chat::list_mutes(&state.db, tournament_id).await?;
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+}
+
+#[derive(Default)]
+pub struct ChatMutation;
+
+#[Object]
+impl ChatMutation {
+    /// Post to a tournament's chat. Muted users are refused.
+    async fn post_tournament_chat_message(
+        &self,
+        ctx: &Context<'_>,
+        input: PostTournamentChatMessageInput,
+    ) -> Result<TournamentChatMessage> {
+        let tournament_id =
+            Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let user_id = require_participant(ctx, tournament_id).await?;
+
+        let body = input.body.trim();
+        if body.is_empty() {
+            return Err(async_graphql::Error::new("Message cannot be empty"));
+        }
+        if body.chars().count() > MAX_MESSAGE_CHARS {
+            return Err(async_graphql::Error::new(format!(
+                "Message cannot exceed {MAX_MESSAGE_CHARS} characters"
+            )));
+        }
+
+        let state = ctx.data::<AppState>()?;
+        if tournament_chat::is_muted(&state.db, tournament_id, user_id).await? {
+            return Err(async_graphql::Error::new("You are muted in this chat"));
+        }
+
+        let row = tournament_chat::create_message(&state.db, tournament_id, user_id, body).await?;
+        let message = TournamentChatMessage::from(row);
+        publish_chat_event(
+            tournament_id,
+            TournamentChatEvent {
+                event_type: TournamentChatEventType::MessagePosted,
+                message: message.clone(),
+            },
+        );
+        Ok(message)
+    }
+
+    /// Remove a message from a tournament's chat (managers only).
+    async fn delete_tournament_chat_message(
+        &self,
+        ctx: &Context<'_>,
+        message_id: ID,
+    ) -> Result<TournamentChatMessage> {
+        let message_id = Uuid::parse_str(message_id.as_str()).gql_err("Invalid message ID")?;
+        let state = ctx.data::<AppState>()?;
+        let message = tournament_chat::get_message(&state.db, message_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Message not found"))?;
+        let club_id = get_club_id_for_tournament(&state.db, message.tournament_id).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid manager ID")?;
+
+        let row = tournament_chat::delete_message(&state.db, message_id, manager_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Message already deleted"))?;
+        let tournament_id = row.tournament_id;
+        let message = TournamentChatMessage::from(row);
+        publish_chat_event(
+            tournament_id,
+            TournamentChatEvent {
+                event_type: TournamentChatEventType::MessageDeleted,
+                message: message.clone(),
+            },
+        );
+        Ok(message)
+    }
+
+    /// Stop a user from posting in a tournament's chat, for `minutes` or until
+    /// unmuted (managers only). Muting again replaces the earlier mute.
+    async fn mute_tournament_chat_user(
+        &self,
+        ctx: &Context<'_>,
+        input: MuteTournamentChatUserInput,
+    ) -> Result<TournamentChatMute> {
+        let tournament_id =
+            Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let user_id = Uuid::parse_str(input.user_id.as_str()).gql_err("Invalid user ID")?;
+        let state = ctx.data::<AppState>()?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid manager ID")?;
+
+        let muted_until = match input.minutes {
+            Some(minutes) if minutes <= 0 => {
+                return Err(async_graphql::Error::new("Minutes must be positive"));
+            }
+            Some(minutes) => Some(chrono::Utc::now() + chrono::Duration::minutes(minutes.into())),
+            None => None,
+        };
+        let reason = input
+            .reason
+            .as_deref()
+            .map(str::trim)
+            .filter(|r| !r.is_empty());
+
+        let row = tournament_chat::upsert_mute(
+            &state.db,
+            tournament_id,
+            user_id,
+            manager_id,
+            reason,
+            muted_until,
+        )
+        .await?;
+        Ok(row.into())
+    }
+
+    /// Lift a user's mute in a tournament's chat (managers only).
+    async fn unmute_tournament_chat_user(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        user_id: ID,
+    ) -> Result<bool> {
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let user_id = Uuid::parse_str(user_id.as_str()).gql_err("Invalid user ID")?;
+        let state = ctx.data::<AppState>()?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        Ok(tournament_chat::delete_mute(&state.db, tournament_id, user_id).await?)
+    }
+}
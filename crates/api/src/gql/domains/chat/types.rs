@@ -0,0 +1,82 @@
+use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use infra::repos::tournament_chat::{ChatMessageRow, ChatMuteRow};
+
+/// A chat message. Moderated messages keep their place in the channel with
+/// an empty body.
+#[derive(SimpleObject, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct TournamentChatMessage {
+    pub id: ID,
+    pub tournament_id: ID,
+    /// Null once the author's account is deleted.
+    pub user_id: Option<ID>,
+    pub author_name: Option<String>,
+    pub body: String,
+    pub is_deleted: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ChatMessageRow> for TournamentChatMessage {
+    fn from(r: ChatMessageRow) -> Self {
+        let is_deleted = r.deleted_at.is_some();
+        Self {
+            id: r.id.into(),
+            tournament_id: r.tournament_id.into(),
+            user_id: r.user_id.map(Into::into),
+            author_name: r.author_name,
+            body: if is_deleted { String::new() } else { r.body },
+            is_deleted,
+            created_at: r.created_at,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+pub enum TournamentChatEventType {
+    MessagePosted,
+    MessageDeleted,
+}
+
+#[derive(SimpleObject, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct TournamentChatEvent {
+    pub event_type: TournamentChatEventType,
+    pub message: TournamentChatMessage,
+}
+
+#[derive(SimpleObject, Clone, Debug)]
+pub struct TournamentChatMute {
+    pub user_id: ID,
+    pub muted_by: Option<ID>,
+    pub reason: Option<String>,
+    /// Null when muted until unmuted.
+    pub muted_until: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ChatMuteRow> for TournamentChatMute {
+    fn from(r: ChatMuteRow) -> Self {
+        Self {
+            user_id: r.user_id.into(),
+            muted_by: r.muted_by.map(Into::into),
+            reason: r.reason,
+            muted_until: r.muted_until,
+            created_at: r.created_at,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct PostTournamentChatMessageInput {
+    pub tournament_id: ID,
+    pub body: String,
+}
+
+#[derive(InputObject)]
+pub struct MuteTournamentChatUserInput {
+    pub tournament_id: ID,
+    pub user_id: ID,
+    /// Mute length; omit to mute until unmuted.
+    pub minutes: Option<i32>,
+    pub reason: Option<String>,
+}
@@ -8,7 +8,7 @@ use super::types::{
     InviteClubManagerResponse, RedemptionCode, UpdateClubTableInput,
 };
 use crate::auth::permissions::{
-    is_free_plan, require_admin, require_club_manager, viewer_is_admin,
+    is_free_plan, require_admin, require_club_manager, viewer_is_admin, viewer_manages_club,
 };
 use crate::gql::error::ResultExt;
 use crate::gql::types::{Club, ClubTable, CompanyLookup, OnboardClubInput, OnboardClubPayload};
@@ -48,6 +48,21 @@ impl ClubQuery {
             .collect())
     }
 
+    /// Federation entity resolver: `Club @key(fields: "id")`. Free ("Home
+    /// Game") clubs resolve only for their own managers and admins, as in the
+    /// club directory.
+    #[graphql(entity)]
+    async fn find_club_by_id(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<Club>> {
+        let state = ctx.data::<AppState>()?;
+        let Some(row) = clubs::get_by_id(&state.db, id).await? else {
+            return Ok(None);
+        };
+        if row.plan == ClubPlan::Free.as_db() && !viewer_manages_club(ctx, id).await {
+            return Ok(None);
+        }
+        Ok(Some(row.into()))
+    }
+
     /// Distinct province slugs clubs resolve to — for a province leaderboard
     /// filter. Slugs are i18n keys; localize client-side.
     async fn club_provinces(&self, ctx: &Context<'_>) -> Result<Vec<String>> {
@@ -95,6 +110,8 @@ impl ClubQuery {
                 // Internal table-assignment lookup for a club the caller is
                 // already viewing; no player-facing free filter needed.
                 exclude_free_clubs: false,
+                restrict_visibility: false,
+                viewer_id: None,
             },
             None,
         )
@@ -104,10 +121,11 @@ impl ClubQuery {
         let mut assigned_table_ids = std::collections::HashSet::new();
 
         for tournament in active_tournaments {
-            // Skip finished tournaments
+            // Skip finished and cancelled tournaments
             if matches!(
                 tournament.live_status,
                 tournaments::TournamentLiveStatus::Finished
+                    | tournaments::TournamentLiveStatus::Cancelled
             ) {
                 continue;
             }
@@ -188,41 +206,45 @@ impl ClubMutation {
             .gql_err("Database operation failed")?
             .ok_or_else(|| async_graphql::Error::new("Club not found"))?;
 
-        // Find or create the invitee's account.
-        let existing = users::get_by_email(&state.db, &email)
-            .await
-            .gql_err("Database operation failed")?;
-        let (user, created_account) = match existing {
-            Some(user) => {
-                users::promote_player_to_manager(&state.db, user.id)
-                    .await
-                    .gql_err("Database operation failed")?;
-                (user, false)
-            }
-            None => {
-                // users.first_name is NOT NULL — fall back to the email local part.
-                let fallback = email.split('@').next().unwrap_or("Manager").to_string();
-                let first_name = input
-                    .first_name
-                    .as_deref()
-                    .map(str::trim)
-                    .filter(|s| !s.is_empty())
-                    .unwrap_or(&fallback);
-                let user = users::create_invited_manager(
-                    &state.db,
-                    &email,
-                    first_name,
-                    input
-                        .last_name
+        // Find or create the invitee's account. The invitee isn't in the
+        // club yet, so the lookup reads past the inviter's scope.
+        let (user, created_account) = infra::db::without_request_scope(async {
+            let existing = users::get_by_email(&state.db, &email)
+                .await
+                .gql_err("Database operation failed")?;
+            Ok::<_, async_graphql::Error>(match existing {
+                Some(user) => {
+                    users::promote_player_to_manager(&state.db, user.id)
+                        .await
+                        .gql_err("Database operation failed")?;
+                    (user, false)
+                }
+                None => {
+                    // users.first_name is NOT NULL — fall back to the email local part.
+                    let fallback = email.split('@').next().unwrap_or("Manager").to_string();
+                    let first_name = input
+                        .first_name
                         .as_deref()
                         .map(str::trim)
-                        .filter(|s| !s.is_empty()),
-                )
-                .await
-                .gql_err("Failed to create the invited account")?;
-                (user, true)
-            }
-        };
+                        .filter(|s| !s.is_empty())
+                        .unwrap_or(&fallback);
+                    let user = users::create_invited_manager(
+                        &state.db,
+                        &email,
+                        first_name,
+                        input
+                            .last_name
+                            .as_deref()
+                            .map(str::trim)
+                            .filter(|s| !s.is_empty()),
+                    )
+                    .await
+                    .gql_err("Failed to create the invited account")?;
+                    (user, true)
+                }
+            })
+        })
+        .await?;
 
         club_managers::create_or_reactivate(&state.db, club_uuid, user.id, inviter_uuid(&inviter)?)
             .await
@@ -237,12 +259,12 @@ impl ClubMutation {
                 .map(char::from)
                 .collect();
             let token_hash = crate::auth::refresh::hash_token(&raw_token);
-            infra::repos::password_reset_tokens::create(
+            infra::db::without_request_scope(infra::repos::password_reset_tokens::create(
                 &state.db,
                 &token_hash,
                 user.id,
                 Utc::now() + Duration::hours(72),
-            )
+            ))
             .await
             .gql_err("Database operation failed")?;
             set_password_token = Some(raw_token);
@@ -312,7 +334,8 @@ impl ClubMutation {
         input: OnboardClubInput,
     ) -> Result<OnboardClubPayload> {
         let state = ctx.data::<AppState>()?;
-        service::onboard_club(state, input).await
+        // Signup is anonymous and creates the user, club and manager rows.
+        infra::db::without_request_scope(service::onboard_club(state, input)).await
     }
 
     /// Predefine a physical table for a club. Managers of the club only.
@@ -478,10 +501,9 @@ impl ClubMutation {
         }
 
         // Hold a row lock on the code for the whole grant so concurrent
-        // redemptions of a capped code can't oversell its last use.
-        let mut tx = state
-            .db
-            .begin()
+        // redemptions of a capped code can't oversell its last use. Codes are
+        // service-only rows, so the transaction runs outside the request scope.
+        let mut tx = infra::db::without_request_scope(state.db.begin())
             .await
             .gql_err("Failed to start redemption")?;
 
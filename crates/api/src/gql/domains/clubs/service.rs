@@ -16,7 +16,7 @@ use crate::state::AppState;
 use infra::repos::clubs::CreateClubData;
 
 /// Supported onboarding countries (ISO 2-letter codes).
-const SUPPORTED_COUNTRIES: [&str; 4] = ["BE", "FR", "LU", "NL"];
+pub(crate) const SUPPORTED_COUNTRIES: [&str; 4] = ["BE", "FR", "LU", "NL"];
 
 /// Strip everything but alphanumerics, uppercase, and drop a leading country
 /// code if the user typed it, yielding the bare national number.
@@ -55,15 +55,6 @@ fn vat_format_ok(country: &str, number: &str) -> bool {
     }
 }
 
-/// Whether a VIES "not found" should reject onboarding. Defaults to `true`;
-/// flip with `VIES_HARD_BLOCK=false` if false-rejects (non-VAT non-profits)
-/// become a problem in practice.
-fn vies_hard_block() -> bool {
-    std::env::var("VIES_HARD_BLOCK")
-        .map(|v| !matches!(v.trim().to_lowercase().as_str(), "false" | "0" | "no"))
-        .unwrap_or(true)
-}
-
 pub async fn onboard_club(state: &AppState, input: OnboardClubInput) -> Result<OnboardClubPayload> {
     let country = input.country.trim().to_uppercase();
     if !SUPPORTED_COUNTRIES.contains(&country.as_str()) {
@@ -104,9 +95,10 @@ pub async fn onboard_club(state: &AppState, input: OnboardClubInput) -> Result<O
 
             // 2. VIES registry check. Unreachable VIES (available == false) never
             //    blocks; a definitive "not found" blocks only when the hard-block
-            //    flag is on.
+            //    flag is on (`VIES_HARD_BLOCK`, default on; turn it off if
+            //    false-rejects of non-VAT non-profits become a problem).
             let lookup = vies::lookup(&country, &vat_number).await;
-            if lookup.available && !lookup.valid && vies_hard_block() {
+            if lookup.available && !lookup.valid && state.config().vies_hard_block {
                 return Err(async_graphql::Error::new(
                     "No company found for this VAT number",
                 ));
@@ -151,7 +143,7 @@ pub async fn onboard_club(state: &AppState, input: OnboardClubInput) -> Result<O
         r#"
         INSERT INTO users (email, first_name, last_name, password_hash, role, is_active)
         VALUES ($1, $2, $3, $4, 'manager', true)
-        RETURNING id, email, username, first_name, last_name, phone, is_active, role, locale, created_at, updated_at
+        RETURNING id, email, username, first_name, last_name, phone, avatar_url, is_active, role, locale, created_at, updated_at
         "#,
     )
     .bind(&input.email)
@@ -188,16 +180,12 @@ pub async fn onboard_club(state: &AppState, input: OnboardClubInput) -> Result<O
     tx.commit().await.gql_err("Database operation failed")?;
 
     // 6. Mint JWT so the client logs straight in
-    let user: User = user_row.into();
-    let role_str: String = user.role.into();
     let token = state
         .jwt_service()
-        .create_token(
-            uuid::Uuid::parse_str(user.id.as_str()).gql_err("Invalid user ID")?,
-            user.email.clone(),
-            role_str,
-        )
+        .create_token(&state.db, user_row.id)
+        .await
         .gql_err("Failed to issue token")?;
+    let user: User = user_row.into();
 
     Ok(OnboardClubPayload {
         token,
@@ -1,4 +1,4 @@
-use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use async_graphql::{ComplexObject, Context, Enum, InputObject, SimpleObject, ID};
 use chrono::{DateTime, Utc};
 
 use crate::gql::types::User;
@@ -33,6 +33,7 @@ impl ClubPlan {
 }
 
 #[derive(SimpleObject, Clone)]
+#[graphql(complex)]
 pub struct Club {
     pub id: ID,
     pub name: String,
@@ -69,6 +70,20 @@ impl From<infra::models::ClubRow> for Club {
     }
 }
 
+#[ComplexObject]
+impl Club {
+    /// The organization this club belongs to; null for stand-alone clubs.
+    async fn organization(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<crate::gql::types::Organization>> {
+        let state = ctx.data::<crate::state::AppState>()?;
+        let id = uuid::Uuid::parse_str(self.id.as_str())?;
+        let row = infra::repos::organizations::get_for_club(&state.db, id).await?;
+        Ok(row.map(Into::into))
+    }
+}
+
 /// Self-serve onboarding payload: creates the owner's account **and** their
 /// club in one transaction, returning a JWT so the client logs straight in.
 #[derive(InputObject)]
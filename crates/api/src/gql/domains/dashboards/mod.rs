@@ -0,0 +1,8 @@
+//! Manager overviews. `tournamentOpsDashboard` reads from one snapshot so its
+//! counts agree with each other; `clubDashboard` runs under the club's row
+//! scope, with "today" and "this week" taken in UTC.
+
+pub mod resolvers;
+pub mod types;
+
+pub use resolvers::DashboardQuery;
@@ -0,0 +1,289 @@
+use std::collections::{HashMap, HashSet};
+
+use async_graphql::{Context, Object, Result};
+use chrono::{Datelike, Duration, Utc};
+use uuid::Uuid;
+
+use crate::auth::permissions::require_club_manager;
+use crate::gql::common::helpers::get_club_id_for_tournament;
+use crate::gql::domains::registrations::tournament_players;
+use crate::gql::domains::tournaments::clock::load_tournament_clock;
+use crate::gql::error::ResultExt;
+use crate::gql::types::{
+    ActivityLogEntry, CallQueue, ClubDashboard, ClubDashboardTournament, ClubTable, Incident,
+    OpenTable, PendingCall, RegistrationFunnel, TournamentOpsDashboard,
+};
+use crate::state::AppState;
+use infra::db::RowScope;
+use infra::repos::{
+    activity_log, closed_seats, club_tables, clubs, incidents, table_seat_assignments,
+    tournament_registrations, tournaments,
+};
+
+/// Activity log entries shown on the ops dashboard.
+const RECENT_ACTIVITY: i64 = 20;
+
+#[derive(Default)]
+pub struct DashboardQuery;
+
+#[Object]
+impl DashboardQuery {
+    /// The manager home screen: today's and this week's tournaments with
+    /// their live player counts, tables free for cash games, players waiting
+    /// to get in and incidents awaiting review. Managers of the club only.
+    async fn club_dashboard(&self, ctx: &Context<'_>, club_id: Uuid) -> Result<ClubDashboard> {
+        require_club_manager(ctx, club_id).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let now = Utc::now();
+        let day_start = now
+            .date_naive()
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight is a valid time")
+            .and_utc();
+        let day_end = day_start + Duration::days(1);
+        let week_start =
+            day_start - Duration::days(i64::from(now.weekday().num_days_from_monday()));
+        let week_end = week_start + Duration::days(7);
+
+        // Incidents are club-scoped rows: read under the club's scope.
+        let mut tx = state
+            .scoped(RowScope::club(club_id))
+            .await
+            .gql_err("Database operation failed")?;
+        let club = clubs::get_by_id(&mut *tx, club_id)
+            .await
+            .gql_err("Database operation failed")?
+            .ok_or_else(|| async_graphql::Error::new("Club not found"))?;
+        let week =
+            tournaments::list_by_club_starting_between(&mut *tx, club_id, week_start, week_end)
+                .await
+                .gql_err("Database operation failed")?;
+        let ids: Vec<Uuid> = week.iter().map(|t| t.id).collect();
+        let counts = tournament_registrations::count_by_status_for_tournaments(&mut *tx, &ids)
+            .await
+            .gql_err("Database operation failed")?;
+        let free_tables = club_tables::list_available_by_club(&mut *tx, club_id)
+            .await
+            .gql_err("Database operation failed")?;
+        let unresolved = incidents::review_queue(&mut *tx, club_id)
+            .await
+            .gql_err("Database operation failed")?;
+        tx.commit().await.gql_err("Database operation failed")?;
+
+        let count = |tournament_id: Uuid, statuses: &[&str]| {
+            counts
+                .iter()
+                .filter(|(t, s, _)| *t == tournament_id && statuses.contains(&s.as_str()))
+                .map(|(_, _, n)| *n as i32)
+                .sum::<i32>()
+        };
+        let this_week: Vec<ClubDashboardTournament> = week
+            .into_iter()
+            .map(|t| ClubDashboardTournament {
+                live_players: count(t.id, &["checked_in", "seated"]),
+                confirmed_players: count(t.id, &["registered", "checked_in", "seated", "busted"]),
+                pending_players: count(t.id, &["waitlisted", "alternate"]),
+                tournament: t.into(),
+            })
+            .collect();
+        let today = this_week
+            .iter()
+            .filter(|t| t.tournament.start_time >= day_start && t.tournament.start_time < day_end)
+            .cloned()
+            .collect();
+
+        Ok(ClubDashboard {
+            club: club.into(),
+            today,
+            pending_join_requests: this_week.iter().map(|t| t.pending_players).sum(),
+            this_week,
+            open_cash_tables: free_tables
+                .into_iter()
+                .map(|row| ClubTable {
+                    id: row.id.into(),
+                    club_id: row.club_id.into(),
+                    table_number: row.table_number,
+                    max_seats: row.max_seats,
+                    is_active: row.is_active,
+                    is_default: row.is_default,
+                    is_assigned: false,
+                    created_at: row.created_at,
+                    updated_at: row.updated_at,
+                })
+                .collect(),
+            unresolved_incidents: unresolved.into_iter().map(Incident::from).collect(),
+            generated_at: now,
+        })
+    }
+
+    /// The tournament director's screen: registration funnel, checked-in
+    /// players without a seat, tables with free seats, who to call from the
+    /// waitlist and alternates, the clock and the latest activity. Managers
+    /// of the tournament's club only.
+    async fn tournament_ops_dashboard(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: Uuid,
+    ) -> Result<TournamentOpsDashboard> {
+        let state = ctx.data::<AppState>()?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        // Counts, lists and seats from one snapshot, so a player moving
+        // between statuses is never counted twice.
+        let mut tx = state
+            .snapshot()
+            .await
+            .gql_err("Database operation failed")?;
+        let tournament = tournaments::get_by_id(&mut *tx, tournament_id)
+            .await
+            .gql_err("Database operation failed")?
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        let counts = tournament_registrations::count_by_status(&mut *tx, tournament_id)
+            .await
+            .gql_err("Database operation failed")?;
+        let checked_in =
+            tournament_registrations::list_by_status(&mut *tx, tournament_id, "checked_in")
+                .await
+                .gql_err("Database operation failed")?;
+        let waitlisted =
+            tournament_registrations::list_by_status(&mut *tx, tournament_id, "waitlisted")
+                .await
+                .gql_err("Database operation failed")?;
+        let alternates = tournament_registrations::list_alternates(&mut *tx, tournament_id)
+            .await
+            .gql_err("Database operation failed")?;
+        let openings = tournament_registrations::count_alternate_openings(&mut *tx, tournament_id)
+            .await
+            .gql_err("Database operation failed")?;
+        let confirmed =
+            tournament_registrations::count_confirmed_by_tournament(&mut *tx, tournament_id)
+                .await
+                .gql_err("Database operation failed")?;
+        let tables = club_tables::list_assigned_to_tournament(&mut *tx, tournament_id)
+            .await
+            .gql_err("Database operation failed")?;
+        let seats = table_seat_assignments::list_current_for_tournament(&mut *tx, tournament_id)
+            .await
+            .gql_err("Database operation failed")?;
+        let closed = closed_seats::list_for_tournament(&mut *tx, tournament_id)
+            .await
+            .gql_err("Database operation failed")?;
+        tx.commit().await.gql_err("Database operation failed")?;
+
+        let count = |status: &str| {
+            counts
+                .iter()
+                .find(|(s, _)| s == status)
+                .map_or(0, |(_, n)| *n as i32)
+        };
+        let funnel = RegistrationFunnel {
+            registered: count("registered"),
+            checked_in: count("checked_in"),
+            seated: count("seated"),
+            busted: count("busted"),
+            waitlisted: count("waitlisted"),
+            alternates: count("alternate"),
+            cancelled: count("cancelled"),
+            no_shows: count("no_show"),
+        };
+
+        let seated_players: HashSet<Uuid> = seats.iter().map(|s| s.club_player_id).collect();
+        let unseated: Vec<_> = checked_in
+            .into_iter()
+            .filter(|r| !seated_players.contains(&r.club_player_id))
+            .collect();
+
+        let mut taken: HashMap<Uuid, HashSet<i32>> = HashMap::new();
+        for seat in &seats {
+            taken
+                .entry(seat.club_table_id)
+                .or_default()
+                .insert(seat.seat_number);
+        }
+        let mut shut: HashMap<Uuid, HashSet<i32>> = HashMap::new();
+        for seat in &closed {
+            shut.entry(seat.club_table_id)
+                .or_default()
+                .insert(seat.seat_number);
+        }
+        let mut open_tables: Vec<OpenTable> = tables
+            .into_iter()
+            .filter(|t| t.is_active)
+            .filter_map(|t| {
+                let occupied = taken.get(&t.id);
+                let closed = shut.get(&t.id);
+                let open_seats: Vec<i32> = (1..=t.max_seats)
+                    .filter(|n| !occupied.is_some_and(|o| o.contains(n)))
+                    .filter(|n| !closed.is_some_and(|c| c.contains(n)))
+                    .collect();
+                (!open_seats.is_empty()).then(|| OpenTable {
+                    club_table_id: t.id.into(),
+                    table_number: t.table_number,
+                    max_seats: t.max_seats,
+                    occupied_seats: occupied.map_or(0, |o| o.len() as i32),
+                    open_seats,
+                })
+            })
+            .collect();
+        open_tables.sort_by_key(|t| t.table_number);
+
+        // Waitlisted players fill spots under the seat cap; alternates fill
+        // seats freed by eliminations.
+        let open_spots = tournament
+            .seat_cap
+            .map(|cap| (i64::from(cap) - confirmed).max(0) as i32);
+        let waitlist_calls = open_spots.map_or(0, |spots| spots as usize);
+        let waitlist_calls: Vec<_> = waitlisted.into_iter().take(waitlist_calls).collect();
+        let alternate_calls: Vec<_> = alternates.into_iter().take(openings as usize).collect();
+
+        let unseated_count = unseated.len();
+        let waitlist_count = waitlist_calls.len();
+        let mut players = tournament_players(
+            ctx,
+            unseated
+                .into_iter()
+                .chain(waitlist_calls)
+                .chain(alternate_calls)
+                .collect(),
+        )
+        .await?;
+        let calls = players.split_off(unseated_count);
+        let pending_calls = calls
+            .into_iter()
+            .enumerate()
+            .map(|(i, player)| {
+                let (queue, position) = if i < waitlist_count {
+                    (CallQueue::Waitlist, i + 1)
+                } else {
+                    (CallQueue::Alternates, i - waitlist_count + 1)
+                };
+                PendingCall {
+                    queue,
+                    position: position as i32,
+                    player,
+                }
+            })
+            .collect();
+
+        let (clock, activity) =
+            tokio::try_join!(load_tournament_clock(&state.db, tournament_id), async {
+                activity_log::list_by_tournament(&state.db, tournament_id, None, RECENT_ACTIVITY, 0)
+                    .await
+                    .gql_err("Database operation failed")
+            })?;
+
+        Ok(TournamentOpsDashboard {
+            tournament: tournament.into(),
+            funnel,
+            unseated_players: players,
+            open_tables,
+            open_spots,
+            pending_calls,
+            clock,
+            recent_activity: activity.into_iter().map(ActivityLogEntry::from).collect(),
+            generated_at: Utc::now(),
+        })
+    }
+}
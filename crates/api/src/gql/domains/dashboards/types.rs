@@ -0,0 +1,95 @@
+use async_graphql::{Enum, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use crate::gql::types::{
+    ActivityLogEntry, Club, ClubTable, Incident, Tournament, TournamentClock, TournamentPlayer,
+};
+
+/// How many registrations are at each step, from sign-up to the rail.
+#[derive(SimpleObject, Clone, Debug, Default)]
+pub struct RegistrationFunnel {
+    pub registered: i32,
+    pub checked_in: i32,
+    pub seated: i32,
+    pub busted: i32,
+    pub waitlisted: i32,
+    pub alternates: i32,
+    pub cancelled: i32,
+    pub no_shows: i32,
+}
+
+/// A linked table with at least one free seat.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct OpenTable {
+    pub club_table_id: ID,
+    pub table_number: i32,
+    pub max_seats: i32,
+    pub occupied_seats: i32,
+    /// Free seat numbers, ascending.
+    pub open_seats: Vec<i32>,
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum CallQueue {
+    Waitlist,
+    Alternates,
+}
+
+/// A player the floor can call now: a waitlisted player with a spot open
+/// under the seat cap, or an alternate with a seat freed by an elimination.
+#[derive(SimpleObject, Clone)]
+pub struct PendingCall {
+    pub queue: CallQueue,
+    /// 1-based place in its queue.
+    pub position: i32,
+    pub player: TournamentPlayer,
+}
+
+/// Everything a tournament director watches during the event, read from one
+/// snapshot so the counts and lists agree.
+#[derive(SimpleObject, Clone)]
+pub struct TournamentOpsDashboard {
+    pub tournament: Tournament,
+    pub funnel: RegistrationFunnel,
+    /// Checked-in players still waiting for a seat.
+    pub unseated_players: Vec<TournamentPlayer>,
+    /// Active linked tables with free seats, by table number.
+    pub open_tables: Vec<OpenTable>,
+    /// Spots left under the seat cap; null when the tournament has none.
+    pub open_spots: Option<i32>,
+    pub pending_calls: Vec<PendingCall>,
+    pub clock: Option<TournamentClock>,
+    /// The latest activity log entries, newest first.
+    pub recent_activity: Vec<ActivityLogEntry>,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// A tournament on the club dashboard, with where its field stands.
+#[derive(SimpleObject, Clone)]
+pub struct ClubDashboardTournament {
+    pub tournament: Tournament,
+    /// Players checked in or seated, i.e. still in the room.
+    pub live_players: i32,
+    /// Registrations holding a place: registered, checked in, seated or busted.
+    pub confirmed_players: i32,
+    /// Players waiting to get in: waitlisted or queued as alternates.
+    pub pending_players: i32,
+}
+
+/// The manager home screen for a club. Days and weeks are UTC; the week
+/// starts on Monday.
+#[derive(SimpleObject, Clone)]
+pub struct ClubDashboard {
+    pub club: Club,
+    /// Tournaments starting today, earliest first.
+    pub today: Vec<ClubDashboardTournament>,
+    /// Tournaments starting this week, today's included.
+    pub this_week: Vec<ClubDashboardTournament>,
+    /// Active tables no live tournament is using, free for cash games.
+    pub open_cash_tables: Vec<ClubTable>,
+    /// Players waiting to get into this week's tournaments.
+    pub pending_join_requests: i32,
+    /// Incidents not reviewed yet, most severe first.
+    pub unresolved_incidents: Vec<Incident>,
+    pub generated_at: DateTime<Utc>,
+}
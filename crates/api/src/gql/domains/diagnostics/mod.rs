@@ -0,0 +1,4 @@
+pub mod resolvers;
+pub mod types;
+
+pub use resolvers::{DiagnosticsMutation, DiagnosticsQuery};
@@ -0,0 +1,421 @@
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use async_graphql::{Context, Object, Result};
+use infra::phone;
+use infra::repos::maintenance::{self, AppliedMigrationRow};
+use infra::repos::users;
+
+use crate::auth::permissions::require_admin;
+use crate::services::heartbeat;
+use crate::state::AppState;
+
+use super::types::{
+    BackgroundServiceStatus, ConfigDiagnostics, ConfigSetting, MaintenanceReport, MaintenanceTask,
+    MigrationInfo, MigrationState, MigrationStatus, PiiEncryptionStatus, PiiKeyUsage, PoolStats,
+    ServiceStatus, TableMaintenance,
+};
+
+/// Only tables at least this bloated are worth a manual vacuum.
+const MIN_DEAD_RATIO: f64 = 0.1;
+const MAX_VACUUM_TABLES: i32 = 100;
+/// Rows re-sealed per round trip of a PII key rotation.
+const RESEAL_BATCH: i64 = 500;
+/// Phone numbers read per round trip of a normalization run.
+const PHONE_BATCH: i64 = 500;
+
+#[derive(Default)]
+pub struct DiagnosticsQuery;
+
+#[Object]
+impl DiagnosticsQuery {
+    /// Every setting this instance read at startup, where it came from, and
+    /// the startup warnings. Secret values are never returned. Admins only.
+    async fn config_diagnostics(&self, ctx: &Context<'_>) -> Result<ConfigDiagnostics> {
+        require_admin(ctx).await?;
+
+        let config = ctx.data::<AppState>()?.config();
+        Ok(ConfigDiagnostics {
+            settings: config
+                .redacted()
+                .into_iter()
+                .map(ConfigSetting::from)
+                .collect(),
+            warnings: config.warnings().to_vec(),
+        })
+    }
+
+    /// The database's applied migrations against those built into this
+    /// instance. Admins only.
+    async fn migration_status(&self, ctx: &Context<'_>) -> Result<MigrationStatus> {
+        require_admin(ctx).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let applied = maintenance::applied_migrations(&state.db).await?;
+        let embedded: Vec<(i64, &str, &[u8])> = crate::MIGRATOR
+            .iter()
+            .filter(|m| !m.migration_type.is_down_migration())
+            .map(|m| (m.version, m.description.as_ref(), m.checksum.as_ref()))
+            .collect();
+        Ok(compare_migrations(&embedded, &applied))
+    }
+
+    /// This instance's connection pool and the server's connection count.
+    /// Admins only.
+    async fn pool_stats(&self, ctx: &Context<'_>) -> Result<PoolStats> {
+        require_admin(ctx).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let server_connections = maintenance::database_connections(&state.db).await?;
+        let size = state.db.size() as i32;
+        let idle = state.db.num_idle() as i32;
+        let options = state.db.options();
+        Ok(PoolStats {
+            size,
+            idle,
+            in_use: (size - idle).max(0),
+            max_connections: options.get_max_connections() as i32,
+            min_connections: options.get_min_connections() as i32,
+            server_connections: server_connections as i32,
+        })
+    }
+
+    /// When this instance's background loops last ticked, and the backlog
+    /// of scheduled announcements. Admins only.
+    async fn background_service_status(
+        &self,
+        ctx: &Context<'_>,
+    ) -> Result<BackgroundServiceStatus> {
+        require_admin(ctx).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let backlog = maintenance::announcement_backlog(&state.db).await?;
+        let services: Vec<ServiceStatus> = heartbeat::snapshot()
+            .into_iter()
+            .map(|(name, beat)| ServiceStatus {
+                name: name.to_string(),
+                last_tick_at: beat.last_tick_at,
+                ticks: beat.ticks as i64,
+                restarts: beat.restarts as i32,
+            })
+            .collect();
+        Ok(BackgroundServiceStatus {
+            last_clock_tick_at: services
+                .iter()
+                .find(|s| s.name == "clock_service")
+                .and_then(|s| s.last_tick_at),
+            services,
+            pending_announcements: backlog.pending as i32,
+            overdue_announcements: backlog.due as i32,
+            oldest_overdue_at: backlog.oldest_due_at,
+        })
+    }
+
+    /// Whether personal data is encrypted at rest and which keys the stored
+    /// values are sealed with. Admins only.
+    async fn pii_encryption_status(&self, ctx: &Context<'_>) -> Result<PiiEncryptionStatus> {
+        require_admin(ctx).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let keyring = infra::pii::keyring();
+        let phones = users::count_phones_by_key(&state.db).await?;
+        Ok(PiiEncryptionStatus {
+            enabled: keyring.is_some(),
+            active_key_id: keyring.as_ref().map(|k| k.active_id().to_string()),
+            key_ids: keyring
+                .as_ref()
+                .map(|k| k.key_ids().map(String::from).collect())
+                .unwrap_or_default(),
+            phones: phones
+                .into_iter()
+                .map(|(key_id, rows)| PiiKeyUsage { key_id, rows })
+                .collect(),
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct DiagnosticsMutation;
+
+#[Object]
+impl DiagnosticsMutation {
+    /// Run one maintenance task now. `dryRun` reports what the task would
+    /// touch without doing it. Vacuum considers tables with at least
+    /// `minDeadTuples` dead tuples, most bloated first, up to `limit`.
+    /// Admins only.
+    async fn run_maintenance(
+        &self,
+        ctx: &Context<'_>,
+        task: MaintenanceTask,
+        #[graphql(default = false)] dry_run: bool,
+        #[graphql(default = 1000)] min_dead_tuples: i64,
+        #[graphql(default = 20)] limit: i32,
+    ) -> Result<MaintenanceReport> {
+        require_admin(ctx).await?;
+        let state = ctx.data::<AppState>()?;
+        if !(1..=MAX_VACUUM_TABLES).contains(&limit) {
+            return Err(async_graphql::Error::new(format!(
+                "limit must be between 1 and {MAX_VACUUM_TABLES}"
+            )));
+        }
+        if min_dead_tuples < 0 {
+            return Err(async_graphql::Error::new(
+                "minDeadTuples must not be negative",
+            ));
+        }
+
+        let started = Instant::now();
+        let mut tables = Vec::new();
+        let mut tournaments_rebuilt = None;
+        let mut rows_resealed = None;
+        let mut phones_normalized = None;
+        let mut phones_unreadable = None;
+        match task {
+            MaintenanceTask::Vacuum => {
+                let candidates = maintenance::vacuum_candidates(
+                    &state.db,
+                    min_dead_tuples,
+                    MIN_DEAD_RATIO,
+                    limit as i64,
+                )
+                .await?;
+                for table in candidates {
+                    if !dry_run {
+                        maintenance::vacuum_analyze(
+                            &state.db,
+                            &table.schema_name,
+                            &table.table_name,
+                        )
+                        .await?;
+                    }
+                    tables.push(TableMaintenance {
+                        dead_ratio: table.dead_ratio(),
+                        schema_name: table.schema_name,
+                        table_name: table.table_name,
+                        live_tuples: table.live_tuples,
+                        dead_tuples: table.dead_tuples,
+                        last_vacuum_at: table.last_vacuum_at,
+                        last_autovacuum_at: table.last_autovacuum_at,
+                        vacuumed: !dry_run,
+                    });
+                }
+            }
+            MaintenanceTask::RebuildLeaderboard => {
+                tournaments_rebuilt = Some(if dry_run {
+                    maintenance::count_scored_tournaments(&state.db).await? as i32
+                } else {
+                    maintenance::rebuild_result_points(&state.db).await?
+                });
+            }
+            MaintenanceTask::RotatePiiKey => {
+                rows_resealed = Some(rotate_pii_key(state, dry_run).await?);
+            }
+            MaintenanceTask::NormalizePhones => {
+                let (normalized, unreadable) = normalize_phones(state, dry_run).await?;
+                phones_normalized = Some(normalized);
+                phones_unreadable = Some(unreadable);
+            }
+        }
+
+        tracing::info!(?task, dry_run, "Ran maintenance task");
+        Ok(MaintenanceReport {
+            task,
+            dry_run,
+            tables,
+            tournaments_rebuilt,
+            rows_resealed,
+            phones_normalized,
+            phones_unreadable,
+            duration_ms: started.elapsed().as_millis() as i64,
+        })
+    }
+}
+
+/// Re-seal every phone number not under the active key, in batches. Rows
+/// a user changes meanwhile are skipped: their new value is already sealed
+/// with the active key.
+async fn rotate_pii_key(state: &AppState, dry_run: bool) -> Result<i32> {
+    let keyring = infra::pii::keyring()
+        .ok_or_else(|| async_graphql::Error::new("PII_ENCRYPTION_KEYS is not configured"))?;
+    let active = keyring.active_id();
+    if dry_run {
+        return Ok(users::count_unrotated_phones(&state.db, active).await? as i32);
+    }
+
+    let mut resealed = 0;
+    loop {
+        let batch = users::list_unrotated_phones(&state.db, active, RESEAL_BATCH).await?;
+        let mut rewritten = 0;
+        for row in &batch {
+            if users::reseal_phone(&state.db, row).await? {
+                rewritten += 1;
+            }
+        }
+        resealed += rewritten;
+        if (batch.len() as i64) < RESEAL_BATCH || rewritten == 0 {
+            break;
+        }
+    }
+    Ok(resealed)
+}
+
+/// Rewrite every phone number not yet in E.164, in batches. Returns the
+/// numbers rewritten and those that couldn't be read (not a phone number, or
+/// sealed with a key this instance lacks), which are left alone.
+/// Rows a user changes meanwhile are skipped: new numbers are normalized on
+/// write.
+async fn normalize_phones(state: &AppState, dry_run: bool) -> Result<(i32, i32)> {
+    let mut normalized = 0;
+    let mut unreadable = 0;
+    let mut after = None;
+    loop {
+        let batch = users::list_phones_after(&state.db, after, PHONE_BATCH).await?;
+        for row in &batch {
+            let Ok(current) = infra::pii::open(&row.stored) else {
+                unreadable += 1;
+                continue;
+            };
+            let country = row.country.as_deref().unwrap_or(phone::DEFAULT_COUNTRY);
+            match phone::normalize(&current, country) {
+                Ok(e164) if e164 == current => {}
+                Ok(e164) => {
+                    if dry_run || users::replace_phone(&state.db, row, &e164).await? {
+                        normalized += 1;
+                    }
+                }
+                Err(_) => unreadable += 1,
+            }
+        }
+        match batch.last() {
+            Some(last) if batch.len() as i64 == PHONE_BATCH => after = Some(last.id),
+            _ => break,
+        }
+    }
+    Ok((normalized, unreadable))
+}
+
+/// Line the `embedded` (version, description, checksum) migrations up
+/// against the `applied` ledger.
+fn compare_migrations(
+    embedded: &[(i64, &str, &[u8])],
+    applied: &[AppliedMigrationRow],
+) -> MigrationStatus {
+    let applied_by_version: BTreeMap<i64, &AppliedMigrationRow> =
+        applied.iter().map(|row| (row.version, row)).collect();
+    let mut migrations: BTreeMap<i64, MigrationInfo> = BTreeMap::new();
+
+    for &(version, description, checksum) in embedded {
+        let row = applied_by_version.get(&version);
+        let state = match row {
+            None => MigrationState::Pending,
+            Some(row) if !row.success => MigrationState::Failed,
+            Some(row) if row.checksum.as_slice() != checksum => MigrationState::Modified,
+            Some(_) => MigrationState::Applied,
+        };
+        migrations.insert(
+            version,
+            MigrationInfo {
+                version,
+                description: description.to_string(),
+                state,
+                installed_on: row.map(|r| r.installed_on),
+                execution_time_ms: row.map(|r| r.execution_time as f64 / 1_000_000.0),
+            },
+        );
+    }
+    for row in applied {
+        migrations
+            .entry(row.version)
+            .or_insert_with(|| MigrationInfo {
+                version: row.version,
+                description: row.description.clone(),
+                state: if row.success {
+                    MigrationState::Unknown
+                } else {
+                    MigrationState::Failed
+                },
+                installed_on: Some(row.installed_on),
+                execution_time_ms: Some(row.execution_time as f64 / 1_000_000.0),
+            });
+    }
+
+    let migrations: Vec<MigrationInfo> = migrations.into_values().collect();
+    let count = |state: MigrationState| migrations.iter().filter(|m| m.state == state).count();
+    MigrationStatus {
+        applied_count: applied.iter().filter(|r| r.success).count() as i32,
+        pending_count: count(MigrationState::Pending) as i32,
+        latest_applied_version: applied
+            .iter()
+            .filter(|r| r.success)
+            .map(|r| r.version)
+            .max(),
+        up_to_date: migrations
+            .iter()
+            .all(|m| m.state == MigrationState::Applied),
+        migrations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn applied(version: i64, checksum: &[u8], success: bool) -> AppliedMigrationRow {
+        AppliedMigrationRow {
+            version,
+            description: format!("m{version}"),
+            installed_on: Utc::now(),
+            success,
+            checksum: checksum.to_vec(),
+            execution_time: 2_500_000,
+        }
+    }
+
+    #[test]
+    fn classifies_each_migration() {
+        let embedded = [
+            (1, "m1", &b"a"[..]),
+            (2, "m2", &b"b"[..]),
+            (3, "m3", &b"c"[..]),
+            (4, "m4", &b"d"[..]),
+        ];
+        let status = compare_migrations(
+            &embedded,
+            &[
+                applied(1, b"a", true),
+                applied(2, b"changed", true),
+                applied(3, b"c", false),
+                applied(9, b"z", true),
+            ],
+        );
+
+        let states: Vec<_> = status
+            .migrations
+            .iter()
+            .map(|m| (m.version, m.state))
+            .collect();
+        assert_eq!(
+            states,
+            vec![
+                (1, MigrationState::Applied),
+                (2, MigrationState::Modified),
+                (3, MigrationState::Failed),
+                (4, MigrationState::Pending),
+                (9, MigrationState::Unknown),
+            ]
+        );
+        assert_eq!(status.applied_count, 3);
+        assert_eq!(status.pending_count, 1);
+        assert_eq!(status.latest_applied_version, Some(9));
+        assert!(!status.up_to_date);
+        assert_eq!(status.migrations[0].execution_time_ms, Some(2.5));
+    }
+
+    #[test]
+    fn up_to_date_when_everything_is_applied() {
+        let status = compare_migrations(&[(1, "m1", &b"a"[..])], &[applied(1, b"a", true)]);
+        assert!(status.up_to_date);
+        assert_eq!(status.pending_count, 0);
+    }
+}
@@ -0,0 +1,196 @@
+use async_graphql::{Enum, SimpleObject};
+use chrono::{DateTime, Utc};
+
+use crate::config::{Setting, Source};
+
+/// Where a setting's value came from.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ConfigSource {
+    Env,
+    /// Read from the file named by `<NAME>_FILE`.
+    File,
+    Default,
+    Unset,
+}
+
+impl From<Source> for ConfigSource {
+    fn from(source: Source) -> Self {
+        match source {
+            Source::Env => ConfigSource::Env,
+            Source::File => ConfigSource::File,
+            Source::Default => ConfigSource::Default,
+            Source::Unset => ConfigSource::Unset,
+        }
+    }
+}
+
+/// One setting as this instance resolved it at startup.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct ConfigSetting {
+    pub name: String,
+    /// Never returned for secrets; `isSet` says whether one is configured.
+    pub value: Option<String>,
+    pub source: ConfigSource,
+    pub secret: bool,
+    pub is_set: bool,
+}
+
+impl From<Setting> for ConfigSetting {
+    fn from(s: Setting) -> Self {
+        Self {
+            is_set: matches!(s.source, Source::Env | Source::File),
+            name: s.name,
+            value: s.value,
+            source: s.source.into(),
+            secret: s.secret,
+        }
+    }
+}
+
+/// The instance's configuration, secrets redacted.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct ConfigDiagnostics {
+    pub settings: Vec<ConfigSetting>,
+    /// Allowed but probably unintended settings, as logged at startup.
+    pub warnings: Vec<String>,
+}
+
+/// How one migration stands against the database's ledger.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum MigrationState {
+    Applied,
+    /// Embedded in this build but not applied yet.
+    Pending,
+    /// Recorded as started but never finished.
+    Failed,
+    /// Applied, but the file in this build has changed since.
+    Modified,
+    /// Applied, but unknown to this build (a newer or removed migration).
+    Unknown,
+}
+
+#[derive(SimpleObject, Clone, Debug)]
+pub struct MigrationInfo {
+    pub version: i64,
+    pub description: String,
+    pub state: MigrationState,
+    pub installed_on: Option<DateTime<Utc>>,
+    pub execution_time_ms: Option<f64>,
+}
+
+/// The database's schema version against the migrations this build carries.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct MigrationStatus {
+    pub applied_count: i32,
+    pub pending_count: i32,
+    pub latest_applied_version: Option<i64>,
+    /// True when every embedded migration is applied unchanged and nothing
+    /// failed or is unknown.
+    pub up_to_date: bool,
+    /// Oldest first.
+    pub migrations: Vec<MigrationInfo>,
+}
+
+/// This instance's connection pool.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct PoolStats {
+    /// Connections currently open, idle or in use.
+    pub size: i32,
+    pub idle: i32,
+    pub in_use: i32,
+    pub max_connections: i32,
+    pub min_connections: i32,
+    /// Connections to this database on the server, from every instance
+    /// and client.
+    pub server_connections: i32,
+}
+
+/// One background loop's heartbeat.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct ServiceStatus {
+    pub name: String,
+    /// `None` until its first tick.
+    pub last_tick_at: Option<DateTime<Utc>>,
+    pub ticks: i64,
+    /// Times the supervisor restarted it after a panic.
+    pub restarts: i32,
+}
+
+/// Whether this instance's background loops are alive and keeping up.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct BackgroundServiceStatus {
+    pub last_clock_tick_at: Option<DateTime<Utc>>,
+    pub services: Vec<ServiceStatus>,
+    /// Scheduled announcements not sent yet.
+    pub pending_announcements: i32,
+    /// Pending announcements already past their scheduled time.
+    pub overdue_announcements: i32,
+    pub oldest_overdue_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum MaintenanceTask {
+    /// `VACUUM (ANALYZE)` the tables with the most dead tuples.
+    Vacuum,
+    /// Recompute every tournament result's stored points, which the
+    /// leaderboards rank on.
+    RebuildLeaderboard,
+    /// Re-seal personal data (plaintext or under an older key) with the
+    /// active `PII_ENCRYPTION_KEYS` key.
+    RotatePiiKey,
+    /// Rewrite phone numbers saved as typed in E.164, reading national
+    /// numbers as the country of the user's first club.
+    NormalizePhones,
+}
+
+/// A table considered by a vacuum run.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct TableMaintenance {
+    pub schema_name: String,
+    pub table_name: String,
+    pub live_tuples: i64,
+    pub dead_tuples: i64,
+    pub dead_ratio: f64,
+    pub last_vacuum_at: Option<DateTime<Utc>>,
+    pub last_autovacuum_at: Option<DateTime<Utc>>,
+    /// False on a dry run.
+    pub vacuumed: bool,
+}
+
+#[derive(SimpleObject, Clone, Debug)]
+pub struct MaintenanceReport {
+    pub task: MaintenanceTask,
+    pub dry_run: bool,
+    /// Vacuum: the candidate tables.
+    pub tables: Vec<TableMaintenance>,
+    /// Rebuild: tournaments recomputed, or that would be on a dry run.
+    pub tournaments_rebuilt: Option<i32>,
+    /// Rotation: values re-sealed, or that would be on a dry run.
+    pub rows_resealed: Option<i32>,
+    /// Phone normalization: numbers rewritten, or that would be on a dry run.
+    pub phones_normalized: Option<i32>,
+    /// Phone normalization: numbers that couldn't be read and were left as
+    /// they are.
+    pub phones_unreadable: Option<i32>,
+    pub duration_ms: i64,
+}
+
+/// Stored values sealed with one key.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct PiiKeyUsage {
+    /// `None` for values stored in plaintext.
+    pub key_id: Option<String>,
+    pub rows: i64,
+}
+
+/// Whether personal data is encrypted at rest, and with which keys.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct PiiEncryptionStatus {
+    pub enabled: bool,
+    pub active_key_id: Option<String>,
+    /// Configured key ids, active first.
+    pub key_ids: Vec<String>,
+    /// Stored phone numbers by sealing key. A finished rotation leaves only
+    /// the active key.
+    pub phones: Vec<PiiKeyUsage>,
+}
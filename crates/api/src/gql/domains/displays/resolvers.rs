@@ -0,0 +1,134 @@
+use async_graphql::{Context, ErrorExtensions, Object, Result, ID};
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::auth::display;
+use crate::auth::jwt::{DisplayClaims, DISPLAY_TOKEN_TTL_MINUTES};
+use crate::auth::permissions::require_club_manager;
+use crate::error::AppError;
+use crate::gql::error::{auth_error, ResultExt};
+use crate::state::AppState;
+use infra::repos::display_devices;
+
+use super::types::{CreateDisplayPairingInput, DisplayDevice, DisplayPairing, DisplaySession};
+
+const MAX_NAME_LEN: usize = 80;
+
+/// Pairing and renewal failures. A refused code or a revoked device is
+/// `UNAUTHENTICATED`, so the screen drops back to its pairing prompt.
+fn display_error(e: AppError) -> async_graphql::Error {
+    match e {
+        AppError::Unauthorized(message) => {
+            async_graphql::Error::new(message).extend_with(|_, e| e.set("code", "UNAUTHENTICATED"))
+        }
+        other => async_graphql::Error::new(format!("Database operation failed: {other}")),
+    }
+}
+
+fn session(claims: &DisplayClaims, token: String) -> DisplaySession {
+    DisplaySession {
+        token,
+        expires_at: DateTime::<Utc>::from_timestamp(claims.exp, 0)
+            .unwrap_or_else(|| Utc::now() + Duration::minutes(DISPLAY_TOKEN_TTL_MINUTES)),
+        device_id: ID(claims.sub.clone()),
+        club_id: claims.club_id.into(),
+    }
+}
+
+#[derive(Default)]
+pub struct DisplayQuery;
+
+#[Object]
+impl DisplayQuery {
+    /// The club's paired and pending screens, revoked ones included (managers only).
+    async fn club_display_devices(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+    ) -> Result<Vec<DisplayDevice>> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let rows = display_devices::list_for_club(&state.db, club_id).await?;
+        Ok(rows.into_iter().map(DisplayDevice::from).collect())
+    }
+}
+
+#[derive(Default)]
+pub struct DisplayMutation;
+
+#[Object]
+impl DisplayMutation {
+    /// Register a screen for the club and get the code to pair it with
+    /// (managers only).
+    async fn create_display_pairing(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateDisplayPairingInput,
+    ) -> Result<DisplayPairing> {
+        let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid user ID")?;
+
+        let name = input.name.trim();
+        if name.is_empty() || name.chars().count() > MAX_NAME_LEN {
+            return Err(async_graphql::Error::new(format!(
+                "Display name must be 1-{MAX_NAME_LEN} characters"
+            )));
+        }
+
+        let state = ctx.data::<AppState>()?;
+        let (device, code) = display::start_pairing(&state.db, club_id, name, manager_id)
+            .await
+            .gql_err("Database operation failed")?;
+
+        Ok(DisplayPairing {
+            expires_at: device.pairing_expires_at.unwrap_or_else(|| {
+                Utc::now() + Duration::minutes(display::PAIRING_CODE_TTL_MINUTES)
+            }),
+            device: device.into(),
+            code,
+        })
+    }
+
+    /// Exchange a pairing code for the screen's display token. Called by the
+    /// screen itself, signed out.
+    async fn pair_display(&self, ctx: &Context<'_>, code: String) -> Result<DisplaySession> {
+        let state = ctx.data::<AppState>()?;
+        let (claims, token) = display::pair(&state.db, state.jwt_service(), &code)
+            .await
+            .map_err(display_error)?;
+
+        Ok(session(&claims, token))
+    }
+
+    /// Swap the current display token for a fresh one. Fails once the screen
+    /// has been revoked.
+    async fn renew_display_token(&self, ctx: &Context<'_>) -> Result<DisplaySession> {
+        let current = ctx.data::<DisplayClaims>().map_err(|_| auth_error())?;
+        let state = ctx.data::<AppState>()?;
+        let (claims, token) = display::renew(&state.db, state.jwt_service(), current)
+            .await
+            .map_err(display_error)?;
+
+        Ok(session(&claims, token))
+    }
+
+    /// Cut a screen off. Its token stops working on its next request
+    /// (managers of the screen's club).
+    async fn revoke_display_device(&self, ctx: &Context<'_>, id: ID) -> Result<DisplayDevice> {
+        let id = Uuid::parse_str(id.as_str()).gql_err("Invalid display ID")?;
+        let state = ctx.data::<AppState>()?;
+        let device = display_devices::get_by_id(&state.db, id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Display not found"))?;
+        require_club_manager(ctx, device.club_id).await?;
+
+        display_devices::revoke(&state.db, id).await?;
+        let device = display_devices::get_by_id(&state.db, id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Display not found"))?;
+        Ok(device.into())
+    }
+}
@@ -0,0 +1,82 @@
+use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use infra::repos::display_devices::DisplayDeviceRow;
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum DisplayDeviceStatus {
+    /// Waiting for its pairing code to be entered.
+    Pending,
+    /// The pairing code ran out before it was used.
+    Expired,
+    Paired,
+    Revoked,
+}
+
+/// A screen paired (or being paired) to a club to show its clock, seating
+/// and promotions.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct DisplayDevice {
+    pub id: ID,
+    pub club_id: ID,
+    pub name: String,
+    pub status: DisplayDeviceStatus,
+    pub pairing_expires_at: Option<DateTime<Utc>>,
+    pub paired_at: Option<DateTime<Utc>>,
+    /// Last pairing or token renewal.
+    pub last_seen_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_by: Option<ID>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<DisplayDeviceRow> for DisplayDevice {
+    fn from(row: DisplayDeviceRow) -> Self {
+        let status = if row.revoked_at.is_some() {
+            DisplayDeviceStatus::Revoked
+        } else if row.paired_at.is_some() {
+            DisplayDeviceStatus::Paired
+        } else if row.pairing_expires_at.is_some_and(|at| at > Utc::now()) {
+            DisplayDeviceStatus::Pending
+        } else {
+            DisplayDeviceStatus::Expired
+        };
+        Self {
+            id: row.id.into(),
+            club_id: row.club_id.into(),
+            name: row.name,
+            status,
+            pairing_expires_at: row.pairing_expires_at,
+            paired_at: row.paired_at,
+            last_seen_at: row.last_seen_at,
+            revoked_at: row.revoked_at,
+            created_by: row.created_by.map(Into::into),
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// A pairing code to type into the screen. Shown once.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct DisplayPairing {
+    pub device: DisplayDevice,
+    pub code: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// A display token for a paired screen. Send it as the bearer token and call
+/// `renewDisplayToken` before `expiresAt`.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct DisplaySession {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+    pub device_id: ID,
+    pub club_id: ID,
+}
+
+#[derive(InputObject)]
+pub struct CreateDisplayPairingInput {
+    pub club_id: ID,
+    /// How the screen shows up in the device list (e.g. "Lobby TV").
+    pub name: String,
+}
@@ -1,3 +1,9 @@
+//! Buy-ins, rebuys and add-ons recorded at the desk. Rake
+//! (`tournaments.rake_cents`) is charged on top of each initial entry and
+//! re-entry, so it never enters the prize pool the entries trigger keeps;
+//! the accounting domain's `clubRakeReport` totals it per period.
+
+pub mod pricing;
 pub mod resolvers;
 pub mod types;
 
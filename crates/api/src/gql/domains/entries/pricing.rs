@@ -0,0 +1,70 @@
+//! Tiered buy-in pricing. A tournament can offer an early-bird price to
+//! players who registered before a cut-off; everyone else pays the regular
+//! buy-in. The tier follows the registration time, not the moment the desk
+//! takes the money, so an early registrant still pays the early price when
+//! they buy in on the night.
+
+use chrono::{DateTime, Utc};
+
+use infra::models::TournamentRow;
+
+use super::types::PriceTier;
+
+/// The tier and price of a buy-in for a player registered at `registered_at`.
+pub fn buy_in_price(
+    regular_cents: i64,
+    early_bird_cents: Option<i64>,
+    early_bird_until: Option<DateTime<Utc>>,
+    registered_at: DateTime<Utc>,
+) -> (PriceTier, i64) {
+    match (early_bird_cents, early_bird_until) {
+        (Some(cents), Some(until)) if registered_at < until => (PriceTier::EarlyBird, cents),
+        _ => (PriceTier::Regular, regular_cents),
+    }
+}
+
+/// [`buy_in_price`] for a tournament row.
+pub fn tournament_buy_in(
+    tournament: &TournamentRow,
+    registered_at: DateTime<Utc>,
+) -> (PriceTier, i64) {
+    buy_in_price(
+        tournament.buy_in_cents,
+        tournament.early_bird_buy_in_cents,
+        tournament.early_bird_until,
+        registered_at,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn early_registrants_pay_the_early_price() {
+        let until = Utc.with_ymd_and_hms(2026, 11, 1, 0, 0, 0).unwrap();
+        let before = until - chrono::Duration::seconds(1);
+        assert_eq!(
+            buy_in_price(5_000, Some(4_000), Some(until), before),
+            (PriceTier::EarlyBird, 4_000)
+        );
+        assert_eq!(
+            buy_in_price(5_000, Some(4_000), Some(until), until),
+            (PriceTier::Regular, 5_000)
+        );
+    }
+
+    #[test]
+    fn without_a_full_tier_everyone_pays_regular() {
+        let now = Utc::now();
+        assert_eq!(
+            buy_in_price(5_000, Some(4_000), None, now),
+            (PriceTier::Regular, 5_000)
+        );
+        assert_eq!(
+            buy_in_price(5_000, None, Some(now + chrono::Duration::days(1)), now),
+            (PriceTier::Regular, 5_000)
+        );
+    }
+}
@@ -1,16 +1,26 @@
-use async_graphql::{Context, Object, Result, ID};
+use async_graphql::{dataloader::DataLoader, Context, Object, Result, ID};
+use chrono::Utc;
 use uuid::Uuid;
 
 use crate::auth::jwt::Claims;
-use crate::gql::error::{auth_error, ResultExt};
+use crate::gql::domains::printouts::receipts;
+use crate::gql::domains::tickets;
+use crate::gql::error::{auth_error, GqlError, ResultExt};
+use crate::gql::loaders::ClubPlayerLoader;
+use crate::gql::subscriptions::publish_entry_event;
 use crate::state::AppState;
+use infra::models::TournamentEntryRow;
 use infra::repos::{
-    tournament_entries, tournament_entries::CreateTournamentEntry, tournament_payouts, tournaments,
+    buy_in_credits, entry_tickets, staff_shifts, tournament_entries,
+    tournament_entries::{CreateTournamentEntry, EntryRejection},
+    tournament_payouts, tournament_registrations, tournaments,
 };
 
+use super::pricing;
 use super::types::{
-    AddTournamentEntryInput, CashReportLine, EntryType, PaymentMethod, TournamentCashReport,
-    TournamentEntry, TournamentEntryStats,
+    AddTournamentEntryInput, CashReportLine, EntryEventType, EntryType, PaymentMethod,
+    TournamentCashReport, TournamentEntry, TournamentEntryEvent, TournamentEntryStats,
+    TournamentEntryTotals,
 };
 
 #[derive(Default)]
@@ -55,19 +65,29 @@ impl EntryQuery {
             Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
 
         let stats = tournament_entries::get_stats(&state.db, tournament_id).await?;
+        let prize_pool_cents = tournament_payouts::get_by_tournament(&state.db, tournament_id)
+            .await?
+            .map(|p| p.total_prize_pool)
+            .unwrap_or(0);
 
         Ok(TournamentEntryStats {
             tournament_id: tournament_id.into(),
             total_entries: stats.total_entries as i32,
-            total_amount_cents: stats.total_amount_cents as i32,
+            total_amount_cents: stats.total_amount_cents.into(),
             unique_players: stats.unique_players as i32,
             initial_count: stats.initial_count as i32,
             rebuy_count: stats.rebuy_count as i32,
             re_entry_count: stats.re_entry_count as i32,
             addon_count: stats.addon_count as i32,
-            total_rake_cents: stats.total_rake_cents as i32,
+            rake_per_entry_cents: stats.rake_cents.into(),
+            total_rake_cents: stats.total_rake_cents.into(),
+            prize_pool_cents: prize_pool_cents.into(),
             total_chips: stats.total_chips,
             players_remaining: stats.players_remaining as i32,
+            early_bird_count: stats.early_bird_count as i32,
+            early_bird_amount_cents: stats.early_bird_amount_cents.into(),
+            regular_count: stats.regular_count as i32,
+            regular_amount_cents: stats.regular_amount_cents.into(),
         })
     }
 
@@ -95,6 +115,7 @@ impl EntryQuery {
             .await?
             .map(|p| p.total_prize_pool)
             .unwrap_or(0);
+        let staff_cost_cents = staff_shifts::cost_for_tournament(&state.db, tournament_id).await?;
 
         let total_collected_cents: i64 = raw_lines.iter().map(|l| l.amount_cents).sum();
         let lines = raw_lines
@@ -102,7 +123,7 @@ impl EntryQuery {
             .map(|l| CashReportLine {
                 payment_method: PaymentMethod::from(l.payment_method),
                 entry_type: EntryType::from(l.entry_type),
-                amount_cents: l.amount_cents as i32,
+                amount_cents: l.amount_cents.into(),
                 count: l.count as i32,
             })
             .collect();
@@ -110,14 +131,70 @@ impl EntryQuery {
         Ok(TournamentCashReport {
             tournament_id: tournament_id.into(),
             lines,
-            total_collected_cents: total_collected_cents as i32,
-            total_rake_cents: stats.total_rake_cents as i32,
-            prize_pool_cents,
+            total_collected_cents: total_collected_cents.into(),
+            total_rake_cents: stats.total_rake_cents.into(),
+            prize_pool_cents: prize_pool_cents.into(),
             entry_count: stats.total_entries as i32,
+            staff_cost_cents: staff_cost_cents.into(),
         })
     }
 }
 
+/// Announce an entry change on the tournament's entries channel, with the
+/// totals it leaves. Best-effort: a failed lookup only costs the update.
+async fn publish_entry_change(
+    ctx: &Context<'_>,
+    event_type: EntryEventType,
+    entry: &TournamentEntryRow,
+) {
+    let Ok(state) = ctx.data::<AppState>() else {
+        return;
+    };
+    let stats = match tournament_entries::get_stats(&state.db, entry.tournament_id).await {
+        Ok(stats) => stats,
+        Err(e) => {
+            tracing::warn!(
+                "entries: totals for tournament {} failed: {e}",
+                entry.tournament_id
+            );
+            return;
+        }
+    };
+    let prize_pool_cents = tournament_payouts::get_by_tournament(&state.db, entry.tournament_id)
+        .await
+        .ok()
+        .flatten()
+        .map(|p| p.total_prize_pool)
+        .unwrap_or(0);
+    let display_name = match ctx.data::<DataLoader<ClubPlayerLoader>>() {
+        Ok(loader) => loader.load_one(entry.club_player_id).await.ok().flatten(),
+        Err(_) => None,
+    }
+    .map(|rp| rp.display_name)
+    .unwrap_or_else(|| "Unknown".to_string());
+
+    publish_entry_event(TournamentEntryEvent {
+        event_type,
+        tournament_id: entry.tournament_id.into(),
+        entry_id: entry.id.into(),
+        entry_type: EntryType::from(entry.entry_type.clone()),
+        club_player_id: entry.club_player_id.into(),
+        display_name,
+        chips_received: entry.chips_received,
+        totals: TournamentEntryTotals {
+            total_entries: stats.total_entries as i32,
+            unique_players: stats.unique_players as i32,
+            rebuy_count: stats.rebuy_count as i32,
+            re_entry_count: stats.re_entry_count as i32,
+            addon_count: stats.addon_count as i32,
+            players_remaining: stats.players_remaining as i32,
+            total_chips: stats.total_chips,
+            prize_pool_cents: prize_pool_cents.into(),
+        },
+        timestamp: Utc::now(),
+    });
+}
+
 #[derive(Default)]
 pub struct EntryMutation;
 
@@ -147,14 +224,66 @@ impl EntryMutation {
         let manager = require_club_manager(ctx, club_id).await?;
         let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid manager ID")?;
 
-        // Use provided amount or default to tournament buy_in_cents
-        let amount_cents = input.amount_cents.unwrap_or(tournament.buy_in_cents);
+        // An offline desk replays its queued mutation with the id it generated:
+        // hand back the entry recorded the first time.
+        let client_id = input
+            .id
+            .as_ref()
+            .map(|id| Uuid::parse_str(id.as_str()))
+            .transpose()
+            .gql_err("Invalid entry ID")?;
+        if let Some(id) = client_id {
+            if let Some(existing) = tournament_entries::get_by_id(&state.db, id).await? {
+                if existing.tournament_id != tournament_id
+                    || existing.user_id != Some(user_id)
+                    || existing.entry_type != String::from(input.entry_type)
+                {
+                    return Err(async_graphql::Error::new(
+                        "Entry ID is already used by a different entry",
+                    ));
+                }
+                return Ok(existing.into());
+            }
+        }
+
+        // An initial buy-in is priced by the player's registration time
+        // (walk-ins register as they buy in); rebuys and re-entries always
+        // pay the regular buy-in. A provided amount overrides the price but
+        // the buy-in still counts toward its tier.
         let is_initial = matches!(input.entry_type, EntryType::Initial);
-        let payment_method = String::from(
-            input
-                .payment_method
-                .unwrap_or(super::types::PaymentMethod::Cash),
-        );
+        let registration = if is_initial {
+            tournament_registrations::get_by_tournament_and_user(&state.db, tournament_id, user_id)
+                .await?
+        } else {
+            None
+        };
+        let (price_tier, price_cents) = if is_initial {
+            let registered_at = registration
+                .as_ref()
+                .map(|r| r.registration_time)
+                .unwrap_or_else(Utc::now);
+            let (tier, cents) = pricing::tournament_buy_in(&tournament, registered_at);
+            (Some(tier), cents)
+        } else {
+            (None, tournament.buy_in_cents)
+        };
+        let amount_cents = input.amount_cents.map(i64::from).unwrap_or(price_cents);
+
+        // A package or voucher redeemed at registration pays the buy-in,
+        // unless the desk says the player paid some other way.
+        let redemption = match &registration {
+            Some(registration) => buy_in_credits::open_redemption(&state.db, registration.id)
+                .await?
+                .filter(|r| r.entry_id.is_none()),
+            None => None,
+        };
+        let payment_method = input.payment_method.unwrap_or(if redemption.is_some() {
+            PaymentMethod::Voucher
+        } else {
+            PaymentMethod::Cash
+        });
+        let redemption = redemption.filter(|_| payment_method == PaymentMethod::Voucher);
+        let payment_method = String::from(payment_method);
 
         // Default the chip count of an initial buy-in to the tournament's configured
         // starting stack when the manager didn't override it explicitly.
@@ -165,6 +294,7 @@ impl EntryMutation {
         });
 
         let create_data = CreateTournamentEntry {
+            id: client_id,
             tournament_id,
             user_id: Some(user_id),
             club_player_id: None,
@@ -174,29 +304,51 @@ impl EntryMutation {
             recorded_by: Some(manager_id),
             notes: input.notes,
             payment_method: payment_method.clone(),
+            price_tier: price_tier.map(|tier| tier.as_db().to_string()),
         };
 
-        let entry_row = tournament_entries::create(&state.db, create_data).await?;
+        let entry_row = tournament_entries::create(&state.db, create_data)
+            .await
+            .map_err(entry_error)?;
+        if let Some(redemption) = redemption {
+            buy_in_credits::link_entry(&state.db, redemption.id, entry_row.id).await?;
+        }
 
         // Mandatory drink voucher: bought together with the initial buy-in. It is
         // excluded from the prize pool (paper voucher IRL). Keyed to the same roster
         // player as the buy-in; skipped if the tournament has no voucher.
-        if is_initial && tournament.voucher_value_cents > 0 {
+        let voucher_cents = if is_initial {
+            tournament.voucher_value_cents
+        } else {
+            0
+        };
+        if voucher_cents > 0 {
             let voucher_data = CreateTournamentEntry {
+                id: None,
                 tournament_id,
                 user_id: entry_row.user_id,
                 club_player_id: Some(entry_row.club_player_id),
                 entry_type: "voucher".to_string(),
-                amount_cents: tournament.voucher_value_cents,
+                amount_cents: voucher_cents,
                 chips_received: None,
                 recorded_by: Some(manager_id),
                 notes: Some("Mandatory drink voucher".to_string()),
                 // The voucher is paid together with the buy-in, same method.
                 payment_method: payment_method.clone(),
+                price_tier: None,
             };
             tournament_entries::create(&state.db, voucher_data).await?;
         }
 
+        // Receipt for the desk's thermal printer, voucher included.
+        if receipts::issues_receipt(&entry_row.entry_type) {
+            receipts::issue_logged(&state.db, entry_row.id, voucher_cents, Some(manager_id)).await;
+        }
+        if tickets::issues_ticket(&entry_row.entry_type) {
+            tickets::issue_logged(&state.db, entry_row.id, Some(manager_id)).await;
+        }
+        publish_entry_change(ctx, EntryEventType::Added, &entry_row).await;
+
         // Log activity
         {
             let db = state.db.clone();
@@ -282,9 +434,16 @@ impl EntryMutation {
         let manager = require_club_manager(ctx, club_id).await?;
         let manager_id = Uuid::parse_str(manager.id.as_str()).ok();
 
-        let result = tournament_entries::delete(&state.db, entry_id)
+        // The entry's ticket must stop admitting anyone.
+        let mut tx = state.db.begin().await?;
+        entry_tickets::void_for_entry(&mut *tx, entry_id, manager_id, "Entry deleted").await?;
+        let result = tournament_entries::delete(&mut *tx, entry_id)
             .await
-            .gql_err("Failed to delete entry")?;
+            .map_err(entry_error)?;
+        tx.commit().await?;
+        if result {
+            publish_entry_change(ctx, EntryEventType::Removed, &entry).await;
+        }
 
         // Log activity
         {
@@ -310,3 +469,34 @@ impl EntryMutation {
         Ok(result)
     }
 }
+
+/// A refused entry as a message the desk can show, with a machine-readable
+/// `extensions.code`; any other failure is an internal error.
+fn entry_error(error: sqlx::Error) -> async_graphql::Error {
+    use async_graphql::ErrorExtensions;
+
+    let (code, message) = match EntryRejection::classify(&error) {
+        Some(EntryRejection::DuplicateInitial) => (
+            "DUPLICATE_INITIAL_ENTRY",
+            "This player has already bought in; record a rebuy or re-entry instead",
+        ),
+        Some(EntryRejection::RebuyLimit) => (
+            "REBUY_LIMIT_REACHED",
+            "This player has used all the rebuys the tournament allows",
+        ),
+        Some(EntryRejection::TournamentFinished) => (
+            "TOURNAMENT_FINISHED",
+            "The tournament is finished; no more entries can be recorded",
+        ),
+        Some(EntryRejection::TournamentCancelled) => (
+            "TOURNAMENT_CANCELLED",
+            "The tournament was cancelled; no more entries can be recorded",
+        ),
+        Some(EntryRejection::PayoutsFinalized) => (
+            "PAYOUTS_FINALIZED",
+            "Payouts are final; reopen them to change the entries",
+        ),
+        None => return GqlError::from(error).into(),
+    };
+    async_graphql::Error::new(message).extend_with(|_, e| e.set("code", code))
+}
@@ -1,7 +1,18 @@
-use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use async_graphql::{ComplexObject, Context, Enum, InputObject, Result, SimpleObject, ID};
 use chrono::{DateTime, Utc};
+use uuid::Uuid;
 
-#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+use crate::auth::guards::EntryAmountGuard;
+use crate::auth::permissions::require_club_manager;
+use crate::gql::common::helpers::get_club_id_for_tournament;
+use crate::gql::domains::printouts::types::EntryReceipt;
+use crate::gql::domains::tickets::types::EntryTicket;
+use crate::gql::error::ResultExt;
+use crate::gql::scalars::Money;
+use crate::state::AppState;
+use infra::repos::{entry_receipts, entry_tickets};
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
 pub enum EntryType {
     Initial,
     Rebuy,
@@ -78,7 +89,32 @@ impl From<PaymentMethod> for String {
     }
 }
 
+/// Price tier a buy-in was charged at.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum PriceTier {
+    /// The player registered before the early-bird cut-off.
+    EarlyBird,
+    Regular,
+}
+
+impl PriceTier {
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "early_bird" => PriceTier::EarlyBird,
+            _ => PriceTier::Regular,
+        }
+    }
+
+    pub fn as_db(self) -> &'static str {
+        match self {
+            PriceTier::EarlyBird => "early_bird",
+            PriceTier::Regular => "regular",
+        }
+    }
+}
+
 #[derive(SimpleObject, Clone)]
+#[graphql(complex)]
 pub struct TournamentEntry {
     pub id: ID,
     pub tournament_id: ID,
@@ -87,15 +123,52 @@ pub struct TournamentEntry {
     /// The club roster identity — always present.
     pub club_player_id: ID,
     pub entry_type: EntryType,
-    pub amount_cents: i32,
+    /// The paying player, managers of the tournament's club, and admins.
+    #[graphql(guard = "EntryAmountGuard::new(self.user_id.as_ref(), &self.tournament_id)")]
+    pub amount_cents: Money,
     pub chips_received: Option<i32>,
     pub recorded_by: Option<ID>,
     pub notes: Option<String>,
     pub payment_method: PaymentMethod,
+    /// Price tier of a buy-in; null for other entry types.
+    pub price_tier: Option<PriceTier>,
+    /// Level being played when the entry was recorded; null before the start.
+    pub level_number: Option<i32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+#[ComplexObject]
+impl TournamentEntry {
+    /// The buy-in receipt issued with this entry, with a fresh print link.
+    /// Null for vouchers and bonuses. Club managers.
+    async fn receipt(&self, ctx: &Context<'_>) -> Result<Option<EntryReceipt>> {
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(self.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let entry_id = Uuid::parse_str(self.id.as_str()).gql_err("Invalid entry ID")?;
+        let row = entry_receipts::get_by_entry(&state.db, entry_id).await?;
+        Ok(row.map(|row| EntryReceipt::new(row, state, Utc::now())))
+    }
+
+    /// The entry's QR ticket: the live one, or the latest voided one. Null
+    /// for rebuys, add-ons, vouchers and bonuses. Club managers.
+    async fn ticket(&self, ctx: &Context<'_>) -> Result<Option<EntryTicket>> {
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(self.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let entry_id = Uuid::parse_str(self.id.as_str()).gql_err("Invalid entry ID")?;
+        let row = entry_tickets::get_for_entry(&state.db, entry_id).await?;
+        Ok(row.map(|row| EntryTicket::new(row, state)))
+    }
+}
+
 impl From<infra::models::TournamentEntryRow> for TournamentEntry {
     fn from(row: infra::models::TournamentEntryRow) -> Self {
         Self {
@@ -104,11 +177,13 @@ impl From<infra::models::TournamentEntryRow> for TournamentEntry {
             user_id: row.user_id.map(Into::into),
             club_player_id: row.club_player_id.into(),
             entry_type: EntryType::from(row.entry_type),
-            amount_cents: row.amount_cents,
+            amount_cents: row.amount_cents.into(),
             chips_received: row.chips_received,
             recorded_by: row.recorded_by.map(|id| id.into()),
             notes: row.notes,
             payment_method: PaymentMethod::from(row.payment_method),
+            price_tier: row.price_tier.as_deref().map(PriceTier::from_db),
+            level_number: row.level_number,
             created_at: row.created_at,
             updated_at: row.updated_at,
         }
@@ -119,23 +194,77 @@ impl From<infra::models::TournamentEntryRow> for TournamentEntry {
 pub struct TournamentEntryStats {
     pub tournament_id: ID,
     pub total_entries: i32,
-    pub total_amount_cents: i32,
+    pub total_amount_cents: Money,
     pub unique_players: i32,
     pub initial_count: i32,
     pub rebuy_count: i32,
     pub re_entry_count: i32,
     pub addon_count: i32,
-    pub total_rake_cents: i32,
+    /// Rake charged on top of each initial entry and re-entry.
+    pub rake_per_entry_cents: Money,
+    /// Rake on the initial entries and re-entries recorded so far.
+    pub total_rake_cents: Money,
+    /// Entries minus the bounty slice, vouchers excluded. Rake is charged on
+    /// top of the buy-in and never enters the pool.
+    pub prize_pool_cents: Money,
     pub total_chips: i64,
     pub players_remaining: i32,
+    /// Buy-ins at the early-bird price, and the money they brought in.
+    pub early_bird_count: i32,
+    pub early_bird_amount_cents: Money,
+    /// Buy-ins at the regular price, and the money they brought in.
+    pub regular_count: i32,
+    pub regular_amount_cents: Money,
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+pub enum EntryEventType {
+    Added,
+    /// Deleted as a correction.
+    Removed,
+}
+
+/// Where the tournament's money and chips stand after an entry change.
+#[derive(SimpleObject, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct TournamentEntryTotals {
+    pub total_entries: i32,
+    pub unique_players: i32,
+    pub rebuy_count: i32,
+    pub re_entry_count: i32,
+    pub addon_count: i32,
+    pub players_remaining: i32,
+    pub total_chips: i64,
+    /// Entries minus the bounty slice, vouchers excluded (rake is charged on
+    /// top and never enters the pool).
+    pub prize_pool_cents: Money,
+}
+
+/// An entry recorded or removed at the desk, with the running totals, for
+/// the prize-pool ticker and the other cashier terminals. Payment details
+/// stay in the manager-only `tournamentEntries`.
+#[derive(SimpleObject, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct TournamentEntryEvent {
+    pub event_type: EntryEventType,
+    pub tournament_id: ID,
+    pub entry_id: ID,
+    pub entry_type: EntryType,
+    pub club_player_id: ID,
+    /// Roster name, as the venue knows the player.
+    pub display_name: String,
+    pub chips_received: Option<i32>,
+    pub totals: TournamentEntryTotals,
+    pub timestamp: DateTime<Utc>,
 }
 
 #[derive(InputObject)]
 pub struct AddTournamentEntryInput {
+    /// Client-generated id, so an offline desk can replay the mutation
+    /// safely: a replay returns the entry already recorded under it.
+    pub id: Option<ID>,
     pub tournament_id: ID,
     pub user_id: ID,
     pub entry_type: EntryType,
-    pub amount_cents: Option<i32>,
+    pub amount_cents: Option<Money>,
     pub chips_received: Option<i32>,
     pub notes: Option<String>,
     /// How the player paid; defaults to CASH when omitted.
@@ -147,7 +276,7 @@ pub struct AddTournamentEntryInput {
 pub struct CashReportLine {
     pub payment_method: PaymentMethod,
     pub entry_type: EntryType,
-    pub amount_cents: i32,
+    pub amount_cents: Money,
     pub count: i32,
 }
 
@@ -158,8 +287,10 @@ pub struct TournamentCashReport {
     pub tournament_id: ID,
     pub lines: Vec<CashReportLine>,
     /// Sum of every entry's amount across all methods (gross collected).
-    pub total_collected_cents: i32,
-    pub total_rake_cents: i32,
-    pub prize_pool_cents: i32,
+    pub total_collected_cents: Money,
+    pub total_rake_cents: Money,
+    pub prize_pool_cents: Money,
     pub entry_count: i32,
+    /// Labour cost of the closed staff shifts booked against this tournament.
+    pub staff_cost_cents: Money,
 }
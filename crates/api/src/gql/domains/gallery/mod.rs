@@ -0,0 +1,10 @@
+//! Tournament photo galleries. Photos are stored by the media service and
+//! linked by URL, with a caption and tagged roster entries. Outside the
+//! club's managers a photo shows only when every tagged player consents: an
+//! account holder through `user_privacy_settings.appear_in_photos` (off by
+//! default), a walk-in through the tag's `consent_recorded`.
+
+pub mod resolvers;
+pub mod types;
+
+pub use resolvers::{GalleryMutation, GalleryQuery};
@@ -0,0 +1,189 @@
+use async_graphql::{Context, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::auth::permissions::{require_club_manager, viewer_manages_club};
+use crate::gql::common::helpers::{
+    get_club_id_for_tournament, tournament_access, TournamentAccess,
+};
+use crate::gql::error::ResultExt;
+use crate::state::AppState;
+use infra::repos::{club_players, tournament_photos, tournament_photos::CreatePhoto};
+
+use super::types::{
+    AddTournamentPhotoInput, PhotoTagInput, TournamentPhoto, UpdateTournamentPhotoInput,
+};
+
+const MAX_CAPTION_LEN: usize = 280;
+
+async fn get_photo(
+    state: &AppState,
+    photo_id: &ID,
+) -> Result<tournament_photos::TournamentPhotoRow> {
+    let id = Uuid::parse_str(photo_id.as_str()).gql_err("Invalid photo ID")?;
+    tournament_photos::get_by_id(&state.db, id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Photo not found"))
+}
+
+fn validate_upload(url: &str, content_type: Option<&str>) -> Result<()> {
+    if !(url.starts_with("https://") || url.starts_with("http://")) {
+        return Err(async_graphql::Error::new(
+            "Photo URL must be an http(s) URL",
+        ));
+    }
+    if content_type.is_some_and(|t| !t.starts_with("image/")) {
+        return Err(async_graphql::Error::new("Photos must be images"));
+    }
+    Ok(())
+}
+
+/// Trimmed caption; an empty one means none.
+fn caption(raw: &str) -> Result<Option<String>> {
+    let caption = Some(raw.trim()).filter(|c| !c.is_empty());
+    if caption.is_some_and(|c| c.chars().count() > MAX_CAPTION_LEN) {
+        return Err(async_graphql::Error::new(format!(
+            "Caption must be at most {MAX_CAPTION_LEN} characters"
+        )));
+    }
+    Ok(caption.map(str::to_string))
+}
+
+/// Tagged players must be on the club's roster.
+async fn parse_tags(
+    state: &AppState,
+    club_id: Uuid,
+    tags: &[PhotoTagInput],
+) -> Result<Vec<(Uuid, bool)>> {
+    let mut parsed = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let id = Uuid::parse_str(tag.club_player_id.as_str()).gql_err("Invalid player ID")?;
+        club_players::get_by_id(&state.db, id)
+            .await?
+            .filter(|p| p.club_id == club_id)
+            .ok_or_else(|| async_graphql::Error::new("Tagged player is not in this club"))?;
+        parsed.push((id, tag.consent_recorded));
+    }
+    Ok(parsed)
+}
+
+#[derive(Default)]
+pub struct GalleryQuery;
+
+#[Object]
+impl GalleryQuery {
+    /// A tournament's photo gallery, oldest first. The club's managers see
+    /// every photo; everyone else, whoever can see the tournament, only those
+    /// all tagged players consent to showing (`isPublic`).
+    async fn tournament_gallery(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        invite_token: Option<String>,
+    ) -> Result<Vec<TournamentPhoto>> {
+        let state = ctx.data::<AppState>()?;
+        let tid = Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tid).await?;
+
+        let consented_only = !viewer_manages_club(ctx, club_id).await;
+        if consented_only
+            && tournament_access(ctx, tid, invite_token.as_deref()).await?
+                != TournamentAccess::Visible
+        {
+            return Ok(vec![]);
+        }
+
+        let rows = tournament_photos::list_for_tournament(&state.db, tid, consented_only).await?;
+        Ok(rows.into_iter().map(TournamentPhoto::from).collect())
+    }
+}
+
+#[derive(Default)]
+pub struct GalleryMutation;
+
+#[Object]
+impl GalleryMutation {
+    /// Add a photo the media service stored to a tournament's gallery
+    /// (managers only).
+    async fn add_tournament_photo(
+        &self,
+        ctx: &Context<'_>,
+        input: AddTournamentPhotoInput,
+    ) -> Result<TournamentPhoto> {
+        let state = ctx.data::<AppState>()?;
+        let tid = Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tid).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid user ID")?;
+
+        let url = input.url.trim();
+        let content_type = input.content_type.as_deref().map(str::trim);
+        validate_upload(url, content_type)?;
+        let caption = input.caption.as_deref().map(caption).transpose()?.flatten();
+        let tags = parse_tags(state, club_id, &input.tags).await?;
+
+        let mut tx = state.db.begin().await?;
+        let row = tournament_photos::create(
+            &mut *tx,
+            CreatePhoto {
+                tournament_id: tid,
+                url: url.to_string(),
+                content_type: content_type.map(str::to_string),
+                caption,
+                uploaded_by: manager_id,
+            },
+        )
+        .await?;
+        for (club_player_id, consent_recorded) in tags {
+            tournament_photos::add_tag(&mut *tx, row.id, club_player_id, consent_recorded).await?;
+        }
+        tx.commit().await?;
+
+        Ok(row.into())
+    }
+
+    /// Change a photo's caption or tags (managers only).
+    async fn update_tournament_photo(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateTournamentPhotoInput,
+    ) -> Result<TournamentPhoto> {
+        let state = ctx.data::<AppState>()?;
+        let mut row = get_photo(state, &input.photo_id).await?;
+        let club_id = get_club_id_for_tournament(&state.db, row.tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let caption = input.caption.as_deref().map(caption).transpose()?;
+        let tags = match &input.tags {
+            Some(tags) => Some(parse_tags(state, club_id, tags).await?),
+            None => None,
+        };
+
+        let mut tx = state.db.begin().await?;
+        if let Some(caption) = caption {
+            row = tournament_photos::update_caption(&mut *tx, row.id, caption.as_deref())
+                .await?
+                .ok_or_else(|| async_graphql::Error::new("Photo not found"))?;
+        }
+        if let Some(tags) = tags {
+            tournament_photos::clear_tags(&mut *tx, row.id).await?;
+            for (club_player_id, consent_recorded) in tags {
+                tournament_photos::add_tag(&mut *tx, row.id, club_player_id, consent_recorded)
+                    .await?;
+            }
+        }
+        tx.commit().await?;
+
+        Ok(row.into())
+    }
+
+    /// Remove a photo from the gallery (managers only). The file itself stays
+    /// with the media service.
+    async fn delete_tournament_photo(&self, ctx: &Context<'_>, photo_id: ID) -> Result<bool> {
+        let state = ctx.data::<AppState>()?;
+        let row = get_photo(state, &photo_id).await?;
+        let club_id = get_club_id_for_tournament(&state.db, row.tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        Ok(tournament_photos::delete(&state.db, row.id).await?)
+    }
+}
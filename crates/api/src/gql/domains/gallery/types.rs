@@ -0,0 +1,106 @@
+use async_graphql::{ComplexObject, Context, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use crate::state::AppState;
+use infra::repos::tournament_photos::{self, PhotoTagRow, TournamentPhotoRow};
+
+/// A photo in a tournament's gallery, stored by the media service.
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct TournamentPhoto {
+    pub id: ID,
+    pub tournament_id: ID,
+    pub url: String,
+    pub content_type: Option<String>,
+    pub caption: Option<String>,
+    pub uploaded_by: Option<ID>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<TournamentPhotoRow> for TournamentPhoto {
+    fn from(row: TournamentPhotoRow) -> Self {
+        Self {
+            id: row.id.into(),
+            tournament_id: row.tournament_id.into(),
+            url: row.url,
+            content_type: row.content_type,
+            caption: row.caption,
+            uploaded_by: row.uploaded_by.map(Into::into),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[ComplexObject]
+impl TournamentPhoto {
+    /// Players in the photo, by name.
+    async fn tags(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<PhotoTag>> {
+        let state = ctx.data::<AppState>()?;
+        let id = uuid::Uuid::parse_str(self.id.as_str())?;
+        let rows = tournament_photos::list_tags(&state.db, id).await?;
+        Ok(rows.into_iter().map(PhotoTag::from).collect())
+    }
+
+    /// Whether the public site shows the photo: every tagged player consents.
+    async fn is_public(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
+        let state = ctx.data::<AppState>()?;
+        let id = uuid::Uuid::parse_str(self.id.as_str())?;
+        let rows = tournament_photos::list_tags(&state.db, id).await?;
+        Ok(rows.iter().all(|tag| tag.consented))
+    }
+}
+
+/// A player tagged in a photo.
+#[derive(SimpleObject, Clone)]
+pub struct PhotoTag {
+    pub club_player_id: ID,
+    pub display_name: String,
+    /// Consent the club collected in person. Only counts for players without
+    /// an account; the others decide in their privacy settings.
+    pub consent_recorded: bool,
+    /// Whether the player consents to the photo being shown publicly.
+    pub consented: bool,
+}
+
+impl From<PhotoTagRow> for PhotoTag {
+    fn from(row: PhotoTagRow) -> Self {
+        Self {
+            club_player_id: row.club_player_id.into(),
+            display_name: row.display_name,
+            consent_recorded: row.consent_recorded,
+            consented: row.consented,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct AddTournamentPhotoInput {
+    pub tournament_id: ID,
+    /// Where the media service stored the upload.
+    pub url: String,
+    /// An `image/*` type, if known.
+    pub content_type: Option<String>,
+    pub caption: Option<String>,
+    #[graphql(default)]
+    pub tags: Vec<PhotoTagInput>,
+}
+
+#[derive(InputObject)]
+pub struct PhotoTagInput {
+    pub club_player_id: ID,
+    /// The player, who has no account, agreed in person to the photo being
+    /// shown publicly.
+    #[graphql(default)]
+    pub consent_recorded: bool,
+}
+
+#[derive(InputObject)]
+pub struct UpdateTournamentPhotoInput {
+    pub photo_id: ID,
+    /// New caption; empty clears it, omitted keeps it.
+    pub caption: Option<String>,
+    /// Replaces the tags when given.
+    pub tags: Option<Vec<PhotoTagInput>>,
+}
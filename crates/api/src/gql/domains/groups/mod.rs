@@ -0,0 +1,43 @@
+//! Group bookings: friends link their registrations with a short code so the
+//! seat draw can seat them together, or split them up when the club wants a
+//! fair field (see `seating::service::pick_group_seats`).
+
+pub mod resolvers;
+pub mod types;
+
+pub use resolvers::{GroupMutation, GroupQuery};
+
+use rand::RngExt;
+
+/// Unambiguous characters for group codes (no 0/O, 1/I/L).
+const CODE_ALPHABET: &[u8] = b"23456789ABCDEFGHJKMNPQRSTUVWXYZ";
+
+/// A random six-character group code.
+pub fn group_code() -> String {
+    let mut rng = rand::rng();
+    (0..6)
+        .map(|_| char::from(CODE_ALPHABET[rng.random_range(0..CODE_ALPHABET.len())]))
+        .collect()
+}
+
+/// Normalize a typed code: case and separators don't matter.
+pub fn normalize_code(input: &str) -> String {
+    input
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_survive_sloppy_typing() {
+        let code = group_code();
+        assert_eq!(code.len(), 6);
+        let typed = format!(" {}-{} ", &code[..3], &code[3..]).to_lowercase();
+        assert_eq!(normalize_code(&typed), code);
+    }
+}
@@ -0,0 +1,230 @@
+use async_graphql::{Context, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::auth::jwt::Claims;
+use crate::auth::permissions::require_club_manager;
+use crate::gql::common::helpers::get_club_id_for_tournament;
+use crate::gql::error::ResultExt;
+use crate::state::AppState;
+use infra::models::TournamentRegistrationRow;
+use infra::repos::{registration_groups, tournament_registrations};
+
+use super::types::{
+    GroupSeatingPolicy, RegistrationGroup, SeatingSettings, UpdateSeatingSettingsInput,
+};
+use super::{group_code, normalize_code};
+
+/// The caller's live (not cancelled) registration for the tournament.
+async fn my_registration(
+    ctx: &Context<'_>,
+    tournament_id: Uuid,
+) -> Result<(Uuid, TournamentRegistrationRow)> {
+    let claims = ctx.data::<Claims>()?;
+    let user_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+    let state = ctx.data::<AppState>()?;
+    let registration =
+        tournament_registrations::get_by_tournament_and_user(&state.db, tournament_id, user_id)
+            .await?
+            .filter(|r| r.status != "cancelled")
+            .ok_or_else(|| {
+                async_graphql::Error::new("You are not registered for this tournament")
+            })?;
+    Ok((user_id, registration))
+}
+
+/// A group with its members and the seating its club applies.
+async fn load_group(
+    state: &AppState,
+    row: registration_groups::GroupRow,
+) -> Result<RegistrationGroup> {
+    let members = registration_groups::list_members(&state.db, row.id).await?;
+    let policy =
+        registration_groups::group_policy_for_tournament(&state.db, row.tournament_id).await?;
+    Ok(RegistrationGroup::new(
+        row,
+        members,
+        GroupSeatingPolicy::from(policy.as_str()),
+    ))
+}
+
+#[derive(Default)]
+pub struct GroupQuery;
+
+#[Object]
+impl GroupQuery {
+    /// The group the logged-in player's registration belongs to, if any.
+    async fn my_registration_group(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Option<RegistrationGroup>> {
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+        let (_, registration) = my_registration(ctx, tournament_id).await?;
+        match registration_groups::get_for_registration(&state.db, registration.id).await? {
+            Some(row) => Ok(Some(load_group(state, row).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Every group booking in a tournament, for the floor. Club managers.
+    async fn tournament_registration_groups(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Vec<RegistrationGroup>> {
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let mut groups = Vec::new();
+        for row in registration_groups::list_for_tournament(&state.db, tournament_id).await? {
+            groups.push(load_group(state, row).await?);
+        }
+        Ok(groups)
+    }
+
+    /// How the club's seat draw handles group bookings. Club managers.
+    async fn club_seating_settings(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+    ) -> Result<SeatingSettings> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+
+        let state = ctx.data::<AppState>()?;
+        let mut conn = state.db.acquire().await?;
+        let row = registration_groups::get_or_create_settings(&mut conn, club_id).await?;
+        Ok(row.into())
+    }
+}
+
+#[derive(Default)]
+pub struct GroupMutation;
+
+#[Object]
+impl GroupMutation {
+    /// Start a group with your registration. Friends join with its code.
+    async fn create_registration_group(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<RegistrationGroup> {
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+        let (user_id, registration) = my_registration(ctx, tournament_id).await?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+
+        let mut tx = state.db.begin().await?;
+        // The group is the club's row, made on the player's behalf.
+        infra::db::act_for_club(&mut tx, club_id).await?;
+        if registration_groups::get_for_registration(&mut *tx, registration.id)
+            .await?
+            .is_some()
+        {
+            return Err(async_graphql::Error::new("You are already in a group"));
+        }
+        let row =
+            registration_groups::create(&mut *tx, tournament_id, &group_code(), user_id).await?;
+        registration_groups::add_member(&mut *tx, row.id, registration.id).await?;
+        tx.commit().await?;
+
+        load_group(state, row).await
+    }
+
+    /// Join a friend's group with its code, up to the club's group size.
+    async fn join_registration_group(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        code: String,
+    ) -> Result<RegistrationGroup> {
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+        let (_, registration) = my_registration(ctx, tournament_id).await?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+
+        // The lock keeps two friends from both taking a group's last spot.
+        let mut tx = state.db.begin().await?;
+        // The club's group size applies to the player joining.
+        infra::db::act_for_club(&mut tx, club_id).await?;
+        let settings = registration_groups::get_or_create_settings(&mut tx, club_id).await?;
+        let row =
+            registration_groups::lock_by_code(&mut *tx, tournament_id, &normalize_code(&code))
+                .await?
+                .ok_or_else(|| async_graphql::Error::new("Unknown group code"))?;
+        if registration_groups::get_for_registration(&mut *tx, registration.id)
+            .await?
+            .is_some()
+        {
+            return Err(async_graphql::Error::new("You are already in a group"));
+        }
+        let members = registration_groups::list_members(&mut *tx, row.id).await?;
+        if members.len() as i32 >= settings.max_group_size {
+            return Err(async_graphql::Error::new(format!(
+                "This group is full ({} players)",
+                settings.max_group_size
+            )));
+        }
+        registration_groups::add_member(&mut *tx, row.id, registration.id).await?;
+        tx.commit().await?;
+
+        load_group(state, row).await
+    }
+
+    /// Leave your group. The last member to leave closes it.
+    async fn leave_registration_group(&self, ctx: &Context<'_>, tournament_id: ID) -> Result<bool> {
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+        let (_, registration) = my_registration(ctx, tournament_id).await?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+
+        let mut tx = state.db.begin().await?;
+        infra::db::act_for_club(&mut tx, club_id).await?;
+        let Some(group_id) = registration_groups::remove_member(&mut *tx, registration.id).await?
+        else {
+            return Ok(false);
+        };
+        registration_groups::delete_if_empty(&mut *tx, group_id).await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    /// Choose whether the seat draw keeps groups together or splits them,
+    /// and how big a group may be. Club managers.
+    async fn update_club_seating_settings(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateSeatingSettingsInput,
+    ) -> Result<SeatingSettings> {
+        let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+
+        let state = ctx.data::<AppState>()?;
+        let current =
+            registration_groups::get_or_create_settings(&mut *state.db.acquire().await?, club_id)
+                .await?;
+        let max_group_size = input.max_group_size.unwrap_or(current.max_group_size);
+        if !(2..=10).contains(&max_group_size) {
+            return Err(async_graphql::Error::new(
+                "Group size must be between 2 and 10",
+            ));
+        }
+        let group_policy = input
+            .group_policy
+            .map(|p| p.as_str().to_string())
+            .unwrap_or(current.group_policy);
+
+        let row =
+            registration_groups::upsert_settings(&state.db, club_id, &group_policy, max_group_size)
+                .await?;
+        Ok(row.into())
+    }
+}
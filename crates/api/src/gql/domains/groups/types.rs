@@ -0,0 +1,107 @@
+use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use infra::repos::registration_groups::{GroupRow, MemberRow, SeatingSettingsRow};
+
+/// What the seat draw does with a group booking.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GroupSeatingPolicy {
+    /// Seat the group at the same starting table when it fits.
+    Together,
+    /// Guarantee the group's members start at different tables (as long as
+    /// there are enough tables).
+    Apart,
+}
+
+impl GroupSeatingPolicy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            GroupSeatingPolicy::Together => "together",
+            GroupSeatingPolicy::Apart => "apart",
+        }
+    }
+}
+
+impl From<&str> for GroupSeatingPolicy {
+    fn from(s: &str) -> Self {
+        match s {
+            "apart" => GroupSeatingPolicy::Apart,
+            _ => GroupSeatingPolicy::Together,
+        }
+    }
+}
+
+/// A club's handling of group bookings.
+#[derive(SimpleObject, Clone)]
+pub struct SeatingSettings {
+    pub club_id: ID,
+    pub group_policy: GroupSeatingPolicy,
+    /// Most registrations one group may link.
+    pub max_group_size: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<SeatingSettingsRow> for SeatingSettings {
+    fn from(row: SeatingSettingsRow) -> Self {
+        Self {
+            club_id: row.club_id.into(),
+            group_policy: GroupSeatingPolicy::from(row.group_policy.as_str()),
+            max_group_size: row.max_group_size,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct RegistrationGroupMember {
+    pub registration_id: ID,
+    pub club_player_id: ID,
+    pub user_id: Option<ID>,
+    pub display_name: String,
+    pub joined_at: DateTime<Utc>,
+}
+
+impl From<MemberRow> for RegistrationGroupMember {
+    fn from(row: MemberRow) -> Self {
+        Self {
+            registration_id: row.registration_id.into(),
+            club_player_id: row.club_player_id.into(),
+            user_id: row.user_id.map(Into::into),
+            display_name: row.display_name,
+            joined_at: row.joined_at,
+        }
+    }
+}
+
+/// Friends' registrations linked for the seat draw.
+#[derive(SimpleObject, Clone)]
+pub struct RegistrationGroup {
+    pub id: ID,
+    pub tournament_id: ID,
+    /// Shared with friends so they can join.
+    pub code: String,
+    /// What the draw will do with the group, per the club's setting.
+    pub seating: GroupSeatingPolicy,
+    pub members: Vec<RegistrationGroupMember>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl RegistrationGroup {
+    pub fn new(row: GroupRow, members: Vec<MemberRow>, seating: GroupSeatingPolicy) -> Self {
+        Self {
+            id: row.id.into(),
+            tournament_id: row.tournament_id.into(),
+            code: row.code,
+            seating,
+            members: members.into_iter().map(Into::into).collect(),
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct UpdateSeatingSettingsInput {
+    pub club_id: ID,
+    pub group_policy: Option<GroupSeatingPolicy>,
+    pub max_group_size: Option<i32>,
+}
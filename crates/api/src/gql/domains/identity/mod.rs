@@ -1,3 +1,8 @@
+//! The club roster (`club_player`). Rosters and printed seat lists follow
+//! the club's `club_name_settings`, "Last, First" by default, compared with
+//! an ICU `player_name_<locale>` collation since the database collates
+//! byte-wise; `NameSettingsRow::order_by` and `printed_name` build the SQL.
+
 pub mod resolvers;
 pub mod service;
 pub mod types;
@@ -5,13 +5,13 @@ use crate::auth::jwt::Claims;
 use crate::auth::permissions::require_club_manager;
 use crate::gql::error::ResultExt;
 use crate::state::AppState;
-use infra::repos::club_players;
+use infra::repos::{club_name_settings, club_players};
 
 use super::service;
 use super::types::{
-    ArchiveClubPlayerInput, BulkRosterResult, ClaimClubPlayerInput, ClubPlayer,
+    ArchiveClubPlayerInput, BulkRosterResult, ClaimClubPlayerInput, ClubNameSettings, ClubPlayer,
     CreateClubPlayerInput, CreateClubPlayersBulkInput, FormatRosterImportInput, ImportCandidate,
-    SkippedRow, UpdateClubPlayerInput,
+    SkippedRow, UpdateClubNameSettingsInput, UpdateClubPlayerInput,
 };
 
 #[derive(Default)]
@@ -19,16 +19,29 @@ pub struct IdentityQuery;
 
 #[Object]
 impl IdentityQuery {
-    /// Full club roster (app users and non-users alike). Managers of the club only.
+    /// Full club roster (app users and non-users alike), in the club's name
+    /// order and collation. Managers of the club only.
     async fn club_players(&self, ctx: &Context<'_>, club_id: ID) -> Result<Vec<ClubPlayer>> {
         let club_uuid = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
         require_club_manager(ctx, club_uuid).await?;
 
         let state = ctx.data::<AppState>()?;
-        let rows = club_players::list_by_club(&state.db, club_uuid).await?;
+        let names = club_name_settings::get_or_create(&state.db, club_uuid).await?;
+        let rows = club_players::list_by_club_sorted(&state.db, club_uuid, &names).await?;
         Ok(rows.into_iter().map(ClubPlayer::from).collect())
     }
 
+    /// How the club orders player names on the roster and seat lists.
+    /// Managers of the club only.
+    async fn club_name_settings(&self, ctx: &Context<'_>, club_id: ID) -> Result<ClubNameSettings> {
+        let club_uuid = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_uuid).await?;
+
+        let state = ctx.data::<AppState>()?;
+        let row = club_name_settings::get_or_create(&state.db, club_uuid).await?;
+        Ok(row.into())
+    }
+
     /// The current user's roster entries across every club — the cross-club profile.
     async fn my_cross_club_profile(&self, ctx: &Context<'_>) -> Result<Vec<ClubPlayer>> {
         let state = ctx.data::<AppState>()?;
@@ -45,6 +58,28 @@ pub struct IdentityMutation;
 
 #[Object]
 impl IdentityMutation {
+    /// Set how the club orders player names: "First Last" or "Last, First",
+    /// compared with the chosen language's collation. Seat lists print names
+    /// the same way from their next generation. Managers of the club only.
+    async fn update_club_name_settings(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateClubNameSettingsInput,
+    ) -> Result<ClubNameSettings> {
+        let club_uuid = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_uuid).await?;
+
+        let state = ctx.data::<AppState>()?;
+        let row = club_name_settings::upsert(
+            &state.db,
+            club_uuid,
+            input.name_order.as_str(),
+            input.sort_locale.as_str(),
+        )
+        .await?;
+        Ok(row.into())
+    }
+
     /// Add someone the club registers who is not (yet) an app user to the roster.
     /// Managers of the club only.
     async fn create_club_player(
@@ -1,9 +1,10 @@
-use async_graphql::{ComplexObject, Context, InputObject, SimpleObject, ID};
+use async_graphql::{ComplexObject, Context, Enum, InputObject, SimpleObject, ID};
 use chrono::{DateTime, Utc};
 
 use crate::gql::domains::clubs::types::Club;
 use crate::gql::error::ResultExt;
 use crate::state::AppState;
+use infra::repos::club_name_settings::NameSettingsRow;
 
 /// A club roster entry. Exists for everyone a club has registered, whether or
 /// not they are an onboarded app user. `app_user_id` is set once claimed.
@@ -102,6 +103,101 @@ pub struct ArchiveClubPlayerInput {
     pub is_active: bool,
 }
 
+// ---------------------------------------------------------------------------
+// Name ordering and collation.
+// ---------------------------------------------------------------------------
+
+/// How the club sorts player names, and prints them on seat lists.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum PlayerNameOrder {
+    /// "Émile Dupont", sorted by given name.
+    FirstLast,
+    /// "Dupont, Émile", sorted by family name.
+    LastFirst,
+}
+
+impl From<&str> for PlayerNameOrder {
+    fn from(s: &str) -> Self {
+        match s {
+            "first_last" => PlayerNameOrder::FirstLast,
+            _ => PlayerNameOrder::LastFirst,
+        }
+    }
+}
+
+impl PlayerNameOrder {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PlayerNameOrder::FirstLast => "first_last",
+            PlayerNameOrder::LastFirst => "last_first",
+        }
+    }
+}
+
+/// The language whose alphabet rules compare names, so accented and
+/// non-Latin names sort where a reader expects them.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum NameSortLocale {
+    /// Language-neutral Unicode ordering.
+    Unicode,
+    English,
+    French,
+    Dutch,
+    German,
+}
+
+impl From<&str> for NameSortLocale {
+    fn from(s: &str) -> Self {
+        match s {
+            "en" => NameSortLocale::English,
+            "fr" => NameSortLocale::French,
+            "nl" => NameSortLocale::Dutch,
+            "de" => NameSortLocale::German,
+            _ => NameSortLocale::Unicode,
+        }
+    }
+}
+
+impl NameSortLocale {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NameSortLocale::Unicode => "und",
+            NameSortLocale::English => "en",
+            NameSortLocale::French => "fr",
+            NameSortLocale::Dutch => "nl",
+            NameSortLocale::German => "de",
+        }
+    }
+}
+
+/// How a club orders player names on the roster and printed seat lists.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct ClubNameSettings {
+    pub club_id: ID,
+    pub name_order: PlayerNameOrder,
+    pub sort_locale: NameSortLocale,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<NameSettingsRow> for ClubNameSettings {
+    fn from(row: NameSettingsRow) -> Self {
+        Self {
+            club_id: row.club_id.into(),
+            name_order: PlayerNameOrder::from(row.name_order.as_str()),
+            sort_locale: NameSortLocale::from(row.sort_locale.as_str()),
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+/// Replaces the club's name settings.
+#[derive(InputObject)]
+pub struct UpdateClubNameSettingsInput {
+    pub club_id: ID,
+    pub name_order: PlayerNameOrder,
+    pub sort_locale: NameSortLocale,
+}
+
 // ---------------------------------------------------------------------------
 // Bulk import (Excel/CSV) — AI formatting + bulk roster creation.
 // ---------------------------------------------------------------------------
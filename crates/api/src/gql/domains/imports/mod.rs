@@ -0,0 +1,6 @@
+pub mod parser;
+pub mod resolvers;
+pub mod service;
+pub mod types;
+
+pub use resolvers::{ImportMutation, ImportQuery};
@@ -0,0 +1,333 @@
+//! Parse results exports from external tournament software (The Tournament
+//! Director, spreadsheet exports, ...) into one row per finisher.
+//!
+//! Exports differ in layout and column naming, so columns are recognised by
+//! a list of common aliases (case- and punctuation-insensitive) rather than a
+//! fixed schema. CSV files may be comma- or semicolon-separated. XML files are
+//! scanned for repeated elements whose attributes or text-only children carry
+//! at least a finishing position and a player name.
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceFormat {
+    Csv,
+    Xml,
+}
+
+/// One finisher as read from the export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedRow {
+    /// 1-based row number in the source, for error messages.
+    pub row_number: usize,
+    pub final_position: i32,
+    pub display_name: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub email: Option<String>,
+    pub prize_cents: i64,
+    pub rebuys: i32,
+    pub addons: i32,
+}
+
+const POSITION: &[&str] = &[
+    "place",
+    "position",
+    "pos",
+    "rank",
+    "finish",
+    "finishposition",
+    "finalposition",
+];
+const NAME: &[&str] = &[
+    "name",
+    "player",
+    "playername",
+    "fullname",
+    "displayname",
+    "nickname",
+];
+const FIRST_NAME: &[&str] = &["firstname", "first", "givenname"];
+const LAST_NAME: &[&str] = &["lastname", "last", "surname", "familyname"];
+const EMAIL: &[&str] = &["email", "emailaddress", "mail"];
+const PRIZE: &[&str] = &["winnings", "prize", "prizemoney", "payout", "won", "cashed"];
+const REBUYS: &[&str] = &["rebuys", "rebuy", "numrebuys"];
+const ADDONS: &[&str] = &["addons", "addon", "numaddons"];
+
+/// Lowercase and drop everything but letters and digits, so "Add-ons",
+/// "add_ons" and "AddOns" all compare equal.
+fn normalize_key(key: &str) -> String {
+    key.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Parse an export. Errors name the offending row so the manager can fix the
+/// file; an export without any finisher is an error too.
+pub fn parse(format: SourceFormat, content: &str) -> Result<Vec<ParsedRow>, String> {
+    let records = match format {
+        SourceFormat::Csv => csv_records(content)?,
+        SourceFormat::Xml => xml_records(content)?,
+    };
+
+    let mut rows = Vec::new();
+    for (index, record) in records.into_iter().enumerate() {
+        if record.values().all(|v| v.trim().is_empty()) {
+            continue;
+        }
+        rows.push(to_row(index + 1, &record)?);
+    }
+    if rows.is_empty() {
+        return Err("No results found in the file".to_string());
+    }
+    Ok(rows)
+}
+
+fn csv_records(content: &str) -> Result<Vec<HashMap<String, String>>, String> {
+    let content = content.trim_start_matches('\u{feff}');
+    // European spreadsheet exports use ';' so ',' can be the decimal mark.
+    let header = content.lines().next().unwrap_or_default();
+    let delimiter = if header.matches(';').count() > header.matches(',').count() {
+        b';'
+    } else {
+        b','
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(content.as_bytes());
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|e| format!("Invalid CSV header: {e}"))?
+        .iter()
+        .map(normalize_key)
+        .collect();
+
+    reader
+        .records()
+        .map(|record| {
+            let record = record.map_err(|e| format!("Invalid CSV: {e}"))?;
+            Ok(headers
+                .iter()
+                .cloned()
+                .zip(record.iter().map(str::to_string))
+                .collect())
+        })
+        .collect()
+}
+
+fn xml_records(content: &str) -> Result<Vec<HashMap<String, String>>, String> {
+    let doc = roxmltree::Document::parse(content).map_err(|e| format!("Invalid XML: {e}"))?;
+
+    let records = doc
+        .descendants()
+        .filter(|node| node.is_element())
+        .filter_map(|node| {
+            let mut fields: HashMap<String, String> = node
+                .attributes()
+                .map(|a| (normalize_key(a.name()), a.value().to_string()))
+                .collect();
+            for child in node.children().filter(|c| c.is_element()) {
+                if child.children().all(|c| c.is_text()) {
+                    fields.insert(
+                        normalize_key(child.tag_name().name()),
+                        child.text().unwrap_or_default().to_string(),
+                    );
+                }
+            }
+            let has_name = [NAME, FIRST_NAME, LAST_NAME]
+                .iter()
+                .any(|aliases| lookup(&fields, aliases).is_some());
+            (lookup(&fields, POSITION).is_some() && has_name).then_some(fields)
+        })
+        .collect();
+    Ok(records)
+}
+
+fn lookup<'a>(fields: &'a HashMap<String, String>, aliases: &[&str]) -> Option<&'a str> {
+    aliases
+        .iter()
+        .find_map(|alias| fields.get(*alias))
+        .map(|v| v.trim())
+        .filter(|v| !v.is_empty())
+}
+
+fn to_row(row_number: usize, fields: &HashMap<String, String>) -> Result<ParsedRow, String> {
+    let position = lookup(fields, POSITION)
+        .ok_or_else(|| format!("Row {row_number}: missing finishing position"))?;
+    let final_position = parse_position(position)
+        .ok_or_else(|| format!("Row {row_number}: invalid finishing position '{position}'"))?;
+
+    let first_name = lookup(fields, FIRST_NAME).map(str::to_string);
+    let last_name = lookup(fields, LAST_NAME).map(str::to_string);
+    let display_name = match lookup(fields, NAME) {
+        Some(name) => name.to_string(),
+        None => format!(
+            "{} {}",
+            first_name.as_deref().unwrap_or_default(),
+            last_name.as_deref().unwrap_or_default()
+        )
+        .trim()
+        .to_string(),
+    };
+    if display_name.is_empty() {
+        return Err(format!("Row {row_number}: missing player name"));
+    }
+
+    let prize_cents = match lookup(fields, PRIZE) {
+        Some(amount) => parse_money(amount)
+            .ok_or_else(|| format!("Row {row_number}: invalid prize amount '{amount}'"))?,
+        None => 0,
+    };
+    let count = |aliases: &[&str], what: &str| -> Result<i32, String> {
+        match lookup(fields, aliases) {
+            Some(v) => v
+                .parse::<i32>()
+                .ok()
+                .filter(|n| *n >= 0)
+                .ok_or_else(|| format!("Row {row_number}: invalid {what} count '{v}'")),
+            None => Ok(0),
+        }
+    };
+
+    Ok(ParsedRow {
+        row_number,
+        final_position,
+        display_name,
+        first_name,
+        last_name,
+        email: lookup(fields, EMAIL).map(str::to_string),
+        prize_cents,
+        rebuys: count(REBUYS, "rebuy")?,
+        addons: count(ADDONS, "add-on")?,
+    })
+}
+
+/// "1", "1st", "2nd", "3." → the number.
+fn parse_position(value: &str) -> Option<i32> {
+    let digits: String = value.chars().take_while(char::is_ascii_digit).collect();
+    digits.parse().ok().filter(|p| *p > 0)
+}
+
+/// Parse a money amount into cents. Accepts currency symbols and both
+/// "1,234.50" and "1.234,50": when both separators appear the last one is the
+/// decimal mark; a lone ',' is decimal only when followed by at most two
+/// digits, a lone '.' unless it repeats.
+pub fn parse_money(value: &str) -> Option<i64> {
+    let cleaned: String = value
+        .chars()
+        .filter(|c| c.is_ascii_digit() || *c == '.' || *c == ',' || *c == '-')
+        .collect();
+    if cleaned.is_empty() || cleaned.starts_with('-') {
+        return None;
+    }
+
+    let last_dot = cleaned.rfind('.');
+    let last_comma = cleaned.rfind(',');
+    let decimal_mark = match (last_dot, last_comma) {
+        (Some(d), Some(c)) => Some(if d > c { '.' } else { ',' }),
+        (Some(_), None) => (cleaned.matches('.').count() == 1).then_some('.'),
+        (None, Some(c)) => {
+            (cleaned.matches(',').count() == 1 && cleaned.len() - c - 1 <= 2).then_some(',')
+        }
+        (None, None) => None,
+    };
+
+    let (whole, fraction) = match decimal_mark.and_then(|m| cleaned.rsplit_once(m)) {
+        Some((whole, fraction)) => (whole.to_string(), fraction.to_string()),
+        None => (cleaned.clone(), String::new()),
+    };
+    let whole: String = whole.chars().filter(char::is_ascii_digit).collect();
+    if fraction.len() > 2 || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let units: i64 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().ok()?
+    };
+    let cents: i64 = format!("{fraction:0<2}").parse().ok()?;
+    units.checked_mul(100)?.checked_add(cents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_money_in_either_locale() {
+        assert_eq!(parse_money("€1,234.50"), Some(123_450));
+        assert_eq!(parse_money("1.234,50 €"), Some(123_450));
+        assert_eq!(parse_money("250"), Some(25_000));
+        assert_eq!(parse_money("12,5"), Some(1_250));
+        assert_eq!(parse_money("1,500"), Some(150_000));
+        assert_eq!(parse_money("1.500.000"), Some(150_000_000));
+        assert_eq!(parse_money("$0.99"), Some(99));
+        assert_eq!(parse_money("-5"), None);
+        assert_eq!(parse_money("n/a"), None);
+    }
+
+    #[test]
+    fn parses_csv_with_aliased_columns() {
+        let csv = "Place;Player Name;E-mail;Winnings;Rebuys;Add-Ons\n\
+                   1st;Alice Martin;alice@example.com;€ 1.200,00;1;1\n\
+                   2nd;Bob;;600;0;1\n\
+                   ;;;;;\n\
+                   3rd;Chloé Dubois;;;;\n";
+        let rows = parse(SourceFormat::Csv, csv).unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].final_position, 1);
+        assert_eq!(rows[0].display_name, "Alice Martin");
+        assert_eq!(rows[0].email.as_deref(), Some("alice@example.com"));
+        assert_eq!(rows[0].prize_cents, 120_000);
+        assert_eq!((rows[0].rebuys, rows[0].addons), (1, 1));
+        assert_eq!(rows[1].email, None);
+        assert_eq!(rows[2].prize_cents, 0);
+        assert_eq!(rows[2].row_number, 4);
+    }
+
+    #[test]
+    fn composes_name_from_first_and_last() {
+        let csv = "Rank,First Name,Last Name,Prize\n1,Alice,Martin,100\n";
+        let rows = parse(SourceFormat::Csv, csv).unwrap();
+        assert_eq!(rows[0].display_name, "Alice Martin");
+        assert_eq!(rows[0].first_name.as_deref(), Some("Alice"));
+        assert_eq!(rows[0].last_name.as_deref(), Some("Martin"));
+    }
+
+    #[test]
+    fn parses_xml_attributes_and_children() {
+        let xml = r#"<?xml version="1.0"?>
+            <Tournament name="Friday Deepstack">
+              <Players>
+                <Player place="1" name="Alice Martin" email="alice@example.com" winnings="1200.00"/>
+                <Player>
+                  <Place>2</Place>
+                  <Nickname>Bob</Nickname>
+                  <Winnings>600</Winnings>
+                  <AddOns>1</AddOns>
+                </Player>
+              </Players>
+            </Tournament>"#;
+        let rows = parse(SourceFormat::Xml, xml).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].display_name, "Alice Martin");
+        assert_eq!(rows[0].prize_cents, 120_000);
+        assert_eq!(rows[1].display_name, "Bob");
+        assert_eq!(rows[1].addons, 1);
+    }
+
+    #[test]
+    fn reports_the_offending_row() {
+        let csv = "Place,Name\n1,Alice\nfirst,Bob\n";
+        let err = parse(SourceFormat::Csv, csv).unwrap_err();
+        assert!(err.starts_with("Row 2:"), "{err}");
+
+        let err = parse(SourceFormat::Csv, "Place,Name\n").unwrap_err();
+        assert_eq!(err, "No results found in the file");
+    }
+}
@@ -0,0 +1,114 @@
+use async_graphql::{Context, Object, Result};
+use uuid::Uuid;
+
+use crate::auth::permissions::require_club_manager;
+use crate::gql::error::ResultExt;
+use crate::gql::scalars::Money;
+use crate::state::AppState;
+
+use super::parser;
+use super::service::{self, ImportParams};
+use super::types::{
+    ImportPlayerMatch, ImportTournamentResultsInput, ImportTournamentResultsResponse,
+    PreviewResultsImportInput, ResultsImportPreview, ResultsImportRow,
+};
+
+#[derive(Default)]
+pub struct ImportQuery;
+
+#[Object]
+impl ImportQuery {
+    /// Parse a results export and show how each row would be matched to the
+    /// club's players, without writing anything. Managers of the club only.
+    async fn preview_results_import(
+        &self,
+        ctx: &Context<'_>,
+        input: PreviewResultsImportInput,
+    ) -> Result<ResultsImportPreview> {
+        let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+
+        let state = ctx.data::<AppState>()?;
+        let rows = parser::parse(input.format.into(), &input.content)
+            .map_err(async_graphql::Error::new)?;
+        service::validate_rows(&rows)?;
+
+        let mut conn = state.db.acquire().await?;
+        let resolved = service::resolve_rows(&mut conn, club_id, rows).await?;
+
+        let total_prize_cents = resolved.iter().map(|r| r.row.prize_cents).sum::<i64>();
+        let new_players = resolved
+            .iter()
+            .filter(|r| r.player_match == ImportPlayerMatch::NewPlayer)
+            .count() as i32;
+        Ok(ResultsImportPreview {
+            rows: resolved
+                .into_iter()
+                .map(|r| ResultsImportRow {
+                    row_number: r.row.row_number as i32,
+                    final_position: r.row.final_position,
+                    display_name: r.row.display_name,
+                    email: r.row.email,
+                    prize_cents: Money(r.row.prize_cents),
+                    rebuys: r.row.rebuys,
+                    addons: r.row.addons,
+                    player_match: r.player_match,
+                    club_player_id: r.club_player_id.map(Into::into),
+                })
+                .collect(),
+            total_prize_cents: Money(total_prize_cents),
+            new_players,
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct ImportMutation;
+
+#[Object]
+impl ImportMutation {
+    /// Import a finished tournament from another tournament program's export
+    /// (CSV or XML): creates the tournament, missing roster entries, entries
+    /// and results, and scores it so the club's leaderboard history carries
+    /// over. Managers of the club only.
+    async fn import_tournament_results(
+        &self,
+        ctx: &Context<'_>,
+        input: ImportTournamentResultsInput,
+    ) -> Result<ImportTournamentResultsResponse> {
+        let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid manager ID")?;
+
+        let name = input.name.trim().to_string();
+        if name.is_empty() {
+            return Err(async_graphql::Error::new("Tournament name cannot be empty"));
+        }
+
+        let state = ctx.data::<AppState>()?;
+        let rows = parser::parse(input.format.into(), &input.content)
+            .map_err(async_graphql::Error::new)?;
+
+        let output = service::import_results(
+            &state.db,
+            ImportParams {
+                club_id,
+                name,
+                start_time: input.start_time,
+                end_time: input.end_time,
+                buy_in_cents: input.buy_in_cents.cents(),
+                rake_cents: input.rake_cents.map(Money::cents),
+                addon_price_cents: input.addon_price_cents.map(Money::cents),
+                recorded_by: manager_id,
+            },
+            rows,
+        )
+        .await?;
+
+        Ok(ImportTournamentResultsResponse {
+            tournament: output.tournament.into(),
+            results: output.results.into_iter().map(Into::into).collect(),
+            new_players: output.new_players,
+        })
+    }
+}
@@ -0,0 +1,358 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::gql::error::GqlError;
+use infra::models::{ClubPlayerRow, TournamentResultRow, TournamentRow};
+use infra::repos::{
+    club_players, tournament_entries, tournament_entries::CreateTournamentEntry,
+    tournament_payouts, tournament_registrations,
+    tournament_registrations::CreateTournamentRegistration, tournament_results,
+    tournament_results::CreateTournamentResult, tournaments, tournaments::CreateTournamentData,
+    users,
+};
+
+use super::parser::ParsedRow;
+use super::types::ImportPlayerMatch;
+
+/// A parsed row together with the player it maps to.
+pub struct ResolvedRow {
+    pub row: ParsedRow,
+    pub player_match: ImportPlayerMatch,
+    /// Existing roster entry, when matched.
+    pub club_player_id: Option<Uuid>,
+    /// App user matched by email, when any.
+    pub app_user_id: Option<Uuid>,
+}
+
+/// Tournament details that the export doesn't carry (parsed by the resolver).
+pub struct ImportParams {
+    pub club_id: Uuid,
+    pub name: String,
+    pub start_time: DateTime<Utc>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub buy_in_cents: i64,
+    pub rake_cents: Option<i64>,
+    pub addon_price_cents: Option<i64>,
+    pub recorded_by: Uuid,
+}
+
+pub struct ImportOutput {
+    pub tournament: TournamentRow,
+    pub results: Vec<TournamentResultRow>,
+    pub new_players: i32,
+}
+
+/// Reject files that can't describe a single tournament: repeated finishing
+/// positions, or the same player listed twice.
+pub fn validate_rows(rows: &[ParsedRow]) -> Result<(), GqlError> {
+    let mut positions: HashMap<i32, usize> = HashMap::new();
+    let mut players: HashMap<String, usize> = HashMap::new();
+    for row in rows {
+        if let Some(first) = positions.insert(row.final_position, row.row_number) {
+            return Err(GqlError::new(format!(
+                "Rows {first} and {} both finished in position {}",
+                row.row_number, row.final_position
+            )));
+        }
+        let key = row
+            .email
+            .as_deref()
+            .map(|e| format!("email:{}", e.to_lowercase()))
+            .unwrap_or_else(|| format!("name:{}", row.display_name.to_lowercase()));
+        if let Some(first) = players.insert(key, row.row_number) {
+            return Err(GqlError::new(format!(
+                "Rows {first} and {} are the same player",
+                row.row_number
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Map each row to the club's players: an app user by email first, then a
+/// unique case-insensitive roster name, otherwise a new player.
+pub async fn resolve_rows(
+    conn: &mut PgConnection,
+    club_id: Uuid,
+    rows: Vec<ParsedRow>,
+) -> Result<Vec<ResolvedRow>, GqlError> {
+    let mut roster_by_name: HashMap<String, Vec<ClubPlayerRow>> = HashMap::new();
+    for entry in club_players::list_by_club(&mut *conn, club_id).await? {
+        roster_by_name
+            .entry(entry.display_name.to_lowercase())
+            .or_default()
+            .push(entry);
+    }
+
+    let mut resolved = Vec::with_capacity(rows.len());
+    for row in rows {
+        let user_id = match row.email.as_deref() {
+            Some(email) => users::id_by_email(&mut *conn, email).await?,
+            None => None,
+        };
+        let resolution = if let Some(user_id) = user_id {
+            let entry =
+                club_players::find_by_club_and_app_user(&mut *conn, club_id, user_id).await?;
+            (
+                ImportPlayerMatch::AppUser,
+                entry.map(|e| e.id),
+                Some(user_id),
+            )
+        } else {
+            match roster_by_name
+                .get(&row.display_name.to_lowercase())
+                .map(Vec::as_slice)
+            {
+                Some([entry]) => (
+                    ImportPlayerMatch::RosterName,
+                    Some(entry.id),
+                    entry.app_user_id,
+                ),
+                Some([_, _, ..]) => (ImportPlayerMatch::Ambiguous, None, None),
+                _ => (ImportPlayerMatch::NewPlayer, None, None),
+            }
+        };
+        let (player_match, club_player_id, app_user_id) = resolution;
+        resolved.push(ResolvedRow {
+            row,
+            player_match,
+            club_player_id,
+            app_user_id,
+        });
+    }
+
+    // Two rows landing on one roster entry would register them twice.
+    let mut seen: HashMap<Uuid, usize> = HashMap::new();
+    for r in &resolved {
+        if let Some(id) = r.club_player_id {
+            if let Some(first) = seen.insert(id, r.row.row_number) {
+                return Err(GqlError::new(format!(
+                    "Rows {first} and {} match the same roster entry",
+                    r.row.row_number
+                )));
+            }
+        }
+    }
+
+    Ok(resolved)
+}
+
+/// Create a finished tournament from an export: the tournament, any missing
+/// roster entries, registrations, buy-in/rebuy/add-on entries, results and the
+/// paid positions, all in one transaction. Finishing the tournament lets the
+/// points trigger score it like any other, so the club's leaderboards pick the
+/// history up.
+pub async fn import_results(
+    pool: &PgPool,
+    params: ImportParams,
+    rows: Vec<ParsedRow>,
+) -> Result<ImportOutput, GqlError> {
+    validate_rows(&rows)?;
+    if params.buy_in_cents < 0 {
+        return Err(GqlError::new("Buy-in cannot be negative"));
+    }
+    if rows.iter().any(|r| r.addons > 0) && params.addon_price_cents.is_none() {
+        return Err(GqlError::new(
+            "The file has add-ons: an add-on price is required",
+        ));
+    }
+
+    let mut tx = pool.begin().await?;
+
+    if tournaments::exists_by_club_name_and_start(
+        &mut *tx,
+        params.club_id,
+        &params.name,
+        params.start_time,
+    )
+    .await?
+    {
+        return Err(GqlError::new(
+            "A tournament with this name and start time already exists",
+        ));
+    }
+
+    let resolved = resolve_rows(&mut tx, params.club_id, rows).await?;
+    if let Some(r) = resolved
+        .iter()
+        .find(|r| r.player_match == ImportPlayerMatch::Ambiguous)
+    {
+        return Err(GqlError::new(format!(
+            "Row {}: several roster entries are named '{}'; add an email to pick one",
+            r.row.row_number, r.row.display_name
+        )));
+    }
+
+    let tournament = tournaments::create(
+        &mut *tx,
+        CreateTournamentData {
+            club_id: params.club_id,
+            name: params.name,
+            description: None,
+            start_time: params.start_time,
+            end_time: params.end_time,
+            buy_in_cents: params.buy_in_cents,
+            rake_cents: params.rake_cents,
+            seat_cap: None,
+            starting_stack: None,
+            early_bird_bonus_chips: None,
+            level_two_bonus_chips: None,
+            voucher_value_cents: None,
+            rebuy_max: None,
+            addon_chips: None,
+            addon_price_cents: params.addon_price_cents,
+            late_registration_level: None,
+            bounty_type: None,
+            bounty_amount_cents: None,
+            leaderboard_config_id: None,
+            chip_race_rule: None,
+            visibility: None,
+            early_bird_buy_in_cents: None,
+            early_bird_until: None,
+            series_id: None,
+            flight_label: None,
+            is_final_day: false,
+            points_multiplier: None,
+            seats_per_table: None,
+        },
+    )
+    .await?;
+
+    let mut new_players = 0;
+    for r in &resolved {
+        let club_player_id = match r.club_player_id {
+            Some(id) => id,
+            None => {
+                if r.app_user_id.is_none() {
+                    new_players += 1;
+                }
+                club_players::create(
+                    &mut *tx,
+                    params.club_id,
+                    &r.row.display_name,
+                    r.row.first_name.as_deref(),
+                    r.row.last_name.as_deref(),
+                    r.app_user_id,
+                )
+                .await?
+                .id
+            }
+        };
+
+        tournament_registrations::create(
+            &mut *tx,
+            CreateTournamentRegistration {
+                id: None,
+                tournament_id: tournament.id,
+                user_id: r.app_user_id,
+                club_player_id: Some(club_player_id),
+                notes: None,
+                // The winner was never knocked out.
+                status: Some(
+                    if r.row.final_position == 1 {
+                        "seated"
+                    } else {
+                        "busted"
+                    }
+                    .into(),
+                ),
+                invite_id: None,
+            },
+        )
+        .await?;
+
+        let entries = std::iter::once(("initial", params.buy_in_cents))
+            .chain(std::iter::repeat_n(
+                ("rebuy", params.buy_in_cents),
+                r.row.rebuys as usize,
+            ))
+            .chain(std::iter::repeat_n(
+                ("addon", params.addon_price_cents.unwrap_or(0)),
+                r.row.addons as usize,
+            ));
+        for (entry_type, amount_cents) in entries {
+            tournament_entries::create(
+                &mut *tx,
+                CreateTournamentEntry {
+                    tournament_id: tournament.id,
+                    user_id: r.app_user_id,
+                    club_player_id: Some(club_player_id),
+                    entry_type: entry_type.to_string(),
+                    amount_cents,
+                    recorded_by: Some(params.recorded_by),
+                    notes: Some("Imported".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        }
+
+        tournament_results::create(
+            &mut *tx,
+            CreateTournamentResult {
+                tournament_id: tournament.id,
+                user_id: r.app_user_id,
+                club_player_id: Some(club_player_id),
+                final_position: r.row.final_position,
+                prize_cents: r.row.prize_cents,
+                notes: None,
+            },
+        )
+        .await?;
+    }
+
+    // The entries trigger has set the prize pool; record who it went to.
+    let prize_pool = tournament_payouts::get_by_tournament(&mut *tx, tournament.id)
+        .await?
+        .map(|p| p.total_prize_pool)
+        .unwrap_or(0);
+    let mut paid: Vec<&ParsedRow> = resolved
+        .iter()
+        .map(|r| &r.row)
+        .filter(|row| row.prize_cents > 0)
+        .collect();
+    paid.sort_by_key(|row| row.final_position);
+    let payout_positions: Vec<serde_json::Value> = paid
+        .iter()
+        .map(|row| {
+            let percentage = if prize_pool > 0 {
+                (row.prize_cents as f64 * 10000.0 / prize_pool as f64).round() / 100.0
+            } else {
+                0.0
+            };
+            serde_json::json!({
+                "position": row.final_position,
+                "amount_cents": row.prize_cents,
+                "percentage": percentage,
+            })
+        })
+        .collect();
+    tournament_payouts::update_positions(
+        &mut *tx,
+        tournament.id,
+        serde_json::Value::Array(payout_positions),
+    )
+    .await?;
+
+    let tournament = tournaments::update_live_status(
+        &mut *tx,
+        tournament.id,
+        tournaments::TournamentLiveStatus::Finished,
+    )
+    .await?
+    .ok_or_else(|| GqlError::new("Tournament not found"))?;
+
+    // Re-read the results so the response carries the trigger-computed points.
+    let results = tournament_results::list_by_tournament(&mut *tx, tournament.id).await?;
+
+    tx.commit().await?;
+
+    Ok(ImportOutput {
+        tournament,
+        results,
+        new_players,
+    })
+}
@@ -0,0 +1,94 @@
+use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use crate::gql::domains::results::types::TournamentResult;
+use crate::gql::scalars::Money;
+use crate::gql::types::Tournament;
+
+use super::parser::SourceFormat;
+
+/// File format of a results export.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ResultsImportFormat {
+    Csv,
+    Xml,
+}
+
+impl From<ResultsImportFormat> for SourceFormat {
+    fn from(format: ResultsImportFormat) -> Self {
+        match format {
+            ResultsImportFormat::Csv => SourceFormat::Csv,
+            ResultsImportFormat::Xml => SourceFormat::Xml,
+        }
+    }
+}
+
+/// How an imported row was matched to the club's players.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ImportPlayerMatch {
+    /// The email belongs to an app user; their roster entry is used (and
+    /// created if they have none in this club yet).
+    AppUser,
+    /// The name matches exactly one roster entry (case-insensitive).
+    RosterName,
+    /// The name matches several roster entries. Add an email to the row to
+    /// pick one; the import is refused while any row is ambiguous.
+    Ambiguous,
+    /// No match: a new roster entry will be created.
+    NewPlayer,
+}
+
+#[derive(InputObject)]
+pub struct PreviewResultsImportInput {
+    pub club_id: ID,
+    pub format: ResultsImportFormat,
+    /// The exported file's contents.
+    pub content: String,
+}
+
+#[derive(InputObject)]
+pub struct ImportTournamentResultsInput {
+    pub club_id: ID,
+    pub format: ResultsImportFormat,
+    /// The exported file's contents.
+    pub content: String,
+    pub name: String,
+    pub start_time: DateTime<Utc>,
+    pub end_time: Option<DateTime<Utc>>,
+    /// Buy-in, also charged for every rebuy.
+    pub buy_in_cents: Money,
+    pub rake_cents: Option<Money>,
+    /// Required when any row has add-ons.
+    pub addon_price_cents: Option<Money>,
+}
+
+/// One parsed row and the player it resolves to.
+#[derive(SimpleObject, Clone)]
+pub struct ResultsImportRow {
+    /// Row number in the source file (data rows, 1-based).
+    pub row_number: i32,
+    pub final_position: i32,
+    pub display_name: String,
+    pub email: Option<String>,
+    pub prize_cents: Money,
+    pub rebuys: i32,
+    pub addons: i32,
+    pub player_match: ImportPlayerMatch,
+    /// The existing roster entry the row maps to, if any.
+    pub club_player_id: Option<ID>,
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct ResultsImportPreview {
+    pub rows: Vec<ResultsImportRow>,
+    pub total_prize_cents: Money,
+    /// Rows that would create a new roster entry.
+    pub new_players: i32,
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct ImportTournamentResultsResponse {
+    pub tournament: Tournament,
+    pub results: Vec<TournamentResult>,
+    pub new_players: i32,
+}
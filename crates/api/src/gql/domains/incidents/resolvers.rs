@@ -0,0 +1,355 @@
+use async_graphql::{Context, Object, Result, ID};
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::auth::jwt::Claims;
+use crate::auth::permissions::{require_club_manager, viewer_manages_club};
+use crate::gql::common::helpers::get_club_id_for_tournament;
+use crate::gql::domains::staff::types::StaffRole;
+use crate::gql::error::ResultExt;
+use crate::state::AppState;
+use infra::db::RowScope;
+use infra::repos::{
+    club_players, club_staff, club_tables, incidents, incidents::CreateIncident, player_exclusions,
+    player_exclusions::CreatePlayerExclusion,
+};
+
+use super::types::{
+    FileIncidentInput, Incident, IncidentAttachment, IncidentAttachmentInput, IncidentOutcome,
+    IncidentStatus, PlayerExclusion, ReviewIncidentInput, UpdateIncidentFollowUpInput,
+};
+
+/// Incidents are filed by the floor: a manager of the club, or an active
+/// floor-staff member whose roster entry is linked to the caller's account.
+/// Returns the caller's user ID.
+async fn require_floor_staff(ctx: &Context<'_>, club_id: Uuid) -> Result<Uuid> {
+    let claims = ctx.data::<Claims>()?;
+    let caller = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+    if viewer_manages_club(ctx, club_id).await {
+        return Ok(caller);
+    }
+    let state = ctx.data::<AppState>()?;
+    club_staff::find_active_for_user(&state.db, club_id, caller, StaffRole::Floor.as_db())
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Only floor staff can file incidents"))?;
+    Ok(caller)
+}
+
+async fn get_incident(state: &AppState, incident_id: &ID) -> Result<incidents::IncidentRow> {
+    let id = Uuid::parse_str(incident_id.as_str()).gql_err("Invalid incident ID")?;
+    incidents::get_by_id(&state.db, id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Incident not found"))
+}
+
+fn validate_attachment(input: &IncidentAttachmentInput) -> Result<()> {
+    let url = input.url.trim();
+    if !(url.starts_with("https://") || url.starts_with("http://")) {
+        return Err(async_graphql::Error::new(
+            "Attachment URL must be an http(s) URL",
+        ));
+    }
+    Ok(())
+}
+
+#[derive(Default)]
+pub struct IncidentQuery;
+
+#[Object]
+impl IncidentQuery {
+    /// A single incident (managers only).
+    async fn incident(&self, ctx: &Context<'_>, id: ID) -> Result<Incident> {
+        let state = ctx.data::<AppState>()?;
+        let row = get_incident(state, &id).await?;
+        require_club_manager(ctx, row.club_id).await?;
+        Ok(row.into())
+    }
+
+    /// A club's incidents, newest first, optionally for one tournament
+    /// (managers only).
+    async fn incidents(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+        tournament_id: Option<ID>,
+    ) -> Result<Vec<Incident>> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        let tournament_id = tournament_id
+            .map(|id| Uuid::parse_str(id.as_str()))
+            .transpose()
+            .gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+
+        let mut tx = state.scoped(RowScope::club(club_id)).await?;
+        let rows = incidents::list_by_club(&mut *tx, club_id, tournament_id).await?;
+        tx.commit().await?;
+        Ok(rows.into_iter().map(Incident::from).collect())
+    }
+
+    /// Incidents awaiting review, most severe first (managers only).
+    async fn incident_review_queue(&self, ctx: &Context<'_>, club_id: ID) -> Result<Vec<Incident>> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let mut tx = state.scoped(RowScope::club(club_id)).await?;
+        let rows = incidents::review_queue(&mut *tx, club_id).await?;
+        tx.commit().await?;
+        Ok(rows.into_iter().map(Incident::from).collect())
+    }
+
+    /// A club's exclusions, newest first (managers only).
+    async fn player_exclusions(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+        #[graphql(default = true)] active_only: bool,
+    ) -> Result<Vec<PlayerExclusion>> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let mut tx = state.scoped(RowScope::club(club_id)).await?;
+        let rows = player_exclusions::list_by_club(&mut *tx, club_id, active_only).await?;
+        tx.commit().await?;
+        Ok(rows.into_iter().map(PlayerExclusion::from).collect())
+    }
+}
+
+#[derive(Default)]
+pub struct IncidentMutation;
+
+#[Object]
+impl IncidentMutation {
+    /// File an incident report (floor staff and managers).
+    async fn file_incident(&self, ctx: &Context<'_>, input: FileIncidentInput) -> Result<Incident> {
+        let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
+        let reporter = require_floor_staff(ctx, club_id).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let description = input.description.trim();
+        if description.is_empty() {
+            return Err(async_graphql::Error::new("A description is required"));
+        }
+
+        let tournament_id = match input.tournament_id {
+            Some(id) => {
+                let id = Uuid::parse_str(id.as_str()).gql_err("Invalid tournament ID")?;
+                if get_club_id_for_tournament(&state.db, id).await? != club_id {
+                    return Err(async_graphql::Error::new(
+                        "Tournament belongs to a different club",
+                    ));
+                }
+                Some(id)
+            }
+            None => None,
+        };
+        let club_table_id = match input.club_table_id {
+            Some(id) => {
+                let id = Uuid::parse_str(id.as_str()).gql_err("Invalid table ID")?;
+                let table = club_tables::get_by_id(&state.db, id)
+                    .await?
+                    .ok_or_else(|| async_graphql::Error::new("Table not found"))?;
+                if table.club_id != club_id {
+                    return Err(async_graphql::Error::new(
+                        "Table belongs to a different club",
+                    ));
+                }
+                Some(id)
+            }
+            None => None,
+        };
+
+        let mut player_ids = Vec::with_capacity(input.involved_club_player_ids.len());
+        for id in &input.involved_club_player_ids {
+            let id = Uuid::parse_str(id.as_str()).gql_err("Invalid club player ID")?;
+            let player = club_players::get_by_id(&state.db, id)
+                .await?
+                .ok_or_else(|| async_graphql::Error::new("Player not found"))?;
+            if player.club_id != club_id {
+                return Err(async_graphql::Error::new(
+                    "Player is not on this club's roster",
+                ));
+            }
+            player_ids.push(id);
+        }
+        for attachment in &input.attachments {
+            validate_attachment(attachment)?;
+        }
+
+        let mut tx = state.db.begin().await?;
+        let row = incidents::create(
+            &mut *tx,
+            CreateIncident {
+                club_id,
+                tournament_id,
+                club_table_id,
+                category: input.category.as_db().to_string(),
+                severity: input.severity.as_db().to_string(),
+                description: description.to_string(),
+                reported_by: reporter,
+            },
+        )
+        .await?;
+        incidents::add_players(&mut *tx, row.id, &player_ids).await?;
+        for attachment in &input.attachments {
+            incidents::add_attachment(
+                &mut *tx,
+                row.id,
+                attachment.url.trim(),
+                attachment.content_type.as_deref(),
+                reporter,
+            )
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(row.into())
+    }
+
+    /// Attach a file (by URL) to an incident (floor staff and managers).
+    async fn add_incident_attachment(
+        &self,
+        ctx: &Context<'_>,
+        incident_id: ID,
+        attachment: IncidentAttachmentInput,
+    ) -> Result<IncidentAttachment> {
+        let state = ctx.data::<AppState>()?;
+        let incident = get_incident(state, &incident_id).await?;
+        let caller = require_floor_staff(ctx, incident.club_id).await?;
+        validate_attachment(&attachment)?;
+
+        let row = incidents::add_attachment(
+            &state.db,
+            incident.id,
+            attachment.url.trim(),
+            attachment.content_type.as_deref(),
+            caller,
+        )
+        .await?;
+        Ok(row.into())
+    }
+
+    /// Record follow-up on an unreviewed incident (floor staff and managers).
+    async fn update_incident_follow_up(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateIncidentFollowUpInput,
+    ) -> Result<Incident> {
+        let state = ctx.data::<AppState>()?;
+        let incident = get_incident(state, &input.incident_id).await?;
+        require_floor_staff(ctx, incident.club_id).await?;
+
+        if !matches!(
+            input.status,
+            IncidentStatus::Open | IncidentStatus::UnderReview
+        ) {
+            return Err(async_graphql::Error::new(
+                "Use reviewIncident to resolve or dismiss an incident",
+            ));
+        }
+
+        let row = incidents::update_follow_up(
+            &state.db,
+            incident.id,
+            input.status.as_db(),
+            input.notes.as_deref(),
+        )
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Incident has already been reviewed"))?;
+        Ok(row.into())
+    }
+
+    /// Close an incident with an outcome (managers only). An `EXCLUSION`
+    /// outcome excludes every involved player from the club.
+    async fn review_incident(
+        &self,
+        ctx: &Context<'_>,
+        input: ReviewIncidentInput,
+    ) -> Result<Incident> {
+        let state = ctx.data::<AppState>()?;
+        let incident = get_incident(state, &input.incident_id).await?;
+        let manager = require_club_manager(ctx, incident.club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid manager ID")?;
+
+        let ends_at = match (input.outcome, input.exclusion_days) {
+            (IncidentOutcome::Exclusion, Some(days)) if days <= 0 => {
+                return Err(async_graphql::Error::new(
+                    "Exclusion length must be at least one day",
+                ));
+            }
+            (IncidentOutcome::Exclusion, Some(days)) => {
+                Some(Utc::now() + Duration::days(days as i64))
+            }
+            (_, _) => None,
+        };
+
+        let status = match input.outcome {
+            IncidentOutcome::NoAction => IncidentStatus::Dismissed,
+            _ => IncidentStatus::Resolved,
+        };
+
+        let mut tx = state.db.begin().await?;
+        let row = incidents::review(
+            &mut *tx,
+            incident.id,
+            status.as_db(),
+            input.outcome.as_db(),
+            input.notes.as_deref(),
+            manager_id,
+        )
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Incident has already been reviewed"))?;
+
+        if input.outcome == IncidentOutcome::Exclusion {
+            let players = incidents::list_players(&mut *tx, incident.id).await?;
+            if players.is_empty() {
+                return Err(async_graphql::Error::new(
+                    "An exclusion needs at least one involved player",
+                ));
+            }
+            let reason = input
+                .notes
+                .clone()
+                .unwrap_or_else(|| incident.description.clone());
+            for player in players {
+                player_exclusions::create(
+                    &mut *tx,
+                    CreatePlayerExclusion {
+                        club_id: incident.club_id,
+                        club_player_id: player.id,
+                        incident_id: Some(incident.id),
+                        reason: reason.clone(),
+                        ends_at,
+                        created_by: manager_id,
+                    },
+                )
+                .await?;
+            }
+        }
+        tx.commit().await?;
+
+        Ok(row.into())
+    }
+
+    /// End an exclusion early (managers only).
+    async fn lift_player_exclusion(
+        &self,
+        ctx: &Context<'_>,
+        exclusion_id: ID,
+    ) -> Result<PlayerExclusion> {
+        let state = ctx.data::<AppState>()?;
+        let id = Uuid::parse_str(exclusion_id.as_str()).gql_err("Invalid exclusion ID")?;
+        let exclusion = player_exclusions::get_by_id(&state.db, id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Exclusion not found"))?;
+        let manager = require_club_manager(ctx, exclusion.club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid manager ID")?;
+
+        let row = player_exclusions::lift(&state.db, id, manager_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Exclusion has already been lifted"))?;
+        Ok(row.into())
+    }
+}
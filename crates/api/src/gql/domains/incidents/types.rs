@@ -0,0 +1,279 @@
+use async_graphql::{ComplexObject, Context, Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use crate::gql::domains::identity::types::ClubPlayer;
+use crate::state::AppState;
+use infra::repos::incidents::{self, IncidentAttachmentRow, IncidentRow};
+use infra::repos::player_exclusions::PlayerExclusionRow;
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum IncidentCategory {
+    RulingDispute,
+    Conduct,
+    Cheating,
+    Other,
+}
+
+impl IncidentCategory {
+    pub fn as_db(self) -> &'static str {
+        match self {
+            IncidentCategory::RulingDispute => "ruling_dispute",
+            IncidentCategory::Conduct => "conduct",
+            IncidentCategory::Cheating => "cheating",
+            IncidentCategory::Other => "other",
+        }
+    }
+
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "ruling_dispute" => IncidentCategory::RulingDispute,
+            "conduct" => IncidentCategory::Conduct,
+            "cheating" => IncidentCategory::Cheating,
+            _ => IncidentCategory::Other,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum IncidentSeverity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl IncidentSeverity {
+    pub fn as_db(self) -> &'static str {
+        match self {
+            IncidentSeverity::Low => "low",
+            IncidentSeverity::Medium => "medium",
+            IncidentSeverity::High => "high",
+            IncidentSeverity::Critical => "critical",
+        }
+    }
+
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "medium" => IncidentSeverity::Medium,
+            "high" => IncidentSeverity::High,
+            "critical" => IncidentSeverity::Critical,
+            _ => IncidentSeverity::Low,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum IncidentStatus {
+    Open,
+    UnderReview,
+    Resolved,
+    Dismissed,
+}
+
+impl IncidentStatus {
+    pub fn as_db(self) -> &'static str {
+        match self {
+            IncidentStatus::Open => "open",
+            IncidentStatus::UnderReview => "under_review",
+            IncidentStatus::Resolved => "resolved",
+            IncidentStatus::Dismissed => "dismissed",
+        }
+    }
+
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "under_review" => IncidentStatus::UnderReview,
+            "resolved" => IncidentStatus::Resolved,
+            "dismissed" => IncidentStatus::Dismissed,
+            _ => IncidentStatus::Open,
+        }
+    }
+}
+
+/// What the review decided.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum IncidentOutcome {
+    /// Nothing to act on; the incident is dismissed.
+    NoAction,
+    Warning,
+    Penalty,
+    /// Every involved player is excluded from the club.
+    Exclusion,
+}
+
+impl IncidentOutcome {
+    pub fn as_db(self) -> &'static str {
+        match self {
+            IncidentOutcome::NoAction => "no_action",
+            IncidentOutcome::Warning => "warning",
+            IncidentOutcome::Penalty => "penalty",
+            IncidentOutcome::Exclusion => "exclusion",
+        }
+    }
+
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "warning" => IncidentOutcome::Warning,
+            "penalty" => IncidentOutcome::Penalty,
+            "exclusion" => IncidentOutcome::Exclusion,
+            _ => IncidentOutcome::NoAction,
+        }
+    }
+}
+
+/// An incident report filed by the floor.
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct Incident {
+    pub id: ID,
+    pub club_id: ID,
+    pub tournament_id: Option<ID>,
+    pub club_table_id: Option<ID>,
+    pub category: IncidentCategory,
+    pub severity: IncidentSeverity,
+    pub description: String,
+    pub status: IncidentStatus,
+    pub follow_up_notes: Option<String>,
+    pub reported_by: Option<ID>,
+    pub review_outcome: Option<IncidentOutcome>,
+    pub review_notes: Option<String>,
+    pub reviewed_by: Option<ID>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<IncidentRow> for Incident {
+    fn from(row: IncidentRow) -> Self {
+        Self {
+            id: row.id.into(),
+            club_id: row.club_id.into(),
+            tournament_id: row.tournament_id.map(Into::into),
+            club_table_id: row.club_table_id.map(Into::into),
+            category: IncidentCategory::from_db(&row.category),
+            severity: IncidentSeverity::from_db(&row.severity),
+            description: row.description,
+            status: IncidentStatus::from_db(&row.status),
+            follow_up_notes: row.follow_up_notes,
+            reported_by: row.reported_by.map(Into::into),
+            review_outcome: row.review_outcome.as_deref().map(IncidentOutcome::from_db),
+            review_notes: row.review_notes,
+            reviewed_by: row.reviewed_by.map(Into::into),
+            reviewed_at: row.reviewed_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[ComplexObject]
+impl Incident {
+    /// Players involved in the incident.
+    async fn involved_players(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<ClubPlayer>> {
+        let state = ctx.data::<AppState>()?;
+        let id = uuid::Uuid::parse_str(self.id.as_str())?;
+        let rows = incidents::list_players(&state.db, id).await?;
+        Ok(rows.into_iter().map(ClubPlayer::from).collect())
+    }
+
+    async fn attachments(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<IncidentAttachment>> {
+        let state = ctx.data::<AppState>()?;
+        let id = uuid::Uuid::parse_str(self.id.as_str())?;
+        let rows = incidents::list_attachments(&state.db, id).await?;
+        Ok(rows.into_iter().map(IncidentAttachment::from).collect())
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct IncidentAttachment {
+    pub id: ID,
+    pub url: String,
+    pub content_type: Option<String>,
+    pub uploaded_by: Option<ID>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<IncidentAttachmentRow> for IncidentAttachment {
+    fn from(row: IncidentAttachmentRow) -> Self {
+        Self {
+            id: row.id.into(),
+            url: row.url,
+            content_type: row.content_type,
+            uploaded_by: row.uploaded_by.map(Into::into),
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// A club exclusion (ban).
+#[derive(SimpleObject, Clone)]
+pub struct PlayerExclusion {
+    pub id: ID,
+    pub club_id: ID,
+    pub club_player_id: ID,
+    /// The incident whose review imposed it, if any.
+    pub incident_id: Option<ID>,
+    pub reason: String,
+    pub starts_at: DateTime<Utc>,
+    /// Null = indefinite.
+    pub ends_at: Option<DateTime<Utc>>,
+    pub lifted_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<PlayerExclusionRow> for PlayerExclusion {
+    fn from(row: PlayerExclusionRow) -> Self {
+        Self {
+            id: row.id.into(),
+            club_id: row.club_id.into(),
+            club_player_id: row.club_player_id.into(),
+            incident_id: row.incident_id.map(Into::into),
+            reason: row.reason,
+            starts_at: row.starts_at,
+            ends_at: row.ends_at,
+            lifted_at: row.lifted_at,
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct FileIncidentInput {
+    pub club_id: ID,
+    pub tournament_id: Option<ID>,
+    pub club_table_id: Option<ID>,
+    pub category: IncidentCategory,
+    pub severity: IncidentSeverity,
+    pub description: String,
+    #[graphql(default)]
+    pub involved_club_player_ids: Vec<ID>,
+    #[graphql(default)]
+    pub attachments: Vec<IncidentAttachmentInput>,
+}
+
+#[derive(InputObject)]
+pub struct IncidentAttachmentInput {
+    pub url: String,
+    pub content_type: Option<String>,
+}
+
+#[derive(InputObject)]
+pub struct UpdateIncidentFollowUpInput {
+    pub incident_id: ID,
+    /// `OPEN` or `UNDER_REVIEW`; closing goes through `reviewIncident`.
+    pub status: IncidentStatus,
+    pub notes: Option<String>,
+}
+
+#[derive(InputObject)]
+pub struct ReviewIncidentInput {
+    pub incident_id: ID,
+    pub outcome: IncidentOutcome,
+    pub notes: Option<String>,
+    /// Exclusion length in days; omit for an indefinite exclusion. Only used
+    /// with `EXCLUSION`.
+    pub exclusion_days: Option<i32>,
+}
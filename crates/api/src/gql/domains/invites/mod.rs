@@ -0,0 +1,80 @@
+pub mod resolvers;
+pub mod types;
+
+pub use resolvers::{InviteMutation, InviteQuery};
+
+use chrono::{DateTime, Utc};
+
+use infra::repos::tournament_invites::InviteRow;
+
+/// Why an invite can't be used any more, as shown to the player; `None` when
+/// it still admits registrations.
+pub fn unusable_reason(row: &InviteRow, now: DateTime<Utc>) -> Option<&'static str> {
+    if row.revoked_at.is_some() {
+        Some("This invite link has been revoked")
+    } else if row.expires_at.is_some_and(|at| at <= now) {
+        Some("This invite link has expired")
+    } else if row.max_uses.is_some_and(|max| row.use_count >= max) {
+        Some("This invite link has been used up")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    fn row() -> InviteRow {
+        InviteRow {
+            id: Uuid::nil(),
+            tournament_id: Uuid::nil(),
+            label: None,
+            max_uses: Some(2),
+            use_count: 1,
+            expires_at: Some(Utc::now() + Duration::hours(1)),
+            revoked_at: None,
+            created_by: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn checks_limits() {
+        let now = Utc::now();
+        assert_eq!(unusable_reason(&row(), now), None);
+        let used_up = InviteRow {
+            use_count: 2,
+            ..row()
+        };
+        assert_eq!(
+            unusable_reason(&used_up, now),
+            Some("This invite link has been used up")
+        );
+        let expired = InviteRow {
+            expires_at: Some(now),
+            ..row()
+        };
+        assert_eq!(
+            unusable_reason(&expired, now),
+            Some("This invite link has expired")
+        );
+        let revoked = InviteRow {
+            revoked_at: Some(now),
+            ..row()
+        };
+        assert_eq!(
+            unusable_reason(&revoked, now),
+            Some("This invite link has been revoked")
+        );
+        let unlimited = InviteRow {
+            max_uses: None,
+            expires_at: None,
+            use_count: 500,
+            ..row()
+        };
+        assert_eq!(unusable_reason(&unlimited, now), None);
+    }
+}
@@ -0,0 +1,113 @@
+use async_graphql::{Context, Object, Result, ID};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::auth::permissions::require_club_manager;
+use crate::gql::common::helpers::get_club_id_for_tournament;
+use crate::gql::error::ResultExt;
+use crate::state::AppState;
+use infra::repos::tournament_invites;
+
+use super::types::{CreateTournamentInviteInput, TournamentInvite};
+
+const MAX_LABEL_LENGTH: usize = 100;
+
+#[derive(Default)]
+pub struct InviteQuery;
+
+#[Object]
+impl InviteQuery {
+    /// A tournament's invite links, newest first. Managers only.
+    async fn tournament_invites(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Vec<TournamentInvite>> {
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let now = Utc::now();
+        Ok(
+            tournament_invites::list_for_tournament(&state.db, tournament_id)
+                .await?
+                .into_iter()
+                .map(|row| TournamentInvite::new(row, state, now))
+                .collect(),
+        )
+    }
+}
+
+#[derive(Default)]
+pub struct InviteMutation;
+
+#[Object]
+impl InviteMutation {
+    /// Create an invite link. Registering through it skips the members-only
+    /// and unlisted restrictions. Managers only.
+    async fn create_tournament_invite(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateTournamentInviteInput,
+    ) -> Result<TournamentInvite> {
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid user ID")?;
+
+        let label = input
+            .label
+            .as_deref()
+            .map(str::trim)
+            .filter(|l| !l.is_empty());
+        if label.is_some_and(|l| l.chars().count() > MAX_LABEL_LENGTH) {
+            return Err(async_graphql::Error::new(format!(
+                "Label must be at most {MAX_LABEL_LENGTH} characters"
+            )));
+        }
+        if input.max_uses.is_some_and(|max| max < 1) {
+            return Err(async_graphql::Error::new("Max uses must be at least 1"));
+        }
+        let now = Utc::now();
+        if input.expires_at.is_some_and(|at| at <= now) {
+            return Err(async_graphql::Error::new("Expiry must be in the future"));
+        }
+
+        let row = tournament_invites::create(
+            &state.db,
+            tournament_id,
+            label,
+            input.max_uses,
+            input.expires_at,
+            manager_id,
+        )
+        .await
+        .gql_err("Failed to create invite")?;
+        Ok(TournamentInvite::new(row, state, now))
+    }
+
+    /// Revoke an invite link; registrations already made through it stay.
+    /// Managers only.
+    async fn revoke_tournament_invite(
+        &self,
+        ctx: &Context<'_>,
+        invite_id: ID,
+    ) -> Result<TournamentInvite> {
+        let state = ctx.data::<AppState>()?;
+        let invite_id = Uuid::parse_str(invite_id.as_str()).gql_err("Invalid invite ID")?;
+        let invite = tournament_invites::get_by_id(&state.db, invite_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Invite not found"))?;
+        let club_id = get_club_id_for_tournament(&state.db, invite.tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let row = tournament_invites::revoke(&state.db, invite_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Invite not found"))?;
+        Ok(TournamentInvite::new(row, state, Utc::now()))
+    }
+}
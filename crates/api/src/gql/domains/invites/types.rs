@@ -0,0 +1,55 @@
+use async_graphql::{InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use crate::gql::domains::printouts::link::{self, TokenScope};
+use crate::state::AppState;
+use infra::repos::tournament_invites::InviteRow;
+
+use super::unusable_reason;
+
+/// An invite link to a private tournament. `token` goes in the link and is
+/// redeemed with `registerWithInvite`.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct TournamentInvite {
+    pub id: ID,
+    pub tournament_id: ID,
+    pub label: Option<String>,
+    pub token: String,
+    /// Null = unlimited.
+    pub max_uses: Option<i32>,
+    /// Registrations made through this invite.
+    pub use_count: i32,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    /// Whether the link still admits registrations.
+    pub is_usable: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TournamentInvite {
+    pub fn new(row: InviteRow, state: &AppState, now: DateTime<Utc>) -> Self {
+        Self {
+            id: row.id.into(),
+            tournament_id: row.tournament_id.into(),
+            token: link::encode_token(&state.auth_config().jwt_secret, TokenScope::Invite, row.id),
+            is_usable: unusable_reason(&row, now).is_none(),
+            label: row.label,
+            max_uses: row.max_uses,
+            use_count: row.use_count,
+            expires_at: row.expires_at,
+            revoked_at: row.revoked_at,
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreateTournamentInviteInput {
+    pub tournament_id: ID,
+    /// Who the link is for, e.g. "Sponsor guests".
+    pub label: Option<String>,
+    /// Omit for unlimited uses.
+    pub max_uses: Option<i32>,
+    /// Omit for a link that never expires.
+    pub expires_at: Option<DateTime<Utc>>,
+}
@@ -1,3 +1,8 @@
+//! Leagues: which tournaments count, how they score and over what period.
+//! Manual point changes are audited `leaderboard_adjustments` with a reason
+//! code, added to the league's standings: `addLeaderboardAdjustment` by
+//! roster entry, `adjustPlayerPoints` by app user.
+
 pub mod resolvers;
 pub mod types;
 
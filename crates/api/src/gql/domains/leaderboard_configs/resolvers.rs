@@ -2,14 +2,17 @@ use async_graphql::{Context, Object, Result, ID};
 use uuid::Uuid;
 
 use crate::auth::permissions::require_club_manager;
+use crate::gql::domains::leaderboards::resolvers::tie_break_rules;
 use crate::gql::error::ResultExt;
 use crate::state::AppState;
-use infra::repos::{leaderboard_adjustments, leaderboard_configs};
+use infra::repos::tournament_results::TieBreak;
+use infra::repos::{club_players, leaderboard_adjustments, leaderboard_configs};
 use infra::scoring::{event_points_with, ScoringFormula as InfraFormula};
 
 use super::types::{
     AddLeaderboardAdjustmentInput, CreateLeaderboardConfigInput, LeaderboardAdjustment,
-    LeaderboardConfig, ScoringFormulaInput, ScoringSampleInput, UpdateLeaderboardConfigInput,
+    LeaderboardConfig, PointsAdjustmentReason, ScoringFormulaInput, ScoringSampleInput,
+    UpdateLeaderboardConfigInput,
 };
 
 #[derive(Default)]
@@ -75,7 +78,7 @@ impl LeaderboardConfigQuery {
                     &f,
                     s.field_size.max(0) as u32,
                     s.rank.max(0) as u32,
-                    s.buy_in_cents as f64 / 100.0,
+                    s.buy_in_cents.cents() as f64 / 100.0,
                 ) as i32
             })
             .collect())
@@ -103,6 +106,10 @@ impl LeaderboardConfigMutation {
             .membership_mode
             .map(String::from)
             .unwrap_or_else(|| "all_in_period".to_string());
+        let tie_breaks = match input.tie_breaks {
+            Some(rules) => tie_break_rules(rules)?,
+            None => TieBreak::DEFAULT_ORDER.to_vec(),
+        };
 
         let row = leaderboard_configs::create(
             &state.db,
@@ -113,6 +120,7 @@ impl LeaderboardConfigMutation {
                 membership_mode,
                 period_start: input.period_start,
                 period_end: input.period_end,
+                tie_breaks: tie_breaks.iter().map(|t| t.as_str().to_string()).collect(),
             },
         )
         .await
@@ -146,6 +154,7 @@ impl LeaderboardConfigMutation {
             }
             None => None,
         };
+        let tie_breaks = input.tie_breaks.map(tie_break_rules).transpose()?;
 
         let updated = leaderboard_configs::update(
             &state.db,
@@ -156,6 +165,8 @@ impl LeaderboardConfigMutation {
                 membership_mode: input.membership_mode.map(String::from),
                 period_start: input.period_start,
                 period_end: input.period_end,
+                tie_breaks: tie_breaks
+                    .map(|rules| rules.iter().map(|t| t.as_str().to_string()).collect()),
             },
         )
         .await
@@ -195,6 +206,8 @@ impl LeaderboardConfigMutation {
         if reason.is_empty() {
             return Err(async_graphql::Error::new("A reason is required"));
         }
+        let reason_code = input.reason_code.unwrap_or(PointsAdjustmentReason::Other);
+        validate_adjustment(reason_code, input.points_delta)?;
         let club_player_id =
             Uuid::parse_str(input.club_player_id.as_str()).gql_err("Invalid player ID")?;
         let created_by = Uuid::parse_str(manager.id.as_str()).ok();
@@ -204,6 +217,7 @@ impl LeaderboardConfigMutation {
             config.id,
             club_player_id,
             input.points_delta,
+            reason_code.as_str(),
             reason,
             created_by,
         )
@@ -212,6 +226,44 @@ impl LeaderboardConfigMutation {
         Ok(LeaderboardAdjustment::from(row))
     }
 
+    /// Adjust an app user's points in a league (`configId`, as for
+    /// `leaderboard`) for a penalty or correction. Recorded as an
+    /// audited adjustment and added to the league's standings. Managers of the
+    /// league's club and admins.
+    async fn adjust_player_points(
+        &self,
+        ctx: &Context<'_>,
+        user_id: ID,
+        config_id: ID,
+        delta: i32,
+        reason: PointsAdjustmentReason,
+        note: Option<String>,
+    ) -> Result<LeaderboardAdjustment> {
+        let state = ctx.data::<AppState>()?;
+        let config = load_config(state, &config_id).await?;
+        let manager = require_club_manager(ctx, config.club_id).await?;
+        validate_adjustment(reason, delta)?;
+
+        let user_uuid = Uuid::parse_str(user_id.as_str()).gql_err("Invalid user ID")?;
+        let player = club_players::find_by_club_and_app_user(&state.db, config.club_id, user_uuid)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Player is not on the club's roster"))?;
+        let created_by = Uuid::parse_str(manager.id.as_str()).ok();
+
+        let row = leaderboard_adjustments::create(
+            &state.db,
+            config.id,
+            player.id,
+            delta,
+            reason.as_str(),
+            note.as_deref().map(str::trim).unwrap_or_default(),
+            created_by,
+        )
+        .await
+        .gql_err("Failed to adjust points")?;
+        Ok(LeaderboardAdjustment::from(row))
+    }
+
     /// Remove a manual adjustment. Managers of the league's club only.
     async fn remove_leaderboard_adjustment(
         &self,
@@ -241,6 +293,22 @@ async fn load_config(
 }
 
 /// Reject obviously broken formulas before they are stored.
+/// A non-zero delta whose sign matches the reason.
+fn validate_adjustment(reason: PointsAdjustmentReason, delta: i32) -> Result<()> {
+    match reason {
+        _ if delta == 0 => Err(async_graphql::Error::new(
+            "An adjustment must change the points",
+        )),
+        PointsAdjustmentReason::Penalty if delta > 0 => {
+            Err(async_graphql::Error::new("A penalty must take points off"))
+        }
+        PointsAdjustmentReason::Bonus if delta < 0 => {
+            Err(async_graphql::Error::new("A bonus must add points"))
+        }
+        _ => Ok(()),
+    }
+}
+
 fn validate_formula(f: &InfraFormula) -> Result<()> {
     if !f.base_points.is_finite()
         || !f.field_multiplier.is_finite()
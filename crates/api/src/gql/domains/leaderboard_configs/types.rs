@@ -2,6 +2,10 @@ use async_graphql::{Enum, InputObject, SimpleObject, ID};
 use chrono::{DateTime, Utc};
 use infra::scoring as sc;
 
+use crate::gql::scalars::Money;
+use crate::gql::types::LeaderboardTieBreak;
+use infra::repos::tournament_results::TieBreak;
+
 /// Shape of the per-position factor in the scoring formula.
 #[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, Default)]
 pub enum PositionCurve {
@@ -58,6 +62,40 @@ impl From<MembershipMode> for String {
     }
 }
 
+/// Why a manual point adjustment was made.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum PointsAdjustmentReason {
+    /// Points taken off, e.g. for a rules breach. Deltas must be negative.
+    Penalty,
+    /// Fixing a wrongly recorded result.
+    Correction,
+    /// Points awarded outside a result. Deltas must be positive.
+    Bonus,
+    Other,
+}
+
+impl From<&str> for PointsAdjustmentReason {
+    fn from(s: &str) -> Self {
+        match s {
+            "penalty" => PointsAdjustmentReason::Penalty,
+            "correction" => PointsAdjustmentReason::Correction,
+            "bonus" => PointsAdjustmentReason::Bonus,
+            _ => PointsAdjustmentReason::Other,
+        }
+    }
+}
+
+impl PointsAdjustmentReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PointsAdjustmentReason::Penalty => "penalty",
+            PointsAdjustmentReason::Correction => "correction",
+            PointsAdjustmentReason::Bonus => "bonus",
+            PointsAdjustmentReason::Other => "other",
+        }
+    }
+}
+
 /// A league's scoring formula (see `infra::scoring`).
 #[derive(SimpleObject, Clone)]
 pub struct ScoringFormula {
@@ -121,6 +159,8 @@ pub struct LeaderboardConfig {
     pub period_start: Option<DateTime<Utc>>,
     pub period_end: Option<DateTime<Utc>>,
     pub is_default: bool,
+    /// How players level on points are ordered, first rule first.
+    pub tie_breaks: Vec<LeaderboardTieBreak>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -139,6 +179,12 @@ impl From<infra::repos::leaderboard_configs::LeaderboardConfigRow> for Leaderboa
             period_start: row.period_start,
             period_end: row.period_end,
             is_default: row.is_default,
+            tie_breaks: row
+                .tie_breaks
+                .iter()
+                .filter_map(|t| TieBreak::from_db(t))
+                .map(LeaderboardTieBreak::from)
+                .collect(),
             created_at: row.created_at,
             updated_at: row.updated_at,
         }
@@ -152,6 +198,8 @@ pub struct LeaderboardAdjustment {
     pub config_id: ID,
     pub club_player_id: ID,
     pub points_delta: i32,
+    pub reason_code: PointsAdjustmentReason,
+    /// Free-text note (may be empty for coded adjustments).
     pub reason: String,
     pub created_by: Option<ID>,
     pub created_at: DateTime<Utc>,
@@ -166,6 +214,7 @@ impl From<infra::repos::leaderboard_adjustments::LeaderboardAdjustmentRow>
             config_id: row.config_id.into(),
             club_player_id: row.club_player_id.into(),
             points_delta: row.points_delta,
+            reason_code: PointsAdjustmentReason::from(row.reason_code.as_str()),
             reason: row.reason,
             created_by: row.created_by.map(|id| id.into()),
             created_at: row.created_at,
@@ -182,6 +231,9 @@ pub struct CreateLeaderboardConfigInput {
     pub period_start: Option<DateTime<Utc>>,
     pub period_end: Option<DateTime<Utc>>,
     pub is_default: Option<bool>,
+    /// Defaults to head-to-head, most wins, highest single score, earliest
+    /// achievement.
+    pub tie_breaks: Option<Vec<LeaderboardTieBreak>>,
 }
 
 #[derive(InputObject)]
@@ -193,6 +245,9 @@ pub struct UpdateLeaderboardConfigInput {
     pub period_start: Option<DateTime<Utc>>,
     pub period_end: Option<DateTime<Utc>>,
     pub is_default: Option<bool>,
+    /// Replaces the rules; an empty list falls back to winnings and
+    /// tournaments played.
+    pub tie_breaks: Option<Vec<LeaderboardTieBreak>>,
 }
 
 #[derive(InputObject)]
@@ -201,6 +256,8 @@ pub struct AddLeaderboardAdjustmentInput {
     pub club_player_id: ID,
     pub points_delta: i32,
     pub reason: String,
+    /// Defaults to `OTHER`.
+    pub reason_code: Option<PointsAdjustmentReason>,
 }
 
 /// One sample row for the live scoring preview.
@@ -208,5 +265,5 @@ pub struct AddLeaderboardAdjustmentInput {
 pub struct ScoringSampleInput {
     pub field_size: i32,
     pub rank: i32,
-    pub buy_in_cents: i32,
+    pub buy_in_cents: Money,
 }
@@ -1,4 +1,19 @@
+//! Scoring and rankings.
+//!
+//! Final-table and ITM thresholds come from the `club_stats_settings` of
+//! each tournament's club (`tournament_results::StatThresholds`; top 9 and
+//! any prize by default), and `LeaderboardOptions` overrides them per query.
+//!
+//! A tournament's `points_multiplier` weights its results' points:
+//! `calculate_tournament_points` applies it after the 60-point cap when
+//! results are entered, and leagues apply it when they recompute.
+//!
+//! Players level on points are ordered by `tournament_results::TieBreak`
+//! rules: the league's `tie_breaks`, else head-to-head, most wins, highest
+//! single score, earliest achievement. `LeaderboardOptions.tieBreaks`
+//! overrides them.
+
 pub mod resolvers;
 pub mod types;
 
-pub use resolvers::LeaderboardQuery;
+pub use resolvers::{LeaderboardMutation, LeaderboardQuery};
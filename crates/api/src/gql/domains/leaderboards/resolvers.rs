@@ -1,11 +1,55 @@
-use async_graphql::{Context, Object, Result};
-use infra::repos::tournament_results;
+use async_graphql::{Context, Object, Result, ID};
+use infra::repos::tournament_results::{self, StatThresholds, TieBreak};
+use infra::repos::{club_stats_settings, tournament_series};
+use uuid::Uuid;
 
-use crate::auth::permissions::{viewer_is_admin, viewer_manages_club};
+use crate::auth::permissions::{require_club_manager, viewer_is_admin, viewer_manages_club};
+use crate::gql::error::ResultExt;
 use crate::gql::types::{PaginatedResponse, PaginationInput, Role, User};
 use crate::state::AppState;
 
-use super::types::{LeaderboardEntry, LeaderboardPeriod};
+use super::types::{
+    LeaderboardEntry, LeaderboardOptions, LeaderboardPeriod, LeaderboardTieBreak, StatsSettings,
+    UpdateStatsSettingsInput,
+};
+
+/// Check final-table and ITM thresholds, from settings or query arguments.
+fn validate_thresholds(final_table_size: Option<i32>, itm_places: Option<i32>) -> Result<()> {
+    if final_table_size.is_some_and(|n| !(2..=10).contains(&n)) {
+        return Err(async_graphql::Error::new(
+            "Final table size must be between 2 and 10",
+        ));
+    }
+    if itm_places.is_some_and(|n| n < 1) {
+        return Err(async_graphql::Error::new("Paid places must be at least 1"));
+    }
+    Ok(())
+}
+
+/// Stat thresholds from query options, validated like club settings.
+fn stat_thresholds(options: &LeaderboardOptions) -> Result<StatThresholds> {
+    validate_thresholds(options.final_table_size, options.itm_places)?;
+    Ok(StatThresholds {
+        final_table_size: options.final_table_size,
+        itm_places: options.itm_places,
+    })
+}
+
+/// Tie-break rules from query options or a league input, rejecting repeats.
+pub(crate) fn tie_break_rules(rules: Vec<LeaderboardTieBreak>) -> Result<Vec<TieBreak>> {
+    let mut out: Vec<TieBreak> = Vec::with_capacity(rules.len());
+    for rule in rules {
+        let rule = TieBreak::from(rule);
+        if out.contains(&rule) {
+            return Err(async_graphql::Error::new(format!(
+                "Tie-break {} is listed twice",
+                rule.as_str()
+            )));
+        }
+        out.push(rule);
+    }
+    Ok(out)
+}
 
 #[derive(Default)]
 pub struct LeaderboardQuery;
@@ -13,6 +57,7 @@ pub struct LeaderboardQuery;
 #[Object]
 impl LeaderboardQuery {
     /// Get player leaderboard with comprehensive statistics and points
+    #[allow(clippy::too_many_arguments)]
     async fn leaderboard(
         &self,
         ctx: &Context<'_>,
@@ -27,8 +72,12 @@ impl LeaderboardQuery {
             desc = "League id (see leaderboardConfigs). When set, points are recomputed from the league's formula and `period` is ignored (the league's own period applies)."
         )]
         config_id: Option<uuid::Uuid>,
+        options: Option<LeaderboardOptions>,
     ) -> Result<PaginatedResponse<LeaderboardEntry>> {
         let state = ctx.data::<AppState>()?;
+        let options = options.unwrap_or_default();
+        let thresholds = stat_thresholds(&options)?;
+        let tie_breaks = options.tie_breaks.map(tie_break_rules).transpose()?;
 
         let period = period.unwrap_or(LeaderboardPeriod::AllTime);
         let infra_period: infra::repos::tournament_results::LeaderboardPeriod = period.into();
@@ -41,7 +90,15 @@ impl LeaderboardQuery {
 
         // League path: recompute points on read from the league's formula.
         if let Some(config_uuid) = config_id {
-            return league_leaderboard(ctx, state, config_uuid, &limit_offset).await;
+            return league_leaderboard(
+                ctx,
+                state,
+                config_uuid,
+                &limit_offset,
+                thresholds,
+                tie_breaks,
+            )
+            .await;
         }
 
         // Free ("Home Game") clubs never appear in player-facing leaderboards.
@@ -62,13 +119,17 @@ impl LeaderboardQuery {
                 Some(limit_offset.offset as i32),
                 club_id,
                 province.clone(),
+                None,
                 exclude_free,
+                thresholds,
+                tie_breaks.as_deref().unwrap_or(&TieBreak::DEFAULT_ORDER),
             ),
             tournament_results::count_leaderboard(
                 &state.db,
                 infra_period,
                 club_id,
                 province.clone(),
+                None,
                 exclude_free,
             )
         )?;
@@ -92,10 +153,120 @@ impl LeaderboardQuery {
             has_next_page,
         })
     }
+
+    /// Standings across a series' events: points, winnings and stats summed
+    /// over every member tournament's results, ranked like `leaderboard`.
+    async fn series_leaderboard(
+        &self,
+        ctx: &Context<'_>,
+        series_id: ID,
+        pagination: Option<PaginationInput>,
+        options: Option<LeaderboardOptions>,
+    ) -> Result<PaginatedResponse<LeaderboardEntry>> {
+        let state = ctx.data::<AppState>()?;
+        let series_id = Uuid::parse_str(series_id.as_str()).gql_err("Invalid series ID")?;
+        let series = tournament_series::get_by_id(&state.db, series_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Series not found"))?;
+        let options = options.unwrap_or_default();
+        let thresholds = stat_thresholds(&options)?;
+        let tie_breaks = options.tie_breaks.map(tie_break_rules).transpose()?;
+        let limit_offset = pagination
+            .unwrap_or(PaginationInput {
+                limit: Some(100),
+                offset: Some(0),
+            })
+            .to_limit_offset();
+
+        // As with a club leaderboard, a free club's series is only ranked for
+        // its own managers.
+        let exclude_free = !viewer_manages_club(ctx, series.club_id).await;
+        let period = infra::repos::tournament_results::LeaderboardPeriod::AllTime;
+        let (rows, total_count) = tokio::try_join!(
+            tournament_results::get_leaderboard(
+                &state.db,
+                period,
+                Some(limit_offset.limit as i32),
+                Some(limit_offset.offset as i32),
+                Some(series.club_id),
+                None,
+                Some(series.id),
+                exclude_free,
+                thresholds,
+                tie_breaks.as_deref().unwrap_or(&TieBreak::DEFAULT_ORDER),
+            ),
+            tournament_results::count_leaderboard(
+                &state.db,
+                period,
+                Some(series.club_id),
+                None,
+                Some(series.id),
+                exclude_free,
+            )
+        )?;
+
+        let offset = limit_offset.offset as i32;
+        let entries: Vec<LeaderboardEntry> = rows
+            .into_iter()
+            .enumerate()
+            .map(|(index, entry)| to_gql_entry(entry, offset + (index as i32) + 1))
+            .collect();
+
+        let page_size = entries.len() as i32;
+        let has_next_page = (offset + page_size) < total_count as i32;
+
+        Ok(PaginatedResponse {
+            items: entries,
+            total_count: total_count as i32,
+            page_size,
+            offset,
+            has_next_page,
+        })
+    }
+
+    /// What the club's leaderboard stats count as a final table and a cash.
+    /// Club managers.
+    async fn club_stats_settings(&self, ctx: &Context<'_>, club_id: ID) -> Result<StatsSettings> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+
+        let state = ctx.data::<AppState>()?;
+        let row = club_stats_settings::get_or_create(&state.db, club_id).await?;
+        Ok(row.into())
+    }
+}
+
+#[derive(Default)]
+pub struct LeaderboardMutation;
+
+#[Object]
+impl LeaderboardMutation {
+    /// Set what the club's leaderboard stats count as a final table and a
+    /// cash, e.g. 6 for short-handed final tables. Applies to past results
+    /// too: stats are computed on read. Club managers.
+    async fn update_club_stats_settings(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateStatsSettingsInput,
+    ) -> Result<StatsSettings> {
+        let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        validate_thresholds(Some(input.final_table_size), input.itm_places)?;
+
+        let state = ctx.data::<AppState>()?;
+        let row = club_stats_settings::upsert(
+            &state.db,
+            club_id,
+            input.final_table_size,
+            input.itm_places,
+        )
+        .await?;
+        Ok(row.into())
+    }
 }
 
 /// Map an infra leaderboard row to the GraphQL type, stamping its 1-based rank.
-fn to_gql_entry(
+pub(crate) fn to_gql_entry(
     entry: infra::repos::tournament_results::LeaderboardEntry,
     rank: i32,
 ) -> LeaderboardEntry {
@@ -109,16 +280,18 @@ fn to_gql_entry(
             username: entry.username.clone(),
             first_name: entry.first_name.clone().unwrap_or_default(),
             last_name: entry.last_name.clone(),
-            phone: entry.phone.clone(),
+            phone: entry.phone.clone().map(String::from),
+            avatar_url: entry.avatar_url.clone(),
             is_active: entry.is_active.unwrap_or(true),
             role: Role::from(entry.role.clone()),
             locale: entry.locale.clone().unwrap_or_default(),
+            contact_details_visible: false,
         }),
         rank,
         total_tournaments: entry.total_tournaments,
-        total_buy_ins: entry.total_buy_ins,
-        total_winnings: entry.total_winnings,
-        net_profit: entry.net_profit,
+        total_buy_ins: entry.total_buy_ins.into(),
+        total_winnings: entry.total_winnings.into(),
+        net_profit: entry.net_profit.into(),
         total_itm: entry.total_itm,
         itm_percentage: entry.itm_percentage,
         roi_percentage: entry.roi_percentage,
@@ -126,6 +299,9 @@ fn to_gql_entry(
         first_places: entry.first_places,
         final_tables: entry.final_tables,
         points: entry.points,
+        head_to_head_wins: entry.head_to_head_wins,
+        best_result_points: entry.best_result_points,
+        points_reached_at: entry.points_reached_at,
     }
 }
 
@@ -135,12 +311,21 @@ async fn league_leaderboard(
     state: &AppState,
     config_id: uuid::Uuid,
     limit_offset: &infra::pagination::LimitOffset,
+    thresholds: StatThresholds,
+    tie_breaks: Option<Vec<TieBreak>>,
 ) -> Result<PaginatedResponse<LeaderboardEntry>> {
     let config = infra::repos::leaderboard_configs::get_by_id(&state.db, config_id)
         .await?
         .ok_or_else(|| async_graphql::Error::new("League not found"))?;
     let formula: infra::scoring::ScoringFormula =
         serde_json::from_value(config.formula_params).unwrap_or_default();
+    let tie_breaks = tie_breaks.unwrap_or_else(|| {
+        config
+            .tie_breaks
+            .iter()
+            .filter_map(|t| TieBreak::from_db(t))
+            .collect()
+    });
 
     // A free club's league is hidden from the player app; only its own managers
     // and admins can read it.
@@ -157,6 +342,8 @@ async fn league_leaderboard(
         Some(limit_offset.limit as i32),
         Some(limit_offset.offset as i32),
         exclude_free,
+        thresholds,
+        &tie_breaks,
     )
     .await?;
 
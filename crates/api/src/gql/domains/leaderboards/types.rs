@@ -1,6 +1,10 @@
-use async_graphql::{Enum, SimpleObject, ID};
+use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
 
+use crate::gql::scalars::Money;
 use crate::gql::types::User;
+use infra::repos::club_stats_settings::StatsSettingsRow;
+use infra::repos::tournament_results::TieBreak;
 
 #[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
 pub enum LeaderboardPeriod {
@@ -10,6 +14,9 @@ pub enum LeaderboardPeriod {
     Last6Months,
     Last30Days,
     Last7Days,
+    /// Last 52 weeks, each result's points decaying linearly with age.
+    /// Decayed values are refreshed nightly.
+    Rolling52,
 }
 
 impl From<LeaderboardPeriod> for infra::repos::tournament_results::LeaderboardPeriod {
@@ -33,6 +40,44 @@ impl From<LeaderboardPeriod> for infra::repos::tournament_results::LeaderboardPe
             LeaderboardPeriod::Last7Days => {
                 infra::repos::tournament_results::LeaderboardPeriod::Last7Days
             }
+            LeaderboardPeriod::Rolling52 => {
+                infra::repos::tournament_results::LeaderboardPeriod::Rolling52
+            }
+        }
+    }
+}
+
+/// Rule ordering players level on points, applied in the order given.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum LeaderboardTieBreak {
+    /// Most finishes ahead of the other tied players in shared tournaments.
+    HeadToHead,
+    /// Most first places.
+    MostWins,
+    /// Highest points from a single tournament.
+    HighestSingleScore,
+    /// Reached the total first.
+    EarliestAchievement,
+}
+
+impl From<TieBreak> for LeaderboardTieBreak {
+    fn from(t: TieBreak) -> Self {
+        match t {
+            TieBreak::HeadToHead => LeaderboardTieBreak::HeadToHead,
+            TieBreak::MostWins => LeaderboardTieBreak::MostWins,
+            TieBreak::HighestSingleScore => LeaderboardTieBreak::HighestSingleScore,
+            TieBreak::EarliestAchievement => LeaderboardTieBreak::EarliestAchievement,
+        }
+    }
+}
+
+impl From<LeaderboardTieBreak> for TieBreak {
+    fn from(t: LeaderboardTieBreak) -> Self {
+        match t {
+            LeaderboardTieBreak::HeadToHead => TieBreak::HeadToHead,
+            LeaderboardTieBreak::MostWins => TieBreak::MostWins,
+            LeaderboardTieBreak::HighestSingleScore => TieBreak::HighestSingleScore,
+            LeaderboardTieBreak::EarliestAchievement => TieBreak::EarliestAchievement,
         }
     }
 }
@@ -46,16 +91,23 @@ pub struct LeaderboardEntry {
     pub user: Option<User>, // Full user object, when the player has an account
     pub rank: i32,          // Position in leaderboard (1-based)
     pub total_tournaments: i32,
-    pub total_buy_ins: i32,  // Total amount spent (cents)
-    pub total_winnings: i32, // Total amount won (cents)
-    pub net_profit: i32,     // winnings - buy_ins (cents)
-    pub total_itm: i32,      // Number of tournaments where player finished in the money
-    pub itm_percentage: f64, // (total_itm / total_tournaments) * 100
-    pub roi_percentage: f64, // ((total_winnings - total_buy_ins) / total_buy_ins) * 100
-    pub average_finish: f64, // Average finishing position
-    pub first_places: i32,   // Number of first place finishes
-    pub final_tables: i32,   // Number of final table finishes (top 9)
-    pub points: f64,         // Calculated leaderboard points
+    pub total_buy_ins: Money,  // Total amount spent (cents)
+    pub total_winnings: Money, // Total amount won (cents)
+    pub net_profit: Money,     // winnings - buy_ins (cents)
+    pub total_itm: i32,        // Number of tournaments where player finished in the money
+    pub itm_percentage: f64,   // (total_itm / total_tournaments) * 100
+    pub roi_percentage: f64,   // ((total_winnings - total_buy_ins) / total_buy_ins) * 100
+    pub average_finish: f64,   // Average finishing position
+    pub first_places: i32,     // Number of first place finishes
+    pub final_tables: i32,     // Number of final table finishes (club's final-table size)
+    pub points: f64,           // Calculated leaderboard points
+    /// Finishes ahead of players on the same points, in shared tournaments.
+    pub head_to_head_wins: i32,
+    /// Most points scored in a single tournament.
+    pub best_result_points: f64,
+    /// Start of the last tournament that scored points: when the player
+    /// reached their total.
+    pub points_reached_at: Option<DateTime<Utc>>,
 }
 
 #[derive(SimpleObject)]
@@ -64,3 +116,53 @@ pub struct LeaderboardResponse {
     pub total_players: i32,
     pub period: LeaderboardPeriod,
 }
+
+/// What a club's leaderboard stats count as a final table and a cash.
+#[derive(SimpleObject, Clone)]
+pub struct StatsSettings {
+    pub club_id: ID,
+    /// Finishing places that make the final table (9 by default; 6 for
+    /// short-handed final tables).
+    pub final_table_size: i32,
+    /// Places counted as in the money. Null counts any finish with a prize.
+    pub itm_places: Option<i32>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<StatsSettingsRow> for StatsSettings {
+    fn from(row: StatsSettingsRow) -> Self {
+        Self {
+            club_id: row.club_id.into(),
+            final_table_size: row.final_table_size,
+            itm_places: row.itm_places,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+/// Per-query overrides for how `leaderboard` and `seriesLeaderboard` count
+/// stats and rank players. Omitted fields fall back to the club's settings.
+#[derive(InputObject, Default)]
+pub struct LeaderboardOptions {
+    /// Places that count as a final table in `finalTables`. Defaults to each
+    /// tournament's club setting (see clubStatsSettings), 9 without one.
+    pub final_table_size: Option<i32>,
+    /// Places that count as in the money in `totalItm` / `itmPercentage`.
+    /// Defaults to each tournament's club setting; without one, any finish
+    /// with a prize.
+    pub itm_places: Option<i32>,
+    /// How players level on points are ordered, first rule first. Defaults
+    /// to the league's rules with `configId`, otherwise head-to-head, most
+    /// wins, highest single score, earliest achievement.
+    pub tie_breaks: Option<Vec<LeaderboardTieBreak>>,
+}
+
+/// Replaces the club's settings.
+#[derive(InputObject)]
+pub struct UpdateStatsSettingsInput {
+    pub club_id: ID,
+    /// 2 to 10.
+    pub final_table_size: i32,
+    /// Null to count any finish with a prize.
+    pub itm_places: Option<i32>,
+}
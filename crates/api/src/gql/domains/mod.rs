@@ -1,28 +1,55 @@
 // Domain modules will be added here incrementally during migration
 // Each domain contains: mod.rs, resolvers.rs, types.rs
 
+pub mod accounting;
 pub mod achievements;
 pub mod activity_log;
 pub mod analytics;
 pub mod announcements;
 pub mod attendance;
 pub mod auth;
+pub mod bankroll;
+pub mod buy_in_credits;
+pub mod chat;
 pub mod clubs;
+pub mod dashboards;
 pub mod devices;
+pub mod diagnostics;
+pub mod displays;
 pub mod drinks;
 pub mod entries;
+pub mod gallery;
+pub mod groups;
 pub mod identity;
+pub mod imports;
+pub mod incidents;
+pub mod invites;
 pub mod leaderboard_configs;
 pub mod leaderboards;
 pub mod notes;
+pub mod organizations;
+pub mod persisted_operations;
 pub mod predictions;
+pub mod printouts;
+pub mod promotions;
+pub mod qualifications;
+pub mod questions;
+pub mod quotas;
+pub mod raffles;
+pub mod recaps;
 pub mod registrations;
 pub mod results;
+pub mod retention;
+pub mod rules;
 pub mod scouting;
 pub mod seasons;
+pub mod seat_changes;
 pub mod seating;
 pub mod series;
 pub mod social;
+pub mod staff;
+pub mod sync;
 pub mod templates;
+pub mod tickets;
 pub mod tournaments;
 pub mod users;
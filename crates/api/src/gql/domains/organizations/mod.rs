@@ -0,0 +1,10 @@
+//! Organizations: groups of clubs run by one operator, with their own admins
+//! and consolidated finances and leaderboard. With
+//! `require_tournament_approval` on, a tournament a club manager creates
+//! stays hidden from players (`TournamentAccess::AwaitingApproval`) until an
+//! organization admin approves it.
+
+pub mod resolvers;
+pub mod types;
+
+pub use resolvers::{OrganizationMutation, OrganizationQuery};
@@ -0,0 +1,622 @@
+use std::collections::HashMap;
+
+use async_graphql::{Context, Object, Result, ID};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::auth::jwt::Claims;
+use crate::auth::permissions::{require_admin, require_organization_admin, viewer_is_admin};
+use crate::gql::domains::clubs::service::SUPPORTED_COUNTRIES;
+use crate::gql::domains::clubs::types::{ClubManager, ClubPlan};
+use crate::gql::domains::leaderboards::resolvers::to_gql_entry;
+use crate::gql::domains::tournaments::lobby::publish_schedule_change;
+use crate::gql::error::ResultExt;
+use crate::gql::subscriptions::publish_user_notification;
+use crate::gql::types::{
+    Club, ClubTournamentEventType, LeaderboardPeriod, NotificationType, PaginatedResponse,
+    PaginationInput, UserNotification, TITLE_TOURNAMENT_APPROVED, TITLE_TOURNAMENT_REJECTED,
+};
+use crate::services::push_service;
+use crate::state::AppState;
+use infra::repos::{
+    club_managers, clubs, clubs::CreateClubData, organizations, tournament_approvals,
+    tournament_results, tournaments,
+};
+
+use super::types::{
+    ClubFinancials, CreateOrganizationClubInput, CreateOrganizationInput, MoveClubManagerInput,
+    Organization, OrganizationAdmin, OrganizationClubStats, OrganizationFinancialReport,
+    OrganizationLeaderboardEntry, OrganizationPlayerProfile, TournamentApproval,
+};
+
+async fn get_organization(state: &AppState, id: &ID) -> Result<organizations::OrganizationRow> {
+    let id = Uuid::parse_str(id.as_str()).gql_err("Invalid organization ID")?;
+    organizations::get_by_id(&state.db, id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Organization not found"))
+}
+
+/// Resolve a club and check it belongs to the organization.
+async fn get_organization_club(
+    state: &AppState,
+    organization_id: Uuid,
+    club_id: &ID,
+) -> Result<infra::models::ClubRow> {
+    let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+    let club = clubs::get_by_id(&state.db, club_id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Club not found"))?;
+    let in_org = organizations::get_for_club(&state.db, club_id)
+        .await?
+        .is_some_and(|o| o.id == organization_id);
+    if !in_org {
+        return Err(async_graphql::Error::new(
+            "Club belongs to a different organization",
+        ));
+    }
+    Ok(club)
+}
+
+fn caller_id(ctx: &Context<'_>) -> Result<Uuid> {
+    let claims = ctx.data::<Claims>()?;
+    Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")
+}
+
+/// Approve or reject a tournament awaiting its organization's review, then
+/// tell the manager who created it. An approved tournament joins the lobby
+/// schedule as if just created.
+async fn review_tournament(
+    ctx: &Context<'_>,
+    tournament_id: ID,
+    approved: bool,
+    note: Option<String>,
+) -> Result<TournamentApproval> {
+    let state = ctx.data::<AppState>()?;
+    let tournament_id = Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+    let pending = tournament_approvals::get(&state.db, tournament_id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Tournament doesn't need approval"))?;
+    let reviewer = require_organization_admin(ctx, pending.organization_id).await?;
+    let reviewer = Uuid::parse_str(reviewer.id.as_str()).gql_err("Invalid user ID")?;
+    let tournament = tournaments::get_by_id(&state.db, tournament_id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+
+    let note = note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+    let reviewed = tournament_approvals::review(
+        &state.db,
+        tournament_id,
+        approved,
+        reviewer,
+        note.as_deref(),
+    )
+    .await?
+    .ok_or_else(|| async_graphql::Error::new("Tournament has already been reviewed"))?;
+
+    if approved {
+        publish_schedule_change(ClubTournamentEventType::Created, &tournament);
+    }
+
+    let db = state.db.clone();
+    let creator = reviewed.requested_by;
+    tokio::spawn(async move {
+        crate::gql::domains::activity_log::log_and_publish(
+            &db,
+            tournament_id,
+            "tournament",
+            if approved { "approved" } else { "rejected" },
+            Some(reviewer),
+            None,
+            serde_json::json!({ "note": note }),
+        )
+        .await;
+
+        let Some(user_id) = creator else {
+            return;
+        };
+        let (notification_type, title, message) = if approved {
+            (
+                NotificationType::TournamentApproved,
+                TITLE_TOURNAMENT_APPROVED,
+                format!(
+                    "{} was approved and is now on the schedule",
+                    tournament.name
+                ),
+            )
+        } else {
+            (
+                NotificationType::TournamentRejected,
+                TITLE_TOURNAMENT_REJECTED,
+                match &note {
+                    Some(note) => format!("{} wasn't approved: {}", tournament.name, note),
+                    None => format!("{} wasn't approved", tournament.name),
+                },
+            )
+        };
+        publish_user_notification(UserNotification {
+            id: ID::from(Uuid::new_v4().to_string()),
+            user_id: ID::from(user_id.to_string()),
+            notification_type,
+            title: title.to_string(),
+            message,
+            tournament_id: Some(ID::from(tournament_id.to_string())),
+            created_at: chrono::Utc::now(),
+        });
+        push_service::send_tournament_reviewed(&db, user_id, tournament_id, approved).await;
+    });
+
+    Ok(reviewed.into())
+}
+
+fn is_valid_slug(slug: &str) -> bool {
+    !slug.is_empty()
+        && !slug.starts_with('-')
+        && !slug.ends_with('-')
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// Load the leaderboard rows for an organization and attach each player's
+/// per-club breakdown (one extra query for the whole page).
+async fn load_leaderboard(
+    state: &AppState,
+    organization_id: Uuid,
+    period: LeaderboardPeriod,
+    limit: i32,
+    offset: i32,
+    user_id: Option<Uuid>,
+    exclude_free: bool,
+) -> Result<(Vec<OrganizationLeaderboardEntry>, i64)> {
+    let period: tournament_results::LeaderboardPeriod = period.into();
+    let (rows, total) = tournament_results::get_organization_leaderboard(
+        &state.db,
+        organization_id,
+        period,
+        Some(limit),
+        Some(offset),
+        user_id,
+        exclude_free,
+    )
+    .await?;
+
+    let keys: Vec<Uuid> = rows.iter().map(|r| r.player_key).collect();
+    let mut breakdown: HashMap<Uuid, Vec<OrganizationClubStats>> = HashMap::new();
+    if !keys.is_empty() {
+        for row in tournament_results::get_organization_club_breakdown(
+            &state.db,
+            organization_id,
+            period,
+            &keys,
+            exclude_free,
+        )
+        .await?
+        {
+            breakdown
+                .entry(row.player_key)
+                .or_default()
+                .push(row.into());
+        }
+    }
+
+    let entries = rows
+        .into_iter()
+        .map(|row| OrganizationLeaderboardEntry {
+            club_breakdown: breakdown.remove(&row.player_key).unwrap_or_default(),
+            clubs_played: row.clubs_played,
+            entry: to_gql_entry(row.entry, row.rank),
+        })
+        .collect();
+    Ok((entries, total))
+}
+
+#[derive(Default)]
+pub struct OrganizationQuery;
+
+#[Object]
+impl OrganizationQuery {
+    async fn organization(&self, ctx: &Context<'_>, id: ID) -> Result<Organization> {
+        let state = ctx.data::<AppState>()?;
+        Ok(get_organization(state, &id).await?.into())
+    }
+
+    /// Every organization (admins only).
+    async fn organizations(&self, ctx: &Context<'_>) -> Result<Vec<Organization>> {
+        require_admin(ctx).await?;
+        let state = ctx.data::<AppState>()?;
+        let rows = organizations::list(&state.db).await?;
+        Ok(rows.into_iter().map(Organization::from).collect())
+    }
+
+    /// Organizations the viewer administers.
+    async fn my_organizations(&self, ctx: &Context<'_>) -> Result<Vec<Organization>> {
+        let user_id = caller_id(ctx)?;
+        let state = ctx.data::<AppState>()?;
+        let rows = organizations::list_for_admin(&state.db, user_id).await?;
+        Ok(rows.into_iter().map(Organization::from).collect())
+    }
+
+    /// An organization's admins (organization admins only).
+    async fn organization_admins(
+        &self,
+        ctx: &Context<'_>,
+        organization_id: ID,
+    ) -> Result<Vec<OrganizationAdmin>> {
+        let state = ctx.data::<AppState>()?;
+        let organization = get_organization(state, &organization_id).await?;
+        require_organization_admin(ctx, organization.id).await?;
+
+        // Fellow admins needn't share a club with the caller.
+        let rows = infra::db::without_request_scope(organizations::list_admins(
+            &state.db,
+            organization.id,
+        ))
+        .await?;
+        Ok(rows.into_iter().map(OrganizationAdmin::from).collect())
+    }
+
+    /// Tournaments created at the organization's clubs that are waiting for
+    /// approval, oldest first (organization admins only).
+    async fn pending_tournament_approvals(
+        &self,
+        ctx: &Context<'_>,
+        organization_id: ID,
+    ) -> Result<Vec<TournamentApproval>> {
+        let state = ctx.data::<AppState>()?;
+        let organization = get_organization(state, &organization_id).await?;
+        require_organization_admin(ctx, organization.id).await?;
+
+        let rows = tournament_approvals::list_pending(&state.db, organization.id).await?;
+        Ok(rows.into_iter().map(TournamentApproval::from).collect())
+    }
+
+    /// Money across every club of the organization over `[from, to)`, per club
+    /// and in total (organization admins only).
+    async fn organization_financial_report(
+        &self,
+        ctx: &Context<'_>,
+        organization_id: ID,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<OrganizationFinancialReport> {
+        let state = ctx.data::<AppState>()?;
+        let organization = get_organization(state, &organization_id).await?;
+        require_organization_admin(ctx, organization.id).await?;
+        if to <= from {
+            return Err(async_graphql::Error::new("`to` must be after `from`"));
+        }
+
+        let rows = organizations::financial_report(&state.db, organization.id, from, to).await?;
+        let sum = |f: fn(&organizations::ClubFinancialsRow) -> i64| rows.iter().map(f).sum::<i64>();
+        let report = OrganizationFinancialReport {
+            organization_id: organization.id.into(),
+            from,
+            to,
+            tournament_count: sum(|r| r.tournament_count) as i32,
+            entry_count: sum(|r| r.entry_count) as i32,
+            total_collected_cents: sum(|r| r.total_collected_cents).into(),
+            total_rake_cents: sum(|r| r.total_rake_cents).into(),
+            prize_pool_cents: sum(|r| r.prize_pool_cents).into(),
+            staff_cost_cents: sum(|r| r.staff_cost_cents).into(),
+            clubs: rows.into_iter().map(ClubFinancials::from).collect(),
+        };
+        Ok(report)
+    }
+
+    /// Leaderboard across every club in the organization. App users are ranked
+    /// once on their combined results, with a per-club breakdown.
+    async fn organization_leaderboard(
+        &self,
+        ctx: &Context<'_>,
+        organization_id: ID,
+        period: Option<LeaderboardPeriod>,
+        pagination: Option<PaginationInput>,
+    ) -> Result<PaginatedResponse<OrganizationLeaderboardEntry>> {
+        let state = ctx.data::<AppState>()?;
+        let organization = get_organization(state, &organization_id).await?;
+
+        let page_params = pagination.unwrap_or(PaginationInput {
+            limit: Some(100),
+            offset: Some(0),
+        });
+        let limit_offset = page_params.to_limit_offset();
+        let offset = limit_offset.offset as i32;
+
+        // Free ("Home Game") clubs stay out of player-facing leaderboards.
+        let (entries, total_count) = load_leaderboard(
+            state,
+            organization.id,
+            period.unwrap_or(LeaderboardPeriod::AllTime),
+            limit_offset.limit as i32,
+            offset,
+            None,
+            !viewer_is_admin(ctx),
+        )
+        .await?;
+
+        let page_size = entries.len() as i32;
+        Ok(PaginatedResponse {
+            items: entries,
+            total_count: total_count as i32,
+            page_size,
+            offset,
+            has_next_page: (offset + page_size) < total_count as i32,
+        })
+    }
+
+    /// The viewer's combined stats, rank and loyalty across an organization.
+    async fn my_organization_profile(
+        &self,
+        ctx: &Context<'_>,
+        organization_id: ID,
+        period: Option<LeaderboardPeriod>,
+    ) -> Result<OrganizationPlayerProfile> {
+        let claims = ctx.data::<Claims>()?;
+        let user_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+        let state = ctx.data::<AppState>()?;
+        let organization = get_organization(state, &organization_id).await?;
+
+        let (mut entries, _) = load_leaderboard(
+            state,
+            organization.id,
+            period.unwrap_or(LeaderboardPeriod::AllTime),
+            1,
+            0,
+            Some(user_id),
+            true,
+        )
+        .await?;
+        let loyalty = organizations::loyalty_for_user(&state.db, organization.id, user_id).await?;
+
+        Ok(OrganizationPlayerProfile {
+            organization: organization.into(),
+            stats: entries.pop(),
+            loyalty: loyalty.into(),
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct OrganizationMutation;
+
+#[Object]
+impl OrganizationMutation {
+    /// Create an organization (admins only).
+    async fn create_organization(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateOrganizationInput,
+    ) -> Result<Organization> {
+        require_admin(ctx).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let name = input.name.trim();
+        if name.is_empty() {
+            return Err(async_graphql::Error::new("A name is required"));
+        }
+        let slug = input.slug.trim();
+        if !is_valid_slug(slug) {
+            return Err(async_graphql::Error::new(
+                "Slug may only contain lowercase letters, digits and dashes",
+            ));
+        }
+
+        let row = organizations::create(&state.db, name, slug)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Slug is already taken"))?;
+        Ok(row.into())
+    }
+
+    /// Make a user an admin of the organization (organization admins only).
+    async fn add_organization_admin(
+        &self,
+        ctx: &Context<'_>,
+        organization_id: ID,
+        user_id: ID,
+    ) -> Result<Vec<OrganizationAdmin>> {
+        let state = ctx.data::<AppState>()?;
+        let organization = get_organization(state, &organization_id).await?;
+        let caller = require_organization_admin(ctx, organization.id).await?;
+        let caller = Uuid::parse_str(caller.id.as_str()).gql_err("Invalid user ID")?;
+        let user_id = Uuid::parse_str(user_id.as_str()).gql_err("Invalid user ID")?;
+
+        infra::db::without_request_scope(infra::repos::users::get_by_id(&state.db, user_id))
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("User not found"))?;
+        organizations::add_admin(&state.db, organization.id, user_id, caller).await?;
+
+        // Fellow admins needn't share a club with the caller.
+        let rows = infra::db::without_request_scope(organizations::list_admins(
+            &state.db,
+            organization.id,
+        ))
+        .await?;
+        Ok(rows.into_iter().map(OrganizationAdmin::from).collect())
+    }
+
+    /// Remove an organization admin (organization admins only). The last admin
+    /// of an organization can only be removed by a platform admin.
+    async fn remove_organization_admin(
+        &self,
+        ctx: &Context<'_>,
+        organization_id: ID,
+        user_id: ID,
+    ) -> Result<bool> {
+        let state = ctx.data::<AppState>()?;
+        let organization = get_organization(state, &organization_id).await?;
+        require_organization_admin(ctx, organization.id).await?;
+        let user_id = Uuid::parse_str(user_id.as_str()).gql_err("Invalid user ID")?;
+
+        if !viewer_is_admin(ctx)
+            && organizations::count_admins(&state.db, organization.id).await? <= 1
+        {
+            return Err(async_graphql::Error::new(
+                "Cannot remove the last admin of an organization",
+            ));
+        }
+        Ok(organizations::remove_admin(&state.db, organization.id, user_id).await?)
+    }
+
+    /// Open a new club inside the organization (organization admins only).
+    /// The club is billed on the organization's tier: the highest plan among
+    /// its existing clubs, and at least the paid club plan.
+    async fn create_organization_club(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateOrganizationClubInput,
+    ) -> Result<Club> {
+        let state = ctx.data::<AppState>()?;
+        let organization = get_organization(state, &input.organization_id).await?;
+        require_organization_admin(ctx, organization.id).await?;
+
+        let name = input.name.trim();
+        if name.is_empty() {
+            return Err(async_graphql::Error::new("A club name is required"));
+        }
+        let country = input.country.trim().to_uppercase();
+        if !SUPPORTED_COUNTRIES.contains(&country.as_str()) {
+            return Err(async_graphql::Error::new("Unsupported country"));
+        }
+
+        let existing = organizations::list_clubs(&state.db, organization.id).await?;
+        let plan = if existing.iter().any(|c| c.plan == ClubPlan::Casino.as_db()) {
+            ClubPlan::Casino
+        } else {
+            ClubPlan::Club
+        };
+
+        // The new club is in no one's scope until it joins the organization.
+        let mut tx = infra::db::without_request_scope(state.db.begin()).await?;
+        let club = clubs::create(
+            &mut *tx,
+            CreateClubData {
+                name: name.to_string(),
+                address: input.address,
+                city: input.city,
+                postal_code: input.postal_code,
+                country,
+                vat_number: input.vat_number,
+                needs_review: false,
+                plan: plan.as_db().to_string(),
+            },
+        )
+        .await?;
+        organizations::set_club_organization(&mut *tx, club.id, Some(organization.id)).await?;
+        tx.commit().await?;
+
+        Ok(club.into())
+    }
+
+    /// Move a manager from one of the organization's clubs to another
+    /// (organization admins only). A club's last manager can't be moved away.
+    async fn move_club_manager(
+        &self,
+        ctx: &Context<'_>,
+        input: MoveClubManagerInput,
+    ) -> Result<ClubManager> {
+        let state = ctx.data::<AppState>()?;
+        let from_club_id =
+            Uuid::parse_str(input.from_club_id.as_str()).gql_err("Invalid club ID")?;
+        let organization = organizations::get_for_club(&state.db, from_club_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Club is not part of an organization"))?;
+        let caller = require_organization_admin(ctx, organization.id).await?;
+        let caller = Uuid::parse_str(caller.id.as_str()).gql_err("Invalid user ID")?;
+        let to_club = get_organization_club(state, organization.id, &input.to_club_id).await?;
+        if to_club.id == from_club_id {
+            return Err(async_graphql::Error::new(
+                "Source and destination clubs are the same",
+            ));
+        }
+        let user_id = Uuid::parse_str(input.user_id.as_str()).gql_err("Invalid user ID")?;
+
+        let assignment = club_managers::find_active(&state.db, from_club_id, user_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("User does not manage the source club"))?;
+        if club_managers::count_active_by_club(&state.db, from_club_id).await? <= 1 {
+            return Err(async_graphql::Error::new(
+                "Cannot move the last manager of a club",
+            ));
+        }
+
+        club_managers::create_or_reactivate(&state.db, to_club.id, user_id, caller).await?;
+        club_managers::deactivate(&state.db, assignment.id).await?;
+
+        club_managers::list_by_club_with_users(&state.db, to_club.id)
+            .await?
+            .into_iter()
+            .find(|m| m.user_id == user_id)
+            .map(ClubManager::from)
+            .ok_or_else(|| async_graphql::Error::new("Manager assignment not found"))
+    }
+
+    /// Require (or stop requiring) approval of tournaments that club managers
+    /// create at the organization's clubs (organization admins only).
+    /// Tournaments already created are unaffected.
+    async fn set_organization_tournament_approval(
+        &self,
+        ctx: &Context<'_>,
+        organization_id: ID,
+        required: bool,
+    ) -> Result<Organization> {
+        let state = ctx.data::<AppState>()?;
+        let organization = get_organization(state, &organization_id).await?;
+        require_organization_admin(ctx, organization.id).await?;
+
+        let row =
+            organizations::set_require_tournament_approval(&state.db, organization.id, required)
+                .await?
+                .ok_or_else(|| async_graphql::Error::new("Organization not found"))?;
+        Ok(row.into())
+    }
+
+    /// Approve a tournament awaiting review: it becomes visible and open for
+    /// registration (organization admins only).
+    async fn approve_tournament(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        note: Option<String>,
+    ) -> Result<TournamentApproval> {
+        review_tournament(ctx, tournament_id, true, note).await
+    }
+
+    /// Reject a tournament awaiting review; it stays hidden. The reason is
+    /// passed on to the manager who created it (organization admins only).
+    async fn reject_tournament(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        reason: Option<String>,
+    ) -> Result<TournamentApproval> {
+        review_tournament(ctx, tournament_id, false, reason).await
+    }
+
+    /// Move a club into an organization, or out of it by omitting
+    /// `organizationId` (admins only).
+    async fn set_club_organization(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+        organization_id: Option<ID>,
+    ) -> Result<Option<Organization>> {
+        require_admin(ctx).await?;
+        let state = ctx.data::<AppState>()?;
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+
+        let organization = match organization_id {
+            Some(id) => Some(get_organization(state, &id).await?),
+            None => None,
+        };
+        let updated = organizations::set_club_organization(
+            &state.db,
+            club_id,
+            organization.as_ref().map(|o| o.id),
+        )
+        .await?;
+        if !updated {
+            return Err(async_graphql::Error::new("Club not found"));
+        }
+        Ok(organization.map(Organization::from))
+    }
+}
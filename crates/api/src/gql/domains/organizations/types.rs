@@ -0,0 +1,273 @@
+use async_graphql::{ComplexObject, Context, Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use crate::gql::domains::clubs::types::{Club, ClubPlan};
+use crate::gql::domains::leaderboards::types::LeaderboardEntry;
+use crate::gql::domains::tournaments::types::Tournament;
+use crate::gql::scalars::Money;
+use crate::state::AppState;
+use infra::repos::organizations::{
+    self, ClubFinancialsRow, OrganizationAdminRow, OrganizationLoyaltyRow, OrganizationRow,
+};
+use infra::repos::tournament_approvals::TournamentApprovalRow;
+use infra::repos::tournament_results::OrganizationClubBreakdown as ClubBreakdownRow;
+
+/// A group of clubs run by the same operator. Players' stats and loyalty
+/// carry across its venues through their app account.
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct Organization {
+    pub id: ID,
+    pub name: String,
+    pub slug: String,
+    /// Tournaments created by club managers stay off public schedules until
+    /// an organization admin approves them.
+    pub require_tournament_approval: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<OrganizationRow> for Organization {
+    fn from(row: OrganizationRow) -> Self {
+        Self {
+            id: row.id.into(),
+            name: row.name,
+            slug: row.slug,
+            require_tournament_approval: row.require_tournament_approval,
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[ComplexObject]
+impl Organization {
+    async fn clubs(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Club>> {
+        let state = ctx.data::<AppState>()?;
+        let id = uuid::Uuid::parse_str(self.id.as_str())?;
+        let rows = organizations::list_clubs(&state.db, id).await?;
+        Ok(rows.into_iter().map(Club::from).collect())
+    }
+}
+
+/// A player's totals at one club of the organization.
+#[derive(SimpleObject, Clone)]
+pub struct OrganizationClubStats {
+    pub club_id: ID,
+    pub club_name: String,
+    pub total_tournaments: i32,
+    pub total_buy_ins: Money,
+    pub total_winnings: Money,
+    pub points: f64,
+}
+
+impl From<ClubBreakdownRow> for OrganizationClubStats {
+    fn from(row: ClubBreakdownRow) -> Self {
+        Self {
+            club_id: row.club_id.into(),
+            club_name: row.club_name,
+            total_tournaments: row.total_tournaments,
+            total_buy_ins: row.total_buy_ins.into(),
+            total_winnings: row.total_winnings.into(),
+            points: row.points,
+        }
+    }
+}
+
+/// An organization-wide leaderboard row: the usual leaderboard stats summed
+/// over every club in the organization, plus the per-club split.
+#[derive(SimpleObject, Clone)]
+pub struct OrganizationLeaderboardEntry {
+    #[graphql(flatten)]
+    pub entry: LeaderboardEntry,
+    /// Number of the organization's clubs the player has played at.
+    pub clubs_played: i32,
+    pub club_breakdown: Vec<OrganizationClubStats>,
+}
+
+/// How often a player turns up across the organization's venues.
+#[derive(SimpleObject, Clone)]
+pub struct OrganizationLoyalty {
+    pub tournaments_played: i32,
+    pub check_ins: i32,
+    pub clubs_visited: i32,
+    /// Distinct calendar months with at least one tournament played.
+    pub months_active: i32,
+    pub first_played_at: Option<DateTime<Utc>>,
+    pub last_played_at: Option<DateTime<Utc>>,
+}
+
+impl From<OrganizationLoyaltyRow> for OrganizationLoyalty {
+    fn from(row: OrganizationLoyaltyRow) -> Self {
+        Self {
+            tournaments_played: row.tournaments_played as i32,
+            check_ins: row.check_ins as i32,
+            clubs_visited: row.clubs_visited as i32,
+            months_active: row.months_active as i32,
+            first_played_at: row.first_played_at,
+            last_played_at: row.last_played_at,
+        }
+    }
+}
+
+/// The viewer's standing across an organization.
+#[derive(SimpleObject, Clone)]
+pub struct OrganizationPlayerProfile {
+    pub organization: Organization,
+    /// Null until the player has played at one of the organization's clubs.
+    pub stats: Option<OrganizationLeaderboardEntry>,
+    pub loyalty: OrganizationLoyalty,
+}
+
+#[derive(InputObject)]
+pub struct CreateOrganizationInput {
+    pub name: String,
+    /// Lowercase letters, digits and dashes.
+    pub slug: String,
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct OrganizationAdmin {
+    pub id: ID,
+    pub organization_id: ID,
+    pub user_id: ID,
+    pub email: String,
+    pub first_name: String,
+    pub last_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<OrganizationAdminRow> for OrganizationAdmin {
+    fn from(row: OrganizationAdminRow) -> Self {
+        Self {
+            id: row.id.into(),
+            organization_id: row.organization_id.into(),
+            user_id: row.user_id.into(),
+            email: row.email,
+            first_name: row.first_name,
+            last_name: row.last_name,
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// One club's line in the consolidated financial report.
+#[derive(SimpleObject, Clone)]
+pub struct ClubFinancials {
+    pub club_id: ID,
+    pub club_name: String,
+    pub plan: ClubPlan,
+    pub tournament_count: i32,
+    pub entry_count: i32,
+    /// Gross collected across every entry.
+    pub total_collected_cents: Money,
+    pub total_rake_cents: Money,
+    pub prize_pool_cents: Money,
+    /// Labour cost of the closed staff shifts clocked in during the window.
+    pub staff_cost_cents: Money,
+}
+
+impl From<ClubFinancialsRow> for ClubFinancials {
+    fn from(row: ClubFinancialsRow) -> Self {
+        Self {
+            club_id: row.club_id.into(),
+            club_name: row.club_name,
+            plan: ClubPlan::from_db(&row.plan),
+            tournament_count: row.tournament_count as i32,
+            entry_count: row.entry_count as i32,
+            total_collected_cents: row.total_collected_cents.into(),
+            total_rake_cents: row.total_rake_cents.into(),
+            prize_pool_cents: row.prize_pool_cents.into(),
+            staff_cost_cents: row.staff_cost_cents.into(),
+        }
+    }
+}
+
+/// Money across every club of an organization over `[from, to)`.
+#[derive(SimpleObject, Clone)]
+pub struct OrganizationFinancialReport {
+    pub organization_id: ID,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub clubs: Vec<ClubFinancials>,
+    pub tournament_count: i32,
+    pub entry_count: i32,
+    pub total_collected_cents: Money,
+    pub total_rake_cents: Money,
+    pub prize_pool_cents: Money,
+    pub staff_cost_cents: Money,
+}
+
+#[derive(InputObject)]
+pub struct CreateOrganizationClubInput {
+    pub organization_id: ID,
+    pub name: String,
+    pub address: Option<String>,
+    pub city: Option<String>,
+    pub postal_code: Option<String>,
+    /// ISO 2-letter code.
+    pub country: String,
+    pub vat_number: Option<String>,
+}
+
+#[derive(InputObject)]
+pub struct MoveClubManagerInput {
+    pub user_id: ID,
+    pub from_club_id: ID,
+    pub to_club_id: ID,
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TournamentApprovalStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl From<&str> for TournamentApprovalStatus {
+    fn from(s: &str) -> Self {
+        match s {
+            "approved" => Self::Approved,
+            "rejected" => Self::Rejected,
+            _ => Self::Pending,
+        }
+    }
+}
+
+/// An organization's review of a tournament created at one of its clubs.
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct TournamentApproval {
+    pub tournament_id: ID,
+    pub organization_id: ID,
+    pub status: TournamentApprovalStatus,
+    /// The manager who created the tournament.
+    pub requested_by: Option<ID>,
+    pub requested_at: DateTime<Utc>,
+    pub reviewed_by: Option<ID>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+}
+
+impl From<TournamentApprovalRow> for TournamentApproval {
+    fn from(row: TournamentApprovalRow) -> Self {
+        Self {
+            tournament_id: row.tournament_id.into(),
+            organization_id: row.organization_id.into(),
+            status: row.status.as_str().into(),
+            requested_by: row.requested_by.map(Into::into),
+            requested_at: row.requested_at,
+            reviewed_by: row.reviewed_by.map(Into::into),
+            reviewed_at: row.reviewed_at,
+            review_note: row.review_note,
+        }
+    }
+}
+
+#[ComplexObject]
+impl TournamentApproval {
+    async fn tournament(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Tournament>> {
+        let state = ctx.data::<AppState>()?;
+        let id = uuid::Uuid::parse_str(self.tournament_id.as_str())?;
+        let row = infra::repos::tournaments::get_by_id(&state.db, id).await?;
+        Ok(row.map(Tournament::from))
+    }
+}
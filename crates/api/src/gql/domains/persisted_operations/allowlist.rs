@@ -0,0 +1,148 @@
+//! Locked mode: with GQL_OPERATION_ALLOWLIST=true only operations registered
+//! for the calling API client may execute, so production rejects ad-hoc
+//! queries while staging keeps its playground.
+//!
+//! Clients name themselves with the `x-client-name` header (in the
+//! `connection_init` headers over WebSocket) and send either the registered
+//! query text or, Apollo-style, only its hash in
+//! `extensions.persistedQuery.sha256Hash`. Admins are exempt so operations
+//! can be registered at deploy time.
+
+use std::any::{Any, TypeId};
+use std::sync::Arc;
+
+use async_graphql::extensions::{
+    Extension, ExtensionContext, ExtensionFactory, NextPrepareRequest,
+};
+use async_graphql::{Request, ServerError, ServerResult, Value};
+use sha2::{Digest, Sha256};
+
+use crate::auth::Claims;
+use crate::state::AppState;
+use infra::repos::persisted_operations;
+
+/// Header naming the API client a request comes from.
+pub const CLIENT_NAME_HEADER: &str = "x-client-name";
+
+/// The API client a request comes from, as it named itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApiClient(pub String);
+
+/// Hex SHA-256 of the exact query text, as clients compute it.
+pub fn operation_hash(query: &str) -> String {
+    Sha256::digest(query.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// The hash a request names in `extensions.persistedQuery`, if any.
+fn persisted_hash(request: &Request) -> Option<&str> {
+    match request.extensions.get("persistedQuery") {
+        Some(Value::Object(persisted)) => match persisted.get("sha256Hash") {
+            Some(Value::String(hash)) => Some(hash.as_str()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Rejects every operation not on the calling client's allow-list.
+pub struct OperationAllowList;
+
+impl ExtensionFactory for OperationAllowList {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(OperationAllowList)
+    }
+}
+
+#[async_graphql::async_trait::async_trait]
+impl Extension for OperationAllowList {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        mut request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        // Query data isn't attached to the context yet; WebSocket connection
+        // data is.
+        fn lookup<'a, T: Any + Send + Sync>(
+            request: &'a Request,
+            ctx: &ExtensionContext<'a>,
+        ) -> Option<&'a T> {
+            request
+                .data
+                .get(&TypeId::of::<T>())
+                .and_then(|d| d.downcast_ref::<T>())
+                .or_else(|| ctx.data_opt::<T>())
+        }
+
+        if lookup::<Claims>(&request, ctx).is_some_and(|claims| claims.role == "admin") {
+            return next.run(ctx, request).await;
+        }
+        let Some(ApiClient(client)) = lookup::<ApiClient>(&request, ctx).cloned() else {
+            return Err(ServerError::new(
+                format!("Unknown API client: send the {CLIENT_NAME_HEADER} header"),
+                None,
+            ));
+        };
+
+        let by_hash = request.query.is_empty();
+        let hash = match persisted_hash(&request) {
+            Some(hash) if by_hash => hash.to_string(),
+            _ => operation_hash(&request.query),
+        };
+        let state = ctx
+            .data::<AppState>()
+            .map_err(|e| ServerError::new(e.message, None))?;
+        let operation = persisted_operations::find_active(&state.db, &client, &hash)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to look up persisted operation: {e}");
+                ServerError::new("Failed to check the operation allow-list", None)
+            })?;
+        match operation {
+            Some(operation) => {
+                if by_hash {
+                    request.query = operation.query;
+                }
+                next.run(ctx, request).await
+            }
+            None => {
+                tracing::warn!("Rejected unregistered operation {hash} from client {client}");
+                Err(ServerError::new(
+                    "Operation is not on this client's allow-list",
+                    None,
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hashes_match_the_clients_sha256() {
+        assert_eq!(
+            operation_hash("{ __typename }"),
+            "7f56e67dd21ab3f30d1ff8b7bed08893f0a0db86449836189b361dd1e56ddb4b"
+        );
+    }
+
+    #[test]
+    fn reads_apollo_style_hashes() {
+        let mut request = Request::new("");
+        assert_eq!(persisted_hash(&request), None);
+        let mut persisted = async_graphql::indexmap::IndexMap::new();
+        persisted.insert(
+            async_graphql::Name::new("sha256Hash"),
+            Value::String("abc".into()),
+        );
+        request
+            .extensions
+            .insert("persistedQuery".into(), Value::Object(persisted));
+        assert_eq!(persisted_hash(&request), Some("abc"));
+    }
+}
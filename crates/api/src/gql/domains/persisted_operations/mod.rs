@@ -0,0 +1,5 @@
+pub mod allowlist;
+pub mod resolvers;
+pub mod types;
+
+pub use resolvers::{PersistedOperationMutation, PersistedOperationQuery};
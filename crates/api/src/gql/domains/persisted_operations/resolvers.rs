@@ -0,0 +1,96 @@
+use async_graphql::{Context, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::auth::permissions::require_admin;
+use crate::gql::error::ResultExt;
+use crate::state::AppState;
+use infra::repos::persisted_operations;
+
+use super::allowlist::operation_hash;
+use super::types::{PersistedOperation, RegisterPersistedOperationsInput};
+
+#[derive(Default)]
+pub struct PersistedOperationQuery;
+
+#[Object]
+impl PersistedOperationQuery {
+    /// The operation allow-list, optionally of one client. Admins only.
+    async fn persisted_operations(
+        &self,
+        ctx: &Context<'_>,
+        client_name: Option<String>,
+        #[graphql(default = false)] include_revoked: bool,
+    ) -> Result<Vec<PersistedOperation>> {
+        require_admin(ctx).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let rows =
+            persisted_operations::list(&state.db, client_name.as_deref(), include_revoked).await?;
+        Ok(rows.into_iter().map(PersistedOperation::from).collect())
+    }
+}
+
+#[derive(Default)]
+pub struct PersistedOperationMutation;
+
+#[Object]
+impl PersistedOperationMutation {
+    /// Allow a client's operations, typically from its build at deploy time.
+    /// Registering an operation again reinstates it. Admins only.
+    async fn register_persisted_operations(
+        &self,
+        ctx: &Context<'_>,
+        input: RegisterPersistedOperationsInput,
+    ) -> Result<Vec<PersistedOperation>> {
+        let admin = require_admin(ctx).await?;
+        let admin_id = Uuid::parse_str(admin.id.as_str()).gql_err("Invalid user ID")?;
+        let state = ctx.data::<AppState>()?;
+
+        let client_name = input.client_name.trim();
+        if client_name.is_empty() {
+            return Err(async_graphql::Error::new("Client name is required"));
+        }
+        for operation in &input.operations {
+            async_graphql::parser::parse_query(&operation.query).map_err(|e| {
+                async_graphql::Error::new(format!(
+                    "Invalid operation {}: {e}",
+                    operation.operation_name.as_deref().unwrap_or("(unnamed)")
+                ))
+            })?;
+        }
+
+        let mut tx = state.db.begin().await?;
+        let mut registered = Vec::with_capacity(input.operations.len());
+        for operation in &input.operations {
+            let row = persisted_operations::register(
+                &mut *tx,
+                client_name,
+                operation.operation_name.as_deref(),
+                &operation_hash(&operation.query),
+                &operation.query,
+                Some(admin_id),
+            )
+            .await?;
+            registered.push(row.into());
+        }
+        tx.commit().await?;
+
+        Ok(registered)
+    }
+
+    /// Take an operation off the allow-list. Admins only.
+    async fn revoke_persisted_operation(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+    ) -> Result<PersistedOperation> {
+        require_admin(ctx).await?;
+        let state = ctx.data::<AppState>()?;
+        let id = Uuid::parse_str(id.as_str()).gql_err("Invalid operation ID")?;
+
+        let row = persisted_operations::revoke(&state.db, id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Operation not found"))?;
+        Ok(row.into())
+    }
+}
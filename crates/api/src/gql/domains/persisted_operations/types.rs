@@ -0,0 +1,46 @@
+use async_graphql::{InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use infra::repos::persisted_operations::PersistedOperationRow;
+
+/// An operation an API client may run when the server is locked.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct PersistedOperation {
+    pub id: ID,
+    pub client_name: String,
+    pub operation_name: Option<String>,
+    /// Hex SHA-256 of the query text; clients may send it alone as
+    /// `extensions.persistedQuery.sha256Hash`.
+    pub sha256_hash: String,
+    pub query: String,
+    pub created_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl From<PersistedOperationRow> for PersistedOperation {
+    fn from(row: PersistedOperationRow) -> Self {
+        Self {
+            id: row.id.into(),
+            client_name: row.client_name,
+            operation_name: row.operation_name,
+            sha256_hash: row.sha256_hash,
+            query: row.query,
+            created_at: row.created_at,
+            revoked_at: row.revoked_at,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct PersistedOperationInput {
+    pub operation_name: Option<String>,
+    /// The exact text the client sends; the hash covers every character.
+    pub query: String,
+}
+
+#[derive(InputObject)]
+pub struct RegisterPersistedOperationsInput {
+    /// Name the client sends in the `x-client-name` header.
+    pub client_name: String,
+    pub operations: Vec<PersistedOperationInput>,
+}
@@ -0,0 +1,352 @@
+//! ESC/POS rendering of buy-in receipts and seat slips for the desk's thermal
+//! printer.
+//!
+//! The payload is raw printer bytes: the desk's print bridge (or a WebUSB
+//! page) forwards it as-is. Text is sent in code page 858 (CP850 with the
+//! euro sign), laid out for 80 mm paper in font A, and the verification code
+//! is printed as a QR code by the printer itself.
+
+use infra::repos::entry_receipts::ReceiptRow;
+use infra::repos::seat_slip_jobs::SeatSlipJobRow;
+
+use crate::gql::scalars::Money;
+
+/// Characters per line on 80 mm paper in font A.
+pub const LINE_WIDTH: usize = 48;
+
+const ESC: u8 = 0x1b;
+const GS: u8 = 0x1d;
+/// `ESC t` number of code page 858.
+const CODE_PAGE_858: u8 = 19;
+
+/// A minimal ESC/POS command writer.
+#[derive(Default)]
+struct EscPos {
+    bytes: Vec<u8>,
+}
+
+impl EscPos {
+    /// Reset the printer and select code page 858.
+    fn init(&mut self) -> &mut Self {
+        self.bytes
+            .extend_from_slice(&[ESC, b'@', ESC, b't', CODE_PAGE_858]);
+        self
+    }
+
+    fn center(&mut self, on: bool) -> &mut Self {
+        self.bytes.extend_from_slice(&[ESC, b'a', u8::from(on)]);
+        self
+    }
+
+    fn bold(&mut self, on: bool) -> &mut Self {
+        self.bytes.extend_from_slice(&[ESC, b'E', u8::from(on)]);
+        self
+    }
+
+    /// Double width and height (halves the characters per line).
+    fn large(&mut self, on: bool) -> &mut Self {
+        self.bytes
+            .extend_from_slice(&[GS, b'!', if on { 0x11 } else { 0x00 }]);
+        self
+    }
+
+    fn line(&mut self, text: &str) -> &mut Self {
+        self.bytes.extend(encode(text));
+        self.bytes.push(b'\n');
+        self
+    }
+
+    fn rule(&mut self) -> &mut Self {
+        self.line(&"-".repeat(LINE_WIDTH))
+    }
+
+    /// Print `data` as a QR code (model 2, module size 6, correction M).
+    fn qr(&mut self, data: &str) -> &mut Self {
+        let mut command = |function: &[u8]| {
+            let len = (function.len() + 1) as u16;
+            self.bytes.extend_from_slice(&[GS, b'(', b'k']);
+            self.bytes.extend_from_slice(&len.to_le_bytes());
+            self.bytes.push(b'1');
+            self.bytes.extend_from_slice(function);
+        };
+        command(&[65, 50, 0]);
+        command(&[67, 6]);
+        command(&[69, 49]);
+        let mut store = vec![80, 48];
+        store.extend_from_slice(data.as_bytes());
+        command(&store);
+        command(&[81, 48]);
+        self
+    }
+
+    /// Feed past the tear bar and partially cut.
+    fn cut(&mut self) -> &mut Self {
+        self.bytes.extend_from_slice(&[GS, b'V', 66, 0]);
+        self
+    }
+}
+
+/// Render a receipt. `copy` prints a COPY banner for reprints.
+pub fn receipt(row: &ReceiptRow, copy: bool) -> Vec<u8> {
+    let mut p = EscPos::default();
+    p.init().center(true).bold(true).large(true);
+    p.line(&truncate(&row.club_name, LINE_WIDTH / 2));
+    p.large(false)
+        .line(&truncate(&row.tournament_name, LINE_WIDTH));
+    p.bold(false)
+        .line(&format!("{} UTC", row.issued_at.format("%Y-%m-%d %H:%M")));
+    if copy {
+        p.bold(true).line("*** COPY ***").bold(false);
+    }
+    p.center(false).rule();
+
+    p.line(&columns("Player", &row.player_name));
+    p.line(&columns("Entry", entry_label(&row.entry_type)));
+    p.line(&columns("Paid by", payment_label(&row.payment_method)));
+    p.line(&columns(
+        "Entry id",
+        &row.entry_id.map(|id| id.to_string()).unwrap_or_default(),
+    ));
+    p.rule();
+
+    p.line(&columns(
+        entry_label(&row.entry_type),
+        &Money(row.amount_cents).euros(),
+    ));
+    if row.voucher_cents > 0 {
+        p.line(&columns("Drink voucher", &Money(row.voucher_cents).euros()));
+    }
+    p.bold(true)
+        .line(&columns(
+            "TOTAL",
+            &Money(row.amount_cents + row.voucher_cents).euros(),
+        ))
+        .bold(false);
+    if let Some(chips) = row.chips_received {
+        p.line(&columns("Chips", &thousands(chips.into())));
+    }
+    p.rule();
+
+    p.center(true).qr(&row.verification_code);
+    p.line(&format!("Verify: {}", row.verification_code));
+    p.line("Keep this receipt until the end of the event");
+    p.line("").line("").cut();
+    p.bytes
+}
+
+/// Render a seat slip: the player's table and seat in large type, to hand
+/// over at the desk. Reprints carry a COPY banner.
+pub fn seat_slip(row: &SeatSlipJobRow) -> Vec<u8> {
+    let mut p = EscPos::default();
+    p.init().center(true).bold(true);
+    p.line(&truncate(&row.tournament_name, LINE_WIDTH));
+    p.bold(false)
+        .line(&format!("{} UTC", row.created_at.format("%Y-%m-%d %H:%M")));
+    if row.reprint_of.is_some() {
+        p.bold(true).line("*** COPY ***").bold(false);
+    }
+    p.rule();
+
+    p.line(&truncate(&row.player_name, LINE_WIDTH));
+    p.bold(true).large(true);
+    p.line(&format!("TABLE {}", row.table_number));
+    p.line(&format!("SEAT {}", row.seat_number));
+    p.large(false).bold(false);
+    p.line("").line("").cut();
+    p.bytes
+}
+
+fn entry_label(entry_type: &str) -> &str {
+    match entry_type {
+        "initial" => "Buy-in",
+        "rebuy" => "Rebuy",
+        "re_entry" => "Re-entry",
+        "addon" => "Add-on",
+        "bonus" => "Bonus",
+        other => other,
+    }
+}
+
+fn payment_label(method: &str) -> &str {
+    match method {
+        "cash" => "Cash",
+        "card" => "Card",
+        "bank_transfer" => "Bank transfer",
+        "voucher" => "Voucher",
+        "comp" => "Comp",
+        "other" => "Other",
+        other => other,
+    }
+}
+
+/// `left` and `right` on one line, `right` flush with the margin; `left` is
+/// cut short when both don't fit.
+fn columns(left: &str, right: &str) -> String {
+    let right = truncate(right, LINE_WIDTH);
+    let room = LINE_WIDTH - right.chars().count();
+    let left = truncate(left, room.saturating_sub(1));
+    let pad = room - left.chars().count();
+    format!("{left}{}{right}", " ".repeat(pad))
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    text.chars().take(max).collect()
+}
+
+fn thousands(n: i64) -> String {
+    let digits = n.unsigned_abs().to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+    if n < 0 {
+        out.push('-');
+    }
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(digit);
+    }
+    out
+}
+
+/// Encode text in code page 858; characters outside it become `?`.
+fn encode(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c {
+            '\u{20}'..='\u{7e}' => c as u8,
+            'Ç' => 0x80,
+            'ü' => 0x81,
+            'é' => 0x82,
+            'â' => 0x83,
+            'ä' => 0x84,
+            'à' => 0x85,
+            'ç' => 0x87,
+            'ê' => 0x88,
+            'ë' => 0x89,
+            'è' => 0x8a,
+            'ï' => 0x8b,
+            'î' => 0x8c,
+            'Ä' => 0x8e,
+            'É' => 0x90,
+            'ô' => 0x93,
+            'ö' => 0x94,
+            'û' => 0x96,
+            'ù' => 0x97,
+            'Ö' => 0x99,
+            'Ü' => 0x9a,
+            'á' => 0xa0,
+            'í' => 0xa1,
+            'ó' => 0xa2,
+            'ú' => 0xa3,
+            'ñ' => 0xa4,
+            'Ñ' => 0xa5,
+            'ß' => 0xe1,
+            '€' => 0xd5,
+            _ => b'?',
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    fn row() -> ReceiptRow {
+        ReceiptRow {
+            id: Uuid::nil(),
+            entry_id: Some(Uuid::nil()),
+            tournament_id: Uuid::nil(),
+            club_id: Uuid::nil(),
+            verification_code: "K7Q2-M9XD".into(),
+            club_name: "Poker Club Liège".into(),
+            tournament_name: "Friday Deepstack".into(),
+            player_name: "Zoë Martin".into(),
+            entry_type: "initial".into(),
+            payment_method: "card".into(),
+            amount_cents: 5_000,
+            voucher_cents: 500,
+            chips_received: Some(30_000),
+            issued_by: None,
+            issued_at: Utc.with_ymd_and_hms(2026, 10, 17, 19, 5, 0).unwrap(),
+            print_count: 1,
+            last_printed_at: None,
+        }
+    }
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack.windows(needle.len()).any(|w| w == needle)
+    }
+
+    #[test]
+    fn encodes_cp858() {
+        assert_eq!(encode("Zoë €5"), vec![b'Z', b'o', 0x89, b' ', 0xd5, b'5']);
+        assert_eq!(encode("李"), vec![b'?']);
+    }
+
+    #[test]
+    fn lays_out_two_columns() {
+        let line = columns("TOTAL", "€55.00");
+        assert_eq!(line.chars().count(), LINE_WIDTH);
+        assert!(line.starts_with("TOTAL "));
+        assert!(line.ends_with("€55.00"));
+
+        let long = columns(&"x".repeat(60), "€1.00");
+        assert_eq!(long.chars().count(), LINE_WIDTH);
+        assert!(long.ends_with(" €1.00"));
+    }
+
+    #[test]
+    fn renders_a_receipt() {
+        let bytes = receipt(&row(), false);
+        assert!(bytes.starts_with(&[ESC, b'@', ESC, b't', CODE_PAGE_858]));
+        assert!(bytes.ends_with(&[GS, b'V', 66, 0]));
+        assert!(contains(&bytes, &encode("Zoë Martin")));
+        assert!(contains(&bytes, &encode("Drink voucher")));
+        assert!(contains(&bytes, &encode("€55.00")));
+        assert!(contains(&bytes, b"30,000"));
+        assert!(contains(&bytes, b"2026-10-17 19:05 UTC"));
+        // QR store command: pL pH = len("K7Q2-M9XD") + 3.
+        assert!(contains(
+            &bytes,
+            &[GS, b'(', b'k', 12, 0, b'1', 80, 48, b'K', b'7']
+        ));
+        assert!(!contains(&bytes, b"COPY"));
+    }
+
+    #[test]
+    fn marks_reprints() {
+        let bytes = receipt(&row(), true);
+        assert!(contains(&bytes, b"*** COPY ***"));
+    }
+
+    #[test]
+    fn renders_a_seat_slip() {
+        let mut slip = SeatSlipJobRow {
+            id: Uuid::nil(),
+            club_id: Uuid::nil(),
+            tournament_id: Uuid::nil(),
+            seat_assignment_id: None,
+            tournament_name: "Friday Deepstack".into(),
+            player_name: "Zoë Martin".into(),
+            table_number: 4,
+            seat_number: 7,
+            status: "printing".into(),
+            reprint_of: None,
+            requested_by: None,
+            attempts: 1,
+            error: None,
+            created_at: Utc.with_ymd_and_hms(2026, 10, 17, 19, 5, 0).unwrap(),
+            claimed_at: None,
+            printed_at: None,
+        };
+        let bytes = seat_slip(&slip);
+        assert!(contains(&bytes, &encode("Zoë Martin")));
+        assert!(contains(&bytes, b"TABLE 4\n"));
+        assert!(contains(&bytes, b"SEAT 7\n"));
+        assert!(!contains(&bytes, b"COPY"));
+
+        slip.reprint_of = Some(Uuid::nil());
+        assert!(contains(&seat_slip(&slip), b"*** COPY ***"));
+    }
+}
@@ -0,0 +1,295 @@
+//! Signed download links for printouts, receipts, stat cards and seat slips,
+//! and the bare tokens of invite links and entry tickets.
+//!
+//! A link carries its expiry and an HMAC-SHA256, keyed by the JWT secret, over
+//! `{scope}:{id}:{expires}`, so it can be opened without a session (a
+//! printer's browser, the desk's print bridge) but not forged, kept past
+//! expiry or replayed against the other kind of document. A token is
+//! `{id}.{signature}`, both base64url, signed over `{scope}:{id}`; it doesn't
+//! expire, the row it names holds its limits.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, KeyInit, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+/// How long a download link stays valid.
+pub const LINK_TTL: Duration = Duration::minutes(15);
+
+/// How long a stat card link stays valid: long enough to schedule the post.
+pub const SHARE_LINK_TTL: Duration = Duration::days(7);
+
+/// What a link points at.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LinkScope {
+    /// A stored PDF, served by `/printouts/{id}`.
+    Printout,
+    /// A buy-in receipt's ESC/POS payload, served by `/receipts/{id}`.
+    Receipt,
+    /// A player's monthly stat card PNG, served by `/stat-cards/{id}`.
+    StatCard,
+    /// A seat slip's ESC/POS payload, served by `/seat-slips/{id}`.
+    SeatSlip,
+}
+
+impl LinkScope {
+    fn prefix(&self) -> &'static str {
+        match self {
+            LinkScope::Printout => "printout",
+            LinkScope::Receipt => "receipt",
+            LinkScope::StatCard => "stat_card",
+            LinkScope::SeatSlip => "seat_slip",
+        }
+    }
+
+    fn path(&self) -> &'static str {
+        match self {
+            LinkScope::Printout => "printouts",
+            LinkScope::Receipt => "receipts",
+            LinkScope::StatCard => "stat-cards",
+            LinkScope::SeatSlip => "seat-slips",
+        }
+    }
+
+    fn ttl(&self) -> Duration {
+        match self {
+            LinkScope::Printout | LinkScope::Receipt | LinkScope::SeatSlip => LINK_TTL,
+            LinkScope::StatCard => SHARE_LINK_TTL,
+        }
+    }
+}
+
+/// What a token stands for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TokenScope {
+    /// A tournament invite, redeemed with `registerWithInvite`.
+    Invite,
+    /// An entry ticket's QR code, checked by `scanEntryTicket`.
+    Ticket,
+}
+
+impl TokenScope {
+    fn prefix(&self) -> &'static str {
+        match self {
+            TokenScope::Invite => "invite",
+            TokenScope::Ticket => "ticket",
+        }
+    }
+}
+
+fn mac(secret: &str, message: &str) -> Hmac<Sha256> {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(message.as_bytes());
+    mac
+}
+
+fn link_mac(secret: &str, scope: LinkScope, id: Uuid, expires: i64) -> Hmac<Sha256> {
+    mac(secret, &format!("{}:{id}:{expires}", scope.prefix()))
+}
+
+fn token_mac(secret: &str, scope: TokenScope, id: Uuid) -> Hmac<Sha256> {
+    mac(secret, &format!("{}:{id}", scope.prefix()))
+}
+
+/// Signature for a link expiring at `expires` (Unix seconds).
+pub fn sign(secret: &str, scope: LinkScope, id: Uuid, expires: i64) -> String {
+    URL_SAFE_NO_PAD.encode(link_mac(secret, scope, id, expires).finalize().into_bytes())
+}
+
+/// Whether a link's signature is genuine and it hasn't expired.
+pub fn verify(
+    secret: &str,
+    scope: LinkScope,
+    id: Uuid,
+    expires: i64,
+    signature: &str,
+    now: DateTime<Utc>,
+) -> bool {
+    if expires < now.timestamp() {
+        return false;
+    }
+    let Ok(signature) = URL_SAFE_NO_PAD.decode(signature) else {
+        return false;
+    };
+    link_mac(secret, scope, id, expires)
+        .verify_slice(&signature)
+        .is_ok()
+}
+
+/// A download URL, valid until the returned instant.
+pub fn signed_url(
+    base_url: &str,
+    secret: &str,
+    scope: LinkScope,
+    id: Uuid,
+    now: DateTime<Utc>,
+) -> (String, DateTime<Utc>) {
+    let expires_at = now + scope.ttl();
+    let expires = expires_at.timestamp();
+    let url = format!(
+        "{}/{}/{id}?expires={expires}&signature={}",
+        base_url.trim_end_matches('/'),
+        scope.path(),
+        sign(secret, scope, id, expires)
+    );
+    (url, expires_at)
+}
+
+/// The token for `id`.
+pub fn encode_token(secret: &str, scope: TokenScope, id: Uuid) -> String {
+    format!(
+        "{}.{}",
+        URL_SAFE_NO_PAD.encode(id.as_bytes()),
+        URL_SAFE_NO_PAD.encode(token_mac(secret, scope, id).finalize().into_bytes())
+    )
+}
+
+/// The ID in a genuine token; `None` for anything malformed or forged.
+pub fn decode_token(secret: &str, scope: TokenScope, token: &str) -> Option<Uuid> {
+    let (id, signature) = token.trim().split_once('.')?;
+    let id = Uuid::from_slice(&URL_SAFE_NO_PAD.decode(id).ok()?).ok()?;
+    let signature = URL_SAFE_NO_PAD.decode(signature).ok()?;
+    token_mac(secret, scope, id).verify_slice(&signature).ok()?;
+    Some(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "unit-test-secret";
+
+    #[test]
+    fn verifies_its_own_links() {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+        let expires = (now + LINK_TTL).timestamp();
+        let signature = sign(SECRET, LinkScope::Printout, id, expires);
+        assert!(verify(
+            SECRET,
+            LinkScope::Printout,
+            id,
+            expires,
+            &signature,
+            now
+        ));
+    }
+
+    #[test]
+    fn rejects_tampered_or_expired_links() {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+        let expires = (now + LINK_TTL).timestamp();
+        let signature = sign(SECRET, LinkScope::Printout, id, expires);
+        fn printout(
+            secret: &str,
+            id: Uuid,
+            expires: i64,
+            signature: &str,
+            now: DateTime<Utc>,
+        ) -> bool {
+            verify(secret, LinkScope::Printout, id, expires, signature, now)
+        }
+
+        assert!(!printout(SECRET, Uuid::new_v4(), expires, &signature, now));
+        assert!(!printout(SECRET, id, expires + 60, &signature, now));
+        assert!(!printout("another-secret", id, expires, &signature, now));
+        assert!(!printout(SECRET, id, expires, "not base64!", now));
+        assert!(!verify(
+            SECRET,
+            LinkScope::Receipt,
+            id,
+            expires,
+            &signature,
+            now
+        ));
+        assert!(!printout(
+            SECRET,
+            id,
+            expires,
+            &signature,
+            now + LINK_TTL + Duration::seconds(1)
+        ));
+    }
+
+    #[test]
+    fn builds_urls_under_the_base() {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+        let (url, expires_at) = signed_url(
+            "https://api.example.com/",
+            SECRET,
+            LinkScope::Printout,
+            id,
+            now,
+        );
+        assert!(url.starts_with(&format!("https://api.example.com/printouts/{id}?expires=")));
+        assert_eq!(expires_at, now + LINK_TTL);
+
+        let (url, _) = signed_url(
+            "https://api.example.com",
+            SECRET,
+            LinkScope::Receipt,
+            id,
+            now,
+        );
+        assert!(url.starts_with(&format!("https://api.example.com/receipts/{id}?expires=")));
+
+        let (url, expires_at) = signed_url(
+            "https://api.example.com",
+            SECRET,
+            LinkScope::StatCard,
+            id,
+            now,
+        );
+        assert!(url.starts_with(&format!("https://api.example.com/stat-cards/{id}?expires=")));
+        assert_eq!(expires_at, now + SHARE_LINK_TTL);
+    }
+
+    #[test]
+    fn round_trips_tokens() {
+        let id = Uuid::new_v4();
+        let token = encode_token(SECRET, TokenScope::Invite, id);
+        assert_eq!(decode_token(SECRET, TokenScope::Invite, &token), Some(id));
+        assert_eq!(
+            decode_token(SECRET, TokenScope::Invite, &format!(" {token}\n")),
+            Some(id)
+        );
+    }
+
+    #[test]
+    fn rejects_forged_tokens() {
+        let id = Uuid::new_v4();
+        let token = encode_token(SECRET, TokenScope::Invite, id);
+        assert_eq!(
+            decode_token("another-secret", TokenScope::Invite, &token),
+            None
+        );
+        assert_eq!(decode_token(SECRET, TokenScope::Invite, "garbage"), None);
+        assert_eq!(
+            decode_token(SECRET, TokenScope::Invite, &id.to_string()),
+            None
+        );
+        // Same ID and key, but signed for a ticket: not an invite.
+        let ticket = encode_token(SECRET, TokenScope::Ticket, id);
+        assert_eq!(decode_token(SECRET, TokenScope::Invite, &ticket), None);
+        // Someone else's ID under this token's signature.
+        let (_, signature) = token.split_once('.').unwrap();
+        let other = URL_SAFE_NO_PAD.encode(Uuid::new_v4().as_bytes());
+        assert_eq!(
+            decode_token(SECRET, TokenScope::Invite, &format!("{other}.{signature}")),
+            None
+        );
+        // A link signature over the same ID doesn't make a token.
+        let expires = (Utc::now() + LINK_TTL).timestamp();
+        let forged = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(id.as_bytes()),
+            sign(SECRET, LinkScope::Printout, id, expires)
+        );
+        assert_eq!(decode_token(SECRET, TokenScope::Invite, &forged), None);
+    }
+}
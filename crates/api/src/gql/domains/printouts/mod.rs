@@ -0,0 +1,11 @@
+pub mod escpos;
+pub mod link;
+pub mod pdf;
+pub mod png;
+pub mod receipts;
+pub mod resolvers;
+pub mod service;
+pub mod stat_card;
+pub mod types;
+
+pub use resolvers::{PrintoutMutation, PrintoutQuery};
@@ -0,0 +1,418 @@
+//! Renders the floor printouts as PDF.
+//!
+//! Pages are A4, set in the standard Helvetica fonts every PDF reader ships
+//! (nothing is embedded, so files stay small), with text encoded as
+//! WinAnsi: Latin-1 plus the euro sign and typographic quotes. Characters
+//! outside it print as `?`.
+//!
+//! - Seat cards: one page per table, with every seat and who sits there.
+//! - Seat list: every seated player, alphabetically, with table and seat.
+//! - Payout sheet: finishing places and prizes, with a total and signature
+//!   lines.
+
+use chrono::{DateTime, Utc};
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str};
+
+use crate::gql::scalars::Money;
+
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 50.0;
+/// Rows of the seat list and payout sheet per page.
+const ROWS_PER_PAGE: usize = 34;
+const ROW_HEIGHT: f32 = 20.0;
+
+const REGULAR: Name = Name(b"F1");
+const BOLD: Name = Name(b"F2");
+
+/// A rendered printout.
+pub struct Rendered {
+    pub bytes: Vec<u8>,
+    pub page_count: i32,
+}
+
+/// A table and its seats, in seat order. Open seats have no player.
+pub struct TableSeating {
+    pub table_number: i32,
+    pub seats: Vec<(i32, Option<String>)>,
+}
+
+/// A seated player for the alphabetical seat list.
+pub struct SeatListEntry {
+    pub player_name: String,
+    pub table_number: i32,
+    pub seat_number: i32,
+}
+
+/// A finishing place for the payout sheet.
+pub struct PayoutEntry {
+    pub position: i32,
+    pub player_name: String,
+    pub prize_cents: i64,
+}
+
+/// One page per table: the table number in large type, then each seat.
+pub fn seat_cards(
+    tournament: &str,
+    tables: &[TableSeating],
+    generated_at: DateTime<Utc>,
+) -> Rendered {
+    let pages = tables
+        .iter()
+        .map(|table| {
+            let mut page = Page::new();
+            page.header(tournament, "Seat card", generated_at);
+            page.text(
+                BOLD,
+                56.0,
+                MARGIN,
+                PAGE_HEIGHT - 190.0,
+                &format!("Table {}", table.table_number),
+            );
+            let mut y = PAGE_HEIGHT - 260.0;
+            for (seat, player) in &table.seats {
+                page.text(BOLD, 20.0, MARGIN, y, &format!("Seat {seat}"));
+                page.text(
+                    REGULAR,
+                    20.0,
+                    MARGIN + 120.0,
+                    y,
+                    player.as_deref().unwrap_or("(open)"),
+                );
+                y -= 44.0;
+            }
+            page
+        })
+        .collect();
+    finish(pages)
+}
+
+/// Every seated player with their table and seat, in the order given: the
+/// caller sorts by the club's name order and collation.
+pub fn seat_list(
+    tournament: &str,
+    entries: &[SeatListEntry],
+    generated_at: DateTime<Utc>,
+) -> Rendered {
+    let chunks: Vec<&[SeatListEntry]> = if entries.is_empty() {
+        vec![&[]]
+    } else {
+        entries.chunks(ROWS_PER_PAGE).collect()
+    };
+    let page_count = chunks.len();
+    let pages = chunks
+        .into_iter()
+        .enumerate()
+        .map(|(i, rows)| {
+            let mut page = Page::new();
+            page.header(tournament, "Seat list", generated_at);
+            let mut y = PAGE_HEIGHT - 150.0;
+            page.text(BOLD, 11.0, MARGIN, y, "Player");
+            page.text(BOLD, 11.0, 380.0, y, "Table");
+            page.text(BOLD, 11.0, 460.0, y, "Seat");
+            page.rule(y - 6.0);
+            for entry in rows {
+                y -= ROW_HEIGHT;
+                page.text(REGULAR, 11.0, MARGIN, y, &entry.player_name);
+                page.text(REGULAR, 11.0, 380.0, y, &entry.table_number.to_string());
+                page.text(REGULAR, 11.0, 460.0, y, &entry.seat_number.to_string());
+            }
+            page.footer(i + 1, page_count);
+            page
+        })
+        .collect();
+    finish(pages)
+}
+
+/// Finishing places with their prizes, the total paid and lines for the
+/// director's and cashier's signatures.
+pub fn payout_sheet(
+    tournament: &str,
+    entries: &[PayoutEntry],
+    generated_at: DateTime<Utc>,
+) -> Rendered {
+    let chunks: Vec<&[PayoutEntry]> = if entries.is_empty() {
+        vec![&[]]
+    } else {
+        entries.chunks(ROWS_PER_PAGE).collect()
+    };
+    let page_count = chunks.len();
+    let total: i64 = entries.iter().map(|e| e.prize_cents).sum();
+    let amount_right = PAGE_WIDTH - MARGIN;
+
+    let pages = chunks
+        .into_iter()
+        .enumerate()
+        .map(|(i, rows)| {
+            let mut page = Page::new();
+            page.header(tournament, "Payout sheet", generated_at);
+            let mut y = PAGE_HEIGHT - 150.0;
+            page.text(BOLD, 11.0, MARGIN, y, "Place");
+            page.text(BOLD, 11.0, MARGIN + 60.0, y, "Player");
+            page.text_right(BOLD, 11.0, amount_right, y, "Prize");
+            page.rule(y - 6.0);
+            for entry in rows {
+                y -= ROW_HEIGHT;
+                page.text(REGULAR, 11.0, MARGIN, y, &entry.position.to_string());
+                page.text(REGULAR, 11.0, MARGIN + 60.0, y, &entry.player_name);
+                page.text_right(
+                    REGULAR,
+                    11.0,
+                    amount_right,
+                    y,
+                    &Money(entry.prize_cents).euros(),
+                );
+            }
+            if i + 1 == page_count {
+                page.rule(y - 8.0);
+                y -= 26.0;
+                page.text(BOLD, 11.0, MARGIN + 60.0, y, "Total paid");
+                page.text_right(BOLD, 11.0, amount_right, y, &Money(total).euros());
+                page.text(REGULAR, 10.0, MARGIN, 110.0, "Tournament director");
+                page.text(REGULAR, 10.0, 320.0, 110.0, "Cashier");
+                page.signature_line(MARGIN, 130.0);
+                page.signature_line(320.0, 130.0);
+            }
+            page.footer(i + 1, page_count);
+            page
+        })
+        .collect();
+    finish(pages)
+}
+
+/// Encode text as WinAnsi for the standard fonts; anything outside it
+/// becomes `?`.
+fn encode(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c {
+            '\u{20}'..='\u{7e}' | '\u{a0}'..='\u{ff}' => c as u8,
+            '€' => 0x80,
+            '‘' => 0x91,
+            '’' => 0x92,
+            '“' => 0x93,
+            '”' => 0x94,
+            '•' => 0x95,
+            '–' => 0x96,
+            '—' => 0x97,
+            _ => b'?',
+        })
+        .collect()
+}
+
+/// Approximate Helvetica advance width, good enough to right-align amounts.
+fn text_width(text: &str, size: f32) -> f32 {
+    let em: f32 = text
+        .chars()
+        .map(|c| match c {
+            '0'..='9' | '€' => 0.556,
+            '.' | ',' | ' ' => 0.278,
+            '-' => 0.333,
+            'A'..='Z' => 0.667,
+            _ => 0.5,
+        })
+        .sum();
+    em * size
+}
+
+struct Page {
+    content: Content,
+}
+
+impl Page {
+    fn new() -> Self {
+        Self {
+            content: Content::new(),
+        }
+    }
+
+    fn text(&mut self, font: Name, size: f32, x: f32, y: f32, text: &str) {
+        self.content
+            .begin_text()
+            .set_font(font, size)
+            .next_line(x, y)
+            .show(Str(&encode(text)))
+            .end_text();
+    }
+
+    fn text_right(&mut self, font: Name, size: f32, right: f32, y: f32, text: &str) {
+        self.text(font, size, right - text_width(text, size), y, text);
+    }
+
+    fn rule(&mut self, y: f32) {
+        self.content
+            .set_line_width(0.5)
+            .move_to(MARGIN, y)
+            .line_to(PAGE_WIDTH - MARGIN, y)
+            .stroke();
+    }
+
+    fn signature_line(&mut self, x: f32, y: f32) {
+        self.content
+            .set_line_width(0.5)
+            .move_to(x, y)
+            .line_to(x + 200.0, y)
+            .stroke();
+    }
+
+    fn header(&mut self, tournament: &str, title: &str, generated_at: DateTime<Utc>) {
+        self.text(BOLD, 18.0, MARGIN, PAGE_HEIGHT - 70.0, tournament);
+        self.text(REGULAR, 12.0, MARGIN, PAGE_HEIGHT - 92.0, title);
+        self.text_right(
+            REGULAR,
+            9.0,
+            PAGE_WIDTH - MARGIN,
+            PAGE_HEIGHT - 92.0,
+            &format!("Generated {} UTC", generated_at.format("%Y-%m-%d %H:%M")),
+        );
+        self.rule(PAGE_HEIGHT - 104.0);
+    }
+
+    fn footer(&mut self, number: usize, count: usize) {
+        self.text_right(
+            REGULAR,
+            9.0,
+            PAGE_WIDTH - MARGIN,
+            40.0,
+            &format!("Page {number} of {count}"),
+        );
+    }
+}
+
+/// Assemble the pages into a document.
+fn finish(pages: Vec<Page>) -> Rendered {
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+    let regular_id = Ref::new(3);
+    let bold_id = Ref::new(4);
+    let page_ids: Vec<Ref> = (0..pages.len() as i32)
+        .map(|i| Ref::new(5 + 2 * i))
+        .collect();
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id)
+        .kids(page_ids.iter().copied())
+        .count(pages.len() as i32);
+    pdf.type1_font(regular_id)
+        .base_font(Name(b"Helvetica"))
+        .encoding_predefined(Name(b"WinAnsiEncoding"));
+    pdf.type1_font(bold_id)
+        .base_font(Name(b"Helvetica-Bold"))
+        .encoding_predefined(Name(b"WinAnsiEncoding"));
+
+    for (page, page_id) in pages.into_iter().zip(&page_ids) {
+        let content_id = Ref::new(page_id.get() + 1);
+        let mut writer = pdf.page(*page_id);
+        writer
+            .parent(page_tree_id)
+            .media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT))
+            .contents(content_id);
+        let mut resources = writer.resources();
+        resources
+            .fonts()
+            .pair(REGULAR, regular_id)
+            .pair(BOLD, bold_id);
+        resources.finish();
+        writer.finish();
+        pdf.stream(content_id, &page.content.finish());
+    }
+
+    Rendered {
+        bytes: pdf.finish(),
+        page_count: page_ids.len() as i32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 6, 6, 19, 0, 0).unwrap()
+    }
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack.windows(needle.len()).any(|w| w == needle)
+    }
+
+    fn position(haystack: &[u8], needle: &[u8]) -> usize {
+        haystack
+            .windows(needle.len())
+            .position(|w| w == needle)
+            .unwrap()
+    }
+
+    #[test]
+    fn encodes_winansi() {
+        assert_eq!(encode("Zoë €5"), vec![b'Z', b'o', 0xeb, b' ', 0x80, b'5']);
+        assert_eq!(encode("李"), vec![b'?']);
+    }
+
+    #[test]
+    fn seat_cards_get_a_page_per_table() {
+        let tables = vec![
+            TableSeating {
+                table_number: 1,
+                seats: vec![(1, Some("Alice".into())), (2, None)],
+            },
+            TableSeating {
+                table_number: 2,
+                seats: vec![(1, Some("Bob".into()))],
+            },
+        ];
+        let rendered = seat_cards("Friday Deepstack", &tables, at());
+        assert_eq!(rendered.page_count, 2);
+        assert!(rendered.bytes.starts_with(b"%PDF-"));
+        assert!(contains(&rendered.bytes, b"/Count 2"));
+        assert!(contains(&rendered.bytes, b"(Table 2)"));
+        assert!(contains(&rendered.bytes, b"((open))"));
+    }
+
+    #[test]
+    fn seat_list_keeps_the_given_order_and_paginates() {
+        let mut entries: Vec<SeatListEntry> = (0..40)
+            .map(|i| SeatListEntry {
+                player_name: format!("Player {i:02}"),
+                table_number: 1 + i / 9,
+                seat_number: 1 + i % 9,
+            })
+            .collect();
+        entries.push(SeatListEntry {
+            player_name: "alice".into(),
+            table_number: 9,
+            seat_number: 9,
+        });
+        let rendered = seat_list("Friday Deepstack", &entries, at());
+        assert_eq!(rendered.page_count, 2);
+        assert!(contains(&rendered.bytes, b"(Page 2 of 2)"));
+        assert!(position(&rendered.bytes, b"(Player 00)") < position(&rendered.bytes, b"(alice)"));
+    }
+
+    #[test]
+    fn payout_sheet_totals_the_prizes() {
+        let entries = vec![
+            PayoutEntry {
+                position: 1,
+                player_name: "Alice".into(),
+                prize_cents: 100_000,
+            },
+            PayoutEntry {
+                position: 2,
+                player_name: "Bob".into(),
+                prize_cents: 50_050,
+            },
+        ];
+        let rendered = payout_sheet("Friday Deepstack", &entries, at());
+        assert_eq!(rendered.page_count, 1);
+        assert!(contains(&rendered.bytes, b"(Total paid)"));
+        // Non-ASCII text is written as a hex string: "€1,500.50".
+        assert!(contains(&rendered.bytes, b"<80312C3530302E3530>"));
+    }
+
+    #[test]
+    fn empty_documents_still_have_a_page() {
+        assert_eq!(seat_list("Empty", &[], at()).page_count, 1);
+        assert_eq!(payout_sheet("Empty", &[], at()).page_count, 1);
+    }
+}
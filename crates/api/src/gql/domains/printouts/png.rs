@@ -0,0 +1,258 @@
+//! Minimal PNG encoder for generated images (stat cards).
+//!
+//! Images are palette-based (8-bit indices), which is all flat-colour cards
+//! need. Pixel data is deflated with the fixed Huffman code and
+//! distance-1 matches only: runs of one colour, the bulk of such an image,
+//! shrink to a few bits each without pulling in a compression library.
+
+/// An image of palette indices, row by row.
+pub struct Canvas {
+    pub width: u32,
+    pub height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    /// A canvas filled with palette index `background`.
+    pub fn new(width: u32, height: u32, background: u8) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![background; (width * height) as usize],
+        }
+    }
+
+    /// Fill a rectangle, clipped to the canvas.
+    pub fn fill_rect(&mut self, x: u32, y: u32, width: u32, height: u32, colour: u8) {
+        let x_end = (x + width).min(self.width);
+        let y_end = (y + height).min(self.height);
+        for row in y.min(y_end)..y_end {
+            let start = (row * self.width) as usize;
+            self.pixels[start + x.min(x_end) as usize..start + x_end as usize].fill(colour);
+        }
+    }
+
+    #[cfg(test)]
+    fn pixel(&self, x: u32, y: u32) -> u8 {
+        self.pixels[(y * self.width + x) as usize]
+    }
+}
+
+/// Encode `canvas` as a PNG with the given RGB palette.
+pub fn encode(canvas: &Canvas, palette: &[[u8; 3]]) -> Vec<u8> {
+    let mut ihdr = Vec::with_capacity(13);
+    ihdr.extend_from_slice(&canvas.width.to_be_bytes());
+    ihdr.extend_from_slice(&canvas.height.to_be_bytes());
+    // Bit depth 8, colour type 3 (palette), default compression, filter and
+    // no interlacing.
+    ihdr.extend_from_slice(&[8, 3, 0, 0, 0]);
+
+    let plte: Vec<u8> = palette.iter().flatten().copied().collect();
+
+    // Each scanline is prefixed with filter type 0 (none).
+    let row = canvas.width as usize;
+    let mut raw = Vec::with_capacity((row + 1) * canvas.height as usize);
+    for line in canvas.pixels.chunks(row) {
+        raw.push(0);
+        raw.extend_from_slice(line);
+    }
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    chunk(&mut png, b"IHDR", &ihdr);
+    chunk(&mut png, b"PLTE", &plte);
+    chunk(&mut png, b"IDAT", &zlib(&raw));
+    chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in bytes.chunks(5552) {
+        for &byte in chunk {
+            a += u32::from(byte);
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+/// A zlib stream holding one fixed-Huffman deflate block.
+fn zlib(data: &[u8]) -> Vec<u8> {
+    let mut out = BitWriter::default();
+    out.bits(1, 1); // final block
+    out.bits(1, 2); // fixed Huffman codes
+
+    let mut i = 0;
+    while i < data.len() {
+        let byte = data[i];
+        out.literal(u16::from(byte));
+        let mut run = data[i + 1..].iter().take_while(|&&b| b == byte).count();
+        i += 1 + run;
+        while run >= 3 {
+            let length = run.min(258);
+            out.length(length as u16);
+            out.bits(0, 5); // distance code 0: one byte back
+            run -= length;
+        }
+        for _ in 0..run {
+            out.literal(u16::from(byte));
+        }
+    }
+    out.literal(256); // end of block
+
+    let mut stream = vec![0x78, 0x01];
+    stream.extend(out.finish());
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+    stream
+}
+
+/// Match lengths 3..=258: (first length of each code, extra bits).
+const LENGTHS: [(u16, u8); 29] = [
+    (3, 0),
+    (4, 0),
+    (5, 0),
+    (6, 0),
+    (7, 0),
+    (8, 0),
+    (9, 0),
+    (10, 0),
+    (11, 1),
+    (13, 1),
+    (15, 1),
+    (17, 1),
+    (19, 2),
+    (23, 2),
+    (27, 2),
+    (31, 2),
+    (35, 3),
+    (43, 3),
+    (51, 3),
+    (59, 3),
+    (67, 4),
+    (83, 4),
+    (99, 4),
+    (115, 4),
+    (131, 5),
+    (163, 5),
+    (195, 5),
+    (227, 5),
+    (258, 0),
+];
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    current: u32,
+    filled: u8,
+}
+
+impl BitWriter {
+    /// Append `count` bits of `value`, least significant first.
+    fn bits(&mut self, value: u32, count: u8) {
+        for n in 0..count {
+            self.current |= ((value >> n) & 1) << self.filled;
+            self.filled += 1;
+            if self.filled == 8 {
+                self.bytes.push(self.current as u8);
+                self.current = 0;
+                self.filled = 0;
+            }
+        }
+    }
+
+    /// Append a Huffman code, most significant bit first.
+    fn code(&mut self, code: u32, len: u8) {
+        for n in (0..len).rev() {
+            self.bits((code >> n) & 1, 1);
+        }
+    }
+
+    /// A literal byte (0..=255) or end of block (256), in the fixed code.
+    fn literal(&mut self, symbol: u16) {
+        let symbol = u32::from(symbol);
+        match symbol {
+            0..=143 => self.code(0x30 + symbol, 8),
+            144..=255 => self.code(0x190 + symbol - 144, 9),
+            256..=279 => self.code(symbol - 256, 7),
+            _ => self.code(0xC0 + symbol - 280, 8),
+        }
+    }
+
+    fn length(&mut self, length: u16) {
+        let index = LENGTHS
+            .iter()
+            .rposition(|&(base, _)| base <= length)
+            .expect("match lengths start at 3");
+        let (base, extra) = LENGTHS[index];
+        self.literal(257 + index as u16);
+        self.bits(u32::from(length - base), extra);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.filled > 0 {
+            self.bytes.push(self.current as u8);
+        }
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums_match_reference_values() {
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+
+    #[test]
+    fn writes_header_and_dimensions() {
+        let png = encode(&Canvas::new(300, 200, 0), &[[0, 0, 0]]);
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(u32::from_be_bytes(png[16..20].try_into().unwrap()), 300);
+        assert_eq!(u32::from_be_bytes(png[20..24].try_into().unwrap()), 200);
+        assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+    }
+
+    #[test]
+    fn flat_images_compress_to_runs() {
+        let png = encode(&Canvas::new(1080, 1080, 0), &[[0, 0, 0]]);
+        assert!(png.len() < 20_000, "{} bytes", png.len());
+    }
+
+    #[test]
+    fn fills_clipped_rectangles() {
+        let mut canvas = Canvas::new(10, 10, 0);
+        canvas.fill_rect(8, 8, 5, 5, 1);
+        assert_eq!(canvas.pixel(9, 9), 1);
+        assert_eq!(canvas.pixel(7, 9), 0);
+    }
+}
@@ -0,0 +1,88 @@
+//! Buy-in receipts, issued when the desk adds a paid entry.
+
+use rand::RngExt;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use infra::repos::entry_receipts::{self, ReceiptRow};
+
+/// Unambiguous characters for verification codes (no 0/O, 1/I/L).
+const CODE_ALPHABET: &[u8] = b"23456789ABCDEFGHJKMNPQRSTUVWXYZ";
+
+/// Entry types paid at the desk. Vouchers go on their buy-in's receipt and
+/// bonuses are chip-only, so neither gets its own.
+pub fn issues_receipt(entry_type: &str) -> bool {
+    matches!(entry_type, "initial" | "rebuy" | "re_entry" | "addon")
+}
+
+/// A random `XXXX-XXXX` verification code.
+fn verification_code() -> String {
+    let mut rng = rand::rng();
+    let chars: String = (0..8)
+        .map(|_| char::from(CODE_ALPHABET[rng.random_range(0..CODE_ALPHABET.len())]))
+        .collect();
+    format!("{}-{}", &chars[..4], &chars[4..])
+}
+
+/// Normalize a typed or scanned code: case and separators don't matter.
+pub fn normalize_code(input: &str) -> String {
+    let chars: String = input
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    if chars.len() == 8 {
+        format!("{}-{}", &chars[..4], &chars[4..])
+    } else {
+        chars
+    }
+}
+
+/// Issue the receipt for a freshly added entry. Best-effort: a failure is
+/// logged and the entry stands (the desk can still write one by hand).
+pub async fn issue_logged(
+    db: &PgPool,
+    entry_id: Uuid,
+    voucher_cents: i64,
+    issued_by: Option<Uuid>,
+) -> Option<ReceiptRow> {
+    match entry_receipts::create(db, entry_id, &verification_code(), voucher_cents, issued_by).await
+    {
+        Ok(row) => Some(row),
+        Err(e) => {
+            tracing::error!(entry_id = %entry_id, error = %e, "Receipt issue failed");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_are_grouped_and_unambiguous() {
+        let code = verification_code();
+        assert_eq!(code.len(), 9);
+        assert_eq!(&code[4..5], "-");
+        assert!(code
+            .chars()
+            .filter(|&c| c != '-')
+            .all(|c| CODE_ALPHABET.contains(&(c as u8))));
+    }
+
+    #[test]
+    fn normalizes_typed_codes() {
+        assert_eq!(normalize_code(" k7q2 m9xd "), "K7Q2-M9XD");
+        assert_eq!(normalize_code("K7Q2-M9XD"), "K7Q2-M9XD");
+        assert_eq!(normalize_code("K7Q"), "K7Q");
+    }
+
+    #[test]
+    fn only_paid_entries_get_receipts() {
+        assert!(issues_receipt("initial"));
+        assert!(issues_receipt("addon"));
+        assert!(!issues_receipt("voucher"));
+        assert!(!issues_receipt("bonus"));
+    }
+}
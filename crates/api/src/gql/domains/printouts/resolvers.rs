@@ -0,0 +1,278 @@
+use async_graphql::{Context, Object, Result, ID};
+use chrono::{NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::auth::jwt::Claims;
+use crate::auth::permissions::{require_club_manager, viewer_manages_club};
+use crate::gql::common::helpers::get_club_id_for_tournament;
+use crate::gql::domains::staff::types::StaffRole;
+use crate::gql::error::ResultExt;
+use crate::state::AppState;
+use infra::repos::{
+    club_players, club_staff, entry_receipts, seat_slip_jobs, tournament_printouts,
+};
+
+use super::types::{
+    EntryReceipt, GeneratePlayerStatCardInput, PlayerStatCard, PrintoutKind, SeatSlipJobStatus,
+    SeatSlipPrintJob, TournamentPrintout,
+};
+use super::{receipts, service};
+
+/// Most seat slips handed to the print agent per claim.
+const MAX_SEAT_SLIP_CLAIM: i32 = 50;
+
+/// Seat slips are printed at the desk: a manager of the club, or an active
+/// floor-staff member linked to the caller's account. Returns the caller's
+/// user ID.
+async fn require_desk_staff(ctx: &Context<'_>, club_id: Uuid) -> Result<Uuid> {
+    let claims = ctx.data::<Claims>()?;
+    let caller = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+    if viewer_manages_club(ctx, club_id).await {
+        return Ok(caller);
+    }
+    let state = ctx.data::<AppState>()?;
+    club_staff::find_active_for_user(&state.db, club_id, caller, StaffRole::Floor.as_db())
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Only floor staff can print seat slips"))?;
+    Ok(caller)
+}
+
+async fn load_seat_slip_job(
+    ctx: &Context<'_>,
+    job_id: &ID,
+) -> Result<(seat_slip_jobs::SeatSlipJobRow, Uuid)> {
+    let state = ctx.data::<AppState>()?;
+    let id = Uuid::parse_str(job_id.as_str()).gql_err("Invalid job ID")?;
+    let job = seat_slip_jobs::get_by_id(&state.db, id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Print job not found"))?;
+    let caller = require_desk_staff(ctx, job.club_id).await?;
+    Ok((job, caller))
+}
+
+#[derive(Default)]
+pub struct PrintoutQuery;
+
+#[Object]
+impl PrintoutQuery {
+    /// The tournament's generated PDFs, each with a fresh signed download
+    /// link. Club managers.
+    async fn tournament_printouts(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Vec<TournamentPrintout>> {
+        let state = ctx.data::<AppState>()?;
+        let tid = Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tid).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let now = Utc::now();
+        let rows = tournament_printouts::list_for_tournament(&state.db, tid).await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| TournamentPrintout::new(row, state, now))
+            .collect())
+    }
+
+    /// Look up a buy-in receipt by the code printed on it (typed or scanned
+    /// from its QR code). A deleted entry shows as `voided`. Managers and
+    /// floor staff of the club; null for anyone else or an unknown code.
+    async fn verify_entry_receipt(
+        &self,
+        ctx: &Context<'_>,
+        code: String,
+    ) -> Result<Option<EntryReceipt>> {
+        let claims = ctx.data::<Claims>()?;
+        let caller = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+        let state = ctx.data::<AppState>()?;
+
+        let code = receipts::normalize_code(&code);
+        let Some(row) = entry_receipts::get_by_code(&state.db, &code).await? else {
+            return Ok(None);
+        };
+        let is_staff = viewer_manages_club(ctx, row.club_id).await
+            || club_staff::find_active_for_user(
+                &state.db,
+                row.club_id,
+                caller,
+                StaffRole::Floor.as_db(),
+            )
+            .await?
+            .is_some();
+        if !is_staff {
+            return Ok(None);
+        }
+        Ok(Some(EntryReceipt::new(row, state, Utc::now())))
+    }
+
+    /// The tournament's seat slip print jobs, newest first, optionally of
+    /// one status. Managers and floor staff of the club.
+    async fn seat_slip_print_jobs(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        status: Option<SeatSlipJobStatus>,
+    ) -> Result<Vec<SeatSlipPrintJob>> {
+        let state = ctx.data::<AppState>()?;
+        let tid = Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tid).await?;
+        require_desk_staff(ctx, club_id).await?;
+
+        let now = Utc::now();
+        let rows = seat_slip_jobs::list_for_tournament(
+            &state.db,
+            tid,
+            status.as_ref().map(SeatSlipJobStatus::as_str),
+        )
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| SeatSlipPrintJob::new(row, state, now))
+            .collect())
+    }
+}
+
+#[derive(Default)]
+pub struct PrintoutMutation;
+
+#[Object]
+impl PrintoutMutation {
+    /// Regenerate printouts from the current seating and results, e.g. after
+    /// moving players once the draw is out. Defaults to every kind the
+    /// tournament has data for. Club managers.
+    async fn generate_tournament_printouts(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        kinds: Option<Vec<PrintoutKind>>,
+    ) -> Result<Vec<TournamentPrintout>> {
+        let state = ctx.data::<AppState>()?;
+        let tid = Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tid).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid user ID")?;
+
+        let explicit = kinds.is_some();
+        let kinds = kinds.unwrap_or_else(|| {
+            vec![
+                PrintoutKind::SeatCards,
+                PrintoutKind::SeatList,
+                PrintoutKind::PayoutSheet,
+            ]
+        });
+
+        let now = Utc::now();
+        let mut printouts = Vec::new();
+        for kind in kinds {
+            match service::generate(&state.db, tid, kind, Some(manager_id)).await {
+                Ok(row) => printouts.push(TournamentPrintout::new(row, state, now)),
+                // Asked for everything: skip what there's no data for yet.
+                Err(service::PrintoutError::NoTables | service::PrintoutError::NoResults)
+                    if !explicit => {}
+                Err(service::PrintoutError::Db(e)) => return Err(e.into()),
+                Err(e) => return Err(async_graphql::Error::new(e.to_string())),
+            }
+        }
+        if printouts.is_empty() {
+            return Err(async_graphql::Error::new(
+                "Nothing to print yet: link tables or enter results first",
+            ));
+        }
+        Ok(printouts)
+    }
+
+    /// Render a player's stats for one month at their club (tournaments
+    /// played, ITM percentage, best finish) as a shareable PNG card, replacing
+    /// any earlier card for that month. Club managers.
+    async fn generate_player_stat_card(
+        &self,
+        ctx: &Context<'_>,
+        input: GeneratePlayerStatCardInput,
+    ) -> Result<PlayerStatCard> {
+        let state = ctx.data::<AppState>()?;
+        let club_player_id =
+            Uuid::parse_str(input.club_player_id.as_str()).gql_err("Invalid club player ID")?;
+        let player = club_players::get_by_id(&state.db, club_player_id)
+            .await
+            .gql_err("Database operation failed")?
+            .ok_or_else(|| async_graphql::Error::new("Club player not found"))?;
+        let manager = require_club_manager(ctx, player.club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid user ID")?;
+
+        let month = NaiveDate::from_ymd_opt(input.year, input.month, 1)
+            .ok_or_else(|| async_graphql::Error::new("Invalid month"))?;
+        let now = Utc::now();
+        if month > now.date_naive() {
+            return Err(async_graphql::Error::new("That month hasn't started yet"));
+        }
+
+        match service::generate_stat_card(&state.db, &player, month, Some(manager_id)).await {
+            Ok((row, stats)) => Ok(PlayerStatCard::new(row, stats, state, now)),
+            Err(service::PrintoutError::Db(e)) => Err(e.into()),
+            Err(e) => Err(async_graphql::Error::new(e.to_string())),
+        }
+    }
+
+    /// For the desk's print agent: take the club's oldest pending seat slips
+    /// (up to `limit`, default 10, at most 50) and mark them `PRINTING`.
+    /// Slips claimed but not reported within two minutes are handed out
+    /// again. Poll this, fetch each `printUrl`, then report with
+    /// `completeSeatSlipPrintJob`. Managers and floor staff of the club.
+    async fn claim_seat_slip_print_jobs(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+        #[graphql(default = 10)] limit: i32,
+    ) -> Result<Vec<SeatSlipPrintJob>> {
+        let state = ctx.data::<AppState>()?;
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_desk_staff(ctx, club_id).await?;
+        if limit < 1 {
+            return Err(async_graphql::Error::new("Limit must be at least 1"));
+        }
+
+        let limit = limit.min(MAX_SEAT_SLIP_CLAIM);
+        let now = Utc::now();
+        let rows = seat_slip_jobs::claim(&state.db, club_id, i64::from(limit)).await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| SeatSlipPrintJob::new(row, state, now))
+            .collect())
+    }
+
+    /// Report a claimed seat slip as printed, or as failed with the printer's
+    /// `error`. Only `PRINTING` jobs can be completed; a failed one can be
+    /// queued again with `reprintSeatSlip`.
+    async fn complete_seat_slip_print_job(
+        &self,
+        ctx: &Context<'_>,
+        job_id: ID,
+        error: Option<String>,
+    ) -> Result<SeatSlipPrintJob> {
+        let state = ctx.data::<AppState>()?;
+        let (job, _) = load_seat_slip_job(ctx, &job_id).await?;
+        let error =
+            error
+                .as_deref()
+                .map(str::trim)
+                .map(|e| if e.is_empty() { "Print failed" } else { e });
+
+        let row = seat_slip_jobs::complete(&state.db, job.id, error)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Print job isn't being printed"))?;
+        Ok(SeatSlipPrintJob::new(row, state, Utc::now()))
+    }
+
+    /// Queue a copy of a seat slip, e.g. when the player lost theirs or the
+    /// print failed. The copy is marked as such on paper.
+    async fn reprint_seat_slip(&self, ctx: &Context<'_>, job_id: ID) -> Result<SeatSlipPrintJob> {
+        let state = ctx.data::<AppState>()?;
+        let (job, caller) = load_seat_slip_job(ctx, &job_id).await?;
+
+        let row = seat_slip_jobs::reprint(&state.db, job.id, Some(caller))
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Print job not found"))?;
+        Ok(SeatSlipPrintJob::new(row, state, Utc::now()))
+    }
+}
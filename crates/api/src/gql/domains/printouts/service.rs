@@ -0,0 +1,170 @@
+//! Generates and stores the printouts from the current seating and results,
+//! and players' monthly stat cards.
+
+use chrono::{Months, NaiveDate, Utc};
+use sqlx::PgPool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use infra::models::ClubPlayerRow;
+use infra::repos::player_stat_cards::{self, MonthlyStatsRow, StatCardRow};
+use infra::repos::tournament_printouts::{self, PrintoutRow};
+use infra::repos::{club_name_settings, clubs, tournaments};
+
+use super::pdf::{self, PayoutEntry, SeatListEntry, TableSeating};
+use super::stat_card::{self, StatCard};
+use super::types::PrintoutKind;
+
+/// Why a printout couldn't be generated.
+#[derive(Debug, Error)]
+pub enum PrintoutError {
+    #[error("Tournament not found")]
+    TournamentNotFound,
+    #[error("Club not found")]
+    ClubNotFound,
+    #[error("No tables are linked to this tournament")]
+    NoTables,
+    #[error("No results have been entered for this tournament")]
+    NoResults,
+    #[error(transparent)]
+    Db(#[from] sqlx::Error),
+}
+
+/// Render one printout from the tournament's current state and store it,
+/// replacing the previous one of that kind.
+pub async fn generate(
+    db: &PgPool,
+    tournament_id: Uuid,
+    kind: PrintoutKind,
+    generated_by: Option<Uuid>,
+) -> Result<PrintoutRow, PrintoutError> {
+    let tournament = tournaments::get_by_id(db, tournament_id)
+        .await?
+        .ok_or(PrintoutError::TournamentNotFound)?;
+    let now = Utc::now();
+
+    let rendered = match kind {
+        PrintoutKind::SeatCards | PrintoutKind::SeatList => {
+            let names = club_name_settings::get_or_create(db, tournament.club_id).await?;
+            let seats = tournament_printouts::list_seats(db, tournament_id, &names).await?;
+            if seats.is_empty() {
+                return Err(PrintoutError::NoTables);
+            }
+            if kind == PrintoutKind::SeatCards {
+                pdf::seat_cards(&tournament.name, &table_seating(&seats), now)
+            } else {
+                let seated =
+                    tournament_printouts::list_seated_by_name(db, tournament_id, &names).await?;
+                let entries: Vec<SeatListEntry> = seated
+                    .into_iter()
+                    .filter_map(|s| {
+                        Some(SeatListEntry {
+                            player_name: s.player_name?,
+                            table_number: s.table_number,
+                            seat_number: s.seat_number?,
+                        })
+                    })
+                    .collect();
+                pdf::seat_list(&tournament.name, &entries, now)
+            }
+        }
+        PrintoutKind::PayoutSheet => {
+            let places = tournament_printouts::list_payouts(db, tournament_id).await?;
+            if places.is_empty() {
+                return Err(PrintoutError::NoResults);
+            }
+            let entries: Vec<PayoutEntry> = places
+                .into_iter()
+                .map(|p| PayoutEntry {
+                    position: p.final_position,
+                    player_name: p.player_name,
+                    prize_cents: p.prize_cents,
+                })
+                .collect();
+            pdf::payout_sheet(&tournament.name, &entries, now)
+        }
+    };
+
+    Ok(tournament_printouts::upsert(
+        db,
+        tournament_id,
+        kind.as_str(),
+        &rendered.bytes,
+        rendered.page_count,
+        generated_by,
+    )
+    .await?)
+}
+
+/// Best-effort regeneration after the seat draw or results entry: failures
+/// are logged and never fail the caller.
+pub async fn generate_logged(
+    db: &PgPool,
+    tournament_id: Uuid,
+    kinds: &[PrintoutKind],
+    generated_by: Option<Uuid>,
+) {
+    for &kind in kinds {
+        if let Err(e) = generate(db, tournament_id, kind, generated_by).await {
+            tracing::error!(
+                tournament_id = %tournament_id,
+                kind = kind.as_str(),
+                error = %e,
+                "Printout generation failed",
+            );
+        }
+    }
+}
+
+/// Render a club player's stat card for the month starting on `month` and
+/// store it, replacing an earlier card for that month. Returns the card with
+/// the numbers printed on it.
+pub async fn generate_stat_card(
+    db: &PgPool,
+    player: &ClubPlayerRow,
+    month: NaiveDate,
+    generated_by: Option<Uuid>,
+) -> Result<(StatCardRow, MonthlyStatsRow), PrintoutError> {
+    let club = clubs::get_by_id(db, player.club_id)
+        .await?
+        .ok_or(PrintoutError::ClubNotFound)?;
+    let from = month.and_hms_opt(0, 0, 0).expect("midnight").and_utc();
+    let to = from + Months::new(1);
+    let stats = player_stat_cards::monthly_stats(db, player.id, from, to).await?;
+
+    let period = month.format("%B %Y").to_string();
+    let png = stat_card::render(&StatCard {
+        club_name: &club.name,
+        player_name: &player.display_name,
+        period: &period,
+        tournaments: stats.tournaments,
+        itm: stats.itm,
+        best_finish: stats.best_finish,
+    });
+
+    let row =
+        player_stat_cards::upsert(db, player.club_id, player.id, month, &png, generated_by).await?;
+    Ok((row, stats))
+}
+
+/// Group seat rows into tables with every seat listed, open ones included.
+fn table_seating(seats: &[tournament_printouts::SeatRow]) -> Vec<TableSeating> {
+    let mut tables: Vec<TableSeating> = Vec::new();
+    for row in seats {
+        if tables.last().map(|t| t.table_number) != Some(row.table_number) {
+            tables.push(TableSeating {
+                table_number: row.table_number,
+                seats: (1..=row.max_seats).map(|n| (n, None)).collect(),
+            });
+        }
+        let table = tables.last_mut().expect("pushed above");
+        if let (Some(seat), Some(name)) = (row.seat_number, &row.player_name) {
+            match table.seats.iter_mut().find(|(n, _)| *n == seat) {
+                Some(slot) => slot.1 = Some(name.clone()),
+                // Seated beyond a lowered seat override: still print them.
+                None => table.seats.push((seat, Some(name.clone()))),
+            }
+        }
+    }
+    tables
+}
@@ -0,0 +1,237 @@
+//! Shareable monthly stat card: a square PNG with the player's tournaments,
+//! ITM percentage and best finish at one club. Text is drawn with a
+//! built-in 5×7 pixel font: capitals, digits and common punctuation; accents
+//! are folded, anything else shows as `?`.
+
+use super::png::{self, Canvas};
+
+/// Card side in pixels, the square size social networks expect.
+pub const SIZE: u32 = 1080;
+
+const BACKGROUND: u8 = 0;
+const TEXT: u8 = 1;
+const ACCENT: u8 = 2;
+const MUTED: u8 = 3;
+const PANEL: u8 = 4;
+
+const PALETTE: [[u8; 3]; 5] = [
+    [0x0F, 0x3D, 0x2E], // felt green
+    [0xFF, 0xFF, 0xFF],
+    [0xF2, 0xC9, 0x4C], // gold
+    [0xA7, 0xC4, 0xB5],
+    [0x15, 0x50, 0x3C],
+];
+
+const MARGIN: u32 = 80;
+
+/// What goes on a card.
+pub struct StatCard<'a> {
+    pub club_name: &'a str,
+    pub player_name: &'a str,
+    /// E.g. "September 2026".
+    pub period: &'a str,
+    pub tournaments: i64,
+    pub itm: i64,
+    pub best_finish: Option<i32>,
+}
+
+/// Whole-number share of `tournaments` finished in the money; 0 without
+/// tournaments.
+pub fn itm_percentage(tournaments: i64, itm: i64) -> i64 {
+    if tournaments == 0 {
+        0
+    } else {
+        (itm * 100 + tournaments / 2) / tournaments
+    }
+}
+
+/// Render the card as PNG bytes.
+pub fn render(card: &StatCard) -> Vec<u8> {
+    let mut canvas = Canvas::new(SIZE, SIZE, BACKGROUND);
+
+    text(&mut canvas, card.club_name, MARGIN, 90, 6, MUTED);
+    text(&mut canvas, card.player_name, MARGIN, 165, 8, TEXT);
+    text(&mut canvas, card.period, MARGIN, 260, 6, ACCENT);
+    canvas.fill_rect(MARGIN, 340, SIZE - 2 * MARGIN, 8, ACCENT);
+
+    let best = card.best_finish.map_or("-".to_string(), ordinal);
+    let stats = [
+        ("TOURNAMENTS", card.tournaments.to_string()),
+        (
+            "IN THE MONEY",
+            format!("{}%", itm_percentage(card.tournaments, card.itm)),
+        ),
+        ("BEST FINISH", best),
+    ];
+    for (i, (label, value)) in stats.iter().enumerate() {
+        let top = 400 + i as u32 * 190;
+        canvas.fill_rect(MARGIN, top, SIZE - 2 * MARGIN, 170, PANEL);
+        text(&mut canvas, label, MARGIN + 40, top + 30, 5, MUTED);
+        text(&mut canvas, value, MARGIN + 40, top + 80, 10, TEXT);
+    }
+
+    text(&mut canvas, "POCKETPAIR", MARGIN, SIZE - 70, 4, MUTED);
+
+    png::encode(&canvas, &PALETTE)
+}
+
+/// 1ST, 2ND, 3RD, 4TH, 11TH, 12TH, 13TH, 21ST.
+fn ordinal(n: i32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "TH",
+        (1, _) => "ST",
+        (2, _) => "ND",
+        (3, _) => "RD",
+        _ => "TH",
+    };
+    format!("{n}{suffix}")
+}
+
+/// Draw `s` from (`x`, `y`) with each font pixel `scale` pixels wide,
+/// cut off with three dots where it would run past the right margin.
+fn text(canvas: &mut Canvas, s: &str, x: u32, y: u32, scale: u32, colour: u8) {
+    let advance = 6 * scale;
+    let fits = ((canvas.width - MARGIN - x) / advance) as usize;
+    let mut chars: Vec<char> = s.chars().map(fold).collect();
+    if chars.len() > fits {
+        chars.truncate(fits.saturating_sub(3));
+        while chars.last() == Some(&' ') {
+            chars.pop();
+        }
+        chars.extend(['.', '.', '.']);
+    }
+    for (i, c) in chars.into_iter().enumerate() {
+        let left = x + i as u32 * advance;
+        for (row, bits) in glyph(c).iter().enumerate() {
+            for col in 0..5 {
+                if bits & (0b10000 >> col) != 0 {
+                    canvas.fill_rect(
+                        left + col * scale,
+                        y + row as u32 * scale,
+                        scale,
+                        scale,
+                        colour,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Map a character onto the font: capitals, accents dropped.
+fn fold(c: char) -> char {
+    let c = c.to_uppercase().next().unwrap_or(c);
+    match c {
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' => 'A',
+        'Ç' => 'C',
+        'È' | 'É' | 'Ê' | 'Ë' => 'E',
+        'Ì' | 'Í' | 'Î' | 'Ï' => 'I',
+        'Ñ' => 'N',
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' => 'O',
+        'Ù' | 'Ú' | 'Û' | 'Ü' => 'U',
+        'Ý' | 'Ÿ' => 'Y',
+        _ => c,
+    }
+}
+
+/// Seven 5-bit rows, top to bottom, leftmost pixel in the high bit.
+fn glyph(c: char) -> [u8; 7] {
+    match c {
+        'A' => [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'B' => [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E],
+        'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+        'D' => [0x1E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x1E],
+        'E' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F],
+        'F' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10],
+        'G' => [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F],
+        'H' => [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'I' => [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        'J' => [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F],
+        'M' => [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11],
+        'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+        'O' => [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+        'Q' => [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D],
+        'R' => [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11],
+        'S' => [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E],
+        'T' => [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04],
+        'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A],
+        'X' => [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11],
+        'Y' => [0x11, 0x11, 0x0A, 0x04, 0x04, 0x04, 0x04],
+        'Z' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F],
+        '0' => [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E],
+        '1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        '2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+        '3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+        '4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+        '5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+        '6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+        '7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+        '9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+        ' ' => [0x00; 7],
+        '.' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C],
+        ',' => [0x00, 0x00, 0x00, 0x00, 0x0C, 0x04, 0x08],
+        '-' => [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00],
+        '/' => [0x01, 0x02, 0x02, 0x04, 0x08, 0x08, 0x10],
+        ':' => [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00],
+        '%' => [0x18, 0x19, 0x02, 0x04, 0x08, 0x13, 0x03],
+        '\'' => [0x0C, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00],
+        '#' => [0x0A, 0x0A, 0x1F, 0x0A, 0x1F, 0x0A, 0x0A],
+        '&' => [0x0C, 0x12, 0x14, 0x08, 0x15, 0x12, 0x0D],
+        '(' => [0x02, 0x04, 0x08, 0x08, 0x08, 0x04, 0x02],
+        ')' => [0x08, 0x04, 0x02, 0x02, 0x02, 0x04, 0x08],
+        '!' => [0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x04],
+        '+' => [0x00, 0x04, 0x04, 0x1F, 0x04, 0x04, 0x00],
+        '€' => [0x07, 0x08, 0x1E, 0x08, 0x1E, 0x08, 0x07],
+        _ => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04], // '?'
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ordinals() {
+        let got: Vec<String> = [1, 2, 3, 4, 11, 12, 13, 21, 22, 101]
+            .into_iter()
+            .map(ordinal)
+            .collect();
+        assert_eq!(
+            got,
+            ["1ST", "2ND", "3RD", "4TH", "11TH", "12TH", "13TH", "21ST", "22ND", "101ST"]
+        );
+    }
+
+    #[test]
+    fn itm_percentage_rounds() {
+        assert_eq!(itm_percentage(0, 0), 0);
+        assert_eq!(itm_percentage(3, 1), 33);
+        assert_eq!(itm_percentage(3, 2), 67);
+    }
+
+    #[test]
+    fn folds_accents_and_case() {
+        assert_eq!("Zoë Léa".chars().map(fold).collect::<String>(), "ZOE LEA");
+    }
+
+    #[test]
+    fn renders_a_square_png() {
+        let png = render(&StatCard {
+            club_name: "Liège Poker Club",
+            player_name: "A player with a name far too long for a single line",
+            period: "September 2026",
+            tournaments: 12,
+            itm: 5,
+            best_finish: Some(1),
+        });
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(u32::from_be_bytes(png[16..20].try_into().unwrap()), SIZE);
+        assert_eq!(u32::from_be_bytes(png[20..24].try_into().unwrap()), SIZE);
+    }
+}
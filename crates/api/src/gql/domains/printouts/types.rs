@@ -0,0 +1,298 @@
+use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::gql::domains::entries::types::{EntryType, PaymentMethod};
+use crate::gql::scalars::Money;
+use crate::state::AppState;
+use infra::repos::entry_receipts::ReceiptRow;
+use infra::repos::player_stat_cards::{MonthlyStatsRow, StatCardRow};
+use infra::repos::seat_slip_jobs::SeatSlipJobRow;
+use infra::repos::tournament_printouts::PrintoutRow;
+
+use super::link::{self, LinkScope};
+use super::stat_card;
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum PrintoutKind {
+    /// One page per table listing its seats, for the dealer box.
+    SeatCards,
+    /// Every seated player alphabetically, for the lobby wall.
+    SeatList,
+    /// Finishing places and prizes, signed off at the cage.
+    PayoutSheet,
+}
+
+impl PrintoutKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PrintoutKind::SeatCards => "seat_cards",
+            PrintoutKind::SeatList => "seat_list",
+            PrintoutKind::PayoutSheet => "payout_sheet",
+        }
+    }
+
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "seat_list" => PrintoutKind::SeatList,
+            "payout_sheet" => PrintoutKind::PayoutSheet,
+            _ => PrintoutKind::SeatCards,
+        }
+    }
+
+    /// Download filename stem.
+    pub fn file_stem(&self) -> &'static str {
+        match self {
+            PrintoutKind::SeatCards => "seat-cards",
+            PrintoutKind::SeatList => "seat-list",
+            PrintoutKind::PayoutSheet => "payout-sheet",
+        }
+    }
+}
+
+/// A generated PDF. `downloadUrl` is signed and expires at `urlExpiresAt`;
+/// query again for a fresh one.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct TournamentPrintout {
+    pub id: ID,
+    pub tournament_id: ID,
+    pub kind: PrintoutKind,
+    pub page_count: i32,
+    pub size_bytes: i64,
+    pub generated_at: DateTime<Utc>,
+    pub download_url: String,
+    pub url_expires_at: DateTime<Utc>,
+}
+
+impl TournamentPrintout {
+    pub fn new(row: PrintoutRow, state: &AppState, now: DateTime<Utc>) -> Self {
+        let config = state.auth_config();
+        let (download_url, url_expires_at) = link::signed_url(
+            &config.redirect_base_url,
+            &config.jwt_secret,
+            LinkScope::Printout,
+            row.id,
+            now,
+        );
+        Self {
+            id: row.id.into(),
+            tournament_id: row.tournament_id.into(),
+            kind: PrintoutKind::from_db(&row.kind),
+            page_count: row.page_count,
+            size_bytes: row.size_bytes,
+            generated_at: row.generated_at,
+            download_url,
+            url_expires_at,
+        }
+    }
+}
+
+/// A buy-in receipt. `printUrl` serves the ESC/POS payload for the desk's
+/// thermal printer; it is signed, expires at `urlExpiresAt`, and is left out
+/// once the receipt is void.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct EntryReceipt {
+    pub id: ID,
+    /// Null once the entry was deleted.
+    pub entry_id: Option<ID>,
+    pub tournament_id: ID,
+    /// Printed as a QR code; scan it into `verifyEntryReceipt`.
+    pub verification_code: String,
+    pub club_name: String,
+    pub tournament_name: String,
+    pub player_name: String,
+    pub entry_type: EntryType,
+    pub payment_method: PaymentMethod,
+    pub amount_cents: Money,
+    /// Drink voucher paid together with an initial buy-in.
+    pub voucher_cents: Money,
+    pub chips_received: Option<i32>,
+    pub issued_at: DateTime<Utc>,
+    pub print_count: i32,
+    /// The entry was deleted after the receipt was issued.
+    pub voided: bool,
+    pub print_url: Option<String>,
+    pub url_expires_at: Option<DateTime<Utc>>,
+}
+
+impl EntryReceipt {
+    pub fn new(row: ReceiptRow, state: &AppState, now: DateTime<Utc>) -> Self {
+        let voided = row.entry_id.is_none();
+        let (print_url, url_expires_at) = if voided {
+            (None, None)
+        } else {
+            let config = state.auth_config();
+            let (url, expires_at) = link::signed_url(
+                &config.redirect_base_url,
+                &config.jwt_secret,
+                LinkScope::Receipt,
+                row.id,
+                now,
+            );
+            (Some(url), Some(expires_at))
+        };
+        Self {
+            id: row.id.into(),
+            entry_id: row.entry_id.map(Into::into),
+            tournament_id: row.tournament_id.into(),
+            verification_code: row.verification_code,
+            club_name: row.club_name,
+            tournament_name: row.tournament_name,
+            player_name: row.player_name,
+            entry_type: EntryType::from(row.entry_type),
+            payment_method: PaymentMethod::from(row.payment_method),
+            amount_cents: row.amount_cents.into(),
+            voucher_cents: row.voucher_cents.into(),
+            chips_received: row.chips_received,
+            issued_at: row.issued_at,
+            print_count: row.print_count,
+            voided,
+            print_url,
+            url_expires_at,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum SeatSlipJobStatus {
+    /// Waiting for the print agent.
+    Pending,
+    /// Claimed by the agent, outcome not reported yet.
+    Printing,
+    Printed,
+    Failed,
+}
+
+impl SeatSlipJobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SeatSlipJobStatus::Pending => "pending",
+            SeatSlipJobStatus::Printing => "printing",
+            SeatSlipJobStatus::Printed => "printed",
+            SeatSlipJobStatus::Failed => "failed",
+        }
+    }
+
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "printing" => SeatSlipJobStatus::Printing,
+            "printed" => SeatSlipJobStatus::Printed,
+            "failed" => SeatSlipJobStatus::Failed,
+            _ => SeatSlipJobStatus::Pending,
+        }
+    }
+}
+
+/// A seat slip queued for the desk's thermal printer when a player is
+/// seated. `printUrl` serves the ESC/POS payload; it is signed and expires
+/// at `urlExpiresAt`.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct SeatSlipPrintJob {
+    pub id: ID,
+    pub tournament_id: ID,
+    /// Null once the seat assignment is gone.
+    pub seat_assignment_id: Option<ID>,
+    pub tournament_name: String,
+    pub player_name: String,
+    pub table_number: i32,
+    pub seat_number: i32,
+    pub status: SeatSlipJobStatus,
+    /// The job this one reprints; the slip is marked as a copy.
+    pub reprint_of: Option<ID>,
+    /// Times the agent claimed the job.
+    pub attempts: i32,
+    /// What the agent reported for a failed print.
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub claimed_at: Option<DateTime<Utc>>,
+    pub printed_at: Option<DateTime<Utc>>,
+    pub print_url: String,
+    pub url_expires_at: DateTime<Utc>,
+}
+
+impl SeatSlipPrintJob {
+    pub fn new(row: SeatSlipJobRow, state: &AppState, now: DateTime<Utc>) -> Self {
+        let config = state.auth_config();
+        let (print_url, url_expires_at) = link::signed_url(
+            &config.redirect_base_url,
+            &config.jwt_secret,
+            LinkScope::SeatSlip,
+            row.id,
+            now,
+        );
+        Self {
+            id: row.id.into(),
+            tournament_id: row.tournament_id.into(),
+            seat_assignment_id: row.seat_assignment_id.map(Into::into),
+            tournament_name: row.tournament_name,
+            player_name: row.player_name,
+            table_number: row.table_number,
+            seat_number: row.seat_number,
+            status: SeatSlipJobStatus::from_db(&row.status),
+            reprint_of: row.reprint_of.map(Into::into),
+            attempts: row.attempts,
+            error: row.error,
+            created_at: row.created_at,
+            claimed_at: row.claimed_at,
+            printed_at: row.printed_at,
+            print_url,
+            url_expires_at,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct GeneratePlayerStatCardInput {
+    pub club_player_id: ID,
+    pub year: i32,
+    /// 1 to 12. The current month gives a card of the month so far.
+    pub month: u32,
+}
+
+/// A player's monthly stat card, a 1080×1080 PNG for social posts.
+/// `imageUrl` is signed and expires at `urlExpiresAt`, a week out;
+/// regenerate for a fresh one.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct PlayerStatCard {
+    pub id: ID,
+    pub club_player_id: ID,
+    /// First day of the month the card covers.
+    pub month: NaiveDate,
+    pub tournaments: i64,
+    /// Share of those tournaments finished with a prize, rounded.
+    pub itm_percentage: i64,
+    pub best_finish: Option<i32>,
+    pub size_bytes: i64,
+    pub generated_at: DateTime<Utc>,
+    pub image_url: String,
+    pub url_expires_at: DateTime<Utc>,
+}
+
+impl PlayerStatCard {
+    pub fn new(
+        row: StatCardRow,
+        stats: MonthlyStatsRow,
+        state: &AppState,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let config = state.auth_config();
+        let (image_url, url_expires_at) = link::signed_url(
+            &config.redirect_base_url,
+            &config.jwt_secret,
+            LinkScope::StatCard,
+            row.id,
+            now,
+        );
+        Self {
+            id: row.id.into(),
+            club_player_id: row.club_player_id.into(),
+            month: row.month,
+            tournaments: stats.tournaments,
+            itm_percentage: stat_card::itm_percentage(stats.tournaments, stats.itm),
+            best_finish: stats.best_finish,
+            size_bytes: row.size_bytes,
+            generated_at: row.generated_at,
+            image_url,
+            url_expires_at,
+        }
+    }
+}
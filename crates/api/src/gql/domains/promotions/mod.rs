@@ -0,0 +1,24 @@
+pub mod resolvers;
+pub mod types;
+
+pub use resolvers::{PromotionMutation, PromotionQuery};
+
+use async_graphql::{Context, Result};
+use uuid::Uuid;
+
+use crate::auth::permissions::{viewer_is_display_of, viewer_manages_club};
+use crate::state::AppState;
+
+/// Whether a club's promotions board must be hidden from the viewer: free
+/// ("Home Game") clubs are only visible to their own managers, admins and
+/// the club's paired displays.
+pub async fn board_hidden_from_viewer(ctx: &Context<'_>, club_id: Uuid) -> Result<bool> {
+    if viewer_is_display_of(ctx, club_id) {
+        return Ok(false);
+    }
+    let state = ctx.data::<AppState>()?;
+    if !infra::repos::clubs::is_free(&state.db, club_id).await? {
+        return Ok(false);
+    }
+    Ok(!viewer_manages_club(ctx, club_id).await)
+}
@@ -0,0 +1,391 @@
+use async_graphql::{Context, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::auth::jwt::Claims;
+use crate::auth::permissions::{require_club_manager, viewer_manages_club};
+use crate::gql::domains::staff::types::StaffRole;
+use crate::gql::error::ResultExt;
+use crate::gql::scalars::Money;
+use crate::gql::subscriptions::publish_promotion_event;
+use crate::state::AppState;
+use infra::repos::{club_players, club_staff, promotions};
+
+use super::board_hidden_from_viewer;
+use super::types::{
+    CreatePromotionJackpotInput, PromotionBoardEvent, PromotionBoardEventType, PromotionHand,
+    PromotionJackpot, RecordQualifyingHandInput, UpdatePromotionJackpotInput,
+};
+
+const MAX_NAME_LENGTH: usize = 120;
+
+fn validate_name(name: &str) -> Result<()> {
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+        return Err(async_graphql::Error::new(
+            "Jackpot name must be between 1 and 120 characters",
+        ));
+    }
+    Ok(())
+}
+
+fn require_positive(amount: Money) -> Result<()> {
+    if amount.cents() <= 0 {
+        return Err(async_graphql::Error::new("Amount must be positive"));
+    }
+    Ok(())
+}
+
+/// Qualifying hands are recorded by the floor: a manager of the club, or an
+/// active floor-staff member linked to the caller's account. Returns the
+/// caller's user ID.
+async fn require_floor_staff(ctx: &Context<'_>, club_id: Uuid) -> Result<Uuid> {
+    let claims = ctx.data::<Claims>()?;
+    let caller = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+    if viewer_manages_club(ctx, club_id).await {
+        return Ok(caller);
+    }
+    let state = ctx.data::<AppState>()?;
+    club_staff::find_active_for_user(&state.db, club_id, caller, StaffRole::Floor.as_db())
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Only floor staff can record qualifying hands"))?;
+    Ok(caller)
+}
+
+async fn load_jackpot(state: &AppState, jackpot_id: &ID) -> Result<promotions::JackpotRow> {
+    let id = Uuid::parse_str(jackpot_id.as_str()).gql_err("Invalid jackpot ID")?;
+    promotions::get_jackpot(&state.db, id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Jackpot not found"))
+}
+
+/// Load a hand with its jackpot and check the caller manages the club.
+/// Returns the hand, the jackpot and the caller's user ID.
+async fn load_managed_hand(
+    ctx: &Context<'_>,
+    hand_id: &ID,
+) -> Result<(promotions::HandRow, promotions::JackpotRow, Uuid)> {
+    let state = ctx.data::<AppState>()?;
+    let id = Uuid::parse_str(hand_id.as_str()).gql_err("Invalid hand ID")?;
+    let hand = promotions::get_hand(&state.db, id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Hand not found"))?;
+    let jackpot = load_jackpot(state, &hand.jackpot_id.into()).await?;
+    let manager = require_club_manager(ctx, jackpot.club_id).await?;
+    let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid user ID")?;
+    Ok((hand, jackpot, manager_id))
+}
+
+/// Re-read the jackpot (for its new balance) and push the change to the board.
+async fn publish(
+    state: &AppState,
+    jackpot_id: Uuid,
+    event_type: PromotionBoardEventType,
+    hand: Option<PromotionHand>,
+) -> Result<PromotionJackpot> {
+    let jackpot = promotions::get_jackpot(&state.db, jackpot_id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Jackpot not found"))?;
+    let club_id = jackpot.club_id;
+    let jackpot = PromotionJackpot::from(jackpot);
+    publish_promotion_event(
+        club_id,
+        PromotionBoardEvent {
+            event_type,
+            jackpot: jackpot.clone(),
+            hand,
+        },
+    );
+    Ok(jackpot)
+}
+
+#[derive(Default)]
+pub struct PromotionQuery;
+
+#[Object]
+impl PromotionQuery {
+    /// A club's active jackpots with their running balances, for the lobby
+    /// TV. Free ("Home Game") clubs only show theirs to their managers.
+    async fn promotion_board(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+    ) -> Result<Vec<PromotionJackpot>> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        if board_hidden_from_viewer(ctx, club_id).await? {
+            return Ok(vec![]);
+        }
+        let state = ctx.data::<AppState>()?;
+        let rows = promotions::list_jackpots(&state.db, club_id, true).await?;
+        Ok(rows.into_iter().map(PromotionJackpot::from).collect())
+    }
+
+    /// All of a club's jackpots, inactive ones included. Club managers.
+    async fn club_promotion_jackpots(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+    ) -> Result<Vec<PromotionJackpot>> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        let state = ctx.data::<AppState>()?;
+        let rows = promotions::list_jackpots(&state.db, club_id, false).await?;
+        Ok(rows.into_iter().map(PromotionJackpot::from).collect())
+    }
+}
+
+#[derive(Default)]
+pub struct PromotionMutation;
+
+#[Object]
+impl PromotionMutation {
+    /// Start a high-hand or bad-beat jackpot, optionally with a seed funded
+    /// by the club. Club managers.
+    async fn create_promotion_jackpot(
+        &self,
+        ctx: &Context<'_>,
+        input: CreatePromotionJackpotInput,
+    ) -> Result<PromotionJackpot> {
+        let state = ctx.data::<AppState>()?;
+        let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid user ID")?;
+
+        let name = input.name.trim();
+        validate_name(name)?;
+        if let Some(seed) = input.seed {
+            require_positive(seed)?;
+        }
+        let rules = input
+            .rules
+            .as_deref()
+            .map(str::trim)
+            .filter(|r| !r.is_empty());
+
+        let mut tx = state.db.begin().await?;
+        let jackpot = promotions::create_jackpot(
+            &mut *tx,
+            club_id,
+            input.kind.as_str(),
+            name,
+            rules,
+            manager_id,
+        )
+        .await?;
+        if let Some(seed) = input.seed {
+            promotions::add_entry(
+                &mut *tx,
+                jackpot.id,
+                "seed",
+                seed.cents(),
+                None,
+                None,
+                manager_id,
+            )
+            .await?;
+        }
+        tx.commit().await?;
+
+        publish(
+            state,
+            jackpot.id,
+            PromotionBoardEventType::JackpotUpdated,
+            None,
+        )
+        .await
+    }
+
+    /// Rename a jackpot, change its rules or take it off the board. Club
+    /// managers.
+    async fn update_promotion_jackpot(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdatePromotionJackpotInput,
+    ) -> Result<PromotionJackpot> {
+        let state = ctx.data::<AppState>()?;
+        let jackpot = load_jackpot(state, &input.jackpot_id).await?;
+        require_club_manager(ctx, jackpot.club_id).await?;
+
+        let name = input.name.as_deref().map(str::trim);
+        if let Some(name) = name {
+            validate_name(name)?;
+        }
+        let rules = input.rules.as_deref().map(str::trim);
+
+        promotions::update_jackpot(&state.db, jackpot.id, name, rules, input.is_active)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Jackpot not found"))?;
+        publish(
+            state,
+            jackpot.id,
+            PromotionBoardEventType::JackpotUpdated,
+            None,
+        )
+        .await
+    }
+
+    /// Add money to a jackpot: the drop taken from the games, or a further
+    /// seed funded by the club. Club managers.
+    async fn add_jackpot_contribution(
+        &self,
+        ctx: &Context<'_>,
+        jackpot_id: ID,
+        amount: Money,
+        #[graphql(default = false)] seed: bool,
+        note: Option<String>,
+    ) -> Result<PromotionJackpot> {
+        let state = ctx.data::<AppState>()?;
+        let jackpot = load_jackpot(state, &jackpot_id).await?;
+        let manager = require_club_manager(ctx, jackpot.club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid user ID")?;
+        require_positive(amount)?;
+
+        let note = note.as_deref().map(str::trim).filter(|n| !n.is_empty());
+        promotions::add_entry(
+            &state.db,
+            jackpot.id,
+            if seed { "seed" } else { "contribution" },
+            amount.cents(),
+            None,
+            note,
+            manager_id,
+        )
+        .await?;
+        publish(
+            state,
+            jackpot.id,
+            PromotionBoardEventType::JackpotUpdated,
+            None,
+        )
+        .await
+    }
+
+    /// Record a qualifying hand and the amount it will be paid. It shows on
+    /// the board right away; a manager pays it out. Floor staff or managers.
+    async fn record_qualifying_hand(
+        &self,
+        ctx: &Context<'_>,
+        input: RecordQualifyingHandInput,
+    ) -> Result<PromotionHand> {
+        let state = ctx.data::<AppState>()?;
+        let jackpot = load_jackpot(state, &input.jackpot_id).await?;
+        let recorded_by = require_floor_staff(ctx, jackpot.club_id).await?;
+        if !jackpot.is_active {
+            return Err(async_graphql::Error::new("This jackpot is not active"));
+        }
+        require_positive(input.amount)?;
+
+        let (club_player_id, player_name) = match &input.club_player_id {
+            Some(id) => {
+                let id = Uuid::parse_str(id.as_str()).gql_err("Invalid club player ID")?;
+                let player = club_players::get_by_id(&state.db, id)
+                    .await?
+                    .filter(|p| p.club_id == jackpot.club_id)
+                    .ok_or_else(|| async_graphql::Error::new("Player not found in this club"))?;
+                (Some(player.id), player.display_name)
+            }
+            None => {
+                let name = input
+                    .player_name
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|n| !n.is_empty())
+                    .ok_or_else(|| {
+                        async_graphql::Error::new("A player or player name is required")
+                    })?;
+                (None, name.to_string())
+            }
+        };
+        let hand = input.hand.trim();
+        if hand.is_empty() || hand.chars().count() > 120 {
+            return Err(async_graphql::Error::new(
+                "Hand must be between 1 and 120 characters",
+            ));
+        }
+
+        let row = promotions::create_hand(
+            &state.db,
+            promotions::NewHand {
+                jackpot_id: jackpot.id,
+                club_player_id,
+                player_name,
+                hand: hand.to_string(),
+                table_label: input
+                    .table_label
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|t| !t.is_empty())
+                    .map(String::from),
+                amount_cents: input.amount.cents(),
+                recorded_by,
+            },
+        )
+        .await?;
+        let hand = PromotionHand::from(row);
+        publish(
+            state,
+            jackpot.id,
+            PromotionBoardEventType::HandRecorded,
+            Some(hand.clone()),
+        )
+        .await?;
+        Ok(hand)
+    }
+
+    /// Pay a pending hand out of its jackpot. The payout is booked on the
+    /// jackpot ledger and can't exceed the balance. Club managers.
+    async fn pay_qualifying_hand(&self, ctx: &Context<'_>, hand_id: ID) -> Result<PromotionHand> {
+        let state = ctx.data::<AppState>()?;
+        let (hand, jackpot, manager_id) = load_managed_hand(ctx, &hand_id).await?;
+
+        let mut tx = state.db.begin().await?;
+        promotions::lock_jackpot(&mut *tx, jackpot.id).await?;
+        let balance = promotions::balance(&mut *tx, jackpot.id).await?;
+        if hand.amount_cents > balance {
+            return Err(async_graphql::Error::new(
+                "The payout exceeds the jackpot balance",
+            ));
+        }
+        let paid = promotions::mark_hand_paid(&mut *tx, hand.id, manager_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Only pending hands can be paid"))?;
+        promotions::add_entry(
+            &mut *tx,
+            jackpot.id,
+            "payout",
+            -paid.amount_cents,
+            Some(paid.id),
+            None,
+            manager_id,
+        )
+        .await?;
+        tx.commit().await?;
+
+        let hand = PromotionHand::from(paid);
+        publish(
+            state,
+            jackpot.id,
+            PromotionBoardEventType::HandPaid,
+            Some(hand.clone()),
+        )
+        .await?;
+        Ok(hand)
+    }
+
+    /// Void a pending hand recorded in error. Club managers.
+    async fn void_qualifying_hand(&self, ctx: &Context<'_>, hand_id: ID) -> Result<PromotionHand> {
+        let state = ctx.data::<AppState>()?;
+        let (hand, jackpot, _) = load_managed_hand(ctx, &hand_id).await?;
+        let voided = promotions::void_hand(&state.db, hand.id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Only pending hands can be voided"))?;
+
+        let hand = PromotionHand::from(voided);
+        publish(
+            state,
+            jackpot.id,
+            PromotionBoardEventType::HandVoided,
+            Some(hand.clone()),
+        )
+        .await?;
+        Ok(hand)
+    }
+}
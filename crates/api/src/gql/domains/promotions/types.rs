@@ -0,0 +1,234 @@
+use async_graphql::{ComplexObject, Context, Enum, InputObject, Result, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::auth::permissions::require_club_manager;
+use crate::gql::error::ResultExt;
+use crate::gql::scalars::Money;
+use crate::state::AppState;
+use infra::repos::promotions::{self, HandRow, JackpotEntryRow, JackpotRow};
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+pub enum JackpotKind {
+    /// Paid to the best hand of a session.
+    HighHand,
+    /// Paid when a monster hand is beaten.
+    BadBeat,
+}
+
+impl JackpotKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JackpotKind::HighHand => "high_hand",
+            JackpotKind::BadBeat => "bad_beat",
+        }
+    }
+
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "bad_beat" => JackpotKind::BadBeat,
+            _ => JackpotKind::HighHand,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+pub enum PromotionHandStatus {
+    Pending,
+    Paid,
+    Voided,
+}
+
+impl PromotionHandStatus {
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "paid" => PromotionHandStatus::Paid,
+            "voided" => PromotionHandStatus::Voided,
+            _ => PromotionHandStatus::Pending,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum JackpotEntryKind {
+    /// Money the club puts in to start (or top up) the jackpot.
+    Seed,
+    /// The drop taken from the games.
+    Contribution,
+    /// Paid out to a qualifying hand.
+    Payout,
+}
+
+impl JackpotEntryKind {
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "seed" => JackpotEntryKind::Seed,
+            "payout" => JackpotEntryKind::Payout,
+            _ => JackpotEntryKind::Contribution,
+        }
+    }
+}
+
+/// A running promotion jackpot. `balance` is the sum of its ledger.
+#[derive(SimpleObject, Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[graphql(complex)]
+pub struct PromotionJackpot {
+    pub id: ID,
+    pub club_id: ID,
+    pub kind: JackpotKind,
+    pub name: String,
+    pub rules: Option<String>,
+    pub is_active: bool,
+    pub balance: Money,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<JackpotRow> for PromotionJackpot {
+    fn from(row: JackpotRow) -> Self {
+        Self {
+            id: row.id.into(),
+            club_id: row.club_id.into(),
+            kind: JackpotKind::from_db(&row.kind),
+            name: row.name,
+            rules: row.rules,
+            is_active: row.is_active,
+            balance: Money(row.balance_cents),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[ComplexObject]
+impl PromotionJackpot {
+    /// Qualifying hands, newest first (voided hands left out).
+    async fn recent_hands(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default = 10)] limit: i32,
+    ) -> Result<Vec<PromotionHand>> {
+        let state = ctx.data::<AppState>()?;
+        let id = Uuid::parse_str(self.id.as_str()).gql_err("Invalid jackpot ID")?;
+        let rows = promotions::list_hands(&state.db, id, limit.clamp(1, 100) as i64).await?;
+        Ok(rows.into_iter().map(PromotionHand::from).collect())
+    }
+
+    /// The jackpot's ledger, newest first. Club managers.
+    async fn entries(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default = 50)] limit: i32,
+    ) -> Result<Vec<JackpotEntry>> {
+        let club_id = Uuid::parse_str(self.club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        let state = ctx.data::<AppState>()?;
+        let id = Uuid::parse_str(self.id.as_str()).gql_err("Invalid jackpot ID")?;
+        let rows = promotions::list_entries(&state.db, id, limit.clamp(1, 500) as i64).await?;
+        Ok(rows.into_iter().map(JackpotEntry::from).collect())
+    }
+}
+
+/// A hand the floor recorded as qualifying for a jackpot.
+#[derive(SimpleObject, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PromotionHand {
+    pub id: ID,
+    pub jackpot_id: ID,
+    pub club_player_id: Option<ID>,
+    pub player_name: String,
+    /// The hand as described by the floor, e.g. "Quad kings".
+    pub hand: String,
+    pub table_label: Option<String>,
+    pub amount: Money,
+    pub status: PromotionHandStatus,
+    pub paid_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<HandRow> for PromotionHand {
+    fn from(row: HandRow) -> Self {
+        Self {
+            id: row.id.into(),
+            jackpot_id: row.jackpot_id.into(),
+            club_player_id: row.club_player_id.map(Into::into),
+            player_name: row.player_name,
+            hand: row.hand,
+            table_label: row.table_label,
+            amount: Money(row.amount_cents),
+            status: PromotionHandStatus::from_db(&row.status),
+            paid_at: row.paid_at,
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// A jackpot ledger entry. Payouts carry a negative amount.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct JackpotEntry {
+    pub id: ID,
+    pub kind: JackpotEntryKind,
+    pub amount: Money,
+    pub hand_id: Option<ID>,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<JackpotEntryRow> for JackpotEntry {
+    fn from(row: JackpotEntryRow) -> Self {
+        Self {
+            id: row.id.into(),
+            kind: JackpotEntryKind::from_db(&row.kind),
+            amount: Money(row.amount_cents),
+            hand_id: row.hand_id.map(Into::into),
+            note: row.note,
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+pub enum PromotionBoardEventType {
+    /// Created, edited, seeded or contributed to.
+    JackpotUpdated,
+    HandRecorded,
+    HandPaid,
+    HandVoided,
+}
+
+/// A change on a club's promotions board, for the TV display.
+#[derive(SimpleObject, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PromotionBoardEvent {
+    pub event_type: PromotionBoardEventType,
+    pub jackpot: PromotionJackpot,
+    pub hand: Option<PromotionHand>,
+}
+
+#[derive(InputObject)]
+pub struct CreatePromotionJackpotInput {
+    pub club_id: ID,
+    pub kind: JackpotKind,
+    pub name: String,
+    pub rules: Option<String>,
+    /// Opening balance funded by the club.
+    pub seed: Option<Money>,
+}
+
+#[derive(InputObject)]
+pub struct UpdatePromotionJackpotInput {
+    pub jackpot_id: ID,
+    pub name: Option<String>,
+    pub rules: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(InputObject)]
+pub struct RecordQualifyingHandInput {
+    pub jackpot_id: ID,
+    /// Roster entry of the winner; their name is taken from it.
+    pub club_player_id: Option<ID>,
+    /// Required when no roster entry is given.
+    pub player_name: Option<String>,
+    pub hand: String,
+    pub table_label: Option<String>,
+    pub amount: Money,
+}
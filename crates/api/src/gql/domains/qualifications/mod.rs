@@ -0,0 +1,10 @@
+//! Qualification for a series' final: play `min_events` of its events,
+//! optionally scoring `min_points` there. An event counts once the player was
+//! checked in, seated or busted, or has a result. Creating the final
+//! registers everyone qualified so far; entering results registers the rest.
+
+pub mod resolvers;
+pub mod service;
+pub mod types;
+
+pub use resolvers::{QualificationMutation, QualificationQuery};
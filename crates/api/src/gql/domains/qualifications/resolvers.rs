@@ -0,0 +1,155 @@
+use async_graphql::{Context, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::auth::permissions::require_club_manager;
+use crate::auth::Claims;
+use crate::gql::error::{auth_error, ResultExt};
+use crate::state::AppState;
+use infra::repos::qualification_rules::{
+    self, CreateQualificationRule, QualificationProgressRow, QualificationRuleRow,
+};
+use infra::repos::{club_players, tournament_series};
+
+use super::types::{CreateQualificationRuleInput, QualificationProgress, QualificationRule};
+
+async fn get_rule(state: &AppState, rule_id: &ID) -> Result<QualificationRuleRow> {
+    let id = Uuid::parse_str(rule_id.as_str()).gql_err("Invalid rule ID")?;
+    qualification_rules::get_by_id(&state.db, id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Qualification rule not found"))
+}
+
+#[derive(Default)]
+pub struct QualificationQuery;
+
+#[Object]
+impl QualificationQuery {
+    /// A club's qualification rules, newest first.
+    async fn qualification_rules(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+    ) -> Result<Vec<QualificationRule>> {
+        let state = ctx.data::<AppState>()?;
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        let rows = qualification_rules::list_by_club(&state.db, club_id).await?;
+        Ok(rows.into_iter().map(QualificationRule::from).collect())
+    }
+
+    /// Everyone who has played a rule's events, closest to qualifying first
+    /// (managers only).
+    async fn qualification_standings(
+        &self,
+        ctx: &Context<'_>,
+        rule_id: ID,
+    ) -> Result<Vec<QualificationProgress>> {
+        let state = ctx.data::<AppState>()?;
+        let rule = get_rule(state, &rule_id).await?;
+        require_club_manager(ctx, rule.club_id).await?;
+
+        let rows = qualification_rules::progress(&state.db, &rule, None).await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| QualificationProgress::new(&rule, row))
+            .collect())
+    }
+
+    /// The current user's progress toward every qualification rule of the
+    /// clubs they're on the roster of, or just `clubId`'s.
+    async fn my_qualification_progress(
+        &self,
+        ctx: &Context<'_>,
+        club_id: Option<ID>,
+    ) -> Result<Vec<QualificationProgress>> {
+        let claims = ctx.data::<Claims>().map_err(|_| auth_error())?;
+        let user_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+        let state = ctx.data::<AppState>()?;
+        let club_id = club_id
+            .map(|id| Uuid::parse_str(id.as_str()))
+            .transpose()
+            .gql_err("Invalid club ID")?;
+
+        let mut progress = Vec::new();
+        for player in club_players::list_for_app_user(&state.db, user_id).await? {
+            if club_id.is_some_and(|id| id != player.club_id) {
+                continue;
+            }
+            for rule in qualification_rules::list_by_club(&state.db, player.club_id).await? {
+                let row = qualification_rules::progress(&state.db, &rule, Some(player.id))
+                    .await?
+                    .into_iter()
+                    .next()
+                    .unwrap_or_else(|| QualificationProgressRow {
+                        club_player_id: player.id,
+                        display_name: player.display_name.clone(),
+                        app_user_id: Some(user_id),
+                        events_played: 0,
+                        points: 0,
+                    });
+                progress.push(QualificationProgress::new(&rule, row));
+            }
+        }
+        Ok(progress)
+    }
+}
+
+#[derive(Default)]
+pub struct QualificationMutation;
+
+#[Object]
+impl QualificationMutation {
+    /// Add a qualification rule over a series' events (managers only). Create
+    /// the final with `createTournament(qualificationRuleId)` to register the
+    /// players who qualified.
+    async fn create_qualification_rule(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateQualificationRuleInput,
+    ) -> Result<QualificationRule> {
+        let state = ctx.data::<AppState>()?;
+        let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+
+        let series_id = Uuid::parse_str(input.series_id.as_str()).gql_err("Invalid series ID")?;
+        tournament_series::get_by_id(&state.db, series_id)
+            .await?
+            .filter(|s| s.club_id == club_id)
+            .ok_or_else(|| async_graphql::Error::new("Series not found"))?;
+
+        let name = input.name.trim();
+        if name.is_empty() {
+            return Err(async_graphql::Error::new("Name is required"));
+        }
+        if input.min_events < 1 {
+            return Err(async_graphql::Error::new(
+                "A rule needs at least one event to play",
+            ));
+        }
+        if input.min_points.is_some_and(|points| points < 1) {
+            return Err(async_graphql::Error::new("Minimum points must be positive"));
+        }
+
+        let row = qualification_rules::create(
+            &state.db,
+            CreateQualificationRule {
+                club_id,
+                series_id,
+                name: name.to_string(),
+                min_events: input.min_events,
+                min_points: input.min_points,
+            },
+        )
+        .await?;
+        Ok(row.into())
+    }
+
+    /// Remove a qualification rule (managers only). Players already
+    /// registered in its final stay registered.
+    async fn delete_qualification_rule(&self, ctx: &Context<'_>, rule_id: ID) -> Result<bool> {
+        let state = ctx.data::<AppState>()?;
+        let rule = get_rule(state, &rule_id).await?;
+        require_club_manager(ctx, rule.club_id).await?;
+
+        Ok(qualification_rules::delete(&state.db, rule.id).await?)
+    }
+}
@@ -0,0 +1,69 @@
+//! Registers the players who meet a qualification rule in its final.
+
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use infra::repos::qualification_rules::{self, QualificationRuleRow};
+use infra::repos::tournament_registrations::{self, CreateTournamentRegistration};
+use infra::repos::tournaments;
+
+/// Register every player meeting `rule` in its final, skipping those already
+/// in it in any status (a cancelled qualifier isn't brought back). The final
+/// is a freeroll for its qualifiers, so the seat cap doesn't apply. Returns
+/// how many were registered.
+pub async fn register_qualified(
+    conn: &mut PgConnection,
+    rule: &QualificationRuleRow,
+) -> sqlx::Result<usize> {
+    let Some(final_id) = rule.final_tournament_id else {
+        return Ok(0);
+    };
+    let mut registered = 0;
+    for player in qualification_rules::progress(&mut *conn, rule, None).await? {
+        if !rule.is_met_by(&player) {
+            continue;
+        }
+        let created = tournament_registrations::create_if_absent(
+            &mut *conn,
+            CreateTournamentRegistration {
+                id: None,
+                tournament_id: final_id,
+                user_id: player.app_user_id,
+                club_player_id: Some(player.club_player_id),
+                notes: Some(format!("Qualified: {}", rule.name)),
+                status: None,
+                invite_id: None,
+            },
+        )
+        .await?;
+        registered += usize::from(created.is_some());
+    }
+    Ok(registered)
+}
+
+/// After results are entered for a tournament, register anyone it just
+/// qualified in the finals of its series' rules. Best-effort: failures are
+/// logged and never fail the caller.
+pub async fn register_for_finals_logged(db: &PgPool, tournament_id: Uuid) {
+    let result: sqlx::Result<()> = async {
+        let Some(series_id) = tournaments::get_by_id(db, tournament_id)
+            .await?
+            .and_then(|t| t.series_id)
+        else {
+            return Ok(());
+        };
+        let mut conn = db.acquire().await?;
+        for rule in qualification_rules::list_with_final_for_series(&mut *conn, series_id).await? {
+            register_qualified(&mut conn, &rule).await?;
+        }
+        Ok(())
+    }
+    .await;
+    if let Err(e) = result {
+        tracing::error!(
+            tournament_id = %tournament_id,
+            error = %e,
+            "Registering qualifiers failed",
+        );
+    }
+}
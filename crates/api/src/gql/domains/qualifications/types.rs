@@ -0,0 +1,79 @@
+use async_graphql::{InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use infra::repos::qualification_rules::{QualificationProgressRow, QualificationRuleRow};
+
+/// What a player needs across a series' events to reach its final, e.g.
+/// 10 of the weekly events for the freeroll final.
+#[derive(SimpleObject, Clone)]
+pub struct QualificationRule {
+    pub id: ID,
+    pub club_id: ID,
+    /// The series whose events count.
+    pub series_id: ID,
+    pub name: String,
+    pub min_events: i32,
+    /// League points also required across those events.
+    pub min_points: Option<i32>,
+    /// Set once the final is created; qualifiers are registered in it.
+    pub final_tournament_id: Option<ID>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<QualificationRuleRow> for QualificationRule {
+    fn from(row: QualificationRuleRow) -> Self {
+        Self {
+            id: row.id.into(),
+            club_id: row.club_id.into(),
+            series_id: row.series_id.into(),
+            name: row.name,
+            min_events: row.min_events,
+            min_points: row.min_points,
+            final_tournament_id: row.final_tournament_id.map(Into::into),
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// A player's tally under a rule.
+#[derive(SimpleObject, Clone)]
+pub struct QualificationProgress {
+    pub rule: QualificationRule,
+    pub club_player_id: ID,
+    pub display_name: String,
+    pub events_played: i32,
+    pub points: i32,
+    pub qualified: bool,
+    /// Events still to play; 0 once there are enough.
+    pub events_needed: i32,
+    /// Points still to score, for a rule with a points threshold.
+    pub points_needed: Option<i32>,
+}
+
+impl QualificationProgress {
+    pub fn new(rule: &QualificationRuleRow, row: QualificationProgressRow) -> Self {
+        let qualified = rule.is_met_by(&row);
+        let events_played = i32::try_from(row.events_played).unwrap_or(i32::MAX);
+        let points = i32::try_from(row.points).unwrap_or(i32::MAX);
+        Self {
+            rule: rule.clone().into(),
+            club_player_id: row.club_player_id.into(),
+            display_name: row.display_name,
+            events_played,
+            points,
+            qualified,
+            events_needed: (rule.min_events - events_played).max(0),
+            points_needed: rule.min_points.map(|min| (min - points).max(0)),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreateQualificationRuleInput {
+    pub club_id: ID,
+    /// The series whose events count (see `addTournamentToSeries`).
+    pub series_id: ID,
+    pub name: String,
+    pub min_events: i32,
+    pub min_points: Option<i32>,
+}
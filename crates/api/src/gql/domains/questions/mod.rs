@@ -0,0 +1,461 @@
+//! Registration form fields: validating the answers players give, storing
+//! them as typed JSON and tallying them for the organizer.
+
+pub mod resolvers;
+pub mod types;
+
+pub use resolvers::{RegistrationQuestionMutation, RegistrationQuestionQuery};
+
+use std::collections::HashSet;
+
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use infra::repos::registration_questions::{AnswerRow, QuestionRow};
+
+use types::{
+    AnswerChoiceCount, AnswerTextResponse, RegistrationAnswerInput, RegistrationQuestion,
+    RegistrationQuestionKind, RegistrationQuestionReport,
+};
+
+/// Longest text answer when the field sets no limit, in characters.
+pub const DEFAULT_TEXT_LIMIT: usize = 500;
+
+/// The longest text answer a field accepts.
+pub fn text_limit(max_length: Option<i32>) -> usize {
+    max_length.map_or(DEFAULT_TEXT_LIMIT, |n| n.max(1) as usize)
+}
+
+/// A validated answer, typed by its field's kind.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Text(String),
+    Select(String),
+    MultiSelect(Vec<String>),
+    Checkbox(bool),
+}
+
+impl FieldValue {
+    /// The stored JSONB shape.
+    pub fn to_json(&self) -> Value {
+        match self {
+            FieldValue::Text(s) | FieldValue::Select(s) => json!(s),
+            FieldValue::MultiSelect(values) => json!(values),
+            FieldValue::Checkbox(checked) => json!(checked),
+        }
+    }
+
+    /// Read a stored answer back; `None` when it doesn't fit `kind`.
+    pub fn from_json(kind: RegistrationQuestionKind, value: &Value) -> Option<Self> {
+        match kind {
+            RegistrationQuestionKind::Text => Some(FieldValue::Text(value.as_str()?.to_string())),
+            RegistrationQuestionKind::Select => {
+                Some(FieldValue::Select(value.as_str()?.to_string()))
+            }
+            RegistrationQuestionKind::MultiSelect => Some(FieldValue::MultiSelect(
+                value
+                    .as_array()?
+                    .iter()
+                    .map(|v| v.as_str().map(str::to_string))
+                    .collect::<Option<_>>()?,
+            )),
+            RegistrationQuestionKind::Checkbox => Some(FieldValue::Checkbox(value.as_bool()?)),
+        }
+    }
+
+    /// Whether it answers a required field: a required checkbox must be
+    /// checked.
+    fn fulfils_required(&self) -> bool {
+        !matches!(self, FieldValue::Checkbox(false))
+    }
+}
+
+/// Normalize one answer to `question`; `Ok(None)` when it is blank.
+fn normalize_answer(
+    question: &QuestionRow,
+    answer: &RegistrationAnswerInput,
+) -> Result<Option<FieldValue>, String> {
+    let invalid = || format!("Invalid answer to: {}", question.prompt);
+    let kind = RegistrationQuestionKind::from_db(&question.kind);
+    // Only the value matching the field's kind may be set.
+    let wrong_value = match kind {
+        RegistrationQuestionKind::Text => answer.selected.is_some() || answer.checked.is_some(),
+        RegistrationQuestionKind::Select | RegistrationQuestionKind::MultiSelect => {
+            answer.text.is_some() || answer.checked.is_some()
+        }
+        RegistrationQuestionKind::Checkbox => answer.text.is_some() || answer.selected.is_some(),
+    };
+    if wrong_value {
+        return Err(invalid());
+    }
+    let selected: Vec<&str> = answer
+        .selected
+        .iter()
+        .flatten()
+        .map(|v| v.trim())
+        .filter(|v| !v.is_empty())
+        .collect();
+    let is_option = |value: &str| question.options.iter().any(|o| o == value);
+
+    match kind {
+        RegistrationQuestionKind::Text => {
+            let text = answer.text.as_deref().unwrap_or_default().trim();
+            if text.is_empty() {
+                return Ok(None);
+            }
+            let limit = text_limit(question.max_length);
+            if text.chars().count() > limit {
+                return Err(format!(
+                    "Answers are limited to {limit} characters: {}",
+                    question.prompt
+                ));
+            }
+            Ok(Some(FieldValue::Text(text.to_string())))
+        }
+        RegistrationQuestionKind::Select => match selected[..] {
+            [] => Ok(None),
+            [value] if is_option(value) => Ok(Some(FieldValue::Select(value.to_string()))),
+            _ => Err(invalid()),
+        },
+        RegistrationQuestionKind::MultiSelect => {
+            if selected.is_empty() {
+                return Ok(None);
+            }
+            if !selected.iter().all(|v| is_option(v)) {
+                return Err(invalid());
+            }
+            // In option order, each once.
+            Ok(Some(FieldValue::MultiSelect(
+                question
+                    .options
+                    .iter()
+                    .filter(|o| selected.contains(&o.as_str()))
+                    .cloned()
+                    .collect(),
+            )))
+        }
+        RegistrationQuestionKind::Checkbox => Ok(answer.checked.map(FieldValue::Checkbox)),
+    }
+}
+
+/// Check answers against the tournament's active `questions` and normalize
+/// them; blank answers come back as `None`. A required field that is
+/// answered must be answered properly; with `require_all`, every required
+/// field must be.
+pub fn validate_answers(
+    questions: &[QuestionRow],
+    answers: &[RegistrationAnswerInput],
+    require_all: bool,
+) -> Result<Vec<(Uuid, Option<FieldValue>)>, String> {
+    let mut seen = HashSet::new();
+    let mut normalized = Vec::new();
+    for answer in answers {
+        let question_id = Uuid::parse_str(answer.question_id.as_str())
+            .map_err(|_| "Invalid question ID".to_string())?;
+        if !seen.insert(question_id) {
+            return Err("Each question can only be answered once".to_string());
+        }
+        let question = questions
+            .iter()
+            .find(|q| q.id == question_id)
+            .ok_or_else(|| "Unknown registration question".to_string())?;
+        normalized.push((question_id, normalize_answer(question, answer)?));
+    }
+
+    let missing: Vec<&str> = questions
+        .iter()
+        .filter(|q| q.is_required)
+        .filter(|q| match normalized.iter().find(|(id, _)| *id == q.id) {
+            Some((_, value)) => !value.as_ref().is_some_and(FieldValue::fulfils_required),
+            None => require_all,
+        })
+        .map(|q| q.prompt.as_str())
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!(
+            "Please answer the required questions: {}",
+            missing.join(", ")
+        ));
+    }
+    Ok(normalized)
+}
+
+/// Tally the answers per field, in field order.
+pub fn build_report(
+    questions: Vec<QuestionRow>,
+    answers: &[AnswerRow],
+) -> Vec<RegistrationQuestionReport> {
+    questions
+        .into_iter()
+        .map(|question| {
+            let kind = RegistrationQuestionKind::from_db(&question.kind);
+            let values: Vec<(&AnswerRow, FieldValue)> = answers
+                .iter()
+                .filter(|a| a.question_id == question.id)
+                .filter_map(|a| Some((a, FieldValue::from_json(kind, &a.answer)?)))
+                .collect();
+            let mut choices: Vec<AnswerChoiceCount> = match kind {
+                RegistrationQuestionKind::Text => Vec::new(),
+                RegistrationQuestionKind::Checkbox => vec!["checked".into(), "unchecked".into()],
+                _ => question.options.clone(),
+            }
+            .into_iter()
+            .map(|value| AnswerChoiceCount { value, count: 0 })
+            .collect();
+            let mut count = |value: &str| match choices.iter_mut().find(|c| c.value == value) {
+                Some(choice) => choice.count += 1,
+                None => choices.push(AnswerChoiceCount {
+                    value: value.to_string(),
+                    count: 1,
+                }),
+            };
+            let mut responses = Vec::new();
+            for (answer, value) in &values {
+                match value {
+                    FieldValue::Text(text) => responses.push(AnswerTextResponse {
+                        player_name: answer.player_name.clone(),
+                        text: text.clone(),
+                    }),
+                    FieldValue::Select(value) => count(value),
+                    FieldValue::MultiSelect(selected) => selected.iter().for_each(|v| count(v)),
+                    FieldValue::Checkbox(checked) => {
+                        count(if *checked { "checked" } else { "unchecked" })
+                    }
+                }
+            }
+            RegistrationQuestionReport {
+                answered: values.len() as i32,
+                question: RegistrationQuestion::from(question),
+                choices,
+                responses,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn question(kind: &str, options: &[&str], is_required: bool) -> QuestionRow {
+        QuestionRow {
+            id: Uuid::new_v4(),
+            tournament_id: Uuid::nil(),
+            kind: kind.into(),
+            prompt: format!("{kind}?"),
+            help_text: None,
+            options: options.iter().map(|o| o.to_string()).collect(),
+            max_length: None,
+            is_required,
+            position: 0,
+            is_active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn text(question: &QuestionRow, text: &str) -> RegistrationAnswerInput {
+        RegistrationAnswerInput {
+            question_id: question.id.into(),
+            text: Some(text.into()),
+            ..Default::default()
+        }
+    }
+
+    fn selected(question: &QuestionRow, values: &[&str]) -> RegistrationAnswerInput {
+        RegistrationAnswerInput {
+            question_id: question.id.into(),
+            selected: Some(values.iter().map(|v| v.to_string()).collect()),
+            ..Default::default()
+        }
+    }
+
+    fn checked(question: &QuestionRow, checked: bool) -> RegistrationAnswerInput {
+        RegistrationAnswerInput {
+            question_id: question.id.into(),
+            checked: Some(checked),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn normalizes_answers() {
+        let menu = question("select", &["Fish", "Vegetarian"], true);
+        let allergies = question("multi_select", &["Nuts", "Gluten", "Dairy"], false);
+        let wheelchair = question("checkbox", &[], false);
+        let needs = question("text", &[], false);
+        let questions = [
+            menu.clone(),
+            allergies.clone(),
+            wheelchair.clone(),
+            needs.clone(),
+        ];
+
+        let answers = validate_answers(
+            &questions,
+            &[
+                selected(&menu, &[" Vegetarian "]),
+                selected(&allergies, &["Dairy", "Nuts", "Dairy"]),
+                checked(&wheelchair, false),
+                text(&needs, "  "),
+            ],
+            true,
+        )
+        .unwrap();
+        assert_eq!(
+            answers,
+            vec![
+                (menu.id, Some(FieldValue::Select("Vegetarian".into()))),
+                (
+                    allergies.id,
+                    Some(FieldValue::MultiSelect(vec!["Nuts".into(), "Dairy".into()]))
+                ),
+                (wheelchair.id, Some(FieldValue::Checkbox(false))),
+                (needs.id, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_bad_and_missing_answers() {
+        let menu = question("select", &["Fish", "Vegetarian"], true);
+        let consent = question("checkbox", &[], true);
+        let mut needs = question("text", &[], false);
+        needs.max_length = Some(5);
+        let questions = [menu.clone(), consent.clone(), needs.clone()];
+        let invalid = Err("Invalid answer to: select?".to_string());
+
+        assert_eq!(
+            validate_answers(&questions, &[selected(&menu, &["Steak"])], false),
+            invalid
+        );
+        assert_eq!(
+            validate_answers(
+                &questions,
+                &[selected(&menu, &["Fish", "Vegetarian"])],
+                false
+            ),
+            invalid
+        );
+        assert_eq!(
+            validate_answers(&questions, &[text(&menu, "Fish")], false),
+            invalid
+        );
+        assert_eq!(
+            validate_answers(&questions, &[text(&needs, "Ramps!")], false),
+            Err("Answers are limited to 5 characters: text?".to_string())
+        );
+        assert_eq!(
+            validate_answers(&questions, &[selected(&menu, &["Fish"])], true),
+            Err("Please answer the required questions: checkbox?".to_string())
+        );
+        // A required field can't be cleared, nor a required checkbox unchecked.
+        assert_eq!(
+            validate_answers(
+                &questions,
+                &[selected(&menu, &[]), checked(&consent, false)],
+                false
+            ),
+            Err("Please answer the required questions: select?, checkbox?".to_string())
+        );
+        assert_eq!(validate_answers(&questions, &[], false), Ok(vec![]));
+        assert_eq!(
+            validate_answers(
+                &questions,
+                &[checked(&question("checkbox", &[], false), true)],
+                false
+            ),
+            Err("Unknown registration question".to_string())
+        );
+    }
+
+    #[test]
+    fn round_trips_stored_answers() {
+        for (kind, value) in [
+            (
+                RegistrationQuestionKind::Text,
+                FieldValue::Text("Ramp".into()),
+            ),
+            (
+                RegistrationQuestionKind::Select,
+                FieldValue::Select("Fish".into()),
+            ),
+            (
+                RegistrationQuestionKind::MultiSelect,
+                FieldValue::MultiSelect(vec!["Nuts".into()]),
+            ),
+            (
+                RegistrationQuestionKind::Checkbox,
+                FieldValue::Checkbox(true),
+            ),
+        ] {
+            assert_eq!(FieldValue::from_json(kind, &value.to_json()), Some(value));
+        }
+        assert_eq!(
+            FieldValue::from_json(RegistrationQuestionKind::Checkbox, &json!("yes")),
+            None
+        );
+    }
+
+    #[test]
+    fn tallies_answers() {
+        let menu = question("select", &["Fish", "Vegetarian"], true);
+        let wheelchair = question("checkbox", &[], false);
+        let needs = question("text", &[], false);
+        let row = |question: &QuestionRow, answer: Value, player: &str| AnswerRow {
+            registration_id: Uuid::new_v4(),
+            question_id: question.id,
+            kind: question.kind.clone(),
+            answer,
+            player_name: player.into(),
+            answered_at: Utc::now(),
+        };
+        let answers = [
+            row(&menu, json!("Fish"), "Ann"),
+            row(&menu, json!("Fish"), "Bob"),
+            row(&menu, json!("Beef"), "Cy"),
+            row(&wheelchair, json!(true), "Bob"),
+            row(&needs, json!("Step-free access"), "Bob"),
+        ];
+        let report = build_report(vec![menu, wheelchair, needs], &answers);
+        assert_eq!(report[0].answered, 3);
+        assert_eq!(
+            report[0].choices,
+            vec![
+                AnswerChoiceCount {
+                    value: "Fish".into(),
+                    count: 2
+                },
+                AnswerChoiceCount {
+                    value: "Vegetarian".into(),
+                    count: 0
+                },
+                AnswerChoiceCount {
+                    value: "Beef".into(),
+                    count: 1
+                },
+            ]
+        );
+        assert_eq!(
+            report[1].choices,
+            vec![
+                AnswerChoiceCount {
+                    value: "checked".into(),
+                    count: 1
+                },
+                AnswerChoiceCount {
+                    value: "unchecked".into(),
+                    count: 0
+                },
+            ]
+        );
+        assert_eq!(
+            report[2].responses,
+            vec![AnswerTextResponse {
+                player_name: "Bob".into(),
+                text: "Step-free access".into()
+            }]
+        );
+    }
+}
@@ -0,0 +1,370 @@
+use async_graphql::{Context, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::auth::jwt::Claims;
+use crate::auth::permissions::require_club_manager;
+use crate::gql::common::helpers::get_club_id_for_tournament;
+use crate::gql::error::ResultExt;
+use crate::state::AppState;
+use infra::models::TournamentRegistrationRow;
+use infra::repos::registration_questions::{self, CreateQuestion, QuestionRow, UpdateQuestion};
+use infra::repos::tournament_registrations;
+
+use super::types::{
+    CreateRegistrationQuestionInput, RegistrationAnswer, RegistrationAnswerInput,
+    RegistrationAnswersReport, RegistrationFormResponse, RegistrationQuestion,
+    RegistrationQuestionKind, UpdateRegistrationQuestionInput,
+};
+use super::{build_report, validate_answers};
+
+const MAX_PROMPT: usize = 200;
+const MAX_HELP_TEXT: usize = 500;
+const MAX_OPTIONS: usize = 20;
+const MAX_TEXT_LIMIT: i32 = 2000;
+
+fn clean_prompt(prompt: &str) -> Result<String> {
+    let prompt = prompt.trim();
+    if prompt.is_empty() || prompt.chars().count() > MAX_PROMPT {
+        return Err(async_graphql::Error::new(format!(
+            "A question must be 1 to {MAX_PROMPT} characters"
+        )));
+    }
+    Ok(prompt.to_string())
+}
+
+/// Trimmed help text; blank means none.
+fn clean_help_text(help_text: &str) -> Result<Option<String>> {
+    let help_text = help_text.trim();
+    if help_text.chars().count() > MAX_HELP_TEXT {
+        return Err(async_graphql::Error::new(format!(
+            "Help text is limited to {MAX_HELP_TEXT} characters"
+        )));
+    }
+    Ok((!help_text.is_empty()).then(|| help_text.to_string()))
+}
+
+fn check_max_length(max_length: i32) -> Result<i32> {
+    if !(1..=MAX_TEXT_LIMIT).contains(&max_length) {
+        return Err(async_graphql::Error::new(format!(
+            "A text field's length limit must be 1 to {MAX_TEXT_LIMIT} characters"
+        )));
+    }
+    Ok(max_length)
+}
+
+/// Trimmed, distinct options; select fields need at least two.
+fn clean_options(options: &[String]) -> Result<Vec<String>> {
+    let mut cleaned: Vec<String> = Vec::new();
+    for option in options.iter().map(|o| o.trim()) {
+        if option.is_empty() || cleaned.iter().any(|c| c == option) {
+            return Err(async_graphql::Error::new(
+                "Options must be non-empty and distinct",
+            ));
+        }
+        cleaned.push(option.to_string());
+    }
+    if cleaned.len() < 2 || cleaned.len() > MAX_OPTIONS {
+        return Err(async_graphql::Error::new(format!(
+            "A select field needs 2 to {MAX_OPTIONS} options"
+        )));
+    }
+    Ok(cleaned)
+}
+
+/// Load a question and check the caller manages its tournament's club.
+async fn managed_question(ctx: &Context<'_>, id: &ID) -> Result<QuestionRow> {
+    let id = Uuid::parse_str(id.as_str()).gql_err("Invalid question ID")?;
+    let state = ctx.data::<AppState>()?;
+    let question = registration_questions::get_by_id(&state.db, id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Question not found"))?;
+    let club_id = get_club_id_for_tournament(&state.db, question.tournament_id).await?;
+    require_club_manager(ctx, club_id).await?;
+    Ok(question)
+}
+
+/// The caller's live (not cancelled) registration for the tournament.
+async fn my_registration(
+    ctx: &Context<'_>,
+    tournament_id: Uuid,
+) -> Result<TournamentRegistrationRow> {
+    let claims = ctx.data::<Claims>()?;
+    let user_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+    let state = ctx.data::<AppState>()?;
+    tournament_registrations::get_by_tournament_and_user(&state.db, tournament_id, user_id)
+        .await?
+        .filter(|r| r.status != "cancelled")
+        .ok_or_else(|| async_graphql::Error::new("You are not registered for this tournament"))
+}
+
+#[derive(Default)]
+pub struct RegistrationQuestionQuery;
+
+#[Object]
+impl RegistrationQuestionQuery {
+    /// A tournament's registration form fields, archived ones included. Club
+    /// managers.
+    async fn tournament_registration_questions(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Vec<RegistrationQuestion>> {
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let rows =
+            registration_questions::list_for_tournament(&state.db, tournament_id, false).await?;
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// The logged-in player's answers for a tournament they registered for.
+    async fn my_registration_answers(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Vec<RegistrationAnswer>> {
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+        let registration = my_registration(ctx, tournament_id).await?;
+        let rows =
+            registration_questions::list_answers_for_registration(&state.db, registration.id)
+                .await?;
+        Ok(rows
+            .iter()
+            .filter_map(RegistrationAnswer::from_row)
+            .collect())
+    }
+
+    /// Every registration's typed form answers, by player name. Registrations
+    /// without answers are left out. Club managers.
+    async fn registration_form_responses(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Vec<RegistrationFormResponse>> {
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let rows =
+            registration_questions::list_answers_for_tournament(&state.db, tournament_id).await?;
+        // Rows come grouped by registration.
+        let mut responses: Vec<RegistrationFormResponse> = Vec::new();
+        for row in &rows {
+            let Some(answer) = RegistrationAnswer::from_row(row) else {
+                continue;
+            };
+            match responses.last_mut() {
+                Some(last) if last.registration_id == ID::from(row.registration_id) => {
+                    last.answers.push(answer)
+                }
+                _ => responses.push(RegistrationFormResponse {
+                    registration_id: row.registration_id.into(),
+                    player_name: row.player_name.clone(),
+                    answers: vec![answer],
+                }),
+            }
+        }
+        Ok(responses)
+    }
+
+    /// The answers of everyone still registered, tallied per question (dinner
+    /// counts, accessibility needs). Club managers.
+    async fn registration_answers_report(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<RegistrationAnswersReport> {
+        let tid = Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+        let club_id = get_club_id_for_tournament(&state.db, tid).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let questions = registration_questions::list_for_tournament(&state.db, tid, false).await?;
+        let answers = registration_questions::list_answers_for_tournament(&state.db, tid).await?;
+        let registrations =
+            registration_questions::count_live_registrations(&state.db, tid).await?;
+        Ok(RegistrationAnswersReport {
+            tournament_id,
+            registrations: registrations as i32,
+            questions: build_report(questions, &answers),
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct RegistrationQuestionMutation;
+
+#[Object]
+impl RegistrationQuestionMutation {
+    /// Add a field to the registration form. Club managers.
+    async fn create_registration_question(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateRegistrationQuestionInput,
+    ) -> Result<RegistrationQuestion> {
+        let tournament_id =
+            Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let prompt = clean_prompt(&input.prompt)?;
+        let help_text = input
+            .help_text
+            .as_deref()
+            .map(clean_help_text)
+            .transpose()?;
+        let options = if input.kind.has_options() {
+            clean_options(&input.options)?
+        } else {
+            Vec::new()
+        };
+        let max_length = match input.kind {
+            RegistrationQuestionKind::Text => input.max_length.map(check_max_length).transpose()?,
+            _ => None,
+        };
+        let position = match input.position {
+            Some(position) => position,
+            None => registration_questions::list_for_tournament(&state.db, tournament_id, false)
+                .await?
+                .iter()
+                .map(|q| q.position + 1)
+                .max()
+                .unwrap_or(0),
+        };
+        let row = registration_questions::create(
+            &state.db,
+            CreateQuestion {
+                tournament_id,
+                kind: input.kind.as_db().to_string(),
+                prompt,
+                help_text: help_text.flatten(),
+                options,
+                max_length,
+                is_required: input.is_required,
+                position,
+            },
+        )
+        .await?;
+        Ok(row.into())
+    }
+
+    /// Edit a field; its kind is fixed. Answers already given are kept;
+    /// options removed from a select field still show in the report. Club
+    /// managers.
+    async fn update_registration_question(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateRegistrationQuestionInput,
+    ) -> Result<RegistrationQuestion> {
+        let question = managed_question(ctx, &input.id).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let kind = RegistrationQuestionKind::from_db(&question.kind);
+        let options = match input.options {
+            Some(_) if !kind.has_options() => {
+                return Err(async_graphql::Error::new("Only select fields have options"));
+            }
+            Some(options) => Some(clean_options(&options)?),
+            None => None,
+        };
+        let max_length = match input.max_length {
+            Some(_) if kind != RegistrationQuestionKind::Text => {
+                return Err(async_graphql::Error::new(
+                    "Only text fields have a length limit",
+                ));
+            }
+            max_length => max_length.map(check_max_length).transpose()?,
+        };
+        let prompt = input.prompt.as_deref().map(clean_prompt).transpose()?;
+        // A blank help text clears it.
+        let help_text = input
+            .help_text
+            .as_deref()
+            .map(|h| clean_help_text(h).map(Option::unwrap_or_default))
+            .transpose()?;
+        let row = registration_questions::update(
+            &state.db,
+            question.id,
+            UpdateQuestion {
+                prompt,
+                help_text,
+                options,
+                max_length,
+                is_required: input.is_required,
+                position: input.position,
+            },
+        )
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Question not found"))?;
+        Ok(row.into())
+    }
+
+    /// Take a field off the form; its answers stay in the report. Club
+    /// managers.
+    async fn archive_registration_question(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+    ) -> Result<RegistrationQuestion> {
+        let question = managed_question(ctx, &id).await?;
+        let state = ctx.data::<AppState>()?;
+        let row = registration_questions::set_active(&state.db, question.id, false)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Question not found"))?;
+        Ok(row.into())
+    }
+
+    /// Change the logged-in player's answers. Only the fields passed are
+    /// touched; a blank answer clears it, unless the field is required.
+    async fn update_registration_answers(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        answers: Vec<RegistrationAnswerInput>,
+    ) -> Result<Vec<RegistrationAnswer>> {
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+        let registration = my_registration(ctx, tournament_id).await?;
+        let questions =
+            registration_questions::list_for_tournament(&state.db, tournament_id, true).await?;
+        let answers =
+            validate_answers(&questions, &answers, false).map_err(async_graphql::Error::new)?;
+
+        let mut tx = state.db.begin().await?;
+        for (question_id, value) in &answers {
+            match value {
+                Some(value) => {
+                    registration_questions::upsert_answer(
+                        &mut *tx,
+                        registration.id,
+                        *question_id,
+                        &value.to_json(),
+                    )
+                    .await?
+                }
+                None => {
+                    registration_questions::delete_answer(&mut *tx, registration.id, *question_id)
+                        .await?
+                }
+            }
+        }
+        tx.commit().await?;
+
+        let rows =
+            registration_questions::list_answers_for_registration(&state.db, registration.id)
+                .await?;
+        Ok(rows
+            .iter()
+            .filter_map(RegistrationAnswer::from_row)
+            .collect())
+    }
+}
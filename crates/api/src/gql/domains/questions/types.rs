@@ -0,0 +1,238 @@
+use async_graphql::{Enum, InputObject, Interface, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use infra::repos::registration_questions::{AnswerRow, QuestionRow};
+
+use super::FieldValue;
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum RegistrationQuestionKind {
+    /// Free text (e.g. accessibility needs).
+    Text,
+    /// Pick one of the options (e.g. the dinner menu).
+    Select,
+    /// Pick any number of the options.
+    MultiSelect,
+    /// Checked or not. A required checkbox must be checked.
+    Checkbox,
+}
+
+impl RegistrationQuestionKind {
+    pub fn as_db(self) -> &'static str {
+        match self {
+            RegistrationQuestionKind::Text => "text",
+            RegistrationQuestionKind::Select => "select",
+            RegistrationQuestionKind::MultiSelect => "multi_select",
+            RegistrationQuestionKind::Checkbox => "checkbox",
+        }
+    }
+
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "select" => RegistrationQuestionKind::Select,
+            "multi_select" => RegistrationQuestionKind::MultiSelect,
+            "checkbox" => RegistrationQuestionKind::Checkbox,
+            _ => RegistrationQuestionKind::Text,
+        }
+    }
+
+    pub fn has_options(self) -> bool {
+        matches!(
+            self,
+            RegistrationQuestionKind::Select | RegistrationQuestionKind::MultiSelect
+        )
+    }
+}
+
+/// A field of a tournament's registration form.
+#[derive(SimpleObject, Clone)]
+pub struct RegistrationQuestion {
+    pub id: ID,
+    pub tournament_id: ID,
+    pub kind: RegistrationQuestionKind,
+    pub prompt: String,
+    /// Shown under the prompt.
+    pub help_text: Option<String>,
+    /// The choices of a select or multi-select field.
+    pub options: Vec<String>,
+    /// Longest accepted answer of a text field, in characters.
+    pub max_length: Option<i32>,
+    /// Players registering themselves must answer it.
+    pub is_required: bool,
+    pub position: i32,
+    pub is_active: bool,
+}
+
+impl From<QuestionRow> for RegistrationQuestion {
+    fn from(row: QuestionRow) -> Self {
+        let kind = RegistrationQuestionKind::from_db(&row.kind);
+        Self {
+            id: row.id.into(),
+            tournament_id: row.tournament_id.into(),
+            kind,
+            prompt: row.prompt,
+            help_text: row.help_text,
+            options: row.options,
+            max_length: (kind == RegistrationQuestionKind::Text)
+                .then(|| super::text_limit(row.max_length) as i32),
+            is_required: row.is_required,
+            position: row.position,
+            is_active: row.is_active,
+        }
+    }
+}
+
+/// The answer to a text field.
+#[derive(SimpleObject, Clone)]
+pub struct TextAnswer {
+    pub question_id: ID,
+    pub answered_at: DateTime<Utc>,
+    pub text: String,
+}
+
+/// The answer to a select or multi-select field.
+#[derive(SimpleObject, Clone)]
+pub struct SelectAnswer {
+    pub question_id: ID,
+    pub answered_at: DateTime<Utc>,
+    /// The chosen options, in the field's option order.
+    pub selected: Vec<String>,
+}
+
+/// The answer to a checkbox field.
+#[derive(SimpleObject, Clone)]
+pub struct CheckboxAnswer {
+    pub question_id: ID,
+    pub answered_at: DateTime<Utc>,
+    pub checked: bool,
+}
+
+/// A registration's answer to one field, typed by the field's kind.
+#[derive(Interface, Clone)]
+#[graphql(
+    field(name = "question_id", ty = "&ID"),
+    field(name = "answered_at", ty = "&DateTime<Utc>")
+)]
+pub enum RegistrationAnswer {
+    Text(TextAnswer),
+    Select(SelectAnswer),
+    Checkbox(CheckboxAnswer),
+}
+
+impl RegistrationAnswer {
+    /// `None` when the stored answer doesn't fit its field's kind.
+    pub fn from_row(row: &AnswerRow) -> Option<Self> {
+        let kind = RegistrationQuestionKind::from_db(&row.kind);
+        let question_id = ID::from(row.question_id);
+        let answered_at = row.answered_at;
+        Some(match FieldValue::from_json(kind, &row.answer)? {
+            FieldValue::Text(text) => RegistrationAnswer::Text(TextAnswer {
+                question_id,
+                answered_at,
+                text,
+            }),
+            FieldValue::Select(value) => RegistrationAnswer::Select(SelectAnswer {
+                question_id,
+                answered_at,
+                selected: vec![value],
+            }),
+            FieldValue::MultiSelect(selected) => RegistrationAnswer::Select(SelectAnswer {
+                question_id,
+                answered_at,
+                selected,
+            }),
+            FieldValue::Checkbox(checked) => RegistrationAnswer::Checkbox(CheckboxAnswer {
+                question_id,
+                answered_at,
+                checked,
+            }),
+        })
+    }
+}
+
+/// One registration's form answers.
+#[derive(SimpleObject, Clone)]
+pub struct RegistrationFormResponse {
+    pub registration_id: ID,
+    pub player_name: String,
+    /// In field order.
+    pub answers: Vec<RegistrationAnswer>,
+}
+
+/// An answer to one field. Set the value matching the field's kind; leaving
+/// it out (or blank) leaves the field unanswered.
+#[derive(InputObject, Clone, Default)]
+pub struct RegistrationAnswerInput {
+    pub question_id: ID,
+    /// For a text field.
+    pub text: Option<String>,
+    /// For a select (one option) or multi-select field.
+    pub selected: Option<Vec<String>>,
+    /// For a checkbox field.
+    pub checked: Option<bool>,
+}
+
+/// How many registrations picked one option.
+#[derive(SimpleObject, Clone, Debug, PartialEq)]
+pub struct AnswerChoiceCount {
+    pub value: String,
+    pub count: i32,
+}
+
+/// A free-text answer and who gave it.
+#[derive(SimpleObject, Clone, Debug, PartialEq)]
+pub struct AnswerTextResponse {
+    pub player_name: String,
+    pub text: String,
+}
+
+/// The answers to one field, tallied.
+#[derive(SimpleObject, Clone)]
+pub struct RegistrationQuestionReport {
+    pub question: RegistrationQuestion,
+    /// Registrations that answered it.
+    pub answered: i32,
+    /// Per-option counts for select fields (every option listed; options
+    /// removed since are appended) and `checked`/`unchecked` counts for
+    /// checkboxes.
+    pub choices: Vec<AnswerChoiceCount>,
+    /// Text answers, by player name.
+    pub responses: Vec<AnswerTextResponse>,
+}
+
+/// The organizer's summary of the registration answers.
+#[derive(SimpleObject, Clone)]
+pub struct RegistrationAnswersReport {
+    pub tournament_id: ID,
+    /// Registrations still in the tournament.
+    pub registrations: i32,
+    pub questions: Vec<RegistrationQuestionReport>,
+}
+
+#[derive(InputObject)]
+pub struct CreateRegistrationQuestionInput {
+    pub tournament_id: ID,
+    pub kind: RegistrationQuestionKind,
+    pub prompt: String,
+    pub help_text: Option<String>,
+    /// Required for select fields; ignored otherwise.
+    #[graphql(default)]
+    pub options: Vec<String>,
+    /// For text fields; defaults to 500 characters.
+    pub max_length: Option<i32>,
+    #[graphql(default = false)]
+    pub is_required: bool,
+    /// Display order; defaults to after the existing fields.
+    pub position: Option<i32>,
+}
+
+#[derive(InputObject)]
+pub struct UpdateRegistrationQuestionInput {
+    pub id: ID,
+    pub prompt: Option<String>,
+    pub help_text: Option<String>,
+    pub options: Option<Vec<String>>,
+    pub max_length: Option<i32>,
+    pub is_required: Option<bool>,
+    pub position: Option<i32>,
+}
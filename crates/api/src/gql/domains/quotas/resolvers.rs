@@ -0,0 +1,37 @@
+use std::time::Instant;
+
+use async_graphql::{Context, Object, Result};
+
+use crate::auth::permissions::require_admin;
+use crate::middleware::quota::quotas;
+
+use super::types::{ApiUsage, PrincipalUsage};
+
+#[derive(Default)]
+pub struct QuotaQuery;
+
+#[Object]
+impl QuotaQuery {
+    /// Per-user and per-client API usage on this instance against the
+    /// quotas, busiest first (default 50, max 500). Admins only.
+    async fn api_usage(&self, ctx: &Context<'_>, limit: Option<i32>) -> Result<ApiUsage> {
+        require_admin(ctx).await?;
+
+        let limit = limit.unwrap_or(50).clamp(1, 500) as usize;
+        let quotas = quotas();
+        let config = quotas.config();
+        let principals = quotas
+            .snapshot(Instant::now())
+            .into_iter()
+            .take(limit)
+            .map(PrincipalUsage::from)
+            .collect();
+
+        Ok(ApiUsage {
+            requests_per_minute: config.requests_per_minute as i64,
+            complexity_per_minute: config.complexity_per_minute as i64,
+            max_subscriptions: config.max_subscriptions as i32,
+            principals,
+        })
+    }
+}
@@ -0,0 +1,42 @@
+use async_graphql::{SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use crate::middleware::quota::UsageSnapshot;
+
+/// What one user or anonymous client is using on this instance.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct PrincipalUsage {
+    /// `user:<id>` for signed-in callers, `ip:<address>` otherwise.
+    pub principal: String,
+    pub user_id: Option<ID>,
+    pub requests_this_minute: i64,
+    pub complexity_this_minute: i64,
+    pub active_subscriptions: i32,
+    /// Requests, queries and subscriptions refused since the instance started.
+    pub rejected: i64,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+impl From<UsageSnapshot> for PrincipalUsage {
+    fn from(s: UsageSnapshot) -> Self {
+        Self {
+            principal: s.principal.to_string(),
+            user_id: s.principal.user_id().map(Into::into),
+            requests_this_minute: s.requests_this_minute as i64,
+            complexity_this_minute: s.complexity_this_minute as i64,
+            active_subscriptions: s.active_subscriptions as i32,
+            rejected: s.rejected as i64,
+            last_seen_at: s.last_seen_at,
+        }
+    }
+}
+
+/// The quotas in force and who is closest to them.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct ApiUsage {
+    /// Limits; 0 means unlimited.
+    pub requests_per_minute: i64,
+    pub complexity_per_minute: i64,
+    pub max_subscriptions: i32,
+    pub principals: Vec<PrincipalUsage>,
+}
@@ -0,0 +1,103 @@
+//! The auditable raffle draw.
+//!
+//! Entrants are put in a canonical order (by registration id) and committed
+//! to with [`entrants_hash`]. Winner `k` (0-based) is then picked from the
+//! entrants not yet drawn: the first 8 bytes of `SHA-256("{seed}:{k}")`, read
+//! big-endian, modulo the number remaining, indexes into them. Publishing the
+//! seed and the entrant list after the draw lets anyone replay it.
+
+use rand::distr::Alphanumeric;
+use rand::RngExt;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// Identifier stored with each draw so the replay procedure can evolve.
+pub const ALGORITHM: &str = "sha256-mod-v1";
+
+/// A fresh draw seed: 32 alphanumeric characters.
+pub fn generate_seed() -> String {
+    rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect()
+}
+
+/// SHA-256 (lowercase hex) of the canonical entrant list: registration ids,
+/// hyphenated lowercase, one per line.
+pub fn entrants_hash(registration_ids: &[Uuid]) -> String {
+    let joined = registration_ids
+        .iter()
+        .map(Uuid::to_string)
+        .collect::<Vec<_>>()
+        .join("\n");
+    Sha256::digest(joined.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Positions (into the canonical entrant list) of the winners, in pick order.
+/// Draws at most `entrant_count` winners.
+pub fn draw(seed: &str, entrant_count: usize, winner_count: usize) -> Vec<usize> {
+    let mut remaining: Vec<usize> = (0..entrant_count).collect();
+    let mut winners = Vec::with_capacity(winner_count.min(entrant_count));
+    for pick in 0..winner_count.min(entrant_count) {
+        let digest = Sha256::digest(format!("{seed}:{pick}").as_bytes());
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&digest[..8]);
+        let index = (u64::from_be_bytes(word) % remaining.len() as u64) as usize;
+        winners.push(remaining.remove(index));
+    }
+    winners
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_replays_the_same_winners() {
+        assert_eq!(draw("seed-a", 50, 5), draw("seed-a", 50, 5));
+    }
+
+    #[test]
+    fn winners_are_distinct_and_in_range() {
+        let winners = draw("seed-b", 20, 10);
+        assert_eq!(winners.len(), 10);
+        let mut sorted = winners.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 10);
+        assert!(winners.iter().all(|&w| w < 20));
+    }
+
+    #[test]
+    fn draws_everyone_when_short_of_entrants() {
+        let mut winners = draw("seed-c", 3, 5);
+        winners.sort_unstable();
+        assert_eq!(winners, vec![0, 1, 2]);
+        assert!(draw("seed-c", 0, 5).is_empty());
+    }
+
+    #[test]
+    fn seed_changes_the_outcome() {
+        assert_ne!(draw("seed-d", 1000, 3), draw("seed-e", 1000, 3));
+    }
+
+    #[test]
+    fn entrants_hash_commits_to_order() {
+        let a = Uuid::from_u128(1);
+        let b = Uuid::from_u128(2);
+        assert_eq!(entrants_hash(&[a, b]), entrants_hash(&[a, b]));
+        assert_ne!(entrants_hash(&[a, b]), entrants_hash(&[b, a]));
+        assert_eq!(entrants_hash(&[a]).len(), 64);
+    }
+
+    #[test]
+    fn generated_seeds_differ() {
+        let seed = generate_seed();
+        assert_eq!(seed.len(), 32);
+        assert_ne!(seed, generate_seed());
+    }
+}
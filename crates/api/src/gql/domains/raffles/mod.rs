@@ -0,0 +1,5 @@
+pub mod draw;
+pub mod resolvers;
+pub mod types;
+
+pub use resolvers::{RaffleMutation, RaffleQuery};
@@ -0,0 +1,214 @@
+use async_graphql::{Context, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::auth::permissions::require_club_manager;
+use crate::gql::common::helpers::{get_club_id_for_tournament, tournament_hidden_from_viewer};
+use crate::gql::domains::activity_log::log_and_publish;
+use crate::gql::error::ResultExt;
+use crate::gql::subscriptions::publish_user_notification;
+use crate::gql::types::{NotificationType, UserNotification, TITLE_RAFFLE_WON};
+use crate::services::push_service;
+use crate::state::AppState;
+use infra::repos::{clubs, raffles};
+
+use super::draw;
+use super::types::{CreateRaffleInput, Raffle};
+
+/// Load a raffle and check the caller manages the club hosting its tournament.
+async fn load_managed_raffle(ctx: &Context<'_>, raffle_id: &ID) -> Result<raffles::RaffleRow> {
+    let state = ctx.data::<AppState>()?;
+    let id = Uuid::parse_str(raffle_id.as_str()).gql_err("Invalid raffle ID")?;
+    let raffle = raffles::get(&state.db, id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Raffle not found"))?;
+    let club_id = get_club_id_for_tournament(&state.db, raffle.tournament_id).await?;
+    require_club_manager(ctx, club_id).await?;
+    Ok(raffle)
+}
+
+#[derive(Default)]
+pub struct RaffleQuery;
+
+#[Object]
+impl RaffleQuery {
+    /// A tournament's raffles, oldest first.
+    async fn tournament_raffles(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Vec<Raffle>> {
+        let tid = Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        if tournament_hidden_from_viewer(ctx, tid).await? {
+            return Ok(vec![]);
+        }
+        let state = ctx.data::<AppState>()?;
+        let rows = raffles::list_for_tournament(&state.db, tid).await?;
+        Ok(rows.into_iter().map(Raffle::from).collect())
+    }
+
+    /// A single raffle, with its draw audit trail once drawn.
+    async fn raffle(&self, ctx: &Context<'_>, id: ID) -> Result<Option<Raffle>> {
+        let state = ctx.data::<AppState>()?;
+        let raffle_id = Uuid::parse_str(id.as_str()).gql_err("Invalid raffle ID")?;
+        let Some(row) = raffles::get(&state.db, raffle_id).await? else {
+            return Ok(None);
+        };
+        if tournament_hidden_from_viewer(ctx, row.tournament_id).await? {
+            return Ok(None);
+        }
+        Ok(Some(Raffle::from(row)))
+    }
+}
+
+#[derive(Default)]
+pub struct RaffleMutation;
+
+#[Object]
+impl RaffleMutation {
+    /// Set up a raffle for a tournament. Club managers.
+    async fn create_raffle(&self, ctx: &Context<'_>, input: CreateRaffleInput) -> Result<Raffle> {
+        let state = ctx.data::<AppState>()?;
+        let tid = Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tid).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let created_by = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid user ID")?;
+
+        let name = input.name.trim();
+        if name.is_empty() || name.chars().count() > 120 {
+            return Err(async_graphql::Error::new(
+                "Raffle name must be between 1 and 120 characters",
+            ));
+        }
+        if !(1..=100).contains(&input.winner_count) {
+            return Err(async_graphql::Error::new(
+                "winnerCount must be between 1 and 100",
+            ));
+        }
+        let prize = input
+            .prize
+            .as_deref()
+            .map(str::trim)
+            .filter(|p| !p.is_empty());
+
+        let row = raffles::create(
+            &state.db,
+            tid,
+            name,
+            prize,
+            input.eligibility.as_str(),
+            input.winner_count,
+            created_by,
+        )
+        .await?;
+        Ok(Raffle::from(row))
+    }
+
+    /// Run the draw: snapshot the eligible entrants, pick the winners from a
+    /// fresh random seed and record the audit trail. Winners are notified
+    /// in-app and by push, and the draw lands in the tournament activity log.
+    /// A raffle is drawn once. Club managers.
+    async fn draw_raffle(&self, ctx: &Context<'_>, raffle_id: ID) -> Result<Raffle> {
+        let state = ctx.data::<AppState>()?;
+        let raffle = load_managed_raffle(ctx, &raffle_id).await?;
+        let claims = ctx.data::<crate::auth::jwt::Claims>()?;
+        let drawn_by = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+
+        let mut tx = state.db.begin().await?;
+        let raffle = raffles::get_for_update(&mut *tx, raffle.id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Raffle not found"))?;
+        if raffle.status != "open" {
+            return Err(async_graphql::Error::new("This raffle is no longer open"));
+        }
+
+        let entrants =
+            raffles::eligible_entrants(&mut *tx, raffle.tournament_id, &raffle.eligibility).await?;
+        if entrants.is_empty() {
+            return Err(async_graphql::Error::new(
+                "No eligible entrants to draw from",
+            ));
+        }
+
+        let seed = draw::generate_seed();
+        let registration_ids: Vec<Uuid> = entrants.iter().map(|e| e.registration_id).collect();
+        let entrants_hash = draw::entrants_hash(&registration_ids);
+        let mut picks = vec![None; entrants.len()];
+        for (pick, position) in draw::draw(&seed, entrants.len(), raffle.winner_count as usize)
+            .into_iter()
+            .enumerate()
+        {
+            picks[position] = Some(pick as i32 + 1);
+        }
+
+        raffles::insert_entrants(&mut *tx, raffle.id, &entrants, &picks).await?;
+        let drawn = raffles::mark_drawn(
+            &mut *tx,
+            raffle.id,
+            draw::ALGORITHM,
+            &seed,
+            &entrants_hash,
+            drawn_by,
+        )
+        .await?;
+        tx.commit().await?;
+
+        let winners = raffles::list_winners(&state.db, drawn.id).await?;
+        let db = state.db.clone();
+        let raffle_for_notify = drawn.clone();
+        tokio::spawn(async move {
+            let raffle = raffle_for_notify;
+            log_and_publish(
+                &db,
+                raffle.tournament_id,
+                "tournament",
+                "raffle_drawn",
+                Some(drawn_by),
+                None,
+                serde_json::json!({
+                    "raffle_id": raffle.id,
+                    "name": raffle.name,
+                    "prize": raffle.prize,
+                    "winners": winners.iter().map(|w| &w.display_name).collect::<Vec<_>>(),
+                }),
+            )
+            .await;
+
+            // Free ("Home Game") clubs are off the player app: no player pings.
+            let club_id = match get_club_id_for_tournament(&db, raffle.tournament_id).await {
+                Ok(id) => id,
+                Err(_) => return,
+            };
+            if clubs::is_free(&db, club_id).await.unwrap_or(true) {
+                return;
+            }
+            let message = match &raffle.prize {
+                Some(prize) => format!("You won {} in the {} raffle", prize, raffle.name),
+                None => format!("You won the {} raffle", raffle.name),
+            };
+            for user_id in winners.iter().filter_map(|w| w.user_id) {
+                publish_user_notification(UserNotification {
+                    id: ID::from(Uuid::new_v4().to_string()),
+                    user_id: ID::from(user_id.to_string()),
+                    notification_type: NotificationType::RaffleWon,
+                    title: TITLE_RAFFLE_WON.to_string(),
+                    message: message.clone(),
+                    tournament_id: Some(ID::from(raffle.tournament_id.to_string())),
+                    created_at: chrono::Utc::now(),
+                });
+                push_service::send_raffle_won(&db, user_id, raffle.id, raffle.tournament_id).await;
+            }
+        });
+
+        Ok(Raffle::from(drawn))
+    }
+
+    /// Cancel a raffle that has not been drawn. Club managers.
+    async fn cancel_raffle(&self, ctx: &Context<'_>, raffle_id: ID) -> Result<Raffle> {
+        let state = ctx.data::<AppState>()?;
+        let raffle = load_managed_raffle(ctx, &raffle_id).await?;
+        let row = raffles::cancel(&state.db, raffle.id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Only open raffles can be cancelled"))?;
+        Ok(Raffle::from(row))
+    }
+}
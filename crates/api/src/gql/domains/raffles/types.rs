@@ -0,0 +1,143 @@
+use async_graphql::{ComplexObject, Context, Enum, InputObject, Result, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use crate::gql::error::ResultExt;
+use crate::state::AppState;
+use infra::repos::raffles::{RaffleEntrantRow, RaffleRow};
+
+/// Who holds a ticket in a raffle.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum RaffleEligibility {
+    /// Every confirmed registration (not waitlisted, cancelled or no-show).
+    Registered,
+    /// Players who showed up: checked in, seated or already busted.
+    CheckedIn,
+    /// Players still in the tournament: checked in or seated.
+    Active,
+}
+
+impl RaffleEligibility {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RaffleEligibility::Registered => "registered",
+            RaffleEligibility::CheckedIn => "checked_in",
+            RaffleEligibility::Active => "active",
+        }
+    }
+
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "checked_in" => RaffleEligibility::CheckedIn,
+            "active" => RaffleEligibility::Active,
+            _ => RaffleEligibility::Registered,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum RaffleStatus {
+    Open,
+    Drawn,
+    Cancelled,
+}
+
+impl RaffleStatus {
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "drawn" => RaffleStatus::Drawn,
+            "cancelled" => RaffleStatus::Cancelled,
+            _ => RaffleStatus::Open,
+        }
+    }
+}
+
+/// A raffle drawn during a tournament. Once drawn, `algorithm`, `seed`,
+/// `entrantsHash` and `entrants` let anyone replay the draw.
+#[derive(SimpleObject, Clone, Debug)]
+#[graphql(complex)]
+pub struct Raffle {
+    pub id: ID,
+    pub tournament_id: ID,
+    pub name: String,
+    pub prize: Option<String>,
+    pub eligibility: RaffleEligibility,
+    pub winner_count: i32,
+    pub status: RaffleStatus,
+    pub algorithm: Option<String>,
+    pub seed: Option<String>,
+    /// SHA-256 of the canonical entrant list (registration ids, one per line).
+    pub entrants_hash: Option<String>,
+    pub drawn_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<RaffleRow> for Raffle {
+    fn from(row: RaffleRow) -> Self {
+        Self {
+            id: row.id.into(),
+            tournament_id: row.tournament_id.into(),
+            name: row.name,
+            prize: row.prize,
+            eligibility: RaffleEligibility::from_db(&row.eligibility),
+            winner_count: row.winner_count,
+            status: RaffleStatus::from_db(&row.status),
+            algorithm: row.algorithm,
+            seed: row.seed,
+            entrants_hash: row.entrants_hash,
+            drawn_at: row.drawn_at,
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[ComplexObject]
+impl Raffle {
+    /// Winners in pick order (empty until drawn).
+    async fn winners(&self, ctx: &Context<'_>) -> Result<Vec<RaffleEntrant>> {
+        let state = ctx.data::<AppState>()?;
+        let raffle_id = uuid::Uuid::parse_str(self.id.as_str()).gql_err("Invalid raffle ID")?;
+        let rows = infra::repos::raffles::list_winners(&state.db, raffle_id).await?;
+        Ok(rows.into_iter().map(RaffleEntrant::from).collect())
+    }
+
+    /// The entrant snapshot taken at draw time, in canonical order (empty
+    /// until drawn).
+    async fn entrants(&self, ctx: &Context<'_>) -> Result<Vec<RaffleEntrant>> {
+        let state = ctx.data::<AppState>()?;
+        let raffle_id = uuid::Uuid::parse_str(self.id.as_str()).gql_err("Invalid raffle ID")?;
+        let rows = infra::repos::raffles::list_entrants(&state.db, raffle_id).await?;
+        Ok(rows.into_iter().map(RaffleEntrant::from).collect())
+    }
+}
+
+/// A ticket holder in a drawn raffle. `pick` is 1 for the first winner drawn.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct RaffleEntrant {
+    pub registration_id: ID,
+    pub position: i32,
+    pub user_id: Option<ID>,
+    pub display_name: String,
+    pub pick: Option<i32>,
+}
+
+impl From<RaffleEntrantRow> for RaffleEntrant {
+    fn from(row: RaffleEntrantRow) -> Self {
+        Self {
+            registration_id: row.registration_id.into(),
+            position: row.position,
+            user_id: row.user_id.map(Into::into),
+            display_name: row.display_name,
+            pick: row.pick,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreateRaffleInput {
+    pub tournament_id: ID,
+    pub name: String,
+    pub prize: Option<String>,
+    pub eligibility: RaffleEligibility,
+    #[graphql(default = 1)]
+    pub winner_count: i32,
+}
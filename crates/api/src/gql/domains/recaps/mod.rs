@@ -0,0 +1,10 @@
+//! Results recaps for the club's website and socials: one per tournament,
+//! stored when its results are entered and posted to the club's webhook.
+//! Imported results get none; `regenerateTournamentRecap` rebuilds and
+//! redelivers one.
+
+pub mod resolvers;
+pub mod service;
+pub mod types;
+
+pub use resolvers::{RecapMutation, RecapQuery};
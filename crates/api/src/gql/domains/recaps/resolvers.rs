@@ -0,0 +1,129 @@
+use async_graphql::{Context, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::auth::permissions::{require_club_manager, viewer_manages_club};
+use crate::gql::common::helpers::{
+    get_club_id_for_tournament, tournament_access, TournamentAccess,
+};
+use crate::gql::error::ResultExt;
+use crate::state::AppState;
+use infra::repos::tournament_recaps::{self, TournamentRecapRow};
+
+use super::service;
+use super::types::{RecapSettings, TournamentRecap, UpdateRecapSettingsInput};
+
+fn to_recap(row: TournamentRecapRow) -> Result<TournamentRecap> {
+    TournamentRecap::try_from(row).gql_err("Stored recap is unreadable")
+}
+
+#[derive(Default)]
+pub struct RecapQuery;
+
+#[Object]
+impl RecapQuery {
+    /// The results recap of a tournament, generated when its results are
+    /// entered. `null` until then. Public to whoever can see the tournament;
+    /// the delivery state is for the club's managers.
+    async fn tournament_recap(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        invite_token: Option<String>,
+    ) -> Result<Option<TournamentRecap>> {
+        let state = ctx.data::<AppState>()?;
+        let tid = Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tid).await?;
+
+        let manager = viewer_manages_club(ctx, club_id).await;
+        if !manager
+            && tournament_access(ctx, tid, invite_token.as_deref()).await?
+                != TournamentAccess::Visible
+        {
+            return Ok(None);
+        }
+
+        let Some(row) = tournament_recaps::get(&state.db, tid).await? else {
+            return Ok(None);
+        };
+        let mut recap = to_recap(row)?;
+        if !manager {
+            recap.delivered_at = None;
+            recap.delivery_error = None;
+        }
+        Ok(Some(recap))
+    }
+
+    /// Where the club's recaps are delivered (managers only).
+    async fn club_recap_settings(&self, ctx: &Context<'_>, club_id: ID) -> Result<RecapSettings> {
+        let state = ctx.data::<AppState>()?;
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+
+        Ok(tournament_recaps::get_settings(&state.db, club_id)
+            .await?
+            .map(RecapSettings::from)
+            .unwrap_or(RecapSettings {
+                club_id: club_id.into(),
+                webhook_url: None,
+                signed: false,
+            }))
+    }
+}
+
+#[derive(Default)]
+pub struct RecapMutation;
+
+#[Object]
+impl RecapMutation {
+    /// Set the webhook the club's recaps are POSTed to (managers only).
+    async fn update_club_recap_settings(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateRecapSettingsInput,
+    ) -> Result<RecapSettings> {
+        let state = ctx.data::<AppState>()?;
+        let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+
+        let url = input
+            .webhook_url
+            .as_deref()
+            .map(str::trim)
+            .filter(|u| !u.is_empty());
+        if url.is_some_and(|u| !(u.starts_with("https://") || u.starts_with("http://"))) {
+            return Err(async_graphql::Error::new(
+                "Webhook URL must be an http(s) URL",
+            ));
+        }
+        let secret = match input.webhook_secret {
+            Some(secret) => Some(secret.trim().to_string()).filter(|s| !s.is_empty()),
+            None => tournament_recaps::get_settings(&state.db, club_id)
+                .await?
+                .and_then(|s| s.webhook_secret),
+        };
+
+        let row =
+            tournament_recaps::upsert_settings(&state.db, club_id, url, secret.as_deref()).await?;
+        Ok(row.into())
+    }
+
+    /// Rebuild a tournament's recap from its current results and photos and
+    /// deliver it again (managers only). Waits for the delivery, so the
+    /// returned recap shows whether the webhook took it.
+    async fn regenerate_tournament_recap(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<TournamentRecap> {
+        let state = ctx.data::<AppState>()?;
+        let tid = Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tid).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let row = service::generate(&state.db, tid)
+            .await
+            .map_err(|e| async_graphql::Error::new(e.to_string()))?;
+        let row = service::deliver(&state.db, row).await?;
+        to_recap(row)
+    }
+}
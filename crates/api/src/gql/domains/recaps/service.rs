@@ -0,0 +1,241 @@
+//! Builds a tournament's results recap once its results are in, and delivers
+//! it to the club's webhook.
+//!
+//! A delivery is a JSON POST of `{"event": "tournament.recap", "recap": …}`.
+//! With a secret set, `X-Recap-Signature: sha256=<hex>` carries an
+//! HMAC-SHA256 of the body keyed by it, so the receiver can check the post
+//! came from us.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, KeyInit, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use sqlx::PgPool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use infra::repos::tournament_recaps::{self, TournamentRecapRow};
+use infra::repos::{
+    clubs, tournament_payouts, tournament_photos, tournament_printouts, tournaments,
+};
+
+/// Finishing places on the podium.
+const PODIUM_PLACES: usize = 3;
+
+/// How long the club's endpoint gets to accept a delivery.
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub const SIGNATURE_HEADER: &str = "X-Recap-Signature";
+
+/// Why a recap couldn't be generated.
+#[derive(Debug, Error)]
+pub enum RecapError {
+    #[error("Tournament not found")]
+    TournamentNotFound,
+    #[error("Club not found")]
+    ClubNotFound,
+    #[error("No results have been entered for this tournament")]
+    NoResults,
+    #[error(transparent)]
+    Db(#[from] sqlx::Error),
+}
+
+/// The stored recap, as posted to the webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Recap {
+    pub tournament_id: Uuid,
+    pub club_id: Uuid,
+    pub club_name: String,
+    pub tournament_name: String,
+    /// Ready-to-post headline, e.g. "Ann Smith wins the Friday Deepstack".
+    pub title: String,
+    pub start_time: DateTime<Utc>,
+    pub entrants: i32,
+    pub prize_pool_cents: i64,
+    /// The top finishers, winner first.
+    pub podium: Vec<RecapPlace>,
+    /// Every place paid, in order.
+    pub payouts: Vec<RecapPlace>,
+    /// Gallery photos every tagged player consents to showing. Empty until
+    /// the photos are uploaded; regenerate the recap to pick them up.
+    pub photos: Vec<RecapPhoto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecapPlace {
+    pub position: i32,
+    pub player_name: String,
+    pub prize_cents: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecapPhoto {
+    pub url: String,
+    pub caption: Option<String>,
+}
+
+/// Build the recap from the tournament's recorded results and store it,
+/// replacing the previous one.
+pub async fn generate(db: &PgPool, tournament_id: Uuid) -> Result<TournamentRecapRow, RecapError> {
+    let tournament = tournaments::get_by_id(db, tournament_id)
+        .await?
+        .ok_or(RecapError::TournamentNotFound)?;
+    let club = clubs::get_by_id(db, tournament.club_id)
+        .await?
+        .ok_or(RecapError::ClubNotFound)?;
+
+    let places: Vec<RecapPlace> = tournament_printouts::list_payouts(db, tournament_id)
+        .await?
+        .into_iter()
+        .map(|p| RecapPlace {
+            position: p.final_position,
+            player_name: p.player_name,
+            prize_cents: p.prize_cents,
+        })
+        .collect();
+    let Some(winner) = places.first() else {
+        return Err(RecapError::NoResults);
+    };
+
+    // The official pool when there is a payout table, else what was paid.
+    let (entrants, prize_pool_cents) =
+        match tournament_payouts::get_by_tournament(db, tournament_id).await? {
+            Some(payout) => (payout.player_count, payout.total_prize_pool),
+            None => (
+                places.len() as i32,
+                places.iter().map(|p| p.prize_cents).sum(),
+            ),
+        };
+
+    let photos = tournament_photos::list_for_tournament(db, tournament_id, true)
+        .await?
+        .into_iter()
+        .map(|p| RecapPhoto {
+            url: p.url,
+            caption: p.caption,
+        })
+        .collect();
+
+    let recap = Recap {
+        tournament_id,
+        club_id: club.id,
+        club_name: club.name,
+        title: format!("{} wins the {}", winner.player_name, tournament.name),
+        tournament_name: tournament.name,
+        start_time: tournament.start_time,
+        entrants,
+        prize_pool_cents,
+        podium: places.iter().take(PODIUM_PLACES).cloned().collect(),
+        payouts: places
+            .iter()
+            .filter(|p| p.prize_cents > 0)
+            .cloned()
+            .collect(),
+        photos,
+    };
+    let payload = serde_json::to_value(&recap).expect("recap serializes");
+    Ok(tournament_recaps::upsert(db, tournament_id, &payload).await?)
+}
+
+/// POST the recap to the club's webhook, if it has one, and record the
+/// outcome. Returns the recap row as it stands afterwards.
+pub async fn deliver(db: &PgPool, row: TournamentRecapRow) -> sqlx::Result<TournamentRecapRow> {
+    let Some(club_id) = row
+        .payload
+        .get("clubId")
+        .and_then(|id| id.as_str())
+        .and_then(|id| Uuid::parse_str(id).ok())
+    else {
+        return Ok(row);
+    };
+    let Some(settings) = tournament_recaps::get_settings(db, club_id).await? else {
+        return Ok(row);
+    };
+    let Some(url) = settings.webhook_url else {
+        return Ok(row);
+    };
+
+    let body = serde_json::to_vec(&serde_json::json!({
+        "event": "tournament.recap",
+        "recap": row.payload,
+    }))
+    .expect("recap serializes");
+    let error = post(&url, settings.webhook_secret.as_deref(), body)
+        .await
+        .err();
+    if let Some(error) = &error {
+        tracing::warn!(
+            tournament_id = %row.tournament_id,
+            error = %error,
+            "Recap delivery failed",
+        );
+    }
+
+    tournament_recaps::mark_delivery(db, row.tournament_id, error.as_deref()).await?;
+    Ok(tournament_recaps::get(db, row.tournament_id)
+        .await?
+        .unwrap_or(row))
+}
+
+async fn post(url: &str, secret: Option<&str>, body: Vec<u8>) -> Result<(), String> {
+    let client = reqwest::Client::builder()
+        .timeout(DELIVERY_TIMEOUT)
+        .build()
+        .map_err(|e| e.to_string())?;
+    let mut request = client
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json");
+    if let Some(secret) = secret {
+        request = request.header(SIGNATURE_HEADER, signature(secret, &body));
+    }
+    let response = request.body(body).send().await.map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("Webhook returned HTTP {}", response.status()));
+    }
+    Ok(())
+}
+
+/// `sha256=` and the hex HMAC-SHA256 of `body` keyed by `secret`.
+pub fn signature(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    let hex: String = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    format!("sha256={hex}")
+}
+
+/// Best-effort recap for results entry: generate now, deliver in the
+/// background. Failures are logged and never fail the caller.
+pub async fn publish_logged(db: &PgPool, tournament_id: Uuid) {
+    let row = match generate(db, tournament_id).await {
+        Ok(row) => row,
+        Err(e) => {
+            tracing::error!(
+                tournament_id = %tournament_id,
+                error = %e,
+                "Recap generation failed",
+            );
+            return;
+        }
+    };
+    let db = db.clone();
+    tokio::spawn(async move {
+        if let Err(e) = deliver(&db, row).await {
+            tracing::error!(
+                tournament_id = %tournament_id,
+                error = %e,
+                "Recap delivery bookkeeping failed",
+            );
+        }
+    });
+}
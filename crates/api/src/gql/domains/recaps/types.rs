@@ -0,0 +1,116 @@
+use async_graphql::{InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use crate::gql::scalars::Money;
+use infra::repos::tournament_recaps::{RecapSettingsRow, TournamentRecapRow};
+
+use super::service;
+
+/// A tournament's results, summarized for the club's website and socials.
+#[derive(SimpleObject, Clone)]
+pub struct TournamentRecap {
+    pub tournament_id: ID,
+    pub club_name: String,
+    pub tournament_name: String,
+    /// Ready-to-post headline.
+    pub title: String,
+    pub start_time: DateTime<Utc>,
+    pub entrants: i32,
+    pub prize_pool_cents: Money,
+    /// The top three finishers, winner first.
+    pub podium: Vec<RecapPlace>,
+    /// Every place paid, in order.
+    pub payouts: Vec<RecapPlace>,
+    /// Public gallery photos; empty until they're uploaded.
+    pub photos: Vec<RecapPhoto>,
+    pub generated_at: DateTime<Utc>,
+    /// When the club's webhook accepted the recap (managers only).
+    pub delivered_at: Option<DateTime<Utc>>,
+    /// Why the last delivery failed (managers only).
+    pub delivery_error: Option<String>,
+}
+
+impl TryFrom<TournamentRecapRow> for TournamentRecap {
+    type Error = serde_json::Error;
+
+    fn try_from(row: TournamentRecapRow) -> Result<Self, Self::Error> {
+        let recap: service::Recap = serde_json::from_value(row.payload)?;
+        Ok(Self {
+            tournament_id: recap.tournament_id.into(),
+            club_name: recap.club_name,
+            tournament_name: recap.tournament_name,
+            title: recap.title,
+            start_time: recap.start_time,
+            entrants: recap.entrants,
+            prize_pool_cents: recap.prize_pool_cents.into(),
+            podium: recap.podium.into_iter().map(RecapPlace::from).collect(),
+            payouts: recap.payouts.into_iter().map(RecapPlace::from).collect(),
+            photos: recap.photos.into_iter().map(RecapPhoto::from).collect(),
+            generated_at: row.generated_at,
+            delivered_at: row.delivered_at,
+            delivery_error: row.delivery_error,
+        })
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct RecapPlace {
+    pub position: i32,
+    pub player_name: String,
+    pub prize_cents: Money,
+}
+
+impl From<service::RecapPlace> for RecapPlace {
+    fn from(place: service::RecapPlace) -> Self {
+        Self {
+            position: place.position,
+            player_name: place.player_name,
+            prize_cents: place.prize_cents.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct RecapPhoto {
+    pub url: String,
+    pub caption: Option<String>,
+}
+
+impl From<service::RecapPhoto> for RecapPhoto {
+    fn from(photo: service::RecapPhoto) -> Self {
+        Self {
+            url: photo.url,
+            caption: photo.caption,
+        }
+    }
+}
+
+/// Where a club's recaps are delivered. The secret itself is never returned.
+#[derive(SimpleObject, Clone)]
+pub struct RecapSettings {
+    pub club_id: ID,
+    pub webhook_url: Option<String>,
+    /// Whether deliveries are signed (`X-Recap-Signature`).
+    pub signed: bool,
+}
+
+impl From<RecapSettingsRow> for RecapSettings {
+    fn from(row: RecapSettingsRow) -> Self {
+        Self {
+            club_id: row.club_id.into(),
+            webhook_url: row.webhook_url,
+            signed: row.webhook_secret.is_some(),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct UpdateRecapSettingsInput {
+    pub club_id: ID,
+    /// http(s) endpoint recaps are POSTed to; empty or omitted turns
+    /// delivery off.
+    pub webhook_url: Option<String>,
+    /// HMAC key for `X-Recap-Signature`; omitted keeps the current one,
+    /// empty removes it.
+    pub webhook_secret: Option<String>,
+}
@@ -0,0 +1,284 @@
+//! Alternates: players who only get in as others bust during late
+//! registration. Managers queue them in order; the alternate seating service
+//! seats the head of the queue into each seat an elimination frees. When late
+//! registration closes, alternates who already bought in are refunded and
+//! cancelled, the others move to the waitlist.
+
+use async_graphql::{ErrorExtensions, ID};
+use chrono::Utc;
+use infra::models::{TableSeatAssignmentRow, TournamentRegistrationRow};
+use infra::repos::tournaments::TournamentLiveStatus;
+use infra::repos::{
+    entry_tickets, notification_preferences, tournament_entries, tournament_registrations,
+    tournaments,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::gql::domains::buy_in_credits;
+use crate::gql::subscriptions::{
+    publish_registration_event, publish_seating_event, publish_user_notification,
+};
+use crate::gql::types::{
+    NotificationType, PlayerRegistrationEvent, RegistrationEventType, SeatAssignment,
+    SeatingChangeEvent, SeatingEventType, TournamentPlayer, UserNotification, TITLE_SEAT_ASSIGNED,
+};
+
+/// Queue `club_player_id` as the tournament's last alternate. Registration
+/// must be open (regular or late); a player who already holds a live place
+/// is `ALREADY_REGISTERED`.
+pub async fn add(
+    db: &PgPool,
+    tournament_id: Uuid,
+    club_player_id: Uuid,
+    notes: Option<String>,
+) -> async_graphql::Result<TournamentRegistrationRow> {
+    let tournament = tournaments::get_by_id(db, tournament_id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+    if !matches!(
+        tournament.live_status,
+        TournamentLiveStatus::RegistrationOpen | TournamentLiveStatus::LateRegistration
+    ) {
+        return Err(async_graphql::Error::new(
+            "Registration is not open for this tournament",
+        ));
+    }
+    if infra::repos::player_exclusions::active_for_club_player(db, club_player_id)
+        .await?
+        .is_some()
+    {
+        return Err(async_graphql::Error::new(
+            "Player is excluded from this club",
+        ));
+    }
+
+    tournament_registrations::add_alternate(db, tournament_id, club_player_id, notes)
+        .await?
+        .ok_or_else(|| {
+            async_graphql::Error::new("Player is already registered for this tournament")
+                .extend_with(|_, e| e.set("code", "ALREADY_REGISTERED"))
+        })
+}
+
+/// Seat alternates, in queue order, into the seats eliminations have freed.
+/// Stops early when the tables have no free seat left. Only acts during late
+/// registration.
+pub async fn seat_alternates(
+    db: &PgPool,
+    tournament_id: Uuid,
+) -> sqlx::Result<Vec<(TournamentRegistrationRow, TableSeatAssignmentRow)>> {
+    let mut tx = db.begin().await?;
+
+    // Serialize with other sweeps (and instances) on the tournament row.
+    if !tournaments::lock_if_late_registration(&mut *tx, tournament_id).await? {
+        return Ok(Vec::new());
+    }
+
+    let openings =
+        tournament_registrations::count_alternate_openings(&mut *tx, tournament_id).await?;
+    let alternates = tournament_registrations::list_alternates(&mut *tx, tournament_id).await?;
+
+    let mut seated = Vec::new();
+    for alternate in alternates.into_iter().take(openings as usize) {
+        let Some(assignment) = crate::gql::domains::seating::service::auto_seat_one_in(
+            &mut tx,
+            tournament_id,
+            alternate.club_player_id,
+            None,
+        )
+        .await?
+        else {
+            break; // no free seat
+        };
+        let registration = tournament_registrations::get_by_id(&mut *tx, alternate.id)
+            .await?
+            .unwrap_or(alternate);
+        seated.push((registration, assignment));
+    }
+
+    tx.commit().await?;
+    Ok(seated)
+}
+
+/// Announce seated alternates: seating and registration events, the player's
+/// seat notification, and the activity log.
+pub async fn announce_seated(
+    db: &PgPool,
+    club_id: Uuid,
+    seated: Vec<(TournamentRegistrationRow, TableSeatAssignmentRow)>,
+) {
+    for (registration, assignment) in seated {
+        let tournament_id = registration.tournament_id;
+        let club_player_id = registration.club_player_id;
+        let user_id = registration.user_id;
+        let seat_number = assignment.seat_number;
+
+        publish_seating_event(SeatingChangeEvent {
+            event_type: SeatingEventType::PlayerAssigned,
+            tournament_id: tournament_id.into(),
+            club_id: club_id.into(),
+            affected_assignment: Some(SeatAssignment::from(assignment)),
+            affected_player: None,
+            message: format!("Alternate seated at seat {seat_number}"),
+            timestamp: Utc::now(),
+        });
+
+        let display_name = infra::repos::club_players::get_by_id(db, club_player_id)
+            .await
+            .ok()
+            .flatten()
+            .map(|rp| rp.display_name)
+            .unwrap_or_else(|| "Unknown".to_string());
+        publish_registration_event(PlayerRegistrationEvent {
+            tournament_id: tournament_id.into(),
+            player: TournamentPlayer {
+                registration: registration.into(),
+                display_name,
+                user: None,
+            },
+            event_type: RegistrationEventType::PlayerPromoted,
+        });
+
+        if let Some(uid) = user_id {
+            let prefs = notification_preferences::get_for_user(db, uid)
+                .await
+                .unwrap_or_default();
+            if prefs.seating_updates {
+                publish_user_notification(UserNotification {
+                    id: ID::from(Uuid::new_v4().to_string()),
+                    user_id: ID::from(uid.to_string()),
+                    notification_type: NotificationType::SeatAssigned,
+                    title: TITLE_SEAT_ASSIGNED.to_string(),
+                    message: format!("A seat freed up: you are seated at seat {seat_number}"),
+                    tournament_id: Some(ID::from(tournament_id.to_string())),
+                    created_at: Utc::now(),
+                });
+            }
+            let db = db.clone();
+            tokio::spawn(async move {
+                crate::services::push_service::send_seating_event(
+                    &db,
+                    uid,
+                    "SEAT_ASSIGNED",
+                    tournament_id,
+                )
+                .await;
+            });
+        }
+
+        crate::gql::domains::activity_log::log_and_publish(
+            db,
+            tournament_id,
+            "registration",
+            "alternate_seated",
+            None,
+            user_id,
+            serde_json::json!({
+                "club_player_id": club_player_id,
+                "seat_number": seat_number,
+            }),
+        )
+        .await;
+    }
+}
+
+/// One pass of the background check: seat alternates wherever eliminations
+/// freed seats. Returns how many were seated.
+pub async fn sweep(db: &PgPool) -> sqlx::Result<usize> {
+    let mut total = 0;
+    for tournament_id in tournament_registrations::list_tournaments_with_alternates(db).await? {
+        let seated = seat_alternates(db, tournament_id).await?;
+        if seated.is_empty() {
+            continue;
+        }
+        total += seated.len();
+        if let Some(tournament) = tournaments::get_by_id(db, tournament_id).await? {
+            announce_seated(db, tournament.club_id, seated).await;
+        }
+    }
+    Ok(total)
+}
+
+/// Late registration closed: refund and cancel alternates who already bought
+/// in (their entries are removed and any voucher given back), move the others
+/// to the waitlist. Runs before the payouts are finalized.
+pub async fn close(db: &PgPool, tournament_id: Uuid, actor_id: Option<Uuid>) -> sqlx::Result<()> {
+    for alternate in tournament_registrations::list_alternates(db, tournament_id).await? {
+        let entries = tournament_entries::list_by_tournament_and_club_player(
+            db,
+            tournament_id,
+            alternate.club_player_id,
+        )
+        .await?;
+
+        let mut tx = db.begin().await?;
+        let (status, action, event_type) = if entries.is_empty() {
+            (
+                "waitlisted",
+                "alternate_waitlisted",
+                RegistrationEventType::PlayerWaitlisted,
+            )
+        } else {
+            for entry in &entries {
+                entry_tickets::void_for_entry(&mut *tx, entry.id, actor_id, "Alternate refunded")
+                    .await?;
+                tournament_entries::delete(&mut *tx, entry.id).await?;
+            }
+            buy_in_credits::refund_for_registration(&mut tx, alternate.id, actor_id).await?;
+            (
+                "cancelled",
+                "alternate_refunded",
+                RegistrationEventType::PlayerUnregistered,
+            )
+        };
+        if !tournament_registrations::release_alternate(&mut *tx, alternate.id, status).await? {
+            // Seated or removed meanwhile: leave it alone.
+            continue;
+        }
+        tx.commit().await?;
+
+        let refunded_cents: i64 = entries.iter().map(|e| e.amount_cents).sum();
+        if let Some(registration) = tournament_registrations::get_by_id(db, alternate.id).await? {
+            let display_name = infra::repos::club_players::get_by_id(db, alternate.club_player_id)
+                .await?
+                .map(|rp| rp.display_name)
+                .unwrap_or_else(|| "Unknown".to_string());
+            publish_registration_event(PlayerRegistrationEvent {
+                tournament_id: tournament_id.into(),
+                player: TournamentPlayer {
+                    registration: registration.into(),
+                    display_name,
+                    user: None,
+                },
+                event_type,
+            });
+        }
+        crate::gql::domains::activity_log::log_and_publish(
+            db,
+            tournament_id,
+            "registration",
+            action,
+            actor_id,
+            alternate.user_id,
+            serde_json::json!({
+                "club_player_id": alternate.club_player_id,
+                "refunded_cents": refunded_cents,
+            }),
+        )
+        .await;
+    }
+    Ok(())
+}
+
+/// Best-effort [`close`] for status changes: failures are logged and never
+/// fail the caller.
+pub async fn close_logged(db: &PgPool, tournament_id: Uuid, actor_id: Option<Uuid>) {
+    if let Err(e) = close(db, tournament_id, actor_id).await {
+        tracing::error!(
+            tournament_id = %tournament_id,
+            error = %e,
+            "Releasing alternates at late registration close failed",
+        );
+    }
+}
@@ -1,5 +1,98 @@
+pub mod alternates;
+pub mod presence;
 pub mod resolvers;
 pub mod service;
 pub mod types;
 
 pub use resolvers::{RegistrationMutation, RegistrationQuery};
+
+use async_graphql::dataloader::DataLoader;
+use async_graphql::{Context, ErrorExtensions};
+use infra::models::{TournamentRegistrationRow, TournamentRow};
+use infra::repos::tournament_clock;
+use infra::repos::tournaments::TournamentLiveStatus;
+use uuid::Uuid;
+
+use crate::gql::error::ResultExt;
+use crate::gql::loaders::{ClubPlayerLoader, UserLoader};
+use types::{TournamentPlayer, TournamentRegistration};
+
+/// Answer to registering a player who is already registered: a live
+/// registration is handed back as is (a retried or double-tapped request),
+/// a closed one (cancelled, busted, no-show) is an `ALREADY_REGISTERED` error.
+pub fn already_registered(
+    existing: TournamentRegistrationRow,
+) -> async_graphql::Result<TournamentRegistration> {
+    match existing.status.as_str() {
+        "cancelled" | "busted" | "no_show" => Err(async_graphql::Error::new(format!(
+            "Player is already registered for this tournament ({})",
+            existing.status.replace('_', "-")
+        ))
+        .extend_with(|_, e| e.set("code", "ALREADY_REGISTERED"))),
+        _ => Ok(existing.into()),
+    }
+}
+
+/// Registration is open before play and during late registration, until the
+/// clock moves past `late_registration_level`. The clock service closes late
+/// registration when that happens; checking the level too keeps players out
+/// if the status change hasn't landed yet. `REGISTRATION_CLOSED` otherwise.
+pub async fn ensure_registration_open(
+    conn: &mut sqlx::PgConnection,
+    tournament: &TournamentRow,
+) -> async_graphql::Result<()> {
+    let closed = |message: &str| {
+        Err(async_graphql::Error::new(message)
+            .extend_with(|_, e| e.set("code", "REGISTRATION_CLOSED")))
+    };
+    match tournament.live_status {
+        TournamentLiveStatus::RegistrationOpen => Ok(()),
+        TournamentLiveStatus::LateRegistration => {
+            let Some(last_level) = tournament.late_registration_level else {
+                return Ok(());
+            };
+            let level = tournament_clock::current_level(&mut *conn, tournament.id)
+                .await
+                .gql_err("Database operation failed")?;
+            if level.is_some_and(|level| level > last_level) {
+                return closed("Late registration has closed for this tournament");
+            }
+            Ok(())
+        }
+        _ => closed("Registration is not open for this tournament"),
+    }
+}
+
+/// Players for registration rows, in order: named from the roster, with the
+/// app user attached when there is one.
+pub async fn tournament_players(
+    ctx: &Context<'_>,
+    registrations: Vec<TournamentRegistrationRow>,
+) -> async_graphql::Result<Vec<TournamentPlayer>> {
+    let rp_ids: Vec<Uuid> = registrations.iter().map(|r| r.club_player_id).collect();
+    let user_ids: Vec<Uuid> = registrations.iter().filter_map(|r| r.user_id).collect();
+    let rp_loader = ctx.data::<DataLoader<ClubPlayerLoader>>()?;
+    let user_loader = ctx.data::<DataLoader<UserLoader>>()?;
+    let (rosters, users) =
+        tokio::try_join!(rp_loader.load_many(rp_ids), user_loader.load_many(user_ids))
+            .gql_err("Data loading failed")?;
+
+    Ok(registrations
+        .into_iter()
+        .map(|registration| {
+            let display_name = rosters
+                .get(&registration.club_player_id)
+                .map(|rp| rp.display_name.clone())
+                .unwrap_or_else(|| "Unknown".to_string());
+            let user = registration
+                .user_id
+                .and_then(|uid| users.get(&uid).cloned())
+                .map(Into::into);
+            TournamentPlayer {
+                registration: registration.into(),
+                display_name,
+                user,
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,74 @@
+//! Who is on site: players' phones send a heartbeat while the app is open at
+//! the venue, and managers see each registered player as checked in, present,
+//! away or not yet arrived, to chase no-shows before the start.
+//!
+//! This module is pure (no DB) so the classification is unit-testable.
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::types::{PresenceStatus, RegistrationStatus};
+
+/// A heartbeat this recent means the player is at the venue now.
+pub const PRESENT_WINDOW_MINUTES: i64 = 15;
+
+/// Where the player stands, or None once presence no longer matters
+/// (busted, cancelled, no-show).
+pub fn presence_status(
+    status: RegistrationStatus,
+    last_seen_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Option<PresenceStatus> {
+    match status {
+        RegistrationStatus::CheckedIn | RegistrationStatus::Seated => {
+            Some(PresenceStatus::CheckedIn)
+        }
+        RegistrationStatus::Registered
+        | RegistrationStatus::Waitlisted
+        | RegistrationStatus::Alternate => Some(match last_seen_at {
+            Some(seen) if now - seen <= Duration::minutes(PRESENT_WINDOW_MINUTES) => {
+                PresenceStatus::Present
+            }
+            Some(_) => PresenceStatus::Away,
+            None => PresenceStatus::NotArrived,
+        }),
+        RegistrationStatus::Busted | RegistrationStatus::Cancelled | RegistrationStatus::NoShow => {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_by_latest_heartbeat() {
+        let now = Utc::now();
+        let registered = RegistrationStatus::Registered;
+        assert_eq!(
+            presence_status(registered, None, now),
+            Some(PresenceStatus::NotArrived)
+        );
+        assert_eq!(
+            presence_status(registered, Some(now - Duration::minutes(5)), now),
+            Some(PresenceStatus::Present)
+        );
+        assert_eq!(
+            presence_status(registered, Some(now - Duration::minutes(40)), now),
+            Some(PresenceStatus::Away)
+        );
+    }
+
+    #[test]
+    fn check_in_wins_and_gone_players_have_none() {
+        let now = Utc::now();
+        assert_eq!(
+            presence_status(RegistrationStatus::Seated, None, now),
+            Some(PresenceStatus::CheckedIn)
+        );
+        assert_eq!(
+            presence_status(RegistrationStatus::Busted, Some(now), now),
+            None
+        );
+    }
+}
@@ -2,25 +2,32 @@ use async_graphql::{dataloader::DataLoader, Context, Object, Result, ID};
 use chrono::Utc;
 use uuid::Uuid;
 
+use super::{already_registered, ensure_registration_open};
 use crate::gql::common::helpers::{
-    display_name_from_user, get_club_id_for_tournament, tournament_hidden_from_viewer,
+    display_name_from_user, get_club_id_for_tournament, tournament_access,
+    tournament_hidden_from_viewer, TournamentAccess,
 };
+use crate::gql::domains::printouts::link::{self, TokenScope};
+use crate::gql::domains::tournaments::lobby::publish_if_full;
+use crate::gql::domains::{buy_in_credits, questions};
 use crate::gql::error::{auth_error, ResultExt};
 use crate::gql::loaders::{ClubPlayerLoader, UserLoader};
 use crate::gql::subscriptions::{
     publish_registration_event, publish_seating_event, publish_user_notification,
 };
 use crate::gql::types::{
-    AssignmentStrategy, CancelRegistrationInput, CancelRegistrationResponse, CheckInPlayerInput,
-    CheckInResponse, NotificationType, PaginatedResponse, PaginationInput, PlayerRegistrationEvent,
-    RegisterForTournamentInput, RegisterRosterPlayerInput, RegistrationEventType, SeatAssignment,
-    SeatingChangeEvent, SeatingEventType, SelfCheckInInput, SelfCheckInResponse, TournamentPlayer,
-    TournamentRegistration, User, UserNotification, TITLE_REGISTRATION_CONFIRMED, TITLE_WAITLISTED,
-    TITLE_WAITLIST_PROMOTED,
+    AddTournamentAlternateInput, AssignmentStrategy, CancelRegistrationInput,
+    CancelRegistrationResponse, CheckInPlayerInput, CheckInResponse, NotificationType,
+    PaginatedResponse, PaginationInput, PlayerRegistrationEvent, RegisterForTournamentInput,
+    RegisterRosterPlayerInput, RegistrationAnswerInput, RegistrationEventType,
+    ReorderTournamentAlternatesInput, SeatAssignment, SeatingChangeEvent, SeatingEventType,
+    SelfCheckInInput, SelfCheckInResponse, TournamentPlayer, TournamentRegistration, User,
+    UserNotification, TITLE_REGISTRATION_CONFIRMED, TITLE_WAITLISTED, TITLE_WAITLIST_PROMOTED,
 };
 use crate::state::AppState;
 use infra::repos::{
-    notification_preferences, table_seat_assignments, tournament_registrations,
+    notification_preferences, registration_groups, registration_questions, table_seat_assignments,
+    tournament_invites, tournament_registrations,
     tournament_registrations::CreateTournamentRegistration, tournaments, users,
 };
 
@@ -129,6 +136,26 @@ impl RegistrationQuery {
             .map(TournamentRegistration::from)
             .collect())
     }
+
+    /// The alternates waiting for a seat, first to be seated first.
+    async fn tournament_alternates(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: Uuid,
+    ) -> Result<Vec<TournamentPlayer>> {
+        use crate::auth::Claims;
+
+        let _claims = ctx.data::<Claims>().map_err(|_| auth_error())?;
+        let state = ctx.data::<AppState>()?;
+
+        if tournament_hidden_from_viewer(ctx, tournament_id).await? {
+            return Ok(vec![]);
+        }
+
+        let alternates =
+            tournament_registrations::list_alternates(&state.db, tournament_id).await?;
+        super::tournament_players(ctx, alternates).await
+    }
 }
 
 #[derive(Default)]
@@ -163,10 +190,24 @@ impl RegistrationMutation {
         // Players can't self-register into a free ("Home Game") club's
         // tournament — those clubs are private and off the player app. The host
         // adds their own players via the manager path / registerRosterPlayer.
-        if !is_manager_registration && tournament_hidden_from_viewer(ctx, tournament_id).await? {
-            return Err(async_graphql::Error::new(
-                "This tournament isn't available in the app",
-            ));
+        // Members-only events need a roster spot, unlisted ones the invite link.
+        if !is_manager_registration {
+            let message = match tournament_access(ctx, tournament_id, input.invite_token.as_deref())
+                .await?
+            {
+                TournamentAccess::Visible => None,
+                TournamentAccess::FreeClub => Some("This tournament isn't available in the app"),
+                TournamentAccess::MembersOnly => {
+                    Some("This tournament is open to club members only")
+                }
+                TournamentAccess::InviteOnly => Some("This tournament requires an invite link"),
+                TournamentAccess::AwaitingApproval => {
+                    Some("This tournament isn't open for registration yet")
+                }
+            };
+            if let Some(message) = message {
+                return Err(async_graphql::Error::new(message));
+            }
         }
 
         // Determine which user to register
@@ -177,169 +218,74 @@ impl RegistrationMutation {
             None => Uuid::parse_str(authenticated_user.id.as_str()).gql_err("Invalid user ID")?,
         };
 
-        // Use a transaction with row-level locking to prevent race conditions
-        let mut tx = state.db.begin().await?;
-
-        // Lock the tournament row to prevent concurrent registrations from racing
-        let tournament = sqlx::query_as::<_, infra::models::TournamentRow>(
-            "SELECT id, club_id, name, description, start_time, end_time, buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents, late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at FROM tournaments WHERE id = $1 FOR UPDATE",
-        )
-        .bind(tournament_id)
-        .fetch_optional(&mut *tx)
-        .await?
-        .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
-
-        // Only allow registration during REGISTRATION_OPEN or LATE_REGISTRATION
-        {
-            use infra::repos::tournaments::TournamentLiveStatus;
-            match tournament.live_status {
-                TournamentLiveStatus::RegistrationOpen | TournamentLiveStatus::LateRegistration => { /* allowed */
-                }
-                _ => {
-                    return Err(async_graphql::Error::new(
-                        "Registration is not open for this tournament",
-                    ));
-                }
-            }
-        }
-
-        // Determine status based on seat capacity
-        let is_waitlisted = if let Some(seat_cap) = tournament.seat_cap {
-            let confirmed_count =
-                tournament_registrations::count_confirmed_by_tournament(&mut *tx, tournament_id)
-                    .await?;
-            confirmed_count >= seat_cap as i64
-        } else {
-            false
-        };
-
-        let status = if is_waitlisted {
-            Some("waitlisted".to_string())
-        } else {
-            None // defaults to 'registered'
-        };
-
-        let create_data = CreateTournamentRegistration {
+        register_app_user(
+            ctx,
             tournament_id,
-            user_id: Some(user_id),
-            club_player_id: None,
-            notes: input.notes.clone(),
-            status,
-        };
-
-        let row = tournament_registrations::create(&mut *tx, create_data).await?;
-
-        tx.commit().await?;
-
-        let tournament_registration: TournamentRegistration = row.into();
-
-        // Emit subscription event
-        if let Some(user_row) = users::get_by_id(&state.db, user_id).await? {
-            let display_name = display_name_from_user(&user_row);
-            let user: User = user_row.into();
-
-            let player = TournamentPlayer {
-                registration: tournament_registration.clone(),
-                display_name,
-                user: Some(user),
-            };
-
-            let event_type = if is_waitlisted {
-                RegistrationEventType::PlayerWaitlisted
-            } else {
-                RegistrationEventType::PlayerRegistered
-            };
-
-            let event = PlayerRegistrationEvent {
-                tournament_id: tournament_id.into(),
-                player,
-                event_type,
-            };
-
-            publish_registration_event(event);
-        }
-
-        // Log activity
-        let action = if is_waitlisted {
-            "waitlisted"
-        } else {
-            "registered"
-        };
-        let db = state.db.clone();
-        tokio::spawn(async move {
-            crate::gql::domains::activity_log::log_and_publish(
-                &db,
-                tournament_id,
-                "registration",
-                action,
-                Some(user_id),
-                Some(user_id),
-                serde_json::json!({}),
-            )
-            .await;
-        });
+            user_id,
+            is_manager_registration,
+            AppRegistration {
+                notes: input.notes,
+                acknowledged_rule_document_ids: &input.acknowledged_rule_document_ids,
+                invite_id: None,
+                voucher_code: input.voucher_code.as_deref(),
+                answers: &input.answers,
+            },
+        )
+        .await
+    }
 
-        // Publish notification (respecting the user's preference; lookup
-        // failures fail open)
-        let prefs = notification_preferences::get_for_user(&state.db, user_id)
-            .await
-            .unwrap_or_default();
-        if is_waitlisted {
-            // Get waitlist position for the notification
-            let position =
-                tournament_registrations::get_waitlist_position(&state.db, tournament_id, user_id)
-                    .await
-                    .unwrap_or(None)
-                    .unwrap_or(0);
-
-            let notification = UserNotification {
-                id: ID::from(Uuid::new_v4().to_string()),
-                user_id: ID::from(user_id.to_string()),
-                notification_type: NotificationType::RegistrationConfirmed,
-                title: TITLE_WAITLISTED.to_string(),
-                message: format!(
-                    "You are on the waitlist for {} (position {})",
-                    tournament.name, position
-                ),
-                tournament_id: Some(ID::from(tournament_id.to_string())),
-                created_at: Utc::now(),
-            };
-            if prefs.registration_updates {
-                publish_user_notification(notification);
-            }
-        } else {
-            let notification = UserNotification {
-                id: ID::from(Uuid::new_v4().to_string()),
-                user_id: ID::from(user_id.to_string()),
-                notification_type: NotificationType::RegistrationConfirmed,
-                title: TITLE_REGISTRATION_CONFIRMED.to_string(),
-                message: format!("You are registered for {}", tournament.name),
-                tournament_id: Some(ID::from(tournament_id.to_string())),
-                created_at: Utc::now(),
-            };
-            if prefs.registration_updates {
-                publish_user_notification(notification);
-            }
+    /// Register yourself through an invite link. The invite opens members-only
+    /// and unlisted tournaments; its use limit and expiry still apply.
+    async fn register_with_invite(
+        &self,
+        ctx: &Context<'_>,
+        token: String,
+        notes: Option<String>,
+        #[graphql(default)] acknowledged_rule_document_ids: Vec<ID>,
+        voucher_code: Option<String>,
+        #[graphql(default)] answers: Vec<RegistrationAnswerInput>,
+    ) -> Result<TournamentRegistration> {
+        use crate::auth::permissions::require_manager_if;
 
-            // Send confirmation email (fire-and-forget)
-            if let Some(email_service) = state.email_service() {
-                if let Some(user_row) = users::get_by_id(&state.db, user_id).await? {
-                    let locale =
-                        crate::services::email_service::Locale::from_str_lossy(&user_row.locale);
-                    crate::services::email_service::spawn_email(
-                        email_service.clone(),
-                        user_row.email,
-                        user_row.first_name,
-                        crate::services::email_service::EmailType::RegistrationConfirmed {
-                            tournament_name: tournament.name.clone(),
-                            locale,
-                        },
-                    );
-                }
-            }
+        let state = ctx.data::<AppState>()?;
+        let user = require_manager_if(ctx, false, "user_id")
+            .await?
+            .ok_or_else(|| {
+                async_graphql::Error::new("You must be logged in to perform this action")
+            })?;
+        let user_id = Uuid::parse_str(user.id.as_str()).gql_err("Invalid user ID")?;
+
+        let invalid = || async_graphql::Error::new("Invalid invite link");
+        let invite_id =
+            link::decode_token(&state.auth_config().jwt_secret, TokenScope::Invite, &token)
+                .ok_or_else(invalid)?;
+        // The signed token is the invitee's access to the invite row.
+        let invite =
+            infra::db::without_request_scope(tournament_invites::get_by_id(&state.db, invite_id))
+                .await?
+                .ok_or_else(invalid)?;
+
+        // An invite doesn't put a free club's tournament on the app.
+        if tournament_access(ctx, invite.tournament_id, None).await? == TournamentAccess::FreeClub {
+            return Err(async_graphql::Error::new(
+                "This tournament isn't available in the app",
+            ));
         }
 
-        Ok(tournament_registration)
+        register_app_user(
+            ctx,
+            invite.tournament_id,
+            user_id,
+            false,
+            AppRegistration {
+                notes,
+                acknowledged_rule_document_ids: &acknowledged_rule_document_ids,
+                invite_id: Some(invite_id),
+                voucher_code: voucher_code.as_deref(),
+                answers: &answers,
+            },
+        )
+        .await
     }
 
     /// Register an account-less roster player into a tournament. Managers only —
@@ -363,31 +309,54 @@ impl RegistrationMutation {
         let manager = require_club_manager(ctx, club_id).await?;
         let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid manager ID")?;
 
+        // An offline desk replays its queued mutation with the id it generated:
+        // hand back the registration made the first time.
+        let client_id = input
+            .id
+            .as_ref()
+            .map(|id| Uuid::parse_str(id.as_str()))
+            .transpose()
+            .gql_err("Invalid registration ID")?;
+        if let Some(id) = client_id {
+            if let Some(existing) = tournament_registrations::get_by_id(&state.db, id).await? {
+                if existing.tournament_id != tournament_id
+                    || existing.club_player_id != club_player_id
+                {
+                    return Err(async_graphql::Error::new(
+                        "Registration ID is already used by a different registration",
+                    ));
+                }
+                return Ok(existing.into());
+            }
+        }
+
         let mut tx = state.db.begin().await?;
 
         // Lock the tournament row to prevent concurrent registrations from racing
         let tournament = sqlx::query_as::<_, infra::models::TournamentRow>(
-            "SELECT id, club_id, name, description, start_time, end_time, buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents, late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at FROM tournaments WHERE id = $1 FOR UPDATE",
+            "SELECT id, club_id, name, description, start_time, end_time, buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents, late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at FROM tournaments WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
         )
         .bind(tournament_id)
         .fetch_optional(&mut *tx)
         .await?
         .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
 
-        // Only allow registration during REGISTRATION_OPEN or LATE_REGISTRATION
+        if infra::repos::player_exclusions::active_for_club_player(&mut *tx, club_player_id)
+            .await?
+            .is_some()
         {
-            use infra::repos::tournaments::TournamentLiveStatus;
-            match tournament.live_status {
-                TournamentLiveStatus::RegistrationOpen | TournamentLiveStatus::LateRegistration => {
-                }
-                _ => {
-                    return Err(async_graphql::Error::new(
-                        "Registration is not open for this tournament",
-                    ));
-                }
-            }
+            return Err(async_graphql::Error::new(
+                "Player is excluded from this club",
+            ));
         }
 
+        ensure_registration_open(&mut tx, &tournament).await?;
+
+        let active_questions =
+            registration_questions::list_for_tournament(&mut *tx, tournament_id, true).await?;
+        let answers = questions::validate_answers(&active_questions, &input.answers, false)
+            .map_err(async_graphql::Error::new)?;
+
         // Determine status based on seat capacity
         let is_waitlisted = if let Some(seat_cap) = tournament.seat_cap {
             let confirmed_count =
@@ -411,14 +380,50 @@ impl RegistrationMutation {
         };
 
         let create_data = CreateTournamentRegistration {
+            id: client_id,
             tournament_id,
             user_id: None,
             club_player_id: Some(club_player_id),
             notes: input.notes.clone(),
             status,
+            invite_id: None,
         };
 
-        let row = tournament_registrations::create(&mut *tx, create_data).await?;
+        let Some(row) = tournament_registrations::create_if_absent(&mut *tx, create_data).await?
+        else {
+            drop(tx);
+            let existing = tournament_registrations::get_by_tournament_and_club_player(
+                &state.db,
+                tournament_id,
+                club_player_id,
+            )
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Failed to register"))?;
+            return already_registered(existing);
+        };
+        for (question_id, value) in &answers {
+            if let Some(value) = value {
+                registration_questions::upsert_answer(
+                    &mut *tx,
+                    row.id,
+                    *question_id,
+                    &value.to_json(),
+                )
+                .await?;
+            }
+        }
+        if let Some(code) = input.voucher_code.as_deref() {
+            buy_in_credits::redeem(
+                &mut tx,
+                code,
+                club_id,
+                club_player_id,
+                tournament_id,
+                row.id,
+                manager_id,
+            )
+            .await?;
+        }
         tx.commit().await?;
 
         let tournament_registration: TournamentRegistration = row.into();
@@ -447,6 +452,9 @@ impl RegistrationMutation {
             },
         };
         publish_registration_event(event);
+        if !is_waitlisted {
+            publish_if_full(&state.db, tournament_id).await;
+        }
 
         // Log activity (manager acted; no app-user target)
         let action = if is_waitlisted {
@@ -524,6 +532,85 @@ impl RegistrationMutation {
         Ok(tournament_registration)
     }
 
+    /// Queue a roster player as the tournament's last alternate: they are
+    /// seated automatically when an elimination frees a seat during late
+    /// registration. Managers only.
+    async fn add_tournament_alternate(
+        &self,
+        ctx: &Context<'_>,
+        input: AddTournamentAlternateInput,
+    ) -> Result<TournamentRegistration> {
+        use crate::auth::permissions::require_club_manager;
+
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_player_id =
+            Uuid::parse_str(input.club_player_id.as_str()).gql_err("Invalid club player ID")?;
+
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid manager ID")?;
+
+        let row =
+            super::alternates::add(&state.db, tournament_id, club_player_id, input.notes).await?;
+        let registration: TournamentRegistration = row.clone().into();
+
+        let mut players = super::tournament_players(ctx, vec![row]).await?;
+        if let Some(player) = players.pop() {
+            publish_registration_event(PlayerRegistrationEvent {
+                tournament_id: tournament_id.into(),
+                player,
+                event_type: RegistrationEventType::PlayerAddedAsAlternate,
+            });
+        }
+
+        let db = state.db.clone();
+        tokio::spawn(async move {
+            crate::gql::domains::activity_log::log_and_publish(
+                &db,
+                tournament_id,
+                "registration",
+                "alternate_added",
+                Some(manager_id),
+                None,
+                serde_json::json!({ "club_player_id": club_player_id }),
+            )
+            .await;
+        });
+
+        Ok(registration)
+    }
+
+    /// Reorder the waiting alternates; registrations not listed keep their
+    /// place. Returns the queue in its new order. Managers only.
+    async fn reorder_tournament_alternates(
+        &self,
+        ctx: &Context<'_>,
+        input: ReorderTournamentAlternatesInput,
+    ) -> Result<Vec<TournamentPlayer>> {
+        use crate::auth::permissions::require_club_manager;
+
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let registration_ids = input
+            .registration_ids
+            .iter()
+            .map(|id| Uuid::parse_str(id.as_str()))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .gql_err("Invalid registration ID")?;
+
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        tournament_registrations::reorder_alternates(&state.db, tournament_id, &registration_ids)
+            .await?;
+        let alternates =
+            tournament_registrations::list_alternates(&state.db, tournament_id).await?;
+        super::tournament_players(ctx, alternates).await
+    }
+
     /// Cancel a registration. If the player was confirmed (not waitlisted), promotes the next waitlisted player.
     async fn cancel_registration(
         &self,
@@ -612,6 +699,9 @@ impl RegistrationMutation {
             .begin()
             .await
             .gql_err("Failed to begin transaction")?;
+        // A player cancelling gives back the club's voucher use and seat.
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        infra::db::act_for_club(&mut tx, club_id).await?;
 
         // Update status to cancelled (keyed on the roster identity, which every
         // registration has).
@@ -636,6 +726,22 @@ impl RegistrationMutation {
             .await?;
         }
 
+        // A cancelled registration leaves its group booking.
+        if let Some(group_id) =
+            registration_groups::remove_member(&mut *tx, registration.id).await?
+        {
+            registration_groups::delete_if_empty(&mut *tx, group_id).await?;
+        }
+
+        // A voucher redeemed for this registration goes back to the player
+        // unless the desk already took the buy-in with it.
+        buy_in_credits::refund_for_registration(
+            &mut tx,
+            registration.id,
+            Some(authenticated_user_id),
+        )
+        .await?;
+
         tx.commit().await.gql_err("Failed to commit transaction")?;
 
         // Get updated registration
@@ -720,6 +826,7 @@ impl RegistrationMutation {
                     event_type: RegistrationEventType::PlayerPromoted,
                 };
                 publish_registration_event(event);
+                publish_if_full(&state.db, tournament_id).await;
 
                 // Notify the promoted player (only when they have an account)
                 if let Some(promoted_uid) = promoted_user_id {
@@ -810,6 +917,31 @@ impl RegistrationMutation {
         })
     }
 
+    /// Presence heartbeat: the player app calls this while open at the venue
+    /// (or on entering its geofence) so managers see who has arrived.
+    async fn heartbeat(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<TournamentRegistration> {
+        use crate::auth::Claims;
+
+        let state = ctx.data::<AppState>()?;
+
+        let claims = ctx.data::<Claims>().map_err(|_| auth_error())?;
+        let user_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+
+        let registration =
+            tournament_registrations::touch_last_seen(&state.db, tournament_id, user_id)
+                .await?
+                .ok_or_else(|| {
+                    async_graphql::Error::new("You are not registered for this tournament")
+                })?;
+        Ok(registration.into())
+    }
+
     /// Self check-in: a player scans a tournament QR code and checks themselves in.
     /// If not registered, registers first then checks in.
     async fn self_check_in(
@@ -868,6 +1000,9 @@ impl RegistrationMutation {
                 };
                 publish_registration_event(event);
             }
+            if result.updated_registration.status != "waitlisted" {
+                publish_if_full(&state.db, tournament_id).await;
+            }
         }
 
         // Publish seating event if auto-assigned
@@ -1035,3 +1170,303 @@ impl RegistrationMutation {
         })
     }
 }
+
+/// What the player sent along with an app registration.
+struct AppRegistration<'a> {
+    notes: Option<String>,
+    acknowledged_rule_document_ids: &'a [ID],
+    invite_id: Option<Uuid>,
+    voucher_code: Option<&'a str>,
+    answers: &'a [RegistrationAnswerInput],
+}
+
+/// Register an app user whose access to the tournament was already checked:
+/// locks the tournament, enforces exclusions, the registration window and rule
+/// acknowledgments, waitlists when full, then publishes the events and
+/// notifications. A registration through an invite link counts against the
+/// invite and remembers it; a voucher code redeems one prepaid buy-in. The
+/// answers to the registration questions are stored with it; players
+/// registering themselves must answer the required ones.
+async fn register_app_user(
+    ctx: &Context<'_>,
+    tournament_id: Uuid,
+    user_id: Uuid,
+    is_manager_registration: bool,
+    request: AppRegistration<'_>,
+) -> Result<TournamentRegistration> {
+    let AppRegistration {
+        notes,
+        acknowledged_rule_document_ids,
+        invite_id,
+        voucher_code,
+        answers,
+    } = request;
+    // Use a transaction with row-level locking to prevent race conditions
+    let state = ctx.data::<AppState>()?;
+    let mut tx = state.db.begin().await?;
+
+    // Lock the tournament row to prevent concurrent registrations from racing
+    let tournament = sqlx::query_as::<_, infra::models::TournamentRow>(
+        "SELECT id, club_id, name, description, start_time, end_time, buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents, late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at FROM tournaments WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+    )
+    .bind(tournament_id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+    // The registration reads and writes the club's rows (exclusions, rules,
+    // invites, vouchers) on the player's behalf.
+    infra::db::act_for_club(&mut tx, tournament.club_id).await?;
+
+    // A retried or double-tapped registration gets the one made the first
+    // time; the tournament lock keeps two such calls from both getting past.
+    if let Some(existing) =
+        tournament_registrations::get_by_tournament_and_user(&mut *tx, tournament_id, user_id)
+            .await?
+    {
+        return already_registered(existing);
+    }
+
+    // Excluded players can't register at the club, whoever is registering them.
+    if infra::repos::player_exclusions::active_for_user(&mut *tx, tournament.club_id, user_id)
+        .await?
+        .is_some()
+    {
+        return Err(async_graphql::Error::new(
+            "Player is excluded from this club",
+        ));
+    }
+
+    ensure_registration_open(&mut tx, &tournament).await?;
+
+    // Invite links carry their own limits; the lock keeps two players from
+    // both taking an invite's last use.
+    if let Some(invite_id) = invite_id {
+        let invite = tournament_invites::lock(&mut *tx, invite_id)
+            .await?
+            .filter(|invite| invite.tournament_id == tournament_id)
+            .ok_or_else(|| async_graphql::Error::new("Invalid invite link"))?;
+        if let Some(reason) = crate::gql::domains::invites::unusable_reason(&invite, Utc::now()) {
+            return Err(async_graphql::Error::new(reason));
+        }
+        tournament_invites::record_use(&mut *tx, invite_id).await?;
+    }
+
+    // Players registering themselves must acknowledge every active rules
+    // document / disclosure that asks for it. Managers registering someone
+    // else acknowledge at the desk, outside the app.
+    let to_acknowledge = if is_manager_registration {
+        Vec::new()
+    } else {
+        let acknowledged = acknowledged_rule_document_ids
+            .iter()
+            .map(|id| Uuid::parse_str(id.as_str()))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .gql_err("Invalid rule document ID")?;
+        let required: Vec<_> =
+            infra::repos::rule_documents::list_for_tournament(&mut *tx, tournament_id)
+                .await?
+                .into_iter()
+                .filter(|doc| doc.requires_acknowledgment)
+                .collect();
+        let missing: Vec<&str> = required
+            .iter()
+            .filter(|doc| !acknowledged.contains(&doc.id))
+            .map(|doc| doc.title.as_str())
+            .collect();
+        if !missing.is_empty() {
+            return Err(async_graphql::Error::new(format!(
+                "You must acknowledge the tournament rules before registering: {}",
+                missing.join(", ")
+            )));
+        }
+        required
+    };
+
+    let active_questions =
+        registration_questions::list_for_tournament(&mut *tx, tournament_id, true).await?;
+    let answers = questions::validate_answers(&active_questions, answers, !is_manager_registration)
+        .map_err(async_graphql::Error::new)?;
+
+    // Determine status based on seat capacity
+    let is_waitlisted = if let Some(seat_cap) = tournament.seat_cap {
+        let confirmed_count =
+            tournament_registrations::count_confirmed_by_tournament(&mut *tx, tournament_id)
+                .await?;
+        confirmed_count >= seat_cap as i64
+    } else {
+        false
+    };
+
+    let status = if is_waitlisted {
+        Some("waitlisted".to_string())
+    } else {
+        None // defaults to 'registered'
+    };
+
+    let create_data = CreateTournamentRegistration {
+        id: None,
+        tournament_id,
+        user_id: Some(user_id),
+        club_player_id: None,
+        notes,
+        status,
+        invite_id,
+    };
+
+    // Paths that don't take the tournament lock (a friend registering them)
+    // can still get there first.
+    let Some(row) = tournament_registrations::create_if_absent(&mut *tx, create_data).await? else {
+        drop(tx);
+        let existing =
+            tournament_registrations::get_by_tournament_and_user(&state.db, tournament_id, user_id)
+                .await?
+                .ok_or_else(|| async_graphql::Error::new("Failed to register"))?;
+        return already_registered(existing);
+    };
+
+    if let Some(code) = voucher_code {
+        buy_in_credits::redeem(
+            &mut tx,
+            code,
+            tournament.club_id,
+            row.club_player_id,
+            tournament_id,
+            row.id,
+            user_id,
+        )
+        .await?;
+    }
+
+    for (question_id, value) in &answers {
+        if let Some(value) = value {
+            registration_questions::upsert_answer(&mut *tx, row.id, *question_id, &value.to_json())
+                .await?;
+        }
+    }
+
+    for doc in &to_acknowledge {
+        infra::repos::rule_documents::acknowledge(
+            &mut *tx,
+            doc.id,
+            doc.version,
+            tournament_id,
+            user_id,
+        )
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    let tournament_registration: TournamentRegistration = row.into();
+
+    // Emit subscription event
+    if let Some(user_row) = users::get_by_id(&state.db, user_id).await? {
+        let display_name = display_name_from_user(&user_row);
+        let user: User = user_row.into();
+
+        let player = TournamentPlayer {
+            registration: tournament_registration.clone(),
+            display_name,
+            user: Some(user),
+        };
+
+        let event_type = if is_waitlisted {
+            RegistrationEventType::PlayerWaitlisted
+        } else {
+            RegistrationEventType::PlayerRegistered
+        };
+
+        let event = PlayerRegistrationEvent {
+            tournament_id: tournament_id.into(),
+            player,
+            event_type,
+        };
+
+        publish_registration_event(event);
+    }
+    if !is_waitlisted {
+        publish_if_full(&state.db, tournament_id).await;
+    }
+
+    // Log activity
+    let action = if is_waitlisted {
+        "waitlisted"
+    } else {
+        "registered"
+    };
+    let db = state.db.clone();
+    tokio::spawn(async move {
+        crate::gql::domains::activity_log::log_and_publish(
+            &db,
+            tournament_id,
+            "registration",
+            action,
+            Some(user_id),
+            Some(user_id),
+            serde_json::json!({}),
+        )
+        .await;
+    });
+
+    // Publish notification (respecting the user's preference; lookup
+    // failures fail open)
+    let prefs = notification_preferences::get_for_user(&state.db, user_id)
+        .await
+        .unwrap_or_default();
+    if is_waitlisted {
+        // Get waitlist position for the notification
+        let position =
+            tournament_registrations::get_waitlist_position(&state.db, tournament_id, user_id)
+                .await
+                .unwrap_or(None)
+                .unwrap_or(0);
+
+        let notification = UserNotification {
+            id: ID::from(Uuid::new_v4().to_string()),
+            user_id: ID::from(user_id.to_string()),
+            notification_type: NotificationType::RegistrationConfirmed,
+            title: TITLE_WAITLISTED.to_string(),
+            message: format!(
+                "You are on the waitlist for {} (position {})",
+                tournament.name, position
+            ),
+            tournament_id: Some(ID::from(tournament_id.to_string())),
+            created_at: Utc::now(),
+        };
+        if prefs.registration_updates {
+            publish_user_notification(notification);
+        }
+    } else {
+        let notification = UserNotification {
+            id: ID::from(Uuid::new_v4().to_string()),
+            user_id: ID::from(user_id.to_string()),
+            notification_type: NotificationType::RegistrationConfirmed,
+            title: TITLE_REGISTRATION_CONFIRMED.to_string(),
+            message: format!("You are registered for {}", tournament.name),
+            tournament_id: Some(ID::from(tournament_id.to_string())),
+            created_at: Utc::now(),
+        };
+        if prefs.registration_updates {
+            publish_user_notification(notification);
+        }
+
+        // Send confirmation email (fire-and-forget)
+        if let Some(email_service) = state.email_service() {
+            if let Some(user_row) = users::get_by_id(&state.db, user_id).await? {
+                let locale =
+                    crate::services::email_service::Locale::from_str_lossy(&user_row.locale);
+                crate::services::email_service::spawn_email(
+                    email_service.clone(),
+                    user_row.email,
+                    user_row.first_name,
+                    crate::services::email_service::EmailType::RegistrationConfirmed {
+                        tournament_name: tournament.name.clone(),
+                        locale,
+                    },
+                );
+            }
+        }
+    }
+
+    Ok(tournament_registration)
+}
@@ -2,8 +2,9 @@ use rand::RngExt;
 use uuid::Uuid;
 
 use infra::repos::{
-    club_tables, table_seat_assignments, table_seat_assignments::CreateSeatAssignment,
-    tournament_entries, tournament_registrations, tournaments,
+    closed_seats, club_tables, table_seat_assignments,
+    table_seat_assignments::CreateSeatAssignment, tournament_entries, tournament_registrations,
+    tournaments,
 };
 
 use crate::gql::types::AssignmentStrategy;
@@ -27,6 +28,12 @@ pub struct CheckInResult {
 
 /// Perform the check-in workflow inside a transaction.
 ///
+/// Status change, early-bird bonus and seat are one transaction: any failure
+/// (including a crash) rolls all of them back, so a player is never left
+/// checked in with a bonus but no seat. The registration row is locked first,
+/// so a concurrent check-in of the same player waits and then fails the
+/// status check.
+///
 /// The caller (resolver) is responsible for:
 /// - Authentication / authorization
 /// - Parsing IDs from GraphQL input
@@ -36,9 +43,11 @@ pub async fn check_in_player(
     pool: &sqlx::PgPool,
     params: CheckInParams,
 ) -> Result<CheckInResult, Box<dyn std::error::Error + Send + Sync>> {
+    let mut tx = pool.begin().await?;
+
     // Validate current registration status
-    let registration = tournament_registrations::get_by_tournament_and_user(
-        pool,
+    let registration = tournament_registrations::lock_by_tournament_and_user(
+        &mut *tx,
         params.tournament_id,
         params.user_id,
     )
@@ -53,9 +62,6 @@ pub async fn check_in_player(
         .into());
     }
 
-    // Begin transaction
-    let mut tx = pool.begin().await?;
-
     // Update status to checked_in
     tournament_registrations::update_status(
         &mut *tx,
@@ -65,18 +71,9 @@ pub async fn check_in_player(
     )
     .await?;
 
-    // Get updated registration
-    let _updated_registration = tournament_registrations::get_by_tournament_and_user(
-        &mut *tx,
-        params.tournament_id,
-        params.user_id,
-    )
-    .await?
-    .ok_or("Failed to get updated registration")?;
-
     // Apply early bird bonus if requested
     if params.grant_early_bird_bonus {
-        let tournament = tournaments::get_by_id(pool, params.tournament_id)
+        let tournament = tournaments::get_by_id(&mut *tx, params.tournament_id)
             .await?
             .ok_or("Tournament not found")?;
 
@@ -96,23 +93,38 @@ pub async fn check_in_player(
     let mut message = String::from("Player checked in successfully");
 
     if params.auto_assign && params.assignment_strategy != AssignmentStrategy::Manual {
-        let tables = club_tables::list_assigned_to_tournament(pool, params.tournament_id).await?;
+        let tables =
+            club_tables::list_assigned_to_tournament(&mut *tx, params.tournament_id).await?;
 
         if !tables.is_empty() {
             let current_assignments =
                 table_seat_assignments::list_current_for_tournament(&mut *tx, params.tournament_id)
                     .await?;
+            let closed = closed_seats::list_for_tournament(&mut *tx, params.tournament_id).await?;
 
             // Decide table + seat based on strategy, as (table_id, seat, table_number).
             let placement: Option<(Uuid, i32, i32)> = match params.assignment_strategy {
                 AssignmentStrategy::Balanced => {
                     // Fill-then-balance: keep tables playable (minimal number of
                     // active tables) instead of spreading players thin.
+                    // Group bookings follow the club's policy.
+                    use crate::gql::domains::seating::service::{
+                        decide_seat_fill_then_balance, group_mate_tables, table_choice,
+                    };
                     let total_after = current_assignments.len() as i32 + 1;
-                    crate::gql::domains::seating::service::decide_seat_fill_then_balance(
+                    let (group_policy, mate_tables) = group_mate_tables(
+                        &mut tx,
+                        params.tournament_id,
+                        registration.club_player_id,
+                        &current_assignments,
+                    )
+                    .await?;
+                    decide_seat_fill_then_balance(
                         &tables,
                         &current_assignments,
+                        &closed,
                         total_after,
+                        table_choice(&group_policy, &mate_tables),
                     )
                     .map(|(table_id, seat)| {
                         let tnum = tables
@@ -143,10 +155,17 @@ pub async fn check_in_player(
                     };
 
                     if let Some(table) = target_table {
+                        // Closed seats are as good as taken.
                         let occupied_seats: std::collections::HashSet<i32> =
                             table_seat_assignments::get_occupied_seats(&mut *tx, table.id)
                                 .await?
                                 .into_iter()
+                                .chain(
+                                    closed
+                                        .iter()
+                                        .filter(|c| c.club_table_id == table.id)
+                                        .map(|c| c.seat_number),
+                                )
                                 .collect();
                         let available_seats: Vec<i32> = (1..=table.max_seats)
                             .filter(|seat| !occupied_seats.contains(seat))
@@ -225,8 +244,9 @@ pub async fn check_in_player(
 
 /// Check-in workflow for an account-less roster player, keyed by
 /// `club_player_id`. Mirrors `check_in_player` but uses the roster-native repo
-/// and seating paths. No early-bird bonus or entry handling — those are
-/// user-id keyed today and don't apply to roster players.
+/// and seating paths, in one transaction likewise. No early-bird bonus or
+/// entry handling — those are user-id keyed today and don't apply to roster
+/// players.
 pub async fn check_in_roster_player(
     pool: &sqlx::PgPool,
     tournament_id: Uuid,
@@ -234,8 +254,10 @@ pub async fn check_in_roster_player(
     manager_id: Uuid,
     auto_assign: bool,
 ) -> Result<CheckInResult, Box<dyn std::error::Error + Send + Sync>> {
-    let registration = tournament_registrations::get_by_tournament_and_club_player(
-        pool,
+    let mut tx = pool.begin().await?;
+
+    let registration = tournament_registrations::lock_by_tournament_and_club_player(
+        &mut *tx,
         tournament_id,
         club_player_id,
     )
@@ -251,23 +273,23 @@ pub async fn check_in_roster_player(
     }
 
     tournament_registrations::update_status_by_club_player(
-        pool,
+        &mut *tx,
         tournament_id,
         club_player_id,
         "checked_in",
     )
     .await?;
 
-    // Auto-seat on a random free seat when requested. `auto_seat_one` is
-    // self-contained (own transaction) and flips the status to "seated".
+    // Auto-seat on a random free seat when requested; this flips the status
+    // to "seated".
     let mut seat_assignment = None;
     let mut message = String::from("Player checked in successfully");
     if auto_assign {
-        match crate::gql::domains::seating::service::auto_seat_one(
-            pool,
+        match crate::gql::domains::seating::service::auto_seat_one_in(
+            &mut tx,
             tournament_id,
             club_player_id,
-            manager_id,
+            Some(manager_id),
         )
         .await?
         {
@@ -285,13 +307,15 @@ pub async fn check_in_roster_player(
     }
 
     let final_registration = tournament_registrations::get_by_tournament_and_club_player(
-        pool,
+        &mut *tx,
         tournament_id,
         club_player_id,
     )
     .await?
     .ok_or("Failed to get final registration")?;
 
+    tx.commit().await?;
+
     Ok(CheckInResult {
         updated_registration: final_registration,
         seat_assignment,
@@ -325,12 +349,15 @@ pub async fn self_check_in(
 
     // Lock the tournament row
     let tournament = sqlx::query_as::<_, infra::models::TournamentRow>(
-        "SELECT id, club_id, name, description, start_time, end_time, buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents, late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at FROM tournaments WHERE id = $1 FOR UPDATE",
+        "SELECT id, club_id, name, description, start_time, end_time, buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents, late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at FROM tournaments WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
     )
     .bind(params.tournament_id)
     .fetch_optional(&mut *tx)
     .await?
     .ok_or("Tournament not found")?;
+    // The check-in seats the player at the club's tables and queues their
+    // seat slip on their behalf.
+    infra::db::act_for_club(&mut tx, tournament.club_id).await?;
 
     // Check tournament status allows check-in
     use infra::repos::tournaments::TournamentLiveStatus;
@@ -380,6 +407,12 @@ pub async fn self_check_in(
                         "You are on the waitlist. Please wait for a spot to open up.".into(),
                     );
                 }
+                "alternate" => {
+                    tx.commit().await?;
+                    return Err(
+                        "You are an alternate. You will be seated when a seat frees up.".into(),
+                    );
+                }
                 "cancelled" | "no_show" => {
                     tx.commit().await?;
                     return Err(format!(
@@ -420,11 +453,13 @@ pub async fn self_check_in(
             if is_waitlisted {
                 // Can't auto-check-in if waitlisted
                 let create_data = tournament_registrations::CreateTournamentRegistration {
+                    id: None,
                     tournament_id: params.tournament_id,
                     user_id: Some(params.user_id),
                     club_player_id: None,
                     notes: Some("Self-registered via QR scan".to_string()),
                     status: Some("waitlisted".to_string()),
+                    invite_id: None,
                 };
                 let row = tournament_registrations::create(&mut *tx, create_data).await?;
                 tx.commit().await?;
@@ -441,11 +476,13 @@ pub async fn self_check_in(
 
             // Register as confirmed
             let create_data = tournament_registrations::CreateTournamentRegistration {
+                id: None,
                 tournament_id: params.tournament_id,
                 user_id: Some(params.user_id),
                 club_player_id: None,
                 notes: Some("Self-registered via QR scan".to_string()),
                 status: None, // defaults to 'registered'
+                invite_id: None,
             };
             tournament_registrations::create(&mut *tx, create_data).await?;
             was_registered = true;
@@ -461,15 +498,6 @@ pub async fn self_check_in(
     )
     .await?;
 
-    // Get updated registration
-    let _updated_registration = tournament_registrations::get_by_tournament_and_user(
-        &mut *tx,
-        params.tournament_id,
-        params.user_id,
-    )
-    .await?
-    .ok_or("Failed to get updated registration")?;
-
     // Auto-assign to table
     let mut seat_assignment: Option<infra::models::TableSeatAssignmentRow> = None;
     let mut message = if was_registered {
@@ -479,7 +507,8 @@ pub async fn self_check_in(
     };
 
     if params.auto_assign && params.assignment_strategy != AssignmentStrategy::Manual {
-        let tables = club_tables::list_assigned_to_tournament(pool, params.tournament_id).await?;
+        let tables =
+            club_tables::list_assigned_to_tournament(&mut *tx, params.tournament_id).await?;
 
         if !tables.is_empty() {
             let current_assignments =
@@ -513,10 +542,19 @@ pub async fn self_check_in(
                 _ => unreachable!(),
             };
 
+            // Closed seats are as good as taken.
             let occupied_seats: std::collections::HashSet<i32> =
                 table_seat_assignments::get_occupied_seats(&mut *tx, target_table.id)
                     .await?
                     .into_iter()
+                    .chain(
+                        closed_seats::list_for_table(
+                            &mut *tx,
+                            params.tournament_id,
+                            target_table.id,
+                        )
+                        .await?,
+                    )
                     .collect();
             let available_seats: Vec<i32> = (1..=target_table.max_seats)
                 .filter(|seat| !occupied_seats.contains(seat))
@@ -602,12 +640,14 @@ pub async fn promote_next_waitlisted(
 
     // Lock the tournament row
     let tournament = sqlx::query_as::<_, infra::models::TournamentRow>(
-        "SELECT id, club_id, name, description, start_time, end_time, buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents, late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at FROM tournaments WHERE id = $1 FOR UPDATE",
+        "SELECT id, club_id, name, description, start_time, end_time, buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents, late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at FROM tournaments WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
     )
     .bind(tournament_id)
     .fetch_optional(&mut *tx)
     .await?
     .ok_or("Tournament not found")?;
+    // A player's own cancellation promotes someone else's registration.
+    infra::db::act_for_club(&mut tx, tournament.club_id).await?;
 
     let seat_cap = match tournament.seat_cap {
         Some(cap) => cap as i64,
@@ -3,11 +3,15 @@ use async_graphql::{ComplexObject, Context, Enum, InputObject, SimpleObject, ID}
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+use crate::auth::permissions::require_club_manager;
+use crate::gql::common::helpers::get_club_id_for_tournament;
+use crate::gql::domains::questions::types::RegistrationAnswerInput;
 use crate::gql::domains::seating::types::SeatAssignment;
 use crate::gql::domains::tournaments::types::Tournament;
 use crate::gql::domains::users::types::User;
 use crate::gql::error::ResultExt;
 use crate::gql::loaders::{ClubPlayerLoader, TournamentLoader, UserLoader};
+use crate::gql::scalars::Money;
 use crate::state::AppState;
 
 #[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
@@ -27,6 +31,10 @@ pub enum RegistrationStatus {
     /// Player was placed on waiting list (tournament full)
     Waitlisted,
 
+    /// Player is queued to take a seat freed by an elimination during late
+    /// registration
+    Alternate,
+
     /// Player cancelled their registration
     Cancelled,
 
@@ -42,6 +50,7 @@ impl From<String> for RegistrationStatus {
             "seated" => RegistrationStatus::Seated,
             "busted" => RegistrationStatus::Busted,
             "waitlisted" => RegistrationStatus::Waitlisted,
+            "alternate" => RegistrationStatus::Alternate,
             "cancelled" => RegistrationStatus::Cancelled,
             "no_show" => RegistrationStatus::NoShow,
             _ => RegistrationStatus::Registered, // Default fallback
@@ -57,18 +66,40 @@ impl From<RegistrationStatus> for String {
             RegistrationStatus::Seated => "seated".to_string(),
             RegistrationStatus::Busted => "busted".to_string(),
             RegistrationStatus::Waitlisted => "waitlisted".to_string(),
+            RegistrationStatus::Alternate => "alternate".to_string(),
             RegistrationStatus::Cancelled => "cancelled".to_string(),
             RegistrationStatus::NoShow => "no_show".to_string(),
         }
     }
 }
 
+/// Whether a registered player is at the venue.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+pub enum PresenceStatus {
+    /// Checked in or seated by the floor.
+    CheckedIn,
+    /// Their phone sent a heartbeat in the last few minutes.
+    Present,
+    /// Seen at the venue earlier, but not recently.
+    Away,
+    /// No check-in and no heartbeat yet.
+    NotArrived,
+}
+
+#[derive(SimpleObject, Clone, Debug)]
+pub struct PlayerPresence {
+    pub status: PresenceStatus,
+    /// Last heartbeat from the player's phone.
+    pub last_seen_at: Option<DateTime<Utc>>,
+}
+
 #[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
 pub enum RegistrationEventType {
     PlayerRegistered,
     PlayerUnregistered,
     PlayerWaitlisted,
     PlayerPromoted,
+    PlayerAddedAsAlternate,
 }
 
 #[derive(SimpleObject, Clone, serde::Serialize, serde::Deserialize)]
@@ -84,9 +115,15 @@ pub struct TournamentRegistration {
     pub status: RegistrationStatus,
     pub notes: Option<String>,
     /// Live progressive-knockout head for this player, in cents (0 for non-PKO).
-    pub current_bounty_cents: i32,
+    pub current_bounty_cents: Money,
     /// Carried-over chip stack for a multi-day final-day seat (null otherwise).
     pub starting_stack: Option<i32>,
+    /// Invite link the player registered through.
+    pub invite_id: Option<ID>,
+    #[graphql(skip)]
+    pub last_seen_at: Option<DateTime<Utc>>,
+    /// Server version of the registration, for offline reconciliation.
+    pub updated_at: DateTime<Utc>,
 }
 
 impl From<infra::models::TournamentRegistrationRow> for TournamentRegistration {
@@ -99,8 +136,11 @@ impl From<infra::models::TournamentRegistrationRow> for TournamentRegistration {
             registration_time: row.registration_time,
             status: row.status.into(),
             notes: row.notes,
-            current_bounty_cents: row.current_bounty_cents,
+            current_bounty_cents: row.current_bounty_cents.into(),
             starting_stack: row.starting_stack,
+            invite_id: row.invite_id.map(Into::into),
+            last_seen_at: row.last_seen_at,
+            updated_at: row.updated_at,
         }
     }
 }
@@ -175,6 +215,49 @@ impl TournamentRegistration {
 
         Ok(position.map(|p| p as i32))
     }
+
+    /// The player's place in the alternates queue (1-based). Null if not an
+    /// alternate.
+    async fn alternate_position(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<i32>> {
+        if self.status != RegistrationStatus::Alternate {
+            return Ok(None);
+        }
+
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(self.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_player_id =
+            Uuid::parse_str(self.club_player_id.as_str()).gql_err("Invalid roster ID")?;
+
+        let position = infra::repos::tournament_registrations::get_alternate_position(
+            &state.db,
+            tournament_id,
+            club_player_id,
+        )
+        .await
+        .gql_err("Failed to get alternate position")?;
+
+        Ok(position.map(|p| p as i32))
+    }
+
+    /// Whether the player is at the venue; null once they busted, cancelled
+    /// or no-showed. Club managers.
+    async fn presence(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<PlayerPresence>> {
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(self.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        Ok(
+            super::presence::presence_status(self.status, self.last_seen_at, Utc::now()).map(
+                |status| PlayerPresence {
+                    status,
+                    last_seen_at: self.last_seen_at,
+                },
+            ),
+        )
+    }
 }
 
 #[derive(SimpleObject, Clone, serde::Serialize, serde::Deserialize)]
@@ -198,12 +281,28 @@ pub struct RegisterForTournamentInput {
     pub tournament_id: ID,
     pub user_id: Option<ID>, // Optional: if provided, admin can register another user
     pub notes: Option<String>,
+    /// Rule documents / disclosures the player has read and accepts. Every
+    /// active document on `Tournament.rules` that requires acknowledgment
+    /// must be listed when registering yourself.
+    #[graphql(default)]
+    pub acknowledged_rule_document_ids: Vec<ID>,
+    /// Token from an unlisted tournament's invite link.
+    pub invite_token: Option<String>,
+    /// Code of a prepaid package or voucher to pay the buy-in with.
+    pub voucher_code: Option<String>,
+    /// Answers to the tournament's registration questions. Required ones must
+    /// be answered when registering yourself.
+    #[graphql(default)]
+    pub answers: Vec<RegistrationAnswerInput>,
 }
 
 /// Register an account-less roster player into a tournament. Managers only —
 /// the club acts on behalf of players who don't have an app account.
 #[derive(InputObject)]
 pub struct RegisterRosterPlayerInput {
+    /// Client-generated id, so an offline desk can replay the mutation
+    /// safely: a replay returns the registration already made under it.
+    pub id: Option<ID>,
     pub tournament_id: ID,
     pub club_player_id: ID,
     pub notes: Option<String>,
@@ -213,6 +312,26 @@ pub struct RegisterRosterPlayerInput {
     /// Immediately seat the player on a random free seat (implies check_in).
     /// No-op when there are no linked tables / free seats, or when waitlisted.
     pub auto_seat: Option<bool>,
+    /// Code of the player's prepaid package or voucher to pay the buy-in with.
+    pub voucher_code: Option<String>,
+    /// Answers the player gave at the desk; none are required.
+    #[graphql(default)]
+    pub answers: Vec<RegistrationAnswerInput>,
+}
+
+/// Queue a roster player as an alternate. Managers only.
+#[derive(InputObject)]
+pub struct AddTournamentAlternateInput {
+    pub tournament_id: ID,
+    pub club_player_id: ID,
+    pub notes: Option<String>,
+}
+
+/// New order of a tournament's waiting alternates, first to be seated first.
+#[derive(InputObject)]
+pub struct ReorderTournamentAlternatesInput {
+    pub tournament_id: ID,
+    pub registration_ids: Vec<ID>,
 }
 
 #[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
@@ -0,0 +1,165 @@
+//! Results feed for external results databases.
+//!
+//! Assembles a club's finished tournaments and their places into
+//! `ResultsFeed`, and renders the flat CSV most submission forms accept. Pure
+//! (no DB) so the layout is unit-testable.
+
+use std::collections::HashMap;
+
+use infra::models::ClubRow;
+use infra::repos::tournament_results::{ResultsFeedEventRow, ResultsFeedPlaceRow};
+use uuid::Uuid;
+
+use super::types::{ResultsFeed, ResultsFeedEvent, ResultsFeedPlace};
+use crate::gql::scalars::Money;
+
+const CSV_HEADER: [&str; 13] = [
+    "event_date",
+    "event_name",
+    "venue",
+    "city",
+    "country",
+    "buy_in",
+    "rake",
+    "entrants",
+    "total_entries",
+    "prize_pool",
+    "place",
+    "player",
+    "prize",
+];
+
+pub fn build(
+    club: ClubRow,
+    events: Vec<ResultsFeedEventRow>,
+    places: Vec<ResultsFeedPlaceRow>,
+) -> ResultsFeed {
+    let mut places_by_event: HashMap<Uuid, Vec<ResultsFeedPlace>> = HashMap::new();
+    for place in places {
+        places_by_event
+            .entry(place.tournament_id)
+            .or_default()
+            .push(ResultsFeedPlace {
+                position: place.final_position,
+                player_name: place.player_name,
+                prize_cents: place.prize_cents.into(),
+            });
+    }
+
+    ResultsFeed {
+        club_name: club.name,
+        city: club.city,
+        country: club.country,
+        events: events
+            .into_iter()
+            .map(|e| ResultsFeedEvent {
+                places: places_by_event.remove(&e.tournament_id).unwrap_or_default(),
+                tournament_id: e.tournament_id.into(),
+                name: e.name,
+                start_time: e.start_time,
+                end_time: e.end_time,
+                buy_in_cents: e.buy_in_cents.into(),
+                rake_cents: e.rake_cents.into(),
+                entrants: e.entrants as i32,
+                total_entries: e.total_entries as i32,
+                prize_pool_cents: e.prize_pool_cents.into(),
+            })
+            .collect(),
+    }
+}
+
+/// Major units with two decimals ("1234.50"), as results databases expect.
+fn amount(money: Money) -> String {
+    let cents = money.cents();
+    format!("{}.{:02}", cents / 100, (cents % 100).abs())
+}
+
+/// One line per finishing place, event columns repeated on each line.
+pub fn render_csv(feed: &ResultsFeed) -> Result<String, csv::Error> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(CSV_HEADER)?;
+    for event in &feed.events {
+        for place in &event.places {
+            writer.write_record([
+                event.start_time.format("%Y-%m-%d").to_string(),
+                event.name.clone(),
+                feed.club_name.clone(),
+                feed.city.clone().unwrap_or_default(),
+                feed.country.clone().unwrap_or_default(),
+                amount(event.buy_in_cents),
+                amount(event.rake_cents),
+                event.entrants.to_string(),
+                event.total_entries.to_string(),
+                amount(event.prize_pool_cents),
+                place.position.to_string(),
+                place.player_name.clone(),
+                amount(place.prize_cents),
+            ])?;
+        }
+    }
+    let bytes = writer.into_inner().map_err(|e| e.into_error())?;
+    Ok(String::from_utf8(bytes).expect("CSV writer only emits UTF-8 input"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn club() -> ClubRow {
+        let now = Utc::now();
+        ClubRow {
+            id: Uuid::new_v4(),
+            name: "Poker Club".into(),
+            city: Some("Liège".into()),
+            postal_code: None,
+            province: None,
+            country: Some("BE".into()),
+            address: None,
+            vat_number: None,
+            needs_review: false,
+            plan: "club".into(),
+            subscription_status: None,
+            subscription_expires_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn renders_one_line_per_place() {
+        let tournament_id = Uuid::new_v4();
+        let event = ResultsFeedEventRow {
+            tournament_id,
+            name: "Friday, Deepstack".into(),
+            start_time: Utc.with_ymd_and_hms(2025, 3, 7, 19, 0, 0).unwrap(),
+            end_time: None,
+            buy_in_cents: 5_000,
+            rake_cents: 500,
+            entrants: 2,
+            total_entries: 3,
+            prize_pool_cents: 15_000,
+        };
+        let place = |position, name: &str, prize| ResultsFeedPlaceRow {
+            tournament_id,
+            final_position: position,
+            player_name: name.into(),
+            prize_cents: prize,
+        };
+        let feed = build(
+            club(),
+            vec![event],
+            vec![place(1, "Alice", 15_005), place(2, "Bob", 0)],
+        );
+
+        let csv = render_csv(&feed).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], CSV_HEADER.join(","));
+        assert_eq!(
+            lines[1],
+            "2025-03-07,\"Friday, Deepstack\",Poker Club,Liège,BE,50.00,5.00,2,3,150.00,1,Alice,150.05"
+        );
+        assert_eq!(lines[2].rsplit(',').next(), Some("0.00"));
+        assert_eq!(lines.len(), 3);
+    }
+}
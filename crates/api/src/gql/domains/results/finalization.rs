@@ -0,0 +1,118 @@
+//! Official prize pool. While players buy in, the entries trigger keeps
+//! `tournament_payouts` current; once registration closes the table is
+//! frozen, announced to the field, and entries that would move the pool are
+//! refused by the database (`entries_payouts_final` migration, surfaced as
+//! `PAYOUTS_FINALIZED`) until a manager reopens it.
+
+use async_graphql::ID;
+use chrono::Utc;
+use infra::models::TournamentPayoutRow;
+use infra::repos::tournaments::TournamentLiveStatus;
+use infra::repos::{tournament_payouts, tournament_registrations, tournaments};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::gql::scalars::Money;
+use crate::gql::subscriptions::publish_user_notification;
+use crate::gql::types::{NotificationType, UserNotification, TITLE_PAYOUTS_FINALIZED};
+
+/// Registration statuses that hold a place in the field.
+const IN_FIELD: [&str; 4] = ["registered", "checked_in", "seated", "busted"];
+
+/// Whether moving from `from` to `to` closes registration: play starts, or
+/// the tournament ends, while registration (regular or late) was open.
+pub fn closes_registration(from: TournamentLiveStatus, to: TournamentLiveStatus) -> bool {
+    matches!(
+        from,
+        TournamentLiveStatus::RegistrationOpen | TournamentLiveStatus::LateRegistration
+    ) && matches!(
+        to,
+        TournamentLiveStatus::InProgress
+            | TournamentLiveStatus::Break
+            | TournamentLiveStatus::FinalTable
+            | TournamentLiveStatus::Finished
+    )
+}
+
+/// Freeze the payout table as the official one and announce it. `None` when
+/// there is no table yet or it is already final.
+pub async fn finalize(
+    db: &PgPool,
+    tournament_id: Uuid,
+    actor_id: Option<Uuid>,
+) -> sqlx::Result<Option<TournamentPayoutRow>> {
+    let Some(payout) = tournament_payouts::finalize(db, tournament_id, actor_id).await? else {
+        return Ok(None);
+    };
+
+    announce(db, &payout).await?;
+    crate::gql::domains::activity_log::log_and_publish(
+        db,
+        tournament_id,
+        "payouts",
+        "finalized",
+        actor_id,
+        None,
+        serde_json::json!({
+            "total_prize_pool": payout.total_prize_pool,
+            "player_count": payout.player_count,
+        }),
+    )
+    .await;
+
+    Ok(Some(payout))
+}
+
+/// Best-effort `finalize` for status changes: failures are logged and never
+/// fail the caller.
+pub async fn finalize_logged(db: &PgPool, tournament_id: Uuid, actor_id: Option<Uuid>) {
+    if let Err(e) = finalize(db, tournament_id, actor_id).await {
+        tracing::error!(
+            tournament_id = %tournament_id,
+            error = %e,
+            "Payout finalization failed",
+        );
+    }
+}
+
+/// Tell every player in the field, in-app and by push, that the payouts are
+/// final.
+async fn announce(db: &PgPool, payout: &TournamentPayoutRow) -> sqlx::Result<()> {
+    let Some(tournament) = tournaments::get_by_id(db, payout.tournament_id).await? else {
+        return Ok(());
+    };
+    let places = payout
+        .payout_positions
+        .as_array()
+        .map_or(0, |positions| positions.len());
+    let message = format!(
+        "The prize pool for {} is final: {}, {places} places paid.",
+        tournament.name,
+        Money(payout.total_prize_pool).euros()
+    );
+
+    let registrations =
+        tournament_registrations::list_by_tournament(db, payout.tournament_id).await?;
+    for user_id in registrations
+        .iter()
+        .filter(|r| IN_FIELD.contains(&r.status.as_str()))
+        .filter_map(|r| r.user_id)
+    {
+        publish_user_notification(UserNotification {
+            id: ID::from(Uuid::new_v4().to_string()),
+            user_id: ID::from(user_id.to_string()),
+            notification_type: NotificationType::PayoutsFinalized,
+            title: TITLE_PAYOUTS_FINALIZED.to_string(),
+            message: message.clone(),
+            tournament_id: Some(ID::from(payout.tournament_id.to_string())),
+            created_at: Utc::now(),
+        });
+        let db = db.clone();
+        let tournament_id = payout.tournament_id;
+        tokio::spawn(async move {
+            crate::services::push_service::send_payouts_finalized(&db, user_id, tournament_id)
+                .await;
+        });
+    }
+    Ok(())
+}
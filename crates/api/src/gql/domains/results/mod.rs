@@ -1,3 +1,5 @@
+pub mod feed;
+pub mod finalization;
 pub mod resolvers;
 pub mod service;
 pub mod types;
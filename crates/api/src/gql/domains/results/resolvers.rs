@@ -2,18 +2,22 @@ use async_graphql::{dataloader::DataLoader, Context, Object, Result, ID};
 use std::collections::HashMap;
 
 use crate::gql::common::helpers::tournament_hidden_from_viewer;
+use crate::gql::domains::tournaments::lobby::publish_schedule_change;
 use crate::gql::error::{auth_error, ResultExt};
-use crate::gql::loaders::TournamentLoader;
+use crate::gql::loaders::{ClubPlayerLoader, TournamentLoader};
+use crate::gql::scalars::Money;
+use crate::gql::types::ClubTournamentEventType;
 use crate::state::AppState;
+use chrono::{DateTime, Utc};
 use infra::repos::{
-    tournament_payouts, tournament_results, tournament_results::UserStatistics, tournaments,
+    clubs, tournament_payouts, tournament_results, tournament_results::UserStatistics, tournaments,
 };
 use uuid::Uuid;
 
 use super::types::{
     CustomPayout, DealType, EnterTournamentResultsInput, EnterTournamentResultsResponse,
-    PayoutPosition, PlayerDeal, PlayerStatistics, PlayerStatsResponse, TournamentPayout,
-    TournamentResult, UserTournamentResult,
+    PlayerDeal, PlayerStatistics, PlayerStatsResponse, ResultsFeed, TournamentPayout,
+    TournamentResult, TournamentResultEvent, UserTournamentResult,
 };
 
 #[derive(Default)]
@@ -82,8 +86,8 @@ impl ResultQuery {
         let convert_stats = |stats: UserStatistics| PlayerStatistics {
             total_itm: stats.total_itm,
             total_tournaments: stats.total_tournaments,
-            total_winnings: stats.total_winnings,
-            total_buy_ins: stats.total_buy_ins,
+            total_winnings: stats.total_winnings.into(),
+            total_buy_ins: stats.total_buy_ins.into(),
             itm_percentage: stats.itm_percentage,
             roi_percentage: stats.roi_percentage,
         };
@@ -112,57 +116,10 @@ impl ResultQuery {
             return Ok(None);
         }
 
-        if let Some(payout_row) =
-            tournament_payouts::get_by_tournament(&state.db, tournament_id).await?
-        {
-            // Parse the JSONB payout_positions into structured data
-            let positions_array = payout_row
-                .payout_positions
-                .as_array()
-                .ok_or_else(|| async_graphql::Error::new("Invalid payout positions format"))?;
-
-            let mut positions = Vec::new();
-            for pos in positions_array {
-                let position = pos
-                    .get("position")
-                    .and_then(|v| v.as_i64())
-                    .ok_or_else(|| async_graphql::Error::new("Invalid position value"))?
-                    as i32;
-
-                let percentage = pos
-                    .get("percentage")
-                    .and_then(|v| v.as_f64())
-                    .ok_or_else(|| async_graphql::Error::new("Invalid percentage value"))?;
-
-                let amount_cents = pos
-                    .get("amount_cents")
-                    .and_then(|v| v.as_i64())
-                    .ok_or_else(|| async_graphql::Error::new("Invalid amount_cents value"))?
-                    as i32;
-
-                positions.push(PayoutPosition {
-                    position,
-                    percentage,
-                    amount_cents,
-                });
-            }
-
-            // Sort positions by position number
-            positions.sort_by_key(|p| p.position);
-
-            Ok(Some(TournamentPayout {
-                id: payout_row.id.into(),
-                tournament_id: payout_row.tournament_id.into(),
-                template_id: payout_row.template_id.map(|id| id.into()),
-                player_count: payout_row.player_count,
-                total_prize_pool: payout_row.total_prize_pool,
-                positions,
-                created_at: payout_row.created_at,
-                updated_at: payout_row.updated_at,
-            }))
-        } else {
-            Ok(None)
-        }
+        tournament_payouts::get_by_tournament(&state.db, tournament_id)
+            .await?
+            .map(TournamentPayout::try_from)
+            .transpose()
     }
 
     /// Get the recorded final results of a tournament (position, prize, points
@@ -186,6 +143,67 @@ impl ResultQuery {
         let rows = tournament_results::list_by_tournament(&state.db, tournament_id).await?;
         Ok(rows.into_iter().map(Into::into).collect())
     }
+
+    /// A club's finished tournaments with their places, in the structure
+    /// external results databases take submissions in (see `ResultsFeed.csv`
+    /// for the file form). `from`/`to` bound the start time, `to` exclusive.
+    /// Managers of the club only.
+    async fn results_feed(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<ResultsFeed> {
+        use crate::auth::permissions::require_club_manager;
+
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+
+        let state = ctx.data::<AppState>()?;
+        let club = clubs::get_by_id(&state.db, club_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Club not found"))?;
+        let events = tournament_results::list_feed_events(&state.db, club_id, from, to).await?;
+        let tournament_ids: Vec<Uuid> = events.iter().map(|e| e.tournament_id).collect();
+        let places = tournament_results::list_feed_places(&state.db, &tournament_ids).await?;
+
+        Ok(super::feed::build(club, events, places))
+    }
+}
+
+/// Announce entered results on the tournament's results channel, last place
+/// first so the winner comes out last. Names come from the roster.
+async fn publish_result_events(
+    ctx: &Context<'_>,
+    tournament_id: Uuid,
+    results: &[infra::models::TournamentResultRow],
+) {
+    let names = match ctx.data::<DataLoader<ClubPlayerLoader>>() {
+        Ok(loader) => loader
+            .load_many(results.iter().map(|r| r.club_player_id))
+            .await
+            .unwrap_or_default(),
+        Err(_) => Default::default(),
+    };
+
+    let mut ordered: Vec<_> = results.iter().collect();
+    ordered.sort_by_key(|r| std::cmp::Reverse(r.final_position));
+    for row in ordered {
+        crate::gql::subscriptions::publish_result_event(TournamentResultEvent {
+            tournament_id: tournament_id.into(),
+            result_id: row.id.into(),
+            club_player_id: row.club_player_id.into(),
+            user_id: row.user_id.map(Into::into),
+            display_name: names
+                .get(&row.club_player_id)
+                .map(|rp| rp.display_name.clone())
+                .unwrap_or_else(|| "Unknown".to_string()),
+            final_position: row.final_position,
+            prize_cents: row.prize_cents.into(),
+            points: row.points,
+        });
+    }
 }
 
 #[derive(Default)]
@@ -227,6 +245,9 @@ impl ResultMutation {
             .await
             .map_err(|e| async_graphql::Error::new(e.to_string()))?;
 
+        publish_result_events(ctx, tournament_id, &output.results).await;
+        publish_schedule_change(ClubTournamentEventType::StatusChanged, &output.tournament);
+
         // Convert results to GQL types
         let results: Vec<super::types::TournamentResult> =
             output.results.into_iter().map(|r| r.into()).collect();
@@ -242,8 +263,8 @@ impl ResultMutation {
                 for (user_id, amount) in payouts_obj {
                     let amount_cents = amount
                         .as_i64()
-                        .ok_or_else(|| async_graphql::Error::new("Invalid payout amount"))?
-                        as i32;
+                        .map(Money)
+                        .ok_or_else(|| async_graphql::Error::new("Invalid payout amount"))?;
                     custom_payouts_vec.push(CustomPayout {
                         user_id: user_id.clone().into(),
                         amount_cents,
@@ -267,7 +288,7 @@ impl ResultMutation {
                 deal_type,
                 affected_positions: deal_row.affected_positions,
                 custom_payouts,
-                total_amount_cents: deal_row.total_amount_cents,
+                total_amount_cents: deal_row.total_amount_cents.into(),
                 notes: deal_row.notes,
                 created_by: deal_row.created_by.into(),
             })
@@ -280,6 +301,11 @@ impl ResultMutation {
             let db = state.db.clone();
             let player_count = results.len();
             let newly_unlocked_achievements = output.newly_unlocked_achievements.clone();
+            let winner = results
+                .iter()
+                .find(|r| r.final_position == 1)
+                .and_then(|r| r.user_id.as_ref())
+                .and_then(|id| Uuid::parse_str(id.as_str()).ok());
             tokio::spawn(async move {
                 // Log activity
                 crate::gql::domains::activity_log::log_and_publish(
@@ -293,6 +319,16 @@ impl ResultMutation {
                 )
                 .await;
 
+                if let Some(winner) = winner {
+                    crate::gql::domains::social::rail::notify_followers(
+                        &db,
+                        winner,
+                        tournament_id,
+                        crate::gql::domains::social::rail::RailEvent::Won,
+                    )
+                    .await;
+                }
+
                 // Publish achievement unlocked notifications
                 for (user_id, achievement) in newly_unlocked_achievements {
                     let notification = crate::gql::types::UserNotification {
@@ -336,10 +372,93 @@ impl ResultMutation {
             });
         }
 
+        // The payout sheet is ready to print as soon as the results are in.
+        crate::gql::domains::printouts::service::generate_logged(
+            &state.db,
+            tournament_id,
+            &[crate::gql::domains::printouts::types::PrintoutKind::PayoutSheet],
+            Some(manager_id),
+        )
+        .await;
+        // So is the recap for the club's website and socials.
+        crate::gql::domains::recaps::service::publish_logged(&state.db, tournament_id).await;
+        // Players these results qualify are registered in their finals.
+        crate::gql::domains::qualifications::service::register_for_finals_logged(
+            &state.db,
+            tournament_id,
+        )
+        .await;
+
         Ok(EnterTournamentResultsResponse {
             success: true,
             results,
             deal: gql_deal,
         })
     }
+
+    /// Make the current payout table the official one ahead of the automatic
+    /// freeze when late registration closes, and announce it to the field.
+    async fn finalize_tournament_payouts(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<TournamentPayout> {
+        use crate::auth::permissions::require_club_manager;
+
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let tournament = tournaments::get_by_id(&state.db, tournament_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        let manager = require_club_manager(ctx, tournament.club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).ok();
+
+        let payout = super::finalization::finalize(&state.db, tournament_id, manager_id)
+            .await?
+            .ok_or_else(|| {
+                async_graphql::Error::new("No open payout table to finalize for this tournament")
+            })?;
+        TournamentPayout::try_from(payout)
+    }
+
+    /// Manager override: unfreeze finalized payouts so entries can be
+    /// corrected; the table follows the entries again until finalized anew.
+    async fn reopen_tournament_payouts(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<TournamentPayout> {
+        use crate::auth::permissions::require_club_manager;
+
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let tournament = tournaments::get_by_id(&state.db, tournament_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        let manager = require_club_manager(ctx, tournament.club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).ok();
+
+        if !tournament_payouts::reopen(&state.db, tournament_id).await? {
+            return Err(async_graphql::Error::new(
+                "Payouts for this tournament are not finalized",
+            ));
+        }
+        crate::gql::domains::activity_log::log_and_publish(
+            &state.db,
+            tournament_id,
+            "payouts",
+            "reopened",
+            manager_id,
+            None,
+            serde_json::json!({}),
+        )
+        .await;
+
+        let payout = tournament_payouts::get_by_tournament(&state.db, tournament_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Payout table not found"))?;
+        TournamentPayout::try_from(payout)
+    }
 }
@@ -19,6 +19,8 @@ pub struct EnterResultsParams {
 
 /// Output of the enter-results workflow.
 pub struct EnterResultsOutput {
+    /// The tournament, now finished.
+    pub tournament: infra::models::TournamentRow,
     pub results: Vec<infra::models::TournamentResultRow>,
     pub deal: Option<infra::models::PlayerDealRow>,
     pub newly_unlocked_achievements: Vec<(
@@ -67,7 +69,10 @@ pub async fn enter_tournament_results(
     // calculate_payouts already dumps any rounding remainder on the last paid
     // position, so this mainly catches a non-zero pool with nothing paid out
     // (e.g. a missing/empty payout template) before we persist anything.
-    let payout_total: i32 = payouts.iter().sum();
+    let payout_total = payouts
+        .iter()
+        .try_fold(0i64, |acc, p| acc.checked_add(*p))
+        .ok_or("Payout total overflows")?;
     if payout_total != total_prize_pool {
         return Err(format!(
             "Payout reconciliation failed: distributed {payout_total} cents but prize pool is {total_prize_pool} cents"
@@ -85,7 +90,9 @@ pub async fn enter_tournament_results(
             for payout in custom {
                 payouts_map.insert(
                     payout.user_id.to_string(),
-                    serde_json::Value::Number(serde_json::Number::from(payout.amount_cents)),
+                    serde_json::Value::Number(serde_json::Number::from(
+                        payout.amount_cents.cents(),
+                    )),
                 );
             }
             Some(serde_json::Value::Object(payouts_map))
@@ -93,7 +100,8 @@ pub async fn enter_tournament_results(
             None
         };
 
-        let total_deal_amount = calculate_deal_total(deal_input, &payouts);
+        let total_deal_amount =
+            calculate_deal_total(deal_input, &payouts).ok_or("Deal total overflows")?;
 
         let deal_data = CreatePlayerDeal {
             tournament_id: params.tournament_id,
@@ -137,12 +145,13 @@ pub async fn enter_tournament_results(
     // transaction so the results, the (optional) deal, and the FINISHED
     // transition commit atomically — a manager can never end up with recorded
     // results on a tournament still stuck at FINAL_TABLE.
-    tournaments::update_live_status(
+    let tournament = tournaments::update_live_status(
         &mut *tx,
         params.tournament_id,
         tournaments::TournamentLiveStatus::Finished,
     )
-    .await?;
+    .await?
+    .ok_or("Tournament not found")?;
 
     // Evaluate achievements for each player
     let mut newly_unlocked_achievements: Vec<(
@@ -180,6 +189,7 @@ pub async fn enter_tournament_results(
     tx.commit().await?;
 
     Ok(EnterResultsOutput {
+        tournament,
         results,
         deal,
         newly_unlocked_achievements,
@@ -192,9 +202,9 @@ async fn calculate_payouts(
     db: &sqlx::PgPool,
     template_id: Option<&ID>,
     positions: &[PlayerPositionInput],
-    total_prize_pool: i32,
+    total_prize_pool: i64,
     deal: Option<&PlayerDealInput>,
-) -> Result<Vec<i32>, Box<dyn std::error::Error + Send + Sync>> {
+) -> Result<Vec<i64>, Box<dyn std::error::Error + Send + Sync>> {
     let mut payouts = vec![0; positions.len()];
 
     if let Some(deal_input) = deal {
@@ -230,7 +240,7 @@ async fn calculate_payouts(
                         total_prize_pool
                     };
 
-                    let per_player = affected_total / deal_input.affected_positions.len() as i32;
+                    let per_player = affected_total / deal_input.affected_positions.len() as i64;
 
                     for position in positions {
                         if deal_input
@@ -256,7 +266,7 @@ async fn calculate_payouts(
                                 if custom.user_id == position.user_id {
                                     let index = (position.final_position - 1) as usize;
                                     if index < payouts.len() {
-                                        payouts[index] = custom.amount_cents;
+                                        payouts[index] = custom.amount_cents.cents();
                                     }
                                 }
                             }
@@ -285,7 +295,7 @@ async fn calculate_payouts(
                             let index = (position.final_position - 1) as usize;
                             if index < payouts.len() {
                                 payouts[index] =
-                                    ((total_prize_pool as f64 * percentage / 100.0).round()) as i32;
+                                    ((total_prize_pool as f64 * percentage / 100.0).round()) as i64;
                             }
                         }
                     }
@@ -308,7 +318,7 @@ async fn calculate_payouts(
                         let index = (position.final_position - 1) as usize;
                         if index < payouts.len() {
                             payouts[index] =
-                                ((total_prize_pool as f64 * percentage / 100.0).round()) as i32;
+                                ((total_prize_pool as f64 * percentage / 100.0).round()) as i64;
                         }
                     }
                 }
@@ -317,11 +327,18 @@ async fn calculate_payouts(
     }
 
     // Adjust for rounding remainder
-    let payout_sum: i32 = payouts.iter().sum();
-    let remainder = total_prize_pool - payout_sum;
+    let payout_sum = payouts
+        .iter()
+        .try_fold(0i64, |acc, p| acc.checked_add(*p))
+        .ok_or("Payout total overflows")?;
+    let remainder = total_prize_pool
+        .checked_sub(payout_sum)
+        .ok_or("Payout total overflows")?;
     if remainder != 0 {
         if let Some(last_paid) = payouts.iter().rposition(|&p| p > 0) {
-            payouts[last_paid] += remainder;
+            payouts[last_paid] = payouts[last_paid]
+                .checked_add(remainder)
+                .ok_or("Payout total overflows")?;
         }
     }
 
@@ -332,8 +349,8 @@ async fn calculate_template_total(
     db: &sqlx::PgPool,
     template_id: &ID,
     affected_positions: &[i32],
-    total_prize_pool: i32,
-) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
+    total_prize_pool: i64,
+) -> Result<i64, Box<dyn std::error::Error + Send + Sync>> {
     let template_id_uuid =
         Uuid::parse_str(template_id.as_str()).map_err(|_| "Invalid template ID")?;
 
@@ -347,7 +364,7 @@ async fn calculate_template_total(
             }
         }
 
-        Ok(((total_prize_pool as f64 * total_percentage / 100.0).round()) as i32)
+        Ok(((total_prize_pool as f64 * total_percentage / 100.0).round()) as i64)
     } else {
         Ok(total_prize_pool)
     }
@@ -373,8 +390,8 @@ async fn calculate_icm_payouts(
     template_id: Option<&ID>,
     positions: &[PlayerPositionInput],
     deal_input: &PlayerDealInput,
-    total_prize_pool: i32,
-) -> Result<Option<Vec<(usize, i32)>>, Box<dyn std::error::Error + Send + Sync>> {
+    total_prize_pool: i64,
+) -> Result<Option<Vec<(usize, i64)>>, Box<dyn std::error::Error + Send + Sync>> {
     let Some(template_id) = template_id else {
         return Ok(None);
     };
@@ -415,18 +432,21 @@ async fn calculate_icm_payouts(
 
     // Prize for each affected finishing place, largest first — ICM assigns the
     // top remaining prize to whoever "wins" at each level of the recursion.
-    let mut place_prizes: Vec<i32> = deal_input
+    let mut place_prizes: Vec<i64> = deal_input
         .affected_positions
         .iter()
         .map(|p| {
             get_position_percentage(&payout_structure, *p)
-                .map(|pct| (total_prize_pool as f64 * pct / 100.0).round() as i32)
+                .map(|pct| (total_prize_pool as f64 * pct / 100.0).round() as i64)
                 .unwrap_or(0)
         })
         .collect();
     place_prizes.sort_unstable_by(|a, b| b.cmp(a));
 
-    let affected_total: i32 = place_prizes.iter().sum();
+    let affected_total = place_prizes
+        .iter()
+        .try_fold(0i64, |acc, p| acc.checked_add(*p))
+        .ok_or("Payout total overflows")?;
     if affected_total <= 0 {
         return Ok(None);
     }
@@ -440,8 +460,8 @@ async fn calculate_icm_payouts(
     let euros_total = affected_total / 100;
     let sub_euro = affected_total - euros_total * 100;
     let raw_euros: Vec<f64> = equities.iter().map(|cents| cents / 100.0).collect();
-    let mut floor_euros: Vec<i32> = raw_euros.iter().map(|e| e.floor() as i32).collect();
-    let assigned: i32 = floor_euros.iter().sum();
+    let mut floor_euros: Vec<i64> = raw_euros.iter().map(|e| e.floor() as i64).collect();
+    let assigned: i64 = floor_euros.iter().sum();
 
     // Hand out the remaining whole euros to the largest fractional parts.
     let mut order: Vec<usize> = (0..n).collect();
@@ -459,7 +479,7 @@ async fn calculate_icm_payouts(
         k += 1;
     }
 
-    let mut cents: Vec<i32> = floor_euros.iter().map(|e| e * 100).collect();
+    let mut cents: Vec<i64> = floor_euros.iter().map(|e| e * 100).collect();
     if sub_euro != 0 {
         let leader = stacks
             .iter()
@@ -515,25 +535,20 @@ fn icm_equity(stacks: &[f64], prizes: &[f64]) -> Vec<f64> {
     equity
 }
 
-fn calculate_deal_total(deal_input: &PlayerDealInput, payouts: &[i32]) -> i32 {
+/// Sum of the amounts covered by the deal, or `None` if it overflows.
+fn calculate_deal_total(deal_input: &PlayerDealInput, payouts: &[i64]) -> Option<i64> {
     match deal_input.deal_type {
-        DealType::Custom => {
-            if let Some(custom_payouts) = &deal_input.custom_payouts {
-                custom_payouts.iter().map(|p| p.amount_cents).sum()
-            } else {
-                0
-            }
-        }
-        _ => {
-            let mut total = 0;
-            for position in &deal_input.affected_positions {
-                let index = (*position - 1) as usize;
-                if index < payouts.len() {
-                    total += payouts[index];
-                }
-            }
-            total
-        }
+        DealType::Custom => match &deal_input.custom_payouts {
+            Some(custom_payouts) => custom_payouts
+                .iter()
+                .try_fold(0i64, |acc, p| acc.checked_add(p.amount_cents.cents())),
+            None => Some(0),
+        },
+        _ => deal_input
+            .affected_positions
+            .iter()
+            .filter_map(|position| payouts.get((*position - 1) as usize))
+            .try_fold(0i64, |acc, p| acc.checked_add(*p)),
     }
 }
 
@@ -6,7 +6,9 @@ use uuid::Uuid;
 use crate::gql::domains::users::types::User;
 use crate::gql::error::ResultExt;
 use crate::gql::loaders::{ClubPlayerLoader, UserLoader};
-use crate::gql::types::Tournament;
+use crate::gql::scalars::Money;
+use crate::gql::types::{RegistrationStatus, Tournament};
+use infra::repos::tournament_results::UserTournamentHistoryRow;
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -18,7 +20,7 @@ pub struct TournamentResult {
     /// The club roster identity — always present.
     pub club_player_id: ID,
     pub final_position: i32,
-    pub prize_cents: i32,
+    pub prize_cents: Money,
     pub points: i32,
     pub notes: Option<String>,
     pub created_at: DateTime<Utc>,
@@ -32,7 +34,7 @@ impl From<infra::models::TournamentResultRow> for TournamentResult {
             user_id: row.user_id.map(Into::into),
             club_player_id: row.club_player_id.into(),
             final_position: row.final_position,
-            prize_cents: row.prize_cents,
+            prize_cents: row.prize_cents.into(),
             points: row.points,
             notes: row.notes,
             created_at: row.created_at,
@@ -69,12 +71,66 @@ impl TournamentResult {
     }
 }
 
+/// One finishing place, published as results are entered so a venue screen
+/// can announce it ("3rd place: Jane — €540") without refetching.
+#[derive(SimpleObject, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct TournamentResultEvent {
+    pub tournament_id: ID,
+    pub result_id: ID,
+    pub club_player_id: ID,
+    pub user_id: Option<ID>,
+    /// Roster name, as the venue knows the player.
+    pub display_name: String,
+    pub final_position: i32,
+    pub prize_cents: Money,
+    pub points: i32,
+}
+
 #[derive(SimpleObject, Clone)]
 pub struct UserTournamentResult {
     pub result: TournamentResult,
     pub tournament: Tournament,
 }
 
+/// One event in a player's tournament history: registration, money in,
+/// finish and points in one row.
+#[derive(SimpleObject, Clone)]
+pub struct TournamentHistoryEntry {
+    pub tournament: Tournament,
+    pub registration_id: ID,
+    pub registration_status: RegistrationStatus,
+    pub registered_at: DateTime<Utc>,
+    /// Buy-ins, rebuys, add-ons and re-entries recorded for the player.
+    pub entry_count: i32,
+    pub entries_total_cents: Money,
+    /// Players who took part, for "12th of 48".
+    pub field_size: i32,
+    /// Null until results are entered.
+    pub final_position: Option<i32>,
+    pub prize_cents: Option<Money>,
+    pub points: Option<i32>,
+    /// Prize minus entries.
+    pub net_cents: Money,
+}
+
+impl TournamentHistoryEntry {
+    pub fn new(row: UserTournamentHistoryRow, tournament: Tournament) -> Self {
+        Self {
+            tournament,
+            registration_id: row.registration_id.into(),
+            registration_status: row.registration_status.into(),
+            registered_at: row.registered_at,
+            entry_count: row.entry_count as i32,
+            entries_total_cents: row.entries_total_cents.into(),
+            field_size: row.field_size as i32,
+            final_position: row.final_position,
+            prize_cents: row.prize_cents.map(Into::into),
+            points: row.points,
+            net_cents: (row.prize_cents.unwrap_or(0) - row.entries_total_cents).into(),
+        }
+    }
+}
+
 #[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
 pub enum DealType {
     EvenSplit,
@@ -85,7 +141,7 @@ pub enum DealType {
 #[derive(SimpleObject, Clone)]
 pub struct CustomPayout {
     pub user_id: ID,
-    pub amount_cents: i32,
+    pub amount_cents: Money,
 }
 
 #[derive(SimpleObject, Clone)]
@@ -95,7 +151,7 @@ pub struct PlayerDeal {
     pub deal_type: DealType,
     pub affected_positions: Vec<i32>,
     pub custom_payouts: Option<Vec<CustomPayout>>,
-    pub total_amount_cents: i32,
+    pub total_amount_cents: Money,
     pub notes: Option<String>,
     pub created_by: ID,
 }
@@ -129,7 +185,7 @@ pub struct PlayerDealInput {
 #[derive(InputObject)]
 pub struct CustomPayoutInput {
     pub user_id: ID,
-    pub amount_cents: i32,
+    pub amount_cents: Money,
 }
 
 #[derive(InputObject)]
@@ -149,8 +205,8 @@ pub struct EnterTournamentResultsResponse {
 pub struct PlayerStatistics {
     pub total_itm: i32,
     pub total_tournaments: i32,
-    pub total_winnings: i32,
-    pub total_buy_ins: i32,
+    pub total_winnings: Money,
+    pub total_buy_ins: Money,
     pub itm_percentage: f64,
     pub roi_percentage: f64,
 }
@@ -167,7 +223,7 @@ pub struct PlayerStatsResponse {
 pub struct PayoutPosition {
     pub position: i32,
     pub percentage: f64,
-    pub amount_cents: i32,
+    pub amount_cents: Money,
 }
 
 #[derive(SimpleObject, Clone)]
@@ -176,8 +232,106 @@ pub struct TournamentPayout {
     pub tournament_id: ID,
     pub template_id: Option<ID>,
     pub player_count: i32,
-    pub total_prize_pool: i32,
+    pub total_prize_pool: Money,
     pub positions: Vec<PayoutPosition>,
+    /// When the table became the official one; until then it follows the
+    /// entries.
+    pub finalized_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+impl TryFrom<infra::models::TournamentPayoutRow> for TournamentPayout {
+    type Error = async_graphql::Error;
+
+    fn try_from(row: infra::models::TournamentPayoutRow) -> Result<Self, Self::Error> {
+        // Parse the JSONB payout_positions into structured data
+        let positions_array = row
+            .payout_positions
+            .as_array()
+            .ok_or_else(|| async_graphql::Error::new("Invalid payout positions format"))?;
+
+        let mut positions = Vec::new();
+        for pos in positions_array {
+            let position = pos
+                .get("position")
+                .and_then(|v| v.as_i64())
+                .ok_or_else(|| async_graphql::Error::new("Invalid position value"))?
+                as i32;
+
+            let percentage = pos
+                .get("percentage")
+                .and_then(|v| v.as_f64())
+                .ok_or_else(|| async_graphql::Error::new("Invalid percentage value"))?;
+
+            let amount_cents = pos
+                .get("amount_cents")
+                .and_then(|v| v.as_i64())
+                .map(Money)
+                .ok_or_else(|| async_graphql::Error::new("Invalid amount_cents value"))?;
+
+            positions.push(PayoutPosition {
+                position,
+                percentage,
+                amount_cents,
+            });
+        }
+
+        // Sort positions by position number
+        positions.sort_by_key(|p| p.position);
+
+        Ok(Self {
+            id: row.id.into(),
+            tournament_id: row.tournament_id.into(),
+            template_id: row.template_id.map(|id| id.into()),
+            player_count: row.player_count,
+            total_prize_pool: row.total_prize_pool.into(),
+            positions,
+            finalized_at: row.finalized_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+/// A club's finalized results in the shape external results databases
+/// (Hendon Mob and similar) take submissions in.
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct ResultsFeed {
+    pub club_name: String,
+    pub city: Option<String>,
+    pub country: Option<String>,
+    pub events: Vec<ResultsFeedEvent>,
+}
+
+#[ComplexObject]
+impl ResultsFeed {
+    /// The feed as CSV, one line per finishing place (see `feed::render_csv`).
+    async fn csv(&self) -> async_graphql::Result<String> {
+        super::feed::render_csv(self).map_err(|e| async_graphql::Error::new(e.to_string()))
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct ResultsFeedEvent {
+    pub tournament_id: ID,
+    pub name: String,
+    pub start_time: DateTime<Utc>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub buy_in_cents: Money,
+    pub rake_cents: Money,
+    /// Distinct players.
+    pub entrants: i32,
+    /// Buy-ins, rebuys and re-entries.
+    pub total_entries: i32,
+    pub prize_pool_cents: Money,
+    pub places: Vec<ResultsFeedPlace>,
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct ResultsFeedPlace {
+    pub position: i32,
+    pub player_name: String,
+    pub prize_cents: Money,
+}
@@ -0,0 +1,145 @@
+use async_graphql::{Context, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::auth::jwt::Claims;
+use crate::auth::permissions::require_club_manager;
+use crate::gql::error::ResultExt;
+use crate::state::AppState;
+use infra::repos::retention::{self, RetentionPolicyRow, SetRetentionPolicy};
+
+use super::service;
+use super::types::{
+    ClubRetentionPolicy, RetentionLine, RetentionMode, RetentionReport, SetClubRetentionPolicyInput,
+};
+
+/// Longest window a policy may set: 10 years.
+const MAX_MONTHS: i32 = 120;
+
+async fn require_policy(state: &AppState, club_id: Uuid) -> Result<RetentionPolicyRow> {
+    retention::get_policy(&state.db, club_id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("This club has no retention policy"))
+}
+
+async fn report(
+    state: &AppState,
+    policy: &RetentionPolicyRow,
+    dry_run: bool,
+) -> Result<RetentionReport> {
+    let lines = service::apply_policy(&state.db, policy, dry_run).await?;
+    Ok(RetentionReport {
+        club_id: policy.club_id.into(),
+        mode: RetentionMode::from_db(&policy.mode),
+        dry_run,
+        lines: lines
+            .into_iter()
+            .map(|line| RetentionLine {
+                record: line.record.into(),
+                months: line.months,
+                rows: line.rows,
+                exempt_rows: line.exempt_rows,
+            })
+            .collect(),
+    })
+}
+
+#[derive(Default)]
+pub struct RetentionQuery;
+
+#[Object]
+impl RetentionQuery {
+    /// The club's retention policy; `null` keeps everything (managers only).
+    async fn club_retention_policy(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+    ) -> Result<Option<ClubRetentionPolicy>> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let row = retention::get_policy(&state.db, club_id).await?;
+        Ok(row.map(ClubRetentionPolicy::from))
+    }
+
+    /// Dry run: how many records the policy would anonymize or purge now,
+    /// and how many financial ones it keeps (managers only).
+    async fn club_retention_report(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+    ) -> Result<RetentionReport> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let policy = require_policy(state, club_id).await?;
+        report(state, &policy, true).await
+    }
+}
+
+#[derive(Default)]
+pub struct RetentionMutation;
+
+#[Object]
+impl RetentionMutation {
+    /// Set how long the club keeps activity, broadcasts and chat. Applied
+    /// by the data-retention job (managers only).
+    async fn set_club_retention_policy(
+        &self,
+        ctx: &Context<'_>,
+        input: SetClubRetentionPolicyInput,
+    ) -> Result<ClubRetentionPolicy> {
+        let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        let claims = ctx.data::<Claims>()?;
+        let manager_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+
+        for months in [
+            input.activity_log_months,
+            input.announcement_months,
+            input.chat_message_months,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if !(1..=MAX_MONTHS).contains(&months) {
+                return Err(async_graphql::Error::new(format!(
+                    "Retention windows must be between 1 and {MAX_MONTHS} months"
+                )));
+            }
+        }
+
+        let state = ctx.data::<AppState>()?;
+        let row = retention::upsert_policy(
+            &state.db,
+            club_id,
+            &SetRetentionPolicy {
+                mode: input.mode.as_db().to_string(),
+                activity_log_months: input.activity_log_months,
+                announcement_months: input.announcement_months,
+                chat_message_months: input.chat_message_months,
+            },
+            manager_id,
+        )
+        .await?;
+        Ok(row.into())
+    }
+
+    /// Apply the club's policy now instead of waiting for the job
+    /// (managers only).
+    async fn apply_club_retention(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+    ) -> Result<RetentionReport> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let policy = require_policy(state, club_id).await?;
+        let report = report(state, &policy, false).await?;
+        tracing::info!(%club_id, "Applied club retention policy");
+        Ok(report)
+    }
+}
@@ -0,0 +1,70 @@
+//! Applying a club's retention policy, shared by the manager's
+//! `applyClubRetention` and the scheduled data-retention job.
+//!
+//! Each batch runs in its own short transaction; a policy is worked down
+//! batch by batch until nothing is due, so a large backlog never holds one
+//! long lock on the activity log.
+
+use sqlx::PgPool;
+
+use infra::repos::retention::{self, RetainedRecord, RetentionPolicyRow};
+
+/// Rows changed per transaction.
+pub const BATCH_LIMIT: i64 = 500;
+
+/// One kind of record under the policy and the rows it changed (or would).
+pub struct AppliedLine {
+    pub record: RetainedRecord,
+    pub months: i32,
+    pub rows: i64,
+    pub exempt_rows: i64,
+}
+
+/// Report what `policy` makes due (`dry_run`) or apply it. Kinds without
+/// a window are skipped.
+pub async fn apply_policy(
+    db: &PgPool,
+    policy: &RetentionPolicyRow,
+    dry_run: bool,
+) -> Result<Vec<AppliedLine>, sqlx::Error> {
+    let purge = policy.mode == "purge";
+    let mut lines = Vec::new();
+    for record in RetainedRecord::ALL {
+        let Some(months) = record.months(policy) else {
+            continue;
+        };
+        let exempt_rows = match record {
+            RetainedRecord::ActivityLog => {
+                retention::count_exempt_activity(db, policy.club_id, months).await?
+            }
+            _ => 0,
+        };
+        let rows = if dry_run {
+            retention::count_due(db, record, policy.club_id, months, purge).await?
+        } else {
+            let mut changed = 0;
+            loop {
+                let mut tx = db.begin().await?;
+                let batch =
+                    retention::apply(&mut tx, record, policy.club_id, months, purge, BATCH_LIMIT)
+                        .await?;
+                tx.commit().await?;
+                changed += batch as i64;
+                if (batch as i64) < BATCH_LIMIT {
+                    break;
+                }
+            }
+            changed
+        };
+        lines.push(AppliedLine {
+            record,
+            months,
+            rows,
+            exempt_rows,
+        });
+    }
+    if !dry_run {
+        retention::mark_applied(db, policy.club_id).await?;
+    }
+    Ok(lines)
+}
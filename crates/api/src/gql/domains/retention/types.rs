@@ -0,0 +1,110 @@
+use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use infra::repos::retention::{RetainedRecord, RetentionPolicyRow};
+
+/// What happens to records past their window.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum RetentionMode {
+    /// Keep the rows, drop who they were by and about.
+    Anonymize,
+    /// Delete the rows.
+    Purge,
+}
+
+impl RetentionMode {
+    pub fn as_db(self) -> &'static str {
+        match self {
+            RetentionMode::Anonymize => "anonymize",
+            RetentionMode::Purge => "purge",
+        }
+    }
+
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "purge" => RetentionMode::Purge,
+            _ => RetentionMode::Anonymize,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum RetainedRecordKind {
+    /// Tournament activity, except entries and results.
+    ActivityLog,
+    /// Sent club and tournament broadcasts.
+    Announcements,
+    /// Tournament chat.
+    ChatMessages,
+}
+
+impl From<RetainedRecord> for RetainedRecordKind {
+    fn from(record: RetainedRecord) -> Self {
+        match record {
+            RetainedRecord::ActivityLog => RetainedRecordKind::ActivityLog,
+            RetainedRecord::Announcements => RetainedRecordKind::Announcements,
+            RetainedRecord::ChatMessages => RetainedRecordKind::ChatMessages,
+        }
+    }
+}
+
+/// How long a club keeps its operational records. A `null` window keeps
+/// that kind forever.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct ClubRetentionPolicy {
+    pub club_id: ID,
+    pub mode: RetentionMode,
+    pub activity_log_months: Option<i32>,
+    pub announcement_months: Option<i32>,
+    pub chat_message_months: Option<i32>,
+    /// Last time the retention job applied it.
+    pub last_applied_at: Option<DateTime<Utc>>,
+    pub updated_by: Option<ID>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<RetentionPolicyRow> for ClubRetentionPolicy {
+    fn from(row: RetentionPolicyRow) -> Self {
+        Self {
+            club_id: row.club_id.into(),
+            mode: RetentionMode::from_db(&row.mode),
+            activity_log_months: row.activity_log_months,
+            announcement_months: row.announcement_months,
+            chat_message_months: row.chat_message_months,
+            last_applied_at: row.last_applied_at,
+            updated_by: row.updated_by.map(Into::into),
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct SetClubRetentionPolicyInput {
+    pub club_id: ID,
+    #[graphql(default_with = "RetentionMode::Anonymize")]
+    pub mode: RetentionMode,
+    pub activity_log_months: Option<i32>,
+    pub announcement_months: Option<i32>,
+    pub chat_message_months: Option<i32>,
+}
+
+/// One kind of record under a club's policy.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct RetentionLine {
+    pub record: RetainedRecordKind,
+    pub months: i32,
+    /// Rows past the window the policy changes: still to change on a dry
+    /// run, changed otherwise.
+    pub rows: i64,
+    /// Rows past the window kept because they are financially relevant.
+    pub exempt_rows: i64,
+}
+
+#[derive(SimpleObject, Clone, Debug)]
+pub struct RetentionReport {
+    pub club_id: ID,
+    pub mode: RetentionMode,
+    pub dry_run: bool,
+    /// Kinds without a window are left out.
+    pub lines: Vec<RetentionLine>,
+}
@@ -0,0 +1,163 @@
+use async_graphql::{Context, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::auth::jwt::Claims;
+use crate::auth::permissions::require_club_manager;
+use crate::gql::common::helpers::get_club_id_for_tournament;
+use crate::gql::error::ResultExt;
+use crate::state::AppState;
+use infra::repos::{
+    rule_documents,
+    rule_documents::{CreateRuleDocument, UpdateRuleDocument},
+};
+
+use super::types::{
+    CreateRuleDocumentInput, RuleAcknowledgment, RuleDocument, UpdateRuleDocumentInput,
+};
+
+async fn get_document(state: &AppState, id: &ID) -> Result<rule_documents::RuleDocumentRow> {
+    let id = Uuid::parse_str(id.as_str()).gql_err("Invalid rule document ID")?;
+    rule_documents::get_by_id(&state.db, id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Rule document not found"))
+}
+
+fn non_empty(value: &str, field: &str) -> Result<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(async_graphql::Error::new(format!("A {field} is required")));
+    }
+    Ok(value.to_string())
+}
+
+#[derive(Default)]
+pub struct RuleDocumentQuery;
+
+#[Object]
+impl RuleDocumentQuery {
+    /// A club's rule documents and disclosures, club-wide and per-tournament,
+    /// newest first.
+    async fn club_rule_documents(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+        #[graphql(default = true)] active_only: bool,
+    ) -> Result<Vec<RuleDocument>> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        let state = ctx.data::<AppState>()?;
+
+        let rows = rule_documents::list_by_club(&state.db, club_id, active_only).await?;
+        Ok(rows.into_iter().map(RuleDocument::from).collect())
+    }
+
+    /// Acknowledgments players gave when registering (managers only).
+    async fn rule_acknowledgments(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Vec<RuleAcknowledgment>> {
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let rows =
+            rule_documents::list_acknowledgments_for_tournament(&state.db, tournament_id).await?;
+        Ok(rows.into_iter().map(RuleAcknowledgment::from).collect())
+    }
+}
+
+#[derive(Default)]
+pub struct RuleDocumentMutation;
+
+#[Object]
+impl RuleDocumentMutation {
+    /// Attach a rules document or disclosure to a club or one of its
+    /// tournaments (managers only).
+    async fn create_rule_document(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateRuleDocumentInput,
+    ) -> Result<RuleDocument> {
+        let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        let claims = ctx.data::<Claims>()?;
+        let created_by = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+        let state = ctx.data::<AppState>()?;
+
+        let tournament_id = match input.tournament_id {
+            Some(id) => {
+                let id = Uuid::parse_str(id.as_str()).gql_err("Invalid tournament ID")?;
+                if get_club_id_for_tournament(&state.db, id).await? != club_id {
+                    return Err(async_graphql::Error::new(
+                        "Tournament belongs to a different club",
+                    ));
+                }
+                Some(id)
+            }
+            None => None,
+        };
+
+        let row = rule_documents::create(
+            &state.db,
+            CreateRuleDocument {
+                club_id,
+                tournament_id,
+                kind: input.kind.as_db().to_string(),
+                title: non_empty(&input.title, "title")?,
+                body_markdown: non_empty(&input.body_markdown, "body")?,
+                requires_acknowledgment: input.requires_acknowledgment,
+                created_by,
+            },
+        )
+        .await?;
+        Ok(row.into())
+    }
+
+    /// Edit a document (managers only). Changing the title or body publishes
+    /// a new version that players registering afterwards must acknowledge.
+    async fn update_rule_document(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateRuleDocumentInput,
+    ) -> Result<RuleDocument> {
+        let state = ctx.data::<AppState>()?;
+        let document = get_document(state, &input.id).await?;
+        require_club_manager(ctx, document.club_id).await?;
+
+        let row = rule_documents::update(
+            &state.db,
+            document.id,
+            UpdateRuleDocument {
+                title: input
+                    .title
+                    .as_deref()
+                    .map(|t| non_empty(t, "title"))
+                    .transpose()?,
+                body_markdown: input
+                    .body_markdown
+                    .as_deref()
+                    .map(|b| non_empty(b, "body"))
+                    .transpose()?,
+                requires_acknowledgment: input.requires_acknowledgment,
+            },
+        )
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Rule document not found"))?;
+        Ok(row.into())
+    }
+
+    /// Retire a document so it no longer shows on tournaments or gates
+    /// registration (managers only).
+    async fn archive_rule_document(&self, ctx: &Context<'_>, id: ID) -> Result<RuleDocument> {
+        let state = ctx.data::<AppState>()?;
+        let document = get_document(state, &id).await?;
+        require_club_manager(ctx, document.club_id).await?;
+
+        let row = rule_documents::set_active(&state.db, document.id, false)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Rule document not found"))?;
+        Ok(row.into())
+    }
+}
@@ -0,0 +1,111 @@
+use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use infra::repos::rule_documents::{RuleAcknowledgmentRow, RuleDocumentRow};
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum RuleDocumentKind {
+    /// House or tournament rules.
+    Rules,
+    /// Disclosures (fees, rake, data use, ...).
+    Disclosure,
+}
+
+impl RuleDocumentKind {
+    pub fn as_db(self) -> &'static str {
+        match self {
+            RuleDocumentKind::Rules => "rules",
+            RuleDocumentKind::Disclosure => "disclosure",
+        }
+    }
+
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "disclosure" => RuleDocumentKind::Disclosure,
+            _ => RuleDocumentKind::Rules,
+        }
+    }
+}
+
+/// A markdown rules document or disclosure attached to a club or to a single
+/// tournament.
+#[derive(SimpleObject, Clone)]
+pub struct RuleDocument {
+    pub id: ID,
+    pub club_id: ID,
+    /// Unset for club-wide documents, which apply to every tournament.
+    pub tournament_id: Option<ID>,
+    pub kind: RuleDocumentKind,
+    pub title: String,
+    pub body_markdown: String,
+    /// Bumped whenever the title or body changes.
+    pub version: i32,
+    /// Players must acknowledge this document to register.
+    pub requires_acknowledgment: bool,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<RuleDocumentRow> for RuleDocument {
+    fn from(row: RuleDocumentRow) -> Self {
+        Self {
+            id: row.id.into(),
+            club_id: row.club_id.into(),
+            tournament_id: row.tournament_id.map(Into::into),
+            kind: RuleDocumentKind::from_db(&row.kind),
+            title: row.title,
+            body_markdown: row.body_markdown,
+            version: row.version,
+            requires_acknowledgment: row.requires_acknowledgment,
+            is_active: row.is_active,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+/// A player's acknowledgment of one version of a document.
+#[derive(SimpleObject, Clone)]
+pub struct RuleAcknowledgment {
+    pub id: ID,
+    pub rule_document_id: ID,
+    pub document_version: i32,
+    pub tournament_id: ID,
+    pub user_id: ID,
+    pub acknowledged_at: DateTime<Utc>,
+}
+
+impl From<RuleAcknowledgmentRow> for RuleAcknowledgment {
+    fn from(row: RuleAcknowledgmentRow) -> Self {
+        Self {
+            id: row.id.into(),
+            rule_document_id: row.rule_document_id.into(),
+            document_version: row.document_version,
+            tournament_id: row.tournament_id.into(),
+            user_id: row.user_id.into(),
+            acknowledged_at: row.acknowledged_at,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreateRuleDocumentInput {
+    pub club_id: ID,
+    /// Attach to a single tournament; omit for a club-wide document.
+    pub tournament_id: Option<ID>,
+    #[graphql(default_with = "RuleDocumentKind::Rules")]
+    pub kind: RuleDocumentKind,
+    pub title: String,
+    pub body_markdown: String,
+    #[graphql(default = true)]
+    pub requires_acknowledgment: bool,
+}
+
+#[derive(InputObject)]
+pub struct UpdateRuleDocumentInput {
+    pub id: ID,
+    pub title: Option<String>,
+    pub body_markdown: Option<String>,
+    pub requires_acknowledgment: Option<bool>,
+}
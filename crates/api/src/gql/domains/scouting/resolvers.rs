@@ -69,7 +69,7 @@ impl ScoutingQuery {
             best_finish: r.stats.best_finish,
             shares_pnl: r.shares_pnl,
             net_cents: if r.shares_pnl {
-                Some(r.stats.net_cents as i32)
+                Some(r.stats.net_cents.into())
             } else {
                 None
             },
@@ -96,15 +96,30 @@ pub struct ScoutingMutation;
 impl ScoutingMutation {
     /// Update the current user's consent flags. Both are granular and explicit
     /// (G4): the client must send each value, there is no implied bundling.
+    /// `appearInPhotos`, added later, keeps its current value when omitted.
     async fn update_privacy_settings(
         &self,
         ctx: &Context<'_>,
         share_named_pl: bool,
         in_scouting_pool: bool,
+        appear_in_photos: Option<bool>,
     ) -> Result<PrivacySettings> {
         let state = ctx.data::<AppState>()?;
         let user_id = current_user_id(ctx)?;
-        let row = privacy::upsert(&state.db, user_id, share_named_pl, in_scouting_pool).await?;
+        let appear_in_photos = match appear_in_photos {
+            Some(consent) => consent,
+            None => privacy::get(&state.db, user_id)
+                .await?
+                .is_some_and(|s| s.appear_in_photos),
+        };
+        let row = privacy::upsert(
+            &state.db,
+            user_id,
+            share_named_pl,
+            in_scouting_pool,
+            appear_in_photos,
+        )
+        .await?;
         Ok(PrivacySettings::from(row))
     }
 }
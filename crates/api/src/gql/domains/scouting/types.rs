@@ -1,6 +1,8 @@
 use async_graphql::{SimpleObject, ID};
 
-/// A user's privacy/consent settings. Both flags default OFF (G4 / GDPR Art.25 —
+use crate::gql::scalars::Money;
+
+/// A user's privacy/consent settings. Every flag defaults OFF (G4 / GDPR Art.25 —
 /// privacy by default); they are independent — opting into discoverability never
 /// implies sharing P/L.
 #[derive(SimpleObject, Clone, Debug, Default)]
@@ -9,6 +11,8 @@ pub struct PrivacySettings {
     pub share_named_pl: bool,
     /// Consent to be discoverable in opponent lookup (exposes performance stats).
     pub in_scouting_pool: bool,
+    /// Consent to club photos you are tagged in being shown publicly.
+    pub appear_in_photos: bool,
 }
 
 impl From<infra::models::UserPrivacySettingsRow> for PrivacySettings {
@@ -16,6 +20,7 @@ impl From<infra::models::UserPrivacySettingsRow> for PrivacySettings {
         Self {
             share_named_pl: r.share_named_pl,
             in_scouting_pool: r.in_scouting_pool,
+            appear_in_photos: r.appear_in_photos,
         }
     }
 }
@@ -49,7 +54,7 @@ pub struct ScoutingProfile {
     /// Whether this player shares identifiable profit/loss.
     pub shares_pnl: bool,
     /// Net profit/loss in cents — null unless `shares_pnl`.
-    pub net_cents: Option<i32>,
+    pub net_cents: Option<Money>,
 }
 
 /// The searcher's free-lookup quota standing.
@@ -0,0 +1,373 @@
+use async_graphql::{Context, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::auth::jwt::Claims;
+use crate::gql::common::helpers::get_club_id_for_tournament;
+use crate::gql::domains::seating::conflicts;
+use crate::gql::error::ResultExt;
+use crate::gql::subscriptions::{publish_seating_event, publish_user_notification};
+use crate::gql::types::{
+    ApproveSeatChangeInput, DeclineSeatChangeInput, NotificationType, RequestSeatChangeInput,
+    SeatAssignment, SeatChangeRequest, SeatChangeRequestStatus, SeatingChangeEvent,
+    SeatingEventType, User, UserNotification, TITLE_SEAT_CHANGE_APPROVED,
+    TITLE_SEAT_CHANGE_DECLINED, TITLE_SEAT_CHANGE_REQUESTED,
+};
+use crate::state::AppState;
+use infra::repos::{
+    closed_seats, club_managers, club_players, club_tables, seat_change_requests,
+    seat_change_requests::CreateSeatChangeRequest, table_seat_assignments,
+    tournament_registrations, users,
+};
+
+/// In-app notification to a player about their own request, gated on the
+/// seating_updates preference like every other seating notification.
+async fn notify_player(
+    state: &AppState,
+    user_id: Uuid,
+    tournament_id: Uuid,
+    notification_type: NotificationType,
+    title: &str,
+    message: String,
+) {
+    let prefs = infra::repos::notification_preferences::get_for_user(&state.db, user_id)
+        .await
+        .unwrap_or_default();
+    if prefs.seating_updates {
+        publish_user_notification(UserNotification {
+            id: ID::from(Uuid::new_v4().to_string()),
+            user_id: ID::from(user_id.to_string()),
+            notification_type,
+            title: title.to_string(),
+            message,
+            tournament_id: Some(ID::from(tournament_id.to_string())),
+            created_at: chrono::Utc::now(),
+        });
+    }
+}
+
+#[derive(Default)]
+pub struct SeatChangeQuery;
+
+#[Object]
+impl SeatChangeQuery {
+    /// Seat change requests for a tournament, oldest first (managers only).
+    /// Defaults to the pending queue.
+    async fn seat_change_requests(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        #[graphql(default_with = "Some(SeatChangeRequestStatus::Pending)")] status: Option<
+            SeatChangeRequestStatus,
+        >,
+    ) -> Result<Vec<SeatChangeRequest>> {
+        use crate::auth::permissions::require_club_manager;
+
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let rows = seat_change_requests::list_by_tournament(
+            &state.db,
+            tournament_id,
+            status.map(SeatChangeRequestStatus::as_db),
+        )
+        .await?;
+        Ok(rows.into_iter().map(SeatChangeRequest::from).collect())
+    }
+}
+
+#[derive(Default)]
+pub struct SeatChangeMutation;
+
+#[Object]
+impl SeatChangeMutation {
+    /// Ask the floor to move the current user to another seat. The user must be
+    /// seated in the tournament and can have only one pending request.
+    async fn request_seat_change(
+        &self,
+        ctx: &Context<'_>,
+        input: RequestSeatChangeInput,
+    ) -> Result<SeatChangeRequest> {
+        let claims = ctx.data::<Claims>()?;
+        let user_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+
+        let reason = input.reason.trim();
+        if reason.is_empty() {
+            return Err(async_graphql::Error::new("A reason is required"));
+        }
+
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        let roster = club_players::find_by_club_and_app_user(&state.db, club_id, user_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Player is not on this club's roster"))?;
+        let club_player_id = roster.id;
+
+        let seat = table_seat_assignments::list_current_for_tournament(&state.db, tournament_id)
+            .await?
+            .into_iter()
+            .find(|a| a.club_player_id == club_player_id)
+            .ok_or_else(|| async_graphql::Error::new("You are not seated in this tournament"))?;
+
+        let row = seat_change_requests::create(
+            &state.db,
+            CreateSeatChangeRequest {
+                tournament_id,
+                club_player_id,
+                user_id,
+                reason: reason.to_string(),
+                from_club_table_id: seat.club_table_id,
+                from_seat_number: seat.seat_number,
+            },
+        )
+        .await?
+        .ok_or_else(|| {
+            async_graphql::Error::new("You already have a pending seat change request")
+        })?;
+
+        // Let the floor know there's something in the queue.
+        for manager in club_managers::list_by_club(&state.db, club_id).await? {
+            publish_user_notification(UserNotification {
+                id: ID::from(Uuid::new_v4().to_string()),
+                user_id: ID::from(manager.user_id.to_string()),
+                notification_type: NotificationType::SeatChangeRequested,
+                title: TITLE_SEAT_CHANGE_REQUESTED.to_string(),
+                message: format!(
+                    "{} (seat {}): {reason}",
+                    roster.display_name, seat.seat_number
+                ),
+                tournament_id: Some(ID::from(tournament_id.to_string())),
+                created_at: chrono::Utc::now(),
+            });
+        }
+
+        Ok(row.into())
+    }
+
+    /// Approve a pending request by moving the player to the given seat
+    /// (managers only).
+    async fn approve_seat_change(
+        &self,
+        ctx: &Context<'_>,
+        input: ApproveSeatChangeInput,
+    ) -> Result<SeatChangeRequest> {
+        use crate::auth::permissions::require_club_manager;
+
+        let state = ctx.data::<AppState>()?;
+        let request_id =
+            Uuid::parse_str(input.request_id.as_str()).gql_err("Invalid request ID")?;
+        let new_club_table_id =
+            Uuid::parse_str(input.new_club_table_id.as_str()).gql_err("Invalid table ID")?;
+
+        let request = seat_change_requests::get_by_id(&state.db, request_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Seat change request not found"))?;
+        let tournament_id = request.tournament_id;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid manager ID")?;
+
+        if request.status != "pending" {
+            return Err(async_graphql::Error::new(
+                "Seat change request has already been resolved",
+            ));
+        }
+
+        // Claim the request and move the player together: a failed move
+        // leaves the request pending.
+        let mut tx = state
+            .db
+            .begin()
+            .await
+            .gql_err("Failed to begin transaction")?;
+
+        // Lock the target table's place in the tournament, so it can't be
+        // broken or resized under the move, and check the seat is on it.
+        let seats =
+            club_tables::lock_seats_in_tournament(&mut *tx, tournament_id, new_club_table_id)
+                .await?
+                .ok_or_else(|| {
+                    async_graphql::Error::new("Target table is not in play in this tournament")
+                })?;
+        if !(1..=seats).contains(&input.new_seat_number) {
+            return Err(async_graphql::Error::new(format!(
+                "Target seat must be between 1 and {seats}"
+            )));
+        }
+        conflicts::ensure_seat_free(
+            &mut *tx,
+            new_club_table_id,
+            input.new_seat_number,
+            "Target seat is already occupied",
+        )
+        .await?;
+        if closed_seats::is_closed(
+            &mut *tx,
+            tournament_id,
+            new_club_table_id,
+            input.new_seat_number,
+        )
+        .await?
+        {
+            return Err(async_graphql::Error::new("Target seat is closed"));
+        }
+
+        // Claim the request before moving so two managers can't both act on it.
+        let resolved = seat_change_requests::resolve(
+            &mut *tx,
+            request_id,
+            SeatChangeRequestStatus::Approved.as_db(),
+            None,
+            manager_id,
+        )
+        .await?
+        .ok_or_else(|| {
+            async_graphql::Error::new("Seat change request has already been resolved")
+        })?;
+
+        let assignment_row = table_seat_assignments::move_player(
+            &mut tx,
+            tournament_id,
+            request.club_player_id,
+            new_club_table_id,
+            input.new_seat_number,
+            Some(manager_id),
+            Some(format!("Seat change request: {}", request.reason)),
+        )
+        .await?;
+        let user_id = request.user_id;
+        tournament_registrations::update_status(&mut *tx, tournament_id, user_id, "seated").await?;
+        tx.commit().await.gql_err("Failed to commit transaction")?;
+
+        let player = users::get_by_id(&state.db, user_id).await?;
+        let assignment: SeatAssignment = assignment_row.into();
+        publish_seating_event(SeatingChangeEvent {
+            event_type: SeatingEventType::PlayerMoved,
+            tournament_id: assignment.tournament_id.clone(),
+            club_id: club_id.into(),
+            affected_assignment: Some(assignment.clone()),
+            affected_player: player.map(User::from),
+            message: format!(
+                "Player moved to seat {} (seat change request)",
+                assignment.seat_number
+            ),
+            timestamp: chrono::Utc::now(),
+        });
+
+        notify_player(
+            state,
+            user_id,
+            tournament_id,
+            NotificationType::SeatChangeApproved,
+            TITLE_SEAT_CHANGE_APPROVED,
+            format!(
+                "Your seat change was approved: you have been moved to seat {}",
+                assignment.seat_number
+            ),
+        )
+        .await;
+        {
+            let db = state.db.clone();
+            tokio::spawn(async move {
+                crate::services::push_service::send_seating_event(
+                    &db,
+                    user_id,
+                    "PLAYER_MOVED",
+                    tournament_id,
+                )
+                .await;
+            });
+        }
+
+        {
+            let db = state.db.clone();
+            let seat_num = assignment.seat_number;
+            tokio::spawn(async move {
+                crate::gql::domains::activity_log::log_and_publish(
+                    &db,
+                    tournament_id,
+                    "seating",
+                    "seat_change_approved",
+                    Some(manager_id),
+                    Some(user_id),
+                    serde_json::json!({"request_id": request_id, "seat_number": seat_num}),
+                )
+                .await;
+            });
+        }
+
+        Ok(resolved.into())
+    }
+
+    /// Decline a pending request with a reason shown to the player (managers
+    /// only).
+    async fn decline_seat_change(
+        &self,
+        ctx: &Context<'_>,
+        input: DeclineSeatChangeInput,
+    ) -> Result<SeatChangeRequest> {
+        use crate::auth::permissions::require_club_manager;
+
+        let state = ctx.data::<AppState>()?;
+        let request_id =
+            Uuid::parse_str(input.request_id.as_str()).gql_err("Invalid request ID")?;
+
+        let request = seat_change_requests::get_by_id(&state.db, request_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Seat change request not found"))?;
+        let tournament_id = request.tournament_id;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid manager ID")?;
+
+        let reason = input.reason.trim();
+        if reason.is_empty() {
+            return Err(async_graphql::Error::new("A decline reason is required"));
+        }
+
+        let resolved = seat_change_requests::resolve(
+            &state.db,
+            request_id,
+            SeatChangeRequestStatus::Declined.as_db(),
+            Some(reason),
+            manager_id,
+        )
+        .await?
+        .ok_or_else(|| {
+            async_graphql::Error::new("Seat change request has already been resolved")
+        })?;
+
+        let user_id = resolved.user_id;
+        notify_player(
+            state,
+            user_id,
+            tournament_id,
+            NotificationType::SeatChangeDeclined,
+            TITLE_SEAT_CHANGE_DECLINED,
+            format!("Your seat change request was declined: {reason}"),
+        )
+        .await;
+
+        {
+            let db = state.db.clone();
+            tokio::spawn(async move {
+                crate::gql::domains::activity_log::log_and_publish(
+                    &db,
+                    tournament_id,
+                    "seating",
+                    "seat_change_declined",
+                    Some(manager_id),
+                    Some(user_id),
+                    serde_json::json!({"request_id": request_id}),
+                )
+                .await;
+            });
+        }
+
+        Ok(resolved.into())
+    }
+}
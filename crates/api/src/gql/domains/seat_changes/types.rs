@@ -0,0 +1,89 @@
+use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use infra::repos::seat_change_requests::SeatChangeRequestRow;
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum SeatChangeRequestStatus {
+    Pending,
+    Approved,
+    Declined,
+}
+
+impl SeatChangeRequestStatus {
+    pub fn as_db(self) -> &'static str {
+        match self {
+            SeatChangeRequestStatus::Pending => "pending",
+            SeatChangeRequestStatus::Approved => "approved",
+            SeatChangeRequestStatus::Declined => "declined",
+        }
+    }
+}
+
+impl From<String> for SeatChangeRequestStatus {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "approved" => SeatChangeRequestStatus::Approved,
+            "declined" => SeatChangeRequestStatus::Declined,
+            _ => SeatChangeRequestStatus::Pending,
+        }
+    }
+}
+
+/// A seated player's request to be moved, and how the floor resolved it.
+#[derive(SimpleObject, Clone)]
+pub struct SeatChangeRequest {
+    pub id: ID,
+    pub tournament_id: ID,
+    pub club_player_id: ID,
+    pub user_id: ID,
+    pub reason: String,
+    /// Table the player was seated at when they asked.
+    pub from_club_table_id: ID,
+    pub from_seat_number: i32,
+    pub status: SeatChangeRequestStatus,
+    pub decline_reason: Option<String>,
+    pub resolved_by: Option<ID>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<SeatChangeRequestRow> for SeatChangeRequest {
+    fn from(row: SeatChangeRequestRow) -> Self {
+        Self {
+            id: row.id.into(),
+            tournament_id: row.tournament_id.into(),
+            club_player_id: row.club_player_id.into(),
+            user_id: row.user_id.into(),
+            reason: row.reason,
+            from_club_table_id: row.from_club_table_id.into(),
+            from_seat_number: row.from_seat_number,
+            status: row.status.into(),
+            decline_reason: row.decline_reason,
+            resolved_by: row.resolved_by.map(Into::into),
+            resolved_at: row.resolved_at,
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct RequestSeatChangeInput {
+    pub tournament_id: ID,
+    /// Why the player wants to move (e.g. an accessibility need).
+    pub reason: String,
+}
+
+#[derive(InputObject)]
+pub struct ApproveSeatChangeInput {
+    pub request_id: ID,
+    pub new_club_table_id: ID,
+    pub new_seat_number: i32,
+}
+
+#[derive(InputObject)]
+pub struct DeclineSeatChangeInput {
+    pub request_id: ID,
+    /// Shown to the player.
+    pub reason: String,
+}
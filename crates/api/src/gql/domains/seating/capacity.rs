@@ -0,0 +1,262 @@
+//! Capacity planning: how many tables a tournament needs at the start and
+//! once late registration closes, and when the final table forms.
+//!
+//! The field is modelled as shrinking exponentially: every minute the same
+//! share of the remaining players busts. That rate, and the share of players
+//! who register late, come from the club's past tournaments. Late entrants
+//! are spread evenly over the late-registration window.
+//!
+//! This module is pure (no DB) so the projection is unit-testable; the
+//! resolver loads the history, structure and table sizes and calls `plan`.
+
+use infra::repos::capacity_planning::FieldHistoryRow;
+
+/// Elimination rate without usable history: half the field every two hours.
+pub const DEFAULT_HALF_LIFE_MINUTES: f64 = 120.0;
+
+/// Seats per table when the club has no tables set up.
+pub const DEFAULT_SEATS_PER_TABLE: i32 = 9;
+
+/// What the club's past tournaments say about fields.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FieldRates {
+    /// Share of the remaining players busting per minute (exponential rate).
+    pub elimination_per_minute: f64,
+    /// Share of the players who register after the start.
+    pub late_entry_share: f64,
+    /// Past tournaments the rates were measured on; 0 means defaults.
+    pub tournaments: usize,
+}
+
+/// Pool the club's history into rates. Tournaments without eliminations or
+/// entrants are skipped; without any usable one, the defaults apply and no
+/// late entries are assumed.
+pub fn field_rates(history: &[FieldHistoryRow]) -> FieldRates {
+    let mut log_shrink = 0.0;
+    let mut minutes = 0.0;
+    let mut entrants = 0;
+    let mut late_entrants = 0;
+    let mut tournaments = 0;
+    for row in history {
+        let Some(elapsed) = row.minutes_to_last_bust.filter(|m| *m > 0.0) else {
+            continue;
+        };
+        if row.entrants < 2 || row.busts < 1 {
+            continue;
+        }
+        let remaining = (row.entrants - row.busts).max(1);
+        log_shrink += (row.entrants as f64 / remaining as f64).ln();
+        minutes += elapsed;
+        entrants += row.entrants;
+        late_entrants += row.late_entrants.min(row.entrants);
+        tournaments += 1;
+    }
+    if tournaments == 0 || log_shrink <= 0.0 {
+        return FieldRates {
+            elimination_per_minute: std::f64::consts::LN_2 / DEFAULT_HALF_LIFE_MINUTES,
+            late_entry_share: 0.0,
+            tournaments: 0,
+        };
+    }
+    FieldRates {
+        elimination_per_minute: log_shrink / minutes,
+        late_entry_share: late_entrants as f64 / entrants as f64,
+        tournaments,
+    }
+}
+
+/// A level of the structure: its number and length.
+#[derive(Debug, Clone, Copy)]
+pub struct PlannedLevel {
+    pub number: i32,
+    pub minutes: i32,
+}
+
+/// The projection, in minutes from the start.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Projection {
+    pub players_at_start: i32,
+    pub tables_at_start: i32,
+    /// Most tables in play at once (late entrants arriving while others bust).
+    pub peak_tables: i32,
+    /// When late registration closes; `None` without late registration.
+    pub late_registration_minutes: Option<i32>,
+    pub players_after_late_registration: i32,
+    pub tables_after_late_registration: i32,
+    pub final_table_minutes: i32,
+    /// The level being played when the final table forms; `None` past the
+    /// end of the structure.
+    pub final_table_level: Option<i32>,
+}
+
+fn tables_for(players: f64, seats: i32) -> i32 {
+    (players.round() as i32 + seats - 1)
+        .div_euclid(seats)
+        .max(1)
+}
+
+/// Minutes until the end of `level`; `None` when the structure doesn't reach
+/// it.
+fn minutes_through(levels: &[PlannedLevel], level: i32) -> Option<i32> {
+    levels.iter().any(|l| l.number == level).then(|| {
+        levels
+            .iter()
+            .filter(|l| l.number <= level)
+            .map(|l| l.minutes)
+            .sum()
+    })
+}
+
+/// The level being played `minutes` after the start.
+fn level_at(levels: &[PlannedLevel], minutes: i32) -> Option<i32> {
+    let mut elapsed = 0;
+    for level in levels {
+        elapsed += level.minutes;
+        if minutes < elapsed {
+            return Some(level.number);
+        }
+    }
+    None
+}
+
+/// Project a tournament of `expected_entries` players at tables of `seats`,
+/// with late registration through `late_registration_level`.
+pub fn plan(
+    expected_entries: i32,
+    seats: i32,
+    rates: FieldRates,
+    levels: &[PlannedLevel],
+    late_registration_level: Option<i32>,
+) -> Projection {
+    let seats = seats.max(2);
+    let entries = expected_entries.max(0) as f64;
+    let lambda = rates.elimination_per_minute;
+    let late_minutes = late_registration_level.and_then(|level| minutes_through(levels, level));
+    // Without a late-registration window everyone is there at the start.
+    let late_share = if late_minutes.is_some_and(|m| m > 0) {
+        rates.late_entry_share
+    } else {
+        0.0
+    };
+    let on_time = entries * (1.0 - late_share);
+    let late = entries * late_share;
+    let window = late_minutes.unwrap_or(0) as f64;
+
+    // Players in play `t` minutes in, during late registration.
+    let players_at = |t: f64| {
+        let arrived = if window > 0.0 {
+            late / (lambda * window) * (1.0 - (-lambda * t).exp())
+        } else {
+            0.0
+        };
+        on_time * (-lambda * t).exp() + arrived
+    };
+
+    let peak = (0..=window as i32)
+        .map(|t| players_at(f64::from(t)))
+        .fold(on_time, f64::max);
+    let after_late_reg = players_at(window).max(1.0);
+
+    let final_table_minutes = if after_late_reg > seats as f64 {
+        window + (after_late_reg / seats as f64).ln() / lambda
+    } else {
+        // Down to one table by the time registration closes: the first
+        // minute it is.
+        (0..=window as i32)
+            .map(f64::from)
+            .find(|&t| players_at(t) <= seats as f64)
+            .unwrap_or(window)
+    }
+    .round() as i32;
+
+    Projection {
+        players_at_start: on_time.round() as i32,
+        tables_at_start: tables_for(on_time, seats),
+        peak_tables: tables_for(peak, seats),
+        late_registration_minutes: late_minutes,
+        players_after_late_registration: after_late_reg.round() as i32,
+        tables_after_late_registration: tables_for(after_late_reg, seats),
+        final_table_minutes,
+        final_table_level: level_at(levels, final_table_minutes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn history(entrants: i64, late: i64, busts: i64, minutes: Option<f64>) -> FieldHistoryRow {
+        FieldHistoryRow {
+            tournament_id: Uuid::nil(),
+            started_at: Utc::now(),
+            entrants,
+            late_entrants: late,
+            busts,
+            minutes_to_last_bust: minutes,
+        }
+    }
+
+    fn levels(count: i32, minutes: i32) -> Vec<PlannedLevel> {
+        (1..=count)
+            .map(|number| PlannedLevel { number, minutes })
+            .collect()
+    }
+
+    #[test]
+    fn pools_the_history() {
+        // 80 → 10 in 180 min and 40 → 5 in 180 min: both shrink 8x.
+        let rates = field_rates(&[
+            history(80, 20, 70, Some(180.0)),
+            history(40, 0, 35, Some(180.0)),
+            history(30, 5, 0, None),
+        ]);
+        assert_eq!(rates.tournaments, 2);
+        assert!((rates.elimination_per_minute - 8f64.ln() / 180.0).abs() < 1e-9);
+        assert!((rates.late_entry_share - 20.0 / 120.0).abs() < 1e-9);
+
+        let defaults = field_rates(&[]);
+        assert_eq!(defaults.tournaments, 0);
+        assert_eq!(defaults.late_entry_share, 0.0);
+    }
+
+    #[test]
+    fn plans_without_late_registration() {
+        let rates = FieldRates {
+            elimination_per_minute: std::f64::consts::LN_2 / 60.0,
+            late_entry_share: 0.5,
+            tournaments: 3,
+        };
+        let plan = plan(72, 9, rates, &levels(20, 30), None);
+        assert_eq!(plan.players_at_start, 72);
+        assert_eq!(plan.tables_at_start, 8);
+        assert_eq!(plan.peak_tables, 8);
+        assert_eq!(plan.late_registration_minutes, None);
+        assert_eq!(plan.tables_after_late_registration, 8);
+        // 72 → 9 halves three times: 180 minutes, into level 7.
+        assert_eq!(plan.final_table_minutes, 180);
+        assert_eq!(plan.final_table_level, Some(7));
+    }
+
+    #[test]
+    fn plans_with_late_registration() {
+        let rates = FieldRates {
+            elimination_per_minute: 0.002,
+            late_entry_share: 0.25,
+            tournaments: 5,
+        };
+        let plan = plan(100, 10, rates, &levels(12, 20), Some(6));
+        assert_eq!(plan.players_at_start, 75);
+        assert_eq!(plan.tables_at_start, 8);
+        assert_eq!(plan.late_registration_minutes, Some(120));
+        // Late entrants outpace the busts, so the room fills up during late
+        // registration: 75·e^(-0.24) ≈ 59 starters plus ≈ 22 late entrants.
+        assert_eq!(plan.peak_tables, 9);
+        assert_eq!(plan.players_after_late_registration, 81);
+        assert_eq!(plan.tables_after_late_registration, 9);
+        // Past the last level of the structure.
+        assert!(plan.final_table_minutes > 240);
+        assert_eq!(plan.final_table_level, None);
+    }
+}
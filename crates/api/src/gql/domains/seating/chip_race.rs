@@ -0,0 +1,104 @@
+//! Color-up chip race.
+//!
+//! When a level removes the smallest chip from play, every stack has to be
+//! expressed in the new minimum denomination. How the odd chips are settled is
+//! configured per tournament (`ChipRaceRule`). Whatever the rule, a player who
+//! still has chips is never raced out: a non-empty stack keeps at least one
+//! chip of the new denomination.
+//!
+//! This module is pure (no DB) so the rounding rules are unit-testable in
+//! isolation; `color_up_table` is the only thing the resolver calls.
+
+use crate::gql::domains::tournaments::types::ChipRaceRule;
+
+/// Color up one table's stacks (in seat order) to `denomination`.
+///
+/// With `ChipRaceRule::Race`, the odd chips of the whole table are pooled and
+/// converted to `round(pool / denomination)` new chips, which go one each to
+/// the players holding the most odd chips (ties to the lower seat). This is
+/// the deterministic equivalent of dealing the race: no player wins more than
+/// one chip, and the table total moves by at most half a chip.
+pub fn color_up_table(stacks: &[i32], denomination: i32, rule: ChipRaceRule) -> Vec<i32> {
+    if denomination <= 1 {
+        return stacks.to_vec();
+    }
+    let d = denomination;
+
+    let mut out: Vec<i32> = match rule {
+        ChipRaceRule::RoundUp => stacks
+            .iter()
+            .map(|&s| s - s % d + if s % d > 0 { d } else { 0 })
+            .collect(),
+        ChipRaceRule::RoundNearest => stacks
+            .iter()
+            .map(|&s| {
+                let rem = s % d;
+                s - rem + if rem * 2 >= d { d } else { 0 }
+            })
+            .collect(),
+        ChipRaceRule::Race => {
+            let mut out: Vec<i32> = stacks.iter().map(|&s| s - s % d).collect();
+            let pool: i64 = stacks.iter().map(|&s| (s % d) as i64).sum();
+            let won = ((pool + (d as i64) / 2) / d as i64) as usize;
+
+            let mut order: Vec<usize> = (0..stacks.len()).filter(|&i| stacks[i] % d > 0).collect();
+            order.sort_by(|&a, &b| (stacks[b] % d).cmp(&(stacks[a] % d)).then(a.cmp(&b)));
+            for &i in order.iter().take(won) {
+                out[i] += d;
+            }
+            out
+        }
+    };
+
+    // Never race a player out of the tournament.
+    for (new, &old) in out.iter_mut().zip(stacks) {
+        if old > 0 && *new == 0 {
+            *new = d;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn race_awards_one_chip_to_the_largest_odd_amounts() {
+        // Odd chips 25 + 50 + 75 = 150 → two new 100 chips, to seats 3 then 2.
+        let out = color_up_table(&[1025, 2050, 75, 3000], 100, ChipRaceRule::Race);
+        assert_eq!(out, vec![1000, 2100, 100, 3000]);
+    }
+
+    #[test]
+    fn race_never_busts_a_player() {
+        // Pool of 50 wins one chip (seat 1); seat 2 still keeps a single chip.
+        let out = color_up_table(&[30, 20, 5000], 100, ChipRaceRule::Race);
+        assert_eq!(out, vec![100, 100, 5000]);
+    }
+
+    #[test]
+    fn rounding_rules() {
+        let stacks = [1025, 1050, 1075, 0];
+        assert_eq!(
+            color_up_table(&stacks, 100, ChipRaceRule::RoundUp),
+            vec![1100, 1100, 1100, 0]
+        );
+        assert_eq!(
+            color_up_table(&stacks, 100, ChipRaceRule::RoundNearest),
+            vec![1000, 1100, 1100, 0]
+        );
+    }
+
+    #[test]
+    fn already_colored_stacks_are_untouched() {
+        let stacks = [500, 12_000, 3_300];
+        for rule in [
+            ChipRaceRule::Race,
+            ChipRaceRule::RoundUp,
+            ChipRaceRule::RoundNearest,
+        ] {
+            assert_eq!(color_up_table(&stacks, 100, rule), stacks.to_vec());
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Seat conflicts. A seat someone already sits in is refused with
+//! `extensions.code = SEAT_OCCUPIED` and the occupant under
+//! `extensions.occupant`, so a client can offer to swap the two players
+//! without looking the seat up again.
+//!
+//! The occupant is part of the API: clients read it as
+//!
+//! ```json
+//! {
+//!   "assignmentId": "…", "tournamentId": "…", "clubTableId": "…",
+//!   "seatNumber": 3, "userId": "…" | null, "clubPlayerId": "…",
+//!   "name": "…", "assignedAt": "2025-05-02T19:00:00Z"
+//! }
+//! ```
+//!
+//! with ids as strings and `assignedAt` in RFC 3339. Add fields freely;
+//! renaming or removing one breaks clients.
+
+use async_graphql::{Error, ErrorExtensions, Value};
+use chrono::{DateTime, Utc};
+use infra::repos::table_seat_assignments::{self, SeatOccupantRow};
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+/// The player in the way, as `extensions.occupant` spells it.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeatOccupant {
+    pub assignment_id: String,
+    /// Club tables are shared, so the seat may be taken in another
+    /// tournament; a swap only makes sense within the same one.
+    pub tournament_id: String,
+    pub club_table_id: String,
+    pub seat_number: i32,
+    /// Set when the occupant has an account.
+    pub user_id: Option<String>,
+    pub club_player_id: String,
+    /// Roster display name.
+    pub name: String,
+    pub assigned_at: DateTime<Utc>,
+}
+
+impl From<SeatOccupantRow> for SeatOccupant {
+    fn from(row: SeatOccupantRow) -> Self {
+        Self {
+            assignment_id: row.assignment_id.to_string(),
+            tournament_id: row.tournament_id.to_string(),
+            club_table_id: row.club_table_id.to_string(),
+            seat_number: row.seat_number,
+            user_id: row.user_id.map(|id| id.to_string()),
+            club_player_id: row.club_player_id.to_string(),
+            name: row.display_name,
+            assigned_at: row.assigned_at,
+        }
+    }
+}
+
+/// Refusal naming who sits in the seat.
+pub fn seat_occupied_error(message: &str, occupant: SeatOccupant) -> Error {
+    let message = format!("{message} by {}", occupant.name);
+    let occupant = serde_json::to_value(&occupant)
+        .ok()
+        .and_then(|json| Value::from_json(json).ok())
+        .unwrap_or(Value::Null);
+    Error::new(message).extend_with(|_, e| {
+        e.set("code", "SEAT_OCCUPIED");
+        e.set("occupant", occupant.clone());
+    })
+}
+
+/// Refuse with [`seat_occupied_error`] when someone currently sits in the
+/// seat.
+pub async fn ensure_seat_free<'e>(
+    executor: impl PgExecutor<'e>,
+    club_table_id: Uuid,
+    seat_number: i32,
+    message: &str,
+) -> async_graphql::Result<()> {
+    match table_seat_assignments::current_occupant(executor, club_table_id, seat_number).await? {
+        Some(occupant) => Err(seat_occupied_error(message, occupant.into())),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    #[test]
+    fn occupant_extension_shape_is_stable() {
+        let id = |n: u128| Uuid::from_u128(n);
+        let occupant = SeatOccupant::from(SeatOccupantRow {
+            assignment_id: id(1),
+            tournament_id: id(2),
+            club_table_id: id(3),
+            seat_number: 3,
+            user_id: None,
+            club_player_id: id(4),
+            display_name: "Alice".to_string(),
+            assigned_at: Utc.with_ymd_and_hms(2025, 5, 2, 19, 0, 0).unwrap(),
+        });
+
+        let error = seat_occupied_error("Seat is already occupied", occupant)
+            .into_server_error(async_graphql::Pos::default());
+        assert_eq!(error.message, "Seat is already occupied by Alice");
+        let extensions = error.extensions.expect("extensions");
+        assert_eq!(
+            extensions.get("code"),
+            Some(&Value::String("SEAT_OCCUPIED".into()))
+        );
+        assert_eq!(
+            extensions
+                .get("occupant")
+                .unwrap()
+                .clone()
+                .into_json()
+                .unwrap(),
+            json!({
+                "assignmentId": id(1).to_string(),
+                "tournamentId": id(2).to_string(),
+                "clubTableId": id(3).to_string(),
+                "seatNumber": 3,
+                "userId": null,
+                "clubPlayerId": id(4).to_string(),
+                "name": "Alice",
+                "assignedAt": "2025-05-02T19:00:00Z",
+            })
+        );
+    }
+}
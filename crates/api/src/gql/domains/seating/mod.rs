@@ -1,3 +1,30 @@
+//! Table assignments, the seat draw and rebalancing.
+//!
+//! A table's seats in a tournament are its assignment's
+//! `max_seats_override`, else the club table's size capped by the
+//! tournament's `seats_per_table`. The draw, auto-assign and balancing read
+//! sizes through `club_tables::list_assigned_to_tournament`; so should
+//! anything new.
+//!
+//! Seats closed late in an event (`tournament_closed_seats`) are skipped by
+//! every seat picker, and the chart balances on the seats left in play.
+//! `recommend_seat_closures` sizes every active table to
+//! `ceil(players / tables)`; balancing reopens all closures when it breaks a
+//! table.
+//!
+//! A table's floor status and notes live on its tournament assignment.
+//! They are informational and never change seating.
+//!
+//! Seating someone into a taken seat fails with `SEAT_OCCUPIED` and the
+//! occupant ([`conflicts`]).
+//!
+//! `swapSeats` trades two seated players in one transaction
+//! (`service::swap_seats`): both seats are vacated before either is
+//! refilled, and stacks travel with the players.
+
+pub mod capacity;
+pub mod chip_race;
+pub mod conflicts;
 pub mod resolvers;
 pub mod service;
 pub mod types;
@@ -2,37 +2,50 @@ use async_graphql::{Context, Object, Result, ID};
 use uuid::Uuid;
 
 use crate::auth::jwt::Claims;
+use crate::auth::permissions::require_viewer_or_display_for_tournament;
 use crate::gql::common::helpers::get_club_id_for_tournament;
+use crate::gql::domains::activity_log::types::ActivityLogEntry;
+use crate::gql::domains::social::rail;
+use crate::gql::domains::tournaments::clock::load_tournament_clock;
+use crate::gql::domains::tournaments::lobby::publish_schedule_change;
 use crate::gql::error::{auth_error, ResultExt};
-use crate::gql::subscriptions::{publish_seating_event, publish_user_notification};
+use crate::gql::subscriptions::{
+    publish_activity_event, publish_clock_update, publish_seating_event, publish_user_notification,
+};
 use crate::gql::types::{
     AssignPlayerToSeatInput, AssignTableToTournamentInput, AssignTablesToTournamentInput,
-    AutoSeatPlayerInput, BalanceTablesInput, MovePlayerInput, NotificationType, SeatAssignment,
-    SeatWithPlayer, SeatingChangeEvent, SeatingEventType, TableWithSeats, Tournament,
-    TournamentBounty, TournamentSeatingChart, TournamentTable, UnassignTableFromTournamentInput,
-    UnseatedPlayer, UpdateStackSizeInput, User, UserNotification, TITLE_PLAYER_ELIMINATED,
-    TITLE_PLAYER_MOVED, TITLE_SEAT_ASSIGNED,
+    AutoSeatPlayerInput, BalanceTablesInput, CapacityPlan, ClubTournamentEventType, ColorUpResult,
+    ColorUpTable, MovePlayerInput, NotificationType, RecordColorUpInput, SeatAssignment,
+    SeatClosureRecommendation, SeatWithPlayer, SeatingChangeEvent, SeatingEventType,
+    SetTableStatusInput, TableFloorStatus, TableSeatsInput, TableStatus, TableWithSeats,
+    Tournament, TournamentBounty, TournamentSeatingChart, TournamentTable,
+    UnassignTableFromTournamentInput, UnseatedPlayer, UpdateStackSizeInput, UpdateStackSizesInput,
+    User, UserNotification, TITLE_PLAYER_ELIMINATED, TITLE_PLAYER_MOVED, TITLE_SEAT_ASSIGNED,
 };
 use crate::state::AppState;
 use infra::repos::{
-    club_players, club_tables, table_seat_assignments,
+    capacity_planning, closed_seats, club_players, club_tables, color_ups, stack_history,
+    stack_history::StackSource, table_seat_assignments,
     table_seat_assignments::CreateSeatAssignment, table_seat_assignments::SeatAssignmentFilter,
     table_seat_assignments::UpdateSeatAssignment, tournament_bounties, tournament_registrations,
     tournaments, users,
 };
 
+use super::conflicts;
+
 #[derive(Default)]
 pub struct SeatingQuery;
 
 #[Object]
 impl SeatingQuery {
-    /// Get the current seating chart for a tournament
+    /// Get the current seating chart for a tournament (signed-in users and
+    /// the club's paired displays)
     async fn tournament_seating_chart(
         &self,
         ctx: &Context<'_>,
         tournament_id: Uuid,
     ) -> Result<TournamentSeatingChart> {
-        let _claims = ctx.data::<Claims>().map_err(|_| auth_error())?;
+        require_viewer_or_display_for_tournament(ctx, tournament_id).await?;
         let state = ctx.data::<AppState>()?;
 
         // Get tournament
@@ -42,16 +55,17 @@ impl SeatingQuery {
 
         let tournament: Tournament = tournament_row.into();
 
-        // Get all active tables for the tournament
-        let table_rows = club_tables::list_assigned_to_tournament(&state.db, tournament_id).await?;
+        // Tables, seats, closed seats, dealers and unseated players in one query
+        let chart = table_seat_assignments::seating_chart(&state.db, tournament_id).await?;
 
-        // For each table, get current seat assignments with player info
         let mut tables = Vec::new();
         let mut table_counts: std::collections::HashMap<Uuid, usize> =
             std::collections::HashMap::new();
         let mut caps: Vec<i32> = Vec::new();
-        for table_row in table_rows {
-            caps.push(table_row.max_seats);
+        for chart_table in chart.tables {
+            // Balance against the seats in play, not the table's size.
+            caps.push(chart_table.effective_seats());
+            let table_row = chart_table.table;
             let table = TournamentTable {
                 id: table_row.id.into(),
                 tournament_id: tournament_id.into(),
@@ -61,13 +75,8 @@ impl SeatingQuery {
                 created_at: table_row.created_at,
             };
 
-            let assignments_with_players =
-                table_seat_assignments::list_current_with_players_for_table(
-                    &state.db,
-                    table_row.id,
-                )
-                .await?;
-            let seats: Vec<SeatWithPlayer> = assignments_with_players
+            let seats: Vec<SeatWithPlayer> = chart_table
+                .seats
                 .into_iter()
                 .map(|ap| SeatWithPlayer {
                     assignment: ap.assignment.into(),
@@ -75,15 +84,19 @@ impl SeatingQuery {
                     player: ap.player.map(Into::into),
                 })
                 .collect();
-
             table_counts.insert(table_row.id, seats.len());
-            tables.push(TableWithSeats { table, seats });
+            tables.push(TableWithSeats {
+                table,
+                seats,
+                current_dealer: chart_table.dealer.map(Into::into),
+                closed_seats: chart_table.closed_seats,
+                status: chart_table.status.into(),
+                notes: chart_table.notes,
+            });
         }
 
-        // Get unassigned players
-        let unassigned_player_rows =
-            table_seat_assignments::list_unassigned_players(&state.db, tournament_id).await?;
-        let unassigned_players: Vec<UnseatedPlayer> = unassigned_player_rows
+        let unassigned_players: Vec<UnseatedPlayer> = chart
+            .unassigned
             .into_iter()
             .map(|rp| UnseatedPlayer {
                 club_player_id: rp.id.into(),
@@ -224,11 +237,130 @@ impl SeatingQuery {
                     .unwrap_or_default(),
                 hunter_club_player_id: r.hunter_club_player_id.into(),
                 victim_club_player_id: r.victim_club_player_id.into(),
-                amount_cents: r.amount_cents,
+                amount_cents: r.amount_cents.into(),
                 created_at: r.created_at,
             })
             .collect())
     }
+
+    /// How many tables the tournament needs at the start and after late
+    /// registration, and when the final table should form. Projected from
+    /// the structure, the expected entries (the registrations so far by
+    /// default) and how fields shrank in the club's past tournaments. Club
+    /// managers.
+    async fn capacity_plan(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        expected_entries: Option<i32>,
+        #[graphql(desc = "Defaults to the smallest linked table (or club table).")]
+        seats_per_table: Option<i32>,
+    ) -> Result<CapacityPlan> {
+        use super::capacity::{self, PlannedLevel};
+        use crate::auth::permissions::require_club_manager;
+
+        let state = ctx.data::<AppState>()?;
+        let tid = Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let tournament = tournaments::get_by_id(&state.db, tid)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        require_club_manager(ctx, tournament.club_id).await?;
+
+        if expected_entries.is_some_and(|n| n < 1) {
+            return Err(async_graphql::Error::new(
+                "Expected entries must be at least 1",
+            ));
+        }
+        if seats_per_table.is_some_and(|n| !(2..=12).contains(&n)) {
+            return Err(async_graphql::Error::new(
+                "Seats per table must be between 2 and 12",
+            ));
+        }
+        let expected_entries = match expected_entries {
+            Some(n) => n,
+            None => {
+                tournament_registrations::count_confirmed_by_tournament(&state.db, tid).await?
+                    as i32
+            }
+        };
+        let seats_per_table = match seats_per_table {
+            Some(n) => n,
+            None => capacity_planning::table_sizes(&state.db, tid)
+                .await?
+                .into_iter()
+                .min()
+                .unwrap_or(capacity::DEFAULT_SEATS_PER_TABLE),
+        };
+
+        let history =
+            capacity_planning::field_history(&state.db, tournament.club_id, tid, 20).await?;
+        let rates = capacity::field_rates(&history);
+        let levels: Vec<PlannedLevel> =
+            infra::repos::tournament_clock::get_all_structures(&state.db, tid)
+                .await?
+                .into_iter()
+                .map(|l| PlannedLevel {
+                    number: l.level_number,
+                    minutes: l.duration_minutes,
+                })
+                .collect();
+        let projection = capacity::plan(
+            expected_entries,
+            seats_per_table,
+            rates,
+            &levels,
+            tournament.late_registration_level,
+        );
+
+        let started_at = capacity_planning::started_at(&state.db, tid)
+            .await?
+            .unwrap_or(tournament.start_time);
+        let at = |minutes: i32| started_at + chrono::Duration::minutes(minutes.into());
+        Ok(CapacityPlan {
+            tournament_id,
+            expected_entries,
+            seats_per_table,
+            based_on_tournaments: rates.tournaments as i32,
+            elimination_rate_per_hour: 1.0 - (-rates.elimination_per_minute * 60.0).exp(),
+            late_entry_share: rates.late_entry_share,
+            players_at_start: projection.players_at_start,
+            tables_at_start: projection.tables_at_start,
+            peak_tables: projection.peak_tables,
+            late_registration_closes_at: projection.late_registration_minutes.map(at),
+            players_after_late_registration: projection.players_after_late_registration,
+            tables_after_late_registration: projection.tables_after_late_registration,
+            projected_final_table_at: at(projection.final_table_minutes),
+            projected_final_table_level: projection.final_table_level,
+        })
+    }
+
+    /// Seats to close or reopen so the active tables play equally
+    /// short-handed as the field shrinks. Empty while the tables are already
+    /// even, or while a table should be broken instead (club managers).
+    async fn seat_closure_recommendations(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Vec<SeatClosureRecommendation>> {
+        use crate::auth::permissions::require_club_manager;
+
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let tables = club_tables::list_assigned_to_tournament(&state.db, tournament_id).await?;
+        let current =
+            table_seat_assignments::list_current_for_tournament(&state.db, tournament_id).await?;
+        let closed = closed_seats::list_for_tournament(&state.db, tournament_id).await?;
+        Ok(
+            super::service::recommend_seat_closures(&tables, &current, &closed)
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        )
+    }
 }
 
 #[derive(Default)]
@@ -318,11 +450,19 @@ impl SeatingMutation {
             });
         }
 
+        // Seats as played here: the override, else capped by the
+        // tournament's seats per table.
+        let max_seats = club_tables::list_assigned_to_tournament(&state.db, tournament_id)
+            .await?
+            .into_iter()
+            .find(|t| t.id == club_table.id)
+            .map_or(club_table.max_seats, |t| t.max_seats);
+
         Ok(TournamentTable {
             id: club_table.id.into(),
             tournament_id: tournament_id.into(),
             table_number: club_table.table_number,
-            max_seats: input.max_seats.unwrap_or(club_table.max_seats),
+            max_seats,
             is_active: club_table.is_active,
             created_at: club_table.created_at,
         })
@@ -426,13 +566,22 @@ impl SeatingMutation {
             });
         }
 
+        let seats: std::collections::HashMap<Uuid, i32> =
+            club_tables::list_assigned_to_tournament(&state.db, tournament_id)
+                .await?
+                .into_iter()
+                .map(|t| (t.id, t.max_seats))
+                .collect();
         Ok(assigned
             .into_iter()
-            .map(|(club_table, max_seats)| TournamentTable {
+            .map(|(club_table, _)| TournamentTable {
                 id: club_table.id.into(),
                 tournament_id: tournament_id.into(),
                 table_number: club_table.table_number,
-                max_seats: max_seats.unwrap_or(club_table.max_seats),
+                max_seats: seats
+                    .get(&club_table.id)
+                    .copied()
+                    .unwrap_or(club_table.max_seats),
                 is_active: club_table.is_active,
                 created_at: club_table.created_at,
             })
@@ -525,6 +674,247 @@ impl SeatingMutation {
         Ok(success)
     }
 
+    /// Play every linked table with `seatsPerTable` seats, e.g. 8-handed on
+    /// 10-seat tables; null goes back to each table's own size. Smaller
+    /// tables keep their size, and a table's own override on its assignment
+    /// still wins. The seat draw, auto-assign and balancing follow it; the
+    /// club tables are untouched. Players already in seats past the new size
+    /// must be moved first (managers only).
+    async fn set_tournament_seats_per_table(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        seats_per_table: Option<i32>,
+    ) -> Result<Tournament> {
+        use crate::auth::permissions::require_club_manager;
+
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+
+        if seats_per_table.is_some_and(|seats| seats < 2) {
+            return Err(async_graphql::Error::new(
+                "Tables must have at least 2 seats",
+            ));
+        }
+
+        // The tournament lock keeps a player from being seated past the new
+        // size between the check and the update.
+        let mut tx = state.db.begin().await?;
+        if !tournaments::lock(&mut *tx, tournament_id).await? {
+            return Err(async_graphql::Error::new("Tournament not found"));
+        }
+        if let Some(seats) = seats_per_table {
+            let stranded = club_tables::seats_beyond_cap(&mut *tx, tournament_id, seats).await?;
+            if let Some((table_number, seat_number)) = stranded.first() {
+                return Err(async_graphql::Error::new(format!(
+                    "Table {table_number} has a player in seat {seat_number}: move them before \
+                     playing {seats}-handed"
+                )));
+            }
+        }
+        let row = tournaments::set_seats_per_table(&mut *tx, tournament_id, seats_per_table)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        tx.commit().await?;
+
+        {
+            let db = state.db.clone();
+            let manager_uuid = Uuid::parse_str(manager.id.as_str()).ok();
+            tokio::spawn(async move {
+                crate::gql::domains::activity_log::log_and_publish(
+                    &db,
+                    tournament_id,
+                    "seating",
+                    "seats_per_table_set",
+                    manager_uuid,
+                    None,
+                    serde_json::json!({ "seats_per_table": seats_per_table }),
+                )
+                .await;
+            });
+        }
+
+        Ok(row.into())
+    }
+
+    /// Set a table's floor status and notes for the tournament, e.g.
+    /// breaking soon, or "new deck needed". Shown on the seating chart and
+    /// pushed to the floor as a seating event; seating itself is unaffected
+    /// (managers only).
+    async fn set_table_status(
+        &self,
+        ctx: &Context<'_>,
+        input: SetTableStatusInput,
+    ) -> Result<TableFloorStatus> {
+        use crate::auth::permissions::require_club_manager;
+
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_table_id =
+            Uuid::parse_str(input.club_table_id.as_str()).gql_err("Invalid table ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid manager ID")?;
+
+        let notes = input
+            .notes
+            .as_deref()
+            .map(str::trim)
+            .filter(|n| !n.is_empty());
+        let row = club_tables::set_status(
+            &state.db,
+            tournament_id,
+            club_table_id,
+            input.status.as_db(),
+            notes,
+            Some(manager_id),
+        )
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Table is not assigned to this tournament"))?;
+        let table_number = club_tables::get_by_id(&state.db, club_table_id)
+            .await?
+            .map(|t| t.table_number)
+            .unwrap_or_default();
+
+        let label = match input.status {
+            TableStatus::Open => "open",
+            TableStatus::BreakingSoon => "breaking soon",
+            TableStatus::Closed => "closed",
+        };
+        publish_seating_event(SeatingChangeEvent {
+            event_type: SeatingEventType::TableStatusChanged,
+            tournament_id: tournament_id.into(),
+            club_id: club_id.into(),
+            affected_assignment: None,
+            affected_player: None,
+            message: match &row.notes {
+                Some(notes) => format!("Table {table_number} {label}: {notes}"),
+                None => format!("Table {table_number} {label}"),
+            },
+            timestamp: chrono::Utc::now(),
+        });
+        {
+            let db = state.db.clone();
+            let details = serde_json::json!({
+                "table_number": table_number,
+                "status": row.status,
+                "notes": row.notes,
+            });
+            tokio::spawn(async move {
+                crate::gql::domains::activity_log::log_and_publish(
+                    &db,
+                    tournament_id,
+                    "seating",
+                    "table_status_set",
+                    Some(manager_id),
+                    None,
+                    details,
+                )
+                .await;
+            });
+        }
+
+        Ok(TableFloorStatus {
+            tournament_id: row.tournament_id.into(),
+            club_table_id: row.club_table_id.into(),
+            table_number,
+            status: row.status.into(),
+            notes: row.notes,
+            updated_by: row.status_updated_by.map(Into::into),
+            updated_at: row.status_updated_at,
+        })
+    }
+
+    /// Close seats at a table so it plays short-handed, e.g. to keep every
+    /// table 7-handed late in the event. Closed seats are skipped by the
+    /// draw, auto-assign and balancing until reopened; occupied seats can't
+    /// be closed. Returns the table's closed seats (managers only).
+    async fn close_table_seats(
+        &self,
+        ctx: &Context<'_>,
+        input: TableSeatsInput,
+    ) -> Result<Vec<i32>> {
+        change_closed_seats(ctx, input, true).await
+    }
+
+    /// Reopen closed seats at a table. Returns the table's closed seats
+    /// (managers only).
+    async fn reopen_table_seats(
+        &self,
+        ctx: &Context<'_>,
+        input: TableSeatsInput,
+    ) -> Result<Vec<i32>> {
+        change_closed_seats(ctx, input, false).await
+    }
+
+    /// Apply the current `seatClosureRecommendations` in one go and return
+    /// what was applied (managers only).
+    async fn apply_seat_closure_recommendations(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Vec<SeatClosureRecommendation>> {
+        use crate::auth::permissions::require_club_manager;
+
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid manager ID")?;
+
+        let mut tx = state
+            .db
+            .begin()
+            .await
+            .gql_err("Failed to begin transaction")?;
+        let tables = club_tables::list_assigned_to_tournament(&mut *tx, tournament_id).await?;
+        let current =
+            table_seat_assignments::list_current_for_tournament(&mut *tx, tournament_id).await?;
+        let closed = closed_seats::list_for_tournament(&mut *tx, tournament_id).await?;
+        let plans = super::service::recommend_seat_closures(&tables, &current, &closed);
+        for plan in &plans {
+            closed_seats::close(
+                &mut *tx,
+                tournament_id,
+                plan.club_table_id,
+                &plan.close,
+                Some(manager_id),
+            )
+            .await?;
+            closed_seats::reopen(&mut *tx, tournament_id, plan.club_table_id, &plan.reopen).await?;
+        }
+        tx.commit().await.gql_err("Failed to commit transaction")?;
+
+        if !plans.is_empty() {
+            let summary: Vec<serde_json::Value> = plans
+                .iter()
+                .map(|p| {
+                    serde_json::json!({
+                        "table_number": p.table_number,
+                        "closed": p.close,
+                        "reopened": p.reopen,
+                        "seats": p.seats_after,
+                    })
+                })
+                .collect();
+            announce_closed_seats(
+                state,
+                tournament_id,
+                club_id,
+                manager_id,
+                "seat_closures_applied",
+                format!("Seat closures applied at {} tables", plans.len()),
+                serde_json::json!({ "tables": summary }),
+            );
+        }
+        Ok(plans.into_iter().map(Into::into).collect())
+    }
+
     /// Assign a player to a specific seat (managers only)
     async fn assign_player_to_seat(
         &self,
@@ -571,11 +961,17 @@ impl SeatingMutation {
             .gql_err("Failed to begin transaction")?;
 
         // Check if seat is available (inside transaction for consistency)
-        let is_available =
-            table_seat_assignments::is_seat_available(&mut *tx, club_table_id, input.seat_number)
-                .await?;
-        if !is_available {
-            return Err(async_graphql::Error::new("Seat is already occupied"));
+        conflicts::ensure_seat_free(
+            &mut *tx,
+            club_table_id,
+            input.seat_number,
+            "Seat is already occupied",
+        )
+        .await?;
+        if closed_seats::is_closed(&mut *tx, tournament_id, club_table_id, input.seat_number)
+            .await?
+        {
+            return Err(async_graphql::Error::new("Seat is closed"));
         }
 
         let create_data = CreateSeatAssignment {
@@ -777,19 +1173,33 @@ impl SeatingMutation {
             .ok_or_else(|| async_graphql::Error::new("Player is not on this club's roster"))?
             .id;
 
+        let mut tx = state
+            .db
+            .begin()
+            .await
+            .gql_err("Failed to begin transaction")?;
+
         // Check if new seat is available
-        let is_available = table_seat_assignments::is_seat_available(
-            &state.db,
+        conflicts::ensure_seat_free(
+            &mut *tx,
             new_club_table_id,
             input.new_seat_number,
+            "Target seat is already occupied",
         )
         .await?;
-        if !is_available {
-            return Err(async_graphql::Error::new("Target seat is already occupied"));
+        if closed_seats::is_closed(
+            &mut *tx,
+            tournament_id,
+            new_club_table_id,
+            input.new_seat_number,
+        )
+        .await?
+        {
+            return Err(async_graphql::Error::new("Target seat is closed"));
         }
 
         let assignment_row = table_seat_assignments::move_player(
-            &state.db,
+            &mut tx,
             tournament_id,
             club_player_id,
             new_club_table_id,
@@ -800,8 +1210,8 @@ impl SeatingMutation {
         .await?;
 
         // Re-confirm registration status is seated after move
-        tournament_registrations::update_status(&state.db, tournament_id, user_id, "seated")
-            .await?;
+        tournament_registrations::update_status(&mut *tx, tournament_id, user_id, "seated").await?;
+        tx.commit().await.gql_err("Failed to commit transaction")?;
 
         // Get player info for the event
         let player = users::get_by_id(&state.db, user_id).await?;
@@ -870,6 +1280,80 @@ impl SeatingMutation {
         Ok(result)
     }
 
+    /// Swap two seated players' seats in one transaction (managers only).
+    /// Returns `userA`'s new seat, then `userB`'s.
+    async fn swap_seats(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        user_a: ID,
+        user_b: ID,
+    ) -> Result<Vec<SeatAssignment>> {
+        use crate::auth::permissions::require_club_manager;
+
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let user_a = Uuid::parse_str(user_a.as_str()).gql_err("Invalid user ID")?;
+        let user_b = Uuid::parse_str(user_b.as_str()).gql_err("Invalid user ID")?;
+
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid manager ID")?;
+
+        let swap = super::service::swap_seats(&state.db, tournament_id, user_a, user_b, manager_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(e.to_string()))?;
+        publish_activity_event(tournament_id, ActivityLogEntry::from(swap.log));
+
+        let first: SeatAssignment = swap.first.into();
+        let second: SeatAssignment = swap.second.into();
+        publish_seating_event(SeatingChangeEvent {
+            event_type: SeatingEventType::SeatsSwapped,
+            tournament_id: tournament_id.into(),
+            club_id: club_id.into(),
+            affected_assignment: None,
+            affected_player: None,
+            message: format!(
+                "Players swapped seats {} and {}",
+                first.seat_number, second.seat_number
+            ),
+            timestamp: chrono::Utc::now(),
+        });
+
+        // Tell both players where they now sit (in-app now, push when
+        // backgrounded); both respect the seating_updates preference.
+        for (user_id, seat_number) in [(user_a, first.seat_number), (user_b, second.seat_number)] {
+            let prefs = infra::repos::notification_preferences::get_for_user(&state.db, user_id)
+                .await
+                .unwrap_or_default();
+            if !prefs.seating_updates {
+                continue;
+            }
+            publish_user_notification(UserNotification {
+                id: ID::from(Uuid::new_v4().to_string()),
+                user_id: ID::from(user_id.to_string()),
+                notification_type: NotificationType::PlayerMoved,
+                title: TITLE_PLAYER_MOVED.to_string(),
+                message: format!("You have been moved to seat {seat_number}"),
+                tournament_id: Some(ID::from(tournament_id.to_string())),
+                created_at: chrono::Utc::now(),
+            });
+            let db = state.db.clone();
+            tokio::spawn(async move {
+                crate::services::push_service::send_seating_event(
+                    &db,
+                    user_id,
+                    "PLAYER_MOVED",
+                    tournament_id,
+                )
+                .await;
+            });
+        }
+
+        Ok(vec![first, second])
+    }
+
     /// Update a player's stack size (managers only)
     async fn update_stack_size(
         &self,
@@ -901,10 +1385,22 @@ impl SeatingMutation {
             notes: None,
         };
 
+        let mut tx = state.db.begin().await?;
         let assignment_row =
-            table_seat_assignments::update(&state.db, current_assignment.id, update_data)
+            table_seat_assignments::update(&mut *tx, current_assignment.id, update_data)
                 .await?
                 .ok_or_else(|| async_graphql::Error::new("Failed to update seat assignment"))?;
+        stack_history::record(
+            &mut *tx,
+            tournament_id,
+            current_assignment.club_player_id,
+            current_assignment.stack_size,
+            input.new_stack_size,
+            StackSource::Manual,
+            Uuid::parse_str(_manager.id.as_str()).ok(),
+        )
+        .await?;
+        tx.commit().await?;
 
         // Get player info for the event
         let player = users::get_by_id(&state.db, user_id).await?;
@@ -945,6 +1441,271 @@ impl SeatingMutation {
         Ok(result)
     }
 
+    /// Update many stacks at once, e.g. the chip counts taken at a break
+    /// (managers only). All-or-nothing: if any player is not seated, nothing is
+    /// written. Publishes a single consolidated seating event.
+    async fn update_stack_sizes(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateStackSizesInput,
+    ) -> Result<Vec<SeatAssignment>> {
+        use crate::auth::permissions::require_club_manager;
+
+        let state = ctx.data::<AppState>()?;
+
+        let tournament_id =
+            Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).ok();
+
+        if input.stacks.is_empty() {
+            return Err(async_graphql::Error::new("No stacks provided"));
+        }
+
+        let mut entries = Vec::with_capacity(input.stacks.len());
+        let mut seen = std::collections::HashSet::new();
+        for entry in &input.stacks {
+            let user_id = Uuid::parse_str(entry.user_id.as_str()).gql_err("Invalid user ID")?;
+            if entry.stack < 0 {
+                return Err(async_graphql::Error::new("Stack size cannot be negative"));
+            }
+            if !seen.insert(user_id) {
+                return Err(async_graphql::Error::new(format!(
+                    "Player {user_id} appears more than once"
+                )));
+            }
+            entries.push((user_id, entry.stack));
+        }
+
+        let mut tx = state.db.begin().await?;
+        let mut updated = Vec::with_capacity(entries.len());
+        for (user_id, stack) in entries {
+            let current =
+                table_seat_assignments::get_current_for_user(&mut *tx, tournament_id, user_id)
+                    .await?
+                    .ok_or_else(|| {
+                        async_graphql::Error::new(format!(
+                            "Player {user_id} is not currently assigned to a seat"
+                        ))
+                    })?;
+
+            let row = table_seat_assignments::update(
+                &mut *tx,
+                current.id,
+                UpdateSeatAssignment {
+                    stack_size: Some(stack),
+                    notes: None,
+                },
+            )
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Failed to update seat assignment"))?;
+
+            stack_history::record(
+                &mut *tx,
+                tournament_id,
+                current.club_player_id,
+                current.stack_size,
+                stack,
+                StackSource::BreakCount,
+                manager_id,
+            )
+            .await?;
+
+            updated.push(SeatAssignment::from(row));
+        }
+        tx.commit().await?;
+
+        let count = updated.len();
+        publish_seating_event(SeatingChangeEvent {
+            event_type: SeatingEventType::StacksUpdated,
+            tournament_id: tournament_id.into(),
+            club_id: club_id.into(),
+            affected_assignment: None,
+            affected_player: None,
+            message: format!("{count} stacks updated"),
+            timestamp: chrono::Utc::now(),
+        });
+
+        {
+            let db = state.db.clone();
+            tokio::spawn(async move {
+                crate::gql::domains::activity_log::log_and_publish(
+                    &db,
+                    tournament_id,
+                    "seating",
+                    "stacks_updated",
+                    manager_id,
+                    None,
+                    serde_json::json!({"count": count}),
+                )
+                .await;
+            });
+        }
+
+        Ok(updated)
+    }
+
+    /// Color up to the level's new minimum denomination (managers only).
+    ///
+    /// Every live stack is settled per the tournament's chip-race rule, each
+    /// change is written to the stack history, and the per-table totals are
+    /// checked against the chips put in play by entries and bonuses. A level
+    /// can only be colored up once.
+    async fn record_color_up(
+        &self,
+        ctx: &Context<'_>,
+        input: RecordColorUpInput,
+    ) -> Result<ColorUpResult> {
+        use crate::auth::permissions::require_club_manager;
+        use crate::gql::domains::tournaments::types::ChipRaceRule;
+        use infra::repos::{tournament_clock, tournament_entries};
+
+        let state = ctx.data::<AppState>()?;
+
+        let tournament_id =
+            Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let tournament = tournaments::get_by_id(&state.db, tournament_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        let manager = require_club_manager(ctx, tournament.club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).ok();
+
+        let denomination = tournament_clock::get_all_structures(&state.db, tournament_id)
+            .await?
+            .into_iter()
+            .find(|l| l.level_number == input.level)
+            .ok_or_else(|| async_graphql::Error::new("Level not found"))?
+            .color_up_denomination
+            .ok_or_else(|| {
+                async_graphql::Error::new(format!("Level {} has no color-up", input.level))
+            })?;
+        let rule = ChipRaceRule::from(tournament.chip_race_rule.clone());
+
+        let chips_in_play = tournament_entries::get_stats(&state.db, tournament_id)
+            .await?
+            .total_chips;
+        let tables = club_tables::list_assigned_to_tournament(&state.db, tournament_id).await?;
+
+        let mut tx = state.db.begin().await?;
+        let mut table_results = Vec::with_capacity(tables.len());
+        let mut adjusted = Vec::new();
+        for table in tables {
+            let seats = table_seat_assignments::list_current_for_table(&mut *tx, table.id).await?;
+            let (counted, uncounted): (Vec<_>, Vec<_>) =
+                seats.into_iter().partition(|a| a.stack_size.is_some());
+
+            let before: Vec<i32> = counted.iter().filter_map(|a| a.stack_size).collect();
+            let after = super::chip_race::color_up_table(&before, denomination, rule);
+
+            for ((assignment, old), new) in counted.iter().zip(&before).zip(&after) {
+                if old == new {
+                    continue;
+                }
+                let row = table_seat_assignments::update(
+                    &mut *tx,
+                    assignment.id,
+                    UpdateSeatAssignment {
+                        stack_size: Some(*new),
+                        notes: None,
+                    },
+                )
+                .await?
+                .ok_or_else(|| async_graphql::Error::new("Failed to update seat assignment"))?;
+                stack_history::record(
+                    &mut *tx,
+                    tournament_id,
+                    assignment.club_player_id,
+                    Some(*old),
+                    *new,
+                    StackSource::ColorUp,
+                    manager_id,
+                )
+                .await?;
+                adjusted.push(SeatAssignment::from(row));
+            }
+
+            table_results.push(ColorUpTable {
+                club_table_id: table.id.into(),
+                table_number: table.table_number,
+                chips_before: before.iter().map(|&c| c as i64).sum(),
+                chips_after: after.iter().map(|&c| c as i64).sum(),
+                uncounted_seats: uncounted.len() as i32,
+                all_seats_counted: uncounted.is_empty(),
+            });
+        }
+
+        let chips_before: i64 = table_results.iter().map(|t| t.chips_before).sum();
+        let chips_after: i64 = table_results.iter().map(|t| t.chips_after).sum();
+        color_ups::create(
+            &mut *tx,
+            color_ups::CreateColorUp {
+                tournament_id,
+                level_number: input.level,
+                denomination,
+                race_rule: tournament.chip_race_rule,
+                chips_before,
+                chips_after,
+                recorded_by: manager_id,
+            },
+        )
+        .await?
+        .ok_or_else(|| {
+            async_graphql::Error::new(format!("Level {} was already colored up", input.level))
+        })?;
+        tx.commit().await?;
+
+        let reconciled =
+            chips_before == chips_in_play && table_results.iter().all(|t| t.all_seats_counted);
+
+        publish_seating_event(SeatingChangeEvent {
+            event_type: SeatingEventType::StacksUpdated,
+            tournament_id: tournament_id.into(),
+            club_id: tournament.club_id.into(),
+            affected_assignment: None,
+            affected_player: None,
+            message: format!("Colored up to {denomination} chips"),
+            timestamp: chrono::Utc::now(),
+        });
+
+        {
+            let db = state.db.clone();
+            let level = input.level;
+            tokio::spawn(async move {
+                crate::gql::domains::activity_log::log_and_publish(
+                    &db,
+                    tournament_id,
+                    "seating",
+                    "color_up",
+                    manager_id,
+                    None,
+                    serde_json::json!({
+                        "level": level,
+                        "denomination": denomination,
+                        "chips_before": chips_before,
+                        "chips_after": chips_after,
+                        "reconciled": reconciled,
+                    }),
+                )
+                .await;
+            });
+        }
+
+        Ok(ColorUpResult {
+            tournament_id: tournament_id.into(),
+            level: input.level,
+            denomination,
+            rule,
+            chips_in_play,
+            chips_before,
+            chips_after,
+            reconciled,
+            tables: table_results,
+            adjusted_assignments: adjusted,
+        })
+    }
+
     /// Automatically balance tables (managers only)
     async fn balance_tables(
         &self,
@@ -1161,6 +1922,16 @@ impl SeatingMutation {
                     .await;
                 });
             }
+            // The field shrank: push the clock with the new pace and
+            // projected finish.
+            {
+                let db = state.db.clone();
+                tokio::spawn(async move {
+                    if let Ok(Some(clock)) = load_tournament_clock(&db, tournament_uuid).await {
+                        publish_clock_update(tournament_uuid, clock);
+                    }
+                });
+            }
 
             // Auto-detect final table: check if remaining players fit on one table
             let remaining =
@@ -1178,12 +1949,18 @@ impl SeatingMutation {
                         t.live_status,
                         TournamentLiveStatus::InProgress | TournamentLiveStatus::Break
                     ) {
-                        tournaments::update_live_status(
+                        if let Some(updated) = tournaments::update_live_status(
                             &state.db,
                             tournament_uuid,
                             TournamentLiveStatus::FinalTable,
                         )
-                        .await?;
+                        .await?
+                        {
+                            publish_schedule_change(
+                                ClubTournamentEventType::StatusChanged,
+                                &updated,
+                            );
+                        }
 
                         let ft_event = SeatingChangeEvent {
                             event_type: SeatingEventType::TournamentStatusChanged,
@@ -1195,6 +1972,33 @@ impl SeatingMutation {
                             timestamp: chrono::Utc::now(),
                         };
                         publish_seating_event(ft_event);
+
+                        // Record who made it, for the friends feed, and
+                        // tell their followers.
+                        let finalists: Vec<Uuid> =
+                            remaining.iter().filter_map(|a| a.user_id).collect();
+                        let db = state.db.clone();
+                        tokio::spawn(async move {
+                            crate::gql::domains::activity_log::log_and_publish(
+                                &db,
+                                tournament_uuid,
+                                "tournament",
+                                "final_table_reached",
+                                Some(manager_id),
+                                None,
+                                serde_json::json!({ "user_ids": finalists }),
+                            )
+                            .await;
+                            for finalist in finalists {
+                                rail::notify_followers(
+                                    &db,
+                                    finalist,
+                                    tournament_uuid,
+                                    rail::RailEvent::FinalTable,
+                                )
+                                .await;
+                            }
+                        });
                     }
                 }
             }
@@ -1219,6 +2023,14 @@ impl SeatingMutation {
                     )
                     .await;
 
+                    rail::notify_followers(
+                        &db,
+                        user_uuid,
+                        tournament_uuid,
+                        rail::RailEvent::Busted,
+                    )
+                    .await;
+
                     // Distinct event for a collected bounty, so feeds can surface it.
                     if let Some(cash) = bounty_cents {
                         crate::gql::domains::activity_log::log_and_publish(
@@ -1246,3 +2058,117 @@ impl SeatingMutation {
         }
     }
 }
+
+/// Close (or reopen) seats at one of a tournament's tables for
+/// `closeTableSeats`/`reopenTableSeats`; returns the table's closed seats.
+async fn change_closed_seats(
+    ctx: &Context<'_>,
+    input: TableSeatsInput,
+    close: bool,
+) -> Result<Vec<i32>> {
+    use crate::auth::permissions::require_club_manager;
+
+    let state = ctx.data::<AppState>()?;
+    let tournament_id =
+        Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+    let club_table_id =
+        Uuid::parse_str(input.club_table_id.as_str()).gql_err("Invalid table ID")?;
+    let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+    let manager = require_club_manager(ctx, club_id).await?;
+    let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid manager ID")?;
+
+    let mut seats = input.seat_numbers;
+    seats.sort_unstable();
+    seats.dedup();
+    if seats.is_empty() {
+        return Err(async_graphql::Error::new("No seats given"));
+    }
+    let table = club_tables::list_assigned_to_tournament(&state.db, tournament_id)
+        .await?
+        .into_iter()
+        .find(|t| t.id == club_table_id)
+        .ok_or_else(|| async_graphql::Error::new("Table is not assigned to this tournament"))?;
+    if let Some(seat) = seats.iter().find(|n| !(1..=table.max_seats).contains(*n)) {
+        return Err(async_graphql::Error::new(format!(
+            "Table {} has no seat {seat}",
+            table.table_number
+        )));
+    }
+
+    if close {
+        let occupied = table_seat_assignments::get_occupied_seats(&state.db, club_table_id).await?;
+        if let Some(seat) = seats.iter().find(|n| occupied.contains(*n)) {
+            return Err(async_graphql::Error::new(format!(
+                "Seat {seat} at table {} is occupied: move the player before closing it",
+                table.table_number
+            )));
+        }
+        closed_seats::close(
+            &state.db,
+            tournament_id,
+            club_table_id,
+            &seats,
+            Some(manager_id),
+        )
+        .await?;
+    } else {
+        closed_seats::reopen(&state.db, tournament_id, club_table_id, &seats).await?;
+    }
+
+    let list = seats
+        .iter()
+        .map(i32::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let (action, verb) = if close {
+        ("seats_closed", "Closed")
+    } else {
+        ("seats_reopened", "Reopened")
+    };
+    announce_closed_seats(
+        state,
+        tournament_id,
+        club_id,
+        manager_id,
+        action,
+        format!("{verb} seats {list} at table {}", table.table_number),
+        serde_json::json!({ "table_number": table.table_number, "seats": seats }),
+    );
+
+    Ok(closed_seats::list_for_table(&state.db, tournament_id, club_table_id).await?)
+}
+
+/// Push a seat-closure change to the seating chart and log it.
+fn announce_closed_seats(
+    state: &AppState,
+    tournament_id: Uuid,
+    club_id: Uuid,
+    manager_id: Uuid,
+    action: &'static str,
+    message: String,
+    details: serde_json::Value,
+) {
+    publish_seating_event(SeatingChangeEvent {
+        event_type: SeatingEventType::TablesBalanced,
+        tournament_id: tournament_id.into(),
+        club_id: club_id.into(),
+        affected_assignment: None,
+        affected_player: None,
+        message,
+        timestamp: chrono::Utc::now(),
+    });
+
+    let db = state.db.clone();
+    tokio::spawn(async move {
+        crate::gql::domains::activity_log::log_and_publish(
+            &db,
+            tournament_id,
+            "seating",
+            action,
+            Some(manager_id),
+            None,
+            details,
+        )
+        .await;
+    });
+}
@@ -3,10 +3,10 @@ use std::collections::{HashMap, HashSet};
 use rand::seq::SliceRandom;
 use uuid::Uuid;
 
-use infra::models::{ClubTableRow, TableSeatAssignmentRow};
+use infra::models::{ClubTableRow, TableSeatAssignmentRow, TournamentActivityLogRow};
 use infra::repos::{
-    club_tables, table_seat_assignments, table_seat_assignments::CreateSeatAssignment,
-    tournament_registrations,
+    activity_log, closed_seats, closed_seats::ClosedSeatRow, club_tables, registration_groups,
+    table_seat_assignments, table_seat_assignments::CreateSeatAssignment, tournament_registrations,
 };
 
 /// Parameters for table balancing (parsed by the resolver).
@@ -45,6 +45,19 @@ struct TableFill {
     table_number: i32,
     max_seats: i32,
     occupied: HashSet<i32>,
+    /// Seats closed for the tournament; never drawn.
+    closed: HashSet<i32>,
+}
+
+impl TableFill {
+    /// Seats players can take: the table's size less its closed seats.
+    fn capacity(&self) -> i32 {
+        self.max_seats - self.closed.len() as i32
+    }
+
+    fn is_free(&self, seat: i32) -> bool {
+        !self.occupied.contains(&seat) && !self.closed.contains(&seat)
+    }
 }
 
 /// One decided seat placement, produced by the (synchronous) draw before any
@@ -57,6 +70,30 @@ struct SeatPlan {
     stack_size: Option<i32>,
 }
 
+/// Where a group member may sit relative to the tables their group already
+/// occupies (see `registration_groups`).
+#[derive(Clone, Copy)]
+pub enum TableChoice<'a> {
+    /// Not in a group: plain fill-then-balance.
+    Any,
+    /// Sit at one of these tables while any of them has a free seat.
+    Prefer(&'a HashSet<Uuid>),
+    /// Stay off these tables while any other table has a free seat.
+    Avoid(&'a HashSet<Uuid>),
+}
+
+/// The draw's handling of a player's group, from the club's group policy and
+/// the tables their group mates already sit at.
+pub fn table_choice<'a>(group_policy: &str, mate_tables: &'a HashSet<Uuid>) -> TableChoice<'a> {
+    if mate_tables.is_empty() {
+        TableChoice::Any
+    } else if group_policy == "apart" {
+        TableChoice::Avoid(mate_tables)
+    } else {
+        TableChoice::Prefer(mate_tables)
+    }
+}
+
 /// Minimal number of tables whose summed capacity covers `total` players.
 ///
 /// This is the casino rule that keeps tables *playable*: open the fewest tables
@@ -80,9 +117,14 @@ fn min_tables_needed(total: i32, caps: &[i32]) -> usize {
     n.max(1)
 }
 
-/// Build per-table fill state from the tournament's linked tables and the
-/// current seat assignments.
-fn build_fills(tables: &[ClubTableRow], current: &[TableSeatAssignmentRow]) -> Vec<TableFill> {
+/// Build per-table fill state from the tournament's linked tables, the
+/// current seat assignments and its closed seats. Closures beyond a table's
+/// seat count (e.g. after lowering its size) don't count.
+fn build_fills(
+    tables: &[ClubTableRow],
+    current: &[TableSeatAssignmentRow],
+    closed: &[ClosedSeatRow],
+) -> Vec<TableFill> {
     let mut occupied: HashMap<Uuid, HashSet<i32>> = HashMap::new();
     for a in current {
         occupied
@@ -90,13 +132,30 @@ fn build_fills(tables: &[ClubTableRow], current: &[TableSeatAssignmentRow]) -> V
             .or_default()
             .insert(a.seat_number);
     }
+    let mut closed_at: HashMap<Uuid, HashSet<i32>> = HashMap::new();
+    for c in closed {
+        closed_at
+            .entry(c.club_table_id)
+            .or_default()
+            .insert(c.seat_number);
+    }
     tables
         .iter()
-        .map(|t| TableFill {
-            id: t.id,
-            table_number: t.table_number,
-            max_seats: t.max_seats,
-            occupied: occupied.remove(&t.id).unwrap_or_default(),
+        .map(|t| {
+            let occupied = occupied.remove(&t.id).unwrap_or_default();
+            let closed = closed_at
+                .remove(&t.id)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|n| *n <= t.max_seats && !occupied.contains(n))
+                .collect();
+            TableFill {
+                id: t.id,
+                table_number: t.table_number,
+                max_seats: t.max_seats,
+                occupied,
+                closed,
+            }
         })
         .collect()
 }
@@ -110,17 +169,22 @@ fn build_fills(tables: &[ClubTableRow], current: &[TableSeatAssignmentRow]) -> V
 /// free seat. Extra linked tables stay empty until the field actually needs
 /// them, so two players land at the *same* table instead of one each.
 ///
+/// A group member's `choice` narrows the candidates first (their group's
+/// tables, or every other table), still active tables first; when it leaves
+/// no free seat the plain rule applies.
+///
 /// Synchronous so the RNG never crosses an await point.
 fn pick_fill_then_balance(
     fills: &mut [TableFill],
     total_after: i32,
+    choice: TableChoice<'_>,
     rng: &mut impl rand::RngExt,
 ) -> Option<(Uuid, i32)> {
     if fills.is_empty() {
         return None;
     }
 
-    let caps: Vec<i32> = fills.iter().map(|f| f.max_seats).collect();
+    let caps: Vec<i32> = fills.iter().map(TableFill::capacity).collect();
     let target = min_tables_needed(total_after, &caps);
 
     // Active set = `target` tables, occupied ones first, then by table number.
@@ -128,37 +192,124 @@ fn pick_fill_then_balance(
     order.sort_by_key(|&i| (fills[i].occupied.is_empty(), fills[i].table_number));
     let active: HashSet<usize> = order.into_iter().take(target).collect();
 
+    // Least-filled table with a free seat among those `allowed`.
+    let least_filled = |allowed: &dyn Fn(usize) -> bool| {
+        (0..fills.len())
+            .filter(|&i| allowed(i) && (fills[i].occupied.len() as i32) < fills[i].capacity())
+            .min_by_key(|&i| (fills[i].occupied.len(), fills[i].table_number))
+    };
+    let grouped = match choice {
+        TableChoice::Any => None,
+        TableChoice::Prefer(tables) => {
+            least_filled(&|i| tables.contains(&fills[i].id) && active.contains(&i))
+                .or_else(|| least_filled(&|i| tables.contains(&fills[i].id)))
+        }
+        TableChoice::Avoid(tables) => {
+            least_filled(&|i| !tables.contains(&fills[i].id) && active.contains(&i))
+                .or_else(|| least_filled(&|i| !tables.contains(&fills[i].id)))
+        }
+    };
     // Least-filled active table with a free seat. Fall back to any table with a
     // free seat so seating never fails while capacity remains (e.g. uneven caps).
-    let idx = (0..fills.len())
-        .filter(|&i| active.contains(&i) && (fills[i].occupied.len() as i32) < fills[i].max_seats)
-        .min_by_key(|&i| (fills[i].occupied.len(), fills[i].table_number))
-        .or_else(|| {
-            (0..fills.len())
-                .filter(|&i| (fills[i].occupied.len() as i32) < fills[i].max_seats)
-                .min_by_key(|&i| (fills[i].occupied.len(), fills[i].table_number))
-        })?;
+    let idx = grouped
+        .or_else(|| least_filled(&|i| active.contains(&i)))
+        .or_else(|| least_filled(&|_| true))?;
 
     let table = &mut fills[idx];
     let free: Vec<i32> = (1..=table.max_seats)
-        .filter(|n| !table.occupied.contains(n))
+        .filter(|n| table.is_free(*n))
         .collect();
     let seat_number = free[rng.random_range(0..free.len())];
     table.occupied.insert(seat_number);
     Some((table.id, seat_number))
 }
 
+/// Seat `size` players of one group (or a lone player, `size` 1) in turn.
+/// `mate_tables` are the tables the group already sits at. Together, a group
+/// new to the tables goes where all of it fits; apart, each member takes a
+/// table none of the others sit at while one is free. Stops when every table
+/// is full.
+fn pick_group_seats(
+    fills: &mut [TableFill],
+    total_after: i32,
+    size: usize,
+    mut mate_tables: HashSet<Uuid>,
+    group_policy: &str,
+    rng: &mut impl rand::RngExt,
+) -> Vec<(Uuid, i32)> {
+    let mut seats = Vec::with_capacity(size);
+    for placed in 0..size {
+        let left = (size - placed) as i32;
+        let roomy: HashSet<Uuid> = if mate_tables.is_empty() && group_policy != "apart" && left > 1
+        {
+            fills
+                .iter()
+                .filter(|f| f.capacity() - f.occupied.len() as i32 >= left)
+                .map(|f| f.id)
+                .collect()
+        } else {
+            HashSet::new()
+        };
+        let choice = if roomy.is_empty() {
+            table_choice(group_policy, &mate_tables)
+        } else {
+            TableChoice::Prefer(&roomy)
+        };
+        let Some((table_id, seat_number)) = pick_fill_then_balance(fills, total_after, choice, rng)
+        else {
+            break;
+        };
+        mate_tables.insert(table_id);
+        seats.push((table_id, seat_number));
+    }
+    seats
+}
+
 /// Decide a single fill-then-balance seat for an externally-managed
 /// transaction (used by the check-in path). `total_after` is the seated count
 /// including this player. Synchronous; the RNG never crosses an await point.
 pub fn decide_seat_fill_then_balance(
     tables: &[ClubTableRow],
     current: &[TableSeatAssignmentRow],
+    closed: &[ClosedSeatRow],
     total_after: i32,
+    choice: TableChoice<'_>,
 ) -> Option<(Uuid, i32)> {
-    let mut fills = build_fills(tables, current);
+    let mut fills = build_fills(tables, current, closed);
     let mut rng = rand::rng();
-    pick_fill_then_balance(&mut fills, total_after, &mut rng)
+    pick_fill_then_balance(&mut fills, total_after, choice, &mut rng)
+}
+
+/// The tables `club_player_id`'s group mates currently sit at, with the
+/// club's group policy. Empty when the player isn't in a group.
+pub async fn group_mate_tables(
+    conn: &mut sqlx::PgConnection,
+    tournament_id: Uuid,
+    club_player_id: Uuid,
+    current: &[TableSeatAssignmentRow],
+) -> Result<(String, HashSet<Uuid>), sqlx::Error> {
+    let members =
+        registration_groups::list_members_for_tournament(&mut *conn, tournament_id).await?;
+    let Some(group_id) = members
+        .iter()
+        .find(|m| m.club_player_id == club_player_id)
+        .map(|m| m.group_id)
+    else {
+        return Ok((String::new(), HashSet::new()));
+    };
+    let mates: HashSet<Uuid> = members
+        .iter()
+        .filter(|m| m.group_id == group_id && m.club_player_id != club_player_id)
+        .map(|m| m.club_player_id)
+        .collect();
+    let tables = current
+        .iter()
+        .filter(|a| mates.contains(&a.club_player_id))
+        .map(|a| a.club_table_id)
+        .collect();
+    let policy =
+        registration_groups::group_policy_for_tournament(&mut *conn, tournament_id).await?;
+    Ok((policy, tables))
 }
 
 /// Seat one checked-in player using fill-then-balance across the linked tables,
@@ -171,33 +322,56 @@ pub async fn auto_seat_one(
     club_player_id: Uuid,
     manager_id: Uuid,
 ) -> Result<Option<TableSeatAssignmentRow>, Box<dyn std::error::Error + Send + Sync>> {
-    let tables = club_tables::list_assigned_to_tournament(pool, tournament_id).await?;
+    let mut tx = pool.begin().await?;
+    let assignment =
+        auto_seat_one_in(&mut tx, tournament_id, club_player_id, Some(manager_id)).await?;
+    tx.commit().await?;
+    Ok(assignment)
+}
+
+/// [`auto_seat_one`] inside the caller's transaction, so the seat stands or
+/// falls with whatever else the caller does (e.g. the check-in).
+/// `assigned_by` is `None` for background seating (alternates).
+pub async fn auto_seat_one_in(
+    conn: &mut sqlx::PgConnection,
+    tournament_id: Uuid,
+    club_player_id: Uuid,
+    assigned_by: Option<Uuid>,
+) -> Result<Option<TableSeatAssignmentRow>, sqlx::Error> {
+    let tables = club_tables::list_assigned_to_tournament(&mut *conn, tournament_id).await?;
     if tables.is_empty() {
         return Ok(None);
     }
 
-    let mut tx = pool.begin().await?;
-
     let current =
-        table_seat_assignments::list_current_for_tournament(&mut *tx, tournament_id).await?;
+        table_seat_assignments::list_current_for_tournament(&mut *conn, tournament_id).await?;
     if current.iter().any(|a| a.club_player_id == club_player_id) {
         return Ok(None); // already seated
     }
 
-    let mut fills = build_fills(&tables, &current);
+    let (group_policy, mate_tables) =
+        group_mate_tables(conn, tournament_id, club_player_id, &current).await?;
+    let closed = closed_seats::list_for_tournament(&mut *conn, tournament_id).await?;
+
+    let mut fills = build_fills(&tables, &current, &closed);
     // Including the player we're about to place.
     let total_after = current.len() as i32 + 1;
 
     let pick = {
         let mut rng = rand::rng();
-        pick_fill_then_balance(&mut fills, total_after, &mut rng)
+        pick_fill_then_balance(
+            &mut fills,
+            total_after,
+            table_choice(&group_policy, &mate_tables),
+            &mut rng,
+        )
     };
     let Some((club_table_id, seat_number)) = pick else {
         return Ok(None); // no free seat
     };
 
     let assignment = table_seat_assignments::create(
-        &mut *tx,
+        &mut *conn,
         CreateSeatAssignment {
             tournament_id,
             club_table_id,
@@ -207,21 +381,20 @@ pub async fn auto_seat_one(
             club_player_id: Some(club_player_id),
             seat_number,
             stack_size: None,
-            assigned_by: Some(manager_id),
+            assigned_by,
             notes: Some("Auto-seated".to_string()),
         },
     )
     .await?;
 
     tournament_registrations::update_status_by_club_player(
-        &mut *tx,
+        &mut *conn,
         tournament_id,
         club_player_id,
         "seated",
     )
     .await?;
 
-    tx.commit().await?;
     Ok(Some(assignment))
 }
 
@@ -256,38 +429,86 @@ pub async fn auto_seat_checked_in(
     let seated: HashSet<Uuid> = current.iter().map(|a| a.club_player_id).collect();
 
     // Eligible = checked-in registrations with a roster identity and no seat yet.
-    let mut eligible: Vec<_> =
-        tournament_registrations::list_by_tournament(&mut *tx, tournament_id)
+    let eligible: Vec<_> = tournament_registrations::list_by_tournament(&mut *tx, tournament_id)
+        .await?
+        .into_iter()
+        .filter(|r| r.status == "checked_in")
+        .filter(|r| !seated.contains(&r.club_player_id))
+        .collect();
+
+    // Group bookings: a group's members are drawn one after the other so the
+    // club's policy (same table, or split up) holds, including against
+    // members seated before the draw.
+    let group_policy =
+        registration_groups::group_policy_for_tournament(&mut *tx, tournament_id).await?;
+    let group_of: HashMap<Uuid, Uuid> =
+        registration_groups::list_members_for_tournament(&mut *tx, tournament_id)
             .await?
             .into_iter()
-            .filter(|r| r.status == "checked_in")
-            .filter(|r| !seated.contains(&r.club_player_id))
+            .map(|m| (m.club_player_id, m.group_id))
             .collect();
+    let mut group_tables: HashMap<Uuid, HashSet<Uuid>> = HashMap::new();
+    for a in &current {
+        if let Some(group_id) = group_of.get(&a.club_player_id) {
+            group_tables
+                .entry(*group_id)
+                .or_default()
+                .insert(a.club_table_id);
+        }
+    }
+    let mut units: Vec<Vec<_>> = Vec::new();
+    let mut unit_of_group: HashMap<Uuid, usize> = HashMap::new();
+    for reg in eligible {
+        match group_of.get(&reg.club_player_id) {
+            Some(group_id) => match unit_of_group.get(group_id) {
+                Some(&i) => units[i].push(reg),
+                None => {
+                    unit_of_group.insert(*group_id, units.len());
+                    units.push(vec![reg]);
+                }
+            },
+            None => units.push(vec![reg]),
+        }
+    }
 
-    let mut fills = build_fills(&tables, &current);
+    let closed = closed_seats::list_for_tournament(&mut *tx, tournament_id).await?;
+    let mut fills = build_fills(&tables, &current, &closed);
     // Size the whole draw up-front so the active-table set (and the even split
     // across it) is fixed for every placement in this draw.
-    let total_after = current.len() as i32 + eligible.len() as i32;
+    let total_after = current.len() as i32 + units.iter().map(Vec::len).sum::<usize>() as i32;
 
     // Decide the whole draw synchronously so the (non-Send) RNG never crosses an
     // await point.
     let mut plan: Vec<SeatPlan> = Vec::new();
     {
         let mut rng = rand::rng();
-        eligible.shuffle(&mut rng);
-        for reg in &eligible {
-            let Some((club_table_id, seat_number)) =
-                pick_fill_then_balance(&mut fills, total_after, &mut rng)
-            else {
-                break; // every table is full
-            };
-            plan.push(SeatPlan {
-                club_player_id: reg.club_player_id,
-                user_id: reg.user_id,
-                club_table_id,
-                seat_number,
-                stack_size: reg.starting_stack,
-            });
+        // Random order, bigger groups first while tables still have room for
+        // them; lone players then balance the tables around the groups.
+        units.shuffle(&mut rng);
+        units.sort_by_key(|unit| std::cmp::Reverse(unit.len()));
+        for unit in &units {
+            let mate_tables = group_of
+                .get(&unit[0].club_player_id)
+                .and_then(|group_id| group_tables.get(group_id))
+                .cloned()
+                .unwrap_or_default();
+            let seats = pick_group_seats(
+                &mut fills,
+                total_after,
+                unit.len(),
+                mate_tables,
+                &group_policy,
+                &mut rng,
+            );
+            for (reg, (club_table_id, seat_number)) in unit.iter().zip(seats) {
+                plan.push(SeatPlan {
+                    club_player_id: reg.club_player_id,
+                    user_id: reg.user_id,
+                    club_table_id,
+                    seat_number,
+                    stack_size: reg.starting_stack,
+                });
+            }
         }
     }
 
@@ -360,6 +581,80 @@ pub fn assess_balance(table_counts: &HashMap<Uuid, usize>, caps: &[i32]) -> Bala
     }
 }
 
+/// Seats to close or reopen at one table so the active tables play equally
+/// short-handed.
+pub struct SeatClosurePlan {
+    pub club_table_id: Uuid,
+    pub table_number: i32,
+    /// Empty seats to close, highest first.
+    pub close: Vec<i32>,
+    /// Closed seats to reopen, lowest first.
+    pub reopen: Vec<i32>,
+    /// Seats in play at the table once the plan is applied.
+    pub seats_after: i32,
+}
+
+/// Recommend partial table closures for the late stages of a tournament.
+///
+/// As the field shrinks, every active table is brought to the same number
+/// of seats in play, `ceil(players / active tables)`, so the tables stay
+/// equally short-handed (20 players on three 9-max tables play 7-handed:
+/// two seats close at each). Only empty seats are closed, highest first; a
+/// table that needs more room gets its lowest closed seats back. Nothing is
+/// recommended while the field fits on fewer tables — break a table first.
+pub fn recommend_seat_closures(
+    tables: &[ClubTableRow],
+    current: &[TableSeatAssignmentRow],
+    closed: &[ClosedSeatRow],
+) -> Vec<SeatClosurePlan> {
+    plan_seat_closures(&build_fills(tables, current, closed))
+}
+
+fn plan_seat_closures(fills: &[TableFill]) -> Vec<SeatClosurePlan> {
+    let active: Vec<&TableFill> = fills.iter().filter(|f| !f.occupied.is_empty()).collect();
+    if active.len() < 2 {
+        return Vec::new();
+    }
+    let total: usize = active.iter().map(|f| f.occupied.len()).sum();
+    let caps: Vec<i32> = fills.iter().map(|f| f.max_seats).collect();
+    if min_tables_needed(total as i32, &caps) < active.len() {
+        return Vec::new();
+    }
+
+    let seats_each = total.div_ceil(active.len()) as i32;
+    let mut plans: Vec<SeatClosurePlan> = active
+        .into_iter()
+        .filter_map(|f| {
+            let want = seats_each.max(f.occupied.len() as i32).min(f.max_seats);
+            let capacity = f.capacity();
+            let (close, reopen) = if capacity > want {
+                let close = (1..=f.max_seats)
+                    .rev()
+                    .filter(|n| f.is_free(*n))
+                    .take((capacity - want) as usize)
+                    .collect();
+                (close, Vec::new())
+            } else if capacity < want {
+                let mut shut: Vec<i32> = f.closed.iter().copied().collect();
+                shut.sort_unstable();
+                shut.truncate((want - capacity) as usize);
+                (Vec::new(), shut)
+            } else {
+                return None;
+            };
+            Some(SeatClosurePlan {
+                club_table_id: f.id,
+                table_number: f.table_number,
+                close,
+                reopen,
+                seats_after: want,
+            })
+        })
+        .collect();
+    plans.sort_by_key(|p| p.table_number);
+    plans
+}
+
 /// Perform the table balancing + consolidation workflow inside a transaction.
 ///
 /// First the field is consolidated onto the minimal number of tables it needs
@@ -433,6 +728,29 @@ pub async fn balance_tables(
     let keep: Vec<&ClubTableRow> = ordered.into_iter().take(target_tables).collect();
     let keep_ids: HashSet<Uuid> = keep.iter().map(|t| t.id).collect();
 
+    // Closed seats stay shut while the table count holds. Breaking a table
+    // reopens them all: they were sized for more tables, and the kept tables
+    // need every seat.
+    let active = table_counts.values().filter(|c| **c > 0).count();
+    let mut closed: HashMap<Uuid, HashSet<i32>> = HashMap::new();
+    if target_tables < active {
+        closed_seats::reopen_all(&mut *tx, params.tournament_id).await?;
+    } else {
+        for c in closed_seats::list_for_tournament(&mut *tx, params.tournament_id).await? {
+            closed
+                .entry(c.club_table_id)
+                .or_default()
+                .insert(c.seat_number);
+        }
+    }
+    let open_seats = |t: &ClubTableRow| {
+        let shut = closed
+            .get(&t.id)
+            .map(|s| s.iter().filter(|n| **n <= t.max_seats).count())
+            .unwrap_or(0);
+        t.max_seats as usize - shut
+    };
+
     // Even split across the kept tables; the fullest tables absorb the
     // remainder so the fewest players move.
     let base = total / target_tables;
@@ -483,19 +801,21 @@ pub async fn balance_tables(
             .iter()
             .find(|t| {
                 let cur = occupied.get(&t.id).map(|s| s.len()).unwrap_or(0);
-                cur < *desired.get(&t.id).unwrap_or(&0) && cur < t.max_seats as usize
+                cur < *desired.get(&t.id).unwrap_or(&0) && cur < open_seats(t)
             })
             .or_else(|| {
-                keep.iter().find(|t| {
-                    occupied.get(&t.id).map(|s| s.len()).unwrap_or(0) < t.max_seats as usize
-                })
+                keep.iter()
+                    .find(|t| occupied.get(&t.id).map(|s| s.len()).unwrap_or(0) < open_seats(t))
             });
         let Some(target_table) = target else {
             continue;
         };
 
+        let shut = closed.get(&target_table.id);
         let set = occupied.entry(target_table.id).or_default();
-        let Some(seat_num) = (1..=target_table.max_seats).find(|s| !set.contains(s)) else {
+        let Some(seat_num) = (1..=target_table.max_seats)
+            .find(|s| !set.contains(s) && !shut.is_some_and(|shut| shut.contains(s)))
+        else {
             continue;
         };
 
@@ -534,6 +854,83 @@ pub async fn balance_tables(
     Ok(BalanceResult { moves })
 }
 
+/// Result of a seat swap.
+pub struct SwapResult {
+    /// The first player's new seat (the second player's old one).
+    pub first: TableSeatAssignmentRow,
+    /// The second player's new seat (the first player's old one).
+    pub second: TableSeatAssignmentRow,
+    /// The `seating`/`seats_swapped` entry written with the swap.
+    pub log: TournamentActivityLogRow,
+}
+
+/// Swap two seated players' seats in one transaction, logging the swap with
+/// it. Two `movePlayer` calls can't do this: each target seat is still
+/// taken when the first move runs.
+///
+/// The caller (resolver) is responsible for auth, ID parsing, GraphQL
+/// conversion, and publishing subscription events.
+pub async fn swap_seats(
+    pool: &sqlx::PgPool,
+    tournament_id: Uuid,
+    first_user_id: Uuid,
+    second_user_id: Uuid,
+    manager_id: Uuid,
+) -> Result<SwapResult, Box<dyn std::error::Error + Send + Sync>> {
+    if first_user_id == second_user_id {
+        return Err("A player can't swap seats with themselves".into());
+    }
+
+    let mut tx = pool.begin().await?;
+    let first =
+        table_seat_assignments::get_current_for_user(&mut *tx, tournament_id, first_user_id)
+            .await?
+            .ok_or("The first player isn't seated in this tournament")?;
+    let second =
+        table_seat_assignments::get_current_for_user(&mut *tx, tournament_id, second_user_id)
+            .await?
+            .ok_or("The second player isn't seated in this tournament")?;
+
+    let (first_seat, second_seat) = match table_seat_assignments::swap_seats(
+        &mut tx,
+        &first,
+        &second,
+        Some(manager_id),
+    )
+    .await
+    {
+        Err(sqlx::Error::RowNotFound) => {
+            return Err("A player moved while swapping; try again".into())
+        }
+        result => result?,
+    };
+
+    let seat = |a: &TableSeatAssignmentRow| serde_json::json!({ "club_table_id": a.club_table_id, "seat_number": a.seat_number });
+    let log = activity_log::log_activity(
+        &mut *tx,
+        tournament_id,
+        "seating",
+        "seats_swapped",
+        Some(manager_id),
+        Some(first_user_id),
+        serde_json::json!({
+            "other_user_id": second_user_id,
+            "club_player_ids": [first.club_player_id, second.club_player_id],
+            "from": seat(&first),
+            "to": seat(&second),
+        }),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(SwapResult {
+        first: first_seat,
+        second: second_seat,
+        log,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -546,6 +943,7 @@ mod tests {
                 table_number: i as i32 + 1,
                 max_seats: c,
                 occupied: HashSet::new(),
+                closed: HashSet::new(),
             })
             .collect()
     }
@@ -556,7 +954,8 @@ mod tests {
         let mut f = fills(caps);
         let mut rng = rand::rng();
         for _ in 0..n {
-            pick_fill_then_balance(&mut f, n as i32, &mut rng).expect("a seat should be found");
+            pick_fill_then_balance(&mut f, n as i32, TableChoice::Any, &mut rng)
+                .expect("a seat should be found");
         }
         let mut counts: Vec<usize> = f.iter().map(|t| t.occupied.len()).collect();
         counts.sort_unstable_by(|a, b| b.cmp(a));
@@ -568,7 +967,7 @@ mod tests {
         let mut f = fills(caps);
         let mut rng = rand::rng();
         for i in 0..n {
-            pick_fill_then_balance(&mut f, (i + 1) as i32, &mut rng)
+            pick_fill_then_balance(&mut f, (i + 1) as i32, TableChoice::Any, &mut rng)
                 .expect("a seat should be found");
         }
         let mut counts: Vec<usize> = f.iter().map(|t| t.occupied.len()).collect();
@@ -576,6 +975,15 @@ mod tests {
         counts
     }
 
+    /// Tables of `caps` seats with the given seats taken.
+    fn seated(caps: &[i32], taken: &[&[i32]]) -> Vec<TableFill> {
+        let mut f = fills(caps);
+        for (table, seats) in f.iter_mut().zip(taken) {
+            table.occupied.extend(seats.iter().copied());
+        }
+        f
+    }
+
     fn counts(values: &[usize]) -> HashMap<Uuid, usize> {
         values
             .iter()
@@ -631,6 +1039,38 @@ mod tests {
         assert_eq!(run_incremental(&[9, 9, 9], 10), vec![9, 1, 0]);
     }
 
+    #[test]
+    fn together_group_shares_a_table() {
+        let mut f = fills(&[9, 9]);
+        let mut rng = rand::rng();
+        // Five lone players already fill most of table 1.
+        for _ in 0..5 {
+            pick_fill_then_balance(&mut f, 12, TableChoice::Any, &mut rng).unwrap();
+        }
+        let seats = pick_group_seats(&mut f, 12, 4, HashSet::new(), "together", &mut rng);
+        assert_eq!(seats.len(), 4);
+        assert!(seats.iter().all(|(table, _)| *table == seats[0].0));
+
+        // A late member joins the table the group already sits at.
+        let mates = HashSet::from([seats[0].0]);
+        let late = pick_group_seats(&mut f, 12, 1, mates, "together", &mut rng);
+        assert_eq!(late[0].0, seats[0].0);
+    }
+
+    #[test]
+    fn apart_group_is_split_even_when_one_table_would_do() {
+        let mut f = fills(&[9, 9, 9]);
+        let mut rng = rand::rng();
+        let seats = pick_group_seats(&mut f, 3, 3, HashSet::new(), "apart", &mut rng);
+        let tables: HashSet<Uuid> = seats.iter().map(|(table, _)| *table).collect();
+        assert_eq!(tables.len(), 3);
+
+        // More members than tables: the rest still get seats.
+        let mut f = fills(&[9, 9]);
+        let seats = pick_group_seats(&mut f, 3, 3, HashSet::new(), "apart", &mut rng);
+        assert_eq!(seats.len(), 3);
+    }
+
     #[test]
     fn assess_balance_thresholds() {
         let caps = [9, 9, 9];
@@ -666,4 +1106,81 @@ mod tests {
         let s = assess_balance(&counts(&[8, 5, 5, 5, 5, 5, 5]), &caps);
         assert!(s.needs_rebalance && s.critical);
     }
+
+    #[test]
+    fn closures_bring_tables_to_the_same_seat_count() {
+        // 20 players on three 9-max tables play 7-handed.
+        let f = seated(
+            &[9, 9, 9],
+            &[
+                &[1, 2, 3, 4, 5, 6, 7],
+                &[1, 2, 3, 4, 5, 6, 9],
+                &[2, 3, 4, 5, 6, 7],
+            ],
+        );
+        let plans = plan_seat_closures(&f);
+        assert_eq!(plans.len(), 3);
+        assert_eq!(plans[0].close, vec![9, 8]);
+        // Occupied seats are never closed.
+        assert_eq!(plans[1].close, vec![8, 7]);
+        assert_eq!(plans[2].close, vec![9, 8]);
+        assert!(plans
+            .iter()
+            .all(|p| p.seats_after == 7 && p.reopen.is_empty()));
+
+        // Applied, there is nothing left to do.
+        let mut f = f;
+        for (table, plan) in f.iter_mut().zip(&plans) {
+            table.closed.extend(plan.close.iter().copied());
+        }
+        assert!(plan_seat_closures(&f).is_empty());
+    }
+
+    #[test]
+    fn closures_reopen_seats_a_table_needs_back() {
+        // 20 players, 7-handed: table 1 was closed down too far.
+        let mut f = seated(
+            &[9, 9, 9],
+            &[
+                &[1, 2, 3, 4, 5, 6],
+                &[1, 2, 3, 4, 5, 6, 7],
+                &[1, 2, 3, 4, 5, 6, 7],
+            ],
+        );
+        f[0].closed.extend([7, 8, 9]);
+        f[1].closed.extend([8, 9]);
+        let plans = plan_seat_closures(&f);
+        assert_eq!(plans.len(), 2);
+        assert_eq!(plans[0].table_number, 1);
+        assert_eq!(plans[0].reopen, vec![7]);
+        assert!(plans[0].close.is_empty());
+        assert_eq!(plans[1].table_number, 3);
+        assert_eq!(plans[1].close, vec![9, 8]);
+    }
+
+    #[test]
+    fn closures_wait_for_consolidation() {
+        // 16 players fit on two 9-max tables: break the third first.
+        let f = seated(
+            &[9, 9, 9],
+            &[&[1, 2, 3, 4, 5, 6], &[1, 2, 3, 4, 5], &[1, 2, 3, 4, 5]],
+        );
+        assert!(plan_seat_closures(&f).is_empty());
+        // A single table is never "short-handed" against another.
+        let f = seated(&[9, 9], &[&[1, 2, 3, 4]]);
+        assert!(plan_seat_closures(&f).is_empty());
+    }
+
+    #[test]
+    fn draw_skips_closed_seats() {
+        let mut f = fills(&[3, 3]);
+        f[0].closed.insert(3);
+        f[1].closed.insert(1);
+        let mut rng = rand::rng();
+        for _ in 0..4 {
+            pick_fill_then_balance(&mut f, 4, TableChoice::Any, &mut rng).unwrap();
+        }
+        assert!(!f[0].occupied.contains(&3) && !f[1].occupied.contains(&1));
+        assert!(pick_fill_then_balance(&mut f, 5, TableChoice::Any, &mut rng).is_none());
+    }
 }
@@ -1,8 +1,10 @@
 use async_graphql::{Enum, InputObject, SimpleObject, ID};
 use chrono::{DateTime, Utc};
 
-use crate::gql::domains::tournaments::types::Tournament;
+use crate::gql::domains::staff::types::CurrentDealer;
+use crate::gql::domains::tournaments::types::{ChipRaceRule, Tournament};
 use crate::gql::domains::users::types::User;
+use crate::gql::scalars::Money;
 
 #[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
 pub enum SeatingEventType {
@@ -10,11 +12,44 @@ pub enum SeatingEventType {
     PlayerMoved,
     PlayerEliminated,
     StackUpdated,
+    StacksUpdated,
     TableCreated,
     TableClosed,
     TableRemoved,
     TournamentStatusChanged,
     TablesBalanced,
+    TableStatusChanged,
+    /// Two players traded seats; both moves arrive as one event.
+    SeatsSwapped,
+}
+
+/// Where a table stands for the floor team.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TableStatus {
+    Open,
+    /// Up next to break; the floor gets the destination seats ready.
+    BreakingSoon,
+    Closed,
+}
+
+impl TableStatus {
+    pub fn as_db(self) -> &'static str {
+        match self {
+            TableStatus::Open => "open",
+            TableStatus::BreakingSoon => "breaking_soon",
+            TableStatus::Closed => "closed",
+        }
+    }
+}
+
+impl From<String> for TableStatus {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "breaking_soon" => TableStatus::BreakingSoon,
+            "closed" => TableStatus::Closed,
+            _ => TableStatus::Open,
+        }
+    }
 }
 
 #[derive(SimpleObject, Clone)]
@@ -68,6 +103,13 @@ impl From<infra::models::TableSeatAssignmentRow> for SeatAssignment {
 pub struct TableWithSeats {
     pub table: TournamentTable,
     pub seats: Vec<SeatWithPlayer>,
+    /// The dealer in the box per the rotation schedule, if one is running.
+    pub current_dealer: Option<CurrentDealer>,
+    /// Seats closed to keep the tables equally short-handed.
+    pub closed_seats: Vec<i32>,
+    pub status: TableStatus,
+    /// Floor notes, e.g. "new deck needed".
+    pub notes: Option<String>,
 }
 
 #[derive(SimpleObject, Clone)]
@@ -161,6 +203,19 @@ pub struct UpdateStackSizeInput {
     pub new_stack_size: i32,
 }
 
+#[derive(InputObject)]
+pub struct StackSizeEntryInput {
+    pub user_id: ID,
+    pub stack: i32,
+}
+
+/// A batch of stack counts, typically entered by the floor at a break.
+#[derive(InputObject)]
+pub struct UpdateStackSizesInput {
+    pub tournament_id: ID,
+    pub stacks: Vec<StackSizeEntryInput>,
+}
+
 #[derive(InputObject)]
 pub struct AssignTableToTournamentInput {
     pub tournament_id: ID,
@@ -186,12 +241,109 @@ pub struct UnassignTableFromTournamentInput {
     pub club_table_id: ID,
 }
 
+#[derive(InputObject)]
+pub struct SetTableStatusInput {
+    pub tournament_id: ID,
+    pub club_table_id: ID,
+    pub status: TableStatus,
+    /// Replaces the table's notes; null or blank clears them.
+    pub notes: Option<String>,
+}
+
+/// A table's floor status in a tournament, as last set.
+#[derive(SimpleObject, Clone)]
+pub struct TableFloorStatus {
+    pub tournament_id: ID,
+    pub club_table_id: ID,
+    pub table_number: i32,
+    pub status: TableStatus,
+    pub notes: Option<String>,
+    pub updated_by: Option<ID>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Seats at one of a tournament's tables, to close or reopen.
+#[derive(InputObject)]
+pub struct TableSeatsInput {
+    pub tournament_id: ID,
+    pub club_table_id: ID,
+    pub seat_numbers: Vec<i32>,
+}
+
+/// Seats to close or reopen at a table so every active table plays equally
+/// short-handed.
+#[derive(SimpleObject, Clone)]
+pub struct SeatClosureRecommendation {
+    pub club_table_id: ID,
+    pub table_number: i32,
+    /// Empty seats to close, highest first.
+    pub close_seats: Vec<i32>,
+    /// Closed seats to reopen, lowest first.
+    pub reopen_seats: Vec<i32>,
+    /// Seats in play at the table afterwards.
+    pub seats_after: i32,
+}
+
+impl From<super::service::SeatClosurePlan> for SeatClosureRecommendation {
+    fn from(plan: super::service::SeatClosurePlan) -> Self {
+        Self {
+            club_table_id: plan.club_table_id.into(),
+            table_number: plan.table_number,
+            close_seats: plan.close,
+            reopen_seats: plan.reopen,
+            seats_after: plan.seats_after,
+        }
+    }
+}
+
 #[derive(InputObject)]
 pub struct BalanceTablesInput {
     pub tournament_id: ID,
     pub target_players_per_table: Option<i32>,
 }
 
+#[derive(InputObject)]
+pub struct RecordColorUpInput {
+    pub tournament_id: ID,
+    /// Structure level whose `colorUpDenomination` is being raced off.
+    pub level: i32,
+}
+
+/// Chip totals of one table before and after a color-up.
+#[derive(SimpleObject, Clone)]
+pub struct ColorUpTable {
+    pub club_table_id: ID,
+    pub table_number: i32,
+    pub chips_before: i64,
+    pub chips_after: i64,
+    /// Seated players with no recorded stack; their chips are not counted.
+    pub uncounted_seats: i32,
+    /// False when some seats have no recorded stack, so the table's total is
+    /// incomplete. Tables have no expected total of their own; the chip count
+    /// is only reconciled tournament-wide against `chipsInPlay`.
+    pub all_seats_counted: bool,
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct ColorUpResult {
+    pub tournament_id: ID,
+    pub level: i32,
+    pub denomination: i32,
+    pub rule: ChipRaceRule,
+    /// Chips issued through entries and bonuses — what the tables should hold.
+    pub chips_in_play: i64,
+    /// Sum of counted stacks before the race.
+    pub chips_before: i64,
+    /// Sum of stacks after the race.
+    pub chips_after: i64,
+    /// True when every seat was counted and the counted chips match
+    /// `chips_in_play`.
+    pub reconciled: bool,
+    pub tables: Vec<ColorUpTable>,
+    /// Seat assignments whose stack changed in the race.
+    pub adjusted_assignments: Vec<SeatAssignment>,
+}
+
 /// A single recorded knockout in a bounty / PKO tournament.
 #[derive(SimpleObject)]
 pub struct TournamentBounty {
@@ -202,6 +354,33 @@ pub struct TournamentBounty {
     pub hunter_name: String,
     pub victim_name: String,
     /// Cash the hunter collected for this knockout, in cents.
-    pub amount_cents: i32,
+    pub amount_cents: Money,
     pub created_at: DateTime<Utc>,
 }
+
+/// Tables and timing projected for a tournament, for setting up the room and
+/// scheduling dealers and floor staff.
+#[derive(SimpleObject, Clone)]
+pub struct CapacityPlan {
+    pub tournament_id: ID,
+    pub expected_entries: i32,
+    pub seats_per_table: i32,
+    /// Past tournaments at the club the rates were measured on; 0 means
+    /// default rates (half the field busts every two hours, no late entries).
+    pub based_on_tournaments: i32,
+    /// Share of the remaining field busting per hour.
+    pub elimination_rate_per_hour: f64,
+    /// Share of the players registering after the start.
+    pub late_entry_share: f64,
+    pub players_at_start: i32,
+    pub tables_at_start: i32,
+    /// Most tables in play at once, while late entrants keep arriving.
+    pub peak_tables: i32,
+    /// End of the late-registration level; `None` without late registration.
+    pub late_registration_closes_at: Option<DateTime<Utc>>,
+    pub players_after_late_registration: i32,
+    pub tables_after_late_registration: i32,
+    pub projected_final_table_at: DateTime<Utc>,
+    /// The level being played then; `None` past the end of the structure.
+    pub projected_final_table_level: Option<i32>,
+}
@@ -1,3 +1,15 @@
+//! Tournament series.
+//!
+//! A series with a final day is a multi-day event: `createTournamentSeries`
+//! creates the flights and the final day, `closeFlight` bags each survivor's
+//! stack (given, or read from their current seat) and `openDayTwo` checks
+//! the qualifiers in to the final day and seats them with that stack.
+//!
+//! Without a final day a series groups independent events, which standalone
+//! tournaments join and leave with `addTournamentToSeries` /
+//! `removeTournamentFromSeries`. The leaderboards domain's
+//! `seriesLeaderboard` ranks players across them.
+
 pub mod resolvers;
 pub mod types;
 
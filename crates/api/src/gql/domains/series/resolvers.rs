@@ -2,17 +2,20 @@ use async_graphql::{Context, Object, Result, ID};
 use uuid::Uuid;
 
 use crate::auth::permissions::require_club_manager;
+use crate::gql::domains::seating::service::auto_seat_checked_in;
+use crate::gql::domains::tournaments::lobby::publish_schedule_change;
 use crate::gql::error::ResultExt;
-use crate::gql::subscriptions::publish_user_notification;
+use crate::gql::subscriptions::{publish_seating_event, publish_user_notification};
 use crate::gql::types::{
-    NotificationType, Tournament, UserNotification, TITLE_QUALIFIED_FOR_DAY_2,
+    ClubTournamentEventType, NotificationType, SeatingChangeEvent, SeatingEventType, Tournament,
+    UserNotification, TITLE_QUALIFIED_FOR_DAY_2,
 };
 use crate::state::AppState;
 use infra::repos::tournament_clock::TournamentStructureLevel;
 use infra::repos::tournaments::CreateTournamentData;
 use infra::repos::{
-    club_players, flight_qualifications, tournament_clock, tournament_registrations,
-    tournament_series, tournaments,
+    club_players, flight_qualifications, table_seat_assignments, tournament_clock,
+    tournament_registrations, tournament_series, tournaments,
 };
 
 use super::types::{CloseFlightInput, CreateTournamentSeriesInput, FlightInput, TournamentSeries};
@@ -58,7 +61,8 @@ pub struct SeriesMutation;
 #[Object]
 impl SeriesMutation {
     /// Create a multi-day series: the event plus one tournament per Day-1 flight
-    /// and the final day, all sharing the same buy-in / blind structure.
+    /// and the final day, all sharing the same buy-in / blind structure. Without
+    /// a final day, an empty group of events.
     async fn create_tournament_series(
         &self,
         ctx: &Context<'_>,
@@ -68,11 +72,28 @@ impl SeriesMutation {
         let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
         require_club_manager(ctx, club_id).await?;
 
+        let Some(final_day) = input.final_day else {
+            if !input.flights.is_empty() {
+                return Err(async_graphql::Error::new("Flights need a final day"));
+            }
+            let series = tournament_series::create(
+                &state.db,
+                club_id,
+                input.title,
+                input.best_stack_forward.unwrap_or(true),
+            )
+            .await
+            .gql_err("Failed to create series")?;
+            return Ok(TournamentSeries::from(series));
+        };
         if input.flights.is_empty() {
             return Err(async_graphql::Error::new(
                 "A series needs at least one flight",
             ));
         }
+        let buy_in_cents = input
+            .buy_in_cents
+            .ok_or_else(|| async_graphql::Error::new("Flights need a buy-in"))?;
 
         // Resolve the shared blind structure once (template or custom), applied
         // to every flight + the final day.
@@ -90,7 +111,7 @@ impl SeriesMutation {
         // Create each flight, then the final day.
         let mut to_create: Vec<(FlightInput, bool)> =
             input.flights.into_iter().map(|f| (f, false)).collect();
-        to_create.push((input.final_day, true));
+        to_create.push((final_day, true));
 
         for (flight, is_final_day) in to_create {
             let data = CreateTournamentData {
@@ -99,8 +120,8 @@ impl SeriesMutation {
                 description: None,
                 start_time: flight.start_time,
                 end_time: None,
-                buy_in_cents: input.buy_in_cents,
-                rake_cents: input.rake_cents,
+                buy_in_cents: buy_in_cents.cents(),
+                rake_cents: input.rake_cents.map(i64::from),
                 seat_cap: input.seat_cap,
                 starting_stack: None,
                 early_bird_bonus_chips: None,
@@ -113,9 +134,15 @@ impl SeriesMutation {
                 bounty_type: None,
                 bounty_amount_cents: None,
                 leaderboard_config_id: None,
+                chip_race_rule: None,
+                visibility: None,
+                early_bird_buy_in_cents: None,
+                early_bird_until: None,
                 series_id: Some(series.id),
                 flight_label: Some(flight.label),
                 is_final_day,
+                points_multiplier: None,
+                seats_per_table: None,
             };
             let row = tournaments::create(&state.db, data)
                 .await
@@ -126,14 +153,15 @@ impl SeriesMutation {
                     .await
                     .gql_err("Failed to add structure level")?;
             }
+            publish_schedule_change(ClubTournamentEventType::Created, &row);
         }
 
         Ok(TournamentSeries::from(series))
     }
 
-    /// Close a Day-1 flight: record its survivors as qualifications (best stack
-    /// forward) and mark the flight finished. Survivors with an app account are
-    /// notified they're through to Day 2.
+    /// Close a Day-1 flight ("bag and tag"): record its survivors' stacks as
+    /// qualifications (best stack forward) and mark the flight finished.
+    /// Survivors with an app account are notified they're through to Day 2.
     async fn close_flight(&self, ctx: &Context<'_>, input: CloseFlightInput) -> Result<Tournament> {
         let state = ctx.data::<AppState>()?;
         let tournament_id =
@@ -155,20 +183,38 @@ impl SeriesMutation {
                 "Cannot close the final day as a flight",
             ));
         }
+        if flight.flight_label.is_none() {
+            return Err(async_graphql::Error::new("Tournament is not a flight"));
+        }
+
+        let survivors = match &input.survivors {
+            Some(survivors) => survivors
+                .iter()
+                .map(|s| {
+                    let id = Uuid::parse_str(s.club_player_id.as_str())
+                        .gql_err("Invalid club player ID")?;
+                    Ok((id, s.chip_count))
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => bagged_stacks(state, tournament_id).await?,
+        };
+        if survivors.iter().any(|&(_, chips)| chips < 1) {
+            return Err(async_graphql::Error::new(
+                "Bagged stacks must have at least one chip",
+            ));
+        }
 
         let final_day_id = tournament_series::final_day_id(&state.db, series_id)
             .await
             .gql_err("Database operation failed")?;
 
-        for survivor in &input.survivors {
-            let club_player_id = Uuid::parse_str(survivor.club_player_id.as_str())
-                .gql_err("Invalid club player ID")?;
+        for (club_player_id, chip_count) in survivors {
             flight_qualifications::record(
                 &state.db,
                 series_id,
                 club_player_id,
                 tournament_id,
-                survivor.chip_count,
+                chip_count,
             )
             .await
             .gql_err("Failed to record qualification")?;
@@ -179,7 +225,6 @@ impl SeriesMutation {
             // Notify the survivor (in-app + push), if they have an account.
             if let Ok(Some(cp)) = club_players::get_by_id(&state.db, club_player_id).await {
                 if let Some(user_id) = cp.app_user_id {
-                    let chip_count = survivor.chip_count;
                     publish_user_notification(UserNotification {
                         id: ID::from(Uuid::new_v4().to_string()),
                         user_id: ID::from(user_id.to_string()),
@@ -210,13 +255,98 @@ impl SeriesMutation {
         .await
         .gql_err("Failed to finish flight")?
         .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        publish_schedule_change(ClubTournamentEventType::StatusChanged, &updated);
+
+        Ok(Tournament::from(updated))
+    }
+
+    /// Add a standalone tournament of the club to a group of events. Flights
+    /// of a multi-day event are created with it and can't be added.
+    async fn add_tournament_to_series(
+        &self,
+        ctx: &Context<'_>,
+        series_id: ID,
+        tournament_id: ID,
+    ) -> Result<Tournament> {
+        let state = ctx.data::<AppState>()?;
+        let series_uuid = Uuid::parse_str(series_id.as_str()).gql_err("Invalid series ID")?;
+        let tournament_uuid =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+
+        let series = tournament_series::get_by_id(&state.db, series_uuid)
+            .await
+            .gql_err("Database operation failed")?
+            .ok_or_else(|| async_graphql::Error::new("Series not found"))?;
+        require_club_manager(ctx, series.club_id).await?;
+
+        let tournament = tournaments::get_by_id(&state.db, tournament_uuid)
+            .await
+            .gql_err("Database operation failed")?
+            .filter(|t| t.club_id == series.club_id)
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        if tournament.series_id == Some(series.id) {
+            return Ok(Tournament::from(tournament));
+        }
+        if tournament.series_id.is_some() {
+            return Err(async_graphql::Error::new(
+                "Tournament is already part of a series",
+            ));
+        }
+        if tournament_series::final_day_id(&state.db, series.id)
+            .await
+            .gql_err("Database operation failed")?
+            .is_some()
+        {
+            return Err(async_graphql::Error::new(
+                "Tournaments can't be added to a multi-day event",
+            ));
+        }
 
+        let updated = tournaments::set_series(&state.db, tournament.id, Some(series.id))
+            .await
+            .gql_err("Failed to add tournament to series")?
+            .ok_or_else(|| async_graphql::Error::new("Tournament is already part of a series"))?;
+        Ok(Tournament::from(updated))
+    }
+
+    /// Take a tournament out of its group of events. Its results leave the
+    /// series leaderboard.
+    async fn remove_tournament_from_series(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Tournament> {
+        let state = ctx.data::<AppState>()?;
+        let tournament_uuid =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+
+        let tournament = tournaments::get_by_id(&state.db, tournament_uuid)
+            .await
+            .gql_err("Database operation failed")?
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        require_club_manager(ctx, tournament.club_id).await?;
+
+        if tournament.series_id.is_none() {
+            return Ok(Tournament::from(tournament));
+        }
+        if tournament.flight_label.is_some() || tournament.is_final_day {
+            return Err(async_graphql::Error::new(
+                "Days of a multi-day event can't leave it",
+            ));
+        }
+
+        let updated = tournaments::set_series(&state.db, tournament.id, None)
+            .await
+            .gql_err("Failed to remove tournament from series")?
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
         Ok(Tournament::from(updated))
     }
 
     /// Seed (or refresh) the final day's registrations from the series'
     /// qualifiers: each best-stack survivor gets a CHECKED_IN registration with
-    /// their carried-over stack. Idempotent.
+    /// their carried-over stack, then is drawn a seat at the final day's tables
+    /// with that stack. Qualifiers already seated keep their seat; without
+    /// tables (or free seats) the rest wait for the seat draw. Idempotent.
     async fn open_day_two(&self, ctx: &Context<'_>, series_id: ID) -> Result<Tournament> {
         let state = ctx.data::<AppState>()?;
         let series_uuid = Uuid::parse_str(series_id.as_str()).gql_err("Invalid series ID")?;
@@ -225,7 +355,8 @@ impl SeriesMutation {
             .await
             .gql_err("Database operation failed")?
             .ok_or_else(|| async_graphql::Error::new("Series not found"))?;
-        require_club_manager(ctx, series.club_id).await?;
+        let manager = require_club_manager(ctx, series.club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid user ID")?;
 
         let final_day_id = tournament_series::final_day_id(&state.db, series_uuid)
             .await
@@ -247,6 +378,23 @@ impl SeriesMutation {
             .gql_err("Failed to seed Day 2 registration")?;
         }
 
+        // The draw seats checked-in players with their registration's stack.
+        let seated = auto_seat_checked_in(&state.db, final_day_id, manager_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(e.to_string()))?;
+        if !seated.assignments.is_empty() {
+            let count = seated.assignments.len();
+            publish_seating_event(SeatingChangeEvent {
+                event_type: SeatingEventType::TablesBalanced,
+                tournament_id: final_day_id.into(),
+                club_id: series.club_id.into(),
+                affected_assignment: None,
+                affected_player: None,
+                message: format!("{count} qualifiers seated for Day 2"),
+                timestamp: chrono::Utc::now(),
+            });
+        }
+
         let final_day = tournaments::get_by_id(&state.db, final_day_id)
             .await
             .gql_err("Database operation failed")?
@@ -255,6 +403,24 @@ impl SeriesMutation {
     }
 }
 
+/// The stacks of every player still seated in a flight, for bagging. Each
+/// seat needs a recorded stack.
+async fn bagged_stacks(state: &AppState, flight_id: Uuid) -> Result<Vec<(Uuid, i32)>> {
+    let seats = table_seat_assignments::list_current_for_tournament(&state.db, flight_id)
+        .await
+        .gql_err("Database operation failed")?;
+    let missing = seats.iter().filter(|a| a.stack_size.is_none()).count();
+    if missing > 0 {
+        return Err(async_graphql::Error::new(format!(
+            "Record the stack of every seated player before bagging ({missing} missing)"
+        )));
+    }
+    Ok(seats
+        .into_iter()
+        .filter_map(|a| Some((a.club_player_id, a.stack_size?)))
+        .collect())
+}
+
 /// Resolve the shared blind structure for a series from either a template id or
 /// an explicit custom structure (template takes precedence). Empty when neither.
 async fn resolve_levels(
@@ -280,6 +446,7 @@ async fn resolve_levels(
                 duration_minutes: l.duration_minutes,
                 is_break: l.is_break,
                 break_duration_minutes: l.break_duration_minutes,
+                color_up_denomination: l.color_up_denomination,
             })
             .collect());
     }
@@ -295,6 +462,7 @@ async fn resolve_levels(
                 duration_minutes: l.duration_minutes,
                 is_break: l.is_break,
                 break_duration_minutes: l.break_duration_minutes,
+                color_up_denomination: l.color_up_denomination,
             })
             .collect());
     }
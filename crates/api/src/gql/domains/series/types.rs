@@ -3,9 +3,13 @@ use chrono::{DateTime, Utc};
 
 use crate::gql::domains::tournaments::types::{Tournament, TournamentStructureInput};
 use crate::gql::error::ResultExt;
+use crate::gql::scalars::Money;
 use crate::state::AppState;
 
-/// A multi-day event grouping several Day-1 flights plus a final day.
+/// A series of tournaments: either a multi-day event (Day-1 flights plus a
+/// final day, created together) or a group of independent events, such as a
+/// festival or season, added one by one. `seriesLeaderboard` ranks players
+/// across either.
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
 pub struct TournamentSeries {
@@ -32,7 +36,8 @@ impl From<infra::repos::tournament_series::TournamentSeriesRow> for TournamentSe
 
 #[ComplexObject]
 impl TournamentSeries {
-    /// All flights + the final day, ordered (flights first, then the final day).
+    /// All flights + the final day, ordered (flights first, then the final
+    /// day); for a group of events, the events by start time.
     async fn flights(&self, ctx: &Context<'_>) -> Result<Vec<Tournament>> {
         let state = ctx.data::<AppState>()?;
         let series_uuid = uuid::Uuid::parse_str(self.id.as_str()).gql_err("Invalid series ID")?;
@@ -85,14 +90,17 @@ pub struct FlightInput {
     pub start_time: DateTime<Utc>,
 }
 
+/// Without `finalDay`, creates a group of events to add existing tournaments
+/// to (`addTournamentToSeries`); the flight settings are then unused.
 #[derive(InputObject)]
 pub struct CreateTournamentSeriesInput {
     pub club_id: ID,
     pub title: String,
     pub best_stack_forward: Option<bool>,
     // Shared config applied to every flight + the final day.
-    pub buy_in_cents: i32,
-    pub rake_cents: Option<i32>,
+    /// Required with a final day.
+    pub buy_in_cents: Option<Money>,
+    pub rake_cents: Option<Money>,
     pub seat_cap: Option<i32>,
     pub late_registration_level: Option<i32>,
     /// Blind structure template applied to every flight (copied per tournament).
@@ -100,9 +108,10 @@ pub struct CreateTournamentSeriesInput {
     /// Custom blind structure (used only when template_id is absent).
     pub structure: Option<Vec<TournamentStructureInput>>,
     /// Day-1 flights, in order.
+    #[graphql(default)]
     pub flights: Vec<FlightInput>,
-    /// The final day (Day 2).
-    pub final_day: FlightInput,
+    /// The final day (Day 2) of a multi-day event.
+    pub final_day: Option<FlightInput>,
 }
 
 #[derive(InputObject)]
@@ -114,5 +123,7 @@ pub struct SurvivorInput {
 #[derive(InputObject)]
 pub struct CloseFlightInput {
     pub tournament_id: ID,
-    pub survivors: Vec<SurvivorInput>,
+    /// Bagged stacks. Omitted, every player still seated in the flight is
+    /// bagged with the stack recorded on their seat.
+    pub survivors: Option<Vec<SurvivorInput>>,
 }
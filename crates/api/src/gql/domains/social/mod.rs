@@ -1,5 +1,8 @@
+pub mod rail;
 pub mod resolvers;
 pub mod types;
 
 pub use resolvers::{SocialMutation, SocialQuery};
-pub use types::{Friend, MutualFlame, YearInPoker};
+pub use types::{
+    FollowedFriend, Friend, FriendActivity, FriendActivityKind, MutualFlame, YearInPoker,
+};
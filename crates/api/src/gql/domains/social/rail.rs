@@ -0,0 +1,97 @@
+//! Rail notifications: tell followers when a friend reaches the final table,
+//! wins or busts. Followers opt in per friend (`friend_follow.notify`).
+
+use async_graphql::ID;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::gql::subscriptions::publish_user_notification;
+use crate::gql::types::{
+    NotificationType, UserNotification, TITLE_FRIEND_BUSTED, TITLE_FRIEND_FINAL_TABLE,
+    TITLE_FRIEND_WON,
+};
+use infra::repos::{friend_follows, tournaments, users};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RailEvent {
+    FinalTable,
+    Won,
+    Busted,
+}
+
+impl RailEvent {
+    fn notification_type(self) -> NotificationType {
+        match self {
+            RailEvent::FinalTable => NotificationType::FriendFinalTable,
+            RailEvent::Won => NotificationType::FriendWon,
+            RailEvent::Busted => NotificationType::FriendBusted,
+        }
+    }
+
+    /// Wire-format type, as used in push payloads.
+    fn wire(self) -> &'static str {
+        match self {
+            RailEvent::FinalTable => "FRIEND_FINAL_TABLE",
+            RailEvent::Won => "FRIEND_WON",
+            RailEvent::Busted => "FRIEND_BUSTED",
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            RailEvent::FinalTable => TITLE_FRIEND_FINAL_TABLE,
+            RailEvent::Won => TITLE_FRIEND_WON,
+            RailEvent::Busted => TITLE_FRIEND_BUSTED,
+        }
+    }
+
+    fn message(self, name: &str, tournament: &str) -> String {
+        match self {
+            RailEvent::FinalTable => format!("{name} reached the final table of {tournament}"),
+            RailEvent::Won => format!("{name} won {tournament}"),
+            RailEvent::Busted => format!("{name} was eliminated from {tournament}"),
+        }
+    }
+}
+
+/// Notify `friend_id`'s opted-in followers, in-app and by push. Best-effort:
+/// callers run this in a spawned task and failures are only logged.
+pub async fn notify_followers(db: &PgPool, friend_id: Uuid, tournament_id: Uuid, event: RailEvent) {
+    let followers = match friend_follows::list_notified_followers(db, friend_id).await {
+        Ok(f) if !f.is_empty() => f,
+        Ok(_) => return,
+        Err(e) => {
+            tracing::warn!(%friend_id, error = %e, "rail: failed to load followers");
+            return;
+        }
+    };
+    let (friend, tournament) = match (
+        users::get_by_id(db, friend_id).await,
+        tournaments::get_by_id(db, tournament_id).await,
+    ) {
+        (Ok(Some(friend)), Ok(Some(tournament))) => (friend, tournament),
+        _ => return,
+    };
+    let name = friend.username.unwrap_or(friend.first_name);
+    let message = event.message(&name, &tournament.name);
+
+    for follower_id in followers {
+        publish_user_notification(UserNotification {
+            id: ID::from(Uuid::new_v4().to_string()),
+            user_id: ID::from(follower_id.to_string()),
+            notification_type: event.notification_type(),
+            title: event.title().to_string(),
+            message: message.clone(),
+            tournament_id: Some(ID::from(tournament_id.to_string())),
+            created_at: chrono::Utc::now(),
+        });
+        crate::services::push_service::send_rail_event(
+            db,
+            follower_id,
+            event.wire(),
+            friend_id,
+            tournament_id,
+        )
+        .await;
+    }
+}
@@ -6,9 +6,13 @@ use crate::auth::jwt::Claims;
 use crate::gql::common::helpers::tournament_hidden_from_viewer;
 use crate::gql::error::ResultExt;
 use crate::state::AppState;
-use infra::repos::{attendance, friendships, wrapped};
+use infra::repos::{attendance, friend_follows, friendships, wrapped};
 
-use super::types::{Friend, YearInPoker};
+use super::types::{FollowedFriend, Friend, FriendActivity, YearInPoker};
+
+/// Default and maximum page size of the friends activity feed.
+const FEED_DEFAULT_LIMIT: i32 = 50;
+const FEED_MAX_LIMIT: i32 = 200;
 
 fn current_user_id(ctx: &Context<'_>) -> Result<Uuid> {
     let claims = ctx.data::<Claims>()?;
@@ -44,6 +48,28 @@ impl SocialQuery {
         Ok(rows.into_iter().map(Friend::from).collect())
     }
 
+    /// The friends the current user follows.
+    async fn my_followed_friends(&self, ctx: &Context<'_>) -> Result<Vec<FollowedFriend>> {
+        let state = ctx.data::<AppState>()?;
+        let user_id = current_user_id(ctx)?;
+        let rows = friend_follows::list_followed(&state.db, user_id).await?;
+        Ok(rows.into_iter().map(FollowedFriend::from).collect())
+    }
+
+    /// Recent wins, cashes, final tables and busts of followed friends,
+    /// newest first.
+    async fn friends_activity(
+        &self,
+        ctx: &Context<'_>,
+        limit: Option<i32>,
+    ) -> Result<Vec<FriendActivity>> {
+        let state = ctx.data::<AppState>()?;
+        let user_id = current_user_id(ctx)?;
+        let limit = limit.unwrap_or(FEED_DEFAULT_LIMIT).clamp(1, FEED_MAX_LIMIT);
+        let rows = friend_follows::activity_feed(&state.db, user_id, limit as i64).await?;
+        Ok(rows.into_iter().map(FriendActivity::from).collect())
+    }
+
     /// "Your Year in Poker" — a shareable annual recap (defaults to this year).
     async fn my_year_in_poker(&self, ctx: &Context<'_>, year: Option<i32>) -> Result<YearInPoker> {
         let state = ctx.data::<AppState>()?;
@@ -66,9 +92,9 @@ impl SocialQuery {
         Ok(YearInPoker {
             year,
             tournaments: stats.tournaments as i32,
-            buyins_cents: buyins as i32,
-            winnings_cents: winnings as i32,
-            net_cents: (winnings - buyins) as i32,
+            buyins_cents: buyins.into(),
+            winnings_cents: winnings.into(),
+            net_cents: (winnings - buyins).into(),
             itm_count: stats.itm_count as i32,
             best_finish: stats.best_finish,
             check_ins: check_ins as i32,
@@ -100,7 +126,7 @@ impl SocialMutation {
             return Err(async_graphql::Error::new("A friendship already exists"));
         }
 
-        let target = infra::repos::users::get_by_id(&state.db, other)
+        let name = infra::repos::users::public_name(&state.db, other)
             .await?
             .ok_or_else(|| async_graphql::Error::new("User not found"))?;
 
@@ -108,7 +134,7 @@ impl SocialMutation {
         Ok(Friend {
             friendship_id: row.id.into(),
             user_id: other.into(),
-            name: target.username.unwrap_or(target.first_name),
+            name,
             status: row.status,
             is_incoming: false,
             i_can_register_them: false,
@@ -131,14 +157,14 @@ impl SocialMutation {
         } else {
             row.requester_id
         };
-        let other = infra::repos::users::get_by_id(&state.db, other_id)
+        let name = infra::repos::users::public_name(&state.db, other_id)
             .await?
             .ok_or_else(|| async_graphql::Error::new("User not found"))?;
 
         Ok(Friend {
             friendship_id: row.id.into(),
             user_id: other_id.into(),
-            name: other.username.unwrap_or(other.first_name),
+            name,
             status: row.status,
             is_incoming: false,
             i_can_register_them: false,
@@ -154,6 +180,43 @@ impl SocialMutation {
         Ok(friendships::remove(&state.db, fid, me).await?)
     }
 
+    /// Follow an accepted friend's tournaments, or change whether rail
+    /// notifications (final table, win, bust) are sent. Notifications are
+    /// off unless asked for.
+    async fn follow_friend(
+        &self,
+        ctx: &Context<'_>,
+        user_id: ID,
+        notify: Option<bool>,
+    ) -> Result<FollowedFriend> {
+        let state = ctx.data::<AppState>()?;
+        let me = current_user_id(ctx)?;
+        let other = Uuid::parse_str(user_id.as_str()).gql_err("Invalid user ID")?;
+
+        let accepted = friendships::get_between(&state.db, me, other)
+            .await?
+            .is_some_and(|f| f.status == "accepted");
+        if !accepted {
+            return Err(async_graphql::Error::new("You can only follow friends"));
+        }
+
+        friend_follows::upsert(&state.db, me, other, notify.unwrap_or(false)).await?;
+        friend_follows::list_followed(&state.db, me)
+            .await?
+            .into_iter()
+            .find(|f| f.user_id == other)
+            .map(FollowedFriend::from)
+            .ok_or_else(|| async_graphql::Error::new("Friend not found"))
+    }
+
+    /// Stop following a friend.
+    async fn unfollow_friend(&self, ctx: &Context<'_>, user_id: ID) -> Result<bool> {
+        let state = ctx.data::<AppState>()?;
+        let me = current_user_id(ctx)?;
+        let other = Uuid::parse_str(user_id.as_str()).gql_err("Invalid user ID")?;
+        Ok(friend_follows::delete(&state.db, me, other).await?)
+    }
+
     /// Allow (or disallow) a friend to register the current user into tournaments.
     /// Sets the permission for the caller's own direction of the friendship.
     async fn set_friend_registration_permission(
@@ -204,13 +267,27 @@ impl SocialMutation {
         // register by user_id; the link trigger resolves their roster identity.
         let mut tx = state.db.begin().await?;
         let create_data = infra::repos::tournament_registrations::CreateTournamentRegistration {
+            id: None,
             tournament_id: tournament_uuid,
             user_id: Some(friend_id),
             club_player_id: None,
             notes: Some("Registered by a friend".to_string()),
             status: None,
+            invite_id: None,
+        };
+        let Some(row) =
+            infra::repos::tournament_registrations::create_if_absent(&mut *tx, create_data).await?
+        else {
+            drop(tx);
+            let existing = infra::repos::tournament_registrations::get_by_tournament_and_user(
+                &state.db,
+                tournament_uuid,
+                friend_id,
+            )
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Failed to register"))?;
+            return crate::gql::domains::registrations::already_registered(existing);
         };
-        let row = infra::repos::tournament_registrations::create(&mut *tx, create_data).await?;
         tx.commit().await?;
 
         Ok(row.into())
@@ -1,8 +1,9 @@
-use async_graphql::{ComplexObject, Context, SimpleObject, ID};
-use chrono::Utc;
+use async_graphql::{ComplexObject, Context, Enum, SimpleObject, ID};
+use chrono::{DateTime, Utc};
 
 use crate::auth::jwt::Claims;
 use crate::gql::error::ResultExt;
+use crate::gql::scalars::Money;
 use crate::state::AppState;
 
 /// A shared flame stays "alive" only while both friends keep turning up: it dies
@@ -81,9 +82,9 @@ impl From<infra::models::FriendRow> for Friend {
 pub struct YearInPoker {
     pub year: i32,
     pub tournaments: i32,
-    pub buyins_cents: i32,
-    pub winnings_cents: i32,
-    pub net_cents: i32,
+    pub buyins_cents: Money,
+    pub winnings_cents: Money,
+    pub net_cents: Money,
     pub itm_count: i32,
     pub best_finish: Option<i32>,
     pub check_ins: i32,
@@ -91,3 +92,68 @@ pub struct YearInPoker {
     pub favorite_club: Option<String>,
     pub nemesis_name: Option<String>,
 }
+
+/// A friend the current user follows.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct FollowedFriend {
+    pub user_id: ID,
+    pub name: String,
+    /// Whether rail notifications (final table, win, bust) are on.
+    pub notify: bool,
+    pub followed_since: DateTime<Utc>,
+}
+
+impl From<infra::repos::friend_follows::FollowedFriendRow> for FollowedFriend {
+    fn from(r: infra::repos::friend_follows::FollowedFriendRow) -> Self {
+        Self {
+            user_id: r.user_id.into(),
+            name: r.name,
+            notify: r.notify,
+            followed_since: r.created_at,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum FriendActivityKind {
+    Won,
+    Cashed,
+    FinalTable,
+    Busted,
+}
+
+/// One event in the friends activity feed.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct FriendActivity {
+    pub kind: FriendActivityKind,
+    pub user_id: ID,
+    pub name: String,
+    pub tournament_id: ID,
+    pub tournament_name: String,
+    pub club_name: String,
+    /// Set for wins and cashes.
+    pub final_position: Option<i32>,
+    pub prize_cents: Option<Money>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl From<infra::repos::friend_follows::FriendActivityRow> for FriendActivity {
+    fn from(r: infra::repos::friend_follows::FriendActivityRow) -> Self {
+        Self {
+            kind: match r.kind.as_str() {
+                "won" => FriendActivityKind::Won,
+                "cashed" => FriendActivityKind::Cashed,
+                "final_table" => FriendActivityKind::FinalTable,
+                _ => FriendActivityKind::Busted,
+            },
+            user_id: r.user_id.into(),
+            name: r.name,
+            tournament_id: r.tournament_id.into(),
+            tournament_name: r.tournament_name,
+            club_name: r.club_name,
+            final_position: r.final_position,
+            prize_cents: r.prize_cents.map(Into::into),
+            occurred_at: r.occurred_at,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod resolvers;
+pub mod rotation;
+pub mod types;
+
+pub use resolvers::{StaffMutation, StaffQuery};
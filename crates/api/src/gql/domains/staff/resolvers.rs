@@ -0,0 +1,470 @@
+use std::collections::{HashMap, HashSet};
+
+use async_graphql::{Context, Object, Result, ID};
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::auth::jwt::Claims;
+use crate::auth::permissions::require_club_manager;
+use crate::gql::common::helpers::get_club_id_for_tournament;
+use crate::gql::error::ResultExt;
+use crate::gql::scalars::Money;
+use crate::state::AppState;
+use infra::repos::club_staff::{ClubStaffRow, CreateClubStaff};
+use infra::repos::{
+    club_staff, club_tables, dealer_rotation, dealer_rotation::CreateDealerPush, staff_shifts,
+    staff_shifts::ClockIn,
+};
+
+use super::rotation::build_rotation;
+use super::types::{
+    ClockInInput, ClockOutInput, ClubStaff, CreateClubStaffInput, DealerDownCount, DealerPush,
+    GenerateDealerRotationInput, SetTournamentDealersInput, StaffRole, StaffShift,
+    StaffShiftReport, StaffShiftSummary,
+};
+
+/// Upper bound on pushes generated at once (a long day at 20-minute pushes).
+const MAX_PUSHES: i32 = 100;
+
+/// Staff clock themselves in and out; a manager can do it for anyone on the
+/// club's roster. Returns the caller's user ID.
+async fn authorize_time_clock(ctx: &Context<'_>, staff: &ClubStaffRow) -> Result<Uuid> {
+    let claims = ctx.data::<Claims>()?;
+    let caller = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+    if staff.user_id != Some(caller) {
+        require_club_manager(ctx, staff.club_id).await?;
+    }
+    Ok(caller)
+}
+
+async fn get_staff(state: &AppState, staff_id: &ID) -> Result<ClubStaffRow> {
+    let staff_id = Uuid::parse_str(staff_id.as_str()).gql_err("Invalid staff ID")?;
+    club_staff::get_by_id(&state.db, staff_id)
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Staff member not found"))
+}
+
+/// Attach dealer names and table numbers to schedule rows.
+async fn to_dealer_pushes(
+    state: &AppState,
+    tournament_id: Uuid,
+    rows: Vec<dealer_rotation::DealerPushRow>,
+) -> Result<Vec<DealerPush>> {
+    let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+    let names: HashMap<Uuid, String> = club_staff::list_by_club(&state.db, club_id, None, false)
+        .await?
+        .into_iter()
+        .map(|s| (s.id, s.display_name))
+        .collect();
+    let table_numbers: HashMap<Uuid, i32> = club_tables::list_by_club(&state.db, club_id)
+        .await?
+        .into_iter()
+        .map(|t| (t.id, t.table_number))
+        .collect();
+
+    Ok(rows
+        .into_iter()
+        .map(|r| DealerPush {
+            id: r.id.into(),
+            staff_id: r.staff_id.into(),
+            dealer_name: names.get(&r.staff_id).cloned().unwrap_or_default(),
+            club_table_id: r.club_table_id.map(Into::into),
+            table_number: r
+                .club_table_id
+                .and_then(|id| table_numbers.get(&id).copied()),
+            push_number: r.push_number,
+            starts_at: r.starts_at,
+            ends_at: r.ends_at,
+        })
+        .collect())
+}
+
+#[derive(Default)]
+pub struct StaffQuery;
+
+#[Object]
+impl StaffQuery {
+    /// A club's staff roster (managers only).
+    async fn club_staff(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+        role: Option<StaffRole>,
+        #[graphql(default = true)] active_only: bool,
+    ) -> Result<Vec<ClubStaff>> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let rows =
+            club_staff::list_by_club(&state.db, club_id, role.map(StaffRole::as_db), active_only)
+                .await?;
+        Ok(rows.into_iter().map(ClubStaff::from).collect())
+    }
+
+    /// The dealers assigned to a tournament (managers only).
+    async fn tournament_dealers(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Vec<ClubStaff>> {
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let rows = dealer_rotation::list_pool(&state.db, tournament_id).await?;
+        Ok(rows.into_iter().map(ClubStaff::from).collect())
+    }
+
+    /// The dealer push schedule for a tournament, in push order (managers only).
+    async fn dealer_rotation(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Vec<DealerPush>> {
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let rows = dealer_rotation::list_pushes(&state.db, tournament_id).await?;
+        to_dealer_pushes(state, tournament_id, rows).await
+    }
+
+    /// Completed downs per dealer between `from` and `to`, across every
+    /// tournament at the club — the payroll figure (managers only).
+    async fn dealer_down_counts(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<DealerDownCount>> {
+        let club_id = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        if to <= from {
+            return Err(async_graphql::Error::new("`to` must be after `from`"));
+        }
+        let state = ctx.data::<AppState>()?;
+
+        let rows = dealer_rotation::down_counts(&state.db, club_id, from, to).await?;
+        Ok(rows.into_iter().map(DealerDownCount::from).collect())
+    }
+
+    /// Hours and pay per staff member for shifts started in `[from, to)` — a
+    /// pay period (managers only).
+    async fn staff_shift_report(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<StaffShiftReport> {
+        let club_uuid = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_uuid).await?;
+        if to <= from {
+            return Err(async_graphql::Error::new("`to` must be after `from`"));
+        }
+        let state = ctx.data::<AppState>()?;
+
+        let lines: Vec<StaffShiftSummary> =
+            staff_shifts::summary_by_club(&state.db, club_uuid, from, to)
+                .await?
+                .into_iter()
+                .map(StaffShiftSummary::from)
+                .collect();
+        let total_minutes = lines.iter().map(|l| l.minutes).sum();
+        let total_cost_cents = Money::checked_sum(lines.iter().map(|l| l.cost_cents))
+            .ok_or_else(|| async_graphql::Error::new("Shift cost total overflows"))?;
+        let open_shifts = staff_shifts::list_by_club(&state.db, club_uuid, from, to)
+            .await?
+            .into_iter()
+            .filter(|s| s.clock_out_at.is_none())
+            .map(StaffShift::from)
+            .collect();
+
+        Ok(StaffShiftReport {
+            club_id,
+            from,
+            to,
+            lines,
+            total_minutes,
+            total_cost_cents,
+            open_shifts,
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct StaffMutation;
+
+#[Object]
+impl StaffMutation {
+    /// Add someone to a club's staff roster (managers only).
+    async fn create_club_staff(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateClubStaffInput,
+    ) -> Result<ClubStaff> {
+        let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
+        require_club_manager(ctx, club_id).await?;
+        let state = ctx.data::<AppState>()?;
+
+        let display_name = input.display_name.trim();
+        if display_name.is_empty() {
+            return Err(async_graphql::Error::new("Display name is required"));
+        }
+        if input.hourly_rate_cents.is_some_and(|r| r.cents() < 0) {
+            return Err(async_graphql::Error::new("Hourly rate cannot be negative"));
+        }
+        let user_id = input
+            .user_id
+            .map(|id| Uuid::parse_str(id.as_str()))
+            .transpose()
+            .gql_err("Invalid user ID")?;
+
+        let row = club_staff::create(
+            &state.db,
+            CreateClubStaff {
+                club_id,
+                display_name: display_name.to_string(),
+                user_id,
+                role: input.role.as_db().to_string(),
+                hourly_rate_cents: input.hourly_rate_cents.map(Money::cents),
+            },
+        )
+        .await?;
+        Ok(row.into())
+    }
+
+    /// Activate or deactivate a staff member (managers only).
+    async fn set_club_staff_active(
+        &self,
+        ctx: &Context<'_>,
+        staff_id: ID,
+        is_active: bool,
+    ) -> Result<ClubStaff> {
+        let staff_id = Uuid::parse_str(staff_id.as_str()).gql_err("Invalid staff ID")?;
+        let state = ctx.data::<AppState>()?;
+        let staff = club_staff::get_by_id(&state.db, staff_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Staff member not found"))?;
+        require_club_manager(ctx, staff.club_id).await?;
+
+        let row = club_staff::set_active(&state.db, staff_id, is_active)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Staff member not found"))?;
+        Ok(row.into())
+    }
+
+    /// Set (or clear) a staff member's hourly rate (managers only). Applies to
+    /// shifts clocked in from now on.
+    async fn set_club_staff_hourly_rate(
+        &self,
+        ctx: &Context<'_>,
+        staff_id: ID,
+        hourly_rate_cents: Option<Money>,
+    ) -> Result<ClubStaff> {
+        let state = ctx.data::<AppState>()?;
+        let staff = get_staff(state, &staff_id).await?;
+        require_club_manager(ctx, staff.club_id).await?;
+        if hourly_rate_cents.is_some_and(|r| r.cents() < 0) {
+            return Err(async_graphql::Error::new("Hourly rate cannot be negative"));
+        }
+
+        let row =
+            club_staff::set_hourly_rate(&state.db, staff.id, hourly_rate_cents.map(Money::cents))
+                .await?
+                .ok_or_else(|| async_graphql::Error::new("Staff member not found"))?;
+        Ok(row.into())
+    }
+
+    /// Start a shift. Staff clock themselves in; managers can clock in anyone
+    /// at their club.
+    async fn clock_in(&self, ctx: &Context<'_>, input: ClockInInput) -> Result<StaffShift> {
+        let state = ctx.data::<AppState>()?;
+        let staff = get_staff(state, &input.staff_id).await?;
+        let caller = authorize_time_clock(ctx, &staff).await?;
+        if !staff.is_active {
+            return Err(async_graphql::Error::new("Staff member is inactive"));
+        }
+
+        let tournament_id = match input.tournament_id {
+            Some(id) => {
+                let id = Uuid::parse_str(id.as_str()).gql_err("Invalid tournament ID")?;
+                if get_club_id_for_tournament(&state.db, id).await? != staff.club_id {
+                    return Err(async_graphql::Error::new(
+                        "Tournament belongs to a different club",
+                    ));
+                }
+                Some(id)
+            }
+            None => None,
+        };
+
+        let row = staff_shifts::clock_in(
+            &state.db,
+            ClockIn {
+                club_id: staff.club_id,
+                staff_id: staff.id,
+                tournament_id,
+                hourly_rate_cents: staff.hourly_rate_cents,
+                notes: input.notes,
+                recorded_by: Some(caller),
+            },
+        )
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Already clocked in"))?;
+        Ok(row.into())
+    }
+
+    /// End the open shift. Staff clock themselves out; managers can clock out
+    /// anyone at their club.
+    async fn clock_out(&self, ctx: &Context<'_>, input: ClockOutInput) -> Result<StaffShift> {
+        let state = ctx.data::<AppState>()?;
+        let staff = get_staff(state, &input.staff_id).await?;
+        authorize_time_clock(ctx, &staff).await?;
+
+        let row = staff_shifts::clock_out(&state.db, staff.id, Utc::now())
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Not clocked in"))?;
+        Ok(row.into())
+    }
+
+    /// Replace the dealer pool for a tournament (managers only). Every member
+    /// must be an active dealer of the hosting club.
+    async fn set_tournament_dealers(
+        &self,
+        ctx: &Context<'_>,
+        input: SetTournamentDealersInput,
+    ) -> Result<Vec<ClubStaff>> {
+        let tournament_id =
+            Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        require_club_manager(ctx, club_id).await?;
+
+        let dealers: HashSet<Uuid> =
+            club_staff::list_by_club(&state.db, club_id, Some(StaffRole::Dealer.as_db()), true)
+                .await?
+                .into_iter()
+                .map(|s| s.id)
+                .collect();
+
+        let mut staff_ids = Vec::with_capacity(input.staff_ids.len());
+        for id in &input.staff_ids {
+            let id = Uuid::parse_str(id.as_str()).gql_err("Invalid staff ID")?;
+            if !dealers.contains(&id) {
+                return Err(async_graphql::Error::new(format!(
+                    "Staff member {id} is not an active dealer at this club"
+                )));
+            }
+            if !staff_ids.contains(&id) {
+                staff_ids.push(id);
+            }
+        }
+
+        let mut tx = state.db.begin().await?;
+        dealer_rotation::set_pool(&mut tx, tournament_id, &staff_ids).await?;
+        tx.commit().await?;
+
+        let rows = dealer_rotation::list_pool(&state.db, tournament_id).await?;
+        Ok(rows.into_iter().map(ClubStaff::from).collect())
+    }
+
+    /// Generate the push schedule from the tournament's dealer pool and active
+    /// tables (managers only). Pushes from `startsAt` on are replaced; downs
+    /// already dealt are kept.
+    async fn generate_dealer_rotation(
+        &self,
+        ctx: &Context<'_>,
+        input: GenerateDealerRotationInput,
+    ) -> Result<Vec<DealerPush>> {
+        let tournament_id =
+            Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let state = ctx.data::<AppState>()?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid manager ID")?;
+
+        if input.push_minutes <= 0 {
+            return Err(async_graphql::Error::new("Push length must be positive"));
+        }
+        if input.pushes <= 0 || input.pushes > MAX_PUSHES {
+            return Err(async_graphql::Error::new(format!(
+                "Pushes must be between 1 and {MAX_PUSHES}"
+            )));
+        }
+
+        let dealers = dealer_rotation::list_pool(&state.db, tournament_id).await?;
+        let tables = club_tables::list_assigned_to_tournament(&state.db, tournament_id).await?;
+        if tables.is_empty() {
+            return Err(async_graphql::Error::new(
+                "Tournament has no tables assigned",
+            ));
+        }
+        let schedule = build_rotation(dealers.len(), tables.len(), input.pushes as usize)
+            .ok_or_else(|| {
+                async_graphql::Error::new(format!(
+                    "Need at least {} dealers for {} tables, the pool has {}",
+                    tables.len(),
+                    tables.len(),
+                    dealers.len()
+                ))
+            })?;
+
+        let starts_at = input.starts_at.unwrap_or_else(Utc::now);
+        let push_length = Duration::minutes(input.push_minutes as i64);
+
+        let mut tx = state.db.begin().await?;
+        dealer_rotation::delete_pushes_from(&mut *tx, tournament_id, starts_at).await?;
+        let mut rows = Vec::with_capacity(schedule.len() * dealers.len());
+        for (push_number, seats) in schedule.iter().enumerate() {
+            let push_start = starts_at + push_length * push_number as i32;
+            for (dealer, table) in dealers.iter().zip(seats) {
+                let row = dealer_rotation::create_push(
+                    &mut *tx,
+                    tournament_id,
+                    CreateDealerPush {
+                        staff_id: dealer.id,
+                        club_table_id: table.map(|t| tables[t].id),
+                        push_number: push_number as i32,
+                        starts_at: push_start,
+                        ends_at: push_start + push_length,
+                    },
+                )
+                .await?;
+                rows.push(row);
+            }
+        }
+        tx.commit().await?;
+
+        {
+            let db = state.db.clone();
+            let pushes = input.pushes;
+            let push_minutes = input.push_minutes;
+            let dealer_count = dealers.len();
+            tokio::spawn(async move {
+                crate::gql::domains::activity_log::log_and_publish(
+                    &db,
+                    tournament_id,
+                    "seating",
+                    "dealer_rotation_generated",
+                    Some(manager_id),
+                    None,
+                    serde_json::json!({
+                        "pushes": pushes,
+                        "push_minutes": push_minutes,
+                        "dealers": dealer_count,
+                    }),
+                )
+                .await;
+            });
+        }
+
+        to_dealer_pushes(state, tournament_id, rows).await
+    }
+}
@@ -0,0 +1,61 @@
+//! Dealer push schedule.
+//!
+//! Dealers stand in a fixed circle of positions: one per active table, then
+//! one per spare dealer (a break). Every push each dealer steps to the next
+//! position, so they work the tables in order and the breaks are spread evenly
+//! across the pool. Pure (no DB) so the rotation is unit-testable.
+
+/// Where each dealer sits for each push: `schedule[push][dealer]` is the index
+/// of the table they deal, or `None` when on break.
+///
+/// Returns `None` when there are fewer dealers than tables (some table would
+/// go without a dealer).
+pub fn build_rotation(
+    dealers: usize,
+    tables: usize,
+    pushes: usize,
+) -> Option<Vec<Vec<Option<usize>>>> {
+    if dealers < tables || dealers == 0 {
+        return None;
+    }
+    let schedule = (0..pushes)
+        .map(|push| {
+            (0..dealers)
+                .map(|dealer| {
+                    let position = (dealer + push) % dealers;
+                    (position < tables).then_some(position)
+                })
+                .collect()
+        })
+        .collect();
+    Some(schedule)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_table_is_dealt_every_push() {
+        let schedule = build_rotation(5, 3, 10).unwrap();
+        for push in &schedule {
+            let mut tables: Vec<usize> = push.iter().flatten().copied().collect();
+            tables.sort();
+            assert_eq!(tables, vec![0, 1, 2]);
+        }
+    }
+
+    #[test]
+    fn dealers_move_to_the_next_table_and_share_breaks() {
+        let schedule = build_rotation(3, 2, 3).unwrap();
+        assert_eq!(schedule[0], vec![Some(0), Some(1), None]);
+        assert_eq!(schedule[1], vec![Some(1), None, Some(0)]);
+        assert_eq!(schedule[2], vec![None, Some(0), Some(1)]);
+    }
+
+    #[test]
+    fn too_few_dealers() {
+        assert!(build_rotation(2, 3, 4).is_none());
+        assert!(build_rotation(0, 0, 1).is_none());
+    }
+}
@@ -0,0 +1,226 @@
+use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use crate::gql::scalars::Money;
+use infra::repos::club_staff::ClubStaffRow;
+use infra::repos::dealer_rotation::{CurrentDealerRow, DealerDownCountRow};
+use infra::repos::staff_shifts::{ShiftSummaryRow, StaffShiftRow};
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum StaffRole {
+    Dealer,
+    Floor,
+    Cashier,
+    Other,
+}
+
+impl StaffRole {
+    pub fn as_db(self) -> &'static str {
+        match self {
+            StaffRole::Dealer => "dealer",
+            StaffRole::Floor => "floor",
+            StaffRole::Cashier => "cashier",
+            StaffRole::Other => "other",
+        }
+    }
+}
+
+impl From<String> for StaffRole {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "dealer" => StaffRole::Dealer,
+            "floor" => StaffRole::Floor,
+            "cashier" => StaffRole::Cashier,
+            _ => StaffRole::Other,
+        }
+    }
+}
+
+/// A member of a club's staff roster.
+#[derive(SimpleObject, Clone)]
+pub struct ClubStaff {
+    pub id: ID,
+    pub club_id: ID,
+    pub display_name: String,
+    /// The staff member's app account, if they have one.
+    pub user_id: Option<ID>,
+    pub role: StaffRole,
+    pub hourly_rate_cents: Option<Money>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ClubStaffRow> for ClubStaff {
+    fn from(row: ClubStaffRow) -> Self {
+        Self {
+            id: row.id.into(),
+            club_id: row.club_id.into(),
+            display_name: row.display_name,
+            user_id: row.user_id.map(Into::into),
+            role: row.role.into(),
+            hourly_rate_cents: row.hourly_rate_cents.map(Money::from),
+            is_active: row.is_active,
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// One dealer's slot in the push schedule.
+#[derive(SimpleObject, Clone)]
+pub struct DealerPush {
+    pub id: ID,
+    pub staff_id: ID,
+    pub dealer_name: String,
+    /// Null when the dealer is on break for this push.
+    pub club_table_id: Option<ID>,
+    pub table_number: Option<i32>,
+    pub push_number: i32,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+/// The dealer currently in the box at a table.
+#[derive(SimpleObject, Clone)]
+pub struct CurrentDealer {
+    pub staff_id: ID,
+    pub display_name: String,
+    /// When the next push relieves them.
+    pub until: DateTime<Utc>,
+}
+
+impl From<CurrentDealerRow> for CurrentDealer {
+    fn from(row: CurrentDealerRow) -> Self {
+        Self {
+            staff_id: row.staff_id.into(),
+            display_name: row.display_name,
+            until: row.ends_at,
+        }
+    }
+}
+
+/// Completed downs for one dealer over a pay period.
+#[derive(SimpleObject, Clone)]
+pub struct DealerDownCount {
+    pub staff_id: ID,
+    pub display_name: String,
+    pub downs: i64,
+    /// Minutes spent dealing (breaks excluded).
+    pub minutes: i64,
+}
+
+impl From<DealerDownCountRow> for DealerDownCount {
+    fn from(row: DealerDownCountRow) -> Self {
+        Self {
+            staff_id: row.staff_id.into(),
+            display_name: row.display_name,
+            downs: row.downs,
+            minutes: row.minutes,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreateClubStaffInput {
+    pub club_id: ID,
+    pub display_name: String,
+    pub user_id: Option<ID>,
+    #[graphql(default_with = "StaffRole::Dealer")]
+    pub role: StaffRole,
+    pub hourly_rate_cents: Option<Money>,
+}
+
+#[derive(InputObject)]
+pub struct SetTournamentDealersInput {
+    pub tournament_id: ID,
+    pub staff_ids: Vec<ID>,
+}
+
+#[derive(InputObject)]
+pub struct GenerateDealerRotationInput {
+    pub tournament_id: ID,
+    /// First push time; defaults to now. Pushes from here on are replaced.
+    pub starts_at: Option<DateTime<Utc>>,
+    #[graphql(default = 30)]
+    pub push_minutes: i32,
+    /// How many pushes to schedule.
+    pub pushes: i32,
+}
+
+/// One clocked shift.
+#[derive(SimpleObject, Clone)]
+pub struct StaffShift {
+    pub id: ID,
+    pub club_id: ID,
+    pub staff_id: ID,
+    /// The tournament this shift is costed against, if any.
+    pub tournament_id: Option<ID>,
+    pub clock_in_at: DateTime<Utc>,
+    /// Null while the shift is open.
+    pub clock_out_at: Option<DateTime<Utc>>,
+    /// Rate in effect at clock-in.
+    pub hourly_rate_cents: Option<Money>,
+    pub notes: Option<String>,
+}
+
+impl From<StaffShiftRow> for StaffShift {
+    fn from(row: StaffShiftRow) -> Self {
+        Self {
+            id: row.id.into(),
+            club_id: row.club_id.into(),
+            staff_id: row.staff_id.into(),
+            tournament_id: row.tournament_id.map(Into::into),
+            clock_in_at: row.clock_in_at,
+            clock_out_at: row.clock_out_at,
+            hourly_rate_cents: row.hourly_rate_cents.map(Money::from),
+            notes: row.notes,
+        }
+    }
+}
+
+/// Hours and pay for one staff member over a pay period.
+#[derive(SimpleObject, Clone)]
+pub struct StaffShiftSummary {
+    pub staff_id: ID,
+    pub display_name: String,
+    pub shifts: i32,
+    pub minutes: i64,
+    pub cost_cents: Money,
+}
+
+impl From<ShiftSummaryRow> for StaffShiftSummary {
+    fn from(row: ShiftSummaryRow) -> Self {
+        Self {
+            staff_id: row.staff_id.into(),
+            display_name: row.display_name,
+            shifts: row.shifts as i32,
+            minutes: row.minutes,
+            cost_cents: row.cost_cents.into(),
+        }
+    }
+}
+
+/// Closed shifts started within a pay period, totalled per staff member.
+#[derive(SimpleObject, Clone)]
+pub struct StaffShiftReport {
+    pub club_id: ID,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub lines: Vec<StaffShiftSummary>,
+    pub total_minutes: i64,
+    pub total_cost_cents: Money,
+    /// Staff still clocked in (not yet counted above).
+    pub open_shifts: Vec<StaffShift>,
+}
+
+#[derive(InputObject)]
+pub struct ClockInInput {
+    pub staff_id: ID,
+    /// Book the shift against a tournament for cost accounting.
+    pub tournament_id: Option<ID>,
+    pub notes: Option<String>,
+}
+
+#[derive(InputObject)]
+pub struct ClockOutInput {
+    pub staff_id: ID,
+}
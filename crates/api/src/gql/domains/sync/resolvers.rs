@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use async_graphql::{Context, Object, Result};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::auth::jwt::Claims;
+use crate::auth::permissions::require_club_manager;
+use crate::gql::error::{auth_error, ResultExt};
+use crate::state::AppState;
+use infra::repos::sync_versions::{self, SyncVersionRow};
+
+use super::types::{SyncEntity, SyncItemInput, SyncRecord, SyncStatus};
+
+/// Most records one `syncStatus` call checks.
+const MAX_ITEMS: usize = 500;
+
+#[derive(Default)]
+pub struct SyncQuery;
+
+#[Object]
+impl SyncQuery {
+    /// Server versions of the records an offline desk holds, in the order
+    /// asked, so it can reconcile its queue after reconnecting. Records of
+    /// clubs the caller doesn't manage come back as not existing.
+    async fn sync_status(
+        &self,
+        ctx: &Context<'_>,
+        items: Vec<SyncItemInput>,
+    ) -> Result<SyncStatus> {
+        ctx.data::<Claims>().map_err(|_| auth_error())?;
+        let state = ctx.data::<AppState>()?;
+        if items.len() > MAX_ITEMS {
+            return Err(async_graphql::Error::new(format!(
+                "At most {MAX_ITEMS} records per call"
+            )));
+        }
+
+        let mut wanted: Vec<(SyncEntity, Uuid)> = Vec::with_capacity(items.len());
+        for item in &items {
+            let id = Uuid::parse_str(item.id.as_str()).gql_err("Invalid record ID")?;
+            wanted.push((item.entity, id));
+        }
+        let ids_of = |entity: SyncEntity| -> Vec<Uuid> {
+            wanted
+                .iter()
+                .filter(|(e, _)| *e == entity)
+                .map(|(_, id)| *id)
+                .collect()
+        };
+        let entry_ids = ids_of(SyncEntity::TournamentEntry);
+        let registration_ids = ids_of(SyncEntity::TournamentRegistration);
+        let (entries, registrations) = tokio::try_join!(
+            sync_versions::tournament_entries(&state.db, &entry_ids),
+            sync_versions::tournament_registrations(&state.db, &registration_ids),
+        )?;
+
+        let mut managed: HashMap<Uuid, bool> = HashMap::new();
+        let mut found: HashMap<(SyncEntity, Uuid), SyncVersionRow> = HashMap::new();
+        for (entity, rows) in [
+            (SyncEntity::TournamentEntry, entries),
+            (SyncEntity::TournamentRegistration, registrations),
+        ] {
+            for row in rows {
+                let visible = match managed.get(&row.club_id) {
+                    Some(visible) => *visible,
+                    None => {
+                        let visible = require_club_manager(ctx, row.club_id).await.is_ok();
+                        managed.insert(row.club_id, visible);
+                        visible
+                    }
+                };
+                if visible {
+                    found.insert((entity, row.id), row);
+                }
+            }
+        }
+
+        let records = wanted
+            .into_iter()
+            .map(|(entity, id)| {
+                let row = found.get(&(entity, id));
+                SyncRecord {
+                    entity,
+                    id: id.into(),
+                    exists: row.is_some(),
+                    version: row.map(|r| r.updated_at),
+                }
+            })
+            .collect();
+
+        Ok(SyncStatus {
+            server_time: Utc::now(),
+            records,
+        })
+    }
+}
@@ -0,0 +1,34 @@
+use async_graphql::{Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+/// Records an offline desk can create with its own ids.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum SyncEntity {
+    TournamentEntry,
+    TournamentRegistration,
+}
+
+#[derive(InputObject)]
+pub struct SyncItemInput {
+    pub entity: SyncEntity,
+    pub id: ID,
+}
+
+/// The server's side of one record the client holds.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct SyncRecord {
+    pub entity: SyncEntity,
+    pub id: ID,
+    /// False when the server has no such record: the queued create never
+    /// arrived, or the record was deleted since.
+    pub exists: bool,
+    /// The record's `updatedAt` on the server; a client copy with an older
+    /// one is stale.
+    pub version: Option<DateTime<Utc>>,
+}
+
+#[derive(SimpleObject, Clone, Debug)]
+pub struct SyncStatus {
+    pub server_time: DateTime<Utc>,
+    pub records: Vec<SyncRecord>,
+}
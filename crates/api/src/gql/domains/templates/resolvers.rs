@@ -171,6 +171,7 @@ fn blind_levels_json(
                     "durationMinutes": l.duration_minutes,
                     "isBreak": l.is_break,
                     "breakDurationMinutes": l.break_duration_minutes,
+                    "colorUpDenomination": l.color_up_denomination,
                 })
             })
             .collect::<Vec<_>>(),
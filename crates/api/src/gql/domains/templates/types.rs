@@ -40,6 +40,8 @@ pub struct BlindStructureLevel {
     pub is_break: bool,
     #[serde(rename = "breakDurationMinutes")]
     pub break_duration_minutes: Option<i32>,
+    #[serde(rename = "colorUpDenomination", default)]
+    pub color_up_denomination: Option<i32>,
 }
 
 #[derive(SimpleObject, Clone)]
@@ -90,6 +92,7 @@ pub struct BlindStructureLevelInput {
     pub duration_minutes: i32,
     pub is_break: bool,
     pub break_duration_minutes: Option<i32>,
+    pub color_up_denomination: Option<i32>,
 }
 
 #[derive(InputObject)]
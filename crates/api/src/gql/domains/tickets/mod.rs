@@ -0,0 +1,23 @@
+pub mod resolvers;
+pub mod types;
+
+pub use resolvers::{TicketMutation, TicketQuery};
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use infra::repos::entry_tickets;
+
+/// Entry types that put a player in a seat and so get a ticket. Rebuys and
+/// add-ons top up a stack that is already seated.
+pub fn issues_ticket(entry_type: &str) -> bool {
+    matches!(entry_type, "initial" | "re_entry")
+}
+
+/// Issue the ticket for a freshly added entry. Best-effort: a failure is
+/// logged and the manager can reissue it later.
+pub async fn issue_logged(db: &PgPool, entry_id: Uuid, issued_by: Option<Uuid>) {
+    if let Err(e) = entry_tickets::create(db, entry_id, issued_by).await {
+        tracing::error!(entry_id = %entry_id, error = %e, "Ticket issue failed");
+    }
+}
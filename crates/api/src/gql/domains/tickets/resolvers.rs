@@ -0,0 +1,178 @@
+use async_graphql::{Context, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::auth::jwt::Claims;
+use crate::auth::permissions::{require_club_manager, viewer_manages_club};
+use crate::gql::domains::printouts::link::{self, TokenScope};
+use crate::gql::domains::staff::types::StaffRole;
+use crate::gql::error::ResultExt;
+use crate::state::AppState;
+use infra::repos::{club_staff, entry_tickets, tournament_entries, tournaments};
+
+use super::issues_ticket;
+use super::types::{EntryTicket, EntryTicketVerification, TicketCheckResult};
+
+const MAX_REASON_LENGTH: usize = 500;
+
+/// Tickets are scanned by the floor: a manager of the club, or an active
+/// floor-staff member linked to the caller's account. Returns the caller's
+/// user ID.
+async fn require_floor_staff(ctx: &Context<'_>, club_id: Uuid) -> Result<Uuid> {
+    let claims = ctx.data::<Claims>()?;
+    let caller = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+    if viewer_manages_club(ctx, club_id).await {
+        return Ok(caller);
+    }
+    let state = ctx.data::<AppState>()?;
+    club_staff::find_active_for_user(&state.db, club_id, caller, StaffRole::Floor.as_db())
+        .await?
+        .ok_or_else(|| async_graphql::Error::new("Only floor staff can verify tickets"))?;
+    Ok(caller)
+}
+
+#[derive(Default)]
+pub struct TicketQuery;
+
+#[Object]
+impl TicketQuery {
+    /// Check a scanned ticket at seating. The first scan of a genuine ticket
+    /// marks it verified; later scans come back as `DUPLICATE`. Pass the
+    /// tournament being seated to catch tickets for another event. Managers
+    /// and floor staff of the ticket's club.
+    async fn verify_entry_ticket(
+        &self,
+        ctx: &Context<'_>,
+        token: String,
+        tournament_id: Option<ID>,
+    ) -> Result<EntryTicketVerification> {
+        let state = ctx.data::<AppState>()?;
+        ctx.data::<Claims>()?;
+        let invalid = EntryTicketVerification {
+            result: TicketCheckResult::Invalid,
+            ticket: None,
+        };
+
+        let Some(ticket_id) =
+            link::decode_token(&state.auth_config().jwt_secret, TokenScope::Ticket, &token)
+        else {
+            return Ok(invalid);
+        };
+        let Some(ticket) = entry_tickets::get_by_id(&state.db, ticket_id).await? else {
+            return Ok(invalid);
+        };
+        let caller = require_floor_staff(ctx, ticket.club_id).await?;
+
+        if let Some(tournament_id) = tournament_id {
+            let tournament_id =
+                Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+            if ticket.tournament_id != tournament_id {
+                return Ok(EntryTicketVerification {
+                    result: TicketCheckResult::WrongTournament,
+                    ticket: Some(EntryTicket::new(ticket, state)),
+                });
+            }
+        }
+
+        let mut tx = state.db.begin().await?;
+        let ticket = entry_tickets::lock(&mut *tx, ticket_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Ticket not found"))?;
+        let (result, ticket) = match ticket.status.as_str() {
+            "voided" => (TicketCheckResult::Voided, ticket),
+            "verified" => (
+                TicketCheckResult::Duplicate,
+                entry_tickets::record_duplicate_scan(&mut *tx, ticket_id).await?,
+            ),
+            _ => (
+                TicketCheckResult::Valid,
+                entry_tickets::mark_verified(&mut *tx, ticket_id, caller).await?,
+            ),
+        };
+        tx.commit().await?;
+
+        Ok(EntryTicketVerification {
+            result,
+            ticket: Some(EntryTicket::new(ticket, state)),
+        })
+    }
+
+    /// The caller's live tickets for a tournament, to show at seating.
+    async fn my_entry_tickets(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Vec<EntryTicket>> {
+        let claims = ctx.data::<Claims>()?;
+        let user_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+
+        let rows = entry_tickets::list_live_for_user(&state.db, tournament_id, user_id).await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| EntryTicket::new(row, state))
+            .collect())
+    }
+}
+
+#[derive(Default)]
+pub struct TicketMutation;
+
+#[Object]
+impl TicketMutation {
+    /// Void a ticket so it no longer admits anyone. Club managers.
+    async fn void_entry_ticket(
+        &self,
+        ctx: &Context<'_>,
+        ticket_id: ID,
+        reason: String,
+    ) -> Result<EntryTicket> {
+        let state = ctx.data::<AppState>()?;
+        let ticket_id = Uuid::parse_str(ticket_id.as_str()).gql_err("Invalid ticket ID")?;
+        let reason = reason.trim();
+        if reason.is_empty() || reason.chars().count() > MAX_REASON_LENGTH {
+            return Err(async_graphql::Error::new(
+                "Reason must be between 1 and 500 characters",
+            ));
+        }
+
+        let ticket = entry_tickets::get_by_id(&state.db, ticket_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Ticket not found"))?;
+        let manager = require_club_manager(ctx, ticket.club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid user ID")?;
+
+        let ticket = entry_tickets::void(&state.db, ticket_id, manager_id, reason)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("This ticket is already void"))?;
+        Ok(EntryTicket::new(ticket, state))
+    }
+
+    /// Void an entry's current ticket and issue a new one, e.g. when the
+    /// player lost theirs. Club managers.
+    async fn reissue_entry_ticket(&self, ctx: &Context<'_>, entry_id: ID) -> Result<EntryTicket> {
+        let state = ctx.data::<AppState>()?;
+        let entry_id = Uuid::parse_str(entry_id.as_str()).gql_err("Invalid entry ID")?;
+
+        let entry = tournament_entries::get_by_id(&state.db, entry_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Entry not found"))?;
+        let tournament = tournaments::get_by_id(&state.db, entry.tournament_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        let manager = require_club_manager(ctx, tournament.club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).gql_err("Invalid user ID")?;
+        if !issues_ticket(&entry.entry_type) {
+            return Err(async_graphql::Error::new(
+                "Only buy-ins and re-entries get tickets",
+            ));
+        }
+
+        let mut tx = state.db.begin().await?;
+        entry_tickets::void_for_entry(&mut *tx, entry_id, Some(manager_id), "Reissued").await?;
+        let ticket = entry_tickets::create(&mut *tx, entry_id, Some(manager_id)).await?;
+        tx.commit().await?;
+        Ok(EntryTicket::new(ticket, state))
+    }
+}
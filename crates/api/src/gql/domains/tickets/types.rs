@@ -0,0 +1,87 @@
+use async_graphql::{Enum, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use crate::gql::domains::printouts::link::{self, TokenScope};
+use crate::state::AppState;
+use infra::repos::entry_tickets::TicketRow;
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum EntryTicketStatus {
+    /// Handed to the player, not scanned yet.
+    Issued,
+    /// Scanned at seating.
+    Verified,
+    /// Lost, reissued or its entry was deleted; no longer admits anyone.
+    Voided,
+}
+
+impl EntryTicketStatus {
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "verified" => EntryTicketStatus::Verified,
+            "voided" => EntryTicketStatus::Voided,
+            _ => EntryTicketStatus::Issued,
+        }
+    }
+}
+
+/// A QR ticket for a seat-granting entry. `token` is the QR payload.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct EntryTicket {
+    pub id: ID,
+    /// Null once the entry was deleted.
+    pub entry_id: Option<ID>,
+    pub tournament_id: ID,
+    pub tournament_name: String,
+    pub club_player_id: ID,
+    pub player_name: String,
+    pub status: EntryTicketStatus,
+    pub token: String,
+    /// Scans after the first one.
+    pub duplicate_scans: i32,
+    pub issued_at: DateTime<Utc>,
+    pub verified_at: Option<DateTime<Utc>>,
+    pub voided_at: Option<DateTime<Utc>>,
+    pub void_reason: Option<String>,
+}
+
+impl EntryTicket {
+    pub fn new(row: TicketRow, state: &AppState) -> Self {
+        Self {
+            id: row.id.into(),
+            entry_id: row.entry_id.map(Into::into),
+            tournament_id: row.tournament_id.into(),
+            tournament_name: row.tournament_name,
+            club_player_id: row.club_player_id.into(),
+            player_name: row.player_name,
+            status: EntryTicketStatus::from_db(&row.status),
+            token: link::encode_token(&state.auth_config().jwt_secret, TokenScope::Ticket, row.id),
+            duplicate_scans: row.scan_count,
+            issued_at: row.issued_at,
+            verified_at: row.verified_at,
+            voided_at: row.voided_at,
+            void_reason: row.void_reason,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TicketCheckResult {
+    /// Genuine and scanned for the first time; the ticket is now verified.
+    Valid,
+    /// Genuine but already verified: someone is presenting a copy.
+    Duplicate,
+    /// Genuine but voided.
+    Voided,
+    /// Genuine but for another tournament than the one being seated.
+    WrongTournament,
+    /// Not a ticket this server issued.
+    Invalid,
+}
+
+/// The outcome of scanning a ticket. `ticket` is null for invalid tokens.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct EntryTicketVerification {
+    pub result: TicketCheckResult,
+    pub ticket: Option<EntryTicket>,
+}
@@ -0,0 +1,223 @@
+//! Cancelling a tournament. In one transaction the tournament becomes
+//! CANCELLED, every registration is cancelled, seats are cleared, the clock
+//! stops, and entries and their tickets are voided (kept, but out of every
+//! total). A payout table frozen when late registration closed is reopened,
+//! so the prize pool falls back to zero with the voided entries. Prepaid
+//! buy-ins and vouchers are credited back on the spot; money paid at the desk
+//! becomes a pending refund per player and payment method. The activity log
+//! entry is written in the same transaction; registrants are told once it
+//! commits. `updateTournamentStatus` can't cancel a tournament, nor change
+//! one that was cancelled.
+
+use std::collections::BTreeMap;
+
+use async_graphql::ID;
+use chrono::Utc;
+use infra::models::{TournamentActivityLogRow, TournamentRegistrationRow, TournamentRow};
+use infra::repos::tournament_refunds::{self, NewTournamentRefund, TournamentRefundRow};
+use infra::repos::{
+    activity_log, entry_tickets, table_seat_assignments, tournament_clock, tournament_entries,
+    tournament_payouts, tournament_registrations, tournaments, users,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::gql::domains::activity_log::types::ActivityLogEntry;
+use crate::gql::domains::buy_in_credits;
+use crate::gql::scalars::Money;
+use crate::gql::subscriptions::{publish_activity_event, publish_user_notification};
+use crate::gql::types::{NotificationType, UserNotification, TITLE_TOURNAMENT_CANCELLED};
+use crate::services::email_service::{spawn_email, EmailService, EmailType, Locale};
+
+/// What a cancellation changed.
+pub struct Cancellation {
+    pub tournament: TournamentRow,
+    pub registrations: Vec<TournamentRegistrationRow>,
+    pub refunds: Vec<TournamentRefundRow>,
+    pub voided_tickets: u64,
+    pub unseated: u64,
+    log: TournamentActivityLogRow,
+}
+
+impl Cancellation {
+    /// Broadcast the activity log entry written with the cancellation.
+    pub fn publish_activity(&self) {
+        publish_activity_event(self.tournament.id, ActivityLogEntry::from(self.log.clone()));
+    }
+}
+
+/// Entries paid this way are handed back at the desk; vouchers and comps
+/// cost the player nothing.
+fn refunded_at_desk(payment_method: &str) -> bool {
+    !matches!(payment_method, "voucher" | "comp")
+}
+
+/// Cancel `tournament_id`. A finished or already cancelled tournament is
+/// refused.
+pub async fn cancel(
+    db: &PgPool,
+    tournament_id: Uuid,
+    actor_id: Option<Uuid>,
+    reason: Option<&str>,
+) -> async_graphql::Result<Cancellation> {
+    let mut tx = db.begin().await?;
+
+    let tournament = tournaments::cancel(&mut *tx, tournament_id)
+        .await?
+        .ok_or_else(|| {
+            async_graphql::Error::new("Only a tournament that hasn't finished can be cancelled")
+        })?;
+
+    let registrations = tournament_registrations::cancel_all(&mut *tx, tournament_id).await?;
+    // Unfreeze first so voiding the entries empties the prize pool.
+    let payout_reopened = tournament_payouts::reopen(&mut *tx, tournament_id).await?;
+    let entries =
+        tournament_entries::void_for_tournament(&mut *tx, tournament_id, actor_id).await?;
+
+    // (player, payment method) -> (amount, entry count)
+    let mut owed: BTreeMap<(Uuid, String), (i64, i32)> = BTreeMap::new();
+    let mut voided_tickets = 0;
+    for entry in &entries {
+        voided_tickets +=
+            entry_tickets::void_for_entry(&mut *tx, entry.id, actor_id, "Tournament cancelled")
+                .await?;
+        if entry.amount_cents > 0 && refunded_at_desk(&entry.payment_method) {
+            let total = owed
+                .entry((entry.club_player_id, entry.payment_method.clone()))
+                .or_default();
+            total.0 = total
+                .0
+                .checked_add(entry.amount_cents)
+                .ok_or_else(|| async_graphql::Error::new("Refund total overflows"))?;
+            total.1 += 1;
+        }
+    }
+
+    // Entries are voided, so every redemption is given back.
+    for registration in &registrations {
+        buy_in_credits::refund_for_cancellation(&mut tx, registration.id, actor_id).await?;
+    }
+
+    let mut refunds = Vec::with_capacity(owed.len());
+    for ((club_player_id, payment_method), (amount_cents, entry_count)) in owed {
+        let registration_id = registrations
+            .iter()
+            .find(|r| r.club_player_id == club_player_id)
+            .map(|r| r.id);
+        refunds.push(
+            tournament_refunds::create(
+                &mut *tx,
+                NewTournamentRefund {
+                    tournament_id,
+                    registration_id,
+                    club_player_id,
+                    amount_cents,
+                    entry_count,
+                    payment_method,
+                    created_by: actor_id,
+                },
+            )
+            .await?,
+        );
+    }
+
+    let unseated =
+        table_seat_assignments::unassign_all_for_tournament(&mut *tx, tournament_id, actor_id)
+            .await?;
+    tournament_clock::stop_clock(&mut *tx, tournament_id).await?;
+
+    let refund_cents = Money::checked_sum(refunds.iter().map(|r| Money(r.amount_cents)))
+        .ok_or_else(|| async_graphql::Error::new("Refund total overflows"))?;
+    let log = activity_log::log_activity(
+        &mut *tx,
+        tournament_id,
+        "tournament",
+        "cancelled",
+        actor_id,
+        None,
+        serde_json::json!({
+            "reason": reason,
+            "cancelled_registrations": registrations.len(),
+            "pending_refunds": refunds.len(),
+            "refund_cents": refund_cents.cents(),
+            "voided_entries": entries.len(),
+            "voided_tickets": voided_tickets,
+            "payout_reopened": payout_reopened,
+            "unseated": unseated,
+        }),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(Cancellation {
+        tournament,
+        registrations,
+        refunds,
+        voided_tickets,
+        unseated,
+        log,
+    })
+}
+
+/// Tell every registrant with an account: in-app notification, push and
+/// email. Registrants are told regardless of their notification
+/// preferences, since they must not travel to a tournament that won't run.
+pub async fn notify_registrants(
+    db: &PgPool,
+    email_service: Option<EmailService>,
+    cancellation: &Cancellation,
+    reason: Option<&str>,
+) {
+    let tournament = &cancellation.tournament;
+    let message = match reason {
+        Some(reason) => format!("{} has been cancelled: {}", tournament.name, reason),
+        None => format!("{} has been cancelled", tournament.name),
+    };
+
+    for user_id in cancellation.registrations.iter().filter_map(|r| r.user_id) {
+        publish_user_notification(UserNotification {
+            id: ID::from(Uuid::new_v4().to_string()),
+            user_id: ID::from(user_id.to_string()),
+            notification_type: NotificationType::TournamentCancelled,
+            title: TITLE_TOURNAMENT_CANCELLED.to_string(),
+            message: message.clone(),
+            tournament_id: Some(ID::from(tournament.id.to_string())),
+            created_at: Utc::now(),
+        });
+        crate::services::push_service::send_tournament_cancelled(db, user_id, tournament.id).await;
+
+        let Some(email_service) = &email_service else {
+            continue;
+        };
+        match users::get_by_id(db, user_id).await {
+            Ok(Some(user)) => spawn_email(
+                email_service.clone(),
+                user.email.clone(),
+                user.first_name.clone(),
+                EmailType::TournamentCancelled {
+                    tournament_name: tournament.name.clone(),
+                    reason: reason.map(str::to_string),
+                    locale: Locale::from_str_lossy(&user.locale),
+                },
+            ),
+            Ok(None) => {}
+            Err(e) => tracing::warn!(%user_id, error = %e, "Loading a cancelled registrant failed"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_money_paid_at_the_desk_is_refunded_there() {
+        assert!(refunded_at_desk("cash"));
+        assert!(refunded_at_desk("card"));
+        assert!(refunded_at_desk("bank_transfer"));
+        assert!(refunded_at_desk("other"));
+        assert!(!refunded_at_desk("voucher"));
+        assert!(!refunded_at_desk("comp"));
+    }
+}
@@ -1,14 +1,46 @@
-use async_graphql::{Context, Object, Result, ID};
+//! Tournament clocks: the level in play, its timer and the structure.
+//!
+//! Play beginning starts the clock: `updateTournamentStatus` into late
+//! registration or play runs `tournament_clock::start_for_play` in the
+//! status update's transaction, creating the clock if missing, and
+//! `publish_started` pushes the start once it commits.
+//!
+//! Late registration closes at the end of `late_registration_level`: any
+//! advance past it, manual or by the clock service, runs
+//! `close_late_registration_if_due`. Registration checks the clock's level
+//! too (`registrations::ensure_registration_open`), so a missed flip can't
+//! let players in.
+//!
+//! A club-wide hold pauses every running clock in the club in one
+//! transaction and records which ones it paused; resuming restarts just
+//! those, pushing each level's end back by the time held.
+//!
+//! The structure editor changes one level at a time and renumbers the rest
+//! through negative numbers, so `unique_tournament_level` holds mid-update.
+//! Once the clock has started, levels up to the one in play are locked; the
+//! level in play may only have its blinds changed.
+
+use async_graphql::{Context, ErrorExtensions, Object, Result, ID};
 use chrono::Utc;
 use std::str::FromStr;
 use uuid::Uuid;
 
-use crate::auth::permissions::require_club_manager;
+use crate::auth::permissions::{is_free_plan, require_club_manager};
 use crate::gql::common::helpers::get_club_id_for_tournament;
-use crate::gql::subscriptions::publish_clock_update;
-use crate::gql::types::{ClockStatus, TournamentClock, TournamentStructure};
+use crate::gql::domains::announcements::types::AnnouncementScope;
+use crate::gql::domains::seating::capacity;
+use crate::gql::subscriptions::{publish_clock_update, publish_seating_event};
+use crate::gql::types::{
+    ClockStatus, ClubClockHold, ClubTournamentEventType, EliminationPace, SeatingChangeEvent,
+    SeatingEventType, TournamentClock, TournamentStructure, TournamentStructureInput,
+};
 use crate::AppState;
 use infra::repos::tournament_clock::{self, ClockStatus as InfraClockStatus};
+use infra::repos::{
+    capacity_planning, club_clock_holds, tournament_entries, tournament_registrations,
+};
+
+use super::pace;
 
 /// Fire-and-forget: record a clock state change in the tournament activity log.
 /// Mirrors the logging used by the other domains; failures are swallowed inside
@@ -74,6 +106,17 @@ pub async fn close_late_registration_if_due(
         tournaments::update_live_status(pool, tournament_id, TournamentLiveStatus::InProgress)
             .await?
             .ok_or("Tournament not found")?;
+    super::lobby::publish_schedule_change(ClubTournamentEventType::StatusChanged, &updated);
+    // Tell the floor (tablets and the desk) too, as a manual change would.
+    publish_seating_event(SeatingChangeEvent {
+        event_type: SeatingEventType::TournamentStatusChanged,
+        tournament_id: tournament_id.into(),
+        club_id: updated.club_id.into(),
+        affected_assignment: None,
+        affected_player: None,
+        message: format!("Late registration closed after level {late_reg_level}"),
+        timestamp: Utc::now(),
+    });
 
     crate::gql::domains::activity_log::log_and_publish(
         pool,
@@ -89,10 +132,56 @@ pub async fn close_late_registration_if_due(
         }),
     )
     .await;
+    crate::gql::domains::registrations::alternates::close_logged(pool, tournament_id, actor_id)
+        .await;
+    crate::gql::domains::results::finalization::finalize_logged(pool, tournament_id, actor_id)
+        .await;
 
     Ok(true)
 }
 
+/// Whether a status change puts the tournament into play, which starts the
+/// clock: from before play into late registration or straight into play.
+pub fn play_begins(
+    from: infra::repos::tournaments::TournamentLiveStatus,
+    to: infra::repos::tournaments::TournamentLiveStatus,
+) -> bool {
+    use infra::repos::tournaments::TournamentLiveStatus;
+
+    matches!(
+        from,
+        TournamentLiveStatus::NotStarted | TournamentLiveStatus::RegistrationOpen
+    ) && matches!(
+        to,
+        TournamentLiveStatus::LateRegistration | TournamentLiveStatus::InProgress
+    )
+}
+
+/// Push a clock started by play beginning (`tournament_clock::start_for_play`)
+/// to clock subscribers and log it. Call once the start is committed.
+pub async fn publish_started(db: &sqlx::PgPool, tournament_id: Uuid, actor_id: Option<Uuid>) {
+    match load_tournament_clock(db, tournament_id).await {
+        Ok(Some(clock)) => {
+            log_clock_event(
+                db,
+                tournament_id,
+                "start",
+                actor_id,
+                serde_json::json!({ "level_number": clock.current_level, "auto": true }),
+            );
+            publish_clock_update(tournament_id, clock);
+        }
+        Ok(None) => {}
+        Err(e) => {
+            tracing::error!(
+                tournament_id = %tournament_id,
+                error = %e.message,
+                "Loading the started clock failed",
+            );
+        }
+    }
+}
+
 /// Helper function to get next structure for a tournament
 async fn get_next_structure(
     pool: &sqlx::PgPool,
@@ -110,8 +199,77 @@ async fn get_next_structure(
         })
 }
 
+/// Elimination pace and projected finish for the clock at `current_level`
+/// with `seconds_left` in it. The live rate starts from the club's past
+/// tournaments; `None` when the inputs can't be loaded, so a failure here
+/// never holds up a clock update.
+pub async fn load_elimination_pace(
+    db: &sqlx::PgPool,
+    tournament_id: Uuid,
+    current_level: i32,
+    seconds_left: Option<i64>,
+) -> Option<EliminationPace> {
+    let club_id = get_club_id_for_tournament(db, tournament_id).await.ok()?;
+    let load = async {
+        let history = capacity_planning::field_history(db, club_id, tournament_id, 20).await?;
+        // The start is logged in the background; until it lands, the running
+        // level's start stands in for it.
+        let started_at = match capacity_planning::started_at(db, tournament_id).await? {
+            Some(at) => Some(at),
+            None => tournament_clock::get_clock(db, tournament_id)
+                .await?
+                .and_then(|c| c.level_started_at),
+        };
+        let eliminations =
+            tournament_registrations::list_elimination_times(db, tournament_id).await?;
+        let stats = tournament_entries::get_stats(db, tournament_id).await?;
+        let structures = tournament_clock::get_all_structures(db, tournament_id).await?;
+        Ok::<_, sqlx::Error>((history, started_at, eliminations, stats, structures))
+    };
+    let (history, started_at, eliminations, stats, structures) = match load.await {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            tracing::warn!("Failed to load elimination pace for {tournament_id}: {e}");
+            return None;
+        }
+    };
+
+    let current = structures.iter().find(|s| s.level_number == current_level);
+    let position = pace::ClockPosition {
+        seconds_left_in_level: seconds_left
+            .or_else(|| current.map(|s| i64::from(s.duration_minutes) * 60))
+            .unwrap_or(0),
+        in_break: current.is_some_and(|s| s.is_break),
+        upcoming: structures
+            .iter()
+            .filter(|s| s.level_number > current_level)
+            .map(|s| pace::UpcomingLevel {
+                minutes: s.duration_minutes,
+                is_break: s.is_break,
+            })
+            .collect(),
+    };
+    let prior = capacity::field_rates(&history).elimination_per_minute;
+    let pace = pace::project(
+        &eliminations,
+        stats.players_remaining as i32,
+        started_at,
+        Utc::now(),
+        prior,
+        &position,
+    );
+
+    Some(EliminationPace {
+        players_remaining: pace.players_remaining,
+        eliminations: pace.eliminations,
+        eliminations_per_hour: pace.eliminations_per_hour,
+        projected_finish_at: pace.projected_finish_at,
+    })
+}
+
 /// Helper function to create TournamentClock with all required fields
-fn create_tournament_clock(
+async fn create_tournament_clock(
+    db: &sqlx::PgPool,
     clock_row: &infra::models::TournamentClockRow,
     structure: Option<&infra::models::TournamentStructureRow>,
     next_structure: Option<TournamentStructure>,
@@ -119,6 +277,14 @@ fn create_tournament_clock(
     total_pause_seconds: i64,
     status: ClockStatus,
 ) -> TournamentClock {
+    let pace = load_elimination_pace(
+        db,
+        clock_row.tournament_id,
+        clock_row.current_level,
+        time_remaining,
+    )
+    .await;
+
     TournamentClock {
         id: clock_row.id.into(),
         tournament_id: clock_row.tournament_id.into(),
@@ -137,6 +303,7 @@ fn create_tournament_clock(
         ante: structure.map(|s| s.ante),
         is_break: structure.map(|s| s.is_break),
         level_duration_minutes: structure.map(|s| s.duration_minutes),
+        pace,
     }
 }
 
@@ -191,14 +358,152 @@ pub async fn load_tournament_clock(
         .unwrap_or(InfraClockStatus::Stopped)
         .into();
 
-    Ok(Some(create_tournament_clock(
-        &clock_row,
-        structure.as_ref(),
-        next_structure,
-        time_remaining,
-        total_pause_seconds,
-        status,
-    )))
+    Ok(Some(
+        create_tournament_clock(
+            db,
+            &clock_row,
+            structure.as_ref(),
+            next_structure,
+            time_remaining,
+            total_pause_seconds,
+            status,
+        )
+        .await,
+    ))
+}
+
+/// Push the current state of the clocks a club-wide hold touched and log the
+/// pause or resume on each tournament's timeline.
+async fn publish_hold_clocks(
+    db: &sqlx::PgPool,
+    hold: &club_clock_holds::ClubClockHoldRow,
+    action: &'static str,
+    actor_id: Option<Uuid>,
+) {
+    for &tournament_id in &hold.tournament_ids {
+        log_clock_event(
+            db,
+            tournament_id,
+            action,
+            actor_id,
+            serde_json::json!({ "hold_id": hold.id, "reason": hold.reason }),
+        );
+        match load_tournament_clock(db, tournament_id).await {
+            Ok(Some(clock)) => publish_clock_update(tournament_id, clock),
+            Ok(None) => {}
+            Err(e) => tracing::error!(
+                tournament_id = %tournament_id,
+                error = %e.message,
+                "Loading a held clock failed",
+            ),
+        }
+    }
+}
+
+/// Tell the club's players about a hold. Best-effort, and skipped for clubs
+/// on the free plan, which can't announce.
+async fn announce_hold(
+    ctx: &Context<'_>,
+    club_id: Uuid,
+    title: &str,
+    body: &str,
+    created_by: Uuid,
+) {
+    let Ok(state) = ctx.data::<AppState>() else {
+        return;
+    };
+    if !matches!(is_free_plan(ctx, club_id).await, Ok(false)) {
+        return;
+    }
+    if let Err(e) = crate::gql::domains::announcements::service::create_announcement(
+        &state.db,
+        AnnouncementScope::Club,
+        Some(club_id),
+        None,
+        title,
+        body,
+        created_by,
+    )
+    .await
+    {
+        tracing::error!(
+            club_id = %club_id,
+            error = %e,
+            "Clock hold announcement failed",
+        );
+    }
+}
+
+/// The last level that has been played or is in play, which the structure
+/// editor can't touch; 0 until the clock first starts. Locks the clock row,
+/// so the level can't advance before the edit in the same transaction
+/// commits.
+async fn locked_through(conn: &mut sqlx::PgConnection, tournament_id: Uuid) -> Result<i32> {
+    Ok(
+        match tournament_clock::lock_clock(conn, tournament_id).await? {
+            Some(clock) if clock.clock_status != "stopped" || clock.level_started_at.is_some() => {
+                clock.current_level
+            }
+            _ => 0,
+        },
+    )
+}
+
+fn level_locked(level_number: i32) -> async_graphql::Error {
+    async_graphql::Error::new(format!(
+        "Level {level_number} has already been played or is in play"
+    ))
+    .extend_with(|_, e| e.set("code", "LEVEL_LOCKED"))
+}
+
+/// Sanity checks on an edited level's numbers.
+fn validate_level(level: &TournamentStructureInput) -> Result<()> {
+    if level.duration_minutes < 1 {
+        return Err(async_graphql::Error::new("A level lasts at least a minute"));
+    }
+    if level.small_blind < 0 || level.big_blind < 0 || level.ante < 0 {
+        return Err(async_graphql::Error::new(
+            "Blinds and antes can't be negative",
+        ));
+    }
+    if !level.is_break && (level.small_blind == 0 || level.big_blind < level.small_blind) {
+        return Err(async_graphql::Error::new(
+            "A level needs a small blind and a big blind at least as large",
+        ));
+    }
+    Ok(())
+}
+
+fn to_level(input: TournamentStructureInput) -> tournament_clock::TournamentStructureLevel {
+    tournament_clock::TournamentStructureLevel {
+        level_number: input.level_number,
+        small_blind: input.small_blind,
+        big_blind: input.big_blind,
+        ante: input.ante,
+        duration_minutes: input.duration_minutes,
+        is_break: input.is_break,
+        break_duration_minutes: input.break_duration_minutes,
+        color_up_denomination: input.color_up_denomination,
+    }
+}
+
+/// After a structure edit: log it, push the clock (its next level may have
+/// changed) and return the structure as it now stands.
+async fn structure_edited(
+    db: &sqlx::PgPool,
+    tournament_id: Uuid,
+    actor_id: Option<Uuid>,
+    metadata: serde_json::Value,
+) -> Result<Vec<TournamentStructure>> {
+    log_clock_event(db, tournament_id, "structure_edited", actor_id, metadata);
+    if let Some(clock) = load_tournament_clock(db, tournament_id).await? {
+        publish_clock_update(tournament_id, clock);
+    }
+    Ok(tournament_clock::get_all_structures(db, tournament_id)
+        .await?
+        .into_iter()
+        .map(TournamentStructure::from)
+        .collect())
 }
 
 #[derive(Default)]
@@ -234,6 +539,21 @@ impl TournamentClockQuery {
             .map(TournamentStructure::from)
             .collect())
     }
+
+    /// The club's clock hold in force, if any (managers only).
+    pub async fn club_clock_hold(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+    ) -> Result<Option<ClubClockHold>> {
+        let state = ctx.data::<AppState>()?;
+        let club_id: Uuid = club_id.parse()?;
+        require_club_manager(ctx, club_id).await?;
+
+        Ok(club_clock_holds::get_active(&state.db, club_id)
+            .await?
+            .map(ClubClockHold::from))
+    }
 }
 
 #[derive(Default)]
@@ -262,6 +582,13 @@ impl TournamentClockMutation {
 
         // Show full duration of first level when clock is created (stopped state)
         let time_remaining_seconds = structure.as_ref().map(|s| (s.duration_minutes as i64) * 60);
+        let pace = load_elimination_pace(
+            &state.db,
+            tournament_id,
+            clock_row.current_level,
+            time_remaining_seconds,
+        )
+        .await;
 
         let clock = TournamentClock {
             id: clock_row.id.into(),
@@ -286,6 +613,7 @@ impl TournamentClockMutation {
             ante: structure.as_ref().map(|s| s.ante),
             is_break: structure.as_ref().map(|s| s.is_break),
             level_duration_minutes: structure.as_ref().map(|s| s.duration_minutes),
+            pace,
         };
 
         // Publish to subscription channel
@@ -331,13 +659,15 @@ impl TournamentClockMutation {
         };
 
         let clock = create_tournament_clock(
+            &state.db,
             &clock_row,
             structure.as_ref(),
             next_structure,
             time_remaining,
             0,
             ClockStatus::Running,
-        );
+        )
+        .await;
 
         // Publish to subscription channel
         publish_clock_update(tournament_id, clock.clone());
@@ -386,13 +716,15 @@ impl TournamentClockMutation {
         let total_pause_seconds = clock_row.total_pause_duration.microseconds / 1_000_000;
 
         let clock = create_tournament_clock(
+            &state.db,
             &clock_row,
             structure.as_ref(),
             next_structure,
             time_remaining,
             total_pause_seconds,
             ClockStatus::Paused,
-        );
+        )
+        .await;
 
         // Publish to subscription channel
         publish_clock_update(tournament_id, clock.clone());
@@ -439,13 +771,15 @@ impl TournamentClockMutation {
         let total_pause_seconds = clock_row.total_pause_duration.microseconds / 1_000_000;
 
         let clock = create_tournament_clock(
+            &state.db,
             &clock_row,
             structure.as_ref(),
             next_structure,
             time_remaining,
             total_pause_seconds,
             ClockStatus::Running,
-        );
+        )
+        .await;
 
         // Publish to subscription channel
         publish_clock_update(tournament_id, clock.clone());
@@ -527,13 +861,15 @@ impl TournamentClockMutation {
         let total_pause_seconds = clock_row.total_pause_duration.microseconds / 1_000_000;
 
         let clock = create_tournament_clock(
+            &state.db,
             &clock_row,
             structure.as_ref(),
             next_structure,
             time_remaining,
             total_pause_seconds,
             status,
-        );
+        )
+        .await;
 
         // Publish to subscription channel
         publish_clock_update(tournament_id, clock.clone());
@@ -592,17 +928,260 @@ impl TournamentClockMutation {
         let total_pause_seconds = clock_row.total_pause_duration.microseconds / 1_000_000;
 
         let clock = create_tournament_clock(
+            &state.db,
             &clock_row,
             structure.as_ref(),
             next_structure,
             time_remaining,
             total_pause_seconds,
             status,
-        );
+        )
+        .await;
 
         // Publish to subscription channel
         publish_clock_update(tournament_id, clock.clone());
 
         Ok(clock)
     }
+
+    /// Insert a level at `level.levelNumber`, moving that level and the ones
+    /// after it down one; a number past the end appends. Only after the level
+    /// in play. Returns the whole structure.
+    pub async fn add_tournament_level(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        level: TournamentStructureInput,
+    ) -> Result<Vec<TournamentStructure>> {
+        let state = ctx.data::<AppState>()?;
+        let tournament_id: Uuid = tournament_id.parse()?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        validate_level(&level)?;
+
+        let mut tx = state.db.begin().await?;
+        let locked = locked_through(&mut tx, tournament_id).await?;
+        let count = tournament_clock::get_all_structures(&mut *tx, tournament_id)
+            .await?
+            .len() as i32;
+        if level.level_number < 1 {
+            return Err(async_graphql::Error::new("Levels are numbered from 1"));
+        }
+        if level.level_number <= locked {
+            return Err(level_locked(level.level_number));
+        }
+        let mut level = to_level(level);
+        level.level_number = level.level_number.min(count + 1);
+        let level_number = level.level_number;
+
+        tournament_clock::insert_level(&mut tx, tournament_id, level).await?;
+        tx.commit().await?;
+
+        structure_edited(
+            &state.db,
+            tournament_id,
+            manager.id.parse().ok(),
+            serde_json::json!({ "change": "add", "level_number": level_number }),
+        )
+        .await
+    }
+
+    /// Change a level's blinds and timing. The level in play keeps its
+    /// duration and break flag; played levels can't change.
+    pub async fn update_tournament_level(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        level: TournamentStructureInput,
+    ) -> Result<Vec<TournamentStructure>> {
+        let state = ctx.data::<AppState>()?;
+        let tournament_id: Uuid = tournament_id.parse()?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        validate_level(&level)?;
+
+        let mut tx = state.db.begin().await?;
+        let locked = locked_through(&mut tx, tournament_id).await?;
+        let level_number = level.level_number;
+        if level_number < locked {
+            return Err(level_locked(level_number));
+        }
+        if level_number == locked {
+            let current = tournament_clock::get_all_structures(&mut *tx, tournament_id)
+                .await?
+                .into_iter()
+                .find(|l| l.level_number == level_number);
+            if current.is_some_and(|c| {
+                c.duration_minutes != level.duration_minutes || c.is_break != level.is_break
+            }) {
+                return Err(async_graphql::Error::new(
+                    "The level in play can only have its blinds changed",
+                )
+                .extend_with(|_, e| e.set("code", "LEVEL_LOCKED")));
+            }
+        }
+
+        tournament_clock::update_level(&mut *tx, tournament_id, to_level(level))
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Level not found"))?;
+        tx.commit().await?;
+
+        structure_edited(
+            &state.db,
+            tournament_id,
+            manager.id.parse().ok(),
+            serde_json::json!({ "change": "update", "level_number": level_number }),
+        )
+        .await
+    }
+
+    /// Move a level to `toLevelNumber`, renumbering the levels in between.
+    /// Both positions must come after the level in play.
+    pub async fn move_tournament_level(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        level_number: i32,
+        to_level_number: i32,
+    ) -> Result<Vec<TournamentStructure>> {
+        let state = ctx.data::<AppState>()?;
+        let tournament_id: Uuid = tournament_id.parse()?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+
+        let mut tx = state.db.begin().await?;
+        let locked = locked_through(&mut tx, tournament_id).await?;
+        let count = tournament_clock::get_all_structures(&mut *tx, tournament_id)
+            .await?
+            .len() as i32;
+        if !(1..=count).contains(&level_number) || !(1..=count).contains(&to_level_number) {
+            return Err(async_graphql::Error::new(format!(
+                "Levels are numbered 1 to {count}"
+            )));
+        }
+        if level_number <= locked || to_level_number <= locked {
+            return Err(level_locked(level_number.min(to_level_number)));
+        }
+
+        if level_number != to_level_number {
+            tournament_clock::move_level(&mut tx, tournament_id, level_number, to_level_number)
+                .await?;
+        }
+        tx.commit().await?;
+
+        structure_edited(
+            &state.db,
+            tournament_id,
+            manager.id.parse().ok(),
+            serde_json::json!({
+                "change": "move",
+                "level_number": level_number,
+                "to_level_number": to_level_number,
+            }),
+        )
+        .await
+    }
+
+    /// Remove a level after the one in play; later levels move up one.
+    pub async fn delete_tournament_level(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+        level_number: i32,
+    ) -> Result<Vec<TournamentStructure>> {
+        let state = ctx.data::<AppState>()?;
+        let tournament_id: Uuid = tournament_id.parse()?;
+        let club_id = get_club_id_for_tournament(&state.db, tournament_id).await?;
+        let manager = require_club_manager(ctx, club_id).await?;
+
+        let mut tx = state.db.begin().await?;
+        if level_number <= locked_through(&mut tx, tournament_id).await? {
+            return Err(level_locked(level_number));
+        }
+        let deleted = tournament_clock::delete_level(&mut tx, tournament_id, level_number).await?;
+        tx.commit().await?;
+        if !deleted {
+            return Err(async_graphql::Error::new("Level not found"));
+        }
+
+        structure_edited(
+            &state.db,
+            tournament_id,
+            manager.id.parse().ok(),
+            serde_json::json!({ "change": "delete", "level_number": level_number }),
+        )
+        .await
+    }
+
+    /// Emergency stop (fire alarm, power cut): pause every running clock in
+    /// the club and announce why to its players. Clocks already paused stay
+    /// as they are and aren't restarted by `resumeAllTournaments`.
+    pub async fn pause_all_tournaments(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+        reason: String,
+    ) -> Result<ClubClockHold> {
+        let state = ctx.data::<AppState>()?;
+        let club_id: Uuid = club_id.parse()?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id: Uuid = manager.id.parse()?;
+
+        let reason = reason.trim();
+        if reason.is_empty() {
+            return Err(async_graphql::Error::new("A reason is required"));
+        }
+
+        let mut tx = state.db.begin().await?;
+        if club_clock_holds::get_active(&mut *tx, club_id)
+            .await?
+            .is_some()
+        {
+            return Err(async_graphql::Error::new(
+                "The club's clocks are already on hold",
+            ));
+        }
+        let hold = club_clock_holds::pause_all(&mut tx, club_id, reason, Some(manager_id)).await?;
+        tx.commit().await?;
+
+        publish_hold_clocks(&state.db, &hold, "pause", Some(manager_id)).await;
+        announce_hold(ctx, club_id, "Play is paused", reason, manager_id).await;
+
+        Ok(hold.into())
+    }
+
+    /// Lift the club's hold: restart the clocks it paused, with the time held
+    /// left out of their levels.
+    pub async fn resume_all_tournaments(
+        &self,
+        ctx: &Context<'_>,
+        club_id: ID,
+    ) -> Result<ClubClockHold> {
+        let state = ctx.data::<AppState>()?;
+        let club_id: Uuid = club_id.parse()?;
+        let manager = require_club_manager(ctx, club_id).await?;
+        let manager_id: Uuid = manager.id.parse()?;
+
+        let not_held = || async_graphql::Error::new("The club's clocks aren't on hold");
+        let mut tx = state.db.begin().await?;
+        let active = club_clock_holds::get_active(&mut *tx, club_id)
+            .await?
+            .ok_or_else(not_held)?;
+        let hold = club_clock_holds::resume(&mut tx, active.id, Some(manager_id))
+            .await?
+            .ok_or_else(not_held)?;
+        tx.commit().await?;
+
+        publish_hold_clocks(&state.db, &hold, "resume", Some(manager_id)).await;
+        announce_hold(
+            ctx,
+            club_id,
+            "Play resumes",
+            "The clocks are running again.",
+            manager_id,
+        )
+        .await;
+
+        Ok(hold.into())
+    }
 }
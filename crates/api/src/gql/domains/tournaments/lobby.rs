@@ -0,0 +1,48 @@
+//! Lobby schedule feed (`clubTournamentsUpdates`): tournaments created,
+//! rescheduled, changing live status, filling up or deleted, published per club.
+
+use chrono::Utc;
+use infra::models::TournamentRow;
+use infra::repos::{tournament_registrations, tournaments};
+use uuid::Uuid;
+
+use crate::gql::subscriptions::publish_club_tournament_event;
+use crate::gql::types::{ClubTournamentEvent, ClubTournamentEventType};
+
+/// Publish a schedule change for `tournament` to its club's lobby channel.
+/// Unlisted tournaments stay off the lobby, as they do off the schedule.
+pub fn publish_schedule_change(event_type: ClubTournamentEventType, tournament: &TournamentRow) {
+    if tournament.visibility == "unlisted" {
+        return;
+    }
+    publish_club_tournament_event(ClubTournamentEvent {
+        event_type,
+        club_id: tournament.club_id.into(),
+        tournament_id: tournament.id.into(),
+        title: tournament.name.clone(),
+        start_time: tournament.start_time,
+        end_time: tournament.end_time,
+        live_status: tournament.live_status.into(),
+        seat_cap: tournament.seat_cap,
+        timestamp: Utc::now(),
+    });
+}
+
+/// After a player takes a confirmed spot, announce the tournament as full if
+/// that was the last seat under its cap. Best-effort: a lookup failure only
+/// costs the lobby an update.
+pub async fn publish_if_full(db: &sqlx::PgPool, tournament_id: Uuid) {
+    let Ok(Some(tournament)) = tournaments::get_by_id(db, tournament_id).await else {
+        return;
+    };
+    let Some(seat_cap) = tournament.seat_cap else {
+        return;
+    };
+    match tournament_registrations::count_confirmed_by_tournament(db, tournament_id).await {
+        Ok(confirmed) if confirmed == seat_cap as i64 => {
+            publish_schedule_change(ClubTournamentEventType::Full, &tournament);
+        }
+        Ok(_) => {}
+        Err(e) => tracing::warn!("lobby: counting registrations for {tournament_id} failed: {e}"),
+    }
+}
@@ -1,4 +1,17 @@
+//! Tournaments and their clocks.
+//!
+//! Inputs are checked by `TournamentSettings::validate`, and changes are
+//! logged under `tournament`. Deleting is a soft delete (`deleted_at`),
+//! refused once results exist, and repo reads skip deleted rows.
+//! `cloneTournament` copies settings, structure and free tables to a new
+//! start time. [`readiness`] is the checklist `updateTournamentStatus`
+//! enforces before play starts.
+
+pub mod cancellation;
 pub mod clock;
+pub mod lobby;
+pub mod pace;
+pub mod readiness;
 pub mod recurrence;
 pub mod resolvers;
 pub mod types;
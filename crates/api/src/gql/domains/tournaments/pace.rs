@@ -0,0 +1,221 @@
+//! Elimination pace and projected finish of a running tournament.
+//!
+//! As in capacity planning (`seating::capacity`), each remaining player busts
+//! at the same rate per minute, so the field shrinks exponentially. Here the
+//! rate is measured live over the last `WINDOW_MINUTES` of play, starting from
+//! the club's historical rate: `PRIOR_ELIMINATIONS` imaginary eliminations at
+//! that rate keep the first few busts (or a long quiet spell) from swinging
+//! the projection wildly. The tournament finishes when one player is left;
+//! breaks on the way push the finish back by their length.
+//!
+//! This module is pure (no DB) so the projection is unit-testable; the clock
+//! loads eliminations and structure and calls `project`.
+
+use chrono::{DateTime, Duration, Utc};
+
+/// How far back eliminations count towards the live rate.
+pub const WINDOW_MINUTES: f64 = 90.0;
+
+/// Weight of the historical rate, in eliminations.
+pub const PRIOR_ELIMINATIONS: f64 = 3.0;
+
+/// A level still to be played after the current one.
+#[derive(Debug, Clone, Copy)]
+pub struct UpcomingLevel {
+    pub minutes: i32,
+    pub is_break: bool,
+}
+
+/// Where the clock is: what is left of the current level and what follows.
+#[derive(Debug, Clone)]
+pub struct ClockPosition {
+    pub seconds_left_in_level: i64,
+    pub in_break: bool,
+    pub upcoming: Vec<UpcomingLevel>,
+}
+
+/// The live pace of a tournament.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pace {
+    pub players_remaining: i32,
+    pub eliminations: i32,
+    /// Expected busts over the next hour of play at the current pace.
+    pub eliminations_per_hour: f64,
+    /// `None` before the clock has started or once one player is left.
+    pub projected_finish_at: Option<DateTime<Utc>>,
+}
+
+/// Share of the remaining players busting per minute: eliminations in the
+/// window over the player-minutes played in it, blended with `prior`.
+/// `eliminations` are the bust times, in any order.
+pub fn live_rate(
+    eliminations: &[DateTime<Utc>],
+    players_remaining: i32,
+    started_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+    prior: f64,
+) -> f64 {
+    let window_start = started_at.max(now - Duration::seconds((WINDOW_MINUTES * 60.0) as i64));
+    let mut recent: Vec<_> = eliminations
+        .iter()
+        .copied()
+        .filter(|t| *t > window_start && *t <= now)
+        .collect();
+    recent.sort_unstable_by(|a, b| b.cmp(a));
+
+    // Walk back from now: before each bust there was one more player.
+    let mut exposure = 0.0;
+    let mut alive = players_remaining.max(0) as f64;
+    let mut until = now;
+    for bust in &recent {
+        exposure += alive * minutes_between(*bust, until);
+        alive += 1.0;
+        until = *bust;
+    }
+    exposure += alive * minutes_between(window_start, until);
+
+    (PRIOR_ELIMINATIONS + recent.len() as f64) / (PRIOR_ELIMINATIONS / prior + exposure)
+}
+
+fn minutes_between(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+    ((to - from).num_milliseconds() as f64 / 60_000.0).max(0.0)
+}
+
+/// Wall-clock minutes from now until `play_minutes` of play are done, adding
+/// the breaks met on the way. Past the end of the structure play continues
+/// without breaks.
+pub fn wall_minutes(play_minutes: f64, position: &ClockPosition) -> f64 {
+    let mut needed = play_minutes;
+    let mut elapsed = 0.0;
+    let current_minutes = position.seconds_left_in_level.max(0) as f64 / 60.0;
+    let levels = std::iter::once((position.in_break, current_minutes)).chain(
+        position
+            .upcoming
+            .iter()
+            .map(|l| (l.is_break, f64::from(l.minutes.max(0)))),
+    );
+    for (is_break, minutes) in levels {
+        if is_break {
+            elapsed += minutes;
+        } else if needed <= minutes {
+            return elapsed + needed;
+        } else {
+            elapsed += minutes;
+            needed -= minutes;
+        }
+    }
+    elapsed + needed
+}
+
+/// The pace now, and when the last elimination is expected. `started_at` is
+/// the first clock start and `prior` the club's historical rate.
+pub fn project(
+    eliminations: &[DateTime<Utc>],
+    players_remaining: i32,
+    started_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+    prior: f64,
+    position: &ClockPosition,
+) -> Pace {
+    let rate = match started_at {
+        Some(started_at) => live_rate(eliminations, players_remaining, started_at, now, prior),
+        None => prior,
+    };
+    let projected_finish_at = started_at
+        .filter(|_| players_remaining > 1 && rate > 0.0)
+        .map(|_| {
+            let play = f64::from(players_remaining).ln() / rate;
+            let wall = wall_minutes(play, position);
+            now + Duration::seconds((wall * 60.0).round() as i64)
+        });
+
+    Pace {
+        players_remaining,
+        eliminations: eliminations.len() as i32,
+        eliminations_per_hour: rate * 60.0 * f64::from(players_remaining.max(0)),
+        projected_finish_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minutes(m: i64) -> Duration {
+        Duration::minutes(m)
+    }
+
+    fn position(seconds_left: i64, upcoming: &[(i32, bool)]) -> ClockPosition {
+        ClockPosition {
+            seconds_left_in_level: seconds_left,
+            in_break: false,
+            upcoming: upcoming
+                .iter()
+                .map(|&(minutes, is_break)| UpcomingLevel { minutes, is_break })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn rate_follows_the_recent_busts() {
+        let now = Utc::now();
+        let started = now - minutes(300);
+        // Busts 30 and 60 minutes ago: 10, 11 and 12 players over the three
+        // half hours of the window. The one 200 minutes ago is outside it.
+        let busts = [now - minutes(30), now - minutes(60), now - minutes(200)];
+        let rate = live_rate(&busts, 10, started, now, 0.01);
+        let expected = (3.0 + 2.0) / (3.0 / 0.01 + 10.0 * 30.0 + 11.0 * 30.0 + 12.0 * 30.0);
+        assert!((rate - expected).abs() < 1e-9);
+
+        // No busts for a long time: the rate drifts below the prior.
+        let quiet = live_rate(&[], 10, started, now, 0.01);
+        assert!(quiet < 0.01);
+        // Just started: the prior dominates.
+        let fresh = live_rate(&[], 10, now - minutes(1), now, 0.01);
+        assert!((fresh - 0.01).abs() < 0.001);
+    }
+
+    #[test]
+    fn breaks_push_the_finish_back() {
+        // 10 minutes left in the level, then a 15-minute break, then 20s.
+        let pos = position(600, &[(15, true), (20, false), (20, false)]);
+        assert_eq!(wall_minutes(5.0, &pos), 5.0);
+        assert_eq!(wall_minutes(25.0, &pos), 40.0);
+        // Past the structure: play continues without breaks.
+        assert_eq!(wall_minutes(100.0, &pos), 115.0);
+
+        let on_break = ClockPosition {
+            in_break: true,
+            ..pos.clone()
+        };
+        assert_eq!(wall_minutes(5.0, &on_break), 30.0);
+    }
+
+    #[test]
+    fn projects_the_last_elimination() {
+        let now = Utc::now();
+        let prior = std::f64::consts::LN_2 / 60.0;
+        let pos = position(3600, &[(60, false), (60, false), (60, false)]);
+        // Not started: pace from history, no finish time.
+        let pace = project(&[], 8, None, now, prior, &pos);
+        assert_eq!(pace.projected_finish_at, None);
+        assert!((pace.eliminations_per_hour - 8.0 * 60.0 * prior).abs() < 1e-9);
+
+        // Just started at the historical pace: 8 → 1 halves three times.
+        let pace = project(&[], 8, Some(now), now, prior, &pos);
+        assert_eq!(pace.projected_finish_at, Some(now + minutes(180)));
+
+        // Nothing to project once one player is left.
+        let pace = project(
+            &[now - minutes(1)],
+            1,
+            Some(now - minutes(5)),
+            now,
+            prior,
+            &pos,
+        );
+        assert_eq!(pace.players_remaining, 1);
+        assert_eq!(pace.eliminations, 1);
+        assert_eq!(pace.projected_finish_at, None);
+    }
+}
@@ -0,0 +1,154 @@
+//! Pre-start checklist: what a tournament needs before play starts — a blind
+//! structure, a payout template fitting the field, a table and blinds for the
+//! level the clock starts on.
+//! `tournamentReadiness` shows it; `updateTournamentStatus` refuses to move a
+//! tournament into play while an item is missing, unless the manager
+//! overrides it.
+
+use async_graphql::{Error, ErrorExtensions};
+use infra::models::TournamentRow;
+use infra::repos::tournaments::TournamentLiveStatus;
+use infra::repos::{
+    club_tables, payout_templates, tournament_clock, tournament_payouts, tournament_registrations,
+};
+use sqlx::PgPool;
+
+use super::types::{ReadinessCheck, ReadinessItem, TournamentReadiness};
+
+/// Whether moving from `from` to `to` starts play. Coming back from a break
+/// or late registration doesn't: the tournament is already running.
+pub fn starts_play(from: TournamentLiveStatus, to: TournamentLiveStatus) -> bool {
+    to == TournamentLiveStatus::InProgress
+        && matches!(
+            from,
+            TournamentLiveStatus::NotStarted | TournamentLiveStatus::RegistrationOpen
+        )
+}
+
+fn item(check: ReadinessCheck, passed: bool, detail: String) -> ReadinessItem {
+    ReadinessItem {
+        check,
+        passed,
+        detail,
+    }
+}
+
+/// Evaluate the checklist against the tournament's current setup.
+pub async fn evaluate(
+    db: &PgPool,
+    tournament: &TournamentRow,
+) -> sqlx::Result<TournamentReadiness> {
+    let levels = tournament_clock::get_all_structures(db, tournament.id).await?;
+    let structure = item(
+        ReadinessCheck::Structure,
+        levels.iter().any(|l| !l.is_break),
+        match levels.len() {
+            0 => "No blind structure".to_string(),
+            n => format!("{n} levels"),
+        },
+    );
+
+    // The payout table's template once entries are in, otherwise the club's
+    // templates for the field registered so far.
+    let players = tournament_registrations::count_participants_by_tournament(db, tournament.id)
+        .await?
+        .try_into()
+        .unwrap_or(i32::MAX);
+    let template = match tournament_payouts::get_by_tournament(db, tournament.id)
+        .await?
+        .and_then(|p| p.template_id)
+    {
+        Some(id) => payout_templates::get_by_id(db, id).await?,
+        None => payout_templates::find_suitable_templates(db, tournament.club_id, players)
+            .await?
+            .into_iter()
+            .next(),
+    };
+    let payout_template = match template {
+        Some(t) => item(ReadinessCheck::PayoutTemplate, true, t.name),
+        None => item(
+            ReadinessCheck::PayoutTemplate,
+            false,
+            format!("No payout template fits {players} players"),
+        ),
+    };
+
+    let tables = club_tables::list_assigned_to_tournament(db, tournament.id).await?;
+    let tables = item(
+        ReadinessCheck::Tables,
+        !tables.is_empty(),
+        match tables.len() {
+            0 => "No tables assigned".to_string(),
+            1 => "1 table".to_string(),
+            n => format!("{n} tables"),
+        },
+    );
+
+    // Play starting starts the clock, so its level needs blinds, unless a
+    // manager already has it running.
+    let clock = match tournament_clock::get_clock(db, tournament.id).await? {
+        Some(clock) if clock.clock_status != "stopped" => {
+            item(ReadinessCheck::Clock, true, "Running".to_string())
+        }
+        clock => {
+            let level = clock.map_or(1, |c| c.current_level);
+            let has_blinds = levels.iter().any(|l| l.level_number == level);
+            item(
+                ReadinessCheck::Clock,
+                has_blinds,
+                if has_blinds {
+                    format!("Starts at level {level}")
+                } else {
+                    format!("Clock level {level} has no blinds")
+                },
+            )
+        }
+    };
+
+    let items = vec![structure, payout_template, tables, clock];
+    Ok(TournamentReadiness {
+        tournament_id: tournament.id.into(),
+        ready: items.iter().all(|i| i.passed),
+        items,
+    })
+}
+
+/// The checks still failing, in checklist order.
+fn missing(readiness: &TournamentReadiness) -> Vec<ReadinessCheck> {
+    readiness
+        .items
+        .iter()
+        .filter(|i| !i.passed)
+        .map(|i| i.check)
+        .collect()
+}
+
+/// The check as the GraphQL enum spells it.
+fn code(check: ReadinessCheck) -> &'static str {
+    match check {
+        ReadinessCheck::Structure => "STRUCTURE",
+        ReadinessCheck::PayoutTemplate => "PAYOUT_TEMPLATE",
+        ReadinessCheck::Tables => "TABLES",
+        ReadinessCheck::Clock => "CLOCK",
+    }
+}
+
+/// Refusal to start play, with the failing items' details in the message
+/// and their checks under `extensions.missing`.
+pub fn not_ready_error(readiness: &TournamentReadiness) -> Error {
+    let details: Vec<&str> = readiness
+        .items
+        .iter()
+        .filter(|i| !i.passed)
+        .map(|i| i.detail.as_str())
+        .collect();
+    let missing: Vec<&str> = missing(readiness).into_iter().map(code).collect();
+    Error::new(format!(
+        "Tournament isn't ready to start: {}",
+        details.join("; ")
+    ))
+    .extend_with(|_, e| {
+        e.set("code", "NOT_READY");
+        e.set("missing", missing.clone());
+    })
+}
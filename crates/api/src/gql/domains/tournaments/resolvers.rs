@@ -1,25 +1,198 @@
-use async_graphql::{Context, Object, Result};
+use async_graphql::{Context, Object, Result, ID};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+use crate::auth::jwt::Claims;
 use crate::auth::permissions::{
     is_free_plan, require_club_manager, viewer_is_admin, viewer_manages_club,
 };
-use crate::gql::common::helpers::tournament_hidden_from_viewer;
+use crate::gql::common::helpers::{tournament_access, TournamentAccess};
 use crate::gql::error::ResultExt;
 use crate::gql::types::{PaginatedResponse, PaginationInput, Tournament, TournamentStatus};
 use crate::state::AppState;
 use infra::models::TournamentRow;
-use infra::repos::tournament_clock::TournamentStructureLevel;
+use infra::repos::tournament_clock::{self, TournamentStructureLevel};
+use infra::repos::tournament_refunds;
 use infra::repos::tournaments::{
     self, CreateTournamentData, TournamentFilter, TournamentLiveStatus, UpdateTournamentData,
 };
 
+use crate::gql::domains::printouts::types::PrintoutKind;
 use crate::gql::domains::seating::types::{SeatingChangeEvent, SeatingEventType};
 use crate::gql::subscriptions::publish_seating_event;
 
+use super::cancellation;
+use super::clock;
+use super::lobby::publish_schedule_change;
+use super::readiness;
 use super::recurrence::{occurrence_starts, MAX_OCCURRENCES};
-use super::types::{CreateTournamentInput, UpdateTournamentInput, UpdateTournamentStatusInput};
+use super::types::{
+    CancelTournamentInput, CloneTournamentInput, ClubTournamentEventType, CreateTournamentInput,
+    TournamentReadiness, TournamentRefund, TournamentVisibility, UpdateTournamentInput,
+    UpdateTournamentStatusInput,
+};
+
+/// An early-bird tier needs both its price and cut-off, and is a discount on
+/// the regular buy-in.
+fn validate_early_bird(
+    early_bird_cents: Option<i64>,
+    early_bird_until: Option<DateTime<Utc>>,
+    regular_cents: i64,
+) -> Result<()> {
+    match (early_bird_cents, early_bird_until) {
+        (Some(cents), Some(_)) if cents > regular_cents => Err(async_graphql::Error::new(
+            "Early-bird buy-in can't be higher than the regular buy-in",
+        )),
+        (Some(_), None) | (None, Some(_)) => Err(async_graphql::Error::new(
+            "Early-bird pricing needs both a price and a cut-off date",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Points multipliers weight a result without drowning out the rest of the
+/// leaderboard.
+fn validate_points_multiplier(multiplier: Option<f64>) -> Result<()> {
+    match multiplier {
+        Some(m) if !(m > 0.0 && m <= 10.0) => Err(async_graphql::Error::new(
+            "Points multiplier must be above 0 and at most 10",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// The schedule, price and chip settings a tournament is checked against
+/// before it is written.
+struct TournamentSettings<'a> {
+    name: &'a str,
+    start_time: DateTime<Utc>,
+    end_time: Option<DateTime<Utc>>,
+    buy_in_cents: i64,
+    rake_cents: i64,
+    seat_cap: Option<i32>,
+    starting_stack: Option<i32>,
+    early_bird_bonus_chips: Option<i32>,
+    level_two_bonus_chips: Option<i32>,
+    late_registration_level: Option<i32>,
+}
+
+impl TournamentSettings<'_> {
+    fn validate(&self) -> Result<()> {
+        let fail = |msg: &str| Err(async_graphql::Error::new(msg));
+        if self.name.trim().is_empty() {
+            return fail("Tournament name can't be empty");
+        }
+        if self.end_time.is_some_and(|end| end <= self.start_time) {
+            return fail("End time must be after the start time");
+        }
+        if self.buy_in_cents < 0 || self.rake_cents < 0 {
+            return fail("Buy-in and rake can't be negative");
+        }
+        if self.rake_cents > self.buy_in_cents {
+            return fail("Rake can't be higher than the buy-in");
+        }
+        if self.seat_cap.is_some_and(|cap| cap < 1) {
+            return fail("Seat cap must be at least 1");
+        }
+        if self.starting_stack.is_some_and(|chips| chips < 1) {
+            return fail("Starting stack must be at least 1 chip");
+        }
+        if self.early_bird_bonus_chips.is_some_and(|chips| chips < 0)
+            || self.level_two_bonus_chips.is_some_and(|chips| chips < 0)
+        {
+            return fail("Bonus chips can't be negative");
+        }
+        if self.late_registration_level.is_some_and(|level| level < 1) {
+            return fail("Late registration must close at level 1 or later");
+        }
+        Ok(())
+    }
+}
+
+/// The settings an update changed, by field name, for the activity log.
+fn changed_fields(before: &TournamentRow, after: &TournamentRow) -> Vec<&'static str> {
+    [
+        ("name", before.name != after.name),
+        ("description", before.description != after.description),
+        ("start_time", before.start_time != after.start_time),
+        ("end_time", before.end_time != after.end_time),
+        ("buy_in_cents", before.buy_in_cents != after.buy_in_cents),
+        ("rake_cents", before.rake_cents != after.rake_cents),
+        ("seat_cap", before.seat_cap != after.seat_cap),
+        (
+            "starting_stack",
+            before.starting_stack != after.starting_stack,
+        ),
+        (
+            "late_registration_level",
+            before.late_registration_level != after.late_registration_level,
+        ),
+        ("visibility", before.visibility != after.visibility),
+        (
+            "points_multiplier",
+            before.points_multiplier != after.points_multiplier,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(field, changed)| changed.then_some(field))
+    .collect()
+}
+
+/// A fresh token for an unlisted tournament's invite link.
+fn new_invite_token() -> String {
+    use rand::{distr::Alphanumeric, RngExt};
+    rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect()
+}
+
+/// Free ("Home Game") clubs run one tournament at a time: a new one waits
+/// until the current one is finished.
+async fn ensure_free_plan_slot(ctx: &Context<'_>, state: &AppState, club_id: Uuid) -> Result<()> {
+    if !is_free_plan(ctx, club_id).await? {
+        return Ok(());
+    }
+    let active: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM tournaments \
+         WHERE club_id = $1 AND live_status NOT IN ('finished', 'cancelled') AND deleted_at IS NULL",
+    )
+    .bind(club_id)
+    .fetch_one(&state.db)
+    .await
+    .gql_err("Database operation failed")?;
+    if active > 0 {
+        return Err(async_graphql::Error::new(
+            "The Home Game (free) plan allows 1 active tournament at a time. Finish the current one or upgrade to Club.",
+        ));
+    }
+    Ok(())
+}
+
+/// Franchised clubs whose organization reviews new tournaments: what a club
+/// manager creates stays hidden until an organization admin approves it.
+/// Organization and platform admins need no approval. Returns the reviewing
+/// organization, if any.
+async fn approval_organization(
+    ctx: &Context<'_>,
+    state: &AppState,
+    club_id: Uuid,
+    manager_id: Uuid,
+) -> Result<Option<Uuid>> {
+    match infra::repos::organizations::get_for_club(&state.db, club_id)
+        .await
+        .gql_err("Database operation failed")?
+    {
+        Some(org) if org.require_tournament_approval && !viewer_is_admin(ctx) => {
+            let org_admin = infra::repos::organizations::is_admin(&state.db, org.id, manager_id)
+                .await
+                .gql_err("Database operation failed")?;
+            Ok((!org_admin).then_some(org.id))
+        }
+        _ => Ok(None),
+    }
+}
 
 /// Create a single tournament occurrence inside an existing transaction: insert
 /// the row, copy the resolved blind structure (if any), and — only when
@@ -117,6 +290,13 @@ impl TournamentQuery {
             Some(cid) => !viewer_manages_club(ctx, cid).await,
             None => !viewer_is_admin(ctx),
         };
+        // The same viewers see every visibility; everyone else gets public
+        // events plus members-only ones of their own clubs, never unlisted.
+        let restrict_visibility = exclude_free_clubs;
+        let viewer_id = ctx
+            .data::<Claims>()
+            .ok()
+            .and_then(|claims| Uuid::parse_str(&claims.sub).ok());
 
         let filter = TournamentFilter {
             club_id,
@@ -124,6 +304,8 @@ impl TournamentQuery {
             to,
             status: status.map(|s| s.into()),
             exclude_free_clubs,
+            restrict_visibility,
+            viewer_id,
         };
 
         let page_params = pagination.unwrap_or(PaginationInput {
@@ -153,13 +335,20 @@ impl TournamentQuery {
         })
     }
 
-    /// Get a single tournament by ID
-    async fn tournament(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<Tournament>> {
+    /// Get a single tournament by ID. Unlisted tournaments need the invite
+    /// token from their link unless the viewer already takes part.
+    async fn tournament(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+        invite_token: Option<String>,
+    ) -> Result<Option<Tournament>> {
         let state = ctx.data::<AppState>()?;
 
         // A free-club tournament is invisible to the player app / public — only
-        // its own managers and admins can open it directly.
-        if tournament_hidden_from_viewer(ctx, id).await? {
+        // its own managers and admins can open it directly. Members-only and
+        // unlisted tournaments are hidden from viewers who can't join them.
+        if tournament_access(ctx, id, invite_token.as_deref()).await? != TournamentAccess::Visible {
             return Ok(None);
         }
 
@@ -169,6 +358,78 @@ impl TournamentQuery {
 
         Ok(row.map(Tournament::from))
     }
+
+    /// Federation entity resolver: `Tournament @key(fields: "id")`. Applies the
+    /// same visibility rules as `tournament`.
+    #[graphql(entity)]
+    async fn find_tournament_by_id(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+    ) -> Result<Option<Tournament>> {
+        self.tournament(ctx, id, None).await
+    }
+
+    /// Open a tournament from its invite link. Null when the token is unknown
+    /// or was rotated.
+    async fn tournament_by_invite_token(
+        &self,
+        ctx: &Context<'_>,
+        token: String,
+    ) -> Result<Option<Tournament>> {
+        let state = ctx.data::<AppState>()?;
+        let Some(id) = tournaments::find_by_invite_token(&state.db, token.trim())
+            .await
+            .gql_err("Database operation failed")?
+        else {
+            return Ok(None);
+        };
+        self.tournament(ctx, id, Some(token.trim().to_string()))
+            .await
+    }
+
+    /// The pre-start checklist: blind structure, payout template, tables and
+    /// clock (managers only).
+    async fn tournament_readiness(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<TournamentReadiness> {
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let tournament = tournaments::get_by_id(&state.db, tournament_id)
+            .await
+            .gql_err("Database operation failed")?
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        require_club_manager(ctx, tournament.club_id).await?;
+
+        readiness::evaluate(&state.db, &tournament)
+            .await
+            .gql_err("Database operation failed")
+    }
+
+    /// Refunds a cancelled tournament owes its players, pending first
+    /// (managers only).
+    async fn tournament_refunds(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: ID,
+    ) -> Result<Vec<TournamentRefund>> {
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let tournament = tournaments::get_by_id(&state.db, tournament_id)
+            .await
+            .gql_err("Database operation failed")?
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        require_club_manager(ctx, tournament.club_id).await?;
+
+        let rows = tournament_refunds::list_for_tournament(&state.db, tournament_id)
+            .await
+            .gql_err("Database operation failed")?;
+        Ok(rows.into_iter().map(TournamentRefund::from).collect())
+    }
 }
 
 #[derive(Default)]
@@ -186,29 +447,41 @@ impl TournamentMutation {
         let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
 
         // Check permissions
-        let _user = require_club_manager(ctx, club_id).await?;
+        let user = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(user.id.as_str()).gql_err("Invalid user ID")?;
+        TournamentSettings {
+            name: &input.name,
+            start_time: input.start_time,
+            end_time: input.end_time,
+            buy_in_cents: input.buy_in_cents.cents(),
+            rake_cents: input.rake_cents.map_or(0, i64::from),
+            seat_cap: input.seat_cap,
+            starting_stack: input.starting_stack,
+            early_bird_bonus_chips: input.early_bird_bonus_chips,
+            level_two_bonus_chips: input.level_two_bonus_chips,
+            late_registration_level: input.late_registration_level,
+        }
+        .validate()?;
+        validate_early_bird(
+            input.early_bird_buy_in_cents.map(i64::from),
+            input.early_bird_until,
+            input.buy_in_cents.cents(),
+        )?;
+        validate_points_multiplier(input.points_multiplier)?;
+        if input.seats_per_table.is_some_and(|seats| seats < 2) {
+            return Err(async_graphql::Error::new(
+                "Tables must have at least 2 seats",
+            ));
+        }
 
         // Free ("Home Game") tier: one-off tournaments only, and just one live
         // at a time. Recurring scheduling and concurrency are Club features.
-        if is_free_plan(ctx, club_id).await? {
-            if input.recurrence_frequency.is_some() {
-                return Err(async_graphql::Error::new(
-                    "Recurring tournaments require the Club plan. Upgrade to schedule a series.",
-                ));
-            }
-            let active: i64 = sqlx::query_scalar(
-                "SELECT COUNT(*) FROM tournaments WHERE club_id = $1 AND live_status <> 'finished'",
-            )
-            .bind(club_id)
-            .fetch_one(&state.db)
-            .await
-            .gql_err("Database operation failed")?;
-            if active > 0 {
-                return Err(async_graphql::Error::new(
-                    "The Home Game (free) plan allows 1 active tournament at a time. Finish the current one or upgrade to Club.",
-                ));
-            }
+        if input.recurrence_frequency.is_some() && is_free_plan(ctx, club_id).await? {
+            return Err(async_graphql::Error::new(
+                "Recurring tournaments require the Club plan. Upgrade to schedule a series.",
+            ));
         }
+        ensure_free_plan_slot(ctx, state, club_id).await?;
 
         let leaderboard_config_id = input
             .leaderboard_config_id
@@ -217,6 +490,26 @@ impl TournamentMutation {
             .transpose()
             .gql_err("Invalid league ID")?;
 
+        let qualification_rule = match input.qualification_rule_id.as_ref() {
+            Some(rule_id) => {
+                if input.recurrence_frequency.is_some() {
+                    return Err(async_graphql::Error::new(
+                        "A qualification final can't be recurring",
+                    ));
+                }
+                let rule_id = Uuid::parse_str(rule_id.as_str()).gql_err("Invalid rule ID")?;
+                let rule = infra::repos::qualification_rules::get_by_id(&state.db, rule_id)
+                    .await
+                    .gql_err("Database operation failed")?
+                    .filter(|r| r.club_id == club_id)
+                    .ok_or_else(|| async_graphql::Error::new("Qualification rule not found"))?;
+                Some(rule)
+            }
+            None => None,
+        };
+
+        let approval_organization = approval_organization(ctx, state, club_id, manager_id).await?;
+
         // Resolve the blind structure once (from a template or the custom
         // levels) so every occurrence gets the same structure.
         let structure: Option<Vec<TournamentStructureLevel>> =
@@ -242,6 +535,7 @@ impl TournamentMutation {
                             duration_minutes: level.duration_minutes,
                             is_break: level.is_break,
                             break_duration_minutes: level.break_duration_minutes,
+                            color_up_denomination: level.color_up_denomination,
                         })
                         .collect(),
                 )
@@ -257,6 +551,7 @@ impl TournamentMutation {
                             duration_minutes: level.duration_minutes,
                             is_break: level.is_break,
                             break_duration_minutes: level.break_duration_minutes,
+                            color_up_denomination: level.color_up_denomination,
                         })
                         .collect()
                 })
@@ -276,8 +571,10 @@ impl TournamentMutation {
                     .map_err(async_graphql::Error::new)?
             }
         };
-        // Preserve each occurrence's duration relative to its own start.
+        // Preserve each occurrence's duration (and early-bird cut-off)
+        // relative to its own start.
         let duration = input.end_time.map(|end| end - input.start_time);
+        let early_bird_lead = input.early_bird_until.map(|until| until - input.start_time);
 
         // Create all occurrences atomically: a mid-run failure rolls back the
         // whole series rather than leaving a partial run behind.
@@ -286,7 +583,7 @@ impl TournamentMutation {
             .begin()
             .await
             .gql_err("Failed to start transaction")?;
-        let mut first: Option<TournamentRow> = None;
+        let mut created: Vec<TournamentRow> = Vec::with_capacity(starts.len());
         for (i, start) in starts.iter().enumerate() {
             let data = CreateTournamentData {
                 club_id,
@@ -294,20 +591,26 @@ impl TournamentMutation {
                 description: input.description.clone(),
                 start_time: *start,
                 end_time: duration.map(|d| *start + d),
-                buy_in_cents: input.buy_in_cents,
-                rake_cents: input.rake_cents,
+                buy_in_cents: input.buy_in_cents.cents(),
+                rake_cents: input.rake_cents.map(i64::from),
                 seat_cap: input.seat_cap,
                 starting_stack: input.starting_stack,
                 early_bird_bonus_chips: input.early_bird_bonus_chips,
                 level_two_bonus_chips: input.level_two_bonus_chips,
-                voucher_value_cents: input.voucher_value_cents,
+                voucher_value_cents: input.voucher_value_cents.map(i64::from),
                 rebuy_max: input.rebuy_max,
                 addon_chips: input.addon_chips,
-                addon_price_cents: input.addon_price_cents,
+                addon_price_cents: input.addon_price_cents.map(i64::from),
                 late_registration_level: input.late_registration_level,
                 bounty_type: input.bounty_type.map(String::from),
-                bounty_amount_cents: input.bounty_amount_cents,
+                bounty_amount_cents: input.bounty_amount_cents.map(i64::from),
                 leaderboard_config_id,
+                chip_race_rule: input.chip_race_rule.map(String::from),
+                visibility: input.visibility.map(String::from),
+                early_bird_buy_in_cents: input.early_bird_buy_in_cents.map(i64::from),
+                early_bird_until: early_bird_lead.map(|lead| *start + lead),
+                points_multiplier: input.points_multiplier,
+                seats_per_table: input.seats_per_table,
                 // Standalone tournaments are not part of a series; series flights are
                 // created via the `createTournamentSeries` mutation.
                 series_id: None,
@@ -318,15 +621,256 @@ impl TournamentMutation {
             // Only the first occurrence reserves the club's default tables
             // (see `create_one`).
             let row = create_one(&mut tx, data, structure.as_deref(), i == 0).await?;
-            if first.is_none() {
-                first = Some(row);
+            if input.visibility == Some(TournamentVisibility::Unlisted) {
+                tournaments::ensure_invite_token(&mut *tx, row.id, &new_invite_token())
+                    .await
+                    .gql_err("Failed to create invite link")?;
             }
+            if let Some(organization_id) = approval_organization {
+                infra::repos::tournament_approvals::create(
+                    &mut *tx,
+                    row.id,
+                    organization_id,
+                    manager_id,
+                )
+                .await
+                .gql_err("Failed to request approval")?;
+            }
+            if let Some(rule) = &qualification_rule {
+                let rule = infra::repos::qualification_rules::set_final(&mut *tx, rule.id, row.id)
+                    .await
+                    .gql_err("Failed to link the final")?
+                    .ok_or_else(|| {
+                        async_graphql::Error::new("This qualification rule already has a final")
+                    })?;
+                crate::gql::domains::qualifications::service::register_qualified(&mut tx, &rule)
+                    .await
+                    .gql_err("Failed to register qualifiers")?;
+            }
+            created.push(row);
         }
         tx.commit().await.gql_err("Failed to create tournament")?;
 
+        // Tournaments awaiting approval reach the lobby once approved.
+        if approval_organization.is_none() {
+            for row in &created {
+                publish_schedule_change(ClubTournamentEventType::Created, row);
+            }
+        }
+
+        // Log activity: one entry per occurrence, so each tournament's own
+        // timeline starts with its creation.
+        {
+            let db = state.db.clone();
+            let logged: Vec<(Uuid, serde_json::Value)> = created
+                .iter()
+                .map(|row| {
+                    (
+                        row.id,
+                        serde_json::json!({
+                            "name": row.name,
+                            "start_time": row.start_time,
+                            "buy_in_cents": row.buy_in_cents,
+                            "seat_cap": row.seat_cap,
+                            "occurrences": created.len(),
+                            "awaiting_approval": approval_organization.is_some(),
+                        }),
+                    )
+                })
+                .collect();
+            tokio::spawn(async move {
+                for (tournament_id, metadata) in logged {
+                    crate::gql::domains::activity_log::log_and_publish(
+                        &db,
+                        tournament_id,
+                        "tournament",
+                        "created",
+                        Some(manager_id),
+                        None,
+                        metadata,
+                    )
+                    .await;
+                }
+            });
+        }
+
         // Return the first occurrence; the client refetches the list to see the
         // full run.
-        Ok(Tournament::from(first.expect("at least one occurrence")))
+        Ok(Tournament::from(
+            created.into_iter().next().expect("at least one occurrence"),
+        ))
+    }
+
+    /// Recreate a tournament at a new start time, e.g. next week's edition of
+    /// a weekly event. Its settings, blind structure and tables are copied;
+    /// registrations, results and the clock are not. The end time and
+    /// early-bird cut-off keep their offset from the start, and tables still
+    /// held by another unfinished tournament are left out. Payouts come from
+    /// the club's payout templates by player count, so the copy pays out like
+    /// the original.
+    async fn clone_tournament(
+        &self,
+        ctx: &Context<'_>,
+        input: CloneTournamentInput,
+    ) -> Result<Tournament> {
+        let state = ctx.data::<AppState>()?;
+        let source_id =
+            Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let source = tournaments::get_by_id(&state.db, source_id)
+            .await
+            .gql_err("Database operation failed")?
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        let club_id = source.club_id;
+        let user = require_club_manager(ctx, club_id).await?;
+        let manager_id = Uuid::parse_str(user.id.as_str()).gql_err("Invalid user ID")?;
+
+        let name = input.name.unwrap_or_else(|| source.name.clone());
+        let start_time = input.start_time;
+        let shift = |at: DateTime<Utc>| start_time + (at - source.start_time);
+        TournamentSettings {
+            name: &name,
+            start_time,
+            end_time: source.end_time.map(shift),
+            buy_in_cents: source.buy_in_cents,
+            rake_cents: source.rake_cents,
+            seat_cap: source.seat_cap,
+            starting_stack: source.starting_stack,
+            early_bird_bonus_chips: source.early_bird_bonus_chips,
+            level_two_bonus_chips: source.level_two_bonus_chips,
+            late_registration_level: source.late_registration_level,
+        }
+        .validate()?;
+        ensure_free_plan_slot(ctx, state, club_id).await?;
+        let approval_organization = approval_organization(ctx, state, club_id, manager_id).await?;
+
+        let structure: Vec<TournamentStructureLevel> =
+            infra::repos::tournament_clock::get_all_structures(&state.db, source_id)
+                .await
+                .gql_err("Failed to fetch structure")?
+                .into_iter()
+                .map(|level| TournamentStructureLevel {
+                    level_number: level.level_number,
+                    small_blind: level.small_blind,
+                    big_blind: level.big_blind,
+                    ante: level.ante,
+                    duration_minutes: level.duration_minutes,
+                    is_break: level.is_break,
+                    break_duration_minutes: level.break_duration_minutes,
+                    color_up_denomination: level.color_up_denomination,
+                })
+                .collect();
+        let tables =
+            infra::repos::club_tables::list_assignments_for_tournament(&state.db, source_id)
+                .await
+                .gql_err("Failed to fetch tables")?;
+
+        let data = CreateTournamentData {
+            club_id,
+            name,
+            description: source.description.clone(),
+            start_time,
+            end_time: source.end_time.map(shift),
+            buy_in_cents: source.buy_in_cents,
+            rake_cents: Some(source.rake_cents),
+            seat_cap: source.seat_cap,
+            starting_stack: source.starting_stack,
+            early_bird_bonus_chips: source.early_bird_bonus_chips,
+            level_two_bonus_chips: source.level_two_bonus_chips,
+            voucher_value_cents: Some(source.voucher_value_cents),
+            rebuy_max: source.rebuy_max,
+            addon_chips: source.addon_chips,
+            addon_price_cents: source.addon_price_cents,
+            late_registration_level: source.late_registration_level,
+            bounty_type: Some(source.bounty_type.clone()),
+            bounty_amount_cents: Some(source.bounty_amount_cents),
+            leaderboard_config_id: source.leaderboard_config_id,
+            chip_race_rule: Some(source.chip_race_rule.clone()),
+            visibility: Some(source.visibility.clone()),
+            early_bird_buy_in_cents: source.early_bird_buy_in_cents,
+            early_bird_until: source.early_bird_until.map(shift),
+            points_multiplier: Some(source.points_multiplier),
+            seats_per_table: source.seats_per_table,
+            // A copy stands alone: series flights are scheduled with the series.
+            series_id: None,
+            flight_label: None,
+            is_final_day: false,
+        };
+
+        let mut tx = state
+            .db
+            .begin()
+            .await
+            .gql_err("Failed to start transaction")?;
+        let row = create_one(&mut tx, data, Some(&structure), false).await?;
+
+        let table_ids: Vec<Uuid> = tables.iter().map(|t| t.club_table_id).collect();
+        let held: std::collections::HashSet<Uuid> =
+            infra::repos::club_tables::active_table_conflicts(&mut *tx, &table_ids, row.id)
+                .await
+                .gql_err("Database operation failed")?
+                .into_iter()
+                .map(|c| c.club_table_id)
+                .collect();
+        for table in tables.iter().filter(|t| !held.contains(&t.club_table_id)) {
+            infra::repos::club_tables::assign_to_tournament(
+                &mut *tx,
+                row.id,
+                table.club_table_id,
+                table.max_seats_override,
+            )
+            .await
+            .gql_err("Failed to assign table")?;
+        }
+
+        if row.visibility == "unlisted" {
+            tournaments::ensure_invite_token(&mut *tx, row.id, &new_invite_token())
+                .await
+                .gql_err("Failed to create invite link")?;
+        }
+        if let Some(organization_id) = approval_organization {
+            infra::repos::tournament_approvals::create(
+                &mut *tx,
+                row.id,
+                organization_id,
+                manager_id,
+            )
+            .await
+            .gql_err("Failed to request approval")?;
+        }
+        tx.commit().await.gql_err("Failed to create tournament")?;
+
+        if approval_organization.is_none() {
+            publish_schedule_change(ClubTournamentEventType::Created, &row);
+        }
+
+        // Log activity
+        {
+            let db = state.db.clone();
+            let tournament_id = row.id;
+            let metadata = serde_json::json!({
+                "name": row.name,
+                "start_time": row.start_time,
+                "buy_in_cents": row.buy_in_cents,
+                "seat_cap": row.seat_cap,
+                "cloned_from": source_id,
+                "tables": tables.len() - held.len(),
+                "awaiting_approval": approval_organization.is_some(),
+            });
+            tokio::spawn(async move {
+                crate::gql::domains::activity_log::log_and_publish(
+                    &db,
+                    tournament_id,
+                    "tournament",
+                    "created",
+                    Some(manager_id),
+                    None,
+                    metadata,
+                )
+                .await;
+            });
+        }
+
+        Ok(Tournament::from(row))
     }
 
     /// Update an existing tournament
@@ -345,7 +889,52 @@ impl TournamentMutation {
             .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
 
         // Check permissions
-        let _user = require_club_manager(ctx, existing.club_id).await?;
+        let user = require_club_manager(ctx, existing.club_id).await?;
+        if input
+            .expected_updated_at
+            .is_some_and(|at| at != existing.updated_at)
+        {
+            return Err(async_graphql::Error::new(
+                "Tournament was changed since you loaded it. Reload and try again.",
+            ));
+        }
+        TournamentSettings {
+            name: input.name.as_deref().unwrap_or(&existing.name),
+            start_time: input.start_time.unwrap_or(existing.start_time),
+            end_time: input.end_time.or(existing.end_time),
+            buy_in_cents: input
+                .buy_in_cents
+                .map(i64::from)
+                .unwrap_or(existing.buy_in_cents),
+            rake_cents: input
+                .rake_cents
+                .map(i64::from)
+                .unwrap_or(existing.rake_cents),
+            seat_cap: input.seat_cap.or(existing.seat_cap),
+            starting_stack: input.starting_stack.or(existing.starting_stack),
+            early_bird_bonus_chips: input
+                .early_bird_bonus_chips
+                .or(existing.early_bird_bonus_chips),
+            level_two_bonus_chips: input
+                .level_two_bonus_chips
+                .or(existing.level_two_bonus_chips),
+            late_registration_level: input
+                .late_registration_level
+                .or(existing.late_registration_level),
+        }
+        .validate()?;
+        validate_early_bird(
+            input
+                .early_bird_buy_in_cents
+                .map(i64::from)
+                .or(existing.early_bird_buy_in_cents),
+            input.early_bird_until.or(existing.early_bird_until),
+            input
+                .buy_in_cents
+                .map(i64::from)
+                .unwrap_or(existing.buy_in_cents),
+        )?;
+        validate_points_multiplier(input.points_multiplier)?;
 
         // Update tournament data
         let data = UpdateTournamentData {
@@ -353,31 +942,70 @@ impl TournamentMutation {
             description: input.description,
             start_time: input.start_time,
             end_time: input.end_time,
-            buy_in_cents: input.buy_in_cents,
-            rake_cents: input.rake_cents,
+            buy_in_cents: input.buy_in_cents.map(i64::from),
+            rake_cents: input.rake_cents.map(i64::from),
             seat_cap: input.seat_cap,
             starting_stack: input.starting_stack,
             early_bird_bonus_chips: input.early_bird_bonus_chips,
             level_two_bonus_chips: input.level_two_bonus_chips,
-            voucher_value_cents: input.voucher_value_cents,
+            voucher_value_cents: input.voucher_value_cents.map(i64::from),
             rebuy_max: input.rebuy_max,
             addon_chips: input.addon_chips,
-            addon_price_cents: input.addon_price_cents,
+            addon_price_cents: input.addon_price_cents.map(i64::from),
             late_registration_level: input.late_registration_level,
             bounty_type: input.bounty_type.map(String::from),
-            bounty_amount_cents: input.bounty_amount_cents,
+            bounty_amount_cents: input.bounty_amount_cents.map(i64::from),
             leaderboard_config_id: input
                 .leaderboard_config_id
                 .as_ref()
                 .map(|id| Uuid::parse_str(id.as_str()))
                 .transpose()
                 .gql_err("Invalid league ID")?,
+            chip_race_rule: input.chip_race_rule.map(String::from),
+            visibility: input.visibility.map(String::from),
+            early_bird_buy_in_cents: input.early_bird_buy_in_cents.map(i64::from),
+            early_bird_until: input.early_bird_until,
+            points_multiplier: input.points_multiplier,
+            expected_updated_at: input.expected_updated_at,
         };
 
         let updated_row = tournaments::update(&state.db, tournament_id, data)
             .await
             .gql_err("Failed to update tournament")?
-            .ok_or_else(|| async_graphql::Error::new("Tournament not found or already finished"))?;
+            .ok_or_else(|| {
+                async_graphql::Error::new(
+                    "Tournament not found, already finished or changed since you loaded it",
+                )
+            })?;
+
+        // Log activity
+        let changed = changed_fields(&existing, &updated_row);
+        if !changed.is_empty() {
+            let db = state.db.clone();
+            let manager_id = Uuid::parse_str(user.id.as_str()).ok();
+            tokio::spawn(async move {
+                crate::gql::domains::activity_log::log_and_publish(
+                    &db,
+                    tournament_id,
+                    "tournament",
+                    "updated",
+                    manager_id,
+                    None,
+                    serde_json::json!({ "fields": changed }),
+                )
+                .await;
+            });
+        }
+        if updated_row.start_time != existing.start_time
+            || updated_row.end_time != existing.end_time
+        {
+            publish_schedule_change(ClubTournamentEventType::Rescheduled, &updated_row);
+        }
+        if input.visibility == Some(TournamentVisibility::Unlisted) {
+            tournaments::ensure_invite_token(&state.db, tournament_id, &new_invite_token())
+                .await
+                .gql_err("Failed to create invite link")?;
+        }
 
         // Handle structure updates if provided
         if let Some(template_id) = input.template_id {
@@ -409,6 +1037,7 @@ impl TournamentMutation {
                             duration_minutes: level.duration_minutes,
                             is_break: level.is_break,
                             break_duration_minutes: level.break_duration_minutes,
+                            color_up_denomination: level.color_up_denomination,
                         },
                     )
                     .collect();
@@ -435,6 +1064,7 @@ impl TournamentMutation {
                             duration_minutes: level_input.duration_minutes,
                             is_break: level_input.is_break,
                             break_duration_minutes: level_input.break_duration_minutes,
+                            color_up_denomination: level_input.color_up_denomination,
                         },
                     )
                     .collect();
@@ -447,6 +1077,58 @@ impl TournamentMutation {
         Ok(Tournament::from(updated_row))
     }
 
+    /// Soft-delete a tournament created by mistake. It drops out of schedules
+    /// and can no longer be registered for; tournaments with results can't
+    /// be deleted.
+    async fn delete_tournament(&self, ctx: &Context<'_>, id: ID) -> Result<bool> {
+        let state = ctx.data::<AppState>()?;
+        let tournament_id = Uuid::parse_str(id.as_str()).gql_err("Invalid tournament ID")?;
+
+        let existing = tournaments::get_by_id(&state.db, tournament_id)
+            .await
+            .gql_err("Database operation failed")?
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        let user = require_club_manager(ctx, existing.club_id).await?;
+        let manager_id = Uuid::parse_str(user.id.as_str()).gql_err("Invalid user ID")?;
+
+        if tournaments::has_results(&state.db, tournament_id)
+            .await
+            .gql_err("Database operation failed")?
+        {
+            return Err(async_graphql::Error::new(
+                "Tournaments with results can't be deleted",
+            ));
+        }
+
+        // The guard is repeated in the update itself, in case results land
+        // in between.
+        let deleted = tournaments::soft_delete(&state.db, tournament_id, manager_id)
+            .await
+            .gql_err("Failed to delete tournament")?
+            .ok_or_else(|| async_graphql::Error::new("Tournament can no longer be deleted"))?;
+        publish_schedule_change(ClubTournamentEventType::Deleted, &deleted);
+
+        // Log activity
+        {
+            let db = state.db.clone();
+            let name = deleted.name.clone();
+            tokio::spawn(async move {
+                crate::gql::domains::activity_log::log_and_publish(
+                    &db,
+                    tournament_id,
+                    "tournament",
+                    "deleted",
+                    Some(manager_id),
+                    None,
+                    serde_json::json!({ "name": name }),
+                )
+                .await;
+            });
+        }
+
+        Ok(true)
+    }
+
     /// Update tournament live status
     async fn update_tournament_status(
         &self,
@@ -465,15 +1147,76 @@ impl TournamentMutation {
 
         // Check permissions
         let _user = require_club_manager(ctx, existing.club_id).await?;
+        let manager_id = Uuid::parse_str(_user.id.as_str()).ok();
 
-        // Update live status
-        let live_status = input.live_status.into();
-        let updated_row = tournaments::update_live_status(&state.db, tournament_id, live_status)
+        // Cancelling refunds and notifies players; only cancelTournament does it.
+        let live_status: TournamentLiveStatus = input.live_status.into();
+        if live_status == TournamentLiveStatus::Cancelled {
+            return Err(async_graphql::Error::new(
+                "Use cancelTournament to cancel a tournament",
+            ));
+        }
+        if existing.live_status == TournamentLiveStatus::Cancelled {
+            return Err(async_graphql::Error::new("Tournament has been cancelled"));
+        }
+
+        // Starting play: the checklist must pass unless overridden.
+        if readiness::starts_play(existing.live_status, live_status) {
+            let checklist = readiness::evaluate(&state.db, &existing)
+                .await
+                .gql_err("Database operation failed")?;
+            if !checklist.ready {
+                if !input.override_readiness {
+                    return Err(readiness::not_ready_error(&checklist));
+                }
+                let missing: Vec<String> = checklist
+                    .items
+                    .iter()
+                    .filter(|i| !i.passed)
+                    .map(|i| i.detail.clone())
+                    .collect();
+                let db = state.db.clone();
+                tokio::spawn(async move {
+                    crate::gql::domains::activity_log::log_and_publish(
+                        &db,
+                        tournament_id,
+                        "tournament",
+                        "readiness_overridden",
+                        manager_id,
+                        None,
+                        serde_json::json!({ "missing": missing }),
+                    )
+                    .await;
+                });
+            }
+        }
+
+        // Update live status; play beginning starts the clock with it.
+        let mut tx = state
+            .db
+            .begin()
+            .await
+            .gql_err("Database operation failed")?;
+        let updated_row = tournaments::update_live_status(&mut *tx, tournament_id, live_status)
             .await
             .gql_err("Failed to update tournament status")?
             .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        let clock_started = if clock::play_begins(existing.live_status, updated_row.live_status) {
+            tournament_clock::start_for_play(&mut tx, tournament_id)
+                .await
+                .gql_err("Failed to start the clock")?
+                .is_some()
+        } else {
+            false
+        };
+        tx.commit().await.gql_err("Database operation failed")?;
 
-        let manager_id = Uuid::parse_str(_user.id.as_str()).ok();
+        if updated_row.live_status != existing.live_status {
+            publish_schedule_change(ClubTournamentEventType::StatusChanged, &updated_row);
+        }
+        if clock_started {
+            clock::publish_started(&state.db, tournament_id, manager_id).await;
+        }
 
         // Log activity
         {
@@ -520,6 +1263,16 @@ impl TournamentMutation {
                             timestamp: chrono::Utc::now(),
                         });
 
+                        // Seat cards and the seat list are ready to print
+                        // right after the draw.
+                        crate::gql::domains::printouts::service::generate_logged(
+                            &state.db,
+                            tournament_id,
+                            &[PrintoutKind::SeatCards, PrintoutKind::SeatList],
+                            manager_id,
+                        )
+                        .await;
+
                         let db = state.db.clone();
                         tokio::spawn(async move {
                             crate::gql::domains::activity_log::log_and_publish(
@@ -546,6 +1299,140 @@ impl TournamentMutation {
             }
         }
 
+        // Registration is over: alternates still waiting are released, then
+        // the payout table becomes the official one.
+        if crate::gql::domains::results::finalization::closes_registration(
+            existing.live_status,
+            updated_row.live_status,
+        ) {
+            crate::gql::domains::registrations::alternates::close_logged(
+                &state.db,
+                tournament_id,
+                manager_id,
+            )
+            .await;
+            crate::gql::domains::results::finalization::finalize_logged(
+                &state.db,
+                tournament_id,
+                manager_id,
+            )
+            .await;
+        }
+
         Ok(Tournament::from(updated_row))
     }
+
+    /// Cancel a tournament that hasn't finished: every registration is
+    /// cancelled, entries are voided and what players paid at the desk
+    /// becomes a pending refund (see `tournamentRefunds`). Registrants get a
+    /// notification, push and email.
+    async fn cancel_tournament(
+        &self,
+        ctx: &Context<'_>,
+        input: CancelTournamentInput,
+    ) -> Result<Tournament> {
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            Uuid::parse_str(input.tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        let existing = tournaments::get_by_id(&state.db, tournament_id)
+            .await
+            .gql_err("Database operation failed")?
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        let manager = require_club_manager(ctx, existing.club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).ok();
+        let reason = input
+            .reason
+            .map(|reason| reason.trim().to_string())
+            .filter(|reason| !reason.is_empty());
+
+        let cancellation =
+            cancellation::cancel(&state.db, tournament_id, manager_id, reason.as_deref()).await?;
+        let tournament = cancellation.tournament.clone();
+
+        publish_schedule_change(ClubTournamentEventType::StatusChanged, &tournament);
+        publish_seating_event(SeatingChangeEvent {
+            event_type: SeatingEventType::TournamentStatusChanged,
+            tournament_id: tournament_id.into(),
+            club_id: tournament.club_id.into(),
+            affected_assignment: None,
+            affected_player: None,
+            message: "Tournament cancelled".to_string(),
+            timestamp: chrono::Utc::now(),
+        });
+        cancellation.publish_activity();
+
+        let db = state.db.clone();
+        let email_service = state.email_service().cloned();
+        tokio::spawn(async move {
+            cancellation::notify_registrants(&db, email_service, &cancellation, reason.as_deref())
+                .await;
+        });
+
+        Ok(Tournament::from(tournament))
+    }
+
+    /// Mark a cancelled tournament's pending refund as handed back.
+    async fn mark_tournament_refund_paid(
+        &self,
+        ctx: &Context<'_>,
+        refund_id: ID,
+    ) -> Result<TournamentRefund> {
+        let state = ctx.data::<AppState>()?;
+        let refund_id = Uuid::parse_str(refund_id.as_str()).gql_err("Invalid refund ID")?;
+        let refund = tournament_refunds::get_by_id(&state.db, refund_id)
+            .await
+            .gql_err("Database operation failed")?
+            .ok_or_else(|| async_graphql::Error::new("Refund not found"))?;
+        let manager = require_club_manager(ctx, refund.club_id).await?;
+        let manager_id = Uuid::parse_str(manager.id.as_str()).ok();
+
+        let refunded = tournament_refunds::mark_refunded(&state.db, refund_id, manager_id)
+            .await
+            .gql_err("Database operation failed")?
+            .ok_or_else(|| async_graphql::Error::new("Refund was already paid"))?;
+
+        let db = state.db.clone();
+        let (tournament_id, club_player_id, amount_cents) = (
+            refunded.tournament_id,
+            refunded.club_player_id,
+            refunded.amount_cents,
+        );
+        tokio::spawn(async move {
+            crate::gql::domains::activity_log::log_and_publish(
+                &db,
+                tournament_id,
+                "tournament",
+                "refund_paid",
+                manager_id,
+                None,
+                serde_json::json!({
+                    "club_player_id": club_player_id,
+                    "amount_cents": amount_cents,
+                }),
+            )
+            .await;
+        });
+
+        Ok(refunded.into())
+    }
+
+    /// Issue a new invite link for a tournament; the old link stops working.
+    async fn regenerate_tournament_invite_token(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: Uuid,
+    ) -> Result<String> {
+        let state = ctx.data::<AppState>()?;
+        let existing = tournaments::get_by_id(&state.db, tournament_id)
+            .await
+            .gql_err("Database operation failed")?
+            .ok_or_else(|| async_graphql::Error::new("Tournament not found"))?;
+        require_club_manager(ctx, existing.club_id).await?;
+
+        let token = new_invite_token();
+        tournaments::rotate_invite_token(&state.db, tournament_id, &token)
+            .await
+            .gql_err("Failed to regenerate invite link")?;
+        Ok(token)
+    }
 }
@@ -2,11 +2,16 @@ use async_graphql::dataloader::DataLoader;
 use async_graphql::{ComplexObject, Context, Enum, InputObject, Result, SimpleObject, ID};
 use chrono::{DateTime, Utc};
 
+use crate::gql::domains::announcements::types::Announcement;
 use crate::gql::domains::clubs::types::Club;
+use crate::gql::domains::entries::types::PaymentMethod;
+use crate::gql::domains::questions::types::RegistrationQuestion;
 use crate::gql::domains::registrations::types::TournamentRegistration;
+use crate::gql::domains::rules::types::RuleDocument;
 use crate::gql::domains::tournaments::recurrence::RecurrenceFrequency;
 use crate::gql::error::ResultExt;
-use crate::gql::loaders::ClubLoader;
+use crate::gql::loaders::{ClubLoader, ClubPlayerLoader};
+use crate::gql::scalars::Money;
 
 // Tournament status enums
 
@@ -26,6 +31,7 @@ pub enum TournamentLiveStatus {
     Break,
     FinalTable,
     Finished,
+    Cancelled,
 }
 
 impl From<TournamentStatus> for infra::repos::tournaments::TournamentStatus {
@@ -58,6 +64,7 @@ impl From<String> for TournamentLiveStatus {
             "break" => TournamentLiveStatus::Break,
             "final_table" => TournamentLiveStatus::FinalTable,
             "finished" => TournamentLiveStatus::Finished,
+            "cancelled" => TournamentLiveStatus::Cancelled,
             _ => TournamentLiveStatus::NotStarted, // Default to not_started for invalid statuses
         }
     }
@@ -82,6 +89,7 @@ impl From<TournamentLiveStatus> for String {
             TournamentLiveStatus::Break => "break".to_string(),
             TournamentLiveStatus::FinalTable => "final_table".to_string(),
             TournamentLiveStatus::Finished => "finished".to_string(),
+            TournamentLiveStatus::Cancelled => "cancelled".to_string(),
         }
     }
 }
@@ -108,6 +116,9 @@ impl From<infra::repos::tournaments::TournamentLiveStatus> for TournamentLiveSta
             infra::repos::tournaments::TournamentLiveStatus::Finished => {
                 TournamentLiveStatus::Finished
             }
+            infra::repos::tournaments::TournamentLiveStatus::Cancelled => {
+                TournamentLiveStatus::Cancelled
+            }
         }
     }
 }
@@ -134,6 +145,9 @@ impl From<TournamentLiveStatus> for infra::repos::tournaments::TournamentLiveSta
             TournamentLiveStatus::Finished => {
                 infra::repos::tournaments::TournamentLiveStatus::Finished
             }
+            TournamentLiveStatus::Cancelled => {
+                infra::repos::tournaments::TournamentLiveStatus::Cancelled
+            }
         }
     }
 }
@@ -197,6 +211,71 @@ impl From<BountyType> for String {
     }
 }
 
+/// How odd chips are settled when a lower denomination is colored up.
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ChipRaceRule {
+    /// Odd chips at each table are pooled and raced off, at most one new chip
+    /// per player.
+    Race,
+    /// Every odd amount is rounded up to the next chip.
+    RoundUp,
+    /// Every stack is rounded to the nearest chip (halves round up).
+    RoundNearest,
+}
+
+impl From<String> for ChipRaceRule {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "round_up" => ChipRaceRule::RoundUp,
+            "round_nearest" => ChipRaceRule::RoundNearest,
+            _ => ChipRaceRule::Race,
+        }
+    }
+}
+
+impl From<ChipRaceRule> for String {
+    fn from(r: ChipRaceRule) -> Self {
+        match r {
+            ChipRaceRule::Race => "race",
+            ChipRaceRule::RoundUp => "round_up",
+            ChipRaceRule::RoundNearest => "round_nearest",
+        }
+        .to_string()
+    }
+}
+
+/// Who can find and register for a tournament.
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TournamentVisibility {
+    /// Listed in public feeds; anyone can register.
+    Public,
+    /// Listed for, and open to, players on the club's roster only.
+    MembersOnly,
+    /// Never listed; reachable and open only through the invite link.
+    Unlisted,
+}
+
+impl From<String> for TournamentVisibility {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "members_only" => TournamentVisibility::MembersOnly,
+            "unlisted" => TournamentVisibility::Unlisted,
+            _ => TournamentVisibility::Public,
+        }
+    }
+}
+
+impl From<TournamentVisibility> for String {
+    fn from(v: TournamentVisibility) -> Self {
+        match v {
+            TournamentVisibility::Public => "public",
+            TournamentVisibility::MembersOnly => "members_only",
+            TournamentVisibility::Unlisted => "unlisted",
+        }
+        .to_string()
+    }
+}
+
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
 pub struct Tournament {
@@ -206,25 +285,31 @@ pub struct Tournament {
     pub club_id: ID,
     pub start_time: DateTime<Utc>,
     pub end_time: Option<DateTime<Utc>>,
-    pub buy_in_cents: i32,
-    pub rake_cents: i32,
+    pub buy_in_cents: Money,
+    pub rake_cents: Money,
     pub seat_cap: Option<i32>,
     pub starting_stack: Option<i32>, // Default chips granted on the initial buy-in
     pub status: TournamentStatus,    // Calculated: UPCOMING, LIVE, COMPLETED
     pub live_status: TournamentLiveStatus, // Direct from DB: NOT_STARTED, IN_PROGRESS, FINISHED, etc.
     pub early_bird_bonus_chips: Option<i32>, // Extra chips for players present at tournament start
     pub level_two_bonus_chips: Option<i32>, // Extra chips for players still seated at end of L2
-    pub voucher_value_cents: i32,          // Mandatory drink voucher (excluded from prize pool)
-    pub rebuy_max: Option<i32>,            // Max rebuys allowed (flyer display)
+    pub voucher_value_cents: Money,        // Mandatory drink voucher (excluded from prize pool)
+    pub rebuy_max: Option<i32>,            // Max rebuys per player, enforced on entry
     pub addon_chips: Option<i32>,          // Add-on chip amount (flyer display)
-    pub addon_price_cents: Option<i32>,    // Add-on price in cents (flyer display)
+    pub addon_price_cents: Option<Money>,  // Add-on price in cents (flyer display)
     pub late_registration_level: Option<i32>, // Blind level until which late registration stays open
     pub bounty_type: BountyType,              // none | fixed | progressive (PKO)
-    pub bounty_amount_cents: i32,             // Bounty slice of each buy-in / rebuy / re-entry
+    pub bounty_amount_cents: Money,           // Bounty slice of each buy-in / rebuy / re-entry
     pub leaderboard_config_id: Option<ID>,    // Optional league tag (feeds `tagged` leagues)
     pub series_id: Option<ID>,                // Multi-day series (NULL = standalone single-day)
     pub flight_label: Option<String>,         // e.g. "Day 1A", "Day 2"
     pub is_final_day: bool,                   // Series final day: results, points, aggregate pool
+    pub chip_race_rule: ChipRaceRule,         // How odd chips are settled at a color-up
+    pub visibility: TournamentVisibility,     // Public, members-only or unlisted (invite link)
+    pub early_bird_buy_in_cents: Option<Money>, // Buy-in for players registered before the cut-off
+    pub early_bird_until: Option<DateTime<Utc>>, // Early-bird registration cut-off
+    pub points_multiplier: f64,               // Weight on leaderboard points (2.0 = counts double)
+    pub seats_per_table: Option<i32>,         // Seats each table plays with (8 = 8-handed)
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -240,6 +325,8 @@ pub struct TournamentStructure {
     pub duration_minutes: i32,
     pub is_break: bool,
     pub break_duration_minutes: Option<i32>,
+    /// Smallest chip in play from this level on; set on color-up levels.
+    pub color_up_denomination: Option<i32>,
 }
 
 impl From<infra::models::TournamentStructureRow> for TournamentStructure {
@@ -254,6 +341,7 @@ impl From<infra::models::TournamentStructureRow> for TournamentStructure {
             duration_minutes: row.duration_minutes,
             is_break: row.is_break,
             break_duration_minutes: row.break_duration_minutes,
+            color_up_denomination: row.color_up_denomination,
         }
     }
 }
@@ -268,25 +356,31 @@ impl From<infra::models::TournamentRow> for Tournament {
             club_id: row.club_id.into(),
             start_time: row.start_time,
             end_time: row.end_time,
-            buy_in_cents: row.buy_in_cents,
-            rake_cents: row.rake_cents,
+            buy_in_cents: row.buy_in_cents.into(),
+            rake_cents: row.rake_cents.into(),
             seat_cap: row.seat_cap,
             starting_stack: row.starting_stack,
             status,
             live_status: row.live_status.into(),
             early_bird_bonus_chips: row.early_bird_bonus_chips,
             level_two_bonus_chips: row.level_two_bonus_chips,
-            voucher_value_cents: row.voucher_value_cents,
+            voucher_value_cents: row.voucher_value_cents.into(),
             rebuy_max: row.rebuy_max,
             addon_chips: row.addon_chips,
-            addon_price_cents: row.addon_price_cents,
+            addon_price_cents: row.addon_price_cents.map(Money),
             late_registration_level: row.late_registration_level,
             bounty_type: BountyType::from(row.bounty_type),
-            bounty_amount_cents: row.bounty_amount_cents,
+            bounty_amount_cents: row.bounty_amount_cents.into(),
             leaderboard_config_id: row.leaderboard_config_id.map(|id| id.into()),
             series_id: row.series_id.map(|id| id.into()),
             flight_label: row.flight_label,
             is_final_day: row.is_final_day,
+            chip_race_rule: ChipRaceRule::from(row.chip_race_rule),
+            visibility: TournamentVisibility::from(row.visibility),
+            early_bird_buy_in_cents: row.early_bird_buy_in_cents.map(Money),
+            early_bird_until: row.early_bird_until,
+            points_multiplier: row.points_multiplier,
+            seats_per_table: row.seats_per_table,
             created_at: row.created_at,
             updated_at: row.updated_at,
         }
@@ -312,6 +406,79 @@ pub struct TournamentClock {
     pub ante: Option<i32>,
     pub is_break: Option<bool>,
     pub level_duration_minutes: Option<i32>,
+    /// Elimination pace and projected finish, recalculated on every update.
+    pub pace: Option<EliminationPace>,
+}
+
+/// Every running clock in a club, paused at once by a manager (fire alarm,
+/// power cut) until they resume them all.
+#[derive(SimpleObject, Clone)]
+pub struct ClubClockHold {
+    pub id: ID,
+    pub club_id: ID,
+    pub reason: String,
+    /// The tournaments whose clocks the hold paused.
+    pub tournament_ids: Vec<ID>,
+    pub paused_by: Option<ID>,
+    pub paused_at: DateTime<Utc>,
+    pub resumed_by: Option<ID>,
+    pub resumed_at: Option<DateTime<Utc>>,
+}
+
+impl From<infra::repos::club_clock_holds::ClubClockHoldRow> for ClubClockHold {
+    fn from(row: infra::repos::club_clock_holds::ClubClockHoldRow) -> Self {
+        Self {
+            id: row.id.into(),
+            club_id: row.club_id.into(),
+            reason: row.reason,
+            tournament_ids: row.tournament_ids.into_iter().map(Into::into).collect(),
+            paused_by: row.paused_by.map(Into::into),
+            paused_at: row.paused_at,
+            resumed_by: row.resumed_by.map(Into::into),
+            resumed_at: row.resumed_at,
+        }
+    }
+}
+
+/// How fast the field is shrinking and when the tournament should end, for
+/// planning breaks and closing staff.
+#[derive(SimpleObject, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EliminationPace {
+    pub players_remaining: i32,
+    /// Players eliminated so far.
+    pub eliminations: i32,
+    /// Expected eliminations over the next hour of play at the recent pace.
+    pub eliminations_per_hour: f64,
+    /// When the last elimination is expected, breaks included, assuming the
+    /// clock keeps running. Null before the clock starts and once one player
+    /// is left.
+    pub projected_finish_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+pub enum ClubTournamentEventType {
+    Created,
+    Rescheduled,
+    StatusChanged,
+    /// The last seat under the seat cap was taken; new players are waitlisted.
+    Full,
+    /// Deleted by a manager; drop it from the schedule.
+    Deleted,
+}
+
+/// A change to a club's tournament schedule, for the lobby screen. Carries
+/// what the schedule shows; the screen refetches anything else.
+#[derive(SimpleObject, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ClubTournamentEvent {
+    pub event_type: ClubTournamentEventType,
+    pub club_id: ID,
+    pub tournament_id: ID,
+    pub title: String,
+    pub start_time: DateTime<Utc>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub live_status: TournamentLiveStatus,
+    pub seat_cap: Option<i32>,
+    pub timestamp: DateTime<Utc>,
 }
 
 #[ComplexObject]
@@ -379,6 +546,54 @@ impl Tournament {
         )
     }
 
+    /// Token for the direct invite link of an unlisted tournament. Only the
+    /// club's managers see it; null for everyone else.
+    async fn invite_token(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<String>> {
+        use crate::auth::permissions::viewer_manages_club;
+        use crate::state::AppState;
+
+        let club_id = uuid::Uuid::parse_str(self.club_id.as_str()).gql_err("Invalid club ID")?;
+        if !viewer_manages_club(ctx, club_id).await {
+            return Ok(None);
+        }
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            uuid::Uuid::parse_str(self.id.as_str()).gql_err("Invalid tournament ID")?;
+        Ok(infra::repos::tournaments::get_invite_token(&state.db, tournament_id).await?)
+    }
+
+    /// The organization's review, for tournaments of clubs whose organization
+    /// approves new tournaments. Only the club's managers see it.
+    async fn approval(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<crate::gql::types::TournamentApproval>> {
+        use crate::auth::permissions::viewer_manages_club;
+        use crate::state::AppState;
+
+        let club_id = uuid::Uuid::parse_str(self.club_id.as_str()).gql_err("Invalid club ID")?;
+        if !viewer_manages_club(ctx, club_id).await {
+            return Ok(None);
+        }
+        let state = ctx.data::<AppState>()?;
+        let tournament_id =
+            uuid::Uuid::parse_str(self.id.as_str()).gql_err("Invalid tournament ID")?;
+        let row = infra::repos::tournament_approvals::get(&state.db, tournament_id).await?;
+        Ok(row.map(Into::into))
+    }
+
+    /// Buy-in for someone registering now: the early-bird price until the
+    /// cut-off, the regular one after.
+    async fn current_buy_in_cents(&self) -> Money {
+        let (_, cents) = crate::gql::domains::entries::pricing::buy_in_price(
+            self.buy_in_cents.into(),
+            self.early_bird_buy_in_cents.map(i64::from),
+            self.early_bird_until,
+            chrono::Utc::now(),
+        );
+        cents.into()
+    }
+
     async fn registrations(
         &self,
         ctx: &Context<'_>,
@@ -399,6 +614,67 @@ impl Tournament {
             .map(TournamentRegistration::from)
             .collect())
     }
+
+    /// Rules and disclosures in force for this tournament: the club's
+    /// club-wide documents followed by the tournament's own.
+    async fn rules(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<RuleDocument>> {
+        use crate::state::AppState;
+
+        let state = ctx.data::<AppState>()?;
+
+        let tournament_id =
+            uuid::Uuid::parse_str(self.id.as_str()).gql_err("Invalid tournament ID")?;
+
+        let documents =
+            infra::repos::rule_documents::list_for_tournament(&state.db, tournament_id).await?;
+
+        Ok(documents.into_iter().map(RuleDocument::from).collect())
+    }
+
+    /// Questions asked when registering (dinner choice, accessibility
+    /// needs), in display order.
+    async fn registration_questions(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<RegistrationQuestion>> {
+        use crate::state::AppState;
+
+        let state = ctx.data::<AppState>()?;
+
+        let tournament_id =
+            uuid::Uuid::parse_str(self.id.as_str()).gql_err("Invalid tournament ID")?;
+
+        let questions = infra::repos::registration_questions::list_for_tournament(
+            &state.db,
+            tournament_id,
+            true,
+        )
+        .await?;
+
+        Ok(questions
+            .into_iter()
+            .map(RegistrationQuestion::from)
+            .collect())
+    }
+
+    /// Floor broadcasts pinned to the lobby, newest first.
+    async fn pinned_announcements(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<Announcement>> {
+        use crate::state::AppState;
+
+        let state = ctx.data::<AppState>()?;
+
+        let tournament_id =
+            uuid::Uuid::parse_str(self.id.as_str()).gql_err("Invalid tournament ID")?;
+
+        let rows =
+            infra::repos::announcements::list_pinned_for_tournament(&state.db, tournament_id)
+                .await?;
+
+        Ok(rows.into_iter().map(Announcement::from).collect())
+    }
 }
 
 // Tournament input types
@@ -410,22 +686,38 @@ pub struct CreateTournamentInput {
     pub description: Option<String>,
     pub start_time: DateTime<Utc>,
     pub end_time: Option<DateTime<Utc>>,
-    pub buy_in_cents: i32,
-    pub rake_cents: Option<i32>,
+    pub buy_in_cents: Money,
+    pub rake_cents: Option<Money>,
     pub seat_cap: Option<i32>,
     /// Default chips a player receives on their initial buy-in.
     pub starting_stack: Option<i32>,
     pub early_bird_bonus_chips: Option<i32>,
     pub level_two_bonus_chips: Option<i32>,
-    pub voucher_value_cents: Option<i32>,
+    pub voucher_value_cents: Option<Money>,
     pub rebuy_max: Option<i32>,
     pub addon_chips: Option<i32>,
-    pub addon_price_cents: Option<i32>,
+    pub addon_price_cents: Option<Money>,
     pub late_registration_level: Option<i32>,
     pub bounty_type: Option<BountyType>,
-    pub bounty_amount_cents: Option<i32>,
+    pub bounty_amount_cents: Option<Money>,
     /// Optional league this tournament counts toward (feeds `tagged` leagues).
     pub leaderboard_config_id: Option<ID>,
+    /// How odd chips are settled at a color-up. Defaults to a chip race.
+    pub chip_race_rule: Option<ChipRaceRule>,
+    /// Who can find and register. Defaults to public; making it unlisted
+    /// mints the invite link token.
+    pub visibility: Option<TournamentVisibility>,
+    /// Early-bird buy-in for players who register before
+    /// `early_bird_until`; set both or neither.
+    pub early_bird_buy_in_cents: Option<Money>,
+    pub early_bird_until: Option<DateTime<Utc>>,
+    /// Weight on the leaderboard points its results score, e.g. 2 for a
+    /// main event counting double. Defaults to 1; up to 10.
+    pub points_multiplier: Option<f64>,
+    /// Seats each linked table plays with, e.g. 8 for 8-handed on 10-seat
+    /// tables. Defaults to each table's own size; change it later with
+    /// `setTournamentSeatsPerTable`.
+    pub seats_per_table: Option<i32>,
     /// Blind structure template ID - if provided, copies levels from template
     pub template_id: Option<ID>,
     /// Custom blind structure levels - only used if template_id is not provided
@@ -437,6 +729,10 @@ pub struct CreateTournamentInput {
     /// Last date (inclusive) to generate occurrences for. Required when
     /// `recurrence_frequency` is set; ignored otherwise.
     pub recurrence_end_date: Option<DateTime<Utc>>,
+    /// Make this the final of a qualification rule: the players who have
+    /// qualified are registered in it, and later qualifiers as their
+    /// results come in. Not for recurring tournaments.
+    pub qualification_rule_id: Option<ID>,
 }
 
 #[derive(InputObject)]
@@ -446,26 +742,52 @@ pub struct UpdateTournamentInput {
     pub description: Option<String>,
     pub start_time: Option<DateTime<Utc>>,
     pub end_time: Option<DateTime<Utc>>,
-    pub buy_in_cents: Option<i32>,
-    pub rake_cents: Option<i32>,
+    pub buy_in_cents: Option<Money>,
+    pub rake_cents: Option<Money>,
     pub seat_cap: Option<i32>,
     /// Default chips a player receives on their initial buy-in.
     pub starting_stack: Option<i32>,
     pub early_bird_bonus_chips: Option<i32>,
     pub level_two_bonus_chips: Option<i32>,
-    pub voucher_value_cents: Option<i32>,
+    pub voucher_value_cents: Option<Money>,
     pub rebuy_max: Option<i32>,
     pub addon_chips: Option<i32>,
-    pub addon_price_cents: Option<i32>,
+    pub addon_price_cents: Option<Money>,
     pub late_registration_level: Option<i32>,
     pub bounty_type: Option<BountyType>,
-    pub bounty_amount_cents: Option<i32>,
+    pub bounty_amount_cents: Option<Money>,
     /// Optional league this tournament counts toward (feeds `tagged` leagues).
     pub leaderboard_config_id: Option<ID>,
+    /// How odd chips are settled at a color-up. Defaults to a chip race.
+    pub chip_race_rule: Option<ChipRaceRule>,
+    /// Who can find and register. Defaults to public; making it unlisted
+    /// mints the invite link token.
+    pub visibility: Option<TournamentVisibility>,
+    /// Early-bird buy-in for players who register before
+    /// `early_bird_until`; set both or neither.
+    pub early_bird_buy_in_cents: Option<Money>,
+    pub early_bird_until: Option<DateTime<Utc>>,
+    /// Weight on the leaderboard points its results score; applies when
+    /// results are entered. Up to 10.
+    pub points_multiplier: Option<f64>,
     /// Blind structure template ID - if provided, replaces structure with template levels
     pub template_id: Option<ID>,
     /// Custom blind structure levels - only used if template_id is not provided
     pub structure: Option<Vec<TournamentStructureInput>>,
+    /// The `updatedAt` the edit was made from. When set, the update is
+    /// rejected if the tournament has changed since.
+    pub expected_updated_at: Option<DateTime<Utc>>,
+}
+
+/// Recreate a tournament at a new start time, e.g. next week's edition of a
+/// weekly event.
+#[derive(InputObject)]
+pub struct CloneTournamentInput {
+    /// The tournament to copy.
+    pub tournament_id: ID,
+    pub start_time: DateTime<Utc>,
+    /// Defaults to the original's name.
+    pub name: Option<String>,
 }
 
 #[derive(InputObject, Clone)]
@@ -477,10 +799,120 @@ pub struct TournamentStructureInput {
     pub duration_minutes: i32,
     pub is_break: bool,
     pub break_duration_minutes: Option<i32>,
+    /// Smallest chip in play from this level on; set on color-up levels.
+    pub color_up_denomination: Option<i32>,
 }
 
 #[derive(InputObject)]
 pub struct UpdateTournamentStatusInput {
     pub tournament_id: ID,
     pub live_status: TournamentLiveStatus,
+    /// Start play even though the pre-start checklist
+    /// (`tournamentReadiness`) has missing items. Logged on the timeline.
+    #[graphql(default)]
+    pub override_readiness: bool,
+}
+
+#[derive(InputObject)]
+pub struct CancelTournamentInput {
+    pub tournament_id: ID,
+    /// Shown to registrants in the cancellation notice and email.
+    pub reason: Option<String>,
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TournamentRefundStatus {
+    /// Owed to the player; the desk hasn't handed it back yet.
+    Pending,
+    Refunded,
+}
+
+impl From<String> for TournamentRefundStatus {
+    fn from(status: String) -> Self {
+        match status.as_str() {
+            "refunded" => TournamentRefundStatus::Refunded,
+            _ => TournamentRefundStatus::Pending,
+        }
+    }
+}
+
+/// Money a cancelled tournament owes a player, for one payment method.
+/// Vouchers and prepaid buy-ins are credited back on cancellation and never
+/// show up here.
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct TournamentRefund {
+    pub id: ID,
+    pub tournament_id: ID,
+    pub registration_id: Option<ID>,
+    pub club_player_id: ID,
+    pub amount_cents: Money,
+    /// Entries the amount covers.
+    pub entry_count: i32,
+    pub payment_method: PaymentMethod,
+    pub status: TournamentRefundStatus,
+    pub created_at: DateTime<Utc>,
+    pub refunded_by: Option<ID>,
+    pub refunded_at: Option<DateTime<Utc>>,
+}
+
+#[ComplexObject]
+impl TournamentRefund {
+    async fn player_name(&self, ctx: &Context<'_>) -> Result<Option<String>> {
+        let club_player_id =
+            uuid::Uuid::parse_str(self.club_player_id.as_str()).gql_err("Invalid player ID")?;
+        let loader = ctx.data::<DataLoader<ClubPlayerLoader>>()?;
+        Ok(loader
+            .load_one(club_player_id)
+            .await?
+            .map(|player| player.display_name))
+    }
+}
+
+impl From<infra::repos::tournament_refunds::TournamentRefundRow> for TournamentRefund {
+    fn from(row: infra::repos::tournament_refunds::TournamentRefundRow) -> Self {
+        Self {
+            id: row.id.into(),
+            tournament_id: row.tournament_id.into(),
+            registration_id: row.registration_id.map(Into::into),
+            club_player_id: row.club_player_id.into(),
+            amount_cents: row.amount_cents.into(),
+            entry_count: row.entry_count,
+            payment_method: row.payment_method.into(),
+            status: row.status.into(),
+            created_at: row.created_at,
+            refunded_by: row.refunded_by.map(Into::into),
+            refunded_at: row.refunded_at,
+        }
+    }
+}
+
+/// An item of the pre-start checklist.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ReadinessCheck {
+    /// The blind structure has levels.
+    Structure,
+    /// A payout template fits the field.
+    PayoutTemplate,
+    /// At least one table is assigned.
+    Tables,
+    /// The clock can start: its level has blinds, or it is already running.
+    Clock,
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct ReadinessItem {
+    pub check: ReadinessCheck,
+    pub passed: bool,
+    /// What was found, or what's missing.
+    pub detail: String,
+}
+
+/// Whether a tournament is set up to start play.
+#[derive(SimpleObject, Clone)]
+pub struct TournamentReadiness {
+    pub tournament_id: ID,
+    /// Every item passed.
+    pub ready: bool,
+    pub items: Vec<ReadinessItem>,
 }
@@ -7,19 +7,86 @@ use crate::auth::jwt::Claims;
 use crate::gql::domains::achievements::types::PlayerAchievement;
 use crate::gql::domains::results::types::{PlayerStatistics, UserTournamentResult};
 use crate::gql::error::ResultExt;
-use crate::gql::loaders::TournamentLoader;
+use crate::gql::loaders::{TournamentLoader, UserLoader};
 use crate::gql::types::{PaginatedResponse, PaginationInput, Role, User};
 use crate::state::AppState;
+use infra::phone;
 use infra::repos::{
-    achievements, friendships, tournament_results, users,
+    achievements, clubs, friendships, tournament_results, user_audit_log, users,
     users::{CreateUserData, UpdateUserData, UserFilter},
 };
 
+use super::service::{self, IssuedContactChange};
 use super::types::{
-    CreatePlayerInput, NotificationPreferences, PlayerProfile, ProfileFriendship,
-    UpdateNotificationPreferencesInput, UpdatePlayerInput,
+    ConfirmContactChangeInput, ContactChangeRequest, ContactChannel, CreatePlayerInput,
+    NotificationPreferences, PlayerProfile, ProfileFriendship, RequestContactChangeInput,
+    UpdateMyProfileInput, UpdateMyProfilePayload, UpdateNotificationPreferencesInput,
+    UpdatePlayerInput,
 };
 
+/// Send the two codes of a contact change, best-effort like the other
+/// account emails. Returns where the current-address code went.
+async fn deliver_contact_change_codes(
+    state: &AppState,
+    issued: &IssuedContactChange,
+    locale: Option<&str>,
+) -> ContactChannel {
+    use crate::services::email_service::Locale;
+
+    let user = &issued.user;
+    let new_value = issued.request.new_value.as_str().to_string();
+    let locale = Locale::from_str_lossy(locale.unwrap_or(&user.locale));
+    let current_phone = user.phone.as_ref().map(|p| p.as_str().to_string());
+    let phone_change = issued.request.channel == ContactChannel::Phone.as_str();
+
+    let send_email = |to: String, code: String, to_new_address: bool| async move {
+        match state.email_service() {
+            Some(email) => {
+                if let Err(e) = email
+                    .send_contact_change_code(&to, &user.first_name, &code, to_new_address, locale)
+                    .await
+                {
+                    tracing::error!("Failed to send contact change code email: {}", e);
+                }
+            }
+            None => {
+                tracing::warn!("Contact change requested but the email service is not configured")
+            }
+        }
+    };
+    let send_sms = |to: String, code: String| async move {
+        let text = format!(
+            "PocketPair: {code} is your code to confirm a phone number change. It expires in {} minutes.",
+            service::CODE_TTL_MINUTES
+        );
+        match state.sms_service() {
+            Some(sms) => {
+                if let Err(e) = sms.send_sms(&to, &text).await {
+                    tracing::error!("Failed to send contact change code SMS: {}", e);
+                }
+            }
+            None => tracing::warn!("Phone change requested but the SMS service is not configured"),
+        }
+    };
+
+    let current_code_sent_to = match current_phone {
+        Some(phone) if phone_change => {
+            send_sms(phone, issued.old_code.clone()).await;
+            ContactChannel::Phone
+        }
+        _ => {
+            send_email(user.email.clone(), issued.old_code.clone(), false).await;
+            ContactChannel::Email
+        }
+    };
+    if phone_change {
+        send_sms(new_value, issued.new_code.clone()).await;
+    } else {
+        send_email(new_value, issued.new_code.clone(), true).await;
+    }
+    current_code_sent_to
+}
+
 #[derive(Default)]
 pub struct UserQuery;
 
@@ -61,6 +128,18 @@ impl UserQuery {
         })
     }
 
+    /// Federation entity resolver: `User @key(fields: "id")`. Requires
+    /// authentication; lookups are batched through the user loader.
+    #[graphql(entity)]
+    async fn find_user_by_id(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<User>> {
+        ctx.data::<Claims>().map_err(|_| {
+            async_graphql::Error::new("You must be logged in to perform this action")
+        })?;
+        let loader = ctx.data::<DataLoader<UserLoader>>()?;
+        let row = loader.load_one(id).await.gql_err("Loading user failed")?;
+        Ok(row.map(User::from))
+    }
+
     /// A player's public profile: identity, lifetime stats, unlocked
     /// achievements and recent finishes — reachable from the leaderboard.
     /// Requires authentication and reports the viewer's friendship relationship
@@ -84,8 +163,8 @@ impl UserQuery {
         let statistics = PlayerStatistics {
             total_itm: stats.total_itm,
             total_tournaments: stats.total_tournaments,
-            total_winnings: stats.total_winnings,
-            total_buy_ins: stats.total_buy_ins,
+            total_winnings: stats.total_winnings.into(),
+            total_buy_ins: stats.total_buy_ins.into(),
             itm_percentage: stats.itm_percentage,
             roi_percentage: stats.roi_percentage,
         };
@@ -183,11 +262,13 @@ impl UserMutation {
         let club_id = Uuid::parse_str(input.club_id.as_str()).gql_err("Invalid club ID")?;
         let _manager = require_club_manager(ctx, club_id).await?;
 
-        // Check if user with email already exists
-        let existing = sqlx::query!("SELECT id FROM users WHERE email = $1", input.email)
-            .fetch_optional(&state.db)
-            .await
-            .gql_err("Database operation failed")?;
+        // Check if user with email already exists, whichever club they're in
+        let existing = infra::db::without_request_scope(
+            sqlx::query!("SELECT id FROM users WHERE email = $1", input.email)
+                .fetch_optional(&state.db),
+        )
+        .await
+        .gql_err("Database operation failed")?;
 
         if existing.is_some() {
             return Err(async_graphql::Error::new(
@@ -195,17 +276,33 @@ impl UserMutation {
             ));
         }
 
+        // National numbers are read as the club's country's.
+        let phone = match input.phone.as_deref().map(str::trim) {
+            Some(raw) if !raw.is_empty() => {
+                let country = clubs::get_by_id(&state.db, club_id)
+                    .await?
+                    .and_then(|club| club.country);
+                Some(service::normalize_phone(
+                    raw,
+                    country.as_deref().unwrap_or(phone::DEFAULT_COUNTRY),
+                )?)
+            }
+            _ => None,
+        };
+
         let create_data = CreateUserData {
             email: input.email,
             first_name: input.first_name,
             last_name: input.last_name,
             username: input.username,
-            phone: input.phone,
+            phone,
         };
 
-        let user_row = users::create(&state.db, create_data).await?;
+        // The new account belongs to no club yet, so it's outside the scope.
+        let user_row =
+            infra::db::without_request_scope(users::create(&state.db, create_data)).await?;
 
-        Ok(user_row.into())
+        Ok(User::from(user_row).with_contact_details())
     }
 
     /// Update an existing player (managers only)
@@ -216,49 +313,210 @@ impl UserMutation {
         use crate::auth::permissions::require_role;
 
         // TODO: users aren't club-scoped, keep role-based for now
-        let _manager = require_role(ctx, Role::Manager).await?;
+        let manager = require_role(ctx, Role::Manager).await?;
 
         let state = ctx.data::<AppState>()?;
 
         let user_id = Uuid::parse_str(input.id.as_str()).gql_err("Invalid user ID")?;
 
-        // Check if user exists
-        let existing = users::get_by_id(&state.db, user_id).await?;
-        if existing.is_none() {
-            return Err(async_graphql::Error::new("User not found"));
-        }
+        // Role-based for now, so the edit reaches past the manager's clubs.
+        infra::db::without_request_scope(async move {
+            // Check if user exists
+            let existing = users::get_by_id(&state.db, user_id)
+                .await?
+                .ok_or_else(|| async_graphql::Error::new("User not found"))?;
+
+            // If email is being updated, check it's not taken by another user
+            if let Some(ref new_email) = input.email {
+                let email_taken = sqlx::query!(
+                    "SELECT id FROM users WHERE email = $1 AND id != $2",
+                    new_email,
+                    user_id
+                )
+                .fetch_optional(&state.db)
+                .await
+                .gql_err("Database operation failed")?;
+
+                if email_taken.is_some() {
+                    return Err(async_graphql::Error::new(
+                        "A user with this email already exists",
+                    ));
+                }
+            }
 
-        // If email is being updated, check it's not taken by another user
-        if let Some(ref new_email) = input.email {
-            let email_taken = sqlx::query!(
-                "SELECT id FROM users WHERE email = $1 AND id != $2",
-                new_email,
-                user_id
-            )
-            .fetch_optional(&state.db)
-            .await
-            .gql_err("Database operation failed")?;
+            let phone = match input.phone {
+                Some(raw) if !raw.trim().is_empty() => {
+                    Some(service::normalize_user_phone(&state.db, user_id, &raw).await?)
+                }
+                other => other,
+            };
+
+            let update_data = UpdateUserData {
+                email: input.email,
+                first_name: input.first_name,
+                last_name: input.last_name,
+                username: input.username,
+                phone,
+            };
+
+            let user_row = users::update(&state.db, user_id, update_data)
+                .await?
+                .ok_or_else(|| async_graphql::Error::new("Failed to update user"))?;
+
+            // Managers skip the confirmation flow, so their edits are audited.
+            let manager_id = Uuid::parse_str(manager.id.as_str()).ok();
+            if existing.email != user_row.email {
+                user_audit_log::record(
+                    &state.db,
+                    user_id,
+                    manager_id,
+                    user_audit_log::EMAIL_CHANGED,
+                    Some(&existing.email),
+                    Some(&user_row.email),
+                )
+                .await?;
+            }
+            let old_phone = existing.phone.map(String::from);
+            let new_phone = user_row.phone.clone().map(String::from);
+            if old_phone != new_phone {
+                user_audit_log::record(
+                    &state.db,
+                    user_id,
+                    manager_id,
+                    user_audit_log::PHONE_CHANGED,
+                    old_phone.as_deref(),
+                    new_phone.as_deref(),
+                )
+                .await?;
+            }
+
+            Ok(User::from(user_row).with_contact_details())
+        })
+        .await
+    }
+
+    /// Edit the current user's own profile. Every field is checked before
+    /// anything is saved; failures carry `code: INVALID_INPUT` and the
+    /// offending `field`, or `USERNAME_TAKEN` / `EMAIL_TAKEN`.
+    async fn update_my_profile(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateMyProfileInput,
+    ) -> Result<UpdateMyProfilePayload> {
+        let state = ctx.data::<AppState>()?;
+        let claims = ctx.data::<Claims>()?;
+        let user_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
 
-            if email_taken.is_some() {
-                return Err(async_graphql::Error::new(
-                    "A user with this email already exists",
-                ));
+        let update = service::validate_profile(
+            input.first_name,
+            input.last_name,
+            input.username,
+            input.avatar_url,
+        )?;
+        let current = users::get_by_id(&state.db, user_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("User not found"))?;
+        let phone = match input.phone {
+            Some(raw) if !raw.trim().is_empty() => {
+                Some(service::normalize_user_phone(&state.db, user_id, &raw).await?)
             }
+            other => other,
+        };
+
+        // Only values that actually change start a confirmation.
+        let contact_changes: Vec<(ContactChannel, String)> = [
+            (
+                ContactChannel::Email,
+                input.email,
+                Some(current.email.as_str()),
+            ),
+            (
+                ContactChannel::Phone,
+                phone,
+                current.phone.as_ref().map(|p| p.as_str()),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(channel, value, current)| {
+            let value = value?.trim().to_string();
+            (Some(value.as_str()) != current).then_some((channel, value))
+        })
+        .collect();
+        for (channel, value) in &contact_changes {
+            service::check_contact_change(&state.db, &current, *channel, value).await?;
         }
 
-        let update_data = UpdateUserData {
-            email: input.email,
-            first_name: input.first_name,
-            last_name: input.last_name,
-            username: input.username,
-            phone: input.phone,
-        };
+        let user_row = service::update_profile(&state.db, user_id, update).await?;
+
+        let mut pending_contact_changes = Vec::new();
+        for (channel, value) in contact_changes {
+            let issued = service::start_contact_change(&state.db, user_id, channel, &value).await?;
+            let current_code_sent_to =
+                deliver_contact_change_codes(state, &issued, input.locale.as_deref()).await;
+            pending_contact_changes.push(ContactChangeRequest {
+                id: issued.request.id.into(),
+                channel,
+                current_code_sent_to,
+                expires_at: issued.request.expires_at,
+            });
+        }
 
-        let user_row = users::update(&state.db, user_id, update_data)
-            .await?
-            .ok_or_else(|| async_graphql::Error::new("Failed to update user"))?;
+        Ok(UpdateMyProfilePayload {
+            user: User::from(user_row).with_contact_details(),
+            pending_contact_changes,
+        })
+    }
+
+    /// Start changing the current user's email or phone. A code goes to the
+    /// current address and another to the new one; nothing changes until
+    /// both are entered with `confirmContactChange`.
+    async fn request_contact_change(
+        &self,
+        ctx: &Context<'_>,
+        input: RequestContactChangeInput,
+    ) -> Result<ContactChangeRequest> {
+        let state = ctx.data::<AppState>()?;
+        let claims = ctx.data::<Claims>()?;
+        let user_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+
+        let issued =
+            service::start_contact_change(&state.db, user_id, input.channel, &input.new_value)
+                .await?;
+        let current_code_sent_to =
+            deliver_contact_change_codes(state, &issued, input.locale.as_deref()).await;
+
+        Ok(ContactChangeRequest {
+            id: issued.request.id.into(),
+            channel: input.channel,
+            current_code_sent_to,
+            expires_at: issued.request.expires_at,
+        })
+    }
 
-        Ok(user_row.into())
+    /// Apply a pending email or phone change with the codes sent to the old
+    /// and new address. An email change ends the current access token's
+    /// validity: refresh the session afterwards.
+    async fn confirm_contact_change(
+        &self,
+        ctx: &Context<'_>,
+        input: ConfirmContactChangeInput,
+    ) -> Result<User> {
+        let state = ctx.data::<AppState>()?;
+        let claims = ctx.data::<Claims>()?;
+        let user_id = Uuid::parse_str(&claims.sub).gql_err("Invalid user ID")?;
+        let request_id =
+            Uuid::parse_str(input.request_id.as_str()).gql_err("Invalid request ID")?;
+
+        let user_row = service::confirm_contact_change(
+            &state.db,
+            user_id,
+            request_id,
+            &input.old_code,
+            &input.new_code,
+        )
+        .await?;
+
+        Ok(User::from(user_row).with_contact_details())
     }
 
     /// Deactivate a player (soft delete) - managers only
@@ -275,11 +533,11 @@ impl UserMutation {
 
         let user_id = Uuid::parse_str(id.as_str()).gql_err("Invalid user ID")?;
 
-        let user_row = users::deactivate(&state.db, user_id)
+        let user_row = infra::db::without_request_scope(users::deactivate(&state.db, user_id))
             .await?
             .ok_or_else(|| async_graphql::Error::new("User not found"))?;
 
-        Ok(user_row.into())
+        Ok(User::from(user_row).with_contact_details())
     }
 
     /// Update the current user's notification preferences. Omitted fields
@@ -322,7 +580,9 @@ impl UserMutation {
     /// deactivated; tournament history is kept under an anonymous name.
     async fn delete_my_account(&self, ctx: &Context<'_>) -> Result<bool> {
         use crate::auth::jwt::Claims;
-        use infra::repos::{device_tokens, refresh_tokens};
+        use infra::repos::{
+            auth_identities, contact_change_requests, device_tokens, refresh_tokens,
+        };
 
         let state = ctx.data::<AppState>()?;
         let claims = ctx.data::<Claims>()?;
@@ -335,7 +595,10 @@ impl UserMutation {
             .ok_or_else(|| async_graphql::Error::new("User not found"))?;
 
         device_tokens::delete_all_for_user(&state.db, user_id).await?;
+        auth_identities::delete_all_for_user(&state.db, user_id).await?;
         refresh_tokens::revoke_all_for_user(&state.db, user_id).await?;
+        contact_change_requests::delete_all_for_user(&state.db, user_id).await?;
+        user_audit_log::delete_all_for_user(&state.db, user_id).await?;
 
         Ok(true)
     }
@@ -351,10 +614,10 @@ impl UserMutation {
 
         let user_id = Uuid::parse_str(id.as_str()).gql_err("Invalid user ID")?;
 
-        let user_row = users::reactivate(&state.db, user_id)
+        let user_row = infra::db::without_request_scope(users::reactivate(&state.db, user_id))
             .await?
             .ok_or_else(|| async_graphql::Error::new("User not found"))?;
 
-        Ok(user_row.into())
+        Ok(User::from(user_row).with_contact_details())
     }
 }
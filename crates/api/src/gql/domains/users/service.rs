@@ -0,0 +1,329 @@
+//! Self-service profile edits. Names, username and avatar are validated and
+//! saved directly; email and phone changes send one code to the account's
+//! current address and one to the new address, and are applied, and written
+//! to the user audit log, only once both come back. Phone numbers are stored
+//! in E.164.
+
+use async_graphql::{Error, ErrorExtensions, Result};
+use chrono::{Duration, Utc};
+use infra::models::UserRow;
+use infra::phone;
+use infra::pii::Pii;
+use infra::repos::contact_change_requests::{self, ContactChangeRequestRow};
+use infra::repos::user_audit_log;
+use infra::repos::users::{self, ProfileUpdate, UpdateUserData};
+use rand::RngExt;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::auth::refresh::hash_token;
+use crate::gql::error::ResultExt;
+
+use super::types::ContactChannel;
+
+/// How long the two codes of a request can be entered.
+pub const CODE_TTL_MINUTES: i64 = 30;
+
+/// Wrong code pairs a request tolerates before it has to be started again.
+pub const MAX_ATTEMPTS: i32 = 5;
+
+const MAX_PHONE_LEN: usize = 32;
+const MAX_NAME_LEN: usize = 50;
+const MAX_AVATAR_URL_LEN: usize = 2048;
+
+fn coded(message: &str, code: &'static str) -> Error {
+    Error::new(message).extend_with(|_, e| e.set("code", code))
+}
+
+/// A rejected profile field: `INVALID_INPUT`, naming the field.
+fn invalid(field: &'static str, message: impl Into<String>) -> Error {
+    Error::new(message.into()).extend_with(|_, e| {
+        e.set("code", "INVALID_INPUT");
+        e.set("field", field);
+    })
+}
+
+/// Trimmed value of an optional field; an empty one means "clear".
+fn clearable(value: Option<String>) -> Option<Option<String>> {
+    value.map(|v| Some(v.trim().to_string()).filter(|v| !v.is_empty()))
+}
+
+/// Check a profile edit field by field and normalize it for storage.
+pub fn validate_profile(
+    first_name: Option<String>,
+    last_name: Option<String>,
+    username: Option<String>,
+    avatar_url: Option<String>,
+) -> Result<ProfileUpdate> {
+    let first_name = first_name.map(|v| v.trim().to_string());
+    if let Some(name) = &first_name {
+        if name.is_empty() || name.chars().count() > MAX_NAME_LEN {
+            return Err(invalid(
+                "firstName",
+                format!("First name must be 1-{MAX_NAME_LEN} characters"),
+            ));
+        }
+    }
+
+    let last_name = clearable(last_name);
+    if let Some(Some(name)) = &last_name {
+        if name.chars().count() > MAX_NAME_LEN {
+            return Err(invalid(
+                "lastName",
+                format!("Last name must be at most {MAX_NAME_LEN} characters"),
+            ));
+        }
+    }
+
+    let username = clearable(username);
+    if let Some(Some(name)) = &username {
+        let allowed = name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'));
+        if !(3..=30).contains(&name.len()) || !allowed {
+            return Err(invalid(
+                "username",
+                "Username must be 3-30 letters, digits, '.', '_' or '-'",
+            ));
+        }
+    }
+
+    let avatar_url = clearable(avatar_url);
+    if let Some(Some(url)) = &avatar_url {
+        let valid = url
+            .strip_prefix("https://")
+            .is_some_and(|rest| !rest.is_empty() && !rest.contains(char::is_whitespace));
+        if !valid || url.len() > MAX_AVATAR_URL_LEN {
+            return Err(invalid("avatarUrl", "Avatar must be an https:// URL"));
+        }
+    }
+
+    Ok(ProfileUpdate {
+        first_name,
+        last_name,
+        username,
+        avatar_url,
+    })
+}
+
+/// A typed phone number in E.164 for storage, national numbers read as
+/// `country`'s. Unreadable numbers are `INVALID_INPUT` on `phone`.
+pub fn normalize_phone(raw: &str, country: &str) -> Result<String> {
+    phone::normalize(raw, country).map_err(|e| invalid("phone", e.to_string()))
+}
+
+/// [`normalize_phone`] with the user's home country, that of the first club
+/// they joined.
+pub async fn normalize_user_phone(db: &PgPool, user_id: Uuid, raw: &str) -> Result<String> {
+    let country = users::home_country(db, user_id).await?;
+    normalize_phone(raw, country.as_deref().unwrap_or(phone::DEFAULT_COUNTRY))
+}
+
+/// Save a validated profile edit. A username another account holds is
+/// `USERNAME_TAKEN`.
+pub async fn update_profile(db: &PgPool, user_id: Uuid, update: ProfileUpdate) -> Result<UserRow> {
+    if let Some(Some(username)) = &update.username {
+        if users::username_taken(db, username, user_id).await? {
+            return Err(coded("This username is already taken", "USERNAME_TAKEN"));
+        }
+    }
+
+    match users::update_profile(db, user_id, update).await {
+        Ok(user) => user.ok_or_else(|| Error::new("User not found")),
+        Err(sqlx::Error::Database(e)) if e.is_unique_violation() => {
+            Err(coded("This username is already taken", "USERNAME_TAKEN"))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn confirmation_code() -> String {
+    format!("{:06}", rand::rng().random_range(0..1_000_000))
+}
+
+/// A started change and the raw codes to deliver: `old_code` to the
+/// account's current address, `new_code` to the new one.
+pub struct IssuedContactChange {
+    pub request: ContactChangeRequestRow,
+    pub user: UserRow,
+    pub old_code: String,
+    pub new_code: String,
+}
+
+async fn email_taken(db: &PgPool, email: &str, user_id: Uuid) -> Result<bool> {
+    Ok(users::id_by_email(db, email)
+        .await?
+        .is_some_and(|id| id != user_id))
+}
+
+/// Whether `user` may switch `channel` to `new_value` (already trimmed). An
+/// email already used by another account is `EMAIL_TAKEN`.
+pub async fn check_contact_change(
+    db: &PgPool,
+    user: &UserRow,
+    channel: ContactChannel,
+    new_value: &str,
+) -> Result<()> {
+    match channel {
+        ContactChannel::Email => {
+            if !new_value.contains('@') || new_value.len() < 3 {
+                return Err(invalid("email", "Invalid email address"));
+            }
+            if new_value == user.email {
+                return Err(invalid("email", "This is already your email address"));
+            }
+            if email_taken(db, new_value, user.id).await? {
+                return Err(coded(
+                    "A user with this email already exists",
+                    "EMAIL_TAKEN",
+                ));
+            }
+        }
+        ContactChannel::Phone => {
+            if new_value.is_empty() || new_value.chars().count() > MAX_PHONE_LEN {
+                return Err(invalid(
+                    "phone",
+                    format!("Phone number must be 1-{MAX_PHONE_LEN} characters"),
+                ));
+            }
+            if user.phone.as_ref().map(Pii::as_str) == Some(new_value) {
+                return Err(invalid("phone", "This is already your phone number"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Start changing `user_id`'s email or phone to `new_value`, replacing any
+/// pending request on that channel.
+pub async fn start_contact_change(
+    db: &PgPool,
+    user_id: Uuid,
+    channel: ContactChannel,
+    new_value: &str,
+) -> Result<IssuedContactChange> {
+    let new_value = match channel {
+        ContactChannel::Phone if !new_value.trim().is_empty() => {
+            normalize_user_phone(db, user_id, new_value).await?
+        }
+        _ => new_value.trim().to_string(),
+    };
+    let new_value = new_value.as_str();
+    let user = users::get_by_id(db, user_id)
+        .await?
+        .filter(|u| u.is_active)
+        .ok_or_else(|| Error::new("User not found"))?;
+    check_contact_change(db, &user, channel, new_value).await?;
+
+    let old_code = confirmation_code();
+    let new_code = confirmation_code();
+    let expires_at = Utc::now() + Duration::minutes(CODE_TTL_MINUTES);
+
+    let mut tx = db.begin().await.gql_err("Database operation failed")?;
+    contact_change_requests::cancel_pending(&mut *tx, user_id, channel.as_str()).await?;
+    let request = contact_change_requests::create(
+        &mut *tx,
+        user_id,
+        channel.as_str(),
+        new_value,
+        &hash_token(&old_code),
+        &hash_token(&new_code),
+        expires_at,
+    )
+    .await?;
+    tx.commit().await.gql_err("Database operation failed")?;
+
+    Ok(IssuedContactChange {
+        request,
+        user,
+        old_code,
+        new_code,
+    })
+}
+
+/// Apply a pending change once both of its codes are right. A wrong pair
+/// counts as an attempt (`INVALID_CODE`); after `MAX_ATTEMPTS` of them the
+/// request is `TOO_MANY_ATTEMPTS`, and past its deadline `CODE_EXPIRED`.
+/// Changing the email bumps the user's token version: the client refreshes.
+pub async fn confirm_contact_change(
+    db: &PgPool,
+    user_id: Uuid,
+    request_id: Uuid,
+    old_code: &str,
+    new_code: &str,
+) -> Result<UserRow> {
+    let mut tx = db.begin().await.gql_err("Database operation failed")?;
+    let request = contact_change_requests::get_pending_for_update(&mut *tx, request_id, user_id)
+        .await?
+        .ok_or_else(|| coded("Change request not found", "CHANGE_NOT_FOUND"))?;
+
+    if request.expires_at <= Utc::now() {
+        return Err(coded("The confirmation codes have expired", "CODE_EXPIRED"));
+    }
+    if request.attempts >= MAX_ATTEMPTS {
+        return Err(coded(
+            "Too many wrong codes, request a new change",
+            "TOO_MANY_ATTEMPTS",
+        ));
+    }
+    if hash_token(old_code.trim()) != request.old_code_hash
+        || hash_token(new_code.trim()) != request.new_code_hash
+    {
+        contact_change_requests::record_failed_attempt(&mut *tx, request.id).await?;
+        tx.commit().await.gql_err("Database operation failed")?;
+        return Err(coded("Invalid confirmation code", "INVALID_CODE"));
+    }
+
+    let current = users::get_by_id(&mut *tx, user_id)
+        .await?
+        .ok_or_else(|| Error::new("User not found"))?;
+    let new_value = request.new_value.into_inner();
+    let (update, action, old_value) = match request.channel.as_str() {
+        "email" => (
+            UpdateUserData {
+                email: Some(new_value.clone()),
+                first_name: None,
+                last_name: None,
+                username: None,
+                phone: None,
+            },
+            user_audit_log::EMAIL_CHANGED,
+            Some(current.email),
+        ),
+        _ => (
+            UpdateUserData {
+                email: None,
+                first_name: None,
+                last_name: None,
+                username: None,
+                phone: Some(new_value.clone()),
+            },
+            user_audit_log::PHONE_CHANGED,
+            current.phone.map(String::from),
+        ),
+    };
+
+    let user = match users::update(&mut *tx, user_id, update).await {
+        Ok(user) => user.ok_or_else(|| Error::new("User not found"))?,
+        Err(sqlx::Error::Database(e)) if e.is_unique_violation() => {
+            return Err(coded(
+                "A user with this email already exists",
+                "EMAIL_TAKEN",
+            ));
+        }
+        Err(e) => return Err(e.into()),
+    };
+    contact_change_requests::mark_confirmed(&mut *tx, request.id).await?;
+    user_audit_log::record(
+        &mut *tx,
+        user_id,
+        Some(user_id),
+        action,
+        old_value.as_deref(),
+        Some(&new_value),
+    )
+    .await?;
+    tx.commit().await.gql_err("Database operation failed")?;
+
+    Ok(user)
+}
@@ -1,23 +1,47 @@
 use async_graphql::{ComplexObject, Context, Enum, InputObject, SimpleObject, ID};
+use chrono::{DateTime, Utc};
 
-use crate::gql::common::types::Role;
+use crate::auth::guards::{AccountOwnerGuard, PersonalDataGuard};
+use crate::gql::common::types::{PaginatedResponse, PaginationInput, Role};
 use crate::gql::domains::achievements::types::PlayerAchievement;
+use crate::gql::domains::auth::types::LinkedProvider;
 use crate::gql::domains::clubs::types::Club;
-use crate::gql::domains::results::types::{PlayerStatistics, UserTournamentResult};
+use crate::gql::domains::results::types::{
+    PlayerStatistics, TournamentHistoryEntry, UserTournamentResult,
+};
 use crate::gql::error::ResultExt;
 
 #[derive(SimpleObject, Clone, serde::Serialize, serde::Deserialize)]
 #[graphql(complex)]
 pub struct User {
     pub id: ID,
+    /// The user themself, managers of a club they play at, and admins.
+    #[graphql(guard = "PersonalDataGuard::new(self)")]
     pub email: String,
     pub username: Option<String>,
     pub first_name: String,
     pub last_name: Option<String>,
+    /// E.164, e.g. `+32470123456`; `formattedPhone` for display. The user
+    /// themself, managers of a club they play at, and admins.
+    #[graphql(guard = "PersonalDataGuard::new(self)")]
     pub phone: Option<String>,
+    pub avatar_url: Option<String>,
     pub is_active: bool,
     pub role: Role,
     pub locale: String,
+    /// Set by resolvers that already authorized the viewer for this account
+    /// (it was just written on their behalf), lifting the contact guards.
+    #[graphql(skip)]
+    #[serde(skip)]
+    pub contact_details_visible: bool,
+}
+
+impl User {
+    /// Show contact details whoever the viewer is; see `contact_details_visible`.
+    pub fn with_contact_details(mut self) -> Self {
+        self.contact_details_visible = true;
+        self
+    }
 }
 
 impl From<infra::models::UserRow> for User {
@@ -28,10 +52,12 @@ impl From<infra::models::UserRow> for User {
             username: row.username,
             first_name: row.first_name,
             last_name: row.last_name,
-            phone: row.phone,
+            phone: row.phone.map(String::from),
+            avatar_url: row.avatar_url,
             is_active: row.is_active,
             role: Role::from(row.role),
             locale: row.locale,
+            contact_details_visible: false,
         }
     }
 }
@@ -56,6 +82,152 @@ impl User {
             Ok(None)
         }
     }
+
+    /// `phone` as dialed from the club's country: the national format for a
+    /// number of that country, the international one otherwise. Without
+    /// `clubId`, the country of the user's first club. The user themself,
+    /// managers of a club they play at, and admins.
+    #[graphql(guard = "PersonalDataGuard::new(self)")]
+    async fn formatted_phone(
+        &self,
+        ctx: &Context<'_>,
+        club_id: Option<ID>,
+    ) -> async_graphql::Result<Option<String>> {
+        use crate::state::AppState;
+
+        let Some(phone) = self.phone.as_deref() else {
+            return Ok(None);
+        };
+        let state = ctx.data::<AppState>()?;
+        let country = match club_id {
+            Some(club_id) => {
+                let club_id = uuid::Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+                infra::repos::clubs::get_by_id(&state.db, club_id)
+                    .await?
+                    .and_then(|club| club.country)
+            }
+            None => {
+                let user_id = uuid::Uuid::parse_str(self.id.as_str()).gql_err("Invalid user ID")?;
+                infra::repos::users::home_country(&state.db, user_id).await?
+            }
+        };
+        Ok(Some(infra::phone::format(
+            phone,
+            country.as_deref().unwrap_or(infra::phone::DEFAULT_COUNTRY),
+        )))
+    }
+
+    /// External logins linked to the account. The user themself and admins.
+    #[graphql(guard = "AccountOwnerGuard::new(self)")]
+    async fn linked_providers(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<LinkedProvider>> {
+        use crate::state::AppState;
+
+        let state = ctx.data::<AppState>()?;
+        let user_id = uuid::Uuid::parse_str(self.id.as_str()).gql_err("Invalid user ID")?;
+        let rows = infra::repos::auth_identities::list_for_user(&state.db, user_id).await?;
+        Ok(rows.into_iter().map(LinkedProvider::from).collect())
+    }
+
+    /// Whether the account can sign in with a password. The user themself and
+    /// admins.
+    #[graphql(guard = "AccountOwnerGuard::new(self)")]
+    async fn has_password(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
+        use crate::state::AppState;
+
+        let state = ctx.data::<AppState>()?;
+        let user_id = uuid::Uuid::parse_str(self.id.as_str()).gql_err("Invalid user ID")?;
+        Ok(infra::repos::users::has_password(&state.db, user_id).await?)
+    }
+
+    /// Every tournament the player registered for, newest first, each with
+    /// what they paid in, where they finished and the points earned;
+    /// cancelled registrations are left out. `clubId` narrows it to one club.
+    /// The user themself, managers of a club they play at, and admins.
+    #[graphql(guard = "PersonalDataGuard::new(self)")]
+    async fn tournament_history(
+        &self,
+        ctx: &Context<'_>,
+        pagination: Option<PaginationInput>,
+        club_id: Option<ID>,
+    ) -> async_graphql::Result<PaginatedResponse<TournamentHistoryEntry>> {
+        use crate::gql::loaders::TournamentLoader;
+        use crate::state::AppState;
+        use async_graphql::dataloader::DataLoader;
+        use infra::repos::tournament_results;
+
+        let state = ctx.data::<AppState>()?;
+        let user_id = uuid::Uuid::parse_str(self.id.as_str()).gql_err("Invalid user ID")?;
+        let club_id = club_id
+            .map(|id| uuid::Uuid::parse_str(id.as_str()))
+            .transpose()
+            .gql_err("Invalid club ID")?;
+        let page = pagination
+            .unwrap_or(PaginationInput {
+                limit: None,
+                offset: None,
+            })
+            .to_limit_offset();
+
+        let (rows, total_count) = tokio::try_join!(
+            tournament_results::list_user_history(
+                &state.db,
+                user_id,
+                club_id,
+                page.limit,
+                page.offset,
+            ),
+            tournament_results::count_user_history(&state.db, user_id, club_id),
+        )
+        .gql_err("Database operation failed")?;
+
+        let tournament_ids: Vec<uuid::Uuid> = rows.iter().map(|r| r.tournament_id).collect();
+        let tournaments = ctx
+            .data::<DataLoader<TournamentLoader>>()?
+            .load_many(tournament_ids)
+            .await
+            .gql_err("Data loading failed")?;
+        let items: Vec<TournamentHistoryEntry> = rows
+            .into_iter()
+            .filter_map(|row| {
+                let tournament = tournaments.get(&row.tournament_id)?.clone().into();
+                Some(TournamentHistoryEntry::new(row, tournament))
+            })
+            .collect();
+
+        let page_size = items.len() as i32;
+        let offset = page.offset as i32;
+        Ok(PaginatedResponse {
+            items,
+            total_count: total_count as i32,
+            page_size,
+            offset,
+            has_next_page: offset + page_size < total_count as i32,
+        })
+    }
+
+    /// Changes to the account's email and phone, newest first. The user
+    /// themself and admins.
+    #[graphql(guard = "AccountOwnerGuard::new(self)")]
+    async fn audit_log(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default = 50)] limit: i32,
+    ) -> async_graphql::Result<Vec<UserAuditEntry>> {
+        use crate::state::AppState;
+
+        let state = ctx.data::<AppState>()?;
+        let user_id = uuid::Uuid::parse_str(self.id.as_str()).gql_err("Invalid user ID")?;
+        let rows = infra::repos::user_audit_log::list_for_user(
+            &state.db,
+            user_id,
+            limit.clamp(1, 200) as i64,
+        )
+        .await?;
+        Ok(rows.into_iter().map(UserAuditEntry::from).collect())
+    }
 }
 
 // Player management input types
@@ -146,3 +318,98 @@ pub struct PlayerProfile {
     /// The friendship row id when one exists, for accept/cancel actions.
     pub friendship_id: Option<ID>,
 }
+
+/// Which contact detail a self-service change targets.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ContactChannel {
+    Email,
+    Phone,
+}
+
+impl ContactChannel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Email => "email",
+            Self::Phone => "phone",
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct RequestContactChangeInput {
+    pub channel: ContactChannel,
+    pub new_value: String,
+    /// Language of the code emails; defaults to the account's locale.
+    pub locale: Option<String>,
+}
+
+/// A pending email or phone change waiting for its two codes.
+#[derive(SimpleObject)]
+pub struct ContactChangeRequest {
+    pub id: ID,
+    pub channel: ContactChannel,
+    /// Where the code for the current address went: the account's phone
+    /// when it has one and the phone is changing, its email otherwise.
+    pub current_code_sent_to: ContactChannel,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(InputObject)]
+pub struct ConfirmContactChangeInput {
+    pub request_id: ID,
+    /// Code sent to the current address.
+    pub old_code: String,
+    /// Code sent to the new address.
+    pub new_code: String,
+}
+
+/// One change to an account's contact details.
+#[derive(SimpleObject)]
+pub struct UserAuditEntry {
+    pub id: ID,
+    /// `email_changed` or `phone_changed`.
+    pub action: String,
+    /// Who made the change: the user themself, or a manager or admin.
+    pub actor_id: Option<ID>,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<infra::repos::user_audit_log::UserAuditEntryRow> for UserAuditEntry {
+    fn from(row: infra::repos::user_audit_log::UserAuditEntryRow) -> Self {
+        Self {
+            id: row.id.into(),
+            action: row.action,
+            actor_id: row.actor_id.map(Into::into),
+            old_value: row.old_value.map(String::from),
+            new_value: row.new_value.map(String::from),
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// The current user's own edit of their profile. Omitted fields keep their
+/// value; an empty `lastName`, `username` or `avatarUrl` clears it. A new
+/// `email` or `phone` isn't applied directly: it starts the same
+/// confirmation as `requestContactChange`.
+#[derive(InputObject)]
+pub struct UpdateMyProfileInput {
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    /// 3-30 letters, digits, `.`, `_` or `-`; unique across accounts.
+    pub username: Option<String>,
+    /// An `https://` image URL.
+    pub avatar_url: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    /// Language of the confirmation emails; defaults to the account's locale.
+    pub locale: Option<String>,
+}
+
+#[derive(SimpleObject)]
+pub struct UpdateMyProfilePayload {
+    pub user: User,
+    /// Email and phone changes waiting for `confirmContactChange`.
+    pub pending_contact_changes: Vec<ContactChangeRequest>,
+}
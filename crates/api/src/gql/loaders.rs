@@ -129,7 +129,7 @@ impl Loader<Uuid> for UserLoader {
 
             let rows: Vec<UserRow> = sqlx::query_as::<_, UserRow>(
                 r#"
-                SELECT id, email, username, first_name, last_name, phone,
+                SELECT id, email, username, first_name, last_name, phone, avatar_url,
                        is_active, role, locale, created_at, updated_at
                 FROM users
                 WHERE id = ANY($1::uuid[])
@@ -285,7 +285,7 @@ impl Loader<Uuid> for TournamentLoader {
                 SELECT id, club_id, name, description, start_time, end_time,
                        buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips,
                        level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips,
-                       addon_price_cents, late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at
+                       addon_price_cents, late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
                 FROM tournaments
                 WHERE id = ANY($1::uuid[])
                 "#,
@@ -299,3 +299,103 @@ impl Loader<Uuid> for TournamentLoader {
         }
     }
 }
+
+// ManagedClubLoader - batch "does this manager manage this club?" checks,
+// keyed by (manager user id, club id). Backs the field guards on money
+// fields, which run once per object in a list.
+#[derive(Clone)]
+pub struct ManagedClubLoader {
+    pool: Db,
+}
+
+impl ManagedClubLoader {
+    pub fn new(pool: Db) -> Self {
+        Self { pool }
+    }
+}
+
+impl Loader<(Uuid, Uuid)> for ManagedClubLoader {
+    type Value = bool;
+    type Error = Arc<sqlx::Error>;
+
+    fn load(
+        &self,
+        keys: &[(Uuid, Uuid)],
+    ) -> impl Future<Output = std::result::Result<HashMap<(Uuid, Uuid), Self::Value>, Self::Error>> + Send
+    {
+        let pool = self.pool.clone();
+        let (managers, clubs): (Vec<Uuid>, Vec<Uuid>) = keys.iter().copied().unzip();
+
+        async move {
+            if managers.is_empty() {
+                return Ok(HashMap::new());
+            }
+
+            let rows: Vec<(Uuid, Uuid, bool)> = sqlx::query_as(
+                r#"
+                SELECT k.manager_id, k.club_id, is_club_manager(k.manager_id, k.club_id)
+                FROM unnest($1::uuid[], $2::uuid[]) AS k(manager_id, club_id)
+                "#,
+            )
+            .bind(&managers)
+            .bind(&clubs)
+            .fetch_all(&pool)
+            .await
+            .map_err(Arc::new)?;
+
+            Ok(rows.into_iter().map(|(m, c, ok)| ((m, c), ok)).collect())
+        }
+    }
+}
+
+// ManagedPlayerLoader - batch "is this user on the roster of a club this
+// manager manages?" checks, keyed by (manager user id, player user id).
+// Backs the field guards on a user's contact details.
+#[derive(Clone)]
+pub struct ManagedPlayerLoader {
+    pool: Db,
+}
+
+impl ManagedPlayerLoader {
+    pub fn new(pool: Db) -> Self {
+        Self { pool }
+    }
+}
+
+impl Loader<(Uuid, Uuid)> for ManagedPlayerLoader {
+    type Value = bool;
+    type Error = Arc<sqlx::Error>;
+
+    fn load(
+        &self,
+        keys: &[(Uuid, Uuid)],
+    ) -> impl Future<Output = std::result::Result<HashMap<(Uuid, Uuid), Self::Value>, Self::Error>> + Send
+    {
+        let pool = self.pool.clone();
+        let (managers, users): (Vec<Uuid>, Vec<Uuid>) = keys.iter().copied().unzip();
+
+        async move {
+            if managers.is_empty() {
+                return Ok(HashMap::new());
+            }
+
+            let rows: Vec<(Uuid, Uuid, bool)> = sqlx::query_as(
+                r#"
+                SELECT k.manager_id, k.user_id, EXISTS (
+                    SELECT 1 FROM club_player cp
+                    WHERE cp.app_user_id = k.user_id
+                      AND is_club_manager(k.manager_id, cp.club_id)
+                )
+                FROM unnest($1::uuid[], $2::uuid[]) AS k(manager_id, user_id)
+                "#,
+            )
+            .bind(&managers)
+            .bind(&users)
+            .fetch_all(&pool)
+            .await
+            .map_err(Arc::new)?;
+
+            Ok(rows.into_iter().map(|(m, u, ok)| ((m, u), ok)).collect())
+        }
+    }
+}
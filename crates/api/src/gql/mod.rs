@@ -14,5 +14,5 @@ pub mod types;
 
 // Re-exports
 pub use root::{MutationRoot, QueryRoot};
-pub use schema::build_schema;
+pub use schema::{build_schema, build_subgraph_schema, GraphqlConfig};
 pub use subscriptions::SubscriptionRoot;
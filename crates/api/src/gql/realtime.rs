@@ -23,14 +23,15 @@ use std::sync::{LazyLock, OnceLock};
 use serde::{Deserialize, Serialize};
 use sqlx::postgres::PgListener;
 use sqlx::PgPool;
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast, mpsc};
 use tokio::task::JoinHandle;
 use uuid::Uuid;
 
 use crate::gql::subscriptions::dispatch_local;
 use crate::gql::types::{
-    ActivityLogEntry, PlayerRegistrationEvent, SeatingChangeEvent, TournamentClock,
-    UserNotification,
+    ActivityLogEntry, ClubTournamentEvent, PlayerRegistrationEvent, PromotionBoardEvent,
+    SeatingChangeEvent, TournamentChatEvent, TournamentClock, TournamentEntryEvent,
+    TournamentResultEvent, UserNotification,
 };
 
 /// Postgres NOTIFY channel name.
@@ -45,6 +46,11 @@ static INSTANCE_ID: LazyLock<Uuid> = LazyLock::new(Uuid::new_v4);
 /// Set once the notifier task is running; `publish_*` enqueue through it.
 static SENDER: OnceLock<mpsc::UnboundedSender<RealtimeEvent>> = OnceLock::new();
 
+/// Every event dispatched on this instance, local or from another instance,
+/// for integrations outside GraphQL (the MQTT bridge).
+static TAP: LazyLock<broadcast::Sender<RealtimeEvent>> =
+    LazyLock::new(|| broadcast::channel(256).0);
+
 /// A real-time event plus the routing the local dispatcher needs. Routing that
 /// isn't already carried inside the event payload (clock/activity tournament id)
 /// is captured explicitly.
@@ -61,6 +67,17 @@ pub enum RealtimeEvent {
         entry: ActivityLogEntry,
     },
     UserNotification(UserNotification),
+    Chat {
+        tournament_id: Uuid,
+        event: TournamentChatEvent,
+    },
+    Promotion {
+        club_id: Uuid,
+        event: PromotionBoardEvent,
+    },
+    Result(TournamentResultEvent),
+    ClubTournament(ClubTournamentEvent),
+    Entry(TournamentEntryEvent),
 }
 
 #[derive(Serialize, Deserialize)]
@@ -82,6 +99,19 @@ pub(crate) fn queue(event: RealtimeEvent) {
     }
 }
 
+/// Receive every event this instance dispatches, whichever instance it
+/// originated on. Slow receivers lag (and skip) rather than block the bus.
+pub fn tap() -> broadcast::Receiver<RealtimeEvent> {
+    TAP.subscribe()
+}
+
+/// Copy an event to the tap, if anything is listening.
+pub(crate) fn send_to_tap(event: &RealtimeEvent) {
+    if TAP.receiver_count() > 0 {
+        let _ = TAP.send(event.clone());
+    }
+}
+
 /// Drain queued events: dispatch to local subscribers and broadcast to other
 /// instances via pg_notify. Spawned once at startup.
 pub fn spawn_realtime_notifier(db: PgPool) -> JoinHandle<()> {
@@ -1,48 +1,98 @@
 use async_graphql::MergedObject;
 
+use crate::gql::domains::accounting::AccountingMutation;
 use crate::gql::domains::announcements::AnnouncementMutation;
 use crate::gql::domains::attendance::AttendanceMutation;
 use crate::gql::domains::auth::AuthMutation;
+use crate::gql::domains::bankroll::BankrollMutation;
+use crate::gql::domains::buy_in_credits::BuyInCreditMutation;
+use crate::gql::domains::chat::ChatMutation;
 use crate::gql::domains::clubs::ClubMutation;
 use crate::gql::domains::devices::DeviceMutation;
+use crate::gql::domains::diagnostics::DiagnosticsMutation;
+use crate::gql::domains::displays::DisplayMutation;
 use crate::gql::domains::drinks::DrinksMutation;
 use crate::gql::domains::entries::EntryMutation;
+use crate::gql::domains::gallery::GalleryMutation;
+use crate::gql::domains::groups::GroupMutation;
 use crate::gql::domains::identity::IdentityMutation;
+use crate::gql::domains::imports::ImportMutation;
+use crate::gql::domains::incidents::IncidentMutation;
+use crate::gql::domains::invites::InviteMutation;
 use crate::gql::domains::leaderboard_configs::LeaderboardConfigMutation;
+use crate::gql::domains::leaderboards::LeaderboardMutation;
 use crate::gql::domains::notes::NotesMutation;
+use crate::gql::domains::organizations::OrganizationMutation;
+use crate::gql::domains::persisted_operations::PersistedOperationMutation;
 use crate::gql::domains::predictions::PredictionsMutation;
+use crate::gql::domains::printouts::PrintoutMutation;
+use crate::gql::domains::promotions::PromotionMutation;
+use crate::gql::domains::qualifications::QualificationMutation;
+use crate::gql::domains::questions::RegistrationQuestionMutation;
+use crate::gql::domains::raffles::RaffleMutation;
+use crate::gql::domains::recaps::RecapMutation;
 use crate::gql::domains::registrations::RegistrationMutation;
 use crate::gql::domains::results::ResultMutation;
+use crate::gql::domains::retention::RetentionMutation;
+use crate::gql::domains::rules::RuleDocumentMutation;
 use crate::gql::domains::scouting::ScoutingMutation;
 use crate::gql::domains::seasons::SeasonsMutation;
+use crate::gql::domains::seat_changes::SeatChangeMutation;
 use crate::gql::domains::seating::SeatingMutation;
 use crate::gql::domains::series::SeriesMutation;
 use crate::gql::domains::social::SocialMutation;
+use crate::gql::domains::staff::StaffMutation;
 use crate::gql::domains::templates::TemplateMutation;
+use crate::gql::domains::tickets::TicketMutation;
 use crate::gql::domains::tournaments::{TournamentClockMutation, TournamentMutation};
 use crate::gql::domains::users::UserMutation;
 
 #[derive(MergedObject, Default)]
 pub struct MutationRoot(
+    AccountingMutation,
     AnnouncementMutation,
     AttendanceMutation,
     AuthMutation,
+    BankrollMutation,
+    BuyInCreditMutation,
+    ChatMutation,
     ClubMutation,
     DeviceMutation,
+    DiagnosticsMutation,
+    DisplayMutation,
     DrinksMutation,
     EntryMutation,
+    GalleryMutation,
+    GroupMutation,
     IdentityMutation,
+    ImportMutation,
+    IncidentMutation,
+    InviteMutation,
     LeaderboardConfigMutation,
+    LeaderboardMutation,
     NotesMutation,
+    OrganizationMutation,
+    PersistedOperationMutation,
     PredictionsMutation,
+    PrintoutMutation,
+    PromotionMutation,
+    QualificationMutation,
+    RegistrationQuestionMutation,
+    RaffleMutation,
+    RecapMutation,
     RegistrationMutation,
     ResultMutation,
+    RetentionMutation,
+    RuleDocumentMutation,
     ScoutingMutation,
     SeasonsMutation,
+    SeatChangeMutation,
     SeatingMutation,
     SeriesMutation,
     SocialMutation,
+    StaffMutation,
     TemplateMutation,
+    TicketMutation,
     TournamentClockMutation,
     TournamentMutation,
     UserMutation,
@@ -1,54 +1,108 @@
 use async_graphql::MergedObject;
 
+use crate::gql::domains::accounting::AccountingQuery;
 use crate::gql::domains::achievements::AchievementQuery;
 use crate::gql::domains::activity_log::ActivityLogQuery;
 use crate::gql::domains::analytics::AnalyticsQuery;
 use crate::gql::domains::announcements::AnnouncementQuery;
 use crate::gql::domains::attendance::AttendanceQuery;
 use crate::gql::domains::auth::AuthQuery;
+use crate::gql::domains::bankroll::BankrollQuery;
+use crate::gql::domains::buy_in_credits::BuyInCreditQuery;
+use crate::gql::domains::chat::ChatQuery;
 use crate::gql::domains::clubs::ClubQuery;
+use crate::gql::domains::dashboards::DashboardQuery;
+use crate::gql::domains::diagnostics::DiagnosticsQuery;
+use crate::gql::domains::displays::DisplayQuery;
 use crate::gql::domains::drinks::DrinksQuery;
 use crate::gql::domains::entries::EntryQuery;
+use crate::gql::domains::gallery::GalleryQuery;
+use crate::gql::domains::groups::GroupQuery;
 use crate::gql::domains::identity::IdentityQuery;
+use crate::gql::domains::imports::ImportQuery;
+use crate::gql::domains::incidents::IncidentQuery;
+use crate::gql::domains::invites::InviteQuery;
 use crate::gql::domains::leaderboard_configs::LeaderboardConfigQuery;
 use crate::gql::domains::leaderboards::LeaderboardQuery;
 use crate::gql::domains::notes::NotesQuery;
+use crate::gql::domains::organizations::OrganizationQuery;
+use crate::gql::domains::persisted_operations::PersistedOperationQuery;
 use crate::gql::domains::predictions::PredictionsQuery;
+use crate::gql::domains::printouts::PrintoutQuery;
+use crate::gql::domains::promotions::PromotionQuery;
+use crate::gql::domains::qualifications::QualificationQuery;
+use crate::gql::domains::questions::RegistrationQuestionQuery;
+use crate::gql::domains::quotas::QuotaQuery;
+use crate::gql::domains::raffles::RaffleQuery;
+use crate::gql::domains::recaps::RecapQuery;
 use crate::gql::domains::registrations::RegistrationQuery;
 use crate::gql::domains::results::ResultQuery;
+use crate::gql::domains::retention::RetentionQuery;
+use crate::gql::domains::rules::RuleDocumentQuery;
 use crate::gql::domains::scouting::ScoutingQuery;
 use crate::gql::domains::seasons::SeasonsQuery;
+use crate::gql::domains::seat_changes::SeatChangeQuery;
 use crate::gql::domains::seating::SeatingQuery;
 use crate::gql::domains::series::SeriesQuery;
 use crate::gql::domains::social::SocialQuery;
+use crate::gql::domains::staff::StaffQuery;
+use crate::gql::domains::sync::SyncQuery;
 use crate::gql::domains::templates::TemplateQuery;
+use crate::gql::domains::tickets::TicketQuery;
 use crate::gql::domains::tournaments::{TournamentClockQuery, TournamentQuery};
 use crate::gql::domains::users::UserQuery;
 
 #[derive(MergedObject, Default)]
 pub struct QueryRoot(
+    AccountingQuery,
     AchievementQuery,
     ActivityLogQuery,
     AnalyticsQuery,
     AnnouncementQuery,
     AttendanceQuery,
     AuthQuery,
+    BankrollQuery,
+    BuyInCreditQuery,
+    ChatQuery,
     ClubQuery,
+    DashboardQuery,
+    DiagnosticsQuery,
+    DisplayQuery,
     DrinksQuery,
     EntryQuery,
+    GalleryQuery,
+    GroupQuery,
     IdentityQuery,
+    ImportQuery,
+    IncidentQuery,
+    InviteQuery,
     LeaderboardConfigQuery,
     LeaderboardQuery,
     NotesQuery,
+    OrganizationQuery,
+    PersistedOperationQuery,
     PredictionsQuery,
+    PrintoutQuery,
+    PromotionQuery,
+    QualificationQuery,
+    QuotaQuery,
+    RegistrationQuestionQuery,
+    RaffleQuery,
+    RecapQuery,
     RegistrationQuery,
     ResultQuery,
+    RetentionQuery,
+    RuleDocumentQuery,
     ScoutingQuery,
     SeasonsQuery,
+    SeatChangeQuery,
     SeatingQuery,
     SeriesQuery,
     SocialQuery,
+    StaffQuery,
+    SyncQuery,
     TemplateQuery,
+    TicketQuery,
     TournamentClockQuery,
     TournamentQuery,
     UserQuery,
@@ -1,10 +1,89 @@
 use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
 use std::fmt;
 
-/// Very simple Money scalar represented as integer cents (e.g., 1299 == €12.99).
-#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+/// Money scalar represented as integer cents (e.g., 1299 == €12.99).
+///
+/// Backed by `i64` (the money columns are BIGINT), so large prize pools and
+/// multi-flight aggregates cannot overflow. Arithmetic on amounts should go
+/// through the `checked_*` helpers, which return `None` instead of wrapping.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Default,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(transparent)]
 pub struct Money(pub i64);
 
+impl Money {
+    pub const ZERO: Money = Money(0);
+
+    /// The amount in integer cents.
+    pub fn cents(self) -> i64 {
+        self.0
+    }
+
+    pub fn checked_add(self, other: Money) -> Option<Money> {
+        self.0.checked_add(other.0).map(Money)
+    }
+
+    pub fn checked_sub(self, other: Money) -> Option<Money> {
+        self.0.checked_sub(other.0).map(Money)
+    }
+
+    pub fn checked_mul(self, factor: i64) -> Option<Money> {
+        self.0.checked_mul(factor).map(Money)
+    }
+
+    /// The amount for people to read: euros with thousands grouped and the
+    /// sign in front, e.g. `€1,234.50` or `-€0.05`.
+    pub fn euros(self) -> String {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let cents = self.0.unsigned_abs();
+        let euros = (cents / 100).to_string();
+        let mut grouped = String::with_capacity(euros.len() + euros.len() / 3);
+        for (i, digit) in euros.chars().enumerate() {
+            if i > 0 && (euros.len() - i).is_multiple_of(3) {
+                grouped.push(',');
+            }
+            grouped.push(digit);
+        }
+        format!("{sign}€{grouped}.{:02}", cents % 100)
+    }
+
+    /// Sum a sequence of amounts, returning `None` on overflow.
+    pub fn checked_sum<I: IntoIterator<Item = Money>>(amounts: I) -> Option<Money> {
+        amounts
+            .into_iter()
+            .try_fold(Money::ZERO, |acc, m| acc.checked_add(m))
+    }
+}
+
+impl From<i64> for Money {
+    fn from(cents: i64) -> Self {
+        Money(cents)
+    }
+}
+
+impl From<i32> for Money {
+    fn from(cents: i32) -> Self {
+        Money(cents as i64)
+    }
+}
+
+impl From<Money> for i64 {
+    fn from(m: Money) -> Self {
+        m.0
+    }
+}
+
 impl fmt::Display for Money {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let euros = self.0 as f64 / 100.0;
@@ -33,3 +112,38 @@ impl ScalarType for Money {
         Value::Number(self.0.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_euros() {
+        assert_eq!(Money(0).euros(), "€0.00");
+        assert_eq!(Money(1_299).euros(), "€12.99");
+        assert_eq!(Money(123_450).euros(), "€1,234.50");
+        assert_eq!(Money(100_000_000).euros(), "€1,000,000.00");
+        assert_eq!(Money(-5).euros(), "-€0.05");
+        assert_eq!(Money(i64::MIN).euros(), "-€92,233,720,368,547,758.08");
+    }
+
+    #[test]
+    fn checked_arithmetic_reports_overflow() {
+        assert_eq!(Money(i64::MAX).checked_add(Money(1)), None);
+        assert_eq!(Money(i64::MAX / 2 + 1).checked_mul(2), None);
+        assert_eq!(Money(20_000).checked_mul(1_200), Some(Money(24_000_000)));
+        assert_eq!(
+            Money::checked_sum([Money(100), Money(250), Money(-50)]),
+            Some(Money(300))
+        );
+    }
+
+    #[test]
+    fn amounts_past_i32_round_trip() {
+        // Past i32::MAX cents (~€21.5M), e.g. a season-long series aggregate.
+        let big = Money(3_000_000_000);
+        let parsed = Money::parse(big.to_value()).expect("parses");
+        assert_eq!(parsed, big);
+        assert_eq!(big.to_string(), "30000000.00");
+    }
+}
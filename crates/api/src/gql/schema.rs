@@ -1,17 +1,72 @@
-use std::env;
+use std::collections::HashSet;
+use std::sync::Arc;
 
 use async_graphql::dataloader::DataLoader;
-use async_graphql::Schema;
+use async_graphql::extensions::{Extension, ExtensionContext, ExtensionFactory, NextParseQuery};
+use async_graphql::parser::types::{ExecutableDocument, Selection, SelectionSet};
+use async_graphql::{Schema, SchemaBuilder, ServerError, ServerResult, Variables};
 
+use super::domains::persisted_operations::allowlist::OperationAllowList;
 use super::loaders::{
-    ClubLoader, ClubPlayerLoader, DrinkLedgerLoader, DrinkWalletLoader, TournamentLoader,
-    UserLoader,
+    ClubLoader, ClubPlayerLoader, DrinkLedgerLoader, DrinkWalletLoader, ManagedClubLoader,
+    ManagedPlayerLoader, TournamentLoader, UserLoader,
 };
 use super::{MutationRoot, QueryRoot, SubscriptionRoot};
+use crate::config::Env;
+use crate::middleware::quota::QuotaExtension;
+use crate::middleware::row_scope::RowScopeExtension;
 use crate::state::AppState;
 
+/// Schema features and query guards.
+#[derive(Clone, Debug)]
+pub struct GraphqlConfig {
+    /// `GQL_FEDERATION`: serve as an Apollo Federation subgraph.
+    pub federation: bool,
+    /// `GQL_INTROSPECTION`: OFF by default (safe for production); turn it on
+    /// locally to explore the schema in a playground.
+    pub introspection: bool,
+    /// Defaults suit introspection queries.
+    pub depth_limit: usize,
+    pub complexity_limit: usize,
+    /// `GQL_OPERATION_ALLOWLIST`: locked mode, where only each client's
+    /// registered operations run.
+    pub operation_allowlist: bool,
+}
+
+impl GraphqlConfig {
+    pub fn load(env: &mut Env) -> Self {
+        Self {
+            federation: env.flag("GQL_FEDERATION", false),
+            introspection: env.flag("GQL_INTROSPECTION", false),
+            depth_limit: env.parse_or("GQL_QUERY_DEPTH_LIMIT", 30),
+            complexity_limit: env.parse_or("GQL_QUERY_COMPLEXITY_LIMIT", 2000),
+            operation_allowlist: env.flag("GQL_OPERATION_ALLOWLIST", false),
+        }
+    }
+}
+
 /// Build the GraphQL schema and inject shared state (AppState) into the context.
+///
+/// Set GQL_FEDERATION=true to serve it as an Apollo Federation subgraph
+/// (`_service` / `_entities`), e.g. behind the operator's supergraph router.
 pub fn build_schema(state: AppState) -> Schema<QueryRoot, MutationRoot, SubscriptionRoot> {
+    let federation_enabled = state.config().graphql.federation;
+
+    let builder = schema_builder(state);
+    if federation_enabled {
+        builder.enable_federation().finish()
+    } else {
+        builder.extension(NoFederationFields).finish()
+    }
+}
+
+/// The schema as an Apollo Federation subgraph, whatever GQL_FEDERATION says.
+/// Entity keys: `User`, `Tournament` and `Club`, each keyed on `id`.
+pub fn build_subgraph_schema(state: AppState) -> Schema<QueryRoot, MutationRoot, SubscriptionRoot> {
+    schema_builder(state).enable_federation().finish()
+}
+
+fn schema_builder(state: AppState) -> SchemaBuilder<QueryRoot, MutationRoot, SubscriptionRoot> {
     let club_loader = DataLoader::new(ClubLoader::new(state.db.clone()), tokio::spawn);
     let user_loader = DataLoader::new(UserLoader::new(state.db.clone()), tokio::spawn);
     let tournament_loader = DataLoader::new(TournamentLoader::new(state.db.clone()), tokio::spawn);
@@ -20,24 +75,12 @@ pub fn build_schema(state: AppState) -> Schema<QueryRoot, MutationRoot, Subscrip
         DataLoader::new(DrinkWalletLoader::new(state.db.clone()), tokio::spawn);
     let drink_ledger_loader =
         DataLoader::new(DrinkLedgerLoader::new(state.db.clone()), tokio::spawn);
+    let managed_club_loader =
+        DataLoader::new(ManagedClubLoader::new(state.db.clone()), tokio::spawn);
+    let managed_player_loader =
+        DataLoader::new(ManagedPlayerLoader::new(state.db.clone()), tokio::spawn);
 
-    // Introspection is OFF by default (safe for production); set
-    // GQL_INTROSPECTION=true locally to explore the schema in a playground.
-    let introspection_enabled = env::var("GQL_INTROSPECTION")
-        .map(|v| v == "true")
-        .unwrap_or(false);
-
-    // Query depth limit (default: 30, suitable for introspection)
-    let depth_limit = env::var("GQL_QUERY_DEPTH_LIMIT")
-        .ok()
-        .and_then(|v| v.parse::<usize>().ok())
-        .unwrap_or(30);
-
-    // Query complexity limit (default: 2000, suitable for introspection)
-    let complexity_limit = env::var("GQL_QUERY_COMPLEXITY_LIMIT")
-        .ok()
-        .and_then(|v| v.parse::<usize>().ok())
-        .unwrap_or(2000);
+    let config = state.config().graphql.clone();
 
     let mut builder = Schema::build(
         QueryRoot::default(),
@@ -51,12 +94,84 @@ pub fn build_schema(state: AppState) -> Schema<QueryRoot, MutationRoot, Subscrip
     .data(club_player_loader)
     .data(drink_wallet_loader)
     .data(drink_ledger_loader)
-    .limit_depth(depth_limit)
-    .limit_complexity(complexity_limit);
+    .data(managed_club_loader)
+    .data(managed_player_loader)
+    .limit_depth(config.depth_limit)
+    .limit_complexity(config.complexity_limit)
+    .extension(QuotaExtension)
+    .extension(RowScopeExtension);
 
-    if !introspection_enabled {
+    if !config.introspection {
         builder = builder.disable_introspection();
     }
 
-    builder.finish()
+    // Locked mode for production: only each client's registered operations
+    // run. Leave it off in staging to keep the playground usable.
+    if config.operation_allowlist {
+        builder = builder.extension(OperationAllowList);
+    }
+
+    builder
+}
+
+/// async-graphql serves `_service` and `_entities` as soon as any entity
+/// resolver exists, and `_service` ignores `disable_introspection`. Without
+/// GQL_FEDERATION the schema must not hand out its SDL, so reject any
+/// operation that selects either field.
+struct NoFederationFields;
+
+impl ExtensionFactory for NoFederationFields {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(NoFederationFields)
+    }
+}
+
+#[async_graphql::async_trait::async_trait]
+impl Extension for NoFederationFields {
+    async fn parse_query(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        query: &str,
+        variables: &Variables,
+        next: NextParseQuery<'_>,
+    ) -> ServerResult<ExecutableDocument> {
+        let document = next.run(ctx, query, variables).await?;
+        for (_, operation) in document.operations.iter() {
+            let mut visited = HashSet::new();
+            if selects_federation_field(&document, &operation.node.selection_set.node, &mut visited)
+            {
+                return Err(ServerError::new(
+                    "Federation is not enabled on this server",
+                    Some(operation.pos),
+                ));
+            }
+        }
+        Ok(document)
+    }
+}
+
+/// Whether a root selection set reaches `_service` / `_entities`, directly or
+/// through fragments.
+fn selects_federation_field<'a>(
+    document: &'a ExecutableDocument,
+    selection_set: &'a SelectionSet,
+    visited: &mut HashSet<&'a str>,
+) -> bool {
+    selection_set.items.iter().any(|item| match &item.node {
+        Selection::Field(field) => {
+            matches!(field.node.name.node.as_str(), "_service" | "_entities")
+        }
+        Selection::InlineFragment(fragment) => {
+            selects_federation_field(document, &fragment.node.selection_set.node, visited)
+        }
+        Selection::FragmentSpread(spread) => {
+            let name = spread.node.fragment_name.node.as_str();
+            if !visited.insert(name) {
+                return false;
+            }
+            document.fragments.get(name).is_some_and(|fragment| {
+                selects_federation_field(document, &fragment.node.selection_set.node, visited)
+            })
+        }
+    })
 }
@@ -9,11 +9,15 @@ use tokio_stream::wrappers::{errors::BroadcastStreamRecvError, BroadcastStream};
 use uuid::Uuid;
 
 use crate::auth::jwt::Claims;
+use crate::auth::permissions::{
+    require_viewer_or_display, require_viewer_or_display_for_tournament,
+};
 use crate::gql::error::{auth_error, ResultExt};
 use crate::gql::realtime::RealtimeEvent;
 use crate::gql::types::{
-    ActivityLogEntry, PlayerRegistrationEvent, SeatingChangeEvent, TournamentClock,
-    UserNotification,
+    ActivityLogEntry, ClubTournamentEvent, PlayerRegistrationEvent, PromotionBoardEvent,
+    SeatingChangeEvent, TournamentChatEvent, TournamentClock, TournamentEntryEvent,
+    TournamentResultEvent, UserNotification,
 };
 
 /// Per-tournament channels for real-time updates
@@ -22,6 +26,9 @@ struct TournamentChannels {
     seating: broadcast::Sender<SeatingChangeEvent>,
     clock: broadcast::Sender<TournamentClock>,
     activity: broadcast::Sender<ActivityLogEntry>,
+    chat: broadcast::Sender<TournamentChatEvent>,
+    results: broadcast::Sender<TournamentResultEvent>,
+    entries: broadcast::Sender<TournamentEntryEvent>,
     last_activity: DateTime<Utc>,
 }
 
@@ -32,6 +39,9 @@ impl TournamentChannels {
             seating: broadcast::channel(100).0,
             clock: broadcast::channel(100).0,
             activity: broadcast::channel(100).0,
+            chat: broadcast::channel(100).0,
+            results: broadcast::channel(100).0,
+            entries: broadcast::channel(100).0,
             last_activity: Utc::now(),
         }
     }
@@ -68,6 +78,10 @@ struct SubscriptionChannels {
     users: HashMap<Uuid, ActivityTrackedSender<UserNotification>>,
     /// Per-club seating channels (for managers watching all club tournaments)
     clubs: HashMap<Uuid, ActivityTrackedSender<SeatingChangeEvent>>,
+    /// Per-club promotions board channels (jackpot TV displays)
+    promotions: HashMap<Uuid, ActivityTrackedSender<PromotionBoardEvent>>,
+    /// Per-club tournament schedule channels (lobby screens)
+    schedules: HashMap<Uuid, ActivityTrackedSender<ClubTournamentEvent>>,
 }
 
 impl SubscriptionChannels {
@@ -76,6 +90,8 @@ impl SubscriptionChannels {
             tournaments: HashMap::new(),
             users: HashMap::new(),
             clubs: HashMap::new(),
+            promotions: HashMap::new(),
+            schedules: HashMap::new(),
         }
     }
 
@@ -110,6 +126,27 @@ impl SubscriptionChannels {
         &tracked.sender
     }
 
+    fn get_or_create_promotions(
+        &mut self,
+        club_id: Uuid,
+    ) -> &broadcast::Sender<PromotionBoardEvent> {
+        let tracked = self
+            .promotions
+            .entry(club_id)
+            .or_insert_with(|| ActivityTrackedSender::new(100));
+        tracked.update_activity();
+        &tracked.sender
+    }
+
+    fn get_or_create_schedule(&mut self, club_id: Uuid) -> &broadcast::Sender<ClubTournamentEvent> {
+        let tracked = self
+            .schedules
+            .entry(club_id)
+            .or_insert_with(|| ActivityTrackedSender::new(100));
+        tracked.update_activity();
+        &tracked.sender
+    }
+
     /// Remove inactive channels (no activity for more than the specified duration)
     fn cleanup_inactive_channels(&mut self, inactive_duration_hours: i64) {
         let cutoff_time = Utc::now() - chrono::Duration::hours(inactive_duration_hours);
@@ -132,12 +169,31 @@ impl SubscriptionChannels {
             .retain(|_, tracked| tracked.last_activity > cutoff_time);
         let removed_clubs = initial_club_count - self.clubs.len();
 
-        if removed_tournaments > 0 || removed_users > 0 || removed_clubs > 0 {
+        // Clean up promotions board channels
+        let initial_promotions_count = self.promotions.len();
+        self.promotions
+            .retain(|_, tracked| tracked.last_activity > cutoff_time);
+        let removed_promotions = initial_promotions_count - self.promotions.len();
+
+        // Clean up lobby schedule channels
+        let initial_schedules_count = self.schedules.len();
+        self.schedules
+            .retain(|_, tracked| tracked.last_activity > cutoff_time);
+        let removed_schedules = initial_schedules_count - self.schedules.len();
+
+        if removed_tournaments > 0
+            || removed_users > 0
+            || removed_clubs > 0
+            || removed_promotions > 0
+            || removed_schedules > 0
+        {
             tracing::info!(
-                "Cleaned up inactive channels: {} tournaments, {} users, {} clubs",
+                "Cleaned up inactive channels: {} tournaments, {} users, {} clubs, {} promotion boards, {} schedules",
                 removed_tournaments,
                 removed_users,
-                removed_clubs
+                removed_clubs,
+                removed_promotions,
+                removed_schedules
             );
         }
     }
@@ -151,14 +207,15 @@ pub struct SubscriptionRoot;
 #[Subscription]
 impl SubscriptionRoot {
     /// Subscribe to tournament clock updates for a specific tournament
+    /// (signed-in users and the club's paired displays)
     async fn tournament_clock_updates(
         &self,
         ctx: &Context<'_>,
         tournament_id: async_graphql::ID,
     ) -> Result<impl Stream<Item = Result<TournamentClock, BroadcastStreamRecvError>>> {
-        let _claims = ctx.data::<Claims>().map_err(|_| auth_error())?;
         let tournament_uuid =
             Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        require_viewer_or_display_for_tournament(ctx, tournament_uuid).await?;
 
         let receiver = {
             let mut channels = CHANNELS.lock();
@@ -170,14 +227,15 @@ impl SubscriptionRoot {
     }
 
     /// Subscribe to player registration events for a specific tournament
+    /// (signed-in users and the club's paired displays)
     async fn tournament_registrations(
         &self,
         ctx: &Context<'_>,
         tournament_id: async_graphql::ID,
     ) -> Result<impl Stream<Item = Result<PlayerRegistrationEvent, BroadcastStreamRecvError>>> {
-        let _claims = ctx.data::<Claims>().map_err(|_| auth_error())?;
         let tournament_uuid =
             Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        require_viewer_or_display_for_tournament(ctx, tournament_uuid).await?;
 
         let receiver = {
             let mut channels = CHANNELS.lock();
@@ -188,15 +246,16 @@ impl SubscriptionRoot {
         Ok(BroadcastStream::new(receiver))
     }
 
-    /// Subscribe to seating changes for a specific tournament
+    /// Subscribe to seating changes for a specific tournament (signed-in
+    /// users and the club's paired displays)
     async fn tournament_seating_changes(
         &self,
         ctx: &Context<'_>,
         tournament_id: async_graphql::ID,
     ) -> Result<impl Stream<Item = Result<SeatingChangeEvent, BroadcastStreamRecvError>>> {
-        let _claims = ctx.data::<Claims>().map_err(|_| auth_error())?;
         let tournament_uuid =
             Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        require_viewer_or_display_for_tournament(ctx, tournament_uuid).await?;
 
         let receiver = {
             let mut channels = CHANNELS.lock();
@@ -207,6 +266,48 @@ impl SubscriptionRoot {
         Ok(BroadcastStream::new(receiver))
     }
 
+    /// Subscribe to a tournament's results as they are entered, one event
+    /// per finishing place with the winner last (signed-in users and the
+    /// club's paired displays)
+    async fn tournament_results_updates(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: async_graphql::ID,
+    ) -> Result<impl Stream<Item = Result<TournamentResultEvent, BroadcastStreamRecvError>>> {
+        let tournament_uuid =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        require_viewer_or_display_for_tournament(ctx, tournament_uuid).await?;
+
+        let receiver = {
+            let mut channels = CHANNELS.lock();
+            let tournament = channels.get_or_create_tournament(tournament_uuid);
+            tournament.results.subscribe()
+        };
+
+        Ok(BroadcastStream::new(receiver))
+    }
+
+    /// Subscribe to a tournament's entries as the desk records or removes
+    /// them, with running totals (signed-in users and the club's paired
+    /// displays)
+    async fn tournament_entries_updates(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: async_graphql::ID,
+    ) -> Result<impl Stream<Item = Result<TournamentEntryEvent, BroadcastStreamRecvError>>> {
+        let tournament_uuid =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        require_viewer_or_display_for_tournament(ctx, tournament_uuid).await?;
+
+        let receiver = {
+            let mut channels = CHANNELS.lock();
+            let tournament = channels.get_or_create_tournament(tournament_uuid);
+            tournament.entries.subscribe()
+        };
+
+        Ok(BroadcastStream::new(receiver))
+    }
+
     /// Subscribe to seating changes for all tournaments in a club (managers only)
     async fn club_seating_changes(
         &self,
@@ -229,6 +330,25 @@ impl SubscriptionRoot {
         Ok(BroadcastStream::new(receiver))
     }
 
+    /// Subscribe to a club's tournament schedule: tournaments created,
+    /// rescheduled, changing live status or filling up (signed-in users and
+    /// the club's paired displays)
+    async fn club_tournaments_updates(
+        &self,
+        ctx: &Context<'_>,
+        club_id: async_graphql::ID,
+    ) -> Result<impl Stream<Item = Result<ClubTournamentEvent, BroadcastStreamRecvError>>> {
+        let club_uuid = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_viewer_or_display(ctx, club_uuid)?;
+
+        let receiver = {
+            let mut channels = CHANNELS.lock();
+            channels.get_or_create_schedule(club_uuid).subscribe()
+        };
+
+        Ok(BroadcastStream::new(receiver))
+    }
+
     /// Subscribe to user-specific notifications (requires authentication)
     async fn user_notifications(
         &self,
@@ -264,6 +384,48 @@ impl SubscriptionRoot {
 
         Ok(BroadcastStream::new(receiver))
     }
+
+    /// Subscribe to a tournament's chat (registered players, club staff)
+    async fn tournament_chat(
+        &self,
+        ctx: &Context<'_>,
+        tournament_id: async_graphql::ID,
+    ) -> Result<impl Stream<Item = Result<TournamentChatEvent, BroadcastStreamRecvError>>> {
+        let tournament_uuid =
+            Uuid::parse_str(tournament_id.as_str()).gql_err("Invalid tournament ID")?;
+        crate::gql::domains::chat::require_participant(ctx, tournament_uuid).await?;
+
+        let receiver = {
+            let mut channels = CHANNELS.lock();
+            let tournament = channels.get_or_create_tournament(tournament_uuid);
+            tournament.chat.subscribe()
+        };
+
+        Ok(BroadcastStream::new(receiver))
+    }
+
+    /// Subscribe to a club's promotions board: jackpot balances and
+    /// qualifying hands as they change
+    async fn promotion_board_updates(
+        &self,
+        ctx: &Context<'_>,
+        club_id: async_graphql::ID,
+    ) -> Result<impl Stream<Item = Result<PromotionBoardEvent, BroadcastStreamRecvError>>> {
+        let club_uuid = Uuid::parse_str(club_id.as_str()).gql_err("Invalid club ID")?;
+        require_viewer_or_display(ctx, club_uuid)?;
+        if crate::gql::domains::promotions::board_hidden_from_viewer(ctx, club_uuid).await? {
+            return Err(async_graphql::Error::new(
+                "This club isn't available in the app",
+            ));
+        }
+
+        let receiver = {
+            let mut channels = CHANNELS.lock();
+            channels.get_or_create_promotions(club_uuid).subscribe()
+        };
+
+        Ok(BroadcastStream::new(receiver))
+    }
 }
 
 // ============================================================================
@@ -306,6 +468,48 @@ pub fn publish_activity_event(tournament_id: Uuid, entry: ActivityLogEntry) {
     });
 }
 
+/// Publish a chat message (or its deletion) to a tournament's chat channel
+pub fn publish_chat_event(tournament_id: Uuid, event: TournamentChatEvent) {
+    crate::gql::realtime::queue(RealtimeEvent::Chat {
+        tournament_id,
+        event,
+    });
+}
+
+/// Publish a change to a club's promotions board
+pub fn publish_promotion_event(club_id: Uuid, event: PromotionBoardEvent) {
+    crate::gql::realtime::queue(RealtimeEvent::Promotion { club_id, event });
+}
+
+/// Publish a finishing place to a tournament's results channel.
+/// `enterTournamentResults` sends one per place, winner last.
+pub fn publish_result_event(event: TournamentResultEvent) {
+    crate::gql::realtime::queue(RealtimeEvent::Result(event));
+}
+
+/// Publish an entry the desk added or removed to a tournament's entries
+/// channel, with the running totals and no payment details.
+pub fn publish_entry_event(event: TournamentEntryEvent) {
+    crate::gql::realtime::queue(RealtimeEvent::Entry(event));
+}
+
+/// Publish a schedule change to a club's lobby channel. Go through
+/// `tournaments::lobby::publish_schedule_change` / `publish_if_full`, which
+/// skip unlisted tournaments, rather than building the event.
+pub fn publish_club_tournament_event(event: ClubTournamentEvent) {
+    crate::gql::realtime::queue(RealtimeEvent::ClubTournament(event));
+}
+
+/// Receive a tournament's clock updates outside GraphQL (the display gRPC API).
+/// The channel closes when the tournament finishes.
+pub fn subscribe_clock_updates(tournament_id: Uuid) -> broadcast::Receiver<TournamentClock> {
+    let mut channels = CHANNELS.lock();
+    channels
+        .get_or_create_tournament(tournament_id)
+        .clock
+        .subscribe()
+}
+
 /// Cleanup tournament channels when a tournament finishes
 pub fn cleanup_tournament_channels(tournament_id: Uuid) {
     let mut channels = CHANNELS.lock();
@@ -327,6 +531,7 @@ pub fn cleanup_inactive_channels(inactive_duration_hours: i64) {
 
 /// Fan a real-time event into this instance's broadcast channels.
 pub(crate) fn dispatch_local(event: RealtimeEvent) {
+    crate::gql::realtime::send_to_tap(&event);
     match event {
         RealtimeEvent::Registration(event) => {
             let Ok(tournament_id) = Uuid::parse_str(event.tournament_id.as_str()) else {
@@ -365,6 +570,41 @@ pub(crate) fn dispatch_local(event: RealtimeEvent) {
             let tournament = channels.get_or_create_tournament(tournament_id);
             let _ = tournament.activity.send(entry);
         }
+        RealtimeEvent::Chat {
+            tournament_id,
+            event,
+        } => {
+            let mut channels = CHANNELS.lock();
+            let tournament = channels.get_or_create_tournament(tournament_id);
+            let _ = tournament.chat.send(event);
+        }
+        RealtimeEvent::Result(event) => {
+            let Ok(tournament_id) = Uuid::parse_str(event.tournament_id.as_str()) else {
+                return;
+            };
+            let mut channels = CHANNELS.lock();
+            let tournament = channels.get_or_create_tournament(tournament_id);
+            let _ = tournament.results.send(event);
+        }
+        RealtimeEvent::Entry(event) => {
+            let Ok(tournament_id) = Uuid::parse_str(event.tournament_id.as_str()) else {
+                return;
+            };
+            let mut channels = CHANNELS.lock();
+            let tournament = channels.get_or_create_tournament(tournament_id);
+            let _ = tournament.entries.send(event);
+        }
+        RealtimeEvent::Promotion { club_id, event } => {
+            let mut channels = CHANNELS.lock();
+            let _ = channels.get_or_create_promotions(club_id).send(event);
+        }
+        RealtimeEvent::ClubTournament(event) => {
+            let Ok(club_id) = Uuid::parse_str(event.club_id.as_str()) else {
+                return;
+            };
+            let mut channels = CHANNELS.lock();
+            let _ = channels.get_or_create_schedule(club_id).send(event);
+        }
         RealtimeEvent::UserNotification(notification) => {
             let Ok(user_id) = Uuid::parse_str(notification.user_id.as_str()) else {
                 return;
@@ -4,17 +4,36 @@
 // Common types (Role, notifications, pagination)
 pub use crate::gql::common::types::{
     NotificationType, PaginatedResponse, PaginationInput, Role, UserNotification,
-    TITLE_PLAYER_ELIMINATED, TITLE_PLAYER_MOVED, TITLE_QUALIFIED_FOR_DAY_2,
-    TITLE_REGISTRATION_CONFIRMED, TITLE_SEAT_ASSIGNED, TITLE_TOURNAMENT_STARTING, TITLE_WAITLISTED,
-    TITLE_WAITLIST_PROMOTED,
+    TITLE_FRIEND_BUSTED, TITLE_FRIEND_FINAL_TABLE, TITLE_FRIEND_WON, TITLE_PAYOUTS_FINALIZED,
+    TITLE_PLAYER_ELIMINATED, TITLE_PLAYER_MOVED, TITLE_QUALIFIED_FOR_DAY_2, TITLE_RAFFLE_WON,
+    TITLE_REGISTRATION_CONFIRMED, TITLE_SEAT_ASSIGNED, TITLE_SEAT_CHANGE_APPROVED,
+    TITLE_SEAT_CHANGE_DECLINED, TITLE_SEAT_CHANGE_REQUESTED, TITLE_TOURNAMENT_APPROVED,
+    TITLE_TOURNAMENT_CANCELLED, TITLE_TOURNAMENT_REJECTED, TITLE_TOURNAMENT_STARTING,
+    TITLE_WAITLISTED, TITLE_WAITLIST_PROMOTED,
+};
+
+// Accounting export types
+pub use crate::gql::domains::accounting::types::{
+    AccountingExport, AccountingLayout, AccountingSettings, ClubRakeReport,
+    CreateAccountingExportInput, RakePeriod, RakeReportPeriod, RakeReportTournament,
+    UpdateAccountingSettingsInput,
 };
 
 // Activity log types
-pub use crate::gql::domains::activity_log::types::{ActivityEventCategory, ActivityLogEntry};
+pub use crate::gql::domains::activity_log::types::{
+    ActivityEventCategory, ActivityLogEntry, TimelineEvent, TimelineEventKind, TournamentTimeline,
+};
 
 // Announcement types
 pub use crate::gql::domains::announcements::types::{
-    Announcement, AnnouncementScope, CreateAnnouncementInput,
+    Announcement, AnnouncementPriority, AnnouncementScope, BroadcastChannel,
+    CreateAnnouncementInput,
+};
+
+// Tournament chat types
+pub use crate::gql::domains::chat::types::{
+    MuteTournamentChatUserInput, PostTournamentChatMessageInput, TournamentChatEvent,
+    TournamentChatEventType, TournamentChatMessage, TournamentChatMute,
 };
 
 // Club types
@@ -23,11 +42,62 @@ pub use crate::gql::domains::clubs::types::{
     OnboardClubPayload, RedemptionCode,
 };
 
+// Dashboard types
+pub use crate::gql::domains::dashboards::types::{
+    CallQueue, ClubDashboard, ClubDashboardTournament, OpenTable, PendingCall, RegistrationFunnel,
+    TournamentOpsDashboard,
+};
+
+// Gallery types
+pub use crate::gql::domains::gallery::types::{
+    AddTournamentPhotoInput, PhotoTag, PhotoTagInput, TournamentPhoto, UpdateTournamentPhotoInput,
+};
+
+// Incident types
+pub use crate::gql::domains::incidents::types::{
+    FileIncidentInput, Incident, IncidentAttachment, IncidentAttachmentInput, IncidentCategory,
+    IncidentOutcome, IncidentSeverity, IncidentStatus, PlayerExclusion, ReviewIncidentInput,
+    UpdateIncidentFollowUpInput,
+};
+
 // Identity / roster types
 pub use crate::gql::domains::identity::types::{
-    ClaimClubPlayerInput, ClubPlayer, CreateClubPlayerInput,
+    ClaimClubPlayerInput, ClubNameSettings, ClubPlayer, CreateClubPlayerInput, NameSortLocale,
+    PlayerNameOrder, UpdateClubNameSettingsInput,
+};
+
+// Results import types
+pub use crate::gql::domains::imports::types::{
+    ImportPlayerMatch, ImportTournamentResultsInput, ImportTournamentResultsResponse,
+    PreviewResultsImportInput, ResultsImportFormat, ResultsImportPreview, ResultsImportRow,
 };
 
+// Organization types
+pub use crate::gql::domains::organizations::types::{
+    ClubFinancials, CreateOrganizationClubInput, CreateOrganizationInput, MoveClubManagerInput,
+    Organization, OrganizationAdmin, OrganizationClubStats, OrganizationFinancialReport,
+    OrganizationLeaderboardEntry, OrganizationLoyalty, OrganizationPlayerProfile,
+    TournamentApproval, TournamentApprovalStatus,
+};
+
+// Persisted operation types
+pub use crate::gql::domains::persisted_operations::types::{
+    PersistedOperation, PersistedOperationInput, RegisterPersistedOperationsInput,
+};
+
+// Config diagnostics types
+pub use crate::gql::domains::diagnostics::types::{
+    BackgroundServiceStatus, ConfigDiagnostics, ConfigSetting, ConfigSource, MaintenanceReport,
+    MaintenanceTask, MigrationInfo, MigrationState, MigrationStatus, PiiEncryptionStatus,
+    PiiKeyUsage, PoolStats, ServiceStatus, TableMaintenance,
+};
+
+// API quota types
+pub use crate::gql::domains::quotas::types::{ApiUsage, PrincipalUsage};
+
+// Offline sync types
+pub use crate::gql::domains::sync::types::{SyncEntity, SyncItemInput, SyncRecord, SyncStatus};
+
 // Notes types
 pub use crate::gql::domains::notes::types::{
     AddPlayerNoteTagInput, AddShowdownObservationInput, FieldPlayerNote, NoteTagKind, PlayerNote,
@@ -36,23 +106,60 @@ pub use crate::gql::domains::notes::types::{
 
 // Analytics types
 pub use crate::gql::domains::analytics::types::{
-    BuyInBreakdown, ClubBreakdown, PnlPoint, ProAnalytics,
+    BuyInBreakdown, ClubBreakdown, ClubLevelStatistics, LevelStatistics, PnlPoint,
+    PooledLevelStatistics, ProAnalytics, RebuyBucket, TournamentLevelStatistics,
 };
 
 // Attendance / streak types
 pub use crate::gql::domains::attendance::types::{AttendanceStreak, CheckInResult};
 
+// Bankroll tracker types
+pub use crate::gql::domains::bankroll::types::{
+    AddBankrollTransactionInput, BankrollHistory, BankrollMonth, BankrollTransaction,
+    BankrollTransactionKind, BankrollTransactionSource,
+};
+
 // Season / season-pass / quest types
 pub use crate::gql::domains::seasons::types::{
     CreateSeasonInput, HallOfFameEntry, QuestProgress, Season, SeasonPass,
 };
 
 // Social types
-pub use crate::gql::domains::social::types::{Friend, MutualFlame, YearInPoker};
+pub use crate::gql::domains::social::types::{
+    FollowedFriend, Friend, FriendActivity, FriendActivityKind, MutualFlame, YearInPoker,
+};
 
 // Predictions (Prediction-Points economy) types
 pub use crate::gql::domains::predictions::types::{PredictionBalance, PredictionEntry};
 
+// Printout types
+pub use crate::gql::domains::printouts::types::{
+    EntryReceipt, PlayerStatCard, PrintoutKind, SeatSlipJobStatus, SeatSlipPrintJob,
+    TournamentPrintout,
+};
+
+// Promotion jackpot types
+pub use crate::gql::domains::promotions::types::{
+    CreatePromotionJackpotInput, JackpotEntry, JackpotEntryKind, JackpotKind, PromotionBoardEvent,
+    PromotionBoardEventType, PromotionHand, PromotionHandStatus, PromotionJackpot,
+    RecordQualifyingHandInput, UpdatePromotionJackpotInput,
+};
+
+// Qualification types
+pub use crate::gql::domains::qualifications::types::{
+    CreateQualificationRuleInput, QualificationProgress, QualificationRule,
+};
+
+// Raffle types
+pub use crate::gql::domains::raffles::types::{
+    CreateRaffleInput, Raffle, RaffleEligibility, RaffleEntrant, RaffleStatus,
+};
+
+// Results recap types
+pub use crate::gql::domains::recaps::types::{
+    RecapPhoto, RecapPlace, RecapSettings, TournamentRecap, UpdateRecapSettingsInput,
+};
+
 // Scouting / privacy (public-stats) types
 pub use crate::gql::domains::scouting::types::{
     PrivacySettings, ScoutingMatch, ScoutingProfile, ScoutingQuota,
@@ -63,25 +170,49 @@ pub use crate::gql::domains::users::types::{CreatePlayerInput, UpdatePlayerInput
 
 // Registration types
 pub use crate::gql::domains::registrations::types::{
-    AssignmentStrategy, CancelRegistrationInput, CancelRegistrationResponse, CheckInPlayerInput,
-    CheckInResponse, PlayerRegistrationEvent, RegisterForTournamentInput,
-    RegisterRosterPlayerInput, RegistrationEventType, RegistrationStatus, SelfCheckInInput,
+    AddTournamentAlternateInput, AssignmentStrategy, CancelRegistrationInput,
+    CancelRegistrationResponse, CheckInPlayerInput, CheckInResponse, PlayerPresence,
+    PlayerRegistrationEvent, PresenceStatus, RegisterForTournamentInput, RegisterRosterPlayerInput,
+    RegistrationEventType, RegistrationStatus, ReorderTournamentAlternatesInput, SelfCheckInInput,
     SelfCheckInResponse, TournamentPlayer, TournamentRegistration, UpdateRegistrationStatusInput,
 };
 
+// Rule document / disclosure types
+pub use crate::gql::domains::rules::types::{
+    CreateRuleDocumentInput, RuleAcknowledgment, RuleDocument, RuleDocumentKind,
+    UpdateRuleDocumentInput,
+};
+
+// Seat change request types
+pub use crate::gql::domains::seat_changes::types::{
+    ApproveSeatChangeInput, DeclineSeatChangeInput, RequestSeatChangeInput, SeatChangeRequest,
+    SeatChangeRequestStatus,
+};
+
+// Staff / dealer rotation types
+pub use crate::gql::domains::staff::types::{
+    ClockInInput, ClockOutInput, ClubStaff, CreateClubStaffInput, CurrentDealer, DealerDownCount,
+    DealerPush, GenerateDealerRotationInput, SetTournamentDealersInput, StaffRole, StaffShift,
+    StaffShiftReport, StaffShiftSummary,
+};
+
 // Seating types
 pub use crate::gql::domains::seating::types::{
     AssignPlayerToSeatInput, AssignTableToTournamentInput, AssignTablesToTournamentInput,
-    AutoSeatPlayerInput, BalanceTablesInput, BulkAssignTableEntry, CreateTournamentTableInput,
-    MovePlayerInput, SeatAssignment, SeatWithPlayer, SeatingChangeEvent, SeatingEventType,
+    AutoSeatPlayerInput, BalanceTablesInput, BulkAssignTableEntry, CapacityPlan, ColorUpResult,
+    ColorUpTable, CreateTournamentTableInput, MovePlayerInput, RecordColorUpInput, SeatAssignment,
+    SeatClosureRecommendation, SeatWithPlayer, SeatingChangeEvent, SeatingEventType,
+    SetTableStatusInput, StackSizeEntryInput, TableFloorStatus, TableSeatsInput, TableStatus,
     TableWithSeats, TournamentBounty, TournamentSeatingChart, TournamentTable,
-    UnassignTableFromTournamentInput, UnseatedPlayer, UpdateStackSizeInput,
+    UnassignTableFromTournamentInput, UnseatedPlayer, UpdateStackSizeInput, UpdateStackSizesInput,
 };
 
 // Tournament types
 pub use crate::gql::domains::tournaments::types::{
-    ClockStatus, CreateTournamentInput, Tournament, TournamentClock, TournamentLiveStatus,
-    TournamentStatus, TournamentStructure, TournamentStructureInput, UpdateTournamentInput,
+    CancelTournamentInput, ClockStatus, CloneTournamentInput, ClubClockHold, ClubTournamentEvent,
+    ClubTournamentEventType, CreateTournamentInput, EliminationPace, Tournament, TournamentClock,
+    TournamentLiveStatus, TournamentRefund, TournamentRefundStatus, TournamentStatus,
+    TournamentStructure, TournamentStructureInput, UpdateTournamentInput,
     UpdateTournamentStatusInput,
 };
 
@@ -94,19 +225,30 @@ pub use crate::gql::domains::auth::types::{
 
 // Entry types
 pub use crate::gql::domains::entries::types::{
-    AddTournamentEntryInput, EntryType, TournamentEntry, TournamentEntryStats,
+    AddTournamentEntryInput, EntryEventType, EntryType, TournamentEntry, TournamentEntryEvent,
+    TournamentEntryStats, TournamentEntryTotals,
 };
 
 // Result types
 pub use crate::gql::domains::results::types::{
     CustomPayout, CustomPayoutInput, DealType, EnterTournamentResultsInput,
     EnterTournamentResultsResponse, PayoutPosition, PlayerDeal, PlayerDealInput,
-    PlayerPositionInput, PlayerStatistics, PlayerStatsResponse, TournamentPayout, TournamentResult,
-    UserTournamentResult,
+    PlayerPositionInput, PlayerStatistics, PlayerStatsResponse, ResultsFeed, ResultsFeedEvent,
+    ResultsFeedPlace, TournamentHistoryEntry, TournamentPayout, TournamentResult,
+    TournamentResultEvent, UserTournamentResult,
+};
+
+// Retention types
+pub use crate::gql::domains::retention::types::{
+    ClubRetentionPolicy, RetainedRecordKind, RetentionLine, RetentionMode, RetentionReport,
+    SetClubRetentionPolicyInput,
 };
 
 // Leaderboard types
-pub use crate::gql::domains::leaderboards::types::{LeaderboardEntry, LeaderboardPeriod};
+pub use crate::gql::domains::leaderboards::types::{
+    LeaderboardEntry, LeaderboardOptions, LeaderboardPeriod, LeaderboardTieBreak, StatsSettings,
+    UpdateStatsSettingsInput,
+};
 
 // Template types
 pub use crate::gql::domains::templates::types::{
@@ -115,3 +257,31 @@ pub use crate::gql::domains::templates::types::{
     PayoutStructureEntryInput, PayoutTemplate, UpdateBlindStructureTemplateInput,
     UpdatePayoutTemplateInput,
 };
+
+// Entry ticket types
+pub use crate::gql::domains::tickets::types::{
+    EntryTicket, EntryTicketStatus, EntryTicketVerification, TicketCheckResult,
+};
+
+// Tournament invite types
+pub use crate::gql::domains::invites::types::{CreateTournamentInviteInput, TournamentInvite};
+
+// Buy-in package and voucher types
+pub use crate::gql::domains::buy_in_credits::types::{
+    BuyInCredit, BuyInCreditKind, BuyInCreditLedgerEntry, BuyInCreditLedgerKind,
+    IssueBuyInCreditInput,
+};
+
+// Group booking types
+pub use crate::gql::domains::groups::types::{
+    GroupSeatingPolicy, RegistrationGroup, RegistrationGroupMember, SeatingSettings,
+    UpdateSeatingSettingsInput,
+};
+
+// Registration form types
+pub use crate::gql::domains::questions::types::{
+    AnswerChoiceCount, AnswerTextResponse, CheckboxAnswer, CreateRegistrationQuestionInput,
+    RegistrationAnswer, RegistrationAnswerInput, RegistrationAnswersReport,
+    RegistrationFormResponse, RegistrationQuestion, RegistrationQuestionKind,
+    RegistrationQuestionReport, SelectAnswer, TextAnswer, UpdateRegistrationQuestionInput,
+};
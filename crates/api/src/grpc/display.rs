@@ -0,0 +1,187 @@
+//! `pp.display.v1.DisplayController`: clock state and headline numbers for
+//! the venue's LED displays.
+
+use std::pin::Pin;
+
+use sqlx::PgPool;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{Stream, StreamExt};
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+
+use super::pb::display_controller_server::DisplayController;
+use super::pb::{
+    BlindLevel, ClockState, ClockStatus, EliminationPace, GetTournamentSummaryRequest,
+    ListLiveTournamentsRequest, ListLiveTournamentsResponse, TournamentSummary, WatchClockRequest,
+};
+use crate::gql::domains::tournaments::clock::load_tournament_clock;
+use crate::gql::subscriptions::subscribe_clock_updates;
+use crate::gql::types::{self as gql, TournamentStructure};
+use infra::models::TournamentRow;
+use infra::repos::{tournament_entries, tournament_payouts, tournaments};
+
+pub struct DisplayService {
+    db: PgPool,
+}
+
+impl DisplayService {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+
+    async fn load_clock(&self, tournament_id: Uuid) -> Result<Option<ClockState>, Status> {
+        let clock = load_tournament_clock(&self.db, tournament_id)
+            .await
+            .map_err(|e| Status::internal(e.message))?;
+        Ok(clock.map(ClockState::from))
+    }
+
+    async fn summarize(&self, tournament: TournamentRow) -> Result<TournamentSummary, Status> {
+        let stats = tournament_entries::get_stats(&self.db, tournament.id)
+            .await
+            .map_err(internal)?;
+        let prize_pool_cents = tournament_payouts::get_by_tournament(&self.db, tournament.id)
+            .await
+            .map_err(internal)?
+            .map(|p| p.total_prize_pool)
+            .unwrap_or(0);
+        let clock = self.load_clock(tournament.id).await?;
+
+        Ok(TournamentSummary {
+            tournament_id: tournament.id.to_string(),
+            club_id: tournament.club_id.to_string(),
+            name: tournament.name,
+            live_status: tournament.live_status.as_str().to_string(),
+            start_time_unix_ms: tournament.start_time.timestamp_millis(),
+            buy_in_cents: tournament.buy_in_cents,
+            prize_pool_cents,
+            total_entries: stats.total_entries as i32,
+            players_remaining: stats.players_remaining as i32,
+            total_chips: stats.total_chips,
+            average_stack: if stats.players_remaining > 0 {
+                stats.total_chips / stats.players_remaining
+            } else {
+                0
+            },
+            clock,
+        })
+    }
+}
+
+fn internal(e: sqlx::Error) -> Status {
+    tracing::error!("display gRPC: database error: {e}");
+    Status::internal("Database error")
+}
+
+fn parse_id(id: &str, what: &str) -> Result<Uuid, Status> {
+    Uuid::parse_str(id).map_err(|_| Status::invalid_argument(format!("Invalid {what} ID")))
+}
+
+impl From<&TournamentStructure> for BlindLevel {
+    fn from(s: &TournamentStructure) -> Self {
+        Self {
+            level: s.level_number,
+            small_blind: s.small_blind,
+            big_blind: s.big_blind,
+            ante: s.ante,
+            is_break: s.is_break,
+            duration_minutes: s.duration_minutes,
+        }
+    }
+}
+
+impl From<&gql::EliminationPace> for EliminationPace {
+    fn from(p: &gql::EliminationPace) -> Self {
+        Self {
+            players_remaining: p.players_remaining,
+            eliminations: p.eliminations,
+            eliminations_per_hour: p.eliminations_per_hour.round() as i32,
+            projected_finish_unix_ms: p.projected_finish_at.map(|t| t.timestamp_millis()),
+        }
+    }
+}
+
+impl From<gql::TournamentClock> for ClockState {
+    fn from(clock: gql::TournamentClock) -> Self {
+        let status = match clock.status {
+            gql::ClockStatus::Stopped => ClockStatus::Stopped,
+            gql::ClockStatus::Running => ClockStatus::Running,
+            gql::ClockStatus::Paused => ClockStatus::Paused,
+        };
+        Self {
+            tournament_id: clock.tournament_id.to_string(),
+            status: status as i32,
+            current_level: clock.current_level,
+            seconds_remaining: clock.time_remaining_seconds,
+            // Only meaningful while running; a paused level's end keeps moving.
+            level_ends_at_unix_ms: match status {
+                ClockStatus::Running => clock.level_end_time.map(|t| t.timestamp_millis()),
+                _ => None,
+            },
+            auto_advance: clock.auto_advance,
+            current: clock.current_structure.as_ref().map(BlindLevel::from),
+            next: clock.next_structure.as_ref().map(BlindLevel::from),
+            pace: clock.pace.as_ref().map(EliminationPace::from),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl DisplayController for DisplayService {
+    async fn get_tournament_summary(
+        &self,
+        request: Request<GetTournamentSummaryRequest>,
+    ) -> Result<Response<TournamentSummary>, Status> {
+        let tournament_id = parse_id(&request.get_ref().tournament_id, "tournament")?;
+        let tournament = tournaments::get_by_id(&self.db, tournament_id)
+            .await
+            .map_err(internal)?
+            .ok_or_else(|| Status::not_found("Tournament not found"))?;
+
+        Ok(Response::new(self.summarize(tournament).await?))
+    }
+
+    async fn list_live_tournaments(
+        &self,
+        request: Request<ListLiveTournamentsRequest>,
+    ) -> Result<Response<ListLiveTournamentsResponse>, Status> {
+        let club_id = parse_id(&request.get_ref().club_id, "club")?;
+        let rows = tournaments::list_live_by_club(&self.db, club_id)
+            .await
+            .map_err(internal)?;
+
+        let mut summaries = Vec::with_capacity(rows.len());
+        for row in rows {
+            summaries.push(self.summarize(row).await?);
+        }
+        Ok(Response::new(ListLiveTournamentsResponse {
+            tournaments: summaries,
+        }))
+    }
+
+    type WatchClockStream = Pin<Box<dyn Stream<Item = Result<ClockState, Status>> + Send>>;
+
+    async fn watch_clock(
+        &self,
+        request: Request<WatchClockRequest>,
+    ) -> Result<Response<Self::WatchClockStream>, Status> {
+        let tournament_id = parse_id(&request.get_ref().tournament_id, "tournament")?;
+
+        // Subscribe before loading the current state so no change slips
+        // between the two.
+        let updates = subscribe_clock_updates(tournament_id);
+        let current = self
+            .load_clock(tournament_id)
+            .await?
+            .ok_or_else(|| Status::not_found("Tournament has no clock"))?;
+
+        // A controller that falls behind skips to the newest state rather than
+        // replaying stale ones.
+        let updates = BroadcastStream::new(updates)
+            .filter_map(|update| update.ok())
+            .map(|clock| Ok(ClockState::from(clock)));
+        let stream = tokio_stream::once(Ok(current)).chain(updates);
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
@@ -0,0 +1,118 @@
+//! Internal gRPC API for venue hardware that can't speak GraphQL (LED clock
+//! controllers). Runs in the api process on its own port, reading the same
+//! repos and following the same real-time bus as the GraphQL subscriptions.
+//!
+//! The contract lives in `crates/api/proto/display/v1/display.proto`; the
+//! bindings under `pb/` are generated from it and checked in so the build
+//! doesn't need `protoc`.
+
+pub mod display;
+
+#[allow(clippy::all)]
+pub mod pb {
+    include!("pb/pp.display.v1.rs");
+}
+
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::net::TcpListener;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::transport::Server;
+use tonic::{Request, Status};
+
+use crate::config::Env;
+use crate::state::AppState;
+use display::DisplayService;
+use pb::display_controller_server::DisplayControllerServer;
+
+/// Where and how to serve the gRPC API. Set both GRPC_PORT and
+/// GRPC_DISPLAY_TOKEN to enable it.
+#[derive(Clone)]
+pub struct GrpcConfig {
+    pub port: u16,
+    pub display_token: String,
+}
+
+impl GrpcConfig {
+    pub fn load(env: &mut Env) -> Option<Self> {
+        let port = env.parse("GRPC_PORT");
+        let display_token = env.secret("GRPC_DISPLAY_TOKEN");
+        if !env.all_or_none(&["GRPC_PORT", "GRPC_DISPLAY_TOKEN"]) {
+            return None;
+        }
+        Some(Self {
+            port: port?,
+            display_token: display_token?,
+        })
+    }
+}
+
+/// Reject calls without `authorization: Bearer <token>` metadata.
+fn check_bearer(expected: &str, req: &Request<()>) -> Result<(), Status> {
+    let presented = req
+        .metadata()
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .ok_or_else(|| Status::unauthenticated("Missing bearer token"))?;
+
+    // Constant-time compare so the token can't be guessed byte by byte.
+    let matches = presented.len() == expected.len()
+        && presented
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0;
+    if matches {
+        Ok(())
+    } else {
+        Err(Status::unauthenticated("Invalid bearer token"))
+    }
+}
+
+/// Serve the display API on `listener` until `shutdown` resolves.
+pub async fn serve(
+    state: AppState,
+    listener: TcpListener,
+    display_token: String,
+    shutdown: impl Future<Output = ()>,
+) -> Result<(), tonic::transport::Error> {
+    let token: Arc<str> = display_token.into();
+    let display = DisplayControllerServer::with_interceptor(
+        DisplayService::new(state.db.clone()),
+        move |req: Request<()>| {
+            check_bearer(&token, &req)?;
+            Ok(req)
+        },
+    );
+
+    Server::builder()
+        .add_service(display)
+        .serve_with_incoming_shutdown(TcpListenerStream::new(listener), shutdown)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_bearer;
+    use tonic::Request;
+
+    fn request_with(auth: Option<&str>) -> Request<()> {
+        let mut req = Request::new(());
+        if let Some(auth) = auth {
+            req.metadata_mut()
+                .insert("authorization", auth.parse().unwrap());
+        }
+        req
+    }
+
+    #[test]
+    fn bearer_token_must_match_exactly() {
+        assert!(check_bearer("s3cret", &request_with(Some("Bearer s3cret"))).is_ok());
+        assert!(check_bearer("s3cret", &request_with(Some("Bearer s3cre"))).is_err());
+        assert!(check_bearer("s3cret", &request_with(Some("Bearer s3cret!"))).is_err());
+        assert!(check_bearer("s3cret", &request_with(Some("s3cret"))).is_err());
+        assert!(check_bearer("s3cret", &request_with(None)).is_err());
+    }
+}
@@ -0,0 +1,601 @@
+// This file is @generated by prost-build.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetTournamentSummaryRequest {
+    #[prost(string, tag = "1")]
+    pub tournament_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListLiveTournamentsRequest {
+    #[prost(string, tag = "1")]
+    pub club_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListLiveTournamentsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub tournaments: ::prost::alloc::vec::Vec<TournamentSummary>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct WatchClockRequest {
+    #[prost(string, tag = "1")]
+    pub tournament_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BlindLevel {
+    #[prost(int32, tag = "1")]
+    pub level: i32,
+    #[prost(int32, tag = "2")]
+    pub small_blind: i32,
+    #[prost(int32, tag = "3")]
+    pub big_blind: i32,
+    #[prost(int32, tag = "4")]
+    pub ante: i32,
+    #[prost(bool, tag = "5")]
+    pub is_break: bool,
+    #[prost(int32, tag = "6")]
+    pub duration_minutes: i32,
+}
+/// How fast the field is shrinking and when the tournament should end.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct EliminationPace {
+    #[prost(int32, tag = "1")]
+    pub players_remaining: i32,
+    #[prost(int32, tag = "2")]
+    pub eliminations: i32,
+    /// Expected eliminations over the next hour of play, rounded.
+    #[prost(int32, tag = "3")]
+    pub eliminations_per_hour: i32,
+    /// When the last elimination is expected, breaks included (Unix
+    /// milliseconds). Unset before the clock starts and once one player is left.
+    #[prost(int64, optional, tag = "4")]
+    pub projected_finish_unix_ms: ::core::option::Option<i64>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ClockState {
+    #[prost(string, tag = "1")]
+    pub tournament_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "ClockStatus", tag = "2")]
+    pub status: i32,
+    #[prost(int32, tag = "3")]
+    pub current_level: i32,
+    /// Seconds left in the level when this message was built; controllers count
+    /// down locally between messages.
+    #[prost(int64, optional, tag = "4")]
+    pub seconds_remaining: ::core::option::Option<i64>,
+    /// Wall-clock end of the running level (Unix milliseconds).
+    #[prost(int64, optional, tag = "5")]
+    pub level_ends_at_unix_ms: ::core::option::Option<i64>,
+    #[prost(bool, tag = "6")]
+    pub auto_advance: bool,
+    #[prost(message, optional, tag = "7")]
+    pub current: ::core::option::Option<BlindLevel>,
+    #[prost(message, optional, tag = "8")]
+    pub next: ::core::option::Option<BlindLevel>,
+    /// Recalculated on every message; eliminations also push a new one.
+    #[prost(message, optional, tag = "9")]
+    pub pace: ::core::option::Option<EliminationPace>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TournamentSummary {
+    #[prost(string, tag = "1")]
+    pub tournament_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub club_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub name: ::prost::alloc::string::String,
+    /// not_started | registration_open | late_registration | in_progress | break
+    /// | final_table | finished
+    #[prost(string, tag = "4")]
+    pub live_status: ::prost::alloc::string::String,
+    #[prost(int64, tag = "5")]
+    pub start_time_unix_ms: i64,
+    #[prost(int64, tag = "6")]
+    pub buy_in_cents: i64,
+    #[prost(int64, tag = "7")]
+    pub prize_pool_cents: i64,
+    #[prost(int32, tag = "8")]
+    pub total_entries: i32,
+    #[prost(int32, tag = "9")]
+    pub players_remaining: i32,
+    #[prost(int64, tag = "10")]
+    pub total_chips: i64,
+    /// total_chips / players_remaining, 0 when nobody is left.
+    #[prost(int64, tag = "11")]
+    pub average_stack: i64,
+    #[prost(message, optional, tag = "12")]
+    pub clock: ::core::option::Option<ClockState>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ClockStatus {
+    Unspecified = 0,
+    Stopped = 1,
+    Running = 2,
+    Paused = 3,
+}
+impl ClockStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "CLOCK_STATUS_UNSPECIFIED",
+            Self::Stopped => "CLOCK_STATUS_STOPPED",
+            Self::Running => "CLOCK_STATUS_RUNNING",
+            Self::Paused => "CLOCK_STATUS_PAUSED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CLOCK_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "CLOCK_STATUS_STOPPED" => Some(Self::Stopped),
+            "CLOCK_STATUS_RUNNING" => Some(Self::Running),
+            "CLOCK_STATUS_PAUSED" => Some(Self::Paused),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod display_controller_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct DisplayControllerClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl DisplayControllerClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> DisplayControllerClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> DisplayControllerClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            DisplayControllerClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Headline numbers for one tournament, including its clock if it has one.
+        pub async fn get_tournament_summary(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTournamentSummaryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TournamentSummary>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pp.display.v1.DisplayController/GetTournamentSummary",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pp.display.v1.DisplayController", "GetTournamentSummary"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Summaries of a club's tournaments that are currently running.
+        pub async fn list_live_tournaments(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListLiveTournamentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListLiveTournamentsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pp.display.v1.DisplayController/ListLiveTournaments",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pp.display.v1.DisplayController", "ListLiveTournaments"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// The tournament's clock now, then every change (start, pause, level
+        /// advance, ...). Ends when the tournament finishes.
+        pub async fn watch_clock(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WatchClockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ClockState>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pp.display.v1.DisplayController/WatchClock",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pp.display.v1.DisplayController", "WatchClock"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod display_controller_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with DisplayControllerServer.
+    #[async_trait]
+    pub trait DisplayController: std::marker::Send + std::marker::Sync + 'static {
+        /// Headline numbers for one tournament, including its clock if it has one.
+        async fn get_tournament_summary(
+            &self,
+            request: tonic::Request<super::GetTournamentSummaryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TournamentSummary>,
+            tonic::Status,
+        >;
+        /// Summaries of a club's tournaments that are currently running.
+        async fn list_live_tournaments(
+            &self,
+            request: tonic::Request<super::ListLiveTournamentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListLiveTournamentsResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the WatchClock method.
+        type WatchClockStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ClockState, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// The tournament's clock now, then every change (start, pause, level
+        /// advance, ...). Ends when the tournament finishes.
+        async fn watch_clock(
+            &self,
+            request: tonic::Request<super::WatchClockRequest>,
+        ) -> std::result::Result<tonic::Response<Self::WatchClockStream>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct DisplayControllerServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> DisplayControllerServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for DisplayControllerServer<T>
+    where
+        T: DisplayController,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/pp.display.v1.DisplayController/GetTournamentSummary" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTournamentSummarySvc<T: DisplayController>(pub Arc<T>);
+                    impl<
+                        T: DisplayController,
+                    > tonic::server::UnaryService<super::GetTournamentSummaryRequest>
+                    for GetTournamentSummarySvc<T> {
+                        type Response = super::TournamentSummary;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTournamentSummaryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DisplayController>::get_tournament_summary(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetTournamentSummarySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pp.display.v1.DisplayController/ListLiveTournaments" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListLiveTournamentsSvc<T: DisplayController>(pub Arc<T>);
+                    impl<
+                        T: DisplayController,
+                    > tonic::server::UnaryService<super::ListLiveTournamentsRequest>
+                    for ListLiveTournamentsSvc<T> {
+                        type Response = super::ListLiveTournamentsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListLiveTournamentsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DisplayController>::list_live_tournaments(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListLiveTournamentsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pp.display.v1.DisplayController/WatchClock" => {
+                    #[allow(non_camel_case_types)]
+                    struct WatchClockSvc<T: DisplayController>(pub Arc<T>);
+                    impl<
+                        T: DisplayController,
+                    > tonic::server::ServerStreamingService<super::WatchClockRequest>
+                    for WatchClockSvc<T> {
+                        type Response = super::ClockState;
+                        type ResponseStream = T::WatchClockStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WatchClockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DisplayController>::watch_clock(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WatchClockSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for DisplayControllerServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "pp.display.v1.DisplayController";
+    impl<T> tonic::server::NamedService for DisplayControllerServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
@@ -4,8 +4,10 @@
 
 pub mod app;
 pub mod auth;
+pub mod config;
 pub mod error;
 pub mod gql;
+pub mod grpc;
 pub mod middleware;
 pub mod observability;
 pub mod routes;
@@ -13,3 +15,7 @@ pub mod services;
 pub mod state;
 
 pub use state::AppState;
+
+/// The migrations compiled into this binary; `migrationStatus` compares them
+/// with what the database has applied.
+pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("../../migrations");
@@ -7,10 +7,12 @@ use tokio::net::TcpListener;
 use tokio::sync::watch;
 
 use api::app::build_router;
+use api::config::Config;
 use api::gql::build_schema;
 use api::services::{
-    data_retention_service, spawn_clock_service, spawn_data_retention_service,
-    spawn_drink_expiry_service, spawn_notification_service, spawn_subscription_expiry_service,
+    spawn_alternate_seating_service, spawn_announcement_dispatch_service, spawn_clock_service,
+    spawn_data_retention_service, spawn_drink_expiry_service, spawn_mqtt_bridge,
+    spawn_notification_service, spawn_rolling_points_service, spawn_subscription_expiry_service,
     supervise,
 };
 use api::state::AppState;
@@ -19,12 +21,19 @@ use api::state::AppState;
 async fn main() -> anyhow::Result<()> {
     dotenvy::dotenv().ok();
 
+    // Everything is read and validated up front: a bad deploy stops here with
+    // the full list of missing and invalid settings.
+    let config = Config::from_env()?;
+
     // Error reporting (opt-in via SENTRY_DSN). Must be initialized before the
     // tracing subscriber so the Sentry layer has a client; the guard is held for
     // the whole process so reports flush until shutdown.
-    let _sentry_guard = api::observability::init_sentry();
+    let _sentry_guard = api::observability::init_sentry(config.sentry.as_ref());
 
-    api::observability::init_tracing();
+    api::observability::init_tracing(config.log_json);
+    for warning in config.warnings() {
+        tracing::warn!("{warning}");
+    }
 
     if _sentry_guard.is_some() {
         tracing::info!("Sentry error reporting enabled");
@@ -33,19 +42,17 @@ async fn main() -> anyhow::Result<()> {
     }
 
     // Configure connection pool with appropriate limits
-    let max_connections: u32 = std::env::var("DATABASE_MAX_CONNECTIONS")
-        .ok()
-        .and_then(|s| s.parse().ok())
-        .unwrap_or(30);
+    let max_connections = config.database_max_connections;
 
-    let pool = PgPoolOptions::new()
+    // Connections carry the row-level security scope of the request using them.
+    let pool = infra::db::scope_connections(PgPoolOptions::new())
         .max_connections(max_connections)
         .min_connections(5) // Pre-warm pool with 5 connections
         .acquire_timeout(std::time::Duration::from_secs(10))
         .idle_timeout(Some(std::time::Duration::from_secs(300))) // 5 minutes (reduced from 10)
         .max_lifetime(Some(std::time::Duration::from_secs(1800))) // 30 minutes
         .test_before_acquire(true) // Verify connection is alive before using
-        .connect(&std::env::var("DATABASE_URL")?)
+        .connect(&config.database_url)
         .await?;
     tracing::info!(
         "Connected to Postgres with max {} connections",
@@ -53,19 +60,23 @@ async fn main() -> anyhow::Result<()> {
     );
 
     // Run database migrations automatically on startup (can be disabled with SKIP_MIGRATIONS=true)
-    let skip_migrations = std::env::var("SKIP_MIGRATIONS")
-        .map(|v| v.to_lowercase() == "true")
-        .unwrap_or(false);
-
-    if skip_migrations {
+    if config.skip_migrations {
         tracing::info!("Skipping database migrations (SKIP_MIGRATIONS=true)");
     } else {
         tracing::info!("Running database migrations...");
-        sqlx::migrate!("../../migrations").run(&pool).await?;
+        api::MIGRATOR.run(&pool).await?;
         tracing::info!("Database migrations completed successfully");
     }
 
-    let state = AppState::new(pool)?;
+    // Personal data columns are sealed with PII_ENCRYPTION_KEYS when set
+    match &config.pii_keys {
+        Some(keys) => tracing::info!("PII encryption enabled (active key {})", keys.active_id()),
+        None => tracing::warn!("PII_ENCRYPTION_KEYS not set: phone numbers are stored unencrypted"),
+    }
+    infra::pii::install(config.pii_keys.clone());
+
+    let port = config.port;
+    let state = AppState::with_config(pool, config);
 
     // Build GraphQL schema from the gql module
     let schema = build_schema(state.clone());
@@ -92,6 +103,12 @@ async fn main() -> anyhow::Result<()> {
     });
     tracing::info!("Notification service started");
 
+    let _announcement_dispatch = supervise("announcement_dispatch_service", shutdown_rx.clone(), {
+        let state = state.clone();
+        move || spawn_announcement_dispatch_service(state.clone())
+    });
+    tracing::info!("Announcement dispatch service started");
+
     let _drink_expiry = supervise("drink_expiry_service", shutdown_rx.clone(), {
         let state = state.clone();
         move || spawn_drink_expiry_service(state.clone())
@@ -104,12 +121,24 @@ async fn main() -> anyhow::Result<()> {
     });
     tracing::info!("Subscription expiry service started");
 
+    let _alternate_seating = supervise("alternate_seating_service", shutdown_rx.clone(), {
+        let state = state.clone();
+        move || spawn_alternate_seating_service(state.clone())
+    });
+    tracing::info!("Alternate seating service started");
+
+    let _rolling_points = supervise("rolling_points_service", shutdown_rx.clone(), {
+        let state = state.clone();
+        move || spawn_rolling_points_service(state.clone())
+    });
+    tracing::info!("Rolling leaderboard points service started");
+
     // GDPR data-retention sweep — destructive (anonymizes dormant accounts), so
     // it only runs when explicitly enabled via ENABLE_DATA_RETENTION.
-    let _data_retention = if data_retention_service::is_enabled() {
+    let _data_retention = if let Some(config) = state.config().data_retention.clone() {
         let handle = supervise("data_retention_service", shutdown_rx.clone(), {
             let state = state.clone();
-            move || spawn_data_retention_service(state.clone())
+            move || spawn_data_retention_service(state.clone(), config.clone())
         });
         tracing::info!("Data-retention service started");
         Some(handle)
@@ -128,11 +157,49 @@ async fn main() -> anyhow::Result<()> {
     let _realtime_listener = api::gql::realtime::spawn_realtime_listener(state.db.clone());
     tracing::info!("Realtime bus (LISTEN/NOTIFY) started");
 
+    // MQTT bridge for in-venue signage/buzzers. It sees every instance's events
+    // via the bus, so configure MQTT_HOST on one instance only.
+    let _mqtt_bridge = match state.config().mqtt.clone() {
+        Some(config) => {
+            let handle = supervise("mqtt_bridge", shutdown_rx.clone(), {
+                let state = state.clone();
+                move || spawn_mqtt_bridge(state.clone(), config.clone())
+            });
+            tracing::info!("MQTT bridge started");
+            Some(handle)
+        }
+        None => {
+            tracing::info!("MQTT bridge disabled (set MQTT_HOST to enable)");
+            None
+        }
+    };
+
+    // Internal gRPC API for the venue's display hardware, on its own port.
+    // Opt-in via GRPC_PORT + GRPC_DISPLAY_TOKEN.
+    let _grpc = match state.config().grpc.clone() {
+        Some(config) => {
+            let listener = TcpListener::bind(format!("0.0.0.0:{}", config.port)).await?;
+            tracing::info!("gRPC display API listening on 0.0.0.0:{}", config.port);
+            let mut shutdown = shutdown_rx.clone();
+            let state = state.clone();
+            Some(tokio::spawn(async move {
+                let stop = async move {
+                    let _ = shutdown.changed().await;
+                };
+                if let Err(e) = api::grpc::serve(state, listener, config.display_token, stop).await
+                {
+                    tracing::error!("gRPC display API stopped: {e}");
+                }
+            }))
+        }
+        None => {
+            tracing::info!("gRPC display API disabled (set GRPC_PORT and GRPC_DISPLAY_TOKEN)");
+            None
+        }
+    };
+
     let app = build_router(state, schema);
 
-    let port: u16 = std::env::var("PORT")
-        .unwrap_or_else(|_| "8080".into())
-        .parse()?;
     let addr = format!("0.0.0.0:{port}");
     let listener = TcpListener::bind(&addr).await?;
     tracing::info!("Listening on {}", addr);
@@ -0,0 +1,129 @@
+//! CSRF protection for the cookie-authenticated `/auth/*` endpoints.
+//!
+//! Browsers attach the refresh cookie to any request to those paths, whichever
+//! site triggers it, so a cookie-carrying POST must name an allowed origin in
+//! `Origin` (or, failing that, `Referer`). Native clients send the token in
+//! `X-Refresh-Token` instead and carry no cookie, so they aren't checked.
+
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderMap, Method},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::auth::config::SecurityConfig;
+use crate::auth::cookie::extract_refresh_token;
+use crate::error::AppError;
+use crate::state::AppState;
+
+/// The request's origin: the `Origin` header, else the scheme and host of the
+/// `Referer`.
+fn request_origin(headers: &HeaderMap) -> Option<String> {
+    if let Some(origin) = headers.get(header::ORIGIN).and_then(|v| v.to_str().ok()) {
+        return Some(origin.trim_end_matches('/').to_string());
+    }
+    let referer = headers.get(header::REFERER)?.to_str().ok()?;
+    let (scheme, rest) = referer.split_once("://")?;
+    let host = rest.split(['/', '?', '#']).next()?;
+    (!host.is_empty()).then(|| format!("{scheme}://{host}"))
+}
+
+/// Whether the request may go ahead under `config`.
+fn check(method: &Method, headers: &HeaderMap, config: &SecurityConfig) -> Result<(), AppError> {
+    if !config.csrf_protection || matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS) {
+        return Ok(());
+    }
+    let has_cookie = headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .any(|v| extract_refresh_token(v).is_some());
+    if !has_cookie {
+        return Ok(());
+    }
+    match request_origin(headers) {
+        Some(origin) if config.is_allowed_origin(&origin) => Ok(()),
+        Some(_) => Err(AppError::Forbidden(
+            "Cross-site request rejected".to_string(),
+        )),
+        None => Err(AppError::Forbidden(
+            "Missing Origin header on a cookie-authenticated request".to_string(),
+        )),
+    }
+}
+
+pub async fn csrf_middleware(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    check(
+        request.method(),
+        request.headers(),
+        &state.auth_config().security,
+    )?;
+    Ok(next.run(request).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::config::SecurityPreset;
+
+    fn config() -> SecurityConfig {
+        let mut config = SecurityConfig::preset(SecurityPreset::Production);
+        config.allowed_origins = vec!["https://app.example.com".to_string()];
+        config
+    }
+
+    fn headers(pairs: &[(header::HeaderName, &str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.clone(), value.parse().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn cookie_posts_need_an_allowed_origin() {
+        let post = Method::POST;
+        let cookie = (header::COOKIE, "refresh_token=abc");
+        assert!(check(&post, &headers(std::slice::from_ref(&cookie)), &config()).is_err());
+        assert!(check(
+            &post,
+            &headers(&[cookie.clone(), (header::ORIGIN, "https://evil.example")]),
+            &config()
+        )
+        .is_err());
+        assert!(check(
+            &post,
+            &headers(&[cookie.clone(), (header::ORIGIN, "https://app.example.com")]),
+            &config()
+        )
+        .is_ok());
+        assert!(check(
+            &post,
+            &headers(&[
+                cookie.clone(),
+                (header::REFERER, "https://app.example.com/login?next=/")
+            ]),
+            &config()
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn skips_requests_without_the_cookie_or_when_disabled() {
+        let post = Method::POST;
+        // Native clients present the token in a header.
+        assert!(check(&post, &headers(&[]), &config()).is_ok());
+
+        let mut disabled = config();
+        disabled.csrf_protection = false;
+        let cross_site = headers(&[
+            (header::COOKIE, "refresh_token=abc"),
+            (header::ORIGIN, "https://evil.example"),
+        ]);
+        assert!(check(&post, &cross_site, &disabled).is_ok());
+    }
+}
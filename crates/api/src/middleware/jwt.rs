@@ -5,12 +5,16 @@ use axum::{
     response::Response,
 };
 
+use crate::auth::jwt::DisplayClaims;
 use crate::auth::Claims;
 use crate::error::AppError;
 use crate::state::AppState;
 
 /// JWT middleware that extracts and validates JWT tokens from Authorization header
 /// and adds claims to the request extensions for GraphQL context.
+/// Tokens minted before the user's current `token_version` are treated as invalid.
+/// A paired display's token adds `DisplayClaims` instead, which only the display
+/// queries and subscriptions accept.
 /// If a token is present but invalid, logs the error but allows the request to proceed.
 /// Individual resolvers must enforce authentication as needed.
 pub async fn jwt_middleware(
@@ -24,15 +28,24 @@ pub async fn jwt_middleware(
             // Check if it's a Bearer token
             if let Some(token) = auth_str.strip_prefix("Bearer ") {
                 // Verify the token
-                match state.jwt_service().verify_token(token) {
+                match state.jwt_service().verify_current(&state.db, token).await {
                     Ok(claims) => {
                         // Add claims to request extensions so GraphQL can access them
                         request.extensions_mut().insert::<Claims>(claims);
                     }
-                    Err(e) => {
-                        // Log the error but continue - let GraphQL resolvers enforce auth
-                        tracing::debug!("JWT validation failed: {}", e);
-                    }
+                    Err(e) => match state
+                        .jwt_service()
+                        .verify_display_current(&state.db, token)
+                        .await
+                    {
+                        Ok(display) => {
+                            request.extensions_mut().insert::<DisplayClaims>(display);
+                        }
+                        Err(_) => {
+                            // Log the error but continue - let GraphQL resolvers enforce auth
+                            tracing::debug!("JWT validation failed: {}", e);
+                        }
+                    },
                 }
             }
         }
@@ -1 +1,4 @@
+pub mod csrf;
 pub mod jwt;
+pub mod quota;
+pub mod row_scope;
@@ -0,0 +1,444 @@
+//! Soft per-principal quotas on the GraphQL endpoint, on top of the per-IP
+//! governor: requests and query complexity per minute, and concurrent
+//! subscriptions. A principal is the signed-in user, or the client IP for
+//! anonymous callers, so one misbehaving kiosk can't drain the DB pool for
+//! the rest of the venue.
+//!
+//! Counters live in memory per instance and use fixed one-minute windows;
+//! admins read them through the `apiUsage` query. Limits come from the
+//! environment (0 disables one):
+//!
+//! - `QUOTA_REQUESTS_PER_MINUTE` (default 600)
+//! - `QUOTA_COMPLEXITY_PER_MINUTE` (default 100000)
+//! - `QUOTA_MAX_SUBSCRIPTIONS` (default 50)
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
+
+use async_graphql::extensions::{
+    Extension, ExtensionContext, ExtensionFactory, NextSubscribe, NextValidation,
+};
+use async_graphql::{Response as GqlResponse, ServerError, ValidationResult};
+use axum::{
+    extract::Request,
+    http::{header::RETRY_AFTER, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{self, BoxStream, StreamExt};
+use parking_lot::Mutex;
+use tower_governor::key_extractor::{KeyExtractor, SmartIpKeyExtractor};
+use uuid::Uuid;
+
+use crate::auth::Claims;
+use crate::config::Env;
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// Who a request is charged to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Principal {
+    User(Uuid),
+    Ip(String),
+}
+
+impl Principal {
+    /// The signed-in user, else the client IP (as forwarded by the proxy).
+    pub fn of_request(request: &Request) -> Self {
+        if let Some(user_id) = request
+            .extensions()
+            .get::<Claims>()
+            .and_then(|claims| Uuid::parse_str(&claims.sub).ok())
+        {
+            return Principal::User(user_id);
+        }
+        let ip = SmartIpKeyExtractor
+            .extract(request)
+            .map(|ip| ip.to_string())
+            .unwrap_or_else(|_| "unknown".to_string());
+        Principal::Ip(ip)
+    }
+
+    pub fn user_id(&self) -> Option<Uuid> {
+        match self {
+            Principal::User(id) => Some(*id),
+            Principal::Ip(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Principal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Principal::User(id) => write!(f, "user:{id}"),
+            Principal::Ip(ip) => write!(f, "ip:{ip}"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QuotaConfig {
+    pub requests_per_minute: u64,
+    pub complexity_per_minute: u64,
+    pub max_subscriptions: usize,
+}
+
+impl QuotaConfig {
+    pub fn load(env: &mut Env) -> Self {
+        Self {
+            requests_per_minute: env.parse_or("QUOTA_REQUESTS_PER_MINUTE", 600),
+            complexity_per_minute: env.parse_or("QUOTA_COMPLEXITY_PER_MINUTE", 100_000),
+            max_subscriptions: env.parse_or("QUOTA_MAX_SUBSCRIPTIONS", 50),
+        }
+    }
+}
+
+/// A principal's counters.
+#[derive(Clone, Debug)]
+struct Usage {
+    window_start: Instant,
+    requests: u64,
+    complexity: u64,
+    subscriptions: usize,
+    /// Requests, queries and subscriptions turned away since startup.
+    rejected: u64,
+    last_seen: Instant,
+    last_seen_at: DateTime<Utc>,
+}
+
+impl Usage {
+    fn new(now: Instant) -> Self {
+        Self {
+            window_start: now,
+            requests: 0,
+            complexity: 0,
+            subscriptions: 0,
+            rejected: 0,
+            last_seen: now,
+            last_seen_at: Utc::now(),
+        }
+    }
+
+    /// Start a new window once the current one is over.
+    fn roll(&mut self, now: Instant) {
+        if now.duration_since(self.window_start) >= WINDOW {
+            self.window_start = now;
+            self.requests = 0;
+            self.complexity = 0;
+        }
+        self.last_seen = now;
+        self.last_seen_at = Utc::now();
+    }
+
+    fn retry_after(&self, now: Instant) -> Duration {
+        WINDOW.saturating_sub(now.duration_since(self.window_start))
+    }
+}
+
+/// A principal's usage as reported to admins.
+#[derive(Clone, Debug)]
+pub struct UsageSnapshot {
+    pub principal: Principal,
+    pub requests_this_minute: u64,
+    pub complexity_this_minute: u64,
+    pub active_subscriptions: usize,
+    pub rejected: u64,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+/// The counters of every principal seen recently.
+pub struct Quotas {
+    config: QuotaConfig,
+    usage: Mutex<HashMap<Principal, Usage>>,
+}
+
+impl Quotas {
+    pub fn new(config: QuotaConfig) -> Self {
+        Self {
+            config,
+            usage: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> QuotaConfig {
+        self.config
+    }
+
+    /// Count a request; Err with the wait until the next window when the
+    /// principal is over its request quota.
+    pub fn charge_request(&self, principal: &Principal, now: Instant) -> Result<(), Duration> {
+        let mut usage = self.usage.lock();
+        let entry = usage
+            .entry(principal.clone())
+            .or_insert_with(|| Usage::new(now));
+        entry.roll(now);
+        let limit = self.config.requests_per_minute;
+        if limit > 0 && entry.requests >= limit {
+            entry.rejected += 1;
+            return Err(entry.retry_after(now));
+        }
+        entry.requests += 1;
+        Ok(())
+    }
+
+    /// Count a validated operation's complexity. An operation is refused
+    /// only once the budget is spent, so one large query always gets through.
+    pub fn charge_complexity(
+        &self,
+        principal: &Principal,
+        complexity: u64,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        let mut usage = self.usage.lock();
+        let entry = usage
+            .entry(principal.clone())
+            .or_insert_with(|| Usage::new(now));
+        entry.roll(now);
+        let limit = self.config.complexity_per_minute;
+        if limit > 0 && entry.complexity >= limit {
+            entry.rejected += 1;
+            return Err(entry.retry_after(now));
+        }
+        entry.complexity += complexity;
+        Ok(())
+    }
+
+    /// Take a subscription slot, released when the guard drops; None when
+    /// the principal already holds its maximum.
+    pub fn open_subscription(
+        self: &Arc<Self>,
+        principal: &Principal,
+        now: Instant,
+    ) -> Option<SubscriptionGuard> {
+        let mut usage = self.usage.lock();
+        let entry = usage
+            .entry(principal.clone())
+            .or_insert_with(|| Usage::new(now));
+        entry.roll(now);
+        let limit = self.config.max_subscriptions;
+        if limit > 0 && entry.subscriptions >= limit {
+            entry.rejected += 1;
+            return None;
+        }
+        entry.subscriptions += 1;
+        Some(SubscriptionGuard {
+            quotas: Arc::clone(self),
+            principal: principal.clone(),
+        })
+    }
+
+    fn close_subscription(&self, principal: &Principal) {
+        if let Some(entry) = self.usage.lock().get_mut(principal) {
+            entry.subscriptions = entry.subscriptions.saturating_sub(1);
+        }
+    }
+
+    /// Everyone's usage, busiest first. Counters of a window that has ended
+    /// read as zero.
+    pub fn snapshot(&self, now: Instant) -> Vec<UsageSnapshot> {
+        let usage = self.usage.lock();
+        let mut rows: Vec<UsageSnapshot> = usage
+            .iter()
+            .map(|(principal, u)| {
+                let current = now.duration_since(u.window_start) < WINDOW;
+                UsageSnapshot {
+                    principal: principal.clone(),
+                    requests_this_minute: if current { u.requests } else { 0 },
+                    complexity_this_minute: if current { u.complexity } else { 0 },
+                    active_subscriptions: u.subscriptions,
+                    rejected: u.rejected,
+                    last_seen_at: u.last_seen_at,
+                }
+            })
+            .collect();
+        rows.sort_by(|a, b| {
+            (b.requests_this_minute, b.complexity_this_minute)
+                .cmp(&(a.requests_this_minute, a.complexity_this_minute))
+                .then_with(|| b.last_seen_at.cmp(&a.last_seen_at))
+        });
+        rows
+    }
+
+    /// Forget principals idle for `max_idle` with no open subscription.
+    pub fn prune_idle(&self, max_idle: Duration, now: Instant) {
+        self.usage
+            .lock()
+            .retain(|_, u| u.subscriptions > 0 || now.duration_since(u.last_seen) < max_idle);
+    }
+}
+
+/// Holds one of a principal's subscription slots.
+pub struct SubscriptionGuard {
+    quotas: Arc<Quotas>,
+    principal: Principal,
+}
+
+impl Drop for SubscriptionGuard {
+    fn drop(&mut self) {
+        self.quotas.close_subscription(&self.principal);
+    }
+}
+
+static QUOTAS: LazyLock<Arc<Quotas>> =
+    LazyLock::new(|| Arc::new(Quotas::new(QuotaConfig::load(&mut Env::new()))));
+
+/// The process-wide quotas.
+pub fn quotas() -> &'static Arc<Quotas> {
+    &QUOTAS
+}
+
+/// Charge each GraphQL request to its principal and answer 429 over quota.
+/// Runs after the JWT middleware; the principal rides along in the request
+/// extensions for the GraphQL extension below.
+pub async fn quota_middleware(mut request: Request, next: Next) -> Response {
+    let principal = Principal::of_request(&request);
+    if let Err(retry_after) = quotas().charge_request(&principal, Instant::now()) {
+        tracing::warn!("Request quota exceeded for {principal}");
+        return (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(RETRY_AFTER, retry_after.as_secs().max(1).to_string())],
+            Json(serde_json::json!({ "error": "Too many requests" })),
+        )
+            .into_response();
+    }
+    request.extensions_mut().insert(principal);
+    next.run(request).await
+}
+
+/// Charges query complexity and caps concurrent subscriptions for the
+/// `Principal` in the request (or connection) data. Operations without one
+/// (in-process execution, tests) are not metered.
+pub struct QuotaExtension;
+
+impl ExtensionFactory for QuotaExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(QuotaExtension)
+    }
+}
+
+#[async_graphql::async_trait::async_trait]
+impl Extension for QuotaExtension {
+    async fn validation(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        next: NextValidation<'_>,
+    ) -> Result<ValidationResult, Vec<ServerError>> {
+        let result = next.run(ctx).await?;
+        if let Some(principal) = ctx.data_opt::<Principal>() {
+            if let Err(retry_after) =
+                quotas().charge_complexity(principal, result.complexity as u64, Instant::now())
+            {
+                tracing::warn!("Complexity quota exceeded for {principal}");
+                return Err(vec![ServerError::new(
+                    format!(
+                        "Query quota exceeded, retry in {}s",
+                        retry_after.as_secs().max(1)
+                    ),
+                    None,
+                )]);
+            }
+        }
+        Ok(result)
+    }
+
+    fn subscribe<'s>(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        stream: BoxStream<'s, GqlResponse>,
+        next: NextSubscribe<'_>,
+    ) -> BoxStream<'s, GqlResponse> {
+        let Some(principal) = ctx.data_opt::<Principal>() else {
+            return next.run(ctx, stream);
+        };
+        match quotas().open_subscription(principal, Instant::now()) {
+            Some(guard) => next
+                .run(ctx, stream)
+                .map(move |response| {
+                    let _slot = &guard;
+                    response
+                })
+                .boxed(),
+            None => {
+                tracing::warn!("Subscription quota exceeded for {principal}");
+                stream::once(async {
+                    GqlResponse::from_errors(vec![ServerError::new(
+                        "Too many open subscriptions",
+                        None,
+                    )])
+                })
+                .boxed()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quotas(requests: u64, complexity: u64, subscriptions: usize) -> Arc<Quotas> {
+        Arc::new(Quotas::new(QuotaConfig {
+            requests_per_minute: requests,
+            complexity_per_minute: complexity,
+            max_subscriptions: subscriptions,
+        }))
+    }
+
+    #[test]
+    fn requests_are_capped_per_window_and_principal() {
+        let q = quotas(2, 0, 0);
+        let kiosk = Principal::Ip("10.0.0.7".into());
+        let user = Principal::User(Uuid::new_v4());
+        let start = Instant::now();
+
+        assert!(q.charge_request(&kiosk, start).is_ok());
+        assert!(q.charge_request(&kiosk, start).is_ok());
+        let retry = q
+            .charge_request(&kiosk, start + Duration::from_secs(20))
+            .unwrap_err();
+        assert_eq!(retry, Duration::from_secs(40));
+        // Someone else is unaffected, and the next window starts afresh.
+        assert!(q.charge_request(&user, start).is_ok());
+        assert!(q.charge_request(&kiosk, start + WINDOW).is_ok());
+
+        let snapshot = q.snapshot(start + WINDOW);
+        let kiosk_row = snapshot.iter().find(|s| s.principal == kiosk).unwrap();
+        assert_eq!(kiosk_row.requests_this_minute, 1);
+        assert_eq!(kiosk_row.rejected, 1);
+    }
+
+    #[test]
+    fn complexity_is_refused_once_the_budget_is_spent() {
+        let q = quotas(0, 1_000, 0);
+        let user = Principal::User(Uuid::new_v4());
+        let now = Instant::now();
+
+        assert!(q.charge_complexity(&user, 900, now).is_ok());
+        // Over budget after this one, but it still runs.
+        assert!(q.charge_complexity(&user, 500, now).is_ok());
+        assert!(q.charge_complexity(&user, 1, now).is_err());
+    }
+
+    #[test]
+    fn subscription_slots_are_released_on_drop() {
+        let q = quotas(0, 0, 1);
+        let user = Principal::User(Uuid::new_v4());
+        let now = Instant::now();
+
+        let first = q.open_subscription(&user, now).unwrap();
+        assert!(q.open_subscription(&user, now).is_none());
+        drop(first);
+        assert!(q.open_subscription(&user, now).is_some());
+
+        // Idle principals are forgotten, unless they still hold a slot.
+        let idle = Principal::Ip("10.0.0.8".into());
+        q.charge_request(&idle, now).unwrap();
+        let _held = q.open_subscription(&user, now).unwrap();
+        q.prune_idle(Duration::from_secs(600), now + Duration::from_secs(601));
+        let left: Vec<_> = q.snapshot(now).into_iter().map(|s| s.principal).collect();
+        assert_eq!(left, vec![user]);
+    }
+}
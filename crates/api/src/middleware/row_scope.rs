@@ -0,0 +1,130 @@
+//! Runs every GraphQL operation under its viewer's row-level security scope
+//! (`infra::db::with_request_scope`), so the database itself hides other
+//! clubs' rows from a resolver that forgets a `club_id` filter:
+//!
+//! - a signed-in user acts for the clubs they manage or administer through
+//!   an organization, and for their own rows; club staff only reach the
+//!   rows their role needs (their shifts, the chat, the floor desk);
+//! - a paired display acts for its club;
+//! - an anonymous caller acts for nobody and only reaches public rows;
+//! - admins act for every club and run unscoped.
+//!
+//! A player's own mutation at a club (registering, cancelling, joining a
+//! group) widens its transaction to that club with `infra::db::act_for_club`
+//! once the resolver has checked it.
+//!
+//! The scope covers queries, mutations and subscription streams. Work a
+//! resolver hands to `tokio::spawn` (activity logging, notifications) and
+//! the schema's DataLoaders, whose batches are shared between requests and
+//! load rows by id, run unscoped.
+
+use std::sync::Arc;
+
+use async_graphql::extensions::{
+    Extension, ExtensionContext, ExtensionFactory, NextExecute, NextSubscribe,
+};
+use async_graphql::{Response as GqlResponse, ServerError};
+use futures_util::stream::{self, BoxStream, StreamExt};
+use infra::db::RowScope;
+use uuid::Uuid;
+
+use crate::auth::jwt::DisplayClaims;
+use crate::auth::Claims;
+use crate::gql::types::Role;
+use crate::state::AppState;
+
+pub struct RowScopeExtension;
+
+impl ExtensionFactory for RowScopeExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(RowScopeExtension)
+    }
+}
+
+#[async_graphql::async_trait::async_trait]
+impl Extension for RowScopeExtension {
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        next: NextExecute<'_>,
+    ) -> GqlResponse {
+        let Ok(state) = ctx.data::<AppState>() else {
+            return next.run(ctx, operation_name).await;
+        };
+        match viewer_scope(
+            state,
+            ctx.data_opt::<Claims>(),
+            ctx.data_opt::<DisplayClaims>(),
+        )
+        .await
+        {
+            Ok(Some(scope)) => {
+                infra::db::with_request_scope(scope, next.run(ctx, operation_name)).await
+            }
+            Ok(None) => next.run(ctx, operation_name).await,
+            Err(e) => scope_error(e),
+        }
+    }
+
+    fn subscribe<'s>(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        stream: BoxStream<'s, GqlResponse>,
+        next: NextSubscribe<'_>,
+    ) -> BoxStream<'s, GqlResponse> {
+        let Ok(state) = ctx.data::<AppState>().cloned() else {
+            return next.run(ctx, stream);
+        };
+        let claims = ctx.data_opt::<Claims>().cloned();
+        let display = ctx.data_opt::<DisplayClaims>().cloned();
+        let mut inner = Some(next.run(ctx, stream));
+        stream::once(async move { viewer_scope(&state, claims.as_ref(), display.as_ref()).await })
+            .flat_map(move |scope| {
+                let mut inner = stream::iter(inner.take()).flatten().boxed();
+                match scope {
+                    // Each poll of the subscription runs under the scope.
+                    Ok(Some(scope)) => stream::poll_fn(move |cx| {
+                        infra::db::with_request_scope_sync(scope.clone(), || {
+                            inner.poll_next_unpin(cx)
+                        })
+                    })
+                    .boxed(),
+                    Ok(None) => inner,
+                    Err(e) => stream::once(async move { scope_error(e) }).boxed(),
+                }
+            })
+            .boxed()
+    }
+}
+
+/// The scope for a request by `claims` or a paired `display`; `None` for
+/// admins.
+async fn viewer_scope(
+    state: &AppState,
+    claims: Option<&Claims>,
+    display: Option<&DisplayClaims>,
+) -> Result<Option<RowScope>, sqlx::Error> {
+    if let Some(claims) = claims {
+        if Role::from(claims.role.clone()) == Role::Admin {
+            return Ok(None);
+        }
+        let Ok(user_id) = Uuid::parse_str(&claims.sub) else {
+            return Ok(Some(RowScope::default()));
+        };
+        let club_ids = infra::repos::club_managers::acting_club_ids(&state.db, user_id).await?;
+        return Ok(Some(RowScope {
+            club_ids,
+            user_id: Some(user_id),
+        }));
+    }
+    if let Some(display) = display {
+        return Ok(Some(RowScope::club(display.club_id)));
+    }
+    Ok(Some(RowScope::default()))
+}
+
+fn scope_error(e: sqlx::Error) -> GqlResponse {
+    tracing::error!("Failed to load the request's row scope: {e}");
+    GqlResponse::from_errors(vec![ServerError::new("Failed to verify permissions", None)])
+}
@@ -19,6 +19,8 @@ use axum::{
 use tracing::Instrument;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
+use crate::config::Env;
+
 static REQUESTS_TOTAL: AtomicU64 = AtomicU64::new(0);
 static RESPONSES_2XX: AtomicU64 = AtomicU64::new(0);
 static RESPONSES_3XX: AtomicU64 = AtomicU64::new(0);
@@ -29,29 +31,45 @@ static START: OnceLock<Instant> = OnceLock::new();
 
 const REQUEST_ID_HEADER: &str = "x-request-id";
 
-/// Initialize Sentry error reporting when `SENTRY_DSN` is set; otherwise a no-op
+/// Sentry settings; present when `SENTRY_DSN` is set.
+///
+/// Tunables: `SENTRY_ENVIRONMENT` (default `production`),
+/// `SENTRY_TRACES_SAMPLE_RATE` (default `0.0` — errors only, no perf tracing).
+#[derive(Clone)]
+pub struct SentryConfig {
+    pub dsn: String,
+    pub environment: String,
+    pub traces_sample_rate: f32,
+}
+
+impl SentryConfig {
+    pub fn load(env: &mut Env) -> Option<Self> {
+        let dsn = env.secret("SENTRY_DSN");
+        let environment = env.string_or("SENTRY_ENVIRONMENT", "production");
+        let traces_sample_rate = env.parse_or("SENTRY_TRACES_SAMPLE_RATE", 0.0);
+        Some(Self {
+            dsn: dsn?,
+            environment,
+            traces_sample_rate,
+        })
+    }
+}
+
+/// Initialize Sentry error reporting when configured; otherwise a no-op
 /// returning `None` (opt-in, mirroring the data-retention sweep). The returned
 /// guard MUST be held for the process lifetime — dropping it flushes and
 /// disables reporting. Call this before [`init_tracing`] so the Sentry tracing
 /// layer has a client to send `error!`/`warn!` events and panics to.
-///
-/// Tunables: `SENTRY_ENVIRONMENT` (default `production`),
-/// `SENTRY_TRACES_SAMPLE_RATE` (default `0.0` — errors only, no perf tracing).
 #[must_use]
-pub fn init_sentry() -> Option<sentry::ClientInitGuard> {
-    let dsn = std::env::var("SENTRY_DSN").ok().filter(|s| !s.is_empty())?;
-    let environment = std::env::var("SENTRY_ENVIRONMENT").unwrap_or_else(|_| "production".into());
-    let traces_sample_rate = std::env::var("SENTRY_TRACES_SAMPLE_RATE")
-        .ok()
-        .and_then(|v| v.parse::<f32>().ok())
-        .unwrap_or(0.0);
+pub fn init_sentry(config: Option<&SentryConfig>) -> Option<sentry::ClientInitGuard> {
+    let config = config?;
 
     Some(sentry::init((
-        dsn,
+        config.dsn.as_str(),
         sentry::ClientOptions {
             release: sentry::release_name!(),
-            environment: Some(environment.into()),
-            traces_sample_rate,
+            environment: Some(config.environment.clone().into()),
+            traces_sample_rate: config.traces_sample_rate,
             // Player/manager PII (emails, names) must not ride along with events.
             send_default_pii: false,
             ..Default::default()
@@ -59,17 +77,14 @@ pub fn init_sentry() -> Option<sentry::ClientInitGuard> {
     )))
 }
 
-/// Initialize tracing. `LOG_FORMAT=json` emits structured JSON lines (for log
-/// aggregation in production); anything else keeps the human-readable format.
+/// Initialize tracing. `json` (`LOG_FORMAT=json`) emits structured JSON lines
+/// (for log aggregation in production); otherwise the human-readable format.
 /// Also stamps the process start instant for the uptime metric. A Sentry layer
 /// is always attached but is inert unless [`init_sentry`] installed a client.
-pub fn init_tracing() {
+pub fn init_tracing(json: bool) {
     let _ = START.set(Instant::now());
 
     let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
-    let json = std::env::var("LOG_FORMAT")
-        .map(|v| v == "json")
-        .unwrap_or(false);
 
     let registry = tracing_subscriber::registry()
         .with(filter)
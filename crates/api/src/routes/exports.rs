@@ -0,0 +1,251 @@
+//! Club CSV exports, streamed from Postgres to the response.
+//!
+//! Rows are read with `fetch()` in a background task, written as CSV into
+//! chunks of about [`CHUNK_BYTES`] and handed to a chunked-transfer body
+//! through a small bounded channel: a multi-season export holds a few chunks
+//! in memory, never the whole file, and a slow client slows the query down
+//! instead of letting rows pile up.
+
+use std::io;
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{Path, Query, State},
+    http::header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_TYPE},
+    response::{IntoResponse, Response},
+    Extension,
+};
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use futures_util::{stream::BoxStream, StreamExt};
+use serde::Deserialize;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use uuid::Uuid;
+
+use crate::auth::jwt::Claims;
+use crate::error::AppError;
+use crate::gql::types::Role;
+use crate::state::AppState;
+use infra::repos::{activity_log, club_managers, clubs, tournament_results};
+
+/// Rows are buffered until a chunk reaches about this size.
+const CHUNK_BYTES: usize = 64 * 1024;
+/// Chunks queued between the query task and the response body.
+const CHUNKS_IN_FLIGHT: usize = 4;
+
+/// Optional date range, both ends inclusive (`?from=2025-01-01&to=2025-12-31`).
+#[derive(Deserialize)]
+pub struct ExportRange {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+impl ExportRange {
+    /// `[from, to)` bounds at UTC midnight.
+    fn bounds(&self) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>) {
+        let midnight = |day: NaiveDate| day.and_hms_opt(0, 0, 0).map(|t| t.and_utc());
+        (
+            self.from.and_then(midnight),
+            self.to
+                .and_then(|day| day.checked_add_days(Days::new(1)))
+                .and_then(midnight),
+        )
+    }
+}
+
+/// Every result of the club's tournaments, one row per finishing player.
+/// Managers of the club only.
+pub async fn club_results(
+    State(state): State<AppState>,
+    Path(club_id): Path<Uuid>,
+    Query(range): Query<ExportRange>,
+    claims: Option<Extension<Claims>>,
+) -> Result<Response, AppError> {
+    require_club_manager(&state, claims, club_id).await?;
+    let (from, to) = range.bounds();
+
+    let (tx, rx) = mpsc::channel(CHUNKS_IN_FLIGHT);
+    let db = state.db.clone();
+    tokio::spawn(async move {
+        let rows = tournament_results::stream_club_export(&db, club_id, from, to);
+        write_csv(
+            rows,
+            &[
+                "tournament_id",
+                "tournament_name",
+                "start_time",
+                "final_position",
+                "club_player_id",
+                "display_name",
+                "prize_cents",
+                "points",
+            ],
+            |row| {
+                vec![
+                    row.tournament_id.to_string(),
+                    row.tournament_name,
+                    row.start_time.to_rfc3339(),
+                    row.final_position.to_string(),
+                    row.club_player_id.to_string(),
+                    row.display_name,
+                    row.prize_cents.to_string(),
+                    row.points.to_string(),
+                ]
+            },
+            tx,
+        )
+        .await;
+    });
+
+    Ok(csv_response("results.csv", rx))
+}
+
+/// The activity log of all the club's tournaments, oldest first. Managers of
+/// the club only.
+pub async fn club_activity(
+    State(state): State<AppState>,
+    Path(club_id): Path<Uuid>,
+    Query(range): Query<ExportRange>,
+    claims: Option<Extension<Claims>>,
+) -> Result<Response, AppError> {
+    require_club_manager(&state, claims, club_id).await?;
+    let (from, to) = range.bounds();
+
+    let (tx, rx) = mpsc::channel(CHUNKS_IN_FLIGHT);
+    let db = state.db.clone();
+    tokio::spawn(async move {
+        let rows = activity_log::stream_club_export(&db, club_id, from, to);
+        write_csv(
+            rows,
+            &[
+                "event_time",
+                "tournament_id",
+                "tournament_name",
+                "event_category",
+                "event_action",
+                "actor_id",
+                "subject_id",
+                "metadata",
+            ],
+            |row| {
+                let id = |id: Option<Uuid>| id.map(|id| id.to_string()).unwrap_or_default();
+                vec![
+                    row.event_time.to_rfc3339(),
+                    row.tournament_id.to_string(),
+                    row.tournament_name,
+                    row.event_category,
+                    row.event_action,
+                    id(row.actor_id),
+                    id(row.subject_id),
+                    row.metadata.to_string(),
+                ]
+            },
+            tx,
+        )
+        .await;
+    });
+
+    Ok(csv_response("activity-log.csv", rx))
+}
+
+async fn require_club_manager(
+    state: &AppState,
+    claims: Option<Extension<Claims>>,
+    club_id: Uuid,
+) -> Result<(), AppError> {
+    let Some(Extension(claims)) = claims else {
+        return Err(AppError::Unauthorized(
+            "Authentication required".to_string(),
+        ));
+    };
+    clubs::get_by_id(&state.db, club_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Club not found".to_string()))?;
+
+    if Role::from(claims.role.clone()) == Role::Admin {
+        return Ok(());
+    }
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::Unauthorized("Invalid user ID".to_string()))?;
+    if !club_managers::is_club_manager(&state.db, user_id, club_id).await? {
+        return Err(AppError::Forbidden(
+            "You are not authorized to manage this club".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Write `rows` as CSV into `tx`, one chunk at a time. A database error
+/// mid-export aborts the body, so the client sees a truncated transfer rather
+/// than a silently short file; a client that hangs up stops the query.
+async fn write_csv<R>(
+    mut rows: BoxStream<'_, sqlx::Result<R>>,
+    header: &[&str],
+    record: fn(R) -> Vec<String>,
+    tx: mpsc::Sender<io::Result<Bytes>>,
+) {
+    let mut writer = chunk_writer();
+    let mut written = writer.write_record(header);
+    while written.is_ok() {
+        match rows.next().await {
+            Some(Ok(row)) => written = writer.write_record(record(row)),
+            Some(Err(e)) => {
+                tracing::error!("Export query failed: {}", e);
+                let _ = tx.send(Err(io::Error::other(e))).await;
+                return;
+            }
+            None => {
+                send_chunk(writer, &tx).await;
+                return;
+            }
+        }
+        if writer.get_ref().len() >= CHUNK_BYTES {
+            match send_chunk(writer, &tx).await {
+                Some(next) => writer = next,
+                None => return,
+            }
+        }
+    }
+    if let Err(e) = written {
+        tracing::error!("Export serialization failed: {}", e);
+        let _ = tx.send(Err(io::Error::other(e))).await;
+    }
+}
+
+fn chunk_writer() -> csv::Writer<Vec<u8>> {
+    csv::Writer::from_writer(Vec::with_capacity(CHUNK_BYTES))
+}
+
+/// Send what the writer holds and return a fresh one, or `None` once the
+/// client has gone.
+async fn send_chunk(
+    writer: csv::Writer<Vec<u8>>,
+    tx: &mpsc::Sender<io::Result<Bytes>>,
+) -> Option<csv::Writer<Vec<u8>>> {
+    let chunk = match writer.into_inner() {
+        Ok(chunk) => chunk,
+        Err(e) => {
+            let _ = tx.send(Err(e.into_error())).await;
+            return None;
+        }
+    };
+    if !chunk.is_empty() && tx.send(Ok(Bytes::from(chunk))).await.is_err() {
+        return None;
+    }
+    Some(chunk_writer())
+}
+
+fn csv_response(filename: &str, rx: mpsc::Receiver<io::Result<Bytes>>) -> Response {
+    (
+        [
+            (CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+            (
+                CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{filename}\""),
+            ),
+            (CACHE_CONTROL, "private, no-store".to_string()),
+        ],
+        Body::from_stream(ReceiverStream::new(rx)),
+    )
+        .into_response()
+}
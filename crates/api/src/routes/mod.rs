@@ -1,5 +1,10 @@
 pub mod auth;
+pub mod exports;
 pub mod health;
 pub mod oauth_server;
+pub mod printouts;
+pub mod receipts;
+pub mod seat_slips;
+pub mod stat_cards;
 pub mod token;
 pub mod unified_auth;
@@ -0,0 +1,77 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_TYPE},
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::gql::domains::printouts::link::{self, LinkScope};
+use crate::gql::domains::printouts::types::PrintoutKind;
+use crate::state::AppState;
+
+#[derive(Deserialize)]
+pub struct DownloadQuery {
+    pub expires: i64,
+    pub signature: String,
+}
+
+/// Serve a printout PDF to anyone holding a valid signed link.
+pub async fn download(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<DownloadQuery>,
+) -> Result<Response, AppError> {
+    let secret = &state.auth_config().jwt_secret;
+    if !link::verify(
+        secret,
+        LinkScope::Printout,
+        id,
+        query.expires,
+        &query.signature,
+        Utc::now(),
+    ) {
+        return Err(AppError::Unauthorized(
+            "Invalid or expired download link".to_string(),
+        ));
+    }
+
+    let printout = infra::repos::tournament_printouts::get_content(&state.db, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Printout not found".to_string()))?;
+
+    let filename = format!(
+        "{}-{}.pdf",
+        slug(&printout.tournament_name),
+        PrintoutKind::from_db(&printout.kind).file_stem()
+    );
+    Ok((
+        [
+            (CONTENT_TYPE, "application/pdf".to_string()),
+            (
+                CONTENT_DISPOSITION,
+                format!("inline; filename=\"{filename}\""),
+            ),
+            (CACHE_CONTROL, "private, no-store".to_string()),
+        ],
+        printout.content,
+    )
+        .into_response())
+}
+
+/// ASCII filename stem: letters and digits, runs of anything else as `-`.
+pub(crate) fn slug(name: &str) -> String {
+    let slug = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase();
+    if slug.is_empty() {
+        "tournament".to_string()
+    } else {
+        slug
+    }
+}
@@ -0,0 +1,62 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_TYPE},
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::printouts::DownloadQuery;
+use crate::error::AppError;
+use crate::gql::domains::printouts::escpos;
+use crate::gql::domains::printouts::link::{self, LinkScope};
+use crate::state::AppState;
+use infra::repos::entry_receipts;
+
+/// Serve a buy-in receipt as raw ESC/POS bytes to anyone holding a valid
+/// signed link. Every fetch counts as a print; reprints carry a COPY banner.
+pub async fn print(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<DownloadQuery>,
+) -> Result<Response, AppError> {
+    let secret = &state.auth_config().jwt_secret;
+    if !link::verify(
+        secret,
+        LinkScope::Receipt,
+        id,
+        query.expires,
+        &query.signature,
+        Utc::now(),
+    ) {
+        return Err(AppError::Unauthorized(
+            "Invalid or expired print link".to_string(),
+        ));
+    }
+
+    let receipt = entry_receipts::get_by_id(&state.db, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Receipt not found".to_string()))?;
+    if receipt.entry_id.is_none() {
+        return Err(AppError::BadRequest(
+            "This receipt was voided: its entry was deleted".to_string(),
+        ));
+    }
+    let receipt = entry_receipts::mark_printed(&state.db, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Receipt not found".to_string()))?;
+
+    let filename = format!("receipt-{}.bin", receipt.verification_code);
+    Ok((
+        [
+            (CONTENT_TYPE, "application/octet-stream".to_string()),
+            (
+                CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{filename}\""),
+            ),
+            (CACHE_CONTROL, "private, no-store".to_string()),
+        ],
+        escpos::receipt(&receipt, receipt.print_count > 1),
+    )
+        .into_response())
+}
@@ -0,0 +1,55 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_TYPE},
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::printouts::DownloadQuery;
+use crate::error::AppError;
+use crate::gql::domains::printouts::escpos;
+use crate::gql::domains::printouts::link::{self, LinkScope};
+use crate::state::AppState;
+use infra::repos::seat_slip_jobs;
+
+/// Serve a seat slip as raw ESC/POS bytes to anyone holding a valid signed
+/// link. Fetching doesn't change the job: the print agent reports the
+/// outcome with `completeSeatSlipPrintJob`.
+pub async fn print(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<DownloadQuery>,
+) -> Result<Response, AppError> {
+    let secret = &state.auth_config().jwt_secret;
+    if !link::verify(
+        secret,
+        LinkScope::SeatSlip,
+        id,
+        query.expires,
+        &query.signature,
+        Utc::now(),
+    ) {
+        return Err(AppError::Unauthorized(
+            "Invalid or expired print link".to_string(),
+        ));
+    }
+
+    let job = seat_slip_jobs::get_by_id(&state.db, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Seat slip not found".to_string()))?;
+
+    let filename = format!("seat-slip-t{}-s{}.bin", job.table_number, job.seat_number);
+    Ok((
+        [
+            (CONTENT_TYPE, "application/octet-stream".to_string()),
+            (
+                CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{filename}\""),
+            ),
+            (CACHE_CONTROL, "private, no-store".to_string()),
+        ],
+        escpos::seat_slip(&job),
+    )
+        .into_response())
+}
@@ -0,0 +1,57 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_TYPE},
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::printouts::{slug, DownloadQuery};
+use crate::error::AppError;
+use crate::gql::domains::printouts::link::{self, LinkScope};
+use crate::state::AppState;
+use infra::repos::player_stat_cards;
+
+/// Serve a stat card PNG to anyone holding a valid signed link, shown inline
+/// so the link can be opened and saved from a phone.
+pub async fn image(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<DownloadQuery>,
+) -> Result<Response, AppError> {
+    let secret = &state.auth_config().jwt_secret;
+    if !link::verify(
+        secret,
+        LinkScope::StatCard,
+        id,
+        query.expires,
+        &query.signature,
+        Utc::now(),
+    ) {
+        return Err(AppError::Unauthorized(
+            "Invalid or expired image link".to_string(),
+        ));
+    }
+
+    let card = player_stat_cards::get_content(&state.db, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Stat card not found".to_string()))?;
+
+    let filename = format!(
+        "{}-{}.png",
+        slug(&card.display_name),
+        card.month.format("%Y-%m")
+    );
+    Ok((
+        [
+            (CONTENT_TYPE, "image/png".to_string()),
+            (
+                CONTENT_DISPOSITION,
+                format!("inline; filename=\"{filename}\""),
+            ),
+            (CACHE_CONTROL, "private, max-age=3600".to_string()),
+        ],
+        card.content,
+    )
+        .into_response())
+}
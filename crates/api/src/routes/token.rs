@@ -4,11 +4,12 @@ use axum::{
     response::IntoResponse,
     Json,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::auth::cookie::{build_clear_cookie, build_refresh_cookie, extract_refresh_token};
-use crate::auth::refresh;
+use crate::auth::{login_link, refresh};
 use crate::error::AppError;
+use crate::gql::types::User;
 use crate::state::AppState;
 
 /// Header native clients use to present the raw refresh token, since they have
@@ -63,16 +64,11 @@ pub async fn refresh_handler(
     )
     .await?;
 
-    // Look up the user to generate a new JWT
-    let user_row = infra::repos::users::get_by_id(&state.db, result.user_id)
-        .await
-        .map_err(|e| AppError::Internal(format!("DB error: {}", e)))?
-        .ok_or_else(|| AppError::Unauthorized("User not found".to_string()))?;
-
-    let role_str: String = crate::gql::types::Role::from(user_row.role).into();
+    // Mint a JWT from the user's current role and club assignments
     let token = state
         .jwt_service()
-        .create_token(result.user_id, user_row.email, role_str)?;
+        .create_token(&state.db, result.user_id)
+        .await?;
 
     // Build new refresh cookie
     let auth_config = state.auth_config();
@@ -83,13 +79,7 @@ pub async fn refresh_handler(
     } else {
         None
     };
-    let cookie_value = build_refresh_cookie(
-        &result.new_raw_token,
-        max_age_secs,
-        &auth_config.cookie_domain,
-        auth_config.cookie_secure,
-        &auth_config.cookie_path,
-    );
+    let cookie_value = build_refresh_cookie(&result.new_raw_token, max_age_secs, auth_config);
 
     // Native callers persist the rotated token from the body; web callers use
     // the Set-Cookie header below (which we still send harmlessly either way).
@@ -110,6 +100,72 @@ pub async fn refresh_handler(
     Ok(response)
 }
 
+#[derive(Deserialize)]
+pub struct LoginLinkRequest {
+    pub token: String,
+    /// When true, the refresh cookie persists across browser sessions.
+    #[serde(default)]
+    pub remember_me: bool,
+    /// Native apps get the raw refresh token in the body (see `login_user`).
+    #[serde(default)]
+    pub native_client: bool,
+}
+
+#[derive(Serialize)]
+pub struct LoginLinkResponse {
+    pub token: String,
+    pub user: User,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+}
+
+/// Exchange an emailed login link for a session: a JWT plus a refresh token,
+/// exactly as a password login issues them. Each link works once.
+pub async fn login_link_handler(
+    State(state): State<AppState>,
+    Json(body): Json<LoginLinkRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = login_link::redeem(&state.db, &body.token).await?;
+
+    // Refuses deactivated accounts
+    let token = state.jwt_service().create_token(&state.db, user_id).await?;
+
+    let user_row = infra::repos::users::get_by_id(&state.db, user_id)
+        .await?
+        .ok_or_else(|| AppError::Unauthorized("User not found".to_string()))?;
+
+    let auth_config = state.auth_config();
+    let raw_refresh = refresh::create_refresh_token(
+        &state.db,
+        user_id,
+        auth_config.refresh_token_expiration_days,
+        body.remember_me,
+    )
+    .await?;
+
+    let max_age_secs = if body.remember_me {
+        Some(auth_config.refresh_token_expiration_days * 24 * 60 * 60)
+    } else {
+        None
+    };
+    let cookie_value = build_refresh_cookie(&raw_refresh, max_age_secs, auth_config);
+
+    let mut response = Json(LoginLinkResponse {
+        token,
+        user: User::from(user_row).with_contact_details(),
+        refresh_token: body.native_client.then_some(raw_refresh),
+    })
+    .into_response();
+    response.headers_mut().insert(
+        SET_COOKIE,
+        cookie_value
+            .parse()
+            .map_err(|_| AppError::Internal("Failed to build cookie header".to_string()))?,
+    );
+
+    Ok(response)
+}
+
 pub async fn logout_handler(
     State(state): State<AppState>,
     req: axum::extract::Request,
@@ -122,11 +178,7 @@ pub async fn logout_handler(
 
     // Clear the cookie
     let auth_config = state.auth_config();
-    let cookie_value = build_clear_cookie(
-        &auth_config.cookie_domain,
-        auth_config.cookie_secure,
-        &auth_config.cookie_path,
-    );
+    let cookie_value = build_clear_cookie(auth_config);
 
     let mut response = axum::http::StatusCode::OK.into_response();
     response.headers_mut().insert(
@@ -0,0 +1,48 @@
+use std::time::Duration;
+use tokio::time::{interval, Interval};
+use tracing::{error, info};
+
+use crate::gql::domains::registrations::alternates::sweep;
+use crate::services::heartbeat;
+use crate::AppState;
+
+// Eliminations come in at the pace of the floor; a seat freed for a few
+// seconds before its alternate is called is fine.
+const SWEEP_INTERVAL_SECONDS: u64 = 15;
+
+/// Background job that checks eliminations against the alternates queue of
+/// every tournament in late registration and seats the next alternates into
+/// the freed seats.
+pub struct AlternateSeatingService {
+    state: AppState,
+    interval: Interval,
+}
+
+impl AlternateSeatingService {
+    pub fn new(state: AppState) -> Self {
+        Self {
+            state,
+            interval: interval(Duration::from_secs(SWEEP_INTERVAL_SECONDS)),
+        }
+    }
+
+    pub async fn run(&mut self) {
+        info!("Starting alternate seating service");
+        loop {
+            self.interval.tick().await;
+            heartbeat::tick("alternate_seating_service");
+            match sweep(&self.state.db).await {
+                Ok(seated) if seated > 0 => info!("Seated {} alternate(s)", seated),
+                Ok(_) => {}
+                Err(e) => error!("Error seating alternates: {}", e),
+            }
+        }
+    }
+}
+
+pub fn spawn_alternate_seating_service(state: AppState) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut service = AlternateSeatingService::new(state);
+        service.run().await;
+    })
+}
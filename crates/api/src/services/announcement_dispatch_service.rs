@@ -0,0 +1,47 @@
+use std::time::Duration;
+use tokio::time::{interval, Interval};
+use tracing::{error, info};
+
+use crate::gql::domains::announcements::service::dispatch_due;
+use crate::services::heartbeat;
+use crate::AppState;
+
+// Scheduled floor broadcasts are minute-granular ("dinner break in 10 min");
+// a 15 s tick keeps them close to on time without hammering the table.
+const DISPATCH_INTERVAL_SECONDS: u64 = 15;
+
+/// Background job that sends scheduled floor broadcasts once they are due.
+/// Claiming uses `SKIP LOCKED`, so running it on every instance is safe.
+pub struct AnnouncementDispatchService {
+    state: AppState,
+    interval: Interval,
+}
+
+impl AnnouncementDispatchService {
+    pub fn new(state: AppState) -> Self {
+        Self {
+            state,
+            interval: interval(Duration::from_secs(DISPATCH_INTERVAL_SECONDS)),
+        }
+    }
+
+    pub async fn run(&mut self) {
+        info!("Starting announcement dispatch service");
+        loop {
+            self.interval.tick().await;
+            heartbeat::tick("announcement_dispatch_service");
+            match dispatch_due(&self.state, chrono::Utc::now()).await {
+                Ok(sent) if sent > 0 => info!("Sent {} scheduled announcement(s)", sent),
+                Ok(_) => {}
+                Err(e) => error!("Error dispatching scheduled announcements: {}", e),
+            }
+        }
+    }
+}
+
+pub fn spawn_announcement_dispatch_service(state: AppState) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut service = AnnouncementDispatchService::new(state);
+        service.run().await;
+    })
+}
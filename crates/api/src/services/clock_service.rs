@@ -5,8 +5,11 @@ use tokio::time::{interval, Interval};
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
+use crate::gql::domains::tournaments::clock::load_elimination_pace;
 use crate::gql::subscriptions::{cleanup_inactive_channels, publish_clock_update};
 use crate::gql::types::{ClockStatus, TournamentClock, TournamentStructure};
+use crate::middleware::quota::quotas;
+use crate::services::heartbeat;
 use crate::AppState;
 use infra::repos::{
     tournament_clock, tournament_clock::ClockStatus as InfraClockStatus, tournaments,
@@ -18,6 +21,8 @@ const STALE_CHECK_INTERVAL: u64 = 60;
 const STALE_TOURNAMENT_HOURS: i32 = 24;
 // Remove subscription channels inactive for more than 2 hours
 const INACTIVE_CHANNEL_HOURS: i64 = 2;
+// Drop quota counters of principals idle for more than 10 minutes
+const QUOTA_IDLE: Duration = Duration::from_secs(600);
 
 pub struct ClockService {
     state: AppState,
@@ -43,6 +48,7 @@ impl ClockService {
 
         loop {
             self.interval.tick().await;
+            heartbeat::tick("clock_service");
             self.tick_count += 1;
 
             if let Err(e) = self.process_tournaments().await {
@@ -67,8 +73,24 @@ impl ClockService {
                     error!("Error cleaning up expired password reset tokens: {}", e);
                 }
 
+                // Clean up expired login links
+                if let Err(e) =
+                    infra::repos::login_link_tokens::delete_expired(&self.state.db).await
+                {
+                    error!("Error cleaning up expired login links: {}", e);
+                }
+
+                // Forget expired proof-of-work challenges
+                if let Err(e) = infra::repos::spent_challenges::delete_expired(&self.state.db).await
+                {
+                    error!("Error cleaning up spent challenges: {}", e);
+                }
+
                 // Clean up inactive subscription channels to prevent memory leaks
                 cleanup_inactive_channels(INACTIVE_CHANNEL_HOURS);
+
+                // Forget quota counters of principals gone quiet
+                quotas().prune_idle(QUOTA_IDLE, std::time::Instant::now());
             }
         }
     }
@@ -82,6 +104,16 @@ impl ClockService {
             match tournament_clock::advance_level(&self.state.db, tournament_id, true, None).await {
                 Ok(clock_row) => {
                     info!("Auto-advanced level for tournament {}", tournament_id);
+                    crate::gql::domains::activity_log::log_and_publish(
+                        &self.state.db,
+                        tournament_id,
+                        "clock",
+                        "auto_advance",
+                        None,
+                        None,
+                        serde_json::json!({ "level_number": clock_row.current_level }),
+                    )
+                    .await;
 
                     // Auto-close late registration if configured. Done before
                     // publishing the clock update so a client refetch triggered
@@ -227,6 +259,13 @@ impl ClockService {
         let total_pause_seconds = clock_row.total_pause_duration.microseconds / 1_000_000;
 
         let status: ClockStatus = clock_status.into();
+        let pace = load_elimination_pace(
+            &self.state.db,
+            tournament_id,
+            clock_row.current_level,
+            time_remaining,
+        )
+        .await;
 
         Ok(Some(TournamentClock {
             id: clock_row.id.into(),
@@ -247,6 +286,7 @@ impl ClockService {
             ante: structure.as_ref().map(|s| s.ante),
             is_break: structure.as_ref().map(|s| s.is_break),
             level_duration_minutes: structure.as_ref().map(|s| s.duration_minutes),
+            pace,
         }))
     }
 }
@@ -1,10 +1,16 @@
 use std::time::Duration;
 
-use infra::repos::{device_tokens, refresh_tokens, users};
+use infra::repos::{
+    auth_identities, contact_change_requests, device_tokens, refresh_tokens, retention,
+    user_audit_log, users,
+};
 use tokio::time::{interval, Interval};
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
+use crate::config::Env;
+use crate::gql::domains::retention::service::apply_policy;
+use crate::services::heartbeat;
 use crate::AppState;
 
 // Defaults: sweep daily, anonymize player accounts dormant for ~3 years, and
@@ -14,9 +20,10 @@ const DEFAULT_INTERVAL_HOURS: u64 = 24;
 const DEFAULT_RETENTION_DAYS: i32 = 1095;
 const DEFAULT_BATCH_LIMIT: i64 = 200;
 
-/// Configuration, read once at startup. The service is only spawned when
-/// `ENABLE_DATA_RETENTION` is truthy (gated in `main.rs`), so this struct
-/// always represents an explicitly enabled job.
+/// Configuration, read once at startup. Only loaded when
+/// `ENABLE_DATA_RETENTION` is on, so this struct always represents an
+/// explicitly enabled job.
+#[derive(Clone, Debug)]
 pub struct RetentionConfig {
     pub retention_days: i32,
     pub batch_limit: i64,
@@ -24,41 +31,30 @@ pub struct RetentionConfig {
 }
 
 impl RetentionConfig {
-    pub fn from_env() -> Self {
-        let retention_days = env_parse("DATA_RETENTION_DAYS").unwrap_or(DEFAULT_RETENTION_DAYS);
-        let batch_limit = env_parse("DATA_RETENTION_BATCH_LIMIT").unwrap_or(DEFAULT_BATCH_LIMIT);
+    /// `None` unless the sweep is enabled. Defaults to OFF — anonymization
+    /// is destructive, so it must be deliberately turned on per environment.
+    pub fn load(env: &mut Env) -> Option<Self> {
+        if !env.flag("ENABLE_DATA_RETENTION", false) {
+            return None;
+        }
+        let retention_days: i32 = env.parse_or("DATA_RETENTION_DAYS", DEFAULT_RETENTION_DAYS);
+        let batch_limit: i64 = env.parse_or("DATA_RETENTION_BATCH_LIMIT", DEFAULT_BATCH_LIMIT);
         let interval_hours: u64 =
-            env_parse("DATA_RETENTION_INTERVAL_HOURS").unwrap_or(DEFAULT_INTERVAL_HOURS);
+            env.parse_or("DATA_RETENTION_INTERVAL_HOURS", DEFAULT_INTERVAL_HOURS);
 
-        Self {
+        Some(Self {
             retention_days: retention_days.max(1),
             batch_limit: batch_limit.clamp(1, 10_000),
             interval: Duration::from_secs(interval_hours.max(1) * 3600),
-        }
+        })
     }
 }
 
-fn env_parse<T: std::str::FromStr>(key: &str) -> Option<T> {
-    std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
-}
-
-/// Whether the retention sweep is enabled. Defaults to OFF — anonymization is
-/// destructive, so it must be deliberately turned on per environment.
-pub fn is_enabled() -> bool {
-    matches!(
-        std::env::var("ENABLE_DATA_RETENTION")
-            .unwrap_or_default()
-            .trim()
-            .to_ascii_lowercase()
-            .as_str(),
-        "1" | "true" | "yes" | "on"
-    )
-}
-
 /// Scheduled GDPR data-retention sweep: anonymizes player accounts with no
 /// activity inside the retention window, reusing the same anonymization path as
 /// self-service account deletion (scrub PII, keep the row for result integrity,
-/// then revoke the account's tokens).
+/// then revoke the account's tokens), then applies each club's retention
+/// policy to its activity log, broadcasts and chat.
 pub struct DataRetentionService {
     state: AppState,
     config: RetentionConfig,
@@ -66,8 +62,7 @@ pub struct DataRetentionService {
 }
 
 impl DataRetentionService {
-    pub fn new(state: AppState) -> Self {
-        let config = RetentionConfig::from_env();
+    pub fn new(state: AppState, config: RetentionConfig) -> Self {
         Self {
             interval: interval(config.interval),
             state,
@@ -84,11 +79,15 @@ impl DataRetentionService {
         );
         loop {
             self.interval.tick().await;
+            heartbeat::tick("data_retention_service");
             match self.sweep().await {
                 Ok(0) => {}
                 Ok(n) => info!("Data retention: anonymized {n} dormant account(s)"),
                 Err(e) => error!("Data retention sweep failed: {e}"),
             }
+            if let Err(e) = self.apply_club_policies().await {
+                error!("Data retention: listing club policies failed: {e}");
+            }
         }
     }
 
@@ -113,18 +112,51 @@ impl DataRetentionService {
         Ok(processed)
     }
 
+    /// Apply every club's retention policy, least recently applied first.
+    async fn apply_club_policies(&self) -> Result<(), sqlx::Error> {
+        for policy in retention::list_active_policies(&self.state.db).await? {
+            match apply_policy(&self.state.db, &policy, false).await {
+                Ok(lines) => {
+                    let rows: i64 = lines.iter().map(|l| l.rows).sum();
+                    if rows > 0 {
+                        info!(
+                            "Data retention: {} {rows} record(s) of club {}",
+                            if policy.mode == "purge" {
+                                "purged"
+                            } else {
+                                "anonymized"
+                            },
+                            policy.club_id
+                        );
+                    }
+                }
+                Err(e) => warn!(
+                    "Data retention: applying club {}'s policy failed: {e}",
+                    policy.club_id
+                ),
+            }
+        }
+        Ok(())
+    }
+
     async fn anonymize_account(&self, id: Uuid) -> Result<(), sqlx::Error> {
         let db = &self.state.db;
         users::anonymize(db, id).await?;
         device_tokens::delete_all_for_user(db, id).await?;
+        auth_identities::delete_all_for_user(db, id).await?;
         refresh_tokens::revoke_all_for_user(db, id).await?;
+        contact_change_requests::delete_all_for_user(db, id).await?;
+        user_audit_log::delete_all_for_user(db, id).await?;
         Ok(())
     }
 }
 
-pub fn spawn_data_retention_service(state: AppState) -> tokio::task::JoinHandle<()> {
+pub fn spawn_data_retention_service(
+    state: AppState,
+    config: RetentionConfig,
+) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
-        let mut service = DataRetentionService::new(state);
+        let mut service = DataRetentionService::new(state, config);
         service.run().await;
     })
 }
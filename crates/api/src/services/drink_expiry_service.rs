@@ -3,6 +3,7 @@ use tokio::time::{interval, Interval};
 use tracing::{error, info};
 
 use crate::gql::domains::drinks::service::run_expiry;
+use crate::services::heartbeat;
 use crate::AppState;
 
 // Run the sweep hourly. The job is idempotent and only touches wallets that have an
@@ -29,6 +30,7 @@ impl DrinkExpiryService {
         info!("Starting drink credit expiry service");
         loop {
             self.interval.tick().await;
+            heartbeat::tick("drink_expiry_service");
             match run_expiry(&self.state.db, chrono::Utc::now()).await {
                 Ok(expired) if expired > 0 => {
                     info!("Expired {} unredeemed drink credit(s)", expired);
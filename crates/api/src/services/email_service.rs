@@ -3,6 +3,8 @@ use serde_json::json;
 use thiserror::Error;
 use tracing::{info, warn};
 
+use crate::config::Env;
+
 #[derive(Debug, Error)]
 pub enum EmailError {
     #[error("Network error: {0}")]
@@ -22,20 +24,28 @@ pub struct EmailConfig {
 }
 
 impl EmailConfig {
-    pub fn from_env() -> Option<Self> {
-        let scw_secret_key = std::env::var("SCW_SECRET_KEY").ok()?;
-        let scw_project_id = std::env::var("SCW_DEFAULT_PROJECT_ID").ok()?;
-        let sender_email = std::env::var("SCW_SENDER_EMAIL").ok()?;
+    pub fn load(env: &mut Env) -> Option<Self> {
+        let scw_secret_key = env.secret("SCW_SECRET_KEY");
+        let scw_project_id = env.string("SCW_DEFAULT_PROJECT_ID");
+        let sender_email = env.string("SCW_SENDER_EMAIL");
+        let scw_region = env.string_or("SCW_REGION", "fr-par");
+        let sender_name = env.string_or("SCW_SENDER_NAME", "PocketPair");
+        let frontend_base_url = env.string_or("FRONTEND_BASE_URL", "http://localhost:3000");
+        if !env.all_or_none(&[
+            "SCW_SECRET_KEY",
+            "SCW_DEFAULT_PROJECT_ID",
+            "SCW_SENDER_EMAIL",
+        ]) {
+            return None;
+        }
 
         Some(Self {
-            scw_secret_key,
-            scw_project_id,
-            scw_region: std::env::var("SCW_REGION").unwrap_or_else(|_| "fr-par".to_string()),
-            sender_email,
-            sender_name: std::env::var("SCW_SENDER_NAME")
-                .unwrap_or_else(|_| "PocketPair".to_string()),
-            frontend_base_url: std::env::var("FRONTEND_BASE_URL")
-                .unwrap_or_else(|_| "http://localhost:3000".to_string()),
+            scw_secret_key: scw_secret_key?,
+            scw_project_id: scw_project_id?,
+            scw_region,
+            sender_email: sender_email?,
+            sender_name,
+            frontend_base_url,
         })
     }
 }
@@ -80,6 +90,20 @@ struct I18n {
     pw_cta: &'static str,
     pw_disclaimer: &'static str,
 
+    // Login link
+    link_subject: &'static str,
+    link_heading: &'static str,
+    link_body: &'static str,
+    link_cta: &'static str,
+    link_disclaimer: &'static str,
+
+    // Contact change codes
+    change_subject: &'static str,
+    change_heading: &'static str,
+    change_body_current: &'static str,
+    change_body_new: &'static str,
+    change_disclaimer: &'static str,
+
     // Registration confirmed
     reg_subject_prefix: &'static str,
     reg_heading: &'static str,
@@ -96,6 +120,13 @@ struct I18n {
     soon_body_tpl: &'static str,
     soon_ready: &'static str,
 
+    // Tournament cancelled
+    cancel_subject_prefix: &'static str,
+    cancel_heading: &'static str,
+    cancel_body_tpl: &'static str,
+    cancel_reason: &'static str,
+    cancel_refund: &'static str,
+
     // Club manager invitation
     invite_subject_tpl: &'static str,
     invite_heading: &'static str,
@@ -125,6 +156,18 @@ static I18N_EN: I18n = I18n {
     pw_cta: "Reset Password",
     pw_disclaimer: "This link expires in 1 hour. If you didn&rsquo;t request a password reset, you can safely ignore this email &mdash; your account is secure.",
 
+    link_subject: "Your PocketPair Login Link",
+    link_heading: "Sign In",
+    link_body: "Use the button below to sign in to PocketPair &mdash; no password needed:",
+    link_cta: "Sign In",
+    link_disclaimer: "This link expires in 15 minutes and works once. If you didn&rsquo;t ask to sign in, you can safely ignore this email.",
+
+    change_subject: "Confirm Your New Contact Details",
+    change_heading: "Confirm the Change",
+    change_body_current: "Someone asked to change the email address or phone number on your PocketPair account. If it was you, enter this code to confirm:",
+    change_body_new: "Enter this code in PocketPair to confirm this is your new email address:",
+    change_disclaimer: "The code expires in 30 minutes. If you didn&rsquo;t ask for this change, ignore this email and consider changing your password.",
+
     reg_subject_prefix: "You're In",
     reg_heading: "Registration Confirmed",
     reg_body_tpl: "Your seat is confirmed for",
@@ -138,6 +181,12 @@ static I18N_EN: I18n = I18n {
     soon_body_tpl: "is starting in about 15 minutes.",
     soon_ready: "Make sure you&rsquo;re ready to take your seat.",
 
+    cancel_subject_prefix: "Tournament Cancelled",
+    cancel_heading: "Tournament Cancelled",
+    cancel_body_tpl: "We&rsquo;re sorry &mdash; the club has cancelled",
+    cancel_reason: "Reason:",
+    cancel_refund: "Your registration has been cancelled. Anything you paid at the desk will be refunded there; vouchers and prepaid buy-ins are credited back automatically.",
+
     invite_subject_tpl: "You\u{2019}ve been invited to manage",
     invite_heading: "Team Invitation",
     invite_body_tpl: "invited you to help manage",
@@ -158,6 +207,18 @@ static I18N_FR: I18n = I18n {
     pw_cta: "R\u{e9}initialiser",
     pw_disclaimer: "Ce lien expire dans 1 heure. Si vous n&rsquo;avez pas demand\u{e9} de r\u{e9}initialisation, vous pouvez ignorer cet e-mail en toute s\u{e9}curit\u{e9}.",
 
+    link_subject: "Votre lien de connexion PocketPair",
+    link_heading: "Connexion",
+    link_body: "Cliquez sur le bouton ci-dessous pour vous connecter \u{e0} PocketPair, sans mot de passe\u{a0}:",
+    link_cta: "Se connecter",
+    link_disclaimer: "Ce lien expire dans 15 minutes et ne fonctionne qu&rsquo;une fois. Si vous n&rsquo;avez pas demand\u{e9} \u{e0} vous connecter, vous pouvez ignorer cet e-mail.",
+
+    change_subject: "Confirmez vos nouvelles coordonn\u{e9}es",
+    change_heading: "Confirmer la modification",
+    change_body_current: "Une modification de l&rsquo;adresse e-mail ou du num\u{e9}ro de t\u{e9}l\u{e9}phone de votre compte PocketPair a \u{e9}t\u{e9} demand\u{e9}e. Si c&rsquo;est vous, saisissez ce code pour confirmer\u{a0}:",
+    change_body_new: "Saisissez ce code dans PocketPair pour confirmer votre nouvelle adresse e-mail\u{a0}:",
+    change_disclaimer: "Ce code expire dans 30 minutes. Si vous n&rsquo;avez pas demand\u{e9} cette modification, ignorez cet e-mail et pensez \u{e0} changer votre mot de passe.",
+
     reg_subject_prefix: "Inscription confirm\u{e9}e",
     reg_heading: "Inscription Confirm\u{e9}e",
     reg_body_tpl: "Votre place est confirm\u{e9}e pour",
@@ -171,6 +232,12 @@ static I18N_FR: I18n = I18n {
     soon_body_tpl: "commence dans environ 15 minutes.",
     soon_ready: "Assurez-vous d&rsquo;\u{ea}tre pr\u{ea}t\u{a0}!",
 
+    cancel_subject_prefix: "Tournoi annul\u{e9}",
+    cancel_heading: "Tournoi Annul\u{e9}",
+    cancel_body_tpl: "Nous sommes d\u{e9}sol\u{e9}s &mdash; le club a annul\u{e9}",
+    cancel_reason: "Motif\u{a0}:",
+    cancel_refund: "Votre inscription a \u{e9}t\u{e9} annul\u{e9}e. Ce que vous avez pay\u{e9} au comptoir vous y sera rembours\u{e9}\u{a0}; les bons et buy-ins pr\u{e9}pay\u{e9}s sont recr\u{e9}dit\u{e9}s automatiquement.",
+
     invite_subject_tpl: "Vous \u{ea}tes invit\u{e9} \u{e0} g\u{e9}rer",
     invite_heading: "Invitation d\u{2019}\u{e9}quipe",
     invite_body_tpl: "vous invite \u{e0} aider \u{e0} g\u{e9}rer",
@@ -191,6 +258,18 @@ static I18N_NL: I18n = I18n {
     pw_cta: "Wachtwoord Resetten",
     pw_disclaimer: "Deze link verloopt over 1 uur. Als je dit niet hebt aangevraagd, kun je deze e-mail veilig negeren.",
 
+    link_subject: "Je PocketPair-inloglink",
+    link_heading: "Inloggen",
+    link_body: "Klik op de knop hieronder om in te loggen bij PocketPair, zonder wachtwoord:",
+    link_cta: "Inloggen",
+    link_disclaimer: "Deze link verloopt over 15 minuten en werkt \u{e9}\u{e9}n keer. Als je niet wilde inloggen, kun je deze e-mail veilig negeren.",
+
+    change_subject: "Bevestig je nieuwe contactgegevens",
+    change_heading: "Wijziging Bevestigen",
+    change_body_current: "Er is gevraagd om het e-mailadres of telefoonnummer van je PocketPair-account te wijzigen. Was jij dat, voer dan deze code in om te bevestigen:",
+    change_body_new: "Voer deze code in PocketPair in om je nieuwe e-mailadres te bevestigen:",
+    change_disclaimer: "De code verloopt over 30 minuten. Heb je deze wijziging niet aangevraagd, negeer dan deze e-mail en overweeg je wachtwoord te wijzigen.",
+
     reg_subject_prefix: "Inschrijving bevestigd",
     reg_heading: "Inschrijving Bevestigd",
     reg_body_tpl: "Je plaats is bevestigd voor",
@@ -204,6 +283,12 @@ static I18N_NL: I18n = I18n {
     soon_body_tpl: "begint over ongeveer 15 minuten.",
     soon_ready: "Zorg dat je klaar bent!",
 
+    cancel_subject_prefix: "Toernooi geannuleerd",
+    cancel_heading: "Toernooi Geannuleerd",
+    cancel_body_tpl: "Het spijt ons &mdash; de club heeft dit toernooi geannuleerd:",
+    cancel_reason: "Reden:",
+    cancel_refund: "Je inschrijving is geannuleerd. Wat je aan de balie betaalde, wordt daar terugbetaald; vouchers en vooruitbetaalde buy-ins worden automatisch teruggeboekt.",
+
     invite_subject_tpl: "Je bent uitgenodigd als beheerder van",
     invite_heading: "Teamuitnodiging",
     invite_body_tpl: "nodigt je uit om mee te beheren:",
@@ -435,6 +520,91 @@ impl EmailService {
             .await
     }
 
+    /// Passwordless sign-in link; `login_token` is the single-use token the
+    /// frontend exchanges at `/auth/login-link`.
+    pub async fn send_login_link(
+        &self,
+        to_email: &str,
+        to_name: &str,
+        login_token: &str,
+        locale: Locale,
+    ) -> Result<(), EmailError> {
+        let t = i18n(locale);
+        let login_link = format!(
+            "{}/auth/login-link?token={}",
+            self.config.frontend_base_url, login_token
+        );
+        let safe_name = encode_text(to_name);
+        let safe_link = encode_text(&login_link);
+
+        let body_html = format!(
+            "{}{}{}{}",
+            paragraph(&format!("{} {},", t.hi, safe_name)),
+            paragraph(t.link_body),
+            cta_button(&safe_link, t.link_cta),
+            muted_paragraph(t.link_disclaimer),
+        );
+
+        let html = wrap_in_layout(
+            t.link_heading,
+            "&#128273;",
+            &body_html,
+            &self.logo_url(),
+            t.footer_tagline,
+        );
+
+        let text = format!(
+            "{} {},\n\n{}\n\n{}: {}\n\n-- PocketPair",
+            t.hi, to_name, t.link_body, t.link_cta, login_link
+        );
+
+        self.send_email(to_email, to_name, t.link_subject, &html, &text)
+            .await
+    }
+
+    /// One of the two codes confirming an email or phone change: to the
+    /// account's current email, or (`to_new_address`) to the new one.
+    pub async fn send_contact_change_code(
+        &self,
+        to_email: &str,
+        to_name: &str,
+        code: &str,
+        to_new_address: bool,
+        locale: Locale,
+    ) -> Result<(), EmailError> {
+        let t = i18n(locale);
+        let body = if to_new_address {
+            t.change_body_new
+        } else {
+            t.change_body_current
+        };
+        let safe_name = encode_text(to_name);
+
+        let body_html = format!(
+            "{}{}{}{}",
+            paragraph(&format!("{} {},", t.hi, safe_name)),
+            paragraph(body),
+            paragraph(&gold(&encode_text(code))),
+            muted_paragraph(t.change_disclaimer),
+        );
+
+        let html = wrap_in_layout(
+            t.change_heading,
+            "&#9993;",
+            &body_html,
+            &self.logo_url(),
+            t.footer_tagline,
+        );
+
+        let text = format!(
+            "{} {},\n\n{}\n\n{}\n\n-- PocketPair",
+            t.hi, to_name, body, code
+        );
+
+        self.send_email(to_email, to_name, t.change_subject, &html, &text)
+            .await
+    }
+
     /// Invitation to co-manage a club. `set_password_token` is Some for freshly
     /// created accounts (72h set-password link); None sends a plain
     /// notification pointing at the app for people who already have an account.
@@ -606,6 +776,59 @@ impl EmailService {
         self.send_email(to_email, to_name, &subject, &html, &text)
             .await
     }
+
+    /// The club cancelled a tournament the recipient registered for. `reason`
+    /// is the manager's note, shown when given.
+    pub async fn send_tournament_cancelled(
+        &self,
+        to_email: &str,
+        to_name: &str,
+        tournament_name: &str,
+        reason: Option<&str>,
+        locale: Locale,
+    ) -> Result<(), EmailError> {
+        let t = i18n(locale);
+        let safe_name = encode_text(to_name);
+        let safe_tournament = encode_text(tournament_name);
+        let subject = format!("{} \u{2663} {}", t.cancel_subject_prefix, tournament_name);
+
+        let mut body_html = format!(
+            "{}{}",
+            paragraph(&format!("{} {},", t.hi, safe_name)),
+            paragraph(&format!(
+                "{} {}.",
+                t.cancel_body_tpl,
+                gold(&safe_tournament)
+            )),
+        );
+        if let Some(reason) = reason {
+            body_html.push_str(&paragraph(&format!(
+                "{} {}",
+                t.cancel_reason,
+                encode_text(reason)
+            )));
+        }
+        body_html.push_str(&paragraph(t.cancel_refund));
+
+        let html = wrap_in_layout(
+            t.cancel_heading,
+            "&#9827;",
+            &body_html,
+            &self.logo_url(),
+            t.footer_tagline,
+        );
+
+        let reason_text = reason
+            .map(|reason| format!("{} {}\n\n", t.cancel_reason, reason))
+            .unwrap_or_default();
+        let text = format!(
+            "{} {},\n\n{} {}.\n\n{}{}\n\n-- PocketPair",
+            t.hi, to_name, t.cancel_body_tpl, tournament_name, reason_text, t.cancel_refund
+        );
+
+        self.send_email(to_email, to_name, &subject, &html, &text)
+            .await
+    }
 }
 
 // ── Fire-and-forget helper ──────────────────────────────────────────
@@ -643,6 +866,21 @@ pub fn spawn_email(
                     .send_tournament_starting_soon(&to_email, &to_name, &tournament_name, locale)
                     .await
             }
+            EmailType::TournamentCancelled {
+                tournament_name,
+                reason,
+                locale,
+            } => {
+                email_service
+                    .send_tournament_cancelled(
+                        &to_email,
+                        &to_name,
+                        &tournament_name,
+                        reason.as_deref(),
+                        locale,
+                    )
+                    .await
+            }
         };
 
         if let Err(e) = result {
@@ -664,4 +902,9 @@ pub enum EmailType {
         tournament_name: String,
         locale: Locale,
     },
+    TournamentCancelled {
+        tournament_name: String,
+        reason: Option<String>,
+        locale: Locale,
+    },
 }
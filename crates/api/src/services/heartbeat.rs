@@ -0,0 +1,59 @@
+//! Liveness of this instance's background services: when each last ticked
+//! and how often its supervisor had to restart it. Read by the admin
+//! `backgroundServiceStatus` query.
+
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Beat {
+    pub last_tick_at: Option<DateTime<Utc>>,
+    pub ticks: u64,
+    pub restarts: u32,
+}
+
+static BEATS: LazyLock<Mutex<BTreeMap<&'static str, Beat>>> = LazyLock::new(Default::default);
+
+/// Record one pass of `service`'s loop.
+pub fn tick(service: &'static str) {
+    let mut beats = BEATS.lock();
+    let beat = beats.entry(service).or_default();
+    beat.last_tick_at = Some(Utc::now());
+    beat.ticks += 1;
+}
+
+/// Record that `service` died and is being restarted.
+pub fn restarted(service: &'static str) {
+    BEATS.lock().entry(service).or_default().restarts += 1;
+}
+
+/// Every service seen so far, by name.
+pub fn snapshot() -> Vec<(&'static str, Beat)> {
+    BEATS
+        .lock()
+        .iter()
+        .map(|(name, beat)| (*name, beat.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_ticks_and_restarts() {
+        tick("heartbeat_test");
+        tick("heartbeat_test");
+        restarted("heartbeat_test");
+
+        let (_, beat) = snapshot()
+            .into_iter()
+            .find(|(name, _)| *name == "heartbeat_test")
+            .unwrap();
+        assert_eq!((beat.ticks, beat.restarts), (2, 1));
+        assert!(beat.last_tick_at.is_some());
+    }
+}
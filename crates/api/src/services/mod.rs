@@ -1,20 +1,35 @@
+pub mod alternate_seating_service;
+pub mod announcement_dispatch_service;
 pub mod clock_service;
 pub mod data_retention_service;
 pub mod drink_expiry_service;
 pub mod email_service;
+pub mod heartbeat;
+pub mod mqtt_bridge;
 pub mod notification_service;
 pub mod openrouter_service;
 pub mod push_service;
+pub mod rolling_points_service;
+pub mod sms_service;
 pub mod subscription_expiry_service;
 pub mod supervisor;
 pub mod vies;
 
+pub use alternate_seating_service::{spawn_alternate_seating_service, AlternateSeatingService};
+pub use announcement_dispatch_service::{
+    spawn_announcement_dispatch_service, AnnouncementDispatchService,
+};
 pub use clock_service::{spawn_clock_service, ClockService};
-pub use data_retention_service::{spawn_data_retention_service, DataRetentionService};
+pub use data_retention_service::{
+    spawn_data_retention_service, DataRetentionService, RetentionConfig,
+};
 pub use drink_expiry_service::{spawn_drink_expiry_service, DrinkExpiryService};
 pub use email_service::{EmailConfig, EmailService};
+pub use mqtt_bridge::{spawn_mqtt_bridge, MqttBridge, MqttConfig};
 pub use notification_service::{spawn_notification_service, NotificationService};
 pub use openrouter_service::{OpenRouterConfig, OpenRouterService};
+pub use rolling_points_service::{spawn_rolling_points_service, RollingPointsService};
+pub use sms_service::{SmsConfig, SmsService};
 pub use subscription_expiry_service::{
     spawn_subscription_expiry_service, SubscriptionExpiryService,
 };
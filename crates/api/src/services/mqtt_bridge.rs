@@ -0,0 +1,496 @@
+//! Optional MQTT bridge: republishes clock ticks, level changes and seating
+//! events so in-venue signage and buzzer systems can follow a tournament
+//! without holding a GraphQL websocket.
+//!
+//! The bridge reads the real-time bus tap, which carries events from every
+//! backend instance, so enable it on one instance only.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use rumqttc::{AsyncClient, EventLoop, MqttOptions, QoS};
+use serde::Serialize;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::config::Env;
+use crate::gql::domains::tournaments::clock::load_tournament_clock;
+use crate::gql::realtime::{tap, RealtimeEvent};
+use crate::gql::types::{ClockStatus, SeatingChangeEvent, SeatingEventType, TournamentClock};
+use crate::AppState;
+use infra::repos::club_tables;
+use infra::repos::tournaments::{self, TournamentLiveStatus};
+
+const DEFAULT_CLOCK_TOPIC: &str = "pp/clubs/{club_id}/tournaments/{tournament_id}/clock";
+const DEFAULT_LEVEL_TOPIC: &str = "pp/clubs/{club_id}/tournaments/{tournament_id}/level";
+const DEFAULT_SEATING_TOPIC: &str = "pp/clubs/{club_id}/tournaments/{tournament_id}/seating";
+
+/// Broker connection and topic layout, from MQTT_* env vars. The bridge is
+/// enabled by setting MQTT_HOST.
+#[derive(Clone)]
+pub struct MqttConfig {
+    pub host: String,
+    pub port: u16,
+    pub client_id: String,
+    pub credentials: Option<(String, String)>,
+    /// Topic templates; `{club_id}` and `{tournament_id}` are substituted.
+    pub clock_topic: String,
+    pub level_topic: String,
+    pub seating_topic: String,
+    /// How often running clocks are re-published (0 = only on changes).
+    pub tick_seconds: u64,
+    /// Only bridge these clubs' tournaments (None = all).
+    pub club_ids: Option<HashSet<Uuid>>,
+}
+
+impl MqttConfig {
+    pub fn load(env: &mut Env) -> Option<Self> {
+        let host = env.string("MQTT_HOST")?;
+        let username = env.string("MQTT_USERNAME");
+        let password = env.secret("MQTT_PASSWORD");
+        env.all_or_none(&["MQTT_USERNAME", "MQTT_PASSWORD"]);
+        let club_ids = env.string("MQTT_CLUB_IDS").map(|ids| {
+            ids.split(',')
+                .filter(|id| !id.trim().is_empty())
+                .filter_map(|id| match Uuid::parse_str(id.trim()) {
+                    Ok(id) => Some(id),
+                    Err(_) => {
+                        env.problem(format!("MQTT_CLUB_IDS: '{}' is not a club id", id.trim()));
+                        None
+                    }
+                })
+                .collect()
+        });
+
+        Some(Self {
+            host,
+            port: env.parse_or("MQTT_PORT", 1883),
+            client_id: env.string_or("MQTT_CLIENT_ID", "pp-service"),
+            credentials: username.zip(password),
+            clock_topic: env.string_or("MQTT_TOPIC_CLOCK", DEFAULT_CLOCK_TOPIC),
+            level_topic: env.string_or("MQTT_TOPIC_LEVEL", DEFAULT_LEVEL_TOPIC),
+            seating_topic: env.string_or("MQTT_TOPIC_SEATING", DEFAULT_SEATING_TOPIC),
+            tick_seconds: env.parse_or("MQTT_TICK_SECONDS", 1),
+            club_ids,
+        })
+    }
+
+    fn bridges_club(&self, club_id: Uuid) -> bool {
+        self.club_ids
+            .as_ref()
+            .is_none_or(|ids| ids.contains(&club_id))
+    }
+}
+
+fn render_topic(template: &str, club_id: Uuid, tournament_id: Uuid) -> String {
+    template
+        .replace("{club_id}", &club_id.to_string())
+        .replace("{tournament_id}", &tournament_id.to_string())
+}
+
+fn clock_status_str(status: ClockStatus) -> &'static str {
+    match status {
+        ClockStatus::Stopped => "stopped",
+        ClockStatus::Running => "running",
+        ClockStatus::Paused => "paused",
+    }
+}
+
+fn seating_event_str(event_type: SeatingEventType) -> &'static str {
+    match event_type {
+        SeatingEventType::PlayerAssigned => "player_assigned",
+        SeatingEventType::PlayerMoved => "player_moved",
+        SeatingEventType::PlayerEliminated => "player_eliminated",
+        SeatingEventType::StackUpdated => "stack_updated",
+        SeatingEventType::StacksUpdated => "stacks_updated",
+        SeatingEventType::TableCreated => "table_created",
+        SeatingEventType::TableClosed => "table_closed",
+        SeatingEventType::TableRemoved => "table_removed",
+        SeatingEventType::TournamentStatusChanged => "tournament_status_changed",
+        SeatingEventType::TablesBalanced => "tables_balanced",
+        SeatingEventType::TableStatusChanged => "table_status_changed",
+        SeatingEventType::SeatsSwapped => "seats_swapped",
+    }
+}
+
+#[derive(Serialize)]
+struct ClockPayload {
+    tournament_id: Uuid,
+    club_id: Uuid,
+    status: &'static str,
+    level: i32,
+    seconds_remaining: Option<i64>,
+    small_blind: Option<i32>,
+    big_blind: Option<i32>,
+    ante: Option<i32>,
+    is_break: Option<bool>,
+    sent_at: DateTime<Utc>,
+}
+
+impl ClockPayload {
+    /// A running clock's remaining time is recomputed from the level end, so
+    /// ticks count down without new events.
+    fn new(
+        club_id: Uuid,
+        tournament_id: Uuid,
+        clock: &TournamentClock,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let seconds_remaining = match (clock.status, clock.level_end_time) {
+            (ClockStatus::Running, Some(end)) => Some((end - now).num_seconds().max(0)),
+            _ => clock.time_remaining_seconds,
+        };
+        Self {
+            tournament_id,
+            club_id,
+            status: clock_status_str(clock.status),
+            level: clock.current_level,
+            seconds_remaining,
+            small_blind: clock.small_blind,
+            big_blind: clock.big_blind,
+            ante: clock.ante,
+            is_break: clock.is_break,
+            sent_at: now,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct LevelPayload {
+    tournament_id: Uuid,
+    club_id: Uuid,
+    previous_level: i32,
+    level: i32,
+    small_blind: Option<i32>,
+    big_blind: Option<i32>,
+    ante: Option<i32>,
+    is_break: Option<bool>,
+    duration_minutes: Option<i32>,
+    sent_at: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+struct SeatingPayload {
+    tournament_id: Uuid,
+    club_id: Uuid,
+    event: &'static str,
+    table_number: Option<i32>,
+    seat_number: Option<i32>,
+    message: String,
+    sent_at: DateTime<Utc>,
+}
+
+struct TrackedClock {
+    club_id: Uuid,
+    clock: TournamentClock,
+}
+
+pub struct MqttBridge {
+    state: AppState,
+    config: MqttConfig,
+    client: AsyncClient,
+    clocks: HashMap<Uuid, TrackedClock>,
+}
+
+impl MqttBridge {
+    pub fn new(state: AppState, config: MqttConfig) -> (Self, EventLoop) {
+        let mut options = MqttOptions::new(&config.client_id, &config.host, config.port);
+        options.set_keep_alive(Duration::from_secs(30));
+        if let Some((user, password)) = &config.credentials {
+            options.set_credentials(user, password);
+        }
+        let (client, eventloop) = AsyncClient::new(options, 256);
+        let bridge = Self {
+            state,
+            config,
+            client,
+            clocks: HashMap::new(),
+        };
+        (bridge, eventloop)
+    }
+
+    pub async fn run(&mut self, mut eventloop: EventLoop) {
+        info!(
+            "Starting MQTT bridge ({}:{})",
+            self.config.host, self.config.port
+        );
+        // Subscribe before seeding so nothing published in between is missed.
+        let mut events = tap();
+        self.seed().await;
+
+        let tick = Duration::from_secs(self.config.tick_seconds.max(1));
+        let mut ticker = tokio::time::interval(tick);
+        loop {
+            tokio::select! {
+                polled = eventloop.poll() => {
+                    // rumqttc reconnects on the next poll; back off meanwhile.
+                    if let Err(e) = polled {
+                        warn!("MQTT connection error: {e}; retrying in 5s");
+                        tokio::time::sleep(Duration::from_secs(5)).await;
+                    }
+                }
+                received = events.recv() => match received {
+                    Ok(event) => self.handle(event).await,
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!("MQTT bridge fell behind; skipped {skipped} event(s)");
+                    }
+                    Err(RecvError::Closed) => return,
+                },
+                _ = ticker.tick(), if self.config.tick_seconds > 0 => self.publish_ticks(),
+            }
+        }
+    }
+
+    /// Pick up clocks that were already running when the bridge started.
+    async fn seed(&mut self) {
+        let mut live = match tournaments::list_live(&self.state.db).await {
+            Ok(rows) => rows,
+            Err(e) => {
+                error!("MQTT bridge: failed to load live tournaments: {e}");
+                return;
+            }
+        };
+        if let Ok(rows) =
+            tournaments::list_by_live_status(&self.state.db, TournamentLiveStatus::LateRegistration)
+                .await
+        {
+            live.extend(rows);
+        }
+
+        for tournament in live {
+            if !self.config.bridges_club(tournament.club_id) {
+                continue;
+            }
+            if let Ok(Some(clock)) = load_tournament_clock(&self.state.db, tournament.id).await {
+                self.clocks.insert(
+                    tournament.id,
+                    TrackedClock {
+                        club_id: tournament.club_id,
+                        clock,
+                    },
+                );
+            }
+        }
+    }
+
+    async fn handle(&mut self, event: RealtimeEvent) {
+        match event {
+            RealtimeEvent::Clock {
+                tournament_id,
+                clock,
+            } => self.handle_clock(tournament_id, *clock).await,
+            RealtimeEvent::Seating(event) => self.handle_seating(event).await,
+            _ => {}
+        }
+    }
+
+    async fn handle_clock(&mut self, tournament_id: Uuid, clock: TournamentClock) {
+        let club_id = match self.clocks.get(&tournament_id) {
+            Some(tracked) => tracked.club_id,
+            None => match tournaments::get_by_id(&self.state.db, tournament_id).await {
+                Ok(Some(tournament)) => tournament.club_id,
+                _ => return,
+            },
+        };
+        if !self.config.bridges_club(club_id) {
+            return;
+        }
+        let now = Utc::now();
+
+        let previous_level = self
+            .clocks
+            .get(&tournament_id)
+            .map(|tracked| tracked.clock.current_level);
+        if let Some(previous_level) = previous_level.filter(|&l| l != clock.current_level) {
+            let payload = LevelPayload {
+                tournament_id,
+                club_id,
+                previous_level,
+                level: clock.current_level,
+                small_blind: clock.small_blind,
+                big_blind: clock.big_blind,
+                ante: clock.ante,
+                is_break: clock.is_break,
+                duration_minutes: clock.level_duration_minutes,
+                sent_at: now,
+            };
+            self.publish(
+                render_topic(&self.config.level_topic, club_id, tournament_id),
+                QoS::AtLeastOnce,
+                false,
+                &payload,
+            );
+        }
+
+        let payload = ClockPayload::new(club_id, tournament_id, &clock, now);
+        self.publish(
+            render_topic(&self.config.clock_topic, club_id, tournament_id),
+            QoS::AtLeastOnce,
+            true,
+            &payload,
+        );
+        self.clocks
+            .insert(tournament_id, TrackedClock { club_id, clock });
+    }
+
+    async fn handle_seating(&mut self, event: SeatingChangeEvent) {
+        let (Ok(tournament_id), Ok(club_id)) = (
+            Uuid::parse_str(event.tournament_id.as_str()),
+            Uuid::parse_str(event.club_id.as_str()),
+        ) else {
+            return;
+        };
+        if !self.config.bridges_club(club_id) {
+            return;
+        }
+
+        let assignment = event.affected_assignment.as_ref();
+        let table_number =
+            match assignment.and_then(|a| Uuid::parse_str(a.club_table_id.as_str()).ok()) {
+                Some(table_id) => club_tables::get_by_id(&self.state.db, table_id)
+                    .await
+                    .ok()
+                    .flatten()
+                    .map(|t| t.table_number),
+                None => None,
+            };
+        let payload = SeatingPayload {
+            tournament_id,
+            club_id,
+            event: seating_event_str(event.event_type),
+            table_number,
+            seat_number: assignment.map(|a| a.seat_number),
+            message: event.message,
+            sent_at: Utc::now(),
+        };
+        self.publish(
+            render_topic(&self.config.seating_topic, club_id, tournament_id),
+            QoS::AtLeastOnce,
+            false,
+            &payload,
+        );
+    }
+
+    /// Re-publish every running clock with its current remaining time.
+    fn publish_ticks(&mut self) {
+        let now = Utc::now();
+        // Forget tournaments that have been idle for a day.
+        self.clocks.retain(|_, tracked| {
+            tracked.clock.status == ClockStatus::Running
+                || tracked
+                    .clock
+                    .level_started_at
+                    .is_some_and(|t| now - t < chrono::Duration::hours(24))
+        });
+
+        for (tournament_id, tracked) in &self.clocks {
+            if tracked.clock.status != ClockStatus::Running {
+                continue;
+            }
+            let payload = ClockPayload::new(tracked.club_id, *tournament_id, &tracked.clock, now);
+            self.publish(
+                render_topic(&self.config.clock_topic, tracked.club_id, *tournament_id),
+                QoS::AtMostOnce,
+                true,
+                &payload,
+            );
+        }
+    }
+
+    /// Never blocks: if the broker is unreachable and the client queue is
+    /// full, the message is dropped (the next tick or event supersedes it).
+    fn publish(&self, topic: String, qos: QoS, retain: bool, payload: &impl Serialize) {
+        let body = match serde_json::to_vec(payload) {
+            Ok(body) => body,
+            Err(e) => {
+                error!("MQTT bridge: failed to serialize payload: {e}");
+                return;
+            }
+        };
+        if let Err(e) = self.client.try_publish(topic, qos, retain, body) {
+            warn!("MQTT bridge: dropped message: {e}");
+        }
+    }
+}
+
+pub fn spawn_mqtt_bridge(state: AppState, config: MqttConfig) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let (mut bridge, eventloop) = MqttBridge::new(state, config);
+        bridge.run(eventloop).await;
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clock(status: ClockStatus, end_in_seconds: i64, now: DateTime<Utc>) -> TournamentClock {
+        TournamentClock {
+            id: "clock".into(),
+            tournament_id: "tournament".into(),
+            status,
+            current_level: 3,
+            time_remaining_seconds: Some(600),
+            level_started_at: Some(now),
+            level_end_time: Some(now + chrono::Duration::seconds(end_in_seconds)),
+            total_pause_duration_seconds: 0,
+            auto_advance: true,
+            current_structure: None,
+            next_structure: None,
+            small_blind: Some(100),
+            big_blind: Some(200),
+            ante: Some(25),
+            is_break: Some(false),
+            level_duration_minutes: Some(20),
+            pace: None,
+        }
+    }
+
+    #[test]
+    fn topic_templates_substitute_ids() {
+        let club_id = Uuid::nil();
+        let tournament_id = Uuid::from_u128(1);
+        assert_eq!(
+            render_topic(DEFAULT_CLOCK_TOPIC, club_id, tournament_id),
+            format!("pp/clubs/{club_id}/tournaments/{tournament_id}/clock")
+        );
+        assert_eq!(
+            render_topic("venue/{tournament_id}", club_id, tournament_id),
+            format!("venue/{tournament_id}")
+        );
+    }
+
+    #[test]
+    fn running_clock_counts_down_from_level_end() {
+        let now = Utc::now();
+        let running = ClockPayload::new(
+            Uuid::nil(),
+            Uuid::nil(),
+            &clock(ClockStatus::Running, 90, now),
+            now,
+        );
+        assert_eq!(running.status, "running");
+        assert_eq!(running.seconds_remaining, Some(90));
+
+        // Past the end: never negative.
+        let overdue = ClockPayload::new(
+            Uuid::nil(),
+            Uuid::nil(),
+            &clock(ClockStatus::Running, -5, now),
+            now,
+        );
+        assert_eq!(overdue.seconds_remaining, Some(0));
+
+        // A paused clock keeps the remaining time it was published with.
+        let paused = ClockPayload::new(
+            Uuid::nil(),
+            Uuid::nil(),
+            &clock(ClockStatus::Paused, 90, now),
+            now,
+        );
+        assert_eq!(paused.status, "paused");
+        assert_eq!(paused.seconds_remaining, Some(600));
+    }
+}
@@ -8,6 +8,7 @@ use uuid::Uuid;
 
 use crate::gql::subscriptions::publish_user_notification;
 use crate::gql::types::{NotificationType, UserNotification, TITLE_TOURNAMENT_STARTING};
+use crate::services::heartbeat;
 use crate::AppState;
 use infra::repos::{notification_preferences, tournament_registrations, tournaments, users};
 
@@ -37,6 +38,7 @@ impl NotificationService {
 
         loop {
             self.interval.tick().await;
+            heartbeat::tick("notification_service");
 
             if let Err(e) = self.check_upcoming_tournaments().await {
                 error!("Error checking upcoming tournaments: {}", e);
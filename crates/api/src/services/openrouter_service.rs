@@ -15,6 +15,8 @@ use serde_json::json;
 use thiserror::Error;
 use tracing::warn;
 
+use crate::config::Env;
+
 /// Cap rows per LLM call to keep request/response within token + body limits.
 /// Larger imports are chunked across multiple calls.
 const MAX_ROWS_PER_CALL: usize = 200;
@@ -37,14 +39,14 @@ pub struct OpenRouterConfig {
 }
 
 impl OpenRouterConfig {
-    pub fn from_env() -> Option<Self> {
-        let api_key = std::env::var("OPENROUTER_API_KEY").ok()?;
+    pub fn load(env: &mut Env) -> Option<Self> {
+        let api_key = env.secret("OPENROUTER_API_KEY");
+        let model = env.string_or("OPENROUTER_MODEL", "deepseek/deepseek-chat-v4-flash");
+        let base_url = env.string_or("OPENROUTER_BASE_URL", "https://openrouter.ai/api/v1");
         Some(Self {
-            api_key,
-            model: std::env::var("OPENROUTER_MODEL")
-                .unwrap_or_else(|_| "deepseek/deepseek-chat-v4-flash".to_string()),
-            base_url: std::env::var("OPENROUTER_BASE_URL")
-                .unwrap_or_else(|_| "https://openrouter.ai/api/v1".to_string()),
+            api_key: api_key?,
+            model,
+            base_url,
         })
     }
 }
@@ -6,14 +6,22 @@
 //! Expo reports as `DeviceNotRegistered` are pruned so we stop targeting dead
 //! installs.
 
+use std::sync::LazyLock;
+
 use serde_json::json;
 use sqlx::PgPool;
 use uuid::Uuid;
 
 use infra::repos::{device_tokens, notification_preferences};
 
+use crate::config::Env;
+
 const EXPO_PUSH_URL: &str = "https://exp.host/--/api/v2/push/send";
 
+/// Optional: an Expo access token enforces "Enhanced Security" on the project.
+static EXPO_ACCESS_TOKEN: LazyLock<Option<String>> =
+    LazyLock::new(|| Env::new().secret("EXPO_ACCESS_TOKEN"));
+
 /// Localized generic push copy. The achievement's localized *name* lives in the
 /// app's i18n bundle (not on the server), so the push body is intentionally
 /// generic; tapping it deep-links to the achievements screen.
@@ -70,6 +78,36 @@ fn seating_copy(event: &str, locale: Option<&str>) -> (&'static str, &'static st
     }
 }
 
+/// Localized copy for rail pushes about a followed friend, keyed by the
+/// wire-format notification type. The friend's name lives in the in-app
+/// notification; the push deep-links to the tournament screen.
+fn rail_copy(event: &str, locale: Option<&str>) -> (&'static str, &'static str) {
+    match (event, locale.unwrap_or("en")) {
+        ("FRIEND_FINAL_TABLE", "fr") => (
+            "Table finale",
+            "Un ami que vous suivez est en table finale.",
+        ),
+        ("FRIEND_FINAL_TABLE", "nl") => (
+            "Finaletafel",
+            "Een vriend die je volgt zit aan de finaletafel.",
+        ),
+        ("FRIEND_FINAL_TABLE", _) => (
+            "Final table",
+            "A friend you follow reached the final table.",
+        ),
+        ("FRIEND_WON", "fr") => ("Victoire", "Un ami que vous suivez a remporté le tournoi."),
+        ("FRIEND_WON", "nl") => (
+            "Gewonnen",
+            "Een vriend die je volgt heeft het toernooi gewonnen.",
+        ),
+        ("FRIEND_WON", _) => ("Tournament won", "A friend you follow won the tournament."),
+        ("FRIEND_BUSTED", "fr") => ("Éliminé", "Un ami que vous suivez a été éliminé."),
+        ("FRIEND_BUSTED", "nl") => ("Uitgeschakeld", "Een vriend die je volgt is uitgeschakeld."),
+        ("FRIEND_BUSTED", _) => ("Busted", "A friend you follow was eliminated."),
+        _ => ("PocketPair", ""),
+    }
+}
+
 /// Push a message to every device registered for `user_id`, with title/body
 /// chosen per device locale by `copy`. Best-effort: errors are logged only.
 async fn send_to_user_devices(
@@ -145,6 +183,165 @@ pub async fn send_seating_event(db: &PgPool, user_id: Uuid, event: &str, tournam
     send_to_user_devices(db, user_id, data, |locale| seating_copy(event, locale)).await;
 }
 
+/// Push a rail alert about a followed friend (final table / win / bust).
+/// Followers opt in per friend, so no global preference applies here;
+/// `data.friend_id` and `data.tournament_id` drive the deep link.
+pub async fn send_rail_event(
+    db: &PgPool,
+    user_id: Uuid,
+    event: &str,
+    friend_id: Uuid,
+    tournament_id: Uuid,
+) {
+    let data = json!({
+        "type": event,
+        "friend_id": friend_id,
+        "tournament_id": tournament_id,
+    });
+    send_to_user_devices(db, user_id, data, |locale| rail_copy(event, locale)).await;
+}
+
+/// Localized copy for a raffle-win push. The raffle and prize live in the
+/// in-app notification; the push deep-links to the tournament screen.
+fn raffle_won_copy(locale: Option<&str>) -> (&'static str, &'static str) {
+    match locale.unwrap_or("en") {
+        "fr" => (
+            "Vous avez gagné la tombola",
+            "Votre ticket a été tiré — passez voir le floor.",
+        ),
+        "nl" => (
+            "Je hebt de tombola gewonnen",
+            "Je lot is getrokken — meld je bij de floor.",
+        ),
+        _ => (
+            "You won the raffle",
+            "Your ticket was drawn — see the floor to collect.",
+        ),
+    }
+}
+
+/// Push a raffle-win alert. `data.raffle_id` and `data.tournament_id` drive
+/// the deep link.
+pub async fn send_raffle_won(db: &PgPool, user_id: Uuid, raffle_id: Uuid, tournament_id: Uuid) {
+    let data = json!({
+        "type": "RAFFLE_WON",
+        "raffle_id": raffle_id,
+        "tournament_id": tournament_id,
+    });
+    send_to_user_devices(db, user_id, data, raffle_won_copy).await;
+}
+
+fn payouts_finalized_copy(locale: Option<&str>) -> (&'static str, &'static str) {
+    match locale.unwrap_or("en") {
+        "fr" => (
+            "Prize pool définitif",
+            "Late registration fermée — touchez pour voir la structure des gains.",
+        ),
+        "nl" => (
+            "Prijzenpot definitief",
+            "Late registratie is gesloten — tik om de uitbetalingen te bekijken.",
+        ),
+        _ => (
+            "Payouts are final",
+            "Late registration has closed — tap to see the payouts.",
+        ),
+    }
+}
+
+/// Push the official payout table's announcement. Gated by the
+/// registration-updates preference; `data.tournament_id` deep-links to the
+/// tournament screen.
+pub async fn send_payouts_finalized(db: &PgPool, user_id: Uuid, tournament_id: Uuid) {
+    let prefs = notification_preferences::get_for_user(db, user_id)
+        .await
+        .unwrap_or_default();
+    if !prefs.registration_updates {
+        return;
+    }
+
+    let data = json!({
+        "type": "PAYOUTS_FINALIZED",
+        "tournament_id": tournament_id,
+    });
+    send_to_user_devices(db, user_id, data, payouts_finalized_copy).await;
+}
+
+fn tournament_cancelled_copy(locale: Option<&str>) -> (&'static str, &'static str) {
+    match locale.unwrap_or("en") {
+        "fr" => (
+            "Tournoi annulé",
+            "Le tournoi a été annulé — touchez pour les détails de remboursement.",
+        ),
+        "nl" => (
+            "Toernooi geannuleerd",
+            "Het toernooi is geannuleerd — tik voor de terugbetaling.",
+        ),
+        _ => (
+            "Tournament cancelled",
+            "The tournament was cancelled — tap for refund details.",
+        ),
+    }
+}
+
+/// Push a cancellation to a registrant. Not gated by preferences: the player
+/// must know not to come and that a refund is waiting.
+pub async fn send_tournament_cancelled(db: &PgPool, user_id: Uuid, tournament_id: Uuid) {
+    let data = json!({
+        "type": "TOURNAMENT_CANCELLED",
+        "tournament_id": tournament_id,
+    });
+    send_to_user_devices(db, user_id, data, tournament_cancelled_copy).await;
+}
+
+/// Localized copy for the organization's decision on a tournament a manager
+/// created; the review note lives in the in-app notification.
+fn tournament_reviewed_copy(approved: bool, locale: Option<&str>) -> (&'static str, &'static str) {
+    match (locale.unwrap_or("en"), approved) {
+        ("fr", true) => (
+            "Tournoi approuvé",
+            "Votre tournoi est approuvé et visible au calendrier.",
+        ),
+        ("fr", false) => (
+            "Tournoi refusé",
+            "Votre tournoi n'a pas été approuvé — touchez pour voir pourquoi.",
+        ),
+        ("nl", true) => (
+            "Toernooi goedgekeurd",
+            "Je toernooi is goedgekeurd en staat nu op de kalender.",
+        ),
+        ("nl", false) => (
+            "Toernooi afgewezen",
+            "Je toernooi is niet goedgekeurd — tik om te zien waarom.",
+        ),
+        (_, true) => (
+            "Tournament approved",
+            "Your tournament was approved and is now on the schedule.",
+        ),
+        (_, false) => (
+            "Tournament rejected",
+            "Your tournament wasn't approved — tap to see why.",
+        ),
+    }
+}
+
+/// Push the organization's decision on a tournament to the manager who
+/// created it. `data.tournament_id` deep-links to the tournament.
+pub async fn send_tournament_reviewed(
+    db: &PgPool,
+    user_id: Uuid,
+    tournament_id: Uuid,
+    approved: bool,
+) {
+    let data = json!({
+        "type": if approved { "TOURNAMENT_APPROVED" } else { "TOURNAMENT_REJECTED" },
+        "tournament_id": tournament_id,
+    });
+    send_to_user_devices(db, user_id, data, |locale| {
+        tournament_reviewed_copy(approved, locale)
+    })
+    .await;
+}
+
 /// Localized copy for a Day-2 qualification push. The chip count is interpolated
 /// into the body; tapping deep-links to the (final-day) tournament screen.
 fn qualified_for_day2_copy(chip_count: i32, locale: Option<&str>) -> (&'static str, String) {
@@ -280,11 +477,8 @@ async fn deliver_batch(db: &PgPool, messages: Vec<serde_json::Value>, tokens: Ve
         .post(EXPO_PUSH_URL)
         .header("accept", "application/json")
         .header("content-type", "application/json");
-    // Optional: an Expo access token enforces "Enhanced Security" on the project.
-    if let Ok(access) = std::env::var("EXPO_ACCESS_TOKEN") {
-        if !access.is_empty() {
-            req = req.bearer_auth(access);
-        }
+    if let Some(access) = EXPO_ACCESS_TOKEN.as_deref() {
+        req = req.bearer_auth(access);
     }
 
     let resp = match req.json(&messages).send().await {
@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDate, Timelike, Utc};
+use tokio::time::{interval, Interval};
+use tracing::{error, info};
+
+use crate::services::heartbeat;
+use crate::AppState;
+
+// Tick hourly but refresh once a day, after REFRESH_HOUR_UTC (quiet hours for
+// clubs). The first tick after a restart refreshes straight away when past that
+// hour, so a deploy never leaves the decay a day behind.
+const CHECK_INTERVAL_SECONDS: u64 = 3600;
+const REFRESH_HOUR_UTC: u32 = 3;
+
+/// Background job that refreshes the decayed points behind the rolling 52-week
+/// leaderboard (`leaderboard_rolling_points`).
+pub struct RollingPointsService {
+    state: AppState,
+    interval: Interval,
+    last_refresh: Option<NaiveDate>,
+}
+
+impl RollingPointsService {
+    pub fn new(state: AppState) -> Self {
+        Self {
+            state,
+            interval: interval(Duration::from_secs(CHECK_INTERVAL_SECONDS)),
+            last_refresh: None,
+        }
+    }
+
+    pub async fn run(&mut self) {
+        info!("Starting rolling leaderboard points service");
+        loop {
+            self.interval.tick().await;
+            heartbeat::tick("rolling_points_service");
+            let now = Utc::now();
+            if !refresh_due(now, self.last_refresh) {
+                continue;
+            }
+            match infra::repos::tournament_results::refresh_rolling_points(&self.state.db).await {
+                Ok(()) => {
+                    self.last_refresh = Some(now.date_naive());
+                    info!("Refreshed rolling leaderboard points");
+                }
+                Err(e) => error!("Error refreshing rolling leaderboard points: {}", e),
+            }
+        }
+    }
+}
+
+/// Whether today's refresh is still to run at `now`.
+fn refresh_due(now: DateTime<Utc>, last_refresh: Option<NaiveDate>) -> bool {
+    now.hour() >= REFRESH_HOUR_UTC && last_refresh.is_none_or(|day| day < now.date_naive())
+}
+
+pub fn spawn_rolling_points_service(state: AppState) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut service = RollingPointsService::new(state);
+        service.run().await;
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn refreshes_once_a_day_after_the_refresh_hour() {
+        let at = |day, hour| Utc.with_ymd_and_hms(2026, 10, day, hour, 0, 0).unwrap();
+        let day = |d| NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+
+        assert!(!refresh_due(at(18, 1), None));
+        assert!(refresh_due(at(18, 3), None));
+        assert!(!refresh_due(at(18, 23), Some(day(18))));
+        assert!(!refresh_due(at(19, 2), Some(day(18))));
+        assert!(refresh_due(at(19, 4), Some(day(18))));
+    }
+}
@@ -0,0 +1,85 @@
+use thiserror::Error;
+use tracing::info;
+
+use crate::config::Env;
+
+#[derive(Debug, Error)]
+pub enum SmsError {
+    #[error("Network error: {0}")]
+    Network(String),
+    #[error("API error (status {status}): {body}")]
+    ApiError { status: u16, body: String },
+}
+
+#[derive(Clone)]
+pub struct SmsConfig {
+    pub account_sid: String,
+    pub auth_token: String,
+    pub from_number: String,
+}
+
+impl SmsConfig {
+    pub fn load(env: &mut Env) -> Option<Self> {
+        let account_sid = env.string("TWILIO_ACCOUNT_SID");
+        let auth_token = env.secret("TWILIO_AUTH_TOKEN");
+        let from_number = env.string("TWILIO_FROM_NUMBER");
+        if !env.all_or_none(&[
+            "TWILIO_ACCOUNT_SID",
+            "TWILIO_AUTH_TOKEN",
+            "TWILIO_FROM_NUMBER",
+        ]) {
+            return None;
+        }
+        Some(Self {
+            account_sid: account_sid?,
+            auth_token: auth_token?,
+            from_number: from_number?,
+        })
+    }
+}
+
+/// Outbound text messages (Twilio Programmable Messaging). Used for floor
+/// broadcasts that must reach players who are away from the app.
+#[derive(Clone)]
+pub struct SmsService {
+    config: SmsConfig,
+    client: reqwest::Client,
+}
+
+impl SmsService {
+    pub fn new(config: SmsConfig) -> Self {
+        Self {
+            config,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub async fn send_sms(&self, to: &str, body: &str) -> Result<(), SmsError> {
+        let url = format!(
+            "https://api.twilio.com/2010-04-01/Accounts/{}/Messages.json",
+            self.config.account_sid
+        );
+
+        let response = self
+            .client
+            .post(&url)
+            .basic_auth(&self.config.account_sid, Some(&self.config.auth_token))
+            .form(&[
+                ("To", to),
+                ("From", self.config.from_number.as_str()),
+                ("Body", body),
+            ])
+            .send()
+            .await
+            .map_err(|e| SmsError::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            return Err(SmsError::ApiError { status, body });
+        }
+
+        info!("SMS sent to {}", to);
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use std::time::Duration;
 use tokio::time::{interval, Interval};
 use tracing::{error, info};
 
+use crate::services::heartbeat;
 use crate::AppState;
 
 // Hourly is plenty: a trial lapsing an hour late is harmless, and the sweep is
@@ -28,6 +29,7 @@ impl SubscriptionExpiryService {
         info!("Starting subscription expiry service");
         loop {
             self.interval.tick().await;
+            heartbeat::tick("subscription_expiry_service");
             match infra::repos::clubs::downgrade_expired(&self.state.db, chrono::Utc::now()).await {
                 Ok(downgraded) if downgraded > 0 => {
                     info!(
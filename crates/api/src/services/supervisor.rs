@@ -23,6 +23,7 @@ pub fn supervise(
                     if *shutdown.borrow() {
                         return;
                     }
+                    super::heartbeat::restarted(name);
                     match res {
                         Ok(()) => {
                             tracing::error!("{name}: task exited unexpectedly; restarting in 5s")
@@ -1,26 +1,35 @@
-use sqlx::PgPool;
+use std::sync::Arc;
+
+use infra::db::RowScope;
+use sqlx::{PgPool, Postgres, Transaction};
 use tracing::{info, warn};
 
 use crate::auth::{AuthConfig, JwtService, OAuthService};
-use crate::services::{EmailConfig, EmailService, OpenRouterConfig, OpenRouterService};
+use crate::config::Config;
+use crate::services::{EmailService, OpenRouterService, SmsService};
 
 #[derive(Clone)]
 pub struct AppState {
     pub db: PgPool,
-    auth_config: AuthConfig,
+    config: Arc<Config>,
     jwt_service: JwtService,
     oauth_service: OAuthService,
     email_service: Option<EmailService>,
     openrouter_service: Option<OpenRouterService>,
+    sms_service: Option<SmsService>,
 }
 
 impl AppState {
+    /// State configured from the environment.
     pub fn new(db: PgPool) -> anyhow::Result<Self> {
-        let auth_config = AuthConfig::from_env()?;
-        let jwt_service = JwtService::new(&auth_config);
-        let oauth_service = OAuthService::new(auth_config.clone());
+        Ok(Self::with_config(db, Config::from_env()?))
+    }
+
+    pub fn with_config(db: PgPool, config: Config) -> Self {
+        let jwt_service = JwtService::new(&config.auth);
+        let oauth_service = OAuthService::new(config.auth.clone());
 
-        let email_service = match EmailConfig::from_env() {
+        let email_service = match config.email.clone() {
             Some(config) => {
                 info!("Email service configured (Scaleway Transactional Email)");
                 Some(EmailService::new(config))
@@ -31,7 +40,7 @@ impl AppState {
             }
         };
 
-        let openrouter_service = match OpenRouterConfig::from_env() {
+        let openrouter_service = match config.openrouter.clone() {
             Some(config) => {
                 info!("OpenRouter service configured (model: {})", config.model);
                 Some(OpenRouterService::new(config))
@@ -42,18 +51,50 @@ impl AppState {
             }
         };
 
-        Ok(Self {
+        let sms_service = match config.sms.clone() {
+            Some(config) => {
+                info!("SMS service configured (Twilio)");
+                Some(SmsService::new(config))
+            }
+            None => {
+                warn!("SMS service not configured: missing TWILIO_ACCOUNT_SID, TWILIO_AUTH_TOKEN, or TWILIO_FROM_NUMBER (SMS broadcasts will be skipped)");
+                None
+            }
+        };
+
+        Self {
             db,
-            auth_config,
+            config: Arc::new(config),
             jwt_service,
             oauth_service,
             email_service,
             openrouter_service,
-        })
+            sms_service,
+        }
+    }
+
+    /// A transaction the row-level security policies limit to `scope`. Run a
+    /// club's or a user's queries through it so a missed filter can't reach
+    /// another club's or user's rows.
+    pub async fn scoped(
+        &self,
+        scope: RowScope,
+    ) -> Result<Transaction<'static, Postgres>, sqlx::Error> {
+        infra::db::begin_scoped(&self.db, scope).await
+    }
+
+    /// A read-only snapshot transaction, for reads that combine several
+    /// queries and must agree with each other.
+    pub async fn snapshot(&self) -> Result<Transaction<'static, Postgres>, sqlx::Error> {
+        infra::db::begin_snapshot(&self.db).await
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
     }
 
     pub fn auth_config(&self) -> &AuthConfig {
-        &self.auth_config
+        &self.config.auth
     }
 
     pub fn jwt_service(&self) -> &JwtService {
@@ -71,4 +112,8 @@ impl AppState {
     pub fn openrouter_service(&self) -> Option<&OpenRouterService> {
         self.openrouter_service.as_ref()
     }
+
+    pub fn sms_service(&self) -> Option<&SmsService> {
+        self.sms_service.as_ref()
+    }
 }
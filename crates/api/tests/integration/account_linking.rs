@@ -0,0 +1,154 @@
+//! Linking and unlinking external logins, and resolving OAuth logins through
+//! linked identities.
+
+use api::auth::identities::find_or_create_oauth_user;
+use api::auth::oauth::{OAuthProvider, OAuthUserInfo};
+use api::gql::build_schema;
+use api::gql::domains::auth::service::{link_provider, unlink_provider};
+use uuid::Uuid;
+
+use crate::common::*;
+
+fn google_user(email: &str) -> OAuthUserInfo {
+    OAuthUserInfo {
+        provider_id: format!("google-{}", Uuid::new_v4()),
+        email: email.to_string(),
+        first_name: "Linked".to_string(),
+        last_name: None,
+        username: None,
+        avatar_url: None,
+    }
+}
+
+#[tokio::test]
+async fn password_account_can_link_google_and_log_in_with_it() {
+    let app = setup_test_db().await;
+    let unique = Uuid::new_v4();
+    let (user_id, claims) =
+        create_test_user(&app, &format!("link_pw_{unique}@test.com"), "player").await;
+
+    // The Google account uses a different email than the password account.
+    let google = google_user(&format!("link_google_{unique}@gmail.test"));
+    link_provider(&app.db, user_id, &OAuthProvider::Google, &google)
+        .await
+        .expect("link succeeds");
+    // Linking the same identity again is a no-op.
+    link_provider(&app.db, user_id, &OAuthProvider::Google, &google)
+        .await
+        .expect("relinking is idempotent");
+
+    let resolved = find_or_create_oauth_user(&app.db, &OAuthProvider::Google, &google)
+        .await
+        .unwrap();
+    assert_eq!(
+        resolved, user_id,
+        "Google login resolves to the linked account"
+    );
+
+    let schema = build_schema(app.clone());
+    let res = execute_graphql(
+        &schema,
+        "query { me { hasPassword linkedProviders { provider email } } }",
+        None,
+        Some(claims),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let me = &res.data.into_json().unwrap()["me"];
+    assert_eq!(me["hasPassword"], true);
+    assert_eq!(me["linkedProviders"][0]["provider"], "google");
+    assert_eq!(me["linkedProviders"][0]["email"], google.email);
+}
+
+#[tokio::test]
+async fn an_identity_links_to_one_account_only() {
+    let app = setup_test_db().await;
+    let unique = Uuid::new_v4();
+    let (first, _) = create_test_user(&app, &format!("link_a_{unique}@test.com"), "player").await;
+    let (second, _) = create_test_user(&app, &format!("link_b_{unique}@test.com"), "player").await;
+
+    let google = google_user(&format!("link_shared_{unique}@gmail.test"));
+    link_provider(&app.db, first, &OAuthProvider::Google, &google)
+        .await
+        .unwrap();
+
+    let err = link_provider(&app.db, second, &OAuthProvider::Google, &google)
+        .await
+        .expect_err("identity already belongs to another account");
+    assert_eq!(service_error_code(&err).as_deref(), Some("IDENTITY_IN_USE"));
+
+    let other = google_user(&format!("link_other_{unique}@gmail.test"));
+    let err = link_provider(&app.db, first, &OAuthProvider::Google, &other)
+        .await
+        .expect_err("one identity per provider");
+    assert_eq!(
+        service_error_code(&err).as_deref(),
+        Some("PROVIDER_ALREADY_LINKED")
+    );
+}
+
+#[tokio::test]
+async fn the_last_login_method_cannot_be_unlinked() {
+    let app = setup_test_db().await;
+    let unique = Uuid::new_v4();
+    let google = google_user(&format!("link_only_{unique}@gmail.test"));
+
+    // A Google sign-up: the account has no password.
+    let user_id = find_or_create_oauth_user(&app.db, &OAuthProvider::Google, &google)
+        .await
+        .unwrap();
+
+    let err = unlink_provider(&app.db, user_id, "google")
+        .await
+        .expect_err("Google is the only way in");
+    assert_eq!(
+        service_error_code(&err).as_deref(),
+        Some("LAST_LOGIN_METHOD")
+    );
+
+    // Once a password exists, Google can go.
+    sqlx::query("UPDATE users SET password_hash = 'set' WHERE id = $1")
+        .bind(user_id)
+        .execute(&app.db)
+        .await
+        .unwrap();
+    unlink_provider(&app.db, user_id, "google")
+        .await
+        .expect("password remains");
+
+    let err = unlink_provider(&app.db, user_id, "google")
+        .await
+        .expect_err("nothing left to unlink");
+    assert_eq!(
+        service_error_code(&err).as_deref(),
+        Some("PROVIDER_NOT_LINKED")
+    );
+}
+
+#[tokio::test]
+async fn linked_providers_are_private_to_the_account() {
+    let app = setup_test_db().await;
+    let schema = build_schema(app.clone());
+    let unique = Uuid::new_v4();
+    let (user_id, _) =
+        create_test_user(&app, &format!("link_owner_{unique}@test.com"), "player").await;
+    let (_, manager) =
+        create_test_user(&app, &format!("link_viewer_{unique}@test.com"), "manager").await;
+
+    // Test users' usernames are `test_<id>`.
+    let res = execute_graphql(
+        &schema,
+        &format!(r#"query {{ users(search: "test_{user_id}") {{ items {{ linkedProviders {{ provider }} }} }} }}"#),
+        None,
+        Some(manager),
+    )
+    .await;
+    let code = res
+        .errors
+        .iter()
+        .find_map(|e| match e.extensions.as_ref()?.get("code")? {
+            async_graphql::Value::String(code) => Some(code.clone()),
+            _ => None,
+        });
+    assert_eq!(code.as_deref(), Some("FORBIDDEN"));
+}
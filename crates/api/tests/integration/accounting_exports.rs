@@ -0,0 +1,293 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+
+const CREATE_EXPORT: &str = r#"
+    mutation($input: CreateAccountingExportInput!) {
+        createAccountingExport(input: $input) { id layout lineCount totalCents content }
+    }
+"#;
+
+#[tokio::test]
+async fn test_accounting_export_snapshots_the_journal() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("acct_mgr_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let (_, player_claims) = create_test_user(
+        &app_state,
+        &format!("acct_player_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Accounting Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+
+    // Three cash buy-ins of 50.00 plus 5.00 rake each; 150.00 paid out.
+    let import = r#"
+        mutation($input: ImportTournamentResultsInput!) {
+            importTournamentResults(input: $input) { tournament { id } }
+        }
+    "#;
+    let vars = Variables::from_json(json!({
+        "input": {
+            "clubId": club_id.to_string(),
+            "format": "CSV",
+            "content": "Place,Name,Winnings\n1,Alice,100\n2,Bob,50\n3,Carol,0\n",
+            "name": "June Weekly",
+            "startTime": "2025-06-06T19:00:00Z",
+            "buyInCents": 5000,
+            "rakeCents": 500,
+        }
+    }));
+    let response = execute_graphql(&schema, import, Some(vars), Some(manager_claims.clone())).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: UpdateAccountingSettingsInput!) {
+            updateClubAccountingSettings(input: $input) { layout cashAccount rakeRevenueAccount }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": { "clubId": club_id.to_string(), "layout": "DATEV", "rakeRevenueAccount": "8401" }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let settings = response.data.into_json().unwrap();
+    assert_eq!(settings["updateClubAccountingSettings"]["layout"], "DATEV");
+    assert_eq!(
+        settings["updateClubAccountingSettings"]["cashAccount"],
+        "1000"
+    );
+
+    let export_vars = |from: &str, to: &str| {
+        Variables::from_json(json!({
+            "input": { "clubId": club_id.to_string(), "from": from, "to": to }
+        }))
+    };
+
+    let response = execute_graphql(
+        &schema,
+        CREATE_EXPORT,
+        Some(export_vars("2025-06-01T00:00:00Z", "2025-07-01T00:00:00Z")),
+        Some(player_claims),
+    )
+    .await;
+    assert!(!response.errors.is_empty(), "only managers export");
+
+    let response = execute_graphql(
+        &schema,
+        CREATE_EXPORT,
+        Some(export_vars("2025-06-01T00:00:00Z", "2025-07-01T00:00:00Z")),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let export = response.data.into_json().unwrap()["createAccountingExport"].clone();
+    assert_eq!(export["layout"], "DATEV");
+    assert_eq!(export["lineCount"], 3);
+    assert_eq!(export["totalCents"], 15_000 + 1_500 + 15_000);
+    let content = export["content"].as_str().unwrap();
+    let lines: Vec<&str> = content.lines().collect();
+    assert!(
+        lines[1].starts_with("150,00;S;1000;1700;0606;"),
+        "{content}"
+    );
+    assert!(lines[2].starts_with("15,00;S;1000;8401;"), "{content}");
+    assert!(lines[3].starts_with("150,00;S;1700;1000;"), "{content}");
+
+    // Stored exports can't be rewritten.
+    let export_id = uuid::Uuid::parse_str(export["id"].as_str().unwrap()).unwrap();
+    let update = sqlx::query("UPDATE accounting_exports SET content = '' WHERE id = $1")
+        .bind(export_id)
+        .execute(&app_state.db)
+        .await;
+    assert!(update.is_err());
+
+    let response = execute_graphql(
+        &schema,
+        "query($clubId: ID!) { accountingExports(clubId: $clubId) { id content } }",
+        Some(Variables::from_json(
+            json!({ "clubId": club_id.to_string() }),
+        )),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let exports = response.data.into_json().unwrap();
+    assert_eq!(exports["accountingExports"][0]["content"], content);
+
+    // Periods without finished tournaments produce no export.
+    let response = execute_graphql(
+        &schema,
+        CREATE_EXPORT,
+        Some(export_vars("2025-07-01T00:00:00Z", "2025-08-01T00:00:00Z")),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(
+        response.errors[0]
+            .message
+            .contains("No finished tournaments"),
+        "{:?}",
+        response.errors
+    );
+}
+
+#[tokio::test]
+async fn test_club_rake_report_groups_by_period() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("rake_mgr_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let (_, player_claims) = create_test_user(
+        &app_state,
+        &format!("rake_player_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Rake Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+
+    // Friday and Sunday of the same week, then the next Monday. Every
+    // tournament is 50.00 plus rake, one buy-in per finisher.
+    let import = r#"
+        mutation($input: ImportTournamentResultsInput!) {
+            importTournamentResults(input: $input) { tournament { id } }
+        }
+    "#;
+    for (name, start, rake, content) in [
+        (
+            "Friday Weekly",
+            "2025-06-06T19:00:00Z",
+            500,
+            "Place,Name,Winnings\n1,Alice,100\n2,Bob,50\n3,Carol,0\n",
+        ),
+        (
+            "Sunday Deepstack",
+            "2025-06-08T15:00:00Z",
+            1000,
+            "Place,Name,Winnings\n1,Alice,100\n2,Bob,0\n",
+        ),
+        (
+            "Monday Turbo",
+            "2025-06-09T20:00:00Z",
+            500,
+            "Place,Name,Winnings\n1,Carol,100\n2,Dave,0\n",
+        ),
+    ] {
+        let vars = Variables::from_json(json!({
+            "input": {
+                "clubId": club_id.to_string(),
+                "format": "CSV",
+                "content": content,
+                "name": name,
+                "startTime": start,
+                "buyInCents": 5000,
+                "rakeCents": rake,
+            }
+        }));
+        let response =
+            execute_graphql(&schema, import, Some(vars), Some(manager_claims.clone())).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+    }
+
+    let report = r#"
+        query($clubId: ID!, $from: DateTime!, $to: DateTime!, $groupBy: RakePeriod) {
+            clubRakeReport(clubId: $clubId, from: $from, to: $to, groupBy: $groupBy) {
+                groupBy
+                periods { periodStart tournamentCount rakedEntries rakeCents prizePoolCents }
+                tournaments { name rakePerEntryCents rakedEntries rakeCents prizePoolCents }
+                rakedEntries totalRakeCents prizePoolCents
+            }
+        }
+    "#;
+    let vars = |group_by: Option<&str>| {
+        Variables::from_json(json!({
+            "clubId": club_id.to_string(),
+            "from": "2025-06-01T00:00:00Z",
+            "to": "2025-07-01T00:00:00Z",
+            "groupBy": group_by,
+        }))
+    };
+
+    let response = execute_graphql(&schema, report, Some(vars(None)), Some(player_claims)).await;
+    assert!(!response.errors.is_empty(), "only managers see the rake");
+
+    let response = execute_graphql(
+        &schema,
+        report,
+        Some(vars(None)),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let daily = response.data.into_json().unwrap()["clubRakeReport"].clone();
+    assert_eq!(daily["groupBy"], "DAY");
+    assert_eq!(daily["rakedEntries"], 7);
+    assert_eq!(daily["totalRakeCents"], 1_500 + 2_000 + 1_000);
+    // Rake is charged on top of the buy-in: the pools hold every buy-in.
+    assert_eq!(daily["prizePoolCents"], 35_000);
+    assert_eq!(
+        daily["tournaments"][1],
+        json!({
+            "name": "Sunday Deepstack",
+            "rakePerEntryCents": 1000,
+            "rakedEntries": 2,
+            "rakeCents": 2000,
+            "prizePoolCents": 10000,
+        })
+    );
+    let days: Vec<&str> = daily["periods"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["periodStart"].as_str().unwrap())
+        .collect();
+    assert_eq!(days, ["2025-06-06", "2025-06-08", "2025-06-09"]);
+
+    let response = execute_graphql(
+        &schema,
+        report,
+        Some(vars(Some("WEEK"))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let weekly = response.data.into_json().unwrap()["clubRakeReport"].clone();
+    assert_eq!(
+        weekly["periods"],
+        json!([
+            { "periodStart": "2025-06-02", "tournamentCount": 2, "rakedEntries": 5, "rakeCents": 3500, "prizePoolCents": 25000 },
+            { "periodStart": "2025-06-09", "tournamentCount": 1, "rakedEntries": 2, "rakeCents": 1000, "prizePoolCents": 10000 },
+        ])
+    );
+
+    let response = execute_graphql(
+        &schema,
+        report,
+        Some(Variables::from_json(json!({
+            "clubId": club_id.to_string(),
+            "from": "2025-07-01T00:00:00Z",
+            "to": "2025-06-01T00:00:00Z",
+        }))),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(!response.errors.is_empty());
+}
@@ -0,0 +1,190 @@
+//! Alternates: an ordered queue seated into seats freed by eliminations
+//! during late registration, moved to the waitlist when it closes.
+
+use api::gql::build_schema;
+use api::gql::domains::registrations::alternates;
+use async_graphql::Variables;
+use fixtures::{Fixtures, TournamentLiveStatus};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::common::*;
+
+const ALTERNATES: &str = r#"
+    query($tournamentId: UUID!) {
+        tournamentAlternates(tournamentId: $tournamentId) {
+            displayName
+            registration { id status alternatePosition }
+        }
+    }
+"#;
+
+const ADD_ALTERNATE: &str = r#"
+    mutation($input: AddTournamentAlternateInput!) {
+        addTournamentAlternate(input: $input) { id status }
+    }
+"#;
+
+async fn registration_status(app: &api::state::AppState, registration_id: &str) -> String {
+    sqlx::query_scalar("SELECT status FROM tournament_registrations WHERE id = $1")
+        .bind(Uuid::parse_str(registration_id).unwrap())
+        .fetch_one(&app.db)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn alternates_take_seats_freed_by_eliminations() {
+    let app = setup_test_db().await;
+    let schema = build_schema(app.clone());
+    let unique = Uuid::new_v4().simple().to_string();
+
+    let (manager_id, manager) =
+        create_test_user(&app, &format!("alt_mgr_{unique}@test.com"), "manager").await;
+    let club_id = create_test_club(&app, "Alternates Club").await;
+    create_club_manager(&app, manager_id, club_id).await;
+    let tournament_id = Fixtures::random()
+        .tournament(club_id)
+        .name("Alternates Tournament")
+        .live_status(TournamentLiveStatus::LateRegistration)
+        .create(&app.db)
+        .await
+        .unwrap();
+
+    // A full two-seat table.
+    let table_id = create_test_club_table(&app, club_id, 1, 2).await;
+    assign_table_to_tournament(&app, tournament_id, table_id).await;
+    let mut seated = Vec::new();
+    for seat in 1..=2 {
+        let (player_id, _) =
+            create_test_user(&app, &format!("alt_p{seat}_{unique}@test.com"), "player").await;
+        create_test_registration(&app, tournament_id, player_id, "seated").await;
+        sqlx::query(
+            "INSERT INTO table_seat_assignments (tournament_id, club_table_id, user_id, seat_number) VALUES ($1, $2, $3, $4)",
+        )
+        .bind(tournament_id)
+        .bind(table_id)
+        .bind(player_id)
+        .bind(seat)
+        .execute(&app.db)
+        .await
+        .unwrap();
+        seated.push(player_id);
+    }
+
+    let mut queued = Vec::new();
+    for name in ["First Alternate", "Second Alternate"] {
+        let club_player_id = Fixtures::random()
+            .club_player(club_id)
+            .display_name(name)
+            .create(&app.db)
+            .await
+            .unwrap();
+        let res = execute_graphql(
+            &schema,
+            ADD_ALTERNATE,
+            Some(Variables::from_json(json!({
+                "input": {
+                    "tournamentId": tournament_id.to_string(),
+                    "clubPlayerId": club_player_id.to_string(),
+                }
+            }))),
+            Some(manager.clone()),
+        )
+        .await;
+        assert!(res.errors.is_empty(), "{:?}", res.errors);
+        let data = res.data.into_json().unwrap();
+        assert_eq!(data["addTournamentAlternate"]["status"], "ALTERNATE");
+        queued.push(
+            data["addTournamentAlternate"]["id"]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        );
+    }
+
+    // The second alternate is moved to the front of the queue.
+    let res = execute_graphql(
+        &schema,
+        r#"mutation($input: ReorderTournamentAlternatesInput!) {
+            reorderTournamentAlternates(input: $input) { displayName }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "registrationIds": [queued[1], queued[0]],
+            }
+        }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data["reorderTournamentAlternates"][0]["displayName"],
+        "Second Alternate"
+    );
+
+    // No elimination yet: nobody is seated.
+    let none = alternates::seat_alternates(&app.db, tournament_id)
+        .await
+        .unwrap();
+    assert!(none.is_empty());
+
+    let res = execute_graphql(
+        &schema,
+        r#"mutation($tournamentId: ID!, $userId: ID!) {
+            eliminatePlayer(tournamentId: $tournamentId, userId: $userId)
+        }"#,
+        Some(Variables::from_json(json!({
+            "tournamentId": tournament_id.to_string(),
+            "userId": seated[0].to_string(),
+        }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+
+    // One bust frees one seat, taken by the head of the queue.
+    let taken = alternates::seat_alternates(&app.db, tournament_id)
+        .await
+        .unwrap();
+    assert_eq!(taken.len(), 1);
+    assert_eq!(taken[0].0.id.to_string(), queued[1]);
+    assert_eq!(registration_status(&app, &queued[1]).await, "seated");
+    let again = alternates::seat_alternates(&app.db, tournament_id)
+        .await
+        .unwrap();
+    assert!(again.is_empty(), "an elimination is only used once");
+
+    let res = execute_graphql(
+        &schema,
+        ALTERNATES,
+        Some(Variables::from_json(
+            json!({ "tournamentId": tournament_id.to_string() }),
+        )),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    let waiting = data["tournamentAlternates"].as_array().unwrap();
+    assert_eq!(waiting.len(), 1);
+    assert_eq!(waiting[0]["registration"]["alternatePosition"], 1);
+
+    // Late registration closes: the alternate left, who never bought in,
+    // goes to the waitlist.
+    let res = execute_graphql(
+        &schema,
+        r#"mutation($input: UpdateTournamentStatusInput!) {
+            updateTournamentStatus(input: $input) { liveStatus }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": { "tournamentId": tournament_id.to_string(), "liveStatus": "IN_PROGRESS" }
+        }))),
+        Some(manager),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    assert_eq!(registration_status(&app, &queued[0]).await, "waitlisted");
+}
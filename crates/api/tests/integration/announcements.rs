@@ -1,6 +1,7 @@
 use crate::common::*;
 use api::gql::build_schema;
-use async_graphql::Variables;
+use async_graphql::{Request, Variables};
+use futures_util::StreamExt;
 use serde_json::json;
 use uuid::Uuid;
 
@@ -203,3 +204,211 @@ async fn test_announcement_preference_toggle() {
     let data = resp.data.into_json().unwrap();
     assert_eq!(data["myNotificationPreferences"]["announcements"], false);
 }
+
+type TestSchema =
+    async_graphql::Schema<api::gql::QueryRoot, api::gql::MutationRoot, api::gql::SubscriptionRoot>;
+
+const BROADCAST: &str = r#"
+    mutation Broadcast(
+        $tournamentId: ID!
+        $message: String!
+        $channels: [BroadcastChannel!]!
+        $scheduledFor: DateTime
+    ) {
+        broadcastAnnouncement(
+            tournamentId: $tournamentId
+            message: $message
+            channels: $channels
+            scheduledFor: $scheduledFor
+        ) {
+            id
+            title
+            body
+            priority
+            channels
+            pinned
+            sentAt
+        }
+    }
+"#;
+
+async fn pinned_bodies(
+    schema: &TestSchema,
+    tournament_id: Uuid,
+    claims: &api::auth::Claims,
+) -> Vec<String> {
+    let query = format!(
+        "query {{ tournament(id: \"{tournament_id}\") {{ pinnedAnnouncements {{ body }} }} }}"
+    );
+    let resp = execute_graphql(schema, &query, None, Some(claims.clone())).await;
+    assert!(resp.errors.is_empty(), "pinned: {:?}", resp.errors);
+    let data = resp.data.into_json().unwrap();
+    data["tournament"]["pinnedAnnouncements"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a["body"].as_str().unwrap().to_string())
+        .collect()
+}
+
+/// A floor broadcast reaches registered players live, is pinned to the lobby,
+/// and scheduled broadcasts stay hidden until the dispatcher sends them.
+#[tokio::test]
+async fn test_floor_broadcast_and_scheduling() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager_claims) =
+        create_test_user(&app_state, "ann_floor_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Floor Broadcast Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Sunday Main").await;
+
+    let (player_id, player_claims) =
+        create_test_user(&app_state, "ann_floor_player@test.com", "player").await;
+    create_test_registration(&app_state, tournament_id, player_id, "registered").await;
+
+    let vars = |message: &str, scheduled_for: Option<String>| {
+        Variables::from_json(json!({
+            "tournamentId": tournament_id.to_string(),
+            "message": message,
+            "channels": ["IN_APP", "PUSH", "SMS"],
+            "scheduledFor": scheduled_for,
+        }))
+    };
+
+    // Players can't broadcast.
+    let resp = execute_graphql(
+        &schema,
+        BROADCAST,
+        Some(vars("Shuffle up", None)),
+        Some(player_claims.clone()),
+    )
+    .await;
+    assert!(!resp.errors.is_empty(), "players can't broadcast");
+
+    // Registered players get it live. Streams subscribe on first poll.
+    let mut stream = schema.execute_stream(
+        Request::new("subscription { userNotifications { notificationType title message } }")
+            .data(player_claims.clone()),
+    );
+    let next_event = tokio::spawn(async move { stream.next().await });
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    let resp = execute_graphql(
+        &schema,
+        BROADCAST,
+        Some(vars("  Last hand before the break  ", None)),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "broadcast: {:?}", resp.errors);
+    let sent = resp.data.into_json().unwrap()["broadcastAnnouncement"].clone();
+    assert_eq!(sent["title"], "Sunday Main");
+    assert_eq!(sent["body"], "Last hand before the break");
+    assert_eq!(sent["priority"], "HIGH");
+    assert_eq!(sent["channels"], json!(["IN_APP", "PUSH", "SMS"]));
+    assert_eq!(sent["pinned"], true);
+    assert!(sent["sentAt"].is_string());
+
+    let event = tokio::time::timeout(std::time::Duration::from_secs(5), next_event)
+        .await
+        .expect("notification")
+        .unwrap()
+        .unwrap();
+    assert!(event.errors.is_empty(), "{:?}", event.errors);
+    let event = event.data.into_json().unwrap();
+    assert_eq!(
+        event["userNotifications"]["notificationType"],
+        "FLOOR_ANNOUNCEMENT"
+    );
+    assert_eq!(
+        event["userNotifications"]["message"],
+        "Last hand before the break"
+    );
+
+    assert_eq!(
+        pinned_bodies(&schema, tournament_id, &player_claims).await,
+        vec!["Last hand before the break"]
+    );
+
+    // A scheduled broadcast is pending: not pinned, not in the feed yet.
+    let in_ten = (chrono::Utc::now() + chrono::Duration::minutes(10)).to_rfc3339();
+    let resp = execute_graphql(
+        &schema,
+        BROADCAST,
+        Some(vars("Dinner break in 10 min", Some(in_ten.clone()))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "schedule: {:?}", resp.errors);
+    let scheduled = resp.data.into_json().unwrap()["broadcastAnnouncement"].clone();
+    assert!(scheduled["sentAt"].is_null());
+    assert_eq!(
+        pinned_bodies(&schema, tournament_id, &player_claims).await,
+        vec!["Last hand before the break"]
+    );
+    let resp = execute_graphql(&schema, FEED, None, Some(player_claims.clone())).await;
+    assert_eq!(feed_titles(&resp).len(), 1);
+
+    // A second scheduled one is cancelled before it goes out.
+    let resp = execute_graphql(
+        &schema,
+        BROADCAST,
+        Some(vars("Cancelled", Some(in_ten))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    let cancelled_id = resp.data.into_json().unwrap()["broadcastAnnouncement"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let cancel = r#"mutation($id: ID!) { cancelScheduledAnnouncement(announcementId: $id) }"#;
+    let resp = execute_graphql(
+        &schema,
+        cancel,
+        Some(Variables::from_json(json!({ "id": cancelled_id }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "cancel: {:?}", resp.errors);
+
+    // Past the due time, the dispatcher sends it.
+    let sent_count = api::gql::domains::announcements::service::dispatch_due(
+        &app_state,
+        chrono::Utc::now() + chrono::Duration::minutes(11),
+    )
+    .await
+    .unwrap();
+    assert!(sent_count >= 1);
+    assert_eq!(
+        pinned_bodies(&schema, tournament_id, &player_claims).await,
+        vec!["Dinner break in 10 min", "Last hand before the break"]
+    );
+
+    // Sent broadcasts can't be cancelled, but can be unpinned.
+    let resp = execute_graphql(
+        &schema,
+        cancel,
+        Some(Variables::from_json(json!({ "id": scheduled["id"] }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(!resp.errors.is_empty(), "already sent");
+
+    let unpin = r#"mutation($id: ID!) {
+        setAnnouncementPinned(announcementId: $id, pinned: false) { pinned }
+    }"#;
+    let resp = execute_graphql(
+        &schema,
+        unpin,
+        Some(Variables::from_json(json!({ "id": scheduled["id"] }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "unpin: {:?}", resp.errors);
+    assert_eq!(
+        pinned_bodies(&schema, tournament_id, &player_claims).await,
+        vec!["Last hand before the break"]
+    );
+}
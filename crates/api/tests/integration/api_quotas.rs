@@ -0,0 +1,68 @@
+use crate::common::*;
+use api::gql::build_schema;
+use api::middleware::quota::Principal;
+use async_graphql::Request;
+
+const API_USAGE: &str = r#"
+    query {
+        apiUsage {
+            requestsPerMinute
+            maxSubscriptions
+            principals { principal userId complexityThisMinute }
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn test_metered_queries_show_up_in_admin_usage() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (admin_id, admin_claims) = create_test_user(
+        &app_state,
+        &format!("quota_admin_{unique}@test.com"),
+        "admin",
+    )
+    .await;
+    let (kiosk_id, kiosk_claims) = create_test_user(
+        &app_state,
+        &format!("quota_kiosk_{unique}@test.com"),
+        "player",
+    )
+    .await;
+
+    // A request charged to the kiosk's account by the HTTP middleware.
+    let response = schema
+        .execute(
+            Request::new("query { me { id email } }")
+                .data(kiosk_claims.clone())
+                .data(Principal::User(kiosk_id)),
+        )
+        .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    // Only admins can look.
+    let response = execute_graphql(&schema, API_USAGE, None, Some(kiosk_claims)).await;
+    assert!(!response.errors.is_empty());
+
+    let response = execute_graphql(&schema, API_USAGE, None, Some(admin_claims)).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let usage = &data["apiUsage"];
+    assert!(usage["requestsPerMinute"].as_i64().unwrap() >= 0);
+    let kiosk = usage["principals"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|p| p["userId"] == kiosk_id.to_string())
+        .expect("kiosk usage is reported");
+    assert_eq!(kiosk["principal"], format!("user:{kiosk_id}"));
+    assert!(kiosk["complexityThisMinute"].as_i64().unwrap() > 0);
+    // Unmetered in-process calls (like the admin's here) are not recorded.
+    assert!(!usage["principals"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|p| p["userId"] == admin_id.to_string()));
+}
@@ -123,3 +123,142 @@ async fn tournament_entries_is_manager_only() {
         "a non-manager player must be denied tournamentEntries"
     );
 }
+
+fn field_error_code(resp: &async_graphql::Response) -> Option<String> {
+    resp.errors
+        .iter()
+        .find_map(|e| match e.extensions.as_ref()?.get("code")? {
+            async_graphql::Value::String(code) => Some(code.clone()),
+            _ => None,
+        })
+}
+
+/// `User.email` / `User.phone` and `TournamentEntry.amountCents` are guarded
+/// on the field, whatever query reached the object: the `users` list is open
+/// to anyone, and seating charts, registrations and leaderboards nest users.
+#[tokio::test]
+async fn personal_data_and_entry_amounts_are_field_guarded() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+    let (manager_id, manager) =
+        create_test_user(&app_state, &format!("fg_mgr_{suffix}@test.com"), "manager").await;
+    let (other_manager_id, other_manager) = create_test_user(
+        &app_state,
+        &format!("fg_other_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let (_, admin) =
+        create_test_user(&app_state, &format!("fg_admin_{suffix}@test.com"), "admin").await;
+    let (player_id, player) = create_test_user(
+        &app_state,
+        &format!("fg_player_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let (_, stranger) = create_test_user(
+        &app_state,
+        &format!("fg_stranger_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Field Guard Club").await;
+    let other_club_id = create_test_club(&app_state, "Other Field Guard Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    create_club_manager(&app_state, other_manager_id, other_club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Field Guard Cup").await;
+    // Registering puts the player on the club's roster.
+    create_test_registration(&app_state, tournament_id, player_id, "registered").await;
+
+    let users_query = r#"query($search: String){
+        users(search: $search){ items { id firstName email phone } }
+    }"#;
+    // Test users' usernames are `test_<id>`.
+    let users_vars = || {
+        Some(Variables::from_json(
+            json!({ "search": format!("test_{player_id}") }),
+        ))
+    };
+
+    for (label, claims) in [
+        ("the player themself", player.clone()),
+        ("a manager of their club", manager.clone()),
+        ("an admin", admin.clone()),
+    ] {
+        let resp = execute_graphql(&schema, users_query, users_vars(), Some(claims)).await;
+        assert!(resp.errors.is_empty(), "{label}: {:?}", resp.errors);
+        let data = resp.data.into_json().unwrap();
+        assert_eq!(
+            data["users"]["items"][0]["email"],
+            json!(format!("fg_player_{suffix}@test.com")),
+            "{label} sees the email"
+        );
+    }
+
+    for (label, claims) in [
+        ("another player", stranger.clone()),
+        ("a manager of another club", other_manager.clone()),
+    ] {
+        let resp = execute_graphql(&schema, users_query, users_vars(), Some(claims)).await;
+        assert_eq!(
+            field_error_code(&resp).as_deref(),
+            Some("FORBIDDEN"),
+            "{label} must not see contact details; got {:?}",
+            resp.errors
+        );
+        assert!(
+            !serde_json::to_string(&resp.data.into_json().unwrap())
+                .unwrap()
+                .contains("@test.com"),
+            "{label}: no email may leak"
+        );
+    }
+
+    let anon = execute_graphql(&schema, users_query, users_vars(), None).await;
+    assert!(is_unauthenticated(&anon), "anonymous: {:?}", anon.errors);
+
+    // Public fields stay readable for everyone.
+    let public = execute_graphql(
+        &schema,
+        r#"query($search: String){ users(search: $search){ items { id firstName } } }"#,
+        users_vars(),
+        Some(stranger),
+    )
+    .await;
+    assert!(public.errors.is_empty(), "{:?}", public.errors);
+
+    // Entry amounts: the tournament's club managers and admins.
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($input: AddTournamentEntryInput!){ addTournamentEntry(input: $input){ id amountCents } }"#,
+        Some(Variables::from_json(json!({ "input": {
+            "tournamentId": tournament_id.to_string(),
+            "userId": player_id.to_string(),
+            "entryType": "INITIAL",
+            "amountCents": 5000,
+        }}))),
+        Some(manager),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "{:?}", resp.errors);
+    assert_eq!(
+        resp.data.into_json().unwrap()["addTournamentEntry"]["amountCents"],
+        json!(5000)
+    );
+    let resp = execute_graphql(
+        &schema,
+        r#"query($id: ID!){ tournamentEntries(tournamentId: $id){ amountCents } }"#,
+        Some(Variables::from_json(
+            json!({ "id": tournament_id.to_string() }),
+        )),
+        Some(admin),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "{:?}", resp.errors);
+    assert_eq!(
+        resp.data.into_json().unwrap()["tournamentEntries"][0]["amountCents"],
+        json!(5000)
+    );
+}
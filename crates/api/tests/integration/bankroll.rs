@@ -0,0 +1,130 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+
+const HISTORY: &str = r#"
+    query {
+        myBankrollHistory {
+            transactions { id kind amountCents source venue tournamentId }
+            months { month buyInsCents cashesCents netCents cumulativeCents }
+            totalBuyInsCents
+            totalCashesCents
+            netCents
+        }
+    }
+"#;
+
+const IMPORT: &str = "mutation { importMyTournamentBankroll }";
+
+#[tokio::test]
+async fn test_bankroll_imports_tournaments_and_stays_private() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("bankroll_mgr_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let player_email = format!("bankroll_player_{suffix}@test.com");
+    let (_, player_claims) = create_test_user(&app_state, &player_email, "player").await;
+    let club_id = create_test_club(&app_state, "Bankroll Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+
+    // The player won 100.00 from a 50.00 + 5.00 tournament in February.
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: ImportTournamentResultsInput!) {
+            importTournamentResults(input: $input) { tournament { id } }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "clubId": club_id.to_string(),
+                "format": "CSV",
+                "content": format!("Place,Name,Email,Winnings\n1,Player,{player_email},100\n2,Other,,0\n"),
+                "name": "February Freezeout",
+                "startTime": "2025-02-14T19:00:00Z",
+                "buyInCents": 5000,
+                "rakeCents": 500,
+            }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let response = execute_graphql(&schema, IMPORT, None, Some(player_claims.clone())).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap()["importMyTournamentBankroll"],
+        2
+    );
+
+    // Re-running changes nothing.
+    let response = execute_graphql(&schema, IMPORT, None, Some(player_claims.clone())).await;
+    assert_eq!(
+        response.data.into_json().unwrap()["importMyTournamentBankroll"],
+        0
+    );
+
+    // An outside game in March.
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: AddBankrollTransactionInput!) {
+            addBankrollTransaction(input: $input) { id kind source }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "kind": "BUY_IN",
+                "amountCents": 20000,
+                "occurredAt": "2025-03-08T20:00:00Z",
+                "venue": "  Casino  ",
+            }
+        }))),
+        Some(player_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let manual = response.data.into_json().unwrap()["addBankrollTransaction"].clone();
+    assert_eq!(manual["source"], "MANUAL");
+
+    let response = execute_graphql(&schema, HISTORY, None, Some(player_claims.clone())).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let history = response.data.into_json().unwrap()["myBankrollHistory"].clone();
+    let transactions = history["transactions"].as_array().unwrap();
+    assert_eq!(transactions.len(), 3);
+    assert_eq!(transactions[0]["venue"], "Casino");
+    let imported_buy_in = transactions
+        .iter()
+        .find(|t| t["source"] == "IMPORT" && t["kind"] == "BUY_IN")
+        .unwrap();
+    assert_eq!(imported_buy_in["amountCents"], 5_500);
+    assert_eq!(imported_buy_in["venue"], "Bankroll Club");
+    assert_eq!(
+        history["months"],
+        json!([
+            { "month": "2025-02", "buyInsCents": 5_500, "cashesCents": 10_000, "netCents": 4_500, "cumulativeCents": 4_500 },
+            { "month": "2025-03", "buyInsCents": 20_000, "cashesCents": 0, "netCents": -20_000, "cumulativeCents": -15_500 },
+        ])
+    );
+    assert_eq!(history["netCents"], -15_500);
+
+    // Nobody else sees or touches the ledger, the club's manager included.
+    let response = execute_graphql(&schema, HISTORY, None, Some(manager_claims.clone())).await;
+    let other = response.data.into_json().unwrap();
+    assert_eq!(other["myBankrollHistory"]["transactions"], json!([]));
+
+    let delete = r#"mutation($id: ID!) { deleteBankrollTransaction(id: $id) }"#;
+    let vars = Variables::from_json(json!({ "id": manual["id"] }));
+    let response = execute_graphql(&schema, delete, Some(vars.clone()), Some(manager_claims)).await;
+    assert!(!response.errors.is_empty());
+
+    let response = execute_graphql(&schema, delete, Some(vars), Some(player_claims.clone())).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let response = execute_graphql(&schema, HISTORY, None, None).await;
+    assert!(!response.errors.is_empty(), "requires authentication");
+}
@@ -0,0 +1,232 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+const ISSUE: &str = r#"
+    mutation($input: IssueBuyInCreditInput!) {
+        issueBuyInCredit(input: $input) { id code kind clubPlayerId totalUses remainingUses }
+    }
+"#;
+
+const REGISTER: &str = r#"
+    mutation($input: RegisterForTournamentInput!) {
+        registerForTournament(input: $input) { id }
+    }
+"#;
+
+fn register_vars(tournament_id: Uuid, code: &str) -> Option<Variables> {
+    Some(Variables::from_json(json!({
+        "input": { "tournamentId": tournament_id.to_string(), "voucherCode": code }
+    })))
+}
+
+/// A package is redeemed at registration, pays the buy-in at the desk and
+/// gets its use back when a registration is cancelled; vouchers can be
+/// bearer codes and stop working once voided.
+#[tokio::test]
+async fn test_buy_in_packages_and_vouchers() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) =
+        create_test_user(&app_state, "credits_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Credits Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let first = create_open_tournament(&app_state, club_id, "Monday Turbo").await;
+    let second = create_open_tournament(&app_state, club_id, "Tuesday Deepstack").await;
+    let (player_id, player) =
+        create_test_user(&app_state, "credits_player@test.com", "player").await;
+    let (_, other) = create_test_user(&app_state, "credits_other@test.com", "player").await;
+    let club_player_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO club_player (club_id, display_name, app_user_id) \
+         VALUES ($1, 'Credits Player', $2) RETURNING id",
+    )
+    .bind(club_id)
+    .bind(player_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+
+    let issue = |input: serde_json::Value| {
+        let mut full = json!({ "clubId": club_id.to_string() });
+        full.as_object_mut()
+            .unwrap()
+            .extend(input.as_object().unwrap().clone());
+        Some(Variables::from_json(json!({ "input": full })))
+    };
+
+    // Packages are sold to someone; only managers issue credits.
+    let resp = execute_graphql(
+        &schema,
+        ISSUE,
+        issue(json!({ "kind": "PACKAGE", "label": "2 tournament bundle", "uses": 2 })),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(!resp.errors.is_empty());
+    let package_input = json!({
+        "kind": "PACKAGE",
+        "label": "2 tournament bundle",
+        "uses": 2,
+        "clubPlayerId": club_player_id.to_string(),
+    });
+    let resp = execute_graphql(
+        &schema,
+        ISSUE,
+        issue(package_input.clone()),
+        Some(player.clone()),
+    )
+    .await;
+    assert!(!resp.errors.is_empty());
+    let resp = execute_graphql(&schema, ISSUE, issue(package_input), Some(manager.clone())).await;
+    assert!(resp.errors.is_empty(), "issue: {:?}", resp.errors);
+    let package = resp.data.into_json().unwrap()["issueBuyInCredit"].clone();
+    assert_eq!(package["remainingUses"], 2);
+    let package_id = package["id"].as_str().unwrap().to_string();
+    let code = package["code"].as_str().unwrap().to_string();
+
+    // Codes are typed loosely; the registration redeems one use.
+    let typed = code.to_lowercase().replace('-', " ");
+    let resp = execute_graphql(
+        &schema,
+        REGISTER,
+        register_vars(first, &typed),
+        Some(player.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "register: {:?}", resp.errors);
+
+    // The desk's buy-in defaults to the voucher and is linked to the use.
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($input: AddTournamentEntryInput!) {
+            addTournamentEntry(input: $input) { id paymentMethod }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": first.to_string(),
+                "userId": player_id.to_string(),
+                "entryType": "INITIAL",
+            }
+        }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "entry: {:?}", resp.errors);
+    let entry = resp.data.into_json().unwrap()["addTournamentEntry"].clone();
+    assert_eq!(entry["paymentMethod"], "VOUCHER");
+
+    // A cancelled registration gives its use back.
+    let resp = execute_graphql(
+        &schema,
+        REGISTER,
+        register_vars(second, &code),
+        Some(player.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "register: {:?}", resp.errors);
+    let resp = execute_graphql(
+        &schema,
+        r#"{ myBuyInCredits { id remainingUses } }"#,
+        None,
+        Some(player.clone()),
+    )
+    .await;
+    let data = resp.data.into_json().unwrap();
+    assert_eq!(data["myBuyInCredits"][0]["remainingUses"], 0);
+    let resp = execute_graphql(
+        &schema,
+        REGISTER,
+        register_vars(second, &code),
+        Some(other.clone()),
+    )
+    .await;
+    assert_eq!(
+        resp.errors[0].message,
+        "This voucher belongs to another player"
+    );
+
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($input: CancelRegistrationInput!) {
+            cancelRegistration(input: $input) { registration { status } }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": { "tournamentId": second.to_string(), "userId": player_id.to_string() }
+        }))),
+        Some(player.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "cancel: {:?}", resp.errors);
+
+    let ledger = r#"query($id: ID!) {
+        buyInCreditLedger(creditId: $id) { kind delta entryId tournamentName }
+    }"#;
+    let ledger_vars = Some(Variables::from_json(json!({ "id": package_id })));
+    let resp = execute_graphql(&schema, ledger, ledger_vars.clone(), Some(other.clone())).await;
+    assert!(!resp.errors.is_empty());
+    let resp = execute_graphql(&schema, ledger, ledger_vars, Some(player.clone())).await;
+    assert!(resp.errors.is_empty(), "ledger: {:?}", resp.errors);
+    let data = resp.data.into_json().unwrap();
+    let ledger = data["buyInCreditLedger"].as_array().unwrap();
+    let kinds: Vec<_> = ledger.iter().map(|l| l["kind"].as_str().unwrap()).collect();
+    assert_eq!(kinds, ["REFUND", "REDEMPTION", "REDEMPTION", "ISSUE"]);
+    assert_eq!(ledger[0]["tournamentName"], "Tuesday Deepstack");
+    assert_eq!(ledger[2]["entryId"], entry["id"]);
+    assert_eq!(
+        ledger
+            .iter()
+            .map(|l| l["delta"].as_i64().unwrap())
+            .sum::<i64>(),
+        1
+    );
+
+    // A bearer voucher becomes the first redeemer's; a voided one is refused
+    // and the registration doesn't go through.
+    let resp = execute_graphql(
+        &schema,
+        ISSUE,
+        issue(json!({ "kind": "VOUCHER", "label": "Freeroll prize" })),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "voucher: {:?}", resp.errors);
+    let voucher = resp.data.into_json().unwrap()["issueBuyInCredit"].clone();
+    assert_eq!(voucher["clubPlayerId"], serde_json::Value::Null);
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($id: ID!) { voidBuyInCredit(creditId: $id) { voidedAt } }"#,
+        Some(Variables::from_json(json!({ "id": voucher["id"] }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "void: {:?}", resp.errors);
+    let resp = execute_graphql(
+        &schema,
+        REGISTER,
+        register_vars(second, voucher["code"].as_str().unwrap()),
+        Some(other.clone()),
+    )
+    .await;
+    assert_eq!(resp.errors[0].message, "This voucher has been voided");
+    let resp = execute_graphql(
+        &schema,
+        REGISTER,
+        register_vars(second, "ZZZZZ-ZZZZZ"),
+        Some(other.clone()),
+    )
+    .await;
+    assert_eq!(resp.errors[0].message, "Unknown voucher code");
+
+    let resp = execute_graphql(
+        &schema,
+        r#"query($club: ID!) { clubBuyInCredits(clubId: $club) { kind remainingUses } }"#,
+        Some(Variables::from_json(json!({ "club": club_id.to_string() }))),
+        Some(manager.clone()),
+    )
+    .await;
+    let data = resp.data.into_json().unwrap();
+    assert_eq!(data["clubBuyInCredits"].as_array().unwrap().len(), 2);
+}
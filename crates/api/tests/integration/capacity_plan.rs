@@ -0,0 +1,165 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use chrono::{Duration, Utc};
+use serde_json::json;
+
+const CAPACITY_PLAN: &str = r#"
+    query($tournamentId: ID!, $expectedEntries: Int, $seatsPerTable: Int) {
+        capacityPlan(
+            tournamentId: $tournamentId
+            expectedEntries: $expectedEntries
+            seatsPerTable: $seatsPerTable
+        ) {
+            expectedEntries seatsPerTable basedOnTournaments eliminationRatePerHour
+            lateEntryShare playersAtStart tablesAtStart peakTables
+            lateRegistrationClosesAt playersAfterLateRegistration tablesAfterLateRegistration
+            projectedFinalTableAt projectedFinalTableLevel
+        }
+    }
+"#;
+
+/// The plan learns the elimination rate and late-entry share from the club's
+/// finished tournaments and projects tables and the final table from them.
+#[tokio::test]
+async fn test_capacity_plan_from_club_history() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) =
+        create_test_user(&app_state, "capacity_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Capacity Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let table_id = create_test_club_table(&app_state, club_id, 1, 8).await;
+
+    // A finished tournament: 16 players, 4 of them late, 14 busted within
+    // four hours (the field shrank 8x, i.e. halved every 80 minutes).
+    let past = create_test_tournament(&app_state, club_id, "Last Friday").await;
+    let past_start = Utc::now() - Duration::days(7);
+    sqlx::query("UPDATE tournaments SET start_time = $2, live_status = 'finished' WHERE id = $1")
+        .bind(past)
+        .bind(past_start)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    for i in 0..16 {
+        let (user_id, _) =
+            create_test_user(&app_state, &format!("capacity_p{i}@test.com"), "player").await;
+        let registration_id = create_test_registration(&app_state, past, user_id, "busted").await;
+        let registered_at = past_start + Duration::minutes(if i < 4 { 30 } else { -30 });
+        sqlx::query("UPDATE tournament_registrations SET registration_time = $2 WHERE id = $1")
+            .bind(registration_id)
+            .bind(registered_at)
+            .execute(&app_state.db)
+            .await
+            .unwrap();
+        if i >= 14 {
+            continue;
+        }
+        sqlx::query(
+            "INSERT INTO table_seat_assignments \
+                 (tournament_id, club_table_id, user_id, club_player_id, seat_number, \
+                  stack_size, is_current, assigned_at, unassigned_at) \
+             SELECT $1, $2, user_id, club_player_id, 1, 0, false, $3, $4 \
+             FROM tournament_registrations WHERE id = $5",
+        )
+        .bind(past)
+        .bind(table_id)
+        .bind(past_start)
+        .bind(past_start + Duration::minutes(240 * (i + 1) / 14))
+        .bind(registration_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    }
+
+    // Tonight: ten 30-minute levels, late registration through level 4.
+    let tournament_id = create_test_tournament(&app_state, club_id, "Friday Deepstack").await;
+    let start = Utc::now() + Duration::hours(1);
+    sqlx::query(
+        "UPDATE tournaments SET start_time = $2, late_registration_level = 4 WHERE id = $1",
+    )
+    .bind(tournament_id)
+    .bind(start)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+    sqlx::query("DELETE FROM tournament_structures WHERE tournament_id = $1")
+        .bind(tournament_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    for level in 1..=10 {
+        sqlx::query(
+            "INSERT INTO tournament_structures \
+                 (tournament_id, level_number, small_blind, big_blind, duration_minutes) \
+             VALUES ($1, $2, $3, $4, 30)",
+        )
+        .bind(tournament_id)
+        .bind(level)
+        .bind(100 * level)
+        .bind(200 * level)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    }
+
+    let vars = |extra: serde_json::Value| {
+        let mut vars = json!({ "tournamentId": tournament_id.to_string() });
+        vars.as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        Some(Variables::from_json(vars))
+    };
+    let (_, player) = create_test_user(&app_state, "capacity_player@test.com", "player").await;
+    let resp = execute_graphql(&schema, CAPACITY_PLAN, vars(json!({})), Some(player)).await;
+    assert!(!resp.errors.is_empty(), "players can't plan capacity");
+    let resp = execute_graphql(
+        &schema,
+        CAPACITY_PLAN,
+        vars(json!({ "seatsPerTable": 20 })),
+        Some(manager.clone()),
+    )
+    .await;
+    assert_eq!(
+        resp.errors[0].message,
+        "Seats per table must be between 2 and 12"
+    );
+
+    let resp = execute_graphql(
+        &schema,
+        CAPACITY_PLAN,
+        vars(json!({ "expectedEntries": 48 })),
+        Some(manager),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "capacityPlan: {:?}", resp.errors);
+    let data = resp.data.into_json().unwrap();
+    let plan = &data["capacityPlan"];
+    assert_eq!(plan["seatsPerTable"], 8, "from the club's only table");
+    assert_eq!(plan["basedOnTournaments"], 1);
+    assert_eq!(plan["lateEntryShare"], 0.25);
+    let hourly = plan["eliminationRatePerHour"].as_f64().unwrap();
+    assert!(
+        (hourly - (1.0 - 0.5f64.powf(0.75))).abs() < 0.01,
+        "{hourly}"
+    );
+    assert_eq!(plan["playersAtStart"], 36);
+    assert_eq!(plan["tablesAtStart"], 5);
+    assert_eq!(plan["peakTables"], 5);
+    let closes_at: chrono::DateTime<Utc> =
+        serde_json::from_value(plan["lateRegistrationClosesAt"].clone()).unwrap();
+    assert_eq!(
+        closes_at.timestamp(),
+        (start + Duration::minutes(120)).timestamp()
+    );
+    // 36·2^-1.5 ≈ 13 starters and ≈ 7 late entrants left: three tables.
+    assert_eq!(plan["playersAfterLateRegistration"], 20);
+    assert_eq!(plan["tablesAfterLateRegistration"], 3);
+    // 20 → 8 players takes ~107 more minutes: level 8 (210–240 min).
+    assert_eq!(plan["projectedFinalTableLevel"], 8);
+    let final_table_at: chrono::DateTime<Utc> =
+        serde_json::from_value(plan["projectedFinalTableAt"].clone()).unwrap();
+    let minutes = (final_table_at - start).num_minutes();
+    assert!((220..=235).contains(&minutes), "{minutes}");
+}
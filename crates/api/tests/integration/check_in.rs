@@ -305,3 +305,166 @@ async fn test_check_in_unauthorized() {
         response.errors[0].message
     );
 }
+
+#[tokio::test]
+async fn test_check_in_rolls_back_bonus_when_seating_fails() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("atomic_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let (player_id, _) = create_test_user(
+        &app_state,
+        &format!("atomic_player_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Atomic Check-In Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id =
+        create_test_tournament(&app_state, club_id, "Atomic Check-In Tournament").await;
+    let table_id = create_test_club_table(&app_state, club_id, 1, 9).await;
+    assign_table_to_tournament(&app_state, tournament_id, table_id).await;
+    create_test_registration(&app_state, tournament_id, player_id, "registered").await;
+    sqlx::query("UPDATE tournaments SET early_bird_bonus_chips = 5000 WHERE id = $1")
+        .bind(tournament_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO tournament_entries (tournament_id, user_id, entry_type, amount_cents, chips_received) \
+         VALUES ($1, $2, 'initial', 5000, 20000)",
+    )
+    .bind(tournament_id)
+    .bind(player_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+    // A stale seat the player still holds makes placing them fail on the
+    // one-current-seat-per-player key, after the status and bonus are written.
+    sqlx::query(
+        "INSERT INTO table_seat_assignments (tournament_id, club_table_id, user_id, seat_number) \
+         VALUES ($1, $2, $3, 9)",
+    )
+    .bind(tournament_id)
+    .bind(table_id)
+    .bind(player_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    let query = r#"
+        mutation CheckInPlayer($input: CheckInPlayerInput!) {
+            checkInPlayer(input: $input) { registration { status } }
+        }
+    "#;
+    let variables = Variables::from_json(json!({
+        "input": {
+            "tournamentId": tournament_id.to_string(),
+            "userId": player_id.to_string(),
+            "grantEarlyBirdBonus": true
+        }
+    }));
+    let response = execute_graphql(&schema, query, Some(variables), Some(manager_claims)).await;
+    assert!(!response.errors.is_empty(), "seating must fail");
+
+    // Nothing of the check-in stuck: no status change, no bonus.
+    let status: String = sqlx::query_scalar(
+        "SELECT status FROM tournament_registrations WHERE tournament_id = $1 AND user_id = $2",
+    )
+    .bind(tournament_id)
+    .bind(player_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(status, "registered");
+    let chips: Option<i32> = sqlx::query_scalar(
+        "SELECT chips_received FROM tournament_entries \
+         WHERE tournament_id = $1 AND user_id = $2 AND entry_type = 'initial'",
+    )
+    .bind(tournament_id)
+    .bind(player_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(chips, Some(20000));
+}
+
+#[tokio::test]
+async fn test_concurrent_roster_check_in_seats_once() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("twice_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Twice Check-In Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id =
+        create_test_tournament(&app_state, club_id, "Twice Check-In Tournament").await;
+    let table_id = create_test_club_table(&app_state, club_id, 1, 9).await;
+    assign_table_to_tournament(&app_state, tournament_id, table_id).await;
+    let club_player_id: uuid::Uuid = sqlx::query_scalar(
+        "INSERT INTO club_player (club_id, display_name) VALUES ($1, 'Walk-in') RETURNING id",
+    )
+    .bind(club_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO tournament_registrations (tournament_id, club_player_id, status) \
+         VALUES ($1, $2, 'registered')",
+    )
+    .bind(tournament_id)
+    .bind(club_player_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    let query = r#"
+        mutation CheckInPlayer($input: CheckInPlayerInput!) {
+            checkInPlayer(input: $input) { registration { status } }
+        }
+    "#;
+    let check_in = || {
+        execute_graphql(
+            &schema,
+            query,
+            Some(Variables::from_json(json!({
+                "input": {
+                    "tournamentId": tournament_id.to_string(),
+                    "clubPlayerId": club_player_id.to_string()
+                }
+            }))),
+            Some(manager_claims.clone()),
+        )
+    };
+
+    // Two desks checking the same player in at once: one wins, the other
+    // sees the player already checked in.
+    let (first, second) = tokio::join!(check_in(), check_in());
+    let succeeded = [&first, &second]
+        .iter()
+        .filter(|r| r.errors.is_empty())
+        .count();
+    assert_eq!(succeeded, 1, "{:?} / {:?}", first.errors, second.errors);
+
+    let seats: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM table_seat_assignments WHERE tournament_id = $1 AND club_player_id = $2",
+    )
+    .bind(tournament_id)
+    .bind(club_player_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(seats, 1);
+}
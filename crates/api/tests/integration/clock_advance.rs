@@ -4,7 +4,12 @@
 //! 5s loop can't be unit-tested against directly.)
 
 use crate::common::*;
+use api::gql::build_schema;
+use api::gql::domains::tournaments::clock::close_late_registration_if_due;
+use async_graphql::Variables;
+use infra::repos::tournaments::TournamentLiveStatus;
 use infra::repos::{tournament_clock, tournaments};
+use serde_json::json;
 use uuid::Uuid;
 
 async fn seed_structures(db: &sqlx::PgPool, tid: Uuid) {
@@ -149,3 +154,56 @@ async fn in_progress_tournament_is_stale_swept_then_finished() {
         "finished tournaments are not stale-swept"
     );
 }
+
+/// Late registration runs through its configured level: registrations are
+/// turned away once the clock is past it, even before the status flips, and
+/// the advance closes it.
+#[tokio::test]
+async fn late_registration_closes_after_its_level() {
+    let app = setup_test_db().await;
+    let db = &app.db;
+    let schema = build_schema(app.clone());
+    let club_id = create_test_club(&app, "Late Reg Club").await;
+    let tid = create_test_tournament(&app, club_id, "Late Reg Tournament").await;
+    seed_structures(db, tid).await;
+    sqlx::query(
+        "UPDATE tournaments SET live_status = 'late_registration', late_registration_level = 1
+         WHERE id = $1",
+    )
+    .bind(tid)
+    .execute(db)
+    .await
+    .unwrap();
+    set_clock(db, tid, 1, 600.0).await;
+
+    let register = r#"mutation($input: RegisterForTournamentInput!) {
+        registerForTournament(input: $input) { id }
+    }"#;
+    let vars = || Variables::from_json(json!({ "input": { "tournamentId": tid.to_string() } }));
+    let (_, early) = create_test_user(&app, "late_reg_early@test.com", "player").await;
+    let resp = execute_graphql(&schema, register, Some(vars()), Some(early)).await;
+    assert!(resp.errors.is_empty(), "register: {:?}", resp.errors);
+
+    // Still level 1: nothing to close.
+    assert!(!close_late_registration_if_due(db, tid, 1, None)
+        .await
+        .unwrap());
+
+    // Past the level but not yet flipped: the level alone keeps players out.
+    set_clock(db, tid, 2, 600.0).await;
+    let (_, late) = create_test_user(&app, "late_reg_late@test.com", "player").await;
+    let resp = execute_graphql(&schema, register, Some(vars()), Some(late.clone())).await;
+    assert_eq!(
+        resp.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("REGISTRATION_CLOSED"))
+    );
+
+    assert!(close_late_registration_if_due(db, tid, 2, None)
+        .await
+        .unwrap());
+    let tournament = tournaments::get_by_id(db, tid).await.unwrap().unwrap();
+    assert_eq!(tournament.live_status, TournamentLiveStatus::InProgress);
+
+    let resp = execute_graphql(&schema, register, Some(vars()), Some(late)).await;
+    assert!(resp.errors[0].message.contains("not open"));
+}
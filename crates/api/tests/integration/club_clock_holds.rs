@@ -0,0 +1,132 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+const CLOCK: &str = r#"
+    query($tournamentId: ID!) {
+        tournamentClock(tournamentId: $tournamentId) { status timeRemainingSeconds }
+    }
+"#;
+
+async fn with_blinds(app_state: &api::AppState, club_id: Uuid, name: &str) -> Uuid {
+    let tournament_id = create_test_tournament(app_state, club_id, name).await;
+    sqlx::query(
+        "INSERT INTO tournament_structures \
+             (tournament_id, level_number, small_blind, big_blind, ante, duration_minutes) \
+         VALUES ($1, 1, 100, 200, 0, 20)",
+    )
+    .bind(tournament_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+    tournament_id
+}
+
+#[tokio::test]
+async fn test_pause_and_resume_all_clocks() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("holdmanager_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Hold Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let running = with_blinds(&app_state, club_id, "Running Event").await;
+    let paused = with_blinds(&app_state, club_id, "Paused Event").await;
+
+    let run = |query: &'static str, vars: serde_json::Value| {
+        let schema = schema.clone();
+        let claims = manager_claims.clone();
+        async move {
+            execute_graphql(
+                &schema,
+                query,
+                Some(Variables::from_json(vars)),
+                Some(claims),
+            )
+            .await
+        }
+    };
+    let clock = |tournament_id: Uuid| {
+        let response = run(CLOCK, json!({ "tournamentId": tournament_id.to_string() }));
+        async move {
+            let response = response.await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+            response.data.into_json().unwrap()["tournamentClock"].clone()
+        }
+    };
+
+    for tournament_id in [running, paused] {
+        let response = run(
+            r#"mutation($tournamentId: ID!) { startTournamentClock(tournamentId: $tournamentId) { status } }"#,
+            json!({ "tournamentId": tournament_id.to_string() }),
+        )
+        .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+    }
+    let response = run(
+        r#"mutation($tournamentId: ID!) { pauseTournamentClock(tournamentId: $tournamentId) { status } }"#,
+        json!({ "tournamentId": paused.to_string() }),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let pause_all = r#"
+        mutation($clubId: ID!) {
+            pauseAllTournaments(clubId: $clubId, reason: "Fire alarm") {
+                id reason tournamentIds resumedAt
+            }
+        }
+    "#;
+    let response = run(pause_all, json!({ "clubId": club_id.to_string() })).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let hold = response.data.into_json().unwrap()["pauseAllTournaments"].clone();
+    assert_eq!(hold["reason"], "Fire alarm");
+    assert_eq!(hold["tournamentIds"], json!([running.to_string()]));
+    assert_eq!(clock(running).await["status"], "PAUSED");
+
+    // One hold at a time.
+    let response = run(pause_all, json!({ "clubId": club_id.to_string() })).await;
+    assert!(response.errors[0].message.contains("already on hold"));
+
+    // A minute of alarm, which mustn't come off the level.
+    sqlx::query(
+        "UPDATE tournament_clocks SET pause_started_at = pause_started_at - INTERVAL '60 seconds' \
+         WHERE tournament_id = $1",
+    )
+    .bind(running)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    let response = run(
+        r#"mutation($clubId: ID!) { resumeAllTournaments(clubId: $clubId) { id resumedAt } }"#,
+        json!({ "clubId": club_id.to_string() }),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let resumed = response.data.into_json().unwrap()["resumeAllTournaments"].clone();
+    assert_eq!(resumed["id"], hold["id"]);
+    assert!(resumed["resumedAt"].is_string());
+
+    let restarted = clock(running).await;
+    assert_eq!(restarted["status"], "RUNNING");
+    assert!(restarted["timeRemainingSeconds"].as_i64().unwrap() > 20 * 60);
+    // The clock paused before the alarm stays paused.
+    assert_eq!(clock(paused).await["status"], "PAUSED");
+
+    let response = run(
+        r#"query($clubId: ID!) { clubClockHold(clubId: $clubId) { id } }"#,
+        json!({ "clubId": club_id.to_string() }),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert!(response.data.into_json().unwrap()["clubClockHold"].is_null());
+}
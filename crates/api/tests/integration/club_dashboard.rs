@@ -0,0 +1,105 @@
+//! The manager home screen: the week's tournaments, free tables, players
+//! waiting to get in and open incidents in one query.
+
+use api::gql::build_schema;
+use async_graphql::Variables;
+use chrono::{Duration, Utc};
+use fixtures::{Fixtures, TournamentLiveStatus};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::common::*;
+
+const CLUB_DASHBOARD: &str = r#"
+    query($clubId: UUID!) {
+        clubDashboard(clubId: $clubId) {
+            club { id }
+            today { tournament { id liveStatus } livePlayers confirmedPlayers pendingPlayers }
+            thisWeek { tournament { id } }
+            openCashTables { tableNumber }
+            pendingJoinRequests
+            unresolvedIncidents { severity }
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn club_dashboard_sums_up_the_week() {
+    let app = setup_test_db().await;
+    let schema = build_schema(app.clone());
+    let unique = Uuid::new_v4().simple().to_string();
+
+    let (manager_id, manager) =
+        create_test_user(&app, &format!("cd_mgr_{unique}@test.com"), "manager").await;
+    let club_id = create_test_club(&app, "Club Dashboard Club").await;
+    create_club_manager(&app, manager_id, club_id).await;
+
+    let tonight = Fixtures::random()
+        .tournament(club_id)
+        .name("Tonight")
+        .starts_at(Utc::now())
+        .live_status(TournamentLiveStatus::LateRegistration)
+        .create(&app.db)
+        .await
+        .unwrap();
+    Fixtures::random()
+        .tournament(club_id)
+        .name("In Two Weeks")
+        .starts_at(Utc::now() + Duration::days(14))
+        .create(&app.db)
+        .await
+        .unwrap();
+    for (i, status) in ["seated", "checked_in", "registered", "waitlisted"]
+        .into_iter()
+        .enumerate()
+    {
+        let (player_id, _) =
+            create_test_user(&app, &format!("cd_p{i}_{unique}@test.com"), "player").await;
+        create_test_registration(&app, tonight, player_id, status).await;
+    }
+    create_test_club_table(&app, club_id, 7, 9).await;
+    sqlx::query(
+        "INSERT INTO incidents (club_id, tournament_id, category, severity, description) VALUES ($1, $2, 'conduct', 'high', 'Abusive language'), ($1, $2, 'other', 'low', 'Spilled drink')",
+    )
+    .bind(club_id)
+    .bind(tonight)
+    .execute(&app.db)
+    .await
+    .unwrap();
+
+    let vars = Some(Variables::from_json(
+        json!({ "clubId": club_id.to_string() }),
+    ));
+    let res = execute_graphql(&schema, CLUB_DASHBOARD, vars.clone(), Some(manager)).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    let dashboard = &data["clubDashboard"];
+
+    assert_eq!(
+        dashboard["today"],
+        json!([{
+            "tournament": { "id": tonight.to_string(), "liveStatus": "LATE_REGISTRATION" },
+            "livePlayers": 2,
+            "confirmedPlayers": 3,
+            "pendingPlayers": 1,
+        }])
+    );
+    assert_eq!(
+        dashboard["thisWeek"],
+        json!([{ "tournament": { "id": tonight.to_string() } }])
+    );
+    assert_eq!(dashboard["openCashTables"], json!([{ "tableNumber": 7 }]));
+    assert_eq!(dashboard["pendingJoinRequests"], 1);
+    assert_eq!(
+        dashboard["unresolvedIncidents"],
+        json!([{ "severity": "HIGH" }, { "severity": "LOW" }])
+    );
+
+    // Another club's manager is turned away.
+    let (other_id, other) =
+        create_test_user(&app, &format!("cd_other_{unique}@test.com"), "manager").await;
+    let other_club = create_test_club(&app, "Other Dashboard Club").await;
+    create_club_manager(&app, other_id, other_club).await;
+    let res = execute_graphql(&schema, CLUB_DASHBOARD, vars, Some(other)).await;
+    assert!(!res.errors.is_empty());
+}
@@ -283,3 +283,86 @@ async fn test_roster_ordered_by_family_name() {
         "roster not ordered by family name"
     );
 }
+
+/// The club's name settings pick the roster's sort key and collation:
+/// accented names sort with their base letter rather than after "Z".
+#[tokio::test]
+async fn test_roster_follows_club_name_settings() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("roster_collation_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Roster Collation Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+
+    for (first, last) in [("Zack", "Alpha"), ("Émile", "Zulu"), ("adam", "Écuyer")] {
+        let vars = Variables::from_json(json!({
+            "input": { "clubId": club_id.to_string(), "firstName": first, "lastName": last }
+        }));
+        let r = execute_graphql(&schema, CREATE, Some(vars), Some(manager_claims.clone())).await;
+        assert!(r.errors.is_empty(), "seed create failed: {:?}", r.errors);
+    }
+
+    let list = r#"
+        query List($clubId: ID!) {
+            clubNameSettings(clubId: $clubId) { nameOrder sortLocale }
+            clubPlayers(clubId: $clubId) { displayName }
+        }
+    "#;
+    let roster = |data: &serde_json::Value| -> Vec<String> {
+        data["clubPlayers"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["displayName"].as_str().unwrap().to_string())
+            .collect()
+    };
+    let vars = || {
+        Some(Variables::from_json(
+            json!({ "clubId": club_id.to_string() }),
+        ))
+    };
+
+    let response = execute_graphql(&schema, list, vars(), Some(manager_claims.clone())).await;
+    assert!(response.errors.is_empty(), "list: {:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["clubNameSettings"]["nameOrder"], "LAST_FIRST");
+    assert_eq!(data["clubNameSettings"]["sortLocale"], "UNICODE");
+    assert_eq!(
+        roster(&data),
+        vec!["Zack Alpha", "adam Écuyer", "Émile Zulu"]
+    );
+
+    let update = r#"
+        mutation Update($input: UpdateClubNameSettingsInput!) {
+            updateClubNameSettings(input: $input) { nameOrder sortLocale }
+        }
+    "#;
+    let vars_update = Variables::from_json(json!({
+        "input": { "clubId": club_id.to_string(), "nameOrder": "FIRST_LAST", "sortLocale": "FRENCH" }
+    }));
+    let response = execute_graphql(
+        &schema,
+        update,
+        Some(vars_update),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "update: {:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["updateClubNameSettings"]["nameOrder"], "FIRST_LAST");
+    assert_eq!(data["updateClubNameSettings"]["sortLocale"], "FRENCH");
+
+    let response = execute_graphql(&schema, list, vars(), Some(manager_claims)).await;
+    assert!(response.errors.is_empty(), "list: {:?}", response.errors);
+    assert_eq!(
+        roster(&response.data.into_json().unwrap()),
+        vec!["adam Écuyer", "Émile Zulu", "Zack Alpha"]
+    );
+}
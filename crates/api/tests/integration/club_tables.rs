@@ -82,7 +82,7 @@ async fn test_club_tables_system() {
         poker_one_club_id,
         chrono::Utc::now(),
         chrono::Utc::now() + chrono::Duration::hours(4),
-        5000i32,
+        5000i64,
         100i32
     )
     .execute(pool)
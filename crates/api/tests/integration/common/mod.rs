@@ -1,5 +1,9 @@
+use std::future::Future;
+use std::time::Duration;
+
 use api::AppState;
 use async_graphql::{Request, Variables};
+use fixtures::{Fixtures, TournamentLiveStatus};
 use sqlx::postgres::PgPoolOptions;
 use testcontainers_modules::postgres::Postgres;
 use testcontainers_modules::testcontainers::runners::SyncRunner;
@@ -41,6 +45,9 @@ extern "C" fn cleanup_container() {
 }
 
 fn get_db_url() -> &'static str {
+    if let Ok(url) = std::env::var("LOCAL_TEST_DB") {
+        return Box::leak(url.into_boxed_str());
+    }
     let tc = TEST_CONTAINER.get_or_init(|| {
         // Spawn a dedicated thread so SyncRunner doesn't conflict with tokio runtime
         std::thread::spawn(|| {
@@ -87,8 +94,11 @@ pub async fn setup_test_db() -> AppState {
     // creates a separate runtime, and SQLx pool background tasks are
     // tied to the runtime that created them.
     let url = get_db_url();
+    if std::env::var("DATABASE_URL").is_err() {
+        std::env::set_var("DATABASE_URL", url);
+    }
 
-    let pool = PgPoolOptions::new()
+    let pool = infra::db::scope_connections(PgPoolOptions::new())
         .max_connections(5)
         .acquire_timeout(std::time::Duration::from_secs(30))
         .connect(url)
@@ -129,6 +139,47 @@ pub async fn execute_graphql(
     schema.execute(request).await
 }
 
+/// The `code` extension of the response's first error.
+#[allow(dead_code)]
+pub fn error_code(response: &async_graphql::Response) -> Option<String> {
+    code(response.errors.first()?.extensions.as_ref()?)
+}
+
+/// The `code` extension of an error returned by a service call made directly,
+/// outside a schema.
+#[allow(dead_code)]
+pub fn service_error_code(error: &async_graphql::Error) -> Option<String> {
+    code(error.extensions.as_ref()?)
+}
+
+fn code(extensions: &async_graphql::ErrorExtensionValues) -> Option<String> {
+    match extensions.get("code")? {
+        async_graphql::Value::String(code) => Some(code.clone()),
+        _ => None,
+    }
+}
+
+/// Poll `check` until it returns `Some`, for effects a mutation leaves to a
+/// background task, like its activity log entry. Panics after five seconds.
+#[allow(dead_code)]
+pub async fn eventually<T, F, Fut>(mut check: F) -> T
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Option<T>>,
+{
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+    loop {
+        if let Some(value) = check().await {
+            return value;
+        }
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "timed out waiting for a background write"
+        );
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+}
+
 /// Create test user and return JWT claims for authentication
 #[allow(dead_code)]
 pub async fn create_test_user(
@@ -136,92 +187,57 @@ pub async fn create_test_user(
     email: &str,
     role: &str,
 ) -> (Uuid, api::auth::Claims) {
-    let user_id = Uuid::new_v4();
-
-    // Insert test user directly into database
-    sqlx::query!(
-        "INSERT INTO users (id, email, username, first_name, last_name, password_hash, role, is_active)
-         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-         ON CONFLICT (email) DO UPDATE SET role = $7",
-        user_id,
-        email,
-        format!("test_{}", user_id),
-        "Test",
-        "User",
-        "$2b$12$dummy.hash.for.testing",
-        role,
-        true
-    )
-    .execute(&app_state.db)
-    .await
-    .expect("Failed to create test user");
-
-    // Get the actual user ID (in case of conflict, it might be different)
-    let actual_user = sqlx::query!("SELECT id FROM users WHERE email = $1", email)
-        .fetch_one(&app_state.db)
+    // On an email conflict the existing account is kept, so use the returned id
+    let user_id = Fixtures::random()
+        .user()
+        .email(email)
+        .name("Test", Some("User"))
+        .role(role)
+        .create(&app_state.db)
         .await
-        .expect("Failed to fetch created user");
-
-    let actual_user_id = actual_user.id;
+        .expect("Failed to create test user");
 
-    let claims = api::auth::Claims::new(actual_user_id, email.to_string(), role.to_string(), 60);
+    let subject = infra::repos::users::get_token_subject(&app_state.db, user_id)
+        .await
+        .expect("Failed to load token subject")
+        .expect("Test user not found");
+    let claims = api::auth::Claims::new(&subject, 60);
 
-    (actual_user_id, claims)
+    (user_id, claims)
 }
 
 /// Create test club and return its ID
 #[allow(dead_code)]
 pub async fn create_test_club(app_state: &AppState, name: &str) -> Uuid {
-    let club_id = Uuid::new_v4();
-
-    sqlx::query!(
-        "INSERT INTO clubs (id, name, city) VALUES ($1, $2, $3) ON CONFLICT (id) DO NOTHING",
-        club_id,
-        name,
-        "Test City"
-    )
-    .execute(&app_state.db)
-    .await
-    .expect("Failed to create test club");
-
-    // Test clubs stand in for real clubs (unrestricted), so put them on the
-    // paid `club` plan — otherwise the free-tier limits (single table, one
-    // active tournament) would trip tests that create several. Runtime query so
-    // it needs no SQLx offline metadata.
-    sqlx::query("UPDATE clubs SET plan = 'club' WHERE id = $1")
-        .bind(club_id)
-        .execute(&app_state.db)
+    Fixtures::random()
+        .club()
+        .name(name)
+        .create(&app_state.db)
         .await
-        .expect("Failed to set test club plan");
-
-    club_id
+        .expect("Failed to create test club")
 }
 
 /// Create test tournament and return its ID
 #[allow(dead_code)]
 pub async fn create_test_tournament(app_state: &AppState, club_id: Uuid, title: &str) -> Uuid {
-    let tournament_id = Uuid::new_v4();
-
-    sqlx::query!(
-        r#"INSERT INTO tournaments (
-            id, name, description, club_id, start_time, end_time,
-            buy_in_cents, seat_cap, live_status
-        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, 'not_started'::tournament_live_status)
-        ON CONFLICT (id) DO NOTHING"#,
-        tournament_id,
-        title,
-        "Test tournament description",
-        club_id,
-        chrono::Utc::now(),
-        chrono::Utc::now() + chrono::Duration::hours(4),
-        5000i32, // $50.00
-        100i32
-    )
-    .execute(&app_state.db)
-    .await
-    .expect("Failed to create test tournament");
+    Fixtures::random()
+        .tournament(club_id)
+        .name(title)
+        .create(&app_state.db)
+        .await
+        .expect("Failed to create test tournament")
+}
 
-    tournament_id
+/// Create test tournament with registration open and return its ID
+#[allow(dead_code)]
+pub async fn create_open_tournament(app_state: &AppState, club_id: Uuid, title: &str) -> Uuid {
+    Fixtures::random()
+        .tournament(club_id)
+        .name(title)
+        .live_status(TournamentLiveStatus::RegistrationOpen)
+        .create(&app_state.db)
+        .await
+        .expect("Failed to create test tournament")
 }
 
 /// Create club manager relationship
@@ -245,20 +261,12 @@ pub async fn create_test_club_table(
     table_number: i32,
     max_seats: i32,
 ) -> Uuid {
-    let table_id = Uuid::new_v4();
-
-    sqlx::query!(
-        "INSERT INTO club_tables (id, club_id, table_number, max_seats) VALUES ($1, $2, $3, $4) ON CONFLICT (id) DO NOTHING",
-        table_id,
-        club_id,
-        table_number,
-        max_seats
-    )
-    .execute(&app_state.db)
-    .await
-    .expect("Failed to create test club table");
-
-    table_id
+    Fixtures::random()
+        .table(club_id, table_number)
+        .max_seats(max_seats)
+        .create(&app_state.db)
+        .await
+        .expect("Failed to create test club table")
 }
 
 /// Create a tournament registration for a user and return its ID
@@ -269,22 +277,13 @@ pub async fn create_test_registration(
     user_id: Uuid,
     status: &str,
 ) -> Uuid {
-    let reg_id = Uuid::new_v4();
-
-    sqlx::query!(
-        r#"INSERT INTO tournament_registrations (id, tournament_id, user_id, status)
-         VALUES ($1, $2, $3, $4)
-         ON CONFLICT DO NOTHING"#,
-        reg_id,
-        tournament_id,
-        user_id,
-        status
-    )
-    .execute(&app_state.db)
-    .await
-    .expect("Failed to create test registration");
-
-    reg_id
+    Fixtures::random()
+        .registration(tournament_id)
+        .user(user_id)
+        .status(status)
+        .create(&app_state.db)
+        .await
+        .expect("Failed to create test registration")
 }
 
 /// Assign a club table to a tournament (creates the tournament_table_assignments row)
@@ -0,0 +1,58 @@
+use crate::common::*;
+use api::gql::build_schema;
+
+const QUERY: &str = r#"
+    query {
+        configDiagnostics {
+            settings { name value source secret isSet }
+            warnings
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn test_config_diagnostics_redacts_secrets() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (_, admin_claims) = create_test_user(
+        &app_state,
+        &format!("diagnostics_admin_{unique}@test.com"),
+        "admin",
+    )
+    .await;
+    let (_, player_claims) = create_test_user(
+        &app_state,
+        &format!("diagnostics_player_{unique}@test.com"),
+        "player",
+    )
+    .await;
+
+    let response = execute_graphql(&schema, QUERY, None, Some(admin_claims)).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let settings = data["configDiagnostics"]["settings"].as_array().unwrap();
+    let setting = |name: &str| {
+        settings
+            .iter()
+            .find(|s| s["name"] == name)
+            .unwrap_or_else(|| panic!("{name} missing"))
+    };
+
+    let jwt = setting("JWT_SECRET");
+    assert_eq!(jwt["secret"], true);
+    assert_eq!(jwt["isSet"], true);
+    assert!(jwt["value"].is_null());
+    assert!(setting("DATABASE_URL")["value"].is_null());
+
+    let path = setting("COOKIE_PATH");
+    assert_eq!(path["secret"], false);
+    assert!(path["value"].is_string());
+    // No serialized value anywhere carries the JWT secret.
+    let secret = app_state.auth_config().jwt_secret.clone();
+    assert!(!data.to_string().contains(&secret));
+
+    let response = execute_graphql(&schema, QUERY, None, Some(player_claims)).await;
+    assert!(!response.errors.is_empty());
+}
@@ -0,0 +1,185 @@
+//! Self-service email and phone changes: both codes are required, wrong
+//! codes run out, and every applied change lands in the audit log.
+
+use api::gql::build_schema;
+use api::gql::domains::users::service::{start_contact_change, MAX_ATTEMPTS};
+use api::gql::domains::users::types::ContactChannel;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::common::*;
+
+const CONFIRM: &str = r#"
+    mutation($input: ConfirmContactChangeInput!) {
+        confirmContactChange(input: $input) { email phone }
+    }
+"#;
+
+const AUDIT_LOG: &str = "{ me { auditLog { action actorId oldValue newValue } } }";
+
+fn confirmation(request_id: Uuid, old_code: &str, new_code: &str) -> Variables {
+    Variables::from_json(json!({
+        "input": {
+            "requestId": request_id.to_string(),
+            "oldCode": old_code,
+            "newCode": new_code,
+        }
+    }))
+}
+
+#[tokio::test]
+async fn an_email_change_needs_both_codes() {
+    let app = setup_test_db().await;
+    let schema = build_schema(app.clone());
+    let unique = Uuid::new_v4();
+    let old_email = format!("change_old_{unique}@test.com");
+    let new_email = format!("change_new_{unique}@test.com");
+    let (user_id, claims) = create_test_user(&app, &old_email, "player").await;
+
+    let issued = start_contact_change(&app.db, user_id, ContactChannel::Email, &new_email)
+        .await
+        .expect("change starts");
+
+    // The new address's code twice doesn't prove control of the old one.
+    let res = execute_graphql(
+        &schema,
+        CONFIRM,
+        Some(confirmation(
+            issued.request.id,
+            &issued.new_code,
+            &issued.new_code,
+        )),
+        Some(claims.clone()),
+    )
+    .await;
+    if issued.old_code != issued.new_code {
+        assert_eq!(error_code(&res).as_deref(), Some("INVALID_CODE"));
+    }
+
+    let res = execute_graphql(
+        &schema,
+        CONFIRM,
+        Some(confirmation(
+            issued.request.id,
+            &issued.old_code,
+            &issued.new_code,
+        )),
+        Some(claims.clone()),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    assert_eq!(
+        res.data.into_json().unwrap()["confirmContactChange"]["email"],
+        new_email
+    );
+
+    let res = execute_graphql(&schema, AUDIT_LOG, None, Some(claims.clone())).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    let entry = &data["me"]["auditLog"][0];
+    assert_eq!(entry["action"], "email_changed");
+    assert_eq!(entry["actorId"], user_id.to_string());
+    assert_eq!(entry["oldValue"], old_email);
+    assert_eq!(entry["newValue"], new_email);
+
+    // A confirmed request can't be replayed.
+    let res = execute_graphql(
+        &schema,
+        CONFIRM,
+        Some(confirmation(
+            issued.request.id,
+            &issued.old_code,
+            &issued.new_code,
+        )),
+        Some(claims),
+    )
+    .await;
+    assert_eq!(error_code(&res).as_deref(), Some("CHANGE_NOT_FOUND"));
+}
+
+#[tokio::test]
+async fn wrong_codes_run_out() {
+    let app = setup_test_db().await;
+    let schema = build_schema(app.clone());
+    let (user_id, claims) = create_test_user(
+        &app,
+        &format!("change_attempts_{}@test.com", Uuid::new_v4()),
+        "player",
+    )
+    .await;
+
+    let issued = start_contact_change(&app.db, user_id, ContactChannel::Phone, "+32470000000")
+        .await
+        .expect("change starts");
+    let wrong = if issued.old_code == "000000" {
+        "111111"
+    } else {
+        "000000"
+    };
+
+    for _ in 0..MAX_ATTEMPTS {
+        let res = execute_graphql(
+            &schema,
+            CONFIRM,
+            Some(confirmation(issued.request.id, wrong, &issued.new_code)),
+            Some(claims.clone()),
+        )
+        .await;
+        assert_eq!(error_code(&res).as_deref(), Some("INVALID_CODE"));
+    }
+
+    let res = execute_graphql(
+        &schema,
+        CONFIRM,
+        Some(confirmation(
+            issued.request.id,
+            &issued.old_code,
+            &issued.new_code,
+        )),
+        Some(claims),
+    )
+    .await;
+    assert_eq!(error_code(&res).as_deref(), Some("TOO_MANY_ATTEMPTS"));
+}
+
+#[tokio::test]
+async fn a_taken_email_is_refused_and_manager_edits_are_audited() {
+    let app = setup_test_db().await;
+    let schema = build_schema(app.clone());
+    let unique = Uuid::new_v4();
+    let taken = format!("change_taken_{unique}@test.com");
+    create_test_user(&app, &taken, "player").await;
+    let (user_id, claims) =
+        create_test_user(&app, &format!("change_player_{unique}@test.com"), "player").await;
+    let (manager_id, manager_claims) =
+        create_test_user(&app, &format!("change_mgr_{unique}@test.com"), "manager").await;
+
+    let err = start_contact_change(&app.db, user_id, ContactChannel::Email, &taken)
+        .await
+        .err()
+        .expect("a taken email is refused");
+    assert_eq!(
+        err.extensions.as_ref().and_then(|e| e.get("code")),
+        Some(&async_graphql::Value::from("EMAIL_TAKEN"))
+    );
+
+    let res = execute_graphql(
+        &schema,
+        "mutation($input: UpdatePlayerInput!) { updatePlayer(input: $input) { phone } }",
+        Some(Variables::from_json(json!({
+            "input": { "id": user_id.to_string(), "phone": "+32471111111" }
+        }))),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+
+    let res = execute_graphql(&schema, AUDIT_LOG, None, Some(claims)).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    let entry = &data["me"]["auditLog"][0];
+    assert_eq!(entry["action"], "phone_changed");
+    assert_eq!(entry["actorId"], manager_id.to_string());
+    assert_eq!(entry["newValue"], "+32471111111");
+}
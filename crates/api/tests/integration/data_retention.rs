@@ -1,9 +1,17 @@
 //! Data-retention sweep: the query that selects dormant accounts and the
-//! anonymization it then applies (the same path self-service deletion uses).
+//! anonymization it then applies (the same path self-service deletion uses),
+//! and the per-club retention policies for activity, broadcasts and chat.
 
+use api::gql::build_schema;
+use async_graphql::Variables;
 use infra::repos::users;
+use serde_json::json;
+use uuid::Uuid;
 
-use crate::common::{create_test_user, setup_test_db};
+use crate::common::{
+    create_club_manager, create_test_club, create_test_tournament, create_test_user,
+    execute_graphql, setup_test_db,
+};
 
 /// Force a user's activity timestamp to a fixed age in days.
 async fn set_last_seen_days_ago(db: &sqlx::PgPool, user_id: uuid::Uuid, days: i32) {
@@ -96,3 +104,249 @@ async fn touch_last_seen_clears_dormancy() {
         "a freshly-active account must drop out of the dormant set"
     );
 }
+
+const SET_POLICY: &str = r#"
+    mutation($input: SetClubRetentionPolicyInput!) {
+        setClubRetentionPolicy(input: $input) { mode activityLogMonths chatMessageMonths }
+    }
+"#;
+
+const REPORT: &str = r#"
+    query($clubId: ID!) {
+        clubRetentionReport(clubId: $clubId) { dryRun lines { record months rows exemptRows } }
+    }
+"#;
+
+const APPLY: &str = r#"
+    mutation($clubId: ID!) {
+        applyClubRetention(clubId: $clubId) { mode dryRun lines { record rows } }
+    }
+"#;
+
+fn line<'a>(data: &'a serde_json::Value, field: &str, record: &str) -> &'a serde_json::Value {
+    data[field]["lines"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|l| l["record"] == record)
+        .unwrap_or_else(|| panic!("no {record} line"))
+}
+
+async fn log_activity(
+    db: &sqlx::PgPool,
+    tournament_id: Uuid,
+    category: &str,
+    actor: Uuid,
+    months_ago: i32,
+) -> Uuid {
+    sqlx::query_scalar(
+        "INSERT INTO tournament_activity_log \
+             (tournament_id, event_category, event_action, actor_id, subject_id, event_time, metadata) \
+         VALUES ($1, $2, 'test', $3, $3, NOW() - make_interval(months => $4), \
+                 jsonb_build_object('club_player_id', gen_random_uuid(), 'table_number', 3)) \
+         RETURNING id",
+    )
+    .bind(tournament_id)
+    .bind(category)
+    .bind(actor)
+    .bind(months_ago)
+    .fetch_one(db)
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn club_policy_anonymizes_then_purges_expired_records() {
+    let app = setup_test_db().await;
+    let db = &app.db;
+    let schema = build_schema(app.clone());
+
+    let unique = Uuid::new_v4();
+    let club_id = create_test_club(&app, "Retention Policy Club").await;
+    let tournament_id = create_test_tournament(&app, club_id, "Retention Cup").await;
+    let (manager_id, manager) =
+        create_test_user(&app, &format!("retention_mgr_{unique}@test.dev"), "manager").await;
+    create_club_manager(&app, manager_id, club_id).await;
+    let (_, player) = create_test_user(
+        &app,
+        &format!("retention_player_{unique}@test.dev"),
+        "player",
+    )
+    .await;
+
+    let old_seating = log_activity(db, tournament_id, "seating", manager_id, 14).await;
+    let old_entry = log_activity(db, tournament_id, "entry", manager_id, 14).await;
+    let recent = log_activity(db, tournament_id, "seating", manager_id, 1).await;
+    let chat: Uuid = sqlx::query_scalar(
+        "INSERT INTO tournament_chat_messages (tournament_id, user_id, body, created_at) \
+         VALUES ($1, $2, 'gg wp', NOW() - INTERVAL '14 months') RETURNING id",
+    )
+    .bind(tournament_id)
+    .bind(manager_id)
+    .fetch_one(db)
+    .await
+    .unwrap();
+    let pending: Uuid = sqlx::query_scalar(
+        "INSERT INTO announcements (scope, club_id, title, body, created_by, created_at, scheduled_for, sent_at) \
+         VALUES ('club', $1, 'Later', 'Still queued', $2, NOW() - INTERVAL '14 months', NOW() + INTERVAL '1 day', NULL) \
+         RETURNING id",
+    )
+    .bind(club_id)
+    .bind(manager_id)
+    .fetch_one(db)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO announcements (scope, club_id, title, body, created_by, created_at) \
+         VALUES ('club', $1, 'Old', 'Sent long ago', $2, NOW() - INTERVAL '14 months')",
+    )
+    .bind(club_id)
+    .bind(manager_id)
+    .execute(db)
+    .await
+    .unwrap();
+
+    let policy = |mode: &str| {
+        Variables::from_json(json!({ "input": {
+            "clubId": club_id, "mode": mode,
+            "activityLogMonths": 12, "announcementMonths": 12, "chatMessageMonths": 12,
+        }}))
+    };
+    let response = execute_graphql(
+        &schema,
+        SET_POLICY,
+        Some(policy("ANONYMIZE")),
+        Some(player.clone()),
+    )
+    .await;
+    assert!(!response.errors.is_empty(), "players can't set a policy");
+    let response = execute_graphql(
+        &schema,
+        SET_POLICY,
+        Some(policy("ANONYMIZE")),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    // The dry run changes nothing.
+    let club_vars = || Some(Variables::from_json(json!({ "clubId": club_id })));
+    let response = execute_graphql(&schema, REPORT, club_vars(), Some(manager.clone())).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["clubRetentionReport"]["dryRun"], true);
+    let activity = line(&data, "clubRetentionReport", "ACTIVITY_LOG");
+    assert_eq!(activity["rows"], 1);
+    assert_eq!(activity["exemptRows"], 1);
+    assert_eq!(
+        line(&data, "clubRetentionReport", "CHAT_MESSAGES")["rows"],
+        1
+    );
+    assert_eq!(
+        line(&data, "clubRetentionReport", "ANNOUNCEMENTS")["rows"],
+        1
+    );
+    let actor: Option<Uuid> =
+        sqlx::query_scalar("SELECT actor_id FROM tournament_activity_log WHERE id = $1")
+            .bind(old_seating)
+            .fetch_one(db)
+            .await
+            .unwrap();
+    assert_eq!(actor, Some(manager_id));
+
+    let response = execute_graphql(&schema, APPLY, club_vars(), Some(manager.clone())).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(line(&data, "applyClubRetention", "ACTIVITY_LOG")["rows"], 1);
+
+    let (actor, metadata): (Option<Uuid>, serde_json::Value) =
+        sqlx::query_as("SELECT actor_id, metadata FROM tournament_activity_log WHERE id = $1")
+            .bind(old_seating)
+            .fetch_one(db)
+            .await
+            .unwrap();
+    assert!(actor.is_none());
+    assert!(metadata.get("club_player_id").is_none());
+    assert_eq!(metadata["table_number"], 3);
+    for kept in [old_entry, recent] {
+        let actor: Option<Uuid> =
+            sqlx::query_scalar("SELECT actor_id FROM tournament_activity_log WHERE id = $1")
+                .bind(kept)
+                .fetch_one(db)
+                .await
+                .unwrap();
+        assert_eq!(
+            actor,
+            Some(manager_id),
+            "entries and recent activity are kept"
+        );
+    }
+    let (user, body): (Option<Uuid>, String) =
+        sqlx::query_as("SELECT user_id, body FROM tournament_chat_messages WHERE id = $1")
+            .bind(chat)
+            .fetch_one(db)
+            .await
+            .unwrap();
+    assert!(user.is_none());
+    assert_eq!(body, "[removed]");
+
+    // Anonymized rows aren't due again; purging deletes them.
+    let response = execute_graphql(&schema, REPORT, club_vars(), Some(manager.clone())).await;
+    let data = response.data.into_json().unwrap();
+    assert_eq!(
+        line(&data, "clubRetentionReport", "ACTIVITY_LOG")["rows"],
+        0
+    );
+
+    let response = execute_graphql(
+        &schema,
+        SET_POLICY,
+        Some(policy("PURGE")),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let response = execute_graphql(&schema, APPLY, club_vars(), Some(manager)).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let remaining: Vec<Uuid> =
+        sqlx::query_scalar("SELECT id FROM tournament_activity_log WHERE id = ANY($1)")
+            .bind([old_seating, old_entry, recent])
+            .fetch_all(db)
+            .await
+            .unwrap();
+    assert!(!remaining.contains(&old_seating));
+    assert!(remaining.contains(&old_entry) && remaining.contains(&recent));
+    let chats: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM tournament_chat_messages WHERE id = $1")
+            .bind(chat)
+            .fetch_one(db)
+            .await
+            .unwrap();
+    assert_eq!(chats, 0);
+    let announcements: Vec<Uuid> =
+        sqlx::query_scalar("SELECT id FROM announcements WHERE club_id = $1")
+            .bind(club_id)
+            .fetch_all(db)
+            .await
+            .unwrap();
+    assert_eq!(
+        announcements,
+        vec![pending],
+        "queued broadcasts are never due"
+    );
+
+    // Outside a retention sweep the activity log stays append-only.
+    sqlx::query("DELETE FROM tournament_activity_log WHERE id = $1")
+        .bind(recent)
+        .execute(db)
+        .await
+        .unwrap();
+    let still_there: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM tournament_activity_log WHERE id = $1")
+            .bind(recent)
+            .fetch_one(db)
+            .await
+            .unwrap();
+    assert_eq!(still_there, 1);
+}
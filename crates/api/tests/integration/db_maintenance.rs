@@ -0,0 +1,161 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+
+const STATUS_QUERY: &str = r#"
+    query {
+        migrationStatus {
+            appliedCount pendingCount latestAppliedVersion upToDate
+            migrations { version state }
+        }
+        poolStats { size idle inUse maxConnections serverConnections }
+        backgroundServiceStatus {
+            lastClockTickAt
+            services { name ticks restarts }
+            pendingAnnouncements overdueAnnouncements
+        }
+    }
+"#;
+
+const MAINTENANCE_MUTATION: &str = r#"
+    mutation($task: MaintenanceTask!, $dryRun: Boolean = false, $minDeadTuples: Int = 1000) {
+        runMaintenance(task: $task, dryRun: $dryRun, minDeadTuples: $minDeadTuples) {
+            task dryRun tournamentsRebuilt
+            tables { tableName deadTuples vacuumed }
+        }
+    }
+"#;
+
+async fn admin_and_player(app_state: &api::AppState) -> (api::auth::Claims, api::auth::Claims) {
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (_, admin) = create_test_user(
+        app_state,
+        &format!("maintenance_admin_{unique}@test.com"),
+        "admin",
+    )
+    .await;
+    let (_, player) = create_test_user(
+        app_state,
+        &format!("maintenance_player_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    (admin, player)
+}
+
+#[tokio::test]
+async fn test_database_and_service_status() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let (admin, player) = admin_and_player(&app_state).await;
+
+    let response = execute_graphql(&schema, STATUS_QUERY, None, Some(admin)).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+
+    // The test database is migrated from the same files the binary embeds.
+    let migrations = &data["migrationStatus"];
+    assert_eq!(migrations["pendingCount"], 0);
+    assert!(migrations["appliedCount"].as_i64().unwrap() > 0);
+    let latest = migrations["latestAppliedVersion"].as_i64().unwrap();
+    let listed = migrations["migrations"].as_array().unwrap();
+    assert_eq!(listed.last().unwrap()["version"].as_i64(), Some(latest));
+    assert!(listed.iter().all(|m| m["state"] != "PENDING"));
+
+    let pool = &data["poolStats"];
+    assert!(pool["size"].as_i64().unwrap() >= 1);
+    assert_eq!(
+        pool["inUse"].as_i64().unwrap(),
+        pool["size"].as_i64().unwrap() - pool["idle"].as_i64().unwrap()
+    );
+    assert!(pool["serverConnections"].as_i64().unwrap() >= 1);
+
+    // No background loops run in tests.
+    let services = &data["backgroundServiceStatus"];
+    assert!(services["lastClockTickAt"].is_null());
+    assert!(services["pendingAnnouncements"].as_i64().unwrap() >= 0);
+
+    let response = execute_graphql(&schema, STATUS_QUERY, None, Some(player)).await;
+    assert!(!response.errors.is_empty());
+}
+
+#[tokio::test]
+async fn test_run_maintenance() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let (admin, player) = admin_and_player(&app_state).await;
+
+    let response = execute_graphql(
+        &schema,
+        MAINTENANCE_MUTATION,
+        Some(Variables::from_json(
+            json!({ "task": "VACUUM", "dryRun": true, "minDeadTuples": 0 }),
+        )),
+        Some(admin.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let report = &response.data.into_json().unwrap()["runMaintenance"];
+    assert_eq!(report["task"], "VACUUM");
+    assert_eq!(report["dryRun"], true);
+    assert!(report["tournamentsRebuilt"].is_null());
+    let tables = report["tables"].as_array().unwrap();
+    assert!(tables.iter().all(|t| t["vacuumed"] == false));
+
+    // An unreachable threshold runs the real path without vacuuming anything.
+    let response = execute_graphql(
+        &schema,
+        MAINTENANCE_MUTATION,
+        Some(Variables::from_json(
+            json!({ "task": "VACUUM", "minDeadTuples": 1_000_000_000 }),
+        )),
+        Some(admin.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let report = &response.data.into_json().unwrap()["runMaintenance"];
+    assert_eq!(report["dryRun"], false);
+    assert_eq!(report["tables"], json!([]));
+
+    let club_id = create_test_club(&app_state, "Maintenance Club").await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Maintenance Cup").await;
+    let (user_id, _) = create_test_user(
+        &app_state,
+        &format!("maintenance_winner_{}@test.com", uuid::Uuid::new_v4()),
+        "player",
+    )
+    .await;
+    sqlx::query(
+        "INSERT INTO tournament_results (tournament_id, user_id, final_position, prize_cents) VALUES ($1, $2, 1, 0)",
+    )
+    .bind(tournament_id)
+    .bind(user_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    let response = execute_graphql(
+        &schema,
+        MAINTENANCE_MUTATION,
+        Some(Variables::from_json(
+            json!({ "task": "REBUILD_LEADERBOARD", "dryRun": true }),
+        )),
+        Some(admin),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let report = &response.data.into_json().unwrap()["runMaintenance"];
+    assert!(report["tournamentsRebuilt"].as_i64().unwrap() >= 1);
+
+    let response = execute_graphql(
+        &schema,
+        MAINTENANCE_MUTATION,
+        Some(Variables::from_json(
+            json!({ "task": "REBUILD_LEADERBOARD" }),
+        )),
+        Some(player),
+    )
+    .await;
+    assert!(!response.errors.is_empty());
+}
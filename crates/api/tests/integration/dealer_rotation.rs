@@ -0,0 +1,206 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_dealer_rotation_schedule_and_down_counts() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("dealermanager_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Dealer Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Dealer Tournament").await;
+
+    let mut table_ids = Vec::new();
+    for table_number in [1, 2] {
+        let club_table_id = Uuid::new_v4();
+        sqlx::query!(
+            "INSERT INTO club_tables (id, club_id, table_number, max_seats) VALUES ($1, $2, $3, $4)",
+            club_table_id,
+            club_id,
+            table_number,
+            9
+        )
+        .execute(&app_state.db)
+        .await
+        .expect("Failed to create club table");
+        sqlx::query!(
+            "INSERT INTO tournament_table_assignments (tournament_id, club_table_id) VALUES ($1, $2)",
+            tournament_id,
+            club_table_id
+        )
+        .execute(&app_state.db)
+        .await
+        .expect("Failed to assign table");
+        table_ids.push(club_table_id);
+    }
+
+    let create_query = r#"
+        mutation Create($input: CreateClubStaffInput!) {
+            createClubStaff(input: $input) { id role }
+        }
+    "#;
+    let mut staff_ids = Vec::new();
+    for name in ["Alice", "Bob", "Carol"] {
+        let variables = Variables::from_json(json!({
+            "input": { "clubId": club_id.to_string(), "displayName": name }
+        }));
+        let response = execute_graphql(
+            &schema,
+            create_query,
+            Some(variables),
+            Some(manager_claims.clone()),
+        )
+        .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["createClubStaff"]["role"], "DEALER");
+        staff_ids.push(data["createClubStaff"]["id"].as_str().unwrap().to_string());
+    }
+
+    // A pool smaller than the table count can't generate a schedule.
+    let pool_query = r#"
+        mutation Pool($input: SetTournamentDealersInput!) {
+            setTournamentDealers(input: $input) { id }
+        }
+    "#;
+    let generate_query = r#"
+        mutation Generate($input: GenerateDealerRotationInput!) {
+            generateDealerRotation(input: $input) { dealerName tableNumber pushNumber }
+        }
+    "#;
+    let variables = Variables::from_json(json!({
+        "input": { "tournamentId": tournament_id.to_string(), "staffIds": [staff_ids[0]] }
+    }));
+    let response = execute_graphql(
+        &schema,
+        pool_query,
+        Some(variables),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    // Started 65 minutes ago with 30-minute pushes: pushes 0 and 1 are done,
+    // push 2 is in progress.
+    let starts_at = chrono::Utc::now() - chrono::Duration::minutes(65);
+    let generate_vars = Variables::from_json(json!({
+        "input": {
+            "tournamentId": tournament_id.to_string(),
+            "startsAt": starts_at.to_rfc3339(),
+            "pushMinutes": 30,
+            "pushes": 3
+        }
+    }));
+    let response = execute_graphql(
+        &schema,
+        generate_query,
+        Some(generate_vars.clone()),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(
+        !response.errors.is_empty(),
+        "One dealer can't cover two tables"
+    );
+
+    let variables = Variables::from_json(json!({
+        "input": { "tournamentId": tournament_id.to_string(), "staffIds": staff_ids }
+    }));
+    let response = execute_graphql(
+        &schema,
+        pool_query,
+        Some(variables),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["setTournamentDealers"].as_array().unwrap().len(), 3);
+
+    let response = execute_graphql(
+        &schema,
+        generate_query,
+        Some(generate_vars),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let pushes = data["generateDealerRotation"].as_array().unwrap();
+    assert_eq!(pushes.len(), 9);
+    // Push 0: Alice at table 1, Bob at table 2, Carol on break.
+    assert_eq!(pushes[0]["dealerName"], "Alice");
+    assert_eq!(pushes[0]["tableNumber"], 1);
+    assert_eq!(pushes[1]["tableNumber"], 2);
+    assert!(pushes[2]["tableNumber"].is_null());
+
+    // Push 2 is live: Bob deals table 1, Carol table 2.
+    let chart_query = r#"
+        query Chart($tournamentId: ID!) {
+            tournamentSeatingChart(tournamentId: $tournamentId) {
+                tables { table { tableNumber } currentDealer { displayName } }
+            }
+        }
+    "#;
+    let variables = Variables::from_json(json!({ "tournamentId": tournament_id.to_string() }));
+    let response = execute_graphql(
+        &schema,
+        chart_query,
+        Some(variables),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let tables = data["tournamentSeatingChart"]["tables"].as_array().unwrap();
+    assert_eq!(tables[0]["currentDealer"]["displayName"], "Bob");
+    assert_eq!(tables[1]["currentDealer"]["displayName"], "Carol");
+
+    // Completed downs: Alice dealt both finished pushes, Bob and Carol one each.
+    let downs_query = r#"
+        query Downs($clubId: ID!, $from: DateTime!, $to: DateTime!) {
+            dealerDownCounts(clubId: $clubId, from: $from, to: $to) {
+                displayName downs minutes
+            }
+        }
+    "#;
+    let variables = Variables::from_json(json!({
+        "clubId": club_id.to_string(),
+        "from": (chrono::Utc::now() - chrono::Duration::days(1)).to_rfc3339(),
+        "to": (chrono::Utc::now() + chrono::Duration::days(1)).to_rfc3339(),
+    }));
+    let response =
+        execute_graphql(&schema, downs_query, Some(variables), Some(manager_claims)).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let counts: Vec<(String, i64, i64)> = data["dealerDownCounts"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| {
+            (
+                c["displayName"].as_str().unwrap().to_string(),
+                c["downs"].as_i64().unwrap(),
+                c["minutes"].as_i64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        counts,
+        vec![
+            ("Alice".to_string(), 2, 60),
+            ("Bob".to_string(), 1, 30),
+            ("Carol".to_string(), 1, 30),
+        ]
+    );
+}
@@ -0,0 +1,195 @@
+//! Display pairing: codes pair one screen, display tokens only reach their
+//! club's display queries, and revoking a screen cuts it off.
+
+use api::auth::jwt::DisplayClaims;
+use api::gql::build_schema;
+use api::AppState;
+use async_graphql::{Request, Variables};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::common::*;
+
+const CREATE_PAIRING: &str = r#"
+    mutation($input: CreateDisplayPairingInput!) {
+        createDisplayPairing(input: $input) { code device { id status } }
+    }
+"#;
+
+const PAIR: &str = r#"
+    mutation($code: String!) {
+        pairDisplay(code: $code) { token deviceId clubId }
+    }
+"#;
+
+const SEATING_CHART: &str =
+    r#"query($id: ID!){ tournamentSeatingChart(tournamentId: $id){ tournament { id } } }"#;
+
+/// A manager of a fresh club and a pairing code for one of its screens.
+async fn start_pairing(app: &AppState) -> (Uuid, api::auth::Claims, String, String) {
+    let schema = build_schema(app.clone());
+    let club_id = create_test_club(app, "Display Club").await;
+    let (manager_id, manager_claims) = create_test_user(
+        app,
+        &format!("display_mgr_{}@test.com", Uuid::new_v4()),
+        "manager",
+    )
+    .await;
+    create_club_manager(app, manager_id, club_id).await;
+
+    let res = execute_graphql(
+        &schema,
+        CREATE_PAIRING,
+        Some(Variables::from_json(json!({
+            "input": { "clubId": club_id.to_string(), "name": "Lobby TV" }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    let pairing = &data["createDisplayPairing"];
+    assert_eq!(pairing["device"]["status"], "PENDING");
+
+    (
+        club_id,
+        manager_claims,
+        pairing["code"].as_str().unwrap().to_string(),
+        pairing["device"]["id"].as_str().unwrap().to_string(),
+    )
+}
+
+async fn pair(app: &AppState, code: &str) -> async_graphql::Response {
+    let schema = build_schema(app.clone());
+    execute_graphql(
+        &schema,
+        PAIR,
+        Some(Variables::from_json(json!({ "code": code }))),
+        None,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn a_pairing_code_pairs_one_screen() {
+    let app = setup_test_db().await;
+    let (club_id, _, code, device_id) = start_pairing(&app).await;
+
+    // Codes are typed by hand: case and separators don't matter.
+    let typed = format!("{}-{}", &code[..4], &code[4..]).to_lowercase();
+    let res = pair(&app, &typed).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    assert_eq!(data["pairDisplay"]["deviceId"], device_id);
+    assert_eq!(data["pairDisplay"]["clubId"], club_id.to_string());
+
+    let token = data["pairDisplay"]["token"].as_str().unwrap();
+    let claims = app
+        .jwt_service()
+        .verify_display_current(&app.db, token)
+        .await
+        .expect("display token verifies");
+    assert_eq!(claims.club_id, club_id);
+    assert!(
+        app.jwt_service().verify_token(token).is_err(),
+        "a display token is not a user session"
+    );
+
+    let res = pair(&app, &code).await;
+    assert!(!res.errors.is_empty(), "a used code must not pair again");
+}
+
+#[tokio::test]
+async fn display_tokens_only_reach_their_clubs_display_queries() {
+    let app = setup_test_db().await;
+    let schema = build_schema(app.clone());
+    let (club_id, _, code, device_id) = start_pairing(&app).await;
+    pair(&app, &code).await;
+    let display = DisplayClaims::new(Uuid::parse_str(&device_id).unwrap(), club_id);
+
+    let own = create_test_tournament(&app, club_id, "Display Own").await;
+    let other_club = create_test_club(&app, "Other Display Club").await;
+    let other = create_test_tournament(&app, other_club, "Display Other").await;
+
+    let chart = |tournament_id: Uuid| {
+        Request::new(SEATING_CHART)
+            .variables(Variables::from_json(
+                json!({ "id": tournament_id.to_string() }),
+            ))
+            .data(display.clone())
+    };
+
+    let res = schema.execute(chart(own)).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+
+    let res = schema.execute(chart(other)).await;
+    assert!(
+        !res.errors.is_empty(),
+        "another club's seating is out of reach"
+    );
+
+    let res = schema
+        .execute(Request::new("{ myNotificationPreferences { __typename } }").data(display.clone()))
+        .await;
+    assert!(
+        !res.errors.is_empty(),
+        "a display token is not a signed-in user"
+    );
+}
+
+#[tokio::test]
+async fn revoked_screens_can_neither_verify_nor_renew() {
+    let app = setup_test_db().await;
+    let schema = build_schema(app.clone());
+    let (club_id, manager_claims, code, device_id) = start_pairing(&app).await;
+
+    let data = pair(&app, &code).await.data.into_json().unwrap();
+    let token = data["pairDisplay"]["token"].as_str().unwrap().to_string();
+    let display = app
+        .jwt_service()
+        .verify_display_current(&app.db, &token)
+        .await
+        .unwrap();
+
+    let renew = || Request::new("mutation { renewDisplayToken { token } }").data(display.clone());
+    let res = schema.execute(renew()).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+
+    let res = execute_graphql(
+        &schema,
+        "query($clubId: ID!) { clubDisplayDevices(clubId: $clubId) { id status lastSeenAt } }",
+        Some(Variables::from_json(
+            json!({ "clubId": club_id.to_string() }),
+        )),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    assert_eq!(data["clubDisplayDevices"][0]["id"], device_id);
+    assert_eq!(data["clubDisplayDevices"][0]["status"], "PAIRED");
+
+    let res = execute_graphql(
+        &schema,
+        "mutation($id: ID!) { revokeDisplayDevice(id: $id) { status } }",
+        Some(Variables::from_json(json!({ "id": device_id }))),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    assert_eq!(
+        res.data.into_json().unwrap()["revokeDisplayDevice"]["status"],
+        "REVOKED"
+    );
+
+    assert!(app
+        .jwt_service()
+        .verify_display_current(&app.db, &token)
+        .await
+        .is_err());
+    let res = schema.execute(renew()).await;
+    assert_eq!(
+        res.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("UNAUTHENTICATED"))
+    );
+}
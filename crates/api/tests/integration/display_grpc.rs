@@ -0,0 +1,178 @@
+use crate::common::*;
+use api::gql::build_schema;
+use api::grpc::pb::display_controller_client::DisplayControllerClient;
+use api::grpc::pb::{
+    ClockStatus, GetTournamentSummaryRequest, ListLiveTournamentsRequest, WatchClockRequest,
+};
+use async_graphql::Variables;
+use serde_json::json;
+use tokio::net::TcpListener;
+use tonic::transport::Channel;
+use tonic::{Code, Request};
+
+const TOKEN: &str = "display-test-token";
+
+/// Serve the display API on an ephemeral port; returns its address.
+async fn start_server(app_state: &api::AppState) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = format!("http://{}", listener.local_addr().unwrap());
+    let state = app_state.clone();
+    tokio::spawn(async move {
+        api::grpc::serve(state, listener, TOKEN.to_string(), std::future::pending())
+            .await
+            .unwrap();
+    });
+    addr
+}
+
+fn authed<T>(message: T, token: &str) -> Request<T> {
+    let mut req = Request::new(message);
+    req.metadata_mut()
+        .insert("authorization", format!("Bearer {token}").parse().unwrap());
+    req
+}
+
+async fn clock_mutation(
+    schema: &async_graphql::Schema<
+        api::gql::QueryRoot,
+        api::gql::MutationRoot,
+        api::gql::SubscriptionRoot,
+    >,
+    mutation: &str,
+    tournament_id: uuid::Uuid,
+    claims: &api::auth::Claims,
+) {
+    let query = format!("mutation($id: ID!) {{ {mutation}(tournamentId: $id) {{ id }} }}");
+    let vars = Variables::from_json(json!({ "id": tournament_id.to_string() }));
+    let response = execute_graphql(schema, &query, Some(vars), Some(claims.clone())).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+}
+
+#[tokio::test]
+async fn test_display_summary_and_clock_stream() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("display_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Display Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Display Open").await;
+    sqlx::query(
+        "INSERT INTO tournament_structures (tournament_id, level_number, small_blind, big_blind, ante, duration_minutes)
+         VALUES ($1, 1, 25, 50, 0, 20), ($1, 2, 50, 100, 0, 20)",
+    )
+    .bind(tournament_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+    sqlx::query("UPDATE tournaments SET live_status = 'in_progress' WHERE id = $1")
+        .bind(tournament_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+
+    let addr = start_server(&app_state).await;
+    let mut client = DisplayControllerClient::<Channel>::connect(addr)
+        .await
+        .unwrap();
+
+    // Calls without the shared token are refused.
+    let err = client
+        .get_tournament_summary(GetTournamentSummaryRequest {
+            tournament_id: tournament_id.to_string(),
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::Unauthenticated);
+    let err = client
+        .get_tournament_summary(authed(
+            GetTournamentSummaryRequest {
+                tournament_id: tournament_id.to_string(),
+            },
+            "wrong-token",
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::Unauthenticated);
+
+    let summary = client
+        .get_tournament_summary(authed(
+            GetTournamentSummaryRequest {
+                tournament_id: tournament_id.to_string(),
+            },
+            TOKEN,
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(summary.name, "Display Open");
+    assert_eq!(summary.live_status, "in_progress");
+    let clock = summary.clock.expect("summary carries the clock");
+    assert_eq!(clock.status, ClockStatus::Stopped as i32);
+    assert_eq!(clock.current.unwrap().big_blind, 50);
+    assert_eq!(clock.next.unwrap().big_blind, 100);
+    // Nobody registered and the clock not started: no finish to project.
+    let pace = clock.pace.expect("clock carries the elimination pace");
+    assert_eq!(pace.players_remaining, 0);
+    assert_eq!(pace.projected_finish_unix_ms, None);
+
+    let live = client
+        .list_live_tournaments(authed(
+            ListLiveTournamentsRequest {
+                club_id: club_id.to_string(),
+            },
+            TOKEN,
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(live.tournaments.len(), 1);
+    assert_eq!(live.tournaments[0].tournament_id, tournament_id.to_string());
+
+    // The stream opens with the current state, then follows clock changes.
+    let mut stream = client
+        .watch_clock(authed(
+            WatchClockRequest {
+                tournament_id: tournament_id.to_string(),
+            },
+            TOKEN,
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+    let first = stream.message().await.unwrap().unwrap();
+    assert_eq!(first.status, ClockStatus::Stopped as i32);
+
+    clock_mutation(
+        &schema,
+        "startTournamentClock",
+        tournament_id,
+        &manager_claims,
+    )
+    .await;
+    let update = tokio::time::timeout(std::time::Duration::from_secs(5), stream.message())
+        .await
+        .expect("clock update within 5s")
+        .unwrap()
+        .unwrap();
+    assert_eq!(update.status, ClockStatus::Running as i32);
+    assert!(update.level_ends_at_unix_ms.is_some());
+
+    // Unknown tournaments are NOT_FOUND rather than an empty stream.
+    let err = client
+        .watch_clock(authed(
+            WatchClockRequest {
+                tournament_id: uuid::Uuid::new_v4().to_string(),
+            },
+            TOKEN,
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::NotFound);
+}
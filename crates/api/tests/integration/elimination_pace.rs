@@ -0,0 +1,135 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_eliminations_drive_the_clock_pace() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("pace_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Pace Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Pace Open").await;
+    sqlx::query(
+        "INSERT INTO tournament_structures (tournament_id, level_number, small_blind, big_blind, ante, duration_minutes, is_break)
+         VALUES ($1, 1, 25, 50, 0, 30, false), ($1, 2, 0, 0, 0, 15, true), ($1, 3, 50, 100, 0, 30, false)",
+    )
+    .bind(tournament_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    let table_id = create_test_club_table(&app_state, club_id, 1, 9).await;
+    assign_table_to_tournament(&app_state, tournament_id, table_id).await;
+    let mut players = Vec::new();
+    for seat in 1..=4 {
+        let (player_id, _) = create_test_user(
+            &app_state,
+            &format!("pace_player{seat}_{unique}@test.com"),
+            "player",
+        )
+        .await;
+        create_test_registration(&app_state, tournament_id, player_id, "seated").await;
+        sqlx::query(
+            "INSERT INTO table_seat_assignments (tournament_id, club_table_id, user_id, seat_number, stack_size) VALUES ($1, $2, $3, $4, 20000)",
+        )
+        .bind(tournament_id)
+        .bind(table_id)
+        .bind(player_id)
+        .bind(seat)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+        players.push(player_id);
+    }
+
+    let clock_query = r#"
+        query($id: ID!) {
+            tournamentClock(tournamentId: $id) {
+                pace { playersRemaining eliminations eliminationsPerHour projectedFinishAt }
+            }
+        }
+    "#;
+    let vars = || Variables::from_json(json!({ "id": tournament_id.to_string() }));
+
+    // Before the clock starts: the pace comes from defaults, no finish yet.
+    let response = execute_graphql(&schema, clock_query, Some(vars()), None).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let pace = &data["tournamentClock"]["pace"];
+    assert_eq!(pace["playersRemaining"], 4);
+    assert_eq!(pace["eliminations"], 0);
+    assert!(pace["projectedFinishAt"].is_null());
+
+    let response = execute_graphql(
+        &schema,
+        "mutation($id: ID!) { startTournamentClock(tournamentId: $id) { pace { projectedFinishAt } } }",
+        Some(vars()),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert!(!data["startTournamentClock"]["pace"]["projectedFinishAt"].is_null());
+
+    let response = execute_graphql(
+        &schema,
+        "mutation($t: ID!, $u: ID!) { eliminatePlayer(tournamentId: $t, userId: $u) }",
+        Some(Variables::from_json(json!({
+            "t": tournament_id.to_string(),
+            "u": players[0].to_string(),
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let eliminated_at: Option<chrono::DateTime<chrono::Utc>> = sqlx::query_scalar(
+        "SELECT eliminated_at FROM tournament_registrations WHERE tournament_id = $1 AND user_id = $2",
+    )
+    .bind(tournament_id)
+    .bind(players[0])
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert!(eliminated_at.is_some());
+
+    let response = execute_graphql(&schema, clock_query, Some(vars()), None).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let pace = &data["tournamentClock"]["pace"];
+    assert_eq!(pace["playersRemaining"], 3);
+    assert_eq!(pace["eliminations"], 1);
+    assert!(pace["eliminationsPerHour"].as_f64().unwrap() > 0.0);
+    let finish: chrono::DateTime<chrono::Utc> =
+        serde_json::from_value(pace["projectedFinishAt"].clone()).unwrap();
+    // The break after level 1 is included: at least 15 minutes out.
+    assert!(finish > chrono::Utc::now() + chrono::Duration::minutes(15));
+
+    // Undoing the bust clears the elimination time.
+    sqlx::query(
+        "UPDATE tournament_registrations SET status = 'seated' WHERE tournament_id = $1 AND user_id = $2",
+    )
+    .bind(tournament_id)
+    .bind(players[0])
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+    let eliminated_at: Option<chrono::DateTime<chrono::Utc>> = sqlx::query_scalar(
+        "SELECT eliminated_at FROM tournament_registrations WHERE tournament_id = $1 AND user_id = $2",
+    )
+    .bind(tournament_id)
+    .bind(players[0])
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert!(eliminated_at.is_none());
+}
@@ -0,0 +1,198 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::{json, Value};
+
+const ADD_ENTRY: &str = r#"
+    mutation($input: AddTournamentEntryInput!) {
+        addTournamentEntry(input: $input) { id ticket { id status token } }
+    }
+"#;
+
+const VERIFY: &str = r#"
+    query($token: String!, $tournamentId: ID) {
+        verifyEntryTicket(token: $token, tournamentId: $tournamentId) {
+            result
+            ticket { status duplicateScans playerName tournamentName voidReason }
+        }
+    }
+"#;
+
+fn verify_vars(token: &str, tournament_id: Option<String>) -> Option<Variables> {
+    Some(Variables::from_json(
+        json!({ "token": token, "tournamentId": tournament_id }),
+    ))
+}
+
+/// A buy-in gets a signed ticket: the first scan verifies it, later scans
+/// and forgeries are flagged, reissuing voids the old one and deleting the
+/// entry voids the new one.
+#[tokio::test]
+async fn test_entry_ticket_lifecycle() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) =
+        create_test_user(&app_state, "ticket_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Ticket Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Sunday Major").await;
+    let other_tournament = create_test_tournament(&app_state, club_id, "Sunday Turbo").await;
+    let (player_id, player) =
+        create_test_user(&app_state, "ticket_player@test.com", "player").await;
+
+    let add_entry = |entry_type: &'static str| {
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "userId": player_id.to_string(),
+                "entryType": entry_type,
+            }
+        })))
+    };
+    let resp = execute_graphql(
+        &schema,
+        ADD_ENTRY,
+        add_entry("INITIAL"),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "entry: {:?}", resp.errors);
+    let entry = resp.data.into_json().unwrap()["addTournamentEntry"].clone();
+    assert_eq!(entry["ticket"]["status"], "ISSUED");
+    let token = entry["ticket"]["token"].as_str().unwrap().to_string();
+
+    // Rebuys don't seat anyone, so they get no ticket.
+    let resp = execute_graphql(
+        &schema,
+        ADD_ENTRY,
+        add_entry("REBUY"),
+        Some(manager.clone()),
+    )
+    .await;
+    let rebuy = resp.data.into_json().unwrap()["addTournamentEntry"].clone();
+    assert!(rebuy["ticket"].is_null());
+
+    // The player can pull their ticket up, but can't verify tickets.
+    let resp = execute_graphql(
+        &schema,
+        r#"query($id: ID!) { myEntryTickets(tournamentId: $id) { token } }"#,
+        Some(Variables::from_json(
+            json!({ "id": tournament_id.to_string() }),
+        )),
+        Some(player.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "mine: {:?}", resp.errors);
+    assert_eq!(
+        resp.data.into_json().unwrap()["myEntryTickets"][0]["token"],
+        token.as_str()
+    );
+    let resp = execute_graphql(&schema, VERIFY, verify_vars(&token, None), Some(player)).await;
+    assert!(!resp.errors.is_empty(), "players can't verify tickets");
+
+    let check = |resp: async_graphql::Response| -> Value {
+        assert!(resp.errors.is_empty(), "verify: {:?}", resp.errors);
+        resp.data.into_json().unwrap()["verifyEntryTicket"].clone()
+    };
+
+    // Scanned at the wrong event: flagged, and still unverified.
+    let result = check(
+        execute_graphql(
+            &schema,
+            VERIFY,
+            verify_vars(&token, Some(other_tournament.to_string())),
+            Some(manager.clone()),
+        )
+        .await,
+    );
+    assert_eq!(result["result"], "WRONG_TOURNAMENT");
+    assert_eq!(result["ticket"]["status"], "ISSUED");
+
+    let result = check(
+        execute_graphql(
+            &schema,
+            VERIFY,
+            verify_vars(&token, Some(tournament_id.to_string())),
+            Some(manager.clone()),
+        )
+        .await,
+    );
+    assert_eq!(result["result"], "VALID");
+    assert_eq!(result["ticket"]["status"], "VERIFIED");
+    assert_eq!(result["ticket"]["tournamentName"], "Sunday Major");
+
+    let result = check(
+        execute_graphql(
+            &schema,
+            VERIFY,
+            verify_vars(&token, None),
+            Some(manager.clone()),
+        )
+        .await,
+    );
+    assert_eq!(result["result"], "DUPLICATE");
+    assert_eq!(result["ticket"]["duplicateScans"], 1);
+
+    // A token with a made-up signature never reaches the database.
+    let (id_part, _) = token.split_once('.').unwrap();
+    let forged = format!("{id_part}.AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA");
+    let result = check(
+        execute_graphql(
+            &schema,
+            VERIFY,
+            verify_vars(&forged, None),
+            Some(manager.clone()),
+        )
+        .await,
+    );
+    assert_eq!(result["result"], "INVALID");
+    assert!(result["ticket"].is_null());
+
+    // Lost ticket: reissue voids the old one.
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($id: ID!) { reissueEntryTicket(entryId: $id) { status token } }"#,
+        Some(Variables::from_json(json!({ "id": entry["id"] }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "reissue: {:?}", resp.errors);
+    let new_token = resp.data.into_json().unwrap()["reissueEntryTicket"]["token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert_ne!(new_token, token);
+    let result = check(
+        execute_graphql(
+            &schema,
+            VERIFY,
+            verify_vars(&token, None),
+            Some(manager.clone()),
+        )
+        .await,
+    );
+    assert_eq!(result["result"], "VOIDED");
+    assert_eq!(result["ticket"]["voidReason"], "Reissued");
+
+    // Deleting the entry voids its live ticket.
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($id: ID!) { deleteTournamentEntry(entryId: $id) }"#,
+        Some(Variables::from_json(json!({ "id": entry["id"] }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "delete: {:?}", resp.errors);
+    let result = check(
+        execute_graphql(
+            &schema,
+            VERIFY,
+            verify_vars(&new_token, None),
+            Some(manager),
+        )
+        .await,
+    );
+    assert_eq!(result["result"], "VOIDED");
+    assert_eq!(result["ticket"]["voidReason"], "Entry deleted");
+}
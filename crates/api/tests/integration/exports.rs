@@ -0,0 +1,139 @@
+use crate::common::*;
+use api::error::AppError;
+use api::routes::exports::{club_activity, club_results, ExportRange};
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Extension;
+
+fn all_time() -> Query<ExportRange> {
+    Query(ExportRange {
+        from: None,
+        to: None,
+    })
+}
+
+async fn body_text(response: axum::response::Response) -> String {
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    String::from_utf8(body.to_vec()).unwrap()
+}
+
+/// Results stream as CSV to the club's managers, and only to them.
+#[tokio::test]
+async fn test_club_results_export() {
+    let app_state = setup_test_db().await;
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager) = create_test_user(
+        &app_state,
+        &format!("export_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let (player_id, player) =
+        create_test_user(&app_state, &format!("export_p_{unique}@test.com"), "player").await;
+    let club_id = create_test_club(&app_state, "Export Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Season Opener").await;
+    sqlx::query(
+        "INSERT INTO tournament_results (tournament_id, user_id, final_position, prize_cents, points) \
+         VALUES ($1, $2, 1, 25000, 40)",
+    )
+    .bind(tournament_id)
+    .bind(player_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    let response = club_results(
+        State(app_state.clone()),
+        Path(club_id),
+        all_time(),
+        Some(Extension(manager.clone())),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"],
+        "text/csv; charset=utf-8"
+    );
+    let csv = body_text(response).await;
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(
+        lines[0],
+        "tournament_id,tournament_name,start_time,final_position,club_player_id,display_name,prize_cents,points"
+    );
+    assert_eq!(lines.len(), 2);
+    assert!(lines[1].starts_with(&format!("{tournament_id},Season Opener,")));
+    assert!(lines[1].ends_with(",25000,40"));
+
+    // A range that ends before the tournament leaves only the header.
+    let yesterday = chrono::Utc::now().date_naive() - chrono::Days::new(1);
+    let response = club_results(
+        State(app_state.clone()),
+        Path(club_id),
+        Query(ExportRange {
+            from: None,
+            to: Some(yesterday),
+        }),
+        Some(Extension(manager)),
+    )
+    .await
+    .unwrap();
+    assert_eq!(body_text(response).await.lines().count(), 1);
+
+    let err = club_results(
+        State(app_state.clone()),
+        Path(club_id),
+        all_time(),
+        Some(Extension(player)),
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, AppError::Forbidden(_)));
+
+    let err = club_results(State(app_state.clone()), Path(club_id), all_time(), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AppError::Unauthorized(_)));
+}
+
+/// An activity log larger than one chunk arrives whole and in order.
+#[tokio::test]
+async fn test_club_activity_export_spans_chunks() {
+    let app_state = setup_test_db().await;
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (_, admin) = create_test_user(
+        &app_state,
+        &format!("export_adm_{unique}@test.com"),
+        "admin",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Busy Club").await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Marathon").await;
+    sqlx::query(
+        "INSERT INTO tournament_activity_log (tournament_id, event_category, event_action, event_time, metadata) \
+         SELECT $1, 'clock', 'level_advanced', NOW() + n * INTERVAL '1 second', jsonb_build_object('level', n) \
+         FROM generate_series(1, 3000) AS n",
+    )
+    .bind(tournament_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    let response = club_activity(
+        State(app_state.clone()),
+        Path(club_id),
+        all_time(),
+        Some(Extension(admin)),
+    )
+    .await
+    .unwrap();
+    let csv = body_text(response).await;
+    assert!(csv.len() > 64 * 1024);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 3001);
+    assert!(lines[1].ends_with(r#","{""level"":1}""#), "{}", lines[1]);
+    assert!(lines[3000].ends_with(r#","{""level"":3000}""#));
+}
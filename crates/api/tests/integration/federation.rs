@@ -0,0 +1,87 @@
+use crate::common::*;
+use api::gql::{build_schema, build_subgraph_schema};
+use async_graphql::Variables;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_subgraph_sdl_declares_entity_keys() {
+    let app_state = setup_test_db().await;
+    let schema = build_subgraph_schema(app_state.clone());
+
+    let response = execute_graphql(&schema, "{ _service { sdl } }", None, None).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let sdl = data["_service"]["sdl"].as_str().unwrap();
+    for entity in ["User", "Tournament", "Club"] {
+        assert!(
+            sdl.contains(&format!("type {entity} @key(fields: \"id\")")),
+            "{entity} is not an entity"
+        );
+    }
+
+    // Without GQL_FEDERATION the SDL stays private, even via fragments.
+    let plain = build_schema(app_state.clone());
+    for query in [
+        "{ _service { sdl } }",
+        "{ ... on QueryRoot { _service { sdl } } }",
+        "query { ...F } fragment F on QueryRoot { _service { sdl } }",
+    ] {
+        let response = execute_graphql(&plain, query, None, None).await;
+        assert!(!response.errors.is_empty(), "{query} was served");
+    }
+}
+
+#[tokio::test]
+async fn test_entities_resolve_with_visibility_rules() {
+    let app_state = setup_test_db().await;
+    let schema = build_subgraph_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (player_id, player_claims) = create_test_user(
+        &app_state,
+        &format!("federationplayer_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Federation Club").await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Federation Open").await;
+    let home_game = create_test_club(&app_state, "Federation Home Game").await;
+    sqlx::query("UPDATE clubs SET plan = 'free' WHERE id = $1")
+        .bind(home_game)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+
+    let query = r#"
+        query($representations: [_Any!]!) {
+            _entities(representations: $representations) {
+                __typename
+                ... on User { id firstName }
+                ... on Tournament { id title }
+                ... on Club { id name }
+            }
+        }
+    "#;
+    let vars = Variables::from_json(json!({
+        "representations": [
+            { "__typename": "User", "id": player_id.to_string() },
+            { "__typename": "Tournament", "id": tournament_id.to_string() },
+            { "__typename": "Club", "id": club_id.to_string() },
+            { "__typename": "Club", "id": home_game.to_string() }
+        ]
+    }));
+
+    let response = execute_graphql(&schema, query, Some(vars.clone()), Some(player_claims)).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let entities = data["_entities"].as_array().unwrap();
+    assert_eq!(entities[0]["id"], player_id.to_string().as_str());
+    assert_eq!(entities[1]["title"], "Federation Open");
+    assert_eq!(entities[2]["name"], "Federation Club");
+    // Free clubs stay private to their own managers.
+    assert!(entities[3].is_null());
+
+    // Users only resolve for authenticated callers.
+    let response = execute_graphql(&schema, query, Some(vars), None).await;
+    assert!(!response.errors.is_empty());
+}
@@ -0,0 +1,234 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::{Schema, Variables};
+use serde_json::json;
+use uuid::Uuid;
+
+const FEED: &str =
+    "query { friendsActivity { kind userId tournamentName finalPosition prizeCents } }";
+
+async fn befriend(
+    schema: &Schema<api::gql::QueryRoot, api::gql::MutationRoot, api::gql::SubscriptionRoot>,
+    requester: &api::auth::Claims,
+    addressee_id: Uuid,
+    addressee: &api::auth::Claims,
+) {
+    let response = execute_graphql(
+        schema,
+        "mutation($id: ID!) { sendFriendRequest(userId: $id) { friendshipId } }",
+        Some(Variables::from_json(
+            json!({ "id": addressee_id.to_string() }),
+        )),
+        Some(requester.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let friendship_id =
+        response.data.into_json().unwrap()["sendFriendRequest"]["friendshipId"].clone();
+    let response = execute_graphql(
+        schema,
+        "mutation($id: ID!) { acceptFriendRequest(friendshipId: $id) { status } }",
+        Some(Variables::from_json(json!({ "id": friendship_id }))),
+        Some(addressee.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+}
+
+#[tokio::test]
+async fn test_follow_friends_and_read_their_activity() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("follow_mgr_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let (_, me) = create_test_user(
+        &app_state,
+        &format!("follow_me_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let alice_email = format!("follow_alice_{suffix}@test.com");
+    let (alice_id, alice) = create_test_user(&app_state, &alice_email, "player").await;
+    let (bob_id, bob) = create_test_user(
+        &app_state,
+        &format!("follow_bob_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let (stranger_id, _) = create_test_user(
+        &app_state,
+        &format!("follow_stranger_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Rail Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+
+    let follow =
+        |user_id: Uuid| Variables::from_json(json!({ "id": user_id.to_string(), "notify": true }));
+    const FOLLOW: &str =
+        "mutation($id: ID!, $notify: Boolean) { followFriend(userId: $id, notify: $notify) { userId notify } }";
+
+    let response =
+        execute_graphql(&schema, FOLLOW, Some(follow(stranger_id)), Some(me.clone())).await;
+    assert!(
+        response.errors[0].message.contains("only follow friends"),
+        "{:?}",
+        response.errors
+    );
+
+    befriend(&schema, &me, alice_id, &alice).await;
+    befriend(&schema, &bob, Uuid::parse_str(&me.sub).unwrap(), &me).await;
+    for friend in [alice_id, bob_id] {
+        let response =
+            execute_graphql(&schema, FOLLOW, Some(follow(friend)), Some(me.clone())).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.into_json().unwrap()["followFriend"]["notify"],
+            true
+        );
+    }
+
+    // Alice busts, which leaves Bob alone at the final table.
+    let tournament_id = create_test_tournament(&app_state, club_id, "Rail Night").await;
+    sqlx::query("UPDATE tournaments SET live_status = 'in_progress' WHERE id = $1")
+        .bind(tournament_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    let table_id = create_test_club_table(&app_state, club_id, 1, 9).await;
+    assign_table_to_tournament(&app_state, tournament_id, table_id).await;
+    for (seat, player) in [(1, alice_id), (2, bob_id)] {
+        sqlx::query(
+            "INSERT INTO table_seat_assignments (tournament_id, club_table_id, user_id, seat_number, stack_size) \
+             VALUES ($1, $2, $3, $4, 10000)",
+        )
+        .bind(tournament_id)
+        .bind(table_id)
+        .bind(player)
+        .bind(seat)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    }
+    let response = execute_graphql(
+        &schema,
+        "mutation($t: ID!, $u: ID!) { eliminatePlayer(tournamentId: $t, userId: $u) }",
+        Some(Variables::from_json(json!({
+            "t": tournament_id.to_string(),
+            "u": alice_id.to_string(),
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    // Alice wins an imported tournament.
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: ImportTournamentResultsInput!) {
+            importTournamentResults(input: $input) { tournament { id } }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "clubId": club_id.to_string(),
+                "format": "CSV",
+                "content": format!("Place,Name,Email,Winnings\n1,Alice,{alice_email},80\n2,Someone,,0\n"),
+                "name": "Rail Classic",
+                "startTime": "2025-05-02T19:00:00Z",
+                "buyInCents": 4000,
+            }
+        }))),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    // Activity log entries are written in the background.
+    let feed = eventually(|| async {
+        let response = execute_graphql(&schema, FEED, None, Some(me.clone())).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let feed = response.data.into_json().unwrap()["friendsActivity"].clone();
+        (feed.as_array().unwrap().len() >= 3).then_some(feed)
+    })
+    .await;
+    let events: Vec<(String, String)> = feed
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| {
+            (
+                e["kind"].as_str().unwrap().to_string(),
+                e["userId"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    assert_eq!(events.len(), 3, "{feed}");
+    assert!(events.contains(&("BUSTED".into(), alice_id.to_string())));
+    assert!(events.contains(&("FINAL_TABLE".into(), bob_id.to_string())));
+    let win = feed
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|e| e["kind"] == "WON")
+        .unwrap();
+    assert_eq!(win["userId"], alice_id.to_string());
+    assert_eq!(win["prizeCents"], 8_000);
+
+    // Alice's view of the feed is her own: she follows nobody.
+    let response = execute_graphql(&schema, FEED, None, Some(alice)).await;
+    assert_eq!(
+        response.data.into_json().unwrap()["friendsActivity"],
+        json!([])
+    );
+
+    // Unfriending Bob drops him from the follow list and the feed.
+    let response = execute_graphql(
+        &schema,
+        "query { myFriends { friendshipId userId } }",
+        None,
+        Some(me.clone()),
+    )
+    .await;
+    let friends = response.data.into_json().unwrap()["myFriends"].clone();
+    let bob_friendship = friends
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["userId"] == bob_id.to_string())
+        .unwrap()["friendshipId"]
+        .clone();
+    let response = execute_graphql(
+        &schema,
+        "mutation($id: ID!) { removeFriend(friendshipId: $id) }",
+        Some(Variables::from_json(json!({ "id": bob_friendship }))),
+        Some(me.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let response = execute_graphql(
+        &schema,
+        "query { myFollowedFriends { userId } }",
+        None,
+        Some(me.clone()),
+    )
+    .await;
+    assert_eq!(
+        response.data.into_json().unwrap()["myFollowedFriends"],
+        json!([{ "userId": alice_id.to_string() }])
+    );
+    let response = execute_graphql(&schema, FEED, None, Some(me)).await;
+    let feed = response.data.into_json().unwrap()["friendsActivity"].clone();
+    assert!(feed
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|e| e["userId"] == alice_id.to_string()));
+}
@@ -0,0 +1,233 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_incident_review_excludes_involved_players() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("incidentmanager_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let (floor_user, floor_claims) = create_test_user(
+        &app_state,
+        &format!("incidentfloor_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let (offender, offender_claims) = create_test_user(
+        &app_state,
+        &format!("incidentoffender_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Incident Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_open_tournament(&app_state, club_id, "Incident Tournament").await;
+
+    let offender_cp: Uuid = sqlx::query_scalar(
+        "INSERT INTO club_player (club_id, display_name, app_user_id) VALUES ($1, 'Offender', $2) RETURNING id",
+    )
+    .bind(club_id)
+    .bind(offender)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+
+    // Link the floor person to the staff roster.
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: CreateClubStaffInput!) { createClubStaff(input: $input) { id } }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "clubId": club_id.to_string(),
+                "displayName": "Floor",
+                "userId": floor_user.to_string(),
+                "role": "FLOOR"
+            }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let file_query = r#"
+        mutation($input: FileIncidentInput!) {
+            fileIncident(input: $input) {
+                id status severity
+                involvedPlayers { id }
+                attachments { url }
+            }
+        }
+    "#;
+    let file_vars = |severity: &str| {
+        Variables::from_json(json!({
+            "input": {
+                "clubId": club_id.to_string(),
+                "tournamentId": tournament_id.to_string(),
+                "category": "CONDUCT",
+                "severity": severity,
+                "description": "Abusive language towards the dealer",
+                "involvedClubPlayerIds": [offender_cp.to_string()],
+                "attachments": [{ "url": "https://files.example.com/clip.mp4", "contentType": "video/mp4" }]
+            }
+        }))
+    };
+
+    // Players who aren't floor staff can't file.
+    let response = execute_graphql(
+        &schema,
+        file_query,
+        Some(file_vars("HIGH")),
+        Some(offender_claims.clone()),
+    )
+    .await;
+    assert!(!response.errors.is_empty(), "Non-staff must be rejected");
+
+    let response = execute_graphql(
+        &schema,
+        file_query,
+        Some(file_vars("LOW")),
+        Some(floor_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let response = execute_graphql(
+        &schema,
+        file_query,
+        Some(file_vars("HIGH")),
+        Some(floor_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let incident = &data["fileIncident"];
+    assert_eq!(incident["status"], "OPEN");
+    assert_eq!(
+        incident["involvedPlayers"][0]["id"],
+        offender_cp.to_string()
+    );
+    assert_eq!(
+        incident["attachments"][0]["url"],
+        "https://files.example.com/clip.mp4"
+    );
+    let incident_id = incident["id"].as_str().unwrap().to_string();
+
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: UpdateIncidentFollowUpInput!) {
+            updateIncidentFollowUp(input: $input) { status followUpNotes }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": { "incidentId": incident_id, "status": "UNDER_REVIEW", "notes": "Spoke to the dealer" }
+        }))),
+        Some(floor_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["updateIncidentFollowUp"]["status"], "UNDER_REVIEW");
+
+    // The queue puts the high-severity report first; floor staff can't see it.
+    let queue_query =
+        r#"query($clubId: ID!) { incidentReviewQueue(clubId: $clubId) { id severity } }"#;
+    let queue_vars = Variables::from_json(json!({ "clubId": club_id.to_string() }));
+    let response = execute_graphql(
+        &schema,
+        queue_query,
+        Some(queue_vars.clone()),
+        Some(floor_claims),
+    )
+    .await;
+    assert!(!response.errors.is_empty(), "Review queue is managers only");
+    let response = execute_graphql(
+        &schema,
+        queue_query,
+        Some(queue_vars.clone()),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let queue = data["incidentReviewQueue"].as_array().unwrap();
+    assert_eq!(queue.len(), 2);
+    assert_eq!(queue[0]["id"], incident_id.as_str());
+
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: ReviewIncidentInput!) {
+            reviewIncident(input: $input) { status reviewOutcome }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": { "incidentId": incident_id, "outcome": "EXCLUSION", "exclusionDays": 30 }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["reviewIncident"]["status"], "RESOLVED");
+    assert_eq!(data["reviewIncident"]["reviewOutcome"], "EXCLUSION");
+
+    // The excluded player can no longer register at the club.
+    let register_query = r#"
+        mutation($input: RegisterForTournamentInput!) {
+            registerForTournament(input: $input) { id }
+        }
+    "#;
+    let register_vars = Variables::from_json(json!({
+        "input": { "tournamentId": tournament_id.to_string() }
+    }));
+    let response = execute_graphql(
+        &schema,
+        register_query,
+        Some(register_vars.clone()),
+        Some(offender_claims.clone()),
+    )
+    .await;
+    assert!(
+        response.errors[0].message.contains("excluded"),
+        "{:?}",
+        response.errors
+    );
+
+    // Lifting the exclusion restores access.
+    let response = execute_graphql(
+        &schema,
+        r#"query($clubId: ID!) { playerExclusions(clubId: $clubId) { id clubPlayerId endsAt } }"#,
+        Some(queue_vars),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    let data = response.data.into_json().unwrap();
+    let exclusions = data["playerExclusions"].as_array().unwrap();
+    assert_eq!(exclusions.len(), 1);
+    assert_eq!(exclusions[0]["clubPlayerId"], offender_cp.to_string());
+    assert!(!exclusions[0]["endsAt"].is_null());
+
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($id: ID!) { liftPlayerExclusion(exclusionId: $id) { liftedAt } }"#,
+        Some(Variables::from_json(json!({ "id": exclusions[0]["id"] }))),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let response = execute_graphql(
+        &schema,
+        register_query,
+        Some(register_vars),
+        Some(offender_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+}
@@ -0,0 +1,110 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn adjust_player_points_feeds_league_standings() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let unique = Uuid::new_v4().simple().to_string();
+
+    let (manager_id, manager_claims) =
+        create_test_user(&app_state, &format!("adj_mgr_{unique}@test.com"), "manager").await;
+    let club_id = create_test_club(&app_state, "Adjustment Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let (player_id, player_claims) =
+        create_test_user(&app_state, &format!("adj_p_{unique}@test.com"), "player").await;
+    let (stranger_id, _) =
+        create_test_user(&app_state, &format!("adj_s_{unique}@test.com"), "player").await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Adjustment Weekly").await;
+    create_test_registration(&app_state, tournament_id, player_id, "registered").await;
+
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: CreateLeaderboardConfigInput!) {
+            createLeaderboardConfig(input: $input) { id }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "clubId": club_id.to_string(),
+                "name": "Winter League",
+                "formula": {
+                    "basePoints": 3.0, "fieldMultiplier": 1.0, "buyinMultiplier": 1.0,
+                    "positionCurve": "SQRT", "minPlayers": 0, "cap": 60
+                }
+            }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let league_id = response.data.into_json().unwrap()["createLeaderboardConfig"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let adjust = r#"
+        mutation($userId: ID!, $configId: ID!, $delta: Int!, $reason: PointsAdjustmentReason!) {
+            adjustPlayerPoints(
+                userId: $userId, configId: $configId, delta: $delta, reason: $reason,
+                note: "Slow play"
+            ) { pointsDelta reasonCode reason }
+        }
+    "#;
+    let run = |user: Uuid, delta: i32, reason: &str, claims| {
+        let schema = schema.clone();
+        let vars = json!({
+            "userId": user.to_string(),
+            "configId": league_id,
+            "delta": delta,
+            "reason": reason,
+        });
+        async move {
+            execute_graphql(
+                &schema,
+                adjust,
+                Some(Variables::from_json(vars)),
+                Some(claims),
+            )
+            .await
+        }
+    };
+
+    let response = run(player_id, -5, "PENALTY", manager_claims.clone()).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["adjustPlayerPoints"]["reasonCode"], "PENALTY");
+    assert_eq!(data["adjustPlayerPoints"]["pointsDelta"], -5);
+    assert_eq!(data["adjustPlayerPoints"]["reason"], "Slow play");
+
+    // A penalty takes points off; players can't adjust; the user must be on
+    // the club's roster.
+    assert!(!run(player_id, 5, "PENALTY", manager_claims.clone())
+        .await
+        .errors
+        .is_empty());
+    assert!(!run(player_id, -5, "PENALTY", player_claims)
+        .await
+        .errors
+        .is_empty());
+    assert!(!run(stranger_id, -5, "CORRECTION", manager_claims.clone())
+        .await
+        .errors
+        .is_empty());
+
+    let response = execute_graphql(
+        &schema,
+        r#"query($configId: UUID) {
+            leaderboard(configId: $configId) { items { user { id } points } }
+        }"#,
+        Some(Variables::from_json(json!({ "configId": league_id }))),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let items = response.data.into_json().unwrap()["leaderboard"]["items"].clone();
+    assert_eq!(items[0]["user"]["id"], player_id.to_string());
+    assert_eq!(items[0]["points"], -5.0);
+}
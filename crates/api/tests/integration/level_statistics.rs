@@ -0,0 +1,190 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+async fn add_entry(
+    schema: &async_graphql::Schema<
+        api::gql::QueryRoot,
+        api::gql::MutationRoot,
+        api::gql::SubscriptionRoot,
+    >,
+    claims: &api::auth::Claims,
+    tournament_id: Uuid,
+    user_id: Uuid,
+    entry_type: &str,
+) -> serde_json::Value {
+    let response = execute_graphql(
+        schema,
+        r#"mutation($input: AddTournamentEntryInput!) {
+            addTournamentEntry(input: $input) { levelNumber }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "userId": user_id.to_string(),
+                "entryType": entry_type,
+                "chipsReceived": 10000,
+            }
+        }))),
+        Some(claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    response.data.into_json().unwrap()["addTournamentEntry"]["levelNumber"].clone()
+}
+
+async fn set_level(app_state: &api::AppState, tournament_id: Uuid, level: i32) {
+    sqlx::query(
+        "UPDATE tournament_clocks SET clock_status = 'running', current_level = $2, level_started_at = NOW() WHERE tournament_id = $1",
+    )
+    .bind(tournament_id)
+    .bind(level)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+}
+
+async fn bust(app_state: &api::AppState, tournament_id: Uuid, user_id: Uuid) {
+    sqlx::query(
+        "UPDATE tournament_registrations SET status = 'busted' WHERE tournament_id = $1 AND user_id = $2",
+    )
+    .bind(tournament_id)
+    .bind(user_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_level_statistics_follow_the_clock() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("levels_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let (_, player_claims) = create_test_user(
+        &app_state,
+        &format!("levels_outsider_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Levels Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Levels Open").await;
+    sqlx::query(
+        "INSERT INTO tournament_structures (tournament_id, level_number, small_blind, big_blind, ante, duration_minutes)
+         VALUES ($1, 1, 50, 100, 0, 20), ($1, 2, 100, 200, 0, 20), ($1, 3, 200, 400, 0, 20)",
+    )
+    .bind(tournament_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    let mut players = Vec::new();
+    for i in 0..4 {
+        let (player_id, _) = create_test_user(
+            &app_state,
+            &format!("levels_player{i}_{unique}@test.com"),
+            "player",
+        )
+        .await;
+        create_test_registration(&app_state, tournament_id, player_id, "seated").await;
+        players.push(player_id);
+    }
+
+    // Three buy-ins before the clock starts, one late in level 1.
+    for player in &players[..3] {
+        let level = add_entry(&schema, &manager_claims, tournament_id, *player, "INITIAL").await;
+        assert!(level.is_null());
+    }
+    set_level(&app_state, tournament_id, 1).await;
+    let level = add_entry(
+        &schema,
+        &manager_claims,
+        tournament_id,
+        players[3],
+        "INITIAL",
+    )
+    .await;
+    assert_eq!(level, 1);
+    add_entry(&schema, &manager_claims, tournament_id, players[0], "REBUY").await;
+    add_entry(&schema, &manager_claims, tournament_id, players[0], "REBUY").await;
+
+    // Two busts in level 3; level 2 is quiet.
+    set_level(&app_state, tournament_id, 3).await;
+    bust(&app_state, tournament_id, players[1]).await;
+    bust(&app_state, tournament_id, players[2]).await;
+
+    let query = r#"
+        query($id: ID!) {
+            tournamentLevelStatistics(tournamentId: $id) {
+                entriesBeforeStart
+                levels {
+                    levelNumber entries rebuys eliminations playersAtStart playersAtEnd
+                    chipsInPlay averageStack averageStackBigBlinds
+                    structure { bigBlind }
+                }
+                rebuyDistribution { rebuys players }
+            }
+        }
+    "#;
+    let vars = Variables::from_json(json!({ "id": tournament_id.to_string() }));
+
+    let response = execute_graphql(&schema, query, Some(vars.clone()), Some(player_claims)).await;
+    assert!(!response.errors.is_empty(), "players can't see level stats");
+
+    let response = execute_graphql(&schema, query, Some(vars), Some(manager_claims.clone())).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let stats = response.data.into_json().unwrap()["tournamentLevelStatistics"].clone();
+    assert_eq!(stats["entriesBeforeStart"], 3);
+    let levels = stats["levels"].as_array().unwrap();
+    assert_eq!(levels.len(), 3);
+    assert_eq!(levels[0]["entries"], 1);
+    assert_eq!(levels[0]["rebuys"], 2);
+    assert_eq!(levels[0]["playersAtStart"], 3);
+    assert_eq!(levels[0]["playersAtEnd"], 4);
+    assert_eq!(levels[0]["chipsInPlay"], 60000);
+    assert_eq!(levels[0]["averageStack"], 15000);
+    assert_eq!(levels[0]["averageStackBigBlinds"], 150.0);
+    assert_eq!(levels[1]["eliminations"], 0);
+    assert_eq!(levels[1]["playersAtEnd"], 4);
+    assert_eq!(levels[2]["structure"]["bigBlind"], 400);
+    assert_eq!(levels[2]["eliminations"], 2);
+    assert_eq!(levels[2]["playersAtEnd"], 2);
+    assert_eq!(levels[2]["averageStack"], 30000);
+    assert_eq!(
+        stats["rebuyDistribution"],
+        json!([{ "rebuys": 0, "players": 3 }, { "rebuys": 2, "players": 1 }])
+    );
+
+    // Once finished, the tournament feeds the club's per-level averages.
+    sqlx::query("UPDATE tournaments SET live_status = 'finished' WHERE id = $1")
+        .bind(tournament_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    let response = execute_graphql(
+        &schema,
+        r#"query($club: ID!) {
+            clubLevelStatistics(clubId: $club) {
+                tournaments
+                levels { levelNumber tournaments averageRebuys bustRate }
+            }
+        }"#,
+        Some(Variables::from_json(json!({ "club": club_id.to_string() }))),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let club = response.data.into_json().unwrap()["clubLevelStatistics"].clone();
+    assert_eq!(club["tournaments"], 1);
+    assert_eq!(club["levels"][0]["averageRebuys"], 2.0);
+    assert_eq!(club["levels"][2]["bustRate"], 0.5);
+}
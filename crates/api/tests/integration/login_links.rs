@@ -0,0 +1,104 @@
+//! Passwordless login links: single use, expiry and the per-user email cap.
+
+use api::auth::login_link::{issue, redeem, MAX_LINKS_PER_HOUR};
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::common::*;
+
+#[tokio::test]
+async fn a_login_link_works_once() {
+    let app = setup_test_db().await;
+    let (user_id, _) = create_test_user(
+        &app,
+        &format!("link_once_{}@test.com", Uuid::new_v4()),
+        "player",
+    )
+    .await;
+
+    let raw = issue(&app.db, user_id).await.unwrap().expect("link issued");
+    assert_eq!(redeem(&app.db, &raw).await.unwrap(), user_id);
+    assert!(
+        redeem(&app.db, &raw).await.is_err(),
+        "a used link must not log in again"
+    );
+}
+
+#[tokio::test]
+async fn a_new_link_retires_the_previous_one() {
+    let app = setup_test_db().await;
+    let (user_id, _) = create_test_user(
+        &app,
+        &format!("link_newest_{}@test.com", Uuid::new_v4()),
+        "player",
+    )
+    .await;
+
+    let first = issue(&app.db, user_id).await.unwrap().unwrap();
+    let second = issue(&app.db, user_id).await.unwrap().unwrap();
+
+    assert!(redeem(&app.db, &first).await.is_err());
+    assert_eq!(redeem(&app.db, &second).await.unwrap(), user_id);
+}
+
+#[tokio::test]
+async fn expired_links_are_rejected() {
+    let app = setup_test_db().await;
+    let (user_id, _) = create_test_user(
+        &app,
+        &format!("link_expired_{}@test.com", Uuid::new_v4()),
+        "player",
+    )
+    .await;
+
+    let raw = issue(&app.db, user_id).await.unwrap().unwrap();
+    sqlx::query(
+        "UPDATE login_link_tokens SET expires_at = now() - interval '1 minute' WHERE user_id = $1",
+    )
+    .bind(user_id)
+    .execute(&app.db)
+    .await
+    .unwrap();
+
+    assert!(redeem(&app.db, &raw).await.is_err());
+}
+
+#[tokio::test]
+async fn link_emails_are_capped_per_hour() {
+    let app = setup_test_db().await;
+    let (user_id, _) = create_test_user(
+        &app,
+        &format!("link_cap_{}@test.com", Uuid::new_v4()),
+        "player",
+    )
+    .await;
+
+    for _ in 0..MAX_LINKS_PER_HOUR {
+        assert!(issue(&app.db, user_id).await.unwrap().is_some());
+    }
+    assert!(
+        issue(&app.db, user_id).await.unwrap().is_none(),
+        "links beyond the hourly cap are not issued"
+    );
+}
+
+#[tokio::test]
+async fn requesting_a_link_does_not_reveal_accounts() {
+    let app = setup_test_db().await;
+    let schema = build_schema(app.clone());
+
+    let res = execute_graphql(
+        &schema,
+        "mutation($input: RequestLoginLinkInput!) { requestLoginLink(input: $input) }",
+        Some(Variables::from_json(json!({
+            "input": { "email": format!("nobody_{}@test.com", Uuid::new_v4()) }
+        }))),
+        None,
+    )
+    .await;
+
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    assert_eq!(res.data.into_json().unwrap()["requestLoginLink"], true);
+}
@@ -4,31 +4,86 @@
 
 mod common;
 
+mod account_linking;
+mod accounting_exports;
+mod alternates;
 mod announcements;
+mod api_quotas;
 mod auth;
 mod authz_guards;
+mod bankroll;
+mod buy_in_credits;
+mod capacity_plan;
 mod check_in;
 mod clock_advance;
 mod clock_lifecycle;
 mod club;
+mod club_clock_holds;
+mod club_dashboard;
 mod club_roster;
 mod club_tables;
+mod config_diagnostics;
+mod contact_change;
 mod data_retention;
+mod db_maintenance;
+mod dealer_rotation;
+mod display_devices;
+mod display_grpc;
 mod drinks;
 mod eliminate_player;
+mod elimination_pace;
+mod entry_tickets;
+mod exports;
+mod federation;
+mod friend_follows;
+mod incidents;
+mod leaderboard_adjustments;
+mod level_statistics;
+mod login_links;
 mod money_reconciliation;
+mod multi_day_flights;
+mod my_profile;
 mod notification;
+mod offline_sync;
+mod ops_dashboard;
+mod organizations;
 mod payouts;
 mod permission;
+mod persisted_operations;
+mod pii_encryption;
 mod player_management;
+mod presence;
+mod printouts;
+mod promotions;
+mod qualification_rules;
 mod query_coverage;
+mod raffles;
 mod refresh_token_security;
+mod registration_groups;
+mod registration_questions;
+mod results_import;
+mod row_security;
+mod rule_documents;
+mod seat_slip_print_jobs;
+mod series_leaderboard;
+mod signup_challenge;
+mod staff_time_clock;
+mod structure_editor;
 mod system;
 mod table_seating;
 mod tables_module;
+mod token_claims;
 mod tournament;
+mod tournament_cancellation;
+mod tournament_chat;
 mod tournament_clock;
 mod tournament_entries;
+mod tournament_gallery;
+mod tournament_invites;
+mod tournament_readiness;
+mod tournament_recaps;
 mod tournament_results;
+mod tournament_timeline;
+mod tournament_visibility;
 mod unassign_table;
 mod user;
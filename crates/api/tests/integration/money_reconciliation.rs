@@ -11,7 +11,7 @@ use uuid::Uuid;
 type TestSchema =
     async_graphql::Schema<api::gql::QueryRoot, api::gql::MutationRoot, api::gql::SubscriptionRoot>;
 
-const BUY_IN: i32 = 5000;
+const BUY_IN: i64 = 5000;
 
 async fn add_initial_entry(
     schema: &TestSchema,
@@ -68,7 +68,7 @@ async fn three_funded_players(
     (app_state, schema, tournament_id, manager_claims, players)
 }
 
-async fn prize_pool(app_state: &api::AppState, tournament_id: Uuid) -> i32 {
+async fn prize_pool(app_state: &api::AppState, tournament_id: Uuid) -> i64 {
     sqlx::query_scalar("SELECT total_prize_pool FROM tournament_payouts WHERE tournament_id = $1")
         .bind(tournament_id)
         .fetch_one(&app_state.db)
@@ -124,7 +124,7 @@ async fn results_distribute_exactly_the_prize_pool() {
 
     // The core invariant: every cent of the pool is paid out, no more, no less.
     assert_eq!(
-        distributed, pool as i64,
+        distributed, pool,
         "distributed payouts must reconcile to the prize pool"
     );
 }
@@ -173,3 +173,49 @@ async fn funded_results_without_a_payout_structure_are_rejected_and_rolled_back(
         "no results persisted on reconciliation failure"
     );
 }
+
+#[tokio::test]
+async fn custom_deal_amounts_that_overflow_are_rejected() {
+    let (app_state, schema, tournament_id, manager_claims, players) =
+        three_funded_players("money_overflow").await;
+
+    let mutation = r#"
+        mutation EnterResults($input: EnterTournamentResultsInput!) {
+            enterTournamentResults(input: $input) { success }
+        }
+    "#;
+    // Two custom payouts at i64::MAX cannot be summed; this must surface as an
+    // error instead of panicking or wrapping into a bogus total.
+    let vars = Variables::from_json(json!({
+        "input": {
+            "tournamentId": tournament_id.to_string(),
+            "playerPositions": [
+                { "userId": players[0].to_string(), "finalPosition": 1 },
+                { "userId": players[1].to_string(), "finalPosition": 2 },
+                { "userId": players[2].to_string(), "finalPosition": 3 }
+            ],
+            "deal": {
+                "dealType": "CUSTOM",
+                "affectedPositions": [1, 2],
+                "customPayouts": [
+                    { "userId": players[0].to_string(), "amountCents": i64::MAX },
+                    { "userId": players[1].to_string(), "amountCents": i64::MAX }
+                ]
+            }
+        }
+    }));
+
+    let resp = execute_graphql(&schema, mutation, Some(vars), Some(manager_claims)).await;
+    assert!(
+        !resp.errors.is_empty(),
+        "overflowing custom payouts must be rejected"
+    );
+
+    let persisted: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM tournament_results WHERE tournament_id = $1")
+            .bind(tournament_id)
+            .fetch_one(&app_state.db)
+            .await
+            .unwrap();
+    assert_eq!(persisted, 0, "nothing persisted when the totals overflow");
+}
@@ -0,0 +1,152 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_bagged_stacks_carry_over_to_day_two_seats() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("flightmanager_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Flights Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+
+    let response = execute_graphql(
+        &schema,
+        r#"
+            mutation($input: CreateTournamentSeriesInput!) {
+                createTournamentSeries(input: $input) { id flights { id } }
+            }
+        "#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "clubId": club_id.to_string(),
+                "title": "Main Event",
+                "buyInCents": 20000,
+                "flights": [{ "label": "1A", "startTime": "2026-11-06T18:00:00Z" }],
+                "finalDay": { "label": "Day 2", "startTime": "2026-11-08T14:00:00Z" }
+            }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let series = response.data.into_json().unwrap()["createTournamentSeries"].clone();
+    let series_id = series["id"].as_str().unwrap().to_string();
+    let flight_id = Uuid::parse_str(series["flights"][0]["id"].as_str().unwrap()).unwrap();
+
+    let flight_table = create_test_club_table(&app_state, club_id, 1, 9).await;
+    assign_table_to_tournament(&app_state, flight_id, flight_table).await;
+    let mut players = Vec::new();
+    for (seat, (name, stack)) in [("Ann", Some(41_500)), ("Ben", None)]
+        .into_iter()
+        .enumerate()
+    {
+        let club_player_id = sqlx::query_scalar::<_, Uuid>(
+            "INSERT INTO club_player (club_id, display_name) VALUES ($1, $2) RETURNING id",
+        )
+        .bind(club_id)
+        .bind(name)
+        .fetch_one(&app_state.db)
+        .await
+        .unwrap();
+        let seat_id = sqlx::query_scalar::<_, Uuid>(
+            "INSERT INTO table_seat_assignments \
+                 (tournament_id, club_table_id, club_player_id, seat_number, stack_size) \
+             VALUES ($1, $2, $3, $4, $5) RETURNING id",
+        )
+        .bind(flight_id)
+        .bind(flight_table)
+        .bind(club_player_id)
+        .bind(seat as i32 + 1)
+        .bind(stack)
+        .fetch_one(&app_state.db)
+        .await
+        .unwrap();
+        players.push((club_player_id, seat_id));
+    }
+
+    let close_flight = || {
+        execute_graphql(
+            &schema,
+            r#"
+                mutation($input: CloseFlightInput!) {
+                    closeFlight(input: $input) { liveStatus }
+                }
+            "#,
+            Some(Variables::from_json(json!({
+                "input": { "tournamentId": flight_id.to_string() }
+            }))),
+            Some(manager_claims.clone()),
+        )
+    };
+    let response = close_flight().await;
+    assert_eq!(
+        response.errors[0].message,
+        "Record the stack of every seated player before bagging (1 missing)"
+    );
+
+    sqlx::query("UPDATE table_seat_assignments SET stack_size = 18250 WHERE id = $1")
+        .bind(players[1].1)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    let response = close_flight().await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap()["closeFlight"]["liveStatus"],
+        "FINISHED"
+    );
+
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($seriesId: ID!) { openDayTwo(seriesId: $seriesId) { id } }"#,
+        Some(Variables::from_json(json!({ "seriesId": series_id }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let final_day = response.data.into_json().unwrap()["openDayTwo"]["id"]
+        .as_str()
+        .map(|id| Uuid::parse_str(id).unwrap())
+        .unwrap();
+
+    // No Day 2 tables yet: qualifiers are checked in, waiting for the draw.
+    let seated = |tournament_id: Uuid| {
+        let db = app_state.db.clone();
+        async move {
+            sqlx::query_as::<_, (Uuid, Option<i32>)>(
+                "SELECT club_player_id, stack_size FROM table_seat_assignments \
+                 WHERE tournament_id = $1 AND is_current ORDER BY stack_size DESC",
+            )
+            .bind(tournament_id)
+            .fetch_all(&db)
+            .await
+            .unwrap()
+        }
+    };
+    assert!(seated(final_day).await.is_empty());
+
+    let day_two_table = create_test_club_table(&app_state, club_id, 2, 9).await;
+    assign_table_to_tournament(&app_state, final_day, day_two_table).await;
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($seriesId: ID!) { openDayTwo(seriesId: $seriesId) { id } }"#,
+        Some(Variables::from_json(json!({ "seriesId": series_id }))),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        seated(final_day).await,
+        vec![(players[0].0, Some(41_500)), (players[1].0, Some(18_250))]
+    );
+}
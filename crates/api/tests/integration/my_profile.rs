@@ -0,0 +1,126 @@
+//! Players editing their own profile: per-field validation, and email
+//! changes deferred to the confirmation flow.
+
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::common::*;
+
+const UPDATE: &str = r#"
+    mutation($input: UpdateMyProfileInput!) {
+        updateMyProfile(input: $input) {
+            user { email firstName lastName username avatarUrl }
+            pendingContactChanges { channel currentCodeSentTo }
+        }
+    }
+"#;
+
+fn input(fields: serde_json::Value) -> Option<Variables> {
+    Some(Variables::from_json(json!({ "input": fields })))
+}
+
+fn extension(res: &async_graphql::Response, key: &str) -> Option<String> {
+    match res.errors.first()?.extensions.as_ref()?.get(key)? {
+        async_graphql::Value::String(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn a_player_edits_their_own_profile() {
+    let app = setup_test_db().await;
+    let schema = build_schema(app.clone());
+    let unique = Uuid::new_v4().simple().to_string();
+    let (_, claims) = create_test_user(&app, &format!("profile_{unique}@test.com"), "player").await;
+    let username = format!("ace_{}", &unique[..8]);
+
+    let res = execute_graphql(
+        &schema,
+        UPDATE,
+        input(json!({
+            "firstName": "  Jane ",
+            "lastName": "",
+            "username": username,
+            "avatarUrl": "https://cdn.example.com/jane.png",
+        })),
+        Some(claims.clone()),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    let user = &data["updateMyProfile"]["user"];
+    assert_eq!(user["firstName"], "Jane");
+    assert_eq!(user["lastName"], serde_json::Value::Null);
+    assert_eq!(user["username"], username);
+    assert_eq!(user["avatarUrl"], "https://cdn.example.com/jane.png");
+
+    for (fields, field) in [
+        (json!({ "firstName": " " }), "firstName"),
+        (json!({ "username": "no spaces" }), "username"),
+        (
+            json!({ "avatarUrl": "http://insecure.example.com/a.png" }),
+            "avatarUrl",
+        ),
+        (json!({ "email": "not-an-email" }), "email"),
+    ] {
+        let res = execute_graphql(&schema, UPDATE, input(fields), Some(claims.clone())).await;
+        assert_eq!(extension(&res, "code").as_deref(), Some("INVALID_INPUT"));
+        assert_eq!(extension(&res, "field").as_deref(), Some(field));
+    }
+}
+
+#[tokio::test]
+async fn usernames_are_unique_and_emails_wait_for_confirmation() {
+    let app = setup_test_db().await;
+    let schema = build_schema(app.clone());
+    let unique = Uuid::new_v4().simple().to_string();
+    let (_, first) =
+        create_test_user(&app, &format!("profile_a_{unique}@test.com"), "player").await;
+    let email = format!("profile_b_{unique}@test.com");
+    let (_, second) = create_test_user(&app, &email, "player").await;
+    let username = format!("Shark_{}", &unique[..8]);
+
+    let res = execute_graphql(
+        &schema,
+        UPDATE,
+        input(json!({ "username": username })),
+        Some(first),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+
+    let res = execute_graphql(
+        &schema,
+        UPDATE,
+        input(json!({ "username": username.to_lowercase() })),
+        Some(second.clone()),
+    )
+    .await;
+    assert_eq!(extension(&res, "code").as_deref(), Some("USERNAME_TAKEN"));
+
+    let res = execute_graphql(
+        &schema,
+        UPDATE,
+        input(json!({
+            "firstName": "Bea",
+            "email": format!("profile_b_new_{unique}@test.com"),
+        })),
+        Some(second),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    let payload = &data["updateMyProfile"];
+    assert_eq!(payload["user"]["firstName"], "Bea");
+    assert_eq!(
+        payload["user"]["email"], email,
+        "the email only changes once confirmed"
+    );
+    assert_eq!(payload["pendingContactChanges"][0]["channel"], "EMAIL");
+    assert_eq!(
+        payload["pendingContactChanges"][0]["currentCodeSentTo"],
+        "EMAIL"
+    );
+}
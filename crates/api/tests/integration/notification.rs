@@ -1,6 +1,7 @@
 use crate::common::*;
 use api::gql::build_schema;
 use async_graphql::Variables;
+use fixtures::{Fixtures, TournamentLiveStatus};
 use serde_json::json;
 
 /// Test that registration mutation triggers notification
@@ -13,14 +14,7 @@ async fn test_registration_triggers_notification() {
     // Create club and tournament
     let club_id = create_test_club(&app_state, "Notification Test Club").await;
     let tournament_id =
-        create_test_tournament(&app_state, club_id, "Notification Test Tournament").await;
-
-    // Open registration so the player can register
-    sqlx::query("UPDATE tournaments SET live_status = 'registration_open'::tournament_live_status WHERE id = $1")
-        .bind(tournament_id)
-        .execute(&app_state.db)
-        .await
-        .expect("Failed to open registration");
+        create_open_tournament(&app_state, club_id, "Notification Test Tournament").await;
 
     // Create player
     let (_, player_claims) =
@@ -66,27 +60,15 @@ async fn test_tournaments_starting_soon_query() {
     // Create a tournament starting soon (within 15 minutes)
     let club_id = create_test_club(&app_state, "Starting Soon Club").await;
 
-    // Insert tournament with start_time in the future
-    let tournament_id = uuid::Uuid::new_v4();
-    let start_time = chrono::Utc::now() + chrono::Duration::minutes(10);
-
-    sqlx::query(
-        r#"INSERT INTO tournaments (
-            id, name, description, club_id, start_time, end_time,
-            buy_in_cents, seat_cap, live_status
-        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, 'registration_open'::tournament_live_status)"#,
-    )
-    .bind(tournament_id)
-    .bind("Starting Soon Tournament")
-    .bind("Test tournament starting soon")
-    .bind(club_id)
-    .bind(start_time)
-    .bind(start_time + chrono::Duration::hours(4))
-    .bind(5000i32)
-    .bind(100i32)
-    .execute(&app_state.db)
-    .await
-    .expect("Failed to create tournament");
+    let tournament_id = Fixtures::random()
+        .tournament(club_id)
+        .name("Starting Soon Tournament")
+        .description("Test tournament starting soon")
+        .starts_at(chrono::Utc::now() + chrono::Duration::minutes(10))
+        .live_status(TournamentLiveStatus::RegistrationOpen)
+        .create(&app_state.db)
+        .await
+        .expect("Failed to create tournament");
 
     // Query using the standalone function
     let upcoming = infra::repos::tournaments::list_starting_soon(&app_state.db, 16).await;
@@ -0,0 +1,185 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+const ADD_ENTRY: &str = r#"
+    mutation($input: AddTournamentEntryInput!) {
+        addTournamentEntry(input: $input) { id entryType updatedAt }
+    }
+"#;
+
+const REGISTER_ROSTER: &str = r#"
+    mutation($input: RegisterRosterPlayerInput!) {
+        registerRosterPlayer(input: $input) { id status updatedAt }
+    }
+"#;
+
+const SYNC_STATUS: &str = r#"
+    query($items: [SyncItemInput!]!) {
+        syncStatus(items: $items) {
+            serverTime
+            records { entity id exists version }
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn test_offline_replays_are_idempotent_and_reconcilable() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("sync_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let (other_manager_id, other_claims) = create_test_user(
+        &app_state,
+        &format!("sync_other_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let (player_id, _) = create_test_user(
+        &app_state,
+        &format!("sync_player_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Sync Club").await;
+    let other_club_id = create_test_club(&app_state, "Other Sync Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    create_club_manager(&app_state, other_manager_id, other_club_id).await;
+    let tournament_id = create_open_tournament(&app_state, club_id, "Sync Open").await;
+    create_test_registration(&app_state, tournament_id, player_id, "registered").await;
+    let roster_player = infra::repos::club_players::create(
+        &app_state.db,
+        club_id,
+        "Walk-in Wendy",
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    // The desk queued a buy-in and a registration while offline, and
+    // replays each twice once it reconnects.
+    let entry_id = Uuid::new_v4();
+    let entry_input = json!({ "input": {
+        "id": entry_id,
+        "tournamentId": tournament_id,
+        "userId": player_id,
+        "entryType": "INITIAL",
+        "amountCents": 5000,
+    }});
+    let registration_id = Uuid::new_v4();
+    let registration_input = json!({ "input": {
+        "id": registration_id,
+        "tournamentId": tournament_id,
+        "clubPlayerId": roster_player.id,
+    }});
+    let mut registration_version = serde_json::Value::Null;
+    for _ in 0..2 {
+        let response = execute_graphql(
+            &schema,
+            ADD_ENTRY,
+            Some(Variables::from_json(entry_input.clone())),
+            Some(manager_claims.clone()),
+        )
+        .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["addTournamentEntry"]["id"], entry_id.to_string());
+
+        let response = execute_graphql(
+            &schema,
+            REGISTER_ROSTER,
+            Some(Variables::from_json(registration_input.clone())),
+            Some(manager_claims.clone()),
+        )
+        .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        assert_eq!(
+            data["registerRosterPlayer"]["id"],
+            registration_id.to_string()
+        );
+        registration_version = data["registerRosterPlayer"]["updatedAt"].clone();
+    }
+    let initial_entries: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM tournament_entries WHERE tournament_id = $1 AND entry_type = 'initial'",
+    )
+    .bind(tournament_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(initial_entries, 1);
+
+    // Reusing an id for something else is a conflict, not a replay.
+    let mut conflicting = entry_input.clone();
+    conflicting["input"]["entryType"] = json!("REBUY");
+    let response = execute_graphql(
+        &schema,
+        ADD_ENTRY,
+        Some(Variables::from_json(conflicting)),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert_eq!(
+        response.errors[0].message,
+        "Entry ID is already used by a different entry"
+    );
+
+    // The floor edited the registration meanwhile: its version moved on.
+    sqlx::query(
+        "UPDATE tournament_registrations SET status = 'checked_in', updated_at = NOW() + INTERVAL '1 second' WHERE id = $1",
+    )
+    .bind(registration_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+    let never_arrived = Uuid::new_v4();
+    let items = json!({ "items": [
+        { "entity": "TOURNAMENT_ENTRY", "id": entry_id },
+        { "entity": "TOURNAMENT_REGISTRATION", "id": registration_id },
+        { "entity": "TOURNAMENT_ENTRY", "id": never_arrived },
+    ]});
+    let response = execute_graphql(
+        &schema,
+        SYNC_STATUS,
+        Some(Variables::from_json(items.clone())),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let records = data["syncStatus"]["records"].as_array().unwrap();
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0]["exists"], true);
+    assert_eq!(records[1]["entity"], "TOURNAMENT_REGISTRATION");
+    assert_eq!(records[1]["exists"], true);
+    assert!(records[1]["version"].is_string());
+    assert_ne!(records[1]["version"], registration_version);
+    assert_eq!(records[2]["exists"], false);
+    assert!(records[2]["version"].is_null());
+
+    // Another club's manager learns nothing about these records.
+    let response = execute_graphql(
+        &schema,
+        SYNC_STATUS,
+        Some(Variables::from_json(items)),
+        Some(other_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert!(data["syncStatus"]["records"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|r| r["exists"] == false));
+}
@@ -0,0 +1,126 @@
+//! The tournament director's dashboard: funnel counts, unseated players,
+//! open seats and who to call next, from one query.
+
+use api::gql::build_schema;
+use async_graphql::Variables;
+use fixtures::{Fixtures, TournamentLiveStatus};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::common::*;
+
+const DASHBOARD: &str = r#"
+    query($tournamentId: UUID!) {
+        tournamentOpsDashboard(tournamentId: $tournamentId) {
+            tournament { id }
+            funnel { registered checkedIn seated busted waitlisted alternates cancelled noShows }
+            unseatedPlayers { registration { userId } }
+            openTables { tableNumber maxSeats occupiedSeats openSeats }
+            openSpots
+            pendingCalls { queue position player { registration { userId } } }
+            recentActivity { id }
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn ops_dashboard_shows_the_floor_at_a_glance() {
+    let app = setup_test_db().await;
+    let schema = build_schema(app.clone());
+    let unique = Uuid::new_v4().simple().to_string();
+
+    let (manager_id, manager) =
+        create_test_user(&app, &format!("ops_mgr_{unique}@test.com"), "manager").await;
+    let club_id = create_test_club(&app, "Ops Dashboard Club").await;
+    create_club_manager(&app, manager_id, club_id).await;
+    let tournament_id = Fixtures::random()
+        .tournament(club_id)
+        .name("Ops Dashboard Tournament")
+        .seat_cap(3)
+        .live_status(TournamentLiveStatus::RegistrationOpen)
+        .create(&app.db)
+        .await
+        .unwrap();
+    let table_id = create_test_club_table(&app, club_id, 4, 3).await;
+    assign_table_to_tournament(&app, tournament_id, table_id).await;
+
+    let mut players = Vec::new();
+    for (i, status) in ["seated", "checked_in", "registered", "waitlisted"]
+        .into_iter()
+        .enumerate()
+    {
+        let (player_id, _) =
+            create_test_user(&app, &format!("ops_p{i}_{unique}@test.com"), "player").await;
+        create_test_registration(&app, tournament_id, player_id, status).await;
+        players.push(player_id);
+    }
+    sqlx::query(
+        "INSERT INTO table_seat_assignments (tournament_id, club_table_id, user_id, seat_number) VALUES ($1, $2, $3, 1)",
+    )
+    .bind(tournament_id)
+    .bind(table_id)
+    .bind(players[0])
+    .execute(&app.db)
+    .await
+    .unwrap();
+
+    let vars = || {
+        Some(Variables::from_json(json!({
+            "tournamentId": tournament_id.to_string(),
+        })))
+    };
+
+    let res = execute_graphql(&schema, DASHBOARD, vars(), Some(manager.clone())).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    let dashboard = &data["tournamentOpsDashboard"];
+    assert_eq!(dashboard["funnel"]["seated"], 1);
+    assert_eq!(dashboard["funnel"]["checkedIn"], 1);
+    assert_eq!(dashboard["funnel"]["registered"], 1);
+    assert_eq!(dashboard["funnel"]["waitlisted"], 1);
+    assert_eq!(dashboard["funnel"]["noShows"], 0);
+    let unseated = dashboard["unseatedPlayers"].as_array().unwrap();
+    assert_eq!(unseated.len(), 1);
+    assert_eq!(
+        unseated[0]["registration"]["userId"],
+        players[1].to_string()
+    );
+    assert_eq!(
+        dashboard["openTables"],
+        json!([{ "tableNumber": 4, "maxSeats": 3, "occupiedSeats": 1, "openSeats": [2, 3] }])
+    );
+    // The field is full: nobody to call off the waitlist yet.
+    assert_eq!(dashboard["openSpots"], 0);
+    assert!(dashboard["pendingCalls"].as_array().unwrap().is_empty());
+
+    // A cancellation opens a spot for the head of the waitlist.
+    sqlx::query(
+        "UPDATE tournament_registrations SET status = 'cancelled' WHERE tournament_id = $1 AND user_id = $2",
+    )
+    .bind(tournament_id)
+    .bind(players[2])
+    .execute(&app.db)
+    .await
+    .unwrap();
+
+    let res = execute_graphql(&schema, DASHBOARD, vars(), Some(manager)).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    let dashboard = &data["tournamentOpsDashboard"];
+    assert_eq!(dashboard["funnel"]["cancelled"], 1);
+    assert_eq!(dashboard["openSpots"], 1);
+    assert_eq!(
+        dashboard["pendingCalls"],
+        json!([{
+            "queue": "WAITLIST",
+            "position": 1,
+            "player": { "registration": { "userId": players[3].to_string() } },
+        }])
+    );
+
+    // Players don't get the dashboard.
+    let (_, player) =
+        create_test_user(&app, &format!("ops_outsider_{unique}@test.com"), "player").await;
+    let res = execute_graphql(&schema, DASHBOARD, vars(), Some(player)).await;
+    assert!(!res.errors.is_empty());
+}
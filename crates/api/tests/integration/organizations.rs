@@ -0,0 +1,615 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+/// Register `user_id` in `tournament_id` and record their finish.
+async fn play(
+    app_state: &api::AppState,
+    tournament_id: Uuid,
+    user_id: Uuid,
+    position: i32,
+    prize_cents: i64,
+    points: i32,
+) {
+    create_test_registration(app_state, tournament_id, user_id, "registered").await;
+    sqlx::query(
+        "INSERT INTO tournament_results (tournament_id, user_id, final_position, prize_cents, points) \
+         VALUES ($1, $2, $3, $4, $5)",
+    )
+    .bind(tournament_id)
+    .bind(user_id)
+    .bind(position)
+    .bind(prize_cents)
+    .bind(points)
+    .execute(&app_state.db)
+    .await
+    .expect("Failed to record result");
+}
+
+#[tokio::test]
+async fn test_organization_leaderboard_merges_players_across_clubs() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (_, admin_claims) =
+        create_test_user(&app_state, &format!("orgadmin_{suffix}@test.com"), "admin").await;
+    let (traveller, traveller_claims) = create_test_user(
+        &app_state,
+        &format!("orgtraveller_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let (local, _) =
+        create_test_user(&app_state, &format!("orglocal_{suffix}@test.com"), "player").await;
+
+    let north = create_test_club(&app_state, "Org North").await;
+    let south = create_test_club(&app_state, "Org South").await;
+    let outside = create_test_club(&app_state, "Stand-alone Club").await;
+
+    // Only admins create organizations.
+    let create_query = r#"
+        mutation($input: CreateOrganizationInput!) {
+            createOrganization(input: $input) { id slug }
+        }
+    "#;
+    let create_vars = Variables::from_json(json!({
+        "input": { "name": "Org Group", "slug": format!("org-group-{suffix}") }
+    }));
+    let response = execute_graphql(
+        &schema,
+        create_query,
+        Some(create_vars.clone()),
+        Some(traveller_claims.clone()),
+    )
+    .await;
+    assert!(!response.errors.is_empty());
+
+    let response = execute_graphql(
+        &schema,
+        create_query,
+        Some(create_vars.clone()),
+        Some(admin_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let org_id = data["createOrganization"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // Slugs are unique.
+    let response = execute_graphql(
+        &schema,
+        create_query,
+        Some(create_vars),
+        Some(admin_claims.clone()),
+    )
+    .await;
+    assert!(!response.errors.is_empty());
+
+    for club in [north, south] {
+        let response = execute_graphql(
+            &schema,
+            r#"mutation($clubId: ID!, $orgId: ID) { setClubOrganization(clubId: $clubId, organizationId: $orgId) { id } }"#,
+            Some(Variables::from_json(json!({
+                "clubId": club.to_string(),
+                "orgId": org_id
+            }))),
+            Some(admin_claims.clone()),
+        )
+        .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+    }
+
+    let north_t = create_test_tournament(&app_state, north, "North Weekly").await;
+    let south_t = create_test_tournament(&app_state, south, "South Weekly").await;
+    let outside_t = create_test_tournament(&app_state, outside, "Outside Weekly").await;
+
+    play(&app_state, north_t, traveller, 2, 5_000, 20).await;
+    play(&app_state, south_t, traveller, 1, 10_000, 30).await;
+    play(&app_state, north_t, local, 1, 15_000, 40).await;
+    // Results outside the organization don't count.
+    play(&app_state, outside_t, local, 1, 50_000, 60).await;
+
+    let response = execute_graphql(
+        &schema,
+        r#"query($id: ID!) {
+            organizationLeaderboard(organizationId: $id) {
+                totalCount
+                items {
+                    rank points totalTournaments totalWinnings clubsPlayed
+                    user { id }
+                    clubBreakdown { clubId totalTournaments points }
+                }
+            }
+        }"#,
+        Some(Variables::from_json(json!({ "id": org_id }))),
+        None,
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let board = &data["organizationLeaderboard"];
+    assert_eq!(board["totalCount"], 2);
+    let items = board["items"].as_array().unwrap();
+    assert_eq!(items[0]["user"]["id"], traveller.to_string().as_str());
+    assert_eq!(items[0]["rank"], 1);
+    assert_eq!(items[0]["points"], 50.0);
+    assert_eq!(items[0]["totalTournaments"], 2);
+    assert_eq!(items[0]["clubsPlayed"], 2);
+    assert_eq!(items[0]["clubBreakdown"].as_array().unwrap().len(), 2);
+    assert_eq!(items[1]["user"]["id"], local.to_string().as_str());
+    assert_eq!(items[1]["points"], 40.0);
+    assert_eq!(items[1]["clubsPlayed"], 1);
+
+    let response = execute_graphql(
+        &schema,
+        r#"query($id: ID!) {
+            myOrganizationProfile(organizationId: $id) {
+                organization { slug clubs { id } }
+                stats { rank clubsPlayed }
+                loyalty { tournamentsPlayed clubsVisited monthsActive }
+            }
+        }"#,
+        Some(Variables::from_json(json!({ "id": org_id }))),
+        Some(traveller_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let profile = &data["myOrganizationProfile"];
+    assert_eq!(
+        profile["organization"]["clubs"].as_array().unwrap().len(),
+        2
+    );
+    assert_eq!(profile["stats"]["rank"], 1);
+    assert_eq!(profile["stats"]["clubsPlayed"], 2);
+    assert_eq!(profile["loyalty"]["tournamentsPlayed"], 2);
+    assert_eq!(profile["loyalty"]["clubsVisited"], 2);
+
+    // Stand-alone clubs report no organization.
+    let response = execute_graphql(
+        &schema,
+        r#"query { clubs { id organization { id } } }"#,
+        None,
+        Some(admin_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let clubs = data["clubs"].as_array().unwrap();
+    let find = |id: Uuid| {
+        clubs
+            .iter()
+            .find(|c| c["id"] == id.to_string().as_str())
+            .unwrap()
+            .clone()
+    };
+    assert_eq!(find(north)["organization"]["id"], org_id.as_str());
+    assert!(find(outside)["organization"].is_null());
+}
+
+#[tokio::test]
+async fn test_organization_admin_scope() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (_, admin_claims) = create_test_user(
+        &app_state,
+        &format!("orgscopeadmin_{suffix}@test.com"),
+        "admin",
+    )
+    .await;
+    let (org_admin, org_admin_claims) = create_test_user(
+        &app_state,
+        &format!("orgscopeowner_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let (manager_a, manager_a_claims) = create_test_user(
+        &app_state,
+        &format!("orgscopemgra_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let (manager_b, _) = create_test_user(
+        &app_state,
+        &format!("orgscopemgrb_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let (player, _) = create_test_user(
+        &app_state,
+        &format!("orgscopeplayer_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: CreateOrganizationInput!) { createOrganization(input: $input) { id } }"#,
+        Some(Variables::from_json(json!({
+            "input": { "name": "Scope Group", "slug": format!("scope-group-{suffix}") }
+        }))),
+        Some(admin_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let org_id = data["createOrganization"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let home = create_test_club(&app_state, "Scope Home").await;
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($clubId: ID!, $orgId: ID) { setClubOrganization(clubId: $clubId, organizationId: $orgId) { id } }"#,
+        Some(Variables::from_json(json!({ "clubId": home.to_string(), "orgId": org_id }))),
+        Some(admin_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    create_club_manager(&app_state, manager_a, home).await;
+    create_club_manager(&app_state, manager_b, home).await;
+
+    let report_query = r#"
+        query($id: ID!, $from: DateTime!, $to: DateTime!) {
+            organizationFinancialReport(organizationId: $id, from: $from, to: $to) {
+                tournamentCount entryCount totalCollectedCents
+                clubs { clubId tournamentCount totalCollectedCents }
+            }
+        }
+    "#;
+    let report_vars = Variables::from_json(json!({
+        "id": org_id,
+        "from": (chrono::Utc::now() - chrono::Duration::days(1)).to_rfc3339(),
+        "to": (chrono::Utc::now() + chrono::Duration::days(1)).to_rfc3339(),
+    }));
+
+    // Not an organization admin yet.
+    let response = execute_graphql(
+        &schema,
+        report_query,
+        Some(report_vars.clone()),
+        Some(org_admin_claims.clone()),
+    )
+    .await;
+    assert!(!response.errors.is_empty());
+
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($org: ID!, $user: ID!) { addOrganizationAdmin(organizationId: $org, userId: $user) { userId } }"#,
+        Some(Variables::from_json(json!({ "org": org_id, "user": org_admin.to_string() }))),
+        Some(admin_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    // Organization admins open clubs inside their organization.
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: CreateOrganizationClubInput!) {
+            createOrganizationClub(input: $input) { id plan organization { id } }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": { "organizationId": org_id, "name": "Scope Annex", "country": "BE" }
+        }))),
+        Some(org_admin_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let annex = data["createOrganizationClub"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert_eq!(data["createOrganizationClub"]["plan"], "CLUB");
+    assert_eq!(
+        data["createOrganizationClub"]["organization"]["id"],
+        org_id.as_str()
+    );
+
+    // ...and move managers between them.
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: MoveClubManagerInput!) { moveClubManager(input: $input) { userId } }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "userId": manager_b.to_string(),
+                "fromClubId": home.to_string(),
+                "toClubId": annex
+            }
+        }))),
+        Some(org_admin_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let moved: Vec<(Uuid, bool)> = sqlx::query_as(
+        "SELECT club_id, is_active FROM club_managers WHERE user_id = $1 ORDER BY is_active",
+    )
+    .bind(manager_b)
+    .fetch_all(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(moved, vec![(home, false), (annex.parse().unwrap(), true)]);
+
+    // The last manager of a club stays put.
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: MoveClubManagerInput!) { moveClubManager(input: $input) { userId } }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "userId": manager_a.to_string(),
+                "fromClubId": home.to_string(),
+                "toClubId": annex
+            }
+        }))),
+        Some(org_admin_claims.clone()),
+    )
+    .await;
+    assert!(!response.errors.is_empty());
+
+    // Consolidated finances: one entry at the home club.
+    let tournament_id = create_test_tournament(&app_state, home, "Scope Weekly").await;
+    create_test_registration(&app_state, tournament_id, player, "registered").await;
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: AddTournamentEntryInput!) { addTournamentEntry(input: $input) { id } }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "userId": player.to_string(),
+                "entryType": "INITIAL",
+                "amountCents": 5000
+            }
+        }))),
+        Some(manager_a_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let response = execute_graphql(
+        &schema,
+        report_query,
+        Some(report_vars),
+        Some(org_admin_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let report = &data["organizationFinancialReport"];
+    assert_eq!(report["tournamentCount"], 1);
+    assert_eq!(report["entryCount"], 1);
+    assert_eq!(report["totalCollectedCents"], 5000);
+    assert_eq!(report["clubs"].as_array().unwrap().len(), 2);
+
+    // Club managers don't get organization scope.
+    let response = execute_graphql(
+        &schema,
+        r#"query($id: ID!) { organizationAdmins(organizationId: $id) { userId } }"#,
+        Some(Variables::from_json(json!({ "id": org_id }))),
+        Some(manager_a_claims.clone()),
+    )
+    .await;
+    assert!(!response.errors.is_empty());
+
+    // An organization keeps at least one admin.
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($org: ID!, $user: ID!) { removeOrganizationAdmin(organizationId: $org, userId: $user) }"#,
+        Some(Variables::from_json(json!({ "org": org_id, "user": org_admin.to_string() }))),
+        Some(org_admin_claims.clone()),
+    )
+    .await;
+    assert!(!response.errors.is_empty());
+}
+
+#[tokio::test]
+async fn test_tournament_approval_workflow() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (org_admin, org_admin_claims) = create_test_user(
+        &app_state,
+        &format!("approvalowner_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let (manager, manager_claims) = create_test_user(
+        &app_state,
+        &format!("approvalmgr_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let (_, player_claims) = create_test_user(
+        &app_state,
+        &format!("approvalplayer_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+
+    let org_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO organizations (name, slug) VALUES ('Franchise', $1) RETURNING id",
+    )
+    .bind(format!("franchise-{suffix}"))
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO organization_admins (organization_id, user_id) VALUES ($1, $2)")
+        .bind(org_id)
+        .bind(org_admin)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    let club_id = create_test_club(&app_state, "Franchise Venue").await;
+    sqlx::query("UPDATE clubs SET organization_id = $1 WHERE id = $2")
+        .bind(org_id)
+        .bind(club_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    create_club_manager(&app_state, manager, club_id).await;
+
+    // Only organization admins switch the workflow on.
+    let set_approval = r#"mutation($org: ID!) {
+        setOrganizationTournamentApproval(organizationId: $org, required: true) {
+            requireTournamentApproval
+        }
+    }"#;
+    let vars = || Some(Variables::from_json(json!({ "org": org_id.to_string() })));
+    let response =
+        execute_graphql(&schema, set_approval, vars(), Some(manager_claims.clone())).await;
+    assert!(
+        !response.errors.is_empty(),
+        "managers can't change the workflow"
+    );
+    let response = execute_graphql(
+        &schema,
+        set_approval,
+        vars(),
+        Some(org_admin_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let create = |name: &'static str| {
+        let schema = schema.clone();
+        let claims = manager_claims.clone();
+        async move {
+            let response = execute_graphql(
+                &schema,
+                r#"mutation($input: CreateTournamentInput!) {
+                    createTournament(input: $input) { id approval { status } }
+                }"#,
+                Some(Variables::from_json(json!({
+                    "input": {
+                        "clubId": club_id.to_string(),
+                        "name": name,
+                        "startTime": "2026-12-01T19:00:00Z",
+                        "buyInCents": 5000
+                    }
+                }))),
+                Some(claims),
+            )
+            .await;
+            assert!(response.errors.is_empty(), "create: {:?}", response.errors);
+            let data = response.data.into_json().unwrap();
+            assert_eq!(data["createTournament"]["approval"]["status"], "PENDING");
+            data["createTournament"]["id"].as_str().unwrap().to_string()
+        }
+    };
+    let rejected_id = create("Unbranded Freezeout").await;
+    let approved_id = create("Franchise Main Event").await;
+
+    let visible_to_player = |id: String| {
+        let schema = schema.clone();
+        let claims = player_claims.clone();
+        async move {
+            let response = execute_graphql(
+                &schema,
+                &format!(r#"query {{ tournament(id: "{id}") {{ id }} }}"#),
+                None,
+                Some(claims),
+            )
+            .await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+            !response.data.into_json().unwrap()["tournament"].is_null()
+        }
+    };
+    assert!(!visible_to_player(approved_id.clone()).await);
+
+    let pending = r#"query($org: ID!) {
+        pendingTournamentApprovals(organizationId: $org) { tournamentId tournament { title } }
+    }"#;
+    let response = execute_graphql(&schema, pending, vars(), Some(manager_claims.clone())).await;
+    assert!(!response.errors.is_empty(), "managers don't review");
+    let response = execute_graphql(&schema, pending, vars(), Some(org_admin_claims.clone())).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let names: Vec<&str> = data["pendingTournamentApprovals"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a["tournament"]["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["Unbranded Freezeout", "Franchise Main Event"]);
+
+    let response = execute_graphql(
+        &schema,
+        &format!(
+            r#"mutation {{
+                rejectTournament(tournamentId: "{rejected_id}", reason: "Use the franchise branding") {{
+                    status reviewNote
+                }}
+            }}"#
+        ),
+        None,
+        Some(org_admin_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["rejectTournament"]["status"], "REJECTED");
+    assert_eq!(
+        data["rejectTournament"]["reviewNote"],
+        "Use the franchise branding"
+    );
+    assert!(!visible_to_player(rejected_id.clone()).await);
+
+    let response = execute_graphql(
+        &schema,
+        &format!(r#"mutation {{ approveTournament(tournamentId: "{rejected_id}") {{ status }} }}"#),
+        None,
+        Some(org_admin_claims.clone()),
+    )
+    .await;
+    assert!(
+        response.errors[0].message.contains("already been reviewed"),
+        "{:?}",
+        response.errors
+    );
+
+    let response = execute_graphql(
+        &schema,
+        &format!(r#"mutation {{ approveTournament(tournamentId: "{approved_id}") {{ status }} }}"#),
+        None,
+        Some(org_admin_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert!(visible_to_player(approved_id.clone()).await);
+
+    let response = execute_graphql(&schema, pending, vars(), Some(org_admin_claims)).await;
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["pendingTournamentApprovals"], json!([]));
+
+    let db = &app_state.db;
+    let ids = vec![
+        Uuid::parse_str(&approved_id).unwrap(),
+        Uuid::parse_str(&rejected_id).unwrap(),
+    ];
+    let actions: Vec<String> = eventually(|| {
+        let ids = ids.clone();
+        async move {
+            let actions: Vec<String> = sqlx::query_scalar(
+                "SELECT event_action FROM tournament_activity_log \
+                 WHERE tournament_id = ANY($1) AND event_action IN ('approved', 'rejected') \
+                 ORDER BY event_action",
+            )
+            .bind(ids)
+            .fetch_all(db)
+            .await
+            .unwrap();
+            (actions.len() >= 2).then_some(actions)
+        }
+    })
+    .await;
+    assert_eq!(actions, vec!["approved", "rejected"]);
+}
@@ -147,3 +147,196 @@ async fn test_payout_recalculation_on_entry() {
         assert_eq!(payout["playerCount"], 2);
     }
 }
+
+#[tokio::test]
+async fn payouts_freeze_when_late_registration_closes() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let unique = uuid::Uuid::new_v4().simple().to_string();
+
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("payout_final_manager_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Payout Final Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id =
+        create_open_tournament(&app_state, club_id, "Payout Final Tournament").await;
+    let (player_id, _) = create_test_user(
+        &app_state,
+        &format!("payout_final_player_{unique}@test.com"),
+        "player",
+    )
+    .await;
+
+    let add_entry = r#"
+        mutation AddEntry($input: AddTournamentEntryInput!) {
+            addTournamentEntry(input: $input) { id }
+        }
+    "#;
+    let entry = || {
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "userId": player_id.to_string(),
+                "entryType": "REBUY",
+                "amountCents": 5000
+            }
+        })))
+    };
+    let tournament_vars = || {
+        Some(Variables::from_json(
+            json!({ "tournamentId": tournament_id.to_string() }),
+        ))
+    };
+    let payout_query = r#"
+        query($tournamentId: ID!) {
+            tournamentPayout(tournamentId: $tournamentId) { totalPrizePool finalizedAt }
+        }
+    "#;
+
+    let res = execute_graphql(&schema, add_entry, entry(), Some(manager_claims.clone())).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+
+    // Play starts: registration is over and the table becomes official. The
+    // bare test event has no structure or clock, so skip the start checklist.
+    let res = execute_graphql(
+        &schema,
+        r#"mutation($input: UpdateTournamentStatusInput!) {
+            updateTournamentStatus(input: $input) { liveStatus }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "liveStatus": "IN_PROGRESS",
+                "overrideReadiness": true
+            }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+
+    let res = execute_graphql(&schema, payout_query, tournament_vars(), None).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(data["tournamentPayout"]["totalPrizePool"], 5000);
+    assert!(data["tournamentPayout"]["finalizedAt"].is_string());
+
+    let res = execute_graphql(&schema, add_entry, entry(), Some(manager_claims.clone())).await;
+    let code = res
+        .errors
+        .first()
+        .and_then(|e| e.extensions.as_ref()?.get("code").cloned());
+    assert_eq!(
+        code,
+        Some(async_graphql::Value::String("PAYOUTS_FINALIZED".into()))
+    );
+
+    // The manager override reopens the pool; the next entry moves it again.
+    let res = execute_graphql(
+        &schema,
+        r#"mutation($tournamentId: ID!) {
+            reopenTournamentPayouts(tournamentId: $tournamentId) { finalizedAt }
+        }"#,
+        tournament_vars(),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    assert!(data["reopenTournamentPayouts"]["finalizedAt"].is_null());
+
+    let res = execute_graphql(&schema, add_entry, entry(), Some(manager_claims.clone())).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let res = execute_graphql(&schema, payout_query, tournament_vars(), None).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(data["tournamentPayout"]["totalPrizePool"], 10000);
+}
+
+#[tokio::test]
+async fn entries_after_finalization_are_refused_by_the_database() {
+    use infra::repos::tournament_entries::{self, CreateTournamentEntry, EntryRejection};
+
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager_claims) =
+        create_test_user(&app_state, "final_pool_manager@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Final Pool Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Final Pool Event").await;
+    let (player_id, _) = create_test_user(&app_state, "final_pool_player@test.com", "player").await;
+
+    let res = execute_graphql(
+        &schema,
+        r#"mutation($input: AddTournamentEntryInput!) {
+            addTournamentEntry(input: $input) { id }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "userId": player_id.to_string(),
+                "entryType": "INITIAL",
+                "amountCents": 5000
+            }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let entry_id = res.data.into_json().unwrap()["addTournamentEntry"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    infra::repos::tournament_payouts::finalize(&app_state.db, tournament_id, Some(manager_id))
+        .await
+        .unwrap()
+        .expect("payout table finalized");
+
+    // Any write path, not only the mutation, is refused.
+    let entry = |entry_type: &str| CreateTournamentEntry {
+        tournament_id,
+        user_id: Some(player_id),
+        entry_type: entry_type.to_string(),
+        amount_cents: 5000,
+        ..Default::default()
+    };
+    let err = tournament_entries::create(&app_state.db, entry("rebuy"))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        EntryRejection::classify(&err),
+        Some(EntryRejection::PayoutsFinalized)
+    );
+
+    // Vouchers never reach the pool.
+    tournament_entries::create(&app_state.db, entry("voucher"))
+        .await
+        .unwrap();
+
+    let res = execute_graphql(
+        &schema,
+        r#"mutation($entryId: ID!) { deleteTournamentEntry(entryId: $entryId) }"#,
+        Some(Variables::from_json(json!({ "entryId": entry_id }))),
+        Some(manager_claims),
+    )
+    .await;
+    assert_eq!(
+        error_code(&res).as_deref(),
+        Some("PAYOUTS_FINALIZED"),
+        "{:?}",
+        res.errors
+    );
+
+    let pool: i64 = sqlx::query_scalar(
+        "SELECT total_prize_pool FROM tournament_payouts WHERE tournament_id = $1",
+    )
+    .bind(tournament_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(pool, 5000);
+}
@@ -0,0 +1,130 @@
+use crate::common::*;
+use api::gql::build_schema;
+use api::gql::domains::persisted_operations::allowlist::{ApiClient, OperationAllowList};
+use api::gql::{MutationRoot, QueryRoot, SubscriptionRoot};
+use async_graphql::{Request, Schema, Variables};
+use serde_json::json;
+
+const PING: &str = "query Ping { __typename }";
+
+#[tokio::test]
+async fn test_locked_mode_runs_only_registered_operations() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let locked = Schema::build(
+        QueryRoot::default(),
+        MutationRoot::default(),
+        SubscriptionRoot,
+    )
+    .data(app_state.clone())
+    .extension(OperationAllowList)
+    .finish();
+
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (_, admin_claims) = create_test_user(
+        &app_state,
+        &format!("allowlist_admin_{unique}@test.com"),
+        "admin",
+    )
+    .await;
+    let (_, player_claims) = create_test_user(
+        &app_state,
+        &format!("allowlist_player_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let kiosk = format!("kiosk-{unique}");
+
+    let response = execute_graphql(
+        &schema,
+        r#"
+        mutation($input: RegisterPersistedOperationsInput!) {
+            registerPersistedOperations(input: $input) { id operationName sha256Hash }
+        }
+        "#,
+        Some(Variables::from_json(json!({ "input": {
+            "clientName": kiosk,
+            "operations": [{ "operationName": "Ping", "query": PING }],
+        }}))),
+        Some(admin_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let operation = &data["registerPersistedOperations"][0];
+    let hash = operation["sha256Hash"].as_str().unwrap().to_string();
+    let operation_id = operation["id"].as_str().unwrap().to_string();
+
+    let run = |request: Request, client: Option<&str>| {
+        let mut request = request.data(player_claims.clone());
+        if let Some(client) = client {
+            request = request.data(ApiClient(client.to_string()));
+        }
+        locked.execute(request)
+    };
+
+    // The registered text runs; anything else, or an unnamed client, doesn't.
+    let response = run(Request::new(PING), Some(&kiosk)).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let response = run(Request::new("{ __typename }"), Some(&kiosk)).await;
+    assert_eq!(
+        response.errors[0].message,
+        "Operation is not on this client's allow-list"
+    );
+    let response = run(Request::new(PING), None).await;
+    assert!(response.errors[0].message.starts_with("Unknown API client"));
+    let response = run(Request::new(PING), Some("display")).await;
+    assert!(!response.errors.is_empty());
+
+    // The hash alone is enough.
+    let mut by_hash = Request::new("");
+    by_hash.extensions.insert(
+        "persistedQuery".to_string(),
+        async_graphql::Value::from_json(json!({ "version": 1, "sha256Hash": hash })).unwrap(),
+    );
+    let response = run(by_hash, Some(&kiosk)).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap()["__typename"],
+        "QueryRoot"
+    );
+
+    // Admins aren't locked out.
+    let response = locked
+        .execute(Request::new("{ __typename }").data(admin_claims.clone()))
+        .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    // A revoked operation stops running and drops off the default listing.
+    let response = execute_graphql(
+        &schema,
+        "mutation($id: ID!) { revokePersistedOperation(id: $id) { revokedAt } }",
+        Some(Variables::from_json(json!({ "id": operation_id }))),
+        Some(admin_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let response = run(Request::new(PING), Some(&kiosk)).await;
+    assert!(!response.errors.is_empty());
+
+    let list = r#"
+        query($client: String, $all: Boolean!) {
+            persistedOperations(clientName: $client, includeRevoked: $all) { id }
+        }
+    "#;
+    for (all, expected) in [(false, 0), (true, 1)] {
+        let response = execute_graphql(
+            &schema,
+            list,
+            Some(Variables::from_json(json!({ "client": kiosk, "all": all }))),
+            Some(admin_claims.clone()),
+        )
+        .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        assert_eq!(
+            data["persistedOperations"].as_array().unwrap().len(),
+            expected
+        );
+    }
+}
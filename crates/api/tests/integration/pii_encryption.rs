@@ -0,0 +1,140 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use base64::Engine;
+use infra::pii::{self, Keyring};
+use infra::repos::users;
+use serde_json::json;
+use uuid::Uuid;
+
+const UPDATE_PHONE: &str = r#"
+    mutation($input: UpdatePlayerInput!) {
+        updatePlayer(input: $input) { id phone }
+    }
+"#;
+
+const ROTATE: &str = r#"
+    mutation($dryRun: Boolean = false) {
+        runMaintenance(task: ROTATE_PII_KEY, dryRun: $dryRun) { dryRun rowsResealed }
+    }
+"#;
+
+const STATUS: &str = r#"
+    query {
+        piiEncryptionStatus { enabled activeKeyId keyIds phones { keyId rows } }
+    }
+"#;
+
+fn key(byte: u8) -> String {
+    base64::engine::general_purpose::STANDARD.encode([byte; 32])
+}
+
+async fn stored_phone(app_state: &api::AppState, user_id: Uuid) -> String {
+    sqlx::query_scalar("SELECT phone FROM users WHERE id = $1")
+        .bind(user_id)
+        .fetch_one(&app_state.db)
+        .await
+        .unwrap()
+}
+
+/// The keyring is process-wide, so this is the only test that installs one.
+/// Later keyrings keep the earlier keys so other tests' rows stay readable.
+#[tokio::test]
+async fn test_phone_numbers_are_sealed_and_rotated() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let unique = Uuid::new_v4();
+    let (_, admin) =
+        create_test_user(&app_state, &format!("pii_admin_{unique}@test.com"), "admin").await;
+    let (player_id, player) = create_test_user(
+        &app_state,
+        &format!("pii_player_{unique}@test.com"),
+        "player",
+    )
+    .await;
+
+    // A number written before encryption was enabled stays plaintext.
+    sqlx::query("UPDATE users SET phone = '+32470000001' WHERE id = $1")
+        .bind(player_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+
+    pii::install(Some(Keyring::parse(&format!("k1:{}", key(1))).unwrap()));
+
+    let (other_id, _) = create_test_user(
+        &app_state,
+        &format!("pii_other_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let response = execute_graphql(
+        &schema,
+        UPDATE_PHONE,
+        Some(Variables::from_json(json!({
+            "input": { "id": other_id, "phone": "+32470000002" }
+        }))),
+        Some(admin.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    // Callers see plaintext; the column holds ciphertext.
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["updatePlayer"]["phone"], "+32470000002");
+    let stored = stored_phone(&app_state, other_id).await;
+    assert!(stored.starts_with("enc:v1:k1:"), "{stored}");
+    assert!(!stored.contains("32470000002"));
+    let row = users::get_by_id(&app_state.db, other_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(row.phone.unwrap().as_str(), "+32470000002");
+    assert_eq!(stored_phone(&app_state, player_id).await, "+32470000001");
+
+    // Rotate onto k2: both the plaintext and the k1 value get sealed with it.
+    pii::install(Some(
+        Keyring::parse(&format!("k2:{},k1:{}", key(2), key(1))).unwrap(),
+    ));
+    let response = execute_graphql(
+        &schema,
+        ROTATE,
+        Some(Variables::from_json(json!({ "dryRun": true }))),
+        Some(admin.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert!(data["runMaintenance"]["rowsResealed"].as_i64().unwrap() >= 2);
+    assert_eq!(stored_phone(&app_state, player_id).await, "+32470000001");
+
+    let response = execute_graphql(&schema, ROTATE, None, Some(admin.clone())).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert!(data["runMaintenance"]["rowsResealed"].as_i64().unwrap() >= 2);
+
+    for (user_id, phone) in [(player_id, "+32470000001"), (other_id, "+32470000002")] {
+        assert!(stored_phone(&app_state, user_id)
+            .await
+            .starts_with("enc:v1:k2:"));
+        let row = users::get_by_id(&app_state.db, user_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(row.phone.unwrap().as_str(), phone);
+    }
+
+    let response = execute_graphql(&schema, STATUS, None, Some(admin)).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let status = &response.data.into_json().unwrap()["piiEncryptionStatus"];
+    assert_eq!(status["enabled"], true);
+    assert_eq!(status["activeKeyId"], "k2");
+    assert_eq!(status["keyIds"], json!(["k2", "k1"]));
+    assert!(status["phones"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|usage| usage["keyId"] == "k2"));
+
+    let response = execute_graphql(&schema, ROTATE, None, Some(player)).await;
+    assert!(!response.errors.is_empty());
+}
@@ -428,3 +428,107 @@ async fn test_deactivate_reactivate_lifecycle() {
     let data = response.data.into_json().unwrap();
     assert_eq!(data["reactivatePlayer"]["isActive"], true);
 }
+
+/// Phones are stored in E.164, national numbers read as the club's country's,
+/// and shown back the way that country dials them. Numbers saved before
+/// normalization are rewritten by the maintenance task.
+#[tokio::test]
+async fn test_player_phone_is_normalized() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("phone_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let (_, admin_claims) = create_test_user(
+        &app_state,
+        &format!("phone_admin_{unique}@test.com"),
+        "admin",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Phone Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+
+    let query = r#"
+        mutation CreatePlayer($input: CreatePlayerInput!, $clubId: ID) {
+            createPlayer(input: $input) { phone formattedPhone(clubId: $clubId) }
+        }
+    "#;
+    let create = |email: String, phone: &str| {
+        Some(Variables::from_json(json!({
+            "input": {
+                "email": email,
+                "firstName": "Phone",
+                "phone": phone,
+                "clubId": club_id.to_string()
+            },
+            "clubId": club_id.to_string()
+        })))
+    };
+
+    let response = execute_graphql(
+        &schema,
+        query,
+        create(format!("phone_ok_{unique}@test.com"), "0470/12.34.56"),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let user = &response.data.into_json().unwrap()["createPlayer"];
+    assert_eq!(user["phone"], "+32470123456");
+    assert_eq!(user["formattedPhone"], "0470 12 34 56");
+
+    let response = execute_graphql(
+        &schema,
+        query,
+        create(format!("phone_bad_{unique}@test.com"), "call me maybe"),
+        Some(manager_claims),
+    )
+    .await;
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(response.errors[0].message, "Invalid phone number");
+
+    // A number saved as typed, before normalization.
+    let (legacy_id, _) = create_test_user(
+        &app_state,
+        &format!("phone_legacy_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    sqlx::query("UPDATE users SET phone = '+33 (0)6 12 34 56 78' WHERE id = $1")
+        .bind(legacy_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+
+    let backfill = r#"
+        mutation($dryRun: Boolean!) {
+            runMaintenance(task: NORMALIZE_PHONES, dryRun: $dryRun) {
+                phonesNormalized phonesUnreadable
+            }
+        }
+    "#;
+    for dry_run in [true, false] {
+        let response = execute_graphql(
+            &schema,
+            backfill,
+            Some(Variables::from_json(json!({ "dryRun": dry_run }))),
+            Some(admin_claims.clone()),
+        )
+        .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let report = &response.data.into_json().unwrap()["runMaintenance"];
+        assert!(report["phonesNormalized"].as_i64().unwrap() >= 1);
+    }
+    let phone = infra::repos::users::get_by_id(&app_state.db, legacy_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .phone
+        .unwrap();
+    assert_eq!(phone.as_str(), "+33612345678");
+}
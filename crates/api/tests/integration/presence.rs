@@ -0,0 +1,120 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+
+const HEARTBEAT: &str = r#"
+    mutation($id: ID!) {
+        heartbeat(tournamentId: $id) { status }
+    }
+"#;
+
+const PLAYERS: &str = r#"
+    query($id: UUID!) {
+        tournamentPlayers(tournamentId: $id) {
+            items { registration { userId presence { status lastSeenAt } } }
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn test_heartbeat_marks_players_present_for_managers() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("presence_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let (here_id, here_claims) = create_test_user(
+        &app_state,
+        &format!("presence_here_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let (away_id, _) = create_test_user(
+        &app_state,
+        &format!("presence_away_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let (late_id, _) = create_test_user(
+        &app_state,
+        &format!("presence_late_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let (seated_id, _) = create_test_user(
+        &app_state,
+        &format!("presence_seated_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let (_, stranger_claims) = create_test_user(
+        &app_state,
+        &format!("presence_stranger_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Presence Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Presence Open").await;
+    for user_id in [here_id, away_id, late_id] {
+        create_test_registration(&app_state, tournament_id, user_id, "registered").await;
+    }
+    create_test_registration(&app_state, tournament_id, seated_id, "seated").await;
+
+    let vars = Variables::from_json(json!({ "id": tournament_id }));
+    let response = execute_graphql(&schema, HEARTBEAT, Some(vars.clone()), Some(here_claims)).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    // Someone who pinged an hour ago and left.
+    sqlx::query(
+        "UPDATE tournament_registrations SET last_seen_at = NOW() - INTERVAL '1 hour'
+         WHERE tournament_id = $1 AND user_id = $2",
+    )
+    .bind(tournament_id)
+    .bind(away_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    // Only registered players can ping.
+    let response = execute_graphql(
+        &schema,
+        HEARTBEAT,
+        Some(vars.clone()),
+        Some(stranger_claims.clone()),
+    )
+    .await;
+    assert_eq!(
+        response.errors[0].message,
+        "You are not registered for this tournament"
+    );
+
+    let response =
+        execute_graphql(&schema, PLAYERS, Some(vars.clone()), Some(manager_claims)).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let presence_of = |user_id: uuid::Uuid| {
+        data["tournamentPlayers"]["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|p| p["registration"]["userId"] == user_id.to_string())
+            .unwrap()["registration"]["presence"]
+            .clone()
+    };
+    assert_eq!(presence_of(here_id)["status"], "PRESENT");
+    assert!(presence_of(here_id)["lastSeenAt"].is_string());
+    assert_eq!(presence_of(away_id)["status"], "AWAY");
+    assert_eq!(presence_of(late_id)["status"], "NOT_ARRIVED");
+    assert_eq!(presence_of(seated_id)["status"], "CHECKED_IN");
+
+    // Players can't see who is on site.
+    let response = execute_graphql(&schema, PLAYERS, Some(vars), Some(stranger_claims)).await;
+    assert!(!response.errors.is_empty());
+}
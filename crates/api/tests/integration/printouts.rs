@@ -0,0 +1,460 @@
+use crate::common::*;
+use api::gql::build_schema;
+use api::routes::printouts::{download, DownloadQuery};
+use api::routes::receipts::print;
+use api::routes::stat_cards::image;
+use async_graphql::Variables;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use chrono::{Datelike, Duration, Months, NaiveDate, Utc};
+use fixtures::Fixtures;
+use serde_json::json;
+use uuid::Uuid;
+
+const PRINTOUTS: &str = r#"
+    query($id: ID!) {
+        tournamentPrintouts(tournamentId: $id) { id kind pageCount downloadUrl urlExpiresAt }
+    }
+"#;
+
+/// Split a signed download URL into the handler's path and query parts.
+fn parse_url(url: &str) -> (Uuid, DownloadQuery) {
+    let (path, query) = url.split_once('?').unwrap();
+    let id = Uuid::parse_str(path.rsplit('/').next().unwrap()).unwrap();
+    let mut expires = 0;
+    let mut signature = String::new();
+    for pair in query.split('&') {
+        match pair.split_once('=').unwrap() {
+            ("expires", v) => expires = v.parse().unwrap(),
+            ("signature", v) => signature = v.to_string(),
+            _ => {}
+        }
+    }
+    (id, DownloadQuery { expires, signature })
+}
+
+/// The seat draw produces seat cards and a seat list, entering results
+/// produces the payout sheet, and each is downloadable through its signed
+/// link only.
+#[tokio::test]
+async fn test_printouts_after_seat_draw_and_results() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) = create_test_user(&app_state, "print_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Print Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Friday Deepstack").await;
+    for number in [1, 2] {
+        let table_id = create_test_club_table(&app_state, club_id, number, 6).await;
+        assign_table_to_tournament(&app_state, tournament_id, table_id).await;
+    }
+
+    let mut players = Vec::new();
+    let mut player_claims = Vec::new();
+    for i in 0..3 {
+        let (user_id, claims) =
+            create_test_user(&app_state, &format!("print_p{i}@test.com"), "player").await;
+        create_test_registration(&app_state, tournament_id, user_id, "checked_in").await;
+        players.push(user_id);
+        player_claims.push(claims);
+    }
+    sqlx::query("UPDATE tournaments SET live_status = 'registration_open' WHERE id = $1")
+        .bind(tournament_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+
+    // The seat draw.
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($input: UpdateTournamentStatusInput!) {
+            updateTournamentStatus(input: $input) { liveStatus }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": { "tournamentId": tournament_id.to_string(), "liveStatus": "LATE_REGISTRATION" }
+        }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "draw: {:?}", resp.errors);
+
+    let vars = Some(Variables::from_json(
+        json!({ "id": tournament_id.to_string() }),
+    ));
+    let resp = execute_graphql(
+        &schema,
+        PRINTOUTS,
+        vars.clone(),
+        Some(player_claims[0].clone()),
+    )
+    .await;
+    assert!(!resp.errors.is_empty(), "players can't fetch printouts");
+
+    let resp = execute_graphql(&schema, PRINTOUTS, vars.clone(), Some(manager.clone())).await;
+    assert!(resp.errors.is_empty(), "printouts: {:?}", resp.errors);
+    let printouts = resp.data.into_json().unwrap()["tournamentPrintouts"].clone();
+    let kinds: Vec<&str> = printouts
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["kind"].as_str().unwrap())
+        .collect();
+    assert_eq!(kinds, vec!["SEAT_CARDS", "SEAT_LIST"]);
+    // One seat card per linked table, the empty one included.
+    assert_eq!(printouts[0]["pageCount"], 2);
+
+    let (id, query) = parse_url(printouts[1]["downloadUrl"].as_str().unwrap());
+    let response = download(State(app_state.clone()), Path(id), Query(query))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "application/pdf");
+    assert!(response.headers()["content-disposition"]
+        .to_str()
+        .unwrap()
+        .contains("friday-deepstack-seat-list.pdf"));
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert!(body.starts_with(b"%PDF-"));
+
+    // A forged or tampered link is refused.
+    let (id, mut query) = parse_url(printouts[1]["downloadUrl"].as_str().unwrap());
+    query.expires += 3600;
+    let err = download(State(app_state.clone()), Path(id), Query(query))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, api::error::AppError::Unauthorized(_)));
+
+    // Entering results makes the payout sheet available right away.
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($input: EnterTournamentResultsInput!) {
+            enterTournamentResults(input: $input) { success }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "playerPositions": players
+                    .iter()
+                    .enumerate()
+                    .map(|(i, id)| json!({ "userId": id.to_string(), "finalPosition": i + 1 }))
+                    .collect::<Vec<_>>(),
+            }
+        }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "results: {:?}", resp.errors);
+
+    let resp = execute_graphql(&schema, PRINTOUTS, vars, Some(manager.clone())).await;
+    let printouts = resp.data.into_json().unwrap()["tournamentPrintouts"].clone();
+    assert_eq!(printouts[2]["kind"], "PAYOUT_SHEET");
+    let (id, query) = parse_url(printouts[2]["downloadUrl"].as_str().unwrap());
+    let response = download(State(app_state.clone()), Path(id), Query(query))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Regenerating on demand replaces the documents in place.
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($id: ID!) {
+            generateTournamentPrintouts(tournamentId: $id, kinds: [SEAT_LIST]) { id kind }
+        }"#,
+        Some(Variables::from_json(
+            json!({ "id": tournament_id.to_string() }),
+        )),
+        Some(manager),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "regenerate: {:?}", resp.errors);
+    let regenerated = resp.data.into_json().unwrap()["generateTournamentPrintouts"].clone();
+    assert_eq!(regenerated[0]["id"], printouts[1]["id"]);
+}
+
+const VERIFY_RECEIPT: &str = r#"
+    query($code: String!) {
+        verifyEntryReceipt(code: $code) { id voided printUrl amountCents }
+    }
+"#;
+
+/// A paid entry gets a receipt with a signed ESC/POS print link; reprints
+/// are marked as copies, the code verifies for staff only, and deleting the
+/// entry voids the receipt.
+#[tokio::test]
+async fn test_buy_in_receipt_print_and_verify() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) =
+        create_test_user(&app_state, "receipt_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Receipt Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Monday Turbo").await;
+    sqlx::query("UPDATE tournaments SET voucher_value_cents = 500 WHERE id = $1")
+        .bind(tournament_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    let (player_id, player) =
+        create_test_user(&app_state, "receipt_player@test.com", "player").await;
+
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($input: AddTournamentEntryInput!) {
+            addTournamentEntry(input: $input) {
+                id
+                receipt {
+                    verificationCode tournamentName paymentMethod
+                    amountCents voucherCents printCount printUrl
+                }
+            }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "userId": player_id.to_string(),
+                "entryType": "INITIAL",
+                "amountCents": 5000,
+                "paymentMethod": "CARD"
+            }
+        }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "entry: {:?}", resp.errors);
+    let entry = resp.data.into_json().unwrap()["addTournamentEntry"].clone();
+    let receipt = entry["receipt"].clone();
+    assert_eq!(receipt["tournamentName"], "Monday Turbo");
+    assert_eq!(receipt["paymentMethod"], "CARD");
+    assert_eq!(receipt["amountCents"], 5000);
+    assert_eq!(receipt["voucherCents"], 500);
+    assert_eq!(receipt["printCount"], 0);
+    let code = receipt["verificationCode"].as_str().unwrap().to_string();
+    let print_url = receipt["printUrl"].as_str().unwrap().to_string();
+    assert!(print_url.contains("/receipts/"));
+
+    // The first fetch is the original, later ones are copies.
+    let (id, query) = parse_url(&print_url);
+    let response = print(State(app_state.clone()), Path(id), Query(query))
+        .await
+        .unwrap();
+    assert_eq!(
+        response.headers()["content-type"],
+        "application/octet-stream"
+    );
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert!(body.starts_with(&[0x1b, b'@']));
+    let contains = |body: &[u8], needle: &[u8]| body.windows(needle.len()).any(|w| w == needle);
+    assert!(contains(&body, code.as_bytes()));
+    assert!(!contains(&body, b"COPY"));
+
+    let (id, query) = parse_url(&print_url);
+    let response = print(State(app_state.clone()), Path(id), Query(query))
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert!(contains(&body, b"*** COPY ***"));
+
+    // A printout link doesn't open a receipt.
+    let (id, query) = parse_url(&print_url);
+    let err = download(State(app_state.clone()), Path(id), Query(query))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, api::error::AppError::Unauthorized(_)));
+
+    // Staff can verify the code however it was typed; players see nothing.
+    let vars = Some(Variables::from_json(
+        json!({ "code": code.to_lowercase().replace('-', " ") }),
+    ));
+    let resp = execute_graphql(&schema, VERIFY_RECEIPT, vars.clone(), Some(player)).await;
+    assert!(resp.errors.is_empty(), "verify: {:?}", resp.errors);
+    assert!(resp.data.into_json().unwrap()["verifyEntryReceipt"].is_null());
+
+    let resp = execute_graphql(&schema, VERIFY_RECEIPT, vars.clone(), Some(manager.clone())).await;
+    let verified = resp.data.into_json().unwrap()["verifyEntryReceipt"].clone();
+    assert_eq!(verified["voided"], false);
+    assert_eq!(verified["amountCents"], 5000);
+
+    // Deleting the entry voids the receipt.
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($id: ID!) { deleteTournamentEntry(entryId: $id) }"#,
+        Some(Variables::from_json(json!({ "id": entry["id"] }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "delete: {:?}", resp.errors);
+
+    let resp = execute_graphql(&schema, VERIFY_RECEIPT, vars, Some(manager)).await;
+    let verified = resp.data.into_json().unwrap()["verifyEntryReceipt"].clone();
+    assert_eq!(verified["voided"], true);
+    assert!(verified["printUrl"].is_null());
+
+    let (id, query) = parse_url(&print_url);
+    let err = print(State(app_state.clone()), Path(id), Query(query))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, api::error::AppError::BadRequest(_)));
+}
+
+const STAT_CARD: &str = r#"
+    mutation($input: GeneratePlayerStatCardInput!) {
+        generatePlayerStatCard(input: $input) {
+            id month tournaments itmPercentage bestFinish sizeBytes imageUrl
+        }
+    }
+"#;
+
+/// A month of a player's results renders as a PNG behind a signed link;
+/// tournaments outside the month don't count.
+#[tokio::test]
+async fn test_player_stat_card() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let unique = Uuid::new_v4().simple().to_string();
+
+    let (manager_id, manager) = create_test_user(
+        &app_state,
+        &format!("card_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Card Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let club_player_id = Fixtures::random()
+        .club_player(club_id)
+        .display_name("Zoë Card")
+        .create(&app_state.db)
+        .await
+        .unwrap();
+
+    // Last month: three tournaments, two cashes, best finish 2nd.
+    let today = Utc::now().date_naive();
+    let this_month = NaiveDate::from_ymd_opt(today.year(), today.month(), 1).unwrap();
+    let last_month = (this_month - Duration::days(1)).with_day(1).unwrap();
+    let in_last_month = last_month.and_hms_opt(20, 0, 0).unwrap().and_utc() + Duration::days(9);
+    for (i, result) in [Some((2, 30000_i64)), Some((4, 8000)), Some((15, 0)), None]
+        .into_iter()
+        .enumerate()
+    {
+        let starts_at = if result.is_some() {
+            in_last_month + Duration::days(i as i64)
+        } else {
+            in_last_month - Duration::days(40)
+        };
+        let tournament_id = Fixtures::random()
+            .tournament(club_id)
+            .starts_at(starts_at)
+            .create(&app_state.db)
+            .await
+            .unwrap();
+        Fixtures::random()
+            .registration(tournament_id)
+            .club_player(club_player_id)
+            .status("busted")
+            .create(&app_state.db)
+            .await
+            .unwrap();
+        let (position, prize) = result.unwrap_or((1, 50000));
+        sqlx::query(
+            "INSERT INTO tournament_results (tournament_id, club_player_id, final_position, prize_cents) \
+             VALUES ($1, $2, $3, $4)",
+        )
+        .bind(tournament_id)
+        .bind(club_player_id)
+        .bind(position)
+        .bind(prize)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    }
+
+    let vars = |year: i32, month: u32| {
+        Some(Variables::from_json(json!({
+            "input": {
+                "clubPlayerId": club_player_id.to_string(),
+                "year": year,
+                "month": month,
+            }
+        })))
+    };
+    let resp = execute_graphql(
+        &schema,
+        STAT_CARD,
+        vars(last_month.year(), last_month.month()),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "card: {:?}", resp.errors);
+    let card = resp.data.into_json().unwrap()["generatePlayerStatCard"].clone();
+    assert_eq!(card["month"], last_month.to_string());
+    assert_eq!(card["tournaments"], 3);
+    assert_eq!(card["itmPercentage"], 67);
+    assert_eq!(card["bestFinish"], 2);
+
+    let (id, query) = parse_url(card["imageUrl"].as_str().unwrap());
+    let response = image(State(app_state.clone()), Path(id), Query(query))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "image/png");
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(&body[..8], b"\x89PNG\r\n\x1a\n");
+    assert_eq!(card["sizeBytes"], body.len());
+
+    // Regenerating replaces the card.
+    let resp = execute_graphql(
+        &schema,
+        STAT_CARD,
+        vars(last_month.year(), last_month.month()),
+        Some(manager.clone()),
+    )
+    .await;
+    let again = resp.data.into_json().unwrap()["generatePlayerStatCard"].clone();
+    assert_eq!(again["id"], card["id"]);
+
+    // No cards for months to come or for players of other clubs' managers.
+    let next_month = this_month + Months::new(1);
+    let resp = execute_graphql(
+        &schema,
+        STAT_CARD,
+        vars(next_month.year(), next_month.month()),
+        Some(manager),
+    )
+    .await;
+    assert!(!resp.errors.is_empty());
+
+    let (other_id, other) = create_test_user(
+        &app_state,
+        &format!("card_other_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let other_club = create_test_club(&app_state, "Other Card Club").await;
+    create_club_manager(&app_state, other_id, other_club).await;
+    let resp = execute_graphql(
+        &schema,
+        STAT_CARD,
+        vars(last_month.year(), last_month.month()),
+        Some(other),
+    )
+    .await;
+    assert!(!resp.errors.is_empty());
+
+    // A tampered link is refused.
+    let (id, mut query) = parse_url(card["imageUrl"].as_str().unwrap());
+    query.expires += 1;
+    let err = image(State(app_state.clone()), Path(id), Query(query))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, api::error::AppError::Unauthorized(_)));
+}
@@ -0,0 +1,229 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::{Request, Variables};
+use futures_util::StreamExt;
+use serde_json::json;
+use std::time::Duration;
+
+const RECORD: &str = r#"
+    mutation($input: RecordQualifyingHandInput!) {
+        recordQualifyingHand(input: $input) { id playerName hand amount status }
+    }
+"#;
+
+const PAY: &str = r#"
+    mutation($id: ID!) { payQualifyingHand(handId: $id) { status paidAt } }
+"#;
+
+/// The floor records qualifying hands, managers pay them out of the jackpot,
+/// the board follows along live and the movements reach the accounting export.
+#[tokio::test]
+async fn test_promotion_jackpot_lifecycle() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) = create_test_user(&app_state, "promo_mgr@test.com", "manager").await;
+    let (floor_user, floor) = create_test_user(&app_state, "promo_floor@test.com", "player").await;
+    let (_, player) = create_test_user(&app_state, "promo_player@test.com", "player").await;
+    let club_id = create_test_club(&app_state, "Promo Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($input: CreateClubStaffInput!) { createClubStaff(input: $input) { id } }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "clubId": club_id.to_string(),
+                "displayName": "Floor",
+                "userId": floor_user.to_string(),
+                "role": "FLOOR"
+            }
+        }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "{:?}", resp.errors);
+
+    // A bad-beat jackpot seeded with 500.00, then 20.00 of drop.
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($input: CreatePromotionJackpotInput!) {
+            createPromotionJackpot(input: $input) { id kind balance }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "clubId": club_id.to_string(),
+                "kind": "BAD_BEAT",
+                "name": "Bad Beat",
+                "rules": "Quad eights or better beaten",
+                "seed": 50_000,
+            }
+        }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "create: {:?}", resp.errors);
+    let jackpot = resp.data.into_json().unwrap()["createPromotionJackpot"].clone();
+    assert_eq!(jackpot["balance"], 50_000);
+    let jackpot_id = jackpot["id"].as_str().unwrap().to_string();
+
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($id: ID!) { addJackpotContribution(jackpotId: $id, amount: 2000) { balance } }"#,
+        Some(Variables::from_json(json!({ "id": jackpot_id }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "contribute: {:?}", resp.errors);
+    assert_eq!(
+        resp.data.into_json().unwrap()["addJackpotContribution"]["balance"],
+        52_000
+    );
+
+    // The TV display follows the board.
+    let mut stream = schema.execute_stream(
+        Request::new(format!(
+            "subscription {{ promotionBoardUpdates(clubId: \"{club_id}\") {{ eventType hand {{ playerName }} jackpot {{ balance }} }} }}"
+        ))
+        .data(player.clone()),
+    );
+    let next = tokio::spawn(async move { stream.next().await });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let record = |amount: i64| {
+        Some(Variables::from_json(json!({
+            "input": {
+                "jackpotId": jackpot_id,
+                "playerName": "Alice",
+                "hand": "Quad nines",
+                "tableLabel": "Table 3",
+                "amount": amount,
+            }
+        })))
+    };
+    let resp = execute_graphql(&schema, RECORD, record(30_000), Some(player.clone())).await;
+    assert!(!resp.errors.is_empty(), "players can't record hands");
+
+    let resp = execute_graphql(&schema, RECORD, record(30_000), Some(floor.clone())).await;
+    assert!(resp.errors.is_empty(), "record: {:?}", resp.errors);
+    let hand = resp.data.into_json().unwrap()["recordQualifyingHand"].clone();
+    assert_eq!(hand["status"], "PENDING");
+    let hand_id = hand["id"].as_str().unwrap().to_string();
+
+    let event = tokio::time::timeout(Duration::from_secs(5), next)
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    let event = event.data.into_json().unwrap()["promotionBoardUpdates"].clone();
+    assert_eq!(event["eventType"], "HAND_RECORDED");
+    assert_eq!(event["hand"]["playerName"], "Alice");
+
+    // Floor staff can't pay; managers can, once.
+    let resp = execute_graphql(
+        &schema,
+        PAY,
+        Some(Variables::from_json(json!({ "id": hand_id }))),
+        Some(floor.clone()),
+    )
+    .await;
+    assert!(!resp.errors.is_empty(), "floor staff can't pay out");
+    let resp = execute_graphql(
+        &schema,
+        PAY,
+        Some(Variables::from_json(json!({ "id": hand_id }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "pay: {:?}", resp.errors);
+    assert_eq!(
+        resp.data.into_json().unwrap()["payQualifyingHand"]["status"],
+        "PAID"
+    );
+    let resp = execute_graphql(
+        &schema,
+        PAY,
+        Some(Variables::from_json(json!({ "id": hand_id }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(!resp.errors.is_empty(), "a hand is paid once");
+
+    // 220.00 left: a 300.00 hand can't be paid.
+    let resp = execute_graphql(&schema, RECORD, record(30_000), Some(floor)).await;
+    let big_hand = resp.data.into_json().unwrap()["recordQualifyingHand"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let resp = execute_graphql(
+        &schema,
+        PAY,
+        Some(Variables::from_json(json!({ "id": big_hand }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(
+        resp.errors[0]
+            .message
+            .contains("exceeds the jackpot balance"),
+        "{:?}",
+        resp.errors
+    );
+
+    let board_query = format!(
+        "query {{ promotionBoard(clubId: \"{club_id}\") {{ name balance recentHands {{ status }} }} }}"
+    );
+    let resp = execute_graphql(&schema, &board_query, None, Some(player.clone())).await;
+    assert!(resp.errors.is_empty(), "board: {:?}", resp.errors);
+    let board = resp.data.into_json().unwrap()["promotionBoard"].clone();
+    assert_eq!(board[0]["balance"], 22_000);
+    assert_eq!(board[0]["recentHands"].as_array().unwrap().len(), 2);
+
+    // The ledger is for managers only and can't be rewritten.
+    let ledger_query = format!(
+        "query {{ promotionBoard(clubId: \"{club_id}\") {{ entries {{ kind amount }} }} }}"
+    );
+    let resp = execute_graphql(&schema, &ledger_query, None, Some(player)).await;
+    assert!(!resp.errors.is_empty(), "players can't read the ledger");
+    let resp = execute_graphql(&schema, &ledger_query, None, Some(manager.clone())).await;
+    assert!(resp.errors.is_empty(), "ledger: {:?}", resp.errors);
+    let amounts: Vec<i64> = resp.data.into_json().unwrap()["promotionBoard"][0]["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["amount"].as_i64().unwrap())
+        .collect();
+    assert_eq!(amounts, vec![-30_000, 2_000, 50_000]);
+    let update = sqlx::query(
+        "UPDATE promotion_jackpot_entries SET amount_cents = 0 \
+         WHERE jackpot_id = $1::uuid",
+    )
+    .bind(&jackpot_id)
+    .execute(&app_state.db)
+    .await;
+    assert!(update.is_err());
+
+    // The jackpot movements are booked in the accounting export.
+    let now = chrono::Utc::now();
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($input: CreateAccountingExportInput!) {
+            createAccountingExport(input: $input) { lineCount totalCents content }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "clubId": club_id.to_string(),
+                "from": (now - chrono::Duration::hours(1)).to_rfc3339(),
+                "to": (now + chrono::Duration::hours(1)).to_rfc3339(),
+            }
+        }))),
+        Some(manager),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "export: {:?}", resp.errors);
+    let export = resp.data.into_json().unwrap()["createAccountingExport"].clone();
+    assert_eq!(export["lineCount"], 3);
+    assert_eq!(export["totalCents"], 50_000 + 2_000 + 30_000);
+    let content = export["content"].as_str().unwrap();
+    assert!(content.contains("Bad Beat: jackpot paid"), "{content}");
+}
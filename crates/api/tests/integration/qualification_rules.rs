@@ -0,0 +1,177 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_qualifiers_are_registered_in_the_final() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("qualmanager_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let (ann, _) =
+        create_test_user(&app_state, &format!("qualann_{suffix}@test.com"), "player").await;
+    let (ben, ben_claims) =
+        create_test_user(&app_state, &format!("qualben_{suffix}@test.com"), "player").await;
+    let club_id = create_test_club(&app_state, "Qualification Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let first = create_test_tournament(&app_state, club_id, "Weekly 1").await;
+    let second = create_test_tournament(&app_state, club_id, "Weekly 2").await;
+
+    let response = execute_graphql(
+        &schema,
+        r#"
+            mutation($input: CreateTournamentSeriesInput!) {
+                createTournamentSeries(input: $input) { id }
+            }
+        "#,
+        Some(Variables::from_json(json!({
+            "input": { "clubId": club_id.to_string(), "title": "Winter Weeklies" }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let series_id = response.data.into_json().unwrap()["createTournamentSeries"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    for tournament_id in [first, second] {
+        let response = execute_graphql(
+            &schema,
+            r#"
+                mutation($seriesId: ID!, $tournamentId: ID!) {
+                    addTournamentToSeries(seriesId: $seriesId, tournamentId: $tournamentId) { id }
+                }
+            "#,
+            Some(Variables::from_json(json!({
+                "seriesId": series_id,
+                "tournamentId": tournament_id.to_string()
+            }))),
+            Some(manager_claims.clone()),
+        )
+        .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+    }
+
+    let response = execute_graphql(
+        &schema,
+        r#"
+            mutation($input: CreateQualificationRuleInput!) {
+                createQualificationRule(input: $input) { id }
+            }
+        "#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "clubId": club_id.to_string(),
+                "seriesId": series_id,
+                "name": "Freeroll final",
+                "minEvents": 2
+            }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let rule_id = response.data.into_json().unwrap()["createQualificationRule"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // Ann plays both weeklies, Ben the first; registering alone doesn't count.
+    create_test_registration(&app_state, first, ann, "busted").await;
+    create_test_registration(&app_state, first, ben, "busted").await;
+    create_test_registration(&app_state, second, ann, "busted").await;
+    create_test_registration(&app_state, second, ben, "registered").await;
+
+    let response = execute_graphql(
+        &schema,
+        r#"
+            query {
+                myQualificationProgress {
+                    eventsPlayed eventsNeeded qualified rule { name }
+                }
+            }
+        "#,
+        None,
+        Some(ben_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap()["myQualificationProgress"],
+        json!([{
+            "eventsPlayed": 1,
+            "eventsNeeded": 1,
+            "qualified": false,
+            "rule": { "name": "Freeroll final" }
+        }])
+    );
+
+    let response = execute_graphql(
+        &schema,
+        r#"
+            mutation($input: CreateTournamentInput!) {
+                createTournament(input: $input) { id }
+            }
+        "#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "clubId": club_id.to_string(),
+                "name": "Freeroll Final",
+                "startTime": "2027-03-01T18:00:00Z",
+                "buyInCents": 0,
+                "qualificationRuleId": rule_id
+            }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let final_id = response.data.into_json().unwrap()["createTournament"]["id"]
+        .as_str()
+        .map(|id| Uuid::parse_str(id).unwrap())
+        .unwrap();
+
+    let registered = |db: sqlx::PgPool| async move {
+        sqlx::query_scalar::<_, Uuid>(
+            "SELECT user_id FROM tournament_registrations \
+             WHERE tournament_id = $1 ORDER BY registration_time",
+        )
+        .bind(final_id)
+        .fetch_all(&db)
+        .await
+        .unwrap()
+    };
+    assert_eq!(registered(app_state.db.clone()).await, vec![ann]);
+
+    // Ben's result in the second weekly qualifies him.
+    let response = execute_graphql(
+        &schema,
+        r#"
+            mutation($input: EnterTournamentResultsInput!) {
+                enterTournamentResults(input: $input) { success }
+            }
+        "#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": second.to_string(),
+                "playerPositions": [
+                    { "userId": ann.to_string(), "finalPosition": 1 },
+                    { "userId": ben.to_string(), "finalPosition": 2 }
+                ]
+            }
+        }))),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(registered(app_state.db.clone()).await, vec![ann, ben]);
+}
@@ -0,0 +1,197 @@
+use crate::common::*;
+use api::gql::build_schema;
+use api::gql::domains::raffles::draw;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+const CREATE: &str = r#"
+    mutation($input: CreateRaffleInput!) {
+        createRaffle(input: $input) { id status winnerCount eligibility }
+    }
+"#;
+
+const DRAW: &str = r#"
+    mutation($id: ID!) {
+        drawRaffle(raffleId: $id) {
+            status
+            algorithm
+            seed
+            entrantsHash
+            drawnAt
+            entrants { registrationId position displayName pick }
+            winners { registrationId position pick }
+        }
+    }
+"#;
+
+const CANCEL: &str = r#"mutation($id: ID!) { cancelRaffle(raffleId: $id) { status } }"#;
+
+fn id_vars(id: &str) -> Option<Variables> {
+    Some(Variables::from_json(json!({ "id": id })))
+}
+
+/// A draw snapshots only the eligible players, records a replayable audit
+/// trail and can only happen once.
+#[tokio::test]
+async fn test_raffle_draw_is_auditable() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) =
+        create_test_user(&app_state, "raffle_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Raffle Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Raffle Night").await;
+
+    let mut player_claims = Vec::new();
+    for (i, status) in ["checked_in", "seated", "busted", "registered", "waitlisted"]
+        .iter()
+        .enumerate()
+    {
+        let (user_id, claims) =
+            create_test_user(&app_state, &format!("raffle_p{i}@test.com"), "player").await;
+        create_test_registration(&app_state, tournament_id, user_id, status).await;
+        player_claims.push(claims);
+    }
+
+    let create = |eligibility: &str, winners: i32| {
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "name": "Dinner break raffle",
+                "prize": "Bottle of champagne",
+                "eligibility": eligibility,
+                "winnerCount": winners,
+            }
+        })))
+    };
+
+    // Players can't run raffles.
+    let resp = execute_graphql(
+        &schema,
+        CREATE,
+        create("ACTIVE", 1),
+        Some(player_claims[0].clone()),
+    )
+    .await;
+    assert!(!resp.errors.is_empty(), "players can't create raffles");
+
+    let resp = execute_graphql(&schema, CREATE, create("ACTIVE", 5), Some(manager.clone())).await;
+    assert!(resp.errors.is_empty(), "create: {:?}", resp.errors);
+    let raffle = resp.data.into_json().unwrap()["createRaffle"].clone();
+    assert_eq!(raffle["status"], "OPEN");
+    let raffle_id = raffle["id"].as_str().unwrap().to_string();
+
+    let resp = execute_graphql(
+        &schema,
+        DRAW,
+        id_vars(&raffle_id),
+        Some(player_claims[0].clone()),
+    )
+    .await;
+    assert!(!resp.errors.is_empty(), "players can't draw");
+
+    // Only checked-in and seated players are still in: both win, even though
+    // five winners were asked for.
+    let resp = execute_graphql(&schema, DRAW, id_vars(&raffle_id), Some(manager.clone())).await;
+    assert!(resp.errors.is_empty(), "draw: {:?}", resp.errors);
+    let drawn = resp.data.into_json().unwrap()["drawRaffle"].clone();
+    assert_eq!(drawn["status"], "DRAWN");
+    assert_eq!(drawn["algorithm"], draw::ALGORITHM);
+    assert!(drawn["drawnAt"].is_string());
+    let entrants = drawn["entrants"].as_array().unwrap();
+    assert_eq!(entrants.len(), 2);
+    assert_eq!(drawn["winners"].as_array().unwrap().len(), 2);
+
+    // The audit trail replays: hash the entrant list, redraw from the seed.
+    let registration_ids: Vec<Uuid> = entrants
+        .iter()
+        .map(|e| Uuid::parse_str(e["registrationId"].as_str().unwrap()).unwrap())
+        .collect();
+    assert_eq!(
+        drawn["entrantsHash"].as_str().unwrap(),
+        draw::entrants_hash(&registration_ids)
+    );
+    let replayed = draw::draw(drawn["seed"].as_str().unwrap(), entrants.len(), 5);
+    let positions: Vec<usize> = drawn["winners"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|w| w["position"].as_u64().unwrap() as usize)
+        .collect();
+    assert_eq!(positions, replayed);
+
+    // A raffle is drawn once and can't be cancelled afterwards.
+    let resp = execute_graphql(&schema, DRAW, id_vars(&raffle_id), Some(manager.clone())).await;
+    assert!(!resp.errors.is_empty(), "no redraws");
+    let resp = execute_graphql(&schema, CANCEL, id_vars(&raffle_id), Some(manager.clone())).await;
+    assert!(!resp.errors.is_empty(), "drawn raffles can't be cancelled");
+
+    // Everyone who showed up, busted players included, gets a ticket.
+    let resp = execute_graphql(
+        &schema,
+        CREATE,
+        create("CHECKED_IN", 1),
+        Some(manager.clone()),
+    )
+    .await;
+    let second_id = resp.data.into_json().unwrap()["createRaffle"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let resp = execute_graphql(&schema, DRAW, id_vars(&second_id), Some(manager.clone())).await;
+    assert!(resp.errors.is_empty(), "draw: {:?}", resp.errors);
+    let drawn = resp.data.into_json().unwrap()["drawRaffle"].clone();
+    assert_eq!(drawn["entrants"].as_array().unwrap().len(), 3);
+    assert_eq!(drawn["winners"].as_array().unwrap().len(), 1);
+
+    // Open raffles can be cancelled.
+    let resp = execute_graphql(
+        &schema,
+        CREATE,
+        create("REGISTERED", 1),
+        Some(manager.clone()),
+    )
+    .await;
+    let third_id = resp.data.into_json().unwrap()["createRaffle"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let resp = execute_graphql(&schema, CANCEL, id_vars(&third_id), Some(manager.clone())).await;
+    assert!(resp.errors.is_empty(), "cancel: {:?}", resp.errors);
+    assert_eq!(
+        resp.data.into_json().unwrap()["cancelRaffle"]["status"],
+        "CANCELLED"
+    );
+
+    // Players see the tournament's raffles.
+    let query = format!(
+        "query {{ tournamentRaffles(tournamentId: \"{tournament_id}\") {{ status winners {{ pick }} }} }}"
+    );
+    let resp = execute_graphql(&schema, &query, None, Some(player_claims[0].clone())).await;
+    assert!(resp.errors.is_empty(), "list: {:?}", resp.errors);
+    let statuses: Vec<String> = resp.data.into_json().unwrap()["tournamentRaffles"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["status"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(statuses, vec!["DRAWN", "DRAWN", "CANCELLED"]);
+
+    // The draws land in the activity log.
+    let db = &app_state.db;
+    let logged: i64 = eventually(|| async move {
+        let logged: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM tournament_activity_log \
+             WHERE tournament_id = $1 AND event_action = 'raffle_drawn'",
+        )
+        .bind(tournament_id)
+        .fetch_one(db)
+        .await
+        .unwrap();
+        (logged >= 2).then_some(logged)
+    })
+    .await;
+    assert_eq!(logged, 2);
+}
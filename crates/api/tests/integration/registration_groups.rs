@@ -0,0 +1,259 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+const SEAT_DRAW: &str = r#"
+    mutation($input: UpdateTournamentStatusInput!) {
+        updateTournamentStatus(input: $input) { liveStatus }
+    }
+"#;
+
+/// A tournament open for registration with two linked 6-max tables and
+/// `players` checked-in players. Returns the tournament and the players.
+async fn seat_draw_fixture(
+    app_state: &api::AppState,
+    club_id: Uuid,
+    first_table: i32,
+    players: usize,
+    prefix: &str,
+) -> (Uuid, Vec<(Uuid, api::auth::Claims)>) {
+    let tournament_id = create_test_tournament(app_state, club_id, prefix).await;
+    for number in [first_table, first_table + 1] {
+        let table_id = create_test_club_table(app_state, club_id, number, 6).await;
+        assign_table_to_tournament(app_state, tournament_id, table_id).await;
+    }
+    let mut users = Vec::new();
+    for i in 0..players {
+        let user = create_test_user(app_state, &format!("{prefix}_p{i}@test.com"), "player").await;
+        create_test_registration(app_state, tournament_id, user.0, "checked_in").await;
+        users.push(user);
+    }
+    sqlx::query("UPDATE tournaments SET live_status = 'registration_open' WHERE id = $1")
+        .bind(tournament_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    (tournament_id, users)
+}
+
+/// Each seated player's table after the draw.
+async fn tables_by_user(app_state: &api::AppState, tournament_id: Uuid) -> HashMap<Uuid, Uuid> {
+    sqlx::query_as::<_, (Uuid, Uuid)>(
+        "SELECT user_id, club_table_id FROM table_seat_assignments \
+         WHERE tournament_id = $1 AND is_current",
+    )
+    .bind(tournament_id)
+    .fetch_all(&app_state.db)
+    .await
+    .unwrap()
+    .into_iter()
+    .collect()
+}
+
+fn tournament_vars(tournament_id: Uuid, extra: serde_json::Value) -> Option<Variables> {
+    let mut vars = json!({ "tournamentId": tournament_id.to_string() });
+    vars.as_object_mut()
+        .unwrap()
+        .extend(extra.as_object().unwrap().clone());
+    Some(Variables::from_json(vars))
+}
+
+/// Friends link registrations with a code, up to the club's group size. The
+/// seat draw seats a group together, or splits it when the club says so.
+#[tokio::test]
+async fn test_group_bookings_and_seat_draw() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) =
+        create_test_user(&app_state, "groups_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Groups Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+
+    let settings = r#"mutation($input: UpdateSeatingSettingsInput!) {
+        updateClubSeatingSettings(input: $input) { groupPolicy maxGroupSize }
+    }"#;
+    let resp = execute_graphql(
+        &schema,
+        settings,
+        Some(Variables::from_json(json!({
+            "input": { "clubId": club_id.to_string(), "maxGroupSize": 3 }
+        }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "settings: {:?}", resp.errors);
+    let data = resp.data.into_json().unwrap();
+    assert_eq!(data["updateClubSeatingSettings"]["groupPolicy"], "TOGETHER");
+
+    // Eight players need both tables; three of them book together.
+    let (together, players) = seat_draw_fixture(&app_state, club_id, 1, 8, "groups_t").await;
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($tournamentId: ID!) {
+            createRegistrationGroup(tournamentId: $tournamentId) { code seating members { userId } }
+        }"#,
+        tournament_vars(together, json!({})),
+        Some(players[0].1.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "create: {:?}", resp.errors);
+    let group = resp.data.into_json().unwrap()["createRegistrationGroup"].clone();
+    assert_eq!(group["seating"], "TOGETHER");
+    let code = group["code"].as_str().unwrap().to_lowercase();
+
+    let join = r#"mutation($tournamentId: ID!, $code: String!) {
+        joinRegistrationGroup(tournamentId: $tournamentId, code: $code) { members { userId } }
+    }"#;
+    for (_, claims) in &players[1..3] {
+        let resp = execute_graphql(
+            &schema,
+            join,
+            tournament_vars(together, json!({ "code": code })),
+            Some(claims.clone()),
+        )
+        .await;
+        assert!(resp.errors.is_empty(), "join: {:?}", resp.errors);
+    }
+    let resp = execute_graphql(
+        &schema,
+        join,
+        tournament_vars(together, json!({ "code": code })),
+        Some(players[3].1.clone()),
+    )
+    .await;
+    assert_eq!(resp.errors[0].message, "This group is full (3 players)");
+    let resp = execute_graphql(
+        &schema,
+        join,
+        tournament_vars(together, json!({ "code": code })),
+        Some(players[1].1.clone()),
+    )
+    .await;
+    assert_eq!(resp.errors[0].message, "You are already in a group");
+
+    let resp = execute_graphql(
+        &schema,
+        r#"query($tournamentId: ID!) {
+            myRegistrationGroup(tournamentId: $tournamentId) { members { userId } }
+        }"#,
+        tournament_vars(together, json!({})),
+        Some(players[2].1.clone()),
+    )
+    .await;
+    let data = resp.data.into_json().unwrap();
+    assert_eq!(
+        data["myRegistrationGroup"]["members"]
+            .as_array()
+            .unwrap()
+            .len(),
+        3
+    );
+
+    let resp = execute_graphql(
+        &schema,
+        SEAT_DRAW,
+        Some(Variables::from_json(json!({
+            "input": { "tournamentId": together.to_string(), "liveStatus": "LATE_REGISTRATION" }
+        }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "draw: {:?}", resp.errors);
+    let tables = tables_by_user(&app_state, together).await;
+    assert_eq!(tables.len(), 8);
+    assert_eq!(tables[&players[0].0], tables[&players[1].0]);
+    assert_eq!(tables[&players[0].0], tables[&players[2].0]);
+
+    // Fairness-minded club: the same pair is split even though four players
+    // would fit on one table.
+    let resp = execute_graphql(
+        &schema,
+        settings,
+        Some(Variables::from_json(json!({
+            "input": { "clubId": club_id.to_string(), "groupPolicy": "APART" }
+        }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "settings: {:?}", resp.errors);
+    let (apart, players) = seat_draw_fixture(&app_state, club_id, 3, 4, "groups_a").await;
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($tournamentId: ID!) {
+            createRegistrationGroup(tournamentId: $tournamentId) { code seating }
+        }"#,
+        tournament_vars(apart, json!({})),
+        Some(players[0].1.clone()),
+    )
+    .await;
+    let group = resp.data.into_json().unwrap()["createRegistrationGroup"].clone();
+    assert_eq!(group["seating"], "APART");
+    let resp = execute_graphql(
+        &schema,
+        join,
+        tournament_vars(apart, json!({ "code": group["code"] })),
+        Some(players[1].1.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "join: {:?}", resp.errors);
+
+    let resp = execute_graphql(
+        &schema,
+        SEAT_DRAW,
+        Some(Variables::from_json(json!({
+            "input": { "tournamentId": apart.to_string(), "liveStatus": "LATE_REGISTRATION" }
+        }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "draw: {:?}", resp.errors);
+    let tables = tables_by_user(&app_state, apart).await;
+    assert_eq!(tables.len(), 4);
+    assert_ne!(tables[&players[0].0], tables[&players[1].0]);
+
+    // Managers see the bookings; leaving the last spot closes the group.
+    let resp = execute_graphql(
+        &schema,
+        r#"query($tournamentId: ID!) {
+            tournamentRegistrationGroups(tournamentId: $tournamentId) { id }
+        }"#,
+        tournament_vars(apart, json!({})),
+        Some(manager.clone()),
+    )
+    .await;
+    let data = resp.data.into_json().unwrap();
+    assert_eq!(
+        data["tournamentRegistrationGroups"]
+            .as_array()
+            .unwrap()
+            .len(),
+        1
+    );
+    let leave = r#"mutation($tournamentId: ID!) {
+        leaveRegistrationGroup(tournamentId: $tournamentId)
+    }"#;
+    for (_, claims) in &players[..2] {
+        let resp = execute_graphql(
+            &schema,
+            leave,
+            tournament_vars(apart, json!({})),
+            Some(claims.clone()),
+        )
+        .await;
+        assert_eq!(
+            resp.data.into_json().unwrap()["leaveRegistrationGroup"],
+            true
+        );
+    }
+    let count: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM registration_groups WHERE tournament_id = $1")
+            .bind(apart)
+            .fetch_one(&app_state.db)
+            .await
+            .unwrap();
+    assert_eq!(count, 0);
+}
@@ -0,0 +1,264 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+
+const CREATE_QUESTION: &str = r#"
+    mutation($input: CreateRegistrationQuestionInput!) {
+        createRegistrationQuestion(input: $input) { id kind options maxLength isRequired position }
+    }
+"#;
+
+const REGISTER: &str = r#"
+    mutation($input: RegisterForTournamentInput!) {
+        registerForTournament(input: $input) { id }
+    }
+"#;
+
+const ANSWER_FIELDS: &str = r#"
+    __typename questionId
+    ... on TextAnswer { text }
+    ... on SelectAnswer { selected }
+    ... on CheckboxAnswer { checked }
+"#;
+
+/// Organizers build a registration form, players fill it in (required fields
+/// included) and the typed responses and report come back to the organizer.
+#[tokio::test]
+async fn test_registration_form_and_report() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) =
+        create_test_user(&app_state, "questions_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Questions Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_open_tournament(&app_state, club_id, "Dinner Deepstack").await;
+
+    let mut field_ids = Vec::new();
+    for input in [
+        json!({ "kind": "SELECT", "prompt": "Dinner", "options": ["Fish", " Vegetarian "], "isRequired": true }),
+        json!({ "kind": "TEXT", "prompt": "Accessibility needs", "helpText": "Ramps, seating, ...", "maxLength": 100 }),
+        json!({ "kind": "CHECKBOX", "prompt": "Photo consent", "isRequired": true }),
+    ] {
+        let mut input = input;
+        input["tournamentId"] = json!(tournament_id.to_string());
+        let resp = execute_graphql(
+            &schema,
+            CREATE_QUESTION,
+            Some(Variables::from_json(json!({ "input": input }))),
+            Some(manager.clone()),
+        )
+        .await;
+        assert!(resp.errors.is_empty(), "create: {:?}", resp.errors);
+        let data = resp.data.into_json().unwrap();
+        field_ids.push(
+            data["createRegistrationQuestion"]["id"]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        );
+    }
+    let [dinner, needs, consent] = [&field_ids[0], &field_ids[1], &field_ids[2]];
+
+    // Select fields need real options.
+    let resp = execute_graphql(
+        &schema,
+        CREATE_QUESTION,
+        Some(Variables::from_json(json!({ "input": {
+            "tournamentId": tournament_id.to_string(), "kind": "SELECT",
+            "prompt": "Drink", "options": ["Beer"]
+        }}))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert_eq!(
+        resp.errors[0].message,
+        "A select field needs 2 to 20 options"
+    );
+
+    let resp = execute_graphql(
+        &schema,
+        r#"query($id: ID!) {
+            tournament(id: $id) { registrationQuestions { kind options maxLength helpText position } }
+        }"#,
+        Some(Variables::from_json(json!({ "id": tournament_id.to_string() }))),
+        None,
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "tournament: {:?}", resp.errors);
+    let data = resp.data.into_json().unwrap();
+    let fields = &data["tournament"]["registrationQuestions"];
+    assert_eq!(fields[0]["options"], json!(["Fish", "Vegetarian"]));
+    assert_eq!(fields[1]["maxLength"], 100);
+    assert_eq!(fields[1]["helpText"], "Ramps, seating, ...");
+    assert_eq!(fields[2]["kind"], "CHECKBOX");
+    assert_eq!(fields[2]["position"], 2);
+
+    let register = |claims: api::auth::Claims, answers: serde_json::Value| {
+        let schema = schema.clone();
+        async move {
+            execute_graphql(
+                &schema,
+                REGISTER,
+                Some(Variables::from_json(json!({ "input": {
+                    "tournamentId": tournament_id.to_string(), "answers": answers
+                }}))),
+                Some(claims),
+            )
+            .await
+        }
+    };
+
+    let (_, ann) = create_test_user(&app_state, "questions_ann@test.com", "player").await;
+    let resp = register(
+        ann.clone(),
+        json!([{ "questionId": consent, "checked": false }]),
+    )
+    .await;
+    assert_eq!(
+        resp.errors[0].message,
+        "Please answer the required questions: Dinner, Photo consent"
+    );
+    let resp = register(
+        ann.clone(),
+        json!([
+            { "questionId": dinner, "selected": ["Steak"] },
+            { "questionId": consent, "checked": true }
+        ]),
+    )
+    .await;
+    assert_eq!(resp.errors[0].message, "Invalid answer to: Dinner");
+    let resp = register(
+        ann.clone(),
+        json!([
+            { "questionId": dinner, "selected": ["Fish"] },
+            { "questionId": needs, "text": "Step-free access please" },
+            { "questionId": consent, "checked": true }
+        ]),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "register: {:?}", resp.errors);
+
+    let (_, bob) = create_test_user(&app_state, "questions_bob@test.com", "player").await;
+    let resp = register(
+        bob.clone(),
+        json!([
+            { "questionId": dinner, "selected": ["Fish"] },
+            { "questionId": consent, "checked": true }
+        ]),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "register: {:?}", resp.errors);
+
+    // Bob changes his mind; a required answer can't be cleared.
+    let update = format!(
+        r#"mutation($tournamentId: ID!, $answers: [RegistrationAnswerInput!]!) {{
+            updateRegistrationAnswers(tournamentId: $tournamentId, answers: $answers) {{ {ANSWER_FIELDS} }}
+        }}"#
+    );
+    let resp = execute_graphql(
+        &schema,
+        &update,
+        Some(Variables::from_json(json!({
+            "tournamentId": tournament_id.to_string(),
+            "answers": [{ "questionId": dinner, "selected": ["Vegetarian"] }]
+        }))),
+        Some(bob.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "update: {:?}", resp.errors);
+    let data = resp.data.into_json().unwrap();
+    assert_eq!(
+        data["updateRegistrationAnswers"],
+        json!([
+            { "__typename": "SelectAnswer", "questionId": dinner, "selected": ["Vegetarian"] },
+            { "__typename": "CheckboxAnswer", "questionId": consent, "checked": true }
+        ])
+    );
+    let resp = execute_graphql(
+        &schema,
+        &update,
+        Some(Variables::from_json(json!({
+            "tournamentId": tournament_id.to_string(),
+            "answers": [{ "questionId": dinner, "selected": [] }]
+        }))),
+        Some(bob.clone()),
+    )
+    .await;
+    assert_eq!(
+        resp.errors[0].message,
+        "Please answer the required questions: Dinner"
+    );
+
+    let vars = Some(Variables::from_json(
+        json!({ "tournamentId": tournament_id.to_string() }),
+    ));
+    let responses = format!(
+        r#"query($tournamentId: ID!) {{
+            registrationFormResponses(tournamentId: $tournamentId) {{
+                playerName answers {{ {ANSWER_FIELDS} }}
+            }}
+        }}"#
+    );
+    let resp = execute_graphql(&schema, &responses, vars.clone(), Some(bob.clone())).await;
+    assert!(!resp.errors.is_empty(), "players can't read the responses");
+    let resp = execute_graphql(&schema, &responses, vars.clone(), Some(manager.clone())).await;
+    assert!(resp.errors.is_empty(), "responses: {:?}", resp.errors);
+    let data = resp.data.into_json().unwrap();
+    let responses = data["registrationFormResponses"].as_array().unwrap();
+    assert_eq!(responses.len(), 2);
+    let ann_answers = responses
+        .iter()
+        .find(|r| r["answers"].as_array().unwrap().len() == 3)
+        .unwrap();
+    assert_eq!(
+        ann_answers["answers"][1],
+        json!({ "__typename": "TextAnswer", "questionId": needs, "text": "Step-free access please" })
+    );
+
+    let report = r#"query($tournamentId: ID!) {
+        registrationAnswersReport(tournamentId: $tournamentId) {
+            registrations
+            questions {
+                question { prompt }
+                answered
+                choices { value count }
+                responses { text }
+            }
+        }
+    }"#;
+    let resp = execute_graphql(&schema, report, vars, Some(manager.clone())).await;
+    assert!(resp.errors.is_empty(), "report: {:?}", resp.errors);
+    let data = resp.data.into_json().unwrap();
+    let report = &data["registrationAnswersReport"];
+    assert_eq!(report["registrations"], 2);
+    assert_eq!(report["questions"][0]["answered"], 2);
+    assert_eq!(
+        report["questions"][0]["choices"],
+        json!([{ "value": "Fish", "count": 1 }, { "value": "Vegetarian", "count": 1 }])
+    );
+    assert_eq!(
+        report["questions"][1]["responses"],
+        json!([{ "text": "Step-free access please" }])
+    );
+    assert_eq!(
+        report["questions"][2]["choices"],
+        json!([{ "value": "checked", "count": 2 }, { "value": "unchecked", "count": 0 }])
+    );
+
+    // Archived fields drop off the registration form.
+    for id in [dinner, consent] {
+        let resp = execute_graphql(
+            &schema,
+            r#"mutation($id: ID!) { archiveRegistrationQuestion(id: $id) { isActive } }"#,
+            Some(Variables::from_json(json!({ "id": id }))),
+            Some(manager.clone()),
+        )
+        .await;
+        assert!(resp.errors.is_empty(), "archive: {:?}", resp.errors);
+    }
+    let (_, cy) = create_test_user(&app_state, "questions_cy@test.com", "player").await;
+    let resp = register(cy, json!([])).await;
+    assert!(resp.errors.is_empty(), "register: {:?}", resp.errors);
+}
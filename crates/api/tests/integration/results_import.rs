@@ -0,0 +1,243 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+const PREVIEW: &str = r#"
+    query($input: PreviewResultsImportInput!) {
+        previewResultsImport(input: $input) {
+            totalPrizeCents
+            newPlayers
+            rows { rowNumber finalPosition displayName playerMatch clubPlayerId }
+        }
+    }
+"#;
+
+const IMPORT: &str = r#"
+    mutation($input: ImportTournamentResultsInput!) {
+        importTournamentResults(input: $input) {
+            newPlayers
+            tournament { id title liveStatus }
+            results { finalPosition prizeCents points userId clubPlayerId }
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn test_import_results_from_csv_export() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("import_mgr_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let alice_email = format!("import_alice_{suffix}@test.com");
+    let (alice_id, alice_claims) = create_test_user(&app_state, &alice_email, "player").await;
+    let club_id = create_test_club(&app_state, "Import Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+
+    let bob_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO club_player (club_id, display_name) VALUES ($1, 'Bob Roster') RETURNING id",
+    )
+    .bind(club_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+
+    let content = format!(
+        "Place;Player;Email;Winnings;Rebuys\n\
+         1;Alice A.;{alice_email};€ 200,00;0\n\
+         2;bob roster;;100;1\n\
+         3;Charlie Newcomer;;;2\n"
+    );
+
+    let preview_vars = Variables::from_json(json!({
+        "input": { "clubId": club_id.to_string(), "format": "CSV", "content": content }
+    }));
+    let response = execute_graphql(
+        &schema,
+        PREVIEW,
+        Some(preview_vars.clone()),
+        Some(alice_claims.clone()),
+    )
+    .await;
+    assert!(!response.errors.is_empty(), "only managers can preview");
+
+    let response = execute_graphql(
+        &schema,
+        PREVIEW,
+        Some(preview_vars),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let preview = &response.data.into_json().unwrap()["previewResultsImport"];
+    assert_eq!(preview["totalPrizeCents"], 30_000);
+    assert_eq!(preview["newPlayers"], 1);
+    let matches: Vec<&str> = preview["rows"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["playerMatch"].as_str().unwrap())
+        .collect();
+    assert_eq!(matches, ["APP_USER", "ROSTER_NAME", "NEW_PLAYER"]);
+    assert_eq!(preview["rows"][1]["clubPlayerId"], bob_id.to_string());
+
+    let import_vars = Variables::from_json(json!({
+        "input": {
+            "clubId": club_id.to_string(),
+            "format": "CSV",
+            "content": content,
+            "name": "Imported Friday Deepstack",
+            "startTime": "2025-03-07T19:00:00Z",
+            "buyInCents": 5000,
+        }
+    }));
+    let response = execute_graphql(
+        &schema,
+        IMPORT,
+        Some(import_vars.clone()),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let imported = &response.data.into_json().unwrap()["importTournamentResults"];
+    assert_eq!(imported["newPlayers"], 1);
+    assert_eq!(imported["tournament"]["liveStatus"], "FINISHED");
+    let tournament_id = Uuid::parse_str(imported["tournament"]["id"].as_str().unwrap()).unwrap();
+
+    let results = imported["results"].as_array().unwrap();
+    assert_eq!(results.len(), 3);
+    let winner = results.iter().find(|r| r["finalPosition"] == 1).unwrap();
+    assert_eq!(winner["userId"], alice_id.to_string());
+    assert_eq!(winner["prizeCents"], 20_000);
+    assert!(winner["points"].as_i64().unwrap() > 0, "points are scored");
+    let runner_up = results.iter().find(|r| r["finalPosition"] == 2).unwrap();
+    assert_eq!(runner_up["clubPlayerId"], bob_id.to_string());
+
+    // Alice got a roster entry linked to her account; Charlie a fresh one.
+    let roster: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM club_player WHERE club_id = $1")
+        .bind(club_id)
+        .fetch_one(&app_state.db)
+        .await
+        .unwrap();
+    assert_eq!(roster, 3);
+    let linked: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM club_player WHERE club_id = $1 AND app_user_id = $2",
+    )
+    .bind(club_id)
+    .bind(alice_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(linked, 1);
+
+    // One buy-in each plus three rebuys make up the prize pool.
+    let (entries, collected): (i64, i64) = sqlx::query_as(
+        "SELECT COUNT(*), COALESCE(SUM(amount_cents), 0)::BIGINT FROM tournament_entries WHERE tournament_id = $1",
+    )
+    .bind(tournament_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!((entries, collected), (6, 30_000));
+    let (prize_pool, positions): (i64, serde_json::Value) = sqlx::query_as(
+        "SELECT total_prize_pool, payout_positions FROM tournament_payouts WHERE tournament_id = $1",
+    )
+    .bind(tournament_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(prize_pool, 30_000);
+    assert_eq!(positions.as_array().unwrap().len(), 2);
+    assert_eq!(positions[0]["amount_cents"], 20_000);
+
+    // Importing the same file twice is refused.
+    let response = execute_graphql(
+        &schema,
+        IMPORT,
+        Some(import_vars),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(
+        response.errors[0].message.contains("already exists"),
+        "{:?}",
+        response.errors
+    );
+}
+
+#[tokio::test]
+async fn test_import_rejects_inconsistent_files() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("import_mgr2_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Import Club 2").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+
+    let import = |content: &str| {
+        Variables::from_json(json!({
+            "input": {
+                "clubId": club_id.to_string(),
+                "format": "XML",
+                "content": content,
+                "name": "Broken Import",
+                "startTime": "2025-03-14T19:00:00Z",
+                "buyInCents": 2000,
+            }
+        }))
+    };
+
+    let duplicate_place = r#"<Results>
+        <Player place="1" name="Ann"/>
+        <Player place="1" name="Ben"/>
+    </Results>"#;
+    let response = execute_graphql(
+        &schema,
+        IMPORT,
+        Some(import(duplicate_place)),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(
+        response.errors[0].message.contains("position 1"),
+        "{:?}",
+        response.errors
+    );
+
+    let addons_without_price = r#"<Results>
+        <Player place="1" name="Ann" addons="1"/>
+    </Results>"#;
+    let response = execute_graphql(
+        &schema,
+        IMPORT,
+        Some(import(addons_without_price)),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(
+        response.errors[0].message.contains("add-on price"),
+        "{:?}",
+        response.errors
+    );
+
+    // Nothing was written by the failed attempts.
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tournaments WHERE club_id = $1")
+        .bind(club_id)
+        .fetch_one(&app_state.db)
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+}
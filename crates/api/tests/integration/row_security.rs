@@ -0,0 +1,456 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use infra::db::{begin_scoped, with_request_scope, RowScope};
+use serde_json::json;
+use sqlx::postgres::{PgConnection, PgPoolOptions};
+use uuid::Uuid;
+
+/// Whether the test database connects as a superuser, which bypasses
+/// row-level security. An ordinary login is subject to it already.
+async fn connects_as_superuser(app_state: &api::AppState) -> bool {
+    sqlx::query_scalar("SELECT rolsuper FROM pg_roles WHERE rolname = current_user")
+        .fetch_one(&app_state.db)
+        .await
+        .unwrap()
+}
+
+/// The ordinary role the probes switch to inside their transaction when the
+/// test database connects as a superuser.
+const PROBE_ROLE: &str = "rls_probe";
+
+async fn ensure_probe_role(app_state: &api::AppState) {
+    if !connects_as_superuser(app_state).await {
+        return;
+    }
+    sqlx::query(&format!(
+        "DO $$ BEGIN
+             CREATE ROLE {PROBE_ROLE} NOLOGIN;
+         EXCEPTION WHEN duplicate_object THEN NULL;
+         END $$"
+    ))
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+    sqlx::query(&format!(
+        "GRANT SELECT, INSERT ON ALL TABLES IN SCHEMA public TO {PROBE_ROLE}"
+    ))
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+}
+
+/// Put the transaction on `conn` under row-level security: switch to
+/// [`PROBE_ROLE`] if the login is a superuser.
+async fn probe(conn: &mut PgConnection) {
+    let superuser: bool =
+        sqlx::query_scalar("SELECT rolsuper FROM pg_roles WHERE rolname = current_user")
+            .fetch_one(&mut *conn)
+            .await
+            .unwrap();
+    if superuser {
+        sqlx::query(&format!("SET LOCAL ROLE {PROBE_ROLE}"))
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+    }
+}
+
+/// The role the player flows run as: an ordinary role with the privileges the
+/// service's own login has, so row-level security applies to every query.
+const APP_ROLE: &str = "rls_app";
+
+/// An app whose pooled connections all run as [`APP_ROLE`], for driving whole
+/// mutations under row-level security. Against a database that doesn't
+/// connect as a superuser the usual app already is one.
+async fn app_under_row_security(app_state: &api::AppState) -> api::AppState {
+    if !connects_as_superuser(app_state).await {
+        return app_state.clone();
+    }
+    sqlx::query(&format!(
+        "DO $$ BEGIN
+             CREATE ROLE {APP_ROLE} NOLOGIN;
+         EXCEPTION WHEN duplicate_object THEN NULL;
+         END $$"
+    ))
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+    for grant in [
+        format!(
+            "GRANT SELECT, INSERT, UPDATE, DELETE ON ALL TABLES IN SCHEMA public TO {APP_ROLE}"
+        ),
+        format!("GRANT USAGE, SELECT ON ALL SEQUENCES IN SCHEMA public TO {APP_ROLE}"),
+    ] {
+        sqlx::query(&grant).execute(&app_state.db).await.unwrap();
+    }
+
+    let options = app_state
+        .db
+        .connect_options()
+        .as_ref()
+        .clone()
+        .options([("role", APP_ROLE)]);
+    let pool = infra::db::scope_connections(PgPoolOptions::new())
+        .max_connections(5)
+        .connect_with(options)
+        .await
+        .unwrap();
+    api::AppState::new(pool).unwrap()
+}
+
+/// The clubs among `ids` whose accounting settings (a club-private table) a
+/// connection taken from the pool can see.
+async fn visible_settings(app_state: &api::AppState, ids: &[Uuid]) -> Vec<Uuid> {
+    let mut tx = app_state.db.begin().await.unwrap();
+    probe(&mut tx).await;
+    let mut visible: Vec<Uuid> =
+        sqlx::query_scalar("SELECT club_id FROM club_accounting_settings WHERE club_id = ANY($1)")
+            .bind(ids)
+            .fetch_all(&mut *tx)
+            .await
+            .unwrap();
+    visible.sort();
+    visible
+}
+
+#[tokio::test]
+async fn test_every_table_has_row_security() {
+    let app_state = setup_test_db().await;
+
+    // Tables added by later migrations need their policies too.
+    let unprotected: Vec<String> = sqlx::query_scalar(
+        "SELECT c.relname::TEXT FROM pg_class c JOIN pg_namespace n ON n.oid = c.relnamespace \
+         WHERE n.nspname = 'public' AND c.relkind IN ('r', 'p') AND NOT c.relrowsecurity \
+         ORDER BY 1",
+    )
+    .fetch_all(&app_state.db)
+    .await
+    .unwrap();
+    assert!(unprotected.is_empty(), "{unprotected:?}");
+}
+
+#[tokio::test]
+async fn test_club_scope_hides_other_clubs_rows() {
+    let app_state = setup_test_db().await;
+    ensure_probe_role(&app_state).await;
+
+    let club_a = create_test_club(&app_state, "RLS Club A").await;
+    let club_b = create_test_club(&app_state, "RLS Club B").await;
+    for club_id in [club_a, club_b] {
+        sqlx::query("INSERT INTO club_accounting_settings (club_id) VALUES ($1)")
+            .bind(club_id)
+            .execute(&app_state.db)
+            .await
+            .unwrap();
+    }
+    let ids = [club_a, club_b];
+    let mut both = ids.to_vec();
+    both.sort();
+
+    // A request sees only the clubs it acts for.
+    assert_eq!(
+        with_request_scope(RowScope::club(club_a), visible_settings(&app_state, &ids)).await,
+        vec![club_a]
+    );
+    assert_eq!(
+        with_request_scope(RowScope::club(club_b), visible_settings(&app_state, &ids)).await,
+        vec![club_b]
+    );
+    // A request acting for no club sees none of their private rows.
+    assert!(
+        with_request_scope(RowScope::default(), visible_settings(&app_state, &ids))
+            .await
+            .is_empty()
+    );
+    // Outside a request (background services) nothing is filtered, on the
+    // same pooled connections.
+    assert_eq!(visible_settings(&app_state, &ids).await, both);
+
+    // A scoped transaction narrows a request; its scope ends with it.
+    let mut tx = begin_scoped(&app_state.db, RowScope::club(club_a))
+        .await
+        .unwrap();
+    probe(&mut tx).await;
+    let visible: Vec<Uuid> =
+        sqlx::query_scalar("SELECT club_id FROM club_accounting_settings WHERE club_id = ANY($1)")
+            .bind(ids)
+            .fetch_all(&mut *tx)
+            .await
+            .unwrap();
+    assert_eq!(visible, vec![club_a]);
+    tx.commit().await.unwrap();
+
+    let leaked: Option<String> =
+        sqlx::query_scalar("SELECT NULLIF(current_setting('app.scoped', true), '')")
+            .fetch_one(&app_state.db)
+            .await
+            .unwrap();
+    assert!(leaked.is_none());
+}
+
+#[tokio::test]
+async fn test_user_scope_guards_private_rows() {
+    let app_state = setup_test_db().await;
+    ensure_probe_role(&app_state).await;
+
+    let unique = Uuid::new_v4();
+    let (alice, _) = create_test_user(
+        &app_state,
+        &format!("rls_alice_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let (bob, _) =
+        create_test_user(&app_state, &format!("rls_bob_{unique}@test.com"), "player").await;
+    for user_id in [alice, bob] {
+        sqlx::query(
+            "INSERT INTO bankroll_transactions (user_id, kind, amount_cents, occurred_at) VALUES ($1, 'buy_in', 1000, NOW())",
+        )
+        .bind(user_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    }
+
+    let mut tx = begin_scoped(&app_state.db, RowScope::user(alice))
+        .await
+        .unwrap();
+    probe(&mut tx).await;
+    // Even without a user filter only the scoped user's rows come back.
+    let owners: Vec<Uuid> = sqlx::query_scalar(
+        "SELECT DISTINCT user_id FROM bankroll_transactions WHERE user_id = ANY($1)",
+    )
+    .bind([alice, bob])
+    .fetch_all(&mut *tx)
+    .await
+    .unwrap();
+    assert_eq!(owners, vec![alice]);
+
+    // Writing a row for someone else is refused.
+    let write = sqlx::query(
+        "INSERT INTO bankroll_transactions (user_id, kind, amount_cents, occurred_at) VALUES ($1, 'buy_in', 1000, NOW())",
+    )
+    .bind(bob)
+    .execute(&mut *tx)
+    .await;
+    assert!(write.is_err());
+}
+
+#[tokio::test]
+async fn test_player_self_check_in_under_row_security() {
+    let app_state = setup_test_db().await;
+    let player_app = app_under_row_security(&app_state).await;
+    let schema = build_schema(player_app.clone());
+
+    let unique = Uuid::new_v4();
+    let (player_id, player_claims) = create_test_user(
+        &app_state,
+        &format!("rls_checkin_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "RLS Check-In Club").await;
+    let tournament_id = create_open_tournament(&app_state, club_id, "RLS Check-In").await;
+    let table_id = create_test_club_table(&app_state, club_id, 1, 9).await;
+    assign_table_to_tournament(&app_state, tournament_id, table_id).await;
+    create_test_registration(&app_state, tournament_id, player_id, "registered").await;
+
+    let response = execute_graphql(
+        &schema,
+        r#"
+        mutation SelfCheckIn($input: SelfCheckInInput!) {
+            selfCheckIn(input: $input) {
+                registration { status }
+                seatAssignment { clubTableId }
+            }
+        }
+        "#,
+        Some(Variables::from_json(json!({
+            "input": { "tournamentId": tournament_id.to_string() }
+        }))),
+        Some(player_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    // The player's request reads the club's tables and queues the seat slip.
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["selfCheckIn"]["registration"]["status"], "SEATED");
+    assert_eq!(
+        data["selfCheckIn"]["seatAssignment"]["clubTableId"],
+        table_id.to_string()
+    );
+}
+
+/// How many rows of `table` with `column = id` a connection taken from the
+/// pool can see.
+async fn visible_rows(app_state: &api::AppState, table: &str, column: &str, id: Uuid) -> i64 {
+    let mut tx = app_state.db.begin().await.unwrap();
+    probe(&mut tx).await;
+    sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {table} WHERE {column} = $1"))
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_other_club_cannot_read_field_or_roster() {
+    let app_state = setup_test_db().await;
+    ensure_probe_role(&app_state).await;
+
+    let unique = Uuid::new_v4();
+    let (player_id, _) = create_test_user(
+        &app_state,
+        &format!("rls_field_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let club_a = create_test_club(&app_state, "RLS Field Club A").await;
+    let club_b = create_test_club(&app_state, "RLS Field Club B").await;
+    let tournament_id = create_test_tournament(&app_state, club_a, "RLS Members Only").await;
+    sqlx::query("UPDATE tournaments SET visibility = 'members_only' WHERE id = $1")
+        .bind(tournament_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    create_test_registration(&app_state, tournament_id, player_id, "registered").await;
+    sqlx::query(
+        "INSERT INTO tournament_entries (tournament_id, user_id, club_player_id, entry_type, amount_cents)
+         SELECT tournament_id, user_id, club_player_id, 'initial', 5000
+         FROM tournament_registrations WHERE tournament_id = $1",
+    )
+    .bind(tournament_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO tournament_results (tournament_id, user_id, club_player_id, final_position, prize_cents)
+         SELECT tournament_id, user_id, club_player_id, 1, 0
+         FROM tournament_registrations WHERE tournament_id = $1",
+    )
+    .bind(tournament_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    let field = |scope: RowScope| {
+        let app_state = &app_state;
+        async move {
+            with_request_scope(scope, async {
+                [
+                    visible_rows(
+                        app_state,
+                        "tournament_entries",
+                        "tournament_id",
+                        tournament_id,
+                    )
+                    .await,
+                    visible_rows(
+                        app_state,
+                        "tournament_results",
+                        "tournament_id",
+                        tournament_id,
+                    )
+                    .await,
+                    visible_rows(app_state, "club_player", "club_id", club_a).await,
+                ]
+            })
+            .await
+        }
+    };
+
+    // The hosting club sees its field; another club sees none of it.
+    assert_eq!(field(RowScope::club(club_a)).await, [1, 1, 1]);
+    assert_eq!(field(RowScope::club(club_b)).await, [0, 0, 0]);
+    assert_eq!(field(RowScope::default()).await, [0, 0, 0]);
+
+    // Once the tournament is public its field is, but the roster stays with
+    // its club: others only get the players' public columns.
+    sqlx::query("UPDATE tournaments SET visibility = 'public' WHERE id = $1")
+        .bind(tournament_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    assert_eq!(field(RowScope::club(club_b)).await, [1, 1, 0]);
+    let public_roster = with_request_scope(
+        RowScope::club(club_b),
+        visible_rows(&app_state, "club_player_public", "club_id", club_a),
+    )
+    .await;
+    assert_eq!(public_roster, 1);
+}
+
+#[tokio::test]
+async fn test_staff_scope_is_narrower_than_a_managers() {
+    let app_state = setup_test_db().await;
+    ensure_probe_role(&app_state).await;
+
+    let unique = Uuid::new_v4();
+    let (dealer_id, _) = create_test_user(
+        &app_state,
+        &format!("rls_dealer_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let (floor_id, _) = create_test_user(
+        &app_state,
+        &format!("rls_floor_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "RLS Staff Club").await;
+    for (user_id, role) in [(dealer_id, "dealer"), (floor_id, "floor")] {
+        sqlx::query(
+            "INSERT INTO club_staff (club_id, display_name, user_id, role) VALUES ($1, $3, $2, $3)",
+        )
+        .bind(club_id)
+        .bind(user_id)
+        .bind(role)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    }
+    sqlx::query("INSERT INTO club_accounting_settings (club_id) VALUES ($1)")
+        .bind(club_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO incidents (club_id, category, severity, description) \
+         VALUES ($1, 'conduct', 'low', 'Splashed the pot')",
+    )
+    .bind(club_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    // Staff don't act for their club...
+    for user_id in [dealer_id, floor_id] {
+        assert!(
+            infra::repos::club_managers::acting_club_ids(&app_state.db, user_id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    let staff = |user_id: Uuid| {
+        let app_state = &app_state;
+        async move {
+            with_request_scope(RowScope::user(user_id), async {
+                [
+                    visible_rows(app_state, "club_accounting_settings", "club_id", club_id).await,
+                    visible_rows(app_state, "club_staff", "user_id", user_id).await,
+                    visible_rows(app_state, "incidents", "club_id", club_id).await,
+                ]
+            })
+            .await
+        }
+    };
+
+    // ... but see their own staff record, and the floor its incidents.
+    assert_eq!(staff(dealer_id).await, [0, 1, 0]);
+    assert_eq!(staff(floor_id).await, [0, 1, 1]);
+}
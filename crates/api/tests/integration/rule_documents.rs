@@ -0,0 +1,221 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_registration_requires_rule_acknowledgment() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("rulesmanager_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let (player_id, player_claims) = create_test_user(
+        &app_state,
+        &format!("rulesplayer_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Rules Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_open_tournament(&app_state, club_id, "Rules Tournament").await;
+
+    let create_query = r#"
+        mutation($input: CreateRuleDocumentInput!) {
+            createRuleDocument(input: $input) { id version kind requiresAcknowledgment }
+        }
+    "#;
+
+    // Players can't publish rules.
+    let response = execute_graphql(
+        &schema,
+        create_query,
+        Some(Variables::from_json(json!({
+            "input": { "clubId": club_id.to_string(), "title": "Rules", "bodyMarkdown": "x" }
+        }))),
+        Some(player_claims.clone()),
+    )
+    .await;
+    assert!(!response.errors.is_empty());
+
+    // Club-wide house rules, plus a tournament-specific disclosure.
+    let response = execute_graphql(
+        &schema,
+        create_query,
+        Some(Variables::from_json(json!({
+            "input": {
+                "clubId": club_id.to_string(),
+                "title": "House Rules",
+                "bodyMarkdown": "# House rules\n\nOne player to a hand."
+            }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let house_rules = data["createRuleDocument"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert_eq!(data["createRuleDocument"]["kind"], "RULES");
+    assert_eq!(data["createRuleDocument"]["version"], 1);
+
+    let response = execute_graphql(
+        &schema,
+        create_query,
+        Some(Variables::from_json(json!({
+            "input": {
+                "clubId": club_id.to_string(),
+                "tournamentId": tournament_id.to_string(),
+                "kind": "DISCLOSURE",
+                "title": "Rake Disclosure",
+                "bodyMarkdown": "10% of the buy-in is retained as rake."
+            }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let disclosure = data["createRuleDocument"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // Both show up on the tournament, club-wide first.
+    let response = execute_graphql(
+        &schema,
+        r#"query($id: ID!) { tournament(id: $id) { rules { id title } } }"#,
+        Some(Variables::from_json(
+            json!({ "id": tournament_id.to_string() }),
+        )),
+        Some(player_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let rules = data["tournament"]["rules"].as_array().unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[0]["id"], house_rules.as_str());
+    assert_eq!(rules[1]["id"], disclosure.as_str());
+
+    // Editing the body publishes a new version.
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: UpdateRuleDocumentInput!) { updateRuleDocument(input: $input) { version } }"#,
+        Some(Variables::from_json(json!({
+            "input": { "id": house_rules, "bodyMarkdown": "# House rules\n\nEnglish only at the table." }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["updateRuleDocument"]["version"], 2);
+
+    let register_query = r#"
+        mutation($input: RegisterForTournamentInput!) {
+            registerForTournament(input: $input) { id }
+        }
+    "#;
+
+    // Missing the disclosure: rejected.
+    let response = execute_graphql(
+        &schema,
+        register_query,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "acknowledgedRuleDocumentIds": [house_rules]
+            }
+        }))),
+        Some(player_claims.clone()),
+    )
+    .await;
+    assert!(!response.errors.is_empty());
+    assert!(
+        response.errors[0].message.contains("Rake Disclosure"),
+        "{}",
+        response.errors[0].message
+    );
+
+    let response = execute_graphql(
+        &schema,
+        register_query,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "acknowledgedRuleDocumentIds": [house_rules, disclosure]
+            }
+        }))),
+        Some(player_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    // The acknowledgments were recorded against the current versions.
+    let response = execute_graphql(
+        &schema,
+        r#"query($id: ID!) { ruleAcknowledgments(tournamentId: $id) { ruleDocumentId documentVersion userId acknowledgedAt } }"#,
+        Some(Variables::from_json(json!({ "id": tournament_id.to_string() }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let acks = data["ruleAcknowledgments"].as_array().unwrap();
+    assert_eq!(acks.len(), 2);
+    assert!(acks
+        .iter()
+        .all(|a| a["userId"] == player_id.to_string().as_str()));
+    let house_ack = acks
+        .iter()
+        .find(|a| a["ruleDocumentId"] == house_rules.as_str())
+        .unwrap();
+    assert_eq!(house_ack["documentVersion"], 2);
+
+    // Archived documents no longer gate registration.
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($id: ID!) { archiveRuleDocument(id: $id) { isActive } }"#,
+        Some(Variables::from_json(json!({ "id": disclosure }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let remaining: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM rule_documents WHERE tournament_id = $1 AND is_active",
+    )
+    .bind(tournament_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(remaining, 0);
+
+    // Managers registering someone else aren't asked for acknowledgments.
+    let (other_id, _) = create_test_user(
+        &app_state,
+        &format!("rulesother_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let response = execute_graphql(
+        &schema,
+        register_query,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "userId": other_id.to_string()
+            }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+}
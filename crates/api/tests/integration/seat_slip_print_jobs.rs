@@ -0,0 +1,161 @@
+use crate::common::*;
+use api::gql::build_schema;
+use api::routes::printouts::DownloadQuery;
+use api::routes::seat_slips::print;
+use async_graphql::Variables;
+use axum::extract::{Path, Query, State};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+const JOB_FIELDS: &str = "id status playerName tableNumber seatNumber reprintOf attempts error \
+                          printUrl";
+
+fn parse_url(url: &str) -> (Uuid, DownloadQuery) {
+    let (path, query) = url.split_once('?').unwrap();
+    let id = Uuid::parse_str(path.rsplit('/').next().unwrap()).unwrap();
+    let mut expires = 0;
+    let mut signature = String::new();
+    for pair in query.split('&') {
+        match pair.split_once('=').unwrap() {
+            ("expires", v) => expires = v.parse().unwrap(),
+            ("signature", v) => signature = v.to_string(),
+            _ => {}
+        }
+    }
+    (id, DownloadQuery { expires, signature })
+}
+
+/// Seating a player queues a slip; the agent claims it, fetches the ESC/POS
+/// bytes and reports it printed; a reprint queues a marked copy.
+#[tokio::test]
+async fn test_seat_slip_queue_claim_and_reprint() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) = create_test_user(&app_state, "slip_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Slip Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Sunday Slip").await;
+    let table_id = create_test_club_table(&app_state, club_id, 4, 9).await;
+    assign_table_to_tournament(&app_state, tournament_id, table_id).await;
+    let (player_id, player) = create_test_user(&app_state, "slip_p@test.com", "player").await;
+
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($input: AssignPlayerToSeatInput!) { assignPlayerToSeat(input: $input) { id } }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "clubTableId": table_id.to_string(),
+                "userId": player_id.to_string(),
+                "seatNumber": 7
+            }
+        }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "seat: {:?}", resp.errors);
+
+    let list = |status: &str| {
+        let query = format!(
+            "query($id: ID!) {{ seatSlipPrintJobs(tournamentId: $id, status: {status}) {{ {JOB_FIELDS} }} }}"
+        );
+        (
+            query,
+            Variables::from_json(json!({ "id": tournament_id.to_string() })),
+        )
+    };
+    let (query, vars) = list("PENDING");
+    let resp = execute_graphql(&schema, &query, Some(vars), Some(manager.clone())).await;
+    assert!(resp.errors.is_empty(), "list: {:?}", resp.errors);
+    let pending = resp.data.into_json().unwrap()["seatSlipPrintJobs"].clone();
+    assert_eq!(pending.as_array().unwrap().len(), 1);
+    assert_eq!(pending[0]["playerName"], "Test User");
+    assert_eq!(pending[0]["tableNumber"], 4);
+    assert_eq!(pending[0]["seatNumber"], 7);
+
+    // Players can't drain the queue.
+    let claim = format!(
+        "mutation($club: ID!) {{ claimSeatSlipPrintJobs(clubId: $club) {{ {JOB_FIELDS} }} }}"
+    );
+    let club_vars = || Variables::from_json(json!({ "club": club_id.to_string() }));
+    let resp = execute_graphql(&schema, &claim, Some(club_vars()), Some(player)).await;
+    assert!(!resp.errors.is_empty());
+
+    let resp = execute_graphql(&schema, &claim, Some(club_vars()), Some(manager.clone())).await;
+    assert!(resp.errors.is_empty(), "claim: {:?}", resp.errors);
+    let claimed = resp.data.into_json().unwrap()["claimSeatSlipPrintJobs"].clone();
+    assert_eq!(claimed.as_array().unwrap().len(), 1);
+    assert_eq!(claimed[0]["status"], "PRINTING");
+    assert_eq!(claimed[0]["attempts"], 1);
+    let job_id = claimed[0]["id"].as_str().unwrap().to_string();
+
+    let fetch = |url: &Value| {
+        let (id, query) = parse_url(url.as_str().unwrap());
+        let state = app_state.clone();
+        async move {
+            let response = print(State(state), Path(id), Query(query)).await.unwrap();
+            axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap()
+        }
+    };
+    let contains = |body: &[u8], needle: &[u8]| body.windows(needle.len()).any(|w| w == needle);
+    let body = fetch(&claimed[0]["printUrl"]).await;
+    assert!(body.starts_with(&[0x1b, b'@']));
+    assert!(contains(&body, b"TABLE 4"));
+    assert!(contains(&body, b"SEAT 7"));
+    assert!(!contains(&body, b"COPY"));
+
+    // A claimed job isn't handed out twice.
+    let resp = execute_graphql(&schema, &claim, Some(club_vars()), Some(manager.clone())).await;
+    let again = resp.data.into_json().unwrap()["claimSeatSlipPrintJobs"].clone();
+    assert!(again.as_array().unwrap().is_empty());
+
+    let complete =
+        format!("mutation($id: ID!) {{ completeSeatSlipPrintJob(jobId: $id) {{ {JOB_FIELDS} }} }}");
+    let job_vars = || Variables::from_json(json!({ "id": job_id }));
+    let resp = execute_graphql(&schema, &complete, Some(job_vars()), Some(manager.clone())).await;
+    assert!(resp.errors.is_empty(), "complete: {:?}", resp.errors);
+    let done = resp.data.into_json().unwrap()["completeSeatSlipPrintJob"].clone();
+    assert_eq!(done["status"], "PRINTED");
+
+    // Only a claimed job can be completed.
+    let resp = execute_graphql(&schema, &complete, Some(job_vars()), Some(manager.clone())).await;
+    assert!(!resp.errors.is_empty());
+
+    let reprint =
+        format!("mutation($id: ID!) {{ reprintSeatSlip(jobId: $id) {{ {JOB_FIELDS} }} }}");
+    let resp = execute_graphql(&schema, &reprint, Some(job_vars()), Some(manager.clone())).await;
+    assert!(resp.errors.is_empty(), "reprint: {:?}", resp.errors);
+    let copy = resp.data.into_json().unwrap()["reprintSeatSlip"].clone();
+    assert_eq!(copy["status"], "PENDING");
+    assert_eq!(copy["reprintOf"], job_id.as_str());
+    assert_eq!(copy["seatNumber"], 7);
+
+    let resp = execute_graphql(&schema, &claim, Some(club_vars()), Some(manager.clone())).await;
+    let claimed = resp.data.into_json().unwrap()["claimSeatSlipPrintJobs"].clone();
+    assert_eq!(claimed.as_array().unwrap().len(), 1);
+    assert_eq!(claimed[0]["id"], copy["id"]);
+    let body = fetch(&claimed[0]["printUrl"]).await;
+    assert!(contains(&body, b"*** COPY ***"));
+
+    // A failed print keeps the printer's message.
+    let resp = execute_graphql(
+        &schema,
+        "mutation($id: ID!) { completeSeatSlipPrintJob(jobId: $id, error: \"Out of paper\") { status error } }",
+        Some(Variables::from_json(json!({ "id": copy["id"] }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "fail: {:?}", resp.errors);
+    let failed = resp.data.into_json().unwrap()["completeSeatSlipPrintJob"].clone();
+    assert_eq!(failed["status"], "FAILED");
+    assert_eq!(failed["error"], "Out of paper");
+
+    let (query, vars) = list("PRINTED");
+    let resp = execute_graphql(&schema, &query, Some(vars), Some(manager)).await;
+    let printed = resp.data.into_json().unwrap()["seatSlipPrintJobs"].clone();
+    assert_eq!(printed.as_array().unwrap().len(), 1);
+    assert_eq!(printed[0]["id"], job_id.as_str());
+}
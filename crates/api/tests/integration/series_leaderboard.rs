@@ -0,0 +1,151 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+/// Register `user_id` in `tournament_id` and record their finish.
+async fn play(
+    app_state: &api::AppState,
+    tournament_id: Uuid,
+    user_id: Uuid,
+    position: i32,
+    points: i32,
+) {
+    create_test_registration(app_state, tournament_id, user_id, "registered").await;
+    sqlx::query(
+        "INSERT INTO tournament_results (tournament_id, user_id, final_position, prize_cents, points) \
+         VALUES ($1, $2, $3, 0, $4)",
+    )
+    .bind(tournament_id)
+    .bind(user_id)
+    .bind(position)
+    .bind(points)
+    .execute(&app_state.db)
+    .await
+    .expect("Failed to record result");
+}
+
+#[tokio::test]
+async fn test_series_leaderboard_sums_member_events() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("seriesmanager_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let (alice, _) = create_test_user(
+        &app_state,
+        &format!("seriesalice_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let (bob, _) = create_test_user(
+        &app_state,
+        &format!("seriesbob_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Series Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let first = create_test_tournament(&app_state, club_id, "Festival Event 1").await;
+    let second = create_test_tournament(&app_state, club_id, "Festival Event 2").await;
+    let outside = create_test_tournament(&app_state, club_id, "Weekly Turbo").await;
+
+    let response = execute_graphql(
+        &schema,
+        r#"
+            mutation($input: CreateTournamentSeriesInput!) {
+                createTournamentSeries(input: $input) { id flights { id } }
+            }
+        "#,
+        Some(Variables::from_json(json!({
+            "input": { "clubId": club_id.to_string(), "title": "Spring Festival" }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let series = response.data.into_json().unwrap()["createTournamentSeries"].clone();
+    assert!(series["flights"].as_array().unwrap().is_empty());
+    let series_id = series["id"].as_str().unwrap().to_string();
+
+    let add = |tournament_id: Uuid| {
+        let schema = schema.clone();
+        let claims = manager_claims.clone();
+        let series_id = series_id.clone();
+        async move {
+            execute_graphql(
+                &schema,
+                r#"
+                    mutation($seriesId: ID!, $tournamentId: ID!) {
+                        addTournamentToSeries(seriesId: $seriesId, tournamentId: $tournamentId) {
+                            seriesId
+                        }
+                    }
+                "#,
+                Some(Variables::from_json(json!({
+                    "seriesId": series_id,
+                    "tournamentId": tournament_id.to_string()
+                }))),
+                Some(claims),
+            )
+            .await
+        }
+    };
+    for tournament_id in [first, second, outside] {
+        let response = add(tournament_id).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+    }
+
+    let response = execute_graphql(
+        &schema,
+        r#"
+            mutation($tournamentId: ID!) {
+                removeTournamentFromSeries(tournamentId: $tournamentId) { seriesId }
+            }
+        "#,
+        Some(Variables::from_json(
+            json!({ "tournamentId": outside.to_string() }),
+        )),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert!(response.data.into_json().unwrap()["removeTournamentFromSeries"]["seriesId"].is_null());
+
+    play(&app_state, first, alice, 1, 50).await;
+    play(&app_state, first, bob, 2, 30).await;
+    play(&app_state, second, bob, 1, 40).await;
+    // Outside the series: doesn't count.
+    play(&app_state, outside, alice, 1, 100).await;
+
+    let response = execute_graphql(
+        &schema,
+        r#"
+            query($seriesId: ID!) {
+                seriesLeaderboard(seriesId: $seriesId) {
+                    totalCount
+                    items { rank points totalTournaments firstPlaces user { id } }
+                }
+            }
+        "#,
+        Some(Variables::from_json(json!({ "seriesId": series_id }))),
+        None,
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let board = &response.data.into_json().unwrap()["seriesLeaderboard"];
+    assert_eq!(board["totalCount"], 2);
+    let items = board["items"].as_array().unwrap();
+    assert_eq!(items[0]["user"]["id"], bob.to_string());
+    assert_eq!(items[0]["points"], 70.0);
+    assert_eq!(items[0]["totalTournaments"], 2);
+    assert_eq!(items[1]["user"]["id"], alice.to_string());
+    assert_eq!(items[1]["points"], 50.0);
+    assert_eq!(items[1]["firstPlaces"], 1);
+}
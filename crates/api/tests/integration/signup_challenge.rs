@@ -0,0 +1,118 @@
+//! Bot gate on signup and password reset, in its proof-of-work mode.
+
+use api::auth::challenge::{ChallengeConfig, ChallengeKind};
+use api::gql::build_schema;
+use api::AppState;
+use async_graphql::Variables;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::common::*;
+
+const DIFFICULTY: u32 = 8;
+
+const REGISTER: &str = r#"
+    mutation($input: UserRegistrationInput!) { registerUser(input: $input) { id } }
+"#;
+
+const RESET: &str = r#"
+    mutation($input: RequestPasswordResetInput!) { requestPasswordReset(input: $input) { success } }
+"#;
+
+const CHALLENGE: &str = "{ signupChallenge { kind puzzle difficulty } }";
+
+async fn pow_state() -> AppState {
+    let app = setup_test_db().await;
+    let mut config = app.config().clone();
+    config.auth.challenge = ChallengeConfig {
+        kind: ChallengeKind::ProofOfWork,
+        captcha_site_key: None,
+        captcha_secret: None,
+        pow_difficulty: DIFFICULTY,
+    };
+    AppState::with_config(app.db.clone(), config)
+}
+
+/// A response with `DIFFICULTY` (8) leading zero bits: a zero first byte.
+fn solve(puzzle: &str) -> String {
+    (0u64..)
+        .map(|n| format!("{puzzle}:{n}"))
+        .find(|r| Sha256::digest(r.as_bytes())[0] == 0)
+        .unwrap()
+}
+
+fn registration(response: Option<&str>) -> Variables {
+    let unique = Uuid::new_v4();
+    Variables::from_json(json!({
+        "input": {
+            "email": format!("pow_{unique}@test.com"),
+            "password": "testpassword123",
+            "firstName": "Proof",
+            "lastName": "Ofwork",
+            "challengeResponse": response,
+        }
+    }))
+}
+
+#[tokio::test]
+async fn the_gate_is_off_by_default() {
+    let app = setup_test_db().await;
+    let schema = build_schema(app);
+
+    let res = execute_graphql(&schema, CHALLENGE, None, None).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    assert_eq!(
+        res.data.into_json().unwrap()["signupChallenge"]["kind"],
+        "NONE"
+    );
+
+    let res = execute_graphql(&schema, REGISTER, Some(registration(None)), None).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+}
+
+#[tokio::test]
+async fn signup_needs_a_solved_puzzle() {
+    let schema = build_schema(pow_state().await);
+
+    let res = execute_graphql(&schema, REGISTER, Some(registration(None)), None).await;
+    assert_eq!(error_code(&res).as_deref(), Some("CHALLENGE_FAILED"));
+
+    let res = execute_graphql(&schema, CHALLENGE, None, None).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(data["signupChallenge"]["kind"], "PROOF_OF_WORK");
+    assert_eq!(data["signupChallenge"]["difficulty"], DIFFICULTY);
+    let puzzle = data["signupChallenge"]["puzzle"].as_str().unwrap();
+
+    let unsolved = (0u64..)
+        .map(|n| format!("{puzzle}:{n}"))
+        .find(|r| Sha256::digest(r.as_bytes())[0] != 0)
+        .unwrap();
+    let res = execute_graphql(&schema, REGISTER, Some(registration(Some(&unsolved))), None).await;
+    assert_eq!(error_code(&res).as_deref(), Some("CHALLENGE_FAILED"));
+
+    let solved = solve(puzzle);
+    let res = execute_graphql(&schema, REGISTER, Some(registration(Some(&solved))), None).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+}
+
+#[tokio::test]
+async fn a_solution_is_accepted_once() {
+    let schema = build_schema(pow_state().await);
+
+    let res = execute_graphql(&schema, CHALLENGE, None, None).await;
+    let data = res.data.into_json().unwrap();
+    let solved = solve(data["signupChallenge"]["puzzle"].as_str().unwrap());
+
+    let reset = Variables::from_json(json!({
+        "input": {
+            "email": format!("nobody_{}@test.com", Uuid::new_v4()),
+            "challengeResponse": solved,
+        }
+    }));
+    let res = execute_graphql(&schema, RESET, Some(reset.clone()), None).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+
+    let res = execute_graphql(&schema, RESET, Some(reset), None).await;
+    assert_eq!(error_code(&res).as_deref(), Some("CHALLENGE_FAILED"));
+}
@@ -0,0 +1,158 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_staff_clock_in_out_and_shift_report() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("clockmanager_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let (dealer_user, dealer_claims) = create_test_user(
+        &app_state,
+        &format!("clockdealer_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let (_, other_claims) = create_test_user(
+        &app_state,
+        &format!("clockother_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Time Clock Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Time Clock Tournament").await;
+
+    let variables = Variables::from_json(json!({
+        "input": {
+            "clubId": club_id.to_string(),
+            "displayName": "Dana",
+            "userId": dealer_user.to_string(),
+            "hourlyRateCents": 1200
+        }
+    }));
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: CreateClubStaffInput!) { createClubStaff(input: $input) { id hourlyRateCents } }"#,
+        Some(variables),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["createClubStaff"]["hourlyRateCents"], 1200);
+    let staff_id = data["createClubStaff"]["id"].as_str().unwrap().to_string();
+
+    let clock_in = r#"
+        mutation($input: ClockInInput!) {
+            clockIn(input: $input) { id tournamentId hourlyRateCents clockOutAt }
+        }
+    "#;
+    let clock_in_vars = Variables::from_json(json!({
+        "input": { "staffId": staff_id, "tournamentId": tournament_id.to_string() }
+    }));
+
+    // Someone else can't punch the dealer's clock.
+    let response = execute_graphql(
+        &schema,
+        clock_in,
+        Some(clock_in_vars.clone()),
+        Some(other_claims),
+    )
+    .await;
+    assert!(
+        !response.errors.is_empty(),
+        "Other players must be rejected"
+    );
+
+    // The dealer clocks themselves in, against the tournament.
+    let response = execute_graphql(
+        &schema,
+        clock_in,
+        Some(clock_in_vars.clone()),
+        Some(dealer_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["clockIn"]["tournamentId"], tournament_id.to_string());
+    assert_eq!(data["clockIn"]["hourlyRateCents"], 1200);
+    assert!(data["clockIn"]["clockOutAt"].is_null());
+    let shift_id = uuid::Uuid::parse_str(data["clockIn"]["id"].as_str().unwrap()).unwrap();
+
+    let response = execute_graphql(
+        &schema,
+        clock_in,
+        Some(clock_in_vars),
+        Some(dealer_claims.clone()),
+    )
+    .await;
+    assert!(!response.errors.is_empty(), "Double clock-in must fail");
+
+    // Make it a two-hour shift.
+    sqlx::query("UPDATE staff_shifts SET clock_in_at = NOW() - INTERVAL '2 hours' WHERE id = $1")
+        .bind(shift_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: ClockOutInput!) { clockOut(input: $input) { clockOutAt } }"#,
+        Some(Variables::from_json(
+            json!({ "input": { "staffId": staff_id } }),
+        )),
+        Some(dealer_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let response = execute_graphql(
+        &schema,
+        r#"query($clubId: ID!, $from: DateTime!, $to: DateTime!) {
+            staffShiftReport(clubId: $clubId, from: $from, to: $to) {
+                lines { displayName shifts minutes costCents }
+                totalMinutes
+                totalCostCents
+                openShifts { id }
+            }
+        }"#,
+        Some(Variables::from_json(json!({
+            "clubId": club_id.to_string(),
+            "from": (chrono::Utc::now() - chrono::Duration::days(1)).to_rfc3339(),
+            "to": (chrono::Utc::now() + chrono::Duration::days(1)).to_rfc3339(),
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let report = &data["staffShiftReport"];
+    assert_eq!(report["lines"][0]["displayName"], "Dana");
+    assert_eq!(report["lines"][0]["shifts"], 1);
+    assert_eq!(report["totalMinutes"], 120);
+    assert_eq!(report["totalCostCents"], 2400);
+    assert!(report["openShifts"].as_array().unwrap().is_empty());
+
+    // The shift's cost shows up in the tournament's cash report.
+    let response = execute_graphql(
+        &schema,
+        r#"query($id: ID!) { tournamentCashReport(tournamentId: $id) { staffCostCents } }"#,
+        Some(Variables::from_json(
+            json!({ "id": tournament_id.to_string() }),
+        )),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["tournamentCashReport"]["staffCostCents"], 2400);
+}
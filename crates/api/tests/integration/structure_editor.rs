@@ -0,0 +1,159 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::{json, Value};
+
+fn level(number: i32, small: i32, duration: i32) -> Value {
+    json!({
+        "levelNumber": number,
+        "smallBlind": small,
+        "bigBlind": small * 2,
+        "ante": 0,
+        "durationMinutes": duration,
+        "isBreak": false
+    })
+}
+
+fn small_blinds(structure: &Value) -> Vec<i64> {
+    structure
+        .as_array()
+        .unwrap()
+        .iter()
+        .enumerate()
+        .map(|(i, level)| {
+            assert_eq!(level["levelNumber"], i as i64 + 1);
+            level["smallBlind"].as_i64().unwrap()
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_edit_structure_levels() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("structeditor_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Structure Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Structure Event").await;
+    sqlx::query(
+        "INSERT INTO tournament_structures \
+             (tournament_id, level_number, small_blind, big_blind, ante, duration_minutes) \
+         VALUES ($1, 1, 100, 200, 0, 20), ($1, 2, 200, 400, 0, 20), ($1, 3, 300, 600, 0, 20)",
+    )
+    .bind(tournament_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    let run = |query: &str, vars: Value| {
+        let query = query.to_string();
+        let schema = schema.clone();
+        let claims = manager_claims.clone();
+        let mut vars = vars;
+        vars["tournamentId"] = json!(tournament_id.to_string());
+        async move {
+            execute_graphql(
+                &schema,
+                &query,
+                Some(Variables::from_json(vars)),
+                Some(claims),
+            )
+            .await
+        }
+    };
+    let fields = "levelNumber smallBlind durationMinutes";
+
+    let add = format!(
+        "mutation($tournamentId: ID!, $level: TournamentStructureInput!) {{ \
+            addTournamentLevel(tournamentId: $tournamentId, level: $level) {{ {fields} }} }}"
+    );
+    let response = run(&add, json!({ "level": level(2, 150, 20) })).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        small_blinds(&response.data.into_json().unwrap()["addTournamentLevel"]),
+        vec![100, 150, 200, 300]
+    );
+
+    // Past the end appends.
+    let response = run(&add, json!({ "level": level(9, 400, 20) })).await;
+    assert_eq!(
+        small_blinds(&response.data.into_json().unwrap()["addTournamentLevel"]),
+        vec![100, 150, 200, 300, 400]
+    );
+
+    let response = run(
+        r#"mutation($tournamentId: ID!) {
+            moveTournamentLevel(tournamentId: $tournamentId, levelNumber: 5, toLevelNumber: 2) {
+                smallBlind levelNumber
+            }
+        }"#,
+        json!({}),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        small_blinds(&response.data.into_json().unwrap()["moveTournamentLevel"]),
+        vec![100, 400, 150, 200, 300]
+    );
+
+    let delete = r#"
+        mutation($tournamentId: ID!, $levelNumber: Int!) {
+            deleteTournamentLevel(tournamentId: $tournamentId, levelNumber: $levelNumber) {
+                smallBlind levelNumber
+            }
+        }
+    "#;
+    let response = run(delete, json!({ "levelNumber": 2 })).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        small_blinds(&response.data.into_json().unwrap()["deleteTournamentLevel"]),
+        vec![100, 150, 200, 300]
+    );
+
+    let response = run(
+        &add,
+        json!({ "level": { "levelNumber": 2, "smallBlind": 300, "bigBlind": 200,
+        "ante": 0, "durationMinutes": 20, "isBreak": false } }),
+    )
+    .await;
+    assert!(response.errors[0].message.contains("big blind"));
+
+    // Once level 1 is running it is locked, bar its blinds.
+    let response = run(
+        r#"mutation($tournamentId: ID!) { startTournamentClock(tournamentId: $tournamentId) { status } }"#,
+        json!({}),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let response = run(delete, json!({ "levelNumber": 1 })).await;
+    let code = response.errors[0].extensions.as_ref().unwrap().get("code");
+    assert_eq!(
+        code,
+        Some(&async_graphql::Value::String("LEVEL_LOCKED".into()))
+    );
+    let response = run(&add, json!({ "level": level(1, 50, 20) })).await;
+    assert!(!response.errors.is_empty());
+
+    let update = format!(
+        "mutation($tournamentId: ID!, $level: TournamentStructureInput!) {{ \
+            updateTournamentLevel(tournamentId: $tournamentId, level: $level) {{ {fields} }} }}"
+    );
+    let response = run(&update, json!({ "level": level(1, 100, 30) })).await;
+    assert!(response.errors[0]
+        .message
+        .contains("only have its blinds changed"));
+    let response = run(&update, json!({ "level": level(1, 125, 20) })).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        small_blinds(&response.data.into_json().unwrap()["updateTournamentLevel"]),
+        vec![125, 150, 200, 300]
+    );
+}
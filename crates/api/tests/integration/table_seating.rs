@@ -415,6 +415,293 @@ async fn test_update_stack_size() {
     assert_eq!(assignment["userId"], player_id.to_string());
 }
 
+#[tokio::test]
+async fn test_update_stack_sizes_bulk() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("bulkstackmanager_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let (player_a, _) = create_test_user(
+        &app_state,
+        &format!("bulkstack_a_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let (player_b, _) = create_test_user(
+        &app_state,
+        &format!("bulkstack_b_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let (unseated, _) = create_test_user(
+        &app_state,
+        &format!("bulkstack_unseated_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Bulk Stack Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Bulk Stack Tournament").await;
+
+    let club_table_id = Uuid::new_v4();
+    sqlx::query!(
+        "INSERT INTO club_tables (id, club_id, table_number, max_seats) VALUES ($1, $2, $3, $4)",
+        club_table_id,
+        club_id,
+        1,
+        9
+    )
+    .execute(&app_state.db)
+    .await
+    .expect("Failed to create club table");
+
+    sqlx::query!(
+        "INSERT INTO tournament_table_assignments (tournament_id, club_table_id) VALUES ($1, $2)",
+        tournament_id,
+        club_table_id
+    )
+    .execute(&app_state.db)
+    .await
+    .expect("Failed to create test table");
+
+    for (seat, player) in [(1, player_a), (2, player_b)] {
+        sqlx::query!(
+            "INSERT INTO table_seat_assignments (tournament_id, club_table_id, user_id, seat_number, stack_size) VALUES ($1, $2, $3, $4, $5)",
+            tournament_id,
+            club_table_id,
+            player,
+            seat,
+            20000
+        )
+        .execute(&app_state.db)
+        .await
+        .expect("Failed to create initial seat assignment");
+    }
+
+    let query = r#"
+        mutation UpdateStackSizes($input: UpdateStackSizesInput!) {
+            updateStackSizes(input: $input) {
+                userId
+                stackSize
+            }
+        }
+    "#;
+
+    let variables = Variables::from_json(json!({
+        "input": {
+            "tournamentId": tournament_id.to_string(),
+            "stacks": [
+                { "userId": player_a.to_string(), "stack": 31500 },
+                { "userId": player_b.to_string(), "stack": 8500 }
+            ]
+        }
+    }));
+    let response = execute_graphql(
+        &schema,
+        query,
+        Some(variables),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(
+        response.errors.is_empty(),
+        "Bulk stack update should succeed: {:?}",
+        response.errors
+    );
+    let data = response.data.into_json().unwrap();
+    let updated = data["updateStackSizes"].as_array().unwrap();
+    assert_eq!(updated.len(), 2);
+    assert_eq!(updated[0]["userId"], player_a.to_string());
+    assert_eq!(updated[0]["stackSize"], 31500);
+    assert_eq!(updated[1]["stackSize"], 8500);
+
+    let history: Vec<(Option<i32>, i32, String)> = sqlx::query_as(
+        "SELECT h.previous_stack, h.stack_size, h.source FROM tournament_stack_history h \
+         JOIN club_player cp ON cp.id = h.club_player_id \
+         WHERE h.tournament_id = $1 AND cp.app_user_id = $2",
+    )
+    .bind(tournament_id)
+    .bind(player_a)
+    .fetch_all(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(
+        history,
+        vec![(Some(20000), 31500, "break_count".to_string())]
+    );
+
+    // One unseated player rejects the whole batch.
+    let variables = Variables::from_json(json!({
+        "input": {
+            "tournamentId": tournament_id.to_string(),
+            "stacks": [
+                { "userId": player_a.to_string(), "stack": 1000 },
+                { "userId": unseated.to_string(), "stack": 1000 }
+            ]
+        }
+    }));
+    let response = execute_graphql(&schema, query, Some(variables), Some(manager_claims)).await;
+    assert!(
+        !response.errors.is_empty(),
+        "Batch with an unseated player should fail"
+    );
+
+    let stack: Option<i32> = sqlx::query_scalar(
+        "SELECT stack_size FROM table_seat_assignments \
+         WHERE tournament_id = $1 AND user_id = $2 AND is_current = true",
+    )
+    .bind(tournament_id)
+    .bind(player_a)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(stack, Some(31500), "failed batch must not partially apply");
+}
+
+#[tokio::test]
+async fn test_record_color_up_races_odd_chips() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("colorupmanager_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Color Up Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Color Up Tournament").await;
+
+    sqlx::query(
+        "INSERT INTO tournament_structures \
+         (tournament_id, level_number, small_blind, big_blind, ante, duration_minutes, color_up_denomination) \
+         VALUES ($1, 2, 100, 200, 0, 20, 100)",
+    )
+    .bind(tournament_id)
+    .execute(&app_state.db)
+    .await
+    .expect("Failed to configure color-up");
+
+    let club_table_id = Uuid::new_v4();
+    sqlx::query!(
+        "INSERT INTO club_tables (id, club_id, table_number, max_seats) VALUES ($1, $2, $3, $4)",
+        club_table_id,
+        club_id,
+        1,
+        9
+    )
+    .execute(&app_state.db)
+    .await
+    .expect("Failed to create club table");
+
+    sqlx::query!(
+        "INSERT INTO tournament_table_assignments (tournament_id, club_table_id) VALUES ($1, $2)",
+        tournament_id,
+        club_table_id
+    )
+    .execute(&app_state.db)
+    .await
+    .expect("Failed to create test table");
+
+    // Odd chips 25 + 50 + 75 = 150 → two new 100 chips, to seats 3 and 2.
+    let mut players = Vec::new();
+    for (seat, stack) in [(1, 1025), (2, 2050), (3, 75)] {
+        let (player, _) = create_test_user(
+            &app_state,
+            &format!("colorup_p{seat}_{suffix}@test.com"),
+            "player",
+        )
+        .await;
+        sqlx::query!(
+            "INSERT INTO table_seat_assignments (tournament_id, club_table_id, user_id, seat_number, stack_size) VALUES ($1, $2, $3, $4, $5)",
+            tournament_id,
+            club_table_id,
+            player,
+            seat,
+            stack
+        )
+        .execute(&app_state.db)
+        .await
+        .expect("Failed to create initial seat assignment");
+        players.push(player);
+    }
+
+    let query = r#"
+        mutation RecordColorUp($input: RecordColorUpInput!) {
+            recordColorUp(input: $input) {
+                denomination
+                rule
+                chipsBefore
+                chipsAfter
+                reconciled
+                tables { chipsBefore chipsAfter uncountedSeats allSeatsCounted }
+                adjustedAssignments { userId stackSize }
+            }
+        }
+    "#;
+    let variables = Variables::from_json(json!({
+        "input": { "tournamentId": tournament_id.to_string(), "level": 2 }
+    }));
+    let response = execute_graphql(
+        &schema,
+        query,
+        Some(variables.clone()),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(
+        response.errors.is_empty(),
+        "Color-up should succeed: {:?}",
+        response.errors
+    );
+    let data = response.data.into_json().unwrap();
+    let result = &data["recordColorUp"];
+    assert_eq!(result["denomination"], 100);
+    assert_eq!(result["rule"], "RACE");
+    assert_eq!(result["chipsBefore"], 3150);
+    assert_eq!(result["chipsAfter"], 3200);
+    assert_eq!(result["tables"][0]["uncountedSeats"], 0);
+    assert_eq!(result["tables"][0]["allSeatsCounted"], true);
+    // No entries were recorded, so the counted chips can't match what's in play.
+    assert_eq!(result["reconciled"], false);
+    assert_eq!(result["adjustedAssignments"].as_array().unwrap().len(), 3);
+
+    let stacks: Vec<Option<i32>> = sqlx::query_scalar(
+        "SELECT stack_size FROM table_seat_assignments \
+         WHERE tournament_id = $1 AND is_current = true ORDER BY seat_number",
+    )
+    .bind(tournament_id)
+    .fetch_all(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(stacks, vec![Some(1000), Some(2100), Some(100)]);
+
+    let history_rows: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM tournament_stack_history \
+         WHERE tournament_id = $1 AND source = 'color_up'",
+    )
+    .bind(tournament_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(history_rows, 3);
+
+    // A level can only be colored up once.
+    let response = execute_graphql(&schema, query, Some(variables), Some(manager_claims)).await;
+    assert!(
+        !response.errors.is_empty(),
+        "Second color-up of the same level should fail"
+    );
+}
+
 #[tokio::test]
 async fn test_balance_tables() {
     let app_state = setup_test_db().await;
@@ -762,3 +1049,1016 @@ async fn test_seat_assignment_filtering() {
         assert_eq!(assignments[0]["player"]["id"], player_id.to_string());
     }
 }
+
+#[tokio::test]
+async fn test_seat_change_request_approve_and_decline() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("seatchangemanager_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Seat Change Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Seat Change Tournament").await;
+
+    let club_table_id = Uuid::new_v4();
+    sqlx::query!(
+        "INSERT INTO club_tables (id, club_id, table_number, max_seats) VALUES ($1, $2, $3, $4)",
+        club_table_id,
+        club_id,
+        1,
+        9
+    )
+    .execute(&app_state.db)
+    .await
+    .expect("Failed to create club table");
+
+    sqlx::query!(
+        "INSERT INTO tournament_table_assignments (tournament_id, club_table_id) VALUES ($1, $2)",
+        tournament_id,
+        club_table_id
+    )
+    .execute(&app_state.db)
+    .await
+    .expect("Failed to create test table");
+
+    let mut players = Vec::new();
+    for seat in [1, 2] {
+        let (player, claims) = create_test_user(
+            &app_state,
+            &format!("seatchange_p{seat}_{suffix}@test.com"),
+            "player",
+        )
+        .await;
+        sqlx::query!(
+            "INSERT INTO table_seat_assignments (tournament_id, club_table_id, user_id, seat_number, stack_size) VALUES ($1, $2, $3, $4, $5)",
+            tournament_id,
+            club_table_id,
+            player,
+            seat,
+            10000
+        )
+        .execute(&app_state.db)
+        .await
+        .expect("Failed to create initial seat assignment");
+        players.push((player, claims));
+    }
+
+    let request_query = r#"
+        mutation RequestSeatChange($input: RequestSeatChangeInput!) {
+            requestSeatChange(input: $input) { id status fromSeatNumber reason }
+        }
+    "#;
+    let mut request_ids = Vec::new();
+    for (_, claims) in &players {
+        let variables = Variables::from_json(json!({
+            "input": { "tournamentId": tournament_id.to_string(), "reason": "Needs an aisle seat" }
+        }));
+        let response = execute_graphql(
+            &schema,
+            request_query,
+            Some(variables),
+            Some(claims.clone()),
+        )
+        .await;
+        assert!(
+            response.errors.is_empty(),
+            "Request should succeed: {:?}",
+            response.errors
+        );
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["requestSeatChange"]["status"], "PENDING");
+        request_ids.push(
+            data["requestSeatChange"]["id"]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        );
+    }
+
+    // Only one open request per player.
+    let variables = Variables::from_json(json!({
+        "input": { "tournamentId": tournament_id.to_string(), "reason": "Again" }
+    }));
+    let response = execute_graphql(
+        &schema,
+        request_query,
+        Some(variables),
+        Some(players[0].1.clone()),
+    )
+    .await;
+    assert!(!response.errors.is_empty(), "Duplicate request should fail");
+
+    // Players can't see the queue; the manager sees both, oldest first.
+    let queue_query = r#"
+        query Queue($tournamentId: ID!) {
+            seatChangeRequests(tournamentId: $tournamentId) { id userId status }
+        }
+    "#;
+    let variables = Variables::from_json(json!({ "tournamentId": tournament_id.to_string() }));
+    let response = execute_graphql(
+        &schema,
+        queue_query,
+        Some(variables.clone()),
+        Some(players[0].1.clone()),
+    )
+    .await;
+    assert!(
+        !response.errors.is_empty(),
+        "Players must not see the queue"
+    );
+
+    let response = execute_graphql(
+        &schema,
+        queue_query,
+        Some(variables.clone()),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let queue = data["seatChangeRequests"].as_array().unwrap();
+    assert_eq!(queue.len(), 2);
+    assert_eq!(queue[0]["id"], request_ids[0].as_str());
+
+    let approve_query = r#"
+        mutation Approve($input: ApproveSeatChangeInput!) {
+            approveSeatChange(input: $input) { status resolvedBy }
+        }
+    "#;
+
+    // A table outside the tournament, or a seat off the table, is refused
+    // and leaves the request pending.
+    let spare_table_id = create_test_club_table(&app_state, club_id, 2, 9).await;
+    for (table_id, seat) in [(spare_table_id, 5), (club_table_id, 10)] {
+        let variables = Variables::from_json(json!({
+            "input": {
+                "requestId": request_ids[0],
+                "newClubTableId": table_id.to_string(),
+                "newSeatNumber": seat
+            }
+        }));
+        let response = execute_graphql(
+            &schema,
+            approve_query,
+            Some(variables),
+            Some(manager_claims.clone()),
+        )
+        .await;
+        assert!(!response.errors.is_empty(), "seat {seat} should be refused");
+    }
+    let status: String =
+        sqlx::query_scalar("SELECT status FROM seat_change_requests WHERE id = $1::uuid")
+            .bind(&request_ids[0])
+            .fetch_one(&app_state.db)
+            .await
+            .unwrap();
+    assert_eq!(status, "pending");
+
+    // Approving moves the first player to seat 5.
+    let variables = Variables::from_json(json!({
+        "input": {
+            "requestId": request_ids[0],
+            "newClubTableId": club_table_id.to_string(),
+            "newSeatNumber": 5
+        }
+    }));
+    let response = execute_graphql(
+        &schema,
+        approve_query,
+        Some(variables.clone()),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(
+        response.errors.is_empty(),
+        "Approve should succeed: {:?}",
+        response.errors
+    );
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["approveSeatChange"]["status"], "APPROVED");
+    assert_eq!(
+        data["approveSeatChange"]["resolvedBy"],
+        manager_id.to_string()
+    );
+
+    let seat: i32 = sqlx::query_scalar(
+        "SELECT seat_number FROM table_seat_assignments \
+         WHERE tournament_id = $1 AND user_id = $2 AND is_current = true",
+    )
+    .bind(tournament_id)
+    .bind(players[0].0)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(seat, 5);
+
+    // A resolved request can't be approved twice.
+    let response = execute_graphql(
+        &schema,
+        approve_query,
+        Some(variables),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(!response.errors.is_empty(), "Double approval should fail");
+
+    // Declining records the reason and leaves the player where they are.
+    let decline_query = r#"
+        mutation Decline($input: DeclineSeatChangeInput!) {
+            declineSeatChange(input: $input) { status declineReason }
+        }
+    "#;
+    let variables = Variables::from_json(json!({
+        "input": { "requestId": request_ids[1], "reason": "No aisle seats free" }
+    }));
+    let response = execute_graphql(
+        &schema,
+        decline_query,
+        Some(variables),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["declineSeatChange"]["status"], "DECLINED");
+    assert_eq!(
+        data["declineSeatChange"]["declineReason"],
+        "No aisle seats free"
+    );
+
+    // The pending queue is now empty.
+    let variables = Variables::from_json(json!({ "tournamentId": tournament_id.to_string() }));
+    let response =
+        execute_graphql(&schema, queue_query, Some(variables), Some(manager_claims)).await;
+    let data = response.data.into_json().unwrap();
+    assert!(data["seatChangeRequests"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_seating_chart_reads_one_snapshot() {
+    use infra::repos::table_seat_assignments;
+
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (player_id, claims) =
+        create_test_user(&app_state, &format!("snapshot_{unique}@test.com"), "player").await;
+    let club_id = create_test_club(&app_state, "Snapshot Club").await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Snapshot Tournament").await;
+    let table_one = create_test_club_table(&app_state, club_id, 1, 9).await;
+    let table_two = create_test_club_table(&app_state, club_id, 2, 9).await;
+    assign_table_to_tournament(&app_state, tournament_id, table_one).await;
+    assign_table_to_tournament(&app_state, tournament_id, table_two).await;
+    create_test_registration(&app_state, tournament_id, player_id, "seated").await;
+    sqlx::query(
+        "INSERT INTO table_seat_assignments (tournament_id, club_table_id, user_id, seat_number) \
+         VALUES ($1, $2, $3, 4)",
+    )
+    .bind(tournament_id)
+    .bind(table_one)
+    .bind(player_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    let mut snapshot = infra::db::begin_snapshot(&app_state.db).await.unwrap();
+    let before = table_seat_assignments::seating_chart(&mut *snapshot, tournament_id)
+        .await
+        .unwrap();
+    let seats = |chart: &table_seat_assignments::SeatingChartRows| -> Vec<(Uuid, i32)> {
+        chart
+            .tables
+            .iter()
+            .flat_map(|t| t.seats.iter())
+            .map(|s| (s.assignment.club_table_id, s.assignment.seat_number))
+            .collect()
+    };
+    assert_eq!(seats(&before), vec![(table_one, 4)]);
+
+    // The player moves tables while the chart is being read.
+    sqlx::query(
+        "UPDATE table_seat_assignments SET is_current = false, unassigned_at = NOW() \
+         WHERE tournament_id = $1 AND user_id = $2",
+    )
+    .bind(tournament_id)
+    .bind(player_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO table_seat_assignments (tournament_id, club_table_id, user_id, seat_number) \
+         VALUES ($1, $2, $3, 2)",
+    )
+    .bind(tournament_id)
+    .bind(table_two)
+    .bind(player_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    let during = table_seat_assignments::seating_chart(&mut *snapshot, tournament_id)
+        .await
+        .unwrap();
+    assert_eq!(seats(&during), vec![(table_one, 4)]);
+    assert!(during.unassigned.is_empty());
+    snapshot.commit().await.unwrap();
+
+    // A fresh read sees the move, with the player listed exactly once.
+    let response = execute_graphql(
+        &schema,
+        r#"query($id: UUID!) {
+            tournamentSeatingChart(tournamentId: $id) {
+                tables { table { tableNumber } seats { assignment { seatNumber } player { id } } }
+                unassignedPlayers { clubPlayerId }
+            }
+        }"#,
+        Some(Variables::from_json(json!({ "id": tournament_id }))),
+        Some(claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let chart = &data["tournamentSeatingChart"];
+    let seated: Vec<(i64, i64)> = chart["tables"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|t| {
+            let number = t["table"]["tableNumber"].as_i64().unwrap();
+            t["seats"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(move |s| (number, s["assignment"]["seatNumber"].as_i64().unwrap()))
+        })
+        .collect();
+    assert_eq!(seated, vec![(2, 2)]);
+    assert_eq!(
+        chart["tables"][1]["seats"][0]["player"]["id"],
+        json!(player_id.to_string())
+    );
+    assert_eq!(chart["unassignedPlayers"], json!([]));
+}
+
+/// Playing 8-handed on 10-seat tables: the seat draw fills seats 1-8 only,
+/// the club tables keep their size, and the cap can't strand seated players.
+#[tokio::test]
+async fn test_seats_per_table_caps_the_draw() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) =
+        create_test_user(&app_state, "eight_max_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Eight Max Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Eight Max").await;
+    for number in [1, 2] {
+        let table_id = create_test_club_table(&app_state, club_id, number, 10).await;
+        assign_table_to_tournament(&app_state, tournament_id, table_id).await;
+    }
+    for i in 0..16 {
+        let (user_id, _) =
+            create_test_user(&app_state, &format!("eight_max_p{i}@test.com"), "player").await;
+        create_test_registration(&app_state, tournament_id, user_id, "checked_in").await;
+    }
+
+    let set_seats = r#"mutation($id: ID!, $seats: Int) {
+        setTournamentSeatsPerTable(tournamentId: $id, seatsPerTable: $seats) { seatsPerTable }
+    }"#;
+    let seats_vars = |seats: i32| {
+        Variables::from_json(json!({ "id": tournament_id.to_string(), "seats": seats }))
+    };
+    let resp = execute_graphql(
+        &schema,
+        set_seats,
+        Some(seats_vars(8)),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "set seats: {:?}", resp.errors);
+    assert_eq!(
+        resp.data.into_json().unwrap()["setTournamentSeatsPerTable"]["seatsPerTable"],
+        8
+    );
+
+    let resp = execute_graphql(
+        &schema,
+        "query($id: UUID!) { tournamentTables(tournamentId: $id) { maxSeats } }",
+        Some(Variables::from_json(
+            json!({ "id": tournament_id.to_string() }),
+        )),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "tables: {:?}", resp.errors);
+    let tables = resp.data.into_json().unwrap()["tournamentTables"].clone();
+    assert!(tables
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|t| t["maxSeats"] == 8));
+
+    // The seat draw.
+    sqlx::query("UPDATE tournaments SET live_status = 'registration_open' WHERE id = $1")
+        .bind(tournament_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($input: UpdateTournamentStatusInput!) {
+            updateTournamentStatus(input: $input) { liveStatus }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": { "tournamentId": tournament_id.to_string(), "liveStatus": "LATE_REGISTRATION" }
+        }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "draw: {:?}", resp.errors);
+
+    let seats: Vec<i32> = sqlx::query_scalar(
+        "SELECT seat_number FROM table_seat_assignments WHERE tournament_id = $1 AND is_current",
+    )
+    .bind(tournament_id)
+    .fetch_all(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(seats.len(), 16);
+    assert!(seats.iter().all(|&s| s <= 8), "seats: {seats:?}");
+
+    let physical: Vec<i32> =
+        sqlx::query_scalar("SELECT max_seats FROM club_tables WHERE club_id = $1")
+            .bind(club_id)
+            .fetch_all(&app_state.db)
+            .await
+            .unwrap();
+    assert!(physical.iter().all(|&s| s == 10));
+
+    // Going 6-handed would leave players in seats 7 and 8 off the table.
+    let resp = execute_graphql(&schema, set_seats, Some(seats_vars(6)), Some(manager)).await;
+    assert!(
+        resp.errors[0].message.contains("move them"),
+        "{:?}",
+        resp.errors
+    );
+}
+
+/// Late in the event the tables close seats to play equally short-handed;
+/// closed seats show on the chart and can't be sat in until reopened.
+#[tokio::test]
+async fn test_closed_seats_keep_tables_even() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) =
+        create_test_user(&app_state, "short_handed_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Short Handed Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Short Handed").await;
+    let mut table_ids = Vec::new();
+    for number in [1, 2] {
+        let table_id = create_test_club_table(&app_state, club_id, number, 4).await;
+        assign_table_to_tournament(&app_state, tournament_id, table_id).await;
+        table_ids.push(table_id);
+    }
+
+    let seat = r#"mutation($input: AssignPlayerToSeatInput!) {
+        assignPlayerToSeat(input: $input) { id }
+    }"#;
+    let seat_vars = |user_id: Uuid, table: usize, seat_number: i32| {
+        Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "clubTableId": table_ids[table].to_string(),
+                "userId": user_id.to_string(),
+                "seatNumber": seat_number,
+            }
+        }))
+    };
+    // Five players: 3 + 2, so both tables should play 3-handed.
+    for (i, (table, seat_number)) in [(0, 1), (0, 2), (0, 3), (1, 1), (1, 2)]
+        .into_iter()
+        .enumerate()
+    {
+        let (user_id, _) =
+            create_test_user(&app_state, &format!("short_handed_p{i}@test.com"), "player").await;
+        let resp = execute_graphql(
+            &schema,
+            seat,
+            Some(seat_vars(user_id, table, seat_number)),
+            Some(manager.clone()),
+        )
+        .await;
+        assert!(resp.errors.is_empty(), "seat {i}: {:?}", resp.errors);
+    }
+
+    let tid_vars = || Variables::from_json(json!({ "id": tournament_id.to_string() }));
+    let resp = execute_graphql(
+        &schema,
+        "query($id: ID!) { seatClosureRecommendations(tournamentId: $id) { tableNumber closeSeats reopenSeats seatsAfter } }",
+        Some(tid_vars()),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "recommend: {:?}", resp.errors);
+    let plans = resp.data.into_json().unwrap()["seatClosureRecommendations"].clone();
+    assert_eq!(
+        plans,
+        json!([
+            { "tableNumber": 1, "closeSeats": [4], "reopenSeats": [], "seatsAfter": 3 },
+            { "tableNumber": 2, "closeSeats": [4], "reopenSeats": [], "seatsAfter": 3 },
+        ])
+    );
+
+    let resp = execute_graphql(
+        &schema,
+        "mutation($id: ID!) { applySeatClosureRecommendations(tournamentId: $id) { tableNumber } }",
+        Some(tid_vars()),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "apply: {:?}", resp.errors);
+
+    let resp = execute_graphql(
+        &schema,
+        "query($id: UUID!) { tournamentSeatingChart(tournamentId: $id) { tables { table { tableNumber } closedSeats } } }",
+        Some(tid_vars()),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "chart: {:?}", resp.errors);
+    let tables = resp.data.into_json().unwrap()["tournamentSeatingChart"]["tables"].clone();
+    assert!(tables
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|t| t["closedSeats"] == json!([4])));
+
+    // Nobody sits in a closed seat.
+    let (late_id, _) = create_test_user(&app_state, "short_handed_late@test.com", "player").await;
+    let resp = execute_graphql(
+        &schema,
+        seat,
+        Some(seat_vars(late_id, 1, 4)),
+        Some(manager.clone()),
+    )
+    .await;
+    assert_eq!(resp.errors[0].message, "Seat is closed");
+
+    let change = |mutation: &str, table: usize, seats: Vec<i32>| {
+        let query = format!("mutation($input: TableSeatsInput!) {{ {mutation}(input: $input) }}");
+        let vars = Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "clubTableId": table_ids[table].to_string(),
+                "seatNumbers": seats,
+            }
+        }));
+        (query, vars)
+    };
+    let (query, vars) = change("closeTableSeats", 0, vec![1]);
+    let resp = execute_graphql(&schema, &query, Some(vars), Some(manager.clone())).await;
+    assert!(
+        resp.errors[0].message.contains("occupied"),
+        "{:?}",
+        resp.errors
+    );
+    let (query, vars) = change("closeTableSeats", 0, vec![5]);
+    let resp = execute_graphql(&schema, &query, Some(vars), Some(manager.clone())).await;
+    assert_eq!(resp.errors[0].message, "Table 1 has no seat 5");
+
+    let (query, vars) = change("reopenTableSeats", 1, vec![4]);
+    let resp = execute_graphql(&schema, &query, Some(vars), Some(manager.clone())).await;
+    assert!(resp.errors.is_empty(), "reopen: {:?}", resp.errors);
+    assert_eq!(
+        resp.data.into_json().unwrap()["reopenTableSeats"],
+        json!([])
+    );
+    let resp = execute_graphql(&schema, seat, Some(seat_vars(late_id, 1, 4)), Some(manager)).await;
+    assert!(resp.errors.is_empty(), "reopened seat: {:?}", resp.errors);
+}
+
+/// The chart judges balance against the seats in play: four players can't
+/// fit on one table once each table has closed its fourth seat.
+#[tokio::test]
+async fn test_chart_balance_counts_closed_seats() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) =
+        create_test_user(&app_state, "closed_balance_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Closed Balance Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Closed Balance").await;
+    let mut table_ids = Vec::new();
+    for number in [1, 2] {
+        let table_id = create_test_club_table(&app_state, club_id, number, 4).await;
+        assign_table_to_tournament(&app_state, tournament_id, table_id).await;
+        table_ids.push(table_id);
+    }
+    for (i, (table, seat_number)) in [(0, 1), (0, 2), (0, 3), (1, 1)].into_iter().enumerate() {
+        let (user_id, _) = create_test_user(
+            &app_state,
+            &format!("closed_balance_p{i}@test.com"),
+            "player",
+        )
+        .await;
+        let resp = execute_graphql(
+            &schema,
+            r#"mutation($input: AssignPlayerToSeatInput!) { assignPlayerToSeat(input: $input) { id } }"#,
+            Some(Variables::from_json(json!({
+                "input": {
+                    "tournamentId": tournament_id.to_string(),
+                    "clubTableId": table_ids[table].to_string(),
+                    "userId": user_id.to_string(),
+                    "seatNumber": seat_number,
+                }
+            }))),
+            Some(manager.clone()),
+        )
+        .await;
+        assert!(resp.errors.is_empty(), "seat {i}: {:?}", resp.errors);
+    }
+
+    let chart = || {
+        let schema = schema.clone();
+        let manager = manager.clone();
+        async move {
+            let resp = execute_graphql(
+                &schema,
+                "query($id: UUID!) { tournamentSeatingChart(tournamentId: $id) { needsConsolidation suggestedTableCount } }",
+                Some(Variables::from_json(json!({ "id": tournament_id.to_string() }))),
+                Some(manager),
+            )
+            .await;
+            assert!(resp.errors.is_empty(), "chart: {:?}", resp.errors);
+            resp.data.into_json().unwrap()["tournamentSeatingChart"].clone()
+        }
+    };
+
+    // Four 4-max seats per table: the field fits on one.
+    let balance = chart().await;
+    assert_eq!(balance["needsConsolidation"], true);
+    assert_eq!(balance["suggestedTableCount"], 1);
+
+    for table_id in &table_ids {
+        let resp = execute_graphql(
+            &schema,
+            "mutation($input: TableSeatsInput!) { closeTableSeats(input: $input) }",
+            Some(Variables::from_json(json!({
+                "input": {
+                    "tournamentId": tournament_id.to_string(),
+                    "clubTableId": table_id.to_string(),
+                    "seatNumbers": [4],
+                }
+            }))),
+            Some(manager.clone()),
+        )
+        .await;
+        assert!(resp.errors.is_empty(), "close: {:?}", resp.errors);
+    }
+
+    // Three seats in play per table: four players need both.
+    let balance = chart().await;
+    assert_eq!(balance["needsConsolidation"], false);
+    assert_eq!(balance["suggestedTableCount"], 2);
+}
+
+/// The floor marks a table as breaking soon with a note; the chart shows it.
+#[tokio::test]
+async fn test_set_table_status_shows_on_chart() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) =
+        create_test_user(&app_state, "table_status_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Table Status Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Table Status").await;
+    let table_id = create_test_club_table(&app_state, club_id, 3, 9).await;
+    assign_table_to_tournament(&app_state, tournament_id, table_id).await;
+    let spare_id = create_test_club_table(&app_state, club_id, 4, 9).await;
+    let (_, player) = create_test_user(&app_state, "table_status_p@test.com", "player").await;
+
+    let set_status = r#"mutation($input: SetTableStatusInput!) {
+        setTableStatus(input: $input) { tableNumber status notes updatedAt }
+    }"#;
+    let vars = |table: Uuid, status: &str, notes: Option<&str>| {
+        Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "clubTableId": table.to_string(),
+                "status": status,
+                "notes": notes,
+            }
+        }))
+    };
+
+    let resp = execute_graphql(
+        &schema,
+        set_status,
+        Some(vars(table_id, "BREAKING_SOON", Some("  New deck needed "))),
+        Some(player),
+    )
+    .await;
+    assert!(!resp.errors.is_empty(), "players can't set table status");
+
+    let resp = execute_graphql(
+        &schema,
+        set_status,
+        Some(vars(table_id, "BREAKING_SOON", Some("  New deck needed "))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "set status: {:?}", resp.errors);
+    let set = resp.data.into_json().unwrap()["setTableStatus"].clone();
+    assert_eq!(set["tableNumber"], 3);
+    assert_eq!(set["status"], "BREAKING_SOON");
+    assert_eq!(set["notes"], "New deck needed");
+    assert!(set["updatedAt"].is_string());
+
+    let chart = r#"query($id: UUID!) {
+        tournamentSeatingChart(tournamentId: $id) { tables { status notes } }
+    }"#;
+    let chart_vars = || Variables::from_json(json!({ "id": tournament_id.to_string() }));
+    let resp = execute_graphql(&schema, chart, Some(chart_vars()), Some(manager.clone())).await;
+    assert!(resp.errors.is_empty(), "chart: {:?}", resp.errors);
+    let tables = resp.data.into_json().unwrap()["tournamentSeatingChart"]["tables"].clone();
+    assert_eq!(
+        tables,
+        json!([{ "status": "BREAKING_SOON", "notes": "New deck needed" }])
+    );
+
+    // Blank notes clear them.
+    let resp = execute_graphql(
+        &schema,
+        set_status,
+        Some(vars(table_id, "OPEN", Some(" "))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "reopen: {:?}", resp.errors);
+    let resp = execute_graphql(&schema, chart, Some(chart_vars()), Some(manager.clone())).await;
+    let tables = resp.data.into_json().unwrap()["tournamentSeatingChart"]["tables"].clone();
+    assert_eq!(tables, json!([{ "status": "OPEN", "notes": null }]));
+
+    // Only tables in play in the tournament.
+    let resp = execute_graphql(
+        &schema,
+        set_status,
+        Some(vars(spare_id, "CLOSED", None)),
+        Some(manager),
+    )
+    .await;
+    assert_eq!(
+        resp.errors[0].message,
+        "Table is not assigned to this tournament"
+    );
+}
+
+#[tokio::test]
+async fn test_seat_conflict_names_the_occupant() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) =
+        create_test_user(&app_state, "seat_conflict_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Seat Conflict Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Seat Conflict").await;
+    let table_id = create_test_club_table(&app_state, club_id, 1, 9).await;
+    assign_table_to_tournament(&app_state, tournament_id, table_id).await;
+    let (first_id, _) = create_test_user(&app_state, "seat_conflict_p1@test.com", "player").await;
+    let (second_id, _) = create_test_user(&app_state, "seat_conflict_p2@test.com", "player").await;
+
+    let seat = r#"mutation($input: AssignPlayerToSeatInput!) {
+        assignPlayerToSeat(input: $input) { id assignedAt }
+    }"#;
+    let seat_vars = |user_id: Uuid, seat_number: i32| {
+        Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "clubTableId": table_id.to_string(),
+                "userId": user_id.to_string(),
+                "seatNumber": seat_number,
+            }
+        }))
+    };
+    let resp = execute_graphql(
+        &schema,
+        seat,
+        Some(seat_vars(first_id, 3)),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "seat first: {:?}", resp.errors);
+    let first_seat = resp.data.into_json().unwrap()["assignPlayerToSeat"].clone();
+
+    let occupant_of = |resp: &async_graphql::Response| {
+        let error = &resp.errors[0];
+        let extensions = error.extensions.as_ref().expect("extensions");
+        assert_eq!(
+            extensions.get("code"),
+            Some(&async_graphql::Value::String("SEAT_OCCUPIED".into()))
+        );
+        (
+            error.message.clone(),
+            extensions
+                .get("occupant")
+                .unwrap()
+                .clone()
+                .into_json()
+                .unwrap(),
+        )
+    };
+
+    let resp = execute_graphql(
+        &schema,
+        seat,
+        Some(seat_vars(second_id, 3)),
+        Some(manager.clone()),
+    )
+    .await;
+    let (message, occupant) = occupant_of(&resp);
+    assert_eq!(occupant["assignmentId"], first_seat["id"]);
+    assert_eq!(occupant["tournamentId"], tournament_id.to_string());
+    assert_eq!(occupant["clubTableId"], table_id.to_string());
+    assert_eq!(occupant["seatNumber"], 3);
+    assert_eq!(occupant["userId"], first_id.to_string());
+    assert!(occupant["clubPlayerId"].is_string());
+    let name = occupant["name"].as_str().unwrap();
+    assert_eq!(message, format!("Seat is already occupied by {name}"));
+    assert_eq!(
+        occupant["assignedAt"]
+            .as_str()
+            .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok()),
+        first_seat["assignedAt"]
+            .as_str()
+            .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+    );
+
+    // Moving onto the taken seat reports the same occupant.
+    let resp = execute_graphql(
+        &schema,
+        seat,
+        Some(seat_vars(second_id, 5)),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "seat second: {:?}", resp.errors);
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($input: MovePlayerInput!) { movePlayer(input: $input) { id } }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "userId": second_id.to_string(),
+                "newClubTableId": table_id.to_string(),
+                "newSeatNumber": 3,
+            }
+        }))),
+        Some(manager),
+    )
+    .await;
+    let (message, occupant) = occupant_of(&resp);
+    assert!(message.starts_with("Target seat is already occupied by "));
+    assert_eq!(occupant["userId"], first_id.to_string());
+}
+
+#[tokio::test]
+async fn test_swap_seats_trades_two_players_at_once() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) =
+        create_test_user(&app_state, "seat_swap_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Seat Swap Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Seat Swap").await;
+    let mut table_ids = Vec::new();
+    for number in [1, 2] {
+        let table_id = create_test_club_table(&app_state, club_id, number, 9).await;
+        assign_table_to_tournament(&app_state, tournament_id, table_id).await;
+        table_ids.push(table_id);
+    }
+    let (first_id, player) = create_test_user(&app_state, "seat_swap_p1@test.com", "player").await;
+    let (second_id, _) = create_test_user(&app_state, "seat_swap_p2@test.com", "player").await;
+    let (standing_id, _) = create_test_user(&app_state, "seat_swap_p3@test.com", "player").await;
+
+    let seat = r#"mutation($input: AssignPlayerToSeatInput!) {
+        assignPlayerToSeat(input: $input) { id }
+    }"#;
+    for (user_id, table, seat_number, stack) in
+        [(first_id, 0, 2, 12_000), (second_id, 1, 7, 30_000)]
+    {
+        let resp = execute_graphql(
+            &schema,
+            seat,
+            Some(Variables::from_json(json!({
+                "input": {
+                    "tournamentId": tournament_id.to_string(),
+                    "clubTableId": table_ids[table].to_string(),
+                    "userId": user_id.to_string(),
+                    "seatNumber": seat_number,
+                    "stackSize": stack,
+                }
+            }))),
+            Some(manager.clone()),
+        )
+        .await;
+        assert!(resp.errors.is_empty(), "seat: {:?}", resp.errors);
+    }
+
+    let swap = r#"mutation($tournamentId: ID!, $userA: ID!, $userB: ID!) {
+        swapSeats(tournamentId: $tournamentId, userA: $userA, userB: $userB) {
+            userId clubTableId seatNumber stackSize isCurrent
+        }
+    }"#;
+    let swap_vars = |a: Uuid, b: Uuid| {
+        Variables::from_json(json!({
+            "tournamentId": tournament_id.to_string(),
+            "userA": a.to_string(),
+            "userB": b.to_string(),
+        }))
+    };
+
+    let resp = execute_graphql(
+        &schema,
+        swap,
+        Some(swap_vars(first_id, second_id)),
+        Some(player),
+    )
+    .await;
+    assert!(!resp.errors.is_empty(), "players can't swap seats");
+
+    let resp = execute_graphql(
+        &schema,
+        swap,
+        Some(swap_vars(first_id, second_id)),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "swap: {:?}", resp.errors);
+    let swapped = resp.data.into_json().unwrap()["swapSeats"].clone();
+    assert_eq!(
+        swapped,
+        json!([
+            { "userId": first_id.to_string(), "clubTableId": table_ids[1].to_string(), "seatNumber": 7, "stackSize": 12000, "isCurrent": true },
+            { "userId": second_id.to_string(), "clubTableId": table_ids[0].to_string(), "seatNumber": 2, "stackSize": 30000, "isCurrent": true },
+        ])
+    );
+
+    let current: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM table_seat_assignments WHERE tournament_id = $1 AND is_current",
+    )
+    .bind(tournament_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(current, 2);
+    let logged: Vec<(Option<Uuid>, serde_json::Value)> = sqlx::query_as(
+        "SELECT subject_id, metadata FROM tournament_activity_log \
+         WHERE tournament_id = $1 AND event_action = 'seats_swapped'",
+    )
+    .bind(tournament_id)
+    .fetch_all(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(logged.len(), 1);
+    assert_eq!(logged[0].0, Some(first_id));
+    assert_eq!(logged[0].1["other_user_id"], second_id.to_string());
+    assert_eq!(logged[0].1["from"]["seat_number"], 2);
+    assert_eq!(logged[0].1["to"]["seat_number"], 7);
+
+    // Both players must be seated, and be two different players.
+    let resp = execute_graphql(
+        &schema,
+        swap,
+        Some(swap_vars(first_id, standing_id)),
+        Some(manager.clone()),
+    )
+    .await;
+    assert_eq!(
+        resp.errors[0].message,
+        "The second player isn't seated in this tournament"
+    );
+    let resp = execute_graphql(
+        &schema,
+        swap,
+        Some(swap_vars(first_id, first_id)),
+        Some(manager),
+    )
+    .await;
+    assert_eq!(
+        resp.errors[0].message,
+        "A player can't swap seats with themselves"
+    );
+}
@@ -0,0 +1,132 @@
+//! Access-token claims (role, managed clubs, token version) and revocation:
+//! a role or club-assignment change must invalidate tokens minted before it.
+
+use crate::common::{create_club_manager, create_test_club, create_test_user, setup_test_db};
+
+#[tokio::test]
+async fn minted_token_carries_role_clubs_and_version() {
+    let app = setup_test_db().await;
+    let (manager_id, _) = create_test_user(&app, "claims-manager@test.dev", "manager").await;
+    let club_id = create_test_club(&app, "Claims Club").await;
+    create_club_manager(&app, manager_id, club_id).await;
+
+    let token = app
+        .jwt_service()
+        .create_token(&app.db, manager_id)
+        .await
+        .expect("token minted");
+    let claims = app
+        .jwt_service()
+        .verify_current(&app.db, &token)
+        .await
+        .expect("fresh token is current");
+
+    assert_eq!(claims.sub, manager_id.to_string());
+    assert_eq!(claims.role, "manager");
+    assert_eq!(claims.club_ids, vec![club_id]);
+}
+
+#[tokio::test]
+async fn role_change_revokes_existing_tokens() {
+    let app = setup_test_db().await;
+    let (user_id, _) = create_test_user(&app, "claims-demoted@test.dev", "manager").await;
+    let token = app
+        .jwt_service()
+        .create_token(&app.db, user_id)
+        .await
+        .unwrap();
+
+    sqlx::query("UPDATE users SET role = 'player' WHERE id = $1")
+        .bind(user_id)
+        .execute(&app.db)
+        .await
+        .unwrap();
+
+    assert!(
+        app.jwt_service()
+            .verify_current(&app.db, &token)
+            .await
+            .is_err(),
+        "a token minted before the demotion must be rejected"
+    );
+
+    // The refresh flow mints a token reflecting the new role.
+    let token = app
+        .jwt_service()
+        .create_token(&app.db, user_id)
+        .await
+        .unwrap();
+    let claims = app
+        .jwt_service()
+        .verify_current(&app.db, &token)
+        .await
+        .expect("re-minted token is current");
+    assert_eq!(claims.role, "player");
+}
+
+#[tokio::test]
+async fn removing_a_club_assignment_revokes_existing_tokens() {
+    let app = setup_test_db().await;
+    let (manager_id, _) = create_test_user(&app, "claims-unassigned@test.dev", "manager").await;
+    let club_id = create_test_club(&app, "Claims Unassign Club").await;
+    create_club_manager(&app, manager_id, club_id).await;
+    let token = app
+        .jwt_service()
+        .create_token(&app.db, manager_id)
+        .await
+        .unwrap();
+
+    sqlx::query("UPDATE club_managers SET is_active = false WHERE user_id = $1 AND club_id = $2")
+        .bind(manager_id)
+        .bind(club_id)
+        .execute(&app.db)
+        .await
+        .unwrap();
+
+    assert!(app
+        .jwt_service()
+        .verify_current(&app.db, &token)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn unrelated_profile_edits_keep_tokens_valid() {
+    let app = setup_test_db().await;
+    let (user_id, _) = create_test_user(&app, "claims-rename@test.dev", "player").await;
+    let token = app
+        .jwt_service()
+        .create_token(&app.db, user_id)
+        .await
+        .unwrap();
+
+    sqlx::query("UPDATE users SET first_name = 'Renamed' WHERE id = $1")
+        .bind(user_id)
+        .execute(&app.db)
+        .await
+        .unwrap();
+
+    assert!(app
+        .jwt_service()
+        .verify_current(&app.db, &token)
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn deactivated_users_cannot_be_issued_tokens() {
+    let app = setup_test_db().await;
+    let (user_id, _) = create_test_user(&app, "claims-inactive@test.dev", "player").await;
+
+    sqlx::query("UPDATE users SET is_active = false WHERE id = $1")
+        .bind(user_id)
+        .execute(&app.db)
+        .await
+        .unwrap();
+
+    assert!(app
+        .jwt_service()
+        .create_token(&app.db, user_id)
+        .await
+        .is_err());
+}
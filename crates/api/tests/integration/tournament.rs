@@ -1,7 +1,10 @@
 use crate::common::*;
 use api::gql::build_schema;
-use async_graphql::Variables;
+use async_graphql::{Request, Variables};
+use fixtures::Fixtures;
+use futures_util::StreamExt;
 use serde_json::json;
+use uuid::Uuid;
 
 #[tokio::test]
 async fn test_register_for_tournament() {
@@ -11,14 +14,7 @@ async fn test_register_for_tournament() {
     let (user_id, claims) = create_test_user(&app_state, "playerreg@test.com", "player").await;
     let club_id = create_test_club(&app_state, "Registration Club").await;
     let tournament_id =
-        create_test_tournament(&app_state, club_id, "Registration Tournament").await;
-
-    // Open registration so the player can register
-    sqlx::query("UPDATE tournaments SET live_status = 'registration_open'::tournament_live_status WHERE id = $1")
-        .bind(tournament_id)
-        .execute(&app_state.db)
-        .await
-        .expect("Failed to open registration");
+        create_open_tournament(&app_state, club_id, "Registration Tournament").await;
 
     let query = r#"
         mutation RegisterForTournament($input: RegisterForTournamentInput!) {
@@ -53,6 +49,74 @@ async fn test_register_for_tournament() {
     assert_eq!(registration["userId"], user_id.to_string());
 }
 
+#[tokio::test]
+async fn test_register_twice_returns_existing_registration() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (user_id, claims) = create_test_user(&app_state, "doubletap@test.com", "player").await;
+    let club_id = create_test_club(&app_state, "Double Tap Club").await;
+    let tournament_id = create_open_tournament(&app_state, club_id, "Double Tap Tournament").await;
+
+    let query = r#"
+        mutation RegisterForTournament($input: RegisterForTournamentInput!) {
+            registerForTournament(input: $input) { id status }
+        }
+    "#;
+    let register = || {
+        execute_graphql(
+            &schema,
+            query,
+            Some(Variables::from_json(json!({
+                "input": { "tournamentId": tournament_id.to_string() }
+            }))),
+            Some(claims.clone()),
+        )
+    };
+
+    // Both of two concurrent submissions succeed with the same registration.
+    let (first, second) = tokio::join!(register(), register());
+    assert!(first.errors.is_empty(), "{:?}", first.errors);
+    assert!(second.errors.is_empty(), "{:?}", second.errors);
+    let first = first.data.into_json().unwrap();
+    let second = second.data.into_json().unwrap();
+    assert_eq!(
+        first["registerForTournament"]["id"],
+        second["registerForTournament"]["id"]
+    );
+    let count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM tournament_registrations WHERE tournament_id = $1 AND user_id = $2",
+    )
+    .bind(tournament_id)
+    .bind(user_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(count, 1);
+
+    // A cancelled registration isn't silently handed back.
+    sqlx::query(
+        "UPDATE tournament_registrations SET status = 'cancelled' WHERE tournament_id = $1",
+    )
+    .bind(tournament_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+    let response = register().await;
+    let code = response
+        .errors
+        .first()
+        .and_then(|e| e.extensions.as_ref())
+        .and_then(|x| x.get("code"))
+        .cloned();
+    assert_eq!(
+        code,
+        Some(async_graphql::Value::String("ALREADY_REGISTERED".into())),
+        "{:?}",
+        response.errors
+    );
+}
+
 #[tokio::test]
 async fn test_create_tournament_with_rake() {
     let app_state = setup_test_db().await;
@@ -405,3 +469,483 @@ async fn test_create_tournament_without_recurrence_makes_one() {
         "no recurrence should create exactly one tournament"
     );
 }
+
+#[tokio::test]
+async fn create_tournament_validates_input_and_logs_creation() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager_claims) =
+        create_test_user(&app_state, "create_validate_manager@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Create Validation Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+
+    let base = json!({
+        "clubId": club_id.to_string(),
+        "name": "Friday Deepstack",
+        "description": "200 BB deep",
+        "startTime": "2026-11-06T19:00:00Z",
+        "endTime": "2026-11-07T01:00:00Z",
+        "buyInCents": 5000,
+        "rakeCents": 500,
+        "seatCap": 60,
+        "startingStack": 20000,
+        "earlyBirdBonusChips": 5000,
+        "lateRegistrationLevel": 6
+    });
+
+    let invalid = [
+        ("name", json!("   "), "name can't be empty"),
+        ("endTime", json!("2026-11-06T18:00:00Z"), "End time"),
+        ("buyInCents", json!(-100), "can't be negative"),
+        ("rakeCents", json!(6000), "Rake can't be higher"),
+        ("seatCap", json!(0), "Seat cap"),
+        ("startingStack", json!(0), "Starting stack"),
+        ("earlyBirdBonusChips", json!(-1), "Bonus chips"),
+        ("lateRegistrationLevel", json!(0), "Late registration"),
+    ];
+    for (field, value, message) in invalid {
+        let mut input = base.clone();
+        input[field] = value;
+        let response = execute_graphql(
+            &schema,
+            CREATE_RECURRING_MUTATION,
+            Some(Variables::from_json(json!({ "input": input }))),
+            Some(manager_claims.clone()),
+        )
+        .await;
+        assert!(
+            response
+                .errors
+                .first()
+                .is_some_and(|e| e.message.contains(message)),
+            "{field} should be rejected with {message:?}: {:?}",
+            response.errors
+        );
+    }
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tournaments WHERE club_id = $1")
+        .bind(club_id)
+        .fetch_one(&app_state.db)
+        .await
+        .unwrap();
+    assert_eq!(count, 0, "rejected input must not create a tournament");
+
+    let response = execute_graphql(
+        &schema,
+        CREATE_RECURRING_MUTATION,
+        Some(Variables::from_json(json!({ "input": base }))),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "create: {:?}", response.errors);
+    let tournament_id: Uuid = response.data.into_json().unwrap()["createTournament"]["id"]
+        .as_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+
+    let db = &app_state.db;
+    let (actor_id, metadata): (Option<Uuid>, serde_json::Value) = eventually(|| async move {
+        sqlx::query_as(
+            "SELECT actor_id, metadata FROM tournament_activity_log \
+             WHERE tournament_id = $1 AND event_category = 'tournament' AND event_action = 'created'",
+        )
+        .bind(tournament_id)
+        .fetch_optional(db)
+        .await
+        .unwrap()
+    })
+    .await;
+    assert_eq!(actor_id, Some(manager_id));
+    assert_eq!(metadata["name"], "Friday Deepstack");
+    assert_eq!(metadata["buy_in_cents"], 5000);
+}
+
+#[tokio::test]
+async fn update_tournament_rejects_stale_edits_and_invalid_settings() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager_claims) =
+        create_test_user(&app_state, "stale_edit_manager@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Stale Edit Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Stale Edit Cup").await;
+
+    let mutation = r#"
+        mutation UpdateTournament($input: UpdateTournamentInput!) {
+            updateTournament(input: $input) { id title updatedAt }
+        }
+    "#;
+    let update = |input: serde_json::Value| {
+        let schema = schema.clone();
+        let claims = manager_claims.clone();
+        async move {
+            execute_graphql(
+                &schema,
+                mutation,
+                Some(Variables::from_json(json!({ "input": input }))),
+                Some(claims),
+            )
+            .await
+        }
+    };
+
+    // The buy-in in the fixture is 5000: a rake above it is refused.
+    let response = update(json!({ "id": tournament_id.to_string(), "rakeCents": 6000 })).await;
+    assert!(
+        response.errors[0].message.contains("Rake can't be higher"),
+        "{:?}",
+        response.errors
+    );
+
+    let loaded_at: chrono::DateTime<chrono::Utc> =
+        sqlx::query_scalar("SELECT updated_at FROM tournaments WHERE id = $1")
+            .bind(tournament_id)
+            .fetch_one(&app_state.db)
+            .await
+            .unwrap();
+
+    let response = update(json!({
+        "id": tournament_id.to_string(),
+        "name": "Renamed Cup",
+        "expectedUpdatedAt": loaded_at,
+    }))
+    .await;
+    assert!(response.errors.is_empty(), "update: {:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["updateTournament"]["title"], "Renamed Cup");
+
+    // A second edit made from the same (now stale) copy is rejected.
+    let response = update(json!({
+        "id": tournament_id.to_string(),
+        "name": "Other Name",
+        "expectedUpdatedAt": loaded_at,
+    }))
+    .await;
+    assert!(
+        response.errors[0]
+            .message
+            .contains("changed since you loaded it"),
+        "{:?}",
+        response.errors
+    );
+
+    let db = &app_state.db;
+    let fields: serde_json::Value = eventually(|| async move {
+        sqlx::query_scalar(
+            "SELECT metadata->'fields' FROM tournament_activity_log \
+             WHERE tournament_id = $1 AND event_action = 'updated'",
+        )
+        .bind(tournament_id)
+        .fetch_optional(db)
+        .await
+        .unwrap()
+    })
+    .await;
+    assert_eq!(fields, json!(["name"]));
+}
+
+#[tokio::test]
+async fn delete_tournament_soft_deletes_unless_results_exist() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager_claims) =
+        create_test_user(&app_state, "delete_tournament_manager@test.com", "manager").await;
+    let (player_id, player_claims) =
+        create_test_user(&app_state, "delete_tournament_player@test.com", "player").await;
+    let club_id = create_test_club(&app_state, "Delete Tournament Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let mistake = create_test_tournament(&app_state, club_id, "Created By Mistake").await;
+    let played = create_test_tournament(&app_state, club_id, "Already Played").await;
+    sqlx::query(
+        "INSERT INTO tournament_results (tournament_id, user_id, final_position, prize_cents) \
+         VALUES ($1, $2, 1, 0)",
+    )
+    .bind(played)
+    .bind(player_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    let mutation = "mutation($id: ID!) { deleteTournament(id: $id) }";
+    let delete = |id: Uuid, claims| {
+        let schema = schema.clone();
+        async move {
+            execute_graphql(
+                &schema,
+                mutation,
+                Some(Variables::from_json(json!({ "id": id.to_string() }))),
+                Some(claims),
+            )
+            .await
+        }
+    };
+
+    let response = delete(mistake, player_claims).await;
+    assert!(
+        !response.errors.is_empty(),
+        "players can't delete tournaments"
+    );
+
+    let response = delete(played, manager_claims.clone()).await;
+    assert!(
+        response.errors[0].message.contains("with results"),
+        "{:?}",
+        response.errors
+    );
+
+    let response = delete(mistake, manager_claims.clone()).await;
+    assert!(response.errors.is_empty(), "delete: {:?}", response.errors);
+    assert_eq!(response.data.into_json().unwrap()["deleteTournament"], true);
+
+    // The row stays, marked deleted, but lookups no longer find it.
+    let deleted_by: Option<Uuid> =
+        sqlx::query_scalar("SELECT deleted_by FROM tournaments WHERE id = $1")
+            .bind(mistake)
+            .fetch_one(&app_state.db)
+            .await
+            .unwrap();
+    assert_eq!(deleted_by, Some(manager_id));
+
+    let query = format!(r#"query {{ tournament(id: "{mistake}") {{ id }} }}"#);
+    let response = execute_graphql(&schema, &query, None, Some(manager_claims.clone())).await;
+    let data = response.data.into_json().unwrap();
+    assert!(
+        !response.errors.is_empty() || data["tournament"].is_null(),
+        "a deleted tournament is not found"
+    );
+
+    let response = delete(mistake, manager_claims).await;
+    assert!(!response.errors.is_empty(), "deleting twice fails");
+}
+
+#[tokio::test]
+async fn clone_tournament_copies_settings_structure_and_tables() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager_claims) =
+        create_test_user(&app_state, "clone_tournament_manager@test.com", "manager").await;
+    let (_, player_claims) =
+        create_test_user(&app_state, "clone_tournament_player@test.com", "player").await;
+    let club_id = create_test_club(&app_state, "Clone Tournament Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+
+    // Last week's edition: finished, with a structure and two tables, one
+    // short-handed.
+    let source = create_test_tournament(&app_state, club_id, "Thursday Deepstack").await;
+    sqlx::query(
+        "UPDATE tournaments SET live_status = 'finished', rake_cents = 500, seat_cap = 60, \
+         starting_stack = 30000, end_time = start_time + INTERVAL '6 hours' WHERE id = $1",
+    )
+    .bind(source)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+    for (level, small_blind) in [(1, 100), (2, 200)] {
+        sqlx::query(
+            "INSERT INTO tournament_structures \
+             (tournament_id, level_number, small_blind, big_blind, ante, duration_minutes) \
+             VALUES ($1, $2, $3, $3 * 2, 0, 20)",
+        )
+        .bind(source)
+        .bind(level)
+        .bind(small_blind)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    }
+    let table_one = create_test_club_table(&app_state, club_id, 1, 9).await;
+    let table_two = create_test_club_table(&app_state, club_id, 2, 9).await;
+    for (table, seats) in [(table_one, Some(6)), (table_two, None)] {
+        sqlx::query(
+            "INSERT INTO tournament_table_assignments \
+             (tournament_id, club_table_id, max_seats_override) VALUES ($1, $2, $3)",
+        )
+        .bind(source)
+        .bind(table)
+        .bind(seats)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    }
+    // Table 2 is meanwhile booked by a tournament that hasn't finished.
+    let other = create_test_tournament(&app_state, club_id, "Satellite").await;
+    sqlx::query(
+        "INSERT INTO tournament_table_assignments (tournament_id, club_table_id) VALUES ($1, $2)",
+    )
+    .bind(other)
+    .bind(table_two)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    let mutation = r#"
+        mutation($input: CloneTournamentInput!) {
+            cloneTournament(input: $input) { id title startTime endTime buyInCents seatCap }
+        }
+    "#;
+    let start_time = chrono::Utc::now() + chrono::Duration::days(7);
+    let vars = || {
+        Some(Variables::from_json(json!({
+            "input": { "tournamentId": source.to_string(), "startTime": start_time.to_rfc3339() }
+        })))
+    };
+
+    let response = execute_graphql(&schema, mutation, vars(), Some(player_claims)).await;
+    assert!(
+        !response.errors.is_empty(),
+        "players can't clone tournaments"
+    );
+
+    let response = execute_graphql(&schema, mutation, vars(), Some(manager_claims)).await;
+    assert!(response.errors.is_empty(), "clone: {:?}", response.errors);
+    let cloned = response.data.into_json().unwrap()["cloneTournament"].clone();
+    assert_eq!(cloned["title"], "Thursday Deepstack");
+    assert_eq!(cloned["buyInCents"], 5000);
+    assert_eq!(cloned["seatCap"], 60);
+    let clone_id: Uuid = cloned["id"].as_str().unwrap().parse().unwrap();
+    assert_ne!(clone_id, source);
+
+    let (start, end, rake, stack, live_status): (
+        chrono::DateTime<chrono::Utc>,
+        Option<chrono::DateTime<chrono::Utc>>,
+        i64,
+        Option<i32>,
+        String,
+    ) = sqlx::query_as(
+        "SELECT start_time, end_time, rake_cents, starting_stack, live_status::text \
+         FROM tournaments WHERE id = $1",
+    )
+    .bind(clone_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(start.timestamp(), start_time.timestamp());
+    assert_eq!(end.map(|end| end - start), Some(chrono::Duration::hours(6)));
+    assert_eq!((rake, stack), (500, Some(30000)));
+    assert_ne!(live_status, "finished");
+
+    let levels: Vec<(i32, i32)> = sqlx::query_as(
+        "SELECT level_number, small_blind FROM tournament_structures \
+         WHERE tournament_id = $1 ORDER BY level_number",
+    )
+    .bind(clone_id)
+    .fetch_all(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(levels, vec![(1, 100), (2, 200)]);
+
+    // The short-handed table comes along; the booked one is left out.
+    let tables: Vec<(Uuid, Option<i32>)> = sqlx::query_as(
+        "SELECT club_table_id, max_seats_override FROM tournament_table_assignments \
+         WHERE tournament_id = $1 AND is_active = true",
+    )
+    .bind(clone_id)
+    .fetch_all(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(tables, vec![(table_one, Some(6))]);
+
+    let db = &app_state.db;
+    let cloned_from: Option<String> = eventually(|| async move {
+        sqlx::query_scalar(
+            "SELECT metadata->>'cloned_from' FROM tournament_activity_log \
+             WHERE tournament_id = $1 AND event_category = 'tournament' AND event_action = 'created'",
+        )
+        .bind(clone_id)
+        .fetch_optional(db)
+        .await
+        .unwrap()
+    })
+    .await;
+    assert_eq!(cloned_from, Some(source.to_string()));
+}
+
+#[tokio::test]
+async fn lobby_screens_follow_status_changes_and_full_tournaments() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let unique = Uuid::new_v4();
+
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("lobby_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let (_, player_claims) = create_test_user(
+        &app_state,
+        &format!("lobby_player_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Lobby Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = Fixtures::random()
+        .tournament(club_id)
+        .name("Lobby Deepstack")
+        .seat_cap(1)
+        .create(&app_state.db)
+        .await
+        .expect("Failed to create test tournament");
+
+    // Streams subscribe on first poll, so start listening before changing.
+    let mut stream = schema.execute_stream(
+        Request::new(format!(
+            "subscription {{ clubTournamentsUpdates(clubId: \"{club_id}\") {{ eventType tournamentId liveStatus seatCap }} }}"
+        ))
+        .data(player_claims.clone()),
+    );
+    let events = tokio::spawn(async move {
+        let mut events = Vec::new();
+        while events.len() < 2 {
+            events.push(stream.next().await.expect("schedule event"));
+        }
+        events
+    });
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    let response = execute_graphql(
+        &schema,
+        "mutation($input: UpdateTournamentStatusInput!) { updateTournamentStatus(input: $input) { id } }",
+        Some(Variables::from_json(json!({
+            "input": { "tournamentId": tournament_id.to_string(), "liveStatus": "REGISTRATION_OPEN" }
+        }))),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    // The only seat goes: the lobby shows the tournament as full.
+    let response = execute_graphql(
+        &schema,
+        "mutation($input: RegisterForTournamentInput!) { registerForTournament(input: $input) { id } }",
+        Some(Variables::from_json(json!({
+            "input": { "tournamentId": tournament_id.to_string() }
+        }))),
+        Some(player_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let events = tokio::time::timeout(std::time::Duration::from_secs(5), events)
+        .await
+        .expect("schedule events in time")
+        .unwrap();
+    let events: Vec<_> = events
+        .into_iter()
+        .map(|e| {
+            assert!(e.errors.is_empty(), "{:?}", e.errors);
+            e.data.into_json().unwrap()["clubTournamentsUpdates"].clone()
+        })
+        .collect();
+    assert_eq!(events[0]["eventType"], "STATUS_CHANGED");
+    assert_eq!(events[0]["liveStatus"], "REGISTRATION_OPEN");
+    assert_eq!(events[1]["eventType"], "FULL");
+    assert_eq!(events[1]["tournamentId"], tournament_id.to_string());
+    assert_eq!(events[1]["seatCap"], 1);
+}
@@ -0,0 +1,272 @@
+//! Cancelling a tournament: registrations cancelled, seats cleared, entries
+//! voided, the prize pool emptied and desk payments left as pending refunds.
+
+use api::gql::build_schema;
+use async_graphql::Variables;
+use fixtures::{Fixtures, TournamentLiveStatus};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::common::*;
+
+const CANCEL: &str = r#"
+    mutation($input: CancelTournamentInput!) {
+        cancelTournament(input: $input) { id status liveStatus }
+    }
+"#;
+
+const REFUNDS: &str = r#"
+    query($tournamentId: ID!) {
+        tournamentRefunds(tournamentId: $tournamentId) {
+            id playerName amountCents entryCount paymentMethod status
+        }
+    }
+"#;
+
+async fn insert_entry(
+    app: &api::state::AppState,
+    tournament_id: Uuid,
+    user_id: Uuid,
+    entry_type: &str,
+    amount_cents: i64,
+    payment_method: &str,
+) {
+    sqlx::query(
+        "INSERT INTO tournament_entries (tournament_id, user_id, entry_type, amount_cents, payment_method) \
+         VALUES ($1, $2, $3, $4, $5)",
+    )
+    .bind(tournament_id)
+    .bind(user_id)
+    .bind(entry_type)
+    .bind(amount_cents)
+    .bind(payment_method)
+    .execute(&app.db)
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_cancel_tournament_refunds_and_releases_everyone() {
+    let app = setup_test_db().await;
+    let schema = build_schema(app.clone());
+    let unique = Uuid::new_v4().simple().to_string();
+
+    let (manager_id, manager) =
+        create_test_user(&app, &format!("cancel_mgr_{unique}@test.com"), "manager").await;
+    let club_id = create_test_club(&app, "Cancellation Club").await;
+    create_club_manager(&app, manager_id, club_id).await;
+    let tournament_id = Fixtures::random()
+        .tournament(club_id)
+        .name("Flooded Friday")
+        .live_status(TournamentLiveStatus::LateRegistration)
+        .create(&app.db)
+        .await
+        .unwrap();
+    let table_id = create_test_club_table(&app, club_id, 1, 9).await;
+    assign_table_to_tournament(&app, tournament_id, table_id).await;
+
+    // One player paid cash and card at the desk and is seated; the other
+    // played on a voucher.
+    let (paid_id, paid) =
+        create_test_user(&app, &format!("cancel_p1_{unique}@test.com"), "player").await;
+    create_test_registration(&app, tournament_id, paid_id, "seated").await;
+    insert_entry(&app, tournament_id, paid_id, "initial", 5000, "cash").await;
+    insert_entry(&app, tournament_id, paid_id, "addon", 1000, "card").await;
+    sqlx::query(
+        "INSERT INTO table_seat_assignments (tournament_id, club_table_id, user_id, seat_number) VALUES ($1, $2, $3, 4)",
+    )
+    .bind(tournament_id)
+    .bind(table_id)
+    .bind(paid_id)
+    .execute(&app.db)
+    .await
+    .unwrap();
+    let (voucher_id, _) =
+        create_test_user(&app, &format!("cancel_p2_{unique}@test.com"), "player").await;
+    create_test_registration(&app, tournament_id, voucher_id, "registered").await;
+    insert_entry(&app, tournament_id, voucher_id, "initial", 5000, "voucher").await;
+    // Late registration closed: the payout table is frozen.
+    sqlx::query("UPDATE tournament_payouts SET finalized_at = NOW() WHERE tournament_id = $1")
+        .bind(tournament_id)
+        .execute(&app.db)
+        .await
+        .unwrap();
+
+    let cancel_vars = || {
+        Variables::from_json(json!({
+            "input": { "tournamentId": tournament_id.to_string(), "reason": "  Venue flooded " }
+        }))
+    };
+
+    // Players can't cancel.
+    let res = execute_graphql(&schema, CANCEL, Some(cancel_vars()), Some(paid.clone())).await;
+    assert!(!res.errors.is_empty());
+
+    let res = execute_graphql(&schema, CANCEL, Some(cancel_vars()), Some(manager.clone())).await;
+    assert!(res.errors.is_empty(), "cancel: {:?}", res.errors);
+    let cancelled = res.data.into_json().unwrap()["cancelTournament"].clone();
+    assert_eq!(cancelled["liveStatus"], "CANCELLED");
+    assert_eq!(cancelled["status"], "COMPLETED");
+
+    let statuses: Vec<String> =
+        sqlx::query_scalar("SELECT status FROM tournament_registrations WHERE tournament_id = $1")
+            .bind(tournament_id)
+            .fetch_all(&app.db)
+            .await
+            .unwrap();
+    assert_eq!(statuses, vec!["cancelled", "cancelled"]);
+    let (seated, entries, voided): (i64, i64, i64) = sqlx::query_as(
+        "SELECT (SELECT COUNT(*) FROM table_seat_assignments WHERE tournament_id = $1 AND is_current), \
+                (SELECT COUNT(*) FROM tournament_entries WHERE tournament_id = $1), \
+                (SELECT COUNT(*) FROM tournament_entries WHERE tournament_id = $1 AND voided_at IS NOT NULL)",
+    )
+    .bind(tournament_id)
+    .fetch_one(&app.db)
+    .await
+    .unwrap();
+    assert_eq!((seated, entries, voided), (0, 3, 3));
+    // The frozen payout table was reopened and emptied with the entries.
+    let (prize_pool, finalized): (i64, bool) = sqlx::query_as(
+        "SELECT total_prize_pool, finalized_at IS NOT NULL FROM tournament_payouts WHERE tournament_id = $1",
+    )
+    .bind(tournament_id)
+    .fetch_one(&app.db)
+    .await
+    .unwrap();
+    assert_eq!((prize_pool, finalized), (0, false));
+    let reason: Option<String> = sqlx::query_scalar(
+        "SELECT metadata->>'reason' FROM tournament_activity_log \
+         WHERE tournament_id = $1 AND event_action = 'cancelled'",
+    )
+    .bind(tournament_id)
+    .fetch_one(&app.db)
+    .await
+    .unwrap();
+    assert_eq!(reason.as_deref(), Some("Venue flooded"));
+
+    // Only the desk payments are owed back, one refund per payment method.
+    let refunds_vars =
+        || Variables::from_json(json!({ "tournamentId": tournament_id.to_string() }));
+    let res = execute_graphql(
+        &schema,
+        REFUNDS,
+        Some(refunds_vars()),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "refunds: {:?}", res.errors);
+    let refunds = res.data.into_json().unwrap()["tournamentRefunds"].clone();
+    let refunds = refunds.as_array().unwrap();
+    assert_eq!(refunds.len(), 2);
+    let mut owed: Vec<(String, i64)> = refunds
+        .iter()
+        .map(|r| {
+            assert_eq!(r["status"], "PENDING");
+            assert_eq!(r["entryCount"], 1);
+            assert_eq!(r["playerName"], "Test User");
+            (
+                r["paymentMethod"].as_str().unwrap().to_string(),
+                r["amountCents"].as_i64().unwrap(),
+            )
+        })
+        .collect();
+    owed.sort();
+    assert_eq!(
+        owed,
+        vec![("CARD".to_string(), 1000), ("CASH".to_string(), 5000)]
+    );
+
+    let pay = r#"mutation($id: ID!) { markTournamentRefundPaid(refundId: $id) { status } }"#;
+    let pay_vars = || Variables::from_json(json!({ "id": refunds[0]["id"] }));
+    let res = execute_graphql(&schema, pay, Some(pay_vars()), Some(manager.clone())).await;
+    assert!(res.errors.is_empty(), "pay: {:?}", res.errors);
+    assert_eq!(
+        res.data.into_json().unwrap()["markTournamentRefundPaid"]["status"],
+        "REFUNDED"
+    );
+    let res = execute_graphql(&schema, pay, Some(pay_vars()), Some(manager.clone())).await;
+    assert!(!res.errors.is_empty());
+
+    // A cancelled tournament stays cancelled.
+    let res = execute_graphql(&schema, CANCEL, Some(cancel_vars()), Some(manager.clone())).await;
+    assert!(!res.errors.is_empty());
+    let res = execute_graphql(
+        &schema,
+        r#"mutation($input: UpdateTournamentStatusInput!) {
+            updateTournamentStatus(input: $input) { liveStatus }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": { "tournamentId": tournament_id.to_string(), "liveStatus": "IN_PROGRESS" }
+        }))),
+        Some(manager),
+    )
+    .await;
+    assert!(!res.errors.is_empty());
+}
+
+#[tokio::test]
+async fn test_entries_are_refused_after_cancellation() {
+    let app = setup_test_db().await;
+    let schema = build_schema(app.clone());
+    let unique = Uuid::new_v4().simple().to_string();
+
+    let (manager_id, manager) = create_test_user(
+        &app,
+        &format!("cancel_entry_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app, "Cancelled Entry Club").await;
+    create_club_manager(&app, manager_id, club_id).await;
+    let tournament_id = Fixtures::random()
+        .tournament(club_id)
+        .name("Called Off")
+        .live_status(TournamentLiveStatus::LateRegistration)
+        .create(&app.db)
+        .await
+        .unwrap();
+    let (player_id, _) =
+        create_test_user(&app, &format!("cancel_entry_p_{unique}@test.com"), "player").await;
+    create_test_registration(&app, tournament_id, player_id, "registered").await;
+
+    let res = execute_graphql(
+        &schema,
+        CANCEL,
+        Some(Variables::from_json(json!({
+            "input": { "tournamentId": tournament_id.to_string() }
+        }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(res.errors.is_empty(), "cancel: {:?}", res.errors);
+
+    let res = execute_graphql(
+        &schema,
+        r#"mutation($input: AddTournamentEntryInput!) {
+            addTournamentEntry(input: $input) { id }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "userId": player_id.to_string(),
+                "entryType": "INITIAL",
+                "amountCents": 5000
+            }
+        }))),
+        Some(manager),
+    )
+    .await;
+    let code = res
+        .errors
+        .first()
+        .and_then(|e| match e.extensions.as_ref()?.get("code")? {
+            async_graphql::Value::String(code) => Some(code.clone()),
+            _ => None,
+        });
+    assert_eq!(
+        code.as_deref(),
+        Some("TOURNAMENT_CANCELLED"),
+        "{:?}",
+        res.errors
+    );
+}
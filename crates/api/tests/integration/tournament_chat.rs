@@ -0,0 +1,184 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::{Request, Variables};
+use futures_util::StreamExt;
+use serde_json::json;
+
+const POST: &str = r#"
+    mutation($input: PostTournamentChatMessageInput!) {
+        postTournamentChatMessage(input: $input) { id body authorName }
+    }
+"#;
+
+const MESSAGES: &str = r#"
+    query($t: ID!, $p: PaginationInput) {
+        tournamentChatMessages(tournamentId: $t, pagination: $p) {
+            totalCount
+            hasNextPage
+            items { body isDeleted }
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn test_tournament_chat_with_moderation() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager) = create_test_user(
+        &app_state,
+        &format!("chat_mgr_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let (player_id, player) = create_test_user(
+        &app_state,
+        &format!("chat_player_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let (_, outsider) =
+        create_test_user(&app_state, &format!("chat_out_{suffix}@test.com"), "player").await;
+    let club_id = create_test_club(&app_state, "Chat Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Chatty Open").await;
+    create_test_registration(&app_state, tournament_id, player_id, "registered").await;
+
+    let post = |body: &str| {
+        Variables::from_json(json!({
+            "input": { "tournamentId": tournament_id.to_string(), "body": body }
+        }))
+    };
+
+    let response = execute_graphql(&schema, POST, Some(post("hi")), Some(outsider.clone())).await;
+    assert!(!response.errors.is_empty(), "outsiders can't post");
+    let response = execute_graphql(
+        &schema,
+        MESSAGES,
+        Some(Variables::from_json(
+            json!({ "t": tournament_id.to_string() }),
+        )),
+        Some(outsider),
+    )
+    .await;
+    assert!(!response.errors.is_empty(), "outsiders can't read");
+
+    // Subscribers see new messages live.
+    // Streams subscribe on first poll, so start listening before posting.
+    let mut stream = schema.execute_stream(
+        Request::new(format!(
+            "subscription {{ tournamentChat(tournamentId: \"{tournament_id}\") {{ eventType message {{ body }} }} }}"
+        ))
+        .data(player.clone()),
+    );
+    let next_event = tokio::spawn(async move { stream.next().await });
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    let response = execute_graphql(
+        &schema,
+        POST,
+        Some(post("  good luck all  ")),
+        Some(player.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let message = response.data.into_json().unwrap()["postTournamentChatMessage"].clone();
+    assert_eq!(message["body"], "good luck all");
+
+    let event = tokio::time::timeout(std::time::Duration::from_secs(5), next_event)
+        .await
+        .expect("chat event")
+        .unwrap()
+        .unwrap();
+    assert!(event.errors.is_empty(), "{:?}", event.errors);
+    let event = event.data.into_json().unwrap();
+    assert_eq!(event["tournamentChat"]["eventType"], "MESSAGE_POSTED");
+    assert_eq!(event["tournamentChat"]["message"]["body"], "good luck all");
+
+    let response =
+        execute_graphql(&schema, POST, Some(post("floor!")), Some(manager.clone())).await;
+    assert!(
+        response.errors.is_empty(),
+        "staff can post: {:?}",
+        response.errors
+    );
+
+    // Moderation: delete the player's message, then mute them.
+    let response = execute_graphql(
+        &schema,
+        "mutation($id: ID!) { deleteTournamentChatMessage(messageId: $id) { body isDeleted } }",
+        Some(Variables::from_json(json!({ "id": message["id"] }))),
+        Some(player.clone()),
+    )
+    .await;
+    assert!(!response.errors.is_empty(), "players can't moderate");
+    let response = execute_graphql(
+        &schema,
+        "mutation($id: ID!) { deleteTournamentChatMessage(messageId: $id) { body isDeleted } }",
+        Some(Variables::from_json(json!({ "id": message["id"] }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let deleted = response.data.into_json().unwrap()["deleteTournamentChatMessage"].clone();
+    assert_eq!(deleted, json!({ "body": "", "isDeleted": true }));
+
+    let response = execute_graphql(
+        &schema,
+        "mutation($input: MuteTournamentChatUserInput!) { muteTournamentChatUser(input: $input) { userId mutedUntil } }",
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "userId": player_id.to_string(),
+                "minutes": 10,
+                "reason": "spam",
+            }
+        }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let response = execute_graphql(&schema, POST, Some(post("again")), Some(player.clone())).await;
+    assert!(
+        response.errors[0].message.contains("muted"),
+        "{:?}",
+        response.errors
+    );
+
+    let response = execute_graphql(
+        &schema,
+        "mutation($t: ID!, $u: ID!) { unmuteTournamentChatUser(tournamentId: $t, userId: $u) }",
+        Some(Variables::from_json(json!({
+            "t": tournament_id.to_string(),
+            "u": player_id.to_string(),
+        }))),
+        Some(manager),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let response = execute_graphql(&schema, POST, Some(post("sorry")), Some(player.clone())).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let response = execute_graphql(
+        &schema,
+        MESSAGES,
+        Some(Variables::from_json(json!({
+            "t": tournament_id.to_string(),
+            "p": { "limit": 2 },
+        }))),
+        Some(player),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let page = response.data.into_json().unwrap()["tournamentChatMessages"].clone();
+    assert_eq!(page["totalCount"], 3);
+    assert_eq!(page["hasNextPage"], true);
+    assert_eq!(
+        page["items"],
+        json!([
+            { "body": "sorry", "isDeleted": false },
+            { "body": "floor!", "isDeleted": false },
+        ])
+    );
+}
@@ -328,3 +328,83 @@ async fn test_tournament_clock_unauthorized() {
     );
     assert!(response.errors[0].message.contains("Access denied"));
 }
+
+#[tokio::test]
+async fn test_clock_starts_with_play() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("playclock_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Play Clock Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let with_blinds = create_open_tournament(&app_state, club_id, "With Blinds").await;
+    let without_blinds = create_open_tournament(&app_state, club_id, "Without Blinds").await;
+    sqlx::query(
+        "INSERT INTO tournament_structures \
+             (tournament_id, level_number, small_blind, big_blind, ante, duration_minutes) \
+         VALUES ($1, 1, 100, 200, 0, 20), ($1, 2, 200, 400, 0, 20)",
+    )
+    .bind(with_blinds)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    for tournament_id in [with_blinds, without_blinds] {
+        let response = execute_graphql(
+            &schema,
+            r#"
+                mutation($input: UpdateTournamentStatusInput!) {
+                    updateTournamentStatus(input: $input) { liveStatus }
+                }
+            "#,
+            Some(Variables::from_json(json!({
+                "input": {
+                    "tournamentId": tournament_id.to_string(),
+                    "liveStatus": "LATE_REGISTRATION"
+                }
+            }))),
+            Some(manager_claims.clone()),
+        )
+        .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+    }
+
+    let clock = |tournament_id: uuid::Uuid| {
+        let schema = schema.clone();
+        async move {
+            let response = execute_graphql(
+                &schema,
+                r#"
+                    query($tournamentId: ID!) {
+                        tournamentClock(tournamentId: $tournamentId) {
+                            status currentLevel timeRemainingSeconds
+                        }
+                    }
+                "#,
+                Some(Variables::from_json(
+                    json!({ "tournamentId": tournament_id.to_string() }),
+                )),
+                None,
+            )
+            .await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+            response.data.into_json().unwrap()["tournamentClock"].clone()
+        }
+    };
+
+    // Level 1 is running with its full duration ahead.
+    let started = clock(with_blinds).await;
+    assert_eq!(started["status"], "RUNNING");
+    assert_eq!(started["currentLevel"], 1);
+    assert!(started["timeRemainingSeconds"].as_i64().unwrap() > 19 * 60);
+
+    // Without blinds there is nothing to run; the status change still goes
+    // through.
+    assert_eq!(clock(without_blinds).await["status"], "STOPPED");
+}
@@ -1,7 +1,9 @@
 use crate::common::*;
 use api::gql::build_schema;
-use async_graphql::Variables;
+use async_graphql::{Request, Variables};
+use futures_util::StreamExt;
 use serde_json::json;
+use uuid::Uuid;
 
 #[tokio::test]
 async fn test_add_tournament_entry_initial() {
@@ -505,7 +507,9 @@ async fn test_entry_stats_total_rake_with_rake_tournament() {
                 rebuyCount
                 reEntryCount
                 addonCount
+                rakePerEntryCents
                 totalRakeCents
+                prizePoolCents
             }
         }
     "#;
@@ -534,7 +538,10 @@ async fn test_entry_stats_total_rake_with_rake_tournament() {
     assert_eq!(stats["reEntryCount"], 1);
     assert_eq!(stats["addonCount"], 1);
     // totalRakeCents = count(initial + re_entry) * rake_cents = 3 * 500 = 1500
+    assert_eq!(stats["rakePerEntryCents"], 500);
     assert_eq!(stats["totalRakeCents"], 1500);
+    // Rake is charged on top of the buy-in, so every entry goes to the pool.
+    assert_eq!(stats["prizePoolCents"], 18500);
 }
 
 #[tokio::test]
@@ -730,9 +737,11 @@ async fn test_duplicate_initial_entry_rejected() {
         Some(manager_claims.clone()),
     )
     .await;
-    assert!(
-        !r2.errors.is_empty(),
-        "a second initial entry for the same player must be rejected"
+    assert_eq!(
+        error_code(&r2).as_deref(),
+        Some("DUPLICATE_INITIAL_ENTRY"),
+        "a second initial entry for the same player must be rejected: {:?}",
+        r2.errors
     );
 
     // A rebuy for the same player is still allowed — multiple funding rows of
@@ -750,3 +759,344 @@ async fn test_duplicate_initial_entry_rejected() {
         r3.errors
     );
 }
+
+#[tokio::test]
+async fn test_rebuy_limit_and_finished_tournament_rejected() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager_claims) =
+        create_test_user(&app_state, "rebuy_cap_manager@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Rebuy Cap Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Rebuy Cap Tournament").await;
+    let (player_id, _) = create_test_user(&app_state, "rebuy_cap_player@test.com", "player").await;
+    let (other_id, _) = create_test_user(&app_state, "rebuy_cap_other@test.com", "player").await;
+    sqlx::query("UPDATE tournaments SET rebuy_max = 2 WHERE id = $1")
+        .bind(tournament_id)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+
+    let mutation = r#"
+        mutation AddEntry($input: AddTournamentEntryInput!) {
+            addTournamentEntry(input: $input) { id entryType }
+        }
+    "#;
+    let entry = |user_id: uuid::Uuid, entry_type: &str| {
+        Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "userId": user_id.to_string(),
+                "entryType": entry_type,
+                "amountCents": 5000
+            }
+        }))
+    };
+
+    for entry_type in ["INITIAL", "REBUY", "REBUY"] {
+        let response = execute_graphql(
+            &schema,
+            mutation,
+            Some(entry(player_id, entry_type)),
+            Some(manager_claims.clone()),
+        )
+        .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+    }
+
+    // The third rebuy is one more than rebuy_max allows.
+    let response = execute_graphql(
+        &schema,
+        mutation,
+        Some(entry(player_id, "REBUY")),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert_eq!(
+        error_code(&response).as_deref(),
+        Some("REBUY_LIMIT_REACHED"),
+        "{:?}",
+        response.errors
+    );
+
+    // The cap is per player, and add-ons don't count against it.
+    for (user_id, entry_type) in [(other_id, "REBUY"), (player_id, "ADDON")] {
+        let response = execute_graphql(
+            &schema,
+            mutation,
+            Some(entry(user_id, entry_type)),
+            Some(manager_claims.clone()),
+        )
+        .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+    }
+
+    sqlx::query(
+        "UPDATE tournaments SET live_status = 'finished'::tournament_live_status WHERE id = $1",
+    )
+    .bind(tournament_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+    let response = execute_graphql(
+        &schema,
+        mutation,
+        Some(entry(other_id, "INITIAL")),
+        Some(manager_claims),
+    )
+    .await;
+    assert_eq!(
+        error_code(&response).as_deref(),
+        Some("TOURNAMENT_FINISHED"),
+        "{:?}",
+        response.errors
+    );
+
+    let rebuys: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM tournament_entries WHERE tournament_id = $1 AND entry_type = 'rebuy'",
+    )
+    .bind(tournament_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(rebuys, 3);
+}
+
+#[tokio::test]
+async fn test_early_bird_buy_in_pricing() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager_claims) =
+        create_test_user(&app_state, "early_bird_manager@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Early Bird Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Early Bird Deepstack").await;
+
+    // €40 until the cut-off, the regular €50 after.
+    let cut_off = chrono::Utc::now() + chrono::Duration::hours(1);
+    let update = r#"
+        mutation($input: UpdateTournamentInput!) {
+            updateTournament(input: $input) {
+                buyInCents earlyBirdBuyInCents earlyBirdUntil currentBuyInCents
+            }
+        }
+    "#;
+    let response = execute_graphql(
+        &schema,
+        update,
+        Some(Variables::from_json(json!({
+            "input": { "id": tournament_id.to_string(), "earlyBirdBuyInCents": 4000 }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors[0]
+        .message
+        .contains("both a price and a cut-off"));
+
+    let response = execute_graphql(
+        &schema,
+        update,
+        Some(Variables::from_json(json!({
+            "input": {
+                "id": tournament_id.to_string(),
+                "earlyBirdBuyInCents": 4000,
+                "earlyBirdUntil": cut_off.to_rfc3339(),
+            }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "update: {:?}", response.errors);
+    let tournament = response.data.into_json().unwrap()["updateTournament"].clone();
+    assert_eq!(tournament["buyInCents"], 5000);
+    assert_eq!(tournament["earlyBirdBuyInCents"], 4000);
+    assert_eq!(tournament["currentBuyInCents"], 4000);
+
+    // One player registered in time, the other after the cut-off.
+    let (early_id, _) = create_test_user(&app_state, "early_bird_a@test.com", "player").await;
+    let (late_id, _) = create_test_user(&app_state, "early_bird_b@test.com", "player").await;
+    create_test_registration(&app_state, tournament_id, early_id, "registered").await;
+    create_test_registration(&app_state, tournament_id, late_id, "registered").await;
+    sqlx::query(
+        "UPDATE tournament_registrations SET registration_time = $3 \
+         WHERE tournament_id = $1 AND user_id = $2",
+    )
+    .bind(tournament_id)
+    .bind(late_id)
+    .bind(cut_off + chrono::Duration::minutes(5))
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    let add_entry = r#"
+        mutation($input: AddTournamentEntryInput!) {
+            addTournamentEntry(input: $input) { amountCents priceTier }
+        }
+    "#;
+    let mut charged = Vec::new();
+    for (user_id, entry_type) in [
+        (early_id, "INITIAL"),
+        (late_id, "INITIAL"),
+        (early_id, "REBUY"),
+    ] {
+        let response = execute_graphql(
+            &schema,
+            add_entry,
+            Some(Variables::from_json(json!({
+                "input": {
+                    "tournamentId": tournament_id.to_string(),
+                    "userId": user_id.to_string(),
+                    "entryType": entry_type,
+                }
+            }))),
+            Some(manager_claims.clone()),
+        )
+        .await;
+        assert!(response.errors.is_empty(), "entry: {:?}", response.errors);
+        charged.push(response.data.into_json().unwrap()["addTournamentEntry"].clone());
+    }
+    assert_eq!(
+        charged[0],
+        json!({ "amountCents": 4000, "priceTier": "EARLY_BIRD" })
+    );
+    assert_eq!(
+        charged[1],
+        json!({ "amountCents": 5000, "priceTier": "REGULAR" })
+    );
+    // Rebuys are always at the regular price and carry no tier.
+    assert_eq!(
+        charged[2],
+        json!({ "amountCents": 5000, "priceTier": null })
+    );
+
+    let response = execute_graphql(
+        &schema,
+        r#"query($id: ID!) {
+            tournamentEntryStats(tournamentId: $id) {
+                earlyBirdCount earlyBirdAmountCents regularCount regularAmountCents
+            }
+        }"#,
+        Some(Variables::from_json(
+            json!({ "id": tournament_id.to_string() }),
+        )),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "stats: {:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap()["tournamentEntryStats"],
+        json!({
+            "earlyBirdCount": 1,
+            "earlyBirdAmountCents": 4000,
+            "regularCount": 1,
+            "regularAmountCents": 5000,
+        })
+    );
+}
+
+#[tokio::test]
+async fn entries_stream_with_running_totals() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let unique = Uuid::new_v4();
+
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("entry_feed_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let (player_id, player_claims) = create_test_user(
+        &app_state,
+        &format!("entry_feed_player_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Entry Feed Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Entry Feed").await;
+
+    // Streams subscribe on first poll, so start listening before the desk.
+    let mut stream = schema.execute_stream(
+        Request::new(format!(
+            "subscription {{ tournamentEntriesUpdates(tournamentId: \"{tournament_id}\") {{ eventType entryId entryType totals {{ totalEntries rebuyCount prizePoolCents }} }} }}"
+        ))
+        .data(player_claims),
+    );
+    let events = tokio::spawn(async move {
+        let mut events = Vec::new();
+        while events.len() < 3 {
+            events.push(stream.next().await.expect("entry event"));
+        }
+        events
+    });
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    let add =
+        "mutation($input: AddTournamentEntryInput!) { addTournamentEntry(input: $input) { id } }";
+    let mut rebuy_id = String::new();
+    for entry_type in ["INITIAL", "REBUY"] {
+        let response = execute_graphql(
+            &schema,
+            add,
+            Some(Variables::from_json(json!({
+                "input": {
+                    "tournamentId": tournament_id.to_string(),
+                    "userId": player_id.to_string(),
+                    "entryType": entry_type,
+                    "amountCents": 5000,
+                }
+            }))),
+            Some(manager_claims.clone()),
+        )
+        .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        rebuy_id = response.data.into_json().unwrap()["addTournamentEntry"]["id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+    }
+
+    // A correction at another terminal removes the rebuy again.
+    let response = execute_graphql(
+        &schema,
+        "mutation($entryId: ID!) { deleteTournamentEntry(entryId: $entryId) }",
+        Some(Variables::from_json(json!({ "entryId": rebuy_id }))),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let events = tokio::time::timeout(std::time::Duration::from_secs(5), events)
+        .await
+        .expect("entry events in time")
+        .unwrap();
+    let events: Vec<_> = events
+        .into_iter()
+        .map(|e| {
+            assert!(e.errors.is_empty(), "{:?}", e.errors);
+            e.data.into_json().unwrap()["tournamentEntriesUpdates"].clone()
+        })
+        .collect();
+
+    assert_eq!(events[0]["eventType"], "ADDED");
+    assert_eq!(events[0]["entryType"], "INITIAL");
+    assert_eq!(events[0]["totals"]["totalEntries"], 1);
+    assert_eq!(events[1]["entryType"], "REBUY");
+    assert_eq!(events[1]["totals"]["totalEntries"], 2);
+    assert_eq!(events[1]["totals"]["rebuyCount"], 1);
+    assert_eq!(events[2]["eventType"], "REMOVED");
+    assert_eq!(events[2]["entryId"], rebuy_id);
+    assert_eq!(events[2]["totals"]["totalEntries"], 1);
+    assert_eq!(
+        events[2]["totals"]["prizePoolCents"],
+        events[0]["totals"]["prizePoolCents"]
+    );
+    assert!(
+        events[1]["totals"]["prizePoolCents"].as_i64()
+            > events[0]["totals"]["prizePoolCents"].as_i64()
+    );
+}
@@ -0,0 +1,182 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+use uuid::Uuid;
+
+const GALLERY: &str = r#"
+    query($tournamentId: ID!) {
+        tournamentGallery(tournamentId: $tournamentId) {
+            id caption isPublic
+            tags { displayName consentRecorded consented }
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn test_gallery_shows_photos_publicly_only_with_consent() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("gallerymanager_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let (winner_id, winner_claims) = create_test_user(
+        &app_state,
+        &format!("gallerywinner_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Gallery Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Gallery Tournament").await;
+
+    let add_player = |name: &'static str, user: Option<Uuid>| {
+        let db = app_state.db.clone();
+        async move {
+            sqlx::query_scalar::<_, Uuid>(
+                "INSERT INTO club_player (club_id, display_name, app_user_id) VALUES ($1, $2, $3) RETURNING id",
+            )
+            .bind(club_id)
+            .bind(name)
+            .bind(user)
+            .fetch_one(&db)
+            .await
+            .unwrap()
+        }
+    };
+    let winner_cp = add_player("Winner", Some(winner_id)).await;
+    let walk_in_cp = add_player("Walk-in", None).await;
+
+    let add = r#"
+        mutation($input: AddTournamentPhotoInput!) {
+            addTournamentPhoto(input: $input) { id caption isPublic }
+        }
+    "#;
+    let response = execute_graphql(
+        &schema,
+        add,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "url": "https://media.example.com/final.pdf",
+                "contentType": "application/pdf"
+            }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert_eq!(response.errors[0].message, "Photos must be images");
+
+    let response = execute_graphql(
+        &schema,
+        add,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "url": "https://media.example.com/final.jpg",
+                "contentType": "image/jpeg",
+                "caption": "  Heads-up for the title  ",
+                "tags": [
+                    { "clubPlayerId": winner_cp.to_string() },
+                    { "clubPlayerId": walk_in_cp.to_string(), "consentRecorded": true }
+                ]
+            }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let photo = response.data.into_json().unwrap()["addTournamentPhoto"].clone();
+    assert_eq!(photo["caption"], "Heads-up for the title");
+    // The winner hasn't consented in their privacy settings yet.
+    assert_eq!(photo["isPublic"], false);
+
+    let gallery = |claims| {
+        let schema = schema.clone();
+        async move {
+            let response = execute_graphql(
+                &schema,
+                GALLERY,
+                Some(Variables::from_json(
+                    json!({ "tournamentId": tournament_id.to_string() }),
+                )),
+                claims,
+            )
+            .await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+            response.data.into_json().unwrap()["tournamentGallery"].clone()
+        }
+    };
+
+    let managed = gallery(Some(manager_claims.clone())).await;
+    assert_eq!(managed.as_array().unwrap().len(), 1);
+    assert_eq!(managed[0]["tags"][0]["displayName"], "Walk-in");
+    assert_eq!(managed[0]["tags"][0]["consented"], true);
+    assert_eq!(managed[0]["tags"][1]["displayName"], "Winner");
+    assert_eq!(managed[0]["tags"][1]["consented"], false);
+    assert!(gallery(None).await.as_array().unwrap().is_empty());
+
+    // Consent recorded by the club doesn't stand in for a player's own.
+    let response = execute_graphql(
+        &schema,
+        r#"
+            mutation($input: UpdateTournamentPhotoInput!) {
+                updateTournamentPhoto(input: $input) { caption isPublic }
+            }
+        "#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "photoId": photo["id"],
+                "caption": "",
+                "tags": [
+                    { "clubPlayerId": winner_cp.to_string(), "consentRecorded": true },
+                    { "clubPlayerId": walk_in_cp.to_string(), "consentRecorded": true }
+                ]
+            }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let updated = &response.data.into_json().unwrap()["updateTournamentPhoto"];
+    assert!(updated["caption"].is_null());
+    assert_eq!(updated["isPublic"], false);
+
+    let response = execute_graphql(
+        &schema,
+        r#"
+            mutation {
+                updatePrivacySettings(
+                    shareNamedPl: false, inScoutingPool: false, appearInPhotos: true
+                ) { appearInPhotos }
+            }
+        "#,
+        None,
+        Some(winner_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let public = gallery(None).await;
+    assert_eq!(public.as_array().unwrap().len(), 1);
+    assert_eq!(public[0]["isPublic"], true);
+
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($id: ID!) { deleteTournamentPhoto(photoId: $id) }"#,
+        Some(Variables::from_json(json!({ "id": photo["id"] }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert!(gallery(Some(manager_claims))
+        .await
+        .as_array()
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,180 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+
+const CREATE: &str = r#"
+    mutation($input: CreateTournamentInviteInput!) {
+        createTournamentInvite(input: $input) { id token maxUses useCount isUsable }
+    }
+"#;
+
+const REGISTER: &str = r#"
+    mutation($token: String!) {
+        registerWithInvite(token: $token) { id tournamentId inviteId }
+    }
+"#;
+
+fn token_vars(token: &str) -> Option<Variables> {
+    Some(Variables::from_json(json!({ "token": token })))
+}
+
+/// An invite opens a members-only tournament to outsiders until it is used
+/// up, expires or is revoked; registrations remember their invite.
+#[tokio::test]
+async fn test_invite_links() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) =
+        create_test_user(&app_state, "invite_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Invite Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Members Only Cup").await;
+    sqlx::query(
+        "UPDATE tournaments SET visibility = 'members_only', \
+             live_status = 'registration_open'::tournament_live_status WHERE id = $1",
+    )
+    .bind(tournament_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+    let (_, guest) = create_test_user(&app_state, "invite_guest@test.com", "player").await;
+    let (_, second) = create_test_user(&app_state, "invite_second@test.com", "player").await;
+
+    // Only managers create invites.
+    let create_vars = |extra: serde_json::Value| {
+        let mut input = json!({ "tournamentId": tournament_id.to_string() });
+        input
+            .as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        Some(Variables::from_json(json!({ "input": input })))
+    };
+    let resp = execute_graphql(
+        &schema,
+        CREATE,
+        create_vars(json!({ "maxUses": 1 })),
+        Some(guest.clone()),
+    )
+    .await;
+    assert!(!resp.errors.is_empty());
+
+    let resp = execute_graphql(
+        &schema,
+        CREATE,
+        create_vars(json!({ "maxUses": 1, "label": "Sponsor guest" })),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "create: {:?}", resp.errors);
+    let invite = resp.data.into_json().unwrap()["createTournamentInvite"].clone();
+    let invite_id = invite["id"].as_str().unwrap().to_string();
+    let token = invite["token"].as_str().unwrap().to_string();
+    assert_eq!(invite["isUsable"], true);
+
+    // Without the invite the tournament is closed to non-members.
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($input: RegisterForTournamentInput!) {
+            registerForTournament(input: $input) { id }
+        }"#,
+        Some(Variables::from_json(
+            json!({ "input": { "tournamentId": tournament_id.to_string() } }),
+        )),
+        Some(guest.clone()),
+    )
+    .await;
+    assert!(resp.errors[0].message.contains("club members only"));
+
+    let resp = execute_graphql(&schema, REGISTER, token_vars(&token), Some(guest.clone())).await;
+    assert!(resp.errors.is_empty(), "register: {:?}", resp.errors);
+    let registration = resp.data.into_json().unwrap()["registerWithInvite"].clone();
+    assert_eq!(registration["tournamentId"], tournament_id.to_string());
+    assert_eq!(registration["inviteId"], invite_id);
+
+    // Single use: the next player is turned away.
+    let resp = execute_graphql(&schema, REGISTER, token_vars(&token), Some(second.clone())).await;
+    assert!(resp.errors[0].message.contains("used up"));
+
+    // Forged tokens are rejected outright.
+    let (id_part, _) = token.split_once('.').unwrap();
+    let resp = execute_graphql(
+        &schema,
+        REGISTER,
+        token_vars(&format!("{id_part}.AAAA")),
+        Some(second.clone()),
+    )
+    .await;
+    assert!(resp.errors[0].message.contains("Invalid invite link"));
+
+    // Expired and revoked invites stop working.
+    let resp = execute_graphql(
+        &schema,
+        CREATE,
+        create_vars(json!({})),
+        Some(manager.clone()),
+    )
+    .await;
+    let open = resp.data.into_json().unwrap()["createTournamentInvite"].clone();
+    sqlx::query("UPDATE tournament_invites SET expires_at = NOW() - INTERVAL '1 minute' WHERE id = $1::uuid")
+        .bind(open["id"].as_str().unwrap())
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    let resp = execute_graphql(
+        &schema,
+        REGISTER,
+        token_vars(open["token"].as_str().unwrap()),
+        Some(second.clone()),
+    )
+    .await;
+    assert!(resp.errors[0].message.contains("expired"));
+
+    let resp = execute_graphql(
+        &schema,
+        CREATE,
+        create_vars(json!({})),
+        Some(manager.clone()),
+    )
+    .await;
+    let open = resp.data.into_json().unwrap()["createTournamentInvite"].clone();
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($id: ID!) { revokeTournamentInvite(inviteId: $id) { isUsable } }"#,
+        Some(Variables::from_json(json!({ "id": open["id"] }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "revoke: {:?}", resp.errors);
+    let resp = execute_graphql(
+        &schema,
+        REGISTER,
+        token_vars(open["token"].as_str().unwrap()),
+        Some(second.clone()),
+    )
+    .await;
+    assert!(resp.errors[0].message.contains("revoked"));
+
+    // The manager's list shows the use count.
+    let resp = execute_graphql(
+        &schema,
+        r#"query($id: ID!) { tournamentInvites(tournamentId: $id) { id useCount isUsable } }"#,
+        Some(Variables::from_json(
+            json!({ "id": tournament_id.to_string() }),
+        )),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "list: {:?}", resp.errors);
+    let invites = resp.data.into_json().unwrap()["tournamentInvites"].clone();
+    let used = invites
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|i| i["id"] == invite_id.as_str())
+        .unwrap();
+    assert_eq!(used["useCount"], 1);
+    assert_eq!(used["isUsable"], false);
+    assert_eq!(invites.as_array().unwrap().len(), 3);
+}
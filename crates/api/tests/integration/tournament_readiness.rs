@@ -0,0 +1,130 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+
+const READINESS: &str = r#"
+    query($tournamentId: ID!) {
+        tournamentReadiness(tournamentId: $tournamentId) {
+            ready
+            items { check passed }
+        }
+    }
+"#;
+
+const START: &str = r#"
+    mutation($input: UpdateTournamentStatusInput!) {
+        updateTournamentStatus(input: $input) { liveStatus }
+    }
+"#;
+
+#[tokio::test]
+async fn test_start_requires_checklist() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("readymanager_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Readiness Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Readiness Event").await;
+    let vars = || {
+        Some(Variables::from_json(
+            json!({ "tournamentId": tournament_id.to_string() }),
+        ))
+    };
+    let start = || {
+        Some(Variables::from_json(json!({
+            "input": { "tournamentId": tournament_id.to_string(), "liveStatus": "IN_PROGRESS" }
+        })))
+    };
+
+    let response = execute_graphql(&schema, READINESS, vars(), Some(manager_claims.clone())).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let readiness = &response.data.into_json().unwrap()["tournamentReadiness"];
+    assert_eq!(readiness["ready"], false);
+    assert_eq!(
+        readiness["items"],
+        json!([
+            { "check": "STRUCTURE", "passed": false },
+            { "check": "PAYOUT_TEMPLATE", "passed": false },
+            { "check": "TABLES", "passed": false },
+            { "check": "CLOCK", "passed": false }
+        ])
+    );
+
+    let response = execute_graphql(&schema, START, start(), Some(manager_claims.clone())).await;
+    let extensions = response.errors[0].extensions.as_ref().unwrap();
+    assert_eq!(
+        extensions.get("code"),
+        Some(&async_graphql::Value::String("NOT_READY".into()))
+    );
+
+    // Set the event up.
+    sqlx::query(
+        "INSERT INTO tournament_structures \
+             (tournament_id, level_number, small_blind, big_blind, ante, duration_minutes) \
+         VALUES ($1, 1, 100, 200, 0, 20)",
+    )
+    .bind(tournament_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+    let table_id = create_test_club_table(&app_state, club_id, 1, 9).await;
+    assign_table_to_tournament(&app_state, tournament_id, table_id).await;
+    let response = execute_graphql(
+        &schema,
+        r#"
+            mutation($clubId: ID!) {
+                createPayoutTemplate(input: {
+                    clubId: $clubId, name: "Top 3", minPlayers: 0,
+                    payoutStructure: [
+                        { position: 1, percentage: 50 },
+                        { position: 2, percentage: 30 },
+                        { position: 3, percentage: 20 }
+                    ]
+                }) { id }
+            }
+        "#,
+        Some(Variables::from_json(
+            json!({ "clubId": club_id.to_string() }),
+        )),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let response = execute_graphql(&schema, READINESS, vars(), Some(manager_claims.clone())).await;
+    assert_eq!(
+        response.data.into_json().unwrap()["tournamentReadiness"]["ready"],
+        true
+    );
+    let response = execute_graphql(&schema, START, start(), Some(manager_claims)).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap()["updateTournamentStatus"]["liveStatus"],
+        "IN_PROGRESS"
+    );
+
+    // Starting play started the clock on level 1.
+    let response = execute_graphql(
+        &schema,
+        r#"
+            query($tournamentId: ID!) {
+                tournamentClock(tournamentId: $tournamentId) { status currentLevel }
+            }
+        "#,
+        vars(),
+        None,
+    )
+    .await;
+    assert_eq!(
+        response.data.into_json().unwrap()["tournamentClock"],
+        json!({ "status": "RUNNING", "currentLevel": 1 })
+    );
+}
@@ -0,0 +1,158 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+
+const RECAP: &str = r#"
+    query($tournamentId: ID!) {
+        tournamentRecap(tournamentId: $tournamentId) {
+            title entrants prizePoolCents deliveredAt deliveryError
+            podium { position playerName prizeCents }
+            payouts { position playerName }
+            photos { url }
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn test_entering_results_generates_recap() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("recapmanager_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Recap Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Friday Deepstack").await;
+
+    let mut players = Vec::new();
+    for name in ["Ann", "Ben", "Cleo", "Dan"] {
+        let (user_id, _) = create_test_user(
+            &app_state,
+            &format!("recap{name}_{suffix}@test.com"),
+            "player",
+        )
+        .await;
+        create_test_registration(&app_state, tournament_id, user_id, "busted").await;
+        sqlx::query("UPDATE club_player SET display_name = $2 WHERE app_user_id = $1")
+            .bind(user_id)
+            .bind(name)
+            .execute(&app_state.db)
+            .await
+            .unwrap();
+        players.push(user_id);
+    }
+
+    let recap = |claims| {
+        let schema = schema.clone();
+        async move {
+            let response = execute_graphql(
+                &schema,
+                RECAP,
+                Some(Variables::from_json(
+                    json!({ "tournamentId": tournament_id.to_string() }),
+                )),
+                claims,
+            )
+            .await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+            response.data.into_json().unwrap()["tournamentRecap"].clone()
+        }
+    };
+    assert!(recap(None).await.is_null());
+
+    let response = execute_graphql(
+        &schema,
+        r#"
+            mutation($input: EnterTournamentResultsInput!) {
+                enterTournamentResults(input: $input) { success }
+            }
+        "#,
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "playerPositions": players
+                    .iter()
+                    .enumerate()
+                    .map(|(i, id)| json!({ "userId": id.to_string(), "finalPosition": i + 1 }))
+                    .collect::<Vec<_>>()
+            }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let public = recap(None).await;
+    assert_eq!(public["title"], "Ann wins the Friday Deepstack");
+    let podium: Vec<_> = public["podium"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["playerName"].as_str().unwrap())
+        .collect();
+    assert_eq!(podium, ["Ann", "Ben", "Cleo"]);
+    assert!(public["photos"].as_array().unwrap().is_empty());
+
+    // Prizes corrected afterwards: the manager rebuilds the recap.
+    for (user_id, prize) in [(players[0], 30_000), (players[1], 20_000)] {
+        sqlx::query(
+            "UPDATE tournament_results SET prize_cents = $3 \
+             WHERE tournament_id = $1 AND user_id = $2",
+        )
+        .bind(tournament_id)
+        .bind(user_id)
+        .bind(prize)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    }
+    let response = execute_graphql(
+        &schema,
+        r#"
+            mutation($input: UpdateRecapSettingsInput!) {
+                updateClubRecapSettings(input: $input) { webhookUrl signed }
+            }
+        "#,
+        Some(Variables::from_json(json!({
+            "input": { "clubId": club_id.to_string(), "webhookUrl": "ftp://example.com" }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert_eq!(
+        response.errors[0].message,
+        "Webhook URL must be an http(s) URL"
+    );
+
+    let response = execute_graphql(
+        &schema,
+        r#"
+            mutation($tournamentId: ID!) {
+                regenerateTournamentRecap(tournamentId: $tournamentId) {
+                    prizePoolCents payouts { position playerName prizeCents }
+                }
+            }
+        "#,
+        Some(Variables::from_json(
+            json!({ "tournamentId": tournament_id.to_string() }),
+        )),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let rebuilt = &response.data.into_json().unwrap()["regenerateTournamentRecap"];
+    assert_eq!(rebuilt["prizePoolCents"], 50_000);
+    assert_eq!(
+        rebuilt["payouts"],
+        json!([
+            { "position": 1, "playerName": "Ann", "prizeCents": 30_000 },
+            { "position": 2, "playerName": "Ben", "prizeCents": 20_000 }
+        ])
+    );
+}
@@ -1,7 +1,10 @@
 use crate::common::*;
 use api::gql::build_schema;
-use async_graphql::Variables;
+use async_graphql::{Request, Variables};
+use futures_util::StreamExt;
+use infra::repos::tournament_results;
 use serde_json::json;
+use uuid::Uuid;
 
 #[tokio::test]
 async fn test_enter_tournament_results() {
@@ -352,3 +355,663 @@ async fn test_my_recent_tournament_results() {
     );
     assert_eq!(results[0]["result"]["finalPosition"], 2);
 }
+
+#[tokio::test]
+async fn test_results_feed_lists_finished_events() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let suffix = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("feed_mgr_{suffix}@test.com"),
+        "manager",
+    )
+    .await;
+    let (_, player_claims) = create_test_user(
+        &app_state,
+        &format!("feed_player_{suffix}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Feed Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+
+    // A finished event (imported) and one that hasn't been played yet.
+    create_test_tournament(&app_state, club_id, "Upcoming Event").await;
+    let import = r#"
+        mutation($input: ImportTournamentResultsInput!) {
+            importTournamentResults(input: $input) { tournament { id } }
+        }
+    "#;
+    let vars = Variables::from_json(json!({
+        "input": {
+            "clubId": club_id.to_string(),
+            "format": "CSV",
+            "content": "Place,Name,Winnings\n1,Alice,100\n2,Bob,50\n3,Carol,0\n",
+            "name": "March Monthly",
+            "startTime": "2025-03-01T19:00:00Z",
+            "buyInCents": 5000,
+        }
+    }));
+    let response = execute_graphql(&schema, import, Some(vars), Some(manager_claims.clone())).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let query = r#"
+        query($clubId: ID!, $from: DateTime) {
+            resultsFeed(clubId: $clubId, from: $from) {
+                clubName
+                events { name entrants totalEntries prizePoolCents places { position playerName prizeCents } }
+                csv
+            }
+        }
+    "#;
+    let vars =
+        |from: &str| Variables::from_json(json!({ "clubId": club_id.to_string(), "from": from }));
+
+    let response = execute_graphql(
+        &schema,
+        query,
+        Some(vars("2025-01-01T00:00:00Z")),
+        Some(player_claims),
+    )
+    .await;
+    assert!(!response.errors.is_empty(), "only managers read the feed");
+
+    let response = execute_graphql(
+        &schema,
+        query,
+        Some(vars("2025-01-01T00:00:00Z")),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let feed = &response.data.into_json().unwrap()["resultsFeed"];
+    let events = feed["events"].as_array().unwrap();
+    assert_eq!(events.len(), 1, "only finished events are reported");
+    assert_eq!(events[0]["name"], "March Monthly");
+    assert_eq!(events[0]["entrants"], 3);
+    assert_eq!(events[0]["totalEntries"], 3);
+    assert_eq!(events[0]["prizePoolCents"], 15_000);
+    assert_eq!(events[0]["places"][0]["playerName"], "Alice");
+    assert_eq!(events[0]["places"][0]["prizeCents"], 10_000);
+
+    let csv = feed["csv"].as_str().unwrap();
+    assert_eq!(csv.lines().count(), 4);
+    assert!(csv
+        .lines()
+        .nth(1)
+        .unwrap()
+        .starts_with("2025-03-01,March Monthly,"));
+    assert!(csv.lines().nth(1).unwrap().ends_with(",1,Alice,100.00"));
+
+    // The window excludes events starting before `from`.
+    let response = execute_graphql(
+        &schema,
+        query,
+        Some(vars("2025-04-01T00:00:00Z")),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let feed = response.data.into_json().unwrap();
+    assert_eq!(feed["resultsFeed"]["events"], json!([]));
+}
+
+#[tokio::test]
+async fn results_are_announced_live_winner_last() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let unique = Uuid::new_v4();
+
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("live_results_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Live Results Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Live Results").await;
+
+    let mut players = Vec::new();
+    for n in 1..=3 {
+        let (id, claims) = create_test_user(
+            &app_state,
+            &format!("live_results_p{n}_{unique}@test.com"),
+            "player",
+        )
+        .await;
+        players.push((id, claims));
+    }
+
+    // Streams subscribe on first poll, so start listening before entering.
+    let mut stream = schema.execute_stream(
+        Request::new(format!(
+            "subscription {{ tournamentResultsUpdates(tournamentId: \"{tournament_id}\") {{ finalPosition displayName prizeCents }} }}"
+        ))
+        .data(players[0].1.clone()),
+    );
+    let events = tokio::spawn(async move {
+        let mut events = Vec::new();
+        while events.len() < 3 {
+            events.push(stream.next().await.expect("result event"));
+        }
+        events
+    });
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    let response = execute_graphql(
+        &schema,
+        "mutation($input: EnterTournamentResultsInput!) { enterTournamentResults(input: $input) { success } }",
+        Some(Variables::from_json(json!({
+            "input": {
+                "tournamentId": tournament_id.to_string(),
+                "playerPositions": players
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (id, _))| json!({ "userId": id.to_string(), "finalPosition": i + 1 }))
+                    .collect::<Vec<_>>(),
+            }
+        }))),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let events = tokio::time::timeout(std::time::Duration::from_secs(5), events)
+        .await
+        .expect("result events")
+        .unwrap();
+    let positions: Vec<i64> = events
+        .into_iter()
+        .map(|event| {
+            assert!(event.errors.is_empty(), "{:?}", event.errors);
+            let data = event.data.into_json().unwrap();
+            assert!(data["tournamentResultsUpdates"]["displayName"].is_string());
+            data["tournamentResultsUpdates"]["finalPosition"]
+                .as_i64()
+                .unwrap()
+        })
+        .collect();
+    assert_eq!(positions, vec![3, 2, 1]);
+}
+
+#[tokio::test]
+async fn tournament_history_joins_entries_finish_and_points() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let unique = Uuid::new_v4().simple().to_string();
+
+    let (player_id, player_claims) =
+        create_test_user(&app_state, &format!("hist_p_{unique}@test.com"), "player").await;
+    let (rival_id, _) =
+        create_test_user(&app_state, &format!("hist_r_{unique}@test.com"), "player").await;
+    let club_id = create_test_club(&app_state, "History Club").await;
+    let other_club_id = create_test_club(&app_state, "Other History Club").await;
+
+    let finished = create_test_tournament(&app_state, club_id, "History Main Event").await;
+    create_test_registration(&app_state, finished, player_id, "busted").await;
+    create_test_registration(&app_state, finished, rival_id, "busted").await;
+    for (entry_type, amount) in [("initial", 5000_i64), ("rebuy", 2500)] {
+        sqlx::query(
+            "INSERT INTO tournament_entries (tournament_id, user_id, entry_type, amount_cents) \
+             VALUES ($1, $2, $3, $4)",
+        )
+        .bind(finished)
+        .bind(player_id)
+        .bind(entry_type)
+        .bind(amount)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    }
+    sqlx::query(
+        "INSERT INTO tournament_results (tournament_id, user_id, final_position, prize_cents, points) \
+         VALUES ($1, $2, 2, 12000, 30)",
+    )
+    .bind(finished)
+    .bind(player_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+
+    let elsewhere = create_test_tournament(&app_state, other_club_id, "History Elsewhere").await;
+    create_test_registration(&app_state, elsewhere, player_id, "registered").await;
+    let cancelled = create_test_tournament(&app_state, club_id, "History Cancelled").await;
+    create_test_registration(&app_state, cancelled, player_id, "cancelled").await;
+
+    let query = r#"
+        query($clubId: ID) {
+            me {
+                tournamentHistory(clubId: $clubId) {
+                    totalCount
+                    items {
+                        tournament { id }
+                        registrationStatus
+                        entryCount
+                        entriesTotalCents
+                        fieldSize
+                        finalPosition
+                        prizeCents
+                        points
+                        netCents
+                    }
+                }
+            }
+        }
+    "#;
+
+    let response = execute_graphql(&schema, query, None, Some(player_claims.clone())).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    // The cancelled registration is left out.
+    assert_eq!(data["me"]["tournamentHistory"]["totalCount"], 2);
+
+    let response = execute_graphql(
+        &schema,
+        query,
+        Some(Variables::from_json(
+            json!({ "clubId": club_id.to_string() }),
+        )),
+        Some(player_claims),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(
+        data["me"]["tournamentHistory"]["items"],
+        json!([{
+            "tournament": { "id": finished.to_string() },
+            "registrationStatus": "BUSTED",
+            "entryCount": 2,
+            "entriesTotalCents": 7500,
+            "fieldSize": 2,
+            "finalPosition": 2,
+            "prizeCents": 12000,
+            "points": 30,
+            "netCents": 4500,
+        }])
+    );
+}
+
+#[tokio::test]
+async fn leaderboard_stats_follow_final_table_and_itm_thresholds() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let unique = Uuid::new_v4().simple().to_string();
+
+    let (manager_id, manager_claims) =
+        create_test_user(&app_state, &format!("thr_mgr_{unique}@test.com"), "manager").await;
+    let club_id = create_test_club(&app_state, "Short-Handed Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let (player_id, _) =
+        create_test_user(&app_state, &format!("thr_p_{unique}@test.com"), "player").await;
+
+    // 2nd with a prize, then 7th and 8th without one.
+    for (i, (position, prize)) in [(2, 10000_i64), (7, 0), (8, 0)].into_iter().enumerate() {
+        let tournament_id =
+            create_test_tournament(&app_state, club_id, &format!("Threshold Event {i}")).await;
+        create_test_registration(&app_state, tournament_id, player_id, "busted").await;
+        sqlx::query(
+            "INSERT INTO tournament_results (tournament_id, user_id, final_position, prize_cents) \
+             VALUES ($1, $2, $3, $4)",
+        )
+        .bind(tournament_id)
+        .bind(player_id)
+        .bind(position)
+        .bind(prize)
+        .execute(&app_state.db)
+        .await
+        .unwrap();
+    }
+
+    let query = r#"
+        query($clubId: UUID, $options: LeaderboardOptions) {
+            leaderboard(clubId: $clubId, options: $options) {
+                items { totalTournaments totalItm itmPercentage finalTables }
+            }
+        }
+    "#;
+    let stats = |vars: serde_json::Value| {
+        let schema = schema.clone();
+        let claims = manager_claims.clone();
+        async move {
+            let response = execute_graphql(
+                &schema,
+                query,
+                Some(Variables::from_json(vars)),
+                Some(claims),
+            )
+            .await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+            response.data.into_json().unwrap()["leaderboard"]["items"][0].clone()
+        }
+    };
+
+    // Defaults: top 9 make the final table, a prize makes a cash.
+    let row = stats(json!({ "clubId": club_id.to_string() })).await;
+    assert_eq!(row["totalTournaments"], 3);
+    assert_eq!(row["totalItm"], 1);
+    assert_eq!(row["finalTables"], 3);
+
+    // A 6-max club.
+    let response = execute_graphql(
+        &schema,
+        r#"mutation($input: UpdateStatsSettingsInput!) {
+            updateClubStatsSettings(input: $input) { finalTableSize itmPlaces }
+        }"#,
+        Some(Variables::from_json(json!({
+            "input": { "clubId": club_id.to_string(), "finalTableSize": 6, "itmPlaces": null }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let row = stats(json!({ "clubId": club_id.to_string() })).await;
+    assert_eq!(row["finalTables"], 1);
+    assert_eq!(row["totalItm"], 1);
+
+    // Query options override the club's settings.
+    let row = stats(json!({
+        "clubId": club_id.to_string(),
+        "options": { "finalTableSize": 8, "itmPlaces": 7 },
+    }))
+    .await;
+    assert_eq!(row["finalTables"], 3);
+    assert_eq!(row["totalItm"], 2);
+    assert_eq!(row["itmPercentage"], 66.67);
+
+    let response = execute_graphql(
+        &schema,
+        query,
+        Some(Variables::from_json(json!({
+            "clubId": club_id.to_string(),
+            "options": { "finalTableSize": 1 },
+        }))),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(!response.errors.is_empty());
+}
+
+#[tokio::test]
+async fn leaderboard_orders_players_level_on_points_by_tie_breaks() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let unique = Uuid::new_v4().simple().to_string();
+
+    let (manager_id, manager_claims) =
+        create_test_user(&app_state, &format!("tie_mgr_{unique}@test.com"), "manager").await;
+    let club_id = create_test_club(&app_state, "Tie-Break Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let (alice, _) =
+        create_test_user(&app_state, &format!("tie_a_{unique}@test.com"), "player").await;
+    let (bob, _) =
+        create_test_user(&app_state, &format!("tie_b_{unique}@test.com"), "player").await;
+
+    // Both on 20 points: Alice beat Bob in the one event they shared, Bob won
+    // two events on his own.
+    let events: [&[(Uuid, i32, i32)]; 3] = [
+        &[(alice, 1, 20), (bob, 2, 10)],
+        &[(bob, 1, 5)],
+        &[(bob, 1, 5)],
+    ];
+    for (i, results) in events.into_iter().enumerate() {
+        let tournament_id =
+            create_test_tournament(&app_state, club_id, &format!("Tie-Break Event {i}")).await;
+        for &(player_id, position, points) in results {
+            create_test_registration(&app_state, tournament_id, player_id, "busted").await;
+            sqlx::query(
+                "INSERT INTO tournament_results (tournament_id, user_id, final_position, prize_cents, points) \
+                 VALUES ($1, $2, $3, 0, $4)",
+            )
+            .bind(tournament_id)
+            .bind(player_id)
+            .bind(position)
+            .bind(points)
+            .execute(&app_state.db)
+            .await
+            .unwrap();
+        }
+    }
+
+    let query = r#"
+        query($clubId: UUID, $options: LeaderboardOptions) {
+            leaderboard(clubId: $clubId, options: $options) {
+                items { user { id } points headToHeadWins bestResultPoints firstPlaces }
+            }
+        }
+    "#;
+    let standings = |vars: serde_json::Value| {
+        let schema = schema.clone();
+        let claims = manager_claims.clone();
+        async move {
+            let response = execute_graphql(
+                &schema,
+                query,
+                Some(Variables::from_json(vars)),
+                Some(claims),
+            )
+            .await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+            response.data.into_json().unwrap()["leaderboard"]["items"].clone()
+        }
+    };
+
+    // Default rules: head-to-head first.
+    let items = standings(json!({ "clubId": club_id.to_string() })).await;
+    assert_eq!(items[0]["user"]["id"], alice.to_string());
+    assert_eq!(items[0]["points"], 20.0);
+    assert_eq!(items[0]["headToHeadWins"], 1);
+    assert_eq!(items[0]["bestResultPoints"], 20.0);
+    assert_eq!(items[1]["user"]["id"], bob.to_string());
+    assert_eq!(items[1]["points"], 20.0);
+    assert_eq!(items[1]["headToHeadWins"], 0);
+
+    let items = standings(json!({
+        "clubId": club_id.to_string(),
+        "options": { "tieBreaks": ["MOST_WINS", "HEAD_TO_HEAD"] },
+    }))
+    .await;
+    assert_eq!(items[0]["user"]["id"], bob.to_string());
+    assert_eq!(items[0]["firstPlaces"], 2);
+
+    let response = execute_graphql(
+        &schema,
+        query,
+        Some(Variables::from_json(json!({
+            "clubId": club_id.to_string(),
+            "options": { "tieBreaks": ["MOST_WINS", "MOST_WINS"] },
+        }))),
+        Some(manager_claims),
+    )
+    .await;
+    assert!(!response.errors.is_empty());
+}
+
+#[tokio::test]
+async fn rolling_leaderboard_decays_points_with_age() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let unique = Uuid::new_v4().simple().to_string();
+
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("roll_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Rolling Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let (player_id, _) =
+        create_test_user(&app_state, &format!("roll_p_{unique}@test.com"), "player").await;
+
+    let add_result = |weeks_ago: i64, points: i32| {
+        let app_state = app_state.clone();
+        async move {
+            let tournament_id =
+                create_test_tournament(&app_state, club_id, &format!("Rolling Event {weeks_ago}"))
+                    .await;
+            sqlx::query("UPDATE tournaments SET start_time = NOW() - make_interval(weeks => $2) WHERE id = $1")
+                .bind(tournament_id)
+                .bind(weeks_ago as i32)
+                .execute(&app_state.db)
+                .await
+                .unwrap();
+            create_test_registration(&app_state, tournament_id, player_id, "busted").await;
+            sqlx::query(
+                "INSERT INTO tournament_results (tournament_id, user_id, final_position, prize_cents, points) \
+                 VALUES ($1, $2, 1, 0, $3)",
+            )
+            .bind(tournament_id)
+            .bind(player_id)
+            .bind(points)
+            .execute(&app_state.db)
+            .await
+            .unwrap();
+        }
+    };
+
+    // Half-way through the window, and past it.
+    add_result(26, 20).await;
+    add_result(60, 30).await;
+    tournament_results::refresh_rolling_points(&app_state.db)
+        .await
+        .unwrap();
+    // Entered after the nightly refresh: counts in full until the next one.
+    add_result(0, 8).await;
+
+    let query = r#"
+        query($clubId: UUID, $period: LeaderboardPeriod) {
+            leaderboard(clubId: $clubId, period: $period) {
+                items { points totalTournaments }
+            }
+        }
+    "#;
+    let standing = |period: &'static str| {
+        let schema = schema.clone();
+        let claims = manager_claims.clone();
+        async move {
+            let response = execute_graphql(
+                &schema,
+                query,
+                Some(Variables::from_json(
+                    json!({ "clubId": club_id.to_string(), "period": period }),
+                )),
+                Some(claims),
+            )
+            .await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+            response.data.into_json().unwrap()["leaderboard"]["items"][0].clone()
+        }
+    };
+
+    let row = standing("ALL_TIME").await;
+    assert_eq!(row["points"], 58.0);
+    assert_eq!(row["totalTournaments"], 3);
+
+    let row = standing("ROLLING_52").await;
+    assert_eq!(row["points"], 18.0);
+    assert_eq!(row["totalTournaments"], 2);
+}
+
+#[tokio::test]
+async fn points_multiplier_weights_points_when_results_are_entered() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let unique = Uuid::new_v4().simple().to_string();
+
+    let (manager_id, manager_claims) = create_test_user(
+        &app_state,
+        &format!("mult_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Multiplier Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let mut players = Vec::new();
+    for i in 0..3 {
+        let (player_id, _) = create_test_user(
+            &app_state,
+            &format!("mult_p{i}_{unique}@test.com"),
+            "player",
+        )
+        .await;
+        players.push(player_id);
+    }
+
+    let weekly = create_test_tournament(&app_state, club_id, "Weekly").await;
+    let main_event = create_test_tournament(&app_state, club_id, "Main Event").await;
+
+    let update = r#"
+        mutation($input: UpdateTournamentInput!) {
+            updateTournament(input: $input) { id pointsMultiplier }
+        }
+    "#;
+    let response = execute_graphql(
+        &schema,
+        update,
+        Some(Variables::from_json(json!({
+            "input": { "id": main_event.to_string(), "pointsMultiplier": 2.0 }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["updateTournament"]["pointsMultiplier"], 2.0);
+
+    let response = execute_graphql(
+        &schema,
+        update,
+        Some(Variables::from_json(json!({
+            "input": { "id": main_event.to_string(), "pointsMultiplier": 0.0 }
+        }))),
+        Some(manager_claims.clone()),
+    )
+    .await;
+    assert!(!response.errors.is_empty());
+
+    let mut winner_points = Vec::new();
+    for tournament_id in [weekly, main_event] {
+        for &player_id in &players {
+            create_test_registration(&app_state, tournament_id, player_id, "busted").await;
+        }
+        let response = execute_graphql(
+            &schema,
+            r#"mutation($input: EnterTournamentResultsInput!) {
+                enterTournamentResults(input: $input) { success }
+            }"#,
+            Some(Variables::from_json(json!({
+                "input": {
+                    "tournamentId": tournament_id.to_string(),
+                    "playerPositions": players
+                        .iter()
+                        .enumerate()
+                        .map(|(i, id)| json!({ "userId": id.to_string(), "finalPosition": i + 1 }))
+                        .collect::<Vec<_>>(),
+                }
+            }))),
+            Some(manager_claims.clone()),
+        )
+        .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+        let response = execute_graphql(
+            &schema,
+            r#"query($id: ID!) { tournamentResults(tournamentId: $id) { finalPosition points } }"#,
+            Some(Variables::from_json(
+                json!({ "id": tournament_id.to_string() }),
+            )),
+            None,
+        )
+        .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        winner_points.push(data["tournamentResults"][0]["points"].as_i64().unwrap());
+    }
+
+    assert!(winner_points[0] > 0);
+    assert_eq!(winner_points[1], winner_points[0] * 2);
+}
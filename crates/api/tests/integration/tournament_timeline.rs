@@ -0,0 +1,164 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+
+const TIMELINE: &str = r#"
+    query($id: UUID!, $kinds: [TimelineEventKind!], $after: String, $first: Int) {
+        tournamentTimeline(tournamentId: $id, kinds: $kinds, after: $after, first: $first) {
+            events { cursor kind action levelNumber playerName title }
+            endCursor
+            hasNextPage
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn test_timeline_merges_sources_in_order() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let (manager_id, _) = create_test_user(
+        &app_state,
+        &format!("timeline_mgr_{unique}@test.com"),
+        "manager",
+    )
+    .await;
+    let (player_id, _) = create_test_user(
+        &app_state,
+        &format!("timeline_player_{unique}@test.com"),
+        "player",
+    )
+    .await;
+    let club_id = create_test_club(&app_state, "Timeline Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let tournament_id = create_test_tournament(&app_state, club_id, "Timeline Open").await;
+    create_test_registration(&app_state, tournament_id, player_id, "seated").await;
+
+    // 50 minutes ago the player bought in, the clock started at 40, level 2
+    // began at 30, an announcement went out at 20 and the player busted at 10.
+    let db = &app_state.db;
+    sqlx::query(
+        "INSERT INTO tournament_entries (tournament_id, user_id, club_player_id, entry_type, amount_cents, chips_received, created_at)
+         SELECT tournament_id, user_id, club_player_id, 'initial', 5000, 20000, NOW() - INTERVAL '50 minutes'
+         FROM tournament_registrations WHERE tournament_id = $1 AND user_id = $2",
+    )
+    .bind(tournament_id)
+    .bind(player_id)
+    .execute(db)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO tournament_activity_log (tournament_id, event_category, event_action, actor_id, subject_id, event_time, metadata) VALUES
+         ($1, 'clock', 'start', $2, NULL, NOW() - INTERVAL '40 minutes', '{}'),
+         ($1, 'registration', 'check_in', $2, $3, NOW() - INTERVAL '35 minutes', '{}'),
+         ($1, 'clock', 'auto_advance', NULL, NULL, NOW() - INTERVAL '30 minutes', '{\"level_number\": 2}'),
+         ($1, 'seating', 'player_eliminated', $2, $3, NOW() - INTERVAL '10 minutes', '{}')",
+    )
+    .bind(tournament_id)
+    .bind(manager_id)
+    .bind(player_id)
+    .execute(db)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO announcements (scope, club_id, tournament_id, title, body, created_by, sent_at)
+         VALUES ('tournament', $1, $2, 'Dinner break at level 6', 'Food is served in the bar.', $3, NOW() - INTERVAL '20 minutes')",
+    )
+    .bind(club_id)
+    .bind(tournament_id)
+    .bind(manager_id)
+    .execute(db)
+    .await
+    .unwrap();
+    sqlx::query(
+        "UPDATE tournament_registrations SET status = 'busted', eliminated_at = NOW() - INTERVAL '10 minutes', eliminated_level = 2
+         WHERE tournament_id = $1 AND user_id = $2",
+    )
+    .bind(tournament_id)
+    .bind(player_id)
+    .execute(db)
+    .await
+    .unwrap();
+
+    // Page through two events at a time.
+    let mut seen = Vec::new();
+    let mut after: Option<String> = None;
+    loop {
+        let response = execute_graphql(
+            &schema,
+            TIMELINE,
+            Some(Variables::from_json(json!({
+                "id": tournament_id,
+                "after": after,
+                "first": 2,
+            }))),
+            None,
+        )
+        .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let page = response.data.into_json().unwrap()["tournamentTimeline"].clone();
+        seen.extend(page["events"].as_array().unwrap().iter().cloned());
+        if !page["hasNextPage"].as_bool().unwrap() {
+            break;
+        }
+        after = page["endCursor"].as_str().map(String::from);
+    }
+
+    let summary: Vec<(String, String)> = seen
+        .iter()
+        .map(|e| {
+            (
+                e["kind"].as_str().unwrap().to_string(),
+                e["action"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    let expected = [
+        ("ENTRY", "initial"),
+        ("CLOCK", "start"),
+        ("CLOCK", "auto_advance"),
+        ("ANNOUNCEMENT", "normal"),
+        ("ELIMINATION", "eliminated"),
+    ];
+    assert_eq!(
+        summary,
+        expected
+            .iter()
+            .map(|(k, a)| (k.to_string(), a.to_string()))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(seen[2]["levelNumber"], 2);
+    assert_eq!(seen[3]["title"], "Dinner break at level 6");
+    assert_eq!(seen[4]["levelNumber"], 2);
+    assert!(seen[4]["playerName"].is_string());
+
+    // Narrowed to some kinds.
+    let response = execute_graphql(
+        &schema,
+        TIMELINE,
+        Some(Variables::from_json(json!({
+            "id": tournament_id,
+            "kinds": ["CLOCK"],
+        }))),
+        None,
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let page = response.data.into_json().unwrap()["tournamentTimeline"].clone();
+    assert_eq!(page["events"].as_array().unwrap().len(), 2);
+    assert_eq!(page["hasNextPage"], false);
+
+    let response = execute_graphql(
+        &schema,
+        TIMELINE,
+        Some(Variables::from_json(json!({
+            "id": tournament_id,
+            "after": "garbage",
+        }))),
+        None,
+    )
+    .await;
+    assert_eq!(response.errors[0].message, "Invalid cursor");
+}
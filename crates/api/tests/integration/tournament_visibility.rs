@@ -0,0 +1,224 @@
+use crate::common::*;
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+const LIST: &str = r#"
+    query($clubId: UUID) {
+        tournaments(clubId: $clubId) { items { id } }
+    }
+"#;
+
+const GET: &str = r#"
+    query($id: UUID!, $token: String) {
+        tournament(id: $id, inviteToken: $token) { id visibility inviteToken }
+    }
+"#;
+
+const REGISTER: &str = r#"
+    mutation($input: RegisterForTournamentInput!) {
+        registerForTournament(input: $input) { id }
+    }
+"#;
+
+fn listed_ids(data: Value) -> Vec<String> {
+    data["tournaments"]["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+fn register_vars(tournament_id: Uuid, token: Option<&str>) -> Option<Variables> {
+    Some(Variables::from_json(json!({
+        "input": { "tournamentId": tournament_id.to_string(), "inviteToken": token }
+    })))
+}
+
+/// Members-only tournaments are listed for and open to the club's roster;
+/// unlisted ones only through their invite link, which managers can rotate.
+#[tokio::test]
+async fn test_tournament_visibility() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+
+    let (manager_id, manager) =
+        create_test_user(&app_state, "visibility_mgr@test.com", "manager").await;
+    let club_id = create_test_club(&app_state, "Visibility Club").await;
+    create_club_manager(&app_state, manager_id, club_id).await;
+    let public_id = create_open_tournament(&app_state, club_id, "Open Deepstack").await;
+    let members_id = create_open_tournament(&app_state, club_id, "Members Monthly").await;
+    let unlisted_id = create_open_tournament(&app_state, club_id, "Private Invitational").await;
+
+    let (member_id, member) =
+        create_test_user(&app_state, "visibility_member@test.com", "player").await;
+    sqlx::query(
+        "INSERT INTO club_player (club_id, display_name, app_user_id, is_active) \
+         VALUES ($1, 'Member', $2, true)",
+    )
+    .bind(club_id)
+    .bind(member_id)
+    .execute(&app_state.db)
+    .await
+    .unwrap();
+    let (_, outsider) =
+        create_test_user(&app_state, "visibility_outsider@test.com", "player").await;
+
+    let update = r#"
+        mutation($id: ID!, $visibility: TournamentVisibility!) {
+            updateTournament(input: { id: $id, visibility: $visibility }) {
+                visibility inviteToken
+            }
+        }
+    "#;
+    let resp = execute_graphql(
+        &schema,
+        update,
+        Some(Variables::from_json(
+            json!({ "id": members_id.to_string(), "visibility": "MEMBERS_ONLY" }),
+        )),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "members only: {:?}", resp.errors);
+    let resp = execute_graphql(
+        &schema,
+        update,
+        Some(Variables::from_json(
+            json!({ "id": unlisted_id.to_string(), "visibility": "UNLISTED" }),
+        )),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "unlisted: {:?}", resp.errors);
+    let data = resp.data.into_json().unwrap()["updateTournament"].clone();
+    assert_eq!(data["visibility"], "UNLISTED");
+    let token = data["inviteToken"].as_str().unwrap().to_string();
+    assert_eq!(token.len(), 32);
+
+    let list_vars = Some(Variables::from_json(json!({ "clubId": club_id })));
+    let ids = |claims| {
+        let schema = &schema;
+        let vars = list_vars.clone();
+        async move {
+            let resp = execute_graphql(schema, LIST, vars, claims).await;
+            assert!(resp.errors.is_empty(), "list: {:?}", resp.errors);
+            listed_ids(resp.data.into_json().unwrap())
+        }
+    };
+    let (public, members, unlisted) = (
+        public_id.to_string(),
+        members_id.to_string(),
+        unlisted_id.to_string(),
+    );
+
+    // Managers see everything, members the roster events, outsiders and
+    // anonymous visitors only public ones. Unlisted never shows up in lists
+    // for players.
+    let seen = ids(Some(manager.clone())).await;
+    assert!(seen.contains(&members) && seen.contains(&unlisted));
+    let seen = ids(Some(member.clone())).await;
+    assert!(seen.contains(&public) && seen.contains(&members) && !seen.contains(&unlisted));
+    let seen = ids(Some(outsider.clone())).await;
+    assert!(seen.contains(&public) && !seen.contains(&members) && !seen.contains(&unlisted));
+    let seen = ids(None).await;
+    assert!(seen.contains(&public) && !seen.contains(&members));
+
+    // Direct lookups follow the same rules; the invite token opens unlisted.
+    let get = |id: Uuid, token: Option<&str>, claims| {
+        let schema = &schema;
+        let vars = Some(Variables::from_json(json!({ "id": id, "token": token })));
+        async move {
+            let resp = execute_graphql(schema, GET, vars, claims).await;
+            assert!(resp.errors.is_empty(), "get: {:?}", resp.errors);
+            resp.data.into_json().unwrap()["tournament"].clone()
+        }
+    };
+    assert!(get(members_id, None, Some(outsider.clone()))
+        .await
+        .is_null());
+    assert!(!get(members_id, None, Some(member.clone())).await.is_null());
+    assert!(get(unlisted_id, None, Some(outsider.clone()))
+        .await
+        .is_null());
+    assert!(get(unlisted_id, Some("wrong"), Some(outsider.clone()))
+        .await
+        .is_null());
+    let opened = get(unlisted_id, Some(&token), Some(outsider.clone())).await;
+    assert_eq!(opened["id"], unlisted);
+    // Only managers are shown the token itself.
+    assert!(opened["inviteToken"].is_null());
+
+    let resp = execute_graphql(
+        &schema,
+        r#"query($token: String!) { tournamentByInviteToken(token: $token) { id } }"#,
+        Some(Variables::from_json(json!({ "token": token }))),
+        None,
+    )
+    .await;
+    assert_eq!(
+        resp.data.into_json().unwrap()["tournamentByInviteToken"]["id"],
+        unlisted
+    );
+
+    // Registration: outsiders are refused for members-only and without the
+    // link; with it they get in and keep seeing the tournament.
+    let resp = execute_graphql(
+        &schema,
+        REGISTER,
+        register_vars(members_id, None),
+        Some(outsider.clone()),
+    )
+    .await;
+    assert!(resp.errors[0].message.contains("club members only"));
+    let resp = execute_graphql(
+        &schema,
+        REGISTER,
+        register_vars(members_id, None),
+        Some(member.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "member register: {:?}", resp.errors);
+    let resp = execute_graphql(
+        &schema,
+        REGISTER,
+        register_vars(unlisted_id, None),
+        Some(outsider.clone()),
+    )
+    .await;
+    assert!(resp.errors[0].message.contains("invite link"));
+    let resp = execute_graphql(
+        &schema,
+        REGISTER,
+        register_vars(unlisted_id, Some(&token)),
+        Some(outsider.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "invite register: {:?}", resp.errors);
+    assert!(!get(unlisted_id, None, Some(outsider.clone()))
+        .await
+        .is_null());
+
+    // Rotating the token retires the old link.
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($id: UUID!) { regenerateTournamentInviteToken(tournamentId: $id) }"#,
+        Some(Variables::from_json(json!({ "id": unlisted_id }))),
+        Some(manager.clone()),
+    )
+    .await;
+    assert!(resp.errors.is_empty(), "rotate: {:?}", resp.errors);
+    let rotated = resp.data.into_json().unwrap()["regenerateTournamentInviteToken"].clone();
+    assert_ne!(rotated.as_str().unwrap(), token);
+    let resp = execute_graphql(
+        &schema,
+        r#"mutation($id: UUID!) { regenerateTournamentInviteToken(tournamentId: $id) }"#,
+        Some(Variables::from_json(json!({ "id": unlisted_id }))),
+        Some(outsider.clone()),
+    )
+    .await;
+    assert!(!resp.errors.is_empty());
+    assert!(get(unlisted_id, Some(&token), None).await.is_null());
+}
@@ -0,0 +1,161 @@
+//! Seed data for the hot-path scenarios, shared by the load tests and the
+//! criterion benches. Rows are inserted in bulk with `generate_series` so a
+//! realistic club is ready in well under a second.
+
+use api::auth::Claims;
+use api::AppState;
+use fixtures::{Fixtures, TournamentLiveStatus};
+use infra::models::TokenSubjectRow;
+use uuid::Uuid;
+
+use crate::common::*;
+
+/// A running tournament with every table full.
+pub struct SeatedTournament {
+    pub tournament_id: Uuid,
+    pub manager: Claims,
+}
+
+pub async fn seated_tournament(
+    app_state: &AppState,
+    tables: i32,
+    seats_per_table: i32,
+) -> SeatedTournament {
+    let (manager_id, manager) = manager(app_state).await;
+    let club_id = create_test_club(app_state, "Load Club").await;
+    create_club_manager(app_state, manager_id, club_id).await;
+    let tournament_id = Fixtures::seeded(1)
+        .tournament(club_id)
+        .name("Load Main Event")
+        .seat_cap(tables * seats_per_table)
+        .create(&app_state.db)
+        .await
+        .expect("Failed to create load tournament");
+
+    for number in 1..=tables {
+        let table_id = create_test_club_table(app_state, club_id, number, seats_per_table).await;
+        assign_table_to_tournament(app_state, tournament_id, table_id).await;
+        sqlx::query(
+            "WITH players AS ( \
+                 INSERT INTO club_player (club_id, display_name) \
+                 SELECT $1, 'T' || $3 || ' S' || n FROM generate_series(1, $4) AS n \
+                 RETURNING id, display_name \
+             ), registered AS ( \
+                 INSERT INTO tournament_registrations (tournament_id, club_player_id, status) \
+                 SELECT $2, id, 'seated' FROM players \
+             ) \
+             INSERT INTO table_seat_assignments (tournament_id, club_table_id, club_player_id, seat_number) \
+             SELECT $2, $5, id, row_number() OVER (ORDER BY display_name)::int FROM players",
+        )
+        .bind(club_id)
+        .bind(tournament_id)
+        .bind(number)
+        .bind(seats_per_table)
+        .bind(table_id)
+        .execute(&app_state.db)
+        .await
+        .expect("Failed to seat load players");
+    }
+
+    SeatedTournament {
+        tournament_id,
+        manager,
+    }
+}
+
+/// A club with a season of finished tournaments: every player entered every
+/// event and the top tenth cashed.
+pub async fn club_season(app_state: &AppState, tournaments: i32, players: i32) -> Uuid {
+    let club_id = create_test_club(app_state, "Load League Club").await;
+    sqlx::query(
+        "INSERT INTO club_player (club_id, display_name) \
+         SELECT $1, 'League ' || n FROM generate_series(1, $2) AS n",
+    )
+    .bind(club_id)
+    .bind(players)
+    .execute(&app_state.db)
+    .await
+    .expect("Failed to create league players");
+
+    for n in 1..=tournaments {
+        let tournament_id = create_test_tournament(app_state, club_id, &format!("Week {n}")).await;
+        sqlx::query(
+            "WITH field AS ( \
+                 SELECT id, row_number() OVER (ORDER BY md5(id::text || $2::text))::int AS place \
+                 FROM club_player WHERE club_id = $1 \
+             ), registered AS ( \
+                 INSERT INTO tournament_registrations (tournament_id, club_player_id, status) \
+                 SELECT $2, id, 'busted' FROM field \
+             ) \
+             INSERT INTO tournament_results (tournament_id, club_player_id, final_position, prize_cents, points) \
+             SELECT $2, id, place, 100000 / place, $3 - place \
+             FROM field WHERE place <= GREATEST($3 / 10, 1)",
+        )
+        .bind(club_id)
+        .bind(tournament_id)
+        .bind(players)
+        .execute(&app_state.db)
+        .await
+        .expect("Failed to record league results");
+        sqlx::query(
+            "UPDATE tournaments SET live_status = 'finished'::tournament_live_status \
+             WHERE id = $1",
+        )
+        .bind(tournament_id)
+        .execute(&app_state.db)
+        .await
+        .expect("Failed to finish league tournament");
+    }
+
+    club_id
+}
+
+/// A tournament open for registration with room for `seats` players.
+pub async fn open_tournament(app_state: &AppState, seats: i32) -> Uuid {
+    let club_id = create_test_club(app_state, "Load Registration Club").await;
+    let tournament_id = Fixtures::seeded(1)
+        .tournament(club_id)
+        .name("Load Deepstack")
+        .seat_cap(seats)
+        .live_status(TournamentLiveStatus::RegistrationOpen)
+        .create(&app_state.db)
+        .await
+        .expect("Failed to create load tournament");
+    tournament_id
+}
+
+/// `count` fresh player accounts, with claims to act as each of them.
+pub async fn players(app_state: &AppState, count: i32) -> Vec<Claims> {
+    let batch = Uuid::new_v4().simple().to_string();
+    let rows: Vec<(Uuid, String)> = sqlx::query_as(
+        "INSERT INTO users (email, username, first_name, last_name, password_hash, role, is_active) \
+         SELECT 'load_' || $1 || '_' || n || '@test.com', 'load_' || $1 || '_' || n, \
+                'Load', 'Player ' || n, '$2b$12$dummy.hash.for.testing', 'player', true \
+         FROM generate_series(1, $2) AS n \
+         RETURNING id, email",
+    )
+    .bind(&batch)
+    .bind(count)
+    .fetch_all(&app_state.db)
+    .await
+    .expect("Failed to create load players");
+
+    rows.into_iter()
+        .map(|(id, email)| {
+            let subject = TokenSubjectRow {
+                id,
+                email,
+                role: Some("player".to_string()),
+                is_active: true,
+                club_ids: vec![],
+                token_version: 0,
+            };
+            Claims::new(&subject, 60)
+        })
+        .collect()
+}
+
+async fn manager(app_state: &AppState) -> (Uuid, Claims) {
+    let email = format!("load_manager_{}@test.com", Uuid::new_v4().simple());
+    create_test_user(app_state, &email, "manager").await
+}
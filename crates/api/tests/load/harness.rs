@@ -0,0 +1,76 @@
+//! Latency measurement and regression budgets.
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use futures_util::{stream, StreamExt};
+
+/// Multiplies every budget, for CI runners slower than a developer machine
+/// (`LOAD_BUDGET_SCALE=2.5`).
+const BUDGET_SCALE_VAR: &str = "LOAD_BUDGET_SCALE";
+
+/// Latency budget for one scenario. A run fails when its p95 or worst
+/// request goes over.
+pub struct Budget {
+    pub p95: Duration,
+    pub max: Duration,
+}
+
+/// Latencies of one scenario's requests, sorted.
+pub struct Latencies(Vec<Duration>);
+
+impl Latencies {
+    fn percentile(&self, p: usize) -> Duration {
+        let idx = (self.0.len() * p).div_ceil(100).saturating_sub(1);
+        self.0[idx.min(self.0.len() - 1)]
+    }
+
+    /// Panic with the observed figures when the run is over budget.
+    pub fn assert_within(&self, scenario: &str, budget: Budget) {
+        let scale = std::env::var(BUDGET_SCALE_VAR)
+            .ok()
+            .and_then(|v| v.parse::<f64>().ok())
+            .unwrap_or(1.0);
+        let (p50, p95, max) = (
+            self.percentile(50),
+            self.percentile(95),
+            *self.0.last().unwrap(),
+        );
+        println!(
+            "{scenario}: {} requests, p50 {p50:?}, p95 {p95:?}, max {max:?}",
+            self.0.len()
+        );
+        assert!(
+            p95 <= budget.p95.mul_f64(scale),
+            "{scenario}: p95 {p95:?} over budget {:?}",
+            budget.p95.mul_f64(scale)
+        );
+        assert!(
+            max <= budget.max.mul_f64(scale),
+            "{scenario}: max {max:?} over budget {:?}",
+            budget.max.mul_f64(scale)
+        );
+    }
+}
+
+/// Run `requests` calls of `op`, at most `concurrency` in flight, timing each.
+pub async fn run<F, Fut>(requests: usize, concurrency: usize, op: F) -> Latencies
+where
+    F: Fn(usize) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let mut latencies: Vec<Duration> = stream::iter(0..requests)
+        .map(|i| {
+            let call = op(i);
+            async move {
+                let start = Instant::now();
+                call.await;
+                start.elapsed()
+            }
+        })
+        .buffer_unordered(concurrency)
+        .collect()
+        .await;
+    latencies.sort();
+    Latencies(latencies)
+}
@@ -0,0 +1,154 @@
+//! Load tests for the hot paths: the seating chart, the leaderboard and
+//! registration. Each scenario seeds a realistic club, fires concurrent
+//! requests through the schema and fails when latency goes over its budget.
+//!
+//! Budgets are set for a debug build on a developer machine, about twice
+//! what one observes there: they catch a query going quadratic or an N+1
+//! creeping back in, not a few percent (that's what the criterion benches in
+//! `benches/` are for). Ignored by default; run them on their own so timings
+//! aren't skewed by the integration suite:
+//!
+//! ```text
+//! cargo test -p api --test load -- --ignored --test-threads=1 --nocapture
+//! ```
+
+// The merged GraphQL schema generates deeply-nested async future types.
+#![recursion_limit = "512"]
+
+#[allow(dead_code)]
+#[path = "../integration/common/mod.rs"]
+mod common;
+mod fixtures;
+mod harness;
+
+use std::time::Duration;
+
+use api::gql::build_schema;
+use async_graphql::Variables;
+use serde_json::json;
+
+use common::{execute_graphql, setup_test_db};
+use harness::Budget;
+
+const SEATING_CHART: &str = r#"
+    query($id: UUID!) {
+        tournamentSeatingChart(tournamentId: $id) {
+            tables {
+                table { id tableNumber maxSeats }
+                seats { assignment { seatNumber stackSize } displayName player { id } }
+                currentDealer { displayName }
+            }
+            unassignedPlayers { clubPlayerId }
+            needsRebalancing
+        }
+    }
+"#;
+
+const LEADERBOARD: &str = r#"
+    query($clubId: ID) {
+        leaderboard(clubId: $clubId, period: ALL_TIME, pagination: { limit: 50, offset: 0 }) {
+            items { rank displayName totalWinnings totalTournaments points }
+            totalCount
+        }
+    }
+"#;
+
+const REGISTER: &str = r#"
+    mutation($input: RegisterForTournamentInput!) {
+        registerForTournament(input: $input) { id status }
+    }
+"#;
+
+/// Floor staff refreshing a 30-table chart.
+#[tokio::test]
+#[ignore = "load test: run with --ignored"]
+async fn load_seating_chart() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let seeded = fixtures::seated_tournament(&app_state, 30, 9).await;
+
+    let latencies = harness::run(200, 8, |_| {
+        let (schema, manager) = (&schema, seeded.manager.clone());
+        async move {
+            let vars = Variables::from_json(json!({ "id": seeded.tournament_id }));
+            let response = execute_graphql(schema, SEATING_CHART, Some(vars), Some(manager)).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+    })
+    .await;
+
+    latencies.assert_within(
+        "seating chart (30 tables x 9)",
+        Budget {
+            p95: Duration::from_millis(400),
+            max: Duration::from_millis(1000),
+        },
+    );
+}
+
+/// Players opening the league table after a 40-week season of 300 players.
+#[tokio::test]
+#[ignore = "load test: run with --ignored"]
+async fn load_leaderboard() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let club_id = fixtures::club_season(&app_state, 40, 300).await;
+
+    let latencies = harness::run(100, 8, |_| {
+        let schema = &schema;
+        async move {
+            let vars = Variables::from_json(json!({ "clubId": club_id.to_string() }));
+            let response = execute_graphql(schema, LEADERBOARD, Some(vars), None).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+    })
+    .await;
+
+    latencies.assert_within(
+        "leaderboard (40 events x 300 players)",
+        Budget {
+            p95: Duration::from_millis(3000),
+            max: Duration::from_millis(5000),
+        },
+    );
+}
+
+/// Registration opening: 300 players signing up at once, contending for
+/// the same tournament.
+#[tokio::test]
+#[ignore = "load test: run with --ignored"]
+async fn load_registration() {
+    let app_state = setup_test_db().await;
+    let schema = build_schema(app_state.clone());
+    let tournament_id = fixtures::open_tournament(&app_state, 300).await;
+    let players = fixtures::players(&app_state, 300).await;
+
+    let latencies = harness::run(players.len(), 16, |i| {
+        let (schema, player) = (&schema, players[i].clone());
+        async move {
+            let vars = Variables::from_json(json!({
+                "input": { "tournamentId": tournament_id.to_string() }
+            }));
+            let response = execute_graphql(schema, REGISTER, Some(vars), Some(player)).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+    })
+    .await;
+
+    let registered: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM tournament_registrations WHERE tournament_id = $1",
+    )
+    .bind(tournament_id)
+    .fetch_one(&app_state.db)
+    .await
+    .unwrap();
+    assert_eq!(registered, 300);
+
+    latencies.assert_within(
+        "registration (300 players, 16 at a time)",
+        Budget {
+            p95: Duration::from_millis(250),
+            max: Duration::from_millis(750),
+        },
+    );
+}
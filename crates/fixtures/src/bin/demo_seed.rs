@@ -0,0 +1,1138 @@
+//! Generates the App Store review demo seed (`fixtures/demo/demo_seed.sql`).
+//!
+//! Builds a realistic dataset for the pocketpair.app pilot environment: two
+//! clubs, a cast of Belgian players, ~10 weeks of finished tournaments with
+//! entries/results/payouts, upcoming events the reviewer can register for,
+//! achievements, attendance streaks and a season.
+//!
+//! Deterministic: a fixed [`Fixtures::seeded`] RNG plus name-based (v5) ids,
+//! so re-running produces the same SQL. Dates are anchored on [`anchor`] (a
+//! Saturday); regenerate and re-apply whenever the demo needs to look "fresh"
+//! again.
+//!
+//! Usage: `cargo run -q -p fixtures --bin demo_seed > fixtures/demo/demo_seed.sql`
+//! Apply: `psql "$DATABASE_URL" -f fixtures/00_cleanup.sql -f fixtures/demo/demo_seed.sql`
+
+use std::fmt::Display;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use fixtures::{Fixtures, Level};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::RngExt;
+use sha1::{Digest, Sha1};
+use uuid::Uuid;
+
+const SEED: u64 = 20260704;
+const NS: Uuid = Uuid::from_u128(0xf0c7cc4e_2f2b_4e37_9a2e_0d5b7a1c9e11);
+
+/// Regenerate when stale.
+fn anchor() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 7, 4, 0, 0, 0).unwrap()
+}
+
+/// Both demo logins share this password: PocketPair2026
+const HASH_DEMO: &str = "$2b$12$4.9dd0XFONNttsTDb4YrIOHPgClE4n1vpZc9liC0YigLYmI7uckbO";
+/// Pre-existing owner admin (password: admin), kept from fixtures/02_users.sql
+const HASH_ADMIN: &str = "$2b$12$YkLUdU.KpnCZ78RF3eOMxuDK3DahuGBxf9Q.fqY1oqAZLQovqdjA6";
+
+/// (key, first, last, username, locale)
+const PLAYERS: &[(&str, &str, &str, &str, &str)] = &[
+    ("maxime", "Maxime", "Dupont", "maxthegrinder", "fr"),
+    ("julien", "Julien", "Lambert", "jlamb_poker", "fr"),
+    ("nicolas", "Nicolas", "Peeters", "nico_peeters", "nl"),
+    ("thomas", "Thomas", "Janssens", "tommy_jans", "nl"),
+    ("lucas", "Lucas", "Dubois", "lucky_dubois", "fr"),
+    ("antoine", "Antoine", "Lejeune", "antoine_lj", "fr"),
+    ("hugo", "Hugo", "Claes", "hugoclaes", "nl"),
+    ("sofie", "Sofie", "Vermeulen", "sofie_v", "nl"),
+    ("emma", "Emma", "Van Damme", "emma_vd", "nl"),
+    ("lea", "Léa", "François", "lea_frcs", "fr"),
+    ("camille", "Camille", "Renard", "cam_renard", "fr"),
+    ("sarah", "Sarah", "Goossens", "sgoossens", "nl"),
+    ("manon", "Manon", "Gérard", "manon_g", "fr"),
+    ("julie", "Julie", "Mertens", "julie_m", "nl"),
+    ("kevin", "Kevin", "De Smet", "kdesmet", "nl"),
+    ("dries", "Dries", "Wouters", "dries_w", "nl"),
+    ("bart", "Bart", "Willems", "bartwillems", "nl"),
+    ("olivier", "Olivier", "Simon", "oli_simon", "fr"),
+    ("mathieu", "Mathieu", "Collard", "mcollard", "fr"),
+    ("pierre", "Pierre", "Bodart", "pbodart", "fr"),
+    ("yannick", "Yannick", "Dumont", "yannick_d", "fr"),
+    ("florian", "Florian", "Petit", "flo_petit", "fr"),
+];
+
+/// By field size, mirrors the club default templates.
+const PAYOUT_PCT: &[((usize, usize), &[i64])] = &[
+    ((3, 10), &[70, 30]),
+    ((11, 20), &[50, 30, 20]),
+    ((21, 30), &[37, 25, 15, 12, 11]),
+];
+
+const DEEPSTACK_DESC: &str =
+    "Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.";
+const BOUNTY_DESC: &str =
+    "€10 fixed bounty per knockout. Rebuys allowed for the first four levels.";
+const MAIN_DESC: &str = "Our flagship monthly freezeout. 30k stack, 30-minute levels, top 5 paid.";
+
+/// Reviewer + tablemates at Liège table 1 (key, seat, stack). Most carry a
+/// reviewer note; Antoine is un-noted so the "tap to add a note" state is
+/// visible too.
+const LIVE_SEATS: &[(&str, i32, i32)] = &[
+    ("nicolas", 1, 18500),
+    ("maxime", 2, 41000),
+    ("julien", 3, 22000),
+    ("reviewer", 4, 33500),
+    ("kevin", 5, 27500),
+    ("antoine", 6, 15000),
+    ("thomas", 7, 24000),
+    ("bart", 8, 30500),
+    ("lucas", 9, 12500),
+];
+/// A mid-tournament level of [`levels`] (150/300, 300 ante).
+const LIVE_LEVEL: i32 = 6;
+
+/// Reviewer's arc: 6 events over ~9 weeks (3 within the last 30 days, 3
+/// older) so the 30-day and 1-year stat views clearly differ. 3 cashes incl.
+/// one win; ITM 50%. The win sits ~5 weeks back, so it shows up in the 1-year
+/// view but not the 30-day one. (tournament key, final position)
+const REVIEWER_SCRIPT: &[(&str, usize)] = &[
+    ("deepstack16", 12),   // ~9 weeks back
+    ("deepstack18", 9),    // ~7 weeks back
+    ("deepstack20", 1),    // ~5 weeks back, the win (outside the 30-day window)
+    ("deepstack23", 3),    // ~2 weeks back, cash
+    ("antwerp_freeze", 8), // ~13 days back
+    ("deepstack24", 2),    // ~1 week back, cash
+];
+
+/// Default scoring formula, mirrors `infra::scoring::ScoringFormula::default()`.
+const FORMULA: &str = r#"{"base_points": 2.0, "field_multiplier": 3.0, "buyin_multiplier": 1.0, "min_players": 1, "cap": 60}"#;
+
+/// Scouting pool opt-ins (opponent key, shares_named_pl) — a mix so some
+/// profiles show €P/L, some don't.
+const POOL: &[(&str, bool)] = &[
+    ("maxime", true),
+    ("julien", true),
+    ("nicolas", false),
+    ("thomas", true),
+    ("lucas", false),
+    ("sofie", true),
+    ("emma", false),
+    ("lea", true),
+    ("camille", false),
+    ("kevin", true),
+    ("dries", false),
+    ("bart", true),
+];
+
+/// One entry of the reviewer's scouting book.
+struct Note {
+    subject: &'static str,
+    style: &'static str,
+    body: &'static str,
+    /// (kind, tag)
+    tags: &'static [(&'static str, &'static str)],
+    /// (finished tournament key, showdown)
+    showdown: Option<(&'static str, &'static str)>,
+}
+
+const NOTES: &[Note] = &[
+    Note {
+        subject: "maxime",
+        style: "LAG",
+        body: "Very aggressive pre-flop, 3-bets light from the button. Fires rivers when the board bricks, slows right down out of position.",
+        tags: &[("tag", "aggressive"), ("tag", "tricky"), ("tell", "Reaches for chips early when strong")],
+        showdown: Some(("deepstack24", "Showed a three-barrel bluff with seven-high on a paired board.")),
+    },
+    Note {
+        subject: "julien",
+        style: "TAG",
+        body: "Solid regular. Opens tight from early position and is value-heavy. Folds to a well-timed 3-bet.",
+        tags: &[("tag", "tight"), ("tag", "aggressive")],
+        showdown: None,
+    },
+    Note {
+        subject: "nicolas",
+        style: "LP",
+        body: "Calls far too wide pre and post. Rarely folds top pair. Print value, do not bluff him.",
+        tags: &[("tag", "loose"), ("tag", "passive"), ("tag", "station")],
+        showdown: Some(("deepstack23", "Called three streets with second pair, no kicker.")),
+    },
+    Note {
+        subject: "thomas",
+        style: "TAG",
+        body: "Patient, waits for premiums, c-bets small. Respect his river raises, they are the nuts.",
+        tags: &[("tag", "tight"), ("tag", "rock")],
+        showdown: None,
+    },
+    Note {
+        subject: "lucas",
+        style: "LAG",
+        body: "Loose and splashy, overplays draws and turns middle pair into a bluff. Call down lighter.",
+        tags: &[("tag", "loose"), ("tag", "aggressive"), ("tag", "maniac")],
+        showdown: None,
+    },
+    Note {
+        subject: "sofie",
+        style: "TP",
+        body: "Tight and passive, only bets when she is strong. Fold to any turn or river aggression.",
+        tags: &[("tag", "tight"), ("tag", "passive")],
+        showdown: None,
+    },
+    Note {
+        subject: "emma",
+        style: "LP",
+        body: "Calling station, chases gutshots to the river. Value bet thin, never try to bluff her off a pair.",
+        tags: &[("tag", "station"), ("tag", "loose")],
+        showdown: None,
+    },
+    Note {
+        subject: "kevin",
+        style: "LAG",
+        body: "Table captain, applies constant pressure and iso-raises limpers. Trap with strong hands and let him barrel.",
+        tags: &[("tag", "aggressive"), ("tag", "tricky"), ("tag", "maniac")],
+        showdown: Some(("main2", "4-bet shoved A-5 suited over my 3-bet and tabled it.")),
+    },
+    Note {
+        subject: "bart",
+        style: "TAG",
+        body: "Balanced reg, genuinely hard to read. Avoid marginal spots against him out of position.",
+        tags: &[("tag", "tricky")],
+        showdown: None,
+    },
+    Note {
+        subject: "dries",
+        style: "LP",
+        body: "Passive, only min-raises the nuts. Believe his big bets and fold your bluff-catchers.",
+        tags: &[("tag", "passive"), ("tag", "rock")],
+        showdown: None,
+    },
+];
+
+/// Name-based (v5) id under [`NS`], so every run yields the same ids.
+fn uid(name: &str) -> Uuid {
+    let digest = Sha1::new()
+        .chain_update(NS.as_bytes())
+        .chain_update(name.as_bytes())
+        .finalize();
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[..16]);
+    uuid::Builder::from_sha1_bytes(bytes).into_uuid()
+}
+
+fn club_liege() -> Uuid {
+    uid("club:liege")
+}
+
+fn club_antwerp() -> Uuid {
+    uid("club:antwerp")
+}
+
+/// App account of a cast member (`reviewer`, `manager` or a [`PLAYERS`] key).
+fn user(key: &str) -> Uuid {
+    uid(&format!("user:{key}"))
+}
+
+/// Roster row of a cast member at a club.
+fn cp(club: Uuid, key: &str) -> Uuid {
+    uid(&format!("cp:{club}:{key}"))
+}
+
+fn q(s: impl Display) -> String {
+    format!("'{}'", s.to_string().replace('\'', "''"))
+}
+
+fn ts(dt: DateTime<Utc>) -> String {
+    format!("'{}'", dt.format("%Y-%m-%d %H:%M:%S+00"))
+}
+
+/// `amount` random cast members, in random order.
+fn sample(rng: &mut StdRng, amount: usize) -> Vec<&'static str> {
+    let mut keys: Vec<&str> = PLAYERS.iter().map(|p| p.0).collect();
+    keys.shuffle(rng);
+    keys.truncate(amount);
+    keys
+}
+
+fn levels() -> Vec<Level> {
+    let with_ante = |sb, bb| Level {
+        ante: bb,
+        ..Level::blinds(sb, bb, 20)
+    };
+    vec![
+        Level::blinds(25, 50, 20),
+        Level::blinds(50, 100, 20),
+        Level::blinds(75, 150, 20),
+        with_ante(100, 200),
+        Level::pause(15),
+        with_ante(150, 300),
+        with_ante(200, 400),
+        with_ante(300, 600),
+        with_ante(400, 800),
+        Level::pause(15),
+        with_ante(500, 1000),
+        with_ante(600, 1200),
+        with_ante(800, 1600),
+        with_ante(1000, 2000),
+    ]
+}
+
+fn payout_split(pool: i64, entrants: usize) -> Vec<i64> {
+    for ((lo, hi), pcts) in PAYOUT_PCT {
+        if (*lo..=*hi).contains(&entrants) {
+            let mut prizes: Vec<i64> = pcts
+                .iter()
+                .map(|p| (pool as f64 * *p as f64 / 100.0 / 500.0).round_ties_even() as i64 * 500)
+                .collect();
+            prizes[0] += pool - prizes.iter().sum::<i64>(); // winner absorbs rounding
+            return prizes;
+        }
+    }
+    vec![pool]
+}
+
+struct Tournament {
+    key: String,
+    id: Uuid,
+    club: Uuid,
+    name: String,
+    desc: &'static str,
+    start: DateTime<Utc>,
+    buy_in: i64,
+    rake: i64,
+    stack: i64,
+    status: &'static str,
+    bounty: i64,
+    seat_cap: Option<i32>,
+    /// Finished events: the field in finishing order.
+    participants: Vec<&'static str>,
+    rebuys: Vec<&'static str>,
+    /// Upcoming events: who has registered so far.
+    registered: Vec<&'static str>,
+}
+
+impl Tournament {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        key: impl Into<String>,
+        club: Uuid,
+        name: impl Into<String>,
+        desc: &'static str,
+        start: DateTime<Utc>,
+        buy_in: i64,
+        rake: i64,
+        stack: i64,
+        status: &'static str,
+    ) -> Self {
+        let key = key.into();
+        Self {
+            id: uid(&format!("tournament:{key}")),
+            key,
+            club,
+            name: name.into(),
+            desc,
+            start,
+            buy_in,
+            rake,
+            stack,
+            status,
+            bounty: 0,
+            seat_cap: None,
+            participants: Vec::new(),
+            rebuys: Vec::new(),
+            registered: Vec::new(),
+        }
+    }
+
+    fn bounty(mut self, bounty: i64) -> Self {
+        self.bounty = bounty;
+        self
+    }
+
+    fn seat_cap(mut self, seat_cap: i32) -> Self {
+        self.seat_cap = Some(seat_cap);
+        self
+    }
+
+    fn is_finished(&self) -> bool {
+        self.status == "finished"
+    }
+
+    /// Entries and rebuys, less rake and the bounties paid out at the table.
+    fn prize_pool(&self) -> i64 {
+        let players = self.participants.len() as i64;
+        let entries = players + self.rebuys.len() as i64;
+        entries * self.buy_in - players * self.rake - entries * self.bounty
+    }
+}
+
+/// 20:00 CEST on the Friday `weeks_back` weeks before the anchor Saturday.
+fn fri(weeks_back: i64) -> DateTime<Utc> {
+    anchor() - Duration::days(1 + 7 * weeks_back) + Duration::hours(18)
+}
+
+/// 15:00 CEST on the Sunday `weeks_back` weeks before the anchor.
+fn sun(weeks_back: i64) -> DateTime<Utc> {
+    anchor() - Duration::days(6 + 7 * (weeks_back - 1)) + Duration::hours(13)
+}
+
+fn schedule() -> Vec<Tournament> {
+    let (liege, antwerp) = (club_liege(), club_antwerp());
+    let mut tournaments = Vec::new();
+
+    // Finished — Friday Night Deepstack #14..#24 (11 weeks of history, ~2.5 months)
+    for (i, weeks_back) in (1..=11).rev().enumerate() {
+        tournaments.push(Tournament::new(
+            format!("deepstack{}", 14 + i),
+            liege,
+            format!("Friday Night Deepstack #{}", 14 + i),
+            DEEPSTACK_DESC,
+            fri(weeks_back),
+            5000,
+            500,
+            25000,
+            "finished",
+        ));
+    }
+
+    // Finished — Sunday Bounty #3..#8 (every other Sunday, spanning ~11 weeks)
+    for (i, weeks_back) in [11, 9, 7, 5, 3, 1].into_iter().enumerate() {
+        tournaments.push(
+            Tournament::new(
+                format!("bounty{}", 3 + i),
+                liege,
+                format!("Sunday Bounty #{}", 3 + i),
+                BOUNTY_DESC,
+                sun(weeks_back),
+                4000,
+                500,
+                20000,
+                "finished",
+            )
+            .bounty(1000),
+        );
+    }
+
+    // Finished — Monthly Main Event #1/#2 (last Saturday of May / June)
+    for (n, days_back) in [(1, 35), (2, 7)] {
+        tournaments.push(Tournament::new(
+            format!("main{n}"),
+            liege,
+            format!("Monthly Main Event #{n}"),
+            MAIN_DESC,
+            anchor() - Duration::days(days_back) + Duration::hours(17),
+            15000,
+            1000,
+            30000,
+            "finished",
+        ));
+    }
+
+    // Finished — one Antwerp event the reviewer travelled to (cross-club passport)
+    tournaments.push(Tournament::new(
+        "antwerp_freeze",
+        antwerp,
+        "Sunday Freezeout",
+        "Classic freezeout, one bullet, 20k stack.",
+        anchor() - Duration::days(13) + Duration::hours(13),
+        4000,
+        400,
+        20000,
+        "finished",
+    ));
+
+    // Upcoming
+    tournaments.push(Tournament::new(
+        "deepstack25",
+        liege,
+        "Friday Night Deepstack #25",
+        DEEPSTACK_DESC,
+        anchor() + Duration::days(6) + Duration::hours(18),
+        5000,
+        500,
+        25000,
+        "registration_open",
+    ));
+    tournaments.push(
+        Tournament::new(
+            "bounty9",
+            liege,
+            "Sunday Bounty #9",
+            BOUNTY_DESC,
+            anchor() + Duration::days(8) + Duration::hours(13),
+            4000,
+            500,
+            20000,
+            "registration_open",
+        )
+        .bounty(1000),
+    );
+    tournaments.push(
+        Tournament::new(
+            "main3",
+            liege,
+            "Monthly Main Event #3",
+            MAIN_DESC,
+            anchor() + Duration::days(14) + Duration::hours(17),
+            15000,
+            1000,
+            30000,
+            "registration_open",
+        )
+        .seat_cap(40),
+    );
+    tournaments.push(Tournament::new(
+        "antwerp_turbo",
+        antwerp,
+        "Midweek Turbo",
+        "Fast structure, 12-minute levels — done before midnight.",
+        anchor() + Duration::days(5) + Duration::hours(17) + Duration::minutes(30),
+        3000,
+        300,
+        15000,
+        "registration_open",
+    ));
+
+    // In-progress event the reviewer is seated at, right now — powers the "My
+    // Table" view (see tablemates + private notes during a live tournament).
+    // Times are NOW()-relative at apply time, so it always looks live
+    // regardless of the anchor.
+    tournaments.push(Tournament::new(
+        "live_now",
+        liege,
+        "Tuesday Night Live",
+        "Live now, deepstack in progress. 25k stack, 20-minute levels.",
+        anchor(),
+        5000,
+        500,
+        25000,
+        "in_progress",
+    ));
+
+    tournaments
+}
+
+/// Draws the finished fields (with the reviewer's scripted finishes) and the
+/// upcoming registration lists.
+fn draw_fields(rng: &mut StdRng, tournaments: &mut [Tournament]) {
+    for t in tournaments.iter_mut().filter(|t| t.is_finished()) {
+        let n = rng.random_range(16..=22);
+        let mut field = sample(rng, n);
+        if rng.random_bool(0.4) && !field.contains(&"manager") {
+            // Marc plays sometimes
+            field[rng.random_range(0..n)] = "manager";
+        }
+        if let Some(&(_, pos)) = REVIEWER_SCRIPT.iter().find(|(key, _)| *key == t.key) {
+            field.retain(|k| *k != "reviewer");
+            field.insert(pos - 1, "reviewer");
+        }
+        t.participants = field;
+        if t.bounty > 0 {
+            t.rebuys = t
+                .participants
+                .iter()
+                .copied()
+                .filter(|k| *k != "reviewer" && rng.random_bool(0.2))
+                .collect();
+        }
+    }
+
+    // Upcoming registration lists (reviewer pre-registered for Sunday Bounty #9)
+    for (key, registered) in [
+        ("deepstack25", sample(rng, 9)),
+        ("bounty9", [vec!["reviewer"], sample(rng, 7)].concat()),
+        ("main3", sample(rng, 14)),
+        ("antwerp_turbo", sample(rng, 5)),
+    ] {
+        if let Some(t) = tournaments.iter_mut().find(|t| t.key == key) {
+            t.registered = registered;
+        }
+    }
+}
+
+fn values(rows: Vec<String>) -> String {
+    rows.join(",\n") + ";"
+}
+
+fn users(rng: &mut StdRng) -> Vec<String> {
+    let mut rows = vec![
+        format!("({}, 'reviewer@pocketpair.app', 'alexmartin', 'Alex', 'Martin', 'player', 'en', {}, NOW() - interval '1 day')", q(user("reviewer")), q(HASH_DEMO)),
+        format!("({}, 'manager@pocketpair.app', 'marc_ldc', 'Marc', 'Delvaux', 'manager', 'fr', {}, NOW() - interval '2 hours')", q(user("manager")), q(HASH_DEMO)),
+        format!("({}, 'moyse94@gmail.com', 'super_admin', 'Admin', 'Global', 'admin', 'en', {}, NOW())", q(user("admin")), q(HASH_ADMIN)),
+    ];
+    for (key, first, last, username, locale) in PLAYERS {
+        let seen = rng.random_range(1..=96);
+        rows.push(format!(
+            "({}, '{key}.{}@demo.pocketpair.app', {}, {}, {}, 'player', '{locale}', NULL, NOW() - interval '{seen} hours')",
+            q(user(key)),
+            last.to_lowercase().replace(' ', ""),
+            q(username),
+            q(first),
+            q(last),
+        ));
+    }
+    vec![
+        "-- === Users ===".to_string(),
+        "INSERT INTO users (id, email, username, first_name, last_name, role, locale, password_hash, last_seen_at) VALUES\n".to_string()
+            + &values(rows),
+    ]
+}
+
+fn clubs() -> Vec<String> {
+    let (liege, antwerp) = (club_liege(), club_antwerp());
+    let manager = user("manager");
+    let mut out = vec![
+        "\n-- === Clubs (insert re-fires the default template triggers) ===".to_string(),
+        // 'free' clubs are hidden from player discovery
+        format!(
+            "INSERT INTO clubs (id, name, city, country, address, postal_code, plan) VALUES\n\
+             ({}, 'Soumagne Poker Club', 'Soumagne', 'BE', 'Rue du Centre 12', '4630', 'club'),\n\
+             ({}, 'Antwerp Card Room', 'Antwerpen', 'BE', 'Lange Koepoortstraat 47', '2000', 'club');",
+            q(liege),
+            q(antwerp)
+        ),
+        format!(
+            "INSERT INTO club_managers (club_id, user_id) VALUES\n({}, {}), ({}, {});",
+            q(liege),
+            q(manager),
+            q(antwerp),
+            q(manager)
+        ),
+    ];
+    let tables = [(liege, 4), (antwerp, 2)]
+        .into_iter()
+        .flat_map(|(club, count)| {
+            (1..=count).map(move |n| {
+                format!(
+                    "({}, {}, {n}, 9, true)",
+                    q(uid(&format!("table:{club}:{n}"))),
+                    q(club)
+                )
+            })
+        })
+        .collect();
+    out.push(
+        "INSERT INTO club_tables (id, club_id, table_number, max_seats, is_default) VALUES\n"
+            .to_string()
+            + &values(tables),
+    );
+
+    // club_player roster rows (club-scoped identity used by regs/entries/results)
+    out.push("\n-- === Club rosters ===".to_string());
+    let cast: Vec<(&str, String)> = [
+        ("reviewer", "Alex Martin".to_string()),
+        ("manager", "Marc Delvaux".to_string()),
+    ]
+    .into_iter()
+    .chain(
+        PLAYERS
+            .iter()
+            .map(|(key, first, last, ..)| (*key, format!("{first} {last}"))),
+    )
+    .collect();
+    let mut rows = Vec::new();
+    for club in [liege, antwerp] {
+        for (key, display) in &cast {
+            rows.push(format!(
+                "({}, {}, {}, {})",
+                q(cp(club, key)),
+                q(club),
+                q(display),
+                q(user(key))
+            ));
+        }
+    }
+    out.push(
+        "INSERT INTO club_player (id, club_id, display_name, app_user_id) VALUES\n".to_string()
+            + &values(rows),
+    );
+    out
+}
+
+fn tournament_rows(tournaments: &[Tournament]) -> Vec<String> {
+    let rows = tournaments
+        .iter()
+        .map(|t| {
+            let (start, end) = match t.status {
+                "in_progress" => (
+                    "NOW() - interval '95 minutes'".to_string(),
+                    "NULL".to_string(),
+                ),
+                "finished" => (ts(t.start), ts(t.start + Duration::hours(5))),
+                _ => (ts(t.start), "NULL".to_string()),
+            };
+            // Finished events are closed once their results are in (see
+            // `results`): the entry-limit trigger refuses entries after that.
+            let status = if t.is_finished() { "in_progress" } else { t.status };
+            let cap = t.seat_cap.map_or("NULL".to_string(), |c| c.to_string());
+            let bounty_type = if t.bounty > 0 { "'fixed'" } else { "'none'" };
+            format!(
+                "({}, {}, {}, {}, {start}, {end}, {}, {}, {cap}, {}, '{status}', {bounty_type}, {}, 6)",
+                q(t.id),
+                q(t.club),
+                q(&t.name),
+                q(t.desc),
+                t.buy_in,
+                t.rake,
+                t.stack,
+                t.bounty
+            )
+        })
+        .collect();
+
+    let structures = tournaments
+        .iter()
+        .flat_map(|t| {
+            (1..).zip(levels()).map(move |(number, level)| {
+                let break_minutes = if level.is_break {
+                    level.duration_minutes.to_string()
+                } else {
+                    "NULL".to_string()
+                };
+                format!(
+                    "({}, {number}, {}, {}, {}, {}, {}, {break_minutes})",
+                    q(t.id),
+                    level.small_blind,
+                    level.big_blind,
+                    level.ante,
+                    level.duration_minutes,
+                    level.is_break
+                )
+            })
+        })
+        .collect();
+
+    vec![
+        "\n-- === Tournaments ===".to_string(),
+        "INSERT INTO tournaments (id, club_id, name, description, start_time, end_time, \
+         buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, bounty_type, \
+         bounty_amount_cents, late_registration_level) VALUES\n"
+            .to_string()
+            + &values(rows),
+        "\n-- === Blind structures ===".to_string(),
+        "INSERT INTO tournament_structures (tournament_id, level_number, small_blind, big_blind, \
+         ante, duration_minutes, is_break, break_duration_minutes) VALUES\n"
+            .to_string()
+            + &values(structures),
+    ]
+}
+
+const REGISTRATIONS: &str = "INSERT INTO tournament_registrations (tournament_id, user_id, club_player_id, registration_time, status) VALUES\n";
+const ENTRIES: &str =
+    "INSERT INTO tournament_entries (tournament_id, user_id, club_player_id, entry_type, \
+     amount_cents, chips_received, payment_method, recorded_by, created_at) VALUES\n";
+const CHECK_INS: &str =
+    "INSERT INTO check_in (app_user_id, tournament_id, club_id, checked_in_at) VALUES\n";
+
+fn registrations(rng: &mut StdRng, tournaments: &[Tournament]) -> Vec<String> {
+    let manager = q(user("manager"));
+    let (mut regs, mut entries, mut check_ins) = (Vec::new(), Vec::new(), Vec::new());
+    // The live event is emitted in its own block, see `live_table`.
+    for t in tournaments.iter().filter(|t| t.status != "in_progress") {
+        if t.is_finished() {
+            for key in &t.participants {
+                let reg_time = t.start
+                    - Duration::days(rng.random_range(1..=5))
+                    - Duration::minutes(rng.random_range(0..=600));
+                regs.push(format!(
+                    "({}, {}, {}, {}, 'busted')",
+                    q(t.id),
+                    q(user(key)),
+                    q(cp(t.club, key)),
+                    ts(reg_time)
+                ));
+                entries.push(format!(
+                    "({}, {}, {}, 'initial', {}, {}, 'cash', {manager}, {})",
+                    q(t.id),
+                    q(user(key)),
+                    q(cp(t.club, key)),
+                    t.buy_in,
+                    t.stack,
+                    ts(t.start)
+                ));
+                let checked_in = t.start - Duration::minutes(rng.random_range(5..=40));
+                check_ins.push(format!(
+                    "({}, {}, {}, {})",
+                    q(user(key)),
+                    q(t.id),
+                    q(t.club),
+                    ts(checked_in)
+                ));
+            }
+            for key in &t.rebuys {
+                let at = t.start + Duration::minutes(rng.random_range(30..=90));
+                entries.push(format!(
+                    "({}, {}, {}, 'rebuy', {}, {}, 'cash', {manager}, {})",
+                    q(t.id),
+                    q(user(key)),
+                    q(cp(t.club, key)),
+                    t.buy_in,
+                    t.stack,
+                    ts(at)
+                ));
+            }
+        } else {
+            for key in &t.registered {
+                let reg_time = anchor() - Duration::hours(rng.random_range(2..=72));
+                regs.push(format!(
+                    "({}, {}, {}, {}, 'registered')",
+                    q(t.id),
+                    q(user(key)),
+                    q(cp(t.club, key)),
+                    ts(reg_time)
+                ));
+            }
+        }
+    }
+    vec![
+        "\n-- === Registrations, entries, check-ins ===".to_string(),
+        REGISTRATIONS.to_string() + &values(regs),
+        ENTRIES.to_string() + &values(entries),
+        CHECK_INS.to_string() + &values(check_ins),
+    ]
+}
+
+fn live_table(live: &Tournament) -> Vec<String> {
+    let liege = club_liege();
+    let table = uid(&format!("table:{liege}:1"));
+    let manager = q(user("manager"));
+    let (mut regs, mut entries, mut check_ins, mut seats) =
+        (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+    for (key, seat, stack) in LIVE_SEATS {
+        let user_id = q(user(key));
+        let cpid = q(cp(liege, key));
+        regs.push(format!(
+            "({}, {user_id}, {cpid}, NOW() - interval '2 hours', 'seated')",
+            q(live.id)
+        ));
+        entries.push(format!(
+            "({}, {user_id}, {cpid}, 'initial', {}, {}, 'cash', {manager}, NOW() - interval '110 minutes')",
+            q(live.id),
+            live.buy_in,
+            live.stack
+        ));
+        check_ins.push(format!(
+            "({user_id}, {}, {}, NOW() - interval '110 minutes')",
+            q(live.id),
+            q(liege)
+        ));
+        seats.push(format!(
+            "({}, {}, {user_id}, {cpid}, {seat}, {stack}, true, {manager}, NOW() - interval '95 minutes')",
+            q(live.id),
+            q(table)
+        ));
+    }
+    vec![
+        "\n-- === Live in-progress tournament: seated field + running clock ===".to_string(),
+        REGISTRATIONS.to_string() + &values(regs),
+        ENTRIES.to_string() + &values(entries),
+        CHECK_INS.to_string() + &values(check_ins),
+        "INSERT INTO table_seat_assignments (tournament_id, club_table_id, user_id, club_player_id, \
+         seat_number, stack_size, is_current, assigned_by, assigned_at) VALUES\n"
+            .to_string()
+            + &values(seats),
+        // Park the clock PAUSED at a mid-event level so the demo shows a stable
+        // live snapshot (150/300, ~12:00 on the clock) that neither drifts nor
+        // freezes at 00:00. A paused clock is skipped by the auto-advance
+        // sweep; remaining time is computed as level_end_time - pause_started_at.
+        format!(
+            "UPDATE tournament_clocks SET clock_status = 'paused', auto_advance = false, \
+             current_level = {LIVE_LEVEL}, level_started_at = NOW() - interval '8 minutes', \
+             level_end_time = NOW() + interval '12 minutes', pause_started_at = NOW() \
+             WHERE tournament_id = {};",
+            q(live.id)
+        ),
+    ]
+}
+
+fn results(tournaments: &[Tournament]) -> Vec<String> {
+    let (mut results, mut payouts, mut finished) = (Vec::new(), Vec::new(), Vec::new());
+    for t in tournaments.iter().filter(|t| t.is_finished()) {
+        finished.push(q(t.id));
+        let n = t.participants.len();
+        let pool = t.prize_pool();
+        let prizes = payout_split(pool, n);
+        for (pos, key) in (1..).zip(&t.participants) {
+            let prize = prizes.get(pos - 1).copied().unwrap_or(0);
+            let points = n + 1 - pos;
+            results.push(format!(
+                "({}, {}, {}, {pos}, {prize}, {points})",
+                q(t.id),
+                q(user(key)),
+                q(cp(t.club, key))
+            ));
+        }
+        let positions = (1..)
+            .zip(&prizes)
+            .map(|(pos, amount)| format!(r#"{{"position": {pos}, "amount_cents": {amount}}}"#))
+            .collect::<Vec<_>>()
+            .join(", ");
+        payouts.push(format!(
+            "({}, {n}, {pool}, {}::jsonb)",
+            q(t.id),
+            q(format!("[{positions}]"))
+        ));
+    }
+    vec![
+        "\n-- === Results & payouts ===".to_string(),
+        "INSERT INTO tournament_results (tournament_id, user_id, club_player_id, final_position, prize_cents, points) VALUES\n"
+            .to_string()
+            + &values(results),
+        "INSERT INTO tournament_payouts (tournament_id, player_count, total_prize_pool, payout_positions) VALUES\n"
+            .to_string()
+            + &payouts.join(",\n")
+            + "\nON CONFLICT (tournament_id) DO UPDATE SET player_count = EXCLUDED.player_count, \
+               total_prize_pool = EXCLUDED.total_prize_pool, payout_positions = EXCLUDED.payout_positions;",
+        // Finishing fires the points trigger, as when the desk closes the event.
+        format!(
+            "UPDATE tournaments SET live_status = 'finished' WHERE id IN (\n{}\n);",
+            finished.join(",\n")
+        ),
+    ]
+}
+
+/// One cast member's finished events, each as (start, tournament id).
+#[derive(Default)]
+struct Record {
+    played: Vec<(DateTime<Utc>, Uuid)>,
+    cashes: Vec<(DateTime<Utc>, Uuid)>,
+    wins: Vec<(DateTime<Utc>, Uuid)>,
+    clubs: Vec<Uuid>,
+}
+
+/// Records of everyone who played, in order of first appearance.
+fn records(tournaments: &[Tournament]) -> Vec<(&'static str, Record)> {
+    let mut records: Vec<(&str, Record)> = Vec::new();
+    for t in tournaments.iter().filter(|t| t.is_finished()) {
+        let paid = payout_split(t.prize_pool(), t.participants.len()).len();
+        for (pos, key) in (1..).zip(&t.participants) {
+            let index = match records.iter().position(|(k, _)| k == key) {
+                Some(index) => index,
+                None => {
+                    records.push((key, Record::default()));
+                    records.len() - 1
+                }
+            };
+            let record = &mut records[index].1;
+            record.played.push((t.start, t.id));
+            if !record.clubs.contains(&t.club) {
+                record.clubs.push(t.club);
+            }
+            if pos <= paid {
+                record.cashes.push((t.start, t.id));
+            }
+            if pos == 1 {
+                record.wins.push((t.start, t.id));
+            }
+        }
+    }
+    for (_, record) in &mut records {
+        record.played.sort();
+        record.cashes.sort();
+        record.wins.sort();
+    }
+    records
+}
+
+fn achievements(records: &[(&str, Record)]) -> Vec<String> {
+    let mut rows = Vec::new();
+    let mut ach = |key: &str, code: &str, (when, tid): (DateTime<Utc>, Uuid), progress: usize| {
+        rows.push(format!(
+            "SELECT {}::uuid, id, {}::timestamptz, {}::uuid, {progress} FROM achievements WHERE code = '{code}'",
+            q(user(key)),
+            ts(when),
+            q(tid)
+        ));
+    };
+
+    for (key, r) in records {
+        ach(key, "first_registration", r.played[0], 1);
+        if let Some(&first) = r.cashes.first() {
+            ach(key, "first_cash", first, 1);
+        }
+        if let Some(&first) = r.wins.first() {
+            ach(key, "first_win", first, 1);
+        }
+        if r.played.len() >= 5 {
+            ach(key, "tournaments_5", r.played[4], r.played.len());
+        }
+        if r.clubs.len() >= 2 {
+            ach(key, "clubs_2", r.played[r.played.len() - 1], 2);
+        }
+        let itm =
+            (100.0 * r.cashes.len() as f64 / r.played.len() as f64).round_ties_even() as usize;
+        if itm >= 50 && r.played.len() >= 4 {
+            ach(key, "itm_rate_50", r.cashes[r.cashes.len() - 1], itm);
+        }
+    }
+    // Reviewer extra: 5-week attendance streak unlocked during the finale week
+    if let Some((_, reviewer)) = records.iter().find(|(k, _)| *k == "reviewer") {
+        ach(
+            "reviewer",
+            "streak_play_5",
+            reviewer.played[reviewer.played.len() - 1],
+            5,
+        );
+    }
+
+    vec![
+        "\n-- === Achievements (derived from the seeded results, so they stay consistent) ==="
+            .to_string(),
+        "INSERT INTO player_achievements (user_id, achievement_id, unlocked_at, tournament_id, progress)\n"
+            .to_string()
+            + &rows.join("\nUNION ALL\n")
+            + ";",
+    ]
+}
+
+fn streaks(rng: &mut StdRng, records: &[(&str, Record)]) -> Vec<String> {
+    let rows = records
+        .iter()
+        .map(|(key, r)| {
+            let played = r.played.len();
+            let mut current = played.min(rng.random_range(0..=5));
+            let mut longest = current.max(played.min(rng.random_range(1..=7)));
+            if *key == "reviewer" {
+                (current, longest) = (4, 5);
+            }
+            format!(
+                "({}, {current}, {longest}, {})",
+                q(user(key)),
+                ts(r.played[played - 1].0)
+            )
+        })
+        .collect();
+    vec![
+        "\n-- === Attendance streaks ===".to_string(),
+        "INSERT INTO attendance_streak (app_user_id, current_streak, longest_streak, last_check_in_at) VALUES\n"
+            .to_string()
+            + &values(rows),
+    ]
+}
+
+fn season() -> Vec<String> {
+    let liege = club_liege();
+    let season_id = uid("season:summer2026");
+    let config_id = uid("lbconfig:summer2026");
+    let starts = ts(Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap());
+    let ends = ts(Utc.with_ymd_and_hms(2026, 8, 31, 22, 0, 0).unwrap());
+    let passes = ["reviewer"]
+        .into_iter()
+        .chain(PLAYERS[..10].iter().map(|p| p.0))
+        .map(|key| format!("({}, {})", q(season_id), q(user(key))))
+        .collect();
+    vec![
+        "\n-- === Season & league ===".to_string(),
+        format!(
+            "INSERT INTO season (id, club_id, name, starts_at, ends_at) VALUES\n\
+             ({}, {}, 'Summer Series 2026', {starts}, {ends});",
+            q(season_id),
+            q(liege)
+        ),
+        "INSERT INTO season_pass (season_id, app_user_id) VALUES\n".to_string() + &values(passes),
+        format!(
+            "INSERT INTO leaderboard_configs (id, club_id, name, formula_params, membership_mode, period_start, period_end, is_default) VALUES\n\
+             ({}, {}, 'Summer Series 2026', {}::jsonb, 'all_in_period', {starts}, {ends}, true);",
+            q(config_id),
+            q(liege),
+            q(FORMULA)
+        ),
+        format!(
+            "UPDATE tournaments SET leaderboard_config_id = {} WHERE club_id = {} AND start_time >= {starts};",
+            q(config_id),
+            q(liege)
+        ),
+    ]
+}
+
+/// Opponent lookup is consent-gated: only users who opted into the pool are
+/// discoverable, and only those who also share named P/L expose the euro
+/// figure. Opt a realistic subset in so the demo account can actually search
+/// and look up.
+fn scouting_pool() -> Vec<String> {
+    // reviewer: discoverable, shares P/L
+    let rows = [("reviewer", true)]
+        .iter()
+        .chain(POOL)
+        .map(|(key, share)| format!("({}, {share}, true)", q(user(key))))
+        .collect();
+    vec![
+        "\n-- === Scouting pool opt-ins (discoverability + named P/L consent) ===".to_string(),
+        "INSERT INTO user_privacy_settings (app_user_id, share_named_pl, in_scouting_pool) VALUES\n"
+            .to_string() + &values(rows),
+    ]
+}
+
+/// The reviewer's private scouting book: one note per opponent
+/// (author-scoped), with a style read, quick tags/tells, and the occasional
+/// showdown logged.
+fn opponent_notes() -> Vec<String> {
+    let (mut notes, mut tags, mut showdowns) = (Vec::new(), Vec::new(), Vec::new());
+    for note in NOTES {
+        let id = uid(&format!("note:reviewer:{}", note.subject));
+        notes.push(format!(
+            "({}, {}, {}, {}, {})",
+            q(id),
+            q(user("reviewer")),
+            q(cp(club_liege(), note.subject)),
+            q(note.body),
+            q(note.style)
+        ));
+        for (kind, tag) in note.tags {
+            tags.push(format!("({}, '{kind}', {})", q(id), q(tag)));
+        }
+        if let Some((tournament, description)) = note.showdown {
+            showdowns.push(format!(
+                "({}, {}, {})",
+                q(id),
+                q(uid(&format!("tournament:{tournament}"))),
+                q(description)
+            ));
+        }
+    }
+    vec![
+        "\n-- === Opponent notes (reviewer's private scouting book) ===".to_string(),
+        "INSERT INTO player_note (id, author_app_user_id, subject_club_player_id, body, style) VALUES\n"
+            .to_string()
+            + &values(notes),
+        "INSERT INTO player_note_tag (note_id, kind, tag) VALUES\n".to_string() + &values(tags),
+        "INSERT INTO showdown_observation (note_id, tournament_id, description) VALUES\n"
+            .to_string()
+            + &values(showdowns),
+    ]
+}
+
+fn main() {
+    let mut fx = Fixtures::seeded(SEED);
+    let rng = fx.rng();
+
+    let mut out = users(rng);
+    out.extend(clubs());
+
+    let mut tournaments = schedule();
+    draw_fields(rng, &mut tournaments);
+    out.extend(tournament_rows(&tournaments));
+    out.extend(registrations(rng, &tournaments));
+    if let Some(live) = tournaments.iter().find(|t| t.status == "in_progress") {
+        out.extend(live_table(live));
+    }
+    out.extend(results(&tournaments));
+
+    let records = records(&tournaments);
+    out.extend(achievements(&records));
+    out.extend(streaks(rng, &records));
+    out.extend(season());
+    out.extend(scouting_pool());
+    out.extend(opponent_notes());
+
+    println!("-- App Store review demo seed — GENERATED by `cargo run -p fixtures --bin demo_seed`, do not edit by hand.");
+    println!(
+        "-- Anchor date: {}. Regenerate + re-apply when the data looks stale.",
+        anchor().date_naive()
+    );
+    println!("-- Apply after fixtures/00_cleanup.sql. Logins: reviewer@pocketpair.app /");
+    println!("-- manager@pocketpair.app, password PocketPair2026 (admin unchanged).");
+    println!("BEGIN;");
+    println!("{}", out.join("\n"));
+    println!("COMMIT;");
+}
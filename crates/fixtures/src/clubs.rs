@@ -0,0 +1,122 @@
+use sqlx::{PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+/// A club. Defaults to the paid `club` plan so free-tier limits (one table,
+/// one active tournament) don't get in the way.
+#[derive(Debug, Clone)]
+pub struct ClubFactory {
+    id: Uuid,
+    name: String,
+    city: String,
+    plan: String,
+}
+
+impl ClubFactory {
+    pub(crate) fn new(name: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            city: "Test City".to_string(),
+            plan: "club".to_string(),
+        }
+    }
+
+    pub fn id(mut self, id: Uuid) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn city(mut self, city: impl Into<String>) -> Self {
+        self.city = city.into();
+        self
+    }
+
+    /// `free`, `club` or `casino`.
+    pub fn plan(mut self, plan: impl Into<String>) -> Self {
+        self.plan = plan.into();
+        self
+    }
+
+    pub async fn create<'e>(self, executor: impl PgExecutor<'e>) -> SqlxResult<Uuid> {
+        sqlx::query(
+            "INSERT INTO clubs (id, name, city, plan) VALUES ($1, $2, $3, $4)
+             ON CONFLICT (id) DO NOTHING",
+        )
+        .bind(self.id)
+        .bind(self.name)
+        .bind(self.city)
+        .bind(self.plan)
+        .execute(executor)
+        .await?;
+        Ok(self.id)
+    }
+}
+
+/// A roster entry, optionally linked to an app account.
+#[derive(Debug, Clone)]
+pub struct ClubPlayerFactory {
+    id: Uuid,
+    club_id: Uuid,
+    display_name: Option<String>,
+    first_name: String,
+    last_name: String,
+    app_user_id: Option<Uuid>,
+}
+
+impl ClubPlayerFactory {
+    pub(crate) fn new(club_id: Uuid, first_name: String, last_name: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            club_id,
+            display_name: None,
+            first_name,
+            last_name,
+            app_user_id: None,
+        }
+    }
+
+    pub fn id(mut self, id: Uuid) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Defaults to "First L." with a short id suffix, unique in the club.
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    pub fn app_user(mut self, user_id: Uuid) -> Self {
+        self.app_user_id = Some(user_id);
+        self
+    }
+
+    pub async fn create<'e>(self, executor: impl PgExecutor<'e>) -> SqlxResult<Uuid> {
+        let display_name = self.display_name.unwrap_or_else(|| {
+            format!(
+                "{} {}. {}",
+                self.first_name,
+                &self.last_name[..1],
+                &self.id.simple().to_string()[..4]
+            )
+        });
+        sqlx::query(
+            "INSERT INTO club_player (id, club_id, display_name, first_name, last_name, app_user_id)
+             VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(self.id)
+        .bind(self.club_id)
+        .bind(display_name)
+        .bind(self.first_name)
+        .bind(self.last_name)
+        .bind(self.app_user_id)
+        .execute(executor)
+        .await?;
+        Ok(self.id)
+    }
+}
@@ -0,0 +1,64 @@
+use sqlx::{PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+/// A registration in `registered` status. Give it an app user, a roster
+/// player or both; the link trigger fills in the other side.
+#[derive(Debug, Clone)]
+pub struct RegistrationFactory {
+    id: Uuid,
+    tournament_id: Uuid,
+    user_id: Option<Uuid>,
+    club_player_id: Option<Uuid>,
+    status: String,
+}
+
+impl RegistrationFactory {
+    pub(crate) fn new(tournament_id: Uuid) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            tournament_id,
+            user_id: None,
+            club_player_id: None,
+            status: "registered".to_string(),
+        }
+    }
+
+    pub fn id(mut self, id: Uuid) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn user(mut self, user_id: Uuid) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    pub fn club_player(mut self, club_player_id: Uuid) -> Self {
+        self.club_player_id = Some(club_player_id);
+        self
+    }
+
+    /// `registered`, `checked_in`, `seated`, `busted`, `waitlisted`, ...
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = status.into();
+        self
+    }
+
+    /// Insert the registration and return its id. A player already registered
+    /// for the tournament keeps their registration.
+    pub async fn create<'e>(self, executor: impl PgExecutor<'e>) -> SqlxResult<Uuid> {
+        sqlx::query(
+            "INSERT INTO tournament_registrations (id, tournament_id, user_id, club_player_id, status)
+             VALUES ($1, $2, $3, $4, $5)
+             ON CONFLICT DO NOTHING",
+        )
+        .bind(self.id)
+        .bind(self.tournament_id)
+        .bind(self.user_id)
+        .bind(self.club_player_id)
+        .bind(self.status)
+        .execute(executor)
+        .await?;
+        Ok(self.id)
+    }
+}
@@ -0,0 +1,64 @@
+use sqlx::{PgPool, Result as SqlxResult};
+use uuid::Uuid;
+
+/// A club table of 9 seats, optionally linked to a tournament.
+#[derive(Debug, Clone)]
+pub struct TableFactory {
+    id: Uuid,
+    club_id: Uuid,
+    table_number: i32,
+    max_seats: i32,
+    tournament_id: Option<Uuid>,
+}
+
+impl TableFactory {
+    pub(crate) fn new(club_id: Uuid, table_number: i32) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            club_id,
+            table_number,
+            max_seats: 9,
+            tournament_id: None,
+        }
+    }
+
+    pub fn id(mut self, id: Uuid) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn max_seats(mut self, max_seats: i32) -> Self {
+        self.max_seats = max_seats;
+        self
+    }
+
+    /// Also assign the table to this tournament.
+    pub fn for_tournament(mut self, tournament_id: Uuid) -> Self {
+        self.tournament_id = Some(tournament_id);
+        self
+    }
+
+    pub async fn create(self, db: &PgPool) -> SqlxResult<Uuid> {
+        sqlx::query(
+            "INSERT INTO club_tables (id, club_id, table_number, max_seats) VALUES ($1, $2, $3, $4)
+             ON CONFLICT (id) DO NOTHING",
+        )
+        .bind(self.id)
+        .bind(self.club_id)
+        .bind(self.table_number)
+        .bind(self.max_seats)
+        .execute(db)
+        .await?;
+        if let Some(tournament_id) = self.tournament_id {
+            sqlx::query(
+                "INSERT INTO tournament_table_assignments (tournament_id, club_table_id) VALUES ($1, $2)
+                 ON CONFLICT DO NOTHING",
+            )
+            .bind(tournament_id)
+            .bind(self.id)
+            .execute(db)
+            .await?;
+        }
+        Ok(self.id)
+    }
+}
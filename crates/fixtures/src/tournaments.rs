@@ -0,0 +1,185 @@
+use chrono::{DateTime, Duration, Utc};
+use infra::repos::tournaments::TournamentLiveStatus;
+use sqlx::{PgPool, Result as SqlxResult};
+use uuid::Uuid;
+
+/// One level of a blind structure.
+#[derive(Debug, Clone, Copy)]
+pub struct Level {
+    pub small_blind: i32,
+    pub big_blind: i32,
+    pub ante: i32,
+    pub duration_minutes: i32,
+    pub is_break: bool,
+}
+
+impl Level {
+    pub fn blinds(small_blind: i32, big_blind: i32, duration_minutes: i32) -> Self {
+        Self {
+            small_blind,
+            big_blind,
+            ante: 0,
+            duration_minutes,
+            is_break: false,
+        }
+    }
+
+    pub fn pause(duration_minutes: i32) -> Self {
+        Self {
+            small_blind: 0,
+            big_blind: 0,
+            ante: 0,
+            duration_minutes,
+            is_break: true,
+        }
+    }
+
+    /// `count` playing levels from 25/50, each roughly 1.5x the last, with a
+    /// big-blind ante from level 5.
+    pub fn standard(count: usize, duration_minutes: i32) -> Vec<Self> {
+        const BIG_BLINDS: &[i32] = &[
+            50, 100, 150, 200, 300, 400, 600, 800, 1000, 1200, 1600, 2000, 3000, 4000, 5000, 6000,
+            8000, 10000, 15000, 20000,
+        ];
+        (0..count)
+            .map(|i| {
+                let big_blind = BIG_BLINDS.get(i).copied().unwrap_or_else(|| {
+                    BIG_BLINDS[BIG_BLINDS.len() - 1] * 2_i32.pow((i + 1 - BIG_BLINDS.len()) as u32)
+                });
+                let mut level = Self::blinds(big_blind / 2, big_blind, duration_minutes);
+                if i >= 4 {
+                    level.ante = big_blind;
+                }
+                level
+            })
+            .collect()
+    }
+}
+
+/// A tournament starting now, with a €50 buy-in and 100 seats, not started.
+#[derive(Debug, Clone)]
+pub struct TournamentFactory {
+    id: Uuid,
+    club_id: Uuid,
+    name: String,
+    description: String,
+    start_time: DateTime<Utc>,
+    duration: Duration,
+    buy_in_cents: i64,
+    seat_cap: i32,
+    live_status: TournamentLiveStatus,
+    structure: Vec<Level>,
+}
+
+impl TournamentFactory {
+    pub(crate) fn new(club_id: Uuid, name: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            club_id,
+            name,
+            description: "Test tournament description".to_string(),
+            start_time: Utc::now(),
+            duration: Duration::hours(4),
+            buy_in_cents: 5_000,
+            seat_cap: 100,
+            live_status: TournamentLiveStatus::NotStarted,
+            structure: Vec::new(),
+        }
+    }
+
+    pub fn id(mut self, id: Uuid) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    pub fn starts_at(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = start_time;
+        self
+    }
+
+    pub fn lasting(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn buy_in_cents(mut self, buy_in_cents: i64) -> Self {
+        self.buy_in_cents = buy_in_cents;
+        self
+    }
+
+    pub fn seat_cap(mut self, seat_cap: i32) -> Self {
+        self.seat_cap = seat_cap;
+        self
+    }
+
+    /// Reached the way the app gets there: inserted `not_started`, then moved
+    /// to this status, so the status triggers (clock, points) fire.
+    pub fn live_status(mut self, live_status: TournamentLiveStatus) -> Self {
+        self.live_status = live_status;
+        self
+    }
+
+    pub fn structure(mut self, levels: Vec<Level>) -> Self {
+        self.structure = levels;
+        self
+    }
+
+    pub async fn create(self, db: &PgPool) -> SqlxResult<Uuid> {
+        let mut tx = db.begin().await?;
+        sqlx::query(
+            "INSERT INTO tournaments (
+                 id, name, description, club_id, start_time, end_time,
+                 buy_in_cents, seat_cap, live_status
+             ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, 'not_started')
+             ON CONFLICT (id) DO NOTHING",
+        )
+        .bind(self.id)
+        .bind(self.name)
+        .bind(self.description)
+        .bind(self.club_id)
+        .bind(self.start_time)
+        .bind(self.start_time + self.duration)
+        .bind(self.buy_in_cents)
+        .bind(self.seat_cap)
+        .execute(&mut *tx)
+        .await?;
+
+        for (number, level) in (1..).zip(&self.structure) {
+            sqlx::query(
+                "INSERT INTO tournament_structures
+                     (tournament_id, level_number, small_blind, big_blind, ante,
+                      duration_minutes, is_break, break_duration_minutes)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, CASE WHEN $7 THEN $6 END)",
+            )
+            .bind(self.id)
+            .bind(number)
+            .bind(level.small_blind)
+            .bind(level.big_blind)
+            .bind(level.ante)
+            .bind(level.duration_minutes)
+            .bind(level.is_break)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        if self.live_status != TournamentLiveStatus::NotStarted {
+            sqlx::query("UPDATE tournaments SET live_status = $2 WHERE id = $1")
+                .bind(self.id)
+                .bind(self.live_status)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(self.id)
+    }
+}
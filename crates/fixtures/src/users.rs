@@ -0,0 +1,101 @@
+use sqlx::{PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+/// Bcrypt-shaped placeholder; no password verifies against it.
+const NO_PASSWORD: &str = "$2b$12$dummy.hash.for.testing";
+
+/// An app account. Defaults to an active player with a unique email.
+#[derive(Debug, Clone)]
+pub struct UserFactory {
+    id: Uuid,
+    email: Option<String>,
+    username: Option<String>,
+    first_name: String,
+    last_name: Option<String>,
+    role: String,
+    password_hash: String,
+    is_active: bool,
+}
+
+impl UserFactory {
+    pub(crate) fn new(first_name: String, last_name: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            email: None,
+            username: None,
+            first_name,
+            last_name: Some(last_name),
+            role: "player".to_string(),
+            password_hash: NO_PASSWORD.to_string(),
+            is_active: true,
+        }
+    }
+
+    pub fn id(mut self, id: Uuid) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    pub fn name(mut self, first_name: impl Into<String>, last_name: Option<&str>) -> Self {
+        self.first_name = first_name.into();
+        self.last_name = last_name.map(str::to_string);
+        self
+    }
+
+    /// `player`, `manager` or `admin`.
+    pub fn role(mut self, role: impl Into<String>) -> Self {
+        self.role = role.into();
+        self
+    }
+
+    pub fn password_hash(mut self, hash: impl Into<String>) -> Self {
+        self.password_hash = hash.into();
+        self
+    }
+
+    pub fn inactive(mut self) -> Self {
+        self.is_active = false;
+        self
+    }
+
+    /// Insert the user and return its id. An existing account with the same
+    /// email is kept (taking the new role) and its id returned.
+    pub async fn create<'e>(self, executor: impl PgExecutor<'e>) -> SqlxResult<Uuid> {
+        let short = self.id.simple().to_string()[..8].to_string();
+        let email = self.email.unwrap_or_else(|| {
+            format!(
+                "{}.{}.{short}@example.test",
+                self.first_name.to_lowercase(),
+                self.last_name.as_deref().unwrap_or("player").to_lowercase()
+            )
+        });
+        let username = self.username.unwrap_or_else(|| format!("test_{}", self.id));
+        let id: Uuid = sqlx::query_scalar(
+            "INSERT INTO users (id, email, username, first_name, last_name, password_hash, role, is_active)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+             ON CONFLICT (email) DO UPDATE SET role = EXCLUDED.role
+             RETURNING id",
+        )
+        .bind(self.id)
+        .bind(email)
+        .bind(username)
+        .bind(self.first_name)
+        .bind(self.last_name)
+        .bind(self.password_hash)
+        .bind(self.role)
+        .bind(self.is_active)
+        .fetch_one(executor)
+        .await?;
+        Ok(id)
+    }
+}
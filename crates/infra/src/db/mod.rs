@@ -1,4 +1,8 @@
-use sqlx::PgPool;
+use std::future::Future;
+
+use sqlx::postgres::{PgConnection, PgPoolOptions};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
 
 pub type Db = PgPool;
 
@@ -6,3 +10,138 @@ pub async fn ping(pool: &Db) -> Result<(), sqlx::Error> {
     let _: i32 = sqlx::query_scalar("SELECT 1").fetch_one(pool).await?;
     Ok(())
 }
+
+/// The clubs and user a request acts for, as seen by the row-level security
+/// policies: on a scoped connection, rows of any other club (or, for a
+/// user's private tables, any other user) are invisible and can't be
+/// written. An empty scope sees no club's private rows at all.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RowScope {
+    pub club_ids: Vec<Uuid>,
+    pub user_id: Option<Uuid>,
+}
+
+impl RowScope {
+    pub fn club(club_id: Uuid) -> Self {
+        Self {
+            club_ids: vec![club_id],
+            user_id: None,
+        }
+    }
+
+    pub fn user(user_id: Uuid) -> Self {
+        Self {
+            club_ids: Vec::new(),
+            user_id: Some(user_id),
+        }
+    }
+}
+
+tokio::task_local! {
+    static REQUEST_SCOPE: Option<RowScope>;
+}
+
+/// Run `fut` as a request limited to `scope`: every connection it takes from
+/// a pool built with [`scope_connections`] carries the scope.
+pub async fn with_request_scope<F: Future>(scope: RowScope, fut: F) -> F::Output {
+    REQUEST_SCOPE.scope(Some(scope), fut).await
+}
+
+/// [`with_request_scope`] for a synchronous call, e.g. one poll of a stream.
+pub fn with_request_scope_sync<R>(scope: RowScope, f: impl FnOnce() -> R) -> R {
+    REQUEST_SCOPE.sync_scope(Some(scope), f)
+}
+
+/// Run `fut` outside its request's scope. For the few steps that must see
+/// rows before the caller has a scope, like exchanging a display's pairing
+/// code or reading the club assignments a new token carries.
+pub async fn without_request_scope<F: Future>(fut: F) -> F::Output {
+    REQUEST_SCOPE.scope(None, fut).await
+}
+
+/// The scope of the request running on this task, if any.
+pub fn request_scope() -> Option<RowScope> {
+    REQUEST_SCOPE.try_with(Option::clone).ok().flatten()
+}
+
+/// Apply the current request's scope to every connection as it's handed out,
+/// new or pooled, and clear it for everything else (background services,
+/// migrations, auth lookups). Setting it on each checkout means a scope never
+/// outlives the request that set it.
+pub fn scope_connections(options: PgPoolOptions) -> PgPoolOptions {
+    options
+        .after_connect(|conn, _| Box::pin(async move { apply_request_scope(conn).await }))
+        .before_acquire(|conn, _| {
+            Box::pin(async move {
+                apply_request_scope(conn).await?;
+                Ok(true)
+            })
+        })
+}
+
+async fn apply_request_scope(conn: &mut PgConnection) -> Result<(), sqlx::Error> {
+    set_scope(conn, request_scope().as_ref(), false).await
+}
+
+/// Set the policies' settings on `conn`: `local` limits them to the current
+/// transaction. Without a scope the connection is unrestricted.
+async fn set_scope(
+    conn: &mut PgConnection,
+    scope: Option<&RowScope>,
+    local: bool,
+) -> Result<(), sqlx::Error> {
+    let club_ids = scope.map(|s| s.club_ids.clone()).unwrap_or_default();
+    let user_id = scope
+        .and_then(|s| s.user_id)
+        .map(|id| id.to_string())
+        .unwrap_or_default();
+    sqlx::query(
+        "SELECT set_config('app.scoped', $1, $4), \
+                set_config('app.club_ids', $2::uuid[]::text, $4), \
+                set_config('app.user_id', $3, $4)",
+    )
+    .bind(if scope.is_some() { "on" } else { "" })
+    .bind(club_ids)
+    .bind(user_id)
+    .bind(local)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+/// Begin a transaction limited to `scope` by the row-level security
+/// policies (`SET LOCAL`, so the connection returns to its request's scope
+/// once it commits or rolls back). Narrows a request to one club or user.
+pub async fn begin_scoped(
+    pool: &Db,
+    scope: RowScope,
+) -> Result<Transaction<'static, Postgres>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    set_scope(&mut tx, Some(&scope), true).await?;
+    Ok(tx)
+}
+
+/// Let the transaction on `conn` act for `club_id` as well as its request's
+/// scope, until it ends (`SET LOCAL`). For a player's own mutation at a club
+/// (registering, cancelling, joining a group) that reads and writes the
+/// club's rows on their behalf once the resolver has checked they may.
+pub async fn act_for_club(conn: &mut PgConnection, club_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "SELECT set_config('app.club_ids', array_append(app_club_scope(), $1)::text, true)",
+    )
+    .bind(club_id)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+/// Begin a read-only transaction that sees the database as of its first
+/// query (`REPEATABLE READ`): views assembled from several queries are
+/// consistent with each other, whatever commits in between.
+pub async fn begin_snapshot(pool: &Db) -> Result<Transaction<'static, Postgres>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+        .execute(&mut *tx)
+        .await?;
+    Ok(tx)
+}
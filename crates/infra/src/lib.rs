@@ -1,5 +1,7 @@
 pub mod db;
 pub mod models;
 pub mod pagination;
+pub mod phone;
+pub mod pii;
 pub mod repos;
 pub mod scoring;
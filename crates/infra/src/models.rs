@@ -1,3 +1,4 @@
+use crate::pii::Pii;
 use crate::repos::tournaments::TournamentLiveStatus;
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
@@ -51,8 +52,8 @@ pub struct TournamentRow {
     pub description: Option<String>,
     pub start_time: DateTime<Utc>,
     pub end_time: Option<DateTime<Utc>>,
-    pub buy_in_cents: i32,
-    pub rake_cents: i32,
+    pub buy_in_cents: i64,
+    pub rake_cents: i64,
     pub seat_cap: Option<i32>,
     /// Default chips a player receives on their initial buy-in (NULL = not set).
     pub starting_stack: Option<i32>,
@@ -61,18 +62,18 @@ pub struct TournamentRow {
     /// Second early-bird bonus, granted to players still seated at the end of L2.
     pub level_two_bonus_chips: Option<i32>,
     /// Mandatory drink voucher value (cents). Excluded from the prize pool.
-    pub voucher_value_cents: i32,
+    pub voucher_value_cents: i64,
     /// Max number of rebuys allowed (flyer display).
     pub rebuy_max: Option<i32>,
     /// Add-on chip amount (flyer display).
     pub addon_chips: Option<i32>,
     /// Add-on price in cents (flyer display).
-    pub addon_price_cents: Option<i32>,
+    pub addon_price_cents: Option<i64>,
     pub late_registration_level: Option<i32>,
     /// Bounty / PKO format: none | fixed | progressive.
     pub bounty_type: String,
     /// Slice of each buy-in (and rebuy / re-entry) diverted to the bounty pool.
-    pub bounty_amount_cents: i32,
+    pub bounty_amount_cents: i64,
     /// Optional league tag (feeds `tagged` leaderboard configs). NULL = untagged.
     pub leaderboard_config_id: Option<Uuid>,
     /// Multi-day series this tournament belongs to. NULL = standalone single-day.
@@ -81,6 +82,20 @@ pub struct TournamentRow {
     pub flight_label: Option<String>,
     /// True for the series' final day (carries results, points, aggregate pool).
     pub is_final_day: bool,
+    /// How odd chips are settled at a color-up: race | round_up | round_nearest.
+    pub chip_race_rule: String,
+    /// `public` | `members_only` | `unlisted`.
+    pub visibility: String,
+    /// Buy-in for players registered before `early_bird_until`. NULL = no
+    /// early-bird tier.
+    pub early_bird_buy_in_cents: Option<i64>,
+    pub early_bird_until: Option<DateTime<Utc>>,
+    /// Weight applied to the points this tournament's results score (2.0 for
+    /// a main event counting double).
+    pub points_multiplier: f64,
+    /// Seats each linked table plays with (8 for 8-handed on 10-seat tables),
+    /// unless the table's assignment overrides it. NULL = the tables' own size.
+    pub seats_per_table: Option<i32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -97,7 +112,7 @@ impl TournamentRow {
             | LiveStatus::InProgress
             | LiveStatus::Break
             | LiveStatus::FinalTable => TournamentStatus::InProgress,
-            LiveStatus::Finished => TournamentStatus::Completed,
+            LiveStatus::Finished | LiveStatus::Cancelled => TournamentStatus::Completed,
         }
     }
 }
@@ -109,7 +124,9 @@ pub struct UserRow {
     pub username: Option<String>,
     pub first_name: String,
     pub last_name: Option<String>,
-    pub phone: Option<String>,
+    /// Encrypted at rest.
+    pub phone: Option<Pii>,
+    pub avatar_url: Option<String>,
     pub is_active: bool,
     pub role: Option<String>,
     pub locale: String,
@@ -117,6 +134,18 @@ pub struct UserRow {
     pub updated_at: DateTime<Utc>,
 }
 
+/// What an access token asserts about a user, read when the token is minted.
+#[derive(Debug, Clone, FromRow)]
+pub struct TokenSubjectRow {
+    pub id: Uuid,
+    pub email: String,
+    pub role: Option<String>,
+    pub is_active: bool,
+    /// Active `club_managers` assignments.
+    pub club_ids: Vec<Uuid>,
+    pub token_version: i32,
+}
+
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct TournamentRegistrationRow {
     pub id: Uuid,
@@ -127,10 +156,16 @@ pub struct TournamentRegistrationRow {
     pub status: String,
     pub notes: Option<String>,
     /// Live progressive-knockout head for this player, in cents (0 for non-PKO).
-    pub current_bounty_cents: i32,
+    pub current_bounty_cents: i64,
     /// Chip stack carried into this tournament (imported Day 2 qualifier stack).
     /// NULL = use the default starting stack.
     pub starting_stack: Option<i32>,
+    /// Invite link the player registered through, if any.
+    pub invite_id: Option<Uuid>,
+    /// Last presence heartbeat from the player's phone at the venue.
+    pub last_seen_at: Option<DateTime<Utc>>,
+    /// Place in the alternates queue; kept once the alternate is seated.
+    pub alternate_position: Option<i32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -142,7 +177,7 @@ pub struct TournamentResultRow {
     pub user_id: Option<Uuid>,
     pub club_player_id: Uuid,
     pub final_position: i32,
-    pub prize_cents: i32,
+    pub prize_cents: i64,
     pub points: i32,
     pub notes: Option<String>,
     pub created_at: DateTime<Utc>,
@@ -169,7 +204,7 @@ pub struct PlayerDealRow {
     pub deal_type: String,
     pub affected_positions: Vec<i32>,
     pub custom_payouts: Option<serde_json::Value>, // JSONB field
-    pub total_amount_cents: i32,
+    pub total_amount_cents: i64,
     pub notes: Option<String>,
     pub created_by: Uuid,
     pub created_at: DateTime<Utc>,
@@ -199,6 +234,12 @@ pub struct TournamentTableAssignmentRow {
     pub assigned_at: DateTime<Utc>,
     pub deactivated_at: Option<DateTime<Utc>>,
     pub max_seats_override: Option<i32>,
+    /// Floor status: `open`, `breaking_soon` or `closed`.
+    pub status: String,
+    /// Floor notes, e.g. "new deck needed".
+    pub notes: Option<String>,
+    pub status_updated_by: Option<Uuid>,
+    pub status_updated_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -232,6 +273,8 @@ pub struct TournamentStructureRow {
     pub duration_minutes: i32,
     pub is_break: bool,
     pub break_duration_minutes: Option<i32>,
+    /// Smallest chip in play from this level on; set on levels with a color-up.
+    pub color_up_denomination: Option<i32>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -406,6 +449,7 @@ pub struct UserPrivacySettingsRow {
     pub app_user_id: Uuid,
     pub share_named_pl: bool,
     pub in_scouting_pool: bool,
+    pub appear_in_photos: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -497,6 +541,16 @@ pub struct AnnouncementRow {
     pub body: String,
     /// The manager/admin who authored it (NULL if that account was later removed).
     pub created_by: Option<Uuid>,
+    /// `normal` | `high` (floor broadcasts).
+    pub priority: String,
+    /// Delivery channels, a subset of `in_app` | `push` | `sms`.
+    pub channels: Vec<String>,
+    /// Pinned to the tournament lobby (tournament scope only).
+    pub pinned: bool,
+    /// Due time of a scheduled broadcast; NULL for immediate ones.
+    pub scheduled_for: Option<DateTime<Utc>>,
+    /// Delivery time; NULL while a scheduled broadcast is pending.
+    pub sent_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -535,8 +589,11 @@ pub struct TournamentPayoutRow {
     pub tournament_id: Uuid,
     pub template_id: Option<Uuid>,
     pub player_count: i32,
-    pub total_prize_pool: i32,
+    pub total_prize_pool: i64,
     pub payout_positions: serde_json::Value,
+    /// Set once the table is the official one (late registration closed).
+    pub finalized_at: Option<DateTime<Utc>>,
+    pub finalized_by: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -548,11 +605,15 @@ pub struct TournamentEntryRow {
     pub user_id: Option<Uuid>,
     pub club_player_id: Uuid,
     pub entry_type: String,
-    pub amount_cents: i32,
+    pub amount_cents: i64,
     pub chips_received: Option<i32>,
     pub recorded_by: Option<Uuid>,
     pub notes: Option<String>,
     pub payment_method: String,
+    /// `early_bird` | `regular` for buy-ins; NULL for other entry types.
+    pub price_tier: Option<String>,
+    /// Clock level when the entry was recorded; NULL before the start.
+    pub level_number: Option<i32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
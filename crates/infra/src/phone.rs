@@ -0,0 +1,269 @@
+//! Phone numbers in E.164 (`+32470123456`): the form they are stored, looked
+//! up and texted in. Numbers are typed every which way ("0470/12.34.56",
+//! "+32 (0)470 12 34 56", "0032 470 123456"), so they are normalized on
+//! write, with the club's country standing in for a missing country code, and
+//! formatted back for display the way that country dials them.
+//!
+//! Any well-formed international number is accepted. Only the countries in
+//! `COUNTRIES` get national parsing, length checks and display grouping;
+//! numbers from elsewhere must be typed with their country code and are shown
+//! as stored.
+
+use std::ops::RangeInclusive;
+
+/// Country assumed for national numbers when nothing better is known; it is
+/// also the default country of a club.
+pub const DEFAULT_COUNTRY: &str = "BE";
+
+/// Digits in an E.164 number, country code included. The shortest in service
+/// (Niue, +683 and four digits) have seven.
+const E164_LEN: RangeInclusive<usize> = 7..=15;
+
+/// A country whose national numbers can be resolved and formatted.
+struct Country {
+    iso: &'static str,
+    calling_code: &'static str,
+    /// Whether national numbers are dialed with a leading `0`.
+    trunk_prefix: bool,
+    /// Plausible lengths of the number after the calling code.
+    significant_len: RangeInclusive<usize>,
+    /// Digits of the leading (area or mobile) group, given the number after
+    /// the calling code.
+    lead_group: fn(&str) -> usize,
+    /// Whether the rest is grouped in threes rather than pairs.
+    triples: bool,
+}
+
+const COUNTRIES: &[Country] = &[
+    Country {
+        iso: "BE",
+        calling_code: "32",
+        trunk_prefix: true,
+        significant_len: 8..=9,
+        // Mobiles (04xx), then the one-digit areas: Brussels, Antwerp,
+        // Liège, Ghent.
+        lead_group: |n| match n.as_bytes().first() {
+            Some(b'4') if n.len() == 9 => 3,
+            Some(b'2' | b'3' | b'4' | b'9') => 1,
+            _ => 2,
+        },
+        triples: false,
+    },
+    Country {
+        iso: "FR",
+        calling_code: "33",
+        trunk_prefix: true,
+        significant_len: 9..=9,
+        lead_group: |_| 1,
+        triples: false,
+    },
+    Country {
+        iso: "LU",
+        calling_code: "352",
+        trunk_prefix: false,
+        significant_len: 4..=11,
+        lead_group: |n| n.len().min(3),
+        triples: true,
+    },
+    Country {
+        iso: "NL",
+        calling_code: "31",
+        trunk_prefix: true,
+        significant_len: 9..=9,
+        // Mobiles (06), else a two-digit area.
+        lead_group: |n| if n.starts_with('6') { 1 } else { 2 },
+        triples: false,
+    },
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PhoneError {
+    #[error("Invalid phone number")]
+    Invalid,
+    #[error("Enter the phone number with its country code, e.g. +32 470 12 34 56")]
+    MissingCountryCode,
+}
+
+fn country(iso: &str) -> Option<&'static Country> {
+    COUNTRIES.iter().find(|c| c.iso.eq_ignore_ascii_case(iso))
+}
+
+fn country_of(international: &str) -> Option<&'static Country> {
+    COUNTRIES
+        .iter()
+        .find(|c| international.starts_with(c.calling_code))
+}
+
+/// Normalize a typed phone number to E.164. Numbers without a country code
+/// (no `+` or `00`) are read as national numbers of `default_country`, an
+/// ISO 3166 alpha-2 code; that needs a country in `COUNTRIES`.
+pub fn normalize(raw: &str, default_country: &str) -> Result<String, PhoneError> {
+    // "+32 (0)470…": the bracketed trunk prefix is dialed at home only.
+    let raw = raw.trim().replace("(0)", "");
+    let mut digits = String::with_capacity(raw.len());
+    let mut plus = false;
+    for (i, c) in raw.chars().enumerate() {
+        match c {
+            '+' if i == 0 => plus = true,
+            '0'..='9' => digits.push(c),
+            ' ' | '.' | '-' | '/' | '(' | ')' => {}
+            _ => return Err(PhoneError::Invalid),
+        }
+    }
+
+    let international = if plus {
+        digits
+    } else if let Some(rest) = digits.strip_prefix("00") {
+        rest.to_string()
+    } else {
+        let home = country(default_country).ok_or(PhoneError::MissingCountryCode)?;
+        let national = if home.trunk_prefix {
+            digits
+                .strip_prefix('0')
+                .ok_or(PhoneError::MissingCountryCode)?
+        } else {
+            digits.as_str()
+        };
+        format!("{}{national}", home.calling_code)
+    };
+
+    if !E164_LEN.contains(&international.len()) || international.starts_with('0') {
+        return Err(PhoneError::Invalid);
+    }
+    if let Some(c) = country_of(&international) {
+        if !c
+            .significant_len
+            .contains(&(international.len() - c.calling_code.len()))
+        {
+            return Err(PhoneError::Invalid);
+        }
+    }
+    Ok(format!("+{international}"))
+}
+
+/// Display an E.164 number as dialed from `club_country`: the national format
+/// for numbers of that country ("0470 12 34 56"), the grouped international
+/// format otherwise ("+33 6 12 34 56 78"). Numbers of countries without
+/// formatting rules, or not in E.164, come back as stored.
+pub fn format(e164: &str, club_country: &str) -> String {
+    let Some(c) = e164.strip_prefix('+').and_then(country_of) else {
+        return e164.to_string();
+    };
+    let national = &e164[1 + c.calling_code.len()..];
+    let lead = (c.lead_group)(national).min(national.len());
+    let (head, rest) = national.split_at(lead);
+
+    let mut groups = vec![head];
+    let mut rest = rest;
+    let first = if c.triples || rest.len() % 2 == 1 {
+        3
+    } else {
+        2
+    };
+    let step = if c.triples { 3 } else { 2 };
+    let mut size = first;
+    while !rest.is_empty() {
+        let (group, tail) = rest.split_at(size.min(rest.len()));
+        groups.push(group);
+        rest = tail;
+        size = step;
+    }
+    let grouped = groups.join(" ");
+
+    if c.iso.eq_ignore_ascii_case(club_country) {
+        if c.trunk_prefix {
+            format!("0{grouped}")
+        } else {
+            grouped
+        }
+    } else {
+        format!("+{} {grouped}", c.calling_code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_the_ways_numbers_are_typed() {
+        for raw in [
+            "0470 12 34 56",
+            "0470/12.34.56",
+            "+32 470 12 34 56",
+            "+32 (0)470 12 34 56",
+            "0032 470 123456",
+            " +32470123456 ",
+        ] {
+            assert_eq!(normalize(raw, "BE").as_deref(), Ok("+32470123456"), "{raw}");
+        }
+        assert_eq!(
+            normalize("06 12 34 56 78", "FR").as_deref(),
+            Ok("+33612345678")
+        );
+        assert_eq!(
+            normalize("621 123 456", "LU").as_deref(),
+            Ok("+352621123456")
+        );
+        // The country code wins over the club's country.
+        assert_eq!(
+            normalize("+33 6 12 34 56 78", "BE").as_deref(),
+            Ok("+33612345678")
+        );
+    }
+
+    #[test]
+    fn accepts_international_numbers_from_anywhere() {
+        for (raw, e164) in [
+            ("+1 415 555 2671", "+14155552671"),
+            ("+49 151 23456789", "+4915123456789"),
+            ("0044 7911 123456", "+447911123456"),
+            ("+81 90-1234-5678", "+819012345678"),
+            ("+683 4002", "+6834002"),
+        ] {
+            assert_eq!(normalize(raw, "BE").as_deref(), Ok(e164), "{raw}");
+        }
+        // Whatever the club's country, as long as the code is typed.
+        assert_eq!(
+            normalize("+49 151 23456789", "DE").as_deref(),
+            Ok("+4915123456789")
+        );
+        assert_eq!(format("+4915123456789", "DE"), "+4915123456789");
+    }
+
+    #[test]
+    fn rejects_what_is_not_a_phone_number() {
+        assert_eq!(normalize("", "BE"), Err(PhoneError::MissingCountryCode));
+        assert_eq!(normalize("call me", "BE"), Err(PhoneError::Invalid));
+        assert_eq!(normalize("+32 470", "BE"), Err(PhoneError::Invalid));
+        assert_eq!(normalize("+683 400", "BE"), Err(PhoneError::Invalid));
+        assert_eq!(normalize("+0 470 12 34 56", "BE"), Err(PhoneError::Invalid));
+        assert_eq!(normalize("+33 6 12 34", "BE"), Err(PhoneError::Invalid));
+        assert_eq!(
+            normalize("+1234567890123456", "BE"),
+            Err(PhoneError::Invalid)
+        );
+        // A national number needs a country to belong to.
+        assert_eq!(
+            normalize("0470 12 34 56", "US"),
+            Err(PhoneError::MissingCountryCode)
+        );
+        assert_eq!(
+            normalize("470 12 34 56", "BE"),
+            Err(PhoneError::MissingCountryCode)
+        );
+    }
+
+    #[test]
+    fn formats_for_the_club_country() {
+        assert_eq!(format("+32470123456", "BE"), "0470 12 34 56");
+        assert_eq!(format("+3221234567", "be"), "02 123 45 67");
+        assert_eq!(format("+3281123456", "BE"), "081 12 34 56");
+        assert_eq!(format("+33612345678", "FR"), "06 12 34 56 78");
+        assert_eq!(format("+352621123456", "LU"), "621 123 456");
+        assert_eq!(format("+33612345678", "BE"), "+33 6 12 34 56 78");
+        assert_eq!(format("+32470123456", "NL"), "+32 470 12 34 56");
+        assert_eq!(format("+14155552671", "BE"), "+14155552671");
+        assert_eq!(format("0470 12 34 56", "BE"), "0470 12 34 56");
+    }
+}
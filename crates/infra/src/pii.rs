@@ -0,0 +1,287 @@
+//! Application-level encryption of personal data at rest (AES-256-GCM).
+//!
+//! Columns holding PII are read and written through [`Pii`], whose sqlx
+//! `Encode` seals the value with the active key and whose `Decode` opens it
+//! again, so repos and callers only ever see plaintext. A sealed value is
+//! stored as `enc:v1:<key id>:<base64(nonce || ciphertext || tag)>`; the key
+//! id lets a rotated keyring still open rows sealed with an older key.
+//! Every seal draws a fresh nonce, so SQL can't filter or search on a
+//! sealed column.
+//!
+//! With no keyring installed values are written as plaintext, and plaintext
+//! rows (written before encryption was enabled) are always readable, so
+//! turning encryption on needs no migration: run the rotation job to seal
+//! the existing rows.
+
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef};
+use sqlx::{Decode, Encode, Postgres, Type};
+
+const PREFIX: &str = "enc:v1:";
+
+/// The keys PII is sealed with: the first is active, the rest only open
+/// rows not yet rotated onto it.
+#[derive(Clone)]
+pub struct Keyring {
+    keys: Vec<(String, LessSafeKey)>,
+}
+
+impl Keyring {
+    /// Parse `id:base64key[,id:base64key…]`, active key first. Keys are 32
+    /// random bytes (`openssl rand -base64 32`); ids are short and unique.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut keys: Vec<(String, LessSafeKey)> = Vec::new();
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (id, material) = entry
+                .split_once(':')
+                .ok_or_else(|| "expected id:base64key, got an entry without ':'".to_string())?;
+            if id.is_empty()
+                || !id
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            {
+                return Err(format!("invalid key id {id:?} (letters, digits, - and _)"));
+            }
+            if keys.iter().any(|(existing, _)| existing == id) {
+                return Err(format!("duplicate key id {id:?}"));
+            }
+            let bytes = STANDARD
+                .decode(material.trim())
+                .map_err(|_| format!("key {id:?} is not valid base64"))?;
+            let key = UnboundKey::new(&AES_256_GCM, &bytes)
+                .map_err(|_| format!("key {id:?} must be 32 bytes"))?;
+            keys.push((id.to_string(), LessSafeKey::new(key)));
+        }
+        if keys.is_empty() {
+            return Err("no keys given".to_string());
+        }
+        Ok(Self { keys })
+    }
+
+    /// Id of the key new values are sealed with.
+    pub fn active_id(&self) -> &str {
+        &self.keys[0].0
+    }
+
+    pub fn key_ids(&self) -> impl Iterator<Item = &str> {
+        self.keys.iter().map(|(id, _)| id.as_str())
+    }
+
+    pub fn seal(&self, plaintext: &str) -> Result<String, PiiError> {
+        let (id, key) = &self.keys[0];
+        let mut nonce = [0u8; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|_| PiiError::Crypto)?;
+        let mut in_out = plaintext.as_bytes().to_vec();
+        key.seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(id.as_bytes()),
+            &mut in_out,
+        )
+        .map_err(|_| PiiError::Crypto)?;
+
+        let mut payload = nonce.to_vec();
+        payload.extend_from_slice(&in_out);
+        Ok(format!("{PREFIX}{id}:{}", STANDARD.encode(payload)))
+    }
+
+    pub fn open(&self, sealed: &str) -> Result<String, PiiError> {
+        let (id, payload) = parse_sealed(sealed).ok_or(PiiError::Malformed)?;
+        let (_, key) = self
+            .keys
+            .iter()
+            .find(|(known, _)| known == id)
+            .ok_or_else(|| PiiError::UnknownKey(id.to_string()))?;
+        let mut payload = STANDARD.decode(payload).map_err(|_| PiiError::Malformed)?;
+        if payload.len() < NONCE_LEN {
+            return Err(PiiError::Malformed);
+        }
+        let mut in_out = payload.split_off(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(&payload).map_err(|_| PiiError::Malformed)?;
+        let plaintext = key
+            .open_in_place(nonce, Aad::from(id.as_bytes()), &mut in_out)
+            .map_err(|_| PiiError::Crypto)?;
+        String::from_utf8(plaintext.to_vec()).map_err(|_| PiiError::Malformed)
+    }
+}
+
+impl fmt::Debug for Keyring {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Keyring")
+            .field("key_ids", &self.key_ids().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PiiError {
+    #[error("encrypted value is malformed")]
+    Malformed,
+    #[error("encrypted with key {0:?}, which is not in PII_ENCRYPTION_KEYS")]
+    UnknownKey(String),
+    #[error("encrypted value found but no PII_ENCRYPTION_KEYS configured")]
+    NoKeyring,
+    #[error("encryption failed or the value was tampered with")]
+    Crypto,
+}
+
+/// `(key id, payload)` of a sealed value; `None` for plaintext.
+fn parse_sealed(value: &str) -> Option<(&str, &str)> {
+    value.strip_prefix(PREFIX)?.split_once(':')
+}
+
+/// Id of the key `value` is sealed with; `None` for plaintext.
+pub fn sealed_with(value: &str) -> Option<&str> {
+    parse_sealed(value).map(|(id, _)| id)
+}
+
+static KEYRING: RwLock<Option<Arc<Keyring>>> = RwLock::new(None);
+
+/// Seal every PII value this process writes from now on with `keyring`
+/// (`None`: write plaintext). Called once at startup.
+pub fn install(keyring: Option<Keyring>) {
+    *KEYRING.write().unwrap_or_else(|e| e.into_inner()) = keyring.map(Arc::new);
+}
+
+pub fn keyring() -> Option<Arc<Keyring>> {
+    KEYRING.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Seal `plaintext` with the installed keyring, or pass it through when
+/// encryption is off.
+pub fn seal(plaintext: &str) -> Result<String, PiiError> {
+    match keyring() {
+        Some(keyring) => keyring.seal(plaintext),
+        None => Ok(plaintext.to_string()),
+    }
+}
+
+/// Open a stored value: sealed values are decrypted, plaintext passes through.
+/// For columns read around [`Pii`]'s `Decode`, e.g. nested in JSON Postgres
+/// built.
+pub fn open(stored: &str) -> Result<String, PiiError> {
+    if !stored.starts_with(PREFIX) {
+        return Ok(stored.to_string());
+    }
+    keyring().ok_or(PiiError::NoKeyring)?.open(stored)
+}
+
+/// A personal-data string column, encrypted at rest. Holds the plaintext;
+/// sealing and opening happen as it is bound to and decoded from a query.
+/// `Debug` never prints the value.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Pii(String);
+
+impl Pii {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl From<String> for Pii {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Pii> for String {
+    fn from(value: Pii) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Debug for Pii {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Pii(<redacted>)")
+    }
+}
+
+impl Type<Postgres> for Pii {
+    fn type_info() -> PgTypeInfo {
+        <String as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <String as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Pii {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let stored = <&str as Decode<Postgres>>::decode(value)?;
+        Ok(Self(open(stored)?))
+    }
+}
+
+impl Encode<'_, Postgres> for Pii {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <String as Encode<Postgres>>::encode(seal(&self.0)?, buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(byte: u8) -> String {
+        STANDARD.encode([byte; 32])
+    }
+
+    #[test]
+    fn seals_and_opens_with_any_key_in_the_ring() {
+        let old = Keyring::parse(&format!("k1:{}", key(1))).unwrap();
+        let sealed = old.seal("+32470123456").unwrap();
+        assert!(sealed.starts_with("enc:v1:k1:"));
+        assert_eq!(sealed_with(&sealed), Some("k1"));
+        // Random nonces: the same value never seals the same way twice.
+        assert_ne!(sealed, old.seal("+32470123456").unwrap());
+
+        let rotated = Keyring::parse(&format!("k2:{}, k1:{}", key(2), key(1))).unwrap();
+        assert_eq!(rotated.active_id(), "k2");
+        assert_eq!(rotated.open(&sealed).unwrap(), "+32470123456");
+        assert!(rotated.seal("x").unwrap().starts_with("enc:v1:k2:"));
+
+        let other = Keyring::parse(&format!("k3:{}", key(3))).unwrap();
+        assert!(matches!(other.open(&sealed), Err(PiiError::UnknownKey(_))));
+    }
+
+    #[test]
+    fn rejects_tampering_and_bad_keys() {
+        let ring = Keyring::parse(&format!("k1:{}", key(1))).unwrap();
+        let sealed = ring.seal("secret").unwrap();
+        // Relabelling the key id breaks the authenticated data.
+        let relabelled = Keyring::parse(&format!("k1:{},k9:{}", key(1), key(1))).unwrap();
+        let moved = sealed.replacen("enc:v1:k1:", "enc:v1:k9:", 1);
+        assert!(matches!(relabelled.open(&moved), Err(PiiError::Crypto)));
+
+        assert!(Keyring::parse("").is_err());
+        assert!(Keyring::parse("k1:c2hvcnQ=").is_err());
+        assert!(Keyring::parse(&format!("k1:{},k1:{}", key(1), key(2))).is_err());
+        assert!(Keyring::parse(&format!("bad id:{}", key(1))).is_err());
+    }
+
+    #[test]
+    fn plaintext_passes_through() {
+        assert_eq!(sealed_with("+32470123456"), None);
+        assert_eq!(open("+32470123456").unwrap(), "+32470123456");
+    }
+}
@@ -0,0 +1,321 @@
+//! Accounting exports: per-club chart-of-accounts settings, the journal
+//! source figures, and the immutable export snapshots.
+
+use chrono::{DateTime, Utc};
+use sqlx::{Acquire, FromRow, PgExecutor, Postgres, Result as SqlxResult};
+use uuid::Uuid;
+
+const SETTINGS_COLS: &str = "club_id, layout, cash_account, card_account, bank_account, promotion_account, prize_liability_account, rake_revenue_account, jackpot_liability_account, created_at, updated_at";
+const EXPORT_COLS: &str = "id, club_id, period_from, period_to, layout, line_count, total_cents, content, created_by, created_at";
+
+/// A club's CSV layout and account numbers for the journal.
+#[derive(Debug, Clone, FromRow)]
+pub struct AccountingSettingsRow {
+    pub club_id: Uuid,
+    /// "generic" | "datev"
+    pub layout: String,
+    pub cash_account: String,
+    pub card_account: String,
+    pub bank_account: String,
+    /// Entries paid with a voucher or comped.
+    pub promotion_account: String,
+    pub prize_liability_account: String,
+    pub rake_revenue_account: String,
+    /// Promotion jackpots held for players until paid out.
+    pub jackpot_liability_account: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpsertAccountingSettings {
+    pub layout: String,
+    pub cash_account: String,
+    pub card_account: String,
+    pub bank_account: String,
+    pub promotion_account: String,
+    pub prize_liability_account: String,
+    pub rake_revenue_account: String,
+    pub jackpot_liability_account: String,
+}
+
+/// A stored export. Rows are never updated (enforced by a trigger).
+#[derive(Debug, Clone, FromRow)]
+pub struct AccountingExportRow {
+    pub id: Uuid,
+    pub club_id: Uuid,
+    pub period_from: DateTime<Utc>,
+    pub period_to: DateTime<Utc>,
+    pub layout: String,
+    pub line_count: i32,
+    /// Sum of all journal line amounts.
+    pub total_cents: i64,
+    pub content: String,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateAccountingExport {
+    pub club_id: Uuid,
+    pub period_from: DateTime<Utc>,
+    pub period_to: DateTime<Utc>,
+    pub layout: String,
+    pub line_count: i32,
+    pub total_cents: i64,
+    pub content: String,
+    pub created_by: Uuid,
+}
+
+/// A finished tournament's figures that aren't per payment method.
+#[derive(Debug, Clone, FromRow)]
+pub struct JournalTournamentRow {
+    pub tournament_id: Uuid,
+    pub name: String,
+    pub start_time: DateTime<Utc>,
+    /// Rake per initial entry and re-entry.
+    pub rake_cents: i64,
+    pub prizes_paid_cents: i64,
+    pub bounties_paid_cents: i64,
+}
+
+/// Money taken in for one tournament through one payment method.
+#[derive(Debug, Clone, FromRow)]
+pub struct JournalCollectionRow {
+    pub tournament_id: Uuid,
+    pub payment_method: String,
+    pub amount_cents: i64,
+}
+
+/// A promotion jackpot ledger movement (seed, contribution or payout).
+#[derive(Debug, Clone, FromRow)]
+pub struct JournalJackpotEntryRow {
+    pub entry_id: Uuid,
+    pub jackpot_name: String,
+    /// `seed` | `contribution` | `payout`.
+    pub kind: String,
+    /// Always positive; `kind` gives the direction.
+    pub amount_cents: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+/// One tournament's rake: what it charged per entry, how many entries paid
+/// it, and the prize pool the entries built.
+#[derive(Debug, Clone, FromRow)]
+pub struct RakeTournamentRow {
+    pub tournament_id: Uuid,
+    pub name: String,
+    pub start_time: DateTime<Utc>,
+    pub rake_per_entry_cents: i64,
+    /// Initial entries and re-entries.
+    pub raked_entries: i64,
+    pub rake_cents: i64,
+    pub prize_pool_cents: i64,
+}
+
+/// The club's settings, created with the defaults on first use.
+pub async fn get_or_create_settings<'a>(
+    conn: impl Acquire<'a, Database = Postgres>,
+    club_id: Uuid,
+) -> SqlxResult<AccountingSettingsRow> {
+    let mut conn = conn.acquire().await?;
+    super::get_or_insert_default(
+        &mut conn,
+        "club_accounting_settings",
+        SETTINGS_COLS,
+        club_id,
+    )
+    .await
+}
+
+pub async fn upsert_settings<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    data: UpsertAccountingSettings,
+) -> SqlxResult<AccountingSettingsRow> {
+    sqlx::query_as::<_, AccountingSettingsRow>(&format!(
+        "INSERT INTO club_accounting_settings \
+            (club_id, layout, cash_account, card_account, bank_account, promotion_account, \
+             prize_liability_account, rake_revenue_account, jackpot_liability_account) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) \
+         ON CONFLICT (club_id) DO UPDATE SET \
+            layout = EXCLUDED.layout, \
+            cash_account = EXCLUDED.cash_account, \
+            card_account = EXCLUDED.card_account, \
+            bank_account = EXCLUDED.bank_account, \
+            promotion_account = EXCLUDED.promotion_account, \
+            prize_liability_account = EXCLUDED.prize_liability_account, \
+            rake_revenue_account = EXCLUDED.rake_revenue_account, \
+            jackpot_liability_account = EXCLUDED.jackpot_liability_account \
+         RETURNING {SETTINGS_COLS}"
+    ))
+    .bind(club_id)
+    .bind(data.layout)
+    .bind(data.cash_account)
+    .bind(data.card_account)
+    .bind(data.bank_account)
+    .bind(data.promotion_account)
+    .bind(data.prize_liability_account)
+    .bind(data.rake_revenue_account)
+    .bind(data.jackpot_liability_account)
+    .fetch_one(executor)
+    .await
+}
+
+/// The club's finished tournaments starting in `[from, to)`, oldest first.
+pub async fn list_journal_tournaments<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> SqlxResult<Vec<JournalTournamentRow>> {
+    sqlx::query_as::<_, JournalTournamentRow>(
+        r#"
+        SELECT t.id AS tournament_id, t.name, t.start_time,
+               (COALESCE(t.rake_cents, 0) *
+                   (SELECT COUNT(*) FROM tournament_entries e
+                     WHERE e.tournament_id = t.id AND e.entry_type IN ('initial', 're_entry'))
+               )::BIGINT AS rake_cents,
+               (SELECT COALESCE(SUM(r.prize_cents), 0) FROM tournament_results r
+                 WHERE r.tournament_id = t.id)::BIGINT AS prizes_paid_cents,
+               (SELECT COALESCE(SUM(b.amount_cents), 0) FROM tournament_bounties b
+                 WHERE b.tournament_id = t.id)::BIGINT AS bounties_paid_cents
+        FROM tournaments t
+        WHERE t.club_id = $1
+          AND t.live_status = 'finished'
+          AND t.start_time >= $2 AND t.start_time < $3
+        ORDER BY t.start_time ASC, t.id ASC
+        "#,
+    )
+    .bind(club_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(executor)
+    .await
+}
+
+/// Rake per tournament for the club's tournaments starting in `[from, to)`,
+/// oldest first. Cancelled tournaments took no rake and are left out.
+pub async fn list_rake_tournaments<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> SqlxResult<Vec<RakeTournamentRow>> {
+    sqlx::query_as::<_, RakeTournamentRow>(
+        r#"
+        SELECT t.id AS tournament_id, t.name, t.start_time,
+               COALESCE(t.rake_cents, 0)::BIGINT AS rake_per_entry_cents,
+               raked.entries AS raked_entries,
+               (COALESCE(t.rake_cents, 0) * raked.entries)::BIGINT AS rake_cents,
+               COALESCE(p.total_prize_pool, 0)::BIGINT AS prize_pool_cents
+        FROM tournaments t
+        CROSS JOIN LATERAL (
+            SELECT COUNT(*) AS entries FROM tournament_entries e
+             WHERE e.tournament_id = t.id AND e.entry_type IN ('initial', 're_entry')
+        ) raked
+        LEFT JOIN tournament_payouts p ON p.tournament_id = t.id
+        WHERE t.club_id = $1
+          AND t.deleted_at IS NULL
+          AND t.live_status <> 'cancelled'
+          AND t.start_time >= $2 AND t.start_time < $3
+        ORDER BY t.start_time ASC, t.id ASC
+        "#,
+    )
+    .bind(club_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(executor)
+    .await
+}
+
+/// Money taken in per tournament and payment method. Voucher and bonus chip
+/// entries carry no money and are left out.
+pub async fn list_journal_collections<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_ids: &[Uuid],
+) -> SqlxResult<Vec<JournalCollectionRow>> {
+    sqlx::query_as::<_, JournalCollectionRow>(
+        r#"
+        SELECT tournament_id, payment_method, SUM(amount_cents)::BIGINT AS amount_cents
+        FROM tournament_entries
+        WHERE tournament_id = ANY($1) AND entry_type NOT IN ('voucher', 'bonus')
+        GROUP BY tournament_id, payment_method
+        ORDER BY tournament_id, payment_method
+        "#,
+    )
+    .bind(tournament_ids)
+    .fetch_all(executor)
+    .await
+}
+
+/// The club's promotion jackpot ledger movements in `[from, to)`, oldest first.
+pub async fn list_journal_jackpot_entries<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> SqlxResult<Vec<JournalJackpotEntryRow>> {
+    sqlx::query_as::<_, JournalJackpotEntryRow>(
+        r#"
+        SELECT e.id AS entry_id, j.name AS jackpot_name, e.kind,
+               ABS(e.amount_cents)::BIGINT AS amount_cents, e.created_at
+        FROM promotion_jackpot_entries e
+        JOIN promotion_jackpots j ON j.id = e.jackpot_id
+        WHERE j.club_id = $1 AND e.created_at >= $2 AND e.created_at < $3
+        ORDER BY e.created_at ASC, e.id ASC
+        "#,
+    )
+    .bind(club_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(executor)
+    .await
+}
+
+pub async fn create_export<'e>(
+    executor: impl PgExecutor<'e>,
+    data: CreateAccountingExport,
+) -> SqlxResult<AccountingExportRow> {
+    sqlx::query_as::<_, AccountingExportRow>(&format!(
+        "INSERT INTO accounting_exports \
+            (club_id, period_from, period_to, layout, line_count, total_cents, content, created_by) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8) \
+         RETURNING {EXPORT_COLS}"
+    ))
+    .bind(data.club_id)
+    .bind(data.period_from)
+    .bind(data.period_to)
+    .bind(data.layout)
+    .bind(data.line_count)
+    .bind(data.total_cents)
+    .bind(data.content)
+    .bind(data.created_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_export<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<AccountingExportRow>> {
+    sqlx::query_as::<_, AccountingExportRow>(&format!(
+        "SELECT {EXPORT_COLS} FROM accounting_exports WHERE id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A club's exports, newest first.
+pub async fn list_exports<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+) -> SqlxResult<Vec<AccountingExportRow>> {
+    sqlx::query_as::<_, AccountingExportRow>(&format!(
+        "SELECT {EXPORT_COLS} FROM accounting_exports WHERE club_id = $1 ORDER BY created_at DESC"
+    ))
+    .bind(club_id)
+    .fetch_all(executor)
+    .await
+}
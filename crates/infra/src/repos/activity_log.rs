@@ -1,7 +1,22 @@
 use crate::models::TournamentActivityLogRow;
-use sqlx::{PgPool, Postgres, Result as SqlxResult};
+use chrono::{DateTime, Utc};
+use futures_core::stream::BoxStream;
+use sqlx::{FromRow, PgExecutor, PgPool, Postgres, Result as SqlxResult};
 use uuid::Uuid;
 
+/// One line of a club's activity log export.
+#[derive(Debug, Clone, FromRow)]
+pub struct ActivityExportRow {
+    pub event_time: DateTime<Utc>,
+    pub tournament_id: Uuid,
+    pub tournament_name: String,
+    pub event_category: String,
+    pub event_action: String,
+    pub actor_id: Option<Uuid>,
+    pub subject_id: Option<Uuid>,
+    pub metadata: serde_json::Value,
+}
+
 /// Insert a new activity log entry and return it.
 pub async fn log_activity(
     executor: impl sqlx::Executor<'_, Database = Postgres>,
@@ -94,3 +109,28 @@ pub async fn count_by_tournament(
         Ok(row.0)
     }
 }
+
+/// Every activity entry of the club's tournaments logged in `[from, to)`,
+/// oldest first, streamed row by row for exports.
+pub fn stream_club_export<'e>(
+    executor: impl PgExecutor<'e> + 'e,
+    club_id: Uuid,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+) -> BoxStream<'e, SqlxResult<ActivityExportRow>> {
+    sqlx::query_as::<_, ActivityExportRow>(
+        "SELECT al.event_time, al.tournament_id, t.name AS tournament_name,
+                al.event_category, al.event_action, al.actor_id, al.subject_id,
+                COALESCE(al.metadata, '{}'::jsonb) AS metadata
+         FROM tournament_activity_log al
+         JOIN tournaments t ON t.id = al.tournament_id
+         WHERE t.club_id = $1
+           AND ($2::timestamptz IS NULL OR al.event_time >= $2)
+           AND ($3::timestamptz IS NULL OR al.event_time < $3)
+         ORDER BY al.event_time, al.id",
+    )
+    .bind(club_id)
+    .bind(from)
+    .bind(to)
+    .fetch(executor)
+}
@@ -18,7 +18,7 @@ pub struct ClubBreakdownRow {
 
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct BuyInBreakdownRow {
-    pub buy_in_cents: i32,
+    pub buy_in_cents: i64,
     pub tournaments: i64,
     pub buyins_cents: i64,
     pub winnings_cents: i64,
@@ -46,8 +46,8 @@ pub async fn by_club<'e>(
     sqlx::query_as::<_, ClubBreakdownRow>(&format!(
         "SELECT t.club_id AS club_id, c.name AS club_name, \
                 COUNT(*) AS tournaments, \
-                COALESCE(SUM(t.buy_in_cents), 0) AS buyins_cents, \
-                COALESCE(SUM(tr.prize_cents), 0) AS winnings_cents \
+                COALESCE(SUM(t.buy_in_cents), 0)::bigint AS buyins_cents, \
+                COALESCE(SUM(tr.prize_cents), 0)::bigint AS winnings_cents \
          {BASE_FROM} \
          GROUP BY t.club_id, c.name \
          ORDER BY tournaments DESC",
@@ -64,8 +64,8 @@ pub async fn by_buy_in<'e>(
     sqlx::query_as::<_, BuyInBreakdownRow>(&format!(
         "SELECT t.buy_in_cents AS buy_in_cents, \
                 COUNT(*) AS tournaments, \
-                COALESCE(SUM(t.buy_in_cents), 0) AS buyins_cents, \
-                COALESCE(SUM(tr.prize_cents), 0) AS winnings_cents \
+                COALESCE(SUM(t.buy_in_cents), 0)::bigint AS buyins_cents, \
+                COALESCE(SUM(tr.prize_cents), 0)::bigint AS winnings_cents \
          {BASE_FROM} \
          GROUP BY t.buy_in_cents \
          ORDER BY t.buy_in_cents ASC",
@@ -81,7 +81,7 @@ pub async fn pnl_timeline<'e>(
 ) -> SqlxResult<Vec<PnlPointRow>> {
     sqlx::query_as::<_, PnlPointRow>(&format!(
         "SELECT t.start_time::date AS day, \
-                COALESCE(SUM(tr.prize_cents), 0) - COALESCE(SUM(t.buy_in_cents), 0) AS net_cents \
+                COALESCE(SUM(tr.prize_cents), 0)::bigint - COALESCE(SUM(t.buy_in_cents), 0)::bigint AS net_cents \
          {BASE_FROM} \
          GROUP BY day \
          ORDER BY day ASC",
@@ -1,11 +1,13 @@
+use chrono::{DateTime, Utc};
 use sqlx::{PgExecutor, Result as SqlxResult};
 use uuid::Uuid;
 
 use crate::models::AnnouncementRow;
 use crate::pagination::LimitOffset;
+use crate::pii::Pii;
 
-const COLUMNS: &str =
-    "id, scope, club_id, tournament_id, title, body, created_by, created_at, updated_at";
+const COLUMNS: &str = "id, scope, club_id, tournament_id, title, body, created_by, priority, \
+     channels, pinned, scheduled_for, sent_at, created_at, updated_at";
 
 /// Persist a new announcement. `club_id`/`tournament_id` must match the scope
 /// (enforced by the table CHECK constraint).
@@ -32,6 +34,115 @@ pub async fn create<'e>(
     .await
 }
 
+/// Persist a tournament floor broadcast. An immediate broadcast
+/// (`scheduled_for` NULL) is stamped as sent on insert; a scheduled one stays
+/// pending until [`claim_due`] picks it up.
+#[allow(clippy::too_many_arguments)]
+pub async fn create_broadcast<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    tournament_id: Uuid,
+    title: &str,
+    body: &str,
+    channels: &[String],
+    pinned: bool,
+    scheduled_for: Option<DateTime<Utc>>,
+    created_by: Uuid,
+) -> SqlxResult<AnnouncementRow> {
+    sqlx::query_as::<_, AnnouncementRow>(&format!(
+        "INSERT INTO announcements (scope, club_id, tournament_id, title, body, created_by, \
+             priority, channels, pinned, scheduled_for, sent_at) \
+         VALUES ('tournament', $1, $2, $3, $4, $5, 'high', $6, $7, $8, \
+             CASE WHEN $8::timestamptz IS NULL THEN NOW() END) \
+         RETURNING {COLUMNS}"
+    ))
+    .bind(club_id)
+    .bind(tournament_id)
+    .bind(title)
+    .bind(body)
+    .bind(created_by)
+    .bind(channels)
+    .bind(pinned)
+    .bind(scheduled_for)
+    .fetch_one(executor)
+    .await
+}
+
+/// Fetch an announcement by id.
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<AnnouncementRow>> {
+    sqlx::query_as::<_, AnnouncementRow>(&format!(
+        "SELECT {COLUMNS} FROM announcements WHERE id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Mark every scheduled broadcast due at `now` as sent and return them for
+/// delivery. `SKIP LOCKED` lets several instances poll without double-sending.
+pub async fn claim_due<'e>(
+    executor: impl PgExecutor<'e>,
+    now: DateTime<Utc>,
+) -> SqlxResult<Vec<AnnouncementRow>> {
+    sqlx::query_as::<_, AnnouncementRow>(&format!(
+        "UPDATE announcements SET sent_at = NOW() \
+         WHERE id IN ( \
+             SELECT id FROM announcements \
+             WHERE sent_at IS NULL AND scheduled_for <= $1 \
+             ORDER BY scheduled_for \
+             FOR UPDATE SKIP LOCKED) \
+         RETURNING {COLUMNS}"
+    ))
+    .bind(now)
+    .fetch_all(executor)
+    .await
+}
+
+/// Delete a broadcast that has not gone out yet. Returns false when it was
+/// already sent (or does not exist).
+pub async fn delete_pending<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<bool> {
+    let result = sqlx::query("DELETE FROM announcements WHERE id = $1 AND sent_at IS NULL")
+        .bind(id)
+        .execute(executor)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Pin or unpin a tournament announcement in its lobby.
+pub async fn set_pinned<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    pinned: bool,
+) -> SqlxResult<Option<AnnouncementRow>> {
+    sqlx::query_as::<_, AnnouncementRow>(&format!(
+        "UPDATE announcements SET pinned = $2 \
+         WHERE id = $1 AND scope = 'tournament' RETURNING {COLUMNS}"
+    ))
+    .bind(id)
+    .bind(pinned)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A tournament's pinned, already-sent announcements, newest first — the
+/// lobby banner.
+pub async fn list_pinned_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<AnnouncementRow>> {
+    sqlx::query_as::<_, AnnouncementRow>(&format!(
+        "SELECT {COLUMNS} FROM announcements \
+         WHERE tournament_id = $1 AND pinned = true AND sent_at IS NOT NULL \
+         ORDER BY sent_at DESC"
+    ))
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
 /// The predicate matching every announcement an app user should see in their
 /// feed: any platform announcement, club announcements for clubs they are a
 /// claimed active roster member of, and tournament announcements for tournaments
@@ -47,6 +158,7 @@ const FEED_PREDICATE: &str = "(\
         WHERE tr.status NOT IN ('cancelled', 'no_show') \
           AND (tr.user_id = $1 OR cp.app_user_id = $1))) \
 ) \
+AND a.sent_at IS NOT NULL \
 AND (a.club_id IS NULL OR a.club_id NOT IN (SELECT id FROM clubs WHERE plan = 'free'))";
 
 /// One page of the announcements feed visible to `user_id`, newest first.
@@ -158,3 +270,52 @@ pub async fn audience_device_tokens<'e>(
         }
     }
 }
+
+/// App users registered (not cancelled/no_show) for a tournament — the in-app
+/// audience of a floor broadcast. No preference filter: the in-app channel is
+/// the lobby itself.
+pub async fn tournament_audience_user_ids<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<Uuid>> {
+    sqlx::query_scalar::<_, Uuid>(
+        "SELECT DISTINCT COALESCE(tr.user_id, cp.app_user_id) \
+         FROM tournament_registrations tr \
+         LEFT JOIN club_player cp ON cp.id = tr.club_player_id \
+         WHERE tr.tournament_id = $1 \
+           AND tr.status NOT IN ('cancelled', 'no_show') \
+           AND COALESCE(tr.user_id, cp.app_user_id) IS NOT NULL",
+    )
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Phone numbers of the registered players of a tournament who have one on
+/// file, honouring the `announcements` preference like the push audience.
+pub async fn tournament_audience_phones<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<(Uuid, String)>> {
+    let rows = sqlx::query_as::<_, (Uuid, Pii)>(
+        "SELECT DISTINCT u.id, u.phone \
+         FROM tournament_registrations tr \
+         LEFT JOIN club_player cp ON cp.id = tr.club_player_id \
+         JOIN users u ON u.id = COALESCE(tr.user_id, cp.app_user_id) \
+         LEFT JOIN notification_preferences np ON np.user_id = u.id \
+         WHERE tr.tournament_id = $1 \
+           AND tr.status NOT IN ('cancelled', 'no_show') \
+           AND u.is_active = true \
+           AND NULLIF(TRIM(u.phone), '') IS NOT NULL \
+           AND COALESCE(np.announcements, TRUE) = TRUE",
+    )
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await?;
+    // Sealed values are never blank, so check again once decrypted.
+    Ok(rows
+        .into_iter()
+        .map(|(id, phone)| (id, phone.into_inner()))
+        .filter(|(_, phone)| !phone.trim().is_empty())
+        .collect())
+}
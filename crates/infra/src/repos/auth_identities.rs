@@ -0,0 +1,115 @@
+//! External login identities (Google, ...) linked to an account.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "id, user_id, provider, provider_user_id, email, created_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct AuthIdentityRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub provider: String,
+    pub provider_user_id: String,
+    pub email: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// The account a provider identity is linked to, if any.
+pub async fn find_user_id<'e>(
+    executor: impl PgExecutor<'e>,
+    provider: &str,
+    provider_user_id: &str,
+) -> SqlxResult<Option<Uuid>> {
+    sqlx::query_scalar::<_, Uuid>(
+        "SELECT user_id FROM user_auth_identities WHERE provider = $1 AND provider_user_id = $2",
+    )
+    .bind(provider)
+    .bind(provider_user_id)
+    .fetch_optional(executor)
+    .await
+}
+
+pub async fn list_for_user<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+) -> SqlxResult<Vec<AuthIdentityRow>> {
+    sqlx::query_as::<_, AuthIdentityRow>(&format!(
+        "SELECT {COLS} FROM user_auth_identities WHERE user_id = $1 ORDER BY created_at"
+    ))
+    .bind(user_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Link an identity to an account. Fails with a unique violation when the
+/// identity belongs to another account or the account already has one for
+/// this provider.
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+    provider: &str,
+    provider_user_id: &str,
+    email: Option<&str>,
+) -> SqlxResult<AuthIdentityRow> {
+    sqlx::query_as::<_, AuthIdentityRow>(&format!(
+        "INSERT INTO user_auth_identities (user_id, provider, provider_user_id, email) \
+         VALUES ($1, $2, $3, $4) RETURNING {COLS}"
+    ))
+    .bind(user_id)
+    .bind(provider)
+    .bind(provider_user_id)
+    .bind(email)
+    .fetch_one(executor)
+    .await
+}
+
+/// Record an identity seen at login, unless it (or another identity for the
+/// same provider) is already linked.
+pub async fn record<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+    provider: &str,
+    provider_user_id: &str,
+    email: Option<&str>,
+) -> SqlxResult<()> {
+    sqlx::query(
+        "INSERT INTO user_auth_identities (user_id, provider, provider_user_id, email) \
+         VALUES ($1, $2, $3, $4) ON CONFLICT DO NOTHING",
+    )
+    .bind(user_id)
+    .bind(provider)
+    .bind(provider_user_id)
+    .bind(email)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+/// Returns whether an identity was removed.
+pub async fn delete<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+    provider: &str,
+) -> SqlxResult<bool> {
+    let result =
+        sqlx::query("DELETE FROM user_auth_identities WHERE user_id = $1 AND provider = $2")
+            .bind(user_id)
+            .bind(provider)
+            .execute(executor)
+            .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Remove every linked identity (account deletion).
+pub async fn delete_all_for_user<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+) -> SqlxResult<()> {
+    sqlx::query("DELETE FROM user_auth_identities WHERE user_id = $1")
+        .bind(user_id)
+        .execute(executor)
+        .await?;
+    Ok(())
+}
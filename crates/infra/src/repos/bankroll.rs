@@ -0,0 +1,189 @@
+//! Personal bankroll ledger. Every query is scoped to the owning user; there
+//! is deliberately no club-level accessor.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "id, user_id, kind, amount_cents, occurred_at, venue, description, source, tournament_id, created_at, updated_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct BankrollTransactionRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    /// "buy_in" | "cash"
+    pub kind: String,
+    pub amount_cents: i64,
+    pub occurred_at: DateTime<Utc>,
+    pub venue: Option<String>,
+    pub description: Option<String>,
+    /// "manual" | "import"
+    pub source: String,
+    pub tournament_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateBankrollTransaction {
+    pub kind: String,
+    pub amount_cents: i64,
+    pub occurred_at: DateTime<Utc>,
+    pub venue: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Net result of one calendar month (UTC).
+#[derive(Debug, Clone, FromRow)]
+pub struct BankrollMonthRow {
+    pub month: chrono::NaiveDate,
+    pub buy_ins_cents: i64,
+    pub cashes_cents: i64,
+}
+
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+    data: CreateBankrollTransaction,
+) -> SqlxResult<BankrollTransactionRow> {
+    sqlx::query_as::<_, BankrollTransactionRow>(&format!(
+        "INSERT INTO bankroll_transactions (user_id, kind, amount_cents, occurred_at, venue, description) \
+         VALUES ($1, $2, $3, $4, $5, $6) \
+         RETURNING {COLS}"
+    ))
+    .bind(user_id)
+    .bind(data.kind)
+    .bind(data.amount_cents)
+    .bind(data.occurred_at)
+    .bind(data.venue)
+    .bind(data.description)
+    .fetch_one(executor)
+    .await
+}
+
+/// Delete one of the user's rows. Returns false when it doesn't exist or
+/// belongs to someone else.
+pub async fn delete_owned<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+    id: Uuid,
+) -> SqlxResult<bool> {
+    let affected = sqlx::query("DELETE FROM bankroll_transactions WHERE id = $1 AND user_id = $2")
+        .bind(id)
+        .bind(user_id)
+        .execute(executor)
+        .await?
+        .rows_affected();
+    Ok(affected > 0)
+}
+
+/// The user's rows in `[from, to)`, newest first. Both bounds are optional.
+pub async fn list_for_user<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+) -> SqlxResult<Vec<BankrollTransactionRow>> {
+    sqlx::query_as::<_, BankrollTransactionRow>(&format!(
+        "SELECT {COLS} FROM bankroll_transactions \
+         WHERE user_id = $1 \
+           AND ($2::timestamptz IS NULL OR occurred_at >= $2) \
+           AND ($3::timestamptz IS NULL OR occurred_at < $3) \
+         ORDER BY occurred_at DESC, created_at DESC"
+    ))
+    .bind(user_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(executor)
+    .await
+}
+
+/// Buy-ins and cashes per month over the same window as `list_for_user`,
+/// oldest first.
+pub async fn monthly_for_user<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+) -> SqlxResult<Vec<BankrollMonthRow>> {
+    sqlx::query_as::<_, BankrollMonthRow>(
+        "SELECT date_trunc('month', occurred_at AT TIME ZONE 'UTC')::date AS month, \
+                COALESCE(SUM(amount_cents) FILTER (WHERE kind = 'buy_in'), 0)::bigint AS buy_ins_cents, \
+                COALESCE(SUM(amount_cents) FILTER (WHERE kind = 'cash'), 0)::bigint AS cashes_cents \
+         FROM bankroll_transactions \
+         WHERE user_id = $1 \
+           AND ($2::timestamptz IS NULL OR occurred_at >= $2) \
+           AND ($3::timestamptz IS NULL OR occurred_at < $3) \
+         GROUP BY month \
+         ORDER BY month ASC",
+    )
+    .bind(user_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(executor)
+    .await
+}
+
+/// Copy the user's finished tournaments into their ledger: one buy-in row
+/// (entries they paid for plus rake on each initial entry and re-entry) and
+/// one cash row when they won a prize. Entries and results count when made
+/// under the user's account or under a roster entry linked to it.
+///
+/// Safe to re-run: existing imported rows are refreshed in place, so a later
+/// correction by the club flows through. Returns the rows inserted or
+/// changed.
+pub async fn import_from_tournaments<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+) -> SqlxResult<u64> {
+    let affected = sqlx::query(
+        "WITH paid AS ( \
+             SELECT t.id AS tournament_id, t.name, t.start_time, c.name AS club_name, \
+                    COALESCE(SUM(e.amount_cents) FILTER (WHERE e.entry_type NOT IN ('voucher', 'bonus') \
+                                                  AND e.payment_method NOT IN ('voucher', 'comp')), 0)::bigint \
+                    + t.rake_cents * COUNT(*) FILTER (WHERE e.entry_type IN ('initial', 're_entry')) AS amount_cents \
+             FROM tournament_entries e \
+             JOIN tournaments t ON t.id = e.tournament_id \
+             JOIN clubs c ON c.id = t.club_id \
+             LEFT JOIN club_player cp ON cp.id = e.club_player_id \
+             WHERE COALESCE(e.user_id, cp.app_user_id) = $1 \
+               AND t.live_status = 'finished' \
+             GROUP BY t.id, t.name, t.start_time, c.name \
+         ), \
+         won AS ( \
+             SELECT t.id AS tournament_id, t.name, t.start_time, c.name AS club_name, \
+                    SUM(r.prize_cents)::bigint AS amount_cents \
+             FROM tournament_results r \
+             JOIN tournaments t ON t.id = r.tournament_id \
+             JOIN clubs c ON c.id = t.club_id \
+             LEFT JOIN club_player cp ON cp.id = r.club_player_id \
+             WHERE COALESCE(r.user_id, cp.app_user_id) = $1 \
+               AND t.live_status = 'finished' \
+               AND r.prize_cents > 0 \
+             GROUP BY t.id, t.name, t.start_time, c.name \
+         ), \
+         source AS ( \
+             SELECT 'buy_in' AS kind, * FROM paid WHERE amount_cents > 0 \
+             UNION ALL \
+             SELECT 'cash' AS kind, * FROM won \
+         ) \
+         INSERT INTO bankroll_transactions \
+             (user_id, kind, amount_cents, occurred_at, venue, description, source, tournament_id) \
+         SELECT $1, kind, amount_cents, start_time, club_name, name, 'import', tournament_id \
+         FROM source \
+         ON CONFLICT (user_id, tournament_id, kind) WHERE source = 'import' AND tournament_id IS NOT NULL \
+         DO UPDATE SET amount_cents = EXCLUDED.amount_cents, \
+                       occurred_at = EXCLUDED.occurred_at, \
+                       venue = EXCLUDED.venue, \
+                       description = EXCLUDED.description \
+         WHERE (bankroll_transactions.amount_cents, bankroll_transactions.occurred_at, \
+                bankroll_transactions.venue, bankroll_transactions.description) \
+               IS DISTINCT FROM \
+               (EXCLUDED.amount_cents, EXCLUDED.occurred_at, EXCLUDED.venue, EXCLUDED.description)",
+    )
+    .bind(user_id)
+    .execute(executor)
+    .await?
+    .rows_affected();
+    Ok(affected)
+}
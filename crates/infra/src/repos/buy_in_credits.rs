@@ -0,0 +1,253 @@
+//! Prepaid buy-in credits (packages and vouchers) and their use ledger.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+/// Credit columns plus the owner's name and the ledger balance; `c` aliases
+/// `buy_in_credits`.
+const COLS: &str = "c.id, c.club_id, c.kind, c.code, c.label, c.club_player_id, \
+     cp.display_name AS player_name, c.total_uses, \
+     COALESCE((SELECT SUM(l.delta) FROM buy_in_credit_ledger l WHERE l.credit_id = c.id), 0)::INT \
+         AS remaining_uses, \
+     c.expires_at, c.issued_by, c.issued_at, c.voided_by, c.voided_at";
+const JOINS: &str = "LEFT JOIN club_player cp ON cp.id = c.club_player_id";
+const LEDGER_COLS: &str = "l.id, l.credit_id, l.kind, l.delta, l.tournament_id, \
+     t.name AS tournament_name, l.registration_id, l.entry_id, l.reverses_id, l.created_by, \
+     l.created_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct CreditRow {
+    pub id: Uuid,
+    pub club_id: Uuid,
+    /// `package` | `voucher`.
+    pub kind: String,
+    pub code: String,
+    pub label: String,
+    /// `None` for a bearer voucher nobody has redeemed yet.
+    pub club_player_id: Option<Uuid>,
+    pub player_name: Option<String>,
+    pub total_uses: i32,
+    /// Sum of the credit's ledger.
+    pub remaining_uses: i32,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub issued_by: Option<Uuid>,
+    pub issued_at: DateTime<Utc>,
+    pub voided_by: Option<Uuid>,
+    pub voided_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct LedgerRow {
+    pub id: Uuid,
+    pub credit_id: Uuid,
+    /// `issue` | `redemption` | `refund`.
+    pub kind: String,
+    pub delta: i32,
+    pub tournament_id: Option<Uuid>,
+    pub tournament_name: Option<String>,
+    pub registration_id: Option<Uuid>,
+    pub entry_id: Option<Uuid>,
+    pub reverses_id: Option<Uuid>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewCredit {
+    pub club_id: Uuid,
+    pub kind: String,
+    pub code: String,
+    pub label: String,
+    pub club_player_id: Option<Uuid>,
+    pub total_uses: i32,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub issued_by: Uuid,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct NewLedgerEntry {
+    pub credit_id: Uuid,
+    pub kind: &'static str,
+    pub delta: i32,
+    pub tournament_id: Option<Uuid>,
+    pub registration_id: Option<Uuid>,
+    pub reverses_id: Option<Uuid>,
+    pub created_by: Option<Uuid>,
+}
+
+/// Insert a credit. The caller books its `issue` ledger entry.
+pub async fn create<'e>(executor: impl PgExecutor<'e>, data: NewCredit) -> SqlxResult<Uuid> {
+    sqlx::query_scalar(
+        "INSERT INTO buy_in_credits \
+             (club_id, kind, code, label, club_player_id, total_uses, expires_at, issued_by) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING id",
+    )
+    .bind(data.club_id)
+    .bind(data.kind)
+    .bind(data.code)
+    .bind(data.label)
+    .bind(data.club_player_id)
+    .bind(data.total_uses)
+    .bind(data.expires_at)
+    .bind(data.issued_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<CreditRow>> {
+    sqlx::query_as::<_, CreditRow>(&format!(
+        "SELECT {COLS} FROM buy_in_credits c {JOINS} WHERE c.id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Lock the credit with this code so concurrent redemptions see each
+/// other's ledger entries.
+pub async fn lock_by_code<'e>(
+    executor: impl PgExecutor<'e>,
+    code: &str,
+) -> SqlxResult<Option<CreditRow>> {
+    sqlx::query_as::<_, CreditRow>(&format!(
+        "SELECT {COLS} FROM buy_in_credits c {JOINS} WHERE c.code = $1 FOR UPDATE OF c"
+    ))
+    .bind(code)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A club's credits, newest first, optionally for one player.
+pub async fn list_for_club<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    club_player_id: Option<Uuid>,
+) -> SqlxResult<Vec<CreditRow>> {
+    sqlx::query_as::<_, CreditRow>(&format!(
+        "SELECT {COLS} FROM buy_in_credits c {JOINS} \
+         WHERE c.club_id = $1 AND ($2::UUID IS NULL OR c.club_player_id = $2) \
+         ORDER BY c.issued_at DESC, c.id"
+    ))
+    .bind(club_id)
+    .bind(club_player_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Credits owned by the user's roster identities, newest first.
+pub async fn list_for_user<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+) -> SqlxResult<Vec<CreditRow>> {
+    sqlx::query_as::<_, CreditRow>(&format!(
+        "SELECT {COLS} FROM buy_in_credits c {JOINS} \
+         WHERE cp.app_user_id = $1 ORDER BY c.issued_at DESC, c.id"
+    ))
+    .bind(user_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Give a bearer voucher its owner on first redemption.
+pub async fn claim<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    club_player_id: Uuid,
+) -> SqlxResult<()> {
+    sqlx::query(
+        "UPDATE buy_in_credits SET club_player_id = $2 WHERE id = $1 AND club_player_id IS NULL",
+    )
+    .bind(id)
+    .bind(club_player_id)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+/// Void a credit; its remaining uses can't be redeemed any more. `false` when
+/// it was already void.
+pub async fn void<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    voided_by: Uuid,
+) -> SqlxResult<bool> {
+    let result = sqlx::query(
+        "UPDATE buy_in_credits SET voided_at = NOW(), voided_by = $2 \
+         WHERE id = $1 AND voided_at IS NULL",
+    )
+    .bind(id)
+    .bind(voided_by)
+    .execute(executor)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn add_ledger_entry<'e>(
+    executor: impl PgExecutor<'e>,
+    entry: NewLedgerEntry,
+) -> SqlxResult<Uuid> {
+    sqlx::query_scalar(
+        "INSERT INTO buy_in_credit_ledger \
+             (credit_id, kind, delta, tournament_id, registration_id, reverses_id, created_by) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING id",
+    )
+    .bind(entry.credit_id)
+    .bind(entry.kind)
+    .bind(entry.delta)
+    .bind(entry.tournament_id)
+    .bind(entry.registration_id)
+    .bind(entry.reverses_id)
+    .bind(entry.created_by)
+    .fetch_one(executor)
+    .await
+}
+
+/// A credit's ledger, newest first.
+pub async fn list_ledger<'e>(
+    executor: impl PgExecutor<'e>,
+    credit_id: Uuid,
+) -> SqlxResult<Vec<LedgerRow>> {
+    sqlx::query_as::<_, LedgerRow>(&format!(
+        "SELECT {LEDGER_COLS} FROM buy_in_credit_ledger l \
+         LEFT JOIN tournaments t ON t.id = l.tournament_id \
+         WHERE l.credit_id = $1 ORDER BY l.created_at DESC, l.id"
+    ))
+    .bind(credit_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// The registration's redemption that no refund has given back, if any.
+pub async fn open_redemption<'e>(
+    executor: impl PgExecutor<'e>,
+    registration_id: Uuid,
+) -> SqlxResult<Option<LedgerRow>> {
+    sqlx::query_as::<_, LedgerRow>(&format!(
+        "SELECT {LEDGER_COLS} FROM buy_in_credit_ledger l \
+         LEFT JOIN tournaments t ON t.id = l.tournament_id \
+         WHERE l.registration_id = $1 AND l.kind = 'redemption' \
+           AND NOT EXISTS (SELECT 1 FROM buy_in_credit_ledger r WHERE r.reverses_id = l.id) \
+         ORDER BY l.created_at DESC LIMIT 1"
+    ))
+    .bind(registration_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Record the buy-in entry a redemption paid for.
+pub async fn link_entry<'e>(
+    executor: impl PgExecutor<'e>,
+    ledger_id: Uuid,
+    entry_id: Uuid,
+) -> SqlxResult<()> {
+    sqlx::query("UPDATE buy_in_credit_ledger SET entry_id = $2 WHERE id = $1")
+        .bind(ledger_id)
+        .bind(entry_id)
+        .execute(executor)
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,117 @@
+//! Inputs of the capacity plan: how past fields at the club shrank, and the
+//! table sizes a tournament can seat players at.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+/// How one finished tournament's field filled up and shrank.
+#[derive(Debug, Clone, FromRow)]
+pub struct FieldHistoryRow {
+    pub tournament_id: Uuid,
+    /// First clock start, or the scheduled start without a clock history.
+    pub started_at: DateTime<Utc>,
+    /// Registrations that played (not cancelled).
+    pub entrants: i64,
+    /// Of those, the ones registered after the start.
+    pub late_entrants: i64,
+    /// Recorded eliminations.
+    pub busts: i64,
+    /// Minutes from the start to the last elimination.
+    pub minutes_to_last_bust: Option<f64>,
+}
+
+/// The club's most recent finished tournaments, newest first, other than
+/// `exclude_tournament_id`.
+pub async fn field_history<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    exclude_tournament_id: Uuid,
+    limit: i64,
+) -> SqlxResult<Vec<FieldHistoryRow>> {
+    sqlx::query_as::<_, FieldHistoryRow>(
+        r#"
+        WITH recent AS (
+            SELECT t.id,
+                   COALESCE(
+                       (SELECT MIN(l.event_time) FROM tournament_activity_log l
+                        WHERE l.tournament_id = t.id AND l.event_category = 'clock'
+                          AND l.event_action = 'start'),
+                       t.start_time
+                   ) AS started_at
+            FROM tournaments t
+            WHERE t.club_id = $1 AND t.id <> $2 AND t.live_status = 'finished'
+            ORDER BY t.start_time DESC
+            LIMIT $3
+        ),
+        busts AS (
+            -- Eliminating a player zeroes the stack and closes the seat.
+            SELECT s.tournament_id, COUNT(*) AS busts, MAX(s.unassigned_at) AS last_bust_at
+            FROM table_seat_assignments s
+            JOIN recent r ON r.id = s.tournament_id
+            WHERE s.stack_size = 0 AND s.unassigned_at IS NOT NULL
+            GROUP BY s.tournament_id
+        )
+        SELECT r.id AS tournament_id,
+               r.started_at,
+               (SELECT COUNT(*) FROM tournament_registrations reg
+                WHERE reg.tournament_id = r.id AND reg.status <> 'cancelled') AS entrants,
+               (SELECT COUNT(*) FROM tournament_registrations reg
+                WHERE reg.tournament_id = r.id AND reg.status <> 'cancelled'
+                  AND reg.registration_time > r.started_at) AS late_entrants,
+               COALESCE(b.busts, 0) AS busts,
+               (EXTRACT(EPOCH FROM b.last_bust_at - r.started_at) / 60)::FLOAT8
+                   AS minutes_to_last_bust
+        FROM recent r
+        LEFT JOIN busts b ON b.tournament_id = r.id
+        ORDER BY r.started_at DESC
+        "#,
+    )
+    .bind(club_id)
+    .bind(exclude_tournament_id)
+    .bind(limit)
+    .fetch_all(executor)
+    .await
+}
+
+/// Seats of the active tables linked to the tournament, or of the club's
+/// active tables when none are linked yet, capped by the tournament's
+/// `seats_per_table`.
+pub async fn table_sizes<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<i32>> {
+    sqlx::query_scalar(
+        r#"
+        WITH linked AS (
+            SELECT COALESCE(tta.max_seats_override, LEAST(ct.max_seats, t.seats_per_table)) AS seats
+            FROM tournament_table_assignments tta
+            JOIN tournaments t ON t.id = tta.tournament_id
+            JOIN club_tables ct ON ct.id = tta.club_table_id
+            WHERE tta.tournament_id = $1 AND tta.is_active = true
+        )
+        SELECT seats FROM linked
+        UNION ALL
+        SELECT LEAST(ct.max_seats, t.seats_per_table) FROM club_tables ct
+        JOIN tournaments t ON t.club_id = ct.club_id
+        WHERE t.id = $1 AND ct.is_active = true AND NOT EXISTS (SELECT 1 FROM linked)
+        "#,
+    )
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// When the tournament's clock was first started, if it has been.
+pub async fn started_at<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Option<DateTime<Utc>>> {
+    sqlx::query_scalar(
+        "SELECT MIN(event_time) FROM tournament_activity_log \
+         WHERE tournament_id = $1 AND event_category = 'clock' AND event_action = 'start'",
+    )
+    .bind(tournament_id)
+    .fetch_one(executor)
+    .await
+}
@@ -0,0 +1,122 @@
+//! Seats closed for a tournament so its tables play equally short-handed.
+//! Seating never draws, balances or moves anyone into a closed seat.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "tournament_id, club_table_id, seat_number, club_id, closed_by, closed_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ClosedSeatRow {
+    pub tournament_id: Uuid,
+    pub club_table_id: Uuid,
+    pub seat_number: i32,
+    pub club_id: Uuid,
+    pub closed_by: Option<Uuid>,
+    pub closed_at: DateTime<Utc>,
+}
+
+/// Every closed seat of a tournament, by table then seat.
+pub async fn list_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<ClosedSeatRow>> {
+    sqlx::query_as::<_, ClosedSeatRow>(&format!(
+        "SELECT {COLS} FROM tournament_closed_seats \
+         WHERE tournament_id = $1 ORDER BY club_table_id, seat_number"
+    ))
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// The closed seat numbers of one table in a tournament.
+pub async fn list_for_table<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    club_table_id: Uuid,
+) -> SqlxResult<Vec<i32>> {
+    let rows: Vec<(i32,)> = sqlx::query_as(
+        "SELECT seat_number FROM tournament_closed_seats \
+         WHERE tournament_id = $1 AND club_table_id = $2 ORDER BY seat_number",
+    )
+    .bind(tournament_id)
+    .bind(club_table_id)
+    .fetch_all(executor)
+    .await?;
+    Ok(rows.into_iter().map(|(seat,)| seat).collect())
+}
+
+pub async fn is_closed<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    club_table_id: Uuid,
+    seat_number: i32,
+) -> SqlxResult<bool> {
+    let (closed,): (bool,) = sqlx::query_as(
+        "SELECT EXISTS (SELECT 1 FROM tournament_closed_seats \
+         WHERE tournament_id = $1 AND club_table_id = $2 AND seat_number = $3)",
+    )
+    .bind(tournament_id)
+    .bind(club_table_id)
+    .bind(seat_number)
+    .fetch_one(executor)
+    .await?;
+    Ok(closed)
+}
+
+/// Close `seat_numbers` at a table. Seats already closed are left as they
+/// were; returns the newly closed ones.
+pub async fn close<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    club_table_id: Uuid,
+    seat_numbers: &[i32],
+    closed_by: Option<Uuid>,
+) -> SqlxResult<Vec<ClosedSeatRow>> {
+    sqlx::query_as::<_, ClosedSeatRow>(&format!(
+        "INSERT INTO tournament_closed_seats \
+            (tournament_id, club_table_id, seat_number, club_id, closed_by) \
+         SELECT t.id, $2, seat, t.club_id, $4 \
+         FROM tournaments t, UNNEST($3::INT[]) AS seat \
+         WHERE t.id = $1 \
+         ON CONFLICT DO NOTHING \
+         RETURNING {COLS}"
+    ))
+    .bind(tournament_id)
+    .bind(club_table_id)
+    .bind(seat_numbers)
+    .bind(closed_by)
+    .fetch_all(executor)
+    .await
+}
+
+/// Reopen `seat_numbers` at a table; returns how many were closed.
+pub async fn reopen<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    club_table_id: Uuid,
+    seat_numbers: &[i32],
+) -> SqlxResult<u64> {
+    let result = sqlx::query(
+        "DELETE FROM tournament_closed_seats \
+         WHERE tournament_id = $1 AND club_table_id = $2 AND seat_number = ANY($3)",
+    )
+    .bind(tournament_id)
+    .bind(club_table_id)
+    .bind(seat_numbers)
+    .execute(executor)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// Reopen every closed seat of a tournament, e.g. when a table breaks and
+/// the closures no longer fit the table count.
+pub async fn reopen_all<'e>(executor: impl PgExecutor<'e>, tournament_id: Uuid) -> SqlxResult<u64> {
+    let result = sqlx::query("DELETE FROM tournament_closed_seats WHERE tournament_id = $1")
+        .bind(tournament_id)
+        .execute(executor)
+        .await?;
+    Ok(result.rows_affected())
+}
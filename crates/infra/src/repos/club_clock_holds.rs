@@ -0,0 +1,108 @@
+//! Club-wide clock holds: every running clock in a club paused at once, and
+//! resumed together with the held time kept out of the levels.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgConnection, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "id, club_id, reason, tournament_ids, paused_by, paused_at, resumed_by, \
+                    resumed_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ClubClockHoldRow {
+    pub id: Uuid,
+    pub club_id: Uuid,
+    pub reason: String,
+    /// The tournaments whose running clocks the hold paused.
+    pub tournament_ids: Vec<Uuid>,
+    pub paused_by: Option<Uuid>,
+    pub paused_at: DateTime<Utc>,
+    pub resumed_by: Option<Uuid>,
+    pub resumed_at: Option<DateTime<Utc>>,
+}
+
+/// The club's hold in force, if any.
+pub async fn get_active<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+) -> SqlxResult<Option<ClubClockHoldRow>> {
+    sqlx::query_as::<_, ClubClockHoldRow>(&format!(
+        "SELECT {COLS} FROM club_clock_holds WHERE club_id = $1 AND resumed_at IS NULL"
+    ))
+    .bind(club_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Pause every running clock of the club's tournaments and record the hold.
+/// Fails on the active-hold index if the club already has one.
+pub async fn pause_all(
+    conn: &mut PgConnection,
+    club_id: Uuid,
+    reason: &str,
+    paused_by: Option<Uuid>,
+) -> SqlxResult<ClubClockHoldRow> {
+    let now = Utc::now();
+    let tournament_ids: Vec<Uuid> = sqlx::query_scalar(
+        "UPDATE tournament_clocks c
+         SET clock_status = 'paused', pause_started_at = $2
+         FROM tournaments t
+         WHERE t.id = c.tournament_id AND t.club_id = $1 AND t.deleted_at IS NULL
+           AND c.clock_status = 'running'
+         RETURNING c.tournament_id",
+    )
+    .bind(club_id)
+    .bind(now)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    sqlx::query_as::<_, ClubClockHoldRow>(&format!(
+        "INSERT INTO club_clock_holds (club_id, reason, tournament_ids, paused_by, paused_at) \
+         VALUES ($1, $2, $3, $4, $5) RETURNING {COLS}"
+    ))
+    .bind(club_id)
+    .bind(reason)
+    .bind(&tournament_ids)
+    .bind(paused_by)
+    .bind(now)
+    .fetch_one(&mut *conn)
+    .await
+}
+
+/// Restart the clocks the hold paused that are still paused, pushing each
+/// level's end back by the time held, and close the hold. `None` if it was
+/// already resumed.
+pub async fn resume(
+    conn: &mut PgConnection,
+    id: Uuid,
+    resumed_by: Option<Uuid>,
+) -> SqlxResult<Option<ClubClockHoldRow>> {
+    let now = Utc::now();
+    let Some(hold) = sqlx::query_as::<_, ClubClockHoldRow>(&format!(
+        "UPDATE club_clock_holds SET resumed_by = $2, resumed_at = $3 \
+         WHERE id = $1 AND resumed_at IS NULL RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(resumed_by)
+    .bind(now)
+    .fetch_optional(&mut *conn)
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    sqlx::query(
+        "UPDATE tournament_clocks
+         SET clock_status = 'running',
+             total_pause_duration = total_pause_duration + ($2 - pause_started_at),
+             level_end_time = level_end_time + ($2 - pause_started_at),
+             pause_started_at = NULL
+         WHERE tournament_id = ANY($1) AND clock_status = 'paused'",
+    )
+    .bind(&hold.tournament_ids)
+    .bind(now)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(Some(hold))
+}
@@ -74,6 +74,21 @@ pub async fn get_by_id(pool: &Db, id: Uuid) -> Result<Option<ClubManagerRow>> {
     Ok(row)
 }
 
+/// The user's active assignment at a club, if any.
+pub async fn find_active(
+    pool: &Db,
+    club_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<ClubManagerRow>> {
+    sqlx::query_as::<_, ClubManagerRow>(
+        "SELECT * FROM club_managers WHERE club_id = $1 AND user_id = $2 AND is_active = true",
+    )
+    .bind(club_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await
+}
+
 pub async fn deactivate(pool: &Db, id: Uuid) -> Result<Option<ClubManagerRow>> {
     let row = sqlx::query_as!(
         ClubManagerRow,
@@ -168,3 +183,19 @@ pub async fn count_active_by_club(pool: &Db, club_id: Uuid) -> Result<i64> {
     .fetch_one(pool)
     .await
 }
+
+/// Clubs a user acts for: those they actively manage, and every club of the
+/// organizations they administer. The row-level security scope of their
+/// requests; staff reach their club's rows through narrower policies.
+pub async fn acting_club_ids(pool: &Db, user_id: Uuid) -> Result<Vec<Uuid>> {
+    sqlx::query_scalar(
+        "SELECT club_id FROM club_managers WHERE user_id = $1 AND is_active \
+         UNION \
+         SELECT c.id FROM clubs c \
+         JOIN organization_admins oa ON oa.organization_id = c.organization_id \
+         WHERE oa.user_id = $1",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await
+}
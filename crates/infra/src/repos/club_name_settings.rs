@@ -0,0 +1,98 @@
+//! How a club sorts and prints player names: "First Last" or "Last, First",
+//! compared with a locale's ICU collation rather than byte-wise.
+
+use chrono::{DateTime, Utc};
+use sqlx::{Acquire, FromRow, PgExecutor, Postgres, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "club_id, name_order, sort_locale, created_at, updated_at";
+
+/// Locales with a `player_name_<locale>` collation; `und` is the root
+/// (language-neutral) collation.
+pub const SORT_LOCALES: &[&str] = &["und", "en", "fr", "nl", "de"];
+
+#[derive(Debug, Clone, FromRow)]
+pub struct NameSettingsRow {
+    pub club_id: Uuid,
+    /// `first_last` or `last_first`.
+    pub name_order: String,
+    /// One of [`SORT_LOCALES`].
+    pub sort_locale: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl NameSettingsRow {
+    pub fn last_name_first(&self) -> bool {
+        self.name_order == "last_first"
+    }
+
+    /// The quoted collation for the club's locale. Only names from
+    /// [`SORT_LOCALES`] come back, so it is safe to splice into SQL.
+    pub fn collation(&self) -> &'static str {
+        match self.sort_locale.as_str() {
+            "en" => "\"player_name_en\"",
+            "fr" => "\"player_name_fr\"",
+            "nl" => "\"player_name_nl\"",
+            "de" => "\"player_name_de\"",
+            _ => "\"player_name_und\"",
+        }
+    }
+
+    /// ORDER BY terms sorting the `club_player` rows aliased `cp`. Entries
+    /// without a family name sort by their display name.
+    pub fn order_by(&self, cp: &str) -> String {
+        let c = self.collation();
+        if self.last_name_first() {
+            format!(
+                "COALESCE(NULLIF({cp}.last_name, ''), {cp}.display_name) COLLATE {c}, \
+                 {cp}.first_name COLLATE {c}, {cp}.id"
+            )
+        } else {
+            format!("{cp}.display_name COLLATE {c}, {cp}.id")
+        }
+    }
+
+    /// The name as printed for the `club_player` row aliased `cp`: "Last,
+    /// First" when the club prefers it and both parts are known.
+    pub fn printed_name(&self, cp: &str) -> String {
+        if self.last_name_first() {
+            format!(
+                "CASE WHEN COALESCE({cp}.first_name, '') <> '' AND COALESCE({cp}.last_name, '') <> '' \
+                 THEN {cp}.last_name || ', ' || {cp}.first_name ELSE {cp}.display_name END"
+            )
+        } else {
+            format!("{cp}.display_name")
+        }
+    }
+}
+
+/// The club's settings, created with the defaults on first use.
+pub async fn get_or_create<'a>(
+    conn: impl Acquire<'a, Database = Postgres>,
+    club_id: Uuid,
+) -> SqlxResult<NameSettingsRow> {
+    let mut conn = conn.acquire().await?;
+    super::get_or_insert_default(&mut conn, "club_name_settings", COLS, club_id).await
+}
+
+pub async fn upsert<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    name_order: &str,
+    sort_locale: &str,
+) -> SqlxResult<NameSettingsRow> {
+    sqlx::query_as::<_, NameSettingsRow>(&format!(
+        "INSERT INTO club_name_settings (club_id, name_order, sort_locale) \
+         VALUES ($1, $2, $3) \
+         ON CONFLICT (club_id) DO UPDATE SET \
+            name_order = EXCLUDED.name_order, \
+            sort_locale = EXCLUDED.sort_locale \
+         RETURNING {COLS}"
+    ))
+    .bind(club_id)
+    .bind(name_order)
+    .bind(sort_locale)
+    .fetch_one(executor)
+    .await
+}
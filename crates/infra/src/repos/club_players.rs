@@ -1,6 +1,7 @@
 use sqlx::{PgExecutor, Result as SqlxResult};
 use uuid::Uuid;
 
+use super::club_name_settings::NameSettingsRow;
 use crate::models::ClubPlayerRow;
 
 const COLUMNS: &str = "id, club_id, display_name, first_name, last_name, app_user_id, is_active, created_at, updated_at";
@@ -33,6 +34,24 @@ pub async fn list_by_club<'e>(
     .await
 }
 
+/// The active roster in the club's name order and collation, for the roster
+/// view.
+pub async fn list_by_club_sorted<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    names: &NameSettingsRow,
+) -> SqlxResult<Vec<ClubPlayerRow>> {
+    let order_by = names.order_by("cp");
+    sqlx::query_as::<_, ClubPlayerRow>(&format!(
+        "SELECT {COLUMNS} FROM club_player cp \
+         WHERE club_id = $1 AND is_active = true \
+         ORDER BY {order_by}"
+    ))
+    .bind(club_id)
+    .fetch_all(executor)
+    .await
+}
+
 /// All roster entries an app user is linked to, across every club.
 /// The fan-out of these rows is the cross-club profile.
 pub async fn list_for_app_user<'e>(
@@ -0,0 +1,125 @@
+//! A club's staff roster (dealers, floor, cashiers). Staff need not have an
+//! app account.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str =
+    "id, club_id, display_name, user_id, role, hourly_rate_cents, is_active, created_at, updated_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ClubStaffRow {
+    pub id: Uuid,
+    pub club_id: Uuid,
+    pub display_name: String,
+    pub user_id: Option<Uuid>,
+    /// dealer | floor | cashier | other
+    pub role: String,
+    pub hourly_rate_cents: Option<i64>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateClubStaff {
+    pub club_id: Uuid,
+    pub display_name: String,
+    pub user_id: Option<Uuid>,
+    pub role: String,
+    pub hourly_rate_cents: Option<i64>,
+}
+
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    data: CreateClubStaff,
+) -> SqlxResult<ClubStaffRow> {
+    sqlx::query_as::<_, ClubStaffRow>(&format!(
+        "INSERT INTO club_staff (club_id, display_name, user_id, role, hourly_rate_cents) \
+         VALUES ($1, $2, $3, $4, $5) RETURNING {COLS}"
+    ))
+    .bind(data.club_id)
+    .bind(data.display_name)
+    .bind(data.user_id)
+    .bind(data.role)
+    .bind(data.hourly_rate_cents)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<ClubStaffRow>> {
+    sqlx::query_as::<_, ClubStaffRow>(&format!("SELECT {COLS} FROM club_staff WHERE id = $1"))
+        .bind(id)
+        .fetch_optional(executor)
+        .await
+}
+
+/// A club's staff, by name. `role = None` lists every role.
+pub async fn list_by_club<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    role: Option<&str>,
+    active_only: bool,
+) -> SqlxResult<Vec<ClubStaffRow>> {
+    sqlx::query_as::<_, ClubStaffRow>(&format!(
+        "SELECT {COLS} FROM club_staff \
+         WHERE club_id = $1 AND ($2::text IS NULL OR role = $2) AND (NOT $3 OR is_active) \
+         ORDER BY display_name ASC"
+    ))
+    .bind(club_id)
+    .bind(role)
+    .bind(active_only)
+    .fetch_all(executor)
+    .await
+}
+
+pub async fn set_active<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    is_active: bool,
+) -> SqlxResult<Option<ClubStaffRow>> {
+    sqlx::query_as::<_, ClubStaffRow>(&format!(
+        "UPDATE club_staff SET is_active = $2 WHERE id = $1 RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(is_active)
+    .fetch_optional(executor)
+    .await
+}
+
+pub async fn set_hourly_rate<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    hourly_rate_cents: Option<i64>,
+) -> SqlxResult<Option<ClubStaffRow>> {
+    sqlx::query_as::<_, ClubStaffRow>(&format!(
+        "UPDATE club_staff SET hourly_rate_cents = $2 WHERE id = $1 RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(hourly_rate_cents)
+    .fetch_optional(executor)
+    .await
+}
+
+/// The active roster entry in `role` linked to an app account at a club.
+pub async fn find_active_for_user<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    user_id: Uuid,
+    role: &str,
+) -> SqlxResult<Option<ClubStaffRow>> {
+    sqlx::query_as::<_, ClubStaffRow>(&format!(
+        "SELECT {COLS} FROM club_staff \
+         WHERE club_id = $1 AND user_id = $2 AND role = $3 AND is_active \
+         ORDER BY created_at ASC LIMIT 1"
+    ))
+    .bind(club_id)
+    .bind(user_id)
+    .bind(role)
+    .fetch_optional(executor)
+    .await
+}
@@ -0,0 +1,48 @@
+//! What a club's leaderboard statistics count as a final table and a cash.
+
+use chrono::{DateTime, Utc};
+use sqlx::{Acquire, FromRow, PgExecutor, Postgres, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "club_id, final_table_size, itm_places, created_at, updated_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct StatsSettingsRow {
+    pub club_id: Uuid,
+    /// Finishing places that make the final table.
+    pub final_table_size: i32,
+    /// Places counted as in the money; `None` counts any prize.
+    pub itm_places: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The club's settings, created with the defaults on first use.
+pub async fn get_or_create<'a>(
+    conn: impl Acquire<'a, Database = Postgres>,
+    club_id: Uuid,
+) -> SqlxResult<StatsSettingsRow> {
+    let mut conn = conn.acquire().await?;
+    super::get_or_insert_default(&mut conn, "club_stats_settings", COLS, club_id).await
+}
+
+pub async fn upsert<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    final_table_size: i32,
+    itm_places: Option<i32>,
+) -> SqlxResult<StatsSettingsRow> {
+    sqlx::query_as::<_, StatsSettingsRow>(&format!(
+        "INSERT INTO club_stats_settings (club_id, final_table_size, itm_places) \
+         VALUES ($1, $2, $3) \
+         ON CONFLICT (club_id) DO UPDATE SET \
+            final_table_size = EXCLUDED.final_table_size, \
+            itm_places = EXCLUDED.itm_places \
+         RETURNING {COLS}"
+    ))
+    .bind(club_id)
+    .bind(final_table_size)
+    .bind(itm_places)
+    .fetch_one(executor)
+    .await
+}
@@ -155,7 +155,7 @@ pub async fn active_table_conflicts<'e>(
         WHERE tta.club_table_id = ANY($1)
             AND tta.is_active = true
             AND tta.tournament_id <> $2
-            AND t.live_status <> 'finished'
+            AND t.live_status NOT IN ('finished', 'cancelled')
         ORDER BY ct.table_number ASC
         "#,
     )
@@ -226,7 +226,7 @@ pub async fn assign_to_tournament<'e>(
             deactivated_at = NULL,
             max_seats_override = $3,
             updated_at = NOW()
-        RETURNING id, tournament_id, club_table_id, is_active, assigned_at, deactivated_at, max_seats_override, created_at, updated_at
+        RETURNING id, tournament_id, club_table_id, is_active, assigned_at, deactivated_at, max_seats_override, status, notes, status_updated_by, status_updated_at, created_at, updated_at
         "#
     )
     .bind(tournament_id)
@@ -256,6 +256,61 @@ pub async fn unassign_from_tournament<'e>(
     Ok(result.rows_affected() > 0)
 }
 
+/// Set the floor status and notes of a table in a tournament. `None` when
+/// the table isn't in play there.
+pub async fn set_status<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    club_table_id: Uuid,
+    status: &str,
+    notes: Option<&str>,
+    updated_by: Option<Uuid>,
+) -> SqlxResult<Option<TournamentTableAssignmentRow>> {
+    sqlx::query_as::<_, TournamentTableAssignmentRow>(
+        r#"
+        UPDATE tournament_table_assignments
+        SET status = $3, notes = $4, status_updated_by = $5, status_updated_at = NOW(),
+            updated_at = NOW()
+        WHERE tournament_id = $1 AND club_table_id = $2 AND is_active = true
+        RETURNING id, tournament_id, club_table_id, is_active, assigned_at, deactivated_at, max_seats_override, status, notes, status_updated_by, status_updated_at, created_at, updated_at
+        "#,
+    )
+    .bind(tournament_id)
+    .bind(club_table_id)
+    .bind(status)
+    .bind(notes)
+    .bind(updated_by)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Lock the table's active assignment to the tournament (`FOR UPDATE`) and
+/// return the seats it plays with there, as [`list_assigned_to_tournament`]
+/// counts them. `None` when the table isn't in play in the tournament.
+pub async fn lock_seats_in_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    club_table_id: Uuid,
+) -> SqlxResult<Option<i32>> {
+    sqlx::query_scalar(
+        r#"
+        SELECT COALESCE(tta.max_seats_override, LEAST(ct.max_seats, t.seats_per_table))
+        FROM tournament_table_assignments tta
+        INNER JOIN club_tables ct ON ct.id = tta.club_table_id
+        INNER JOIN tournaments t ON t.id = tta.tournament_id
+        WHERE tta.tournament_id = $1 AND tta.club_table_id = $2 AND tta.is_active = true
+        FOR UPDATE OF tta
+        "#,
+    )
+    .bind(tournament_id)
+    .bind(club_table_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// The tournament's active tables, each with the seats it plays with there:
+/// the assignment's override, else the table's seats capped by the
+/// tournament's `seats_per_table`.
 pub async fn list_assigned_to_tournament<'e>(
     executor: impl PgExecutor<'e>,
     tournament_id: Uuid,
@@ -263,10 +318,11 @@ pub async fn list_assigned_to_tournament<'e>(
     sqlx::query_as::<_, ClubTableRow>(
         r#"
         SELECT ct.id, ct.club_id, ct.table_number,
-               COALESCE(tta.max_seats_override, ct.max_seats) as max_seats,
+               COALESCE(tta.max_seats_override, LEAST(ct.max_seats, t.seats_per_table)) as max_seats,
                ct.is_active, ct.is_default, ct.created_at, ct.updated_at
         FROM club_tables ct
         INNER JOIN tournament_table_assignments tta ON ct.id = tta.club_table_id
+        INNER JOIN tournaments t ON t.id = tta.tournament_id
         WHERE tta.tournament_id = $1 AND tta.is_active = true
         ORDER BY ct.table_number ASC
         "#,
@@ -275,3 +331,49 @@ pub async fn list_assigned_to_tournament<'e>(
     .fetch_all(executor)
     .await
 }
+
+/// The tournament's active table assignments, with their seat overrides.
+pub async fn list_assignments_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<TournamentTableAssignmentRow>> {
+    sqlx::query_as::<_, TournamentTableAssignmentRow>(
+        r#"
+        SELECT id, tournament_id, club_table_id, is_active, assigned_at, deactivated_at, max_seats_override, status, notes, status_updated_by, status_updated_at, created_at, updated_at
+        FROM tournament_table_assignments
+        WHERE tournament_id = $1 AND is_active = true
+        ORDER BY assigned_at ASC
+        "#,
+    )
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Occupied seats a tournament-wide `seats_per_table` would leave off their
+/// table, as (table number, seat number). Tables with their own override are
+/// unaffected.
+pub async fn seats_beyond_cap<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    seats_per_table: i32,
+) -> SqlxResult<Vec<(i32, i32)>> {
+    sqlx::query_as(
+        r#"
+        SELECT ct.table_number, tsa.seat_number
+        FROM table_seat_assignments tsa
+        JOIN tournament_table_assignments tta
+            ON tta.tournament_id = tsa.tournament_id AND tta.club_table_id = tsa.club_table_id
+            AND tta.is_active = true
+        JOIN club_tables ct ON ct.id = tsa.club_table_id
+        WHERE tsa.tournament_id = $1 AND tsa.is_current = true
+          AND tta.max_seats_override IS NULL
+          AND tsa.seat_number > LEAST(ct.max_seats, $2)
+        ORDER BY ct.table_number, tsa.seat_number
+        "#,
+    )
+    .bind(tournament_id)
+    .bind(seats_per_table)
+    .fetch_all(executor)
+    .await
+}
@@ -0,0 +1,55 @@
+//! Recorded color-ups (one per tournament level).
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "id, tournament_id, level_number, denomination, race_rule, chips_before, chips_after, recorded_by, created_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ColorUpRow {
+    pub id: Uuid,
+    pub tournament_id: Uuid,
+    pub level_number: i32,
+    pub denomination: i32,
+    pub race_rule: String,
+    pub chips_before: i64,
+    pub chips_after: i64,
+    pub recorded_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateColorUp {
+    pub tournament_id: Uuid,
+    pub level_number: i32,
+    pub denomination: i32,
+    pub race_rule: String,
+    pub chips_before: i64,
+    pub chips_after: i64,
+    pub recorded_by: Option<Uuid>,
+}
+
+/// Insert a color-up record. Returns `None` if this level was already colored
+/// up for the tournament.
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    data: CreateColorUp,
+) -> SqlxResult<Option<ColorUpRow>> {
+    sqlx::query_as::<_, ColorUpRow>(&format!(
+        "INSERT INTO tournament_color_ups \
+         (tournament_id, level_number, denomination, race_rule, chips_before, chips_after, recorded_by) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7) \
+         ON CONFLICT (tournament_id, level_number) DO NOTHING \
+         RETURNING {COLS}"
+    ))
+    .bind(data.tournament_id)
+    .bind(data.level_number)
+    .bind(data.denomination)
+    .bind(data.race_rule)
+    .bind(data.chips_before)
+    .bind(data.chips_after)
+    .bind(data.recorded_by)
+    .fetch_optional(executor)
+    .await
+}
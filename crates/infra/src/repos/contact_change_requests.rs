@@ -0,0 +1,115 @@
+//! Pending self-service email and phone changes, confirmed with a code sent
+//! to the current address and one sent to the new address.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+use crate::pii::Pii;
+
+const COLS: &str = "id, user_id, channel, new_value, old_code_hash, new_code_hash, attempts, \
+                    expires_at, confirmed_at, cancelled_at, created_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ContactChangeRequestRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    /// `email` or `phone`.
+    pub channel: String,
+    /// Encrypted at rest.
+    pub new_value: Pii,
+    pub old_code_hash: String,
+    pub new_code_hash: String,
+    pub attempts: i32,
+    pub expires_at: DateTime<Utc>,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Cancel the user's live request on `channel`, if any.
+pub async fn cancel_pending<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+    channel: &str,
+) -> SqlxResult<u64> {
+    let result = sqlx::query(
+        "UPDATE contact_change_requests SET cancelled_at = now() \
+         WHERE user_id = $1 AND channel = $2 AND confirmed_at IS NULL AND cancelled_at IS NULL",
+    )
+    .bind(user_id)
+    .bind(channel)
+    .execute(executor)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+    channel: &str,
+    new_value: &str,
+    old_code_hash: &str,
+    new_code_hash: &str,
+    expires_at: DateTime<Utc>,
+) -> SqlxResult<ContactChangeRequestRow> {
+    sqlx::query_as::<_, ContactChangeRequestRow>(&format!(
+        "INSERT INTO contact_change_requests \
+             (user_id, channel, new_value, old_code_hash, new_code_hash, expires_at) \
+         VALUES ($1, $2, $3, $4, $5, $6) RETURNING {COLS}"
+    ))
+    .bind(user_id)
+    .bind(channel)
+    .bind(Pii::from(new_value.to_string()))
+    .bind(old_code_hash)
+    .bind(new_code_hash)
+    .bind(expires_at)
+    .fetch_one(executor)
+    .await
+}
+
+/// Lock one of the user's live requests for confirmation.
+pub async fn get_pending_for_update<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    user_id: Uuid,
+) -> SqlxResult<Option<ContactChangeRequestRow>> {
+    sqlx::query_as::<_, ContactChangeRequestRow>(&format!(
+        "SELECT {COLS} FROM contact_change_requests \
+         WHERE id = $1 AND user_id = $2 AND confirmed_at IS NULL AND cancelled_at IS NULL \
+         FOR UPDATE"
+    ))
+    .bind(id)
+    .bind(user_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Count a wrong code against the request.
+pub async fn record_failed_attempt<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<()> {
+    sqlx::query("UPDATE contact_change_requests SET attempts = attempts + 1 WHERE id = $1")
+        .bind(id)
+        .execute(executor)
+        .await?;
+    Ok(())
+}
+
+pub async fn mark_confirmed<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<()> {
+    sqlx::query("UPDATE contact_change_requests SET confirmed_at = now() WHERE id = $1")
+        .bind(id)
+        .execute(executor)
+        .await?;
+    Ok(())
+}
+
+/// Drop every request of a user, e.g. when their account is anonymized.
+pub async fn delete_all_for_user<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+) -> SqlxResult<u64> {
+    let result = sqlx::query("DELETE FROM contact_change_requests WHERE user_id = $1")
+        .bind(user_id)
+        .execute(executor)
+        .await?;
+    Ok(result.rows_affected())
+}
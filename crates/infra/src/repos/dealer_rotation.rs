@@ -0,0 +1,166 @@
+//! Tournament dealer pools and the generated push schedule.
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+use super::club_staff::ClubStaffRow;
+
+const PUSH_COLS: &str =
+    "id, tournament_id, staff_id, club_table_id, push_number, starts_at, ends_at, created_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DealerPushRow {
+    pub id: Uuid,
+    pub tournament_id: Uuid,
+    pub staff_id: Uuid,
+    /// `None` = the dealer is on break for this push.
+    pub club_table_id: Option<Uuid>,
+    pub push_number: i32,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateDealerPush {
+    pub staff_id: Uuid,
+    pub club_table_id: Option<Uuid>,
+    pub push_number: i32,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+/// The dealer sitting at a table right now.
+#[derive(Debug, Clone, FromRow, Deserialize)]
+pub struct CurrentDealerRow {
+    pub club_table_id: Uuid,
+    pub staff_id: Uuid,
+    pub display_name: String,
+    pub ends_at: DateTime<Utc>,
+}
+
+/// Completed downs per dealer over a period, for payroll.
+#[derive(Debug, Clone, FromRow)]
+pub struct DealerDownCountRow {
+    pub staff_id: Uuid,
+    pub display_name: String,
+    pub downs: i64,
+    pub minutes: i64,
+}
+
+/// Replace a tournament's dealer pool.
+pub async fn set_pool(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    tournament_id: Uuid,
+    staff_ids: &[Uuid],
+) -> SqlxResult<()> {
+    sqlx::query("DELETE FROM tournament_dealers WHERE tournament_id = $1")
+        .bind(tournament_id)
+        .execute(&mut **tx)
+        .await?;
+    sqlx::query(
+        "INSERT INTO tournament_dealers (tournament_id, staff_id) \
+         SELECT $1, UNNEST($2::uuid[])",
+    )
+    .bind(tournament_id)
+    .bind(staff_ids)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// The dealers in a tournament's pool, by name.
+pub async fn list_pool<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<ClubStaffRow>> {
+    sqlx::query_as::<_, ClubStaffRow>(
+        "SELECT s.id, s.club_id, s.display_name, s.user_id, s.role, s.hourly_rate_cents, \
+                s.is_active, s.created_at, s.updated_at \
+         FROM tournament_dealers td \
+         JOIN club_staff s ON s.id = td.staff_id \
+         WHERE td.tournament_id = $1 \
+         ORDER BY s.display_name ASC, s.id ASC",
+    )
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Drop every push that hasn't started by `from`, so a schedule can be
+/// regenerated without rewriting downs already dealt.
+pub async fn delete_pushes_from<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    from: DateTime<Utc>,
+) -> SqlxResult<u64> {
+    let result =
+        sqlx::query("DELETE FROM dealer_pushes WHERE tournament_id = $1 AND starts_at >= $2")
+            .bind(tournament_id)
+            .bind(from)
+            .execute(executor)
+            .await?;
+    Ok(result.rows_affected())
+}
+
+pub async fn create_push<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    data: CreateDealerPush,
+) -> SqlxResult<DealerPushRow> {
+    sqlx::query_as::<_, DealerPushRow>(&format!(
+        "INSERT INTO dealer_pushes \
+         (tournament_id, staff_id, club_table_id, push_number, starts_at, ends_at) \
+         VALUES ($1, $2, $3, $4, $5, $6) RETURNING {PUSH_COLS}"
+    ))
+    .bind(tournament_id)
+    .bind(data.staff_id)
+    .bind(data.club_table_id)
+    .bind(data.push_number)
+    .bind(data.starts_at)
+    .bind(data.ends_at)
+    .fetch_one(executor)
+    .await
+}
+
+/// The full schedule, in push order.
+pub async fn list_pushes<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<DealerPushRow>> {
+    sqlx::query_as::<_, DealerPushRow>(&format!(
+        "SELECT {PUSH_COLS} FROM dealer_pushes WHERE tournament_id = $1 \
+         ORDER BY starts_at ASC, club_table_id ASC NULLS LAST"
+    ))
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Downs completed at a club's tables between `from` and `to`, per dealer.
+/// A down counts once it has ended; breaks are not downs.
+pub async fn down_counts<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> SqlxResult<Vec<DealerDownCountRow>> {
+    sqlx::query_as::<_, DealerDownCountRow>(
+        "SELECT s.id AS staff_id, s.display_name, \
+                COUNT(*) AS downs, \
+                COALESCE(SUM(EXTRACT(EPOCH FROM (dp.ends_at - dp.starts_at)) / 60), 0)::bigint AS minutes \
+         FROM dealer_pushes dp \
+         JOIN club_staff s ON s.id = dp.staff_id \
+         WHERE s.club_id = $1 AND dp.club_table_id IS NOT NULL \
+           AND dp.ends_at > $2 AND dp.ends_at <= LEAST($3, NOW()) \
+         GROUP BY s.id, s.display_name \
+         ORDER BY s.display_name ASC",
+    )
+    .bind(club_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(executor)
+    .await
+}
@@ -0,0 +1,127 @@
+//! Screens paired to a club to show its clock, seating and promotions.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "id, club_id, name, pairing_expires_at, paired_at, last_seen_at, revoked_at, \
+                    created_by, created_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DisplayDeviceRow {
+    pub id: Uuid,
+    pub club_id: Uuid,
+    pub name: String,
+    pub pairing_expires_at: Option<DateTime<Utc>>,
+    pub paired_at: Option<DateTime<Utc>>,
+    pub last_seen_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Register a screen waiting to be paired with the given code.
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    name: &str,
+    pairing_code_hash: &str,
+    pairing_expires_at: DateTime<Utc>,
+    created_by: Uuid,
+) -> SqlxResult<DisplayDeviceRow> {
+    sqlx::query_as::<_, DisplayDeviceRow>(&format!(
+        "INSERT INTO display_devices (club_id, name, pairing_code_hash, pairing_expires_at, created_by) \
+         VALUES ($1, $2, $3, $4, $5) RETURNING {COLS}"
+    ))
+    .bind(club_id)
+    .bind(name)
+    .bind(pairing_code_hash)
+    .bind(pairing_expires_at)
+    .bind(created_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<DisplayDeviceRow>> {
+    sqlx::query_as::<_, DisplayDeviceRow>(&format!(
+        "SELECT {COLS} FROM display_devices WHERE id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Every screen of a club, revoked ones included, newest first.
+pub async fn list_for_club<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+) -> SqlxResult<Vec<DisplayDeviceRow>> {
+    sqlx::query_as::<_, DisplayDeviceRow>(&format!(
+        "SELECT {COLS} FROM display_devices WHERE club_id = $1 ORDER BY created_at DESC"
+    ))
+    .bind(club_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Use up a live pairing code. The code is cleared so it pairs exactly one
+/// screen; `None` when it is unknown, expired or its device was revoked.
+pub async fn pair<'e>(
+    executor: impl PgExecutor<'e>,
+    pairing_code_hash: &str,
+) -> SqlxResult<Option<DisplayDeviceRow>> {
+    sqlx::query_as::<_, DisplayDeviceRow>(&format!(
+        "UPDATE display_devices \
+         SET pairing_code_hash = NULL, pairing_expires_at = NULL, \
+             paired_at = now(), last_seen_at = now() \
+         WHERE pairing_code_hash = $1 AND pairing_expires_at > now() AND revoked_at IS NULL \
+         RETURNING {COLS}"
+    ))
+    .bind(pairing_code_hash)
+    .fetch_optional(executor)
+    .await
+}
+
+/// The club of a paired, unrevoked screen, if it still is one.
+pub async fn active_club_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<Uuid>> {
+    sqlx::query_scalar::<_, Uuid>(
+        "SELECT club_id FROM display_devices \
+         WHERE id = $1 AND paired_at IS NOT NULL AND revoked_at IS NULL",
+    )
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Record that a paired screen checked in. `None` once it has been revoked.
+pub async fn touch<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<Option<Uuid>> {
+    sqlx::query_scalar::<_, Uuid>(
+        "UPDATE display_devices SET last_seen_at = now() \
+         WHERE id = $1 AND paired_at IS NOT NULL AND revoked_at IS NULL \
+         RETURNING club_id",
+    )
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Revoke a screen, along with any pairing code it hasn't used yet.
+/// Returns false when it was already revoked.
+pub async fn revoke<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<bool> {
+    let result = sqlx::query(
+        "UPDATE display_devices \
+         SET revoked_at = now(), pairing_code_hash = NULL, pairing_expires_at = NULL \
+         WHERE id = $1 AND revoked_at IS NULL",
+    )
+    .bind(id)
+    .execute(executor)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,113 @@
+//! Buy-in receipts: a snapshot of each entry taken when it's added, printed on
+//! the desk's thermal printer and verified by scanning its code.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "id, entry_id, tournament_id, club_id, verification_code, club_name, \
+     tournament_name, player_name, entry_type, payment_method, amount_cents, voucher_cents, \
+     chips_received, issued_by, issued_at, print_count, last_printed_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ReceiptRow {
+    pub id: Uuid,
+    /// `None` once the entry was deleted: the receipt is void.
+    pub entry_id: Option<Uuid>,
+    pub tournament_id: Uuid,
+    pub club_id: Uuid,
+    pub verification_code: String,
+    pub club_name: String,
+    pub tournament_name: String,
+    pub player_name: String,
+    pub entry_type: String,
+    pub payment_method: String,
+    pub amount_cents: i64,
+    /// Drink voucher collected together with an initial buy-in.
+    pub voucher_cents: i64,
+    pub chips_received: Option<i32>,
+    pub issued_by: Option<Uuid>,
+    pub issued_at: DateTime<Utc>,
+    pub print_count: i32,
+    pub last_printed_at: Option<DateTime<Utc>>,
+}
+
+/// Issue the receipt for an entry, snapshotting the player, tournament and
+/// club names from their current rows.
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    entry_id: Uuid,
+    verification_code: &str,
+    voucher_cents: i64,
+    issued_by: Option<Uuid>,
+) -> SqlxResult<ReceiptRow> {
+    sqlx::query_as::<_, ReceiptRow>(&format!(
+        "INSERT INTO entry_receipts \
+             (entry_id, tournament_id, club_id, verification_code, club_name, tournament_name, \
+              player_name, entry_type, payment_method, amount_cents, voucher_cents, \
+              chips_received, issued_by) \
+         SELECT e.id, e.tournament_id, t.club_id, $2, c.name, t.name, cp.display_name, \
+                e.entry_type, e.payment_method, e.amount_cents, $3, e.chips_received, $4 \
+         FROM tournament_entries e \
+         JOIN tournaments t ON t.id = e.tournament_id \
+         JOIN clubs c ON c.id = t.club_id \
+         JOIN club_player cp ON cp.id = e.club_player_id \
+         WHERE e.id = $1 \
+         RETURNING {COLS}"
+    ))
+    .bind(entry_id)
+    .bind(verification_code)
+    .bind(voucher_cents)
+    .bind(issued_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<ReceiptRow>> {
+    sqlx::query_as::<_, ReceiptRow>(&format!("SELECT {COLS} FROM entry_receipts WHERE id = $1"))
+        .bind(id)
+        .fetch_optional(executor)
+        .await
+}
+
+pub async fn get_by_entry<'e>(
+    executor: impl PgExecutor<'e>,
+    entry_id: Uuid,
+) -> SqlxResult<Option<ReceiptRow>> {
+    sqlx::query_as::<_, ReceiptRow>(&format!(
+        "SELECT {COLS} FROM entry_receipts WHERE entry_id = $1"
+    ))
+    .bind(entry_id)
+    .fetch_optional(executor)
+    .await
+}
+
+pub async fn get_by_code<'e>(
+    executor: impl PgExecutor<'e>,
+    verification_code: &str,
+) -> SqlxResult<Option<ReceiptRow>> {
+    sqlx::query_as::<_, ReceiptRow>(&format!(
+        "SELECT {COLS} FROM entry_receipts WHERE verification_code = $1"
+    ))
+    .bind(verification_code)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Count a print and return the updated receipt; `print_count > 1` means the
+/// printer is producing a copy.
+pub async fn mark_printed<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<ReceiptRow>> {
+    sqlx::query_as::<_, ReceiptRow>(&format!(
+        "UPDATE entry_receipts SET print_count = print_count + 1, last_printed_at = NOW() \
+         WHERE id = $1 RETURNING {COLS}"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
@@ -0,0 +1,185 @@
+//! QR entry tickets: one live ticket per seat-granting entry, scanned at
+//! seating to catch duplicates and forgeries.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+/// Ticket columns with the names shown to the scanner; `k` aliases
+/// `entry_tickets`.
+const COLS: &str = "k.id, k.entry_id, k.tournament_id, t.name AS tournament_name, k.club_id, \
+     k.club_player_id, cp.display_name AS player_name, k.status, k.scan_count, k.issued_by, \
+     k.issued_at, k.verified_by, k.verified_at, k.voided_by, k.voided_at, k.void_reason";
+const JOINS: &str = "JOIN tournaments t ON t.id = k.tournament_id \
+     JOIN club_player cp ON cp.id = k.club_player_id";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct TicketRow {
+    pub id: Uuid,
+    pub entry_id: Option<Uuid>,
+    pub tournament_id: Uuid,
+    pub tournament_name: String,
+    pub club_id: Uuid,
+    pub club_player_id: Uuid,
+    pub player_name: String,
+    /// `issued` | `verified` | `voided`.
+    pub status: String,
+    /// Scans after the first (duplicates).
+    pub scan_count: i32,
+    pub issued_by: Option<Uuid>,
+    pub issued_at: DateTime<Utc>,
+    pub verified_by: Option<Uuid>,
+    pub verified_at: Option<DateTime<Utc>>,
+    pub voided_by: Option<Uuid>,
+    pub voided_at: Option<DateTime<Utc>>,
+    pub void_reason: Option<String>,
+}
+
+/// Issue a ticket for an entry.
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    entry_id: Uuid,
+    issued_by: Option<Uuid>,
+) -> SqlxResult<TicketRow> {
+    sqlx::query_as::<_, TicketRow>(&format!(
+        "WITH inserted AS ( \
+             INSERT INTO entry_tickets \
+                 (entry_id, tournament_id, club_id, club_player_id, issued_by) \
+             SELECT e.id, e.tournament_id, tr.club_id, e.club_player_id, $2 \
+             FROM tournament_entries e JOIN tournaments tr ON tr.id = e.tournament_id \
+             WHERE e.id = $1 \
+             RETURNING *) \
+         SELECT {COLS} FROM inserted k {JOINS}"
+    ))
+    .bind(entry_id)
+    .bind(issued_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<TicketRow>> {
+    sqlx::query_as::<_, TicketRow>(&format!(
+        "SELECT {COLS} FROM entry_tickets k {JOINS} WHERE k.id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Lock a ticket so two scanners can't both see it unverified.
+pub async fn lock<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<Option<TicketRow>> {
+    sqlx::query_as::<_, TicketRow>(&format!(
+        "SELECT {COLS} FROM entry_tickets k {JOINS} WHERE k.id = $1 FOR UPDATE OF k"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// The entry's live ticket, or its latest voided one when none is live.
+pub async fn get_for_entry<'e>(
+    executor: impl PgExecutor<'e>,
+    entry_id: Uuid,
+) -> SqlxResult<Option<TicketRow>> {
+    sqlx::query_as::<_, TicketRow>(&format!(
+        "SELECT {COLS} FROM entry_tickets k {JOINS} WHERE k.entry_id = $1 \
+         ORDER BY k.status = 'voided', k.issued_at DESC LIMIT 1"
+    ))
+    .bind(entry_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A player's live tickets for a tournament, oldest first.
+pub async fn list_live_for_user<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    user_id: Uuid,
+) -> SqlxResult<Vec<TicketRow>> {
+    sqlx::query_as::<_, TicketRow>(&format!(
+        "SELECT {COLS} FROM entry_tickets k {JOINS} \
+         WHERE k.tournament_id = $1 AND cp.app_user_id = $2 AND k.status <> 'voided' \
+         ORDER BY k.issued_at"
+    ))
+    .bind(tournament_id)
+    .bind(user_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// First scan: `issued` becomes `verified`.
+pub async fn mark_verified<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    verified_by: Uuid,
+) -> SqlxResult<TicketRow> {
+    sqlx::query_as::<_, TicketRow>(&format!(
+        "WITH updated AS ( \
+             UPDATE entry_tickets SET status = 'verified', verified_at = NOW(), verified_by = $2 \
+             WHERE id = $1 RETURNING *) \
+         SELECT {COLS} FROM updated k {JOINS}"
+    ))
+    .bind(id)
+    .bind(verified_by)
+    .fetch_one(executor)
+    .await
+}
+
+/// Count a scan of a ticket that was already verified.
+pub async fn record_duplicate_scan<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<TicketRow> {
+    sqlx::query_as::<_, TicketRow>(&format!(
+        "WITH updated AS ( \
+             UPDATE entry_tickets SET scan_count = scan_count + 1 WHERE id = $1 RETURNING *) \
+         SELECT {COLS} FROM updated k {JOINS}"
+    ))
+    .bind(id)
+    .fetch_one(executor)
+    .await
+}
+
+/// Void a live ticket. `None` when it was already void.
+pub async fn void<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    voided_by: Uuid,
+    reason: &str,
+) -> SqlxResult<Option<TicketRow>> {
+    sqlx::query_as::<_, TicketRow>(&format!(
+        "WITH updated AS ( \
+             UPDATE entry_tickets \
+             SET status = 'voided', voided_at = NOW(), voided_by = $2, void_reason = $3 \
+             WHERE id = $1 AND status <> 'voided' RETURNING *) \
+         SELECT {COLS} FROM updated k {JOINS}"
+    ))
+    .bind(id)
+    .bind(voided_by)
+    .bind(reason)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Void an entry's live ticket, e.g. before the entry is deleted.
+pub async fn void_for_entry<'e>(
+    executor: impl PgExecutor<'e>,
+    entry_id: Uuid,
+    voided_by: Option<Uuid>,
+    reason: &str,
+) -> SqlxResult<u64> {
+    let result = sqlx::query(
+        "UPDATE entry_tickets \
+         SET status = 'voided', voided_at = NOW(), voided_by = $2, void_reason = $3 \
+         WHERE entry_id = $1 AND status <> 'voided'",
+    )
+    .bind(entry_id)
+    .bind(voided_by)
+    .bind(reason)
+    .execute(executor)
+    .await?;
+    Ok(result.rows_affected())
+}
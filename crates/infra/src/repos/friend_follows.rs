@@ -0,0 +1,148 @@
+//! Following friends' tournaments: the follow list, who to notify, and the
+//! friends activity feed. Every query requires an accepted friendship between
+//! the two users, so unfriending stops the follow without touching this table.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+/// `ff` is the follow row being checked.
+const ACCEPTED_FRIENDS: &str = "EXISTS (SELECT 1 FROM friendship f \
+     WHERE f.status = 'accepted' \
+       AND LEAST(f.requester_id, f.addressee_id) = LEAST(ff.follower_id, ff.followee_id) \
+       AND GREATEST(f.requester_id, f.addressee_id) = GREATEST(ff.follower_id, ff.followee_id))";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct FollowedFriendRow {
+    pub user_id: Uuid,
+    pub name: String,
+    pub notify: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// One item of the friends activity feed.
+#[derive(Debug, Clone, FromRow)]
+pub struct FriendActivityRow {
+    /// "won" | "cashed" | "final_table" | "busted"
+    pub kind: String,
+    pub user_id: Uuid,
+    pub name: String,
+    pub tournament_id: Uuid,
+    pub tournament_name: String,
+    pub club_name: String,
+    pub final_position: Option<i32>,
+    pub prize_cents: Option<i64>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// Follow a friend, or change the notification choice of an existing follow.
+pub async fn upsert<'e>(
+    executor: impl PgExecutor<'e>,
+    follower_id: Uuid,
+    followee_id: Uuid,
+    notify: bool,
+) -> SqlxResult<()> {
+    sqlx::query(
+        "INSERT INTO friend_follow (follower_id, followee_id, notify) VALUES ($1, $2, $3) \
+         ON CONFLICT (follower_id, followee_id) DO UPDATE SET notify = EXCLUDED.notify",
+    )
+    .bind(follower_id)
+    .bind(followee_id)
+    .bind(notify)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+pub async fn delete<'e>(
+    executor: impl PgExecutor<'e>,
+    follower_id: Uuid,
+    followee_id: Uuid,
+) -> SqlxResult<bool> {
+    let res = sqlx::query("DELETE FROM friend_follow WHERE follower_id = $1 AND followee_id = $2")
+        .bind(follower_id)
+        .bind(followee_id)
+        .execute(executor)
+        .await?;
+    Ok(res.rows_affected() > 0)
+}
+
+/// The friends `follower_id` follows, by name.
+pub async fn list_followed<'e>(
+    executor: impl PgExecutor<'e>,
+    follower_id: Uuid,
+) -> SqlxResult<Vec<FollowedFriendRow>> {
+    sqlx::query_as::<_, FollowedFriendRow>(&format!(
+        "SELECT u.id AS user_id, COALESCE(u.username, u.first_name) AS name, \
+                ff.notify, ff.created_at \
+         FROM friend_follow ff JOIN users_public u ON u.id = ff.followee_id \
+         WHERE ff.follower_id = $1 AND {ACCEPTED_FRIENDS} \
+         ORDER BY name ASC"
+    ))
+    .bind(follower_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Followers of `followee_id` who opted into rail notifications.
+pub async fn list_notified_followers<'e>(
+    executor: impl PgExecutor<'e>,
+    followee_id: Uuid,
+) -> SqlxResult<Vec<Uuid>> {
+    sqlx::query_scalar(&format!(
+        "SELECT ff.follower_id FROM friend_follow ff \
+         WHERE ff.followee_id = $1 AND ff.notify AND {ACCEPTED_FRIENDS}"
+    ))
+    .bind(followee_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Recent activity of the friends `follower_id` follows, newest first:
+/// wins and cashes from results, final tables and busts from the activity
+/// log. Tournaments of free-plan clubs stay off the player app.
+pub async fn activity_feed<'e>(
+    executor: impl PgExecutor<'e>,
+    follower_id: Uuid,
+    limit: i64,
+) -> SqlxResult<Vec<FriendActivityRow>> {
+    sqlx::query_as::<_, FriendActivityRow>(&format!(
+        "WITH followed AS ( \
+             SELECT ff.followee_id AS user_id FROM friend_follow ff \
+             WHERE ff.follower_id = $1 AND {ACCEPTED_FRIENDS} \
+         ), \
+         events AS ( \
+             SELECT CASE WHEN tr.final_position = 1 THEN 'won' ELSE 'cashed' END AS kind, \
+                    cp.app_user_id AS user_id, tr.tournament_id, \
+                    tr.final_position, tr.prize_cents, tr.created_at AS occurred_at \
+             FROM tournament_results tr \
+             JOIN club_player_public cp ON cp.id = tr.club_player_id \
+             WHERE cp.app_user_id IN (SELECT user_id FROM followed) \
+               AND (tr.final_position = 1 OR tr.prize_cents > 0) \
+             UNION ALL \
+             SELECT 'busted', al.subject_id, al.tournament_id, NULL, NULL, al.event_time \
+             FROM tournament_activity_log al \
+             WHERE al.event_category = 'seating' AND al.event_action = 'player_eliminated' \
+               AND al.subject_id IN (SELECT user_id FROM followed) \
+             UNION ALL \
+             SELECT 'final_table', fu.user_id, al.tournament_id, NULL, NULL, al.event_time \
+             FROM tournament_activity_log al \
+             JOIN followed fu ON al.metadata -> 'user_ids' ? fu.user_id::text \
+             WHERE al.event_category = 'tournament' AND al.event_action = 'final_table_reached' \
+         ) \
+         SELECT e.kind, e.user_id, COALESCE(u.username, u.first_name) AS name, \
+                e.tournament_id, t.name AS tournament_name, c.name AS club_name, \
+                e.final_position, e.prize_cents, e.occurred_at \
+         FROM events e \
+         JOIN users_public u ON u.id = e.user_id \
+         JOIN tournaments t ON t.id = e.tournament_id \
+         JOIN clubs c ON c.id = t.club_id \
+         WHERE c.plan <> 'free' \
+         ORDER BY e.occurred_at DESC \
+         LIMIT $2"
+    ))
+    .bind(follower_id)
+    .bind(limit)
+    .fetch_all(executor)
+    .await
+}
@@ -149,7 +149,7 @@ pub async fn list_friends<'e>(
                      THEN f.requester_allows_addressee_reg \
                      ELSE f.addressee_allows_requester_reg END AS can_register_me \
          FROM friendship f \
-         JOIN users other ON other.id = \
+         JOIN users_public other ON other.id = \
               CASE WHEN f.requester_id = $1 THEN f.addressee_id ELSE f.requester_id END \
          WHERE (f.requester_id = $1 OR f.addressee_id = $1) AND f.status = 'accepted' \
          ORDER BY name ASC",
@@ -172,7 +172,7 @@ pub async fn list_incoming<'e>(
                 TRUE AS is_incoming, \
                 FALSE AS i_can_register_them, \
                 FALSE AS can_register_me \
-         FROM friendship f JOIN users u ON u.id = f.requester_id \
+         FROM friendship f JOIN users_public u ON u.id = f.requester_id \
          WHERE f.addressee_id = $1 AND f.status = 'pending' \
          ORDER BY f.created_at DESC",
     )
@@ -194,7 +194,7 @@ pub async fn list_outgoing<'e>(
                 FALSE AS is_incoming, \
                 FALSE AS i_can_register_them, \
                 FALSE AS can_register_me \
-         FROM friendship f JOIN users u ON u.id = f.addressee_id \
+         FROM friendship f JOIN users_public u ON u.id = f.addressee_id \
          WHERE f.requester_id = $1 AND f.status = 'pending' \
          ORDER BY f.created_at DESC",
     )
@@ -0,0 +1,234 @@
+//! Incident reports filed by floor staff, their involved players and
+//! attachments, and the review that closes them.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+use crate::models::ClubPlayerRow;
+
+const COLS: &str = "id, club_id, tournament_id, club_table_id, category, severity, description, status, follow_up_notes, reported_by, review_outcome, review_notes, reviewed_by, reviewed_at, created_at, updated_at";
+
+const ATTACHMENT_COLS: &str = "id, incident_id, url, content_type, uploaded_by, created_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct IncidentRow {
+    pub id: Uuid,
+    pub club_id: Uuid,
+    pub tournament_id: Option<Uuid>,
+    pub club_table_id: Option<Uuid>,
+    /// ruling_dispute | conduct | cheating | other
+    pub category: String,
+    /// low | medium | high | critical
+    pub severity: String,
+    pub description: String,
+    /// open | under_review | resolved | dismissed
+    pub status: String,
+    pub follow_up_notes: Option<String>,
+    pub reported_by: Option<Uuid>,
+    /// no_action | warning | penalty | exclusion
+    pub review_outcome: Option<String>,
+    pub review_notes: Option<String>,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct IncidentAttachmentRow {
+    pub id: Uuid,
+    pub incident_id: Uuid,
+    pub url: String,
+    pub content_type: Option<String>,
+    pub uploaded_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateIncident {
+    pub club_id: Uuid,
+    pub tournament_id: Option<Uuid>,
+    pub club_table_id: Option<Uuid>,
+    pub category: String,
+    pub severity: String,
+    pub description: String,
+    pub reported_by: Uuid,
+}
+
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    data: CreateIncident,
+) -> SqlxResult<IncidentRow> {
+    sqlx::query_as::<_, IncidentRow>(&format!(
+        "INSERT INTO incidents \
+         (club_id, tournament_id, club_table_id, category, severity, description, reported_by) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING {COLS}"
+    ))
+    .bind(data.club_id)
+    .bind(data.tournament_id)
+    .bind(data.club_table_id)
+    .bind(data.category)
+    .bind(data.severity)
+    .bind(data.description)
+    .bind(data.reported_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<IncidentRow>> {
+    sqlx::query_as::<_, IncidentRow>(&format!("SELECT {COLS} FROM incidents WHERE id = $1"))
+        .bind(id)
+        .fetch_optional(executor)
+        .await
+}
+
+/// A club's incidents, newest first, optionally narrowed to one tournament.
+pub async fn list_by_club<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    tournament_id: Option<Uuid>,
+) -> SqlxResult<Vec<IncidentRow>> {
+    sqlx::query_as::<_, IncidentRow>(&format!(
+        "SELECT {COLS} FROM incidents \
+         WHERE club_id = $1 AND ($2::uuid IS NULL OR tournament_id = $2) \
+         ORDER BY created_at DESC"
+    ))
+    .bind(club_id)
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Unreviewed incidents, most severe first, then oldest first.
+pub async fn review_queue<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+) -> SqlxResult<Vec<IncidentRow>> {
+    sqlx::query_as::<_, IncidentRow>(&format!(
+        "SELECT {COLS} FROM incidents \
+         WHERE club_id = $1 AND status IN ('open', 'under_review') \
+         ORDER BY CASE severity \
+                      WHEN 'critical' THEN 0 WHEN 'high' THEN 1 \
+                      WHEN 'medium' THEN 2 ELSE 3 END, \
+                  created_at ASC"
+    ))
+    .bind(club_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Update the follow-up status/notes of an unreviewed incident. Returns `None`
+/// once the incident has been reviewed.
+pub async fn update_follow_up<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    status: &str,
+    follow_up_notes: Option<&str>,
+) -> SqlxResult<Option<IncidentRow>> {
+    sqlx::query_as::<_, IncidentRow>(&format!(
+        "UPDATE incidents \
+         SET status = $2, follow_up_notes = COALESCE($3, follow_up_notes) \
+         WHERE id = $1 AND status IN ('open', 'under_review') \
+         RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(status)
+    .bind(follow_up_notes)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Close an incident with a review outcome. Returns `None` if it was already
+/// reviewed.
+pub async fn review<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    status: &str,
+    outcome: &str,
+    notes: Option<&str>,
+    reviewed_by: Uuid,
+) -> SqlxResult<Option<IncidentRow>> {
+    sqlx::query_as::<_, IncidentRow>(&format!(
+        "UPDATE incidents \
+         SET status = $2, review_outcome = $3, review_notes = $4, \
+             reviewed_by = $5, reviewed_at = NOW() \
+         WHERE id = $1 AND status IN ('open', 'under_review') \
+         RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(status)
+    .bind(outcome)
+    .bind(notes)
+    .bind(reviewed_by)
+    .fetch_optional(executor)
+    .await
+}
+
+pub async fn add_players<'e>(
+    executor: impl PgExecutor<'e>,
+    incident_id: Uuid,
+    club_player_ids: &[Uuid],
+) -> SqlxResult<()> {
+    sqlx::query(
+        "INSERT INTO incident_players (incident_id, club_player_id) \
+         SELECT $1, UNNEST($2::uuid[]) ON CONFLICT DO NOTHING",
+    )
+    .bind(incident_id)
+    .bind(club_player_ids)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+pub async fn list_players<'e>(
+    executor: impl PgExecutor<'e>,
+    incident_id: Uuid,
+) -> SqlxResult<Vec<ClubPlayerRow>> {
+    sqlx::query_as::<_, ClubPlayerRow>(
+        "SELECT cp.id, cp.club_id, cp.display_name, cp.first_name, cp.last_name, \
+                cp.app_user_id, cp.is_active, cp.created_at, cp.updated_at \
+         FROM incident_players ip \
+         JOIN club_player cp ON cp.id = ip.club_player_id \
+         WHERE ip.incident_id = $1 \
+         ORDER BY cp.display_name ASC",
+    )
+    .bind(incident_id)
+    .fetch_all(executor)
+    .await
+}
+
+pub async fn add_attachment<'e>(
+    executor: impl PgExecutor<'e>,
+    incident_id: Uuid,
+    url: &str,
+    content_type: Option<&str>,
+    uploaded_by: Uuid,
+) -> SqlxResult<IncidentAttachmentRow> {
+    sqlx::query_as::<_, IncidentAttachmentRow>(&format!(
+        "INSERT INTO incident_attachments (incident_id, url, content_type, uploaded_by) \
+         VALUES ($1, $2, $3, $4) RETURNING {ATTACHMENT_COLS}"
+    ))
+    .bind(incident_id)
+    .bind(url)
+    .bind(content_type)
+    .bind(uploaded_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn list_attachments<'e>(
+    executor: impl PgExecutor<'e>,
+    incident_id: Uuid,
+) -> SqlxResult<Vec<IncidentAttachmentRow>> {
+    sqlx::query_as::<_, IncidentAttachmentRow>(&format!(
+        "SELECT {ATTACHMENT_COLS} FROM incident_attachments \
+         WHERE incident_id = $1 ORDER BY created_at ASC"
+    ))
+    .bind(incident_id)
+    .fetch_all(executor)
+    .await
+}
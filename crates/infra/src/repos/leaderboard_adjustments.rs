@@ -6,7 +6,8 @@ use chrono::{DateTime, Utc};
 use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
 use uuid::Uuid;
 
-const COLS: &str = "id, config_id, club_player_id, points_delta, reason, created_by, created_at";
+const COLS: &str =
+    "id, config_id, club_player_id, points_delta, reason_code, reason, created_by, created_at";
 
 #[derive(Debug, Clone, FromRow)]
 pub struct LeaderboardAdjustmentRow {
@@ -14,6 +15,9 @@ pub struct LeaderboardAdjustmentRow {
     pub config_id: Uuid,
     pub club_player_id: Uuid,
     pub points_delta: i32,
+    /// `penalty` | `correction` | `bonus` | `other`.
+    pub reason_code: String,
+    /// Free-text note.
     pub reason: String,
     pub created_by: Option<Uuid>,
     pub created_at: DateTime<Utc>,
@@ -24,17 +28,19 @@ pub async fn create<'e>(
     config_id: Uuid,
     club_player_id: Uuid,
     points_delta: i32,
+    reason_code: &str,
     reason: &str,
     created_by: Option<Uuid>,
 ) -> SqlxResult<LeaderboardAdjustmentRow> {
     sqlx::query_as::<_, LeaderboardAdjustmentRow>(&format!(
         "INSERT INTO leaderboard_adjustments \
-         (config_id, club_player_id, points_delta, reason, created_by) \
-         VALUES ($1, $2, $3, $4, $5) RETURNING {COLS}"
+         (config_id, club_player_id, points_delta, reason_code, reason, created_by) \
+         VALUES ($1, $2, $3, $4, $5, $6) RETURNING {COLS}"
     ))
     .bind(config_id)
     .bind(club_player_id)
     .bind(points_delta)
+    .bind(reason_code)
     .bind(reason)
     .bind(created_by)
     .fetch_one(executor)
@@ -9,7 +9,7 @@ use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
 use uuid::Uuid;
 
 const COLS: &str = "id, club_id, name, formula_params, membership_mode, \
-                    period_start, period_end, is_default, created_at, updated_at";
+                    period_start, period_end, is_default, tie_breaks, created_at, updated_at";
 
 #[derive(Debug, Clone, FromRow)]
 pub struct LeaderboardConfigRow {
@@ -21,6 +21,8 @@ pub struct LeaderboardConfigRow {
     pub period_start: Option<DateTime<Utc>>,
     pub period_end: Option<DateTime<Utc>>,
     pub is_default: bool,
+    /// Tie-break rules in the order they apply (see `tournament_results::TieBreak`).
+    pub tie_breaks: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -33,6 +35,7 @@ pub struct CreateLeaderboardConfigData {
     pub membership_mode: String,
     pub period_start: Option<DateTime<Utc>>,
     pub period_end: Option<DateTime<Utc>>,
+    pub tie_breaks: Vec<String>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -42,6 +45,7 @@ pub struct UpdateLeaderboardConfigData {
     pub membership_mode: Option<String>,
     pub period_start: Option<DateTime<Utc>>,
     pub period_end: Option<DateTime<Utc>>,
+    pub tie_breaks: Option<Vec<String>>,
 }
 
 pub async fn create<'e>(
@@ -50,8 +54,8 @@ pub async fn create<'e>(
 ) -> SqlxResult<LeaderboardConfigRow> {
     sqlx::query_as::<_, LeaderboardConfigRow>(&format!(
         "INSERT INTO leaderboard_configs \
-         (club_id, name, formula_params, membership_mode, period_start, period_end) \
-         VALUES ($1, $2, $3, COALESCE($4, 'all_in_period'), $5, $6) \
+         (club_id, name, formula_params, membership_mode, period_start, period_end, tie_breaks) \
+         VALUES ($1, $2, $3, COALESCE($4, 'all_in_period'), $5, $6, $7) \
          RETURNING {COLS}"
     ))
     .bind(data.club_id)
@@ -60,6 +64,7 @@ pub async fn create<'e>(
     .bind(data.membership_mode)
     .bind(data.period_start)
     .bind(data.period_end)
+    .bind(data.tie_breaks)
     .fetch_one(executor)
     .await
 }
@@ -101,6 +106,7 @@ pub async fn update<'e>(
             membership_mode = COALESCE($4, membership_mode), \
             period_start = COALESCE($5, period_start), \
             period_end = COALESCE($6, period_end), \
+            tie_breaks = COALESCE($7, tie_breaks), \
             updated_at = NOW() \
          WHERE id = $1 \
          RETURNING {COLS}"
@@ -111,6 +117,7 @@ pub async fn update<'e>(
     .bind(data.membership_mode)
     .bind(data.period_start)
     .bind(data.period_end)
+    .bind(data.tie_breaks)
     .fetch_optional(executor)
     .await
 }
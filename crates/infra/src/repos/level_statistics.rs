@@ -0,0 +1,148 @@
+//! Per-level activity of tournaments: entries, rebuys and busts by the level
+//! they happened at, and the chip counts recorded during each level.
+//!
+//! Levels are stamped by trigger from the tournament clock; activity without
+//! a level (before the clock started, or recorded before levels were
+//! tracked) comes back with `level_number = NULL`.
+
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+/// What happened during one level of one tournament.
+#[derive(Debug, Clone, Default, FromRow)]
+pub struct LevelActivityRow {
+    pub tournament_id: Uuid,
+    pub level_number: Option<i32>,
+    /// Buy-ins and re-entries: players joining the field.
+    pub entries: i64,
+    pub re_entries: i64,
+    pub rebuys: i64,
+    pub addons: i64,
+    /// Chips handed out with those entries.
+    pub chips: i64,
+    pub eliminations: i64,
+}
+
+/// Average of each player's last chip count in a level.
+#[derive(Debug, Clone, FromRow)]
+pub struct MeasuredStackRow {
+    pub level_number: i32,
+    pub players: i64,
+    pub average_stack: f64,
+}
+
+/// How many players rebought how many times.
+#[derive(Debug, Clone, FromRow)]
+pub struct RebuyBucketRow {
+    pub rebuys: i64,
+    pub players: i64,
+}
+
+const ACTIVITY: &str = r#"
+    SELECT tournament_id, level_number,
+           SUM(entries)::bigint AS entries, SUM(re_entries)::bigint AS re_entries,
+           SUM(rebuys)::bigint AS rebuys, SUM(addons)::bigint AS addons,
+           SUM(chips)::bigint AS chips, SUM(eliminations)::bigint AS eliminations
+    FROM (
+        SELECT e.tournament_id, e.level_number,
+               COUNT(*) FILTER (WHERE e.entry_type IN ('initial', 're_entry')) AS entries,
+               COUNT(*) FILTER (WHERE e.entry_type = 're_entry') AS re_entries,
+               COUNT(*) FILTER (WHERE e.entry_type = 'rebuy') AS rebuys,
+               COUNT(*) FILTER (WHERE e.entry_type = 'addon') AS addons,
+               COALESCE(SUM(e.chips_received), 0) AS chips,
+               0 AS eliminations
+        FROM tournament_entries e
+        WHERE e.tournament_id = ANY($1)
+        GROUP BY e.tournament_id, e.level_number
+        UNION ALL
+        SELECT r.tournament_id, r.eliminated_level, 0, 0, 0, 0, 0, COUNT(*)
+        FROM tournament_registrations r
+        WHERE r.tournament_id = ANY($1) AND r.status = 'busted'
+        GROUP BY r.tournament_id, r.eliminated_level
+    ) a
+    GROUP BY tournament_id, level_number
+    ORDER BY tournament_id, level_number NULLS FIRST
+"#;
+
+/// Activity per level of the given tournaments.
+pub async fn activity<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_ids: &[Uuid],
+) -> SqlxResult<Vec<LevelActivityRow>> {
+    sqlx::query_as::<_, LevelActivityRow>(ACTIVITY)
+        .bind(tournament_ids)
+        .fetch_all(executor)
+        .await
+}
+
+/// Average recorded stack per level, from each player's last count in it.
+/// Busted players' zero stacks are left out.
+pub async fn measured_stacks<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<MeasuredStackRow>> {
+    sqlx::query_as::<_, MeasuredStackRow>(
+        r#"
+        SELECT level_number, COUNT(*) AS players, AVG(stack_size)::FLOAT8 AS average_stack
+        FROM (
+            SELECT DISTINCT ON (level_number, club_player_id) level_number, stack_size
+            FROM tournament_stack_history
+            WHERE tournament_id = $1 AND level_number IS NOT NULL
+            ORDER BY level_number, club_player_id, created_at DESC, id DESC
+        ) last_counts
+        WHERE stack_size > 0
+        GROUP BY level_number
+        ORDER BY level_number
+        "#,
+    )
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Players by number of rebuys, starting at zero.
+pub async fn rebuy_distribution<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<RebuyBucketRow>> {
+    sqlx::query_as::<_, RebuyBucketRow>(
+        r#"
+        SELECT rebuys, COUNT(*) AS players
+        FROM (
+            SELECT club_player_id, COUNT(*) FILTER (WHERE entry_type = 'rebuy') AS rebuys
+            FROM tournament_entries
+            WHERE tournament_id = $1
+            GROUP BY club_player_id
+        ) per_player
+        GROUP BY rebuys
+        ORDER BY rebuys
+        "#,
+    )
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// The club's most recent finished tournaments with level data, newest first.
+pub async fn recent_tracked_tournaments<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    limit: i64,
+) -> SqlxResult<Vec<Uuid>> {
+    sqlx::query_scalar(
+        r#"
+        SELECT t.id FROM tournaments t
+        WHERE t.club_id = $1 AND t.live_status = 'finished'
+          AND (EXISTS (SELECT 1 FROM tournament_entries e
+                       WHERE e.tournament_id = t.id AND e.level_number IS NOT NULL)
+               OR EXISTS (SELECT 1 FROM tournament_registrations r
+                          WHERE r.tournament_id = t.id AND r.eliminated_level IS NOT NULL))
+        ORDER BY t.start_time DESC
+        LIMIT $2
+        "#,
+    )
+    .bind(club_id)
+    .bind(limit)
+    .fetch_all(executor)
+    .await
+}
@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+pub async fn create(
+    pool: &PgPool,
+    token_hash: &str,
+    user_id: Uuid,
+    expires_at: DateTime<Utc>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO login_link_tokens (token_hash, user_id, expires_at) VALUES ($1, $2, $3)",
+    )
+    .bind(token_hash)
+    .bind(user_id)
+    .bind(expires_at)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Mark a live link used and return its user. `None` when the link is
+/// unknown, expired or already used — a link logs in exactly once, even when
+/// redeemed twice concurrently.
+pub async fn consume(pool: &PgPool, token_hash: &str) -> Result<Option<Uuid>, sqlx::Error> {
+    sqlx::query_scalar(
+        "UPDATE login_link_tokens SET used_at = now() \
+         WHERE token_hash = $1 AND used_at IS NULL AND expires_at > now() \
+         RETURNING user_id",
+    )
+    .bind(token_hash)
+    .fetch_optional(pool)
+    .await
+}
+
+/// Links created for a user since the given instant, used or not — the basis
+/// for the cap on login-link emails.
+pub async fn created_since_count(
+    pool: &PgPool,
+    user_id: Uuid,
+    since: DateTime<Utc>,
+) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar(
+        "SELECT COUNT(*) FROM login_link_tokens WHERE user_id = $1 AND created_at >= $2",
+    )
+    .bind(user_id)
+    .bind(since)
+    .fetch_one(pool)
+    .await
+}
+
+/// Retire a user's unused links so only the newest one works.
+pub async fn invalidate_for_user(pool: &PgPool, user_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE login_link_tokens SET used_at = now() WHERE user_id = $1 AND used_at IS NULL",
+    )
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Drop links that expired over a day ago. Recent ones are kept for the
+/// per-user email cap.
+pub async fn delete_expired(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    let result =
+        sqlx::query("DELETE FROM login_link_tokens WHERE expires_at < now() - interval '1 day'")
+            .execute(pool)
+            .await?;
+
+    Ok(result.rows_affected())
+}
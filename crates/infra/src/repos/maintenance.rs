@@ -0,0 +1,146 @@
+//! Database housekeeping for the admin diagnostics: the migration ledger,
+//! table bloat and vacuuming, the scheduled-announcement backlog, and
+//! rebuilding the stored result points the leaderboards rank on.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+
+/// One row of SQLx's `_sqlx_migrations` ledger.
+#[derive(Debug, Clone, FromRow)]
+pub struct AppliedMigrationRow {
+    pub version: i64,
+    pub description: String,
+    pub installed_on: DateTime<Utc>,
+    pub success: bool,
+    pub checksum: Vec<u8>,
+    /// Nanoseconds.
+    pub execution_time: i64,
+}
+
+/// Dead-tuple statistics of one table.
+#[derive(Debug, Clone, FromRow)]
+pub struct TableBloatRow {
+    pub schema_name: String,
+    pub table_name: String,
+    pub live_tuples: i64,
+    pub dead_tuples: i64,
+    pub last_vacuum_at: Option<DateTime<Utc>>,
+    pub last_autovacuum_at: Option<DateTime<Utc>>,
+}
+
+impl TableBloatRow {
+    /// Dead tuples as a share of all tuples.
+    pub fn dead_ratio(&self) -> f64 {
+        let total = self.live_tuples + self.dead_tuples;
+        if total > 0 {
+            self.dead_tuples as f64 / total as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Scheduled announcements not sent yet.
+#[derive(Debug, Clone, Default, FromRow)]
+pub struct AnnouncementBacklogRow {
+    pub pending: i64,
+    /// Pending ones whose time has come.
+    pub due: i64,
+    pub oldest_due_at: Option<DateTime<Utc>>,
+}
+
+pub async fn applied_migrations<'e>(
+    executor: impl PgExecutor<'e>,
+) -> SqlxResult<Vec<AppliedMigrationRow>> {
+    sqlx::query_as::<_, AppliedMigrationRow>(
+        r#"
+        SELECT version, description, installed_on, success, checksum, execution_time
+        FROM _sqlx_migrations
+        ORDER BY version
+        "#,
+    )
+    .fetch_all(executor)
+    .await
+}
+
+/// Tables with at least `min_dead_tuples` dead tuples making up at least
+/// `min_dead_ratio` of the table, most dead tuples first.
+pub async fn vacuum_candidates<'e>(
+    executor: impl PgExecutor<'e>,
+    min_dead_tuples: i64,
+    min_dead_ratio: f64,
+    limit: i64,
+) -> SqlxResult<Vec<TableBloatRow>> {
+    sqlx::query_as::<_, TableBloatRow>(
+        r#"
+        SELECT schemaname::text AS schema_name, relname::text AS table_name,
+               n_live_tup AS live_tuples, n_dead_tup AS dead_tuples,
+               last_vacuum AS last_vacuum_at, last_autovacuum AS last_autovacuum_at
+        FROM pg_stat_user_tables
+        WHERE n_dead_tup >= $1
+          AND n_dead_tup::float8 / GREATEST(n_live_tup + n_dead_tup, 1) >= $2
+        ORDER BY n_dead_tup DESC, relname
+        LIMIT $3
+        "#,
+    )
+    .bind(min_dead_tuples)
+    .bind(min_dead_ratio)
+    .bind(limit)
+    .fetch_all(executor)
+    .await
+}
+
+/// `VACUUM (ANALYZE)` one table. Must not run inside a transaction.
+pub async fn vacuum_analyze<'e>(
+    executor: impl PgExecutor<'e>,
+    schema_name: &str,
+    table_name: &str,
+) -> SqlxResult<()> {
+    let quote = |ident: &str| format!("\"{}\"", ident.replace('"', "\"\""));
+    sqlx::query(&format!(
+        "VACUUM (ANALYZE) {}.{}",
+        quote(schema_name),
+        quote(table_name)
+    ))
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+/// Tournaments with results, i.e. what a points rebuild would touch.
+pub async fn count_scored_tournaments<'e>(executor: impl PgExecutor<'e>) -> SqlxResult<i64> {
+    sqlx::query_scalar("SELECT COUNT(DISTINCT tournament_id) FROM tournament_results")
+        .fetch_one(executor)
+        .await
+}
+
+/// Recompute every result's stored points with the authoritative formula;
+/// returns the number of tournaments processed.
+pub async fn rebuild_result_points<'e>(executor: impl PgExecutor<'e>) -> SqlxResult<i32> {
+    sqlx::query_scalar("SELECT recalculate_all_tournament_points(NULL)")
+        .fetch_one(executor)
+        .await
+}
+
+pub async fn announcement_backlog<'e>(
+    executor: impl PgExecutor<'e>,
+) -> SqlxResult<AnnouncementBacklogRow> {
+    sqlx::query_as::<_, AnnouncementBacklogRow>(
+        r#"
+        SELECT COUNT(*) AS pending,
+               COUNT(*) FILTER (WHERE scheduled_for <= NOW()) AS due,
+               MIN(scheduled_for) FILTER (WHERE scheduled_for <= NOW()) AS oldest_due_at
+        FROM announcements
+        WHERE sent_at IS NULL
+        "#,
+    )
+    .fetch_one(executor)
+    .await
+}
+
+/// Server-side connections to this database, from every client.
+pub async fn database_connections<'e>(executor: impl PgExecutor<'e>) -> SqlxResult<i64> {
+    sqlx::query_scalar("SELECT COUNT(*) FROM pg_stat_activity WHERE datname = current_database()")
+        .fetch_one(executor)
+        .await
+}
@@ -1,43 +1,128 @@
+pub mod accounting;
 pub mod achievements;
 pub mod activity_log;
 pub mod analytics;
 pub mod announcements;
 pub mod attendance;
+pub mod auth_identities;
+pub mod bankroll;
 pub mod bar_stations;
 pub mod blind_structure_templates;
+pub mod buy_in_credits;
+pub mod capacity_planning;
+pub mod closed_seats;
+pub mod club_clock_holds;
 pub mod club_managers;
+pub mod club_name_settings;
 pub mod club_players;
+pub mod club_staff;
+pub mod club_stats_settings;
 pub mod club_tables;
 pub mod clubs;
+pub mod color_ups;
+pub mod contact_change_requests;
+pub mod dealer_rotation;
 pub mod device_tokens;
+pub mod display_devices;
 pub mod drink_ledger;
 pub mod drink_redemptions;
 pub mod drink_wallet_credentials;
 pub mod drink_wallets;
+pub mod entry_receipts;
+pub mod entry_tickets;
 pub mod flight_qualifications;
+pub mod friend_follows;
 pub mod friendships;
+pub mod incidents;
 pub mod leaderboard_adjustments;
 pub mod leaderboard_configs;
+pub mod level_statistics;
+pub mod login_link_tokens;
+pub mod maintenance;
 pub mod notification_preferences;
+pub mod organizations;
 pub mod password_reset_tokens;
 pub mod payout_templates;
+pub mod persisted_operations;
 pub mod player_deals;
+pub mod player_exclusions;
 pub mod player_notes;
+pub mod player_stat_cards;
 pub mod predictions;
 pub mod privacy;
+pub mod promotions;
+pub mod qualification_rules;
 pub mod quests;
+pub mod raffles;
 pub mod redemption_codes;
 pub mod refresh_tokens;
+pub mod registration_groups;
+pub mod registration_questions;
+pub mod retention;
+pub mod rule_documents;
 pub mod scouting;
 pub mod seasons;
+pub mod seat_change_requests;
+pub mod seat_slip_jobs;
+pub mod spent_challenges;
+pub mod stack_history;
+pub mod staff_shifts;
+pub mod sync_versions;
 pub mod table_seat_assignments;
+pub mod tournament_approvals;
 pub mod tournament_bounties;
+pub mod tournament_chat;
 pub mod tournament_clock;
 pub mod tournament_entries;
+pub mod tournament_invites;
 pub mod tournament_payouts;
+pub mod tournament_photos;
+pub mod tournament_printouts;
+pub mod tournament_recaps;
+pub mod tournament_refunds;
 pub mod tournament_registrations;
 pub mod tournament_results;
 pub mod tournament_series;
+pub mod tournament_timeline;
 pub mod tournaments;
+pub mod user_audit_log;
 pub mod users;
 pub mod wrapped;
+
+use sqlx::postgres::PgRow;
+use sqlx::{FromRow, PgConnection, Result as SqlxResult};
+use uuid::Uuid;
+
+/// A club's row of a per-club settings `table`, inserted with the column
+/// defaults on first use. `table` and `cols` are spliced into the SQL, so they
+/// must be constants.
+pub async fn get_or_insert_default<R>(
+    conn: &mut PgConnection,
+    table: &'static str,
+    cols: &'static str,
+    club_id: Uuid,
+) -> SqlxResult<R>
+where
+    R: for<'r> FromRow<'r, PgRow> + Send + Unpin,
+{
+    let select = format!("SELECT {cols} FROM {table} WHERE club_id = $1");
+    if let Some(row) = sqlx::query_as::<_, R>(&select)
+        .bind(club_id)
+        .fetch_optional(&mut *conn)
+        .await?
+    {
+        return Ok(row);
+    }
+    // Read back in a statement of its own: one statement's snapshot misses
+    // the row when a concurrent first use commits it while the insert waits.
+    sqlx::query(&format!(
+        "INSERT INTO {table} (club_id) VALUES ($1) ON CONFLICT (club_id) DO NOTHING"
+    ))
+    .bind(club_id)
+    .execute(&mut *conn)
+    .await?;
+    sqlx::query_as::<_, R>(&select)
+        .bind(club_id)
+        .fetch_one(&mut *conn)
+        .await
+}
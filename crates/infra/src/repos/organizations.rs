@@ -0,0 +1,333 @@
+//! Organizations group clubs run by the same operator. Clubs outside any
+//! organization are unaffected; players' cross-venue identity is their app
+//! account.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+use crate::models::ClubRow;
+use crate::repos::staff_shifts::SHIFT_COST_SQL;
+
+const COLS: &str = "id, name, slug, require_tournament_approval, created_at, updated_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct OrganizationRow {
+    pub id: Uuid,
+    pub name: String,
+    pub slug: String,
+    /// Tournaments created by club managers wait for an organization admin's
+    /// approval before they are public.
+    pub require_tournament_approval: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A player's attendance across an organization's clubs.
+#[derive(Debug, Clone, FromRow)]
+pub struct OrganizationLoyaltyRow {
+    /// Tournaments played (cancellations and no-shows excluded).
+    pub tournaments_played: i64,
+    pub check_ins: i64,
+    pub clubs_visited: i64,
+    /// Distinct calendar months with at least one tournament played.
+    pub months_active: i64,
+    pub first_played_at: Option<DateTime<Utc>>,
+    pub last_played_at: Option<DateTime<Utc>>,
+}
+
+/// An organization admin joined with their user record.
+#[derive(Debug, Clone, FromRow)]
+pub struct OrganizationAdminRow {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub email: String,
+    pub first_name: String,
+    pub last_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// One club's money over a reporting window (tournaments by start time,
+/// shifts by clock-in time).
+#[derive(Debug, Clone, FromRow)]
+pub struct ClubFinancialsRow {
+    pub club_id: Uuid,
+    pub club_name: String,
+    pub plan: String,
+    pub tournament_count: i64,
+    /// Initial buy-ins, rebuys and re-entries.
+    pub entry_count: i64,
+    pub total_collected_cents: i64,
+    pub total_rake_cents: i64,
+    pub prize_pool_cents: i64,
+    pub staff_cost_cents: i64,
+}
+
+/// Create an organization. Returns `None` when the slug is taken.
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    name: &str,
+    slug: &str,
+) -> SqlxResult<Option<OrganizationRow>> {
+    sqlx::query_as::<_, OrganizationRow>(&format!(
+        "INSERT INTO organizations (name, slug) VALUES ($1, $2) \
+         ON CONFLICT (slug) DO NOTHING RETURNING {COLS}"
+    ))
+    .bind(name)
+    .bind(slug)
+    .fetch_optional(executor)
+    .await
+}
+
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<OrganizationRow>> {
+    sqlx::query_as::<_, OrganizationRow>(&format!("SELECT {COLS} FROM organizations WHERE id = $1"))
+        .bind(id)
+        .fetch_optional(executor)
+        .await
+}
+
+pub async fn list<'e>(executor: impl PgExecutor<'e>) -> SqlxResult<Vec<OrganizationRow>> {
+    sqlx::query_as::<_, OrganizationRow>(&format!(
+        "SELECT {COLS} FROM organizations ORDER BY name ASC"
+    ))
+    .fetch_all(executor)
+    .await
+}
+
+/// Turn the tournament approval workflow on or off.
+pub async fn set_require_tournament_approval<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    required: bool,
+) -> SqlxResult<Option<OrganizationRow>> {
+    sqlx::query_as::<_, OrganizationRow>(&format!(
+        "UPDATE organizations SET require_tournament_approval = $2 WHERE id = $1 RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(required)
+    .fetch_optional(executor)
+    .await
+}
+
+/// The organization a club belongs to, if any.
+pub async fn get_for_club<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+) -> SqlxResult<Option<OrganizationRow>> {
+    sqlx::query_as::<_, OrganizationRow>(
+        "SELECT o.id, o.name, o.slug, o.require_tournament_approval, o.created_at, o.updated_at \
+         FROM organizations o JOIN clubs c ON c.organization_id = o.id \
+         WHERE c.id = $1",
+    )
+    .bind(club_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Move a club into an organization, or out of any with `None`. Returns
+/// false when the club doesn't exist.
+pub async fn set_club_organization<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    organization_id: Option<Uuid>,
+) -> SqlxResult<bool> {
+    let result = sqlx::query("UPDATE clubs SET organization_id = $2 WHERE id = $1")
+        .bind(club_id)
+        .bind(organization_id)
+        .execute(executor)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn list_clubs<'e>(
+    executor: impl PgExecutor<'e>,
+    organization_id: Uuid,
+) -> SqlxResult<Vec<ClubRow>> {
+    sqlx::query_as::<_, ClubRow>(
+        "SELECT id, name, city, postal_code, province, country, address, vat_number, needs_review, plan, subscription_status, subscription_expires_at, created_at, updated_at \
+         FROM clubs WHERE organization_id = $1 ORDER BY name ASC",
+    )
+    .bind(organization_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Attendance for an app user across every club in the organization.
+pub async fn loyalty_for_user<'e>(
+    executor: impl PgExecutor<'e>,
+    organization_id: Uuid,
+    user_id: Uuid,
+) -> SqlxResult<OrganizationLoyaltyRow> {
+    sqlx::query_as::<_, OrganizationLoyaltyRow>(
+        r#"
+        SELECT
+            COUNT(DISTINCT reg.tournament_id) AS tournaments_played,
+            (SELECT COUNT(*) FROM check_in ci
+               JOIN clubs cc ON cc.id = ci.club_id
+              WHERE ci.app_user_id = $2 AND cc.organization_id = $1) AS check_ins,
+            COUNT(DISTINCT t.club_id) AS clubs_visited,
+            COUNT(DISTINCT date_trunc('month', t.start_time)) AS months_active,
+            MIN(t.start_time) AS first_played_at,
+            MAX(t.start_time) AS last_played_at
+        FROM tournament_registrations reg
+        JOIN tournaments t ON t.id = reg.tournament_id
+        JOIN clubs c ON c.id = t.club_id
+        WHERE reg.user_id = $2
+          AND c.organization_id = $1
+          AND reg.status NOT IN ('cancelled', 'no_show')
+        "#,
+    )
+    .bind(organization_id)
+    .bind(user_id)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn is_admin<'e>(
+    executor: impl PgExecutor<'e>,
+    organization_id: Uuid,
+    user_id: Uuid,
+) -> SqlxResult<bool> {
+    sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS (SELECT 1 FROM organization_admins \
+         WHERE organization_id = $1 AND user_id = $2)",
+    )
+    .bind(organization_id)
+    .bind(user_id)
+    .fetch_one(executor)
+    .await
+}
+
+/// Make a user an organization admin. Returns false when they already were.
+pub async fn add_admin<'e>(
+    executor: impl PgExecutor<'e>,
+    organization_id: Uuid,
+    user_id: Uuid,
+    created_by: Uuid,
+) -> SqlxResult<bool> {
+    let result = sqlx::query(
+        "INSERT INTO organization_admins (organization_id, user_id, created_by) \
+         VALUES ($1, $2, $3) ON CONFLICT (organization_id, user_id) DO NOTHING",
+    )
+    .bind(organization_id)
+    .bind(user_id)
+    .bind(created_by)
+    .execute(executor)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn remove_admin<'e>(
+    executor: impl PgExecutor<'e>,
+    organization_id: Uuid,
+    user_id: Uuid,
+) -> SqlxResult<bool> {
+    let result =
+        sqlx::query("DELETE FROM organization_admins WHERE organization_id = $1 AND user_id = $2")
+            .bind(organization_id)
+            .bind(user_id)
+            .execute(executor)
+            .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn count_admins<'e>(
+    executor: impl PgExecutor<'e>,
+    organization_id: Uuid,
+) -> SqlxResult<i64> {
+    sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM organization_admins WHERE organization_id = $1",
+    )
+    .bind(organization_id)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn list_admins<'e>(
+    executor: impl PgExecutor<'e>,
+    organization_id: Uuid,
+) -> SqlxResult<Vec<OrganizationAdminRow>> {
+    sqlx::query_as::<_, OrganizationAdminRow>(
+        "SELECT oa.id, oa.organization_id, oa.user_id, u.email, u.first_name, u.last_name, \
+                oa.created_at \
+         FROM organization_admins oa JOIN users u ON u.id = oa.user_id \
+         WHERE oa.organization_id = $1 ORDER BY oa.created_at ASC",
+    )
+    .bind(organization_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Organizations the user administers.
+pub async fn list_for_admin<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+) -> SqlxResult<Vec<OrganizationRow>> {
+    sqlx::query_as::<_, OrganizationRow>(
+        "SELECT o.id, o.name, o.slug, o.require_tournament_approval, o.created_at, o.updated_at \
+         FROM organizations o JOIN organization_admins oa ON oa.organization_id = o.id \
+         WHERE oa.user_id = $1 ORDER BY o.name ASC",
+    )
+    .bind(user_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Per-club money for every club in the organization over `[from, to)`.
+pub async fn financial_report<'e>(
+    executor: impl PgExecutor<'e>,
+    organization_id: Uuid,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> SqlxResult<Vec<ClubFinancialsRow>> {
+    sqlx::query_as::<_, ClubFinancialsRow>(&format!(
+        r#"
+        WITH window_tournaments AS (
+            SELECT t.id, t.club_id, COALESCE(t.rake_cents, 0) AS rake_cents
+            FROM tournaments t
+            JOIN clubs c ON c.id = t.club_id
+            WHERE c.organization_id = $1 AND t.start_time >= $2 AND t.start_time < $3
+        )
+        SELECT
+            c.id AS club_id,
+            c.name AS club_name,
+            c.plan,
+            (SELECT COUNT(*) FROM window_tournaments wt WHERE wt.club_id = c.id)
+                AS tournament_count,
+            (SELECT COUNT(*) FROM tournament_entries e
+               JOIN window_tournaments wt ON wt.id = e.tournament_id
+              WHERE wt.club_id = c.id AND e.entry_type IN ('initial', 'rebuy', 're_entry')
+                AND e.voided_at IS NULL)
+                AS entry_count,
+            (SELECT COALESCE(SUM(e.amount_cents), 0)::bigint FROM tournament_entries e
+               JOIN window_tournaments wt ON wt.id = e.tournament_id
+              WHERE wt.club_id = c.id AND e.voided_at IS NULL)
+                AS total_collected_cents,
+            (SELECT COALESCE(SUM(wt.rake_cents), 0)::bigint FROM tournament_entries e
+               JOIN window_tournaments wt ON wt.id = e.tournament_id
+              WHERE wt.club_id = c.id AND e.entry_type IN ('initial', 're_entry')
+                AND e.voided_at IS NULL)
+                AS total_rake_cents,
+            (SELECT COALESCE(SUM(p.total_prize_pool), 0)::bigint FROM tournament_payouts p
+               JOIN window_tournaments wt ON wt.id = p.tournament_id
+              WHERE wt.club_id = c.id)
+                AS prize_pool_cents,
+            (SELECT COALESCE(SUM({SHIFT_COST_SQL}), 0)::bigint FROM staff_shifts sh
+              WHERE sh.club_id = c.id AND sh.clock_out_at IS NOT NULL
+                AND sh.clock_in_at >= $2 AND sh.clock_in_at < $3)
+                AS staff_cost_cents
+        FROM clubs c
+        WHERE c.organization_id = $1
+        ORDER BY c.name ASC
+        "#
+    ))
+    .bind(organization_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(executor)
+    .await
+}
@@ -0,0 +1,96 @@
+//! The allow-list of GraphQL operations per API client, for the server's
+//! locked mode.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str =
+    "id, client_name, operation_name, sha256_hash, query, created_by, created_at, revoked_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct PersistedOperationRow {
+    pub id: Uuid,
+    pub client_name: String,
+    pub operation_name: Option<String>,
+    /// Hex SHA-256 of `query`.
+    pub sha256_hash: String,
+    pub query: String,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+/// Allow `query` for the client. Registering a revoked operation again
+/// reinstates it.
+pub async fn register<'e>(
+    executor: impl PgExecutor<'e>,
+    client_name: &str,
+    operation_name: Option<&str>,
+    sha256_hash: &str,
+    query: &str,
+    created_by: Option<Uuid>,
+) -> SqlxResult<PersistedOperationRow> {
+    sqlx::query_as::<_, PersistedOperationRow>(&format!(
+        "INSERT INTO persisted_operations \
+            (client_name, operation_name, sha256_hash, query, created_by) \
+         VALUES ($1, $2, $3, $4, $5) \
+         ON CONFLICT (client_name, sha256_hash) DO UPDATE SET \
+            operation_name = EXCLUDED.operation_name, revoked_at = NULL \
+         RETURNING {COLS}"
+    ))
+    .bind(client_name)
+    .bind(operation_name)
+    .bind(sha256_hash)
+    .bind(query)
+    .bind(created_by)
+    .fetch_one(executor)
+    .await
+}
+
+/// The client's live operation with this hash.
+pub async fn find_active<'e>(
+    executor: impl PgExecutor<'e>,
+    client_name: &str,
+    sha256_hash: &str,
+) -> SqlxResult<Option<PersistedOperationRow>> {
+    sqlx::query_as::<_, PersistedOperationRow>(&format!(
+        "SELECT {COLS} FROM persisted_operations \
+         WHERE client_name = $1 AND sha256_hash = $2 AND revoked_at IS NULL"
+    ))
+    .bind(client_name)
+    .bind(sha256_hash)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Registered operations, optionally of one client, by client and name.
+pub async fn list<'e>(
+    executor: impl PgExecutor<'e>,
+    client_name: Option<&str>,
+    include_revoked: bool,
+) -> SqlxResult<Vec<PersistedOperationRow>> {
+    sqlx::query_as::<_, PersistedOperationRow>(&format!(
+        "SELECT {COLS} FROM persisted_operations \
+         WHERE ($1::text IS NULL OR client_name = $1) AND ($2 OR revoked_at IS NULL) \
+         ORDER BY client_name, operation_name NULLS LAST, created_at"
+    ))
+    .bind(client_name)
+    .bind(include_revoked)
+    .fetch_all(executor)
+    .await
+}
+
+/// Take an operation off the allow-list; None if there is no such operation.
+pub async fn revoke<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<PersistedOperationRow>> {
+    sqlx::query_as::<_, PersistedOperationRow>(&format!(
+        "UPDATE persisted_operations SET revoked_at = COALESCE(revoked_at, NOW()) \
+         WHERE id = $1 RETURNING {COLS}"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
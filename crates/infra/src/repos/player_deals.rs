@@ -10,7 +10,7 @@ pub struct CreatePlayerDeal {
     pub deal_type: String,
     pub affected_positions: Vec<i32>,
     pub custom_payouts: Option<JsonValue>,
-    pub total_amount_cents: i32,
+    pub total_amount_cents: i64,
     pub notes: Option<String>,
     pub created_by: Uuid,
 }
@@ -0,0 +1,134 @@
+//! Club exclusions (bans). An active exclusion blocks registration at the
+//! club; it ends at `ends_at` (NULL = indefinite) or when lifted.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "id, club_id, club_player_id, incident_id, reason, starts_at, ends_at, lifted_at, lifted_by, created_by, created_at";
+
+/// SQL predicate for an exclusion in force right now.
+const ACTIVE: &str =
+    "lifted_at IS NULL AND starts_at <= NOW() AND (ends_at IS NULL OR ends_at > NOW())";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct PlayerExclusionRow {
+    pub id: Uuid,
+    pub club_id: Uuid,
+    pub club_player_id: Uuid,
+    pub incident_id: Option<Uuid>,
+    pub reason: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub lifted_at: Option<DateTime<Utc>>,
+    pub lifted_by: Option<Uuid>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreatePlayerExclusion {
+    pub club_id: Uuid,
+    pub club_player_id: Uuid,
+    pub incident_id: Option<Uuid>,
+    pub reason: String,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub created_by: Uuid,
+}
+
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    data: CreatePlayerExclusion,
+) -> SqlxResult<PlayerExclusionRow> {
+    sqlx::query_as::<_, PlayerExclusionRow>(&format!(
+        "INSERT INTO player_exclusions \
+         (club_id, club_player_id, incident_id, reason, ends_at, created_by) \
+         VALUES ($1, $2, $3, $4, $5, $6) RETURNING {COLS}"
+    ))
+    .bind(data.club_id)
+    .bind(data.club_player_id)
+    .bind(data.incident_id)
+    .bind(data.reason)
+    .bind(data.ends_at)
+    .bind(data.created_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<PlayerExclusionRow>> {
+    sqlx::query_as::<_, PlayerExclusionRow>(&format!(
+        "SELECT {COLS} FROM player_exclusions WHERE id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A club's exclusions, newest first.
+pub async fn list_by_club<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    active_only: bool,
+) -> SqlxResult<Vec<PlayerExclusionRow>> {
+    sqlx::query_as::<_, PlayerExclusionRow>(&format!(
+        "SELECT {COLS} FROM player_exclusions \
+         WHERE club_id = $1 AND (NOT $2 OR ({ACTIVE})) \
+         ORDER BY created_at DESC"
+    ))
+    .bind(club_id)
+    .bind(active_only)
+    .fetch_all(executor)
+    .await
+}
+
+/// The exclusion currently in force for a roster entry, if any.
+pub async fn active_for_club_player<'e>(
+    executor: impl PgExecutor<'e>,
+    club_player_id: Uuid,
+) -> SqlxResult<Option<PlayerExclusionRow>> {
+    sqlx::query_as::<_, PlayerExclusionRow>(&format!(
+        "SELECT {COLS} FROM player_exclusions \
+         WHERE club_player_id = $1 AND {ACTIVE} \
+         ORDER BY ends_at DESC NULLS FIRST LIMIT 1"
+    ))
+    .bind(club_player_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// The exclusion currently in force for an app user at a club, if any.
+pub async fn active_for_user<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    user_id: Uuid,
+) -> SqlxResult<Option<PlayerExclusionRow>> {
+    sqlx::query_as::<_, PlayerExclusionRow>(&format!(
+        "SELECT {COLS} FROM player_exclusions \
+         WHERE club_id = $1 AND {ACTIVE} \
+           AND club_player_id IN (SELECT id FROM club_player WHERE club_id = $1 AND app_user_id = $2) \
+         ORDER BY ends_at DESC NULLS FIRST LIMIT 1"
+    ))
+    .bind(club_id)
+    .bind(user_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Lift an exclusion early. Returns `None` if it was already lifted.
+pub async fn lift<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    lifted_by: Uuid,
+) -> SqlxResult<Option<PlayerExclusionRow>> {
+    sqlx::query_as::<_, PlayerExclusionRow>(&format!(
+        "UPDATE player_exclusions SET lifted_at = NOW(), lifted_by = $2 \
+         WHERE id = $1 AND lifted_at IS NULL RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(lifted_by)
+    .fetch_optional(executor)
+    .await
+}
@@ -244,7 +244,7 @@ pub async fn field_with_notes<'e>(
             pn.id AS pn_id, pn.body AS pn_body, pn.style AS pn_style, pn.color AS pn_color, \
             pn.created_at AS pn_created_at, pn.updated_at AS pn_updated_at \
          FROM tournament_registrations reg \
-         JOIN club_player rp ON rp.id = reg.club_player_id \
+         JOIN club_player_public rp ON rp.id = reg.club_player_id \
          LEFT JOIN player_note pn \
             ON pn.subject_club_player_id = rp.id AND pn.author_app_user_id = $2 \
          WHERE reg.tournament_id = $1 \
@@ -277,7 +277,7 @@ pub async fn table_with_notes<'e>(
             pn.id AS pn_id, pn.body AS pn_body, pn.style AS pn_style, pn.color AS pn_color, \
             pn.created_at AS pn_created_at, pn.updated_at AS pn_updated_at \
          FROM table_seat_assignments tsa \
-         JOIN club_player rp ON rp.id = tsa.club_player_id \
+         JOIN club_player_public rp ON rp.id = tsa.club_player_id \
          JOIN club_tables ct ON ct.id = tsa.club_table_id \
          LEFT JOIN player_note pn \
             ON pn.subject_club_player_id = rp.id AND pn.author_app_user_id = $2 \
@@ -0,0 +1,117 @@
+//! Shareable monthly stat cards (PNG) and the numbers printed on them.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+use super::tournament_results::StatThresholds;
+
+/// Card metadata; the PNG bytes are only loaded for downloads.
+const COLS: &str = "id, club_id, club_player_id, month, \
+     octet_length(content)::BIGINT AS size_bytes, generated_by, generated_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct StatCardRow {
+    pub id: Uuid,
+    pub club_id: Uuid,
+    pub club_player_id: Uuid,
+    /// First day of the month the card covers.
+    pub month: NaiveDate,
+    pub size_bytes: i64,
+    pub generated_by: Option<Uuid>,
+    pub generated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct StatCardContentRow {
+    pub display_name: String,
+    pub month: NaiveDate,
+    pub content: Vec<u8>,
+}
+
+/// A player's tournaments at their club over a period.
+#[derive(Debug, Clone, Default, FromRow)]
+pub struct MonthlyStatsRow {
+    /// Tournaments played: checked in, seated or busted, or with a result.
+    pub tournaments: i64,
+    /// Of those, finishes in the money by the club's settings.
+    pub itm: i64,
+    pub best_finish: Option<i32>,
+}
+
+/// Stats for tournaments of `club_player_id` starting in `[from, to)`.
+pub async fn monthly_stats<'e>(
+    executor: impl PgExecutor<'e>,
+    club_player_id: Uuid,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> SqlxResult<MonthlyStatsRow> {
+    let thresholds = StatThresholds::default();
+    sqlx::query_as::<_, MonthlyStatsRow>(&format!(
+        r#"
+        WITH played AS (
+            SELECT tournament_id FROM tournament_registrations
+            WHERE club_player_id = $1 AND status IN ('checked_in', 'seated', 'busted')
+            UNION
+            SELECT tournament_id FROM tournament_results WHERE club_player_id = $1
+        )
+        SELECT COUNT(*) AS tournaments,
+               COUNT(tr.id) FILTER (WHERE {itm}) AS itm,
+               MIN(tr.final_position) AS best_finish
+        FROM played p
+        JOIN tournaments t ON t.id = p.tournament_id
+        LEFT JOIN tournament_results tr
+               ON tr.tournament_id = p.tournament_id AND tr.club_player_id = $1
+        {settings_join}
+        WHERE t.start_time >= $2 AND t.start_time < $3
+        "#,
+        itm = thresholds.itm_sql(),
+        settings_join = StatThresholds::SETTINGS_JOIN,
+    ))
+    .bind(club_player_id)
+    .bind(from)
+    .bind(to)
+    .fetch_one(executor)
+    .await
+}
+
+/// Store a freshly rendered card, replacing the player's card for that month.
+pub async fn upsert<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    club_player_id: Uuid,
+    month: NaiveDate,
+    content: &[u8],
+    generated_by: Option<Uuid>,
+) -> SqlxResult<StatCardRow> {
+    sqlx::query_as::<_, StatCardRow>(&format!(
+        "INSERT INTO player_stat_cards (club_id, club_player_id, month, content, generated_by) \
+         VALUES ($1, $2, $3, $4, $5) \
+         ON CONFLICT (club_player_id, month) DO UPDATE SET \
+             content = EXCLUDED.content, generated_by = EXCLUDED.generated_by, \
+             generated_at = NOW() \
+         RETURNING {COLS}"
+    ))
+    .bind(club_id)
+    .bind(club_player_id)
+    .bind(month)
+    .bind(content)
+    .bind(generated_by)
+    .fetch_one(executor)
+    .await
+}
+
+/// The PNG bytes of a card, with the player's name for the filename.
+pub async fn get_content<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<StatCardContentRow>> {
+    sqlx::query_as::<_, StatCardContentRow>(
+        "SELECT cp.display_name, c.month, c.content \
+         FROM player_stat_cards c JOIN club_player cp ON cp.id = c.club_player_id \
+         WHERE c.id = $1",
+    )
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
@@ -160,7 +160,7 @@ pub async fn list_for_user<'e>(
                 pe.payout_points AS payout_points, pe.created_at AS created_at \
          FROM prediction_entry pe \
          JOIN tournaments t ON t.id = pe.tournament_id \
-         JOIN users w ON w.id = pe.predicted_winner_user_id \
+         JOIN users_public w ON w.id = pe.predicted_winner_user_id \
          WHERE pe.app_user_id = $1 \
          ORDER BY pe.created_at DESC",
     )
@@ -3,7 +3,8 @@ use uuid::Uuid;
 
 use crate::models::UserPrivacySettingsRow;
 
-const COLS: &str = "app_user_id, share_named_pl, in_scouting_pool, created_at, updated_at";
+const COLS: &str =
+    "app_user_id, share_named_pl, in_scouting_pool, appear_in_photos, created_at, updated_at";
 
 pub async fn get<'e>(
     executor: impl PgExecutor<'e>,
@@ -23,19 +24,23 @@ pub async fn upsert<'e>(
     app_user_id: Uuid,
     share_named_pl: bool,
     in_scouting_pool: bool,
+    appear_in_photos: bool,
 ) -> SqlxResult<UserPrivacySettingsRow> {
     sqlx::query_as::<_, UserPrivacySettingsRow>(&format!(
-        "INSERT INTO user_privacy_settings (app_user_id, share_named_pl, in_scouting_pool) \
-         VALUES ($1, $2, $3) \
+        "INSERT INTO user_privacy_settings \
+         (app_user_id, share_named_pl, in_scouting_pool, appear_in_photos) \
+         VALUES ($1, $2, $3, $4) \
          ON CONFLICT (app_user_id) DO UPDATE SET \
             share_named_pl = EXCLUDED.share_named_pl, \
             in_scouting_pool = EXCLUDED.in_scouting_pool, \
+            appear_in_photos = EXCLUDED.appear_in_photos, \
             updated_at = NOW() \
          RETURNING {COLS}"
     ))
     .bind(app_user_id)
     .bind(share_named_pl)
     .bind(in_scouting_pool)
+    .bind(appear_in_photos)
     .fetch_one(executor)
     .await
 }
@@ -0,0 +1,282 @@
+//! Promotions board: running high-hand / bad-beat jackpots, their append-only
+//! ledger and the qualifying hands recorded by the floor.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+/// Jackpot columns plus the ledger balance; `j` aliases `promotion_jackpots`.
+const JACKPOT_COLS: &str = "j.id, j.club_id, j.kind, j.name, j.rules, j.is_active, j.created_by, \
+     j.created_at, j.updated_at, app_jackpot_balance(j.id) AS balance_cents";
+const HAND_COLS: &str = "id, jackpot_id, club_player_id, player_name, hand, table_label, \
+     amount_cents, status, recorded_by, paid_at, paid_by, created_at";
+const ENTRY_COLS: &str =
+    "id, jackpot_id, kind, amount_cents, hand_id, note, created_by, created_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct JackpotRow {
+    pub id: Uuid,
+    pub club_id: Uuid,
+    /// `high_hand` | `bad_beat`.
+    pub kind: String,
+    pub name: String,
+    pub rules: Option<String>,
+    pub is_active: bool,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Sum of the jackpot's ledger entries.
+    pub balance_cents: i64,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct HandRow {
+    pub id: Uuid,
+    pub jackpot_id: Uuid,
+    pub club_player_id: Option<Uuid>,
+    pub player_name: String,
+    pub hand: String,
+    pub table_label: Option<String>,
+    pub amount_cents: i64,
+    /// `pending` | `paid` | `voided`.
+    pub status: String,
+    pub recorded_by: Option<Uuid>,
+    pub paid_at: Option<DateTime<Utc>>,
+    pub paid_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct JackpotEntryRow {
+    pub id: Uuid,
+    pub jackpot_id: Uuid,
+    /// `seed` | `contribution` | `payout`.
+    pub kind: String,
+    /// Positive for seeds and contributions, negative for payouts.
+    pub amount_cents: i64,
+    pub hand_id: Option<Uuid>,
+    pub note: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewHand {
+    pub jackpot_id: Uuid,
+    pub club_player_id: Option<Uuid>,
+    pub player_name: String,
+    pub hand: String,
+    pub table_label: Option<String>,
+    pub amount_cents: i64,
+    pub recorded_by: Uuid,
+}
+
+pub async fn create_jackpot<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    kind: &str,
+    name: &str,
+    rules: Option<&str>,
+    created_by: Uuid,
+) -> SqlxResult<JackpotRow> {
+    sqlx::query_as::<_, JackpotRow>(
+        "INSERT INTO promotion_jackpots (club_id, kind, name, rules, created_by) \
+         VALUES ($1, $2, $3, $4, $5) \
+         RETURNING id, club_id, kind, name, rules, is_active, created_by, created_at, \
+             updated_at, 0::BIGINT AS balance_cents",
+    )
+    .bind(club_id)
+    .bind(kind)
+    .bind(name)
+    .bind(rules)
+    .bind(created_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_jackpot<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<JackpotRow>> {
+    sqlx::query_as::<_, JackpotRow>(&format!(
+        "SELECT {JACKPOT_COLS} FROM promotion_jackpots j WHERE j.id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Lock a jackpot so concurrent payouts see each other's ledger entries.
+pub async fn lock_jackpot<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<bool> {
+    let found: Option<Uuid> =
+        sqlx::query_scalar("SELECT id FROM promotion_jackpots WHERE id = $1 FOR UPDATE")
+            .bind(id)
+            .fetch_optional(executor)
+            .await?;
+    Ok(found.is_some())
+}
+
+/// A club's jackpots, oldest first.
+pub async fn list_jackpots<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    active_only: bool,
+) -> SqlxResult<Vec<JackpotRow>> {
+    sqlx::query_as::<_, JackpotRow>(&format!(
+        "SELECT {JACKPOT_COLS} FROM promotion_jackpots j \
+         WHERE j.club_id = $1 AND (NOT $2 OR j.is_active) \
+         ORDER BY j.created_at, j.id"
+    ))
+    .bind(club_id)
+    .bind(active_only)
+    .fetch_all(executor)
+    .await
+}
+
+/// Update a jackpot's name, rules or active flag; `None` keeps the value.
+pub async fn update_jackpot<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    name: Option<&str>,
+    rules: Option<&str>,
+    is_active: Option<bool>,
+) -> SqlxResult<Option<JackpotRow>> {
+    sqlx::query_as::<_, JackpotRow>(&format!(
+        "WITH updated AS ( \
+             UPDATE promotion_jackpots SET \
+                 name = COALESCE($2, name), \
+                 rules = COALESCE($3, rules), \
+                 is_active = COALESCE($4, is_active) \
+             WHERE id = $1 RETURNING id) \
+         SELECT {JACKPOT_COLS} FROM promotion_jackpots j JOIN updated u ON u.id = j.id"
+    ))
+    .bind(id)
+    .bind(name)
+    .bind(rules)
+    .bind(is_active)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Append a ledger entry. Payouts carry a negative amount and their hand.
+pub async fn add_entry<'e>(
+    executor: impl PgExecutor<'e>,
+    jackpot_id: Uuid,
+    kind: &str,
+    amount_cents: i64,
+    hand_id: Option<Uuid>,
+    note: Option<&str>,
+    created_by: Uuid,
+) -> SqlxResult<JackpotEntryRow> {
+    sqlx::query_as::<_, JackpotEntryRow>(&format!(
+        "INSERT INTO promotion_jackpot_entries \
+             (jackpot_id, kind, amount_cents, hand_id, note, created_by) \
+         VALUES ($1, $2, $3, $4, $5, $6) RETURNING {ENTRY_COLS}"
+    ))
+    .bind(jackpot_id)
+    .bind(kind)
+    .bind(amount_cents)
+    .bind(hand_id)
+    .bind(note)
+    .bind(created_by)
+    .fetch_one(executor)
+    .await
+}
+
+/// A jackpot's ledger, newest first.
+pub async fn list_entries<'e>(
+    executor: impl PgExecutor<'e>,
+    jackpot_id: Uuid,
+    limit: i64,
+) -> SqlxResult<Vec<JackpotEntryRow>> {
+    sqlx::query_as::<_, JackpotEntryRow>(&format!(
+        "SELECT {ENTRY_COLS} FROM promotion_jackpot_entries \
+         WHERE jackpot_id = $1 ORDER BY created_at DESC, id LIMIT $2"
+    ))
+    .bind(jackpot_id)
+    .bind(limit)
+    .fetch_all(executor)
+    .await
+}
+
+/// The current balance of a jackpot (sum of its ledger).
+pub async fn balance<'e>(executor: impl PgExecutor<'e>, jackpot_id: Uuid) -> SqlxResult<i64> {
+    sqlx::query_scalar(
+        "SELECT COALESCE(SUM(amount_cents), 0)::BIGINT FROM promotion_jackpot_entries \
+         WHERE jackpot_id = $1",
+    )
+    .bind(jackpot_id)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn create_hand<'e>(executor: impl PgExecutor<'e>, data: NewHand) -> SqlxResult<HandRow> {
+    sqlx::query_as::<_, HandRow>(&format!(
+        "INSERT INTO promotion_hands \
+             (jackpot_id, club_player_id, player_name, hand, table_label, amount_cents, \
+              recorded_by) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING {HAND_COLS}"
+    ))
+    .bind(data.jackpot_id)
+    .bind(data.club_player_id)
+    .bind(data.player_name)
+    .bind(data.hand)
+    .bind(data.table_label)
+    .bind(data.amount_cents)
+    .bind(data.recorded_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_hand<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<Option<HandRow>> {
+    sqlx::query_as::<_, HandRow>(&format!(
+        "SELECT {HAND_COLS} FROM promotion_hands WHERE id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Mark a pending hand paid. `None` when it isn't pending any more.
+pub async fn mark_hand_paid<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    paid_by: Uuid,
+) -> SqlxResult<Option<HandRow>> {
+    sqlx::query_as::<_, HandRow>(&format!(
+        "UPDATE promotion_hands SET status = 'paid', paid_at = NOW(), paid_by = $2 \
+         WHERE id = $1 AND status = 'pending' RETURNING {HAND_COLS}"
+    ))
+    .bind(id)
+    .bind(paid_by)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Void a pending hand. `None` when it isn't pending any more.
+pub async fn void_hand<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<Option<HandRow>> {
+    sqlx::query_as::<_, HandRow>(&format!(
+        "UPDATE promotion_hands SET status = 'voided' \
+         WHERE id = $1 AND status = 'pending' RETURNING {HAND_COLS}"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A jackpot's qualifying hands, newest first. Voided hands are left out.
+pub async fn list_hands<'e>(
+    executor: impl PgExecutor<'e>,
+    jackpot_id: Uuid,
+    limit: i64,
+) -> SqlxResult<Vec<HandRow>> {
+    sqlx::query_as::<_, HandRow>(&format!(
+        "SELECT {HAND_COLS} FROM promotion_hands \
+         WHERE jackpot_id = $1 AND status <> 'voided' \
+         ORDER BY created_at DESC, id LIMIT $2"
+    ))
+    .bind(jackpot_id)
+    .bind(limit)
+    .fetch_all(executor)
+    .await
+}
@@ -0,0 +1,169 @@
+//! Qualification rules: how many of a series' events (and, optionally, how
+//! many points there) a player needs to be registered in the rule's final.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "id, club_id, series_id, name, min_events, min_points, final_tournament_id, \
+                    created_at, updated_at";
+
+/// Registration statuses that mean the player took their seat.
+const PLAYED: &str = "('checked_in', 'seated', 'busted')";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct QualificationRuleRow {
+    pub id: Uuid,
+    pub club_id: Uuid,
+    pub series_id: Uuid,
+    pub name: String,
+    pub min_events: i32,
+    /// Points also required; `None` counts events only.
+    pub min_points: Option<i32>,
+    pub final_tournament_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A roster player's tally under a rule.
+#[derive(Debug, Clone, FromRow)]
+pub struct QualificationProgressRow {
+    pub club_player_id: Uuid,
+    pub display_name: String,
+    pub app_user_id: Option<Uuid>,
+    pub events_played: i64,
+    pub points: i64,
+}
+
+impl QualificationRuleRow {
+    pub fn is_met_by(&self, progress: &QualificationProgressRow) -> bool {
+        progress.events_played >= i64::from(self.min_events)
+            && self
+                .min_points
+                .is_none_or(|min| progress.points >= i64::from(min))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateQualificationRule {
+    pub club_id: Uuid,
+    pub series_id: Uuid,
+    pub name: String,
+    pub min_events: i32,
+    pub min_points: Option<i32>,
+}
+
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    data: CreateQualificationRule,
+) -> SqlxResult<QualificationRuleRow> {
+    sqlx::query_as::<_, QualificationRuleRow>(&format!(
+        "INSERT INTO qualification_rules (club_id, series_id, name, min_events, min_points) \
+         VALUES ($1, $2, $3, $4, $5) RETURNING {COLS}"
+    ))
+    .bind(data.club_id)
+    .bind(data.series_id)
+    .bind(data.name)
+    .bind(data.min_events)
+    .bind(data.min_points)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<QualificationRuleRow>> {
+    sqlx::query_as::<_, QualificationRuleRow>(&format!(
+        "SELECT {COLS} FROM qualification_rules WHERE id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+pub async fn list_by_club<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+) -> SqlxResult<Vec<QualificationRuleRow>> {
+    sqlx::query_as::<_, QualificationRuleRow>(&format!(
+        "SELECT {COLS} FROM qualification_rules WHERE club_id = $1 ORDER BY created_at DESC, id"
+    ))
+    .bind(club_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Rules counting a series' events whose final already exists.
+pub async fn list_with_final_for_series<'e>(
+    executor: impl PgExecutor<'e>,
+    series_id: Uuid,
+) -> SqlxResult<Vec<QualificationRuleRow>> {
+    sqlx::query_as::<_, QualificationRuleRow>(&format!(
+        "SELECT {COLS} FROM qualification_rules \
+         WHERE series_id = $1 AND final_tournament_id IS NOT NULL"
+    ))
+    .bind(series_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Make `tournament_id` the rule's final. `None` if the rule already has one.
+pub async fn set_final<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    tournament_id: Uuid,
+) -> SqlxResult<Option<QualificationRuleRow>> {
+    sqlx::query_as::<_, QualificationRuleRow>(&format!(
+        "UPDATE qualification_rules SET final_tournament_id = $2 \
+         WHERE id = $1 AND final_tournament_id IS NULL RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(tournament_id)
+    .fetch_optional(executor)
+    .await
+}
+
+pub async fn delete<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<bool> {
+    let result = sqlx::query("DELETE FROM qualification_rules WHERE id = $1")
+        .bind(id)
+        .execute(executor)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Each player's events played and points scored across the rule's series,
+/// the final aside, most events first. An event counts once the player took
+/// their seat or has a result in it. With `club_player_id`, just that
+/// player's tally, if they've played any.
+pub async fn progress<'e>(
+    executor: impl PgExecutor<'e>,
+    rule: &QualificationRuleRow,
+    club_player_id: Option<Uuid>,
+) -> SqlxResult<Vec<QualificationProgressRow>> {
+    sqlx::query_as::<_, QualificationProgressRow>(&format!(
+        "WITH events AS ( \
+            SELECT id FROM tournaments \
+            WHERE series_id = $1 AND id IS DISTINCT FROM $2 AND deleted_at IS NULL), \
+         played AS ( \
+            SELECT r.tournament_id, r.club_player_id FROM tournament_registrations r \
+            JOIN events e ON e.id = r.tournament_id WHERE r.status IN {PLAYED} \
+            UNION \
+            SELECT tr.tournament_id, tr.club_player_id FROM tournament_results tr \
+            JOIN events e ON e.id = tr.tournament_id) \
+         SELECT p.club_player_id, cp.display_name, cp.app_user_id, \
+                COUNT(*) AS events_played, COALESCE(SUM(tr.points), 0)::BIGINT AS points \
+         FROM played p \
+         JOIN club_player_public cp ON cp.id = p.club_player_id \
+         LEFT JOIN tournament_results tr \
+                ON tr.tournament_id = p.tournament_id AND tr.club_player_id = p.club_player_id \
+         WHERE $3::UUID IS NULL OR p.club_player_id = $3 \
+         GROUP BY p.club_player_id, cp.display_name, cp.app_user_id \
+         ORDER BY events_played DESC, points DESC, cp.display_name"
+    ))
+    .bind(rule.series_id)
+    .bind(rule.final_tournament_id)
+    .bind(club_player_id)
+    .fetch_all(executor)
+    .await
+}
@@ -0,0 +1,219 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const RAFFLE_COLS: &str = "id, tournament_id, name, prize, eligibility, winner_count, status, \
+     algorithm, seed, entrants_hash, drawn_at, drawn_by, created_by, created_at, updated_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RaffleRow {
+    pub id: Uuid,
+    pub tournament_id: Uuid,
+    pub name: String,
+    pub prize: Option<String>,
+    /// `registered` | `checked_in` | `active`.
+    pub eligibility: String,
+    pub winner_count: i32,
+    /// `open` | `drawn` | `cancelled`.
+    pub status: String,
+    pub algorithm: Option<String>,
+    pub seed: Option<String>,
+    pub entrants_hash: Option<String>,
+    pub drawn_at: Option<DateTime<Utc>>,
+    pub drawn_by: Option<Uuid>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// One snapshotted entrant of a drawn raffle. `pick` is set for winners.
+#[derive(Debug, Clone, FromRow)]
+pub struct RaffleEntrantRow {
+    pub raffle_id: Uuid,
+    pub registration_id: Uuid,
+    pub position: i32,
+    pub user_id: Option<Uuid>,
+    pub display_name: String,
+    pub pick: Option<i32>,
+}
+
+/// A registration eligible for a draw, before it is snapshotted.
+#[derive(Debug, Clone, FromRow)]
+pub struct EligibleEntrant {
+    pub registration_id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub display_name: String,
+}
+
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    name: &str,
+    prize: Option<&str>,
+    eligibility: &str,
+    winner_count: i32,
+    created_by: Uuid,
+) -> SqlxResult<RaffleRow> {
+    sqlx::query_as::<_, RaffleRow>(&format!(
+        "INSERT INTO raffles (tournament_id, name, prize, eligibility, winner_count, created_by) \
+         VALUES ($1, $2, $3, $4, $5, $6) RETURNING {RAFFLE_COLS}"
+    ))
+    .bind(tournament_id)
+    .bind(name)
+    .bind(prize)
+    .bind(eligibility)
+    .bind(winner_count)
+    .bind(created_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<Option<RaffleRow>> {
+    sqlx::query_as::<_, RaffleRow>(&format!("SELECT {RAFFLE_COLS} FROM raffles WHERE id = $1"))
+        .bind(id)
+        .fetch_optional(executor)
+        .await
+}
+
+/// Lock a raffle row for the duration of a draw transaction.
+pub async fn get_for_update<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<RaffleRow>> {
+    sqlx::query_as::<_, RaffleRow>(&format!(
+        "SELECT {RAFFLE_COLS} FROM raffles WHERE id = $1 FOR UPDATE"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A tournament's raffles, oldest first.
+pub async fn list_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<RaffleRow>> {
+    sqlx::query_as::<_, RaffleRow>(&format!(
+        "SELECT {RAFFLE_COLS} FROM raffles WHERE tournament_id = $1 ORDER BY created_at, id"
+    ))
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Cancel a raffle that has not been drawn yet. `None` when it is not open.
+pub async fn cancel<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<Option<RaffleRow>> {
+    sqlx::query_as::<_, RaffleRow>(&format!(
+        "UPDATE raffles SET status = 'cancelled' WHERE id = $1 AND status = 'open' \
+         RETURNING {RAFFLE_COLS}"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Registrations holding a ticket under `eligibility`, in canonical
+/// (registration id) order — the order the draw indexes into.
+pub async fn eligible_entrants<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    eligibility: &str,
+) -> SqlxResult<Vec<EligibleEntrant>> {
+    let statuses = match eligibility {
+        "active" => "('checked_in', 'seated')",
+        "checked_in" => "('checked_in', 'seated', 'busted')",
+        _ => "('registered', 'checked_in', 'seated', 'busted')",
+    };
+    sqlx::query_as::<_, EligibleEntrant>(&format!(
+        "SELECT tr.id AS registration_id, \
+                COALESCE(tr.user_id, cp.app_user_id) AS user_id, \
+                COALESCE(cp.display_name, u.username, u.first_name, 'Player') AS display_name \
+         FROM tournament_registrations tr \
+         LEFT JOIN club_player cp ON cp.id = tr.club_player_id \
+         LEFT JOIN users u ON u.id = tr.user_id \
+         WHERE tr.tournament_id = $1 AND tr.status IN {statuses} \
+         ORDER BY tr.id"
+    ))
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Persist the entrant snapshot of a draw. `picks[i]` is the pick number of
+/// `entrants[i]` (None for non-winners); positions follow the slice order.
+pub async fn insert_entrants<'e>(
+    executor: impl PgExecutor<'e>,
+    raffle_id: Uuid,
+    entrants: &[EligibleEntrant],
+    picks: &[Option<i32>],
+) -> SqlxResult<()> {
+    let registration_ids: Vec<Uuid> = entrants.iter().map(|e| e.registration_id).collect();
+    let user_ids: Vec<Option<Uuid>> = entrants.iter().map(|e| e.user_id).collect();
+    let names: Vec<String> = entrants.iter().map(|e| e.display_name.clone()).collect();
+    sqlx::query(
+        "INSERT INTO raffle_entrants \
+             (raffle_id, registration_id, position, user_id, display_name, pick) \
+         SELECT $1, e.registration_id, (e.ord - 1)::int, e.user_id, e.display_name, e.pick \
+         FROM UNNEST($2::uuid[], $3::uuid[], $4::text[], $5::int[]) \
+             WITH ORDINALITY AS e(registration_id, user_id, display_name, pick, ord)",
+    )
+    .bind(raffle_id)
+    .bind(&registration_ids)
+    .bind(&user_ids)
+    .bind(&names)
+    .bind(picks)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+/// Mark a raffle drawn and record its audit trail.
+pub async fn mark_drawn<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    algorithm: &str,
+    seed: &str,
+    entrants_hash: &str,
+    drawn_by: Uuid,
+) -> SqlxResult<RaffleRow> {
+    sqlx::query_as::<_, RaffleRow>(&format!(
+        "UPDATE raffles SET status = 'drawn', algorithm = $2, seed = $3, entrants_hash = $4, \
+             drawn_at = NOW(), drawn_by = $5 \
+         WHERE id = $1 RETURNING {RAFFLE_COLS}"
+    ))
+    .bind(id)
+    .bind(algorithm)
+    .bind(seed)
+    .bind(entrants_hash)
+    .bind(drawn_by)
+    .fetch_one(executor)
+    .await
+}
+
+/// The entrant snapshot of a drawn raffle, in canonical order.
+pub async fn list_entrants<'e>(
+    executor: impl PgExecutor<'e>,
+    raffle_id: Uuid,
+) -> SqlxResult<Vec<RaffleEntrantRow>> {
+    sqlx::query_as::<_, RaffleEntrantRow>(
+        "SELECT raffle_id, registration_id, position, user_id, display_name, pick \
+         FROM raffle_entrants WHERE raffle_id = $1 ORDER BY position",
+    )
+    .bind(raffle_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// The winners of a drawn raffle, in pick order.
+pub async fn list_winners<'e>(
+    executor: impl PgExecutor<'e>,
+    raffle_id: Uuid,
+) -> SqlxResult<Vec<RaffleEntrantRow>> {
+    sqlx::query_as::<_, RaffleEntrantRow>(
+        "SELECT raffle_id, registration_id, position, user_id, display_name, pick \
+         FROM raffle_entrants WHERE raffle_id = $1 AND pick IS NOT NULL ORDER BY pick",
+    )
+    .bind(raffle_id)
+    .fetch_all(executor)
+    .await
+}
@@ -0,0 +1,218 @@
+//! Group bookings: friends' registrations linked under a shared code, and the
+//! club's seating settings for them.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgConnection, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const SETTINGS_COLS: &str = "club_id, group_policy, max_group_size, created_at, updated_at";
+const GROUP_COLS: &str = "id, tournament_id, code, created_by, created_at";
+const MEMBER_COLS: &str = "m.group_id, m.registration_id, r.club_player_id, r.user_id, \
+     cp.display_name, r.status, m.joined_at";
+const MEMBER_JOINS: &str = "JOIN tournament_registrations r ON r.id = m.registration_id \
+     JOIN club_player_public cp ON cp.id = r.club_player_id";
+
+/// What the seat draw does with groups, and how big they may be.
+#[derive(Debug, Clone, FromRow)]
+pub struct SeatingSettingsRow {
+    pub club_id: Uuid,
+    /// `together` | `apart`.
+    pub group_policy: String,
+    pub max_group_size: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct GroupRow {
+    pub id: Uuid,
+    pub tournament_id: Uuid,
+    pub code: String,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct MemberRow {
+    pub group_id: Uuid,
+    pub registration_id: Uuid,
+    pub club_player_id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub display_name: String,
+    /// The member's registration status.
+    pub status: String,
+    pub joined_at: DateTime<Utc>,
+}
+
+/// The club's settings, created with the defaults on first use.
+pub async fn get_or_create_settings(
+    conn: &mut PgConnection,
+    club_id: Uuid,
+) -> SqlxResult<SeatingSettingsRow> {
+    super::get_or_insert_default(conn, "club_seating_settings", SETTINGS_COLS, club_id).await
+}
+
+pub async fn upsert_settings<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    group_policy: &str,
+    max_group_size: i32,
+) -> SqlxResult<SeatingSettingsRow> {
+    sqlx::query_as::<_, SeatingSettingsRow>(&format!(
+        "INSERT INTO club_seating_settings (club_id, group_policy, max_group_size) \
+         VALUES ($1, $2, $3) \
+         ON CONFLICT (club_id) DO UPDATE SET \
+            group_policy = EXCLUDED.group_policy, \
+            max_group_size = EXCLUDED.max_group_size \
+         RETURNING {SETTINGS_COLS}"
+    ))
+    .bind(club_id)
+    .bind(group_policy)
+    .bind(max_group_size)
+    .fetch_one(executor)
+    .await
+}
+
+/// The group policy of the tournament's club (`together` when unset).
+pub async fn group_policy_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<String> {
+    sqlx::query_scalar(
+        "SELECT COALESCE(s.group_policy, 'together') FROM tournaments t \
+         LEFT JOIN club_seating_settings s ON s.club_id = t.club_id WHERE t.id = $1",
+    )
+    .bind(tournament_id)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    code: &str,
+    created_by: Uuid,
+) -> SqlxResult<GroupRow> {
+    sqlx::query_as::<_, GroupRow>(&format!(
+        "INSERT INTO registration_groups (tournament_id, code, created_by) \
+         VALUES ($1, $2, $3) RETURNING {GROUP_COLS}"
+    ))
+    .bind(tournament_id)
+    .bind(code)
+    .bind(created_by)
+    .fetch_one(executor)
+    .await
+}
+
+/// Lock the tournament's group with this code so concurrent joins see each
+/// other's members.
+pub async fn lock_by_code<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    code: &str,
+) -> SqlxResult<Option<GroupRow>> {
+    sqlx::query_as::<_, GroupRow>(&format!(
+        "SELECT {GROUP_COLS} FROM registration_groups \
+         WHERE tournament_id = $1 AND code = $2 FOR UPDATE"
+    ))
+    .bind(tournament_id)
+    .bind(code)
+    .fetch_optional(executor)
+    .await
+}
+
+/// The group a registration belongs to, if any.
+pub async fn get_for_registration<'e>(
+    executor: impl PgExecutor<'e>,
+    registration_id: Uuid,
+) -> SqlxResult<Option<GroupRow>> {
+    sqlx::query_as::<_, GroupRow>(&format!(
+        "SELECT {GROUP_COLS} FROM registration_groups \
+         WHERE id = (SELECT group_id FROM registration_group_members WHERE registration_id = $1)"
+    ))
+    .bind(registration_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A tournament's groups, oldest first.
+pub async fn list_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<GroupRow>> {
+    sqlx::query_as::<_, GroupRow>(&format!(
+        "SELECT {GROUP_COLS} FROM registration_groups WHERE tournament_id = $1 \
+         ORDER BY created_at, id"
+    ))
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// A group's members, in joining order.
+pub async fn list_members<'e>(
+    executor: impl PgExecutor<'e>,
+    group_id: Uuid,
+) -> SqlxResult<Vec<MemberRow>> {
+    sqlx::query_as::<_, MemberRow>(&format!(
+        "SELECT {MEMBER_COLS} FROM registration_group_members m {MEMBER_JOINS} \
+         WHERE m.group_id = $1 ORDER BY m.joined_at, m.registration_id"
+    ))
+    .bind(group_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Every group member in the tournament, for the seat draw.
+pub async fn list_members_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<MemberRow>> {
+    sqlx::query_as::<_, MemberRow>(&format!(
+        "SELECT {MEMBER_COLS} FROM registration_group_members m {MEMBER_JOINS} \
+         WHERE r.tournament_id = $1 ORDER BY m.group_id, m.joined_at"
+    ))
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+pub async fn add_member<'e>(
+    executor: impl PgExecutor<'e>,
+    group_id: Uuid,
+    registration_id: Uuid,
+) -> SqlxResult<()> {
+    sqlx::query(
+        "INSERT INTO registration_group_members (group_id, registration_id) VALUES ($1, $2)",
+    )
+    .bind(group_id)
+    .bind(registration_id)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+/// Take a registration out of its group. Returns the group it left.
+pub async fn remove_member<'e>(
+    executor: impl PgExecutor<'e>,
+    registration_id: Uuid,
+) -> SqlxResult<Option<Uuid>> {
+    sqlx::query_scalar(
+        "DELETE FROM registration_group_members WHERE registration_id = $1 RETURNING group_id",
+    )
+    .bind(registration_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Drop a group nobody is left in.
+pub async fn delete_if_empty<'e>(executor: impl PgExecutor<'e>, group_id: Uuid) -> SqlxResult<()> {
+    sqlx::query(
+        "DELETE FROM registration_groups g WHERE g.id = $1 \
+         AND NOT EXISTS (SELECT 1 FROM registration_group_members m WHERE m.group_id = g.id)",
+    )
+    .bind(group_id)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,244 @@
+//! Per-tournament registration form fields (accessibility needs, dinner
+//! choice, ...) and the answers given with each registration, stored as JSONB
+//! typed by the field's kind.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "id, tournament_id, kind, prompt, help_text, options, max_length, is_required, \
+     position, is_active, created_at, updated_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct QuestionRow {
+    pub id: Uuid,
+    pub tournament_id: Uuid,
+    /// text | select | multi_select | checkbox
+    pub kind: String,
+    pub prompt: String,
+    pub help_text: Option<String>,
+    pub options: Vec<String>,
+    /// Longest accepted text answer; `None` uses the default.
+    pub max_length: Option<i32>,
+    pub is_required: bool,
+    pub position: i32,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// An answer with the player it came from.
+#[derive(Debug, Clone, FromRow)]
+pub struct AnswerRow {
+    pub registration_id: Uuid,
+    pub question_id: Uuid,
+    /// The field's kind, which types `answer`.
+    pub kind: String,
+    /// A string (text, select), an array of strings (multi-select) or a
+    /// boolean (checkbox).
+    pub answer: serde_json::Value,
+    pub player_name: String,
+    pub answered_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateQuestion {
+    pub tournament_id: Uuid,
+    pub kind: String,
+    pub prompt: String,
+    pub help_text: Option<String>,
+    pub options: Vec<String>,
+    pub max_length: Option<i32>,
+    pub is_required: bool,
+    pub position: i32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdateQuestion {
+    pub prompt: Option<String>,
+    pub help_text: Option<String>,
+    pub options: Option<Vec<String>>,
+    pub max_length: Option<i32>,
+    pub is_required: Option<bool>,
+    pub position: Option<i32>,
+}
+
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    data: CreateQuestion,
+) -> SqlxResult<QuestionRow> {
+    sqlx::query_as::<_, QuestionRow>(&format!(
+        "INSERT INTO registration_questions \
+         (tournament_id, kind, prompt, help_text, options, max_length, is_required, position) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING {COLS}"
+    ))
+    .bind(data.tournament_id)
+    .bind(data.kind)
+    .bind(data.prompt)
+    .bind(data.help_text)
+    .bind(data.options)
+    .bind(data.max_length)
+    .bind(data.is_required)
+    .bind(data.position)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<QuestionRow>> {
+    sqlx::query_as::<_, QuestionRow>(&format!(
+        "SELECT {COLS} FROM registration_questions WHERE id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+pub async fn update<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    data: UpdateQuestion,
+) -> SqlxResult<Option<QuestionRow>> {
+    sqlx::query_as::<_, QuestionRow>(&format!(
+        "UPDATE registration_questions SET \
+            prompt = COALESCE($2, prompt), \
+            help_text = COALESCE($3, help_text), \
+            options = COALESCE($4, options), \
+            max_length = COALESCE($5, max_length), \
+            is_required = COALESCE($6, is_required), \
+            position = COALESCE($7, position) \
+         WHERE id = $1 RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(data.prompt)
+    .bind(data.help_text)
+    .bind(data.options)
+    .bind(data.max_length)
+    .bind(data.is_required)
+    .bind(data.position)
+    .fetch_optional(executor)
+    .await
+}
+
+pub async fn set_active<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    is_active: bool,
+) -> SqlxResult<Option<QuestionRow>> {
+    sqlx::query_as::<_, QuestionRow>(&format!(
+        "UPDATE registration_questions SET is_active = $2 WHERE id = $1 RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(is_active)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A tournament's questions in display order.
+pub async fn list_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    active_only: bool,
+) -> SqlxResult<Vec<QuestionRow>> {
+    sqlx::query_as::<_, QuestionRow>(&format!(
+        "SELECT {COLS} FROM registration_questions \
+         WHERE tournament_id = $1 AND (NOT $2 OR is_active) \
+         ORDER BY position, created_at"
+    ))
+    .bind(tournament_id)
+    .bind(active_only)
+    .fetch_all(executor)
+    .await
+}
+
+/// Store a registration's answer to a question, replacing an earlier one.
+pub async fn upsert_answer<'e>(
+    executor: impl PgExecutor<'e>,
+    registration_id: Uuid,
+    question_id: Uuid,
+    answer: &serde_json::Value,
+) -> SqlxResult<()> {
+    sqlx::query(
+        "INSERT INTO registration_answers (registration_id, question_id, answer) \
+         VALUES ($1, $2, $3) \
+         ON CONFLICT (registration_id, question_id) DO UPDATE SET \
+            answer = EXCLUDED.answer, answered_at = NOW()",
+    )
+    .bind(registration_id)
+    .bind(question_id)
+    .bind(answer)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+/// Clear a registration's answer to a question.
+pub async fn delete_answer<'e>(
+    executor: impl PgExecutor<'e>,
+    registration_id: Uuid,
+    question_id: Uuid,
+) -> SqlxResult<()> {
+    sqlx::query("DELETE FROM registration_answers WHERE registration_id = $1 AND question_id = $2")
+        .bind(registration_id)
+        .bind(question_id)
+        .execute(executor)
+        .await?;
+    Ok(())
+}
+
+/// One registration's answers.
+pub async fn list_answers_for_registration<'e>(
+    executor: impl PgExecutor<'e>,
+    registration_id: Uuid,
+) -> SqlxResult<Vec<AnswerRow>> {
+    sqlx::query_as::<_, AnswerRow>(
+        "SELECT a.registration_id, a.question_id, q.kind, a.answer, \
+                cp.display_name AS player_name, a.answered_at \
+         FROM registration_answers a \
+         JOIN registration_questions q ON q.id = a.question_id \
+         JOIN tournament_registrations r ON r.id = a.registration_id \
+         JOIN club_player cp ON cp.id = r.club_player_id \
+         WHERE a.registration_id = $1 \
+         ORDER BY q.position, q.created_at",
+    )
+    .bind(registration_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// The answers of every registration still in the tournament (cancelled ones
+/// are left out), by player name then field order.
+pub async fn list_answers_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<AnswerRow>> {
+    sqlx::query_as::<_, AnswerRow>(
+        "SELECT a.registration_id, a.question_id, q.kind, a.answer, \
+                cp.display_name AS player_name, a.answered_at \
+         FROM registration_answers a \
+         JOIN registration_questions q ON q.id = a.question_id \
+         JOIN tournament_registrations r ON r.id = a.registration_id \
+         JOIN club_player cp ON cp.id = r.club_player_id \
+         WHERE r.tournament_id = $1 AND r.status <> 'cancelled' \
+         ORDER BY cp.display_name, a.registration_id, q.position, q.created_at",
+    )
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// How many registrations are still in the tournament.
+pub async fn count_live_registrations<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<i64> {
+    sqlx::query_scalar(
+        "SELECT COUNT(*) FROM tournament_registrations \
+         WHERE tournament_id = $1 AND status <> 'cancelled'",
+    )
+    .bind(tournament_id)
+    .fetch_one(executor)
+    .await
+}
@@ -0,0 +1,305 @@
+//! Per-club retention of operational records: the policies, what they make
+//! due, and applying them. Three kinds of record are covered, each aged by
+//! its own timestamp:
+//!
+//! - `activity_log`: tournament activity, except money-related categories
+//!   ([`FINANCIAL_CATEGORIES`]), which back the club's accounts and are kept;
+//! - `announcements`: club and tournament broadcasts once sent (scheduled
+//!   ones still pending are never due);
+//! - `chat_messages`: tournament chat.
+//!
+//! `anonymize` keeps the rows but drops who they were by and about;
+//! `purge` deletes them. Already-anonymized rows are not due again.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Postgres, Result as SqlxResult, Transaction};
+use uuid::Uuid;
+
+/// Activity-log categories never expired: buy-ins, rebuys, add-ons and
+/// payouts.
+pub const FINANCIAL_CATEGORIES: &[&str] = &["entry", "result"];
+
+/// Activity-log metadata keys that identify a player.
+const PERSONAL_METADATA_KEYS: &[&str] = &[
+    "club_player_id",
+    "user_id",
+    "user_ids",
+    "victim_user_id",
+    "player_name",
+    "display_name",
+    "name",
+];
+
+/// Chat bodies are replaced with this when anonymized.
+pub const REMOVED_CHAT_BODY: &str = "[removed]";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RetentionPolicyRow {
+    pub club_id: Uuid,
+    /// `anonymize` | `purge`
+    pub mode: String,
+    pub activity_log_months: Option<i32>,
+    pub announcement_months: Option<i32>,
+    pub chat_message_months: Option<i32>,
+    pub last_applied_at: Option<DateTime<Utc>>,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SetRetentionPolicy {
+    pub mode: String,
+    pub activity_log_months: Option<i32>,
+    pub announcement_months: Option<i32>,
+    pub chat_message_months: Option<i32>,
+}
+
+/// A kind of record a policy expires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetainedRecord {
+    ActivityLog,
+    Announcements,
+    ChatMessages,
+}
+
+impl RetainedRecord {
+    pub const ALL: [RetainedRecord; 3] = [
+        RetainedRecord::ActivityLog,
+        RetainedRecord::Announcements,
+        RetainedRecord::ChatMessages,
+    ];
+
+    /// The policy's window for this kind, in months.
+    pub fn months(self, policy: &RetentionPolicyRow) -> Option<i32> {
+        match self {
+            RetainedRecord::ActivityLog => policy.activity_log_months,
+            RetainedRecord::Announcements => policy.announcement_months,
+            RetainedRecord::ChatMessages => policy.chat_message_months,
+        }
+    }
+
+    /// `FROM … WHERE …` selecting the club's (`$1`) rows older than `$2`
+    /// months that the given mode would still change.
+    fn due(self, purge: bool) -> String {
+        match self {
+            RetainedRecord::ActivityLog => format!(
+                "FROM tournament_activity_log l \
+                 JOIN tournaments t ON t.id = l.tournament_id \
+                 WHERE t.club_id = $1 \
+                   AND l.event_time < NOW() - make_interval(months => $2) \
+                   AND l.event_category <> ALL({}){}",
+                sql_array(FINANCIAL_CATEGORIES),
+                if purge {
+                    String::new()
+                } else {
+                    format!(
+                        " AND (l.actor_id IS NOT NULL OR l.subject_id IS NOT NULL \
+                           OR COALESCE(l.metadata, '{{}}'::jsonb) ?| {})",
+                        sql_array(PERSONAL_METADATA_KEYS)
+                    )
+                }
+            ),
+            RetainedRecord::Announcements => format!(
+                "FROM announcements a \
+                 WHERE a.club_id = $1 \
+                   AND a.created_at < NOW() - make_interval(months => $2) \
+                   AND NOT (a.sent_at IS NULL AND a.scheduled_for IS NOT NULL){}",
+                if purge {
+                    ""
+                } else {
+                    " AND a.created_by IS NOT NULL"
+                }
+            ),
+            RetainedRecord::ChatMessages => format!(
+                "FROM tournament_chat_messages m \
+                 JOIN tournaments t ON t.id = m.tournament_id \
+                 WHERE t.club_id = $1 \
+                   AND m.created_at < NOW() - make_interval(months => $2){}",
+                if purge {
+                    String::new()
+                } else {
+                    format!(" AND (m.user_id IS NOT NULL OR m.body <> '{REMOVED_CHAT_BODY}')")
+                }
+            ),
+        }
+    }
+
+    fn alias(self) -> &'static str {
+        match self {
+            RetainedRecord::ActivityLog => "l",
+            RetainedRecord::Announcements => "a",
+            RetainedRecord::ChatMessages => "m",
+        }
+    }
+
+    fn table(self) -> &'static str {
+        match self {
+            RetainedRecord::ActivityLog => "tournament_activity_log",
+            RetainedRecord::Announcements => "announcements",
+            RetainedRecord::ChatMessages => "tournament_chat_messages",
+        }
+    }
+
+    fn anonymize_set(self) -> String {
+        match self {
+            RetainedRecord::ActivityLog => format!(
+                "actor_id = NULL, subject_id = NULL, \
+                 metadata = COALESCE(metadata, '{{}}'::jsonb) - {}",
+                sql_array(PERSONAL_METADATA_KEYS)
+            ),
+            RetainedRecord::Announcements => "created_by = NULL".to_string(),
+            RetainedRecord::ChatMessages => format!(
+                "user_id = NULL, body = '{REMOVED_CHAT_BODY}', \
+                 deleted_at = COALESCE(deleted_at, NOW())"
+            ),
+        }
+    }
+}
+
+/// A constant `ARRAY['a', 'b']::text[]` literal of fixed identifiers.
+fn sql_array(items: &[&str]) -> String {
+    let quoted: Vec<String> = items.iter().map(|i| format!("'{i}'")).collect();
+    format!("ARRAY[{}]::text[]", quoted.join(", "))
+}
+
+pub async fn get_policy<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+) -> SqlxResult<Option<RetentionPolicyRow>> {
+    sqlx::query_as::<_, RetentionPolicyRow>(
+        "SELECT club_id, mode, activity_log_months, announcement_months, chat_message_months, \
+                last_applied_at, updated_by, updated_at \
+         FROM club_retention_policies WHERE club_id = $1",
+    )
+    .bind(club_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Policies with at least one window set.
+pub async fn list_active_policies<'e>(
+    executor: impl PgExecutor<'e>,
+) -> SqlxResult<Vec<RetentionPolicyRow>> {
+    sqlx::query_as::<_, RetentionPolicyRow>(
+        "SELECT club_id, mode, activity_log_months, announcement_months, chat_message_months, \
+                last_applied_at, updated_by, updated_at \
+         FROM club_retention_policies \
+         WHERE COALESCE(activity_log_months, announcement_months, chat_message_months) IS NOT NULL \
+         ORDER BY last_applied_at NULLS FIRST, club_id",
+    )
+    .fetch_all(executor)
+    .await
+}
+
+pub async fn upsert_policy<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    data: &SetRetentionPolicy,
+    updated_by: Uuid,
+) -> SqlxResult<RetentionPolicyRow> {
+    sqlx::query_as::<_, RetentionPolicyRow>(
+        r#"
+        INSERT INTO club_retention_policies
+            (club_id, mode, activity_log_months, announcement_months, chat_message_months, updated_by)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (club_id) DO UPDATE SET
+            mode = EXCLUDED.mode,
+            activity_log_months = EXCLUDED.activity_log_months,
+            announcement_months = EXCLUDED.announcement_months,
+            chat_message_months = EXCLUDED.chat_message_months,
+            updated_by = EXCLUDED.updated_by,
+            updated_at = NOW()
+        RETURNING club_id, mode, activity_log_months, announcement_months, chat_message_months,
+                  last_applied_at, updated_by, updated_at
+        "#,
+    )
+    .bind(club_id)
+    .bind(&data.mode)
+    .bind(data.activity_log_months)
+    .bind(data.announcement_months)
+    .bind(data.chat_message_months)
+    .bind(updated_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn mark_applied<'e>(executor: impl PgExecutor<'e>, club_id: Uuid) -> SqlxResult<()> {
+    sqlx::query("UPDATE club_retention_policies SET last_applied_at = NOW() WHERE club_id = $1")
+        .bind(club_id)
+        .execute(executor)
+        .await?;
+    Ok(())
+}
+
+/// Rows of `record` older than `months` the mode would still change.
+pub async fn count_due<'e>(
+    executor: impl PgExecutor<'e>,
+    record: RetainedRecord,
+    club_id: Uuid,
+    months: i32,
+    purge: bool,
+) -> SqlxResult<i64> {
+    sqlx::query_scalar(&format!("SELECT COUNT(*) {}", record.due(purge)))
+        .bind(club_id)
+        .bind(months)
+        .fetch_one(executor)
+        .await
+}
+
+/// Money-related activity older than `months`, which the policy keeps.
+pub async fn count_exempt_activity<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    months: i32,
+) -> SqlxResult<i64> {
+    sqlx::query_scalar(&format!(
+        "SELECT COUNT(*) FROM tournament_activity_log l \
+         JOIN tournaments t ON t.id = l.tournament_id \
+         WHERE t.club_id = $1 \
+           AND l.event_time < NOW() - make_interval(months => $2) \
+           AND l.event_category = ANY({})",
+        sql_array(FINANCIAL_CATEGORIES)
+    ))
+    .bind(club_id)
+    .bind(months)
+    .fetch_one(executor)
+    .await
+}
+
+/// Anonymize or purge up to `limit` due rows of `record`; returns how many
+/// were changed. Opens the activity log's append-only guard for this
+/// transaction only.
+pub async fn apply(
+    tx: &mut Transaction<'_, Postgres>,
+    record: RetainedRecord,
+    club_id: Uuid,
+    months: i32,
+    purge: bool,
+    limit: i64,
+) -> SqlxResult<u64> {
+    sqlx::query("SELECT set_config('app.retention_sweep', 'on', true)")
+        .execute(&mut **tx)
+        .await?;
+
+    let alias = record.alias();
+    let due_ids = format!(
+        "SELECT {alias}.id {} ORDER BY {alias}.id LIMIT $3",
+        record.due(purge)
+    );
+    let sql = if purge {
+        format!("DELETE FROM {} WHERE id IN ({due_ids})", record.table())
+    } else {
+        format!(
+            "UPDATE {} SET {} WHERE id IN ({due_ids})",
+            record.table(),
+            record.anonymize_set()
+        )
+    };
+    let result = sqlx::query(&sql)
+        .bind(club_id)
+        .bind(months)
+        .bind(limit)
+        .execute(&mut **tx)
+        .await?;
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,202 @@
+//! Club/tournament rule documents and disclosures, and the acknowledgments
+//! players give when registering.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "id, club_id, tournament_id, kind, title, body_markdown, version, requires_acknowledgment, is_active, created_by, created_at, updated_at";
+
+const ACK_COLS: &str =
+    "id, rule_document_id, document_version, tournament_id, user_id, acknowledged_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RuleDocumentRow {
+    pub id: Uuid,
+    pub club_id: Uuid,
+    /// `None` = applies to every tournament at the club.
+    pub tournament_id: Option<Uuid>,
+    /// rules | disclosure
+    pub kind: String,
+    pub title: String,
+    pub body_markdown: String,
+    pub version: i32,
+    pub requires_acknowledgment: bool,
+    pub is_active: bool,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RuleAcknowledgmentRow {
+    pub id: Uuid,
+    pub rule_document_id: Uuid,
+    pub document_version: i32,
+    pub tournament_id: Uuid,
+    pub user_id: Uuid,
+    pub acknowledged_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateRuleDocument {
+    pub club_id: Uuid,
+    pub tournament_id: Option<Uuid>,
+    pub kind: String,
+    pub title: String,
+    pub body_markdown: String,
+    pub requires_acknowledgment: bool,
+    pub created_by: Uuid,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdateRuleDocument {
+    pub title: Option<String>,
+    pub body_markdown: Option<String>,
+    pub requires_acknowledgment: Option<bool>,
+}
+
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    data: CreateRuleDocument,
+) -> SqlxResult<RuleDocumentRow> {
+    sqlx::query_as::<_, RuleDocumentRow>(&format!(
+        "INSERT INTO rule_documents \
+         (club_id, tournament_id, kind, title, body_markdown, requires_acknowledgment, created_by) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING {COLS}"
+    ))
+    .bind(data.club_id)
+    .bind(data.tournament_id)
+    .bind(data.kind)
+    .bind(data.title)
+    .bind(data.body_markdown)
+    .bind(data.requires_acknowledgment)
+    .bind(data.created_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<RuleDocumentRow>> {
+    sqlx::query_as::<_, RuleDocumentRow>(&format!(
+        "SELECT {COLS} FROM rule_documents WHERE id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Apply an edit. A changed title or body starts a new version.
+pub async fn update<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    data: UpdateRuleDocument,
+) -> SqlxResult<Option<RuleDocumentRow>> {
+    sqlx::query_as::<_, RuleDocumentRow>(&format!(
+        "UPDATE rule_documents SET \
+            version = version + CASE \
+                WHEN COALESCE($2, title) IS DISTINCT FROM title \
+                  OR COALESCE($3, body_markdown) IS DISTINCT FROM body_markdown \
+                THEN 1 ELSE 0 END, \
+            title = COALESCE($2, title), \
+            body_markdown = COALESCE($3, body_markdown), \
+            requires_acknowledgment = COALESCE($4, requires_acknowledgment) \
+         WHERE id = $1 RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(data.title)
+    .bind(data.body_markdown)
+    .bind(data.requires_acknowledgment)
+    .fetch_optional(executor)
+    .await
+}
+
+pub async fn set_active<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    is_active: bool,
+) -> SqlxResult<Option<RuleDocumentRow>> {
+    sqlx::query_as::<_, RuleDocumentRow>(&format!(
+        "UPDATE rule_documents SET is_active = $2 WHERE id = $1 RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(is_active)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A club's documents (club-wide and per-tournament), newest first.
+pub async fn list_by_club<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    active_only: bool,
+) -> SqlxResult<Vec<RuleDocumentRow>> {
+    sqlx::query_as::<_, RuleDocumentRow>(&format!(
+        "SELECT {COLS} FROM rule_documents \
+         WHERE club_id = $1 AND (NOT $2 OR is_active) \
+         ORDER BY created_at DESC"
+    ))
+    .bind(club_id)
+    .bind(active_only)
+    .fetch_all(executor)
+    .await
+}
+
+/// The active documents that apply to a tournament: the club's club-wide
+/// documents followed by the tournament's own.
+pub async fn list_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<RuleDocumentRow>> {
+    sqlx::query_as::<_, RuleDocumentRow>(&format!(
+        "SELECT {COLS} FROM rule_documents \
+         WHERE is_active \
+           AND (tournament_id = $1 \
+                OR (tournament_id IS NULL \
+                    AND club_id = (SELECT club_id FROM tournaments WHERE id = $1))) \
+         ORDER BY tournament_id NULLS FIRST, created_at ASC"
+    ))
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Record that a player acknowledged a document version for a tournament.
+/// Re-acknowledging the same version is a no-op.
+pub async fn acknowledge<'e>(
+    executor: impl PgExecutor<'e>,
+    rule_document_id: Uuid,
+    document_version: i32,
+    tournament_id: Uuid,
+    user_id: Uuid,
+) -> SqlxResult<()> {
+    sqlx::query(
+        "INSERT INTO rule_acknowledgments \
+         (rule_document_id, document_version, tournament_id, user_id) \
+         VALUES ($1, $2, $3, $4) \
+         ON CONFLICT (rule_document_id, document_version, tournament_id, user_id) DO NOTHING",
+    )
+    .bind(rule_document_id)
+    .bind(document_version)
+    .bind(tournament_id)
+    .bind(user_id)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+/// Every acknowledgment recorded for a tournament, oldest first.
+pub async fn list_acknowledgments_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<RuleAcknowledgmentRow>> {
+    sqlx::query_as::<_, RuleAcknowledgmentRow>(&format!(
+        "SELECT {ACK_COLS} FROM rule_acknowledgments \
+         WHERE tournament_id = $1 ORDER BY acknowledged_at ASC"
+    ))
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
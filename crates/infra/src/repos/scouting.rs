@@ -13,7 +13,7 @@ pub async fn search_pool<'e>(
     let pattern = format!("%{query}%");
     sqlx::query_as::<_, ScoutingMatchRow>(
         "SELECT u.id AS user_id, COALESCE(u.username, u.first_name) AS handle \
-         FROM users u \
+         FROM users_public u \
          JOIN user_privacy_settings ups ON ups.app_user_id = u.id \
          WHERE ups.in_scouting_pool \
            AND (u.username ILIKE $1 OR u.first_name ILIKE $1) \
@@ -34,7 +34,7 @@ pub async fn profile_stats<'e>(
         "SELECT COUNT(*) AS tournaments, \
                 COALESCE(SUM(CASE WHEN tr.prize_cents > 0 THEN 1 ELSE 0 END), 0) AS itm_count, \
                 MIN(tr.final_position) AS best_finish, \
-                COALESCE(SUM(tr.prize_cents), 0) - COALESCE(SUM(t.buy_in_cents), 0) AS net_cents \
+                COALESCE(SUM(tr.prize_cents), 0)::bigint - COALESCE(SUM(t.buy_in_cents), 0)::bigint AS net_cents \
          FROM tournament_results tr JOIN tournaments t ON t.id = tr.tournament_id \
          WHERE tr.user_id = $1",
     )
@@ -50,7 +50,7 @@ pub async fn pool_handle<'e>(
 ) -> SqlxResult<Option<String>> {
     let row: Option<(String,)> = sqlx::query_as(
         "SELECT COALESCE(u.username, u.first_name) AS handle \
-         FROM users u JOIN user_privacy_settings ups ON ups.app_user_id = u.id \
+         FROM users_public u JOIN user_privacy_settings ups ON ups.app_user_id = u.id \
          WHERE u.id = $1 AND ups.in_scouting_pool",
     )
     .bind(target_id)
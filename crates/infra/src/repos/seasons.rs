@@ -149,7 +149,7 @@ pub async fn champion_for_window<'e>(
         "SELECT ci.app_user_id AS app_user_id, \
                 COALESCE(u.username, u.first_name) AS champion_name, \
                 COUNT(*) AS events \
-         FROM check_in ci JOIN users u ON u.id = ci.app_user_id \
+         FROM check_in ci JOIN users_public u ON u.id = ci.app_user_id \
          WHERE ci.club_id = $1 AND ci.checked_in_at >= $2 AND ci.checked_in_at < $3 \
          GROUP BY ci.app_user_id, u.username, u.first_name \
          ORDER BY events DESC, champion_name ASC LIMIT 1",
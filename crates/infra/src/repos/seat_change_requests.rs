@@ -0,0 +1,109 @@
+//! Player-initiated seat change requests and their resolution by the floor.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "id, tournament_id, club_player_id, user_id, reason, from_club_table_id, from_seat_number, status, decline_reason, resolved_by, resolved_at, created_at, updated_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct SeatChangeRequestRow {
+    pub id: Uuid,
+    pub tournament_id: Uuid,
+    pub club_player_id: Uuid,
+    pub user_id: Uuid,
+    pub reason: String,
+    pub from_club_table_id: Uuid,
+    pub from_seat_number: i32,
+    /// pending | approved | declined
+    pub status: String,
+    pub decline_reason: Option<String>,
+    pub resolved_by: Option<Uuid>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateSeatChangeRequest {
+    pub tournament_id: Uuid,
+    pub club_player_id: Uuid,
+    pub user_id: Uuid,
+    pub reason: String,
+    pub from_club_table_id: Uuid,
+    pub from_seat_number: i32,
+}
+
+/// Open a request. Returns `None` if the player already has a pending one.
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    data: CreateSeatChangeRequest,
+) -> SqlxResult<Option<SeatChangeRequestRow>> {
+    sqlx::query_as::<_, SeatChangeRequestRow>(&format!(
+        "INSERT INTO seat_change_requests \
+         (tournament_id, club_player_id, user_id, reason, from_club_table_id, from_seat_number) \
+         VALUES ($1, $2, $3, $4, $5, $6) \
+         ON CONFLICT (tournament_id, club_player_id) WHERE status = 'pending' DO NOTHING \
+         RETURNING {COLS}"
+    ))
+    .bind(data.tournament_id)
+    .bind(data.club_player_id)
+    .bind(data.user_id)
+    .bind(data.reason)
+    .bind(data.from_club_table_id)
+    .bind(data.from_seat_number)
+    .fetch_optional(executor)
+    .await
+}
+
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<SeatChangeRequestRow>> {
+    sqlx::query_as::<_, SeatChangeRequestRow>(&format!(
+        "SELECT {COLS} FROM seat_change_requests WHERE id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A tournament's requests, oldest first (queue order). `status = None` lists all.
+pub async fn list_by_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    status: Option<&str>,
+) -> SqlxResult<Vec<SeatChangeRequestRow>> {
+    sqlx::query_as::<_, SeatChangeRequestRow>(&format!(
+        "SELECT {COLS} FROM seat_change_requests \
+         WHERE tournament_id = $1 AND ($2::text IS NULL OR status = $2) \
+         ORDER BY created_at ASC"
+    ))
+    .bind(tournament_id)
+    .bind(status)
+    .fetch_all(executor)
+    .await
+}
+
+/// Close a pending request as `approved` or `declined`. Returns `None` if the
+/// request is no longer pending (already resolved by someone else).
+pub async fn resolve<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    status: &str,
+    decline_reason: Option<&str>,
+    resolved_by: Uuid,
+) -> SqlxResult<Option<SeatChangeRequestRow>> {
+    sqlx::query_as::<_, SeatChangeRequestRow>(&format!(
+        "UPDATE seat_change_requests \
+         SET status = $2, decline_reason = $3, resolved_by = $4, resolved_at = NOW() \
+         WHERE id = $1 AND status = 'pending' \
+         RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(status)
+    .bind(decline_reason)
+    .bind(resolved_by)
+    .fetch_optional(executor)
+    .await
+}
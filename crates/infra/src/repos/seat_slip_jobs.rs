@@ -0,0 +1,141 @@
+//! The seat slip print queue. Jobs are queued by a trigger on every new seat
+//! assignment; the desk's print agent claims them, prints and reports back.
+
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "id, club_id, tournament_id, seat_assignment_id, tournament_name, \
+                    player_name, table_number, seat_number, status, reprint_of, requested_by, \
+                    attempts, error, created_at, claimed_at, printed_at";
+
+/// How long a claimed job may go unreported before another claim takes it
+/// over, e.g. after the agent crashed mid-print.
+pub const CLAIM_TIMEOUT: Duration = Duration::minutes(2);
+
+#[derive(Debug, Clone, FromRow)]
+pub struct SeatSlipJobRow {
+    pub id: Uuid,
+    pub club_id: Uuid,
+    pub tournament_id: Uuid,
+    /// Null once the seat assignment is gone.
+    pub seat_assignment_id: Option<Uuid>,
+    pub tournament_name: String,
+    pub player_name: String,
+    pub table_number: i32,
+    pub seat_number: i32,
+    /// `pending`, `printing`, `printed` or `failed`.
+    pub status: String,
+    /// The job this one reprints.
+    pub reprint_of: Option<Uuid>,
+    pub requested_by: Option<Uuid>,
+    pub attempts: i32,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub claimed_at: Option<DateTime<Utc>>,
+    pub printed_at: Option<DateTime<Utc>>,
+}
+
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<SeatSlipJobRow>> {
+    sqlx::query_as::<_, SeatSlipJobRow>(&format!(
+        "SELECT {COLS} FROM seat_slip_print_jobs WHERE id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A tournament's jobs, newest first, optionally of one status.
+pub async fn list_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    status: Option<&str>,
+) -> SqlxResult<Vec<SeatSlipJobRow>> {
+    sqlx::query_as::<_, SeatSlipJobRow>(&format!(
+        "SELECT {COLS} FROM seat_slip_print_jobs \
+         WHERE tournament_id = $1 AND ($2::TEXT IS NULL OR status = $2) \
+         ORDER BY created_at DESC, id"
+    ))
+    .bind(tournament_id)
+    .bind(status)
+    .fetch_all(executor)
+    .await
+}
+
+/// Hand the oldest `limit` printable jobs of the club to an agent: pending
+/// ones, and claimed ones whose agent went quiet for [`CLAIM_TIMEOUT`].
+/// Concurrent agents never get the same job.
+pub async fn claim<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    limit: i64,
+) -> SqlxResult<Vec<SeatSlipJobRow>> {
+    let now = Utc::now();
+    sqlx::query_as::<_, SeatSlipJobRow>(&format!(
+        "WITH next AS ( \
+            SELECT id AS next_id FROM seat_slip_print_jobs \
+            WHERE club_id = $1 \
+              AND (status = 'pending' OR (status = 'printing' AND claimed_at < $3)) \
+            ORDER BY created_at, id \
+            LIMIT $2 \
+            FOR UPDATE SKIP LOCKED) \
+         UPDATE seat_slip_print_jobs \
+         SET status = 'printing', claimed_at = $4, attempts = attempts + 1, error = NULL \
+         FROM next WHERE id = next_id \
+         RETURNING {COLS}"
+    ))
+    .bind(club_id)
+    .bind(limit)
+    .bind(now - CLAIM_TIMEOUT)
+    .bind(now)
+    .fetch_all(executor)
+    .await
+    .map(|mut rows| {
+        rows.sort_by_key(|r| (r.created_at, r.id));
+        rows
+    })
+}
+
+/// Record how a claimed job went: printed, or failed with `error`. `None`
+/// unless the job is claimed.
+pub async fn complete<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    error: Option<&str>,
+) -> SqlxResult<Option<SeatSlipJobRow>> {
+    sqlx::query_as::<_, SeatSlipJobRow>(&format!(
+        "UPDATE seat_slip_print_jobs \
+         SET status = CASE WHEN $2::TEXT IS NULL THEN 'printed' ELSE 'failed' END, \
+             error = $2, \
+             printed_at = CASE WHEN $2::TEXT IS NULL THEN NOW() END \
+         WHERE id = $1 AND status = 'printing' RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(error)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Queue a copy of a slip, with its original snapshot.
+pub async fn reprint<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    requested_by: Option<Uuid>,
+) -> SqlxResult<Option<SeatSlipJobRow>> {
+    sqlx::query_as::<_, SeatSlipJobRow>(&format!(
+        "INSERT INTO seat_slip_print_jobs \
+            (club_id, tournament_id, seat_assignment_id, tournament_name, player_name, \
+             table_number, seat_number, reprint_of, requested_by) \
+         SELECT club_id, tournament_id, seat_assignment_id, tournament_name, player_name, \
+                table_number, seat_number, id, $2 \
+         FROM seat_slip_print_jobs WHERE id = $1 \
+         RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(requested_by)
+    .fetch_optional(executor)
+    .await
+}
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+/// Mark a challenge redeemed. False when it already was.
+pub async fn spend(
+    pool: &PgPool,
+    challenge_hash: &str,
+    expires_at: DateTime<Utc>,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        "INSERT INTO spent_challenges (challenge_hash, expires_at) VALUES ($1, $2) \
+         ON CONFLICT (challenge_hash) DO NOTHING",
+    )
+    .bind(challenge_hash)
+    .bind(expires_at)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Forget challenges that have expired: they can't be redeemed anyway.
+pub async fn delete_expired(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM spent_challenges WHERE expires_at < now()")
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected())
+}
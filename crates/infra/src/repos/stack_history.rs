@@ -0,0 +1,85 @@
+//! Append-only chip-count history for seated players.
+//!
+//! The live stack stays on `table_seat_assignments.stack_size`; every change
+//! to it also lands here so break counts can be audited after the fact.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str =
+    "id, tournament_id, club_player_id, previous_stack, stack_size, source, recorded_by, created_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct StackHistoryRow {
+    pub id: Uuid,
+    pub tournament_id: Uuid,
+    pub club_player_id: Uuid,
+    pub previous_stack: Option<i32>,
+    pub stack_size: i32,
+    pub source: String,
+    pub recorded_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Where a recorded stack came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StackSource {
+    /// A single stack edited by the floor.
+    Manual,
+    /// Part of a bulk count entered at a break.
+    BreakCount,
+    /// Odd chips settled when a denomination was colored up.
+    ColorUp,
+}
+
+impl StackSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StackSource::Manual => "manual",
+            StackSource::BreakCount => "break_count",
+            StackSource::ColorUp => "color_up",
+        }
+    }
+}
+
+pub async fn record<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    club_player_id: Uuid,
+    previous_stack: Option<i32>,
+    stack_size: i32,
+    source: StackSource,
+    recorded_by: Option<Uuid>,
+) -> SqlxResult<StackHistoryRow> {
+    sqlx::query_as::<_, StackHistoryRow>(&format!(
+        "INSERT INTO tournament_stack_history \
+         (tournament_id, club_player_id, previous_stack, stack_size, source, recorded_by) \
+         VALUES ($1, $2, $3, $4, $5, $6) RETURNING {COLS}"
+    ))
+    .bind(tournament_id)
+    .bind(club_player_id)
+    .bind(previous_stack)
+    .bind(stack_size)
+    .bind(source.as_str())
+    .bind(recorded_by)
+    .fetch_one(executor)
+    .await
+}
+
+/// A player's stack history in a tournament, oldest first.
+pub async fn list_for_player<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    club_player_id: Uuid,
+) -> SqlxResult<Vec<StackHistoryRow>> {
+    sqlx::query_as::<_, StackHistoryRow>(&format!(
+        "SELECT {COLS} FROM tournament_stack_history \
+         WHERE tournament_id = $1 AND club_player_id = $2 \
+         ORDER BY created_at, id"
+    ))
+    .bind(tournament_id)
+    .bind(club_player_id)
+    .fetch_all(executor)
+    .await
+}
@@ -0,0 +1,147 @@
+//! Staff time clock: one row per shift, open until clocked out.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "id, club_id, staff_id, tournament_id, clock_in_at, clock_out_at, hourly_rate_cents, notes, recorded_by, created_at, updated_at";
+
+/// Cost of a closed shift (aliased `sh`) in cents, rounded to the nearest
+/// cent. NULL rate = 0.
+pub(crate) const SHIFT_COST_SQL: &str =
+    "ROUND(EXTRACT(EPOCH FROM (sh.clock_out_at - sh.clock_in_at)) \
+     * COALESCE(sh.hourly_rate_cents, 0) / 3600)";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct StaffShiftRow {
+    pub id: Uuid,
+    pub club_id: Uuid,
+    pub staff_id: Uuid,
+    pub tournament_id: Option<Uuid>,
+    pub clock_in_at: DateTime<Utc>,
+    pub clock_out_at: Option<DateTime<Utc>>,
+    pub hourly_rate_cents: Option<i64>,
+    pub notes: Option<String>,
+    pub recorded_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClockIn {
+    pub club_id: Uuid,
+    pub staff_id: Uuid,
+    pub tournament_id: Option<Uuid>,
+    pub hourly_rate_cents: Option<i64>,
+    pub notes: Option<String>,
+    pub recorded_by: Option<Uuid>,
+}
+
+/// Hours and pay for one staff member over a period.
+#[derive(Debug, Clone, FromRow)]
+pub struct ShiftSummaryRow {
+    pub staff_id: Uuid,
+    pub display_name: String,
+    pub shifts: i64,
+    pub minutes: i64,
+    pub cost_cents: i64,
+}
+
+/// Open a shift. Returns `None` if the staff member is already clocked in.
+pub async fn clock_in<'e>(
+    executor: impl PgExecutor<'e>,
+    data: ClockIn,
+) -> SqlxResult<Option<StaffShiftRow>> {
+    sqlx::query_as::<_, StaffShiftRow>(&format!(
+        "INSERT INTO staff_shifts \
+         (club_id, staff_id, tournament_id, hourly_rate_cents, notes, recorded_by) \
+         VALUES ($1, $2, $3, $4, $5, $6) \
+         ON CONFLICT (staff_id) WHERE clock_out_at IS NULL DO NOTHING \
+         RETURNING {COLS}"
+    ))
+    .bind(data.club_id)
+    .bind(data.staff_id)
+    .bind(data.tournament_id)
+    .bind(data.hourly_rate_cents)
+    .bind(data.notes)
+    .bind(data.recorded_by)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Close the staff member's open shift. Returns `None` if they aren't clocked in.
+pub async fn clock_out<'e>(
+    executor: impl PgExecutor<'e>,
+    staff_id: Uuid,
+    at: DateTime<Utc>,
+) -> SqlxResult<Option<StaffShiftRow>> {
+    sqlx::query_as::<_, StaffShiftRow>(&format!(
+        "UPDATE staff_shifts SET clock_out_at = $2 \
+         WHERE staff_id = $1 AND clock_out_at IS NULL \
+         RETURNING {COLS}"
+    ))
+    .bind(staff_id)
+    .bind(at)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A club's shifts that started in `[from, to)`, newest first.
+pub async fn list_by_club<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> SqlxResult<Vec<StaffShiftRow>> {
+    sqlx::query_as::<_, StaffShiftRow>(&format!(
+        "SELECT {COLS} FROM staff_shifts \
+         WHERE club_id = $1 AND clock_in_at >= $2 AND clock_in_at < $3 \
+         ORDER BY clock_in_at DESC"
+    ))
+    .bind(club_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(executor)
+    .await
+}
+
+/// Per-staff totals for closed shifts that started in `[from, to)` — a pay
+/// period. Open shifts are left out until they're clocked out.
+pub async fn summary_by_club<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> SqlxResult<Vec<ShiftSummaryRow>> {
+    sqlx::query_as::<_, ShiftSummaryRow>(&format!(
+        "SELECT s.id AS staff_id, s.display_name, \
+                COUNT(*) AS shifts, \
+                COALESCE(SUM(EXTRACT(EPOCH FROM (sh.clock_out_at - sh.clock_in_at)) / 60), 0)::bigint AS minutes, \
+                COALESCE(SUM({SHIFT_COST_SQL}), 0)::bigint AS cost_cents \
+         FROM staff_shifts sh \
+         JOIN club_staff s ON s.id = sh.staff_id \
+         WHERE sh.club_id = $1 AND sh.clock_out_at IS NOT NULL \
+           AND sh.clock_in_at >= $2 AND sh.clock_in_at < $3 \
+         GROUP BY s.id, s.display_name \
+         ORDER BY s.display_name ASC"
+    ))
+    .bind(club_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(executor)
+    .await
+}
+
+/// Labour cost of the closed shifts booked against a tournament.
+pub async fn cost_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<i64> {
+    sqlx::query_scalar::<_, i64>(&format!(
+        "SELECT COALESCE(SUM({SHIFT_COST_SQL}), 0)::bigint FROM staff_shifts sh \
+         WHERE sh.tournament_id = $1 AND sh.clock_out_at IS NOT NULL"
+    ))
+    .bind(tournament_id)
+    .fetch_one(executor)
+    .await
+}
@@ -0,0 +1,43 @@
+//! Server versions of records an offline client may hold, so it can
+//! reconcile its queue after reconnecting. The version is the row's
+//! `updated_at`; a missing row was deleted or voided (or never reached the
+//! server).
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct SyncVersionRow {
+    pub id: Uuid,
+    /// Club owning the record, for access checks.
+    pub club_id: Uuid,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub async fn tournament_entries<'e>(
+    executor: impl PgExecutor<'e>,
+    ids: &[Uuid],
+) -> SqlxResult<Vec<SyncVersionRow>> {
+    sqlx::query_as::<_, SyncVersionRow>(
+        "SELECT e.id, t.club_id, e.updated_at FROM tournament_entries e \
+         JOIN tournaments t ON t.id = e.tournament_id \
+         WHERE e.id = ANY($1) AND e.voided_at IS NULL",
+    )
+    .bind(ids)
+    .fetch_all(executor)
+    .await
+}
+
+pub async fn tournament_registrations<'e>(
+    executor: impl PgExecutor<'e>,
+    ids: &[Uuid],
+) -> SqlxResult<Vec<SyncVersionRow>> {
+    sqlx::query_as::<_, SyncVersionRow>(
+        "SELECT r.id, t.club_id, r.updated_at FROM tournament_registrations r \
+         JOIN tournaments t ON t.id = r.tournament_id WHERE r.id = ANY($1)",
+    )
+    .bind(ids)
+    .fetch_all(executor)
+    .await
+}
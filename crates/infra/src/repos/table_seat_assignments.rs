@@ -1,6 +1,8 @@
 use crate::models::{TableSeatAssignmentRow, UserRow};
+use crate::pii::Pii;
 use chrono::{DateTime, Utc};
-use sqlx::{PgExecutor, PgPool, Result as SqlxResult};
+use sqlx::types::Json;
+use sqlx::{PgConnection, PgExecutor, Result as SqlxResult};
 use uuid::Uuid;
 
 const COLS: &str = "id, tournament_id, club_table_id, user_id, club_player_id, seat_number, stack_size, is_current, assigned_at, unassigned_at, assigned_by, notes, created_at, updated_at";
@@ -34,6 +36,20 @@ pub struct SeatAssignmentWithPlayer {
     pub player: Option<UserRow>,
 }
 
+/// Whoever currently sits in a seat, as a seat conflict reports them.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SeatOccupantRow {
+    pub assignment_id: Uuid,
+    pub tournament_id: Uuid,
+    pub club_table_id: Uuid,
+    pub seat_number: i32,
+    pub user_id: Option<Uuid>,
+    pub club_player_id: Uuid,
+    /// Roster display name.
+    pub display_name: String,
+    pub assigned_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone)]
 pub struct SeatAssignmentFilter {
     pub tournament_id: Option<Uuid>,
@@ -156,7 +172,8 @@ pub async fn list_current_with_players_for_table<'e>(
         username: Option<String>,
         first_name: Option<String>,
         last_name: Option<String>,
-        phone: Option<String>,
+        phone: Option<Pii>,
+        avatar_url: Option<String>,
         is_active: Option<bool>,
         role: Option<String>,
         locale: Option<String>,
@@ -171,11 +188,12 @@ pub async fn list_current_with_players_for_table<'e>(
             tsa.seat_number, tsa.stack_size, tsa.is_current, tsa.assigned_at, tsa.unassigned_at,
             tsa.assigned_by, tsa.notes, tsa.created_at, tsa.updated_at,
             rp.display_name,
-            u.email, u.username, u.first_name, u.last_name, u.phone, u.is_active, u.role,
+            pu.email, u.username, u.first_name, u.last_name, pu.phone, u.avatar_url, u.is_active, u.role,
             u.locale, u.created_at as user_created_at, u.updated_at as user_updated_at
         FROM table_seat_assignments tsa
-        JOIN club_player rp ON tsa.club_player_id = rp.id
-        LEFT JOIN users u ON tsa.user_id = u.id
+        JOIN club_player_public rp ON tsa.club_player_id = rp.id
+        LEFT JOIN users_public u ON tsa.user_id = u.id
+        LEFT JOIN users pu ON pu.id = u.id
         WHERE tsa.club_table_id = $1 AND tsa.is_current = true
         ORDER BY tsa.seat_number ASC
         "#,
@@ -187,14 +205,16 @@ pub async fn list_current_with_players_for_table<'e>(
     let results = rows
         .into_iter()
         .map(|row| {
-            let player = match (row.user_id, row.first_name.clone(), row.email.clone()) {
-                (Some(uid), Some(first_name), Some(email)) => Some(UserRow {
+            // Contact details only come back to the player and their clubs.
+            let player = match (row.user_id, row.first_name.clone()) {
+                (Some(uid), Some(first_name)) => Some(UserRow {
                     id: uid,
-                    email,
+                    email: row.email.clone().unwrap_or_default(),
                     username: row.username.clone(),
                     first_name,
                     last_name: row.last_name.clone(),
                     phone: row.phone.clone(),
+                    avatar_url: row.avatar_url.clone(),
                     is_active: row.is_active.unwrap_or(true),
                     role: row.role.clone(),
                     locale: row.locale.clone().unwrap_or_else(|| "en".to_string()),
@@ -314,10 +334,29 @@ pub async fn unassign_current_seat<'e>(
     Ok(())
 }
 
+/// Unseat everyone still seated in a tournament; returns how many seats
+/// were cleared.
+pub async fn unassign_all_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    unassigned_by: Option<Uuid>,
+) -> SqlxResult<u64> {
+    let result = sqlx::query(
+        "UPDATE table_seat_assignments \
+         SET is_current = false, unassigned_at = NOW(), assigned_by = COALESCE($2, assigned_by), updated_at = NOW() \
+         WHERE tournament_id = $1 AND is_current = true",
+    )
+    .bind(tournament_id)
+    .bind(unassigned_by)
+    .execute(executor)
+    .await?;
+    Ok(result.rows_affected())
+}
+
 /// Move a player to a new seat (creates new assignment and unassigns old one).
-/// Uses a transaction so requires &PgPool. Keyed on the roster identity.
+/// Run it in the caller's transaction. Keyed on the roster identity.
 pub async fn move_player(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     tournament_id: Uuid,
     club_player_id: Uuid,
     new_club_table_id: Uuid,
@@ -325,10 +364,9 @@ pub async fn move_player(
     moved_by: Option<Uuid>,
     notes: Option<String>,
 ) -> SqlxResult<TableSeatAssignmentRow> {
-    let mut tx = pool.begin().await?;
-    unassign_current_seat(&mut *tx, tournament_id, club_player_id, moved_by).await?;
-    let result = create_seat_in_tx(
-        &mut *tx,
+    unassign_current_seat(&mut *conn, tournament_id, club_player_id, moved_by).await?;
+    create_seat_in_tx(
+        &mut *conn,
         tournament_id,
         club_player_id,
         new_club_table_id,
@@ -336,9 +374,46 @@ pub async fn move_player(
         moved_by,
         notes,
     )
+    .await
+}
+
+/// Swap two seated players: both seats are vacated before either is
+/// refilled, so neither player trips over the other's current seat. Stacks
+/// travel with the players. Fails with `RowNotFound` when either assignment
+/// is no longer current. Returns the first and second player's new seats.
+pub async fn swap_seats(
+    conn: &mut PgConnection,
+    first: &TableSeatAssignmentRow,
+    second: &TableSeatAssignmentRow,
+    swapped_by: Option<Uuid>,
+) -> SqlxResult<(TableSeatAssignmentRow, TableSeatAssignmentRow)> {
+    let vacated = sqlx::query(
+        "UPDATE table_seat_assignments \
+         SET is_current = false, unassigned_at = NOW(), assigned_by = COALESCE($2, assigned_by), updated_at = NOW() \
+         WHERE id = ANY($1) AND is_current = true",
+    )
+    .bind(vec![first.id, second.id])
+    .bind(swapped_by)
+    .execute(&mut *conn)
     .await?;
-    tx.commit().await?;
-    Ok(result)
+    if vacated.rows_affected() != 2 {
+        return Err(sqlx::Error::RowNotFound);
+    }
+
+    let seat_in =
+        |player: &TableSeatAssignmentRow, seat: &TableSeatAssignmentRow| CreateSeatAssignment {
+            tournament_id: player.tournament_id,
+            club_table_id: seat.club_table_id,
+            user_id: player.user_id,
+            club_player_id: Some(player.club_player_id),
+            seat_number: seat.seat_number,
+            stack_size: player.stack_size,
+            assigned_by: swapped_by,
+            notes: None,
+        };
+    let first_seat = create(&mut *conn, seat_in(first, second)).await?;
+    let second_seat = create(&mut *conn, seat_in(second, first)).await?;
+    Ok((first_seat, second_seat))
 }
 
 /// Insert a new seat assignment (used within transactions)
@@ -404,20 +479,217 @@ pub async fn list_unassigned_players<'e>(
     .await
 }
 
-pub async fn is_seat_available<'e>(
+/// One table of a tournament's seating chart.
+#[derive(Debug, Clone)]
+pub struct SeatingChartTable {
+    pub table: crate::models::ClubTableRow,
+    pub seats: Vec<SeatAssignmentWithPlayer>,
+    pub dealer: Option<super::dealer_rotation::CurrentDealerRow>,
+    /// Seats closed for the tournament (see `closed_seats`), lowest first.
+    pub closed_seats: Vec<i32>,
+    /// Floor status: `open`, `breaking_soon` or `closed`.
+    pub status: String,
+    pub notes: Option<String>,
+}
+
+impl SeatingChartTable {
+    /// Seats in play: the table's seat count less its closed seats.
+    pub fn effective_seats(&self) -> i32 {
+        let closed = self
+            .closed_seats
+            .iter()
+            .filter(|&&seat| seat <= self.table.max_seats)
+            .count() as i32;
+        self.table.max_seats - closed
+    }
+}
+
+/// A tournament's seating chart: its tables with their current seats,
+/// closed seats and dealer, and the players still waiting for a seat.
+#[derive(Debug, Clone)]
+pub struct SeatingChartRows {
+    pub tables: Vec<SeatingChartTable>,
+    pub unassigned: Vec<crate::models::ClubPlayerRow>,
+}
+
+/// Build the whole seating chart in one statement, nested as JSON by
+/// Postgres. Being a single statement it also reads a single snapshot, so a
+/// player moved in between is never shown on two tables, or both seated and
+/// unassigned.
+pub async fn seating_chart<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<SeatingChartRows> {
+    #[derive(serde::Deserialize)]
+    struct ChartTable {
+        table: crate::models::ClubTableRow,
+        seats: Vec<ChartSeat>,
+        dealer: Option<super::dealer_rotation::CurrentDealerRow>,
+        closed_seats: Vec<i32>,
+        status: String,
+        notes: Option<String>,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct ChartSeat {
+        assignment: TableSeatAssignmentRow,
+        display_name: String,
+        player: Option<UserRow>,
+    }
+
+    let (tables, unassigned): (
+        Json<Vec<ChartTable>>,
+        Json<Vec<crate::models::ClubPlayerRow>>,
+    ) = sqlx::query_as(
+        r#"
+        WITH chart_tables AS (
+            SELECT ct.id, ct.club_id, ct.table_number,
+                   COALESCE(tta.max_seats_override, LEAST(ct.max_seats, t.seats_per_table)) as max_seats,
+                   ct.is_active, ct.is_default, ct.created_at, ct.updated_at,
+                   tta.status, tta.notes
+            FROM club_tables ct
+            INNER JOIN tournament_table_assignments tta ON ct.id = tta.club_table_id
+            INNER JOIN tournaments t ON t.id = tta.tournament_id
+            WHERE tta.tournament_id = $1 AND tta.is_active = true
+        )
+        SELECT
+            (
+                SELECT COALESCE(jsonb_agg(
+                    jsonb_build_object(
+                        'table', to_jsonb(t),
+                        'seats', s.seats,
+                        'dealer', d.dealer,
+                        'closed_seats', c.closed_seats,
+                        'status', t.status,
+                        'notes', t.notes
+                    ) ORDER BY t.table_number
+                ), '[]'::jsonb)
+                FROM chart_tables t
+                CROSS JOIN LATERAL (
+                    SELECT COALESCE(jsonb_agg(
+                        jsonb_build_object(
+                            'assignment', to_jsonb(tsa),
+                            'display_name', rp.display_name,
+                            'player', CASE WHEN u.id IS NOT NULL THEN jsonb_build_object(
+                                'id', u.id, 'email', COALESCE(pu.email, ''), 'username', u.username,
+                                'first_name', u.first_name, 'last_name', u.last_name,
+                                'phone', pu.phone, 'avatar_url', u.avatar_url,
+                                'is_active', u.is_active, 'role', u.role,
+                                'locale', u.locale, 'created_at', u.created_at,
+                                'updated_at', u.updated_at
+                            ) END
+                        ) ORDER BY tsa.seat_number
+                    ), '[]'::jsonb) AS seats
+                    FROM table_seat_assignments tsa
+                    JOIN club_player_public rp ON tsa.club_player_id = rp.id
+                    LEFT JOIN users_public u ON tsa.user_id = u.id
+                    LEFT JOIN users pu ON pu.id = u.id
+                    WHERE tsa.tournament_id = $1 AND tsa.club_table_id = t.id
+                      AND tsa.is_current = true
+                ) s
+                LEFT JOIN LATERAL (
+                    SELECT jsonb_build_object(
+                        'club_table_id', dp.club_table_id, 'staff_id', dp.staff_id,
+                        'display_name', st.display_name, 'ends_at', dp.ends_at
+                    ) AS dealer
+                    FROM dealer_pushes dp
+                    JOIN club_staff st ON st.id = dp.staff_id
+                    WHERE dp.tournament_id = $1 AND dp.club_table_id = t.id
+                      AND dp.starts_at <= NOW() AND dp.ends_at > NOW()
+                    ORDER BY dp.starts_at DESC
+                    LIMIT 1
+                ) d ON true
+                CROSS JOIN LATERAL (
+                    SELECT COALESCE(jsonb_agg(cs.seat_number ORDER BY cs.seat_number), '[]'::jsonb)
+                        AS closed_seats
+                    FROM tournament_closed_seats cs
+                    WHERE cs.tournament_id = $1 AND cs.club_table_id = t.id
+                ) c
+            ) AS tables,
+            (
+                SELECT COALESCE(jsonb_agg(to_jsonb(rp) ORDER BY tr.registration_time), '[]'::jsonb)
+                FROM tournament_registrations tr
+                JOIN club_player rp ON rp.id = tr.club_player_id
+                WHERE tr.tournament_id = $1
+                  AND tr.status IN ('registered', 'checked_in', 'seated')
+                  AND NOT EXISTS (
+                      SELECT 1 FROM table_seat_assignments tsa
+                      WHERE tsa.club_player_id = rp.id
+                        AND tsa.tournament_id = $1 AND tsa.is_current = true
+                  )
+            ) AS unassigned
+        "#,
+    )
+    .bind(tournament_id)
+    .fetch_one(executor)
+    .await?;
+
+    // Phones come back as stored; JSON skips the decode that opens them.
+    let open_phone = |phone: Option<Pii>| -> SqlxResult<Option<Pii>> {
+        phone
+            .map(|p| crate::pii::open(p.as_str()).map(Pii::new))
+            .transpose()
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))
+    };
+
+    let tables = tables
+        .0
+        .into_iter()
+        .map(|t| {
+            let seats = t
+                .seats
+                .into_iter()
+                .map(|seat| {
+                    let player = match seat.player {
+                        Some(mut user) => {
+                            user.phone = open_phone(user.phone)?;
+                            Some(user)
+                        }
+                        None => None,
+                    };
+                    Ok(SeatAssignmentWithPlayer {
+                        assignment: seat.assignment,
+                        display_name: seat.display_name,
+                        player,
+                    })
+                })
+                .collect::<SqlxResult<Vec<_>>>()?;
+            Ok(SeatingChartTable {
+                table: t.table,
+                seats,
+                dealer: t.dealer,
+                closed_seats: t.closed_seats,
+                status: t.status,
+                notes: t.notes,
+            })
+        })
+        .collect::<SqlxResult<Vec<_>>>()?;
+
+    Ok(SeatingChartRows {
+        tables,
+        unassigned: unassigned.0,
+    })
+}
+
+/// The player currently in `seat_number` at the table, in any tournament.
+pub async fn current_occupant<'e>(
     executor: impl PgExecutor<'e>,
     club_table_id: Uuid,
     seat_number: i32,
-) -> SqlxResult<bool> {
-    let result: (i64,) = sqlx::query_as(
-        "SELECT COUNT(*) FROM table_seat_assignments WHERE club_table_id = $1 AND seat_number = $2 AND is_current = true"
+) -> SqlxResult<Option<SeatOccupantRow>> {
+    sqlx::query_as::<_, SeatOccupantRow>(
+        r#"
+        SELECT tsa.id AS assignment_id, tsa.tournament_id, tsa.club_table_id, tsa.seat_number,
+               tsa.user_id, tsa.club_player_id, rp.display_name, tsa.assigned_at
+        FROM table_seat_assignments tsa
+        JOIN club_player rp ON rp.id = tsa.club_player_id
+        WHERE tsa.club_table_id = $1 AND tsa.seat_number = $2 AND tsa.is_current = true
+        "#,
     )
     .bind(club_table_id)
     .bind(seat_number)
-    .fetch_one(executor)
-    .await?;
-
-    Ok(result.0 == 0)
+    .fetch_optional(executor)
+    .await
 }
 
 pub async fn get_occupied_seats<'e>(
@@ -0,0 +1,94 @@
+//! Organization sign-off on tournaments created at franchised clubs. A
+//! tournament without a row here needs no approval.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "tournament_id, organization_id, status, requested_by, requested_at, \
+     reviewed_by, reviewed_at, review_note";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct TournamentApprovalRow {
+    pub tournament_id: Uuid,
+    pub organization_id: Uuid,
+    /// `pending` | `approved` | `rejected`.
+    pub status: String,
+    /// The manager who created the tournament; notified of the decision.
+    pub requested_by: Option<Uuid>,
+    pub requested_at: DateTime<Utc>,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+}
+
+/// Put a freshly created tournament up for the organization's approval.
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    organization_id: Uuid,
+    requested_by: Uuid,
+) -> SqlxResult<TournamentApprovalRow> {
+    sqlx::query_as::<_, TournamentApprovalRow>(&format!(
+        "INSERT INTO tournament_approvals (tournament_id, organization_id, requested_by) \
+         VALUES ($1, $2, $3) RETURNING {COLS}"
+    ))
+    .bind(tournament_id)
+    .bind(organization_id)
+    .bind(requested_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Option<TournamentApprovalRow>> {
+    sqlx::query_as::<_, TournamentApprovalRow>(&format!(
+        "SELECT {COLS} FROM tournament_approvals WHERE tournament_id = $1"
+    ))
+    .bind(tournament_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Tournaments of the organization awaiting review, oldest request first.
+/// Deleted tournaments drop out.
+pub async fn list_pending<'e>(
+    executor: impl PgExecutor<'e>,
+    organization_id: Uuid,
+) -> SqlxResult<Vec<TournamentApprovalRow>> {
+    sqlx::query_as::<_, TournamentApprovalRow>(
+        "SELECT a.tournament_id, a.organization_id, a.status, a.requested_by, a.requested_at, \
+                a.reviewed_by, a.reviewed_at, a.review_note \
+         FROM tournament_approvals a JOIN tournaments t ON t.id = a.tournament_id \
+         WHERE a.organization_id = $1 AND a.status = 'pending' AND t.deleted_at IS NULL \
+         ORDER BY a.requested_at ASC",
+    )
+    .bind(organization_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Record the decision on a pending tournament. `None` when it isn't pending.
+pub async fn review<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    approved: bool,
+    reviewed_by: Uuid,
+    note: Option<&str>,
+) -> SqlxResult<Option<TournamentApprovalRow>> {
+    sqlx::query_as::<_, TournamentApprovalRow>(&format!(
+        "UPDATE tournament_approvals \
+         SET status = CASE WHEN $2 THEN 'approved' ELSE 'rejected' END, \
+             reviewed_by = $3, reviewed_at = NOW(), review_note = $4 \
+         WHERE tournament_id = $1 AND status = 'pending' \
+         RETURNING {COLS}"
+    ))
+    .bind(tournament_id)
+    .bind(approved)
+    .bind(reviewed_by)
+    .bind(note)
+    .fetch_optional(executor)
+    .await
+}
@@ -15,7 +15,7 @@ pub struct BountyRow {
     pub tournament_id: Uuid,
     pub hunter_club_player_id: Uuid,
     pub victim_club_player_id: Uuid,
-    pub amount_cents: i32,
+    pub amount_cents: i64,
     pub created_at: DateTime<Utc>,
 }
 
@@ -23,9 +23,9 @@ pub struct BountyRow {
 #[derive(Debug, Clone, Copy)]
 pub struct KnockoutOutcome {
     /// Cash bounty the hunter collected, in cents.
-    pub cash_cents: i32,
+    pub cash_cents: i64,
     /// Amount added to the hunter's progressive head (0 for fixed bounties).
-    pub head_growth_cents: i32,
+    pub head_growth_cents: i64,
 }
 
 /// Record a knockout bounty inside the elimination transaction.
@@ -44,14 +44,14 @@ pub async fn record_knockout(
     hunter_club_player_id: Uuid,
     victim_club_player_id: Uuid,
     bounty_type: &str,
-    bounty_amount_cents: i32,
+    bounty_amount_cents: i64,
 ) -> Result<Option<KnockoutOutcome>, sqlx::Error> {
     if bounty_amount_cents <= 0 || bounty_type == "none" {
         return Ok(None);
     }
 
     // The victim's head: their accumulated progressive head, or the fixed slice.
-    let head: i32 = if bounty_type == "progressive" {
+    let head: i64 = if bounty_type == "progressive" {
         sqlx::query_scalar(
             "SELECT current_bounty_cents FROM tournament_registrations \
              WHERE tournament_id = $1 AND club_player_id = $2",
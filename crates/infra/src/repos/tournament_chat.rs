@@ -0,0 +1,220 @@
+//! Per-tournament chat: messages, soft deletion by moderators, and mutes.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+/// Message columns, for a message relation aliased `m`.
+const MESSAGE_COLS: &str = "m.id, m.tournament_id, m.user_id, \
+     COALESCE(u.username, u.first_name) AS author_name, \
+     m.body, m.created_at, m.deleted_at, m.deleted_by";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ChatMessageRow {
+    pub id: Uuid,
+    pub tournament_id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub author_name: Option<String>,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub deleted_by: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ChatMuteRow {
+    pub tournament_id: Uuid,
+    pub user_id: Uuid,
+    pub muted_by: Option<Uuid>,
+    pub reason: Option<String>,
+    pub muted_until: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// How `user_id` takes part in the tournament's chat: "staff" (a manager or
+/// active staff member of the hosting club), "player" (registered and not
+/// cancelled), or `None` when they don't.
+pub async fn participant_role<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    user_id: Uuid,
+) -> SqlxResult<Option<String>> {
+    let role: Option<Option<String>> = sqlx::query_scalar(
+        "SELECT CASE \
+             WHEN is_club_manager($2, t.club_id) \
+               OR EXISTS (SELECT 1 FROM club_staff s \
+                          WHERE s.club_id = t.club_id AND s.user_id = $2 AND s.is_active) \
+                 THEN 'staff' \
+             WHEN EXISTS (SELECT 1 FROM tournament_registrations r \
+                          WHERE r.tournament_id = t.id AND r.user_id = $2 \
+                            AND r.status <> 'cancelled') \
+                 THEN 'player' \
+         END \
+         FROM tournaments t WHERE t.id = $1",
+    )
+    .bind(tournament_id)
+    .bind(user_id)
+    .fetch_optional(executor)
+    .await?;
+    Ok(role.flatten())
+}
+
+pub async fn create_message<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    user_id: Uuid,
+    body: &str,
+) -> SqlxResult<ChatMessageRow> {
+    sqlx::query_as::<_, ChatMessageRow>(&format!(
+        "WITH m AS ( \
+             INSERT INTO tournament_chat_messages (tournament_id, user_id, body) \
+             VALUES ($1, $2, $3) \
+             RETURNING * \
+         ) \
+         SELECT {MESSAGE_COLS} FROM m LEFT JOIN users_public u ON u.id = m.user_id"
+    ))
+    .bind(tournament_id)
+    .bind(user_id)
+    .bind(body)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_message<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<ChatMessageRow>> {
+    sqlx::query_as::<_, ChatMessageRow>(&format!(
+        "SELECT {MESSAGE_COLS} FROM tournament_chat_messages m \
+         LEFT JOIN users_public u ON u.id = m.user_id \
+         WHERE m.id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A page of the channel, newest first. Deleted messages keep their place.
+pub async fn list_messages<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    limit: i64,
+    offset: i64,
+) -> SqlxResult<Vec<ChatMessageRow>> {
+    sqlx::query_as::<_, ChatMessageRow>(&format!(
+        "SELECT {MESSAGE_COLS} FROM tournament_chat_messages m \
+         LEFT JOIN users_public u ON u.id = m.user_id \
+         WHERE m.tournament_id = $1 \
+         ORDER BY m.created_at DESC, m.id DESC \
+         LIMIT $2 OFFSET $3"
+    ))
+    .bind(tournament_id)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(executor)
+    .await
+}
+
+pub async fn count_messages<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<i64> {
+    sqlx::query_scalar("SELECT COUNT(*) FROM tournament_chat_messages WHERE tournament_id = $1")
+        .bind(tournament_id)
+        .fetch_one(executor)
+        .await
+}
+
+/// Soft-delete a message. Returns `None` when it doesn't exist or was already
+/// deleted.
+pub async fn delete_message<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    deleted_by: Uuid,
+) -> SqlxResult<Option<ChatMessageRow>> {
+    sqlx::query_as::<_, ChatMessageRow>(&format!(
+        "WITH m AS ( \
+             UPDATE tournament_chat_messages SET deleted_at = NOW(), deleted_by = $2 \
+             WHERE id = $1 AND deleted_at IS NULL \
+             RETURNING * \
+         ) \
+         SELECT {MESSAGE_COLS} FROM m LEFT JOIN users_public u ON u.id = m.user_id"
+    ))
+    .bind(id)
+    .bind(deleted_by)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Mute a user in a tournament's chat, replacing any earlier mute.
+pub async fn upsert_mute<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    user_id: Uuid,
+    muted_by: Uuid,
+    reason: Option<&str>,
+    muted_until: Option<DateTime<Utc>>,
+) -> SqlxResult<ChatMuteRow> {
+    sqlx::query_as::<_, ChatMuteRow>(
+        "INSERT INTO tournament_chat_mutes (tournament_id, user_id, muted_by, reason, muted_until) \
+         VALUES ($1, $2, $3, $4, $5) \
+         ON CONFLICT (tournament_id, user_id) DO UPDATE SET \
+             muted_by = EXCLUDED.muted_by, reason = EXCLUDED.reason, \
+             muted_until = EXCLUDED.muted_until, created_at = NOW() \
+         RETURNING tournament_id, user_id, muted_by, reason, muted_until, created_at",
+    )
+    .bind(tournament_id)
+    .bind(user_id)
+    .bind(muted_by)
+    .bind(reason)
+    .bind(muted_until)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn delete_mute<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    user_id: Uuid,
+) -> SqlxResult<bool> {
+    let res =
+        sqlx::query("DELETE FROM tournament_chat_mutes WHERE tournament_id = $1 AND user_id = $2")
+            .bind(tournament_id)
+            .bind(user_id)
+            .execute(executor)
+            .await?;
+    Ok(res.rows_affected() > 0)
+}
+
+/// Whether the user is currently muted (an expired mute no longer counts).
+pub async fn is_muted<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    user_id: Uuid,
+) -> SqlxResult<bool> {
+    sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM tournament_chat_mutes \
+                        WHERE tournament_id = $1 AND user_id = $2 \
+                          AND (muted_until IS NULL OR muted_until > NOW()))",
+    )
+    .bind(tournament_id)
+    .bind(user_id)
+    .fetch_one(executor)
+    .await
+}
+
+/// Active mutes in a tournament's chat.
+pub async fn list_mutes<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<ChatMuteRow>> {
+    sqlx::query_as::<_, ChatMuteRow>(
+        "SELECT tournament_id, user_id, muted_by, reason, muted_until, created_at \
+         FROM tournament_chat_mutes \
+         WHERE tournament_id = $1 AND (muted_until IS NULL OR muted_until > NOW()) \
+         ORDER BY created_at DESC",
+    )
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
@@ -43,6 +43,7 @@ pub struct TournamentStructureLevel {
     pub duration_minutes: i32,
     pub is_break: bool,
     pub break_duration_minutes: Option<i32>,
+    pub color_up_denomination: Option<i32>,
 }
 
 /// Get tournament clock state
@@ -60,6 +61,34 @@ pub async fn get_clock(
     .await
 }
 
+/// The clock, row-locked until the transaction ends so the level in play
+/// can't move while the caller relies on it.
+pub async fn lock_clock<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Option<TournamentClockRow>> {
+    sqlx::query_as::<_, TournamentClockRow>(
+        "SELECT id, tournament_id, clock_status, current_level, level_started_at, level_end_time,
+                pause_started_at, total_pause_duration, auto_advance, created_at, updated_at
+         FROM tournament_clocks WHERE tournament_id = $1
+         FOR UPDATE",
+    )
+    .bind(tournament_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// The level the tournament's clock is on, if it has a clock.
+pub async fn current_level<'e>(
+    executor: impl sqlx::PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Option<i32>> {
+    sqlx::query_scalar("SELECT current_level FROM tournament_clocks WHERE tournament_id = $1")
+        .bind(tournament_id)
+        .fetch_optional(executor)
+        .await
+}
+
 /// Initialize tournament clock
 pub async fn create_clock(pool: &PgPool, tournament_id: Uuid) -> SqlxResult<TournamentClockRow> {
     sqlx::query_as::<_, TournamentClockRow>(
@@ -117,6 +146,42 @@ pub async fn start_clock(
     Ok(clock)
 }
 
+/// Start the clock as play starts, creating it first if the tournament has
+/// none. `None` when there is nothing to start: the clock is already running
+/// or paused, or its level has no structure.
+pub async fn start_for_play(
+    conn: &mut sqlx::PgConnection,
+    tournament_id: Uuid,
+) -> SqlxResult<Option<TournamentClockRow>> {
+    sqlx::query(
+        "INSERT INTO tournament_clocks (tournament_id, clock_status, current_level)
+         VALUES ($1, 'stopped', 1)
+         ON CONFLICT (tournament_id) DO NOTHING",
+    )
+    .bind(tournament_id)
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::query_as::<_, TournamentClockRow>(
+        "UPDATE tournament_clocks c
+         SET clock_status = 'running',
+             level_started_at = $2,
+             level_end_time = $2 + make_interval(mins => s.duration_minutes),
+             pause_started_at = NULL,
+             total_pause_duration = '0 seconds'
+         FROM tournament_structures s
+         WHERE c.tournament_id = $1 AND c.clock_status = 'stopped'
+           AND s.tournament_id = c.tournament_id AND s.level_number = c.current_level
+         RETURNING c.id, c.tournament_id, c.clock_status, c.current_level, c.level_started_at,
+                   c.level_end_time, c.pause_started_at, c.total_pause_duration, c.auto_advance,
+                   c.created_at, c.updated_at",
+    )
+    .bind(tournament_id)
+    .bind(Utc::now())
+    .fetch_optional(&mut *conn)
+    .await
+}
+
 /// Pause tournament clock
 pub async fn pause_clock(
     pool: &PgPool,
@@ -152,6 +217,25 @@ pub async fn pause_clock(
     Ok(clock)
 }
 
+/// Stop the clock for good, e.g. when the tournament is cancelled. A
+/// tournament without a clock is left alone.
+pub async fn stop_clock<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Option<TournamentClockRow>> {
+    sqlx::query_as::<_, TournamentClockRow>(
+        "UPDATE tournament_clocks
+         SET clock_status = 'stopped',
+             pause_started_at = NULL
+         WHERE tournament_id = $1
+         RETURNING id, tournament_id, clock_status, current_level, level_started_at, level_end_time,
+                   pause_started_at, total_pause_duration, auto_advance, created_at, updated_at",
+    )
+    .bind(tournament_id)
+    .fetch_optional(executor)
+    .await
+}
+
 /// Resume tournament clock from pause
 pub async fn resume_clock(
     pool: &PgPool,
@@ -331,7 +415,7 @@ pub async fn get_current_structure(
 
     sqlx::query_as::<_, TournamentStructureRow>(
         "SELECT id, tournament_id, level_number, small_blind, big_blind, ante,
-                duration_minutes, is_break, break_duration_minutes, created_at
+                duration_minutes, is_break, break_duration_minutes, color_up_denomination, created_at
          FROM tournament_structures
          WHERE tournament_id = $1 AND level_number = $2",
     )
@@ -342,19 +426,19 @@ pub async fn get_current_structure(
 }
 
 /// Get all structures for a tournament
-pub async fn get_all_structures(
-    pool: &PgPool,
+pub async fn get_all_structures<'e>(
+    executor: impl PgExecutor<'e>,
     tournament_id: Uuid,
 ) -> SqlxResult<Vec<TournamentStructureRow>> {
     sqlx::query_as::<_, TournamentStructureRow>(
         "SELECT id, tournament_id, level_number, small_blind, big_blind, ante,
-                duration_minutes, is_break, break_duration_minutes, created_at
+                duration_minutes, is_break, break_duration_minutes, color_up_denomination, created_at
          FROM tournament_structures
          WHERE tournament_id = $1
          ORDER BY level_number ASC",
     )
     .bind(tournament_id)
-    .fetch_all(pool)
+    .fetch_all(executor)
     .await
 }
 
@@ -366,7 +450,7 @@ pub async fn get_next_structure(
 ) -> SqlxResult<Option<TournamentStructureRow>> {
     sqlx::query_as::<_, TournamentStructureRow>(
         "SELECT id, tournament_id, level_number, small_blind, big_blind, ante,
-                duration_minutes, is_break, break_duration_minutes, created_at
+                duration_minutes, is_break, break_duration_minutes, color_up_denomination, created_at
          FROM tournament_structures
          WHERE tournament_id = $1 AND level_number = $2 + 1
          LIMIT 1",
@@ -385,10 +469,10 @@ pub async fn add_structure<'e>(
 ) -> SqlxResult<TournamentStructureRow> {
     sqlx::query_as::<_, TournamentStructureRow>(
         "INSERT INTO tournament_structures
-         (tournament_id, level_number, small_blind, big_blind, ante, duration_minutes, is_break, break_duration_minutes)
-         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+         (tournament_id, level_number, small_blind, big_blind, ante, duration_minutes, is_break, break_duration_minutes, color_up_denomination)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
          RETURNING id, tournament_id, level_number, small_blind, big_blind, ante,
-                   duration_minutes, is_break, break_duration_minutes, created_at"
+                   duration_minutes, is_break, break_duration_minutes, color_up_denomination, created_at"
     )
     .bind(tournament_id)
     .bind(level.level_number)
@@ -398,6 +482,7 @@ pub async fn add_structure<'e>(
     .bind(level.duration_minutes)
     .bind(level.is_break)
     .bind(level.break_duration_minutes)
+    .bind(level.color_up_denomination)
     .fetch_one(executor)
     .await
 }
@@ -423,6 +508,132 @@ pub async fn replace_structures(
     Ok(results)
 }
 
+/// Renumber the levels numbered `from..=to` by `delta`. Goes through negative
+/// numbers so no two rows share a number mid-update.
+async fn shift_levels(
+    conn: &mut sqlx::PgConnection,
+    tournament_id: Uuid,
+    from: i32,
+    to: i32,
+    delta: i32,
+) -> SqlxResult<()> {
+    sqlx::query(
+        "UPDATE tournament_structures SET level_number = -(level_number + $4)
+         WHERE tournament_id = $1 AND level_number BETWEEN $2 AND $3",
+    )
+    .bind(tournament_id)
+    .bind(from)
+    .bind(to)
+    .bind(delta)
+    .execute(&mut *conn)
+    .await?;
+    sqlx::query(
+        "UPDATE tournament_structures SET level_number = -level_number
+         WHERE tournament_id = $1 AND level_number < 0",
+    )
+    .bind(tournament_id)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+/// Insert a level at `level.level_number`, moving that level and the ones
+/// after it down one.
+pub async fn insert_level(
+    conn: &mut sqlx::PgConnection,
+    tournament_id: Uuid,
+    level: TournamentStructureLevel,
+) -> SqlxResult<TournamentStructureRow> {
+    shift_levels(conn, tournament_id, level.level_number, i32::MAX - 1, 1).await?;
+    add_structure(&mut *conn, tournament_id, level).await
+}
+
+/// Overwrite a level's blinds and timing. `None` if there's no such level.
+pub async fn update_level<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    level: TournamentStructureLevel,
+) -> SqlxResult<Option<TournamentStructureRow>> {
+    sqlx::query_as::<_, TournamentStructureRow>(
+        "UPDATE tournament_structures
+         SET small_blind = $3, big_blind = $4, ante = $5, duration_minutes = $6, is_break = $7,
+             break_duration_minutes = $8, color_up_denomination = $9
+         WHERE tournament_id = $1 AND level_number = $2
+         RETURNING id, tournament_id, level_number, small_blind, big_blind, ante,
+                   duration_minutes, is_break, break_duration_minutes, color_up_denomination, created_at",
+    )
+    .bind(tournament_id)
+    .bind(level.level_number)
+    .bind(level.small_blind)
+    .bind(level.big_blind)
+    .bind(level.ante)
+    .bind(level.duration_minutes)
+    .bind(level.is_break)
+    .bind(level.break_duration_minutes)
+    .bind(level.color_up_denomination)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Remove a level and close the gap. `false` if there's no such level.
+pub async fn delete_level(
+    conn: &mut sqlx::PgConnection,
+    tournament_id: Uuid,
+    level_number: i32,
+) -> SqlxResult<bool> {
+    let deleted = sqlx::query(
+        "DELETE FROM tournament_structures WHERE tournament_id = $1 AND level_number = $2",
+    )
+    .bind(tournament_id)
+    .bind(level_number)
+    .execute(&mut *conn)
+    .await?
+    .rows_affected()
+        > 0;
+    if deleted {
+        shift_levels(conn, tournament_id, level_number + 1, i32::MAX - 1, -1).await?;
+    }
+    Ok(deleted)
+}
+
+/// Move a level to position `to`, the levels in between closing up behind
+/// it. `false` if there's no level `from`.
+pub async fn move_level(
+    conn: &mut sqlx::PgConnection,
+    tournament_id: Uuid,
+    from: i32,
+    to: i32,
+) -> SqlxResult<bool> {
+    // Park the level on 0 while the others make room.
+    let parked = sqlx::query(
+        "UPDATE tournament_structures SET level_number = 0
+         WHERE tournament_id = $1 AND level_number = $2",
+    )
+    .bind(tournament_id)
+    .bind(from)
+    .execute(&mut *conn)
+    .await?
+    .rows_affected()
+        > 0;
+    if !parked {
+        return Ok(false);
+    }
+    if from < to {
+        shift_levels(conn, tournament_id, from + 1, to, -1).await?;
+    } else {
+        shift_levels(conn, tournament_id, to, from - 1, 1).await?;
+    }
+    sqlx::query(
+        "UPDATE tournament_structures SET level_number = $2
+         WHERE tournament_id = $1 AND level_number = 0",
+    )
+    .bind(tournament_id)
+    .bind(to)
+    .execute(&mut *conn)
+    .await?;
+    Ok(true)
+}
+
 /// Log clock event into the unified activity log
 async fn log_event(
     pool: &PgPool,
@@ -3,23 +3,61 @@ use uuid::Uuid;
 
 use crate::models::TournamentEntryRow;
 
-const COLS: &str = "id, tournament_id, user_id, club_player_id, entry_type, amount_cents, chips_received, recorded_by, notes, payment_method, created_at, updated_at";
+const COLS: &str = "id, tournament_id, user_id, club_player_id, entry_type, amount_cents, chips_received, recorded_by, notes, payment_method, price_tier, level_number, created_at, updated_at";
 
 #[derive(Debug, Clone, Default)]
 pub struct CreateTournamentEntry {
+    /// Client-generated id (offline desks); None lets the database pick one.
+    pub id: Option<Uuid>,
     pub tournament_id: Uuid,
     /// App user, when the player has an account. The link trigger stamps
     /// whichever of user_id / club_player_id is missing.
     pub user_id: Option<Uuid>,
     pub club_player_id: Option<Uuid>,
     pub entry_type: String,
-    pub amount_cents: i32,
+    pub amount_cents: i64,
     pub chips_received: Option<i32>,
     pub recorded_by: Option<Uuid>,
     pub notes: Option<String>,
     /// How the player paid: cash | card | bank_transfer | voucher | comp | other.
     /// An empty value (e.g. from `Default`) is coerced to "cash" in `create`.
     pub payment_method: String,
+    /// Price tier a buy-in was charged at (`early_bird` | `regular`).
+    pub price_tier: Option<String>,
+}
+
+/// An entry the database refused on one of its entry rules (see the
+/// `entry_limits`, `tournament_cancellation` and `entries_payouts_final`
+/// migrations and `uniq_initial_entry_per_player`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryRejection {
+    /// The player already has an initial buy-in.
+    DuplicateInitial,
+    /// The player has used all of the tournament's `rebuy_max` rebuys.
+    RebuyLimit,
+    /// The tournament is finished.
+    TournamentFinished,
+    /// The tournament was cancelled.
+    TournamentCancelled,
+    /// The payouts are final and the entry would move the prize pool.
+    PayoutsFinalized,
+}
+
+impl EntryRejection {
+    /// The rule `error` broke, if it is an entry-rule violation.
+    pub fn classify(error: &sqlx::Error) -> Option<Self> {
+        let sqlx::Error::Database(db) = error else {
+            return None;
+        };
+        match db.constraint()? {
+            "uniq_initial_entry_per_player" => Some(Self::DuplicateInitial),
+            "tournament_entries_rebuy_limit" => Some(Self::RebuyLimit),
+            "tournament_entries_tournament_open" => Some(Self::TournamentFinished),
+            "tournament_entries_tournament_not_cancelled" => Some(Self::TournamentCancelled),
+            "tournament_entries_payouts_final" => Some(Self::PayoutsFinalized),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -32,9 +70,17 @@ pub struct TournamentEntryStats {
     pub rebuy_count: i64,
     pub re_entry_count: i64,
     pub addon_count: i64,
+    /// Rake charged on top of each initial entry and re-entry.
+    pub rake_cents: i64,
     pub total_rake_cents: i64,
     pub total_chips: i64,
     pub players_remaining: i64,
+    /// Buy-ins charged at the early-bird price, and their total.
+    pub early_bird_count: i64,
+    pub early_bird_amount_cents: i64,
+    /// Buy-ins charged at the regular price, and their total.
+    pub regular_count: i64,
+    pub regular_amount_cents: i64,
 }
 
 pub async fn create<'e>(
@@ -48,8 +94,8 @@ pub async fn create<'e>(
     };
     let row = sqlx::query_as::<_, TournamentEntryRow>(&format!(
         "INSERT INTO tournament_entries \
-            (tournament_id, user_id, club_player_id, entry_type, amount_cents, chips_received, recorded_by, notes, payment_method) \
-         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) RETURNING {COLS}"
+            (id, tournament_id, user_id, club_player_id, entry_type, amount_cents, chips_received, recorded_by, notes, payment_method, price_tier) \
+         VALUES (COALESCE($11, gen_random_uuid()), $1, $2, $3, $4, $5, $6, $7, $8, $9, $10) RETURNING {COLS}"
     ))
     .bind(data.tournament_id)
     .bind(data.user_id)
@@ -60,6 +106,8 @@ pub async fn create<'e>(
     .bind(data.recorded_by)
     .bind(data.notes)
     .bind(payment_method)
+    .bind(data.price_tier)
+    .bind(data.id)
     .fetch_one(executor)
     .await?;
 
@@ -85,7 +133,7 @@ pub async fn list_by_tournament<'e>(
     tournament_id: Uuid,
 ) -> Result<Vec<TournamentEntryRow>> {
     let rows = sqlx::query_as::<_, TournamentEntryRow>(&format!(
-        "SELECT {COLS} FROM tournament_entries WHERE tournament_id = $1 ORDER BY created_at ASC"
+        "SELECT {COLS} FROM tournament_entries WHERE tournament_id = $1 AND voided_at IS NULL ORDER BY created_at ASC"
     ))
     .bind(tournament_id)
     .fetch_all(executor)
@@ -100,7 +148,7 @@ pub async fn list_by_tournament_and_user<'e>(
     user_id: Uuid,
 ) -> Result<Vec<TournamentEntryRow>> {
     let rows = sqlx::query_as::<_, TournamentEntryRow>(&format!(
-        "SELECT {COLS} FROM tournament_entries WHERE tournament_id = $1 AND user_id = $2 ORDER BY created_at ASC"
+        "SELECT {COLS} FROM tournament_entries WHERE tournament_id = $1 AND user_id = $2 AND voided_at IS NULL ORDER BY created_at ASC"
     ))
     .bind(tournament_id)
     .bind(user_id)
@@ -110,20 +158,41 @@ pub async fn list_by_tournament_and_user<'e>(
     Ok(rows)
 }
 
+/// A roster player's entries, including account-less players.
+pub async fn list_by_tournament_and_club_player<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    club_player_id: Uuid,
+) -> Result<Vec<TournamentEntryRow>> {
+    let rows = sqlx::query_as::<_, TournamentEntryRow>(&format!(
+        "SELECT {COLS} FROM tournament_entries WHERE tournament_id = $1 AND club_player_id = $2 AND voided_at IS NULL ORDER BY created_at ASC"
+    ))
+    .bind(tournament_id)
+    .bind(club_player_id)
+    .fetch_all(executor)
+    .await?;
+
+    Ok(rows)
+}
+
 pub async fn get_stats<'e>(
     executor: impl PgExecutor<'e>,
     tournament_id: Uuid,
 ) -> Result<TournamentEntryStats> {
-    let row = sqlx::query_as::<_, (i64, i64, i64, i64, i64, i64, i64, i64, i64, i64)>(
+    let row = sqlx::query_as::<
+        _,
+        (i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64),
+    >(
         r#"
         SELECT
             COUNT(*) FILTER (WHERE e.entry_type IN ('initial', 'rebuy', 're_entry')) as total_entries,
-            COALESCE(SUM(e.amount_cents), 0) as total_amount_cents,
+            COALESCE(SUM(e.amount_cents), 0)::bigint as total_amount_cents,
             COUNT(DISTINCT e.club_player_id) as unique_players,
             COUNT(*) FILTER (WHERE e.entry_type = 'initial') as initial_count,
             COUNT(*) FILTER (WHERE e.entry_type = 'rebuy') as rebuy_count,
             COUNT(*) FILTER (WHERE e.entry_type = 're_entry') as re_entry_count,
             COUNT(*) FILTER (WHERE e.entry_type = 'addon') as addon_count,
+            COALESCE((SELECT rake_cents FROM tournaments WHERE id = $1), 0) as rake_cents,
             COALESCE(
                 COUNT(*) FILTER (WHERE e.entry_type IN ('initial', 're_entry'))
                 * (SELECT rake_cents FROM tournaments WHERE id = $1),
@@ -138,9 +207,15 @@ pub async fn get_stats<'e>(
                    WHERE r.tournament_id = $1) as total_chips,
             (SELECT COUNT(*) FROM tournament_registrations r
              WHERE r.tournament_id = $1
-               AND r.status IN ('registered', 'checked_in', 'seated')) as players_remaining
+               AND r.status IN ('registered', 'checked_in', 'seated')) as players_remaining,
+            COUNT(*) FILTER (WHERE e.price_tier = 'early_bird') as early_bird_count,
+            COALESCE(SUM(e.amount_cents) FILTER (WHERE e.price_tier = 'early_bird'), 0)::bigint
+                as early_bird_amount_cents,
+            COUNT(*) FILTER (WHERE e.price_tier = 'regular') as regular_count,
+            COALESCE(SUM(e.amount_cents) FILTER (WHERE e.price_tier = 'regular'), 0)::bigint
+                as regular_amount_cents
         FROM tournament_entries e
-        WHERE e.tournament_id = $1
+        WHERE e.tournament_id = $1 AND e.voided_at IS NULL
         "#,
     )
     .bind(tournament_id)
@@ -156,9 +231,14 @@ pub async fn get_stats<'e>(
         rebuy_count: row.4,
         re_entry_count: row.5,
         addon_count: row.6,
-        total_rake_cents: row.7,
-        total_chips: row.8,
-        players_remaining: row.9,
+        rake_cents: row.7,
+        total_rake_cents: row.8,
+        total_chips: row.9,
+        players_remaining: row.10,
+        early_bird_count: row.11,
+        early_bird_amount_cents: row.12,
+        regular_count: row.13,
+        regular_amount_cents: row.14,
     })
 }
 
@@ -181,10 +261,10 @@ pub async fn get_cash_report<'e>(
         r#"
         SELECT payment_method,
                entry_type,
-               COALESCE(SUM(amount_cents), 0) AS amount_cents,
+               COALESCE(SUM(amount_cents), 0)::bigint AS amount_cents,
                COUNT(*) AS cnt
         FROM tournament_entries
-        WHERE tournament_id = $1
+        WHERE tournament_id = $1 AND voided_at IS NULL
         GROUP BY payment_method, entry_type
         ORDER BY payment_method, entry_type
         "#,
@@ -214,12 +294,30 @@ pub async fn delete<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> Result<bool>
     Ok(result.rows_affected() > 0)
 }
 
+/// Void every live entry of `tournament_id`, keeping the rows as a record
+/// of what was paid; they drop out of every list, total and the prize pool.
+pub async fn void_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    voided_by: Option<Uuid>,
+) -> Result<Vec<TournamentEntryRow>> {
+    sqlx::query_as::<_, TournamentEntryRow>(&format!(
+        "UPDATE tournament_entries SET voided_at = NOW(), voided_by = $2, updated_at = NOW() \
+         WHERE tournament_id = $1 AND voided_at IS NULL RETURNING {COLS}"
+    ))
+    .bind(tournament_id)
+    .bind(voided_by)
+    .fetch_all(executor)
+    .await
+}
+
 pub async fn get_total_prize_pool<'e>(
     executor: impl PgExecutor<'e>,
     tournament_id: Uuid,
 ) -> Result<i64> {
     let result: (i64,) = sqlx::query_as(
-        "SELECT COALESCE(SUM(amount_cents), 0) FROM tournament_entries WHERE tournament_id = $1",
+        "SELECT COALESCE(SUM(amount_cents), 0)::bigint FROM tournament_entries \
+         WHERE tournament_id = $1 AND voided_at IS NULL",
     )
     .bind(tournament_id)
     .fetch_one(executor)
@@ -0,0 +1,100 @@
+//! Invite links for private tournaments, with optional use limits and expiry.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "id, tournament_id, label, max_uses, use_count, expires_at, revoked_at, \
+     created_by, created_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct InviteRow {
+    pub id: Uuid,
+    pub tournament_id: Uuid,
+    pub label: Option<String>,
+    /// `None` = unlimited.
+    pub max_uses: Option<i32>,
+    pub use_count: i32,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    label: Option<&str>,
+    max_uses: Option<i32>,
+    expires_at: Option<DateTime<Utc>>,
+    created_by: Uuid,
+) -> SqlxResult<InviteRow> {
+    sqlx::query_as::<_, InviteRow>(&format!(
+        "INSERT INTO tournament_invites (tournament_id, label, max_uses, expires_at, created_by) \
+         VALUES ($1, $2, $3, $4, $5) RETURNING {COLS}"
+    ))
+    .bind(tournament_id)
+    .bind(label)
+    .bind(max_uses)
+    .bind(expires_at)
+    .bind(created_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<InviteRow>> {
+    sqlx::query_as::<_, InviteRow>(&format!(
+        "SELECT {COLS} FROM tournament_invites WHERE id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Lock an invite so concurrent registrations can't both take its last use.
+pub async fn lock<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<Option<InviteRow>> {
+    sqlx::query_as::<_, InviteRow>(&format!(
+        "SELECT {COLS} FROM tournament_invites WHERE id = $1 FOR UPDATE"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A tournament's invites, newest first.
+pub async fn list_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<InviteRow>> {
+    sqlx::query_as::<_, InviteRow>(&format!(
+        "SELECT {COLS} FROM tournament_invites WHERE tournament_id = $1 \
+         ORDER BY created_at DESC, id"
+    ))
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Count a registration against the invite.
+pub async fn record_use<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<()> {
+    sqlx::query("UPDATE tournament_invites SET use_count = use_count + 1 WHERE id = $1")
+        .bind(id)
+        .execute(executor)
+        .await?;
+    Ok(())
+}
+
+/// Revoke an invite. `None` when it doesn't exist; revoking twice keeps the
+/// first timestamp.
+pub async fn revoke<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<Option<InviteRow>> {
+    sqlx::query_as::<_, InviteRow>(&format!(
+        "UPDATE tournament_invites SET revoked_at = COALESCE(revoked_at, NOW()) \
+         WHERE id = $1 RETURNING {COLS}"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
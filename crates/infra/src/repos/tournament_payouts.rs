@@ -10,7 +10,8 @@ pub async fn get_by_tournament<'e>(
     let row = sqlx::query_as::<_, TournamentPayoutRow>(
         r#"
         SELECT id, tournament_id, template_id, player_count,
-               total_prize_pool, payout_positions, created_at, updated_at
+               total_prize_pool, payout_positions, finalized_at, finalized_by,
+               created_at, updated_at
         FROM tournament_payouts
         WHERE tournament_id = $1
         "#,
@@ -29,7 +30,8 @@ pub async fn get_by_id<'e>(
     let row = sqlx::query_as::<_, TournamentPayoutRow>(
         r#"
         SELECT id, tournament_id, template_id, player_count,
-               total_prize_pool, payout_positions, created_at, updated_at
+               total_prize_pool, payout_positions, finalized_at, finalized_by,
+               created_at, updated_at
         FROM tournament_payouts
         WHERE id = $1
         "#,
@@ -52,7 +54,8 @@ pub async fn update_positions<'e>(
         SET payout_positions = $2, updated_at = NOW()
         WHERE tournament_id = $1
         RETURNING id, tournament_id, template_id, player_count,
-                  total_prize_pool, payout_positions, created_at, updated_at
+                  total_prize_pool, payout_positions, finalized_at, finalized_by,
+               created_at, updated_at
         "#,
     )
     .bind(tournament_id)
@@ -63,6 +66,47 @@ pub async fn update_positions<'e>(
     Ok(row)
 }
 
+/// Freeze the payout table as the official one. The entries trigger stops
+/// recalculating it; `None` when there is no table or it is already final.
+pub async fn finalize<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    finalized_by: Option<Uuid>,
+) -> Result<Option<TournamentPayoutRow>> {
+    let row = sqlx::query_as::<_, TournamentPayoutRow>(
+        r#"
+        UPDATE tournament_payouts
+        SET finalized_at = NOW(), finalized_by = $2, updated_at = NOW()
+        WHERE tournament_id = $1 AND finalized_at IS NULL
+        RETURNING id, tournament_id, template_id, player_count,
+                  total_prize_pool, payout_positions, finalized_at, finalized_by,
+                  created_at, updated_at
+        "#,
+    )
+    .bind(tournament_id)
+    .bind(finalized_by)
+    .fetch_optional(executor)
+    .await?;
+
+    Ok(row)
+}
+
+/// Unfreeze a finalized payout table so it follows the entries again.
+pub async fn reopen<'e>(executor: impl PgExecutor<'e>, tournament_id: Uuid) -> Result<bool> {
+    let result = sqlx::query(
+        r#"
+        UPDATE tournament_payouts
+        SET finalized_at = NULL, finalized_by = NULL, updated_at = NOW()
+        WHERE tournament_id = $1 AND finalized_at IS NOT NULL
+        "#,
+    )
+    .bind(tournament_id)
+    .execute(executor)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
 pub async fn delete<'e>(executor: impl PgExecutor<'e>, tournament_id: Uuid) -> Result<bool> {
     let result = sqlx::query("DELETE FROM tournament_payouts WHERE tournament_id = $1")
         .bind(tournament_id)
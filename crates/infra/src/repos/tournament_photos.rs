@@ -0,0 +1,168 @@
+//! Tournament photo galleries: photos uploaded to the media service, linked
+//! by URL, with a caption and the players tagged in them.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str =
+    "id, tournament_id, url, content_type, caption, uploaded_by, created_at, updated_at";
+
+/// Whether the tag `t` of the `club_player` row `cp` consents to the photo
+/// being public: a player with an account decides in their privacy settings
+/// (`ups`), the club records it for one without.
+const CONSENTED: &str = "CASE WHEN cp.app_user_id IS NOT NULL \
+     THEN COALESCE(ups.appear_in_photos, FALSE) ELSE t.consent_recorded END";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct TournamentPhotoRow {
+    pub id: Uuid,
+    pub tournament_id: Uuid,
+    pub url: String,
+    pub content_type: Option<String>,
+    pub caption: Option<String>,
+    pub uploaded_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A player tagged in a photo.
+#[derive(Debug, Clone, FromRow)]
+pub struct PhotoTagRow {
+    pub photo_id: Uuid,
+    pub club_player_id: Uuid,
+    pub display_name: String,
+    pub consent_recorded: bool,
+    /// The player's consent to the photo being shown publicly.
+    pub consented: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreatePhoto {
+    pub tournament_id: Uuid,
+    pub url: String,
+    pub content_type: Option<String>,
+    pub caption: Option<String>,
+    pub uploaded_by: Uuid,
+}
+
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    data: CreatePhoto,
+) -> SqlxResult<TournamentPhotoRow> {
+    sqlx::query_as::<_, TournamentPhotoRow>(&format!(
+        "INSERT INTO tournament_photos (tournament_id, url, content_type, caption, uploaded_by) \
+         VALUES ($1, $2, $3, $4, $5) RETURNING {COLS}"
+    ))
+    .bind(data.tournament_id)
+    .bind(data.url)
+    .bind(data.content_type)
+    .bind(data.caption)
+    .bind(data.uploaded_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<TournamentPhotoRow>> {
+    sqlx::query_as::<_, TournamentPhotoRow>(&format!(
+        "SELECT {COLS} FROM tournament_photos WHERE id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A tournament's photos, oldest first. With `consented_only`, just those
+/// every tagged player consents to showing publicly.
+pub async fn list_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    consented_only: bool,
+) -> SqlxResult<Vec<TournamentPhotoRow>> {
+    sqlx::query_as::<_, TournamentPhotoRow>(&format!(
+        "SELECT {COLS} FROM tournament_photos p \
+         WHERE p.tournament_id = $1 \
+           AND (NOT $2 OR NOT EXISTS ( \
+                SELECT 1 FROM tournament_photo_tags t \
+                JOIN club_player_public cp ON cp.id = t.club_player_id \
+                LEFT JOIN user_privacy_settings ups ON ups.app_user_id = cp.app_user_id \
+                WHERE t.photo_id = p.id AND NOT ({CONSENTED}))) \
+         ORDER BY p.created_at ASC, p.id"
+    ))
+    .bind(tournament_id)
+    .bind(consented_only)
+    .fetch_all(executor)
+    .await
+}
+
+pub async fn update_caption<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    caption: Option<&str>,
+) -> SqlxResult<Option<TournamentPhotoRow>> {
+    sqlx::query_as::<_, TournamentPhotoRow>(&format!(
+        "UPDATE tournament_photos SET caption = $2 WHERE id = $1 RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(caption)
+    .fetch_optional(executor)
+    .await
+}
+
+pub async fn delete<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<bool> {
+    let result = sqlx::query("DELETE FROM tournament_photos WHERE id = $1")
+        .bind(id)
+        .execute(executor)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// The players tagged in a photo, by name.
+pub async fn list_tags<'e>(
+    executor: impl PgExecutor<'e>,
+    photo_id: Uuid,
+) -> SqlxResult<Vec<PhotoTagRow>> {
+    sqlx::query_as::<_, PhotoTagRow>(&format!(
+        "SELECT t.photo_id, t.club_player_id, cp.display_name, t.consent_recorded, \
+                {CONSENTED} AS consented \
+         FROM tournament_photo_tags t \
+         JOIN club_player_public cp ON cp.id = t.club_player_id \
+         LEFT JOIN user_privacy_settings ups ON ups.app_user_id = cp.app_user_id \
+         WHERE t.photo_id = $1 \
+         ORDER BY cp.display_name ASC, cp.id"
+    ))
+    .bind(photo_id)
+    .fetch_all(executor)
+    .await
+}
+
+pub async fn clear_tags<'e>(executor: impl PgExecutor<'e>, photo_id: Uuid) -> SqlxResult<()> {
+    sqlx::query("DELETE FROM tournament_photo_tags WHERE photo_id = $1")
+        .bind(photo_id)
+        .execute(executor)
+        .await?;
+    Ok(())
+}
+
+pub async fn add_tag<'e>(
+    executor: impl PgExecutor<'e>,
+    photo_id: Uuid,
+    club_player_id: Uuid,
+    consent_recorded: bool,
+) -> SqlxResult<()> {
+    sqlx::query(
+        "INSERT INTO tournament_photo_tags (photo_id, club_player_id, consent_recorded) \
+         VALUES ($1, $2, $3) \
+         ON CONFLICT (photo_id, club_player_id) \
+         DO UPDATE SET consent_recorded = EXCLUDED.consent_recorded",
+    )
+    .bind(photo_id)
+    .bind(club_player_id)
+    .bind(consent_recorded)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
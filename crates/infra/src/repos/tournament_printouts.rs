@@ -0,0 +1,189 @@
+//! Generated tournament PDFs (seat cards, seat list, payout sheet) and the
+//! data they are rendered from.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+use super::club_name_settings::NameSettingsRow;
+
+/// Printout metadata; the PDF bytes are only loaded for downloads.
+const COLS: &str =
+    "id, tournament_id, kind, page_count, octet_length(content)::BIGINT AS size_bytes, \
+     generated_by, generated_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct PrintoutRow {
+    pub id: Uuid,
+    pub tournament_id: Uuid,
+    /// `seat_cards` | `seat_list` | `payout_sheet`.
+    pub kind: String,
+    pub page_count: i32,
+    pub size_bytes: i64,
+    pub generated_by: Option<Uuid>,
+    pub generated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct PrintoutContentRow {
+    pub kind: String,
+    pub tournament_name: String,
+    pub content: Vec<u8>,
+}
+
+/// A seated player at a table linked to the tournament, or a linked table
+/// nobody sits at yet (`seat_number` and `player_name` are `None`).
+#[derive(Debug, Clone, FromRow)]
+pub struct SeatRow {
+    pub table_number: i32,
+    pub max_seats: i32,
+    pub seat_number: Option<i32>,
+    pub player_name: Option<String>,
+}
+
+/// One finishing place for the payout sheet.
+#[derive(Debug, Clone, FromRow)]
+pub struct PayoutRow {
+    pub final_position: i32,
+    pub player_name: String,
+    pub prize_cents: i64,
+}
+
+/// Store a freshly generated printout, replacing the previous one of the
+/// same kind.
+pub async fn upsert<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    kind: &str,
+    content: &[u8],
+    page_count: i32,
+    generated_by: Option<Uuid>,
+) -> SqlxResult<PrintoutRow> {
+    sqlx::query_as::<_, PrintoutRow>(&format!(
+        "INSERT INTO tournament_printouts (tournament_id, kind, content, page_count, generated_by) \
+         VALUES ($1, $2, $3, $4, $5) \
+         ON CONFLICT (tournament_id, kind) DO UPDATE SET \
+             content = EXCLUDED.content, page_count = EXCLUDED.page_count, \
+             generated_by = EXCLUDED.generated_by, generated_at = NOW() \
+         RETURNING {COLS}"
+    ))
+    .bind(tournament_id)
+    .bind(kind)
+    .bind(content)
+    .bind(page_count)
+    .bind(generated_by)
+    .fetch_one(executor)
+    .await
+}
+
+/// A tournament's printouts, in a fixed kind order.
+pub async fn list_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<PrintoutRow>> {
+    sqlx::query_as::<_, PrintoutRow>(&format!(
+        "SELECT {COLS} FROM tournament_printouts WHERE tournament_id = $1 \
+         ORDER BY array_position(ARRAY['seat_cards', 'seat_list', 'payout_sheet'], kind)"
+    ))
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// The PDF bytes of a printout, with the tournament name for the filename.
+pub async fn get_content<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<PrintoutContentRow>> {
+    sqlx::query_as::<_, PrintoutContentRow>(
+        "SELECT p.kind, t.name AS tournament_name, p.content \
+         FROM tournament_printouts p JOIN tournaments t ON t.id = p.tournament_id \
+         WHERE p.id = $1",
+    )
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// The current seating of the tables linked to the tournament, by table then
+/// seat, names printed the club's way. Open seats are not returned: the
+/// renderer fills them in from `max_seats`.
+pub async fn list_seats<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    names: &NameSettingsRow,
+) -> SqlxResult<Vec<SeatRow>> {
+    let player_name = names.printed_name("cp");
+    sqlx::query_as::<_, SeatRow>(&format!(
+        r#"
+        SELECT ct.table_number,
+               COALESCE(tta.max_seats_override, LEAST(ct.max_seats, t.seats_per_table)) AS max_seats,
+               tsa.seat_number,
+               {player_name} AS player_name
+        FROM tournament_table_assignments tta
+        JOIN tournaments t ON t.id = tta.tournament_id
+        JOIN club_tables ct ON ct.id = tta.club_table_id
+        LEFT JOIN table_seat_assignments tsa
+               ON tsa.club_table_id = ct.id
+              AND tsa.tournament_id = tta.tournament_id
+              AND tsa.is_current = true
+        LEFT JOIN club_player cp ON cp.id = tsa.club_player_id
+        WHERE tta.tournament_id = $1 AND tta.is_active = true
+        ORDER BY ct.table_number, tsa.seat_number
+        "#
+    ))
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// The seated players of the tournament in the club's name order and
+/// collation, for the alphabetical seat list.
+pub async fn list_seated_by_name<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    names: &NameSettingsRow,
+) -> SqlxResult<Vec<SeatRow>> {
+    let player_name = names.printed_name("cp");
+    let order_by = names.order_by("cp");
+    sqlx::query_as::<_, SeatRow>(&format!(
+        r#"
+        SELECT ct.table_number,
+               COALESCE(tta.max_seats_override, LEAST(ct.max_seats, t.seats_per_table)) AS max_seats,
+               tsa.seat_number,
+               {player_name} AS player_name
+        FROM tournament_table_assignments tta
+        JOIN tournaments t ON t.id = tta.tournament_id
+        JOIN club_tables ct ON ct.id = tta.club_table_id
+        JOIN table_seat_assignments tsa
+          ON tsa.club_table_id = ct.id
+         AND tsa.tournament_id = tta.tournament_id
+         AND tsa.is_current = true
+        JOIN club_player cp ON cp.id = tsa.club_player_id
+        WHERE tta.tournament_id = $1 AND tta.is_active = true
+        ORDER BY {order_by}
+        "#
+    ))
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// The tournament's finishing places, in order.
+pub async fn list_payouts<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<PayoutRow>> {
+    sqlx::query_as::<_, PayoutRow>(
+        r#"
+        SELECT r.final_position, cp.display_name AS player_name, r.prize_cents::BIGINT AS prize_cents
+        FROM tournament_results r
+        JOIN club_player cp ON cp.id = r.club_player_id
+        WHERE r.tournament_id = $1
+        ORDER BY r.final_position, cp.display_name
+        "#,
+    )
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
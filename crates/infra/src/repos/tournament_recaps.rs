@@ -0,0 +1,115 @@
+//! Results recaps: the stored summary of a tournament's results, and where
+//! each club wants them delivered.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "tournament_id, payload, generated_at, delivered_at, delivery_error";
+const SETTINGS_COLS: &str = "club_id, webhook_url, webhook_secret, created_at, updated_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct TournamentRecapRow {
+    pub tournament_id: Uuid,
+    pub payload: serde_json::Value,
+    pub generated_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub delivery_error: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RecapSettingsRow {
+    pub club_id: Uuid,
+    /// Where recaps are POSTed; `None` keeps them query-only.
+    pub webhook_url: Option<String>,
+    /// Key for the delivery signature; `None` sends them unsigned.
+    pub webhook_secret: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Store a freshly generated recap, replacing the previous one and its
+/// delivery state.
+pub async fn upsert<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    payload: &serde_json::Value,
+) -> SqlxResult<TournamentRecapRow> {
+    sqlx::query_as::<_, TournamentRecapRow>(&format!(
+        "INSERT INTO tournament_recaps (tournament_id, payload) VALUES ($1, $2) \
+         ON CONFLICT (tournament_id) DO UPDATE SET \
+            payload = EXCLUDED.payload, \
+            generated_at = NOW(), \
+            delivered_at = NULL, \
+            delivery_error = NULL \
+         RETURNING {COLS}"
+    ))
+    .bind(tournament_id)
+    .bind(payload)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Option<TournamentRecapRow>> {
+    sqlx::query_as::<_, TournamentRecapRow>(&format!(
+        "SELECT {COLS} FROM tournament_recaps WHERE tournament_id = $1"
+    ))
+    .bind(tournament_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Record the outcome of a webhook delivery: `error` is `None` on success.
+pub async fn mark_delivery<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    error: Option<&str>,
+) -> SqlxResult<()> {
+    sqlx::query(
+        "UPDATE tournament_recaps SET \
+            delivered_at = CASE WHEN $2::TEXT IS NULL THEN NOW() ELSE delivered_at END, \
+            delivery_error = $2 \
+         WHERE tournament_id = $1",
+    )
+    .bind(tournament_id)
+    .bind(error)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+pub async fn get_settings<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+) -> SqlxResult<Option<RecapSettingsRow>> {
+    sqlx::query_as::<_, RecapSettingsRow>(&format!(
+        "SELECT {SETTINGS_COLS} FROM club_recap_settings WHERE club_id = $1"
+    ))
+    .bind(club_id)
+    .fetch_optional(executor)
+    .await
+}
+
+pub async fn upsert_settings<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    webhook_url: Option<&str>,
+    webhook_secret: Option<&str>,
+) -> SqlxResult<RecapSettingsRow> {
+    sqlx::query_as::<_, RecapSettingsRow>(&format!(
+        "INSERT INTO club_recap_settings (club_id, webhook_url, webhook_secret) \
+         VALUES ($1, $2, $3) \
+         ON CONFLICT (club_id) DO UPDATE SET \
+            webhook_url = EXCLUDED.webhook_url, \
+            webhook_secret = EXCLUDED.webhook_secret \
+         RETURNING {SETTINGS_COLS}"
+    ))
+    .bind(club_id)
+    .bind(webhook_url)
+    .bind(webhook_secret)
+    .fetch_one(executor)
+    .await
+}
@@ -0,0 +1,107 @@
+//! Money a cancelled tournament owes its players. One row per player and
+//! payment method; the desk marks it refunded once the money is handed back.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+const COLS: &str = "id, club_id, tournament_id, registration_id, club_player_id, amount_cents, \
+                    entry_count, payment_method, status, created_by, created_at, refunded_by, \
+                    refunded_at";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct TournamentRefundRow {
+    pub id: Uuid,
+    pub club_id: Uuid,
+    pub tournament_id: Uuid,
+    pub registration_id: Option<Uuid>,
+    pub club_player_id: Uuid,
+    pub amount_cents: i64,
+    pub entry_count: i32,
+    pub payment_method: String,
+    /// `pending` | `refunded`.
+    pub status: String,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub refunded_by: Option<Uuid>,
+    pub refunded_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewTournamentRefund {
+    pub tournament_id: Uuid,
+    pub registration_id: Option<Uuid>,
+    pub club_player_id: Uuid,
+    pub amount_cents: i64,
+    pub entry_count: i32,
+    pub payment_method: String,
+    pub created_by: Option<Uuid>,
+}
+
+/// Record a pending refund; the club comes from the tournament.
+pub async fn create<'e>(
+    executor: impl PgExecutor<'e>,
+    refund: NewTournamentRefund,
+) -> SqlxResult<TournamentRefundRow> {
+    sqlx::query_as::<_, TournamentRefundRow>(&format!(
+        "INSERT INTO tournament_refunds \
+            (club_id, tournament_id, registration_id, club_player_id, amount_cents, entry_count, \
+             payment_method, created_by) \
+         SELECT t.club_id, t.id, $2, $3, $4, $5, $6, $7 FROM tournaments t WHERE t.id = $1 \
+         RETURNING {COLS}"
+    ))
+    .bind(refund.tournament_id)
+    .bind(refund.registration_id)
+    .bind(refund.club_player_id)
+    .bind(refund.amount_cents)
+    .bind(refund.entry_count)
+    .bind(refund.payment_method)
+    .bind(refund.created_by)
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn get_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<TournamentRefundRow>> {
+    sqlx::query_as::<_, TournamentRefundRow>(&format!(
+        "SELECT {COLS} FROM tournament_refunds WHERE id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A tournament's refunds, pending first, then oldest first.
+pub async fn list_for_tournament<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> SqlxResult<Vec<TournamentRefundRow>> {
+    sqlx::query_as::<_, TournamentRefundRow>(&format!(
+        "SELECT {COLS} FROM tournament_refunds WHERE tournament_id = $1 \
+         ORDER BY status = 'refunded', created_at, id"
+    ))
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Mark a pending refund as handed back. None when it is unknown or was
+/// already refunded.
+pub async fn mark_refunded<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    refunded_by: Option<Uuid>,
+) -> SqlxResult<Option<TournamentRefundRow>> {
+    sqlx::query_as::<_, TournamentRefundRow>(&format!(
+        "UPDATE tournament_refunds \
+         SET status = 'refunded', refunded_by = $2, refunded_at = NOW() \
+         WHERE id = $1 AND status = 'pending' \
+         RETURNING {COLS}"
+    ))
+    .bind(id)
+    .bind(refunded_by)
+    .fetch_optional(executor)
+    .await
+}
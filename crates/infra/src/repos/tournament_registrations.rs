@@ -1,13 +1,16 @@
+use chrono::{DateTime, Utc};
 use sqlx::{PgExecutor, Result};
 use uuid::Uuid;
 
 use crate::models::TournamentRegistrationRow;
 
 const COLS: &str =
-    "id, tournament_id, user_id, club_player_id, registration_time, status, notes, current_bounty_cents, starting_stack, created_at, updated_at";
+    "id, tournament_id, user_id, club_player_id, registration_time, status, notes, current_bounty_cents, starting_stack, invite_id, last_seen_at, alternate_position, created_at, updated_at";
 
 #[derive(Debug, Clone, Default)]
 pub struct CreateTournamentRegistration {
+    /// Client-generated id (offline desks); None lets the database pick one.
+    pub id: Option<Uuid>,
     pub tournament_id: Uuid,
     /// App user, when the player has an account. Optional — account-less players
     /// (registered by the club) carry only a roster id. The link trigger stamps
@@ -16,6 +19,8 @@ pub struct CreateTournamentRegistration {
     pub club_player_id: Option<Uuid>,
     pub notes: Option<String>,
     pub status: Option<String>,
+    /// Invite link the player registered through.
+    pub invite_id: Option<Uuid>,
 }
 
 pub async fn create<'e>(
@@ -24,9 +29,9 @@ pub async fn create<'e>(
 ) -> Result<TournamentRegistrationRow> {
     let row = sqlx::query_as::<_, TournamentRegistrationRow>(
         r#"
-        INSERT INTO tournament_registrations (tournament_id, user_id, club_player_id, notes, status)
-        VALUES ($1, $2, $3, $4, COALESCE($5, 'registered'))
-        RETURNING id, tournament_id, user_id, club_player_id, registration_time, status, notes, current_bounty_cents, starting_stack, created_at, updated_at
+        INSERT INTO tournament_registrations (id, tournament_id, user_id, club_player_id, notes, status, invite_id)
+        VALUES (COALESCE($7, gen_random_uuid()), $1, $2, $3, $4, COALESCE($5, 'registered'), $6)
+        RETURNING id, tournament_id, user_id, club_player_id, registration_time, status, notes, current_bounty_cents, starting_stack, invite_id, last_seen_at, alternate_position, created_at, updated_at
         "#
     )
     .bind(data.tournament_id)
@@ -34,16 +39,44 @@ pub async fn create<'e>(
     .bind(data.club_player_id)
     .bind(data.notes)
     .bind(data.status)
+    .bind(data.invite_id)
+    .bind(data.id)
     .fetch_one(executor)
     .await?;
 
     Ok(row)
 }
 
+/// Like [`create`], but `None` when the player (by user or roster identity)
+/// is already registered in the tournament, in whatever status.
+pub async fn create_if_absent<'e>(
+    executor: impl PgExecutor<'e>,
+    data: CreateTournamentRegistration,
+) -> Result<Option<TournamentRegistrationRow>> {
+    let row = sqlx::query_as::<_, TournamentRegistrationRow>(&format!(
+        "INSERT INTO tournament_registrations (id, tournament_id, user_id, club_player_id, notes, status, invite_id) \
+         VALUES (COALESCE($7, gen_random_uuid()), $1, $2, $3, $4, COALESCE($5, 'registered'), $6) \
+         ON CONFLICT DO NOTHING \
+         RETURNING {COLS}"
+    ))
+    .bind(data.tournament_id)
+    .bind(data.user_id)
+    .bind(data.club_player_id)
+    .bind(data.notes)
+    .bind(data.status)
+    .bind(data.invite_id)
+    .bind(data.id)
+    .fetch_optional(executor)
+    .await?;
+
+    Ok(row)
+}
+
 /// Seed a final-day registration for a Day-2 qualifier: status `checked_in`
 /// with the carried-over `starting_stack`. Idempotent on
 /// (tournament_id, club_player_id): re-running updates the stack (best stack
-/// forward) without creating a duplicate registration.
+/// forward) without creating a duplicate registration; a qualifier already
+/// seated for Day 2 stays seated.
 pub async fn upsert_checked_in_with_stack<'e>(
     executor: impl PgExecutor<'e>,
     tournament_id: Uuid,
@@ -55,10 +88,11 @@ pub async fn upsert_checked_in_with_stack<'e>(
         INSERT INTO tournament_registrations (tournament_id, club_player_id, status, starting_stack)
         VALUES ($1, $2, 'checked_in', $3)
         ON CONFLICT (tournament_id, club_player_id) DO UPDATE SET
-            status = 'checked_in',
+            status = CASE WHEN tournament_registrations.status = 'seated'
+                          THEN 'seated' ELSE 'checked_in' END,
             starting_stack = EXCLUDED.starting_stack,
             updated_at = NOW()
-        RETURNING id, tournament_id, user_id, club_player_id, registration_time, status, notes, current_bounty_cents, starting_stack, created_at, updated_at
+        RETURNING id, tournament_id, user_id, club_player_id, registration_time, status, notes, current_bounty_cents, starting_stack, invite_id, last_seen_at, alternate_position, created_at, updated_at
         "#,
     )
     .bind(tournament_id)
@@ -100,6 +134,36 @@ pub async fn get_by_tournament_and_user<'e>(
     Ok(row)
 }
 
+/// Record a presence heartbeat on the user's active registration. Writes at
+/// most once a minute per player; returns the registration, or None when the
+/// user has no active registration in the tournament.
+pub async fn touch_last_seen<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<TournamentRegistrationRow>> {
+    let row = sqlx::query_as::<_, TournamentRegistrationRow>(&format!(
+        "WITH touched AS ( \
+             UPDATE tournament_registrations SET last_seen_at = NOW() \
+             WHERE tournament_id = $1 AND user_id = $2 \
+               AND status IN ('registered', 'waitlisted', 'checked_in', 'seated') \
+               AND (last_seen_at IS NULL OR last_seen_at < NOW() - INTERVAL '1 minute') \
+             RETURNING {COLS}) \
+         SELECT {COLS} FROM touched \
+         UNION ALL \
+         SELECT {COLS} FROM tournament_registrations \
+         WHERE tournament_id = $1 AND user_id = $2 \
+           AND status IN ('registered', 'waitlisted', 'checked_in', 'seated') \
+           AND NOT EXISTS (SELECT 1 FROM touched)"
+    ))
+    .bind(tournament_id)
+    .bind(user_id)
+    .fetch_optional(executor)
+    .await?;
+
+    Ok(row)
+}
+
 pub async fn get_by_tournament_and_club_player<'e>(
     executor: impl PgExecutor<'e>,
     tournament_id: Uuid,
@@ -116,6 +180,41 @@ pub async fn get_by_tournament_and_club_player<'e>(
     Ok(row)
 }
 
+/// The user's registration, row-locked for the rest of the transaction so a
+/// concurrent check-in waits and then sees the new status.
+pub async fn lock_by_tournament_and_user<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<TournamentRegistrationRow>> {
+    let row = sqlx::query_as::<_, TournamentRegistrationRow>(&format!(
+        "SELECT {COLS} FROM tournament_registrations WHERE tournament_id = $1 AND user_id = $2 FOR UPDATE"
+    ))
+    .bind(tournament_id)
+    .bind(user_id)
+    .fetch_optional(executor)
+    .await?;
+
+    Ok(row)
+}
+
+/// Roster-keyed counterpart of [`lock_by_tournament_and_user`].
+pub async fn lock_by_tournament_and_club_player<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    club_player_id: Uuid,
+) -> Result<Option<TournamentRegistrationRow>> {
+    let row = sqlx::query_as::<_, TournamentRegistrationRow>(&format!(
+        "SELECT {COLS} FROM tournament_registrations WHERE tournament_id = $1 AND club_player_id = $2 FOR UPDATE"
+    ))
+    .bind(tournament_id)
+    .bind(club_player_id)
+    .fetch_optional(executor)
+    .await?;
+
+    Ok(row)
+}
+
 pub async fn list_by_tournament<'e>(
     executor: impl PgExecutor<'e>,
     tournament_id: Uuid,
@@ -182,10 +281,11 @@ pub async fn list_user_current<'e>(
 ) -> Result<Vec<TournamentRegistrationRow>> {
     let rows = sqlx::query_as::<_, TournamentRegistrationRow>(
         "SELECT tr.id, tr.tournament_id, tr.user_id, tr.club_player_id, tr.registration_time, \
-                tr.status, tr.notes, tr.current_bounty_cents, tr.starting_stack, tr.created_at, tr.updated_at \
+                tr.status, tr.notes, tr.current_bounty_cents, tr.starting_stack, tr.invite_id, tr.last_seen_at, tr.alternate_position, tr.created_at, tr.updated_at \
          FROM tournament_registrations tr \
          JOIN tournaments t ON tr.tournament_id = t.id \
-         WHERE tr.user_id = $1 AND (t.end_time IS NULL OR t.end_time > NOW()) \
+         WHERE tr.user_id = $1 AND t.deleted_at IS NULL \
+           AND (t.end_time IS NULL OR t.end_time > NOW()) \
            AND t.club_id NOT IN (SELECT id FROM clubs WHERE plan = 'free') \
          ORDER BY tr.created_at DESC",
     )
@@ -231,6 +331,26 @@ pub async fn update_status_by_club_player<'e>(
     Ok(())
 }
 
+/// Cancel every registration of a tournament, busted players included
+/// (alternates leave their queue); no-shows and rows already cancelled are
+/// left as they were. Returns the cancelled rows.
+pub async fn cancel_all<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> Result<Vec<TournamentRegistrationRow>> {
+    let rows = sqlx::query_as::<_, TournamentRegistrationRow>(&format!(
+        "UPDATE tournament_registrations \
+         SET status = 'cancelled', alternate_position = NULL, updated_at = NOW() \
+         WHERE tournament_id = $1 AND status NOT IN ('cancelled', 'no_show') \
+         RETURNING {COLS}"
+    ))
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await?;
+
+    Ok(rows)
+}
+
 /// Count confirmed registrations (those occupying a seat: registered, checked_in, seated, busted).
 /// Waitlisted, cancelled, and no_show do not count.
 pub async fn count_confirmed_by_tournament<'e>(
@@ -251,6 +371,20 @@ pub async fn count_confirmed_by_tournament<'e>(
     Ok(result)
 }
 
+/// When each busted player was eliminated, oldest first.
+pub async fn list_elimination_times<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> Result<Vec<DateTime<Utc>>> {
+    sqlx::query_scalar(
+        "SELECT eliminated_at FROM tournament_registrations \
+         WHERE tournament_id = $1 AND eliminated_at IS NOT NULL ORDER BY eliminated_at",
+    )
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
 /// Get the next waitlisted player (FIFO by registration_time).
 pub async fn get_next_waitlisted<'e>(
     executor: impl PgExecutor<'e>,
@@ -289,3 +423,199 @@ pub async fn get_waitlist_position<'e>(
 
     Ok(result.flatten())
 }
+
+/// Put a player at the end of the tournament's alternates queue. A new
+/// registration is created; a waitlisted, cancelled or no-show one is turned
+/// into an alternate. `None` when the player already holds a live place.
+pub async fn add_alternate<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    club_player_id: Uuid,
+    notes: Option<String>,
+) -> Result<Option<TournamentRegistrationRow>> {
+    let row = sqlx::query_as::<_, TournamentRegistrationRow>(&format!(
+        "INSERT INTO tournament_registrations (tournament_id, club_player_id, notes, status, alternate_position) \
+         VALUES ($1, $2, $3, 'alternate', \
+                 (SELECT COALESCE(MAX(alternate_position), 0) + 1 FROM tournament_registrations WHERE tournament_id = $1)) \
+         ON CONFLICT (tournament_id, club_player_id) DO UPDATE SET \
+             status = 'alternate', \
+             alternate_position = EXCLUDED.alternate_position, \
+             notes = COALESCE(EXCLUDED.notes, tournament_registrations.notes), \
+             updated_at = NOW() \
+         WHERE tournament_registrations.status IN ('waitlisted', 'cancelled', 'no_show') \
+         RETURNING {COLS}"
+    ))
+    .bind(tournament_id)
+    .bind(club_player_id)
+    .bind(notes)
+    .fetch_optional(executor)
+    .await?;
+
+    Ok(row)
+}
+
+/// The alternates still waiting for a seat, in queue order.
+pub async fn list_alternates<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> Result<Vec<TournamentRegistrationRow>> {
+    let rows = sqlx::query_as::<_, TournamentRegistrationRow>(&format!(
+        "SELECT {COLS} FROM tournament_registrations \
+         WHERE tournament_id = $1 AND status = 'alternate' \
+         ORDER BY alternate_position ASC, registration_time ASC"
+    ))
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Renumber the waiting alternates in the given order. Registrations not
+/// listed, or no longer alternates, keep their position.
+pub async fn reorder_alternates<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    registration_ids: &[Uuid],
+) -> Result<u64> {
+    let result = sqlx::query(
+        "UPDATE tournament_registrations r \
+         SET alternate_position = o.position, updated_at = NOW() \
+         FROM UNNEST($2::uuid[]) WITH ORDINALITY AS o(id, position) \
+         WHERE r.id = o.id AND r.tournament_id = $1 AND r.status = 'alternate'",
+    )
+    .bind(tournament_id)
+    .bind(registration_ids)
+    .execute(executor)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// Seats freed by eliminations that no alternate has taken yet: busted
+/// players minus the alternates already brought in.
+pub async fn count_alternate_openings<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> Result<i64> {
+    sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT GREATEST(
+            COUNT(*) FILTER (WHERE status = 'busted')
+              - COUNT(*) FILTER (WHERE alternate_position IS NOT NULL
+                                 AND status IN ('checked_in', 'seated', 'busted')),
+            0)
+        FROM tournament_registrations
+        WHERE tournament_id = $1
+        "#,
+    )
+    .bind(tournament_id)
+    .fetch_one(executor)
+    .await
+}
+
+/// Take a waiting alternate out of the queue into `status` (`waitlisted` or
+/// `cancelled`).
+pub async fn release_alternate<'e>(
+    executor: impl PgExecutor<'e>,
+    registration_id: Uuid,
+    status: &str,
+) -> Result<bool> {
+    let result = sqlx::query(
+        "UPDATE tournament_registrations \
+         SET status = $2, alternate_position = NULL, updated_at = NOW() \
+         WHERE id = $1 AND status = 'alternate'",
+    )
+    .bind(registration_id)
+    .bind(status)
+    .execute(executor)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Tournaments in late registration with alternates waiting.
+pub async fn list_tournaments_with_alternates<'e>(
+    executor: impl PgExecutor<'e>,
+) -> Result<Vec<Uuid>> {
+    sqlx::query_scalar(
+        "SELECT DISTINCT r.tournament_id \
+         FROM tournament_registrations r \
+         JOIN tournaments t ON t.id = r.tournament_id \
+         WHERE r.status = 'alternate' AND t.live_status = 'late_registration'",
+    )
+    .fetch_all(executor)
+    .await
+}
+
+/// Get a player's place in the alternates queue (1-based). Returns None if
+/// not an alternate.
+pub async fn get_alternate_position<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    club_player_id: Uuid,
+) -> Result<Option<i64>> {
+    let result = sqlx::query_scalar::<_, Option<i64>>(
+        r#"
+        SELECT position FROM (
+            SELECT club_player_id,
+                   ROW_NUMBER() OVER (ORDER BY alternate_position ASC, registration_time ASC) as position
+            FROM tournament_registrations
+            WHERE tournament_id = $1 AND status = 'alternate'
+        ) ranked
+        WHERE club_player_id = $2
+        "#,
+    )
+    .bind(tournament_id)
+    .bind(club_player_id)
+    .fetch_optional(executor)
+    .await?;
+
+    Ok(result.flatten())
+}
+
+/// Registrations per status, for the registration funnel.
+pub async fn count_by_status<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+) -> Result<Vec<(String, i64)>> {
+    sqlx::query_as(
+        "SELECT status, COUNT(*) FROM tournament_registrations \
+         WHERE tournament_id = $1 GROUP BY status",
+    )
+    .bind(tournament_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// Registrations per tournament and status, for several tournaments at once.
+pub async fn count_by_status_for_tournaments<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_ids: &[Uuid],
+) -> Result<Vec<(Uuid, String, i64)>> {
+    sqlx::query_as(
+        "SELECT tournament_id, status, COUNT(*) FROM tournament_registrations \
+         WHERE tournament_id = ANY($1) GROUP BY tournament_id, status",
+    )
+    .bind(tournament_ids)
+    .fetch_all(executor)
+    .await
+}
+
+/// Registrations in one status, oldest first.
+pub async fn list_by_status<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    status: &str,
+) -> Result<Vec<TournamentRegistrationRow>> {
+    let rows = sqlx::query_as::<_, TournamentRegistrationRow>(&format!(
+        "SELECT {COLS} FROM tournament_registrations \
+         WHERE tournament_id = $1 AND status = $2 ORDER BY registration_time ASC"
+    ))
+    .bind(tournament_id)
+    .bind(status)
+    .fetch_all(executor)
+    .await?;
+
+    Ok(rows)
+}
@@ -1,10 +1,12 @@
 use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
-use sqlx::{PgExecutor, PgPool, Result, Row};
+use futures_core::stream::BoxStream;
+use sqlx::{FromRow, PgExecutor, PgPool, Result, Row};
 use uuid::Uuid;
 
 use crate::models::TournamentResultRow;
+use crate::pii::Pii;
 use crate::scoring::{event_points_with, ScoringFormula};
 
 const COLS: &str = "id, tournament_id, user_id, club_player_id, final_position, prize_cents, points, notes, created_at, updated_at";
@@ -13,8 +15,8 @@ const COLS: &str = "id, tournament_id, user_id, club_player_id, final_position,
 pub struct UserStatistics {
     pub total_itm: i32,
     pub total_tournaments: i32,
-    pub total_winnings: i32,
-    pub total_buy_ins: i32,
+    pub total_winnings: i64,
+    pub total_buy_ins: i64,
     pub itm_percentage: f64,
     pub roi_percentage: f64,
 }
@@ -31,21 +33,29 @@ pub struct LeaderboardEntry {
     pub first_name: Option<String>,
     pub last_name: Option<String>,
     pub email: Option<String>,
-    pub phone: Option<String>,
+    pub phone: Option<Pii>,
+    pub avatar_url: Option<String>,
     pub is_active: Option<bool>,
     pub role: Option<String>,
     pub locale: Option<String>,
     pub total_tournaments: i32,
-    pub total_buy_ins: i32,  // Total amount spent (cents)
-    pub total_winnings: i32, // Total amount won (cents)
-    pub net_profit: i32,     // winnings - buy_ins (cents)
+    pub total_buy_ins: i64,  // Total amount spent (cents)
+    pub total_winnings: i64, // Total amount won (cents)
+    pub net_profit: i64,     // winnings - buy_ins (cents)
     pub total_itm: i32,      // Number of tournaments where player finished in the money
     pub itm_percentage: f64, // (total_itm / total_tournaments) * 100
     pub roi_percentage: f64, // ((total_winnings - total_buy_ins) / total_buy_ins) * 100
     pub average_finish: f64, // Average finishing position
     pub first_places: i32,   // Number of first place finishes
-    pub final_tables: i32,   // Number of final table finishes (typically top 8-10)
+    pub final_tables: i32,   // Number of final table finishes (see StatThresholds)
     pub points: f64,         // Calculated leaderboard points
+    /// Finishes ahead of players level on points, in tournaments both played.
+    pub head_to_head_wins: i32,
+    /// Points from the player's best single result.
+    pub best_result_points: f64,
+    /// Start of the last tournament that earned points: when the player
+    /// reached their total.
+    pub points_reached_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -56,6 +66,155 @@ pub enum LeaderboardPeriod {
     Last6Months,
     Last30Days,
     Last7Days,
+    /// The last 52 weeks, with each result's points decaying linearly with
+    /// age (see `leaderboard_rolling_points`).
+    Rolling52,
+}
+
+/// Overrides for what leaderboard stats count as a final table and a cash.
+/// Unset thresholds fall back to the tournament's club settings
+/// (`club_stats_settings`), then to the top 9 and any prize.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StatThresholds {
+    pub final_table_size: Option<i32>,
+    /// Places counted as in the money.
+    pub itm_places: Option<i32>,
+}
+
+impl StatThresholds {
+    /// Join providing the club settings (`css`) the conditions read; goes
+    /// after the `tournaments t` join.
+    pub(crate) const SETTINGS_JOIN: &'static str =
+        "LEFT JOIN club_stats_settings css ON css.club_id = t.club_id";
+
+    /// Whether result `tr` made the final table.
+    pub(crate) fn final_table_sql(&self) -> String {
+        format!(
+            "tr.final_position BETWEEN 1 AND COALESCE({}, css.final_table_size, 9)",
+            sql_int(self.final_table_size)
+        )
+    }
+
+    /// Whether result `tr` finished in the money.
+    pub(crate) fn itm_sql(&self) -> String {
+        let places = format!("COALESCE({}, css.itm_places)", sql_int(self.itm_places));
+        format!(
+            "CASE WHEN {places} IS NULL THEN tr.prize_cents > 0 \
+             ELSE tr.final_position BETWEEN 1 AND {places} END"
+        )
+    }
+}
+
+/// An optional integer as a SQL literal.
+fn sql_int(value: Option<i32>) -> String {
+    value.map_or_else(|| "NULL::int".to_string(), |v| v.to_string())
+}
+
+/// Rule ordering players level on points; a leaderboard applies a list of
+/// them in order, then falls back to winnings, tournaments played and id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreak {
+    /// Who finished ahead more often in tournaments the tied players shared.
+    HeadToHead,
+    MostWins,
+    HighestSingleScore,
+    /// Who reached the total first.
+    EarliestAchievement,
+}
+
+impl TieBreak {
+    pub const DEFAULT_ORDER: [TieBreak; 4] = [
+        TieBreak::HeadToHead,
+        TieBreak::MostWins,
+        TieBreak::HighestSingleScore,
+        TieBreak::EarliestAchievement,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TieBreak::HeadToHead => "head_to_head",
+            TieBreak::MostWins => "most_wins",
+            TieBreak::HighestSingleScore => "highest_single_score",
+            TieBreak::EarliestAchievement => "earliest_achievement",
+        }
+    }
+
+    pub fn from_db(s: &str) -> Option<Self> {
+        match s {
+            "head_to_head" => Some(TieBreak::HeadToHead),
+            "most_wins" => Some(TieBreak::MostWins),
+            "highest_single_score" => Some(TieBreak::HighestSingleScore),
+            "earliest_achievement" => Some(TieBreak::EarliestAchievement),
+            _ => None,
+        }
+    }
+
+    /// ORDER BY term over the leaderboard's output columns.
+    fn order_sql(&self) -> &'static str {
+        match self {
+            TieBreak::HeadToHead => "head_to_head_wins DESC",
+            TieBreak::MostWins => "first_places DESC",
+            TieBreak::HighestSingleScore => "best_result_points DESC",
+            TieBreak::EarliestAchievement => "points_reached_at ASC NULLS LAST",
+        }
+    }
+
+    /// The same ordering for entries ranked in Rust.
+    fn compare(&self, a: &LeaderboardEntry, b: &LeaderboardEntry) -> std::cmp::Ordering {
+        match self {
+            TieBreak::HeadToHead => b.head_to_head_wins.cmp(&a.head_to_head_wins),
+            TieBreak::MostWins => b.first_places.cmp(&a.first_places),
+            TieBreak::HighestSingleScore => b
+                .best_result_points
+                .partial_cmp(&a.best_result_points)
+                .unwrap_or(std::cmp::Ordering::Equal),
+            TieBreak::EarliestAchievement => match (a.points_reached_at, b.points_reached_at) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            },
+        }
+    }
+}
+
+/// Tie-break ORDER BY terms, each with a leading comma, to follow the points
+/// term.
+fn tie_break_order_sql(tie_breaks: &[TieBreak]) -> String {
+    tie_breaks
+        .iter()
+        .map(|t| format!(", {}", t.order_sql()))
+        .collect()
+}
+
+/// CTE `head_to_head(key, head_to_head_wins)`: per player, finishes ahead of
+/// players with the same `total_points`. Expects `player_stats` and
+/// `scored(tournament_id, key, final_position)` keyed on `key`.
+fn head_to_head_cte(key: &str) -> String {
+    format!(
+        "head_to_head AS ( \
+            SELECT a.{key}, COUNT(*) as head_to_head_wins \
+            FROM scored a \
+            JOIN scored b ON b.tournament_id = a.tournament_id \
+                AND b.final_position > a.final_position \
+            JOIN player_stats pa ON pa.{key} = a.{key} \
+            JOIN player_stats pb ON pb.{key} = b.{key} AND pb.total_points = pa.total_points \
+            GROUP BY a.{key} \
+        )"
+    )
+}
+
+/// One line of a club's results export.
+#[derive(Debug, Clone, FromRow)]
+pub struct ResultExportRow {
+    pub tournament_id: Uuid,
+    pub tournament_name: String,
+    pub start_time: DateTime<Utc>,
+    pub final_position: i32,
+    pub club_player_id: Uuid,
+    pub display_name: String,
+    pub prize_cents: i64,
+    pub points: i32,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -66,7 +225,7 @@ pub struct CreateTournamentResult {
     pub user_id: Option<Uuid>,
     pub club_player_id: Option<Uuid>,
     pub final_position: i32,
-    pub prize_cents: i32,
+    pub prize_cents: i64,
     pub notes: Option<String>,
 }
 
@@ -118,6 +277,115 @@ pub async fn list_by_tournament<'e>(
     Ok(rows)
 }
 
+/// A finished tournament as reported to external results databases.
+#[derive(Debug, Clone, FromRow)]
+pub struct ResultsFeedEventRow {
+    pub tournament_id: Uuid,
+    pub name: String,
+    pub start_time: DateTime<Utc>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub buy_in_cents: i64,
+    pub rake_cents: i64,
+    /// Distinct players (registrations that played).
+    pub entrants: i64,
+    /// Buy-ins, rebuys and re-entries.
+    pub total_entries: i64,
+    pub prize_pool_cents: i64,
+}
+
+/// One finishing place in a results feed, named by the roster display name.
+#[derive(Debug, Clone, FromRow)]
+pub struct ResultsFeedPlaceRow {
+    pub tournament_id: Uuid,
+    pub final_position: i32,
+    pub player_name: String,
+    pub prize_cents: i64,
+}
+
+/// Every result of the club's tournaments starting in `[from, to)`, oldest
+/// tournament first, streamed row by row so season-long exports never sit in
+/// memory.
+pub fn stream_club_export<'e>(
+    executor: impl PgExecutor<'e> + 'e,
+    club_id: Uuid,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+) -> BoxStream<'e, Result<ResultExportRow>> {
+    sqlx::query_as::<_, ResultExportRow>(
+        r#"
+        SELECT t.id AS tournament_id, t.name AS tournament_name, t.start_time,
+               tr.final_position, tr.club_player_id, cp.display_name,
+               tr.prize_cents, tr.points
+        FROM tournament_results tr
+        JOIN tournaments t ON t.id = tr.tournament_id
+        JOIN club_player cp ON cp.id = tr.club_player_id
+        WHERE t.club_id = $1
+          AND ($2::timestamptz IS NULL OR t.start_time >= $2)
+          AND ($3::timestamptz IS NULL OR t.start_time < $3)
+        ORDER BY t.start_time, t.id, tr.final_position
+        "#,
+    )
+    .bind(club_id)
+    .bind(from)
+    .bind(to)
+    .fetch(executor)
+}
+
+/// A club's finished tournaments with recorded results, oldest first,
+/// optionally limited to those starting in `[from, to)`.
+pub async fn list_feed_events<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+) -> Result<Vec<ResultsFeedEventRow>> {
+    sqlx::query_as::<_, ResultsFeedEventRow>(
+        r#"
+        SELECT t.id AS tournament_id, t.name, t.start_time, t.end_time, t.buy_in_cents,
+               COALESCE(t.rake_cents, 0)::BIGINT AS rake_cents,
+               (SELECT COUNT(*) FROM tournament_registrations tr
+                 WHERE tr.tournament_id = t.id
+                   AND tr.status IN ('registered', 'checked_in', 'seated', 'busted')) AS entrants,
+               (SELECT COUNT(*) FROM tournament_entries te
+                 WHERE te.tournament_id = t.id
+                   AND te.entry_type IN ('initial', 'rebuy', 're_entry')) AS total_entries,
+               COALESCE(tp.total_prize_pool, 0)::BIGINT AS prize_pool_cents
+        FROM tournaments t
+        LEFT JOIN tournament_payouts tp ON tp.tournament_id = t.id
+        WHERE t.club_id = $1
+          AND t.live_status = 'finished'
+          AND EXISTS (SELECT 1 FROM tournament_results r WHERE r.tournament_id = t.id)
+          AND ($2::timestamptz IS NULL OR t.start_time >= $2)
+          AND ($3::timestamptz IS NULL OR t.start_time < $3)
+        ORDER BY t.start_time ASC
+        "#,
+    )
+    .bind(club_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(executor)
+    .await
+}
+
+/// Finishing places of the given tournaments, in finishing order.
+pub async fn list_feed_places<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_ids: &[Uuid],
+) -> Result<Vec<ResultsFeedPlaceRow>> {
+    sqlx::query_as::<_, ResultsFeedPlaceRow>(
+        r#"
+        SELECT r.tournament_id, r.final_position, cp.display_name AS player_name, r.prize_cents
+        FROM tournament_results r
+        JOIN club_player_public cp ON cp.id = r.club_player_id
+        WHERE r.tournament_id = ANY($1)
+        ORDER BY r.tournament_id, r.final_position ASC
+        "#,
+    )
+    .bind(tournament_ids)
+    .fetch_all(executor)
+    .await
+}
+
 pub async fn list_user_recent<'e>(
     executor: impl PgExecutor<'e>,
     user_id: Uuid,
@@ -140,6 +408,85 @@ pub async fn list_user_recent<'e>(
     Ok(rows)
 }
 
+/// One event in a player's history: their registration, what they paid in
+/// and, once results are in, where they finished.
+#[derive(Debug, Clone, FromRow)]
+pub struct UserTournamentHistoryRow {
+    pub tournament_id: Uuid,
+    pub registration_id: Uuid,
+    pub registration_status: String,
+    pub registered_at: DateTime<Utc>,
+    /// Buy-ins, rebuys, add-ons and re-entries recorded for the player.
+    pub entry_count: i64,
+    pub entries_total_cents: i64,
+    /// Players who took part (checked in, seated or busted).
+    pub field_size: i64,
+    pub final_position: Option<i32>,
+    pub prize_cents: Option<i64>,
+    pub points: Option<i32>,
+}
+
+const USER_HISTORY_WHERE: &str = "r.user_id = $1 AND r.status <> 'cancelled' \
+     AND ($2::uuid IS NULL OR t.club_id = $2)";
+
+/// A user's tournaments, newest first, optionally limited to one club.
+/// Cancelled registrations are left out.
+pub async fn list_user_history<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+    club_id: Option<Uuid>,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<UserTournamentHistoryRow>> {
+    sqlx::query_as::<_, UserTournamentHistoryRow>(&format!(
+        r#"
+        SELECT t.id AS tournament_id, r.id AS registration_id,
+               r.status AS registration_status, r.registration_time AS registered_at,
+               e.entry_count, e.entries_total_cents,
+               (SELECT COUNT(*) FROM tournament_registrations f
+                WHERE f.tournament_id = t.id
+                  AND f.status IN ('checked_in', 'seated', 'busted')) AS field_size,
+               tr.final_position, tr.prize_cents, tr.points
+        FROM tournament_registrations r
+        JOIN tournaments t ON t.id = r.tournament_id
+        CROSS JOIN LATERAL (
+            SELECT COUNT(*) AS entry_count,
+                   COALESCE(SUM(te.amount_cents), 0)::BIGINT AS entries_total_cents
+            FROM tournament_entries te
+            WHERE te.tournament_id = r.tournament_id AND te.club_player_id = r.club_player_id
+              AND te.voided_at IS NULL
+        ) e
+        LEFT JOIN tournament_results tr
+               ON tr.tournament_id = r.tournament_id AND tr.club_player_id = r.club_player_id
+        WHERE {USER_HISTORY_WHERE}
+        ORDER BY t.start_time DESC, t.id
+        LIMIT $3 OFFSET $4
+        "#
+    ))
+    .bind(user_id)
+    .bind(club_id)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(executor)
+    .await
+}
+
+pub async fn count_user_history<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+    club_id: Option<Uuid>,
+) -> Result<i64> {
+    sqlx::query_scalar::<_, i64>(&format!(
+        "SELECT COUNT(*) FROM tournament_registrations r \
+         JOIN tournaments t ON t.id = r.tournament_id \
+         WHERE {USER_HISTORY_WHERE}"
+    ))
+    .bind(user_id)
+    .bind(club_id)
+    .fetch_one(executor)
+    .await
+}
+
 /// Uses multiple queries so requires &PgPool
 pub async fn get_user_statistics(
     pool: &PgPool,
@@ -150,20 +497,23 @@ pub async fn get_user_statistics(
     let cutoff_date =
         days_back.map(|days| chrono::Utc::now() - chrono::Duration::days(days as i64));
 
-    // Get ITM count and total prize money
-    let itm_row = sqlx::query(
+    // Get ITM count (by each club's thresholds) and total prize money
+    let thresholds = StatThresholds::default();
+    let itm_row = sqlx::query(&format!(
         r#"
         SELECT
-            COUNT(*) as total_itm,
-            COALESCE(SUM(tr.prize_cents), 0) as total_winnings
+            COUNT(*) FILTER (WHERE {itm}) as total_itm,
+            COALESCE(SUM(tr.prize_cents), 0)::bigint as total_winnings
         FROM tournament_results tr
         JOIN tournaments t ON tr.tournament_id = t.id
+        {settings_join}
         WHERE tr.user_id = $1
-            AND tr.prize_cents > 0
             AND ($2::timestamptz IS NULL OR tr.created_at >= $2)
             AND t.club_id NOT IN (SELECT id FROM clubs WHERE plan = 'free')
         "#,
-    )
+        itm = thresholds.itm_sql(),
+        settings_join = StatThresholds::SETTINGS_JOIN,
+    ))
     .bind(user_id)
     .bind(cutoff_date)
     .fetch_one(pool)
@@ -174,7 +524,7 @@ pub async fn get_user_statistics(
         r#"
         SELECT
             COUNT(DISTINCT reg.tournament_id) as total_tournaments,
-            COALESCE(SUM(t.buy_in_cents), 0) as total_buy_ins
+            COALESCE(SUM(t.buy_in_cents), 0)::bigint as total_buy_ins
         FROM tournament_registrations reg
         JOIN tournaments t ON reg.tournament_id = t.id
         WHERE reg.user_id = $1
@@ -193,9 +543,7 @@ pub async fn get_user_statistics(
     let total_buy_ins: i64 = tournament_row.try_get("total_buy_ins").unwrap_or(0);
 
     let total_itm = total_itm as i32;
-    let total_winnings = total_winnings as i32;
     let total_tournaments = total_tournaments as i32;
-    let total_buy_ins = total_buy_ins as i32;
 
     // Calculate percentages
     let itm_percentage = if total_tournaments > 0 {
@@ -259,11 +607,42 @@ fn period_filter(period: LeaderboardPeriod) -> &'static str {
         LeaderboardPeriod::Last6Months => "AND t.start_time >= NOW() - INTERVAL '6 months'",
         LeaderboardPeriod::Last30Days => "AND t.start_time >= NOW() - INTERVAL '30 days'",
         LeaderboardPeriod::Last7Days => "AND t.start_time >= NOW() - INTERVAL '7 days'",
+        LeaderboardPeriod::Rolling52 => "AND t.start_time >= NOW() - INTERVAL '52 weeks'",
+    }
+}
+
+/// Join providing decayed points (`rlp`) for the rolling period; goes after
+/// the `tournament_results tr` join.
+fn period_points_join(period: LeaderboardPeriod) -> &'static str {
+    match period {
+        LeaderboardPeriod::Rolling52 => {
+            "LEFT JOIN leaderboard_rolling_points rlp ON rlp.result_id = tr.id"
+        }
+        _ => "",
+    }
+}
+
+/// Points result `tr` scores in the period. Rolling results entered since the
+/// view's last refresh have no decayed value yet and count in full.
+fn period_points(period: LeaderboardPeriod) -> &'static str {
+    match period {
+        LeaderboardPeriod::Rolling52 => "COALESCE(rlp.decayed_points, tr.points)",
+        _ => "tr.points",
     }
 }
 
+/// Recompute the rolling leaderboard's decayed points. Run nightly by the
+/// background job; readers keep seeing the previous values meanwhile.
+pub async fn refresh_rolling_points<'e>(executor: impl PgExecutor<'e>) -> Result<()> {
+    sqlx::query("REFRESH MATERIALIZED VIEW CONCURRENTLY leaderboard_rolling_points")
+        .execute(executor)
+        .await?;
+    Ok(())
+}
+
 /// Comprehensive leaderboard keyed on the club roster, so account-less players
 /// rank alongside app users. Uses dynamic SQL so requires &PgPool.
+#[allow(clippy::too_many_arguments)]
 pub async fn get_leaderboard(
     pool: &PgPool,
     period: LeaderboardPeriod,
@@ -271,7 +650,10 @@ pub async fn get_leaderboard(
     offset: Option<i32>,
     club_id: Option<Uuid>,
     province: Option<String>,
+    series_id: Option<Uuid>,
     exclude_free: bool,
+    thresholds: StatThresholds,
+    tie_breaks: &[TieBreak],
 ) -> Result<Vec<LeaderboardEntry>> {
     let date_filter = period_filter(period);
     // Free ("Home Game") clubs never contribute to player-facing leaderboards.
@@ -294,6 +676,12 @@ pub async fn get_leaderboard(
     } else {
         String::new()
     };
+    let series_filter = if series_id.is_some() {
+        next_param += 1;
+        format!("AND t.series_id = ${next_param}")
+    } else {
+        String::new()
+    };
 
     let limit_value = limit.unwrap_or(100).clamp(1, 500);
     let offset_value = offset.unwrap_or(0).max(0);
@@ -301,6 +689,15 @@ pub async fn get_leaderboard(
 
     // Account-less roster entries (u.id IS NULL) always count; app users only
     // when they are active players (managers/admins are staff, not ranked).
+    let tournament_filters =
+        format!("{date_filter} {club_filter} {province_filter} {series_filter} {free_filter}");
+    let itm = thresholds.itm_sql();
+    let final_table = thresholds.final_table_sql();
+    let settings_join = StatThresholds::SETTINGS_JOIN;
+    let points = period_points(period);
+    let points_join = period_points_join(period);
+    let head_to_head = head_to_head_cte("club_player_id");
+    let tie_break_order = tie_break_order_sql(tie_breaks);
     let query = format!(
         r#"
         WITH player_stats AS (
@@ -308,32 +705,49 @@ pub async fn get_leaderboard(
                 rp.id as club_player_id,
                 rp.display_name,
                 u.id as user_id,
-                u.username, u.first_name, u.last_name, u.email, u.phone,
+                u.username, u.first_name, u.last_name, pu.email, pu.phone, u.avatar_url,
                 u.is_active, u.role, u.locale,
                 COUNT(DISTINCT reg.tournament_id) as total_tournaments,
-                COALESCE(SUM(t.buy_in_cents), 0) as total_buy_ins,
-                COALESCE(SUM(tr.prize_cents), 0) as total_winnings,
-                COUNT(tr.id) as total_itm,
+                COALESCE(SUM(t.buy_in_cents), 0)::bigint as total_buy_ins,
+                COALESCE(SUM(tr.prize_cents), 0)::bigint as total_winnings,
+                COUNT(tr.id) FILTER (WHERE {itm}) as total_itm,
                 COALESCE(AVG(tr.final_position::float), 0) as average_finish,
                 SUM(CASE WHEN tr.final_position = 1 THEN 1 ELSE 0 END) as first_places,
-                SUM(CASE WHEN tr.final_position <= 9 THEN 1 ELSE 0 END) as final_tables,
-                COALESCE(SUM(tr.points), 0) as total_points
-            FROM club_player rp
-            LEFT JOIN users u ON u.id = rp.app_user_id
+                SUM(CASE WHEN {final_table} THEN 1 ELSE 0 END) as final_tables,
+                COALESCE(SUM({points}), 0) as total_points,
+                COALESCE(MAX({points}), 0)::bigint as best_result_points,
+                MAX(t.start_time) FILTER (WHERE {points} > 0) as points_reached_at
+            FROM club_player_public rp
+            LEFT JOIN users_public u ON u.id = rp.app_user_id
+            LEFT JOIN users pu ON pu.id = u.id
             JOIN tournament_registrations reg ON reg.club_player_id = rp.id
             JOIN tournaments t ON reg.tournament_id = t.id
             LEFT JOIN tournament_results tr ON tr.club_player_id = rp.id AND tr.tournament_id = t.id
+            {points_join}
+            {settings_join}
             WHERE (u.id IS NULL OR (u.role = 'player' AND u.is_active = true))
-                {} {} {} {}
+                {tournament_filters}
             GROUP BY rp.id, rp.display_name, u.id, u.username, u.first_name, u.last_name,
-                     u.email, u.phone, u.is_active, u.role, u.locale
+                     pu.email, pu.phone, u.avatar_url, u.is_active, u.role, u.locale
             HAVING COUNT(DISTINCT reg.tournament_id) > 0
+        ),
+        scored AS (
+            SELECT tr.tournament_id, tr.club_player_id, tr.final_position
+            FROM tournament_results tr
+            JOIN tournaments t ON t.id = tr.tournament_id
+            WHERE tr.final_position > 0 {tournament_filters}
+        ),
+        {head_to_head},
+        tied AS (
+            SELECT ps.*, COALESCE(h.head_to_head_wins, 0) as head_to_head_wins
+            FROM player_stats ps
+            LEFT JOIN head_to_head h USING (club_player_id)
         )
         SELECT
             club_player_id,
             display_name,
             user_id,
-            username, first_name, last_name, email, phone, is_active, role, locale,
+            username, first_name, last_name, email, phone, avatar_url, is_active, role, locale,
             total_tournaments,
             total_buy_ins,
             total_winnings,
@@ -350,12 +764,15 @@ pub async fn get_leaderboard(
             ROUND(CAST(average_finish AS NUMERIC), 2)::double precision as average_finish,
             first_places,
             final_tables,
-            total_points as points
-        FROM player_stats
-        ORDER BY points DESC, total_winnings DESC, total_tournaments DESC
-        {}
-        "#,
-        date_filter, club_filter, province_filter, free_filter, limit_clause
+            total_points as points,
+            head_to_head_wins,
+            best_result_points,
+            points_reached_at
+        FROM tied
+        ORDER BY total_points DESC{tie_break_order},
+                 total_winnings DESC, total_tournaments DESC, club_player_id
+        {limit_clause}
+        "#
     );
 
     let mut query_builder = sqlx::query(&query);
@@ -365,39 +782,46 @@ pub async fn get_leaderboard(
     if let Some(province) = province {
         query_builder = query_builder.bind(province);
     }
+    if let Some(series_uuid) = series_id {
+        query_builder = query_builder.bind(series_uuid);
+    }
 
     let rows = query_builder.fetch_all(pool).await?;
 
-    let mut leaderboard = Vec::new();
-    for row in rows {
-        let entry = LeaderboardEntry {
-            club_player_id: row.try_get("club_player_id")?,
-            display_name: row.try_get("display_name")?,
-            user_id: row.try_get("user_id")?,
-            username: row.try_get("username")?,
-            first_name: row.try_get("first_name")?,
-            last_name: row.try_get("last_name")?,
-            email: row.try_get("email")?,
-            phone: row.try_get("phone")?,
-            is_active: row.try_get("is_active")?,
-            role: row.try_get("role")?,
-            locale: row.try_get("locale")?,
-            total_tournaments: row.try_get::<i64, _>("total_tournaments")? as i32,
-            total_buy_ins: row.try_get::<i64, _>("total_buy_ins")? as i32,
-            total_winnings: row.try_get::<i64, _>("total_winnings")? as i32,
-            net_profit: row.try_get::<i64, _>("net_profit")? as i32,
-            total_itm: row.try_get::<i64, _>("total_itm")? as i32,
-            itm_percentage: row.try_get::<f64, _>("itm_percentage")?,
-            roi_percentage: row.try_get::<f64, _>("roi_percentage")?,
-            average_finish: row.try_get::<f64, _>("average_finish")?,
-            first_places: row.try_get::<i64, _>("first_places")? as i32,
-            final_tables: row.try_get::<i64, _>("final_tables")? as i32,
-            points: row.try_get::<i64, _>("points")? as f64,
-        };
-        leaderboard.push(entry);
-    }
+    rows.iter().map(leaderboard_entry_from_row).collect()
+}
 
-    Ok(leaderboard)
+/// Map a row produced by the leaderboard queries (same column names as
+/// `get_leaderboard`'s outer SELECT) to a `LeaderboardEntry`.
+fn leaderboard_entry_from_row(row: &sqlx::postgres::PgRow) -> Result<LeaderboardEntry> {
+    Ok(LeaderboardEntry {
+        club_player_id: row.try_get("club_player_id")?,
+        display_name: row.try_get("display_name")?,
+        user_id: row.try_get("user_id")?,
+        username: row.try_get("username")?,
+        first_name: row.try_get("first_name")?,
+        last_name: row.try_get("last_name")?,
+        email: row.try_get("email")?,
+        phone: row.try_get("phone")?,
+        avatar_url: row.try_get("avatar_url")?,
+        is_active: row.try_get("is_active")?,
+        role: row.try_get("role")?,
+        locale: row.try_get("locale")?,
+        total_tournaments: row.try_get::<i64, _>("total_tournaments")? as i32,
+        total_buy_ins: row.try_get::<i64, _>("total_buy_ins")?,
+        total_winnings: row.try_get::<i64, _>("total_winnings")?,
+        net_profit: row.try_get::<i64, _>("net_profit")?,
+        total_itm: row.try_get::<i64, _>("total_itm")? as i32,
+        itm_percentage: row.try_get::<f64, _>("itm_percentage")?,
+        roi_percentage: row.try_get::<f64, _>("roi_percentage")?,
+        average_finish: row.try_get::<f64, _>("average_finish")?,
+        first_places: row.try_get::<i64, _>("first_places")? as i32,
+        final_tables: row.try_get::<i64, _>("final_tables")? as i32,
+        points: row.try_get::<i64, _>("points")? as f64,
+        head_to_head_wins: row.try_get::<i64, _>("head_to_head_wins")? as i32,
+        best_result_points: row.try_get::<i64, _>("best_result_points")? as f64,
+        points_reached_at: row.try_get("points_reached_at")?,
+    })
 }
 
 /// Count total leaderboard entries for pagination
@@ -406,6 +830,7 @@ pub async fn count_leaderboard(
     period: LeaderboardPeriod,
     club_id: Option<Uuid>,
     province: Option<String>,
+    series_id: Option<Uuid>,
     exclude_free: bool,
 ) -> Result<i64> {
     let date_filter = period_filter(period);
@@ -427,22 +852,28 @@ pub async fn count_leaderboard(
     } else {
         String::new()
     };
+    let series_filter = if series_id.is_some() {
+        next_param += 1;
+        format!("AND t.series_id = ${next_param}")
+    } else {
+        String::new()
+    };
 
     let query = format!(
         r#"
         SELECT COUNT(*) as total FROM (
             SELECT rp.id
-            FROM club_player rp
-            LEFT JOIN users u ON u.id = rp.app_user_id
+            FROM club_player_public rp
+            LEFT JOIN users_public u ON u.id = rp.app_user_id
             JOIN tournament_registrations reg ON reg.club_player_id = rp.id
             JOIN tournaments t ON reg.tournament_id = t.id
             WHERE (u.id IS NULL OR (u.role = 'player' AND u.is_active = true))
-                {} {} {} {}
+                {} {} {} {} {}
             GROUP BY rp.id
             HAVING COUNT(DISTINCT reg.tournament_id) > 0
         ) c
         "#,
-        date_filter, club_filter, province_filter, free_filter
+        date_filter, club_filter, province_filter, series_filter, free_filter
     );
 
     let mut query_builder = sqlx::query_scalar::<_, i64>(&query);
@@ -452,11 +883,261 @@ pub async fn count_leaderboard(
     if let Some(province) = province {
         query_builder = query_builder.bind(province);
     }
+    if let Some(series_uuid) = series_id {
+        query_builder = query_builder.bind(series_uuid);
+    }
 
     let count = query_builder.fetch_one(pool).await?;
     Ok(count)
 }
 
+/// One row of an organization-wide leaderboard: the player's totals across
+/// every club in the organization. App users are merged across their roster
+/// entries at each club; account-less roster entries rank on their own.
+#[derive(Debug, Clone)]
+pub struct OrganizationLeaderboardEntry {
+    /// Identity the row is keyed on: the app user ID, or the roster entry ID
+    /// for account-less players.
+    pub player_key: Uuid,
+    /// 1-based position on the full organization leaderboard.
+    pub rank: i32,
+    pub entry: LeaderboardEntry,
+    pub clubs_played: i32,
+}
+
+/// A player's totals at one club of an organization.
+#[derive(Debug, Clone)]
+pub struct OrganizationClubBreakdown {
+    pub player_key: Uuid,
+    pub club_id: Uuid,
+    pub club_name: String,
+    pub total_tournaments: i32,
+    pub total_buy_ins: i64,
+    pub total_winnings: i64,
+    pub points: f64,
+}
+
+/// Organization-wide leaderboard ("travel" leaderboard) and the total number
+/// of ranked players. `user_id` narrows the page to that app user's row,
+/// keeping their rank on the full leaderboard.
+pub async fn get_organization_leaderboard(
+    pool: &PgPool,
+    organization_id: Uuid,
+    period: LeaderboardPeriod,
+    limit: Option<i32>,
+    offset: Option<i32>,
+    user_id: Option<Uuid>,
+    exclude_free: bool,
+) -> Result<(Vec<OrganizationLeaderboardEntry>, i64)> {
+    let date_filter = period_filter(period);
+    let free_filter = if exclude_free {
+        "AND c.plan <> 'free'"
+    } else {
+        ""
+    };
+    let user_filter = if user_id.is_some() {
+        "WHERE user_id = $2"
+    } else {
+        ""
+    };
+    let limit_value = limit.unwrap_or(100).clamp(1, 500);
+    let offset_value = offset.unwrap_or(0).max(0);
+    // Clubs differ in final-table size and paid places: each tournament is
+    // judged by its own club's settings.
+    let thresholds = StatThresholds::default();
+    let itm = thresholds.itm_sql();
+    let final_table = thresholds.final_table_sql();
+    let settings_join = StatThresholds::SETTINGS_JOIN;
+    let points = period_points(period);
+    let points_join = period_points_join(period);
+
+    let player_stats = format!(
+        r#"
+        player_stats AS (
+            SELECT
+                COALESCE(u.id, rp.id) as player_key,
+                (ARRAY_AGG(rp.id ORDER BY rp.id))[1] as club_player_id,
+                (ARRAY_AGG(rp.display_name ORDER BY rp.id))[1] as display_name,
+                u.id as user_id,
+                u.username, u.first_name, u.last_name, pu.email, pu.phone, u.avatar_url,
+                u.is_active, u.role, u.locale,
+                COUNT(DISTINCT t.club_id) as clubs_played,
+                COUNT(DISTINCT reg.tournament_id) as total_tournaments,
+                COALESCE(SUM(t.buy_in_cents), 0)::bigint as total_buy_ins,
+                COALESCE(SUM(tr.prize_cents), 0)::bigint as total_winnings,
+                COUNT(tr.id) FILTER (WHERE {itm}) as total_itm,
+                COALESCE(AVG(tr.final_position::float), 0) as average_finish,
+                SUM(CASE WHEN tr.final_position = 1 THEN 1 ELSE 0 END) as first_places,
+                SUM(CASE WHEN {final_table} THEN 1 ELSE 0 END) as final_tables,
+                COALESCE(SUM({points}), 0) as total_points,
+                COALESCE(MAX({points}), 0)::bigint as best_result_points,
+                MAX(t.start_time) FILTER (WHERE {points} > 0) as points_reached_at
+            FROM club_player_public rp
+            LEFT JOIN users_public u ON u.id = rp.app_user_id
+            LEFT JOIN users pu ON pu.id = u.id
+            JOIN tournament_registrations reg ON reg.club_player_id = rp.id
+            JOIN tournaments t ON reg.tournament_id = t.id
+            JOIN clubs c ON c.id = t.club_id
+            LEFT JOIN tournament_results tr ON tr.club_player_id = rp.id AND tr.tournament_id = t.id
+            {points_join}
+            {settings_join}
+            WHERE c.organization_id = $1
+                AND (u.id IS NULL OR (u.role = 'player' AND u.is_active = true))
+                {date_filter} {free_filter}
+            GROUP BY COALESCE(u.id, rp.id), u.id, u.username, u.first_name, u.last_name,
+                     pu.email, pu.phone, u.avatar_url, u.is_active, u.role, u.locale
+        )
+        "#
+    );
+    let head_to_head = head_to_head_cte("player_key");
+    let tie_break_order = tie_break_order_sql(&TieBreak::DEFAULT_ORDER);
+
+    let query = format!(
+        r#"
+        WITH {player_stats},
+        scored AS (
+            SELECT tr.tournament_id, COALESCE(u.id, rp.id) as player_key, tr.final_position
+            FROM tournament_results tr
+            JOIN club_player_public rp ON rp.id = tr.club_player_id
+            LEFT JOIN users_public u ON u.id = rp.app_user_id
+            JOIN tournaments t ON t.id = tr.tournament_id
+            JOIN clubs c ON c.id = t.club_id
+            WHERE c.organization_id = $1 AND tr.final_position > 0
+                {date_filter} {free_filter}
+        ),
+        {head_to_head},
+        tied AS (
+            SELECT ps.*, COALESCE(h.head_to_head_wins, 0) as head_to_head_wins
+            FROM player_stats ps
+            LEFT JOIN head_to_head h USING (player_key)
+        ),
+        ranked AS (
+            SELECT *, ROW_NUMBER() OVER (
+                ORDER BY total_points DESC{tie_break_order},
+                         total_winnings DESC, total_tournaments DESC, player_key
+            ) as rank
+            FROM tied
+        )
+        SELECT
+            rank, player_key, club_player_id, display_name, user_id,
+            username, first_name, last_name, email, phone, avatar_url, is_active, role, locale,
+            clubs_played,
+            total_tournaments,
+            total_buy_ins,
+            total_winnings,
+            (total_winnings - total_buy_ins) as net_profit,
+            total_itm,
+            CASE
+                WHEN total_tournaments > 0 THEN ROUND(CAST((total_itm::float / total_tournaments::float) * 100.0 AS NUMERIC), 2)::double precision
+                ELSE 0.0
+            END as itm_percentage,
+            CASE
+                WHEN total_buy_ins > 0 THEN ROUND(CAST(((total_winnings - total_buy_ins)::float / total_buy_ins::float) * 100.0 AS NUMERIC), 2)::double precision
+                ELSE 0.0
+            END as roi_percentage,
+            ROUND(CAST(average_finish AS NUMERIC), 2)::double precision as average_finish,
+            first_places,
+            final_tables,
+            total_points as points,
+            head_to_head_wins,
+            best_result_points,
+            points_reached_at
+        FROM ranked
+        {user_filter}
+        ORDER BY rank
+        LIMIT {limit_value} OFFSET {offset_value}
+        "#
+    );
+    let count_query = format!("WITH {player_stats} SELECT COUNT(*) FROM player_stats");
+
+    let mut query_builder = sqlx::query(&query).bind(organization_id);
+    if let Some(user_id) = user_id {
+        query_builder = query_builder.bind(user_id);
+    }
+
+    let rows = query_builder.fetch_all(pool).await?;
+    let total = sqlx::query_scalar::<_, i64>(&count_query)
+        .bind(organization_id)
+        .fetch_one(pool)
+        .await?;
+
+    let entries = rows
+        .iter()
+        .map(|row| {
+            Ok(OrganizationLeaderboardEntry {
+                player_key: row.try_get("player_key")?,
+                rank: row.try_get::<i64, _>("rank")? as i32,
+                entry: leaderboard_entry_from_row(row)?,
+                clubs_played: row.try_get::<i64, _>("clubs_played")? as i32,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok((entries, total))
+}
+
+/// Per-club totals for the given organization leaderboard players (keyed the
+/// same way as `get_organization_leaderboard`), busiest club first.
+pub async fn get_organization_club_breakdown(
+    pool: &PgPool,
+    organization_id: Uuid,
+    period: LeaderboardPeriod,
+    player_keys: &[Uuid],
+    exclude_free: bool,
+) -> Result<Vec<OrganizationClubBreakdown>> {
+    let date_filter = period_filter(period);
+    let free_filter = if exclude_free {
+        "AND c.plan <> 'free'"
+    } else {
+        ""
+    };
+    let points = period_points(period);
+    let points_join = period_points_join(period);
+    let query = format!(
+        r#"
+        SELECT
+            COALESCE(u.id, rp.id) as player_key,
+            c.id as club_id,
+            c.name as club_name,
+            COUNT(DISTINCT reg.tournament_id) as total_tournaments,
+            COALESCE(SUM(t.buy_in_cents), 0)::bigint as total_buy_ins,
+            COALESCE(SUM(tr.prize_cents), 0)::bigint as total_winnings,
+            COALESCE(SUM({points}), 0)::bigint as points
+        FROM club_player_public rp
+        LEFT JOIN users_public u ON u.id = rp.app_user_id
+        JOIN tournament_registrations reg ON reg.club_player_id = rp.id
+        JOIN tournaments t ON reg.tournament_id = t.id
+        JOIN clubs c ON c.id = t.club_id
+        LEFT JOIN tournament_results tr ON tr.club_player_id = rp.id AND tr.tournament_id = t.id
+        {points_join}
+        WHERE c.organization_id = $1
+            AND COALESCE(u.id, rp.id) = ANY($2)
+            {date_filter} {free_filter}
+        GROUP BY COALESCE(u.id, rp.id), c.id, c.name
+        ORDER BY total_tournaments DESC, c.name ASC
+        "#
+    );
+
+    let rows = sqlx::query(&query)
+        .bind(organization_id)
+        .bind(player_keys)
+        .fetch_all(pool)
+        .await?;
+
+    rows.iter()
+        .map(|row| {
+            Ok(OrganizationClubBreakdown {
+                player_key: row.try_get("player_key")?,
+                club_id: row.try_get("club_id")?,
+                club_name: row.try_get("club_name")?,
+                total_tournaments: row.try_get::<i64, _>("total_tournaments")? as i32,
+                total_buy_ins: row.try_get::<i64, _>("total_buy_ins")?,
+                total_winnings: row.try_get::<i64, _>("total_winnings")?,
+                points: row.try_get::<i64, _>("points")? as f64,
+            })
+        })
+        .collect()
+}
+
 /// Leaderboard for a configurable league: points are recomputed per result from
 /// `formula`, capped to the player's best `count_best_n` results, plus audited
 /// manual adjustments. Returns the requested page and the total number of ranked
@@ -474,6 +1155,8 @@ pub async fn get_leaderboard_for_config(
     limit: Option<i32>,
     offset: Option<i32>,
     exclude_free: bool,
+    thresholds: StatThresholds,
+    tie_breaks: &[TieBreak],
 ) -> Result<(Vec<LeaderboardEntry>, i64)> {
     // Tournament-set filter shared by both queries. $1 club, $2 period_start,
     // $3 period_end, $4 config (tagged only). Bind in the same order each time.
@@ -499,27 +1182,32 @@ pub async fn get_leaderboard_for_config(
             rp.id as club_player_id,
             rp.display_name,
             u.id as user_id,
-            u.username, u.first_name, u.last_name, u.email, u.phone,
+            u.username, u.first_name, u.last_name, pu.email, pu.phone, u.avatar_url,
             u.is_active, u.role, u.locale,
             COUNT(DISTINCT reg.tournament_id) as total_tournaments,
-            COALESCE(SUM(t.buy_in_cents), 0) as total_buy_ins,
-            COALESCE(SUM(tr.prize_cents), 0) as total_winnings,
-            COUNT(tr.id) as total_itm,
+            COALESCE(SUM(t.buy_in_cents), 0)::bigint as total_buy_ins,
+            COALESCE(SUM(tr.prize_cents), 0)::bigint as total_winnings,
+            COUNT(tr.id) FILTER (WHERE {itm}) as total_itm,
             COALESCE(AVG(tr.final_position::float), 0) as average_finish,
             SUM(CASE WHEN tr.final_position = 1 THEN 1 ELSE 0 END) as first_places,
-            SUM(CASE WHEN tr.final_position <= 9 THEN 1 ELSE 0 END) as final_tables
-        FROM club_player rp
-        LEFT JOIN users u ON u.id = rp.app_user_id
+            SUM(CASE WHEN {final_table} THEN 1 ELSE 0 END) as final_tables
+        FROM club_player_public rp
+        LEFT JOIN users_public u ON u.id = rp.app_user_id
+        LEFT JOIN users pu ON pu.id = u.id
         JOIN tournament_registrations reg ON reg.club_player_id = rp.id
         JOIN tournaments t ON reg.tournament_id = t.id
         LEFT JOIN tournament_results tr ON tr.club_player_id = rp.id AND tr.tournament_id = t.id
+        {settings_join}
         WHERE (u.id IS NULL OR (u.role = 'player' AND u.is_active = true))
             AND {filter}
         GROUP BY rp.id, rp.display_name, u.id, u.username, u.first_name, u.last_name,
-                 u.email, u.phone, u.is_active, u.role, u.locale
+                 pu.email, pu.phone, u.avatar_url, u.is_active, u.role, u.locale
         HAVING COUNT(DISTINCT reg.tournament_id) > 0
         "#,
-        filter = tournament_filter
+        filter = tournament_filter,
+        itm = thresholds.itm_sql(),
+        final_table = thresholds.final_table_sql(),
+        settings_join = StatThresholds::SETTINGS_JOIN,
     );
 
     let mut stats_q = sqlx::query(&stats_sql)
@@ -536,8 +1224,11 @@ pub async fn get_leaderboard_for_config(
         r#"
         SELECT
             tr.club_player_id as club_player_id,
+            tr.tournament_id as tournament_id,
             tr.final_position as rank,
+            t.start_time as start_time,
             t.buy_in_cents as buy_in_cents,
+            t.points_multiplier as points_multiplier,
             f.field_size as field_size
         FROM tournament_results tr
         JOIN tournaments t ON t.id = tr.tournament_id
@@ -559,23 +1250,40 @@ pub async fn get_leaderboard_for_config(
     }
     let result_rows = results_q.fetch_all(pool).await?;
 
-    // Per-player list of per-tournament point values.
+    // Per-player list of per-tournament point values, plus what the
+    // tie-breaks need: best single result, when points were last scored and
+    // each tournament's finishing order.
     let mut points_by_player: HashMap<Uuid, Vec<u32>> = HashMap::new();
+    let mut last_scored: HashMap<Uuid, DateTime<Utc>> = HashMap::new();
+    let mut finishes: HashMap<Uuid, Vec<(Uuid, i32)>> = HashMap::new();
     for row in &result_rows {
         let club_player_id: Uuid = row.try_get("club_player_id")?;
+        let tournament_id: Uuid = row.try_get("tournament_id")?;
         let rank: i32 = row.try_get("rank")?;
-        let buy_in_cents: i32 = row.try_get("buy_in_cents")?;
+        let start_time: DateTime<Utc> = row.try_get("start_time")?;
+        let buy_in_cents: i64 = row.try_get("buy_in_cents")?;
         let field_size: i64 = row.try_get("field_size")?;
+        let points_multiplier: f64 = row.try_get("points_multiplier")?;
         let pts = event_points_with(
             formula,
             field_size as u32,
             rank.max(0) as u32,
             buy_in_cents as f64 / 100.0,
         );
+        // Same weighting as `calculate_tournament_points`: after the cap.
+        let pts = (pts as f64 * points_multiplier).round() as u32;
         points_by_player
             .entry(club_player_id)
             .or_default()
             .push(pts);
+        if pts > 0 {
+            let reached = last_scored.entry(club_player_id).or_insert(start_time);
+            *reached = (*reached).max(start_time);
+        }
+        finishes
+            .entry(tournament_id)
+            .or_default()
+            .push((club_player_id, rank));
     }
 
     // Best-N aggregation: sum a player's top N results (all if None).
@@ -595,14 +1303,13 @@ pub async fn get_leaderboard_for_config(
     for row in stat_rows {
         let club_player_id: Uuid = row.try_get("club_player_id")?;
         let total_tournaments = row.try_get::<i64, _>("total_tournaments")? as i32;
-        let total_buy_ins = row.try_get::<i64, _>("total_buy_ins")? as i32;
-        let total_winnings = row.try_get::<i64, _>("total_winnings")? as i32;
+        let total_buy_ins = row.try_get::<i64, _>("total_buy_ins")?;
+        let total_winnings = row.try_get::<i64, _>("total_winnings")?;
         let total_itm = row.try_get::<i64, _>("total_itm")? as i32;
 
-        let base_points = points_by_player
-            .remove(&club_player_id)
-            .map(sum_best)
-            .unwrap_or(0);
+        let player_points = points_by_player.remove(&club_player_id).unwrap_or_default();
+        let best_result_points = player_points.iter().copied().max().unwrap_or(0) as f64;
+        let base_points = sum_best(player_points);
         let adjustment = adjustments.get(&club_player_id).copied().unwrap_or(0);
         let points = (base_points + adjustment) as f64;
 
@@ -629,6 +1336,7 @@ pub async fn get_leaderboard_for_config(
             last_name: row.try_get("last_name")?,
             email: row.try_get("email")?,
             phone: row.try_get("phone")?,
+            avatar_url: row.try_get("avatar_url")?,
             is_active: row.try_get("is_active")?,
             role: row.try_get("role")?,
             locale: row.try_get("locale")?,
@@ -643,16 +1351,50 @@ pub async fn get_leaderboard_for_config(
             first_places: row.try_get::<i64, _>("first_places")? as i32,
             final_tables: row.try_get::<i64, _>("final_tables")? as i32,
             points,
+            head_to_head_wins: 0,
+            best_result_points,
+            points_reached_at: last_scored.get(&club_player_id).copied(),
         });
     }
 
-    // Sort like the legacy leaderboard, then paginate in Rust.
+    // Head-to-head only counts finishes against players on the same points.
+    let points_of: HashMap<Uuid, f64> = entries
+        .iter()
+        .map(|e| (e.club_player_id, e.points))
+        .collect();
+    let mut head_to_head: HashMap<Uuid, i32> = HashMap::new();
+    for field in finishes.values() {
+        for &(a, a_rank) in field {
+            let Some(&a_points) = points_of.get(&a) else {
+                continue;
+            };
+            let wins = field
+                .iter()
+                .filter(|&&(b, b_rank)| b_rank > a_rank && points_of.get(&b) == Some(&a_points))
+                .count() as i32;
+            *head_to_head.entry(a).or_default() += wins;
+        }
+    }
+    for entry in &mut entries {
+        entry.head_to_head_wins = head_to_head
+            .get(&entry.club_player_id)
+            .copied()
+            .unwrap_or(0);
+    }
+
+    // Same ordering as `get_leaderboard`, then paginate in Rust.
     entries.sort_by(|a, b| {
         b.points
             .partial_cmp(&a.points)
             .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| {
+                tie_breaks.iter().fold(std::cmp::Ordering::Equal, |ord, t| {
+                    ord.then(t.compare(a, b))
+                })
+            })
             .then(b.total_winnings.cmp(&a.total_winnings))
             .then(b.total_tournaments.cmp(&a.total_tournaments))
+            .then(a.club_player_id.cmp(&b.club_player_id))
     });
 
     let total_count = entries.len() as i64;
@@ -74,6 +74,7 @@ pub async fn distinct_entrant_count<'e>(
         JOIN tournaments t ON t.id = te.tournament_id
         WHERE t.series_id = $1
           AND te.entry_type NOT IN ('voucher', 'bonus')
+          AND te.voided_at IS NULL
         "#,
     )
     .bind(series_id)
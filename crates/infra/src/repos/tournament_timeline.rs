@@ -0,0 +1,89 @@
+//! One ordered stream of everything that happened in a tournament, for
+//! replaying it: clock, seating and status events from the activity log,
+//! entries, eliminations and sent announcements.
+//!
+//! Events are ordered by time, then id, and paged by keyset on that pair so
+//! events logged while a client pages through don't shift its pages.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct TimelineRow {
+    /// `clock` | `seating` | `tournament` | `entry` | `elimination` |
+    /// `announcement`
+    pub kind: String,
+    /// Id of the underlying log entry, entry, registration or announcement.
+    pub source_id: Uuid,
+    pub occurred_at: DateTime<Utc>,
+    /// The log action, the entry type, `eliminated`, or the announcement's
+    /// priority.
+    pub action: String,
+    pub actor_id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+    pub club_player_id: Option<Uuid>,
+    pub level_number: Option<i32>,
+    pub title: Option<String>,
+    pub body: Option<String>,
+    pub metadata: serde_json::Value,
+}
+
+/// Up to `limit` events of the given `kinds` after the `(time, id)` cursor,
+/// oldest first.
+pub async fn list<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    kinds: &[&str],
+    after: Option<(DateTime<Utc>, Uuid)>,
+    limit: i64,
+) -> SqlxResult<Vec<TimelineRow>> {
+    sqlx::query_as::<_, TimelineRow>(
+        r#"
+        SELECT * FROM (
+            SELECT CASE WHEN l.event_category IN ('clock', 'seating') THEN l.event_category
+                        ELSE 'tournament' END AS kind,
+                   l.id AS source_id, l.event_time AS occurred_at, l.event_action AS action,
+                   l.actor_id, l.subject_id AS user_id,
+                   CASE WHEN l.metadata->>'club_player_id' ~* '^[0-9a-f-]{36}$'
+                        THEN (l.metadata->>'club_player_id')::uuid END AS club_player_id,
+                   CASE WHEN jsonb_typeof(l.metadata->'level_number') = 'number'
+                        THEN (l.metadata->>'level_number')::int END AS level_number,
+                   NULL::text AS title, NULL::text AS body,
+                   COALESCE(l.metadata, '{}'::jsonb) AS metadata
+            FROM tournament_activity_log l
+            WHERE l.tournament_id = $1
+              AND l.event_category IN ('clock', 'seating', 'tournament')
+              -- Busts come from the registrations, whatever path recorded them.
+              AND NOT (l.event_category = 'seating' AND l.event_action = 'player_eliminated')
+            UNION ALL
+            SELECT 'entry', e.id, e.created_at, e.entry_type, e.recorded_by, e.user_id,
+                   e.club_player_id, e.level_number, NULL, NULL,
+                   jsonb_build_object('chips_received', e.chips_received)
+            FROM tournament_entries e
+            WHERE e.tournament_id = $1
+            UNION ALL
+            SELECT 'elimination', r.id, r.eliminated_at, 'eliminated', NULL, r.user_id,
+                   r.club_player_id, r.eliminated_level, NULL, NULL, '{}'::jsonb
+            FROM tournament_registrations r
+            WHERE r.tournament_id = $1 AND r.eliminated_at IS NOT NULL
+            UNION ALL
+            SELECT 'announcement', a.id, a.sent_at, a.priority, a.created_by, NULL,
+                   NULL, NULL, a.title, a.body, '{}'::jsonb
+            FROM announcements a
+            WHERE a.tournament_id = $1 AND a.sent_at IS NOT NULL
+        ) events
+        WHERE kind = ANY($2)
+          AND ($3::timestamptz IS NULL OR (occurred_at, source_id) > ($3, $4::uuid))
+        ORDER BY occurred_at, source_id
+        LIMIT $5
+        "#,
+    )
+    .bind(tournament_id)
+    .bind(kinds)
+    .bind(after.map(|(at, _)| at))
+    .bind(after.map(|(_, id)| id))
+    .bind(limit)
+    .fetch_all(executor)
+    .await
+}
@@ -1,6 +1,6 @@
 use crate::{models::TournamentRow, pagination::LimitOffset};
 use chrono::{DateTime, Utc};
-use sqlx::{PgExecutor, Result as SqlxResult};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
 use std::str::FromStr;
 use uuid::Uuid;
 
@@ -13,6 +13,12 @@ pub struct TournamentFilter {
     /// When true, hide tournaments belonging to free ("Home Game") clubs — set
     /// for player-app / public callers who don't manage the club in question.
     pub exclude_free_clubs: bool,
+    /// When true, only list what `viewer_id` may discover: public tournaments,
+    /// and members-only ones of clubs whose roster they're on. Unlisted
+    /// tournaments, and those awaiting or refused organization approval, are
+    /// left out. Off for managers, admins and internal lookups.
+    pub restrict_visibility: bool,
+    pub viewer_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -32,6 +38,7 @@ pub enum TournamentLiveStatus {
     Break,
     FinalTable,
     Finished,
+    Cancelled,
 }
 
 impl TournamentLiveStatus {
@@ -44,6 +51,7 @@ impl TournamentLiveStatus {
             TournamentLiveStatus::Break => "break",
             TournamentLiveStatus::FinalTable => "final_table",
             TournamentLiveStatus::Finished => "finished",
+            TournamentLiveStatus::Cancelled => "cancelled",
         }
     }
 }
@@ -60,6 +68,7 @@ impl FromStr for TournamentLiveStatus {
             "break" => Ok(TournamentLiveStatus::Break),
             "final_table" => Ok(TournamentLiveStatus::FinalTable),
             "finished" => Ok(TournamentLiveStatus::Finished),
+            "cancelled" => Ok(TournamentLiveStatus::Cancelled),
             _ => Err(format!("Unknown tournament live status: {}", s)),
         }
     }
@@ -72,23 +81,29 @@ pub struct CreateTournamentData {
     pub description: Option<String>,
     pub start_time: DateTime<Utc>,
     pub end_time: Option<DateTime<Utc>>,
-    pub buy_in_cents: i32,
-    pub rake_cents: Option<i32>,
+    pub buy_in_cents: i64,
+    pub rake_cents: Option<i64>,
     pub seat_cap: Option<i32>,
     pub starting_stack: Option<i32>,
     pub early_bird_bonus_chips: Option<i32>,
     pub level_two_bonus_chips: Option<i32>,
-    pub voucher_value_cents: Option<i32>,
+    pub voucher_value_cents: Option<i64>,
     pub rebuy_max: Option<i32>,
     pub addon_chips: Option<i32>,
-    pub addon_price_cents: Option<i32>,
+    pub addon_price_cents: Option<i64>,
     pub late_registration_level: Option<i32>,
     pub bounty_type: Option<String>,
-    pub bounty_amount_cents: Option<i32>,
+    pub bounty_amount_cents: Option<i64>,
     pub leaderboard_config_id: Option<Uuid>,
+    pub chip_race_rule: Option<String>,
+    pub visibility: Option<String>,
+    pub early_bird_buy_in_cents: Option<i64>,
+    pub early_bird_until: Option<DateTime<Utc>>,
     pub series_id: Option<Uuid>,
     pub flight_label: Option<String>,
     pub is_final_day: bool,
+    pub points_multiplier: Option<f64>,
+    pub seats_per_table: Option<i32>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -97,20 +112,28 @@ pub struct UpdateTournamentData {
     pub description: Option<String>,
     pub start_time: Option<DateTime<Utc>>,
     pub end_time: Option<DateTime<Utc>>,
-    pub buy_in_cents: Option<i32>,
-    pub rake_cents: Option<i32>,
+    pub buy_in_cents: Option<i64>,
+    pub rake_cents: Option<i64>,
     pub seat_cap: Option<i32>,
     pub starting_stack: Option<i32>,
     pub early_bird_bonus_chips: Option<i32>,
     pub level_two_bonus_chips: Option<i32>,
-    pub voucher_value_cents: Option<i32>,
+    pub voucher_value_cents: Option<i64>,
     pub rebuy_max: Option<i32>,
     pub addon_chips: Option<i32>,
-    pub addon_price_cents: Option<i32>,
+    pub addon_price_cents: Option<i64>,
     pub late_registration_level: Option<i32>,
     pub bounty_type: Option<String>,
-    pub bounty_amount_cents: Option<i32>,
+    pub bounty_amount_cents: Option<i64>,
     pub leaderboard_config_id: Option<Uuid>,
+    pub chip_race_rule: Option<String>,
+    pub visibility: Option<String>,
+    pub early_bird_buy_in_cents: Option<i64>,
+    pub early_bird_until: Option<DateTime<Utc>>,
+    pub points_multiplier: Option<f64>,
+    /// Only apply the update if the row is still at this `updated_at`, so an
+    /// edit made from a stale copy doesn't overwrite someone else's.
+    pub expected_updated_at: Option<DateTime<Utc>>,
 }
 
 pub async fn get_by_id<'e>(
@@ -121,9 +144,9 @@ pub async fn get_by_id<'e>(
         r#"
         SELECT id, club_id, name, description, start_time, end_time,
                buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
-               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at
+               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
         FROM tournaments
-        WHERE id = $1
+        WHERE id = $1 AND deleted_at IS NULL
         "#,
     )
     .bind(id)
@@ -142,9 +165,10 @@ pub async fn list<'e>(
         r#"
         SELECT id, club_id, name, description, start_time, end_time,
                buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
-               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at
+               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
         FROM tournaments
         WHERE ($1::uuid IS NULL OR club_id = $1)
+          AND deleted_at IS NULL
           AND ($2::timestamptz IS NULL OR start_time >= $2)
           AND ($3::timestamptz IS NULL OR start_time <= $3)
           AND (
@@ -154,6 +178,10 @@ pub async fn list<'e>(
             OR ($4 = 'ended' AND end_time IS NOT NULL AND end_time <= NOW())
           )
           AND ($7 = FALSE OR club_id NOT IN (SELECT id FROM clubs WHERE plan = 'free'))
+          AND ($8 = FALSE OR visibility = 'public'
+               OR (visibility = 'members_only' AND club_id IN (
+                   SELECT club_id FROM club_player WHERE app_user_id = $9 AND is_active)))
+          AND ($8 = FALSE OR app_tournament_approved(tournaments.id))
         ORDER BY created_at DESC
         LIMIT $5 OFFSET $6
         "#,
@@ -169,6 +197,8 @@ pub async fn list<'e>(
     .bind(p.limit)
     .bind(p.offset)
     .bind(filter.exclude_free_clubs)
+    .bind(filter.restrict_visibility)
+    .bind(filter.viewer_id)
     .fetch_all(executor)
     .await
 }
@@ -179,6 +209,7 @@ pub async fn count<'e>(executor: impl PgExecutor<'e>, filter: TournamentFilter)
         SELECT COUNT(*)
         FROM tournaments
         WHERE ($1::uuid IS NULL OR club_id = $1)
+          AND deleted_at IS NULL
           AND ($2::timestamptz IS NULL OR start_time >= $2)
           AND ($3::timestamptz IS NULL OR start_time <= $3)
           AND (
@@ -188,6 +219,10 @@ pub async fn count<'e>(executor: impl PgExecutor<'e>, filter: TournamentFilter)
             OR ($4 = 'ended' AND end_time IS NOT NULL AND end_time <= NOW())
           )
           AND ($5 = FALSE OR club_id NOT IN (SELECT id FROM clubs WHERE plan = 'free'))
+          AND ($6 = FALSE OR visibility = 'public'
+               OR (visibility = 'members_only' AND club_id IN (
+                   SELECT club_id FROM club_player WHERE app_user_id = $7 AND is_active)))
+          AND ($6 = FALSE OR app_tournament_approved(tournaments.id))
         "#,
     )
     .bind(filter.club_id)
@@ -199,10 +234,124 @@ pub async fn count<'e>(executor: impl PgExecutor<'e>, filter: TournamentFilter)
         TournamentStatus::Completed => "completed",
     }))
     .bind(filter.exclude_free_clubs)
+    .bind(filter.restrict_visibility)
+    .bind(filter.viewer_id)
     .fetch_one(executor)
     .await
 }
 
+/// Who may see a tournament: its club, the club's plan and its visibility.
+#[derive(Debug, Clone, FromRow)]
+pub struct AccessRow {
+    pub club_id: Uuid,
+    pub plan: String,
+    pub visibility: String,
+    /// `approved` unless an organization review is pending or rejected it.
+    pub approval_status: String,
+}
+
+pub async fn get_access<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<AccessRow>> {
+    sqlx::query_as::<_, AccessRow>(
+        "SELECT t.club_id, c.plan, t.visibility, \
+                COALESCE(ta.status, 'approved') AS approval_status \
+         FROM tournaments t JOIN clubs c ON c.id = t.club_id \
+         LEFT JOIN tournament_approvals ta ON ta.tournament_id = t.id \
+         WHERE t.id = $1 AND t.deleted_at IS NULL",
+    )
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Whether the user is on the club's active roster (a club member).
+pub async fn is_club_member<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    user_id: Uuid,
+) -> SqlxResult<bool> {
+    sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM club_player \
+         WHERE club_id = $1 AND app_user_id = $2 AND is_active)",
+    )
+    .bind(club_id)
+    .bind(user_id)
+    .fetch_one(executor)
+    .await
+}
+
+/// Whether the user is already in the tournament (registered or entered),
+/// which keeps an unlisted tournament visible to them without the link.
+pub async fn is_participant<'e>(
+    executor: impl PgExecutor<'e>,
+    tournament_id: Uuid,
+    user_id: Uuid,
+) -> SqlxResult<bool> {
+    sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM tournament_registrations \
+                        WHERE tournament_id = $1 AND user_id = $2) \
+             OR EXISTS (SELECT 1 FROM tournament_entries \
+                        WHERE tournament_id = $1 AND user_id = $2)",
+    )
+    .bind(tournament_id)
+    .bind(user_id)
+    .fetch_one(executor)
+    .await
+}
+
+/// The tournament's invite token, minting `candidate` if it has none yet.
+pub async fn ensure_invite_token<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    candidate: &str,
+) -> SqlxResult<Option<String>> {
+    sqlx::query_scalar(
+        "UPDATE tournaments SET invite_token = COALESCE(invite_token, $2) \
+         WHERE id = $1 RETURNING invite_token",
+    )
+    .bind(id)
+    .bind(candidate)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Replace the invite token, revoking links built on the old one.
+pub async fn rotate_invite_token<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    token: &str,
+) -> SqlxResult<bool> {
+    let result = sqlx::query("UPDATE tournaments SET invite_token = $2 WHERE id = $1")
+        .bind(id)
+        .bind(token)
+        .execute(executor)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn get_invite_token<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<String>> {
+    sqlx::query_scalar::<_, Option<String>>("SELECT invite_token FROM tournaments WHERE id = $1")
+        .bind(id)
+        .fetch_optional(executor)
+        .await
+        .map(Option::flatten)
+}
+
+pub async fn find_by_invite_token<'e>(
+    executor: impl PgExecutor<'e>,
+    token: &str,
+) -> SqlxResult<Option<Uuid>> {
+    sqlx::query_scalar("SELECT id FROM tournaments WHERE invite_token = $1 AND deleted_at IS NULL")
+        .bind(token)
+        .fetch_optional(executor)
+        .await
+}
+
 pub async fn update_live_status<'e>(
     executor: impl PgExecutor<'e>,
     id: Uuid,
@@ -213,10 +362,10 @@ pub async fn update_live_status<'e>(
         UPDATE tournaments
         SET live_status = $2::tournament_live_status,
             updated_at = NOW()
-        WHERE id = $1
+        WHERE id = $1 AND deleted_at IS NULL
         RETURNING id, club_id, name, description, start_time, end_time,
                  buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
-                 late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at
+                 late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
         "#,
     )
     .bind(id)
@@ -225,6 +374,84 @@ pub async fn update_live_status<'e>(
     .await
 }
 
+/// Move a standalone tournament into a series (`Some`) or out of its one
+/// (`None`). Flights and final days belong to their multi-day event, and a
+/// tournament already in a series must leave it first, so neither matches.
+pub async fn set_series<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    series_id: Option<Uuid>,
+) -> SqlxResult<Option<TournamentRow>> {
+    sqlx::query_as::<_, TournamentRow>(
+        r#"
+        UPDATE tournaments
+        SET series_id = $2,
+            updated_at = NOW()
+        WHERE id = $1 AND deleted_at IS NULL
+          AND flight_label IS NULL AND NOT is_final_day
+          AND ($2::uuid IS NULL OR series_id IS NULL)
+        RETURNING id, club_id, name, description, start_time, end_time,
+                 buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
+                 late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
+        "#,
+    )
+    .bind(id)
+    .bind(series_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Lock a tournament row, as registration and check-in seating do, so a
+/// change to its seating can't interleave with them. `false` if it's gone.
+pub async fn lock<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<bool> {
+    let found: Option<Uuid> = sqlx::query_scalar(
+        "SELECT id FROM tournaments WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+    )
+    .bind(id)
+    .fetch_optional(executor)
+    .await?;
+    Ok(found.is_some())
+}
+
+/// `lock`, but only while the tournament is in late registration; `false`
+/// otherwise. Alternate sweeps serialize on it.
+pub async fn lock_if_late_registration<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<bool> {
+    let found: Option<Uuid> = sqlx::query_scalar(
+        "SELECT id FROM tournaments WHERE id = $1 AND live_status = 'late_registration' FOR UPDATE",
+    )
+    .bind(id)
+    .fetch_optional(executor)
+    .await?;
+    Ok(found.is_some())
+}
+
+/// Set or clear (`None`) how many seats each of the tournament's tables plays
+/// with.
+pub async fn set_seats_per_table<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    seats_per_table: Option<i32>,
+) -> SqlxResult<Option<TournamentRow>> {
+    sqlx::query_as::<_, TournamentRow>(
+        r#"
+        UPDATE tournaments
+        SET seats_per_table = $2,
+            updated_at = NOW()
+        WHERE id = $1 AND deleted_at IS NULL
+        RETURNING id, club_id, name, description, start_time, end_time,
+                 buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
+                 late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
+        "#,
+    )
+    .bind(id)
+    .bind(seats_per_table)
+    .fetch_optional(executor)
+    .await
+}
+
 pub async fn list_by_live_status<'e>(
     executor: impl PgExecutor<'e>,
     live_status: TournamentLiveStatus,
@@ -233,9 +460,9 @@ pub async fn list_by_live_status<'e>(
         r#"
         SELECT id, club_id, name, description, start_time, end_time,
                buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
-               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at
+               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
         FROM tournaments
-        WHERE live_status = $1
+        WHERE live_status = $1 AND deleted_at IS NULL
         ORDER BY start_time ASC
         "#,
     )
@@ -249,9 +476,9 @@ pub async fn list_live<'e>(executor: impl PgExecutor<'e>) -> SqlxResult<Vec<Tour
         r#"
         SELECT id, club_id, name, description, start_time, end_time,
                buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
-               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at
+               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
         FROM tournaments
-        WHERE live_status IN ('in_progress', 'break', 'final_table')
+        WHERE live_status IN ('in_progress', 'break', 'final_table') AND deleted_at IS NULL
         ORDER BY start_time ASC
         "#,
     )
@@ -259,6 +486,71 @@ pub async fn list_live<'e>(executor: impl PgExecutor<'e>) -> SqlxResult<Vec<Tour
     .await
 }
 
+/// A club's tournaments whose clock is in play: late registration through the
+/// final table.
+pub async fn list_live_by_club<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+) -> SqlxResult<Vec<TournamentRow>> {
+    sqlx::query_as::<_, TournamentRow>(
+        r#"
+        SELECT id, club_id, name, description, start_time, end_time,
+               buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
+               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
+        FROM tournaments
+        WHERE club_id = $1 AND deleted_at IS NULL
+          AND live_status IN ('late_registration', 'in_progress', 'break', 'final_table')
+        ORDER BY start_time ASC
+        "#,
+    )
+    .bind(club_id)
+    .fetch_all(executor)
+    .await
+}
+
+/// A club's tournaments starting in `[from, to)`, earliest first.
+pub async fn list_by_club_starting_between<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> SqlxResult<Vec<TournamentRow>> {
+    sqlx::query_as::<_, TournamentRow>(
+        r#"
+        SELECT id, club_id, name, description, start_time, end_time,
+               buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
+               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
+        FROM tournaments
+        WHERE club_id = $1 AND deleted_at IS NULL AND start_time >= $2 AND start_time < $3
+        ORDER BY start_time ASC
+        "#,
+    )
+    .bind(club_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(executor)
+    .await
+}
+
+/// Whether the club already has a tournament with this name starting at this
+/// exact time (guards against importing the same results file twice).
+pub async fn exists_by_club_name_and_start<'e>(
+    executor: impl PgExecutor<'e>,
+    club_id: Uuid,
+    name: &str,
+    start_time: DateTime<Utc>,
+) -> SqlxResult<bool> {
+    sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM tournaments WHERE club_id = $1 AND name = $2 AND start_time = $3 \
+         AND deleted_at IS NULL)",
+    )
+    .bind(club_id)
+    .bind(name)
+    .bind(start_time)
+    .fetch_one(executor)
+    .await
+}
+
 pub async fn list_starting_soon<'e>(
     executor: impl PgExecutor<'e>,
     within_minutes: i32,
@@ -267,9 +559,9 @@ pub async fn list_starting_soon<'e>(
         r#"
         SELECT id, club_id, name, description, start_time, end_time,
                buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
-               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at
+               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
         FROM tournaments
-        WHERE live_status IN ('not_started', 'registration_open')
+        WHERE live_status IN ('not_started', 'registration_open') AND deleted_at IS NULL
           AND start_time > NOW()
           AND start_time <= NOW() + ($1 || ' minutes')::INTERVAL
         ORDER BY start_time ASC
@@ -288,9 +580,9 @@ pub async fn list_by_series<'e>(
         r#"
         SELECT id, club_id, name, description, start_time, end_time,
                buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
-               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at
+               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
         FROM tournaments
-        WHERE series_id = $1
+        WHERE series_id = $1 AND deleted_at IS NULL
         ORDER BY is_final_day ASC, start_time ASC
         "#,
     )
@@ -311,9 +603,9 @@ pub async fn get_by_ids<'e>(
         r#"
         SELECT id, club_id, name, description, start_time, end_time,
                buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
-               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at
+               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
         FROM tournaments
-        WHERE id = ANY($1::uuid[])
+        WHERE id = ANY($1::uuid[]) AND deleted_at IS NULL
         "#,
     )
     .bind(ids)
@@ -332,14 +624,17 @@ pub async fn create<'e>(
                                  late_registration_level, level_two_bonus_chips,
                                  voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
                                  bounty_type, bounty_amount_cents, leaderboard_config_id,
-                                 series_id, flight_label, is_final_day, starting_stack)
+                                 series_id, flight_label, is_final_day, starting_stack,
+                                 chip_race_rule, visibility, early_bird_buy_in_cents,
+                                 early_bird_until, points_multiplier, seats_per_table)
         VALUES ($1, $2, $3, $4, $5, $6, COALESCE($7, 0), $8, $9, $10,
                 $11, COALESCE($12, 0), $13, $14, $15,
                 COALESCE($16, 'none'), COALESCE($17, 0), $18,
-                $19, $20, $21, $22)
+                $19, $20, $21, $22, COALESCE($23, 'race'), COALESCE($24, 'public'),
+                $25, $26, COALESCE($27, 1), $28)
         RETURNING id, club_id, name, description, start_time, end_time,
                   buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
-                  late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at
+                  late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
         "#,
     )
     .bind(data.club_id)
@@ -364,6 +659,12 @@ pub async fn create<'e>(
     .bind(data.flight_label)
     .bind(data.is_final_day)
     .bind(data.starting_stack)
+    .bind(data.chip_race_rule)
+    .bind(data.visibility)
+    .bind(data.early_bird_buy_in_cents)
+    .bind(data.early_bird_until)
+    .bind(data.points_multiplier)
+    .bind(data.seats_per_table)
     .fetch_one(executor)
     .await
 }
@@ -394,11 +695,17 @@ pub async fn update<'e>(
             bounty_amount_cents = COALESCE($17, bounty_amount_cents),
             leaderboard_config_id = COALESCE($18, leaderboard_config_id),
             starting_stack = COALESCE($19, starting_stack),
+            chip_race_rule = COALESCE($20, chip_race_rule),
+            visibility = COALESCE($21, visibility),
+            early_bird_buy_in_cents = COALESCE($22, early_bird_buy_in_cents),
+            early_bird_until = COALESCE($23, early_bird_until),
+            points_multiplier = COALESCE($24, points_multiplier),
             updated_at = NOW()
-        WHERE id = $1 AND live_status != 'finished'
+        WHERE id = $1 AND live_status NOT IN ('finished', 'cancelled') AND deleted_at IS NULL
+          AND ($25::timestamptz IS NULL OR updated_at = $25)
         RETURNING id, club_id, name, description, start_time, end_time,
                   buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
-                  late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at
+                  late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
         "#,
     )
     .bind(id)
@@ -420,10 +727,50 @@ pub async fn update<'e>(
     .bind(data.bounty_amount_cents)
     .bind(data.leaderboard_config_id)
     .bind(data.starting_stack)
+    .bind(data.chip_race_rule)
+    .bind(data.visibility)
+    .bind(data.early_bird_buy_in_cents)
+    .bind(data.early_bird_until)
+    .bind(data.points_multiplier)
+    .bind(data.expected_updated_at)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Soft-delete a tournament that has no results yet. `None` when it is
+/// missing, already deleted or has results.
+pub async fn soft_delete<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    deleted_by: Uuid,
+) -> SqlxResult<Option<TournamentRow>> {
+    sqlx::query_as::<_, TournamentRow>(
+        r#"
+        UPDATE tournaments
+        SET deleted_at = NOW(),
+            deleted_by = $2,
+            updated_at = NOW()
+        WHERE id = $1 AND deleted_at IS NULL
+          AND NOT EXISTS (SELECT 1 FROM tournament_results WHERE tournament_id = $1)
+        RETURNING id, club_id, name, description, start_time, end_time,
+                  buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
+                  late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
+        "#,
+    )
+    .bind(id)
+    .bind(deleted_by)
     .fetch_optional(executor)
     .await
 }
 
+/// Whether any results have been entered for the tournament.
+pub async fn has_results<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> SqlxResult<bool> {
+    sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM tournament_results WHERE tournament_id = $1)")
+        .bind(id)
+        .fetch_one(executor)
+        .await
+}
+
 pub async fn list_stale<'e>(
     executor: impl PgExecutor<'e>,
     max_hours: i32,
@@ -432,9 +779,10 @@ pub async fn list_stale<'e>(
         r#"
         SELECT id, club_id, name, description, start_time, end_time,
                buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
-               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at
+               late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
         FROM tournaments
         WHERE live_status IN ('in_progress', 'late_registration', 'break', 'final_table')
+          AND deleted_at IS NULL
           AND updated_at < NOW() - ($1 || ' hours')::INTERVAL
         ORDER BY updated_at ASC
         "#,
@@ -454,10 +802,32 @@ pub async fn auto_finish<'e>(
         SET live_status = 'finished',
             end_time = NOW(),
             updated_at = NOW()
-        WHERE id = $1 AND live_status != 'finished'
+        WHERE id = $1 AND live_status != 'finished' AND deleted_at IS NULL
+        RETURNING id, club_id, name, description, start_time, end_time,
+                  buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
+                  late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Cancel a tournament that hasn't finished. Returns None when it is
+/// unknown, already finished or already cancelled.
+pub async fn cancel<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> SqlxResult<Option<TournamentRow>> {
+    sqlx::query_as::<_, TournamentRow>(
+        r#"
+        UPDATE tournaments
+        SET live_status = 'cancelled',
+            updated_at = NOW()
+        WHERE id = $1 AND live_status NOT IN ('finished', 'cancelled') AND deleted_at IS NULL
         RETURNING id, club_id, name, description, start_time, end_time,
                   buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, early_bird_bonus_chips, level_two_bonus_chips, voucher_value_cents, rebuy_max, addon_chips, addon_price_cents,
-                  late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, created_at, updated_at
+                  late_registration_level, bounty_type, bounty_amount_cents, leaderboard_config_id, series_id, flight_label, is_final_day, chip_race_rule, visibility, early_bird_buy_in_cents, early_bird_until, points_multiplier, seats_per_table, created_at, updated_at
         "#,
     )
     .bind(id)
@@ -0,0 +1,74 @@
+//! Account-level changes to a user's contact details, with who made them.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Result as SqlxResult};
+use uuid::Uuid;
+
+use crate::pii::Pii;
+
+const COLS: &str = "id, user_id, actor_id, action, old_value, new_value, created_at";
+
+pub const EMAIL_CHANGED: &str = "email_changed";
+pub const PHONE_CHANGED: &str = "phone_changed";
+
+#[derive(Debug, Clone, FromRow)]
+pub struct UserAuditEntryRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub action: String,
+    /// Encrypted at rest.
+    pub old_value: Option<Pii>,
+    /// Encrypted at rest.
+    pub new_value: Option<Pii>,
+    pub created_at: DateTime<Utc>,
+}
+
+pub async fn record<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+    actor_id: Option<Uuid>,
+    action: &str,
+    old_value: Option<&str>,
+    new_value: Option<&str>,
+) -> SqlxResult<()> {
+    sqlx::query(
+        "INSERT INTO user_audit_log (user_id, actor_id, action, old_value, new_value) \
+         VALUES ($1, $2, $3, $4, $5)",
+    )
+    .bind(user_id)
+    .bind(actor_id)
+    .bind(action)
+    .bind(old_value.map(|v| Pii::from(v.to_string())))
+    .bind(new_value.map(|v| Pii::from(v.to_string())))
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+/// A user's entries, newest first.
+pub async fn list_for_user<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+    limit: i64,
+) -> SqlxResult<Vec<UserAuditEntryRow>> {
+    sqlx::query_as::<_, UserAuditEntryRow>(&format!(
+        "SELECT {COLS} FROM user_audit_log WHERE user_id = $1 ORDER BY created_at DESC LIMIT $2"
+    ))
+    .bind(user_id)
+    .bind(limit)
+    .fetch_all(executor)
+    .await
+}
+
+/// Drop a user's history, e.g. when their account is anonymized.
+pub async fn delete_all_for_user<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+) -> SqlxResult<u64> {
+    let result = sqlx::query("DELETE FROM user_audit_log WHERE user_id = $1")
+        .bind(user_id)
+        .execute(executor)
+        .await?;
+    Ok(result.rows_affected())
+}
@@ -2,7 +2,11 @@ use chrono::{DateTime, Utc};
 use sqlx::{PgExecutor, PgPool, Result};
 use uuid::Uuid;
 
-use crate::{models::UserRow, pagination::LimitOffset};
+use crate::{
+    models::{TokenSubjectRow, UserRow},
+    pagination::LimitOffset,
+    pii::Pii,
+};
 
 #[derive(Debug, Clone)]
 pub struct UserFilter {
@@ -28,6 +32,16 @@ pub struct UpdateUserData {
     pub phone: Option<String>,
 }
 
+/// A user's edit of their own profile. `None` keeps a field; for the
+/// nullable ones `Some(None)` clears it.
+#[derive(Debug, Clone, Default)]
+pub struct ProfileUpdate {
+    pub first_name: Option<String>,
+    pub last_name: Option<Option<String>>,
+    pub username: Option<Option<String>>,
+    pub avatar_url: Option<Option<String>>,
+}
+
 pub async fn list(
     pool: &PgPool,
     filter: UserFilter,
@@ -36,27 +50,27 @@ pub async fn list(
     let page = page.unwrap_or_default();
 
     let mut query = sqlx::QueryBuilder::new(
-        "SELECT id, email, username, first_name, last_name, phone, is_active, role, locale, created_at, updated_at FROM users WHERE 1=1"
+        "SELECT u.id, COALESCE(pu.email, '') AS email, u.username, u.first_name, u.last_name, pu.phone, u.avatar_url, u.is_active, u.role, u.locale, u.created_at, u.updated_at FROM users_public u LEFT JOIN users pu ON pu.id = u.id WHERE 1=1"
     );
 
     if let Some(search) = &filter.search {
         let search_pattern = format!("%{}%", search.to_lowercase());
         query.push(" AND (");
-        query.push("LOWER(username) LIKE ");
+        query.push("LOWER(u.username) LIKE ");
         query.push_bind(search_pattern.clone());
-        query.push(" OR LOWER(first_name) LIKE ");
+        query.push(" OR LOWER(u.first_name) LIKE ");
         query.push_bind(search_pattern.clone());
-        query.push(" OR LOWER(last_name) LIKE ");
+        query.push(" OR LOWER(u.last_name) LIKE ");
         query.push_bind(search_pattern);
         query.push(")");
     }
 
     if let Some(is_active) = filter.is_active {
-        query.push(" AND is_active = ");
+        query.push(" AND u.is_active = ");
         query.push_bind(is_active);
     }
 
-    query.push(" ORDER BY created_at DESC");
+    query.push(" ORDER BY u.created_at DESC");
     query.push(" LIMIT ");
     query.push_bind(page.limit);
     query.push(" OFFSET ");
@@ -68,22 +82,23 @@ pub async fn list(
 }
 
 pub async fn count(pool: &PgPool, filter: UserFilter) -> Result<i64> {
-    let mut query = sqlx::QueryBuilder::new("SELECT COUNT(*) as count FROM users WHERE 1=1");
+    let mut query =
+        sqlx::QueryBuilder::new("SELECT COUNT(*) as count FROM users_public u WHERE 1=1");
 
     if let Some(search) = &filter.search {
         let search_pattern = format!("%{}%", search.to_lowercase());
         query.push(" AND (");
-        query.push("LOWER(username) LIKE ");
+        query.push("LOWER(u.username) LIKE ");
         query.push_bind(search_pattern.clone());
-        query.push(" OR LOWER(first_name) LIKE ");
+        query.push(" OR LOWER(u.first_name) LIKE ");
         query.push_bind(search_pattern.clone());
-        query.push(" OR LOWER(last_name) LIKE ");
+        query.push(" OR LOWER(u.last_name) LIKE ");
         query.push_bind(search_pattern);
         query.push(")");
     }
 
     if let Some(is_active) = filter.is_active {
-        query.push(" AND is_active = ");
+        query.push(" AND u.is_active = ");
         query.push_bind(is_active);
     }
 
@@ -93,7 +108,7 @@ pub async fn count(pool: &PgPool, filter: UserFilter) -> Result<i64> {
 
 pub async fn get_by_id<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> Result<Option<UserRow>> {
     let row = sqlx::query_as::<_, UserRow>(
-        "SELECT id, email, username, first_name, last_name, phone, is_active, role, locale, created_at, updated_at FROM users WHERE id = $1"
+        "SELECT id, email, username, first_name, last_name, phone, avatar_url, is_active, role, locale, created_at, updated_at FROM users WHERE id = $1"
     )
     .bind(id)
     .fetch_optional(executor)
@@ -102,19 +117,27 @@ pub async fn get_by_id<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> Result<Op
     Ok(row)
 }
 
+/// The name `id` goes by publicly: their username, else their first name.
+pub async fn public_name<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> Result<Option<String>> {
+    sqlx::query_scalar("SELECT COALESCE(username, first_name) FROM users_public WHERE id = $1")
+        .bind(id)
+        .fetch_optional(executor)
+        .await
+}
+
 pub async fn create<'e>(executor: impl PgExecutor<'e>, data: CreateUserData) -> Result<UserRow> {
     let row = sqlx::query_as::<_, UserRow>(
         r#"
         INSERT INTO users (email, first_name, last_name, username, phone, role, is_active)
         VALUES ($1, $2, $3, $4, $5, 'player', true)
-        RETURNING id, email, username, first_name, last_name, phone, is_active, role, locale, created_at, updated_at
+        RETURNING id, email, username, first_name, last_name, phone, avatar_url, is_active, role, locale, created_at, updated_at
         "#,
     )
     .bind(&data.email)
     .bind(&data.first_name)
     .bind(&data.last_name)
     .bind(&data.username)
-    .bind(&data.phone)
+    .bind(data.phone.clone().map(Pii::from))
     .fetch_one(executor)
     .await?;
 
@@ -136,7 +159,7 @@ pub async fn update<'e>(
             phone = COALESCE($6, phone),
             updated_at = NOW()
         WHERE id = $1
-        RETURNING id, email, username, first_name, last_name, phone, is_active, role, locale, created_at, updated_at
+        RETURNING id, email, username, first_name, last_name, phone, avatar_url, is_active, role, locale, created_at, updated_at
         "#,
     )
     .bind(id)
@@ -144,20 +167,64 @@ pub async fn update<'e>(
     .bind(&data.first_name)
     .bind(&data.last_name)
     .bind(&data.username)
-    .bind(&data.phone)
+    .bind(data.phone.clone().map(Pii::from))
     .fetch_optional(executor)
     .await?;
 
     Ok(row)
 }
 
+pub async fn update_profile<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    data: ProfileUpdate,
+) -> Result<Option<UserRow>> {
+    sqlx::query_as::<_, UserRow>(
+        r#"
+        UPDATE users
+        SET first_name = COALESCE($2, first_name),
+            last_name = CASE WHEN $3 THEN $4 ELSE last_name END,
+            username = CASE WHEN $5 THEN $6 ELSE username END,
+            avatar_url = CASE WHEN $7 THEN $8 ELSE avatar_url END,
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, email, username, first_name, last_name, phone, avatar_url, is_active, role, locale, created_at, updated_at
+        "#,
+    )
+    .bind(id)
+    .bind(&data.first_name)
+    .bind(data.last_name.is_some())
+    .bind(data.last_name.flatten())
+    .bind(data.username.is_some())
+    .bind(data.username.flatten())
+    .bind(data.avatar_url.is_some())
+    .bind(data.avatar_url.flatten())
+    .fetch_optional(executor)
+    .await
+}
+
+/// Whether another account already uses `username`, ignoring case.
+pub async fn username_taken<'e>(
+    executor: impl PgExecutor<'e>,
+    username: &str,
+    except: Uuid,
+) -> Result<bool> {
+    sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM users_public WHERE LOWER(username) = LOWER($1) AND id <> $2)",
+    )
+    .bind(username)
+    .bind(except)
+    .fetch_one(executor)
+    .await
+}
+
 pub async fn deactivate<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> Result<Option<UserRow>> {
     let row = sqlx::query_as::<_, UserRow>(
         r#"
         UPDATE users
         SET is_active = false, updated_at = NOW()
         WHERE id = $1
-        RETURNING id, email, username, first_name, last_name, phone, is_active, role, locale, created_at, updated_at
+        RETURNING id, email, username, first_name, last_name, phone, avatar_url, is_active, role, locale, created_at, updated_at
         "#,
     )
     .bind(id)
@@ -180,10 +247,11 @@ pub async fn anonymize<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> Result<Op
             first_name = 'Deleted',
             last_name = 'Player',
             phone = NULL,
+            avatar_url = NULL,
             is_active = false,
             updated_at = NOW()
         WHERE id = $1
-        RETURNING id, email, username, first_name, last_name, phone, is_active, role, locale, created_at, updated_at
+        RETURNING id, email, username, first_name, last_name, phone, avatar_url, is_active, role, locale, created_at, updated_at
         "#,
     )
     .bind(id)
@@ -199,7 +267,7 @@ pub async fn reactivate<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> Result<O
         UPDATE users
         SET is_active = true, updated_at = NOW()
         WHERE id = $1
-        RETURNING id, email, username, first_name, last_name, phone, is_active, role, locale, created_at, updated_at
+        RETURNING id, email, username, first_name, last_name, phone, avatar_url, is_active, role, locale, created_at, updated_at
         "#,
     )
     .bind(id)
@@ -237,6 +305,62 @@ pub async fn get_last_seen_at<'e>(
     Ok(row.flatten())
 }
 
+/// The claims an access token for this user should carry. Managed clubs are
+/// listed for every role; only managers' assignments grant access.
+pub async fn get_token_subject<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> Result<Option<TokenSubjectRow>> {
+    sqlx::query_as::<_, TokenSubjectRow>(
+        r#"
+        SELECT u.id, u.email, u.role, u.is_active, u.token_version,
+               ARRAY(
+                   SELECT cm.club_id
+                   FROM club_managers cm
+                   WHERE cm.user_id = u.id AND cm.is_active = true
+                   ORDER BY cm.assigned_at
+               ) AS club_ids
+        FROM users u
+        WHERE u.id = $1
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Current token version, compared with the `ver` claim on every request.
+/// `None` when the user no longer exists.
+pub async fn get_token_version<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> Result<Option<i32>> {
+    sqlx::query_scalar::<_, i32>("SELECT token_version FROM users WHERE id = $1")
+        .bind(id)
+        .fetch_optional(executor)
+        .await
+}
+
+/// Whether the account can sign in with a password.
+pub async fn has_password<'e>(executor: impl PgExecutor<'e>, id: Uuid) -> Result<bool> {
+    sqlx::query_scalar::<_, bool>("SELECT password_hash IS NOT NULL FROM users WHERE id = $1")
+        .bind(id)
+        .fetch_optional(executor)
+        .await
+        .map(|v| v.unwrap_or(false))
+}
+
+/// `has_password`, locking the user row so concurrent login-method changes
+/// serialize. `None` when the user doesn't exist.
+pub async fn lock_has_password<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> Result<Option<bool>> {
+    sqlx::query_scalar::<_, bool>(
+        "SELECT password_hash IS NOT NULL FROM users WHERE id = $1 FOR UPDATE",
+    )
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
 /// Active player accounts with no activity within `retention_days` — candidates
 /// for retention anonymization. Scoped to `role = 'player'` so manager/admin
 /// staff accounts are never swept. `last_seen_at` falls back to `created_at`
@@ -265,12 +389,20 @@ pub async fn find_inactive_player_ids<'e>(
     Ok(ids)
 }
 
+/// The id of the account registered under `email`, whoever is asking.
+pub async fn id_by_email<'e>(executor: impl PgExecutor<'e>, email: &str) -> Result<Option<Uuid>> {
+    sqlx::query_scalar("SELECT app_user_by_email($1)")
+        .bind(email)
+        .fetch_one(executor)
+        .await
+}
+
 pub async fn get_by_email<'e>(
     executor: impl PgExecutor<'e>,
     email: &str,
 ) -> Result<Option<UserRow>> {
     sqlx::query_as::<_, UserRow>(
-        "SELECT id, email, username, first_name, last_name, phone, is_active, role, locale, created_at, updated_at FROM users WHERE email = $1",
+        "SELECT id, email, username, first_name, last_name, phone, avatar_url, is_active, role, locale, created_at, updated_at FROM users WHERE email = $1",
     )
     .bind(email)
     .fetch_optional(executor)
@@ -289,7 +421,7 @@ pub async fn create_invited_manager<'e>(
         "
         INSERT INTO users (email, first_name, last_name, role, is_active)
         VALUES ($1, $2, $3, 'manager', true)
-        RETURNING id, email, username, first_name, last_name, phone, is_active, role, locale, created_at, updated_at
+        RETURNING id, email, username, first_name, last_name, phone, avatar_url, is_active, role, locale, created_at, updated_at
         ",
     )
     .bind(email)
@@ -310,3 +442,161 @@ pub async fn promote_player_to_manager<'e>(
         .await?;
     Ok(())
 }
+
+/// Stored phone numbers grouped by the key sealing them (`None`:
+/// plaintext), for checking a key rotation has finished.
+pub async fn count_phones_by_key<'e>(
+    executor: impl PgExecutor<'e>,
+) -> Result<Vec<(Option<String>, i64)>> {
+    sqlx::query_as(
+        r#"
+        SELECT CASE WHEN phone LIKE 'enc:v1:%' THEN split_part(phone, ':', 3) END AS key_id,
+               COUNT(*)
+        FROM users
+        WHERE NULLIF(phone, '') IS NOT NULL
+        GROUP BY 1
+        ORDER BY 1 NULLS FIRST
+        "#,
+    )
+    .fetch_all(executor)
+    .await
+}
+
+/// A phone number not yet sealed with the active key: its stored form
+/// (to update it only if unchanged) and its plaintext.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UnrotatedPhoneRow {
+    pub id: Uuid,
+    pub stored: String,
+    pub phone: Pii,
+}
+
+/// Up to `limit` users whose phone is plaintext or sealed with a key other
+/// than `active_key_id`.
+pub async fn list_unrotated_phones<'e>(
+    executor: impl PgExecutor<'e>,
+    active_key_id: &str,
+    limit: i64,
+) -> Result<Vec<UnrotatedPhoneRow>> {
+    sqlx::query_as::<_, UnrotatedPhoneRow>(
+        r#"
+        SELECT id, phone AS stored, phone
+        FROM users
+        WHERE NULLIF(phone, '') IS NOT NULL
+          AND NOT (phone LIKE 'enc:v1:%' AND split_part(phone, ':', 3) = $1)
+        ORDER BY id
+        LIMIT $2
+        "#,
+    )
+    .bind(active_key_id)
+    .bind(limit)
+    .fetch_all(executor)
+    .await
+}
+
+pub async fn count_unrotated_phones<'e>(
+    executor: impl PgExecutor<'e>,
+    active_key_id: &str,
+) -> Result<i64> {
+    sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*) FROM users
+        WHERE NULLIF(phone, '') IS NOT NULL
+          AND NOT (phone LIKE 'enc:v1:%' AND split_part(phone, ':', 3) = $1)
+        "#,
+    )
+    .bind(active_key_id)
+    .fetch_one(executor)
+    .await
+}
+
+/// Re-seal one phone with the active key, unless the user changed it since
+/// it was read. Returns whether it was rewritten.
+pub async fn reseal_phone<'e>(
+    executor: impl PgExecutor<'e>,
+    row: &UnrotatedPhoneRow,
+) -> Result<bool> {
+    let result = sqlx::query("UPDATE users SET phone = $3 WHERE id = $1 AND phone = $2")
+        .bind(row.id)
+        .bind(&row.stored)
+        .bind(&row.phone)
+        .execute(executor)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// The country of the first club the user joined a roster at, which stands
+/// in for a missing country code when they type a national phone number.
+pub async fn home_country<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+) -> Result<Option<String>> {
+    sqlx::query_scalar(
+        r#"
+        SELECT c.country
+        FROM club_player cp
+        JOIN clubs c ON c.id = cp.club_id
+        WHERE cp.app_user_id = $1 AND c.country IS NOT NULL
+        ORDER BY cp.created_at
+        LIMIT 1
+        "#,
+    )
+    .bind(user_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// A stored phone number with the user's home country (see
+/// [`home_country`]), for normalizing numbers saved as typed. `stored` is
+/// left sealed: open it with [`crate::pii::open`], which fails for keys this
+/// process doesn't hold.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct StoredPhoneRow {
+    pub id: Uuid,
+    pub stored: String,
+    pub country: Option<String>,
+}
+
+/// Up to `limit` users with a phone number, by id, after `after_id`.
+pub async fn list_phones_after<'e>(
+    executor: impl PgExecutor<'e>,
+    after_id: Option<Uuid>,
+    limit: i64,
+) -> Result<Vec<StoredPhoneRow>> {
+    sqlx::query_as::<_, StoredPhoneRow>(
+        r#"
+        SELECT u.id, u.phone AS stored,
+               (SELECT c.country
+                FROM club_player cp
+                JOIN clubs c ON c.id = cp.club_id
+                WHERE cp.app_user_id = u.id AND c.country IS NOT NULL
+                ORDER BY cp.created_at
+                LIMIT 1) AS country
+        FROM users u
+        WHERE NULLIF(u.phone, '') IS NOT NULL
+          AND ($1::uuid IS NULL OR u.id > $1)
+        ORDER BY u.id
+        LIMIT $2
+        "#,
+    )
+    .bind(after_id)
+    .bind(limit)
+    .fetch_all(executor)
+    .await
+}
+
+/// Replace one phone number, unless the user changed it since it was read.
+/// Returns whether it was rewritten.
+pub async fn replace_phone<'e>(
+    executor: impl PgExecutor<'e>,
+    row: &StoredPhoneRow,
+    phone: &str,
+) -> Result<bool> {
+    let result = sqlx::query("UPDATE users SET phone = $3 WHERE id = $1 AND phone = $2")
+        .bind(row.id)
+        .bind(&row.stored)
+        .bind(Pii::from(phone.to_string()))
+        .execute(executor)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
@@ -11,8 +11,8 @@ pub async fn stats_for_year<'e>(
 ) -> SqlxResult<WrappedStatsRow> {
     sqlx::query_as::<_, WrappedStatsRow>(
         "SELECT COUNT(*) AS tournaments, \
-                COALESCE(SUM(t.buy_in_cents), 0) AS buyins_cents, \
-                COALESCE(SUM(tr.prize_cents), 0) AS winnings_cents, \
+                COALESCE(SUM(t.buy_in_cents), 0)::bigint AS buyins_cents, \
+                COALESCE(SUM(tr.prize_cents), 0)::bigint AS winnings_cents, \
                 COALESCE(SUM(CASE WHEN tr.prize_cents > 0 THEN 1 ELSE 0 END), 0) AS itm_count, \
                 MIN(tr.final_position) AS best_finish \
          FROM tournament_results tr JOIN tournaments t ON t.id = tr.tournament_id \
@@ -57,7 +57,7 @@ pub async fn nemesis_for_user<'e>(
          FROM tournament_results r1 \
          JOIN tournament_results r2 \
               ON r2.tournament_id = r1.tournament_id AND r2.user_id <> r1.user_id \
-         JOIN users u ON u.id = r2.user_id \
+         JOIN users_public u ON u.id = r2.user_id \
          WHERE r1.user_id = $1 \
          GROUP BY r2.user_id, u.username, u.first_name \
          HAVING SUM(CASE WHEN r1.final_position > r2.final_position THEN 1 ELSE 0 END) > 0 \
@@ -1,4 +1,4 @@
--- App Store review demo seed — GENERATED by generate_demo_seed.py, do not edit by hand.
+-- App Store review demo seed — GENERATED by `cargo run -p fixtures --bin demo_seed`, do not edit by hand.
 -- Anchor date: 2026-07-04. Regenerate + re-apply when the data looks stale.
 -- Apply after fixtures/00_cleanup.sql. Logins: reviewer@pocketpair.app /
 -- manager@pocketpair.app, password PocketPair2026 (admin unchanged).
@@ -8,28 +8,28 @@ INSERT INTO users (id, email, username, first_name, last_name, role, locale, pas
 ('8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', 'reviewer@pocketpair.app', 'alexmartin', 'Alex', 'Martin', 'player', 'en', '$2b$12$4.9dd0XFONNttsTDb4YrIOHPgClE4n1vpZc9liC0YigLYmI7uckbO', NOW() - interval '1 day'),
 ('f647df8a-5e87-5d63-af45-659fe8a0c16f', 'manager@pocketpair.app', 'marc_ldc', 'Marc', 'Delvaux', 'manager', 'fr', '$2b$12$4.9dd0XFONNttsTDb4YrIOHPgClE4n1vpZc9liC0YigLYmI7uckbO', NOW() - interval '2 hours'),
 ('ff4cc0a7-af86-5de9-b849-badb027c2205', 'moyse94@gmail.com', 'super_admin', 'Admin', 'Global', 'admin', 'en', '$2b$12$YkLUdU.KpnCZ78RF3eOMxuDK3DahuGBxf9Q.fqY1oqAZLQovqdjA6', NOW()),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', 'maxime.dupont@demo.pocketpair.app', 'maxthegrinder', 'Maxime', 'Dupont', 'player', 'fr', NULL, NOW() - interval '75 hours'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'julien.lambert@demo.pocketpair.app', 'jlamb_poker', 'Julien', 'Lambert', 'player', 'fr', NULL, NOW() - interval '20 hours'),
-('9549d323-2ca7-5b7a-a23b-e9844c782d78', 'nicolas.peeters@demo.pocketpair.app', 'nico_peeters', 'Nicolas', 'Peeters', 'player', 'nl', NULL, NOW() - interval '72 hours'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'thomas.janssens@demo.pocketpair.app', 'tommy_jans', 'Thomas', 'Janssens', 'player', 'nl', NULL, NOW() - interval '72 hours'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'lucas.dubois@demo.pocketpair.app', 'lucky_dubois', 'Lucas', 'Dubois', 'player', 'fr', NULL, NOW() - interval '73 hours'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'antoine.lejeune@demo.pocketpair.app', 'antoine_lj', 'Antoine', 'Lejeune', 'player', 'fr', NULL, NOW() - interval '55 hours'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'hugo.claes@demo.pocketpair.app', 'hugoclaes', 'Hugo', 'Claes', 'player', 'nl', NULL, NOW() - interval '17 hours'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 'sofie.vermeulen@demo.pocketpair.app', 'sofie_v', 'Sofie', 'Vermeulen', 'player', 'nl', NULL, NOW() - interval '12 hours'),
-('d8daf41e-26b8-5488-ab26-aeb541e51a82', 'emma.vandamme@demo.pocketpair.app', 'emma_vd', 'Emma', 'Van Damme', 'player', 'nl', NULL, NOW() - interval '34 hours'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', 'lea.françois@demo.pocketpair.app', 'lea_frcs', 'Léa', 'François', 'player', 'fr', NULL, NOW() - interval '5 hours'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', 'camille.renard@demo.pocketpair.app', 'cam_renard', 'Camille', 'Renard', 'player', 'fr', NULL, NOW() - interval '49 hours'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'sarah.goossens@demo.pocketpair.app', 'sgoossens', 'Sarah', 'Goossens', 'player', 'nl', NULL, NOW() - interval '47 hours'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', 'manon.gérard@demo.pocketpair.app', 'manon_g', 'Manon', 'Gérard', 'player', 'fr', NULL, NOW() - interval '44 hours'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', 'julie.mertens@demo.pocketpair.app', 'julie_m', 'Julie', 'Mertens', 'player', 'nl', NULL, NOW() - interval '21 hours'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', 'kevin.desmet@demo.pocketpair.app', 'kdesmet', 'Kevin', 'De Smet', 'player', 'nl', NULL, NOW() - interval '96 hours'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'dries.wouters@demo.pocketpair.app', 'dries_w', 'Dries', 'Wouters', 'player', 'nl', NULL, NOW() - interval '59 hours'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'bart.willems@demo.pocketpair.app', 'bartwillems', 'Bart', 'Willems', 'player', 'nl', NULL, NOW() - interval '57 hours'),
-('72d99112-e37e-5845-a720-ba383679ac66', 'olivier.simon@demo.pocketpair.app', 'oli_simon', 'Olivier', 'Simon', 'player', 'fr', NULL, NOW() - interval '8 hours'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', 'mathieu.collard@demo.pocketpair.app', 'mcollard', 'Mathieu', 'Collard', 'player', 'fr', NULL, NOW() - interval '42 hours'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'pierre.bodart@demo.pocketpair.app', 'pbodart', 'Pierre', 'Bodart', 'player', 'fr', NULL, NOW() - interval '84 hours'),
-('97d54d39-c474-5af5-8e27-d728f570f15b', 'yannick.dumont@demo.pocketpair.app', 'yannick_d', 'Yannick', 'Dumont', 'player', 'fr', NULL, NOW() - interval '68 hours'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', 'florian.petit@demo.pocketpair.app', 'flo_petit', 'Florian', 'Petit', 'player', 'fr', NULL, NOW() - interval '21 hours');
+('1e34fb07-7add-500a-a388-c8494c20c8bb', 'maxime.dupont@demo.pocketpair.app', 'maxthegrinder', 'Maxime', 'Dupont', 'player', 'fr', NULL, NOW() - interval '44 hours'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'julien.lambert@demo.pocketpair.app', 'jlamb_poker', 'Julien', 'Lambert', 'player', 'fr', NULL, NOW() - interval '53 hours'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', 'nicolas.peeters@demo.pocketpair.app', 'nico_peeters', 'Nicolas', 'Peeters', 'player', 'nl', NULL, NOW() - interval '23 hours'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'thomas.janssens@demo.pocketpair.app', 'tommy_jans', 'Thomas', 'Janssens', 'player', 'nl', NULL, NOW() - interval '12 hours'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'lucas.dubois@demo.pocketpair.app', 'lucky_dubois', 'Lucas', 'Dubois', 'player', 'fr', NULL, NOW() - interval '23 hours'),
+('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'antoine.lejeune@demo.pocketpair.app', 'antoine_lj', 'Antoine', 'Lejeune', 'player', 'fr', NULL, NOW() - interval '61 hours'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'hugo.claes@demo.pocketpair.app', 'hugoclaes', 'Hugo', 'Claes', 'player', 'nl', NULL, NOW() - interval '56 hours'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 'sofie.vermeulen@demo.pocketpair.app', 'sofie_v', 'Sofie', 'Vermeulen', 'player', 'nl', NULL, NOW() - interval '39 hours'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', 'emma.vandamme@demo.pocketpair.app', 'emma_vd', 'Emma', 'Van Damme', 'player', 'nl', NULL, NOW() - interval '51 hours'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', 'lea.françois@demo.pocketpair.app', 'lea_frcs', 'Léa', 'François', 'player', 'fr', NULL, NOW() - interval '24 hours'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', 'camille.renard@demo.pocketpair.app', 'cam_renard', 'Camille', 'Renard', 'player', 'fr', NULL, NOW() - interval '33 hours'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'sarah.goossens@demo.pocketpair.app', 'sgoossens', 'Sarah', 'Goossens', 'player', 'nl', NULL, NOW() - interval '95 hours'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', 'manon.gérard@demo.pocketpair.app', 'manon_g', 'Manon', 'Gérard', 'player', 'fr', NULL, NOW() - interval '46 hours'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', 'julie.mertens@demo.pocketpair.app', 'julie_m', 'Julie', 'Mertens', 'player', 'nl', NULL, NOW() - interval '70 hours'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', 'kevin.desmet@demo.pocketpair.app', 'kdesmet', 'Kevin', 'De Smet', 'player', 'nl', NULL, NOW() - interval '43 hours'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'dries.wouters@demo.pocketpair.app', 'dries_w', 'Dries', 'Wouters', 'player', 'nl', NULL, NOW() - interval '87 hours'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'bart.willems@demo.pocketpair.app', 'bartwillems', 'Bart', 'Willems', 'player', 'nl', NULL, NOW() - interval '22 hours'),
+('72d99112-e37e-5845-a720-ba383679ac66', 'olivier.simon@demo.pocketpair.app', 'oli_simon', 'Olivier', 'Simon', 'player', 'fr', NULL, NOW() - interval '19 hours'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', 'mathieu.collard@demo.pocketpair.app', 'mcollard', 'Mathieu', 'Collard', 'player', 'fr', NULL, NOW() - interval '78 hours'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'pierre.bodart@demo.pocketpair.app', 'pbodart', 'Pierre', 'Bodart', 'player', 'fr', NULL, NOW() - interval '96 hours'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', 'yannick.dumont@demo.pocketpair.app', 'yannick_d', 'Yannick', 'Dumont', 'player', 'fr', NULL, NOW() - interval '66 hours'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', 'florian.petit@demo.pocketpair.app', 'flo_petit', 'Florian', 'Petit', 'player', 'fr', NULL, NOW() - interval '57 hours');
 
 -- === Clubs (insert re-fires the default template triggers) ===
 INSERT INTO clubs (id, name, city, country, address, postal_code, plan) VALUES
@@ -98,26 +98,26 @@ INSERT INTO club_player (id, club_id, display_name, app_user_id) VALUES
 
 -- === Tournaments ===
 INSERT INTO tournaments (id, club_id, name, description, start_time, end_time, buy_in_cents, rake_cents, seat_cap, starting_stack, live_status, bounty_type, bounty_amount_cents, late_registration_level) VALUES
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #14', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-04-17 18:00:00+00', '2026-04-17 23:00:00+00', 5000, 500, NULL, 25000, 'finished', 'none', 0, 6),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #15', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-04-24 18:00:00+00', '2026-04-24 23:00:00+00', 5000, 500, NULL, 25000, 'finished', 'none', 0, 6),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #16', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-05-01 18:00:00+00', '2026-05-01 23:00:00+00', 5000, 500, NULL, 25000, 'finished', 'none', 0, 6),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #17', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-05-08 18:00:00+00', '2026-05-08 23:00:00+00', 5000, 500, NULL, 25000, 'finished', 'none', 0, 6),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #18', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-05-15 18:00:00+00', '2026-05-15 23:00:00+00', 5000, 500, NULL, 25000, 'finished', 'none', 0, 6),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #19', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-05-22 18:00:00+00', '2026-05-22 23:00:00+00', 5000, 500, NULL, 25000, 'finished', 'none', 0, 6),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #20', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-05-29 18:00:00+00', '2026-05-29 23:00:00+00', 5000, 500, NULL, 25000, 'finished', 'none', 0, 6),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #21', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-06-05 18:00:00+00', '2026-06-05 23:00:00+00', 5000, 500, NULL, 25000, 'finished', 'none', 0, 6),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #22', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-06-12 18:00:00+00', '2026-06-12 23:00:00+00', 5000, 500, NULL, 25000, 'finished', 'none', 0, 6),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #23', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-06-19 18:00:00+00', '2026-06-19 23:00:00+00', 5000, 500, NULL, 25000, 'finished', 'none', 0, 6),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #24', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-06-26 18:00:00+00', '2026-06-26 23:00:00+00', 5000, 500, NULL, 25000, 'finished', 'none', 0, 6),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', 'Sunday Bounty #3', '€10 fixed bounty per knockout. Rebuys allowed for the first four levels.', '2026-04-19 13:00:00+00', '2026-04-19 18:00:00+00', 4000, 500, NULL, 20000, 'finished', 'fixed', 1000, 6),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', 'Sunday Bounty #4', '€10 fixed bounty per knockout. Rebuys allowed for the first four levels.', '2026-05-03 13:00:00+00', '2026-05-03 18:00:00+00', 4000, 500, NULL, 20000, 'finished', 'fixed', 1000, 6),
-('bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', 'Sunday Bounty #5', '€10 fixed bounty per knockout. Rebuys allowed for the first four levels.', '2026-05-17 13:00:00+00', '2026-05-17 18:00:00+00', 4000, 500, NULL, 20000, 'finished', 'fixed', 1000, 6),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', 'Sunday Bounty #6', '€10 fixed bounty per knockout. Rebuys allowed for the first four levels.', '2026-05-31 13:00:00+00', '2026-05-31 18:00:00+00', 4000, 500, NULL, 20000, 'finished', 'fixed', 1000, 6),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', 'Sunday Bounty #7', '€10 fixed bounty per knockout. Rebuys allowed for the first four levels.', '2026-06-14 13:00:00+00', '2026-06-14 18:00:00+00', 4000, 500, NULL, 20000, 'finished', 'fixed', 1000, 6),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', 'Sunday Bounty #8', '€10 fixed bounty per knockout. Rebuys allowed for the first four levels.', '2026-06-28 13:00:00+00', '2026-06-28 18:00:00+00', 4000, 500, NULL, 20000, 'finished', 'fixed', 1000, 6),
-('af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', 'Monthly Main Event #1', 'Our flagship monthly freezeout. 30k stack, 30-minute levels, top 5 paid.', '2026-05-30 17:00:00+00', '2026-05-30 22:00:00+00', 15000, 1000, NULL, 30000, 'finished', 'none', 0, 6),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', 'Monthly Main Event #2', 'Our flagship monthly freezeout. 30k stack, 30-minute levels, top 5 paid.', '2026-06-27 17:00:00+00', '2026-06-27 22:00:00+00', 15000, 1000, NULL, 30000, 'finished', 'none', 0, 6),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', 'Sunday Freezeout', 'Classic freezeout, one bullet, 20k stack.', '2026-06-21 13:00:00+00', '2026-06-21 18:00:00+00', 4000, 400, NULL, 20000, 'finished', 'none', 0, 6),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #14', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-04-17 18:00:00+00', '2026-04-17 23:00:00+00', 5000, 500, NULL, 25000, 'in_progress', 'none', 0, 6),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #15', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-04-24 18:00:00+00', '2026-04-24 23:00:00+00', 5000, 500, NULL, 25000, 'in_progress', 'none', 0, 6),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #16', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-05-01 18:00:00+00', '2026-05-01 23:00:00+00', 5000, 500, NULL, 25000, 'in_progress', 'none', 0, 6),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #17', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-05-08 18:00:00+00', '2026-05-08 23:00:00+00', 5000, 500, NULL, 25000, 'in_progress', 'none', 0, 6),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #18', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-05-15 18:00:00+00', '2026-05-15 23:00:00+00', 5000, 500, NULL, 25000, 'in_progress', 'none', 0, 6),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #19', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-05-22 18:00:00+00', '2026-05-22 23:00:00+00', 5000, 500, NULL, 25000, 'in_progress', 'none', 0, 6),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #20', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-05-29 18:00:00+00', '2026-05-29 23:00:00+00', 5000, 500, NULL, 25000, 'in_progress', 'none', 0, 6),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #21', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-06-05 18:00:00+00', '2026-06-05 23:00:00+00', 5000, 500, NULL, 25000, 'in_progress', 'none', 0, 6),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #22', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-06-12 18:00:00+00', '2026-06-12 23:00:00+00', 5000, 500, NULL, 25000, 'in_progress', 'none', 0, 6),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #23', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-06-19 18:00:00+00', '2026-06-19 23:00:00+00', 5000, 500, NULL, 25000, 'in_progress', 'none', 0, 6),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #24', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-06-26 18:00:00+00', '2026-06-26 23:00:00+00', 5000, 500, NULL, 25000, 'in_progress', 'none', 0, 6),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', 'Sunday Bounty #3', '€10 fixed bounty per knockout. Rebuys allowed for the first four levels.', '2026-04-19 13:00:00+00', '2026-04-19 18:00:00+00', 4000, 500, NULL, 20000, 'in_progress', 'fixed', 1000, 6),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', 'Sunday Bounty #4', '€10 fixed bounty per knockout. Rebuys allowed for the first four levels.', '2026-05-03 13:00:00+00', '2026-05-03 18:00:00+00', 4000, 500, NULL, 20000, 'in_progress', 'fixed', 1000, 6),
+('bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', 'Sunday Bounty #5', '€10 fixed bounty per knockout. Rebuys allowed for the first four levels.', '2026-05-17 13:00:00+00', '2026-05-17 18:00:00+00', 4000, 500, NULL, 20000, 'in_progress', 'fixed', 1000, 6),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', 'Sunday Bounty #6', '€10 fixed bounty per knockout. Rebuys allowed for the first four levels.', '2026-05-31 13:00:00+00', '2026-05-31 18:00:00+00', 4000, 500, NULL, 20000, 'in_progress', 'fixed', 1000, 6),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', 'Sunday Bounty #7', '€10 fixed bounty per knockout. Rebuys allowed for the first four levels.', '2026-06-14 13:00:00+00', '2026-06-14 18:00:00+00', 4000, 500, NULL, 20000, 'in_progress', 'fixed', 1000, 6),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', 'Sunday Bounty #8', '€10 fixed bounty per knockout. Rebuys allowed for the first four levels.', '2026-06-28 13:00:00+00', '2026-06-28 18:00:00+00', 4000, 500, NULL, 20000, 'in_progress', 'fixed', 1000, 6),
+('af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', 'Monthly Main Event #1', 'Our flagship monthly freezeout. 30k stack, 30-minute levels, top 5 paid.', '2026-05-30 17:00:00+00', '2026-05-30 22:00:00+00', 15000, 1000, NULL, 30000, 'in_progress', 'none', 0, 6),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', 'Monthly Main Event #2', 'Our flagship monthly freezeout. 30k stack, 30-minute levels, top 5 paid.', '2026-06-27 17:00:00+00', '2026-06-27 22:00:00+00', 15000, 1000, NULL, 30000, 'in_progress', 'none', 0, 6),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', 'Sunday Freezeout', 'Classic freezeout, one bullet, 20k stack.', '2026-06-21 13:00:00+00', '2026-06-21 18:00:00+00', 4000, 400, NULL, 20000, 'in_progress', 'none', 0, 6),
 ('929426f0-fb4e-5d04-8c57-d5136cd9caa0', '4114f430-9557-5c65-b29c-038a84013882', 'Friday Night Deepstack #25', 'Weekly deepstack — 25k stack, 20-minute levels, late reg until level 6.', '2026-07-10 18:00:00+00', NULL, 5000, 500, NULL, 25000, 'registration_open', 'none', 0, 6),
 ('e118e501-ca41-522a-a643-8e7eca002afd', '4114f430-9557-5c65-b29c-038a84013882', 'Sunday Bounty #9', '€10 fixed bounty per knockout. Rebuys allowed for the first four levels.', '2026-07-12 13:00:00+00', NULL, 4000, 500, NULL, 20000, 'registration_open', 'fixed', 1000, 6),
 ('b5d4973a-6aab-5176-b523-c56db01a4498', '4114f430-9557-5c65-b29c-038a84013882', 'Monthly Main Event #3', 'Our flagship monthly freezeout. 30k stack, 30-minute levels, top 5 paid.', '2026-07-18 17:00:00+00', NULL, 15000, 1000, 40, 30000, 'registration_open', 'none', 0, 6),
@@ -479,1232 +479,1221 @@ INSERT INTO tournament_structures (tournament_id, level_number, small_blind, big
 
 -- === Registrations, entries, check-ins ===
 INSERT INTO tournament_registrations (tournament_id, user_id, club_player_id, registration_time, status) VALUES
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-04-13 08:38:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-04-14 14:13:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-04-13 15:09:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-04-14 14:15:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-04-15 11:18:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-04-15 12:37:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-04-13 12:13:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-04-12 17:40:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-04-15 09:03:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-04-13 16:18:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-04-14 16:41:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-04-16 12:46:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-04-15 08:11:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-04-13 09:32:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-04-14 13:48:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-04-13 11:25:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-04-12 15:31:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-04-14 11:46:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-04-15 08:13:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-04-13 15:08:00+00', 'busted'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-04-15 14:40:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-04-22 17:37:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-04-22 12:39:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-04-20 11:08:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-04-23 09:59:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-04-23 14:19:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-04-23 13:19:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-04-22 11:21:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-04-21 11:20:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-04-23 09:39:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-04-19 08:41:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-04-19 17:40:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-04-23 08:10:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-04-23 15:39:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-04-22 11:41:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-04-22 09:38:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-04-19 13:26:00+00', 'busted'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-04-20 09:09:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-04-30 13:27:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-04-30 16:04:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-04-27 16:47:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-04-30 10:10:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-04-27 16:50:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-04-29 11:55:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-04-29 15:13:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-04-29 12:32:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-04-30 10:00:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-04-28 17:14:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-04-29 08:43:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', '2026-04-26 09:18:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-04-26 09:52:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-04-28 12:03:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-04-26 12:50:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-04-29 16:11:00+00', 'busted'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-04-26 09:22:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-05-07 13:40:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-05-05 16:14:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-05-04 08:06:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-05-03 10:27:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-05-03 08:50:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-05-03 16:26:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-05-06 16:41:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-05-03 12:16:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-05-07 11:56:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-05-04 13:01:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-05-03 14:48:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-05-05 11:21:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-05-03 14:22:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-05-05 09:57:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-05-06 15:36:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-05-03 15:09:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-05-04 14:43:00+00', 'busted'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-05-05 15:08:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-05-11 09:31:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-05-10 12:23:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-05-13 16:18:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-05-11 15:50:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-05-10 17:13:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-05-13 13:12:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-05-13 13:58:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-05-11 10:58:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', '2026-05-12 11:47:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-05-10 12:03:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-05-14 09:04:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-05-10 14:29:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-05-14 11:00:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-05-10 15:17:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-05-12 17:28:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-05-14 17:06:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-05-10 10:23:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-05-11 16:13:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-05-13 16:43:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-05-11 09:36:00+00', 'busted'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-05-13 16:32:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-05-21 13:05:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-05-20 13:41:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-05-21 13:32:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-05-20 17:33:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-05-17 15:07:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-05-18 10:42:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-05-20 15:42:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-05-20 13:00:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-05-17 12:43:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-05-20 11:11:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-05-20 15:28:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-05-17 12:34:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-05-20 15:56:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-05-17 14:42:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-05-20 16:25:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-05-19 16:02:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-05-18 17:37:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-05-21 15:12:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-05-17 17:41:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-05-20 10:26:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-05-21 08:42:00+00', 'busted'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-05-19 14:48:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', '2026-05-24 15:08:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-05-26 08:28:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-05-24 14:10:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-05-26 14:14:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-05-28 15:26:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-05-25 16:57:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-05-28 13:24:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-05-25 15:47:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-05-28 08:35:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-05-27 11:24:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-05-25 11:43:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-05-24 15:59:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-05-24 16:30:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-05-27 11:55:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-05-25 15:22:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-05-26 08:07:00+00', 'busted'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-05-24 17:21:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-06-03 17:03:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-06-02 11:56:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-06-03 12:28:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-05-31 15:56:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-05-31 16:47:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-06-01 16:19:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-06-03 14:36:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-06-01 16:13:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-06-04 13:07:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-06-01 12:40:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-06-02 12:17:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-06-04 17:16:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-05-31 12:34:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-06-01 13:58:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-06-02 12:25:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-05-31 12:20:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-06-03 15:33:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-05-31 16:19:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-06-02 17:56:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-06-02 10:39:00+00', 'busted'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-05-31 14:45:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-06-08 17:09:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-06-09 15:22:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-06-10 17:56:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-06-10 11:14:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-06-09 14:12:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-06-10 09:29:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-06-08 15:25:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-06-07 09:00:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-06-10 12:41:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-06-11 13:07:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-06-07 15:46:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-06-11 14:32:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-06-09 15:12:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-06-11 12:19:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-06-09 08:47:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-06-11 16:12:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-06-07 08:48:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-06-11 13:03:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-06-09 12:48:00+00', 'busted'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-06-07 10:38:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-06-14 09:34:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-06-16 17:24:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', '2026-06-14 12:17:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-06-15 08:14:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-06-14 17:35:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-06-14 17:17:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-06-15 08:47:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-06-17 10:08:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-06-17 10:33:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-06-15 14:32:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-06-17 08:19:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-06-15 10:11:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-06-14 09:27:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-06-18 13:31:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-06-17 08:47:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-06-16 15:32:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-06-18 16:55:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-06-15 10:17:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-06-15 08:19:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-06-14 15:54:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-06-16 11:42:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-06-18 12:42:00+00', 'busted'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-06-16 10:40:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-06-25 13:36:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', '2026-06-22 13:02:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-06-22 08:12:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-06-23 15:20:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-06-23 08:35:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-06-21 10:32:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-06-23 11:36:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-06-23 12:54:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-06-23 08:07:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-06-24 11:10:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-06-25 10:36:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-06-21 15:43:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-06-23 10:40:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-06-24 08:09:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-06-25 11:23:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-06-25 17:31:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-06-23 15:54:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-06-25 13:46:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-06-24 10:02:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-06-23 13:32:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-06-22 13:04:00+00', 'busted'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-06-24 11:33:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-04-15 11:56:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-04-16 09:18:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-04-14 11:39:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-04-18 04:13:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-04-18 08:52:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-04-18 08:29:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-04-16 11:38:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-04-14 06:22:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-04-14 12:03:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-04-15 09:12:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-04-18 09:10:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-04-16 03:59:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-04-14 09:08:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-04-18 08:47:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-04-15 09:43:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-04-15 10:06:00+00', 'busted'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-04-14 11:27:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-05-02 10:00:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-05-01 03:45:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-05-01 06:42:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-04-28 11:13:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-04-30 03:33:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-05-01 12:20:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-04-29 10:00:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-05-02 12:27:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-05-01 05:28:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-05-02 08:38:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-05-01 08:42:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-04-30 03:05:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-04-29 07:04:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-04-29 08:45:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-04-29 10:04:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-04-29 09:24:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-04-29 11:27:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-04-29 05:01:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-04-28 06:56:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-04-28 11:38:00+00', 'busted'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-04-29 05:28:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-05-15 12:40:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-05-16 07:20:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-05-15 05:06:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-05-12 09:55:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-05-13 04:16:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-05-15 08:49:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-05-16 08:06:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-05-13 12:10:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-05-13 09:20:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-05-13 06:37:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-05-13 04:10:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-05-12 08:50:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-05-15 08:07:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-05-14 06:41:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-05-15 10:03:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-05-16 05:38:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-05-13 11:59:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-05-13 07:49:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-05-16 11:54:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-05-13 07:15:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-05-15 05:34:00+00', 'busted'),
-('bb704a63-899c-5aab-99de-829047fbab66', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-05-13 11:06:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-05-26 11:20:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-05-29 08:26:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-05-26 07:25:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-05-28 05:06:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-05-28 11:40:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-05-30 12:44:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-05-27 11:08:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-05-30 05:28:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-05-27 05:14:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-05-30 12:49:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-05-28 10:29:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-05-28 11:46:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-05-30 11:57:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-05-28 07:45:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-05-30 11:29:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-05-26 05:13:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-05-27 09:54:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-05-29 03:53:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-05-27 08:17:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-05-27 12:28:00+00', 'busted'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-05-28 04:58:00+00', 'busted'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-06-13 08:08:00+00', 'busted'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-06-10 04:17:00+00', 'busted'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-06-12 09:33:00+00', 'busted'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-06-13 06:11:00+00', 'busted'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-06-12 04:24:00+00', 'busted'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-06-10 03:48:00+00', 'busted'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-06-10 09:53:00+00', 'busted'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-06-10 04:45:00+00', 'busted'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-06-10 09:38:00+00', 'busted'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-06-10 08:09:00+00', 'busted'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-06-11 03:35:00+00', 'busted'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-06-10 05:44:00+00', 'busted'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-06-13 06:29:00+00', 'busted'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-06-11 06:29:00+00', 'busted'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-06-11 04:28:00+00', 'busted'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-06-11 06:36:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-06-26 09:47:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-06-27 03:56:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-06-24 12:51:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-06-26 09:26:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-06-25 08:35:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-06-25 12:33:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-06-27 04:25:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-06-24 10:01:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-06-23 04:19:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-06-24 05:23:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-06-26 03:55:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-06-27 03:01:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-06-23 12:13:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-06-23 07:11:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-06-24 04:52:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-06-24 08:09:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-06-24 10:39:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-06-24 08:55:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-06-26 03:26:00+00', 'busted'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-06-25 09:17:00+00', 'busted'),
-('af567fae-395f-5feb-9f12-d849f322ec77', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-05-28 09:31:00+00', 'busted'),
-('af567fae-395f-5feb-9f12-d849f322ec77', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-05-25 08:07:00+00', 'busted'),
-('af567fae-395f-5feb-9f12-d849f322ec77', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-05-25 07:10:00+00', 'busted'),
-('af567fae-395f-5feb-9f12-d849f322ec77', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-05-28 11:07:00+00', 'busted'),
-('af567fae-395f-5feb-9f12-d849f322ec77', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-05-28 09:41:00+00', 'busted'),
-('af567fae-395f-5feb-9f12-d849f322ec77', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-05-28 09:56:00+00', 'busted'),
-('af567fae-395f-5feb-9f12-d849f322ec77', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-05-28 13:25:00+00', 'busted'),
-('af567fae-395f-5feb-9f12-d849f322ec77', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-05-25 09:48:00+00', 'busted'),
-('af567fae-395f-5feb-9f12-d849f322ec77', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-05-25 08:32:00+00', 'busted'),
-('af567fae-395f-5feb-9f12-d849f322ec77', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-05-26 07:32:00+00', 'busted'),
-('af567fae-395f-5feb-9f12-d849f322ec77', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-05-28 10:22:00+00', 'busted'),
-('af567fae-395f-5feb-9f12-d849f322ec77', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-05-25 07:59:00+00', 'busted'),
-('af567fae-395f-5feb-9f12-d849f322ec77', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-05-27 09:51:00+00', 'busted'),
-('af567fae-395f-5feb-9f12-d849f322ec77', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-05-26 13:04:00+00', 'busted'),
-('af567fae-395f-5feb-9f12-d849f322ec77', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-05-25 11:49:00+00', 'busted'),
-('af567fae-395f-5feb-9f12-d849f322ec77', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-05-27 15:09:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-06-23 15:54:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-06-26 08:08:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-06-25 07:47:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-06-22 10:02:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-06-25 09:38:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-06-22 14:14:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-06-22 10:19:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-06-24 10:42:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-06-22 13:41:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-06-25 15:24:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-06-23 09:23:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-06-24 13:11:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-06-23 11:52:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-06-26 15:24:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-06-23 15:49:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-06-22 08:07:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-06-23 15:56:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-06-23 10:22:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-06-24 09:26:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-06-25 09:34:00+00', 'busted'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-06-22 09:25:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '6b633796-0413-59c5-bbd4-b3b4057e46be', '5672800c-1e68-51d2-8662-87227df68078', '2026-06-16 05:15:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', '01c273c3-66de-5ec0-90e0-53372204d05a', '2026-06-20 10:00:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '1c7752a8-b80b-586a-a0ba-9395f6bf840d', '2026-06-16 11:10:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '91d7b783-7169-5ea6-81bb-36d68cd0edc5', '2026-06-16 09:24:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'c392ceff-2b0b-59e4-ae33-160a642c507b', '2026-06-16 09:01:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'd42f03b9-5d10-58d5-b400-02ba13586359', '143b4ae9-d688-5a9e-806f-e7bf9556d0df', '2026-06-18 10:42:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb296191-b1fb-5153-8b43-8d7733e3a6f5', '2026-06-19 09:31:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', 'e0da1350-ad67-59b8-90a0-96d04eaefdb2', '2026-06-20 12:02:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'b5670256-aa9c-5f10-aa21-bfc952c0bd9c', '2026-06-18 10:35:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', 'a911fe17-2c3a-5a87-a83f-e6e813595151', '2026-06-20 03:07:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '28424dec-2b76-51a8-ae22-3af68898413d', '2026-06-16 08:23:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '3961cba8-f14b-5a5a-8139-7986fece05be', '2026-06-18 03:43:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '489ce841-789b-55cc-8129-198d6719cf95', '2026-06-20 12:32:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '4d31d819-ebbb-566b-8c03-29c828ef220b', '01a3a9d7-d2dc-508f-93a0-905ad1cf71bf', '2026-06-20 06:17:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '2df47ade-ad54-5d58-8c88-a105e24f392d', '2026-06-17 05:52:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '548ef1c5-7f5e-53ea-92d0-fa1dc0341edc', '2026-06-19 06:20:00+00', 'busted'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '32403c7e-3427-537a-b4ab-77a7c40b1279', '11d1f6d3-9bae-5eb1-b786-dd5e2a7390b8', '2026-06-17 12:57:00+00', 'busted'),
-('929426f0-fb4e-5d04-8c57-d5136cd9caa0', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-07-03 21:00:00+00', 'registered'),
-('929426f0-fb4e-5d04-8c57-d5136cd9caa0', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-07-01 08:00:00+00', 'registered'),
-('929426f0-fb4e-5d04-8c57-d5136cd9caa0', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-07-03 04:00:00+00', 'registered'),
-('929426f0-fb4e-5d04-8c57-d5136cd9caa0', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-07-01 07:00:00+00', 'registered'),
-('929426f0-fb4e-5d04-8c57-d5136cd9caa0', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-07-02 00:00:00+00', 'registered'),
-('929426f0-fb4e-5d04-8c57-d5136cd9caa0', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-07-01 20:00:00+00', 'registered'),
-('929426f0-fb4e-5d04-8c57-d5136cd9caa0', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-07-03 04:00:00+00', 'registered'),
-('929426f0-fb4e-5d04-8c57-d5136cd9caa0', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-07-03 18:00:00+00', 'registered'),
-('929426f0-fb4e-5d04-8c57-d5136cd9caa0', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-07-02 06:00:00+00', 'registered'),
-('e118e501-ca41-522a-a643-8e7eca002afd', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', '2026-07-01 14:00:00+00', 'registered'),
-('e118e501-ca41-522a-a643-8e7eca002afd', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-07-01 10:00:00+00', 'registered'),
-('e118e501-ca41-522a-a643-8e7eca002afd', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-07-01 20:00:00+00', 'registered'),
-('e118e501-ca41-522a-a643-8e7eca002afd', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-07-02 06:00:00+00', 'registered'),
-('e118e501-ca41-522a-a643-8e7eca002afd', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-07-02 01:00:00+00', 'registered'),
-('e118e501-ca41-522a-a643-8e7eca002afd', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-07-02 12:00:00+00', 'registered'),
-('e118e501-ca41-522a-a643-8e7eca002afd', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-07-01 18:00:00+00', 'registered'),
-('e118e501-ca41-522a-a643-8e7eca002afd', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-07-02 04:00:00+00', 'registered'),
-('b5d4973a-6aab-5176-b523-c56db01a4498', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-07-01 21:00:00+00', 'registered'),
-('b5d4973a-6aab-5176-b523-c56db01a4498', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-07-01 07:00:00+00', 'registered'),
-('b5d4973a-6aab-5176-b523-c56db01a4498', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-07-03 00:00:00+00', 'registered'),
-('b5d4973a-6aab-5176-b523-c56db01a4498', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-07-03 01:00:00+00', 'registered'),
-('b5d4973a-6aab-5176-b523-c56db01a4498', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-07-03 21:00:00+00', 'registered'),
-('b5d4973a-6aab-5176-b523-c56db01a4498', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-07-03 03:00:00+00', 'registered'),
-('b5d4973a-6aab-5176-b523-c56db01a4498', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-07-02 09:00:00+00', 'registered'),
-('b5d4973a-6aab-5176-b523-c56db01a4498', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-07-02 18:00:00+00', 'registered'),
-('b5d4973a-6aab-5176-b523-c56db01a4498', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-07-01 22:00:00+00', 'registered'),
-('b5d4973a-6aab-5176-b523-c56db01a4498', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-07-03 08:00:00+00', 'registered'),
-('b5d4973a-6aab-5176-b523-c56db01a4498', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-07-01 13:00:00+00', 'registered'),
-('b5d4973a-6aab-5176-b523-c56db01a4498', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-07-01 03:00:00+00', 'registered'),
-('b5d4973a-6aab-5176-b523-c56db01a4498', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-07-02 18:00:00+00', 'registered'),
-('b5d4973a-6aab-5176-b523-c56db01a4498', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-07-02 10:00:00+00', 'registered'),
-('27367441-df3e-5883-a39d-d032cd46596a', 'd42f03b9-5d10-58d5-b400-02ba13586359', '143b4ae9-d688-5a9e-806f-e7bf9556d0df', '2026-07-03 18:00:00+00', 'registered'),
-('27367441-df3e-5883-a39d-d032cd46596a', '6b633796-0413-59c5-bbd4-b3b4057e46be', '5672800c-1e68-51d2-8662-87227df68078', '2026-07-01 21:00:00+00', 'registered'),
-('27367441-df3e-5883-a39d-d032cd46596a', '77597f34-b651-5d77-8e79-29f52d3ba161', 'e00d29bd-5569-5437-9c3b-092be24a4a3b', '2026-07-03 04:00:00+00', 'registered'),
-('27367441-df3e-5883-a39d-d032cd46596a', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '548ef1c5-7f5e-53ea-92d0-fa1dc0341edc', '2026-07-03 22:00:00+00', 'registered'),
-('27367441-df3e-5883-a39d-d032cd46596a', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'c392ceff-2b0b-59e4-ae33-160a642c507b', '2026-07-01 15:00:00+00', 'registered');
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-04-16 08:39:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-04-12 09:25:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-04-12 12:52:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-04-12 08:43:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-04-14 13:34:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-04-13 09:10:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-04-16 15:09:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-04-14 15:25:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-04-13 12:11:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-04-14 10:23:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-04-13 16:52:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-04-12 13:42:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-04-15 09:36:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-04-16 10:46:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-04-13 11:40:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-04-13 14:46:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-04-13 08:37:00+00', 'busted'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-04-13 15:27:00+00', 'busted'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-04-20 16:43:00+00', 'busted'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-04-19 12:08:00+00', 'busted'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-04-21 13:52:00+00', 'busted'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-04-21 13:46:00+00', 'busted'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-04-19 08:21:00+00', 'busted'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-04-20 08:47:00+00', 'busted'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-04-23 11:05:00+00', 'busted'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-04-20 10:51:00+00', 'busted'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-04-20 08:48:00+00', 'busted'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-04-19 11:06:00+00', 'busted'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-04-23 11:23:00+00', 'busted'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-04-22 09:06:00+00', 'busted'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-04-22 14:57:00+00', 'busted'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-04-22 11:23:00+00', 'busted'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-04-21 17:46:00+00', 'busted'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-04-20 13:47:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-04-29 15:17:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-04-30 11:23:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-04-28 15:35:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-04-30 09:36:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-04-29 15:27:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-04-28 14:17:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-04-27 17:41:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-04-26 14:08:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-04-26 16:28:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-04-30 16:09:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-04-27 16:08:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', '2026-04-29 08:18:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-04-26 15:24:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-04-28 17:26:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-04-29 13:36:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-04-26 17:55:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-04-27 17:39:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-04-27 16:35:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-04-27 10:58:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-04-29 08:51:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-04-29 09:32:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-04-28 17:51:00+00', 'busted'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-04-27 08:15:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-05-04 13:58:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-05-04 10:55:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-05-06 11:08:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-05-05 11:25:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-05-04 10:21:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-05-06 14:18:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-05-04 13:23:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-05-04 10:56:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-05-03 10:55:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-05-04 12:23:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-05-03 11:07:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-05-03 10:32:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-05-06 10:34:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-05-06 13:09:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-05-06 10:38:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-05-06 13:49:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-05-07 08:35:00+00', 'busted'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-05-04 15:14:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-05-13 17:14:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-05-11 13:45:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-05-10 15:19:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-05-13 10:16:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-05-12 13:17:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-05-11 14:37:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-05-11 09:38:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-05-14 17:46:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', '2026-05-13 16:08:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-05-10 09:05:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-05-14 09:18:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-05-13 12:37:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-05-11 13:01:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-05-13 08:37:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-05-14 15:41:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-05-13 14:47:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-05-14 08:04:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-05-11 10:40:00+00', 'busted'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-05-11 17:47:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-05-20 08:24:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-05-19 14:33:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-05-18 12:27:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-05-19 14:21:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-05-17 16:17:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-05-19 08:07:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-05-19 10:57:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-05-19 13:52:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-05-18 12:13:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-05-20 15:14:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-05-19 14:33:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-05-18 12:19:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-05-20 13:57:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-05-19 14:34:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-05-19 13:41:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-05-17 09:48:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-05-17 14:31:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-05-18 16:49:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-05-17 16:36:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-05-18 16:24:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-05-17 08:55:00+00', 'busted'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-05-19 14:03:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', '2026-05-28 09:12:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-05-25 12:01:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-05-24 09:20:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-05-25 14:00:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-05-25 16:56:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-05-25 14:34:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-05-27 15:13:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-05-24 15:30:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-05-24 12:21:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-05-25 09:58:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-05-27 10:05:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-05-24 11:30:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-05-27 10:23:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-05-25 15:54:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-05-24 08:37:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-05-26 15:28:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-05-28 16:11:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-05-24 16:17:00+00', 'busted'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-05-27 13:58:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-06-04 16:34:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-06-03 12:38:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-06-01 12:21:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-06-04 09:13:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-06-02 10:41:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-06-02 17:26:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-06-01 13:14:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-06-03 08:47:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-06-02 11:57:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-06-04 16:13:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-06-03 15:37:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-06-02 16:00:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-06-02 13:01:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-06-02 17:42:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-06-03 14:12:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-06-02 08:21:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-06-03 14:05:00+00', 'busted'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-06-01 17:13:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-06-07 14:46:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-06-07 17:14:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-06-10 12:12:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-06-11 17:50:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-06-08 08:18:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-06-09 16:48:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-06-07 15:45:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-06-09 17:47:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-06-08 15:58:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-06-08 14:47:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-06-09 16:41:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-06-10 17:13:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-06-09 08:31:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-06-07 15:08:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-06-09 10:34:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-06-08 10:46:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-06-10 09:56:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-06-07 08:16:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-06-07 11:46:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-06-08 08:29:00+00', 'busted'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-06-11 14:50:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-06-14 12:45:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-06-17 10:48:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', '2026-06-14 12:29:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-06-15 12:08:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-06-16 11:56:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-06-16 11:18:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-06-14 13:32:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-06-18 09:35:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-06-17 12:22:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-06-16 16:43:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-06-17 11:14:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-06-15 12:57:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-06-15 16:53:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-06-17 08:14:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-06-18 09:30:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-06-14 08:01:00+00', 'busted'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-06-14 12:02:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-06-24 10:29:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', '2026-06-22 10:35:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-06-22 13:50:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-06-23 08:55:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-06-21 10:56:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-06-24 08:17:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-06-24 08:12:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-06-23 10:16:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-06-21 15:20:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-06-21 09:23:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-06-23 09:07:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-06-22 11:28:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-06-24 14:11:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-06-21 17:19:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-06-21 15:07:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-06-25 09:06:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-06-23 13:39:00+00', 'busted'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-06-23 16:01:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-04-18 03:32:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-04-15 11:51:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-04-15 03:55:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-04-16 10:23:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-04-16 12:34:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-04-14 12:47:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-04-17 06:31:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-04-15 04:45:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-04-18 09:26:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-04-16 06:01:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-04-15 07:39:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-04-15 08:16:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-04-17 08:29:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-04-18 11:08:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-04-15 04:55:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-04-15 10:36:00+00', 'busted'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-04-15 09:10:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-05-01 03:22:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-04-29 10:23:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-05-02 05:21:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-04-30 03:46:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-04-29 06:56:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-05-01 08:52:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-04-28 09:49:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-04-29 05:26:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-04-30 07:25:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-04-30 08:01:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-04-29 04:42:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-05-01 10:23:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-05-02 04:44:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-04-28 07:18:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-04-28 09:31:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-04-29 07:14:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-04-29 09:08:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-05-01 05:34:00+00', 'busted'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-04-29 10:46:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-05-12 05:17:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-05-13 05:25:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-05-16 06:04:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-05-13 08:51:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-05-16 10:19:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-05-12 08:38:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-05-15 07:36:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-05-16 12:12:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-05-12 09:14:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-05-16 06:19:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-05-12 06:59:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-05-13 06:36:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-05-16 11:34:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-05-16 06:13:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-05-13 05:43:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-05-15 11:02:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-05-13 11:21:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-05-16 04:38:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-05-14 10:28:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-05-14 06:16:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-05-15 12:31:00+00', 'busted'),
+('bb704a63-899c-5aab-99de-829047fbab66', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-05-13 04:41:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-05-28 04:56:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-05-29 08:11:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-05-27 12:49:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-05-28 07:26:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-05-27 08:15:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-05-30 06:42:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-05-27 08:14:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-05-29 03:58:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-05-30 04:35:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-05-30 10:36:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-05-29 05:13:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-05-27 05:08:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-05-27 11:22:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-05-26 11:54:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-05-27 05:59:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-05-27 07:08:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-05-28 06:39:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-05-26 09:41:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-05-30 11:37:00+00', 'busted'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-05-27 04:36:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-06-09 09:34:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-06-09 12:15:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-06-12 04:15:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-06-10 04:23:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-06-09 09:22:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-06-13 07:26:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-06-13 03:26:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-06-10 12:27:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-06-12 07:34:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-06-09 07:58:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-06-12 10:05:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-06-12 08:05:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-06-09 10:30:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-06-09 10:24:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-06-09 10:00:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-06-10 04:04:00+00', 'busted'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-06-11 08:56:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-06-27 05:17:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-06-26 12:10:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-06-24 06:42:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-06-25 12:01:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-06-25 07:41:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-06-25 08:00:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-06-27 04:20:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-06-26 12:28:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-06-27 07:27:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-06-23 09:40:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-06-27 07:21:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-06-24 10:59:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-06-27 08:23:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-06-27 11:55:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-06-25 04:04:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-06-23 11:09:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-06-27 04:16:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-06-25 03:46:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-06-27 09:49:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-06-23 13:00:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-06-27 03:04:00+00', 'busted'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-06-24 09:26:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-05-28 13:36:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-05-27 13:58:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-05-28 07:08:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-05-26 11:55:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-05-25 11:58:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-05-26 11:15:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-05-28 15:02:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-05-27 09:31:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-05-28 15:09:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-05-29 08:19:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-05-25 13:22:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-05-25 11:07:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-05-25 14:29:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-05-25 08:52:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-05-25 08:56:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-05-27 09:38:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-05-25 12:32:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-05-26 13:42:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-05-26 07:45:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-05-27 16:55:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-05-26 12:45:00+00', 'busted'),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-05-29 08:20:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-06-25 10:44:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-06-24 11:28:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', '2026-06-26 15:14:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', '2026-06-24 14:26:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-06-22 13:16:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-06-22 16:15:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-06-25 15:45:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-06-25 08:07:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', '2026-06-26 08:17:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-06-22 07:14:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-06-25 16:05:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-06-25 16:42:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-06-25 10:33:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-06-22 10:02:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-06-25 12:21:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-06-26 16:30:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-06-25 07:56:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-06-25 15:17:00+00', 'busted'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-06-25 15:15:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '97d54d39-c474-5af5-8e27-d728f570f15b', '309d1ea4-43c3-5399-a002-98bf3d0e6122', '2026-06-16 04:16:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '28424dec-2b76-51a8-ae22-3af68898413d', '2026-06-18 09:03:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '9549d323-2ca7-5b7a-a23b-e9844c782d78', '26abb989-4577-5d5f-9e71-b34349fcb799', '2026-06-16 10:06:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '548ef1c5-7f5e-53ea-92d0-fa1dc0341edc', '2026-06-19 07:56:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', 'cfad0d73-69ec-574a-a65f-807dc0d5439f', '2026-06-17 06:45:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '1e34fb07-7add-500a-a388-c8494c20c8bb', 'b79193bb-a4b6-5c56-8136-509e5173209f', '2026-06-19 03:22:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '77597f34-b651-5d77-8e79-29f52d3ba161', 'e00d29bd-5569-5437-9c3b-092be24a4a3b', '2026-06-20 11:17:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', 'e0da1350-ad67-59b8-90a0-96d04eaefdb2', '2026-06-17 04:16:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '2df47ade-ad54-5d58-8c88-a105e24f392d', '2026-06-18 07:08:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'c392ceff-2b0b-59e4-ae33-160a642c507b', '2026-06-18 03:59:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '72d99112-e37e-5845-a720-ba383679ac66', '1073194c-1326-5ee9-a4e6-6ba06f7165fa', '2026-06-16 12:15:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '3961cba8-f14b-5a5a-8139-7986fece05be', '2026-06-18 12:07:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '32403c7e-3427-537a-b4ab-77a7c40b1279', '11d1f6d3-9bae-5eb1-b786-dd5e2a7390b8', '2026-06-20 08:15:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '91d7b783-7169-5ea6-81bb-36d68cd0edc5', '2026-06-16 06:56:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'b5670256-aa9c-5f10-aa21-bfc952c0bd9c', '2026-06-16 06:57:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'd42f03b9-5d10-58d5-b400-02ba13586359', '143b4ae9-d688-5a9e-806f-e7bf9556d0df', '2026-06-19 10:23:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', '01c273c3-66de-5ec0-90e0-53372204d05a', '2026-06-18 03:37:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', '4b929dc1-72df-5c8f-9832-46636464b3f3', '2026-06-17 08:36:00+00', 'busted'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '489ce841-789b-55cc-8129-198d6719cf95', '2026-06-20 11:39:00+00', 'busted'),
+('929426f0-fb4e-5d04-8c57-d5136cd9caa0', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-07-03 21:00:00+00', 'registered'),
+('929426f0-fb4e-5d04-8c57-d5136cd9caa0', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-07-02 04:00:00+00', 'registered'),
+('929426f0-fb4e-5d04-8c57-d5136cd9caa0', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-07-01 12:00:00+00', 'registered'),
+('929426f0-fb4e-5d04-8c57-d5136cd9caa0', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-07-01 13:00:00+00', 'registered'),
+('929426f0-fb4e-5d04-8c57-d5136cd9caa0', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-07-01 19:00:00+00', 'registered'),
+('929426f0-fb4e-5d04-8c57-d5136cd9caa0', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-07-03 09:00:00+00', 'registered'),
+('929426f0-fb4e-5d04-8c57-d5136cd9caa0', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-07-02 21:00:00+00', 'registered'),
+('929426f0-fb4e-5d04-8c57-d5136cd9caa0', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', '2026-07-01 08:00:00+00', 'registered'),
+('929426f0-fb4e-5d04-8c57-d5136cd9caa0', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', '2026-07-02 03:00:00+00', 'registered'),
+('e118e501-ca41-522a-a643-8e7eca002afd', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', '2026-07-02 09:00:00+00', 'registered'),
+('e118e501-ca41-522a-a643-8e7eca002afd', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', '2026-07-03 12:00:00+00', 'registered'),
+('e118e501-ca41-522a-a643-8e7eca002afd', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-07-03 01:00:00+00', 'registered'),
+('e118e501-ca41-522a-a643-8e7eca002afd', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', '2026-07-01 01:00:00+00', 'registered'),
+('e118e501-ca41-522a-a643-8e7eca002afd', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', '2026-07-02 21:00:00+00', 'registered'),
+('e118e501-ca41-522a-a643-8e7eca002afd', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-07-03 00:00:00+00', 'registered'),
+('e118e501-ca41-522a-a643-8e7eca002afd', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', '2026-07-02 08:00:00+00', 'registered'),
+('e118e501-ca41-522a-a643-8e7eca002afd', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-07-02 01:00:00+00', 'registered'),
+('b5d4973a-6aab-5176-b523-c56db01a4498', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', '2026-07-02 05:00:00+00', 'registered'),
+('b5d4973a-6aab-5176-b523-c56db01a4498', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', '2026-07-03 15:00:00+00', 'registered'),
+('b5d4973a-6aab-5176-b523-c56db01a4498', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', '2026-07-03 08:00:00+00', 'registered'),
+('b5d4973a-6aab-5176-b523-c56db01a4498', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', '2026-07-02 20:00:00+00', 'registered'),
+('b5d4973a-6aab-5176-b523-c56db01a4498', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', '2026-07-01 19:00:00+00', 'registered'),
+('b5d4973a-6aab-5176-b523-c56db01a4498', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', '2026-07-02 09:00:00+00', 'registered'),
+('b5d4973a-6aab-5176-b523-c56db01a4498', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', '2026-07-01 22:00:00+00', 'registered'),
+('b5d4973a-6aab-5176-b523-c56db01a4498', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', '2026-07-03 18:00:00+00', 'registered'),
+('b5d4973a-6aab-5176-b523-c56db01a4498', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', '2026-07-03 16:00:00+00', 'registered'),
+('b5d4973a-6aab-5176-b523-c56db01a4498', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', '2026-07-01 07:00:00+00', 'registered'),
+('b5d4973a-6aab-5176-b523-c56db01a4498', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', '2026-07-02 20:00:00+00', 'registered'),
+('b5d4973a-6aab-5176-b523-c56db01a4498', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', '2026-07-01 10:00:00+00', 'registered'),
+('b5d4973a-6aab-5176-b523-c56db01a4498', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', '2026-07-01 03:00:00+00', 'registered'),
+('b5d4973a-6aab-5176-b523-c56db01a4498', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', '2026-07-02 18:00:00+00', 'registered'),
+('27367441-df3e-5883-a39d-d032cd46596a', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '2df47ade-ad54-5d58-8c88-a105e24f392d', '2026-07-01 09:00:00+00', 'registered'),
+('27367441-df3e-5883-a39d-d032cd46596a', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', '01c273c3-66de-5ec0-90e0-53372204d05a', '2026-07-03 09:00:00+00', 'registered'),
+('27367441-df3e-5883-a39d-d032cd46596a', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', 'cfad0d73-69ec-574a-a65f-807dc0d5439f', '2026-07-01 19:00:00+00', 'registered'),
+('27367441-df3e-5883-a39d-d032cd46596a', '9549d323-2ca7-5b7a-a23b-e9844c782d78', '26abb989-4577-5d5f-9e71-b34349fcb799', '2026-07-01 12:00:00+00', 'registered'),
+('27367441-df3e-5883-a39d-d032cd46596a', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '3961cba8-f14b-5a5a-8139-7986fece05be', '2026-07-03 10:00:00+00', 'registered');
 INSERT INTO tournament_entries (tournament_id, user_id, club_player_id, entry_type, amount_cents, chips_received, payment_method, recorded_by, created_at) VALUES
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
 ('9e7288be-5f52-5baa-a621-84cec170f8b1', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
 ('9e7288be-5f52-5baa-a621-84cec170f8b1', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
 ('9e7288be-5f52-5baa-a621-84cec170f8b1', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
 ('9e7288be-5f52-5baa-a621-84cec170f8b1', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
 ('9e7288be-5f52-5baa-a621-84cec170f8b1', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-17 18:00:00+00'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
 ('60c66d4a-482e-516e-8975-bb9aab6eae52', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
 ('60c66d4a-482e-516e-8975-bb9aab6eae52', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
 ('60c66d4a-482e-516e-8975-bb9aab6eae52', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
 ('60c66d4a-482e-516e-8975-bb9aab6eae52', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
 ('60c66d4a-482e-516e-8975-bb9aab6eae52', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-24 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
 ('9341bdc4-08cc-5be5-a230-40ac443975f6', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
 ('9341bdc4-08cc-5be5-a230-40ac443975f6', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
 ('9341bdc4-08cc-5be5-a230-40ac443975f6', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
 ('9341bdc4-08cc-5be5-a230-40ac443975f6', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
 ('9341bdc4-08cc-5be5-a230-40ac443975f6', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
 ('9341bdc4-08cc-5be5-a230-40ac443975f6', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-01 18:00:00+00'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
 ('c8c424d5-78bf-535b-883d-fba67c75a804', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
 ('c8c424d5-78bf-535b-883d-fba67c75a804', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
 ('c8c424d5-78bf-535b-883d-fba67c75a804', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
 ('c8c424d5-78bf-535b-883d-fba67c75a804', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
 ('c8c424d5-78bf-535b-883d-fba67c75a804', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
 ('c8c424d5-78bf-535b-883d-fba67c75a804', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-08 18:00:00+00'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
 ('255f34b5-b0c5-5f4e-900b-857493631c40', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
 ('255f34b5-b0c5-5f4e-900b-857493631c40', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
 ('255f34b5-b0c5-5f4e-900b-857493631c40', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
 ('255f34b5-b0c5-5f4e-900b-857493631c40', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
 ('255f34b5-b0c5-5f4e-900b-857493631c40', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
 ('255f34b5-b0c5-5f4e-900b-857493631c40', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-15 18:00:00+00'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
 ('be185dca-6eef-5a2c-98af-08ebd521da8c', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
 ('be185dca-6eef-5a2c-98af-08ebd521da8c', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
 ('be185dca-6eef-5a2c-98af-08ebd521da8c', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
 ('be185dca-6eef-5a2c-98af-08ebd521da8c', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
 ('be185dca-6eef-5a2c-98af-08ebd521da8c', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
 ('be185dca-6eef-5a2c-98af-08ebd521da8c', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-22 18:00:00+00'),
 ('759d5259-5fb0-5fd9-8474-a37c34d00a85', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
 ('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
 ('759d5259-5fb0-5fd9-8474-a37c34d00a85', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
 ('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
 ('759d5259-5fb0-5fd9-8474-a37c34d00a85', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
 ('759d5259-5fb0-5fd9-8474-a37c34d00a85', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
 ('759d5259-5fb0-5fd9-8474-a37c34d00a85', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-29 18:00:00+00'),
 ('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
 ('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
 ('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
 ('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
 ('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
 ('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-05 18:00:00+00'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
 ('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
 ('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
 ('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
 ('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
 ('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
 ('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-12 18:00:00+00'),
 ('5f3dc88d-9581-58e0-934b-19e543e3701d', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
 ('5f3dc88d-9581-58e0-934b-19e543e3701d', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
 ('5f3dc88d-9581-58e0-934b-19e543e3701d', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
 ('5f3dc88d-9581-58e0-934b-19e543e3701d', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
 ('5f3dc88d-9581-58e0-934b-19e543e3701d', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
 ('5f3dc88d-9581-58e0-934b-19e543e3701d', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-19 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
 ('c7bd2cef-ec18-5b52-9862-93d276f78a52', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
 ('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
 ('c7bd2cef-ec18-5b52-9862-93d276f78a52', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
 ('c7bd2cef-ec18-5b52-9862-93d276f78a52', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
 ('c7bd2cef-ec18-5b52-9862-93d276f78a52', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 5000, 25000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-26 18:00:00+00'),
 ('578ef0af-c610-551c-8bde-fc52b91efbf3', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
 ('578ef0af-c610-551c-8bde-fc52b91efbf3', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
 ('578ef0af-c610-551c-8bde-fc52b91efbf3', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
 ('578ef0af-c610-551c-8bde-fc52b91efbf3', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:33:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-04-19 13:00:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
 ('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
 ('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
 ('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
 ('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
 ('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:58:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:00:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 14:17:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:51:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:37:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 13:46:00+00'),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-03 14:20:00+00'),
 ('bb704a63-899c-5aab-99de-829047fbab66', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
 ('bb704a63-899c-5aab-99de-829047fbab66', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
 ('bb704a63-899c-5aab-99de-829047fbab66', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
+('bb704a63-899c-5aab-99de-829047fbab66', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
+('bb704a63-899c-5aab-99de-829047fbab66', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
 ('bb704a63-899c-5aab-99de-829047fbab66', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
 ('bb704a63-899c-5aab-99de-829047fbab66', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
 ('bb704a63-899c-5aab-99de-829047fbab66', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
+('bb704a63-899c-5aab-99de-829047fbab66', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
+('bb704a63-899c-5aab-99de-829047fbab66', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
 ('bb704a63-899c-5aab-99de-829047fbab66', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
+('bb704a63-899c-5aab-99de-829047fbab66', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
+('bb704a63-899c-5aab-99de-829047fbab66', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
 ('bb704a63-899c-5aab-99de-829047fbab66', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 14:03:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 14:13:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:51:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:45:00+00'),
-('bb704a63-899c-5aab-99de-829047fbab66', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 14:11:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
+('bb704a63-899c-5aab-99de-829047fbab66', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:00:00+00'),
+('bb704a63-899c-5aab-99de-829047fbab66', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 14:17:00+00'),
+('bb704a63-899c-5aab-99de-829047fbab66', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-17 13:41:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
 ('a078bcb5-a64a-55e7-a744-b7397594a449', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
 ('a078bcb5-a64a-55e7-a744-b7397594a449', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
 ('a078bcb5-a64a-55e7-a744-b7397594a449', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
 ('a078bcb5-a64a-55e7-a744-b7397594a449', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
 ('a078bcb5-a64a-55e7-a744-b7397594a449', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
 ('a078bcb5-a64a-55e7-a744-b7397594a449', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:40:00+00'),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:57:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:00:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 14:06:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:35:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 13:56:00+00'),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-31 14:25:00+00'),
 ('6ff63587-c134-512f-b50d-f7b69c7c0660', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
 ('6ff63587-c134-512f-b50d-f7b69c7c0660', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
 ('6ff63587-c134-512f-b50d-f7b69c7c0660', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
 ('6ff63587-c134-512f-b50d-f7b69c7c0660', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
 ('6ff63587-c134-512f-b50d-f7b69c7c0660', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
 ('6ff63587-c134-512f-b50d-f7b69c7c0660', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:36:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:32:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:50:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:55:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 14:26:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:34:00+00'),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 14:30:00+00'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:00:00+00'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 14:25:00+00'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:30:00+00'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 14:10:00+00'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 13:55:00+00'),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-14 14:18:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
 ('14f05f89-b10c-59da-a417-8a610e0f6f18', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
 ('14f05f89-b10c-59da-a417-8a610e0f6f18', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
 ('14f05f89-b10c-59da-a417-8a610e0f6f18', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
 ('14f05f89-b10c-59da-a417-8a610e0f6f18', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
 ('14f05f89-b10c-59da-a417-8a610e0f6f18', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
 ('14f05f89-b10c-59da-a417-8a610e0f6f18', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 14:29:00+00'),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:33:00+00'),
-('af567fae-395f-5feb-9f12-d849f322ec77', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:00:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 14:13:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:32:00+00'),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'rebuy', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-28 13:35:00+00'),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
 ('af567fae-395f-5feb-9f12-d849f322ec77', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
-('af567fae-395f-5feb-9f12-d849f322ec77', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
-('af567fae-395f-5feb-9f12-d849f322ec77', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
-('af567fae-395f-5feb-9f12-d849f322ec77', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
 ('af567fae-395f-5feb-9f12-d849f322ec77', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
 ('af567fae-395f-5feb-9f12-d849f322ec77', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
-('af567fae-395f-5feb-9f12-d849f322ec77', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
 ('af567fae-395f-5feb-9f12-d849f322ec77', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
 ('af567fae-395f-5feb-9f12-d849f322ec77', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
 ('af567fae-395f-5feb-9f12-d849f322ec77', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
-('af567fae-395f-5feb-9f12-d849f322ec77', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
-('af567fae-395f-5feb-9f12-d849f322ec77', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
-('af567fae-395f-5feb-9f12-d849f322ec77', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
 ('af567fae-395f-5feb-9f12-d849f322ec77', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
 ('af567fae-395f-5feb-9f12-d849f322ec77', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
+('af567fae-395f-5feb-9f12-d849f322ec77', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-05-30 17:00:00+00'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
 ('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
 ('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
 ('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
 ('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
 ('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '6b633796-0413-59c5-bbd4-b3b4057e46be', '5672800c-1e68-51d2-8662-87227df68078', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', '01c273c3-66de-5ec0-90e0-53372204d05a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '1c7752a8-b80b-586a-a0ba-9395f6bf840d', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '91d7b783-7169-5ea6-81bb-36d68cd0edc5', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'c392ceff-2b0b-59e4-ae33-160a642c507b', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'd42f03b9-5d10-58d5-b400-02ba13586359', '143b4ae9-d688-5a9e-806f-e7bf9556d0df', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb296191-b1fb-5153-8b43-8d7733e3a6f5', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', 'e0da1350-ad67-59b8-90a0-96d04eaefdb2', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'b5670256-aa9c-5f10-aa21-bfc952c0bd9c', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', 'a911fe17-2c3a-5a87-a83f-e6e813595151', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 'initial', 15000, 30000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-27 17:00:00+00'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '97d54d39-c474-5af5-8e27-d728f570f15b', '309d1ea4-43c3-5399-a002-98bf3d0e6122', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
 ('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '28424dec-2b76-51a8-ae22-3af68898413d', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '3961cba8-f14b-5a5a-8139-7986fece05be', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '489ce841-789b-55cc-8129-198d6719cf95', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '4d31d819-ebbb-566b-8c03-29c828ef220b', '01a3a9d7-d2dc-508f-93a0-905ad1cf71bf', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '2df47ade-ad54-5d58-8c88-a105e24f392d', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '9549d323-2ca7-5b7a-a23b-e9844c782d78', '26abb989-4577-5d5f-9e71-b34349fcb799', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
 ('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '548ef1c5-7f5e-53ea-92d0-fa1dc0341edc', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '32403c7e-3427-537a-b4ab-77a7c40b1279', '11d1f6d3-9bae-5eb1-b786-dd5e2a7390b8', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00');
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', 'cfad0d73-69ec-574a-a65f-807dc0d5439f', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '1e34fb07-7add-500a-a388-c8494c20c8bb', 'b79193bb-a4b6-5c56-8136-509e5173209f', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '77597f34-b651-5d77-8e79-29f52d3ba161', 'e00d29bd-5569-5437-9c3b-092be24a4a3b', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', 'e0da1350-ad67-59b8-90a0-96d04eaefdb2', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '2df47ade-ad54-5d58-8c88-a105e24f392d', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'c392ceff-2b0b-59e4-ae33-160a642c507b', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '72d99112-e37e-5845-a720-ba383679ac66', '1073194c-1326-5ee9-a4e6-6ba06f7165fa', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '3961cba8-f14b-5a5a-8139-7986fece05be', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '32403c7e-3427-537a-b4ab-77a7c40b1279', '11d1f6d3-9bae-5eb1-b786-dd5e2a7390b8', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '91d7b783-7169-5ea6-81bb-36d68cd0edc5', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'b5670256-aa9c-5f10-aa21-bfc952c0bd9c', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'd42f03b9-5d10-58d5-b400-02ba13586359', '143b4ae9-d688-5a9e-806f-e7bf9556d0df', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', '01c273c3-66de-5ec0-90e0-53372204d05a', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', '4b929dc1-72df-5c8f-9832-46636464b3f3', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00'),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '489ce841-789b-55cc-8129-198d6719cf95', 'initial', 4000, 20000, 'cash', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '2026-06-21 13:00:00+00');
 INSERT INTO check_in (app_user_id, tournament_id, club_id, checked_in_at) VALUES
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:21:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:47:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:41:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:49:00+00'),
-('d8daf41e-26b8-5488-ab26-aeb541e51a82', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:23:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:25:00+00'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:30:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:32:00+00'),
-('97d54d39-c474-5af5-8e27-d728f570f15b', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:44:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:20:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:47:00+00'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:32:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:50:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:33:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:46:00+00'),
-('9549d323-2ca7-5b7a-a23b-e9844c782d78', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:53:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:24:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:38:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:39:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:25:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:33:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:35:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:52:00+00'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:21:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:30:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:23:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:42:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:20:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:22:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:20:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:35:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:32:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:37:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:35:00+00'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:31:00+00'),
-('9549d323-2ca7-5b7a-a23b-e9844c782d78', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:22:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:36:00+00'),
-('97d54d39-c474-5af5-8e27-d728f570f15b', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:34:00+00'),
-('d8daf41e-26b8-5488-ab26-aeb541e51a82', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:49:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:23:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:46:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:33:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:31:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:50:00+00'),
-('9549d323-2ca7-5b7a-a23b-e9844c782d78', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:55:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:41:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:38:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:29:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:49:00+00'),
-('8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:50:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:53:00+00'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:55:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:43:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:38:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:52:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:50:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:33:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:49:00+00'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:34:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:21:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:27:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:23:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:21:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:32:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:48:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:27:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:20:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:54:00+00'),
-('97d54d39-c474-5af5-8e27-d728f570f15b', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:34:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:34:00+00'),
-('f647df8a-5e87-5d63-af45-659fe8a0c16f', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:22:00+00'),
-('9549d323-2ca7-5b7a-a23b-e9844c782d78', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:27:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:50:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:52:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:24:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:39:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:50:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:39:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:51:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:50:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:48:00+00'),
-('8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:25:00+00'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:20:00+00'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:50:00+00'),
-('9549d323-2ca7-5b7a-a23b-e9844c782d78', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:49:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:35:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:28:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:20:00+00'),
-('d8daf41e-26b8-5488-ab26-aeb541e51a82', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:34:00+00'),
-('97d54d39-c474-5af5-8e27-d728f570f15b', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:41:00+00'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:49:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:31:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:41:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:27:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:55:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:27:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:53:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:22:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:45:00+00'),
+('1e34fb07-7add-500a-a388-c8494c20c8bb', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:20:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:27:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:46:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:53:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:38:00+00'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:43:00+00'),
+('72d99112-e37e-5845-a720-ba383679ac66', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:31:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:36:00+00'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:42:00+00'),
+('f647df8a-5e87-5d63-af45-659fe8a0c16f', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:20:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:41:00+00'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:36:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', '9e7288be-5f52-5baa-a621-84cec170f8b1', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-17 17:46:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:46:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:49:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:24:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:43:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:21:00+00'),
+('1e34fb07-7add-500a-a388-c8494c20c8bb', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:39:00+00'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:55:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:31:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:24:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:45:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:38:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:54:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:45:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:29:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:25:00+00'),
+('72d99112-e37e-5845-a720-ba383679ac66', '60c66d4a-482e-516e-8975-bb9aab6eae52', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-24 17:32:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:44:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:40:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:50:00+00'),
+('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:20:00+00'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:43:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:24:00+00'),
+('f647df8a-5e87-5d63-af45-659fe8a0c16f', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:39:00+00'),
+('1e34fb07-7add-500a-a388-c8494c20c8bb', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:30:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:35:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:40:00+00'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:38:00+00'),
+('8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:41:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:41:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:36:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:28:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:34:00+00'),
+('72d99112-e37e-5845-a720-ba383679ac66', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:45:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:37:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:21:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:42:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:37:00+00'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:53:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', '9341bdc4-08cc-5be5-a230-40ac443975f6', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-01 17:27:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:24:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:46:00+00'),
+('72d99112-e37e-5845-a720-ba383679ac66', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:46:00+00'),
+('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:49:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:39:00+00'),
+('f647df8a-5e87-5d63-af45-659fe8a0c16f', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:26:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:34:00+00'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:33:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:51:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:28:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:46:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:53:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:51:00+00'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:25:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:51:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:54:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:40:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', 'c8c424d5-78bf-535b-883d-fba67c75a804', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-08 17:34:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:36:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:39:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:53:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:55:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:52:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:25:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:52:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:26:00+00'),
+('8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:52:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:40:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:46:00+00'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:52:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:40:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:42:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:49:00+00'),
+('f647df8a-5e87-5d63-af45-659fe8a0c16f', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:35:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:35:00+00'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:31:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', '255f34b5-b0c5-5f4e-900b-857493631c40', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-15 17:49:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:54:00+00'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:46:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:29:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:25:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:49:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:54:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:40:00+00'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:45:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:41:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:54:00+00'),
+('f647df8a-5e87-5d63-af45-659fe8a0c16f', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:44:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:36:00+00'),
 ('1e34fb07-7add-500a-a388-c8494c20c8bb', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:55:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:24:00+00'),
-('97d54d39-c474-5af5-8e27-d728f570f15b', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:24:00+00'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:26:00+00'),
-('d8daf41e-26b8-5488-ab26-aeb541e51a82', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:24:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:41:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:52:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:30:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:39:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:52:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:43:00+00'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:43:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:29:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:33:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:20:00+00'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:36:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:26:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:43:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:27:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:23:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:39:00+00'),
-('9549d323-2ca7-5b7a-a23b-e9844c782d78', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:40:00+00'),
-('8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:54:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:39:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:29:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:44:00+00'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:37:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:38:00+00'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:28:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:51:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:39:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:29:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:30:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:51:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:40:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:54:00+00'),
-('d8daf41e-26b8-5488-ab26-aeb541e51a82', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:43:00+00'),
-('97d54d39-c474-5af5-8e27-d728f570f15b', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:30:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:54:00+00'),
-('9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:40:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:42:00+00'),
-('d8daf41e-26b8-5488-ab26-aeb541e51a82', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:25:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:20:00+00'),
-('97d54d39-c474-5af5-8e27-d728f570f15b', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:52:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:30:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:21:00+00'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:44:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:26:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:51:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:33:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:27:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:34:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:43:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:50:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:43:00+00'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:55:00+00'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:22:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:20:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:55:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:21:00+00'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:45:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:21:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:35:00+00'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:27:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:31:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:41:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:37:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:23:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:27:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:52:00+00'),
+('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:44:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'be185dca-6eef-5a2c-98af-08ebd521da8c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-22 17:27:00+00'),
+('8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:45:00+00'),
+('f647df8a-5e87-5d63-af45-659fe8a0c16f', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:44:00+00'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:21:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:42:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:44:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:47:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:31:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:30:00+00'),
+('72d99112-e37e-5845-a720-ba383679ac66', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:43:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:21:00+00'),
+('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:50:00+00'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:46:00+00'),
+('1e34fb07-7add-500a-a388-c8494c20c8bb', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:52:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:47:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:22:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:45:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:45:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:25:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', '759d5259-5fb0-5fd9-8474-a37c34d00a85', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-29 17:33:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:27:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:35:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:22:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:34:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:27:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:24:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:38:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:47:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:30:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:33:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:41:00+00'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:48:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:46:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:54:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:37:00+00'),
+('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:29:00+00'),
+('1e34fb07-7add-500a-a388-c8494c20c8bb', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:24:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-05 17:34:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:42:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:22:00+00'),
+('72d99112-e37e-5845-a720-ba383679ac66', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:50:00+00'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:26:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:45:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:26:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:29:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:30:00+00'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:32:00+00'),
 ('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:31:00+00'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:25:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:22:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:30:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:38:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:41:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:37:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:49:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:40:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:54:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:20:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:42:00+00'),
-('f647df8a-5e87-5d63-af45-659fe8a0c16f', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:22:00+00'),
-('9549d323-2ca7-5b7a-a23b-e9844c782d78', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:31:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:52:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:31:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:42:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:37:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:28:00+00'),
-('8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:55:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:33:00+00'),
-('d8daf41e-26b8-5488-ab26-aeb541e51a82', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:47:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:26:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:42:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:37:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:36:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:45:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:44:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:21:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:49:00+00'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:47:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:46:00+00'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:31:00+00'),
-('9549d323-2ca7-5b7a-a23b-e9844c782d78', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:49:00+00'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:38:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:20:00+00'),
-('97d54d39-c474-5af5-8e27-d728f570f15b', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:37:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:21:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:44:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:42:00+00'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:30:00+00'),
-('8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:48:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:51:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:25:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:36:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:20:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:50:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:54:00+00'),
-('9549d323-2ca7-5b7a-a23b-e9844c782d78', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:22:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:22:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:40:00+00'),
-('d8daf41e-26b8-5488-ab26-aeb541e51a82', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:36:00+00'),
-('f647df8a-5e87-5d63-af45-659fe8a0c16f', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:46:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:28:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:41:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:35:00+00'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:32:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:34:00+00'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:53:00+00'),
-('97d54d39-c474-5af5-8e27-d728f570f15b', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:41:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:21:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:39:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:45:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:20:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:50:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:20:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:36:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:40:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:54:00+00'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:33:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:24:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:40:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:29:00+00'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:24:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:50:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:53:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:37:00+00'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:53:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:31:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:37:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:43:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:24:00+00'),
+('1e34fb07-7add-500a-a388-c8494c20c8bb', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:45:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:24:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:54:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-12 17:54:00+00'),
+('72d99112-e37e-5845-a720-ba383679ac66', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:37:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:52:00+00'),
+('8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:23:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:24:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:33:00+00'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:44:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:20:00+00'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:51:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:44:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:23:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:54:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:25:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:39:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:37:00+00'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:39:00+00'),
+('1e34fb07-7add-500a-a388-c8494c20c8bb', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:48:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', '5f3dc88d-9581-58e0-934b-19e543e3701d', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-19 17:26:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:37:00+00'),
+('8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:36:00+00'),
+('72d99112-e37e-5845-a720-ba383679ac66', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:25:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:44:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:21:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:53:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:25:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:38:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:30:00+00'),
+('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:38:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:36:00+00'),
+('1e34fb07-7add-500a-a388-c8494c20c8bb', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:25:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:26:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:24:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:37:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:40:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:54:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', 'c7bd2cef-ec18-5b52-9862-93d276f78a52', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-26 17:43:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:33:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:43:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:34:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:41:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:23:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:28:00+00'),
+('72d99112-e37e-5845-a720-ba383679ac66', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:42:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:53:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:25:00+00'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:51:00+00'),
 ('f647df8a-5e87-5d63-af45-659fe8a0c16f', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:20:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:39:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:40:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:22:00+00'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:45:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:20:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:27:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:40:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:49:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', '578ef0af-c610-551c-8bde-fc52b91efbf3', '4114f430-9557-5c65-b29c-038a84013882', '2026-04-19 12:51:00+00'),
+('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:47:00+00'),
+('72d99112-e37e-5845-a720-ba383679ac66', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:25:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:31:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:53:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:24:00+00'),
+('1e34fb07-7add-500a-a388-c8494c20c8bb', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:27:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:32:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:31:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:20:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:54:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:21:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:20:00+00'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:46:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:24:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:49:00+00'),
 ('77597f34-b651-5d77-8e79-29f52d3ba161', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:28:00+00'),
-('f647df8a-5e87-5d63-af45-659fe8a0c16f', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:45:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:48:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:48:00+00'),
-('d8daf41e-26b8-5488-ab26-aeb541e51a82', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:48:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:22:00+00'),
-('97d54d39-c474-5af5-8e27-d728f570f15b', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:49:00+00'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:46:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:40:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:24:00+00'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:25:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:36:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:45:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:50:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:54:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:32:00+00'),
-('9549d323-2ca7-5b7a-a23b-e9844c782d78', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:48:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:26:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:54:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:30:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:39:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:40:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:36:00+00'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:29:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:37:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:38:00+00'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:51:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:45:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:47:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:24:00+00'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:44:00+00'),
-('d8daf41e-26b8-5488-ab26-aeb541e51a82', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:32:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:53:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:24:00+00'),
-('97d54d39-c474-5af5-8e27-d728f570f15b', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:46:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:45:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:43:00+00'),
-('9549d323-2ca7-5b7a-a23b-e9844c782d78', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:40:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:37:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:30:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:34:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:55:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:39:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:38:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:51:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:33:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:44:00+00'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:53:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:40:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:20:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:30:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:21:00+00'),
-('97d54d39-c474-5af5-8e27-d728f570f15b', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:44:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:32:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:29:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:44:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:51:00+00'),
-('9549d323-2ca7-5b7a-a23b-e9844c782d78', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:25:00+00'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:27:00+00'),
-('f647df8a-5e87-5d63-af45-659fe8a0c16f', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:27:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:48:00+00'),
-('d8daf41e-26b8-5488-ab26-aeb541e51a82', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:44:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:34:00+00'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:23:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:24:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:21:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:30:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:28:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:34:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:28:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:36:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:22:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:26:00+00'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:34:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:30:00+00'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:32:00+00'),
-('d8daf41e-26b8-5488-ab26-aeb541e51a82', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:30:00+00'),
-('f647df8a-5e87-5d63-af45-659fe8a0c16f', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:47:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:41:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:31:00+00'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:55:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:22:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:44:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:26:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:53:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:30:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:20:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:26:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:36:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:48:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:37:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:48:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:42:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:50:00+00'),
-('97d54d39-c474-5af5-8e27-d728f570f15b', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:49:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:46:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:50:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', '1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-03 12:30:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:46:00+00'),
+('1e34fb07-7add-500a-a388-c8494c20c8bb', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:51:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:43:00+00'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:39:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:45:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:26:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:34:00+00'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:47:00+00'),
+('f647df8a-5e87-5d63-af45-659fe8a0c16f', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:55:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:47:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:32:00+00'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:55:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:24:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:33:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:25:00+00'),
+('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:55:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:22:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:51:00+00'),
+('72d99112-e37e-5845-a720-ba383679ac66', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:48:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:49:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:30:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', 'bb704a63-899c-5aab-99de-829047fbab66', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-17 12:25:00+00'),
+('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:29:00+00'),
+('f647df8a-5e87-5d63-af45-659fe8a0c16f', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:32:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:30:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:39:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:32:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:41:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:37:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:55:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:22:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:52:00+00'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:40:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:52:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:36:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:47:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:37:00+00'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:30:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:35:00+00'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:52:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:23:00+00'),
+('72d99112-e37e-5845-a720-ba383679ac66', 'a078bcb5-a64a-55e7-a744-b7397594a449', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-31 12:54:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:55:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:22:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:24:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:54:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:53:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:40:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:38:00+00'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:30:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:20:00+00'),
+('1e34fb07-7add-500a-a388-c8494c20c8bb', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:34:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:21:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:45:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:31:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:37:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:29:00+00'),
+('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:31:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', '6ff63587-c134-512f-b50d-f7b69c7c0660', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-14 12:34:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:45:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:24:00+00'),
+('1e34fb07-7add-500a-a388-c8494c20c8bb', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:26:00+00'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:52:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:31:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:37:00+00'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:26:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:25:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:53:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:38:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:54:00+00'),
+('72d99112-e37e-5845-a720-ba383679ac66', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:46:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:27:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:44:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:55:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:54:00+00'),
+('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:51:00+00'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:46:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:55:00+00'),
 ('32403c7e-3427-537a-b4ab-77a7c40b1279', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:36:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:25:00+00'),
-('d8daf41e-26b8-5488-ab26-aeb541e51a82', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:48:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:26:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:20:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:24:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:35:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:20:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:35:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:35:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:51:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:47:00+00'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:54:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:47:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:55:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:44:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:46:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:26:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:34:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:26:00+00'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:40:00+00'),
-('d8daf41e-26b8-5488-ab26-aeb541e51a82', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:54:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:39:00+00'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:40:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:32:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:41:00+00'),
-('97d54d39-c474-5af5-8e27-d728f570f15b', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:54:00+00'),
-('9549d323-2ca7-5b7a-a23b-e9844c782d78', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:23:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:38:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:30:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:52:00+00'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:46:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:42:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:25:00+00'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:43:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:36:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:48:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:36:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:44:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:30:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', '14f05f89-b10c-59da-a417-8a610e0f6f18', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-28 12:48:00+00'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:44:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:25:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:41:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:22:00+00'),
+('72d99112-e37e-5845-a720-ba383679ac66', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:41:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:20:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:44:00+00'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:49:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:43:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:41:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:49:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:30:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:25:00+00'),
+('1e34fb07-7add-500a-a388-c8494c20c8bb', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:49:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:35:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:34:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:37:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:48:00+00'),
+('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:53:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:47:00+00'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:30:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'af567fae-395f-5feb-9f12-d849f322ec77', '4114f430-9557-5c65-b29c-038a84013882', '2026-05-30 16:40:00+00'),
+('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:37:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:28:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:27:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:52:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:27:00+00'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:42:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:28:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:44:00+00'),
+('f647df8a-5e87-5d63-af45-659fe8a0c16f', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:33:00+00'),
+('1e34fb07-7add-500a-a388-c8494c20c8bb', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:50:00+00'),
 ('d42f03b9-5d10-58d5-b400-02ba13586359', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:50:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:51:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:52:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:27:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:23:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:34:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:39:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:23:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:22:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:28:00+00'),
-('8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:25:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:53:00+00'),
-('f647df8a-5e87-5d63-af45-659fe8a0c16f', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:31:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:48:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:49:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:26:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:55:00+00'),
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:32:00+00'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:23:00+00'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:47:00+00');
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:40:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:35:00+00'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:45:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:21:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:42:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:24:00+00'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:41:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4114f430-9557-5c65-b29c-038a84013882', '2026-06-27 16:51:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:28:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:22:00+00'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:36:00+00'),
+('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:20:00+00'),
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:44:00+00'),
+('1e34fb07-7add-500a-a388-c8494c20c8bb', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:34:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:20:00+00'),
+('8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:42:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:20:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:27:00+00'),
+('72d99112-e37e-5845-a720-ba383679ac66', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:33:00+00'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:22:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:21:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:33:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:33:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:50:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:27:00+00'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:46:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '02ebfceb-7a5f-5cf6-af9e-849213e20efd', '5ec5689d-a433-5706-a9ae-31c85c144b23', '2026-06-21 12:31:00+00');
 
 -- === Live in-progress tournament: seated field + running clock ===
 INSERT INTO tournament_registrations (tournament_id, user_id, club_player_id, registration_time, status) VALUES
@@ -1751,608 +1740,630 @@ UPDATE tournament_clocks SET clock_status = 'paused', auto_advance = false, curr
 
 -- === Results & payouts ===
 INSERT INTO tournament_results (tournament_id, user_id, club_player_id, final_position, prize_cents, points) VALUES
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 1, 35000, 21),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 2, 23500, 20),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 3, 14000, 19),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 4, 11500, 18),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 5, 10500, 17),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 6, 0, 16),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 7, 0, 15),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 8, 0, 14),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 9, 0, 13),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 10, 0, 12),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 11, 0, 11),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 12, 0, 10),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 13, 0, 9),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 14, 0, 8),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 15, 0, 7),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 16, 0, 6),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 17, 0, 5),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 18, 0, 4),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 19, 0, 3),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 20, 0, 2),
-('9e7288be-5f52-5baa-a621-84cec170f8b1', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 21, 0, 1),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 1, 38000, 17),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 2, 23000, 16),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 3, 15500, 15),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 4, 0, 14),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 5, 0, 13),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 6, 0, 12),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 7, 0, 11),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 8, 0, 10),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 9, 0, 9),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 10, 0, 8),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 11, 0, 7),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 12, 0, 6),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 13, 0, 5),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 14, 0, 4),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 15, 0, 3),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 16, 0, 2),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 17, 0, 1),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 1, 38000, 17),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 2, 23000, 16),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 3, 15500, 15),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 4, 0, 14),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 5, 0, 13),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 6, 0, 12),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 7, 0, 11),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 8, 0, 10),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 9, 0, 9),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 10, 0, 8),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 11, 0, 7),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 12, 0, 6),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 13, 0, 5),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 14, 0, 4),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 15, 0, 3),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 16, 0, 2),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 17, 0, 1),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 1, 40500, 18),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 2, 24500, 17),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 3, 16000, 16),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 1, 40500, 18),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 2, 24500, 17),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 3, 16000, 16),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 4, 0, 15),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 5, 0, 14),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 6, 0, 13),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 7, 0, 12),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 8, 0, 11),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 9, 0, 10),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 10, 0, 9),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 11, 0, 8),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 12, 0, 7),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 13, 0, 6),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 14, 0, 5),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 15, 0, 4),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 16, 0, 3),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 17, 0, 2),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 18, 0, 1),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 1, 36000, 16),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 2, 21500, 15),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 3, 14500, 14),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 4, 0, 13),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 5, 0, 12),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 6, 0, 11),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 7, 0, 10),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 8, 0, 9),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 9, 0, 8),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 10, 0, 7),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 11, 0, 6),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 12, 0, 5),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 13, 0, 4),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 14, 0, 3),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 15, 0, 2),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 16, 0, 1),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 1, 38000, 23),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 2, 26000, 22),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 3, 15500, 21),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 4, 12500, 20),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 5, 11500, 19),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 6, 0, 18),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 7, 0, 17),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 8, 0, 16),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 9, 0, 15),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 10, 0, 14),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 11, 0, 13),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 12, 0, 12),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 13, 0, 11),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 14, 0, 10),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 15, 0, 9),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 16, 0, 8),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 17, 0, 7),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 18, 0, 6),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 19, 0, 5),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 20, 0, 4),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 21, 0, 3),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 22, 0, 2),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 23, 0, 1),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 1, 40500, 18),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 2, 24500, 17),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 3, 16000, 16),
 ('c8c424d5-78bf-535b-883d-fba67c75a804', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 4, 0, 15),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 5, 0, 14),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 6, 0, 13),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 7, 0, 12),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 8, 0, 11),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 9, 0, 10),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 10, 0, 9),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 11, 0, 8),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 12, 0, 7),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 13, 0, 6),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 14, 0, 5),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 15, 0, 4),
-('c8c424d5-78bf-535b-883d-fba67c75a804', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 16, 0, 3),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 17, 0, 2),
-('c8c424d5-78bf-535b-883d-fba67c75a804', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 18, 0, 1),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 1, 35000, 21),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 2, 23500, 20),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 3, 14000, 19),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 4, 11500, 18),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 5, 10500, 17),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 6, 0, 16),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 7, 0, 15),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 8, 0, 14),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 9, 0, 13),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 10, 0, 12),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 11, 0, 11),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 12, 0, 10),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 13, 0, 9),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 14, 0, 8),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 15, 0, 7),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 16, 0, 6),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 17, 0, 5),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 18, 0, 4),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 19, 0, 3),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 20, 0, 2),
-('255f34b5-b0c5-5f4e-900b-857493631c40', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 21, 0, 1),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 1, 36000, 22),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 2, 25000, 21),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 3, 15000, 20),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 4, 12000, 19),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 5, 11000, 18),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 6, 0, 17),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 7, 0, 16),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 8, 0, 15),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 9, 0, 14),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 10, 0, 13),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 11, 0, 12),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 12, 0, 11),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 13, 0, 10),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 14, 0, 9),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 15, 0, 8),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 16, 0, 7),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 17, 0, 6),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 18, 0, 5),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 19, 0, 4),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 20, 0, 3),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 21, 0, 2),
-('be185dca-6eef-5a2c-98af-08ebd521da8c', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 22, 0, 1),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 1, 38000, 17),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 2, 23000, 16),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 3, 15500, 15),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 4, 0, 14),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 5, 0, 13),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 6, 0, 12),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 7, 0, 11),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 8, 0, 10),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 9, 0, 9),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 10, 0, 8),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 11, 0, 7),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 12, 0, 6),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 13, 0, 5),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 14, 0, 4),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 15, 0, 3),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 16, 0, 2),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 17, 0, 1),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 1, 35000, 21),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 2, 23500, 20),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 3, 14000, 19),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 4, 11500, 18),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 5, 10500, 17),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 6, 0, 16),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 7, 0, 15),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 8, 0, 14),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 9, 0, 13),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 10, 0, 12),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 11, 0, 11),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 12, 0, 10),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 13, 0, 9),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 14, 0, 8),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 15, 0, 7),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 16, 0, 6),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 17, 0, 5),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 18, 0, 4),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 19, 0, 3),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 20, 0, 2),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 21, 0, 1),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 1, 45000, 20),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 2, 27000, 19),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 3, 18000, 18),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 4, 0, 17),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 5, 0, 16),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 6, 0, 15),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 7, 0, 14),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 8, 0, 13),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 9, 0, 12),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 10, 0, 11),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 11, 0, 10),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 12, 0, 9),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 13, 0, 8),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 14, 0, 7),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 15, 0, 6),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 16, 0, 5),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 17, 0, 4),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 18, 0, 3),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 19, 0, 2),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 20, 0, 1),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 1, 38000, 23),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 2, 26000, 22),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 3, 15500, 21),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 4, 12500, 20),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 5, 11500, 19),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 6, 0, 18),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 7, 0, 17),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 8, 0, 16),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 9, 0, 15),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 10, 0, 14),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 11, 0, 13),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 12, 0, 12),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 13, 0, 11),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 14, 0, 10),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 15, 0, 9),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 16, 0, 8),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 17, 0, 7),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 18, 0, 6),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 19, 0, 5),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 20, 0, 4),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 21, 0, 3),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 22, 0, 2),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 23, 0, 1),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 1, 36000, 22),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 2, 25000, 21),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 3, 15000, 20),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 4, 12000, 19),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 5, 11000, 18),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 6, 0, 17),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 7, 0, 16),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 8, 0, 15),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 9, 0, 14),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 10, 0, 13),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 11, 0, 12),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 12, 0, 11),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 13, 0, 10),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 14, 0, 9),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 15, 0, 8),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 16, 0, 7),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 17, 0, 6),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 18, 0, 5),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 19, 0, 4),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 20, 0, 3),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 21, 0, 2),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 22, 0, 1),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 1, 23000, 17),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 2, 13500, 16),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 3, 9000, 15),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 4, 0, 14),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 5, 0, 13),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 6, 0, 12),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 7, 0, 11),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 8, 0, 10),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 5, 0, 14),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 6, 0, 13),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 7, 0, 12),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 8, 0, 11),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 9, 0, 10),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 10, 0, 9),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 11, 0, 8),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 12, 0, 7),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 13, 0, 6),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 14, 0, 5),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 15, 0, 4),
+('c8c424d5-78bf-535b-883d-fba67c75a804', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 16, 0, 3),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 17, 0, 2),
+('c8c424d5-78bf-535b-883d-fba67c75a804', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 18, 0, 1),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 1, 43000, 19),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 2, 25500, 18),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 3, 17000, 17),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 4, 0, 16),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 5, 0, 15),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 6, 0, 14),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 7, 0, 13),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 8, 0, 12),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 9, 0, 11),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 10, 0, 10),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 11, 0, 9),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 12, 0, 8),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 13, 0, 7),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 14, 0, 6),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 15, 0, 5),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 16, 0, 4),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 17, 0, 3),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 18, 0, 2),
+('255f34b5-b0c5-5f4e-900b-857493631c40', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 19, 0, 1),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 1, 36000, 22),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 2, 25000, 21),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 3, 15000, 20),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 4, 12000, 19),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 5, 11000, 18),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 6, 0, 17),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 7, 0, 16),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 8, 0, 15),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 9, 0, 14),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 10, 0, 13),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 11, 0, 12),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 12, 0, 11),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 13, 0, 10),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 14, 0, 9),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 15, 0, 8),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 16, 0, 7),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 17, 0, 6),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 18, 0, 5),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 19, 0, 4),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 20, 0, 3),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 21, 0, 2),
+('be185dca-6eef-5a2c-98af-08ebd521da8c', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 22, 0, 1),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 1, 43000, 19),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 2, 25500, 18),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 3, 17000, 17),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 4, 0, 16),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 5, 0, 15),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 6, 0, 14),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 7, 0, 13),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 8, 0, 12),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 9, 0, 11),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 10, 0, 10),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 11, 0, 9),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 12, 0, 8),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 13, 0, 7),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 14, 0, 6),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 15, 0, 5),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 16, 0, 4),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 17, 0, 3),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 18, 0, 2),
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 19, 0, 1),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 1, 40500, 18),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 2, 24500, 17),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 3, 16000, 16),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 4, 0, 15),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 5, 0, 14),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 6, 0, 13),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 7, 0, 12),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 8, 0, 11),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 9, 0, 10),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 10, 0, 9),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 11, 0, 8),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 12, 0, 7),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 13, 0, 6),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 14, 0, 5),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 15, 0, 4),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 16, 0, 3),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 17, 0, 2),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 18, 0, 1),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 1, 35000, 21),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 2, 23500, 20),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 3, 14000, 19),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 4, 11500, 18),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 5, 10500, 17),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 6, 0, 16),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 7, 0, 15),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 8, 0, 14),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 9, 0, 13),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 10, 0, 12),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 11, 0, 11),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 12, 0, 10),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 13, 0, 9),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 14, 0, 8),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 15, 0, 7),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 16, 0, 6),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 17, 0, 5),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 18, 0, 4),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 19, 0, 3),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 20, 0, 2),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 21, 0, 1),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 1, 38000, 17),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 2, 23000, 16),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 3, 15500, 15),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 4, 0, 14),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 5, 0, 13),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 6, 0, 12),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 7, 0, 11),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 8, 0, 10),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 9, 0, 9),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 10, 0, 8),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 11, 0, 7),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 12, 0, 6),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 13, 0, 5),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 14, 0, 4),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 15, 0, 3),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 16, 0, 2),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 17, 0, 1),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 1, 40500, 18),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', '66de17a9-2aba-5fc2-9138-0846dc98196e', 2, 24500, 17),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 3, 16000, 16),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 4, 0, 15),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 5, 0, 14),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 6, 0, 13),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 7, 0, 12),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 8, 0, 11),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 9, 0, 10),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 10, 0, 9),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 11, 0, 8),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 12, 0, 7),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 13, 0, 6),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 14, 0, 5),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 15, 0, 4),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 16, 0, 3),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 17, 0, 2),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 18, 0, 1),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 1, 21000, 17),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 2, 13000, 16),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 3, 8500, 15),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 4, 0, 14),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 5, 0, 13),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 6, 0, 12),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 7, 0, 11),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 8, 0, 10),
 ('578ef0af-c610-551c-8bde-fc52b91efbf3', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 9, 0, 9),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 10, 0, 8),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 11, 0, 7),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 12, 0, 6),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 13, 0, 5),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 14, 0, 4),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 15, 0, 3),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 16, 0, 2),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 17, 0, 1),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 1, 20500, 21),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 2, 14000, 20),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 3, 8500, 19),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 4, 6500, 18),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 5, 6000, 17),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 6, 0, 16),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 7, 0, 15),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 8, 0, 14),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 9, 0, 13),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 10, 0, 12),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 11, 0, 11),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 12, 0, 10),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 13, 0, 9),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 14, 0, 8),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 15, 0, 7),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 16, 0, 6),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 17, 0, 5),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 18, 0, 4),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 19, 0, 3),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 20, 0, 2),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 21, 0, 1),
-('bb704a63-899c-5aab-99de-829047fbab66', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 1, 26000, 22),
-('bb704a63-899c-5aab-99de-829047fbab66', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 2, 17500, 21),
-('bb704a63-899c-5aab-99de-829047fbab66', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 3, 10500, 20),
-('bb704a63-899c-5aab-99de-829047fbab66', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 4, 8500, 19),
-('bb704a63-899c-5aab-99de-829047fbab66', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 5, 7500, 18),
-('bb704a63-899c-5aab-99de-829047fbab66', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 6, 0, 17),
-('bb704a63-899c-5aab-99de-829047fbab66', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 7, 0, 16),
-('bb704a63-899c-5aab-99de-829047fbab66', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 8, 0, 15),
-('bb704a63-899c-5aab-99de-829047fbab66', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 9, 0, 14),
-('bb704a63-899c-5aab-99de-829047fbab66', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 10, 0, 13),
-('bb704a63-899c-5aab-99de-829047fbab66', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 11, 0, 12),
-('bb704a63-899c-5aab-99de-829047fbab66', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 12, 0, 11),
-('bb704a63-899c-5aab-99de-829047fbab66', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 13, 0, 10),
-('bb704a63-899c-5aab-99de-829047fbab66', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 14, 0, 9),
-('bb704a63-899c-5aab-99de-829047fbab66', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 15, 0, 8),
-('bb704a63-899c-5aab-99de-829047fbab66', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 16, 0, 7),
-('bb704a63-899c-5aab-99de-829047fbab66', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 17, 0, 6),
-('bb704a63-899c-5aab-99de-829047fbab66', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 18, 0, 5),
-('bb704a63-899c-5aab-99de-829047fbab66', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 19, 0, 4),
-('bb704a63-899c-5aab-99de-829047fbab66', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 20, 0, 3),
-('bb704a63-899c-5aab-99de-829047fbab66', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 21, 0, 2),
-('bb704a63-899c-5aab-99de-829047fbab66', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 22, 0, 1),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 1, 21500, 21),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 2, 14500, 20),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 3, 9000, 19),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 4, 7000, 18),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 5, 6500, 17),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 6, 0, 16),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 7, 0, 15),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 8, 0, 14),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 9, 0, 13),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 10, 0, 12),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 11, 0, 11),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 12, 0, 10),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 13, 0, 9),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 14, 0, 8),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 15, 0, 7),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 16, 0, 6),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 17, 0, 5),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 18, 0, 4),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 19, 0, 3),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 20, 0, 2),
-('a078bcb5-a64a-55e7-a744-b7397594a449', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 21, 0, 1),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 1, 30500, 16),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 2, 18500, 15),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 3, 12000, 14),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 4, 0, 13),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 5, 0, 12),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 6, 0, 11),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 7, 0, 10),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 8, 0, 9),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 9, 0, 8),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 10, 0, 7),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 11, 0, 6),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 12, 0, 5),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 13, 0, 4),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 14, 0, 3),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 15, 0, 2),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 16, 0, 1),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 1, 28000, 20),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 2, 17000, 19),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 3, 11000, 18),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 4, 0, 17),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 5, 0, 16),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 6, 0, 15),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 7, 0, 14),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 8, 0, 13),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 9, 0, 12),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 10, 0, 11),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 11, 0, 10),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 12, 0, 9),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 13, 0, 8),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 14, 0, 7),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 15, 0, 6),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 16, 0, 5),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 17, 0, 4),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 18, 0, 3),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 19, 0, 2),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 20, 0, 1),
-('af567fae-395f-5feb-9f12-d849f322ec77', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 1, 112000, 16),
-('af567fae-395f-5feb-9f12-d849f322ec77', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 2, 67000, 15),
-('af567fae-395f-5feb-9f12-d849f322ec77', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 3, 45000, 14),
-('af567fae-395f-5feb-9f12-d849f322ec77', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 4, 0, 13),
-('af567fae-395f-5feb-9f12-d849f322ec77', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 5, 0, 12),
-('af567fae-395f-5feb-9f12-d849f322ec77', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 6, 0, 11),
-('af567fae-395f-5feb-9f12-d849f322ec77', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 7, 0, 10),
-('af567fae-395f-5feb-9f12-d849f322ec77', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 8, 0, 9),
-('af567fae-395f-5feb-9f12-d849f322ec77', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 9, 0, 8),
-('af567fae-395f-5feb-9f12-d849f322ec77', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 10, 0, 7),
-('af567fae-395f-5feb-9f12-d849f322ec77', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 11, 0, 6),
-('af567fae-395f-5feb-9f12-d849f322ec77', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 12, 0, 5),
-('af567fae-395f-5feb-9f12-d849f322ec77', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 13, 0, 4),
-('af567fae-395f-5feb-9f12-d849f322ec77', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 14, 0, 3),
-('af567fae-395f-5feb-9f12-d849f322ec77', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 15, 0, 2),
-('af567fae-395f-5feb-9f12-d849f322ec77', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 16, 0, 1),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 1, 108500, 21),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 2, 73500, 20),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 3, 44000, 19),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 4, 35500, 18),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 5, 32500, 17),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 6, 0, 16),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 7, 0, 15),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 8, 0, 14),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 9, 0, 13),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 10, 0, 12),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 11, 0, 11),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 12, 0, 10),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 13, 0, 9),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 14, 0, 8),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 15, 0, 7),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 16, 0, 6),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 17, 0, 5),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 18, 0, 4),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 19, 0, 3),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 20, 0, 2),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 21, 0, 1),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '6b633796-0413-59c5-bbd4-b3b4057e46be', '5672800c-1e68-51d2-8662-87227df68078', 1, 30700, 17),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', '01c273c3-66de-5ec0-90e0-53372204d05a', 2, 18500, 16),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '1c7752a8-b80b-586a-a0ba-9395f6bf840d', 3, 12000, 15),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '91d7b783-7169-5ea6-81bb-36d68cd0edc5', 4, 0, 14),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'c392ceff-2b0b-59e4-ae33-160a642c507b', 5, 0, 13),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'd42f03b9-5d10-58d5-b400-02ba13586359', '143b4ae9-d688-5a9e-806f-e7bf9556d0df', 6, 0, 12),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb296191-b1fb-5153-8b43-8d7733e3a6f5', 7, 0, 11),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', 'e0da1350-ad67-59b8-90a0-96d04eaefdb2', 8, 0, 10),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'b5670256-aa9c-5f10-aa21-bfc952c0bd9c', 9, 0, 9),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', 'a911fe17-2c3a-5a87-a83f-e6e813595151', 10, 0, 8),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '28424dec-2b76-51a8-ae22-3af68898413d', 11, 0, 7),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '3961cba8-f14b-5a5a-8139-7986fece05be', 12, 0, 6),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '489ce841-789b-55cc-8129-198d6719cf95', 13, 0, 5),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '4d31d819-ebbb-566b-8c03-29c828ef220b', '01a3a9d7-d2dc-508f-93a0-905ad1cf71bf', 14, 0, 4),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '2df47ade-ad54-5d58-8c88-a105e24f392d', 15, 0, 3),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '548ef1c5-7f5e-53ea-92d0-fa1dc0341edc', 16, 0, 2),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '32403c7e-3427-537a-b4ab-77a7c40b1279', '11d1f6d3-9bae-5eb1-b786-dd5e2a7390b8', 17, 0, 1);
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 10, 0, 8),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 11, 0, 7),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 12, 0, 6),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 13, 0, 5),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 14, 0, 4),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 15, 0, 3),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 16, 0, 2),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 17, 0, 1),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 1, 31000, 19),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 2, 19000, 18),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 3, 12500, 17),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 4, 0, 16),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 5, 0, 15),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 6, 0, 14),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 7, 0, 13),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 8, 0, 12),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 9, 0, 11),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 10, 0, 10),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 11, 0, 9),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 12, 0, 8),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 13, 0, 7),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 14, 0, 6),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 15, 0, 5),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 16, 0, 4),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 17, 0, 3),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 18, 0, 2),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 19, 0, 1),
+('bb704a63-899c-5aab-99de-829047fbab66', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 1, 23000, 22),
+('bb704a63-899c-5aab-99de-829047fbab66', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 2, 15000, 21),
+('bb704a63-899c-5aab-99de-829047fbab66', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 3, 9000, 20),
+('bb704a63-899c-5aab-99de-829047fbab66', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 4, 7500, 19),
+('bb704a63-899c-5aab-99de-829047fbab66', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 5, 6500, 18),
+('bb704a63-899c-5aab-99de-829047fbab66', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 6, 0, 17),
+('bb704a63-899c-5aab-99de-829047fbab66', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 7, 0, 16),
+('bb704a63-899c-5aab-99de-829047fbab66', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 8, 0, 15),
+('bb704a63-899c-5aab-99de-829047fbab66', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 9, 0, 14),
+('bb704a63-899c-5aab-99de-829047fbab66', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 10, 0, 13),
+('bb704a63-899c-5aab-99de-829047fbab66', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 11, 0, 12),
+('bb704a63-899c-5aab-99de-829047fbab66', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 12, 0, 11),
+('bb704a63-899c-5aab-99de-829047fbab66', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 13, 0, 10),
+('bb704a63-899c-5aab-99de-829047fbab66', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 14, 0, 9),
+('bb704a63-899c-5aab-99de-829047fbab66', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 15, 0, 8),
+('bb704a63-899c-5aab-99de-829047fbab66', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 16, 0, 7),
+('bb704a63-899c-5aab-99de-829047fbab66', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 17, 0, 6),
+('bb704a63-899c-5aab-99de-829047fbab66', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 18, 0, 5),
+('bb704a63-899c-5aab-99de-829047fbab66', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 19, 0, 4),
+('bb704a63-899c-5aab-99de-829047fbab66', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 20, 0, 3),
+('bb704a63-899c-5aab-99de-829047fbab66', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 21, 0, 2),
+('bb704a63-899c-5aab-99de-829047fbab66', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 22, 0, 1),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 1, 31000, 20),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 2, 18500, 19),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 3, 12500, 18),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 4, 0, 17),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 5, 0, 16),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 6, 0, 15),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 7, 0, 14),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 8, 0, 13),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 9, 0, 12),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 10, 0, 11),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 11, 0, 10),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 12, 0, 9),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 13, 0, 8),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 14, 0, 7),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 15, 0, 6),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 16, 0, 5),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 17, 0, 4),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 18, 0, 3),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 19, 0, 2),
+('a078bcb5-a64a-55e7-a744-b7397594a449', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 20, 0, 1),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 1, 29000, 17),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 2, 17000, 16),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 3, 11500, 15),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 4, 0, 14),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 5, 0, 13),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 6, 0, 12),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 7, 0, 11),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 8, 0, 10),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 9, 0, 9),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 10, 0, 8),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 11, 0, 7),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 12, 0, 6),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 13, 0, 5),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 14, 0, 4),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 15, 0, 3),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 16, 0, 2),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 17, 0, 1),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 1, 24000, 22),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 2, 16000, 21),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 3, 9500, 20),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 4, 7500, 19),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 5, 7000, 18),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 6, 0, 17),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 7, 0, 16),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 8, 0, 15),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 9, 0, 14),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 10, 0, 13),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 11, 0, 12),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 12, 0, 11),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 13, 0, 10),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 14, 0, 9),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 15, 0, 8),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 16, 0, 7),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 17, 0, 6),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 18, 0, 5),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 19, 0, 4),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 20, 0, 3),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 21, 0, 2),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 22, 0, 1),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 1, 114000, 22),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 2, 77000, 21),
+('af567fae-395f-5feb-9f12-d849f322ec77', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 3, 46000, 20),
+('af567fae-395f-5feb-9f12-d849f322ec77', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 4, 37000, 19),
+('af567fae-395f-5feb-9f12-d849f322ec77', '72d99112-e37e-5845-a720-ba383679ac66', 'e495ac48-82e2-5e10-a30b-1c12cb73f7d8', 5, 34000, 18),
+('af567fae-395f-5feb-9f12-d849f322ec77', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 6, 0, 17),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 7, 0, 16),
+('af567fae-395f-5feb-9f12-d849f322ec77', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 8, 0, 15),
+('af567fae-395f-5feb-9f12-d849f322ec77', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 9, 0, 14),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', '57fd4814-25ce-571b-9640-db67f8faad50', 10, 0, 13),
+('af567fae-395f-5feb-9f12-d849f322ec77', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 11, 0, 12),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '7c67e572-a089-5a76-b026-de1f9add6647', 12, 0, 11),
+('af567fae-395f-5feb-9f12-d849f322ec77', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 13, 0, 10),
+('af567fae-395f-5feb-9f12-d849f322ec77', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 14, 0, 9),
+('af567fae-395f-5feb-9f12-d849f322ec77', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 15, 0, 8),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', 'd687bcb0-6571-582d-9740-b70fc839f9f1', 16, 0, 7),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 17, 0, 6),
+('af567fae-395f-5feb-9f12-d849f322ec77', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 18, 0, 5),
+('af567fae-395f-5feb-9f12-d849f322ec77', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 19, 0, 4),
+('af567fae-395f-5feb-9f12-d849f322ec77', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 20, 0, 3),
+('af567fae-395f-5feb-9f12-d849f322ec77', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 21, 0, 2),
+('af567fae-395f-5feb-9f12-d849f322ec77', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 22, 0, 1),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 'f55da36c-7312-53ec-8f37-e2b589c83f23', 1, 133000, 19),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '97d54d39-c474-5af5-8e27-d728f570f15b', '361c38a5-6443-5648-b67f-a18e1f8d8385', 2, 80000, 18),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '711cceba-3e34-5266-ba5e-4ba6adb1e544', '494c6c3f-8ada-5dd4-8ab3-65a0ed2f48ef', 3, 53000, 17),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', '3d0115b8-3643-5a21-93ca-6e331f23e69a', 4, 0, 16),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 'ab5ae0ac-773c-5289-9b17-99528d084f0c', 5, 0, 15),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '0bd96bec-2eda-582e-8221-c93f4ffa7602', 6, 0, 14),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '056eea7d-6958-5494-abb4-207de44015aa', 7, 0, 13),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '6b633796-0413-59c5-bbd4-b3b4057e46be', '3f756db4-a4de-5852-99bb-aca5f73dde3a', 8, 0, 12),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'f647df8a-5e87-5d63-af45-659fe8a0c16f', '9537d048-d4ed-51b7-994c-ff6392d231ed', 9, 0, 11),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '1e34fb07-7add-500a-a388-c8494c20c8bb', '1b425dfe-afef-5083-9a10-c116d29e5e8e', 10, 0, 10),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'd42f03b9-5d10-58d5-b400-02ba13586359', '8d23478b-15b9-5126-b9b5-c2d7f11af72a', 11, 0, 9),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '3ff69306-a2f9-53dc-8148-e3617e682c49', 12, 0, 8),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', '3081bb85-29b9-579d-a485-9c11a7e00422', 13, 0, 7),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', 'bd8707d5-93ba-5e4f-8960-15f051829612', 14, 0, 6),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '77597f34-b651-5d77-8e79-29f52d3ba161', 'b52076a6-b084-50b4-86c3-163081338033', 15, 0, 5),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '4d31d819-ebbb-566b-8c03-29c828ef220b', 'c5b2ecbc-5d74-5bd7-afa8-a206b8321a63', 16, 0, 4),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '12a026ca-4f7e-5462-bb1d-6283478a60ea', 'fb0b8a41-7c9f-5d62-8e83-a8dfdaf79d09', 17, 0, 3),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '9549d323-2ca7-5b7a-a23b-e9844c782d78', 'ff3522bf-e4d5-5633-91b7-ad8bc24a880a', 18, 0, 2),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', '32403c7e-3427-537a-b4ab-77a7c40b1279', '732d7de4-f866-5acb-bd15-71bcf3c70e68', 19, 0, 1),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '97d54d39-c474-5af5-8e27-d728f570f15b', '309d1ea4-43c3-5399-a002-98bf3d0e6122', 1, 34400, 19),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '17b0800f-f72e-5209-85e0-b90be77eb8e8', '28424dec-2b76-51a8-ae22-3af68898413d', 2, 20500, 18),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '9549d323-2ca7-5b7a-a23b-e9844c782d78', '26abb989-4577-5d5f-9e71-b34349fcb799', 3, 13500, 17),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', '548ef1c5-7f5e-53ea-92d0-fa1dc0341edc', 4, 0, 16),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'd8daf41e-26b8-5488-ab26-aeb541e51a82', 'cfad0d73-69ec-574a-a65f-807dc0d5439f', 5, 0, 15),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '1e34fb07-7add-500a-a388-c8494c20c8bb', 'b79193bb-a4b6-5c56-8136-509e5173209f', 6, 0, 14),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '77597f34-b651-5d77-8e79-29f52d3ba161', 'e00d29bd-5569-5437-9c3b-092be24a4a3b', 7, 0, 13),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', 'e0da1350-ad67-59b8-90a0-96d04eaefdb2', 8, 0, 12),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'fbab6b35-2de3-5a9f-a856-bdb1426b1047', '2df47ade-ad54-5d58-8c88-a105e24f392d', 9, 0, 11),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 'c392ceff-2b0b-59e4-ae33-160a642c507b', 10, 0, 10),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '72d99112-e37e-5845-a720-ba383679ac66', '1073194c-1326-5ee9-a4e6-6ba06f7165fa', 11, 0, 9),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'b23e9386-5048-5d4a-b4d2-3e77eebffa13', '3961cba8-f14b-5a5a-8139-7986fece05be', 12, 0, 8),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '32403c7e-3427-537a-b4ab-77a7c40b1279', '11d1f6d3-9bae-5eb1-b786-dd5e2a7390b8', 13, 0, 7),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '725606ae-89a6-5953-b10f-5d6c37ac1dfd', '91d7b783-7169-5ea6-81bb-36d68cd0edc5', 14, 0, 6),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 'b5670256-aa9c-5f10-aa21-bfc952c0bd9c', 15, 0, 5),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'd42f03b9-5d10-58d5-b400-02ba13586359', '143b4ae9-d688-5a9e-806f-e7bf9556d0df', 16, 0, 4),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'eaac0ce1-d164-5538-aac8-f9e19dd79a02', '01c273c3-66de-5ec0-90e0-53372204d05a', 17, 0, 3),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', '8bec458b-ce53-501a-a54c-fbbd7838f6c0', '4b929dc1-72df-5c8f-9832-46636464b3f3', 18, 0, 2),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', '489ce841-789b-55cc-8129-198d6719cf95', 19, 0, 1);
 INSERT INTO tournament_payouts (tournament_id, player_count, total_prize_pool, payout_positions) VALUES
-('9e7288be-5f52-5baa-a621-84cec170f8b1', 21, 94500, '[{"position": 1, "amount_cents": 35000}, {"position": 2, "amount_cents": 23500}, {"position": 3, "amount_cents": 14000}, {"position": 4, "amount_cents": 11500}, {"position": 5, "amount_cents": 10500}]'::jsonb),
-('60c66d4a-482e-516e-8975-bb9aab6eae52', 17, 76500, '[{"position": 1, "amount_cents": 38000}, {"position": 2, "amount_cents": 23000}, {"position": 3, "amount_cents": 15500}]'::jsonb),
-('9341bdc4-08cc-5be5-a230-40ac443975f6', 17, 76500, '[{"position": 1, "amount_cents": 38000}, {"position": 2, "amount_cents": 23000}, {"position": 3, "amount_cents": 15500}]'::jsonb),
+('9e7288be-5f52-5baa-a621-84cec170f8b1', 18, 81000, '[{"position": 1, "amount_cents": 40500}, {"position": 2, "amount_cents": 24500}, {"position": 3, "amount_cents": 16000}]'::jsonb),
+('60c66d4a-482e-516e-8975-bb9aab6eae52', 16, 72000, '[{"position": 1, "amount_cents": 36000}, {"position": 2, "amount_cents": 21500}, {"position": 3, "amount_cents": 14500}]'::jsonb),
+('9341bdc4-08cc-5be5-a230-40ac443975f6', 23, 103500, '[{"position": 1, "amount_cents": 38000}, {"position": 2, "amount_cents": 26000}, {"position": 3, "amount_cents": 15500}, {"position": 4, "amount_cents": 12500}, {"position": 5, "amount_cents": 11500}]'::jsonb),
 ('c8c424d5-78bf-535b-883d-fba67c75a804', 18, 81000, '[{"position": 1, "amount_cents": 40500}, {"position": 2, "amount_cents": 24500}, {"position": 3, "amount_cents": 16000}]'::jsonb),
-('255f34b5-b0c5-5f4e-900b-857493631c40', 21, 94500, '[{"position": 1, "amount_cents": 35000}, {"position": 2, "amount_cents": 23500}, {"position": 3, "amount_cents": 14000}, {"position": 4, "amount_cents": 11500}, {"position": 5, "amount_cents": 10500}]'::jsonb),
+('255f34b5-b0c5-5f4e-900b-857493631c40', 19, 85500, '[{"position": 1, "amount_cents": 43000}, {"position": 2, "amount_cents": 25500}, {"position": 3, "amount_cents": 17000}]'::jsonb),
 ('be185dca-6eef-5a2c-98af-08ebd521da8c', 22, 99000, '[{"position": 1, "amount_cents": 36000}, {"position": 2, "amount_cents": 25000}, {"position": 3, "amount_cents": 15000}, {"position": 4, "amount_cents": 12000}, {"position": 5, "amount_cents": 11000}]'::jsonb),
-('759d5259-5fb0-5fd9-8474-a37c34d00a85', 17, 76500, '[{"position": 1, "amount_cents": 38000}, {"position": 2, "amount_cents": 23000}, {"position": 3, "amount_cents": 15500}]'::jsonb),
-('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 21, 94500, '[{"position": 1, "amount_cents": 35000}, {"position": 2, "amount_cents": 23500}, {"position": 3, "amount_cents": 14000}, {"position": 4, "amount_cents": 11500}, {"position": 5, "amount_cents": 10500}]'::jsonb),
-('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 20, 90000, '[{"position": 1, "amount_cents": 45000}, {"position": 2, "amount_cents": 27000}, {"position": 3, "amount_cents": 18000}]'::jsonb),
-('5f3dc88d-9581-58e0-934b-19e543e3701d', 23, 103500, '[{"position": 1, "amount_cents": 38000}, {"position": 2, "amount_cents": 26000}, {"position": 3, "amount_cents": 15500}, {"position": 4, "amount_cents": 12500}, {"position": 5, "amount_cents": 11500}]'::jsonb),
-('c7bd2cef-ec18-5b52-9862-93d276f78a52', 22, 99000, '[{"position": 1, "amount_cents": 36000}, {"position": 2, "amount_cents": 25000}, {"position": 3, "amount_cents": 15000}, {"position": 4, "amount_cents": 12000}, {"position": 5, "amount_cents": 11000}]'::jsonb),
-('578ef0af-c610-551c-8bde-fc52b91efbf3', 17, 45500, '[{"position": 1, "amount_cents": 23000}, {"position": 2, "amount_cents": 13500}, {"position": 3, "amount_cents": 9000}]'::jsonb),
-('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 21, 55500, '[{"position": 1, "amount_cents": 20500}, {"position": 2, "amount_cents": 14000}, {"position": 3, "amount_cents": 8500}, {"position": 4, "amount_cents": 6500}, {"position": 5, "amount_cents": 6000}]'::jsonb),
-('bb704a63-899c-5aab-99de-829047fbab66', 22, 70000, '[{"position": 1, "amount_cents": 26000}, {"position": 2, "amount_cents": 17500}, {"position": 3, "amount_cents": 10500}, {"position": 4, "amount_cents": 8500}, {"position": 5, "amount_cents": 7500}]'::jsonb),
-('a078bcb5-a64a-55e7-a744-b7397594a449', 21, 58500, '[{"position": 1, "amount_cents": 21500}, {"position": 2, "amount_cents": 14500}, {"position": 3, "amount_cents": 9000}, {"position": 4, "amount_cents": 7000}, {"position": 5, "amount_cents": 6500}]'::jsonb),
-('6ff63587-c134-512f-b50d-f7b69c7c0660', 16, 61000, '[{"position": 1, "amount_cents": 30500}, {"position": 2, "amount_cents": 18500}, {"position": 3, "amount_cents": 12000}]'::jsonb),
-('14f05f89-b10c-59da-a417-8a610e0f6f18', 20, 56000, '[{"position": 1, "amount_cents": 28000}, {"position": 2, "amount_cents": 17000}, {"position": 3, "amount_cents": 11000}]'::jsonb),
-('af567fae-395f-5feb-9f12-d849f322ec77', 16, 224000, '[{"position": 1, "amount_cents": 112000}, {"position": 2, "amount_cents": 67000}, {"position": 3, "amount_cents": 45000}]'::jsonb),
-('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 21, 294000, '[{"position": 1, "amount_cents": 108500}, {"position": 2, "amount_cents": 73500}, {"position": 3, "amount_cents": 44000}, {"position": 4, "amount_cents": 35500}, {"position": 5, "amount_cents": 32500}]'::jsonb),
-('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 17, 61200, '[{"position": 1, "amount_cents": 30700}, {"position": 2, "amount_cents": 18500}, {"position": 3, "amount_cents": 12000}]'::jsonb)
+('759d5259-5fb0-5fd9-8474-a37c34d00a85', 19, 85500, '[{"position": 1, "amount_cents": 43000}, {"position": 2, "amount_cents": 25500}, {"position": 3, "amount_cents": 17000}]'::jsonb),
+('ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe', 18, 81000, '[{"position": 1, "amount_cents": 40500}, {"position": 2, "amount_cents": 24500}, {"position": 3, "amount_cents": 16000}]'::jsonb),
+('7fd42d95-e60d-51b6-81b6-dda8bfe15264', 21, 94500, '[{"position": 1, "amount_cents": 35000}, {"position": 2, "amount_cents": 23500}, {"position": 3, "amount_cents": 14000}, {"position": 4, "amount_cents": 11500}, {"position": 5, "amount_cents": 10500}]'::jsonb),
+('5f3dc88d-9581-58e0-934b-19e543e3701d', 17, 76500, '[{"position": 1, "amount_cents": 38000}, {"position": 2, "amount_cents": 23000}, {"position": 3, "amount_cents": 15500}]'::jsonb),
+('c7bd2cef-ec18-5b52-9862-93d276f78a52', 18, 81000, '[{"position": 1, "amount_cents": 40500}, {"position": 2, "amount_cents": 24500}, {"position": 3, "amount_cents": 16000}]'::jsonb),
+('578ef0af-c610-551c-8bde-fc52b91efbf3', 17, 42500, '[{"position": 1, "amount_cents": 21000}, {"position": 2, "amount_cents": 13000}, {"position": 3, "amount_cents": 8500}]'::jsonb),
+('1e6427c7-b8c4-5560-b68c-46fe26ba151c', 19, 62500, '[{"position": 1, "amount_cents": 31000}, {"position": 2, "amount_cents": 19000}, {"position": 3, "amount_cents": 12500}]'::jsonb),
+('bb704a63-899c-5aab-99de-829047fbab66', 22, 61000, '[{"position": 1, "amount_cents": 23000}, {"position": 2, "amount_cents": 15000}, {"position": 3, "amount_cents": 9000}, {"position": 4, "amount_cents": 7500}, {"position": 5, "amount_cents": 6500}]'::jsonb),
+('a078bcb5-a64a-55e7-a744-b7397594a449', 20, 62000, '[{"position": 1, "amount_cents": 31000}, {"position": 2, "amount_cents": 18500}, {"position": 3, "amount_cents": 12500}]'::jsonb),
+('6ff63587-c134-512f-b50d-f7b69c7c0660', 17, 57500, '[{"position": 1, "amount_cents": 29000}, {"position": 2, "amount_cents": 17000}, {"position": 3, "amount_cents": 11500}]'::jsonb),
+('14f05f89-b10c-59da-a417-8a610e0f6f18', 22, 64000, '[{"position": 1, "amount_cents": 24000}, {"position": 2, "amount_cents": 16000}, {"position": 3, "amount_cents": 9500}, {"position": 4, "amount_cents": 7500}, {"position": 5, "amount_cents": 7000}]'::jsonb),
+('af567fae-395f-5feb-9f12-d849f322ec77', 22, 308000, '[{"position": 1, "amount_cents": 114000}, {"position": 2, "amount_cents": 77000}, {"position": 3, "amount_cents": 46000}, {"position": 4, "amount_cents": 37000}, {"position": 5, "amount_cents": 34000}]'::jsonb),
+('d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226', 19, 266000, '[{"position": 1, "amount_cents": 133000}, {"position": 2, "amount_cents": 80000}, {"position": 3, "amount_cents": 53000}]'::jsonb),
+('02ebfceb-7a5f-5cf6-af9e-849213e20efd', 19, 68400, '[{"position": 1, "amount_cents": 34400}, {"position": 2, "amount_cents": 20500}, {"position": 3, "amount_cents": 13500}]'::jsonb)
 ON CONFLICT (tournament_id) DO UPDATE SET player_count = EXCLUDED.player_count, total_prize_pool = EXCLUDED.total_prize_pool, payout_positions = EXCLUDED.payout_positions;
+UPDATE tournaments SET live_status = 'finished' WHERE id IN (
+'9e7288be-5f52-5baa-a621-84cec170f8b1',
+'60c66d4a-482e-516e-8975-bb9aab6eae52',
+'9341bdc4-08cc-5be5-a230-40ac443975f6',
+'c8c424d5-78bf-535b-883d-fba67c75a804',
+'255f34b5-b0c5-5f4e-900b-857493631c40',
+'be185dca-6eef-5a2c-98af-08ebd521da8c',
+'759d5259-5fb0-5fd9-8474-a37c34d00a85',
+'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe',
+'7fd42d95-e60d-51b6-81b6-dda8bfe15264',
+'5f3dc88d-9581-58e0-934b-19e543e3701d',
+'c7bd2cef-ec18-5b52-9862-93d276f78a52',
+'578ef0af-c610-551c-8bde-fc52b91efbf3',
+'1e6427c7-b8c4-5560-b68c-46fe26ba151c',
+'bb704a63-899c-5aab-99de-829047fbab66',
+'a078bcb5-a64a-55e7-a744-b7397594a449',
+'6ff63587-c134-512f-b50d-f7b69c7c0660',
+'14f05f89-b10c-59da-a417-8a610e0f6f18',
+'af567fae-395f-5feb-9f12-d849f322ec77',
+'d7e8a44d-a8e4-5b0f-9780-6bb41c1cc226',
+'02ebfceb-7a5f-5cf6-af9e-849213e20efd'
+);
 
 -- === Achievements (derived from the seeded results, so they stay consistent) ===
 INSERT INTO player_achievements (user_id, achievement_id, unlocked_at, tournament_id, progress)
-SELECT 'fbab6b35-2de3-5a9f-a856-bdb1426b1047'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
-UNION ALL
-SELECT 'fbab6b35-2de3-5a9f-a856-bdb1426b1047'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT '32403c7e-3427-537a-b4ab-77a7c40b1279'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT 'fbab6b35-2de3-5a9f-a856-bdb1426b1047'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_win'
+SELECT '32403c7e-3427-537a-b4ab-77a7c40b1279'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT 'fbab6b35-2de3-5a9f-a856-bdb1426b1047'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 17 FROM achievements WHERE code = 'tournaments_5'
+SELECT '32403c7e-3427-537a-b4ab-77a7c40b1279'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_win'
 UNION ALL
-SELECT 'fbab6b35-2de3-5a9f-a856-bdb1426b1047'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
+SELECT '32403c7e-3427-537a-b4ab-77a7c40b1279'::uuid, id, '2026-05-17 13:00:00+00'::timestamptz, 'bb704a63-899c-5aab-99de-829047fbab66'::uuid, 17 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT '1e34fb07-7add-500a-a388-c8494c20c8bb'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT '32403c7e-3427-537a-b4ab-77a7c40b1279'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
-SELECT '1e34fb07-7add-500a-a388-c8494c20c8bb'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT '77597f34-b651-5d77-8e79-29f52d3ba161'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT '1e34fb07-7add-500a-a388-c8494c20c8bb'::uuid, id, '2026-05-22 18:00:00+00'::timestamptz, 'be185dca-6eef-5a2c-98af-08ebd521da8c'::uuid, 1 FROM achievements WHERE code = 'first_win'
+SELECT '77597f34-b651-5d77-8e79-29f52d3ba161'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT '1e34fb07-7add-500a-a388-c8494c20c8bb'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 18 FROM achievements WHERE code = 'tournaments_5'
+SELECT '77597f34-b651-5d77-8e79-29f52d3ba161'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 18 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT '711cceba-3e34-5266-ba5e-4ba6adb1e544'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT '77597f34-b651-5d77-8e79-29f52d3ba161'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
-SELECT '711cceba-3e34-5266-ba5e-4ba6adb1e544'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT '725606ae-89a6-5953-b10f-5d6c37ac1dfd'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT '711cceba-3e34-5266-ba5e-4ba6adb1e544'::uuid, id, '2026-05-30 17:00:00+00'::timestamptz, 'af567fae-395f-5feb-9f12-d849f322ec77'::uuid, 1 FROM achievements WHERE code = 'first_win'
+SELECT '725606ae-89a6-5953-b10f-5d6c37ac1dfd'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT '711cceba-3e34-5266-ba5e-4ba6adb1e544'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 17 FROM achievements WHERE code = 'tournaments_5'
+SELECT '725606ae-89a6-5953-b10f-5d6c37ac1dfd'::uuid, id, '2026-05-22 18:00:00+00'::timestamptz, 'be185dca-6eef-5a2c-98af-08ebd521da8c'::uuid, 1 FROM achievements WHERE code = 'first_win'
 UNION ALL
-SELECT '711cceba-3e34-5266-ba5e-4ba6adb1e544'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
+SELECT '725606ae-89a6-5953-b10f-5d6c37ac1dfd'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 20 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT '17b0800f-f72e-5209-85e0-b90be77eb8e8'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT '725606ae-89a6-5953-b10f-5d6c37ac1dfd'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
-SELECT '17b0800f-f72e-5209-85e0-b90be77eb8e8'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT '12a026ca-4f7e-5462-bb1d-6283478a60ea'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT '17b0800f-f72e-5209-85e0-b90be77eb8e8'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 19 FROM achievements WHERE code = 'tournaments_5'
+SELECT '12a026ca-4f7e-5462-bb1d-6283478a60ea'::uuid, id, '2026-05-22 18:00:00+00'::timestamptz, 'be185dca-6eef-5a2c-98af-08ebd521da8c'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT '17b0800f-f72e-5209-85e0-b90be77eb8e8'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
+SELECT '12a026ca-4f7e-5462-bb1d-6283478a60ea'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 16 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT 'd8daf41e-26b8-5488-ab26-aeb541e51a82'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT 'd8daf41e-26b8-5488-ab26-aeb541e51a82'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a'::uuid, id, '2026-04-19 13:00:00+00'::timestamptz, '578ef0af-c610-551c-8bde-fc52b91efbf3'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT 'd8daf41e-26b8-5488-ab26-aeb541e51a82'::uuid, id, '2026-05-01 18:00:00+00'::timestamptz, '9341bdc4-08cc-5be5-a230-40ac443975f6'::uuid, 1 FROM achievements WHERE code = 'first_win'
+SELECT 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a'::uuid, id, '2026-04-24 18:00:00+00'::timestamptz, '60c66d4a-482e-516e-8975-bb9aab6eae52'::uuid, 1 FROM achievements WHERE code = 'first_win'
 UNION ALL
-SELECT 'd8daf41e-26b8-5488-ab26-aeb541e51a82'::uuid, id, '2026-05-17 13:00:00+00'::timestamptz, 'bb704a63-899c-5aab-99de-829047fbab66'::uuid, 14 FROM achievements WHERE code = 'tournaments_5'
+SELECT 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 18 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT 'd8daf41e-26b8-5488-ab26-aeb541e51a82'::uuid, id, '2026-06-27 17:00:00+00'::timestamptz, 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226'::uuid, 50 FROM achievements WHERE code = 'itm_rate_50'
+SELECT 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
-SELECT 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT '1e34fb07-7add-500a-a388-c8494c20c8bb'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 18 FROM achievements WHERE code = 'tournaments_5'
+SELECT '1e34fb07-7add-500a-a388-c8494c20c8bb'::uuid, id, '2026-05-17 13:00:00+00'::timestamptz, 'bb704a63-899c-5aab-99de-829047fbab66'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT 'fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
+SELECT '1e34fb07-7add-500a-a388-c8494c20c8bb'::uuid, id, '2026-05-17 13:00:00+00'::timestamptz, 'bb704a63-899c-5aab-99de-829047fbab66'::uuid, 16 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT '1e34fb07-7add-500a-a388-c8494c20c8bb'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
-SELECT '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e'::uuid, id, '2026-06-26 18:00:00+00'::timestamptz, 'c7bd2cef-ec18-5b52-9862-93d276f78a52'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT '6b633796-0413-59c5-bbd4-b3b4057e46be'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e'::uuid, id, '2026-06-26 18:00:00+00'::timestamptz, 'c7bd2cef-ec18-5b52-9862-93d276f78a52'::uuid, 1 FROM achievements WHERE code = 'first_win'
+SELECT '6b633796-0413-59c5-bbd4-b3b4057e46be'::uuid, id, '2026-04-24 18:00:00+00'::timestamptz, '60c66d4a-482e-516e-8975-bb9aab6eae52'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e'::uuid, id, '2026-05-17 13:00:00+00'::timestamptz, 'bb704a63-899c-5aab-99de-829047fbab66'::uuid, 16 FROM achievements WHERE code = 'tournaments_5'
+SELECT '6b633796-0413-59c5-bbd4-b3b4057e46be'::uuid, id, '2026-06-14 13:00:00+00'::timestamptz, '6ff63587-c134-512f-b50d-f7b69c7c0660'::uuid, 1 FROM achievements WHERE code = 'first_win'
 UNION ALL
-SELECT '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e'::uuid, id, '2026-06-27 17:00:00+00'::timestamptz, 'd7e8a44d-a8e4-5b0f-9780-6bb41c1cc226'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
+SELECT '6b633796-0413-59c5-bbd4-b3b4057e46be'::uuid, id, '2026-05-08 18:00:00+00'::timestamptz, 'c8c424d5-78bf-535b-883d-fba67c75a804'::uuid, 16 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT 'b23e9386-5048-5d4a-b4d2-3e77eebffa13'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT 'eaac0ce1-d164-5538-aac8-f9e19dd79a02'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT 'b23e9386-5048-5d4a-b4d2-3e77eebffa13'::uuid, id, '2026-04-19 13:00:00+00'::timestamptz, '578ef0af-c610-551c-8bde-fc52b91efbf3'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT 'eaac0ce1-d164-5538-aac8-f9e19dd79a02'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT 'b23e9386-5048-5d4a-b4d2-3e77eebffa13'::uuid, id, '2026-05-08 18:00:00+00'::timestamptz, 'c8c424d5-78bf-535b-883d-fba67c75a804'::uuid, 16 FROM achievements WHERE code = 'tournaments_5'
+SELECT 'eaac0ce1-d164-5538-aac8-f9e19dd79a02'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 16 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT 'b23e9386-5048-5d4a-b4d2-3e77eebffa13'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
+SELECT 'eaac0ce1-d164-5538-aac8-f9e19dd79a02'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
-SELECT '97d54d39-c474-5af5-8e27-d728f570f15b'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT '97d54d39-c474-5af5-8e27-d728f570f15b'::uuid, id, '2026-05-22 18:00:00+00'::timestamptz, 'be185dca-6eef-5a2c-98af-08ebd521da8c'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214'::uuid, id, '2026-06-05 18:00:00+00'::timestamptz, 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT '97d54d39-c474-5af5-8e27-d728f570f15b'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 14 FROM achievements WHERE code = 'tournaments_5'
+SELECT 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 18 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT '12a026ca-4f7e-5462-bb1d-6283478a60ea'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
-SELECT '12a026ca-4f7e-5462-bb1d-6283478a60ea'::uuid, id, '2026-04-19 13:00:00+00'::timestamptz, '578ef0af-c610-551c-8bde-fc52b91efbf3'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT '17b0800f-f72e-5209-85e0-b90be77eb8e8'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT '12a026ca-4f7e-5462-bb1d-6283478a60ea'::uuid, id, '2026-05-17 13:00:00+00'::timestamptz, 'bb704a63-899c-5aab-99de-829047fbab66'::uuid, 1 FROM achievements WHERE code = 'first_win'
+SELECT '17b0800f-f72e-5209-85e0-b90be77eb8e8'::uuid, id, '2026-06-05 18:00:00+00'::timestamptz, 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT '12a026ca-4f7e-5462-bb1d-6283478a60ea'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 16 FROM achievements WHERE code = 'tournaments_5'
+SELECT '17b0800f-f72e-5209-85e0-b90be77eb8e8'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 17 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT '12a026ca-4f7e-5462-bb1d-6283478a60ea'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
+SELECT '17b0800f-f72e-5209-85e0-b90be77eb8e8'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
-SELECT '72d99112-e37e-5845-a720-ba383679ac66'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT '8bec458b-ce53-501a-a54c-fbbd7838f6c0'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT '72d99112-e37e-5845-a720-ba383679ac66'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT '8bec458b-ce53-501a-a54c-fbbd7838f6c0'::uuid, id, '2026-05-01 18:00:00+00'::timestamptz, '9341bdc4-08cc-5be5-a230-40ac443975f6'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT '72d99112-e37e-5845-a720-ba383679ac66'::uuid, id, '2026-05-08 18:00:00+00'::timestamptz, 'c8c424d5-78bf-535b-883d-fba67c75a804'::uuid, 1 FROM achievements WHERE code = 'first_win'
+SELECT '8bec458b-ce53-501a-a54c-fbbd7838f6c0'::uuid, id, '2026-05-17 13:00:00+00'::timestamptz, 'bb704a63-899c-5aab-99de-829047fbab66'::uuid, 15 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT '72d99112-e37e-5845-a720-ba383679ac66'::uuid, id, '2026-05-08 18:00:00+00'::timestamptz, 'c8c424d5-78bf-535b-883d-fba67c75a804'::uuid, 16 FROM achievements WHERE code = 'tournaments_5'
+SELECT '8bec458b-ce53-501a-a54c-fbbd7838f6c0'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
-SELECT '32403c7e-3427-537a-b4ab-77a7c40b1279'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT '72d99112-e37e-5845-a720-ba383679ac66'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT '32403c7e-3427-537a-b4ab-77a7c40b1279'::uuid, id, '2026-04-24 18:00:00+00'::timestamptz, '60c66d4a-482e-516e-8975-bb9aab6eae52'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT '72d99112-e37e-5845-a720-ba383679ac66'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT '32403c7e-3427-537a-b4ab-77a7c40b1279'::uuid, id, '2026-06-12 18:00:00+00'::timestamptz, '7fd42d95-e60d-51b6-81b6-dda8bfe15264'::uuid, 1 FROM achievements WHERE code = 'first_win'
+SELECT '72d99112-e37e-5845-a720-ba383679ac66'::uuid, id, '2026-06-19 18:00:00+00'::timestamptz, '5f3dc88d-9581-58e0-934b-19e543e3701d'::uuid, 1 FROM achievements WHERE code = 'first_win'
 UNION ALL
-SELECT '32403c7e-3427-537a-b4ab-77a7c40b1279'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 16 FROM achievements WHERE code = 'tournaments_5'
+SELECT '72d99112-e37e-5845-a720-ba383679ac66'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 15 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT '32403c7e-3427-537a-b4ab-77a7c40b1279'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
+SELECT '72d99112-e37e-5845-a720-ba383679ac66'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
 SELECT 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c'::uuid, id, '2026-04-19 13:00:00+00'::timestamptz, '578ef0af-c610-551c-8bde-fc52b91efbf3'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+UNION ALL
+SELECT 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c'::uuid, id, '2026-04-19 13:00:00+00'::timestamptz, '578ef0af-c610-551c-8bde-fc52b91efbf3'::uuid, 1 FROM achievements WHERE code = 'first_win'
 UNION ALL
-SELECT 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c'::uuid, id, '2026-05-08 18:00:00+00'::timestamptz, 'c8c424d5-78bf-535b-883d-fba67c75a804'::uuid, 19 FROM achievements WHERE code = 'tournaments_5'
+SELECT 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 18 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
 SELECT 'a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
-SELECT 'eaac0ce1-d164-5538-aac8-f9e19dd79a02'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT 'b23e9386-5048-5d4a-b4d2-3e77eebffa13'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT 'eaac0ce1-d164-5538-aac8-f9e19dd79a02'::uuid, id, '2026-05-08 18:00:00+00'::timestamptz, 'c8c424d5-78bf-535b-883d-fba67c75a804'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT 'b23e9386-5048-5d4a-b4d2-3e77eebffa13'::uuid, id, '2026-05-17 13:00:00+00'::timestamptz, 'bb704a63-899c-5aab-99de-829047fbab66'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT 'eaac0ce1-d164-5538-aac8-f9e19dd79a02'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 19 FROM achievements WHERE code = 'tournaments_5'
+SELECT 'b23e9386-5048-5d4a-b4d2-3e77eebffa13'::uuid, id, '2026-05-30 17:00:00+00'::timestamptz, 'af567fae-395f-5feb-9f12-d849f322ec77'::uuid, 1 FROM achievements WHERE code = 'first_win'
 UNION ALL
-SELECT 'eaac0ce1-d164-5538-aac8-f9e19dd79a02'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
+SELECT 'b23e9386-5048-5d4a-b4d2-3e77eebffa13'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 15 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT '4d31d819-ebbb-566b-8c03-29c828ef220b'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT 'b23e9386-5048-5d4a-b4d2-3e77eebffa13'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
-SELECT '4d31d819-ebbb-566b-8c03-29c828ef220b'::uuid, id, '2026-06-26 18:00:00+00'::timestamptz, 'c7bd2cef-ec18-5b52-9862-93d276f78a52'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT 'f647df8a-5e87-5d63-af45-659fe8a0c16f'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT '4d31d819-ebbb-566b-8c03-29c828ef220b'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 19 FROM achievements WHERE code = 'tournaments_5'
+SELECT 'f647df8a-5e87-5d63-af45-659fe8a0c16f'::uuid, id, '2026-05-29 18:00:00+00'::timestamptz, '759d5259-5fb0-5fd9-8474-a37c34d00a85'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT '4d31d819-ebbb-566b-8c03-29c828ef220b'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
+SELECT 'f647df8a-5e87-5d63-af45-659fe8a0c16f'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 10 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT '9549d323-2ca7-5b7a-a23b-e9844c782d78'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT 'fbab6b35-2de3-5a9f-a856-bdb1426b1047'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+UNION ALL
+SELECT 'fbab6b35-2de3-5a9f-a856-bdb1426b1047'::uuid, id, '2026-04-19 13:00:00+00'::timestamptz, '578ef0af-c610-551c-8bde-fc52b91efbf3'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT '9549d323-2ca7-5b7a-a23b-e9844c782d78'::uuid, id, '2026-06-05 18:00:00+00'::timestamptz, 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT 'fbab6b35-2de3-5a9f-a856-bdb1426b1047'::uuid, id, '2026-05-17 13:00:00+00'::timestamptz, 'bb704a63-899c-5aab-99de-829047fbab66'::uuid, 1 FROM achievements WHERE code = 'first_win'
 UNION ALL
-SELECT '9549d323-2ca7-5b7a-a23b-e9844c782d78'::uuid, id, '2026-06-05 18:00:00+00'::timestamptz, 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe'::uuid, 1 FROM achievements WHERE code = 'first_win'
+SELECT 'fbab6b35-2de3-5a9f-a856-bdb1426b1047'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 17 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT '9549d323-2ca7-5b7a-a23b-e9844c782d78'::uuid, id, '2026-05-08 18:00:00+00'::timestamptz, 'c8c424d5-78bf-535b-883d-fba67c75a804'::uuid, 14 FROM achievements WHERE code = 'tournaments_5'
+SELECT 'fbab6b35-2de3-5a9f-a856-bdb1426b1047'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
-SELECT 'd42f03b9-5d10-58d5-b400-02ba13586359'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT '9549d323-2ca7-5b7a-a23b-e9844c782d78'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT 'd42f03b9-5d10-58d5-b400-02ba13586359'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT '9549d323-2ca7-5b7a-a23b-e9844c782d78'::uuid, id, '2026-06-21 13:00:00+00'::timestamptz, '02ebfceb-7a5f-5cf6-af9e-849213e20efd'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT 'd42f03b9-5d10-58d5-b400-02ba13586359'::uuid, id, '2026-05-08 18:00:00+00'::timestamptz, 'c8c424d5-78bf-535b-883d-fba67c75a804'::uuid, 19 FROM achievements WHERE code = 'tournaments_5'
+SELECT '9549d323-2ca7-5b7a-a23b-e9844c782d78'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 15 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT 'd42f03b9-5d10-58d5-b400-02ba13586359'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
+SELECT '9549d323-2ca7-5b7a-a23b-e9844c782d78'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
-SELECT '77597f34-b651-5d77-8e79-29f52d3ba161'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT '711cceba-3e34-5266-ba5e-4ba6adb1e544'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT '77597f34-b651-5d77-8e79-29f52d3ba161'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT '711cceba-3e34-5266-ba5e-4ba6adb1e544'::uuid, id, '2026-05-22 18:00:00+00'::timestamptz, 'be185dca-6eef-5a2c-98af-08ebd521da8c'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT '77597f34-b651-5d77-8e79-29f52d3ba161'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 1 FROM achievements WHERE code = 'first_win'
+SELECT '711cceba-3e34-5266-ba5e-4ba6adb1e544'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 1 FROM achievements WHERE code = 'first_win'
 UNION ALL
-SELECT '77597f34-b651-5d77-8e79-29f52d3ba161'::uuid, id, '2026-05-08 18:00:00+00'::timestamptz, 'c8c424d5-78bf-535b-883d-fba67c75a804'::uuid, 18 FROM achievements WHERE code = 'tournaments_5'
+SELECT '711cceba-3e34-5266-ba5e-4ba6adb1e544'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 16 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT '8bec458b-ce53-501a-a54c-fbbd7838f6c0'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT '97d54d39-c474-5af5-8e27-d728f570f15b'::uuid, id, '2026-04-24 18:00:00+00'::timestamptz, '60c66d4a-482e-516e-8975-bb9aab6eae52'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT '8bec458b-ce53-501a-a54c-fbbd7838f6c0'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT '97d54d39-c474-5af5-8e27-d728f570f15b'::uuid, id, '2026-06-14 13:00:00+00'::timestamptz, '6ff63587-c134-512f-b50d-f7b69c7c0660'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT '8bec458b-ce53-501a-a54c-fbbd7838f6c0'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 1 FROM achievements WHERE code = 'first_win'
+SELECT '97d54d39-c474-5af5-8e27-d728f570f15b'::uuid, id, '2026-06-21 13:00:00+00'::timestamptz, '02ebfceb-7a5f-5cf6-af9e-849213e20efd'::uuid, 1 FROM achievements WHERE code = 'first_win'
 UNION ALL
-SELECT '8bec458b-ce53-501a-a54c-fbbd7838f6c0'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 17 FROM achievements WHERE code = 'tournaments_5'
+SELECT '97d54d39-c474-5af5-8e27-d728f570f15b'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 17 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT '6b633796-0413-59c5-bbd4-b3b4057e46be'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT '97d54d39-c474-5af5-8e27-d728f570f15b'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
-SELECT '6b633796-0413-59c5-bbd4-b3b4057e46be'::uuid, id, '2026-04-19 13:00:00+00'::timestamptz, '578ef0af-c610-551c-8bde-fc52b91efbf3'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT '4d31d819-ebbb-566b-8c03-29c828ef220b'::uuid, id, '2026-04-19 13:00:00+00'::timestamptz, '578ef0af-c610-551c-8bde-fc52b91efbf3'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT '6b633796-0413-59c5-bbd4-b3b4057e46be'::uuid, id, '2026-04-19 13:00:00+00'::timestamptz, '578ef0af-c610-551c-8bde-fc52b91efbf3'::uuid, 1 FROM achievements WHERE code = 'first_win'
+SELECT '4d31d819-ebbb-566b-8c03-29c828ef220b'::uuid, id, '2026-05-01 18:00:00+00'::timestamptz, '9341bdc4-08cc-5be5-a230-40ac443975f6'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT '6b633796-0413-59c5-bbd4-b3b4057e46be'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 19 FROM achievements WHERE code = 'tournaments_5'
+SELECT '4d31d819-ebbb-566b-8c03-29c828ef220b'::uuid, id, '2026-05-01 18:00:00+00'::timestamptz, '9341bdc4-08cc-5be5-a230-40ac443975f6'::uuid, 1 FROM achievements WHERE code = 'first_win'
 UNION ALL
-SELECT '6b633796-0413-59c5-bbd4-b3b4057e46be'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
+SELECT '4d31d819-ebbb-566b-8c03-29c828ef220b'::uuid, id, '2026-05-08 18:00:00+00'::timestamptz, 'c8c424d5-78bf-535b-883d-fba67c75a804'::uuid, 18 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT '725606ae-89a6-5953-b10f-5d6c37ac1dfd'::uuid, id, '2026-04-17 18:00:00+00'::timestamptz, '9e7288be-5f52-5baa-a621-84cec170f8b1'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT 'd42f03b9-5d10-58d5-b400-02ba13586359'::uuid, id, '2026-04-19 13:00:00+00'::timestamptz, '578ef0af-c610-551c-8bde-fc52b91efbf3'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT '725606ae-89a6-5953-b10f-5d6c37ac1dfd'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT 'd42f03b9-5d10-58d5-b400-02ba13586359'::uuid, id, '2026-05-01 18:00:00+00'::timestamptz, '9341bdc4-08cc-5be5-a230-40ac443975f6'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT '725606ae-89a6-5953-b10f-5d6c37ac1dfd'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 19 FROM achievements WHERE code = 'tournaments_5'
+SELECT 'd42f03b9-5d10-58d5-b400-02ba13586359'::uuid, id, '2026-06-05 18:00:00+00'::timestamptz, 'ce9f7038-fb6b-5b24-bc21-9cccb7bffbbe'::uuid, 1 FROM achievements WHERE code = 'first_win'
 UNION ALL
-SELECT '725606ae-89a6-5953-b10f-5d6c37ac1dfd'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
+SELECT 'd42f03b9-5d10-58d5-b400-02ba13586359'::uuid, id, '2026-05-08 18:00:00+00'::timestamptz, 'c8c424d5-78bf-535b-883d-fba67c75a804'::uuid, 19 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214'::uuid, id, '2026-04-19 13:00:00+00'::timestamptz, '578ef0af-c610-551c-8bde-fc52b91efbf3'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT 'd42f03b9-5d10-58d5-b400-02ba13586359'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
-SELECT 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214'::uuid, id, '2026-04-24 18:00:00+00'::timestamptz, '60c66d4a-482e-516e-8975-bb9aab6eae52'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e'::uuid, id, '2026-05-01 18:00:00+00'::timestamptz, '9341bdc4-08cc-5be5-a230-40ac443975f6'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
-SELECT 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214'::uuid, id, '2026-04-24 18:00:00+00'::timestamptz, '60c66d4a-482e-516e-8975-bb9aab6eae52'::uuid, 1 FROM achievements WHERE code = 'first_win'
+SELECT '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e'::uuid, id, '2026-05-01 18:00:00+00'::timestamptz, '9341bdc4-08cc-5be5-a230-40ac443975f6'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 16 FROM achievements WHERE code = 'tournaments_5'
+SELECT '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 1 FROM achievements WHERE code = 'first_win'
 UNION ALL
-SELECT 'ae5c0745-17fb-5a5e-8ea4-41e8cbeca214'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
+SELECT '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e'::uuid, id, '2026-05-22 18:00:00+00'::timestamptz, 'be185dca-6eef-5a2c-98af-08ebd521da8c'::uuid, 15 FROM achievements WHERE code = 'tournaments_5'
+UNION ALL
+SELECT '65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
 SELECT '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea'::uuid, id, '2026-05-01 18:00:00+00'::timestamptz, '9341bdc4-08cc-5be5-a230-40ac443975f6'::uuid, 1 FROM achievements WHERE code = 'first_registration'
 UNION ALL
@@ -2366,42 +2377,44 @@ SELECT '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea'::uuid, id, '2026-06-26 18:00:00+00
 UNION ALL
 SELECT '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea'::uuid, id, '2026-06-26 18:00:00+00'::timestamptz, 'c7bd2cef-ec18-5b52-9862-93d276f78a52'::uuid, 50 FROM achievements WHERE code = 'itm_rate_50'
 UNION ALL
-SELECT 'f647df8a-5e87-5d63-af45-659fe8a0c16f'::uuid, id, '2026-04-19 13:00:00+00'::timestamptz, '578ef0af-c610-551c-8bde-fc52b91efbf3'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+SELECT 'd8daf41e-26b8-5488-ab26-aeb541e51a82'::uuid, id, '2026-04-19 13:00:00+00'::timestamptz, '578ef0af-c610-551c-8bde-fc52b91efbf3'::uuid, 1 FROM achievements WHERE code = 'first_registration'
+UNION ALL
+SELECT 'd8daf41e-26b8-5488-ab26-aeb541e51a82'::uuid, id, '2026-05-08 18:00:00+00'::timestamptz, 'c8c424d5-78bf-535b-883d-fba67c75a804'::uuid, 1 FROM achievements WHERE code = 'first_cash'
 UNION ALL
-SELECT 'f647df8a-5e87-5d63-af45-659fe8a0c16f'::uuid, id, '2026-05-03 13:00:00+00'::timestamptz, '1e6427c7-b8c4-5560-b68c-46fe26ba151c'::uuid, 1 FROM achievements WHERE code = 'first_cash'
+SELECT 'd8daf41e-26b8-5488-ab26-aeb541e51a82'::uuid, id, '2026-05-08 18:00:00+00'::timestamptz, 'c8c424d5-78bf-535b-883d-fba67c75a804'::uuid, 1 FROM achievements WHERE code = 'first_win'
 UNION ALL
-SELECT 'f647df8a-5e87-5d63-af45-659fe8a0c16f'::uuid, id, '2026-06-12 18:00:00+00'::timestamptz, '7fd42d95-e60d-51b6-81b6-dda8bfe15264'::uuid, 8 FROM achievements WHERE code = 'tournaments_5'
+SELECT 'd8daf41e-26b8-5488-ab26-aeb541e51a82'::uuid, id, '2026-05-15 18:00:00+00'::timestamptz, '255f34b5-b0c5-5f4e-900b-857493631c40'::uuid, 18 FROM achievements WHERE code = 'tournaments_5'
 UNION ALL
-SELECT 'f647df8a-5e87-5d63-af45-659fe8a0c16f'::uuid, id, '2026-06-26 18:00:00+00'::timestamptz, 'c7bd2cef-ec18-5b52-9862-93d276f78a52'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
+SELECT 'd8daf41e-26b8-5488-ab26-aeb541e51a82'::uuid, id, '2026-06-28 13:00:00+00'::timestamptz, '14f05f89-b10c-59da-a417-8a610e0f6f18'::uuid, 2 FROM achievements WHERE code = 'clubs_2'
 UNION ALL
 SELECT '8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea'::uuid, id, '2026-06-26 18:00:00+00'::timestamptz, 'c7bd2cef-ec18-5b52-9862-93d276f78a52'::uuid, 5 FROM achievements WHERE code = 'streak_play_5';
 
 -- === Attendance streaks ===
 INSERT INTO attendance_streak (app_user_id, current_streak, longest_streak, last_check_in_at) VALUES
-('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 3, 6, '2026-06-28 13:00:00+00'),
-('1e34fb07-7add-500a-a388-c8494c20c8bb', 5, 5, '2026-06-28 13:00:00+00'),
-('711cceba-3e34-5266-ba5e-4ba6adb1e544', 1, 5, '2026-06-28 13:00:00+00'),
-('17b0800f-f72e-5209-85e0-b90be77eb8e8', 5, 5, '2026-06-28 13:00:00+00'),
-('d8daf41e-26b8-5488-ab26-aeb541e51a82', 2, 4, '2026-06-28 13:00:00+00'),
-('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 0, 4, '2026-06-28 13:00:00+00'),
-('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 0, 6, '2026-06-27 17:00:00+00'),
-('b23e9386-5048-5d4a-b4d2-3e77eebffa13', 0, 4, '2026-06-28 13:00:00+00'),
-('97d54d39-c474-5af5-8e27-d728f570f15b', 0, 7, '2026-06-28 13:00:00+00'),
-('12a026ca-4f7e-5462-bb1d-6283478a60ea', 5, 5, '2026-06-28 13:00:00+00'),
-('72d99112-e37e-5845-a720-ba383679ac66', 5, 5, '2026-06-28 13:00:00+00'),
-('32403c7e-3427-537a-b4ab-77a7c40b1279', 3, 5, '2026-06-28 13:00:00+00'),
-('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 1, 6, '2026-06-28 13:00:00+00'),
-('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 0, 1, '2026-06-28 13:00:00+00'),
-('4d31d819-ebbb-566b-8c03-29c828ef220b', 4, 6, '2026-06-28 13:00:00+00'),
-('9549d323-2ca7-5b7a-a23b-e9844c782d78', 5, 5, '2026-06-27 17:00:00+00'),
-('d42f03b9-5d10-58d5-b400-02ba13586359', 2, 7, '2026-06-28 13:00:00+00'),
-('77597f34-b651-5d77-8e79-29f52d3ba161', 1, 4, '2026-06-28 13:00:00+00'),
-('8bec458b-ce53-501a-a54c-fbbd7838f6c0', 5, 6, '2026-06-28 13:00:00+00'),
-('6b633796-0413-59c5-bbd4-b3b4057e46be', 2, 5, '2026-06-28 13:00:00+00'),
-('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 0, 1, '2026-06-28 13:00:00+00'),
-('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 0, 7, '2026-06-28 13:00:00+00'),
+('32403c7e-3427-537a-b4ab-77a7c40b1279', 1, 7, '2026-06-28 13:00:00+00'),
+('77597f34-b651-5d77-8e79-29f52d3ba161', 5, 5, '2026-06-28 13:00:00+00'),
+('725606ae-89a6-5953-b10f-5d6c37ac1dfd', 2, 3, '2026-06-28 13:00:00+00'),
+('12a026ca-4f7e-5462-bb1d-6283478a60ea', 0, 5, '2026-06-28 13:00:00+00'),
+('fa3d4cfe-8f96-54a5-a2bf-0dce0e28e63a', 1, 6, '2026-06-28 13:00:00+00'),
+('1e34fb07-7add-500a-a388-c8494c20c8bb', 0, 4, '2026-06-28 13:00:00+00'),
+('6b633796-0413-59c5-bbd4-b3b4057e46be', 2, 7, '2026-06-28 13:00:00+00'),
+('eaac0ce1-d164-5538-aac8-f9e19dd79a02', 1, 3, '2026-06-28 13:00:00+00'),
+('ae5c0745-17fb-5a5e-8ea4-41e8cbeca214', 4, 4, '2026-06-28 13:00:00+00'),
+('17b0800f-f72e-5209-85e0-b90be77eb8e8', 5, 7, '2026-06-28 13:00:00+00'),
+('8bec458b-ce53-501a-a54c-fbbd7838f6c0', 0, 2, '2026-06-28 13:00:00+00'),
+('72d99112-e37e-5845-a720-ba383679ac66', 3, 4, '2026-06-28 13:00:00+00'),
+('a2d3ebec-de8d-59dd-8c5b-0ca5af7a454c', 0, 4, '2026-06-28 13:00:00+00'),
+('b23e9386-5048-5d4a-b4d2-3e77eebffa13', 0, 2, '2026-06-28 13:00:00+00'),
+('f647df8a-5e87-5d63-af45-659fe8a0c16f', 4, 6, '2026-06-27 17:00:00+00'),
+('fbab6b35-2de3-5a9f-a856-bdb1426b1047', 1, 6, '2026-06-28 13:00:00+00'),
+('9549d323-2ca7-5b7a-a23b-e9844c782d78', 1, 3, '2026-06-28 13:00:00+00'),
+('711cceba-3e34-5266-ba5e-4ba6adb1e544', 4, 6, '2026-06-28 13:00:00+00'),
+('97d54d39-c474-5af5-8e27-d728f570f15b', 4, 7, '2026-06-28 13:00:00+00'),
+('4d31d819-ebbb-566b-8c03-29c828ef220b', 2, 3, '2026-06-28 13:00:00+00'),
+('d42f03b9-5d10-58d5-b400-02ba13586359', 5, 5, '2026-06-28 13:00:00+00'),
+('65b3a0bd-f6d7-5a33-b7a0-ba560fcb841e', 3, 3, '2026-06-28 13:00:00+00'),
 ('8d69f9f5-1ee6-5e48-8237-61a5c7ecd7ea', 4, 5, '2026-06-26 18:00:00+00'),
-('f647df8a-5e87-5d63-af45-659fe8a0c16f', 4, 4, '2026-06-26 18:00:00+00');
+('d8daf41e-26b8-5488-ab26-aeb541e51a82', 5, 5, '2026-06-28 13:00:00+00');
 
 -- === Season & league ===
 INSERT INTO season (id, club_id, name, starts_at, ends_at) VALUES
@@ -0,0 +1,242 @@
+-- Revert money columns to INTEGER. Fails if any stored amount no longer fits.
+
+DROP FUNCTION IF EXISTS apply_tournament_payout(UUID, BIGINT, INTEGER);
+
+CREATE OR REPLACE FUNCTION apply_tournament_payout(
+    p_tournament_id UUID,
+    p_total_amount  INTEGER,
+    p_player_count  INTEGER
+) RETURNS VOID AS $$
+DECLARE
+    v_club_id UUID;
+    v_template RECORD;
+    v_payout_positions JSONB;
+BEGIN
+    SELECT club_id INTO v_club_id FROM tournaments WHERE id = p_tournament_id;
+
+    SELECT * INTO v_template FROM payout_templates
+    WHERE club_id = v_club_id
+    AND min_players <= p_player_count
+    AND (max_players IS NULL OR max_players >= p_player_count)
+    ORDER BY min_players DESC LIMIT 1;
+
+    IF v_template.id IS NOT NULL THEN
+        SELECT to_jsonb(array_agg(
+            jsonb_build_object(
+                'position', (pos->>'position')::INTEGER,
+                'amount_cents', FLOOR((pos->>'percentage')::NUMERIC * p_total_amount / 100),
+                'percentage', (pos->>'percentage')::NUMERIC
+            ) ORDER BY (pos->>'position')::INTEGER
+        )) INTO v_payout_positions
+        FROM jsonb_array_elements(v_template.payout_structure) pos;
+    ELSE
+        v_payout_positions := '[]'::JSONB;
+    END IF;
+
+    INSERT INTO tournament_payouts (
+        tournament_id, template_id, player_count, total_prize_pool, payout_positions
+    ) VALUES (
+        p_tournament_id, v_template.id, p_player_count, p_total_amount,
+        COALESCE(v_payout_positions, '[]'::JSONB)
+    )
+    ON CONFLICT (tournament_id) DO UPDATE SET
+        total_prize_pool = EXCLUDED.total_prize_pool,
+        player_count = EXCLUDED.player_count,
+        template_id = EXCLUDED.template_id,
+        payout_positions = EXCLUDED.payout_positions,
+        updated_at = NOW();
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION recalculate_prize_pool_from_entries()
+RETURNS TRIGGER AS $$
+DECLARE
+    v_tournament_id UUID;
+    v_series_id UUID;
+    v_final_day_id UUID;
+    v_total_amount INTEGER;
+    v_player_count INTEGER;
+    v_bounty_slice INTEGER;
+BEGIN
+    v_tournament_id := COALESCE(NEW.tournament_id, OLD.tournament_id);
+
+    SELECT series_id INTO v_series_id FROM tournaments WHERE id = v_tournament_id;
+
+    -- (1) The changed tournament's own per-night payout (single-day path,
+    -- also the per-flight cash desk). Vouchers and bonuses are excluded from the
+    -- prize pool; players are counted by club_player_id (account-less safe).
+    SELECT
+        COALESCE(SUM(amount_cents) FILTER (WHERE entry_type NOT IN ('voucher', 'bonus')), 0),
+        COUNT(DISTINCT club_player_id) FILTER (WHERE entry_type NOT IN ('voucher', 'bonus'))
+    INTO v_total_amount, v_player_count
+    FROM tournament_entries WHERE tournament_id = v_tournament_id;
+
+    SELECT COALESCE(bounty_amount_cents, 0) * COUNT(*) FILTER (
+        WHERE te.entry_type IN ('initial', 'rebuy', 're_entry'))
+    INTO v_bounty_slice
+    FROM tournaments t
+    LEFT JOIN tournament_entries te ON te.tournament_id = t.id
+    WHERE t.id = v_tournament_id
+    GROUP BY t.bounty_amount_cents;
+
+    v_total_amount := GREATEST(v_total_amount - COALESCE(v_bounty_slice, 0), 0);
+    PERFORM apply_tournament_payout(v_tournament_id, v_total_amount, v_player_count);
+
+    -- (2) If this tournament belongs to a series, refresh the final day's
+    -- aggregate across all flights.
+    IF v_series_id IS NOT NULL THEN
+        SELECT id INTO v_final_day_id FROM tournaments
+        WHERE series_id = v_series_id AND is_final_day = TRUE LIMIT 1;
+
+        IF v_final_day_id IS NOT NULL THEN
+            SELECT
+                COALESCE(SUM(te.amount_cents) FILTER (WHERE te.entry_type NOT IN ('voucher', 'bonus')), 0),
+                COUNT(DISTINCT te.club_player_id) FILTER (WHERE te.entry_type NOT IN ('voucher', 'bonus'))
+            INTO v_total_amount, v_player_count
+            FROM tournament_entries te
+            JOIN tournaments t ON t.id = te.tournament_id
+            WHERE t.series_id = v_series_id;
+
+            SELECT COALESCE(SUM(sub.slice), 0) INTO v_bounty_slice FROM (
+                SELECT t.bounty_amount_cents * COUNT(*) FILTER (
+                    WHERE te.entry_type IN ('initial', 'rebuy', 're_entry')) AS slice
+                FROM tournaments t
+                JOIN tournament_entries te ON te.tournament_id = t.id
+                WHERE t.series_id = v_series_id AND t.bounty_amount_cents > 0
+                GROUP BY t.id, t.bounty_amount_cents
+            ) sub;
+
+            v_total_amount := GREATEST(v_total_amount - COALESCE(v_bounty_slice, 0), 0);
+            PERFORM apply_tournament_payout(v_final_day_id, v_total_amount, v_player_count);
+        END IF;
+    END IF;
+
+    RETURN COALESCE(NEW, OLD);
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION calculate_tournament_payouts()
+RETURNS TRIGGER AS $$
+DECLARE
+    v_player_count INTEGER;
+    v_total_prize_pool INTEGER;
+    v_template RECORD;
+    v_payout_positions JSONB;
+    v_position RECORD;
+    v_positions_array JSONB[];
+    v_payout_amount INTEGER;
+BEGIN
+    IF NEW.live_status IN ('late_registration', 'in_progress')
+       AND OLD.live_status != NEW.live_status THEN
+
+        IF EXISTS (SELECT 1 FROM tournament_payouts WHERE tournament_id = NEW.id) THEN
+            RETURN NEW;
+        END IF;
+
+        SELECT COUNT(*) INTO v_player_count
+        FROM tournament_registrations
+        WHERE tournament_id = NEW.id
+        AND status IN ('registered', 'checked_in', 'seated', 'busted');
+
+        IF v_player_count = 0 THEN
+            RETURN NEW;
+        END IF;
+
+        v_total_prize_pool := NEW.buy_in_cents * v_player_count;
+
+        -- Find a payout template owned by this tournament's club.
+        SELECT * INTO v_template
+        FROM payout_templates
+        WHERE club_id = NEW.club_id
+        AND min_players <= v_player_count
+        AND (max_players IS NULL OR max_players >= v_player_count)
+        ORDER BY min_players DESC
+        LIMIT 1;
+
+        IF v_template.id IS NULL THEN
+            RAISE WARNING 'No payout template found for % players in tournament %', v_player_count, NEW.id;
+            RETURN NEW;
+        END IF;
+
+        v_positions_array := ARRAY[]::JSONB[];
+
+        FOR v_position IN
+            SELECT * FROM jsonb_array_elements(v_template.payout_structure)
+        LOOP
+            v_payout_amount := FLOOR((v_position.value->>'percentage')::NUMERIC * v_total_prize_pool / 100);
+
+            v_positions_array := array_append(
+                v_positions_array,
+                jsonb_build_object(
+                    'position', (v_position.value->>'position')::INTEGER,
+                    'amount_cents', v_payout_amount,
+                    'percentage', (v_position.value->>'percentage')::NUMERIC
+                )
+            );
+        END LOOP;
+
+        v_payout_positions := to_jsonb(v_positions_array);
+
+        INSERT INTO tournament_payouts (
+            tournament_id, template_id, player_count, total_prize_pool, payout_positions
+        ) VALUES (
+            NEW.id, v_template.id, v_player_count, v_total_prize_pool, v_payout_positions
+        );
+
+        RAISE NOTICE 'Created payouts for tournament % with % players using template %',
+            NEW.id, v_player_count, v_template.name;
+    END IF;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION grow_bounty_head_on_entry()
+RETURNS TRIGGER AS $$
+DECLARE
+    v_bounty_type TEXT;
+    v_bounty_amount INTEGER;
+BEGIN
+    SELECT bounty_type, COALESCE(bounty_amount_cents, 0)
+    INTO v_bounty_type, v_bounty_amount
+    FROM tournaments WHERE id = NEW.tournament_id;
+
+    IF v_bounty_type = 'progressive'
+       AND v_bounty_amount > 0
+       AND NEW.entry_type IN ('initial', 'rebuy', 're_entry') THEN
+        UPDATE tournament_registrations
+        SET current_bounty_cents = current_bounty_cents + v_bounty_amount,
+            updated_at = NOW()
+        WHERE tournament_id = NEW.tournament_id
+          AND club_player_id = NEW.club_player_id;
+    END IF;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+ALTER TABLE tournaments
+    ALTER COLUMN buy_in_cents TYPE INTEGER,
+    ALTER COLUMN rake_cents TYPE INTEGER,
+    ALTER COLUMN voucher_value_cents TYPE INTEGER,
+    ALTER COLUMN addon_price_cents TYPE INTEGER,
+    ALTER COLUMN bounty_amount_cents TYPE INTEGER;
+
+ALTER TABLE tournament_entries
+    ALTER COLUMN amount_cents TYPE INTEGER;
+
+ALTER TABLE tournament_results
+    ALTER COLUMN prize_cents TYPE INTEGER,
+    ALTER COLUMN bounty_winnings_cents TYPE INTEGER;
+
+ALTER TABLE tournament_payouts
+    ALTER COLUMN total_prize_pool TYPE INTEGER;
+
+ALTER TABLE player_deals
+    ALTER COLUMN total_amount_cents TYPE INTEGER;
+
+ALTER TABLE tournament_registrations
+    ALTER COLUMN current_bounty_cents TYPE INTEGER;
+
+ALTER TABLE tournament_bounties
+    ALTER COLUMN amount_cents TYPE INTEGER;
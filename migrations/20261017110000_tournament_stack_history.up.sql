@@ -0,0 +1,15 @@
+-- Audit trail of chip counts. Every stack change on a seat assignment appends a
+-- row so the floor can see how a player's stack evolved across breaks.
+CREATE TABLE tournament_stack_history (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tournament_id  UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    club_player_id UUID NOT NULL REFERENCES club_player(id),
+    previous_stack INTEGER,
+    stack_size     INTEGER NOT NULL CHECK (stack_size >= 0),
+    source         TEXT NOT NULL DEFAULT 'manual'
+        CHECK (source IN ('manual', 'break_count')),
+    recorded_by    UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_stack_history_player
+    ON tournament_stack_history (tournament_id, club_player_id, created_at);
@@ -0,0 +1,9 @@
+DROP TABLE IF EXISTS tournament_color_ups;
+
+DELETE FROM tournament_stack_history WHERE source = 'color_up';
+ALTER TABLE tournament_stack_history DROP CONSTRAINT tournament_stack_history_source_check;
+ALTER TABLE tournament_stack_history ADD CONSTRAINT tournament_stack_history_source_check
+    CHECK (source IN ('manual', 'break_count'));
+
+ALTER TABLE tournaments DROP COLUMN IF EXISTS chip_race_rule;
+ALTER TABLE tournament_structures DROP COLUMN IF EXISTS color_up_denomination;
@@ -0,0 +1,35 @@
+-- Color-up / chip race support.
+
+-- 1. The smallest chip in play from a level onwards. Set on the level at which
+--    the lower denomination is raced off (NULL = no color-up at this level).
+ALTER TABLE tournament_structures
+    ADD COLUMN color_up_denomination INTEGER CHECK (color_up_denomination > 0);
+
+-- 2. How odd chips are settled when coloring up:
+--    race          - odd chips at each table are pooled and raced off, at most
+--                    one new chip per player (TDA chip race)
+--    round_up      - every odd amount is rounded up to the next chip
+--    round_nearest - every stack is rounded to the nearest chip (halves up)
+ALTER TABLE tournaments
+    ADD COLUMN chip_race_rule TEXT NOT NULL DEFAULT 'race'
+        CHECK (chip_race_rule IN ('race', 'round_up', 'round_nearest'));
+
+-- 3. Stack history can now record chip-race adjustments.
+ALTER TABLE tournament_stack_history DROP CONSTRAINT tournament_stack_history_source_check;
+ALTER TABLE tournament_stack_history ADD CONSTRAINT tournament_stack_history_source_check
+    CHECK (source IN ('manual', 'break_count', 'color_up'));
+
+-- 4. One row per color-up performed, so it can't be applied twice and the
+--    chip totals before/after stay on record.
+CREATE TABLE tournament_color_ups (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tournament_id  UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    level_number   INTEGER NOT NULL,
+    denomination   INTEGER NOT NULL CHECK (denomination > 0),
+    race_rule      TEXT NOT NULL,
+    chips_before   BIGINT NOT NULL,
+    chips_after    BIGINT NOT NULL,
+    recorded_by    UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (tournament_id, level_number)
+);
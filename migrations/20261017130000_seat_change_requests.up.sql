@@ -0,0 +1,30 @@
+-- Player-initiated seat change requests (e.g. an accessibility need). The floor
+-- works through pending requests and approves (moving the player) or declines.
+CREATE TABLE seat_change_requests (
+    id                  UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tournament_id       UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    club_player_id      UUID NOT NULL REFERENCES club_player(id),
+    user_id             UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    reason              TEXT NOT NULL,
+    -- Seat held when the request was made.
+    from_club_table_id  UUID NOT NULL REFERENCES club_tables(id),
+    from_seat_number    INTEGER NOT NULL,
+    status              TEXT NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'approved', 'declined')),
+    decline_reason      TEXT,
+    resolved_by         UUID REFERENCES users(id) ON DELETE SET NULL,
+    resolved_at         TIMESTAMPTZ,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- At most one open request per player per tournament.
+CREATE UNIQUE INDEX uniq_pending_seat_change_request
+    ON seat_change_requests (tournament_id, club_player_id)
+    WHERE status = 'pending';
+CREATE INDEX idx_seat_change_requests_tournament
+    ON seat_change_requests (tournament_id, status, created_at);
+
+CREATE TRIGGER trg_seat_change_requests_updated_at
+    BEFORE UPDATE ON seat_change_requests
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
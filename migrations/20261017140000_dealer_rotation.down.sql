@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS dealer_pushes;
+DROP TABLE IF EXISTS tournament_dealers;
+DROP TABLE IF EXISTS club_staff;
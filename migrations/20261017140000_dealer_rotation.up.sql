@@ -0,0 +1,43 @@
+-- Dealer rotation.
+
+-- 1. Club staff roster. Staff may or may not have an app account; dealers are
+--    the first role with behaviour attached (rotation, down counts).
+CREATE TABLE club_staff (
+    id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    club_id       UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    display_name  TEXT NOT NULL,
+    user_id       UUID REFERENCES users(id) ON DELETE SET NULL,
+    role          TEXT NOT NULL DEFAULT 'dealer'
+        CHECK (role IN ('dealer', 'floor', 'cashier', 'other')),
+    is_active     BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_club_staff_club ON club_staff (club_id, is_active);
+
+CREATE TRIGGER trg_club_staff_updated_at
+    BEFORE UPDATE ON club_staff
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
+
+-- 2. The dealers working a tournament.
+CREATE TABLE tournament_dealers (
+    tournament_id  UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    staff_id       UUID NOT NULL REFERENCES club_staff(id) ON DELETE CASCADE,
+    added_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tournament_id, staff_id)
+);
+
+-- 3. The generated push schedule: one row per dealer per push. A NULL table
+--    is a break. Each row with a table is one "down" for payroll.
+CREATE TABLE dealer_pushes (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tournament_id  UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    staff_id       UUID NOT NULL REFERENCES club_staff(id) ON DELETE CASCADE,
+    club_table_id  UUID REFERENCES club_tables(id) ON DELETE CASCADE,
+    push_number    INTEGER NOT NULL CHECK (push_number >= 0),
+    starts_at      TIMESTAMPTZ NOT NULL,
+    ends_at        TIMESTAMPTZ NOT NULL CHECK (ends_at > starts_at),
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_dealer_pushes_tournament ON dealer_pushes (tournament_id, starts_at);
+CREATE INDEX idx_dealer_pushes_staff ON dealer_pushes (staff_id, starts_at);
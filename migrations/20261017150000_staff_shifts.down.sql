@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS staff_shifts;
+ALTER TABLE club_staff DROP COLUMN IF EXISTS hourly_rate_cents;
@@ -0,0 +1,34 @@
+-- Staff time clock.
+
+-- 1. Pay rate, snapshotted onto each shift at clock-in.
+ALTER TABLE club_staff
+    ADD COLUMN hourly_rate_cents BIGINT CHECK (hourly_rate_cents >= 0);
+
+-- 2. One row per shift. An open shift has no clock-out yet. A shift may be
+--    booked against a tournament so its cost lands in that night's figures.
+CREATE TABLE staff_shifts (
+    id                 UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    club_id            UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    staff_id           UUID NOT NULL REFERENCES club_staff(id) ON DELETE CASCADE,
+    tournament_id      UUID REFERENCES tournaments(id) ON DELETE SET NULL,
+    clock_in_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    clock_out_at       TIMESTAMPTZ,
+    hourly_rate_cents  BIGINT CHECK (hourly_rate_cents >= 0),
+    notes              TEXT,
+    recorded_by        UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at         TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at         TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (clock_out_at IS NULL OR clock_out_at > clock_in_at)
+);
+
+-- At most one open shift per staff member.
+CREATE UNIQUE INDEX uniq_open_staff_shift
+    ON staff_shifts (staff_id)
+    WHERE clock_out_at IS NULL;
+CREATE INDEX idx_staff_shifts_club ON staff_shifts (club_id, clock_in_at);
+CREATE INDEX idx_staff_shifts_tournament ON staff_shifts (tournament_id)
+    WHERE tournament_id IS NOT NULL;
+
+CREATE TRIGGER trg_staff_shifts_updated_at
+    BEFORE UPDATE ON staff_shifts
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
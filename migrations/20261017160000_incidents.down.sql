@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS player_exclusions;
+DROP TABLE IF EXISTS incident_attachments;
+DROP TABLE IF EXISTS incident_players;
+DROP TABLE IF EXISTS incidents;
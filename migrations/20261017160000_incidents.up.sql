@@ -0,0 +1,71 @@
+-- Incident reporting and player exclusions.
+
+-- 1. Incident reports filed by the floor.
+CREATE TABLE incidents (
+    id               UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    club_id          UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    tournament_id    UUID REFERENCES tournaments(id) ON DELETE SET NULL,
+    club_table_id    UUID REFERENCES club_tables(id) ON DELETE SET NULL,
+    category         TEXT NOT NULL
+        CHECK (category IN ('ruling_dispute', 'conduct', 'cheating', 'other')),
+    severity         TEXT NOT NULL
+        CHECK (severity IN ('low', 'medium', 'high', 'critical')),
+    description      TEXT NOT NULL,
+    -- open → under_review → resolved | dismissed
+    status           TEXT NOT NULL DEFAULT 'open'
+        CHECK (status IN ('open', 'under_review', 'resolved', 'dismissed')),
+    follow_up_notes  TEXT,
+    reported_by      UUID REFERENCES users(id) ON DELETE SET NULL,
+    review_outcome   TEXT
+        CHECK (review_outcome IN ('no_action', 'warning', 'penalty', 'exclusion')),
+    review_notes     TEXT,
+    reviewed_by      UUID REFERENCES users(id) ON DELETE SET NULL,
+    reviewed_at      TIMESTAMPTZ,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at       TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_incidents_club_status ON incidents (club_id, status, created_at);
+CREATE INDEX idx_incidents_tournament ON incidents (tournament_id)
+    WHERE tournament_id IS NOT NULL;
+
+CREATE TRIGGER trg_incidents_updated_at
+    BEFORE UPDATE ON incidents
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
+
+-- 2. Players involved in an incident.
+CREATE TABLE incident_players (
+    incident_id     UUID NOT NULL REFERENCES incidents(id) ON DELETE CASCADE,
+    club_player_id  UUID NOT NULL REFERENCES club_player(id) ON DELETE CASCADE,
+    PRIMARY KEY (incident_id, club_player_id)
+);
+CREATE INDEX idx_incident_players_player ON incident_players (club_player_id);
+
+-- 3. Supporting files (photos, video clips) — stored elsewhere, linked by URL.
+CREATE TABLE incident_attachments (
+    id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    incident_id   UUID NOT NULL REFERENCES incidents(id) ON DELETE CASCADE,
+    url           TEXT NOT NULL,
+    content_type  TEXT,
+    uploaded_by   UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_incident_attachments_incident ON incident_attachments (incident_id);
+
+-- 4. Club exclusions (bans). An exclusion blocks registration at the club
+--    until it ends (NULL = indefinite) or is lifted.
+CREATE TABLE player_exclusions (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    club_id         UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    club_player_id  UUID NOT NULL REFERENCES club_player(id) ON DELETE CASCADE,
+    incident_id     UUID REFERENCES incidents(id) ON DELETE SET NULL,
+    reason          TEXT NOT NULL,
+    starts_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    ends_at         TIMESTAMPTZ,
+    lifted_at       TIMESTAMPTZ,
+    lifted_by       UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_by      UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (ends_at IS NULL OR ends_at > starts_at)
+);
+CREATE INDEX idx_player_exclusions_player ON player_exclusions (club_player_id);
+CREATE INDEX idx_player_exclusions_club ON player_exclusions (club_id, created_at);
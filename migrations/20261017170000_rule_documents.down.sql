@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS rule_acknowledgments;
+DROP TABLE IF EXISTS rule_documents;
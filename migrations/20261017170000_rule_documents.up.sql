@@ -0,0 +1,39 @@
+-- House rules and disclosures, and players' acknowledgments of them.
+
+-- 1. Markdown documents attached to a club (tournament_id NULL: applies to
+--    every tournament at the club) or to a single tournament. Editing the
+--    title or body bumps `version`, so earlier acknowledgments no longer count.
+CREATE TABLE rule_documents (
+    id                        UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    club_id                   UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    tournament_id             UUID REFERENCES tournaments(id) ON DELETE CASCADE,
+    kind                      TEXT NOT NULL DEFAULT 'rules'
+        CHECK (kind IN ('rules', 'disclosure')),
+    title                     TEXT NOT NULL,
+    body_markdown             TEXT NOT NULL,
+    version                   INTEGER NOT NULL DEFAULT 1,
+    requires_acknowledgment   BOOLEAN NOT NULL DEFAULT TRUE,
+    is_active                 BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by                UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at                TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at                TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_rule_documents_club ON rule_documents (club_id, is_active);
+CREATE INDEX idx_rule_documents_tournament ON rule_documents (tournament_id)
+    WHERE tournament_id IS NOT NULL;
+
+CREATE TRIGGER trg_rule_documents_updated_at
+    BEFORE UPDATE ON rule_documents
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
+
+-- 2. One row per document version a player acknowledged when registering.
+CREATE TABLE rule_acknowledgments (
+    id                UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    rule_document_id  UUID NOT NULL REFERENCES rule_documents(id) ON DELETE CASCADE,
+    document_version  INTEGER NOT NULL,
+    tournament_id     UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    user_id           UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    acknowledged_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (rule_document_id, document_version, tournament_id, user_id)
+);
+CREATE INDEX idx_rule_acknowledgments_tournament ON rule_acknowledgments (tournament_id);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_clubs_organization;
+ALTER TABLE clubs DROP COLUMN IF EXISTS organization_id;
+DROP TABLE IF EXISTS organizations;
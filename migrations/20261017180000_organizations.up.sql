@@ -0,0 +1,20 @@
+-- Organizations: an optional layer above clubs for operators running several
+-- venues. A club with no organization behaves exactly as before.
+
+CREATE TABLE organizations (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name        TEXT NOT NULL,
+    -- URL-safe handle, unique across the platform.
+    slug        TEXT NOT NULL UNIQUE,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER trg_organizations_updated_at
+    BEFORE UPDATE ON organizations
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
+
+ALTER TABLE clubs
+    ADD COLUMN organization_id UUID REFERENCES organizations(id) ON DELETE SET NULL;
+CREATE INDEX idx_clubs_organization ON clubs (organization_id)
+    WHERE organization_id IS NOT NULL;
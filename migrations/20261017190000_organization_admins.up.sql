@@ -0,0 +1,12 @@
+-- Organization admins: users who run every club of an organization (create
+-- clubs, move managers, read consolidated finances) without being global
+-- admins.
+CREATE TABLE organization_admins (
+    id               UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id  UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    user_id          UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_by       UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (organization_id, user_id)
+);
+CREATE INDEX idx_organization_admins_user ON organization_admins (user_id);
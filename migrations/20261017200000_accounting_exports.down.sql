@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS accounting_exports;
+DROP FUNCTION IF EXISTS forbid_accounting_export_changes();
+DROP TABLE IF EXISTS club_accounting_settings;
@@ -0,0 +1,59 @@
+-- Accounting exports: journal lines (money taken in, rake revenue, prize
+-- liabilities, payouts) for a club's accountant.
+--
+-- Each club maps the journal to its chart of accounts and picks a CSV layout;
+-- the defaults follow the German SKR03 chart commonly used with DATEV.
+CREATE TABLE club_accounting_settings (
+    club_id                  UUID PRIMARY KEY REFERENCES clubs(id) ON DELETE CASCADE,
+    layout                   TEXT NOT NULL DEFAULT 'generic' CHECK (layout IN ('generic', 'datev')),
+    cash_account             TEXT NOT NULL DEFAULT '1000',
+    card_account             TEXT NOT NULL DEFAULT '1360',
+    bank_account             TEXT NOT NULL DEFAULT '1200',
+    promotion_account        TEXT NOT NULL DEFAULT '4600',
+    prize_liability_account  TEXT NOT NULL DEFAULT '1700',
+    rake_revenue_account     TEXT NOT NULL DEFAULT '8400',
+    created_at               TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at               TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER trg_club_accounting_settings_updated_at
+    BEFORE UPDATE ON club_accounting_settings
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
+
+-- A generated export is a snapshot of what was handed to the accountant: the
+-- rendered file is stored and never rewritten, even if entries or results are
+-- corrected later (the next export carries the correction).
+CREATE TABLE accounting_exports (
+    id           UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    club_id      UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    period_from  TIMESTAMPTZ NOT NULL,
+    period_to    TIMESTAMPTZ NOT NULL,
+    layout       TEXT NOT NULL CHECK (layout IN ('generic', 'datev')),
+    line_count   INTEGER NOT NULL,
+    total_cents  BIGINT NOT NULL,
+    content      TEXT NOT NULL,
+    created_by   UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (period_to > period_from)
+);
+CREATE INDEX idx_accounting_exports_club ON accounting_exports (club_id, created_at DESC);
+
+-- Only the author link may change (ON DELETE SET NULL); the snapshot itself
+-- is immutable.
+CREATE OR REPLACE FUNCTION forbid_accounting_export_changes()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF (NEW.id, NEW.club_id, NEW.period_from, NEW.period_to, NEW.layout,
+        NEW.line_count, NEW.total_cents, NEW.content, NEW.created_at)
+       IS DISTINCT FROM
+       (OLD.id, OLD.club_id, OLD.period_from, OLD.period_to, OLD.layout,
+        OLD.line_count, OLD.total_cents, OLD.content, OLD.created_at) THEN
+        RAISE EXCEPTION 'Accounting exports are immutable';
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_accounting_exports_immutable
+    BEFORE UPDATE ON accounting_exports
+    FOR EACH ROW EXECUTE PROCEDURE forbid_accounting_export_changes();
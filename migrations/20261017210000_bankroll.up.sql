@@ -0,0 +1,35 @@
+-- Personal bankroll tracker: a player's own ledger of buy-ins and cashes,
+-- both outside games they log by hand and tournaments played in this system
+-- (imported from their entries and results).
+--
+-- Rows belong to the player alone. Nothing here references a club, so club
+-- staff queries never reach it.
+CREATE TABLE bankroll_transactions (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id        UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    kind           TEXT NOT NULL CHECK (kind IN ('buy_in', 'cash')),
+    amount_cents   BIGINT NOT NULL CHECK (amount_cents >= 0),
+    occurred_at    TIMESTAMPTZ NOT NULL,
+    -- Free text for outside games ("Casino Namur", "Online").
+    venue          TEXT,
+    description    TEXT,
+    source         TEXT NOT NULL DEFAULT 'manual' CHECK (source IN ('manual', 'import')),
+    -- Set on imported rows; ON DELETE SET NULL keeps the player's history
+    -- when a club deletes a tournament.
+    tournament_id  UUID REFERENCES tournaments(id) ON DELETE SET NULL,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at     TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_bankroll_transactions_user_time
+    ON bankroll_transactions (user_id, occurred_at DESC);
+
+-- One imported buy-in and one imported cash per tournament, so re-running
+-- the import refreshes rather than duplicates.
+CREATE UNIQUE INDEX uq_bankroll_transactions_import
+    ON bankroll_transactions (user_id, tournament_id, kind)
+    WHERE source = 'import' AND tournament_id IS NOT NULL;
+
+CREATE TRIGGER trg_bankroll_transactions_updated_at
+    BEFORE UPDATE ON bankroll_transactions
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
@@ -0,0 +1,15 @@
+-- Following: a player keeps an eye on some of their friends' tournaments.
+-- One directed row per (follower, followee); following is only offered
+-- between accepted friends, and every read joins back to `friendship`, so
+-- unfriending silently stops the feed and notifications.
+CREATE TABLE friend_follow (
+    follower_id  UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    followee_id  UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    -- Rail notifications (final table, win, bust) are opt-in per followee.
+    notify       BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (follower_id, followee_id),
+    CHECK (follower_id <> followee_id)
+);
+
+CREATE INDEX friend_follow_followee_idx ON friend_follow (followee_id) WHERE notify;
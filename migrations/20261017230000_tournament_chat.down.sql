@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS tournament_chat_mutes;
+DROP TABLE IF EXISTS tournament_chat_messages;
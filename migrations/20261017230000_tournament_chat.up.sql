@@ -0,0 +1,27 @@
+-- Per-tournament chat for the field and the club's staff.
+--
+-- Moderated messages are soft-deleted so the channel's history (and who
+-- removed what) stays auditable; the body is blanked on read.
+CREATE TABLE tournament_chat_messages (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tournament_id  UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    user_id        UUID REFERENCES users(id) ON DELETE SET NULL,
+    body           TEXT NOT NULL CHECK (char_length(body) BETWEEN 1 AND 1000),
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at     TIMESTAMPTZ,
+    deleted_by     UUID REFERENCES users(id) ON DELETE SET NULL
+);
+
+CREATE INDEX idx_tournament_chat_messages_tournament
+    ON tournament_chat_messages (tournament_id, created_at DESC);
+
+-- A muted user can read but not post. `muted_until` NULL = until unmuted.
+CREATE TABLE tournament_chat_mutes (
+    tournament_id  UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    user_id        UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    muted_by       UUID REFERENCES users(id) ON DELETE SET NULL,
+    reason         TEXT,
+    muted_until    TIMESTAMPTZ,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tournament_id, user_id)
+);
@@ -0,0 +1,12 @@
+DROP INDEX IF EXISTS idx_announcements_pending;
+
+-- Pending broadcasts were never delivered; drop them rather than surface them.
+DELETE FROM announcements WHERE sent_at IS NULL;
+
+ALTER TABLE announcements
+    DROP CONSTRAINT IF EXISTS announcements_pinned_tournament,
+    DROP COLUMN IF EXISTS sent_at,
+    DROP COLUMN IF EXISTS scheduled_for,
+    DROP COLUMN IF EXISTS pinned,
+    DROP COLUMN IF EXISTS channels,
+    DROP COLUMN IF EXISTS priority;
@@ -0,0 +1,30 @@
+-- Floor broadcasts: high-priority tournament announcements fanned out over a
+-- chosen set of channels (in-app subscription, push, SMS), optionally pinned to
+-- the tournament lobby and optionally scheduled ("dinner break in 10 min").
+--
+-- Existing announcements keep their behaviour: normal priority, push only,
+-- unpinned and already sent at creation.
+ALTER TABLE announcements
+    ADD COLUMN priority      TEXT NOT NULL DEFAULT 'normal'
+        CHECK (priority IN ('normal', 'high')),
+    ADD COLUMN channels      TEXT[] NOT NULL DEFAULT ARRAY['push']::TEXT[]
+        CHECK (channels <@ ARRAY['in_app', 'push', 'sms']::TEXT[]
+               AND cardinality(channels) > 0),
+    ADD COLUMN pinned        BOOLEAN NOT NULL DEFAULT false,
+    -- When a scheduled broadcast is due; NULL for immediate ones.
+    ADD COLUMN scheduled_for TIMESTAMPTZ,
+    -- When it was delivered. NULL while a scheduled broadcast is pending: it is
+    -- hidden from players until the dispatcher claims and sends it.
+    ADD COLUMN sent_at       TIMESTAMPTZ;
+
+UPDATE announcements SET sent_at = created_at;
+
+ALTER TABLE announcements ALTER COLUMN sent_at SET DEFAULT NOW();
+
+-- Only tournament announcements can be pinned to a lobby.
+ALTER TABLE announcements ADD CONSTRAINT announcements_pinned_tournament
+    CHECK (NOT pinned OR scope = 'tournament');
+
+-- The dispatcher polls pending broadcasts by due time.
+CREATE INDEX idx_announcements_pending ON announcements (scheduled_for)
+    WHERE sent_at IS NULL;
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS raffle_entrants;
+DROP TABLE IF EXISTS raffles;
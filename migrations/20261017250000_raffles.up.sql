@@ -0,0 +1,50 @@
+-- Raffles drawn during a tournament (replacing paper tickets). A raffle is
+-- created open, then drawn exactly once: the eligible registrations are
+-- snapshotted into raffle_entrants in a canonical order, and winners are
+-- picked deterministically from a random seed so anyone holding the seed and
+-- the entrant list can replay the draw (see `draw.rs`).
+CREATE TABLE raffles (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tournament_id  UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    name           TEXT NOT NULL CHECK (char_length(name) BETWEEN 1 AND 120),
+    prize          TEXT,
+    -- Who gets a ticket: every confirmed registration, those who showed up
+    -- (checked in, seated or busted), or those still in the tournament.
+    eligibility    TEXT NOT NULL CHECK (eligibility IN ('registered', 'checked_in', 'active')),
+    winner_count   INTEGER NOT NULL CHECK (winner_count BETWEEN 1 AND 100),
+    status         TEXT NOT NULL DEFAULT 'open' CHECK (status IN ('open', 'drawn', 'cancelled')),
+    -- Audit trail, set when drawn.
+    algorithm      TEXT,
+    seed           TEXT,
+    entrants_hash  TEXT,
+    drawn_at       TIMESTAMPTZ,
+    drawn_by       UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_by     UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT raffles_drawn_audit CHECK (
+        status <> 'drawn'
+        OR (algorithm IS NOT NULL AND seed IS NOT NULL AND entrants_hash IS NOT NULL
+            AND drawn_at IS NOT NULL)
+    )
+);
+
+CREATE INDEX idx_raffles_tournament ON raffles (tournament_id, created_at);
+
+CREATE TRIGGER trg_raffles_updated_at
+    BEFORE UPDATE ON raffles
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
+
+-- The entrant snapshot taken at draw time. `position` is the canonical order
+-- the draw indexes into; `pick` is set (1 = first drawn) for winners.
+CREATE TABLE raffle_entrants (
+    raffle_id       UUID NOT NULL REFERENCES raffles(id) ON DELETE CASCADE,
+    registration_id UUID NOT NULL,
+    position        INTEGER NOT NULL,
+    user_id         UUID REFERENCES users(id) ON DELETE SET NULL,
+    display_name    TEXT NOT NULL,
+    pick            INTEGER,
+    PRIMARY KEY (raffle_id, registration_id),
+    UNIQUE (raffle_id, position),
+    UNIQUE (raffle_id, pick)
+);
@@ -0,0 +1,6 @@
+ALTER TABLE club_accounting_settings DROP COLUMN IF EXISTS jackpot_liability_account;
+
+DROP TABLE IF EXISTS promotion_jackpot_entries;
+DROP FUNCTION IF EXISTS forbid_promotion_jackpot_entry_changes();
+DROP TABLE IF EXISTS promotion_hands;
+DROP TABLE IF EXISTS promotion_jackpots;
@@ -0,0 +1,84 @@
+-- Promotions board: running high-hand and bad-beat jackpots per club.
+--
+-- A jackpot's balance is the sum of its ledger entries, which are append-only:
+-- the club seeds it, adds contributions (typically a drop taken from rake),
+-- and pays qualifying hands out of it. Floor staff record qualifying hands;
+-- paying one books a payout entry. The ledger also feeds the accounting
+-- journal (jackpot liability account).
+CREATE TABLE promotion_jackpots (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    club_id     UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    kind        TEXT NOT NULL CHECK (kind IN ('high_hand', 'bad_beat')),
+    name        TEXT NOT NULL CHECK (char_length(name) BETWEEN 1 AND 120),
+    -- Shown on the board for context, e.g. "Aces full or better".
+    rules       TEXT,
+    is_active   BOOLEAN NOT NULL DEFAULT true,
+    created_by  UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_promotion_jackpots_club ON promotion_jackpots (club_id);
+
+CREATE TRIGGER trg_promotion_jackpots_updated_at
+    BEFORE UPDATE ON promotion_jackpots
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
+
+CREATE TABLE promotion_hands (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    jackpot_id      UUID NOT NULL REFERENCES promotion_jackpots(id) ON DELETE CASCADE,
+    club_player_id  UUID REFERENCES club_player(id) ON DELETE SET NULL,
+    player_name     TEXT NOT NULL,
+    hand            TEXT NOT NULL CHECK (char_length(hand) BETWEEN 1 AND 200),
+    table_label     TEXT,
+    amount_cents    BIGINT NOT NULL CHECK (amount_cents > 0),
+    status          TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'paid', 'voided')),
+    recorded_by     UUID REFERENCES users(id) ON DELETE SET NULL,
+    paid_at         TIMESTAMPTZ,
+    paid_by         UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((status = 'paid') = (paid_at IS NOT NULL))
+);
+
+CREATE INDEX idx_promotion_hands_jackpot ON promotion_hands (jackpot_id, created_at DESC);
+
+-- The jackpot ledger. Seeds and contributions are positive, payouts negative.
+CREATE TABLE promotion_jackpot_entries (
+    id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    jackpot_id    UUID NOT NULL REFERENCES promotion_jackpots(id) ON DELETE CASCADE,
+    kind          TEXT NOT NULL CHECK (kind IN ('seed', 'contribution', 'payout')),
+    amount_cents  BIGINT NOT NULL,
+    hand_id       UUID UNIQUE REFERENCES promotion_hands(id),
+    note          TEXT,
+    created_by    UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((kind = 'payout') = (amount_cents < 0) AND amount_cents <> 0),
+    CHECK ((kind = 'payout') = (hand_id IS NOT NULL))
+);
+
+CREATE INDEX idx_promotion_jackpot_entries_jackpot
+    ON promotion_jackpot_entries (jackpot_id, created_at);
+
+-- Only the author link may change (ON DELETE SET NULL); entries are never
+-- rewritten. Corrections are new entries.
+CREATE OR REPLACE FUNCTION forbid_promotion_jackpot_entry_changes()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF (NEW.id, NEW.jackpot_id, NEW.kind, NEW.amount_cents, NEW.hand_id, NEW.note,
+        NEW.created_at)
+       IS DISTINCT FROM
+       (OLD.id, OLD.jackpot_id, OLD.kind, OLD.amount_cents, OLD.hand_id, OLD.note,
+        OLD.created_at) THEN
+        RAISE EXCEPTION 'Jackpot ledger entries are immutable';
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_promotion_jackpot_entries_immutable
+    BEFORE UPDATE ON promotion_jackpot_entries
+    FOR EACH ROW EXECUTE PROCEDURE forbid_promotion_jackpot_entry_changes();
+
+-- Jackpot money is held for players until paid out.
+ALTER TABLE club_accounting_settings
+    ADD COLUMN jackpot_liability_account TEXT NOT NULL DEFAULT '1710';
@@ -0,0 +1,14 @@
+-- Printable PDFs for the tournament floor: per-table seat cards and the
+-- alphabetical seat list (generated after the seat draw) and the payout sheet
+-- (generated when results are entered). One current document per kind:
+-- regenerating replaces it. Downloads go through short-lived signed URLs.
+CREATE TABLE tournament_printouts (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tournament_id  UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    kind           TEXT NOT NULL CHECK (kind IN ('seat_cards', 'seat_list', 'payout_sheet')),
+    content        BYTEA NOT NULL,
+    page_count     INTEGER NOT NULL CHECK (page_count > 0),
+    generated_by   UUID REFERENCES users(id) ON DELETE SET NULL,
+    generated_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (tournament_id, kind)
+);
@@ -0,0 +1,26 @@
+-- Buy-in receipts for the desk's thermal printer. A receipt is issued when the
+-- entry is added and snapshots what was paid, so reprints always match the
+-- original even if names change later. The verification code is printed as a
+-- QR code; deleting the entry keeps the receipt (entry_id becomes NULL) so a
+-- scanned receipt shows as voided instead of unknown.
+CREATE TABLE entry_receipts (
+    id                 UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    entry_id           UUID UNIQUE REFERENCES tournament_entries(id) ON DELETE SET NULL,
+    tournament_id      UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    club_id            UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    verification_code  TEXT NOT NULL UNIQUE,
+    club_name          TEXT NOT NULL,
+    tournament_name    TEXT NOT NULL,
+    player_name        TEXT NOT NULL,
+    entry_type         TEXT NOT NULL,
+    payment_method     TEXT NOT NULL,
+    amount_cents       BIGINT NOT NULL,
+    voucher_cents      BIGINT NOT NULL DEFAULT 0,
+    chips_received     INTEGER,
+    issued_by          UUID REFERENCES users(id) ON DELETE SET NULL,
+    issued_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    print_count        INTEGER NOT NULL DEFAULT 0,
+    last_printed_at    TIMESTAMPTZ
+);
+
+CREATE INDEX idx_entry_receipts_tournament ON entry_receipts(tournament_id);
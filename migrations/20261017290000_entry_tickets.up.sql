@@ -0,0 +1,25 @@
+-- Signed QR tickets for seat-granting entries (buy-ins and re-entries). The QR
+-- carries the ticket id and an HMAC, so forgeries fail before touching the
+-- database; the row tracks the lifecycle so a second scan of the same ticket
+-- is flagged as a duplicate. Lost tickets are voided and reissued, hence at
+-- most one live ticket per entry rather than one ticket per entry.
+CREATE TABLE entry_tickets (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    entry_id        UUID REFERENCES tournament_entries(id) ON DELETE SET NULL,
+    tournament_id   UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    club_id         UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    club_player_id  UUID NOT NULL REFERENCES club_player(id) ON DELETE CASCADE,
+    status          TEXT NOT NULL DEFAULT 'issued' CHECK (status IN ('issued', 'verified', 'voided')),
+    scan_count      INTEGER NOT NULL DEFAULT 0,
+    issued_by       UUID REFERENCES users(id) ON DELETE SET NULL,
+    issued_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    verified_by     UUID REFERENCES users(id) ON DELETE SET NULL,
+    verified_at     TIMESTAMPTZ,
+    voided_by       UUID REFERENCES users(id) ON DELETE SET NULL,
+    voided_at       TIMESTAMPTZ,
+    void_reason     TEXT
+);
+
+CREATE UNIQUE INDEX idx_entry_tickets_live_entry ON entry_tickets(entry_id)
+    WHERE status <> 'voided';
+CREATE INDEX idx_entry_tickets_tournament ON entry_tickets(tournament_id);
@@ -0,0 +1,3 @@
+ALTER TABLE tournaments
+    DROP COLUMN IF EXISTS invite_token,
+    DROP COLUMN IF EXISTS visibility;
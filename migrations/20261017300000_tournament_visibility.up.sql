@@ -0,0 +1,8 @@
+-- Who can find and register for a tournament: everyone (public feeds),
+-- players on the club's roster (members_only), or only holders of the direct
+-- invite link (unlisted). The invite token is minted when a tournament first
+-- becomes unlisted and can be rotated to revoke old links.
+ALTER TABLE tournaments
+    ADD COLUMN visibility TEXT NOT NULL DEFAULT 'public'
+        CHECK (visibility IN ('public', 'members_only', 'unlisted')),
+    ADD COLUMN invite_token TEXT UNIQUE;
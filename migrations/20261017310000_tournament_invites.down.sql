@@ -0,0 +1,2 @@
+ALTER TABLE tournament_registrations DROP COLUMN IF EXISTS invite_id;
+DROP TABLE IF EXISTS tournament_invites;
@@ -0,0 +1,23 @@
+-- Invite links for private tournaments. The link carries the invite id and an
+-- HMAC, so made-up links fail before touching the database; the row holds the
+-- limits (use count, expiry) and can be revoked. Registrations remember the
+-- invite they came through.
+CREATE TABLE tournament_invites (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tournament_id   UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    label           TEXT,
+    max_uses        INTEGER CHECK (max_uses IS NULL OR max_uses > 0),
+    use_count       INTEGER NOT NULL DEFAULT 0,
+    expires_at      TIMESTAMPTZ,
+    revoked_at      TIMESTAMPTZ,
+    created_by      UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_tournament_invites_tournament ON tournament_invites(tournament_id);
+
+ALTER TABLE tournament_registrations
+    ADD COLUMN invite_id UUID REFERENCES tournament_invites(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_tournament_registrations_invite ON tournament_registrations(invite_id)
+    WHERE invite_id IS NOT NULL;
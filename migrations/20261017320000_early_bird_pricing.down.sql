@@ -0,0 +1,5 @@
+ALTER TABLE tournament_entries DROP COLUMN IF EXISTS price_tier;
+
+ALTER TABLE tournaments
+    DROP COLUMN IF EXISTS early_bird_until,
+    DROP COLUMN IF EXISTS early_bird_buy_in_cents;
@@ -0,0 +1,9 @@
+-- Tiered buy-in pricing: an early-bird price that applies to players who
+-- registered before the cut-off, the regular buy_in_cents after it. Entries
+-- record the tier they were charged at so the two can be reported apart.
+ALTER TABLE tournaments
+    ADD COLUMN early_bird_buy_in_cents BIGINT CHECK (early_bird_buy_in_cents >= 0),
+    ADD COLUMN early_bird_until TIMESTAMPTZ;
+
+ALTER TABLE tournament_entries
+    ADD COLUMN price_tier TEXT CHECK (price_tier IN ('early_bird', 'regular'));
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS buy_in_credit_ledger;
+DROP TABLE IF EXISTS buy_in_credits;
@@ -0,0 +1,42 @@
+-- Prepaid buy-ins: packages ("5 tournament bundle") sold to a player and
+-- single-use vouchers handed out by the club. Each credit has a redemption
+-- code the player enters when registering. Uses live in a ledger
+-- (issue +N, redemption -1, refund +1), so the remaining count is
+-- SUM(delta) and every movement stays auditable.
+CREATE TABLE buy_in_credits (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    club_id         UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    kind            TEXT NOT NULL CHECK (kind IN ('package', 'voucher')),
+    code            TEXT NOT NULL UNIQUE,
+    label           TEXT NOT NULL,
+    -- Owner; NULL for a bearer voucher until its first redemption claims it.
+    club_player_id  UUID REFERENCES club_player(id) ON DELETE SET NULL,
+    total_uses      INTEGER NOT NULL CHECK (total_uses > 0),
+    expires_at      TIMESTAMPTZ,
+    issued_by       UUID REFERENCES users(id) ON DELETE SET NULL,
+    issued_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    voided_by       UUID REFERENCES users(id) ON DELETE SET NULL,
+    voided_at       TIMESTAMPTZ
+);
+
+CREATE INDEX idx_buy_in_credits_club ON buy_in_credits(club_id);
+CREATE INDEX idx_buy_in_credits_player ON buy_in_credits(club_player_id);
+
+CREATE TABLE buy_in_credit_ledger (
+    id               UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    credit_id        UUID NOT NULL REFERENCES buy_in_credits(id) ON DELETE CASCADE,
+    kind             TEXT NOT NULL CHECK (kind IN ('issue', 'redemption', 'refund')),
+    delta            INTEGER NOT NULL,
+    tournament_id    UUID REFERENCES tournaments(id) ON DELETE SET NULL,
+    registration_id  UUID REFERENCES tournament_registrations(id) ON DELETE SET NULL,
+    -- The buy-in entry a redemption paid for, once the desk records it.
+    entry_id         UUID REFERENCES tournament_entries(id) ON DELETE SET NULL,
+    -- The redemption a refund gives back.
+    reverses_id      UUID REFERENCES buy_in_credit_ledger(id) ON DELETE SET NULL,
+    created_by       UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_buy_in_credit_ledger_credit ON buy_in_credit_ledger(credit_id);
+CREATE INDEX idx_buy_in_credit_ledger_registration ON buy_in_credit_ledger(registration_id)
+    WHERE registration_id IS NOT NULL;
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS registration_group_members;
+DROP TABLE IF EXISTS registration_groups;
+DROP TABLE IF EXISTS club_seating_settings;
@@ -0,0 +1,32 @@
+-- Group bookings: friends link their registrations with a short code. The
+-- club decides what the seat draw does with a group: seat it together, or
+-- (for clubs that want a fair field) guarantee its members are split.
+CREATE TABLE club_seating_settings (
+    club_id         UUID PRIMARY KEY REFERENCES clubs(id) ON DELETE CASCADE,
+    group_policy    TEXT NOT NULL DEFAULT 'together' CHECK (group_policy IN ('together', 'apart')),
+    max_group_size  INTEGER NOT NULL DEFAULT 4 CHECK (max_group_size BETWEEN 2 AND 10),
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER trg_club_seating_settings_updated_at
+    BEFORE UPDATE ON club_seating_settings
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
+
+CREATE TABLE registration_groups (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tournament_id   UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    code            TEXT NOT NULL,
+    created_by      UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (tournament_id, code)
+);
+
+-- A registration is in at most one group.
+CREATE TABLE registration_group_members (
+    registration_id UUID PRIMARY KEY REFERENCES tournament_registrations(id) ON DELETE CASCADE,
+    group_id        UUID NOT NULL REFERENCES registration_groups(id) ON DELETE CASCADE,
+    joined_at       TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_registration_group_members_group ON registration_group_members(group_id);
@@ -0,0 +1,34 @@
+-- Questions an organizer asks at registration (accessibility needs, food
+-- choice for an included dinner, ...) and each registration's answers.
+CREATE TABLE registration_questions (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tournament_id   UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    kind            TEXT NOT NULL
+        CHECK (kind IN ('single_choice', 'multi_choice', 'yes_no', 'text')),
+    prompt          TEXT NOT NULL,
+    -- The choices of a single- or multi-choice question; empty otherwise.
+    options         TEXT[] NOT NULL DEFAULT '{}',
+    is_required     BOOLEAN NOT NULL DEFAULT FALSE,
+    position        INTEGER NOT NULL DEFAULT 0,
+    -- Archived questions keep their answers but are no longer asked.
+    is_active       BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_registration_questions_tournament
+    ON registration_questions (tournament_id, position);
+
+CREATE TRIGGER trg_registration_questions_updated_at
+    BEFORE UPDATE ON registration_questions
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
+
+-- One answer per registration and question: the chosen options, `yes`/`no`,
+-- or the free text, as a list.
+CREATE TABLE registration_answers (
+    registration_id  UUID NOT NULL REFERENCES tournament_registrations(id) ON DELETE CASCADE,
+    question_id      UUID NOT NULL REFERENCES registration_questions(id) ON DELETE CASCADE,
+    answer_values    TEXT[] NOT NULL,
+    answered_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (registration_id, question_id)
+);
+CREATE INDEX idx_registration_answers_question ON registration_answers (question_id);
@@ -0,0 +1,23 @@
+ALTER TABLE registration_answers ADD COLUMN answer_values TEXT[];
+UPDATE registration_answers a SET answer_values = CASE jsonb_typeof(a.answer)
+    WHEN 'array' THEN ARRAY(SELECT jsonb_array_elements_text(a.answer))
+    WHEN 'boolean' THEN ARRAY[CASE WHEN a.answer = 'true'::jsonb THEN 'yes' ELSE 'no' END]
+    ELSE ARRAY[a.answer #>> '{}']
+END;
+ALTER TABLE registration_answers
+    ALTER COLUMN answer_values SET NOT NULL,
+    DROP COLUMN answer;
+
+ALTER TABLE registration_questions
+    DROP CONSTRAINT registration_questions_kind_check,
+    DROP COLUMN max_length,
+    DROP COLUMN help_text;
+UPDATE registration_questions SET kind = CASE kind
+    WHEN 'select' THEN 'single_choice'
+    WHEN 'multi_select' THEN 'multi_choice'
+    WHEN 'checkbox' THEN 'yes_no'
+    ELSE kind
+END;
+ALTER TABLE registration_questions
+    ADD CONSTRAINT registration_questions_kind_check
+        CHECK (kind IN ('single_choice', 'multi_choice', 'yes_no', 'text'));
@@ -0,0 +1,28 @@
+-- Registration questions become form fields: text, select, multi-select and
+-- checkbox, with help text and a length limit for text fields. Answers are
+-- stored as JSONB typed by the field: a string for text and select, an array
+-- of strings for multi-select, a boolean for checkbox.
+ALTER TABLE registration_questions DROP CONSTRAINT registration_questions_kind_check;
+UPDATE registration_questions SET kind = CASE kind
+    WHEN 'single_choice' THEN 'select'
+    WHEN 'multi_choice' THEN 'multi_select'
+    WHEN 'yes_no' THEN 'checkbox'
+    ELSE kind
+END;
+ALTER TABLE registration_questions
+    ADD CONSTRAINT registration_questions_kind_check
+        CHECK (kind IN ('text', 'select', 'multi_select', 'checkbox')),
+    ADD COLUMN help_text TEXT,
+    -- Longest accepted text answer; NULL uses the default.
+    ADD COLUMN max_length INTEGER CHECK (max_length BETWEEN 1 AND 2000);
+
+ALTER TABLE registration_answers ADD COLUMN answer JSONB;
+UPDATE registration_answers a SET answer = CASE q.kind
+    WHEN 'multi_select' THEN to_jsonb(a.answer_values)
+    WHEN 'checkbox' THEN to_jsonb(a.answer_values[1] = 'yes')
+    ELSE to_jsonb(a.answer_values[1])
+END
+FROM registration_questions q WHERE q.id = a.question_id;
+ALTER TABLE registration_answers
+    ALTER COLUMN answer SET NOT NULL,
+    DROP COLUMN answer_values;
@@ -0,0 +1,4 @@
+DROP TRIGGER IF EXISTS trg_registrations_eliminated_at ON tournament_registrations;
+DROP FUNCTION IF EXISTS stamp_registration_eliminated_at();
+DROP INDEX IF EXISTS idx_tournament_registrations_eliminated;
+ALTER TABLE tournament_registrations DROP COLUMN IF EXISTS eliminated_at;
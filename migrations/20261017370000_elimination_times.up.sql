@@ -0,0 +1,35 @@
+-- When each player busted, for the live elimination pace and projected
+-- finish. Stamped by trigger so every path that sets the status (seating,
+-- imports, status edits) records it; reverting a bust clears it.
+ALTER TABLE tournament_registrations ADD COLUMN eliminated_at TIMESTAMPTZ;
+
+-- Past busts: the seat closed with an empty stack, else the last status edit.
+UPDATE tournament_registrations r
+SET eliminated_at = COALESCE(
+    (SELECT MAX(s.unassigned_at) FROM table_seat_assignments s
+     WHERE s.tournament_id = r.tournament_id AND s.club_player_id = r.club_player_id
+       AND s.stack_size = 0 AND s.unassigned_at IS NOT NULL),
+    r.updated_at
+)
+WHERE r.status = 'busted';
+
+CREATE INDEX idx_tournament_registrations_eliminated
+    ON tournament_registrations (tournament_id, eliminated_at)
+    WHERE eliminated_at IS NOT NULL;
+
+CREATE FUNCTION stamp_registration_eliminated_at() RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.status = 'busted' THEN
+        IF TG_OP = 'INSERT' OR OLD.status IS DISTINCT FROM 'busted' THEN
+            NEW.eliminated_at := COALESCE(NEW.eliminated_at, NOW());
+        END IF;
+    ELSE
+        NEW.eliminated_at := NULL;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_registrations_eliminated_at
+    BEFORE INSERT OR UPDATE OF status ON tournament_registrations
+    FOR EACH ROW EXECUTE PROCEDURE stamp_registration_eliminated_at();
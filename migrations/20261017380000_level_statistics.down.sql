@@ -0,0 +1,21 @@
+CREATE OR REPLACE FUNCTION stamp_registration_eliminated_at() RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.status = 'busted' THEN
+        IF TG_OP = 'INSERT' OR OLD.status IS DISTINCT FROM 'busted' THEN
+            NEW.eliminated_at := COALESCE(NEW.eliminated_at, NOW());
+        END IF;
+    ELSE
+        NEW.eliminated_at := NULL;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_stack_history_level ON tournament_stack_history;
+DROP TRIGGER IF EXISTS trg_tournament_entries_level ON tournament_entries;
+DROP FUNCTION IF EXISTS stamp_level_number();
+DROP FUNCTION IF EXISTS tournament_current_level(UUID);
+DROP INDEX IF EXISTS idx_tournament_entries_level;
+ALTER TABLE tournament_stack_history DROP COLUMN IF EXISTS level_number;
+ALTER TABLE tournament_entries DROP COLUMN IF EXISTS level_number;
+ALTER TABLE tournament_registrations DROP COLUMN IF EXISTS eliminated_level;
@@ -0,0 +1,48 @@
+-- The level each bust, entry and stack count happened at, for per-level
+-- structure analytics. Stamped by trigger from the tournament clock; NULL
+-- before the clock first starts, and for rows recorded before this migration.
+ALTER TABLE tournament_registrations ADD COLUMN eliminated_level INTEGER;
+ALTER TABLE tournament_entries ADD COLUMN level_number INTEGER;
+ALTER TABLE tournament_stack_history ADD COLUMN level_number INTEGER;
+
+CREATE INDEX idx_tournament_entries_level ON tournament_entries (tournament_id, level_number);
+
+-- The level being played, once the clock has been started.
+CREATE FUNCTION tournament_current_level(p_tournament_id UUID) RETURNS INTEGER AS $$
+    SELECT current_level FROM tournament_clocks
+    WHERE tournament_id = p_tournament_id
+      AND (clock_status <> 'stopped' OR level_started_at IS NOT NULL)
+$$ LANGUAGE sql STABLE;
+
+CREATE FUNCTION stamp_level_number() RETURNS TRIGGER AS $$
+BEGIN
+    NEW.level_number := COALESCE(NEW.level_number, tournament_current_level(NEW.tournament_id));
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_tournament_entries_level
+    BEFORE INSERT ON tournament_entries
+    FOR EACH ROW EXECUTE PROCEDURE stamp_level_number();
+
+CREATE TRIGGER trg_stack_history_level
+    BEFORE INSERT ON tournament_stack_history
+    FOR EACH ROW EXECUTE PROCEDURE stamp_level_number();
+
+-- Busts now record their level alongside their time.
+CREATE OR REPLACE FUNCTION stamp_registration_eliminated_at() RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.status = 'busted' THEN
+        IF TG_OP = 'INSERT' OR OLD.status IS DISTINCT FROM 'busted' THEN
+            NEW.eliminated_at := COALESCE(NEW.eliminated_at, NOW());
+            NEW.eliminated_level := COALESCE(
+                NEW.eliminated_level, tournament_current_level(NEW.tournament_id)
+            );
+        END IF;
+    ELSE
+        NEW.eliminated_at := NULL;
+        NEW.eliminated_level := NULL;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1 @@
+ALTER TABLE tournament_registrations DROP COLUMN IF EXISTS last_seen_at;
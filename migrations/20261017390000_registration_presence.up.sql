@@ -0,0 +1,3 @@
+-- Last heartbeat from the player's phone while at the venue, so managers can
+-- tell who has arrived before the start and chase no-shows.
+ALTER TABLE tournament_registrations ADD COLUMN last_seen_at TIMESTAMPTZ;
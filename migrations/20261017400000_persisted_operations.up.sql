@@ -0,0 +1,14 @@
+-- Operations each API client may run when the server is locked to an
+-- allow-list (GQL_OPERATION_ALLOWLIST=true). Keyed by the SHA-256 of the
+-- exact query text the client sends.
+CREATE TABLE persisted_operations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    client_name TEXT NOT NULL,
+    operation_name TEXT,
+    sha256_hash TEXT NOT NULL,
+    query TEXT NOT NULL,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    revoked_at TIMESTAMPTZ,
+    UNIQUE (client_name, sha256_hash)
+);
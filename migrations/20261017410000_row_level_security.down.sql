@@ -0,0 +1,20 @@
+DO $$
+DECLARE
+    p RECORD;
+BEGIN
+    FOR p IN
+        SELECT schemaname, tablename, policyname
+        FROM pg_policies
+        WHERE schemaname = 'public' AND policyname IN ('club_isolation', 'user_isolation')
+    LOOP
+        EXECUTE format('DROP POLICY %I ON %I.%I', p.policyname, p.schemaname, p.tablename);
+        EXECUTE format('ALTER TABLE %I.%I NO FORCE ROW LEVEL SECURITY', p.schemaname, p.tablename);
+        EXECUTE format('ALTER TABLE %I.%I DISABLE ROW LEVEL SECURITY', p.schemaname, p.tablename);
+    END LOOP;
+END;
+$$;
+
+DROP FUNCTION IF EXISTS enable_user_isolation(REGCLASS);
+DROP FUNCTION IF EXISTS enable_club_isolation(REGCLASS);
+DROP FUNCTION IF EXISTS app_user_scope();
+DROP FUNCTION IF EXISTS app_club_scope();
@@ -0,0 +1,75 @@
+-- Row-level club and user isolation, as defense in depth behind the
+-- resolvers' own checks. A request that runs its queries through
+-- infra::db::begin_scoped sets `app.club_id` / `app.user_id` for its
+-- transaction; the policies then hide (and refuse to write) rows of any
+-- other club or user. Connections with no scope set see every row, so
+-- background services, admin tools and unscoped code paths are unaffected.
+--
+-- FORCE applies the policies to the table owner too. Superusers and roles
+-- with BYPASSRLS always bypass them, so the API should connect as an
+-- ordinary (owner) role for these to take effect.
+
+CREATE OR REPLACE FUNCTION app_club_scope() RETURNS UUID
+LANGUAGE sql STABLE AS $$
+    SELECT NULLIF(current_setting('app.club_id', true), '')::uuid
+$$;
+
+CREATE OR REPLACE FUNCTION app_user_scope() RETURNS UUID
+LANGUAGE sql STABLE AS $$
+    SELECT NULLIF(current_setting('app.user_id', true), '')::uuid
+$$;
+
+-- Isolate a table with a `club_id` column by club. Rows without a club
+-- (shared templates, global announcements) stay visible to every club.
+-- Call this from the migration that creates a new club-scoped table.
+CREATE OR REPLACE FUNCTION enable_club_isolation(tbl REGCLASS) RETURNS VOID
+LANGUAGE plpgsql AS $$
+BEGIN
+    EXECUTE format('ALTER TABLE %s ENABLE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('ALTER TABLE %s FORCE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('DROP POLICY IF EXISTS club_isolation ON %s', tbl);
+    EXECUTE format(
+        'CREATE POLICY club_isolation ON %s
+             USING (app_club_scope() IS NULL OR club_id IS NULL OR club_id = app_club_scope())',
+        tbl
+    );
+END;
+$$;
+
+-- Isolate a table of a user's private rows (`user_id`) by user.
+CREATE OR REPLACE FUNCTION enable_user_isolation(tbl REGCLASS) RETURNS VOID
+LANGUAGE plpgsql AS $$
+BEGIN
+    EXECUTE format('ALTER TABLE %s ENABLE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('ALTER TABLE %s FORCE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('DROP POLICY IF EXISTS user_isolation ON %s', tbl);
+    EXECUTE format(
+        'CREATE POLICY user_isolation ON %s
+             USING (app_user_scope() IS NULL OR user_id = app_user_scope())',
+        tbl
+    );
+END;
+$$;
+
+-- Every existing club-scoped table.
+DO $$
+DECLARE
+    t REGCLASS;
+BEGIN
+    FOR t IN
+        SELECT format('%I.%I', c.table_schema, c.table_name)::regclass
+        FROM information_schema.columns c
+        JOIN information_schema.tables tb USING (table_schema, table_name)
+        WHERE c.table_schema = 'public'
+          AND c.column_name = 'club_id'
+          AND tb.table_type = 'BASE TABLE'
+    LOOP
+        PERFORM enable_club_isolation(t);
+    END LOOP;
+END;
+$$;
+
+-- Rows only their owner ever reads.
+SELECT enable_user_isolation('bankroll_transactions');
+SELECT enable_user_isolation('device_tokens');
+SELECT enable_user_isolation('notification_preferences');
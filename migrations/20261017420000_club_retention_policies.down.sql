@@ -0,0 +1,6 @@
+CREATE OR REPLACE RULE activity_log_no_update AS ON UPDATE TO tournament_activity_log
+    DO INSTEAD NOTHING;
+CREATE OR REPLACE RULE activity_log_no_delete AS ON DELETE TO tournament_activity_log
+    DO INSTEAD NOTHING;
+
+DROP TABLE IF EXISTS club_retention_policies;
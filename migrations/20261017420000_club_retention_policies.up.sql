@@ -0,0 +1,27 @@
+-- Per-club retention of operational records. Each window is in months;
+-- NULL keeps that kind of record forever. `mode` decides whether expired
+-- records are deleted or only stripped of who they were about. Applied by
+-- the data-retention job (ENABLE_DATA_RETENTION). Money-related activity
+-- (entries, results) is never touched: it backs the club's accounts.
+CREATE TABLE club_retention_policies (
+    club_id              UUID PRIMARY KEY REFERENCES clubs(id) ON DELETE CASCADE,
+    mode                 TEXT NOT NULL DEFAULT 'anonymize' CHECK (mode IN ('anonymize', 'purge')),
+    activity_log_months  INTEGER CHECK (activity_log_months >= 1),
+    announcement_months  INTEGER CHECK (announcement_months >= 1),
+    chat_message_months  INTEGER CHECK (chat_message_months >= 1),
+    last_applied_at      TIMESTAMPTZ,
+    updated_by           UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at           TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at           TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+SELECT enable_club_isolation('club_retention_policies');
+
+-- The activity log stays append-only, except inside a retention sweep's
+-- transaction (SET LOCAL app.retention_sweep = 'on').
+CREATE OR REPLACE RULE activity_log_no_update AS ON UPDATE TO tournament_activity_log
+    WHERE current_setting('app.retention_sweep', true) IS DISTINCT FROM 'on'
+    DO INSTEAD NOTHING;
+CREATE OR REPLACE RULE activity_log_no_delete AS ON DELETE TO tournament_activity_log
+    WHERE current_setting('app.retention_sweep', true) IS DISTINCT FROM 'on'
+    DO INSTEAD NOTHING;
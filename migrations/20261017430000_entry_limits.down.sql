@@ -0,0 +1,2 @@
+DROP TRIGGER IF EXISTS trg_tournament_entries_limits ON tournament_entries;
+DROP FUNCTION IF EXISTS enforce_entry_limits();
@@ -0,0 +1,50 @@
+-- Entry rules the desk used to be trusted with, enforced by the database so
+-- concurrent desks, offline replays and imports can't break them:
+--
+-- - no entries of any kind once the tournament is finished;
+-- - no more rebuys per player than the tournament's rebuy_max (NULL: no
+--   limit). Rebuys of one player are serialized with an advisory lock so two
+--   desks can't both take the last one.
+--
+-- One initial buy-in per player is already uniq_initial_entry_per_player.
+-- Violations raise check_violation naming a pseudo-constraint, which the API
+-- maps to an error code like a real constraint.
+CREATE FUNCTION enforce_entry_limits() RETURNS TRIGGER AS $$
+DECLARE
+    v_status tournament_live_status;
+    v_rebuy_max INTEGER;
+    v_rebuys BIGINT;
+BEGIN
+    SELECT live_status, rebuy_max INTO v_status, v_rebuy_max
+    FROM tournaments WHERE id = NEW.tournament_id;
+
+    IF v_status = 'finished' THEN
+        RAISE EXCEPTION 'Tournament % is finished', NEW.tournament_id
+            USING ERRCODE = 'check_violation',
+                  CONSTRAINT = 'tournament_entries_tournament_open';
+    END IF;
+
+    IF NEW.entry_type = 'rebuy' AND v_rebuy_max IS NOT NULL THEN
+        PERFORM pg_advisory_xact_lock(
+            hashtextextended(NEW.tournament_id::text || ':' || NEW.club_player_id::text, 0)
+        );
+        SELECT COUNT(*) INTO v_rebuys
+        FROM tournament_entries
+        WHERE tournament_id = NEW.tournament_id
+          AND club_player_id = NEW.club_player_id
+          AND entry_type = 'rebuy';
+        IF v_rebuys >= v_rebuy_max THEN
+            RAISE EXCEPTION 'Player has used all % rebuys', v_rebuy_max
+                USING ERRCODE = 'check_violation',
+                      CONSTRAINT = 'tournament_entries_rebuy_limit';
+        END IF;
+    END IF;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Named to fire after trg_link_club_player_entries has stamped club_player_id.
+CREATE TRIGGER trg_tournament_entries_limits
+    BEFORE INSERT ON tournament_entries
+    FOR EACH ROW EXECUTE PROCEDURE enforce_entry_limits();
@@ -0,0 +1,2 @@
+ALTER TABLE tournament_registrations
+    DROP CONSTRAINT IF EXISTS tournament_registrations_tournament_id_user_id_key;
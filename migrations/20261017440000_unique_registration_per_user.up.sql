@@ -0,0 +1,35 @@
+-- One registration per app user per tournament. The (tournament_id,
+-- club_player_id) key already implies it while every account maps to one
+-- roster entry per club; this states it on the column registration is keyed
+-- by, so a double-submitted registerForTournament conflicts instead of
+-- racing. Account-less roster players (user_id NULL) are unaffected.
+--
+-- Pre-existing duplicates are not collapsed here: each one can carry
+-- question answers, group membership and buy-in credit ledger rows, and which
+-- row to keep is an operator decision. Abort with the offending pairs so they
+-- can be merged by hand before the migration is re-run.
+DO $$
+DECLARE
+    v_dups TEXT;
+BEGIN
+    SELECT string_agg(
+               format('tournament %s / user %s (%s rows)', tournament_id, user_id, n),
+               E'\n' ORDER BY tournament_id, user_id)
+    INTO v_dups
+    FROM (
+        SELECT tournament_id, user_id, COUNT(*) AS n
+        FROM tournament_registrations
+        WHERE user_id IS NOT NULL
+        GROUP BY tournament_id, user_id
+        HAVING COUNT(*) > 1
+    ) dups;
+
+    IF v_dups IS NOT NULL THEN
+        RAISE EXCEPTION 'Duplicate registrations per user must be resolved first:%', E'\n' || v_dups;
+    END IF;
+END
+$$;
+
+ALTER TABLE tournament_registrations
+    ADD CONSTRAINT tournament_registrations_tournament_id_user_id_key
+    UNIQUE (tournament_id, user_id);
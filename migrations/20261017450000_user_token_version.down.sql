@@ -0,0 +1,4 @@
+DROP TRIGGER IF EXISTS club_managers_bump_token_version ON club_managers;
+DROP TRIGGER IF EXISTS users_bump_token_version ON users;
+DROP FUNCTION IF EXISTS bump_user_token_version();
+ALTER TABLE users DROP COLUMN IF EXISTS token_version;
@@ -0,0 +1,32 @@
+-- Access tokens carry the user's role, managed clubs and this version. Any
+-- change to what those claims describe bumps it, and the JWT middleware
+-- drops tokens minted for an older version, so a demotion or a removed club
+-- assignment takes effect on the next request instead of at token expiry.
+-- Clients recover through the refresh flow, which mints a current token.
+ALTER TABLE users ADD COLUMN token_version INTEGER NOT NULL DEFAULT 0;
+
+CREATE OR REPLACE FUNCTION bump_user_token_version()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_TABLE_NAME = 'users' THEN
+        NEW.token_version := OLD.token_version + 1;
+        RETURN NEW;
+    END IF;
+
+    UPDATE users SET token_version = token_version + 1
+    WHERE id = COALESCE(NEW.user_id, OLD.user_id);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER users_bump_token_version
+    BEFORE UPDATE OF role, email, is_active ON users
+    FOR EACH ROW
+    WHEN (OLD.role IS DISTINCT FROM NEW.role
+       OR OLD.email IS DISTINCT FROM NEW.email
+       OR OLD.is_active IS DISTINCT FROM NEW.is_active)
+    EXECUTE FUNCTION bump_user_token_version();
+
+CREATE TRIGGER club_managers_bump_token_version
+    AFTER INSERT OR DELETE OR UPDATE OF is_active ON club_managers
+    FOR EACH ROW EXECUTE FUNCTION bump_user_token_version();
@@ -0,0 +1,23 @@
+-- External login identities linked to an account. An account may sign in
+-- with its password (users.password_hash) and with any number of linked
+-- providers, one identity per provider. users.oauth_provider/_id keep the
+-- provider the account was created with; login now resolves through here.
+CREATE TABLE user_auth_identities (
+    id               UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id          UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    provider         TEXT NOT NULL,
+    provider_user_id TEXT NOT NULL,
+    -- Email the provider reported when the identity was linked.
+    email            TEXT,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (provider, provider_user_id),
+    UNIQUE (user_id, provider)
+);
+
+INSERT INTO user_auth_identities (user_id, provider, provider_user_id, email, created_at)
+SELECT id, oauth_provider, oauth_provider_id, email, created_at
+FROM users
+WHERE oauth_provider IS NOT NULL
+  AND oauth_provider <> 'custom'
+  AND oauth_provider_id IS NOT NULL
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,12 @@
+-- Single-use passwordless login links. Only the SHA-256 of the emailed token
+-- is stored; redeeming sets used_at so a link logs in once.
+CREATE TABLE login_link_tokens (
+    id         UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    token_hash TEXT NOT NULL UNIQUE,
+    user_id    UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at    TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_login_link_tokens_user_created ON login_link_tokens(user_id, created_at);
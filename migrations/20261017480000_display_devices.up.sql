@@ -0,0 +1,20 @@
+-- Lobby TVs and other screens paired to a club. A manager creates a row with
+-- a short-lived pairing code; the screen exchanges the code for a display
+-- token (see auth::display) that only reaches the club's display queries and
+-- subscriptions. Revoking the row cuts the screen off on its next request.
+CREATE TABLE display_devices (
+    id                 UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    club_id            UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    name               TEXT NOT NULL,
+    pairing_code_hash  TEXT UNIQUE,
+    pairing_expires_at TIMESTAMPTZ,
+    paired_at          TIMESTAMPTZ,
+    last_seen_at       TIMESTAMPTZ,
+    revoked_at         TIMESTAMPTZ,
+    created_by         UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at         TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_display_devices_club ON display_devices(club_id, created_at DESC);
+
+SELECT enable_club_isolation('display_devices');
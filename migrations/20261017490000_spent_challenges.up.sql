@@ -0,0 +1,9 @@
+-- Proof-of-work challenges already redeemed (see auth::challenge), so one
+-- solved puzzle can't be replayed for many signups. Rows outlive their
+-- challenge only until the cleanup sweep.
+CREATE TABLE spent_challenges (
+    challenge_hash TEXT PRIMARY KEY,
+    expires_at     TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_spent_challenges_expires ON spent_challenges(expires_at);
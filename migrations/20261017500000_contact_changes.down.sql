@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS user_audit_log;
+DROP TABLE IF EXISTS contact_change_requests;
@@ -0,0 +1,39 @@
+-- Self-service email and phone changes. A pending request holds the new
+-- value (sealed like users.phone) and hashes of two codes: one sent to the
+-- current address, one to the new address. Both must be entered before the
+-- change is applied (see auth::contact_change).
+CREATE TABLE contact_change_requests (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id        UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    channel        TEXT NOT NULL CHECK (channel IN ('email', 'phone')),
+    new_value      TEXT NOT NULL,
+    old_code_hash  TEXT NOT NULL,
+    new_code_hash  TEXT NOT NULL,
+    attempts       INT NOT NULL DEFAULT 0,
+    expires_at     TIMESTAMPTZ NOT NULL,
+    confirmed_at   TIMESTAMPTZ,
+    cancelled_at   TIMESTAMPTZ,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- One live request per user and channel; a new request cancels the old one.
+CREATE UNIQUE INDEX idx_contact_change_requests_pending
+    ON contact_change_requests(user_id, channel)
+    WHERE confirmed_at IS NULL AND cancelled_at IS NULL;
+
+SELECT enable_user_isolation('contact_change_requests');
+
+-- Account-level changes to a user's contact details, whoever made them: the
+-- user through a confirmed request, or a manager or admin directly. Old and
+-- new values are sealed at rest.
+CREATE TABLE user_audit_log (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id     UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    actor_id    UUID REFERENCES users(id) ON DELETE SET NULL,
+    action      TEXT NOT NULL,
+    old_value   TEXT,
+    new_value   TEXT,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_user_audit_log_user ON user_audit_log(user_id, created_at DESC);
@@ -0,0 +1,53 @@
+-- Official prize pool. When late registration closes the payout table is
+-- frozen: apply_tournament_payout (run by the entries trigger) leaves a
+-- finalized row alone until a manager reopens it.
+ALTER TABLE tournament_payouts
+    ADD COLUMN finalized_at TIMESTAMPTZ,
+    ADD COLUMN finalized_by UUID REFERENCES users(id) ON DELETE SET NULL;
+
+CREATE OR REPLACE FUNCTION apply_tournament_payout(
+    p_tournament_id UUID,
+    p_total_amount  BIGINT,
+    p_player_count  INTEGER
+) RETURNS VOID AS $$
+DECLARE
+    v_club_id UUID;
+    v_template RECORD;
+    v_payout_positions JSONB;
+BEGIN
+    SELECT club_id INTO v_club_id FROM tournaments WHERE id = p_tournament_id;
+
+    SELECT * INTO v_template FROM payout_templates
+    WHERE club_id = v_club_id
+    AND min_players <= p_player_count
+    AND (max_players IS NULL OR max_players >= p_player_count)
+    ORDER BY min_players DESC LIMIT 1;
+
+    IF v_template.id IS NOT NULL THEN
+        SELECT to_jsonb(array_agg(
+            jsonb_build_object(
+                'position', (pos->>'position')::INTEGER,
+                'amount_cents', FLOOR((pos->>'percentage')::NUMERIC * p_total_amount / 100),
+                'percentage', (pos->>'percentage')::NUMERIC
+            ) ORDER BY (pos->>'position')::INTEGER
+        )) INTO v_payout_positions
+        FROM jsonb_array_elements(v_template.payout_structure) pos;
+    ELSE
+        v_payout_positions := '[]'::JSONB;
+    END IF;
+
+    INSERT INTO tournament_payouts (
+        tournament_id, template_id, player_count, total_prize_pool, payout_positions
+    ) VALUES (
+        p_tournament_id, v_template.id, p_player_count, p_total_amount,
+        COALESCE(v_payout_positions, '[]'::JSONB)
+    )
+    ON CONFLICT (tournament_id) DO UPDATE SET
+        total_prize_pool = EXCLUDED.total_prize_pool,
+        player_count = EXCLUDED.player_count,
+        template_id = EXCLUDED.template_id,
+        payout_positions = EXCLUDED.payout_positions,
+        updated_at = NOW()
+    WHERE tournament_payouts.finalized_at IS NULL;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,12 @@
+DROP INDEX IF EXISTS idx_tournament_registrations_alternates;
+
+UPDATE tournament_registrations SET status = 'waitlisted' WHERE status = 'alternate';
+
+ALTER TABLE tournament_registrations
+    DROP COLUMN IF EXISTS alternate_position;
+
+ALTER TABLE tournament_registrations
+    DROP CONSTRAINT tournament_registrations_status_check;
+ALTER TABLE tournament_registrations
+    ADD CONSTRAINT tournament_registrations_status_check
+    CHECK (status IN ('registered', 'checked_in', 'seated', 'busted', 'waitlisted', 'cancelled', 'no_show'));
@@ -0,0 +1,17 @@
+-- Alternates: players who only get in as others bust during late registration.
+-- They queue in `alternate_position` order (distinct from the waitlist, which
+-- follows registration time and the seat cap), are seated into seats freed by
+-- eliminations, and keep their position once seated so each seated alternate
+-- is matched to one elimination.
+ALTER TABLE tournament_registrations
+    DROP CONSTRAINT tournament_registrations_status_check;
+ALTER TABLE tournament_registrations
+    ADD CONSTRAINT tournament_registrations_status_check
+    CHECK (status IN ('registered', 'checked_in', 'seated', 'busted', 'waitlisted', 'alternate', 'cancelled', 'no_show'));
+
+ALTER TABLE tournament_registrations
+    ADD COLUMN alternate_position INTEGER;
+
+CREATE INDEX idx_tournament_registrations_alternates
+    ON tournament_registrations (tournament_id, alternate_position)
+    WHERE status = 'alternate';
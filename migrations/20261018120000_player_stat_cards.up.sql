@@ -0,0 +1,18 @@
+-- Shareable monthly stat cards: a PNG per club player and month with their
+-- tournaments, ITM percentage and best finish at the club, generated by the
+-- club's managers for social posts. Regenerating replaces the card.
+-- Downloads go through signed URLs, like printouts.
+CREATE TABLE player_stat_cards (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    club_id         UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    club_player_id  UUID NOT NULL REFERENCES club_player(id) ON DELETE CASCADE,
+    -- First day of the month the card covers.
+    month           DATE NOT NULL CHECK (EXTRACT(DAY FROM month) = 1),
+    content         BYTEA NOT NULL,
+    generated_by    UUID REFERENCES users(id) ON DELETE SET NULL,
+    generated_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (club_player_id, month)
+);
+CREATE INDEX idx_player_stat_cards_club ON player_stat_cards (club_id);
+
+SELECT enable_club_isolation('player_stat_cards');
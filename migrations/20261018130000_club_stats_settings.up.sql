@@ -0,0 +1,18 @@
+-- What leaderboard statistics count as a final table and as a cash. Clubs
+-- running short-handed events play 6-max final tables, and points-only
+-- leagues pay no prizes, so both thresholds are per club. A club without a
+-- row keeps the defaults: top 9 make the final table, any prize is ITM.
+CREATE TABLE club_stats_settings (
+    club_id           UUID PRIMARY KEY REFERENCES clubs(id) ON DELETE CASCADE,
+    final_table_size  INTEGER NOT NULL DEFAULT 9 CHECK (final_table_size BETWEEN 2 AND 10),
+    -- Places counted as in the money; NULL counts any finish with a prize.
+    itm_places        INTEGER CHECK (itm_places > 0),
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at        TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER trg_club_stats_settings_updated_at
+    BEFORE UPDATE ON club_stats_settings
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
+
+SELECT enable_club_isolation('club_stats_settings');
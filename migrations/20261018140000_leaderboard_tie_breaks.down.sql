@@ -0,0 +1 @@
+ALTER TABLE leaderboard_configs DROP COLUMN IF EXISTS tie_breaks;
@@ -0,0 +1,7 @@
+-- How a league orders players level on points, first rule first: head-to-head
+-- finishes among the tied players, most wins, highest single-event score,
+-- then whoever reached the total earliest.
+ALTER TABLE leaderboard_configs
+    ADD COLUMN tie_breaks TEXT[] NOT NULL
+        DEFAULT ARRAY['head_to_head', 'most_wins', 'highest_single_score', 'earliest_achievement']
+        CHECK (tie_breaks <@ ARRAY['head_to_head', 'most_wins', 'highest_single_score', 'earliest_achievement']);
@@ -0,0 +1,23 @@
+-- Points for the rolling 52-week leaderboard: each result's points decay
+-- linearly with the tournament's age, from full value on the day to nothing
+-- after 52 weeks. Ages are taken at refresh time; the background job
+-- refreshes the view nightly, and results entered since count in full.
+-- Only holds result ids and numbers: reads join tournament_results, whose
+-- club isolation still applies.
+CREATE MATERIALIZED VIEW leaderboard_rolling_points AS
+SELECT
+    tr.id AS result_id,
+    ROUND(
+        tr.points * GREATEST(
+            0,
+            1 - EXTRACT(EPOCH FROM (NOW() - t.start_time)) / EXTRACT(EPOCH FROM INTERVAL '52 weeks')
+        )
+    )::INTEGER AS decayed_points,
+    NOW() AS refreshed_at
+FROM tournament_results tr
+JOIN tournaments t ON t.id = tr.tournament_id
+WHERE t.start_time >= NOW() - INTERVAL '52 weeks';
+
+-- Required by REFRESH MATERIALIZED VIEW CONCURRENTLY.
+CREATE UNIQUE INDEX idx_leaderboard_rolling_points_result
+    ON leaderboard_rolling_points (result_id);
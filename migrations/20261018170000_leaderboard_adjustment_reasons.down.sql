@@ -0,0 +1 @@
+ALTER TABLE leaderboard_adjustments DROP COLUMN IF EXISTS reason_code;
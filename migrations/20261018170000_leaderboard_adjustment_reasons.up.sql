@@ -0,0 +1,6 @@
+-- Reason codes on manual point adjustments, so penalties and corrections can
+-- be told apart (and reported on) without parsing the free-text reason, which
+-- stays as the note.
+ALTER TABLE leaderboard_adjustments
+    ADD COLUMN reason_code TEXT NOT NULL DEFAULT 'other'
+        CHECK (reason_code IN ('penalty', 'correction', 'bonus', 'other'));
@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_tournaments_club_start_active;
+ALTER TABLE tournaments
+    DROP COLUMN IF EXISTS deleted_by,
+    DROP COLUMN IF EXISTS deleted_at;
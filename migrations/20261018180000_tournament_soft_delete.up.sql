@@ -0,0 +1,9 @@
+-- Soft delete for tournaments created by mistake: the row stays (and so does
+-- its activity log) but drops out of schedules, lookups and registration.
+-- Tournaments with results are never deleted.
+ALTER TABLE tournaments
+    ADD COLUMN deleted_at TIMESTAMPTZ,
+    ADD COLUMN deleted_by UUID REFERENCES users(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_tournaments_club_start_active ON tournaments(club_id, start_time)
+    WHERE deleted_at IS NULL;
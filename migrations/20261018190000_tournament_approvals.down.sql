@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS tournament_approvals;
+ALTER TABLE organizations DROP COLUMN IF EXISTS require_tournament_approval;
@@ -0,0 +1,21 @@
+-- Optional approval workflow for franchised clubs: when an organization turns
+-- it on, tournaments its club managers create stay off public schedules until
+-- an organization admin approves them. Tournaments without a row here (every
+-- club outside such an organization) are approved as before.
+ALTER TABLE organizations
+    ADD COLUMN require_tournament_approval BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE tournament_approvals (
+    tournament_id    UUID PRIMARY KEY REFERENCES tournaments(id) ON DELETE CASCADE,
+    organization_id  UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    status           TEXT NOT NULL DEFAULT 'pending'
+                     CHECK (status IN ('pending', 'approved', 'rejected')),
+    requested_by     UUID REFERENCES users(id) ON DELETE SET NULL,
+    requested_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    reviewed_by      UUID REFERENCES users(id) ON DELETE SET NULL,
+    reviewed_at      TIMESTAMPTZ,
+    -- Shown to the creator, e.g. why a tournament was rejected.
+    review_note      TEXT
+);
+CREATE INDEX idx_tournament_approvals_pending ON tournament_approvals (organization_id, requested_at)
+    WHERE status = 'pending';
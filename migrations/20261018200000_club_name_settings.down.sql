@@ -0,0 +1,6 @@
+DROP TABLE IF EXISTS club_name_settings;
+DROP COLLATION IF EXISTS player_name_de;
+DROP COLLATION IF EXISTS player_name_nl;
+DROP COLLATION IF EXISTS player_name_fr;
+DROP COLLATION IF EXISTS player_name_en;
+DROP COLLATION IF EXISTS player_name_und;
@@ -0,0 +1,29 @@
+-- How a club sorts and prints player names. The database collates byte-wise,
+-- which puts "Émile" after "Zoé" and "de Smet" after "Van Damme", so roster
+-- and seat-list ordering goes through one of these ICU collations instead.
+CREATE COLLATION IF NOT EXISTS player_name_und (provider = icu, locale = 'und');
+CREATE COLLATION IF NOT EXISTS player_name_en (provider = icu, locale = 'en');
+CREATE COLLATION IF NOT EXISTS player_name_fr (provider = icu, locale = 'fr');
+CREATE COLLATION IF NOT EXISTS player_name_nl (provider = icu, locale = 'nl');
+CREATE COLLATION IF NOT EXISTS player_name_de (provider = icu, locale = 'de');
+
+-- A club without a row sorts by family name, as the roster always has, with
+-- the root (language-neutral) collation.
+CREATE TABLE club_name_settings (
+    club_id     UUID PRIMARY KEY REFERENCES clubs(id) ON DELETE CASCADE,
+    -- 'last_first' sorts by family name and prints "Last, First" on seat
+    -- lists; 'first_last' sorts and prints the display name.
+    name_order  TEXT NOT NULL DEFAULT 'last_first'
+                CHECK (name_order IN ('first_last', 'last_first')),
+    -- Sorts with the player_name_<sort_locale> collation.
+    sort_locale TEXT NOT NULL DEFAULT 'und'
+                CHECK (sort_locale IN ('und', 'en', 'fr', 'nl', 'de')),
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER trg_club_name_settings_updated_at
+    BEFORE UPDATE ON club_name_settings
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
+
+SELECT enable_club_isolation('club_name_settings');
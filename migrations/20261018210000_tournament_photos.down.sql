@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS tournament_photo_tags;
+DROP TABLE IF EXISTS tournament_photos;
+ALTER TABLE user_privacy_settings DROP COLUMN IF EXISTS appear_in_photos;
@@ -0,0 +1,36 @@
+-- Tournament photo galleries: winner and event photos uploaded to the media
+-- service and linked here by URL, with a caption and the players in them.
+--
+-- Photos show on the public site only with the consent of everyone tagged.
+-- A player with an account gives it once, in their privacy settings (off by
+-- default, like every consent there); for a walk-in without one, the club
+-- records the consent it collected on the tag itself.
+
+ALTER TABLE user_privacy_settings
+    ADD COLUMN appear_in_photos BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE tournament_photos (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tournament_id  UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    url            TEXT NOT NULL,
+    content_type   TEXT,
+    caption        TEXT,
+    uploaded_by    UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at     TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_tournament_photos_tournament ON tournament_photos (tournament_id, created_at);
+
+CREATE TRIGGER trg_tournament_photos_updated_at
+    BEFORE UPDATE ON tournament_photos
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
+
+CREATE TABLE tournament_photo_tags (
+    photo_id         UUID NOT NULL REFERENCES tournament_photos(id) ON DELETE CASCADE,
+    club_player_id   UUID NOT NULL REFERENCES club_player(id) ON DELETE CASCADE,
+    -- Consent the club collected in person; only counts for players without
+    -- an account, whose own setting otherwise decides.
+    consent_recorded BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (photo_id, club_player_id)
+);
+CREATE INDEX idx_tournament_photo_tags_player ON tournament_photo_tags (club_player_id);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS tournament_recaps;
+DROP TABLE IF EXISTS club_recap_settings;
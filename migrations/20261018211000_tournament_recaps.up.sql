@@ -0,0 +1,31 @@
+-- Results recaps: once a tournament's results are entered, a structured
+-- summary (title, podium, paid places, public photos) the club's website or
+-- social automation posts without anyone writing copy. The recap is stored
+-- with the tournament and, when the club has a webhook set, POSTed to it.
+
+CREATE TABLE club_recap_settings (
+    club_id         UUID PRIMARY KEY REFERENCES clubs(id) ON DELETE CASCADE,
+    -- Where recaps are POSTed; NULL keeps them query-only.
+    webhook_url     TEXT CHECK (webhook_url ~ '^https?://'),
+    -- Key for the X-Recap-Signature HMAC; NULL sends deliveries unsigned.
+    webhook_secret  TEXT,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER trg_club_recap_settings_updated_at
+    BEFORE UPDATE ON club_recap_settings
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
+
+SELECT enable_club_isolation('club_recap_settings');
+
+-- One recap per tournament, replaced when results are entered again.
+CREATE TABLE tournament_recaps (
+    tournament_id   UUID PRIMARY KEY REFERENCES tournaments(id) ON DELETE CASCADE,
+    payload         JSONB NOT NULL,
+    generated_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- When the club's webhook accepted this version of the recap, or why the
+    -- last attempt failed.
+    delivered_at    TIMESTAMPTZ,
+    delivery_error  TEXT
+);
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS qualification_rules;
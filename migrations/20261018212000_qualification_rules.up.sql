@@ -0,0 +1,27 @@
+-- Qualification rules: "play 10 of the weekly events to qualify for the
+-- freeroll final". A rule counts the events of a series (the season's
+-- group of events) each roster player took part in, and optionally the
+-- league points they scored there. The final is a tournament created for the
+-- rule; qualified players are registered in it.
+
+CREATE TABLE qualification_rules (
+    id                   UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    club_id              UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    series_id            UUID NOT NULL REFERENCES tournament_series(id) ON DELETE CASCADE,
+    name                 TEXT NOT NULL,
+    min_events           INTEGER NOT NULL CHECK (min_events > 0),
+    -- Points also required across the counted events; NULL counts events only.
+    min_points           INTEGER CHECK (min_points > 0),
+    -- Set once, when the final is created.
+    final_tournament_id  UUID UNIQUE REFERENCES tournaments(id) ON DELETE SET NULL,
+    created_at           TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at           TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_qualification_rules_club ON qualification_rules (club_id);
+CREATE INDEX idx_qualification_rules_series ON qualification_rules (series_id);
+
+CREATE TRIGGER trg_qualification_rules_updated_at
+    BEFORE UPDATE ON qualification_rules
+    FOR EACH ROW EXECUTE PROCEDURE set_updated_at();
+
+SELECT enable_club_isolation('qualification_rules');
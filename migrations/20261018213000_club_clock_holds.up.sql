@@ -0,0 +1,20 @@
+-- Club-wide clock holds: a manager stops every running clock in the club at
+-- once (fire alarm, power cut). The hold remembers which clocks it paused so
+-- resuming restarts those and leaves clocks that were already paused alone.
+
+CREATE TABLE club_clock_holds (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    club_id         UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    reason          TEXT NOT NULL,
+    -- The tournaments whose running clocks the hold paused.
+    tournament_ids  UUID[] NOT NULL DEFAULT '{}',
+    paused_by       UUID REFERENCES users(id) ON DELETE SET NULL,
+    paused_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resumed_by      UUID REFERENCES users(id) ON DELETE SET NULL,
+    resumed_at      TIMESTAMPTZ
+);
+-- One hold in force per club at a time.
+CREATE UNIQUE INDEX idx_club_clock_holds_active
+    ON club_clock_holds (club_id) WHERE resumed_at IS NULL;
+
+SELECT enable_club_isolation('club_clock_holds');
@@ -0,0 +1,3 @@
+DROP TRIGGER IF EXISTS trg_enqueue_seat_slip ON table_seat_assignments;
+DROP FUNCTION IF EXISTS enqueue_seat_slip();
+DROP TABLE IF EXISTS seat_slip_print_jobs;
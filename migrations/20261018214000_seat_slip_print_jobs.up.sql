@@ -0,0 +1,51 @@
+-- Seat slips for the desk's thermal printer. Every new seat (first seating,
+-- table moves, balancing) queues a slip; the desk's print agent claims pending
+-- jobs, prints them and reports back. A slip snapshots the names and numbers
+-- at seating time so a reprint matches what the player was handed. Reprints
+-- are new jobs pointing at the slip they copy.
+
+CREATE TABLE seat_slip_print_jobs (
+    id                  UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    club_id             UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    tournament_id       UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    seat_assignment_id  UUID REFERENCES table_seat_assignments(id) ON DELETE SET NULL,
+    tournament_name     TEXT NOT NULL,
+    player_name         TEXT NOT NULL,
+    table_number        INTEGER NOT NULL,
+    seat_number         INTEGER NOT NULL,
+    status              TEXT NOT NULL DEFAULT 'pending'
+                        CHECK (status IN ('pending', 'printing', 'printed', 'failed')),
+    reprint_of          UUID REFERENCES seat_slip_print_jobs(id) ON DELETE SET NULL,
+    requested_by        UUID REFERENCES users(id) ON DELETE SET NULL,
+    -- How many times an agent has claimed the job.
+    attempts            INTEGER NOT NULL DEFAULT 0,
+    error               TEXT,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    claimed_at          TIMESTAMPTZ,
+    printed_at          TIMESTAMPTZ
+);
+CREATE INDEX idx_seat_slip_print_jobs_queue
+    ON seat_slip_print_jobs (club_id, created_at) WHERE status IN ('pending', 'printing');
+CREATE INDEX idx_seat_slip_print_jobs_tournament ON seat_slip_print_jobs (tournament_id);
+
+CREATE OR REPLACE FUNCTION enqueue_seat_slip() RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO seat_slip_print_jobs
+        (club_id, tournament_id, seat_assignment_id, tournament_name, player_name,
+         table_number, seat_number, requested_by)
+    SELECT t.club_id, t.id, NEW.id, t.name, cp.display_name, ct.table_number,
+           NEW.seat_number, NEW.assigned_by
+    FROM tournaments t
+    JOIN club_tables ct ON ct.id = NEW.club_table_id
+    JOIN club_player cp ON cp.id = NEW.club_player_id
+    WHERE t.id = NEW.tournament_id;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_enqueue_seat_slip
+    AFTER INSERT ON table_seat_assignments
+    FOR EACH ROW WHEN (NEW.is_current)
+    EXECUTE FUNCTION enqueue_seat_slip();
+
+SELECT enable_club_isolation('seat_slip_print_jobs');
@@ -0,0 +1,6 @@
+-- Seats per table for a whole tournament, e.g. 8-handed on the club's 10-seat
+-- tables. Caps each linked table's seats for the seat draw, auto-assign and
+-- balancing; a table's own `max_seats_override` on its assignment still wins.
+-- The club table keeps its physical size.
+ALTER TABLE tournaments
+    ADD COLUMN seats_per_table INTEGER CHECK (seats_per_table >= 2);
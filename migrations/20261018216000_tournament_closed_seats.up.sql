@@ -0,0 +1,17 @@
+-- Seats the director has closed late in a tournament so every table plays
+-- equally short-handed (e.g. 7-handed across three 9-seat tables). A closed
+-- seat is skipped by the seat draw, auto-assign, balancing and manual moves
+-- until it is reopened. Closures are per tournament: the same club table may
+-- be fully open in another event.
+
+CREATE TABLE tournament_closed_seats (
+    tournament_id   UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    club_table_id   UUID NOT NULL REFERENCES club_tables(id) ON DELETE CASCADE,
+    seat_number     INTEGER NOT NULL CHECK (seat_number >= 1),
+    club_id         UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    closed_by       UUID REFERENCES users(id) ON DELETE SET NULL,
+    closed_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tournament_id, club_table_id, seat_number)
+);
+
+SELECT enable_club_isolation('tournament_closed_seats');
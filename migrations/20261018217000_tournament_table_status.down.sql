@@ -0,0 +1,5 @@
+ALTER TABLE tournament_table_assignments
+    DROP COLUMN IF EXISTS status_updated_at,
+    DROP COLUMN IF EXISTS status_updated_by,
+    DROP COLUMN IF EXISTS notes,
+    DROP COLUMN IF EXISTS status;
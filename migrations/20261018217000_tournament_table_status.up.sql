@@ -0,0 +1,9 @@
+-- Floor status and notes for a tournament's tables, e.g. "breaking soon" or
+-- "new deck needed", so the floor team sees what each table needs on the
+-- seating chart. Status is per tournament, like the rest of the assignment.
+ALTER TABLE tournament_table_assignments
+    ADD COLUMN status TEXT NOT NULL DEFAULT 'open'
+        CHECK (status IN ('open', 'breaking_soon', 'closed')),
+    ADD COLUMN notes TEXT,
+    ADD COLUMN status_updated_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    ADD COLUMN status_updated_at TIMESTAMPTZ;
@@ -0,0 +1,115 @@
+-- Without the column, voided entries would count again; drop them as
+-- cancellation did before entries were voided.
+DELETE FROM tournament_entries WHERE voided_at IS NOT NULL;
+
+CREATE OR REPLACE FUNCTION recalculate_prize_pool_from_entries()
+RETURNS TRIGGER AS $$
+DECLARE
+    v_tournament_id UUID;
+    v_series_id UUID;
+    v_final_day_id UUID;
+    v_total_amount BIGINT;
+    v_player_count INTEGER;
+    v_bounty_slice BIGINT;
+BEGIN
+    v_tournament_id := COALESCE(NEW.tournament_id, OLD.tournament_id);
+
+    SELECT series_id INTO v_series_id FROM tournaments WHERE id = v_tournament_id;
+
+    -- (1) The changed tournament's own per-night payout (single-day path,
+    -- also the per-flight cash desk). Vouchers and bonuses are excluded from the
+    -- prize pool; players are counted by club_player_id (account-less safe).
+    SELECT
+        COALESCE(SUM(amount_cents) FILTER (WHERE entry_type NOT IN ('voucher', 'bonus')), 0),
+        COUNT(DISTINCT club_player_id) FILTER (WHERE entry_type NOT IN ('voucher', 'bonus'))
+    INTO v_total_amount, v_player_count
+    FROM tournament_entries WHERE tournament_id = v_tournament_id;
+
+    SELECT COALESCE(bounty_amount_cents, 0) * COUNT(*) FILTER (
+        WHERE te.entry_type IN ('initial', 'rebuy', 're_entry'))
+    INTO v_bounty_slice
+    FROM tournaments t
+    LEFT JOIN tournament_entries te ON te.tournament_id = t.id
+    WHERE t.id = v_tournament_id
+    GROUP BY t.bounty_amount_cents;
+
+    v_total_amount := GREATEST(v_total_amount - COALESCE(v_bounty_slice, 0), 0);
+    PERFORM apply_tournament_payout(v_tournament_id, v_total_amount, v_player_count);
+
+    -- (2) If this tournament belongs to a series, refresh the final day's
+    -- aggregate across all flights.
+    IF v_series_id IS NOT NULL THEN
+        SELECT id INTO v_final_day_id FROM tournaments
+        WHERE series_id = v_series_id AND is_final_day = TRUE LIMIT 1;
+
+        IF v_final_day_id IS NOT NULL THEN
+            SELECT
+                COALESCE(SUM(te.amount_cents) FILTER (WHERE te.entry_type NOT IN ('voucher', 'bonus')), 0),
+                COUNT(DISTINCT te.club_player_id) FILTER (WHERE te.entry_type NOT IN ('voucher', 'bonus'))
+            INTO v_total_amount, v_player_count
+            FROM tournament_entries te
+            JOIN tournaments t ON t.id = te.tournament_id
+            WHERE t.series_id = v_series_id;
+
+            SELECT COALESCE(SUM(sub.slice), 0) INTO v_bounty_slice FROM (
+                SELECT t.bounty_amount_cents * COUNT(*) FILTER (
+                    WHERE te.entry_type IN ('initial', 'rebuy', 're_entry')) AS slice
+                FROM tournaments t
+                JOIN tournament_entries te ON te.tournament_id = t.id
+                WHERE t.series_id = v_series_id AND t.bounty_amount_cents > 0
+                GROUP BY t.id, t.bounty_amount_cents
+            ) sub;
+
+            v_total_amount := GREATEST(v_total_amount - COALESCE(v_bounty_slice, 0), 0);
+            PERFORM apply_tournament_payout(v_final_day_id, v_total_amount, v_player_count);
+        END IF;
+    END IF;
+
+    RETURN COALESCE(NEW, OLD);
+END;
+$$ LANGUAGE plpgsql;
+
+ALTER TABLE tournament_entries
+    DROP COLUMN IF EXISTS voided_by,
+    DROP COLUMN IF EXISTS voided_at;
+
+DROP TABLE IF EXISTS tournament_refunds;
+
+CREATE OR REPLACE FUNCTION enforce_entry_limits() RETURNS TRIGGER AS $$
+DECLARE
+    v_status tournament_live_status;
+    v_rebuy_max INTEGER;
+    v_rebuys BIGINT;
+BEGIN
+    SELECT live_status, rebuy_max INTO v_status, v_rebuy_max
+    FROM tournaments WHERE id = NEW.tournament_id;
+
+    IF v_status = 'finished' THEN
+        RAISE EXCEPTION 'Tournament % is finished', NEW.tournament_id
+            USING ERRCODE = 'check_violation',
+                  CONSTRAINT = 'tournament_entries_tournament_open';
+    END IF;
+
+    IF NEW.entry_type = 'rebuy' AND v_rebuy_max IS NOT NULL THEN
+        PERFORM pg_advisory_xact_lock(
+            hashtextextended(NEW.tournament_id::text || ':' || NEW.club_player_id::text, 0)
+        );
+        SELECT COUNT(*) INTO v_rebuys
+        FROM tournament_entries
+        WHERE tournament_id = NEW.tournament_id
+          AND club_player_id = NEW.club_player_id
+          AND entry_type = 'rebuy';
+        IF v_rebuys >= v_rebuy_max THEN
+            RAISE EXCEPTION 'Player has used all % rebuys', v_rebuy_max
+                USING ERRCODE = 'check_violation',
+                      CONSTRAINT = 'tournament_entries_rebuy_limit';
+        END IF;
+    END IF;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Postgres can't drop an enum value (and triggers pin the column's type), so
+-- 'cancelled' stays in tournament_live_status unused.
+UPDATE tournaments SET live_status = 'finished' WHERE live_status = 'cancelled';
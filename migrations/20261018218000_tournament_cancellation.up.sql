@@ -0,0 +1,149 @@
+-- Cancelling a tournament: a terminal live status next to 'finished',
+-- voided entries, and the refunds the cancellation leaves for the desk.
+-- Each refund covers the money one player paid with one payment method
+-- (vouchers and comps give nothing back in cash); it stays pending until
+-- the desk hands it over.
+
+ALTER TYPE tournament_live_status ADD VALUE IF NOT EXISTS 'cancelled';
+
+-- A cancelled tournament takes no entries either, refused under its own
+-- pseudo-constraint so the desk can tell it apart from a finished one.
+CREATE OR REPLACE FUNCTION enforce_entry_limits() RETURNS TRIGGER AS $$
+DECLARE
+    v_status tournament_live_status;
+    v_rebuy_max INTEGER;
+    v_rebuys BIGINT;
+BEGIN
+    SELECT live_status, rebuy_max INTO v_status, v_rebuy_max
+    FROM tournaments WHERE id = NEW.tournament_id;
+
+    IF v_status = 'finished' THEN
+        RAISE EXCEPTION 'Tournament % is finished', NEW.tournament_id
+            USING ERRCODE = 'check_violation',
+                  CONSTRAINT = 'tournament_entries_tournament_open';
+    END IF;
+
+    IF v_status = 'cancelled' THEN
+        RAISE EXCEPTION 'Tournament % is cancelled', NEW.tournament_id
+            USING ERRCODE = 'check_violation',
+                  CONSTRAINT = 'tournament_entries_tournament_not_cancelled';
+    END IF;
+
+    IF NEW.entry_type = 'rebuy' AND v_rebuy_max IS NOT NULL THEN
+        PERFORM pg_advisory_xact_lock(
+            hashtextextended(NEW.tournament_id::text || ':' || NEW.club_player_id::text, 0)
+        );
+        SELECT COUNT(*) INTO v_rebuys
+        FROM tournament_entries
+        WHERE tournament_id = NEW.tournament_id
+          AND club_player_id = NEW.club_player_id
+          AND entry_type = 'rebuy';
+        IF v_rebuys >= v_rebuy_max THEN
+            RAISE EXCEPTION 'Player has used all % rebuys', v_rebuy_max
+                USING ERRCODE = 'check_violation',
+                      CONSTRAINT = 'tournament_entries_rebuy_limit';
+        END IF;
+    END IF;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TABLE tournament_refunds (
+    id               UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    club_id          UUID NOT NULL REFERENCES clubs(id) ON DELETE CASCADE,
+    tournament_id    UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    registration_id  UUID REFERENCES tournament_registrations(id) ON DELETE SET NULL,
+    club_player_id   UUID NOT NULL REFERENCES club_player(id) ON DELETE CASCADE,
+    amount_cents     BIGINT NOT NULL CHECK (amount_cents > 0),
+    entry_count      INTEGER NOT NULL CHECK (entry_count > 0),
+    payment_method   TEXT NOT NULL,
+    status           TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'refunded')),
+    created_by       UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    refunded_by      UUID REFERENCES users(id) ON DELETE SET NULL,
+    refunded_at      TIMESTAMPTZ
+);
+
+CREATE INDEX idx_tournament_refunds_tournament ON tournament_refunds(tournament_id);
+CREATE INDEX idx_tournament_refunds_pending ON tournament_refunds(club_id)
+    WHERE status = 'pending';
+
+SELECT enable_club_isolation('tournament_refunds');
+
+-- Entries of a cancelled tournament are voided rather than deleted, so the
+-- desk's record of what was paid survives. Voided entries no longer count
+-- towards the prize pool.
+ALTER TABLE tournament_entries
+    ADD COLUMN voided_at TIMESTAMPTZ,
+    ADD COLUMN voided_by UUID REFERENCES users(id) ON DELETE SET NULL;
+
+CREATE OR REPLACE FUNCTION recalculate_prize_pool_from_entries()
+RETURNS TRIGGER AS $$
+DECLARE
+    v_tournament_id UUID;
+    v_series_id UUID;
+    v_final_day_id UUID;
+    v_total_amount BIGINT;
+    v_player_count INTEGER;
+    v_bounty_slice BIGINT;
+BEGIN
+    v_tournament_id := COALESCE(NEW.tournament_id, OLD.tournament_id);
+
+    SELECT series_id INTO v_series_id FROM tournaments WHERE id = v_tournament_id;
+
+    -- (1) The changed tournament's own per-night payout (single-day path,
+    -- also the per-flight cash desk). Vouchers and bonuses are excluded from the
+    -- prize pool; players are counted by club_player_id (account-less safe).
+    SELECT
+        COALESCE(SUM(amount_cents) FILTER (WHERE entry_type NOT IN ('voucher', 'bonus')), 0),
+        COUNT(DISTINCT club_player_id) FILTER (WHERE entry_type NOT IN ('voucher', 'bonus'))
+    INTO v_total_amount, v_player_count
+    FROM tournament_entries
+    WHERE tournament_id = v_tournament_id AND voided_at IS NULL;
+
+    SELECT COALESCE(bounty_amount_cents, 0) * COUNT(*) FILTER (
+        WHERE te.entry_type IN ('initial', 'rebuy', 're_entry'))
+    INTO v_bounty_slice
+    FROM tournaments t
+    LEFT JOIN tournament_entries te
+           ON te.tournament_id = t.id AND te.voided_at IS NULL
+    WHERE t.id = v_tournament_id
+    GROUP BY t.bounty_amount_cents;
+
+    v_total_amount := GREATEST(v_total_amount - COALESCE(v_bounty_slice, 0), 0);
+    PERFORM apply_tournament_payout(v_tournament_id, v_total_amount, v_player_count);
+
+    -- (2) If this tournament belongs to a series, refresh the final day's
+    -- aggregate across all flights.
+    IF v_series_id IS NOT NULL THEN
+        SELECT id INTO v_final_day_id FROM tournaments
+        WHERE series_id = v_series_id AND is_final_day = TRUE LIMIT 1;
+
+        IF v_final_day_id IS NOT NULL THEN
+            SELECT
+                COALESCE(SUM(te.amount_cents) FILTER (WHERE te.entry_type NOT IN ('voucher', 'bonus')), 0),
+                COUNT(DISTINCT te.club_player_id) FILTER (WHERE te.entry_type NOT IN ('voucher', 'bonus'))
+            INTO v_total_amount, v_player_count
+            FROM tournament_entries te
+            JOIN tournaments t ON t.id = te.tournament_id
+            WHERE t.series_id = v_series_id AND te.voided_at IS NULL;
+
+            SELECT COALESCE(SUM(sub.slice), 0) INTO v_bounty_slice FROM (
+                SELECT t.bounty_amount_cents * COUNT(*) FILTER (
+                    WHERE te.entry_type IN ('initial', 'rebuy', 're_entry')) AS slice
+                FROM tournaments t
+                JOIN tournament_entries te
+                  ON te.tournament_id = t.id AND te.voided_at IS NULL
+                WHERE t.series_id = v_series_id AND t.bounty_amount_cents > 0
+                GROUP BY t.id, t.bounty_amount_cents
+            ) sub;
+
+            v_total_amount := GREATEST(v_total_amount - COALESCE(v_bounty_slice, 0), 0);
+            PERFORM apply_tournament_payout(v_final_day_id, v_total_amount, v_player_count);
+        END IF;
+    END IF;
+
+    RETURN COALESCE(NEW, OLD);
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,160 @@
+CREATE OR REPLACE FUNCTION link_club_player()
+RETURNS TRIGGER AS $$
+DECLARE
+    v_club_id UUID;
+    v_cp_id   UUID;
+    v_name    TEXT;
+BEGIN
+    SELECT club_id INTO v_club_id FROM tournaments WHERE id = NEW.tournament_id;
+    IF v_club_id IS NULL THEN
+        RETURN NEW;
+    END IF;
+
+    IF NEW.club_player_id IS NOT NULL THEN
+        IF NEW.user_id IS NULL THEN
+            SELECT app_user_id INTO NEW.user_id
+                FROM club_player WHERE id = NEW.club_player_id;
+        END IF;
+        RETURN NEW;
+    END IF;
+
+    IF NEW.user_id IS NULL THEN
+        RETURN NEW;
+    END IF;
+
+    SELECT id INTO v_cp_id FROM club_player
+        WHERE club_id = v_club_id AND app_user_id = NEW.user_id;
+
+    IF v_cp_id IS NULL THEN
+        SELECT COALESCE(
+            NULLIF(TRIM(COALESCE(first_name, '') || ' ' || COALESCE(last_name, '')), ''),
+            username, email, 'Unknown'
+        ) INTO v_name FROM users WHERE id = NEW.user_id;
+
+        INSERT INTO club_player (club_id, display_name, app_user_id)
+            VALUES (v_club_id, COALESCE(v_name, 'Unknown'), NEW.user_id)
+            RETURNING id INTO v_cp_id;
+    END IF;
+
+    NEW.club_player_id := v_cp_id;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP VIEW club_player_public;
+DROP FUNCTION app_club_players_public(BOOLEAN, UUID[], UUID);
+
+DROP VIEW users_public;
+DROP FUNCTION app_users_public();
+DROP FUNCTION app_user_by_email(TEXT);
+
+DO $$
+DECLARE
+    p RECORD;
+    t RECORD;
+BEGIN
+    FOR p IN
+        SELECT schemaname, tablename, policyname FROM pg_policies WHERE schemaname = 'public'
+    LOOP
+        EXECUTE format('DROP POLICY %I ON %I.%I', p.policyname, p.schemaname, p.tablename);
+    END LOOP;
+    FOR t IN
+        SELECT c.relname FROM pg_class c JOIN pg_namespace n ON n.oid = c.relnamespace
+        WHERE n.nspname = 'public' AND c.relkind IN ('r', 'p') AND c.relrowsecurity
+    LOOP
+        EXECUTE format('ALTER TABLE public.%I NO FORCE ROW LEVEL SECURITY', t.relname);
+        EXECUTE format('ALTER TABLE public.%I DISABLE ROW LEVEL SECURITY', t.relname);
+    END LOOP;
+END;
+$$;
+
+CREATE OR REPLACE FUNCTION trg_seed_club_default_templates()
+RETURNS TRIGGER AS $$
+BEGIN
+    PERFORM seed_club_default_templates(NEW.id);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP FUNCTION app_club_staff(UUID, TEXT);
+DROP FUNCTION app_organization_visible(UUID);
+DROP FUNCTION app_jackpot_balance(UUID);
+DROP FUNCTION enable_member_isolation(REGCLASS, TEXT);
+DROP FUNCTION app_user_visible(UUID);
+DROP FUNCTION app_tournament_player(UUID);
+DROP FUNCTION enable_service_only(REGCLASS);
+DROP FUNCTION enable_parent_isolation(REGCLASS, TEXT);
+DROP FUNCTION enable_user_isolation(REGCLASS, TEXT);
+DROP FUNCTION enable_public_lock(REGCLASS);
+DROP FUNCTION enable_published_read(REGCLASS);
+DROP FUNCTION enable_public_read(REGCLASS);
+DROP FUNCTION enable_own_player_rows(REGCLASS);
+DROP FUNCTION enable_own_rows(REGCLASS, TEXT);
+DROP FUNCTION enable_tournament_isolation(REGCLASS);
+DROP FUNCTION app_tournament_published(UUID);
+DROP FUNCTION app_tournament_approved(UUID);
+DROP FUNCTION app_tournament_visible(UUID);
+DROP FUNCTION app_club_visible(UUID);
+DROP FUNCTION app_user_scope();
+DROP FUNCTION app_club_scope();
+DROP FUNCTION app_scoped();
+
+CREATE FUNCTION app_club_scope() RETURNS UUID
+LANGUAGE sql STABLE AS $$
+    SELECT NULLIF(current_setting('app.club_id', true), '')::uuid
+$$;
+
+CREATE FUNCTION app_user_scope() RETURNS UUID
+LANGUAGE sql STABLE AS $$
+    SELECT NULLIF(current_setting('app.user_id', true), '')::uuid
+$$;
+
+CREATE OR REPLACE FUNCTION enable_club_isolation(tbl REGCLASS) RETURNS VOID
+LANGUAGE plpgsql AS $$
+BEGIN
+    EXECUTE format('ALTER TABLE %s ENABLE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('ALTER TABLE %s FORCE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('DROP POLICY IF EXISTS club_isolation ON %s', tbl);
+    EXECUTE format(
+        'CREATE POLICY club_isolation ON %s
+             USING (app_club_scope() IS NULL OR club_id IS NULL OR club_id = app_club_scope())',
+        tbl
+    );
+END;
+$$;
+
+CREATE FUNCTION enable_user_isolation(tbl REGCLASS) RETURNS VOID
+LANGUAGE plpgsql AS $$
+BEGIN
+    EXECUTE format('ALTER TABLE %s ENABLE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('ALTER TABLE %s FORCE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('DROP POLICY IF EXISTS user_isolation ON %s', tbl);
+    EXECUTE format(
+        'CREATE POLICY user_isolation ON %s
+             USING (app_user_scope() IS NULL OR user_id = app_user_scope())',
+        tbl
+    );
+END;
+$$;
+
+DO $$
+DECLARE
+    t REGCLASS;
+BEGIN
+    FOR t IN
+        SELECT format('%I.%I', c.table_schema, c.table_name)::regclass
+        FROM information_schema.columns c
+        JOIN information_schema.tables tb USING (table_schema, table_name)
+        WHERE c.table_schema = 'public'
+          AND c.column_name = 'club_id'
+          AND tb.table_type = 'BASE TABLE'
+    LOOP
+        PERFORM enable_club_isolation(t);
+    END LOOP;
+END;
+$$;
+
+SELECT enable_user_isolation('bankroll_transactions');
+SELECT enable_user_isolation('device_tokens');
+SELECT enable_user_isolation('notification_preferences');
+SELECT enable_user_isolation('contact_change_requests');
@@ -0,0 +1,807 @@
+-- Row-level security, deny by default. Every GraphQL operation runs under
+-- its viewer's scope (infra::db::with_request_scope), which the pool
+-- applies to each connection it hands out: `app.scoped` marks a request
+-- connection, `app.club_ids` the clubs it acts for (the ones its user
+-- manages, or a display's club) and `app.user_id` its user. A request
+-- connection sees only rows of those clubs, shared rows without a club,
+-- and its user's own rows; with no scope it sees none. Connections outside
+-- a request (background services, auth lookups, migrations) have no
+-- `app.scoped` and are unrestricted, as are admins' requests.
+--
+-- Tables the public API serves to anyone (the lobby, structures, standings)
+-- keep a read-only `public_read` policy, so the isolation there covers
+-- writes. On a tournament's registrations, entries and results it only
+-- covers published tournaments. The roster stays private to its club and
+-- its players; the public reads players' names through `club_player_public`.
+
+DO $$
+DECLARE
+    p RECORD;
+BEGIN
+    FOR p IN
+        SELECT schemaname, tablename, policyname
+        FROM pg_policies
+        WHERE schemaname = 'public' AND policyname IN ('club_isolation', 'user_isolation')
+    LOOP
+        EXECUTE format('DROP POLICY %I ON %I.%I', p.policyname, p.schemaname, p.tablename);
+    END LOOP;
+END;
+$$;
+
+DROP FUNCTION app_club_scope();
+DROP FUNCTION app_user_scope();
+
+CREATE FUNCTION app_scoped() RETURNS BOOLEAN
+LANGUAGE sql STABLE AS $$
+    SELECT COALESCE(current_setting('app.scoped', true), '') = 'on'
+$$;
+
+CREATE FUNCTION app_club_scope() RETURNS UUID[]
+LANGUAGE sql STABLE AS $$
+    SELECT COALESCE(NULLIF(current_setting('app.club_ids', true), '')::uuid[], '{}')
+$$;
+
+CREATE FUNCTION app_user_scope() RETURNS UUID
+LANGUAGE sql STABLE AS $$
+    SELECT NULLIF(current_setting('app.user_id', true), '')::uuid
+$$;
+
+-- Whether a row of `club` is in scope. Rows without a club (shared
+-- templates, global announcements) are visible to every club.
+CREATE FUNCTION app_club_visible(club UUID) RETURNS BOOLEAN
+LANGUAGE sql STABLE AS $$
+    SELECT NOT app_scoped() OR club IS NULL OR club = ANY(app_club_scope())
+$$;
+
+-- Whether a row of `tournament` is in scope, through the tournament's club.
+CREATE FUNCTION app_tournament_visible(tournament UUID) RETURNS BOOLEAN
+LANGUAGE sql STABLE AS $$
+    SELECT NOT app_scoped() OR EXISTS (
+        SELECT 1 FROM tournaments t
+        WHERE t.id = tournament AND t.club_id = ANY(app_club_scope())
+    )
+$$;
+
+-- Whether a tournament needs no approval or its organization approved it.
+-- Reads past the caller's scope, for the lobby.
+CREATE FUNCTION app_tournament_approved(tournament UUID) RETURNS BOOLEAN
+LANGUAGE plpgsql STABLE AS $$
+DECLARE
+    scope TEXT := current_setting('app.scoped', true);
+    approved BOOLEAN;
+BEGIN
+    PERFORM set_config('app.scoped', '', true);
+    SELECT NOT EXISTS (
+        SELECT 1 FROM tournament_approvals a
+        WHERE a.tournament_id = tournament AND a.status <> 'approved'
+    ) INTO approved;
+    PERFORM set_config('app.scoped', COALESCE(scope, ''), true);
+    RETURN approved;
+END;
+$$;
+
+-- Whether a tournament is published: not deleted, not a free club's home
+-- game, not awaiting (or refused) its organization's approval and, when
+-- members-only, open to the viewer as a member. Unlisted tournaments count;
+-- the API checks their invite link. Reads past the caller's scope.
+CREATE FUNCTION app_tournament_published(tournament UUID) RETURNS BOOLEAN
+LANGUAGE plpgsql STABLE AS $$
+DECLARE
+    scope TEXT := current_setting('app.scoped', true);
+    published BOOLEAN;
+BEGIN
+    PERFORM set_config('app.scoped', '', true);
+    SELECT EXISTS (
+        SELECT 1 FROM tournaments t JOIN clubs c ON c.id = t.club_id
+        WHERE t.id = tournament
+          AND t.deleted_at IS NULL
+          AND c.plan <> 'free'
+          AND app_tournament_approved(t.id)
+          AND (t.visibility <> 'members_only' OR EXISTS (
+              SELECT 1 FROM club_player cp
+              WHERE cp.club_id = t.club_id
+                AND cp.app_user_id = app_user_scope()
+                AND cp.is_active
+          ))
+    ) INTO published;
+    PERFORM set_config('app.scoped', COALESCE(scope, ''), true);
+    RETURN published;
+END;
+$$;
+
+-- Isolate a table with a `club_id` column by club. Call this from the
+-- migration that creates a new club-scoped table.
+CREATE OR REPLACE FUNCTION enable_club_isolation(tbl REGCLASS) RETURNS VOID
+LANGUAGE plpgsql AS $$
+BEGIN
+    EXECUTE format('ALTER TABLE %s ENABLE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('ALTER TABLE %s FORCE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('DROP POLICY IF EXISTS club_isolation ON %s', tbl);
+    EXECUTE format(
+        'CREATE POLICY club_isolation ON %s USING (app_club_visible(club_id))',
+        tbl
+    );
+END;
+$$;
+
+-- Isolate a table keyed by `tournament_id` (without its own `club_id`) by
+-- the tournament's club.
+CREATE FUNCTION enable_tournament_isolation(tbl REGCLASS) RETURNS VOID
+LANGUAGE plpgsql AS $$
+BEGIN
+    EXECUTE format('ALTER TABLE %s ENABLE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('ALTER TABLE %s FORCE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('DROP POLICY IF EXISTS tournament_isolation ON %s', tbl);
+    EXECUTE format(
+        'CREATE POLICY tournament_isolation ON %s USING (app_tournament_visible(tournament_id))',
+        tbl
+    );
+END;
+$$;
+
+-- Isolate a table of a user's private rows, owned through `owner`, by user.
+DROP FUNCTION enable_user_isolation(REGCLASS);
+CREATE FUNCTION enable_user_isolation(tbl REGCLASS, owner TEXT DEFAULT 'user_id') RETURNS VOID
+LANGUAGE plpgsql AS $$
+BEGIN
+    EXECUTE format('ALTER TABLE %s ENABLE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('ALTER TABLE %s FORCE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('DROP POLICY IF EXISTS user_isolation ON %s', tbl);
+    EXECUTE format(
+        'CREATE POLICY user_isolation ON %s USING (NOT app_scoped() OR %I = app_user_scope())',
+        tbl, owner
+    );
+END;
+$$;
+
+-- Isolate a child table through its parent: `predicate` checks that the
+-- parent row is in scope. It spells the scope out rather than leaning on
+-- the parent's policies, which may let anyone read the parent.
+CREATE FUNCTION enable_parent_isolation(tbl REGCLASS, predicate TEXT) RETURNS VOID
+LANGUAGE plpgsql AS $$
+BEGIN
+    EXECUTE format('ALTER TABLE %s ENABLE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('ALTER TABLE %s FORCE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('DROP POLICY IF EXISTS parent_isolation ON %s', tbl);
+    EXECUTE format('CREATE POLICY parent_isolation ON %s USING (%s)', tbl, predicate);
+END;
+$$;
+
+-- Keep a table to connections outside a request (auth tokens, the
+-- migration-managed catalogs); `enable_public_read` can open it for reading.
+CREATE FUNCTION enable_service_only(tbl REGCLASS) RETURNS VOID
+LANGUAGE plpgsql AS $$
+BEGIN
+    EXECUTE format('ALTER TABLE %s ENABLE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('ALTER TABLE %s FORCE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('DROP POLICY IF EXISTS service_only ON %s', tbl);
+    EXECUTE format('CREATE POLICY service_only ON %s USING (NOT app_scoped())', tbl);
+END;
+$$;
+
+-- On an isolated table, also let users read and write their own rows,
+-- owned through `owner` (a player's registrations at any club).
+CREATE FUNCTION enable_own_rows(tbl REGCLASS, owner TEXT DEFAULT 'user_id') RETURNS VOID
+LANGUAGE plpgsql AS $$
+BEGIN
+    EXECUTE format('DROP POLICY IF EXISTS own_rows ON %s', tbl);
+    EXECUTE format('CREATE POLICY own_rows ON %s USING (%I = app_user_scope())', tbl, owner);
+END;
+$$;
+
+-- On a club-isolated table, also let players read their own rows, owned
+-- through the club roster (`club_player_id`): their tickets, credits and
+-- wallet at each club.
+CREATE FUNCTION enable_own_player_rows(tbl REGCLASS) RETURNS VOID
+LANGUAGE plpgsql AS $$
+BEGIN
+    EXECUTE format('DROP POLICY IF EXISTS own_player_rows ON %s', tbl);
+    EXECUTE format(
+        'CREATE POLICY own_player_rows ON %s FOR SELECT USING (EXISTS ('
+        'SELECT 1 FROM club_player cp '
+        'WHERE cp.id = club_player_id AND cp.app_user_id = app_user_scope()))',
+        tbl
+    );
+END;
+$$;
+
+-- On an isolated table the public API serves, let anyone read.
+CREATE FUNCTION enable_public_read(tbl REGCLASS) RETURNS VOID
+LANGUAGE plpgsql AS $$
+BEGIN
+    EXECUTE format('DROP POLICY IF EXISTS public_read ON %s', tbl);
+    EXECUTE format('CREATE POLICY public_read ON %s FOR SELECT USING (true)', tbl);
+END;
+$$;
+
+-- On a table keyed by `tournament_id`, let anyone read the rows of
+-- published tournaments (their field, entries and standings).
+CREATE FUNCTION enable_published_read(tbl REGCLASS) RETURNS VOID
+LANGUAGE plpgsql AS $$
+BEGIN
+    EXECUTE format('DROP POLICY IF EXISTS public_read ON %s', tbl);
+    EXECUTE format(
+        'CREATE POLICY public_read ON %s FOR SELECT USING (app_tournament_published(tournament_id))',
+        tbl
+    );
+END;
+$$;
+
+-- On a club-isolated table, let anyone lock rows (`SELECT ... FOR UPDATE`
+-- needs an UPDATE policy) while updates stay limited to the scope's clubs:
+-- a player's registration locks the tournament to check its capacity.
+CREATE FUNCTION enable_public_lock(tbl REGCLASS) RETURNS VOID
+LANGUAGE plpgsql AS $$
+BEGIN
+    EXECUTE format('DROP POLICY IF EXISTS public_lock ON %s', tbl);
+    EXECUTE format(
+        'CREATE POLICY public_lock ON %s FOR UPDATE USING (true) WITH CHECK (app_club_visible(club_id))',
+        tbl
+    );
+END;
+$$;
+
+-- Every club-scoped table, including those created since the policies
+-- were introduced.
+DO $$
+DECLARE
+    t REGCLASS;
+BEGIN
+    FOR t IN
+        SELECT format('%I.%I', c.table_schema, c.table_name)::regclass
+        FROM information_schema.columns c
+        JOIN information_schema.tables tb USING (table_schema, table_name)
+        WHERE c.table_schema = 'public'
+          AND c.column_name = 'club_id'
+          AND tb.table_type = 'BASE TABLE'
+    LOOP
+        PERFORM enable_club_isolation(t);
+    END LOOP;
+END;
+$$;
+
+-- ... and their players' own rows among them.
+DO $$
+DECLARE
+    t REGCLASS;
+BEGIN
+    FOR t IN
+        SELECT format('%I.%I', c.table_schema, c.table_name)::regclass
+        FROM information_schema.columns c
+        JOIN information_schema.tables tb USING (table_schema, table_name)
+        WHERE c.table_schema = 'public'
+          AND c.column_name = 'club_player_id'
+          AND tb.table_type = 'BASE TABLE'
+          AND EXISTS (
+              SELECT 1 FROM information_schema.columns k
+              WHERE k.table_schema = c.table_schema
+                AND k.table_name = c.table_name
+                AND k.column_name = 'club_id'
+          )
+    LOOP
+        PERFORM enable_own_player_rows(t);
+    END LOOP;
+END;
+$$;
+
+SELECT enable_tournament_isolation('tournament_registrations');
+SELECT enable_tournament_isolation('tournament_entries');
+SELECT enable_tournament_isolation('tournament_results');
+SELECT enable_own_rows('tournament_registrations');
+SELECT enable_own_rows('tournament_entries');
+SELECT enable_own_rows('tournament_results');
+SELECT enable_own_rows('club_player', 'app_user_id');
+
+SELECT enable_user_isolation('bankroll_transactions');
+SELECT enable_user_isolation('device_tokens');
+SELECT enable_user_isolation('notification_preferences');
+SELECT enable_user_isolation('contact_change_requests');
+
+SELECT enable_public_read('tournaments');
+SELECT enable_public_lock('tournaments');
+SELECT enable_public_read('announcements');
+SELECT enable_public_read('tournament_series');
+SELECT enable_public_read('season');
+SELECT enable_public_read('club_tables');
+SELECT enable_public_read('blind_structure_templates');
+SELECT enable_public_read('payout_templates');
+SELECT enable_public_read('leaderboard_configs');
+SELECT enable_public_read('club_stats_settings');
+SELECT enable_public_read('club_name_settings');
+SELECT enable_public_read('club_seating_settings');
+SELECT enable_public_read('rule_documents');
+SELECT enable_public_read('qualification_rules');
+SELECT enable_public_read('promotion_jackpots');
+
+SELECT enable_published_read('tournament_registrations');
+SELECT enable_published_read('tournament_entries');
+SELECT enable_published_read('tournament_results');
+
+-- The roster columns a player is shown by in public (standings,
+-- leaderboards, seating, galleries): every row the caller's scope sees,
+-- plus the players of published tournaments. The function reads past the
+-- scope, so the view passes it in.
+CREATE FUNCTION app_club_players_public(scoped BOOLEAN, clubs UUID[], viewer UUID)
+RETURNS TABLE (
+    id UUID, club_id UUID, display_name TEXT, app_user_id UUID,
+    created_at TIMESTAMPTZ, updated_at TIMESTAMPTZ
+)
+LANGUAGE plpgsql STABLE AS $$
+DECLARE
+    scope TEXT := current_setting('app.scoped', true);
+BEGIN
+    PERFORM set_config('app.scoped', '', true);
+    RETURN QUERY
+        SELECT cp.id, cp.club_id, cp.display_name, cp.app_user_id, cp.created_at, cp.updated_at
+        FROM club_player cp
+        WHERE NOT scoped
+           OR cp.club_id = ANY(clubs)
+           OR cp.app_user_id = viewer
+           OR EXISTS (
+               SELECT 1 FROM tournament_registrations r
+               WHERE r.club_player_id = cp.id AND app_tournament_published(r.tournament_id)
+           )
+           OR EXISTS (
+               SELECT 1 FROM tournament_results r
+               WHERE r.club_player_id = cp.id AND app_tournament_published(r.tournament_id)
+           )
+           OR EXISTS (
+               SELECT 1 FROM tournament_photo_tags t
+               JOIN tournament_photos p ON p.id = t.photo_id
+               WHERE t.club_player_id = cp.id AND app_tournament_published(p.tournament_id)
+           );
+    PERFORM set_config('app.scoped', COALESCE(scope, ''), true);
+END;
+$$;
+
+CREATE VIEW club_player_public AS
+SELECT * FROM app_club_players_public(app_scoped(), app_club_scope(), app_user_scope());
+
+-- Every other table keyed by a tournament, by the tournament's club.
+DO $$
+DECLARE
+    t REGCLASS;
+BEGIN
+    FOR t IN
+        SELECT format('%I.%I', c.table_schema, c.table_name)::regclass
+        FROM information_schema.columns c
+        JOIN information_schema.tables tb USING (table_schema, table_name)
+        WHERE c.table_schema = 'public'
+          AND c.column_name = 'tournament_id'
+          AND c.is_nullable = 'NO'
+          AND tb.table_type = 'BASE TABLE'
+          AND NOT EXISTS (
+              SELECT 1 FROM information_schema.columns k
+              WHERE k.table_schema = c.table_schema
+                AND k.table_name = c.table_name
+                AND k.column_name = 'club_id'
+          )
+    LOOP
+        PERFORM enable_tournament_isolation(t);
+    END LOOP;
+END;
+$$;
+
+-- What the clock, lobby and live pages show of a published tournament.
+SELECT enable_published_read('tournament_clocks');
+SELECT enable_published_read('tournament_structures');
+SELECT enable_published_read('tournament_color_ups');
+SELECT enable_published_read('tournament_payouts');
+SELECT enable_published_read('player_deals');
+SELECT enable_published_read('tournament_table_assignments');
+SELECT enable_published_read('table_seat_assignments');
+SELECT enable_published_read('tournament_stack_history');
+SELECT enable_published_read('tournament_bounties');
+SELECT enable_published_read('tournament_photos');
+SELECT enable_published_read('tournament_recaps');
+SELECT enable_published_read('tournament_tags');
+SELECT enable_published_read('registration_questions');
+SELECT enable_published_read('registration_groups');
+SELECT enable_published_read('raffles');
+
+-- A player's own requests, acknowledgments and predictions.
+SELECT enable_own_rows('seat_change_requests');
+SELECT enable_own_rows('rule_acknowledgments');
+SELECT enable_own_rows('prediction_entry', 'app_user_id');
+
+-- Whether the viewer plays in a tournament: registered and not cancelled.
+CREATE FUNCTION app_tournament_player(tournament UUID) RETURNS BOOLEAN
+LANGUAGE sql STABLE AS $$
+    SELECT EXISTS (
+        SELECT 1 FROM tournament_registrations r
+        WHERE r.tournament_id = tournament
+          AND r.user_id = app_user_scope()
+          AND r.status <> 'cancelled'
+    )
+$$;
+
+-- Its players read the tournament's chat and post as themselves; a muted
+-- player reads their mute.
+CREATE POLICY player_read ON tournament_chat_messages FOR SELECT
+    USING (app_tournament_player(tournament_id));
+CREATE POLICY player_post ON tournament_chat_messages FOR INSERT
+    WITH CHECK (user_id = app_user_scope() AND app_tournament_player(tournament_id));
+CREATE POLICY own_rows ON tournament_chat_mutes FOR SELECT
+    USING (user_id = app_user_scope());
+
+-- The public timeline and the friends feed replay the clock, seating and
+-- status events of published tournaments; the rest of the log is the club's.
+CREATE POLICY public_read ON tournament_activity_log FOR SELECT USING (
+    event_category IN ('clock', 'seating', 'tournament')
+    AND app_tournament_published(tournament_id)
+);
+
+-- Children of club- and tournament-scoped rows.
+SELECT enable_parent_isolation('registration_answers',
+    'EXISTS (SELECT 1 FROM tournament_registrations r WHERE r.id = registration_id '
+    'AND (app_tournament_visible(r.tournament_id) OR r.user_id = app_user_scope()))');
+SELECT enable_parent_isolation('registration_group_members',
+    'EXISTS (SELECT 1 FROM tournament_registrations r WHERE r.id = registration_id '
+    'AND (app_tournament_visible(r.tournament_id) OR r.user_id = app_user_scope()))');
+SELECT enable_parent_isolation('tournament_photo_tags',
+    'EXISTS (SELECT 1 FROM tournament_photos p WHERE p.id = photo_id '
+    'AND app_tournament_visible(p.tournament_id))');
+SELECT enable_parent_isolation('raffle_entrants',
+    'EXISTS (SELECT 1 FROM raffles r WHERE r.id = raffle_id AND app_tournament_visible(r.tournament_id))');
+SELECT enable_parent_isolation('incident_attachments',
+    'EXISTS (SELECT 1 FROM incidents i WHERE i.id = incident_id AND app_club_visible(i.club_id))');
+SELECT enable_parent_isolation('incident_players',
+    'EXISTS (SELECT 1 FROM incidents i WHERE i.id = incident_id AND app_club_visible(i.club_id))');
+SELECT enable_parent_isolation('leaderboard_adjustments',
+    'EXISTS (SELECT 1 FROM leaderboard_configs c WHERE c.id = config_id AND app_club_visible(c.club_id))');
+SELECT enable_parent_isolation('flight_qualifications',
+    'EXISTS (SELECT 1 FROM tournament_series s WHERE s.id = series_id AND app_club_visible(s.club_id))');
+SELECT enable_parent_isolation('promotion_hands',
+    'EXISTS (SELECT 1 FROM promotion_jackpots j WHERE j.id = jackpot_id AND app_club_visible(j.club_id))');
+SELECT enable_parent_isolation('promotion_jackpot_entries',
+    'EXISTS (SELECT 1 FROM promotion_jackpots j WHERE j.id = jackpot_id AND app_club_visible(j.club_id))');
+SELECT enable_parent_isolation('buy_in_credit_ledger',
+    'EXISTS (SELECT 1 FROM buy_in_credits c WHERE c.id = credit_id AND app_club_visible(c.club_id))');
+SELECT enable_parent_isolation('drink_ledger_entry',
+    'EXISTS (SELECT 1 FROM drink_wallet w WHERE w.id = wallet_id AND app_club_visible(w.club_id))');
+SELECT enable_parent_isolation('drink_redemption',
+    'EXISTS (SELECT 1 FROM drink_wallet w WHERE w.id = wallet_id AND app_club_visible(w.club_id))');
+-- Printed cards belong to no club until they're activated on a wallet.
+SELECT enable_parent_isolation('drink_wallet_credential',
+    'wallet_id IS NULL OR EXISTS (SELECT 1 FROM drink_wallet w WHERE w.id = wallet_id '
+    'AND app_club_visible(w.club_id))');
+
+-- ... readable where the parent is: the published side of a tournament,
+-- a public leaderboard's adjustments, a player's own credits and wallet.
+CREATE POLICY public_read ON registration_group_members FOR SELECT USING (EXISTS (
+    SELECT 1 FROM registration_groups g
+    WHERE g.id = group_id AND app_tournament_published(g.tournament_id)
+));
+CREATE POLICY public_read ON tournament_photo_tags FOR SELECT USING (EXISTS (
+    SELECT 1 FROM tournament_photos p
+    WHERE p.id = photo_id AND app_tournament_published(p.tournament_id)
+));
+CREATE POLICY public_read ON raffle_entrants FOR SELECT USING (EXISTS (
+    SELECT 1 FROM raffles r WHERE r.id = raffle_id AND app_tournament_published(r.tournament_id)
+));
+CREATE POLICY public_read ON promotion_hands FOR SELECT USING (EXISTS (
+    SELECT 1 FROM promotion_jackpots j WHERE j.id = jackpot_id AND j.is_active
+));
+CREATE POLICY public_read ON leaderboard_adjustments FOR SELECT USING (true);
+CREATE POLICY own_player_rows ON buy_in_credit_ledger FOR SELECT USING (EXISTS (
+    SELECT 1 FROM buy_in_credits c WHERE c.id = credit_id
+));
+CREATE POLICY own_player_rows ON drink_ledger_entry FOR SELECT USING (EXISTS (
+    SELECT 1 FROM drink_wallet w WHERE w.id = wallet_id
+));
+CREATE POLICY own_player_rows ON drink_redemption FOR SELECT USING (EXISTS (
+    SELECT 1 FROM drink_wallet w WHERE w.id = wallet_id
+));
+
+-- A jackpot's balance, shown on the club's public board. The ledger it sums
+-- stays with the club, so this reads past the caller's scope.
+CREATE FUNCTION app_jackpot_balance(jackpot UUID) RETURNS BIGINT
+LANGUAGE plpgsql STABLE AS $$
+DECLARE
+    scope TEXT := current_setting('app.scoped', true);
+    balance BIGINT;
+BEGIN
+    PERFORM set_config('app.scoped', '', true);
+    SELECT COALESCE(SUM(e.amount_cents), 0) INTO balance
+    FROM promotion_jackpot_entries e WHERE e.jackpot_id = jackpot;
+    PERFORM set_config('app.scoped', COALESCE(scope, ''), true);
+    RETURN balance;
+END;
+$$;
+
+-- A player's notes, and what hangs off them, are their author's alone.
+SELECT enable_user_isolation('player_note', 'author_app_user_id');
+SELECT enable_parent_isolation('player_note_tag',
+    'NOT app_scoped() OR EXISTS (SELECT 1 FROM player_note n WHERE n.id = note_id '
+    'AND n.author_app_user_id = app_user_scope())');
+SELECT enable_parent_isolation('showdown_observation',
+    'NOT app_scoped() OR EXISTS (SELECT 1 FROM player_note n WHERE n.id = note_id '
+    'AND n.author_app_user_id = app_user_scope())');
+
+-- Whether a user's rows are in scope: the user's own, or those of a player,
+-- manager or staff member of one of the scope's clubs.
+CREATE FUNCTION app_user_visible(member UUID) RETURNS BOOLEAN
+LANGUAGE sql STABLE AS $$
+    SELECT NOT app_scoped()
+        OR member = app_user_scope()
+        OR EXISTS (
+            SELECT 1 FROM club_player cp
+            WHERE cp.app_user_id = member AND cp.club_id = ANY(app_club_scope())
+        )
+        OR EXISTS (
+            SELECT 1 FROM club_managers m
+            WHERE m.user_id = member AND m.club_id = ANY(app_club_scope())
+        )
+        OR EXISTS (
+            SELECT 1 FROM club_staff s
+            WHERE s.user_id = member AND s.club_id = ANY(app_club_scope())
+        )
+$$;
+
+-- Isolate a table of a user's rows, owned through `owner`, by user and by
+-- the clubs the user belongs to.
+CREATE FUNCTION enable_member_isolation(tbl REGCLASS, owner TEXT) RETURNS VOID
+LANGUAGE plpgsql AS $$
+BEGIN
+    EXECUTE format('ALTER TABLE %s ENABLE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('ALTER TABLE %s FORCE ROW LEVEL SECURITY', tbl);
+    EXECUTE format('DROP POLICY IF EXISTS member_isolation ON %s', tbl);
+    EXECUTE format(
+        'CREATE POLICY member_isolation ON %s USING (app_user_visible(%I))',
+        tbl, owner
+    );
+END;
+$$;
+
+-- Accounts: a user's contact details and credentials reach only them and
+-- their clubs. Everyone reads the profile columns through `users_public`.
+SELECT enable_member_isolation('users', 'id');
+SELECT enable_member_isolation('user_audit_log', 'user_id');
+SELECT enable_member_isolation('player_achievements', 'user_id');
+SELECT enable_member_isolation('attendance_streak', 'app_user_id');
+SELECT enable_member_isolation('prediction_point_ledger', 'app_user_id');
+SELECT enable_member_isolation('season_pass', 'app_user_id');
+-- A public profile shows earned badges; photos and scouting respect
+-- everyone's privacy choices.
+SELECT enable_public_read('player_achievements');
+SELECT enable_user_isolation('user_privacy_settings', 'app_user_id');
+SELECT enable_public_read('user_privacy_settings');
+
+SELECT enable_user_isolation('quest_completion', 'app_user_id');
+SELECT enable_user_isolation('user_auth_identities');
+SELECT enable_user_isolation('refresh_tokens');
+SELECT enable_user_isolation('login_link_tokens');
+SELECT enable_user_isolation('password_reset_tokens');
+SELECT enable_user_isolation('oauth_access_tokens');
+SELECT enable_user_isolation('oauth_refresh_tokens');
+SELECT enable_user_isolation('oauth_authorization_codes');
+
+-- Follows and friendships are their two sides'.
+SELECT enable_user_isolation('friend_follow', 'follower_id');
+SELECT enable_own_rows('friend_follow', 'followee_id');
+SELECT enable_user_isolation('friendship', 'requester_id');
+SELECT enable_own_rows('friendship', 'addressee_id');
+
+CREATE FUNCTION app_users_public()
+RETURNS TABLE (
+    id UUID, username TEXT, first_name TEXT, last_name TEXT, avatar_url TEXT,
+    is_active BOOLEAN, role TEXT, locale TEXT, created_at TIMESTAMPTZ, updated_at TIMESTAMPTZ
+)
+LANGUAGE plpgsql STABLE AS $$
+DECLARE
+    scope TEXT := current_setting('app.scoped', true);
+BEGIN
+    PERFORM set_config('app.scoped', '', true);
+    RETURN QUERY
+        SELECT u.id, u.username, u.first_name, u.last_name, u.avatar_url,
+               u.is_active, u.role, u.locale::TEXT, u.created_at, u.updated_at
+        FROM users u;
+    PERFORM set_config('app.scoped', COALESCE(scope, ''), true);
+END;
+$$;
+
+-- The account registered under `address`, for matching an imported
+-- result or an invitee by email. Reads past the caller's scope.
+CREATE FUNCTION app_user_by_email(address TEXT) RETURNS UUID
+LANGUAGE plpgsql STABLE AS $$
+DECLARE
+    scope TEXT := current_setting('app.scoped', true);
+    found UUID;
+BEGIN
+    PERFORM set_config('app.scoped', '', true);
+    SELECT u.id INTO found FROM users u WHERE u.email = address;
+    PERFORM set_config('app.scoped', COALESCE(scope, ''), true);
+    RETURN found;
+END;
+$$;
+
+-- The profile columns anyone may read of any account: names on
+-- standings, seating, chat and friends lists.
+CREATE VIEW users_public AS
+SELECT * FROM app_users_public();
+
+-- A player's first roster entry at a club is named after their account,
+-- which the club can't see until the entry exists.
+CREATE OR REPLACE FUNCTION link_club_player()
+RETURNS TRIGGER AS $$
+DECLARE
+    v_club_id UUID;
+    v_cp_id   UUID;
+    v_name    TEXT;
+    v_scope   TEXT := current_setting('app.scoped', true);
+BEGIN
+    SELECT club_id INTO v_club_id FROM tournaments WHERE id = NEW.tournament_id;
+    IF v_club_id IS NULL THEN
+        RETURN NEW;
+    END IF;
+
+    IF NEW.club_player_id IS NOT NULL THEN
+        IF NEW.user_id IS NULL THEN
+            SELECT app_user_id INTO NEW.user_id
+                FROM club_player WHERE id = NEW.club_player_id;
+        END IF;
+        RETURN NEW;
+    END IF;
+
+    IF NEW.user_id IS NULL THEN
+        RETURN NEW;
+    END IF;
+
+    SELECT id INTO v_cp_id FROM club_player
+        WHERE club_id = v_club_id AND app_user_id = NEW.user_id;
+
+    IF v_cp_id IS NULL THEN
+        PERFORM set_config('app.scoped', '', true);
+        SELECT COALESCE(
+            NULLIF(TRIM(COALESCE(first_name, '') || ' ' || COALESCE(last_name, '')), ''),
+            username, email, 'Unknown'
+        ) INTO v_name FROM users WHERE id = NEW.user_id;
+        PERFORM set_config('app.scoped', COALESCE(v_scope, ''), true);
+
+        INSERT INTO club_player (club_id, display_name, app_user_id)
+            VALUES (v_club_id, COALESCE(v_name, 'Unknown'), NEW.user_id)
+            RETURNING id INTO v_cp_id;
+    END IF;
+
+    NEW.club_player_id := v_cp_id;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Whether the viewer administers `organization`. Reads past the caller's
+-- scope, as the admins table is itself isolated by it.
+CREATE FUNCTION app_organization_visible(organization UUID) RETURNS BOOLEAN
+LANGUAGE plpgsql STABLE AS $$
+DECLARE
+    scope TEXT := current_setting('app.scoped', true);
+    visible BOOLEAN;
+BEGIN
+    IF NOT app_scoped() THEN
+        RETURN true;
+    END IF;
+    PERFORM set_config('app.scoped', '', true);
+    SELECT EXISTS (
+        SELECT 1 FROM organization_admins oa
+        WHERE oa.organization_id = organization AND oa.user_id = app_user_scope()
+    ) INTO visible;
+    PERFORM set_config('app.scoped', scope, true);
+    RETURN visible;
+END;
+$$;
+
+-- Clubs and organizations are public; their managers and admins write them.
+ALTER TABLE clubs ENABLE ROW LEVEL SECURITY;
+ALTER TABLE clubs FORCE ROW LEVEL SECURITY;
+CREATE POLICY club_isolation ON clubs USING (app_club_visible(id));
+CREATE POLICY organization_clubs ON clubs
+    USING (organization_id IS NOT NULL AND app_organization_visible(organization_id));
+SELECT enable_public_read('clubs');
+SELECT enable_parent_isolation('organizations', 'app_organization_visible(id)');
+SELECT enable_public_read('organizations');
+SELECT enable_parent_isolation('organization_admins', 'app_organization_visible(organization_id)');
+
+-- Catalogs, auth challenges and codes only the service (or an admin)
+-- writes; the catalogs everyone reads.
+SELECT enable_service_only('achievements');
+SELECT enable_public_read('achievements');
+SELECT enable_service_only('tags');
+SELECT enable_public_read('tags');
+SELECT enable_service_only('persisted_operations');
+SELECT enable_service_only('oauth_clients');
+SELECT enable_service_only('redemption_codes');
+SELECT enable_service_only('spent_challenges');
+
+-- sqlx's own bookkeeping: only its owner, the migrating login, reads it.
+ALTER TABLE _sqlx_migrations ENABLE ROW LEVEL SECURITY;
+
+-- A new club's default templates are seeded in its name, whoever creates it
+-- (an organization admin adding a club isn't in its scope yet).
+CREATE OR REPLACE FUNCTION trg_seed_club_default_templates()
+RETURNS TRIGGER AS $$
+DECLARE
+    scope TEXT := current_setting('app.club_ids', true);
+BEGIN
+    PERFORM set_config('app.club_ids', array_append(app_club_scope(), NEW.id)::text, true);
+    PERFORM seed_club_default_templates(NEW.id);
+    PERFORM set_config('app.club_ids', COALESCE(scope, ''), true);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Staff don't act for their club: a dealer sees their own staff record and
+-- shifts and the chat of the club's tournaments; floor staff also run the
+-- desk (incidents, seat slips, receipts, tickets, jackpot hands) and read
+-- the roster for it.
+-- Reads past the caller's scope, as the staff table is itself isolated.
+CREATE FUNCTION app_club_staff(club UUID, staff_role TEXT DEFAULT NULL) RETURNS BOOLEAN
+LANGUAGE plpgsql STABLE AS $$
+DECLARE
+    scope TEXT := current_setting('app.scoped', true);
+    staffed BOOLEAN;
+BEGIN
+    IF NOT app_scoped() THEN
+        RETURN true;
+    END IF;
+    PERFORM set_config('app.scoped', '', true);
+    SELECT EXISTS (
+        SELECT 1 FROM club_staff s
+        WHERE s.club_id = club
+          AND s.user_id = app_user_scope()
+          AND s.is_active
+          AND (staff_role IS NULL OR s.role = staff_role)
+    ) INTO staffed;
+    PERFORM set_config('app.scoped', scope, true);
+    RETURN staffed;
+END;
+$$;
+
+CREATE POLICY own_rows ON club_staff FOR SELECT USING (user_id = app_user_scope());
+CREATE POLICY own_rows ON staff_shifts USING (EXISTS (
+    SELECT 1 FROM club_staff s WHERE s.id = staff_id AND s.user_id = app_user_scope()
+));
+CREATE POLICY staff_read ON tournament_chat_messages FOR SELECT USING (EXISTS (
+    SELECT 1 FROM tournaments t WHERE t.id = tournament_id AND app_club_staff(t.club_id)
+));
+CREATE POLICY staff_post ON tournament_chat_messages FOR INSERT WITH CHECK (
+    user_id = app_user_scope() AND EXISTS (
+        SELECT 1 FROM tournaments t WHERE t.id = tournament_id AND app_club_staff(t.club_id)
+    )
+);
+DO $$
+DECLARE
+    t REGCLASS;
+BEGIN
+    FOREACH t IN ARRAY ARRAY[
+        'incidents', 'seat_slip_print_jobs', 'entry_receipts', 'entry_tickets'
+    ]::REGCLASS[]
+    LOOP
+        EXECUTE format(
+            'CREATE POLICY floor_staff ON %s USING (app_club_staff(club_id, ''floor''))', t);
+    END LOOP;
+END;
+$$;
+CREATE POLICY floor_staff ON incident_players USING (EXISTS (
+    SELECT 1 FROM incidents i WHERE i.id = incident_id AND app_club_staff(i.club_id, 'floor')
+));
+CREATE POLICY floor_staff ON incident_attachments USING (EXISTS (
+    SELECT 1 FROM incidents i WHERE i.id = incident_id AND app_club_staff(i.club_id, 'floor')
+));
+CREATE POLICY floor_staff ON promotion_hands USING (EXISTS (
+    SELECT 1 FROM promotion_jackpots j WHERE j.id = jackpot_id AND app_club_staff(j.club_id, 'floor')
+));
+CREATE POLICY floor_staff ON club_player FOR SELECT
+    USING (app_club_staff(club_id, 'floor'));
+
+-- Deny by default: no table may be left without row-level security.
+DO $$
+DECLARE
+    missing TEXT;
+BEGIN
+    SELECT string_agg(c.relname, ', ' ORDER BY c.relname) INTO missing
+    FROM pg_class c JOIN pg_namespace n ON n.oid = c.relnamespace
+    WHERE n.nspname = 'public' AND c.relkind IN ('r', 'p') AND NOT c.relrowsecurity;
+    IF missing IS NOT NULL THEN
+        RAISE EXCEPTION 'row-level security is disabled on: %', missing;
+    END IF;
+END;
+$$;
@@ -0,0 +1,2 @@
+DROP TRIGGER IF EXISTS trg_tournament_entries_payouts_open ON tournament_entries;
+DROP FUNCTION IF EXISTS enforce_entry_payouts_open();
@@ -0,0 +1,37 @@
+-- Refuse entries that would move a finalized prize pool. The check runs in
+-- the entry's own statement and locks the payout row, so a concurrent
+-- finalize either waits for the entry or the entry sees the final table;
+-- without it an entry could land between the check and the write and
+-- `apply_tournament_payout` would silently leave it out of the pool.
+-- Vouchers and chip-only bonuses never reach the pool and stay allowed.
+-- Deletes cascading from a tournament or player (trigger depth > 1) pass.
+CREATE FUNCTION enforce_entry_payouts_open() RETURNS TRIGGER AS $$
+DECLARE
+    v_entry tournament_entries%ROWTYPE := COALESCE(NEW, OLD);
+    v_scope TEXT := current_setting('app.scoped', true);
+    v_finalized TIMESTAMPTZ;
+BEGIN
+    IF v_entry.entry_type IN ('voucher', 'bonus')
+        OR (TG_OP = 'DELETE' AND pg_trigger_depth() > 1) THEN
+        RETURN v_entry;
+    END IF;
+
+    PERFORM set_config('app.scoped', '', true);
+    SELECT finalized_at INTO v_finalized
+    FROM tournament_payouts WHERE tournament_id = v_entry.tournament_id
+    FOR UPDATE;
+    PERFORM set_config('app.scoped', COALESCE(v_scope, ''), true);
+
+    IF v_finalized IS NOT NULL THEN
+        RAISE EXCEPTION 'Payouts of tournament % are final', v_entry.tournament_id
+            USING ERRCODE = 'check_violation',
+                  CONSTRAINT = 'tournament_entries_payouts_final';
+    END IF;
+
+    RETURN v_entry;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_tournament_entries_payouts_open
+    BEFORE INSERT OR DELETE ON tournament_entries
+    FOR EACH ROW EXECUTE FUNCTION enforce_entry_payouts_open();
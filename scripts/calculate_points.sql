@@ -1,8 +1,9 @@
 -- Update existing tournament results to calculate points using the authoritative formula
 -- Formula: points = min(60, round(3 * (sqrt(field_size) / sqrt(rank)) * (log10(buy_in_eur) + 1) + 2))
+-- then weighted by the tournament's points_multiplier
 
 UPDATE tournament_results 
-SET points = LEAST(60, 
+SET points = ROUND(LEAST(60, 
     ROUND(
         3.0 * (
             SQRT(tournament_info.field_size::float) / SQRT(tournament_results.final_position::float)
@@ -10,15 +11,16 @@ SET points = LEAST(60,
             LOG(tournament_info.buy_in_eur) + 1.0
         ) + 2.0
     )::integer
-)
+) * tournament_info.points_multiplier)::integer
 FROM (
     SELECT 
         t.id as tournament_id,
         t.buy_in_cents::float / 100.0 as buy_in_eur,
+        t.points_multiplier,
         COUNT(tr_count.user_id)::float as field_size
     FROM tournaments t
     LEFT JOIN tournament_registrations tr_count ON t.id = tr_count.tournament_id
-    GROUP BY t.id, t.buy_in_cents
+    GROUP BY t.id, t.buy_in_cents, t.points_multiplier
 ) as tournament_info
 WHERE tournament_results.tournament_id = tournament_info.tournament_id
     AND tournament_results.points = 0  -- Only update results that haven't been calculated